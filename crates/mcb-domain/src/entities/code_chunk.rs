@@ -5,6 +5,10 @@
 //! of source code. Code chunks are the fundamental units of semantic indexing
 //! and search in the system.
 
+use mcb_utils::constants::keys::{METADATA_KEY_NODE_TYPE, METADATA_KEY_SYMBOL_NAME, NAME};
+use mcb_utils::constants::lang::CHUNK_ID_NAMESPACE;
+use mcb_utils::constants::search::TITLE_SIGNATURE_MAX_CHARS;
+use mcb_utils::utils::id;
 use serde::{Deserialize, Serialize};
 
 use crate::value_objects::Language;
@@ -55,3 +59,49 @@ pub struct CodeChunk {
     /// Additional metadata as JSON (context, AST info, etc.)
     pub metadata: serde_json::Value,
 }
+
+impl CodeChunk {
+    /// Deterministic vector record ID for this chunk.
+    ///
+    /// A UUID v5 over the file path, the symbol (metadata `name`, falling back
+    /// to `node_type`) and a hash of the content. Re-indexing unchanged code
+    /// yields the same ID, so stores upsert the record instead of adding a
+    /// duplicate. Line numbers are left out so that edits above a chunk do not
+    /// change its ID; identical chunks of one symbol in one file share an ID.
+    #[must_use]
+    pub fn stable_id(&self) -> String {
+        let symbol = [NAME, METADATA_KEY_NODE_TYPE]
+            .into_iter()
+            .find_map(|key| self.metadata.get(key).and_then(serde_json::Value::as_str))
+            .unwrap_or_default();
+        let key = format!(
+            "{}\n{symbol}\n{}",
+            self.file_path.replace('\\', "/"),
+            id::compute_content_hash(&self.content)
+        );
+        id::deterministic(CHUNK_ID_NAMESPACE, &key).to_string()
+    }
+
+    /// Text embedded as the chunk's title vector: the symbol name, a newline
+    /// and the signature (the first non-blank line, truncated).
+    ///
+    /// The name comes from metadata `symbol_name` (set by the AST chunkers),
+    /// falling back to `name`. Chunks without a symbol have no title.
+    #[must_use]
+    pub fn title(&self) -> Option<String> {
+        let name = [METADATA_KEY_SYMBOL_NAME, NAME]
+            .into_iter()
+            .find_map(|key| self.metadata.get(key).and_then(serde_json::Value::as_str))
+            .filter(|name| !name.trim().is_empty())?;
+        let signature: String = self
+            .content
+            .lines()
+            .map(str::trim)
+            .find(|line| !line.is_empty())
+            .unwrap_or_default()
+            .chars()
+            .take(TITLE_SIGNATURE_MAX_CHARS)
+            .collect();
+        Some(format!("{}\n{signature}", name.trim()))
+    }
+}
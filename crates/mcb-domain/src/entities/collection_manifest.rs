@@ -0,0 +1,28 @@
+//! Collection manifest entities.
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#core-entities)
+//!
+//! How a collection was built, checked before the collection is reused.
+
+use serde::{Deserialize, Serialize};
+
+use crate::value_objects::DistanceMetric;
+
+/// Build properties recorded for an indexed collection.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionManifest {
+    /// Collection the manifest describes.
+    pub collection: String,
+    /// Chunker version that produced every chunk in the collection.
+    pub chunker_version: u32,
+    /// Distance metric the collection's vectors were stored for.
+    pub distance_metric: DistanceMetric,
+    /// Embedding model the collection's vectors were produced by; `None` for
+    /// collections indexed before the model was recorded.
+    pub embedding_model: Option<String>,
+    /// Canonical directory the collection was last indexed from; `None` for
+    /// collections indexed before the root was recorded.
+    pub root_path: Option<String>,
+    /// Unix timestamp (seconds) of the last full index.
+    pub updated_at: i64,
+}
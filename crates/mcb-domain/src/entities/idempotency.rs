@@ -0,0 +1,25 @@
+//! Idempotency entities.
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#core-entities)
+//!
+//! Results of mutating tool calls stored under the client's idempotency
+//! key, so a retried call returns the first result instead of running again.
+
+use serde::{Deserialize, Serialize};
+
+/// Stored result of one mutating tool call.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IdempotencyRecord {
+    /// Organization the call ran for; keys of different organizations never match.
+    pub org_id: String,
+    /// Tool that was called.
+    pub tool_name: String,
+    /// Idempotency key sent by the client.
+    pub key: String,
+    /// Hash of the call's arguments; a retry with other arguments is rejected.
+    pub request_hash: String,
+    /// Serialized tool result returned to retries.
+    pub response: String,
+    /// Unix timestamp (seconds) of the first call.
+    pub created_at: i64,
+}
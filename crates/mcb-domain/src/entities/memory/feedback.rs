@@ -0,0 +1,53 @@
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../../docs/modules/domain.md#core-entities)
+//!
+use serde::{Deserialize, Serialize};
+use serde_with::skip_serializing_none;
+
+/// Relevance judgement on one search result.
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchFeedback {
+    /// Unique feedback identifier.
+    pub id: String,
+    /// Query the result was returned for.
+    pub query: String,
+    /// Collection the result came from.
+    pub collection: String,
+    /// Identifier of the rated chunk.
+    pub chunk_id: String,
+    /// File containing the rated chunk.
+    pub file_path: String,
+    /// `true` for thumbs up, `false` for thumbs down.
+    pub relevant: bool,
+    /// Session that gave the feedback, when known.
+    pub session_id: Option<String>,
+    /// Unix timestamp when the feedback was recorded.
+    pub created_at: i64,
+}
+
+/// Accumulated votes for one file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileFeedbackTally {
+    /// Collection the file belongs to.
+    pub collection: String,
+    /// File path as returned by search.
+    pub file_path: String,
+    /// Thumbs-up count.
+    pub up: u64,
+    /// Thumbs-down count.
+    pub down: u64,
+}
+
+/// Learned score multiplier for one file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FileBoost {
+    /// Collection the file belongs to.
+    pub collection: String,
+    /// File path as returned by search.
+    pub file_path: String,
+    /// Multiplier applied to search scores (1.0 is neutral).
+    pub boost: f64,
+    /// Unix timestamp when the boost was computed.
+    pub updated_at: i64,
+}
@@ -3,10 +3,12 @@
 //!
 //! Memory domain entities
 //!
-//! Includes observations, execution history, and memory search results.
+//! Includes observations, execution history, memory search results and
+//! search relevance feedback.
 
 mod error_pattern;
 mod execution;
+mod feedback;
 mod origin_context;
 mod quality_gate;
 mod search;
@@ -15,6 +17,7 @@ mod session;
 pub use super::observation::{Observation, ObservationMetadata, ObservationType};
 pub use error_pattern::{ErrorPattern, ErrorPatternCategory, ErrorPatternMatch};
 pub use execution::{ExecutionMetadata, ExecutionType};
+pub use feedback::{FileBoost, FileFeedbackTally, SearchFeedback};
 pub use origin_context::OriginContext;
 pub use quality_gate::{QualityGateResult, QualityGateStatus};
 pub use search::{MemoryFilter, MemorySearchIndex, MemorySearchResult};
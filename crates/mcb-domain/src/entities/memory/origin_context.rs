@@ -36,6 +36,8 @@ pub struct OriginContext {
     pub parent_session_id_correlation: Option<String>,
     /// The ID of the execution.
     pub execution_id: Option<String>,
+    /// Correlation ID of the MCP request that produced this record.
+    pub request_id: Option<String>,
     /// The name of the tool being executed.
     pub tool_name: Option<String>,
     /// The ID of the repository.
@@ -75,6 +77,7 @@ impl Default for OriginContext {
             parent_session_id: None,
             parent_session_id_correlation: None,
             execution_id: None,
+            request_id: None,
             tool_name: None,
             repo_id: None,
             repo_path: None,
@@ -19,10 +19,18 @@ pub use api_key::ApiKey;
 pub mod code_chunk;
 pub use code_chunk::CodeChunk;
 
+/// Build manifests of indexed collections
+pub mod collection_manifest;
+pub use collection_manifest::CollectionManifest;
+
 /// Entities for codebase state management and change tracking
 pub mod codebase;
 pub use codebase::{CodebaseSnapshot, FileSnapshot, SnapshotChanges};
 
+/// Stored results of mutating tool calls by idempotency key
+pub mod idempotency;
+pub use idempotency::IdempotencyRecord;
+
 /// Issue-related entities (comments, labels, etc.)
 pub mod issue;
 pub use issue::{IssueComment, IssueLabel, IssueLabelAssignment};
@@ -31,6 +39,10 @@ pub use issue::{IssueComment, IssueLabel, IssueLabelAssignment};
 pub mod memory;
 pub use memory::Observation;
 
+/// Import edges of the module dependency graph
+pub mod module_import;
+pub use module_import::ModuleImport;
+
 /// Observation entities
 pub mod observation;
 
@@ -57,10 +69,18 @@ pub use repository::{Branch, Repository, VcsType};
 pub mod submodule;
 pub use submodule::{SubmoduleDiscoveryConfig, SubmoduleInfo};
 
+/// Links between tests and the symbols they exercise
+pub mod test_link;
+pub use test_link::{TestLink, TestLinkReason};
+
 /// Team and team membership entities
 pub mod team;
 pub use team::{Team, TeamMember, TeamMemberRole};
 
+/// Monthly usage counters for reports and billing exports
+pub mod usage;
+pub use usage::{UsageFilter, UsageRecord};
+
 /// User entity
 pub mod user;
 pub use user::{User, UserRole};
@@ -75,6 +95,10 @@ pub use workflow::{
     Transition, TransitionInput, TransitionTrigger, WorkflowSession, WorkflowState,
 };
 
+/// Workspaces grouping the collections of related repositories
+pub mod workspace;
+pub use workspace::{CollectionFile, SharedFile, Workspace, WorkspaceMember};
+
 /// Git worktree entities
 pub mod worktree;
 pub use worktree::{AgentWorktreeAssignment, Worktree, WorktreeStatus};
@@ -0,0 +1,24 @@
+//! Module import entities.
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#core-entities)
+//!
+//! Import statements extracted from indexed files, the edges of the
+//! module-level dependency graph.
+
+use serde::{Deserialize, Serialize};
+
+/// One import of a module by an indexed file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModuleImport {
+    /// Collection the importing file was indexed into.
+    pub collection: String,
+    /// Workspace-relative path of the importing file.
+    pub source_file: String,
+    /// Import as written, e.g. `crate::config::Loader` or `./utils`.
+    pub specifier: String,
+    /// `/`-separated module path the specifier points at, workspace-relative
+    /// for relative and crate-local imports (e.g. `src/config/Loader`).
+    pub module_path: String,
+    /// Line of the import statement (1-based).
+    pub line: u32,
+}
@@ -0,0 +1,37 @@
+//! Test-to-code link entities.
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#core-entities)
+//!
+//! Links between test functions and the production symbols they exercise,
+//! extracted at index time so agents can find the tests covering a symbol.
+
+use serde::{Deserialize, Serialize};
+
+crate::define_string_enum! {
+    /// Evidence that a test exercises a symbol.
+    pub enum TestLinkReason [strum = "snake_case", serde = "snake_case"] {
+        /// The test body calls or names the symbol.
+        Call,
+        /// The test name is the symbol name with a test prefix or suffix.
+        Name,
+    }
+}
+
+/// A test function linked to a symbol it exercises.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestLink {
+    /// Collection the test was indexed into.
+    pub collection: String,
+    /// Workspace-relative path of the file containing the test.
+    pub test_file: String,
+    /// Name of the test function.
+    pub test_name: String,
+    /// First line of the test (1-based).
+    pub start_line: u32,
+    /// Last line of the test (1-based).
+    pub end_line: u32,
+    /// Name of the linked symbol.
+    pub target: String,
+    /// Why the test is linked to `target`.
+    pub reason: TestLinkReason,
+}
@@ -0,0 +1,53 @@
+//! Usage accounting entities.
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#core-entities)
+//!
+//! Monthly counters of what each organization indexed and searched, per
+//! collection, used for usage reports and billing exports.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+/// `chrono` format of [`UsageRecord::month`].
+const USAGE_MONTH_FORMAT: &str = "%Y-%m";
+
+/// Usage of one collection by one organization in one calendar month.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    /// Organization the usage is billed to.
+    pub org_id: String,
+    /// Collection the usage was recorded for.
+    pub collection: String,
+    /// Calendar month (UTC) as `YYYY-MM`.
+    pub month: String,
+    /// Bytes of file content chunked and embedded.
+    pub indexed_bytes: u64,
+    /// Estimated tokens sent to the embedding provider while indexing.
+    pub embedding_tokens: u64,
+    /// Code search queries run against the collection.
+    pub queries: u64,
+}
+
+impl UsageRecord {
+    /// Empty record for `org_id` and `collection` in the current month.
+    #[must_use]
+    pub fn current(org_id: &str, collection: &str) -> Self {
+        Self {
+            org_id: org_id.to_owned(),
+            collection: collection.to_owned(),
+            month: Utc::now().format(USAGE_MONTH_FORMAT).to_string(),
+            ..Self::default()
+        }
+    }
+}
+
+/// Selects usage records; unset bounds match every record.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UsageFilter {
+    /// Only this organization.
+    pub org_id: Option<String>,
+    /// First month included, as `YYYY-MM`.
+    pub from_month: Option<String>,
+    /// Last month included, as `YYYY-MM`.
+    pub to_month: Option<String>,
+}
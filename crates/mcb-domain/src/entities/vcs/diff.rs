@@ -63,3 +63,13 @@ pub struct RefDiff {
     /// Total number of lines deleted across all files
     pub total_deletions: usize,
 }
+
+/// Which uncommitted changes of a working tree to report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkingTreeScope {
+    /// Changes staged in the index, compared to `HEAD`.
+    Staged,
+    /// Committed, staged, unstaged and untracked changes since a revision
+    /// (branch, tag, SHA or expression such as `HEAD~1`).
+    Since(String),
+}
@@ -12,5 +12,5 @@ mod vcs_repo;
 
 pub use branch::VcsBranch;
 pub use commit::{VcsCommit, VcsCommitInput};
-pub use diff::{DiffStatus, FileDiff, RefDiff};
+pub use diff::{DiffStatus, FileDiff, RefDiff, WorkingTreeScope};
 pub use vcs_repo::VcsRepository;
@@ -0,0 +1,47 @@
+//! Workspace entities.
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#core-entities)
+//!
+//! Workspaces group the collections of related repositories, so files
+//! vendored into several of them are embedded once and searched together.
+
+use serde::{Deserialize, Serialize};
+
+/// A named group of collections searched together.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Workspace {
+    /// Workspace name.
+    pub name: String,
+    /// Member collections, in name order.
+    pub collections: Vec<WorkspaceMember>,
+}
+
+/// A collection belonging to a workspace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkspaceMember {
+    /// Collection name as given when it was added.
+    pub name: String,
+    /// Collection identifier, as the other repositories key collections.
+    pub collection_id: String,
+}
+
+/// A file in a collection.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CollectionFile {
+    /// Identifier of the collection the file was indexed into.
+    pub collection: String,
+    /// Workspace-relative path of the file.
+    pub file_path: String,
+}
+
+/// A file indexed as a copy of an identical file in another collection of
+/// the same workspace: its hash is recorded but its chunks are not stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SharedFile {
+    /// The copy.
+    pub copy: CollectionFile,
+    /// The file whose chunks serve the copy.
+    pub origin: CollectionFile,
+    /// Content hash both files share.
+    pub content_hash: String,
+}
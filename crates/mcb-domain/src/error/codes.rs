@@ -0,0 +1,203 @@
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md)
+//!
+//! Stable, machine-readable error codes for domain errors.
+//!
+//! Codes are part of the public MCP contract: once published, a code string is
+//! never renamed or reused. Clients branch on [`ErrorCode::as_str`] and
+//! [`ErrorCode::is_retryable`] instead of parsing error messages.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::Error;
+
+macro_rules! define_error_codes {
+    ($( $(#[$meta:meta])* $variant:ident => ($code:literal, $retryable:literal, $summary:literal), )+) => {
+        /// Stable error code attached to every domain error surfaced to clients.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+        pub enum ErrorCode {
+            $( $(#[$meta])* #[serde(rename = $code)] $variant, )+
+        }
+
+        impl ErrorCode {
+            /// Every registered error code, in registry order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// The stable wire representation (e.g. `MCB-EMB-429`).
+            #[must_use]
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $code,)+
+                }
+            }
+
+            /// Whether retrying the same request unchanged may succeed.
+            #[must_use]
+            pub const fn is_retryable(self) -> bool {
+                match self {
+                    $(Self::$variant => $retryable,)+
+                }
+            }
+
+            /// One-line, client-safe description of the failure class.
+            #[must_use]
+            pub const fn summary(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $summary,)+
+                }
+            }
+
+            /// Look up a code by its wire representation.
+            #[must_use]
+            pub fn parse(code: &str) -> Option<Self> {
+                match code {
+                    $($code => Some(Self::$variant),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_error_codes! {
+    /// Requested resource does not exist.
+    NotFound => ("MCB-NOT-FOUND", false, "Requested resource was not found"),
+    /// Caller supplied an invalid argument.
+    InvalidArgument => ("MCB-INVALID-ARG", false, "Invalid argument"),
+    /// Regular expression failed to compile.
+    InvalidRegex => ("MCB-INVALID-REGEX", false, "Invalid regular expression"),
+    /// VCS repository does not exist.
+    RepositoryNotFound => ("MCB-VCS-REPO-NOT-FOUND", false, "Repository not found"),
+    /// VCS branch does not exist.
+    BranchNotFound => ("MCB-VCS-BRANCH-NOT-FOUND", false, "Branch not found"),
+    /// Generic VCS failure.
+    Vcs => ("MCB-VCS", false, "Version control operation failed"),
+    /// Observation does not exist.
+    ObservationNotFound => ("MCB-MEM-NOT-FOUND", false, "Observation not found"),
+    /// Observation with the same content hash already stored.
+    DuplicateObservation => ("MCB-MEM-DUPLICATE", false, "Duplicate observation"),
+    /// Observation storage failure.
+    ObservationStorage => ("MCB-MEM-STORAGE", true, "Observation storage failed"),
+    /// Embedding provider rejected the request due to rate limiting.
+    EmbeddingRateLimited => ("MCB-EMB-429", true, "Embedding provider rate limit exceeded"),
+    /// Embedding provider did not answer in time.
+    EmbeddingTimeout => ("MCB-EMB-TIMEOUT", true, "Embedding provider timed out"),
+    /// Generic embedding provider failure.
+    Embedding => ("MCB-EMB", false, "Embedding provider failed"),
+    /// Vector dimensions do not match the collection.
+    VectorDimensionMismatch => (
+        "MCB-VS-DIM-MISMATCH",
+        false,
+        "Embedding dimensions do not match the collection"
+    ),
+    /// Vector collection does not exist.
+    VectorCollectionNotFound => ("MCB-VS-COLLECTION-NOT-FOUND", false, "Collection not found"),
+    /// Write would exceed a configured storage quota.
+    VectorQuotaExceeded => ("MCB-VS-QUOTA", false, "Vector store storage quota exceeded"),
+    /// Vector store circuit is open after repeated failures; calls fail fast.
+    VectorStoreUnavailable => ("MCB-VS-UNAVAILABLE", true, "Vector store unavailable"),
+    /// Generic vector store failure.
+    VectorStore => ("MCB-VS", true, "Vector store operation failed"),
+    /// Relational database failure.
+    Database => ("MCB-DB", true, "Database operation failed"),
+    /// Outbound network failure.
+    Network => ("MCB-NET", true, "Network operation failed"),
+    /// Credentials missing or rejected.
+    Authentication => ("MCB-AUTH", false, "Authentication failed"),
+    /// Configuration missing or invalid.
+    Config => ("MCB-CONFIG", false, "Configuration error"),
+    /// Cache failure.
+    Cache => ("MCB-CACHE", true, "Cache operation failed"),
+    /// Local I/O failure.
+    Io => ("MCB-IO", true, "I/O operation failed"),
+    /// Payload could not be encoded or decoded.
+    Encoding => ("MCB-ENCODING", false, "Encoding error"),
+    /// Browse or highlight failure.
+    Browse => ("MCB-BROWSE", false, "Browse operation failed"),
+    /// Infrastructure component unavailable.
+    Infrastructure => ("MCB-INFRA", true, "Infrastructure error"),
+    /// Unclassified internal failure.
+    Internal => ("MCB-INTERNAL", false, "Internal error"),
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn mentions_any(message: &str, needles: &[&str]) -> bool {
+    let lower = message.to_ascii_lowercase();
+    needles.iter().any(|needle| lower.contains(needle))
+}
+
+fn embedding_code(message: &str) -> ErrorCode {
+    if mentions_any(message, &["429", "rate limit", "too many requests"]) {
+        ErrorCode::EmbeddingRateLimited
+    } else if mentions_any(message, &["timeout", "timed out"]) {
+        ErrorCode::EmbeddingTimeout
+    } else {
+        ErrorCode::Embedding
+    }
+}
+
+fn vector_db_code(message: &str) -> ErrorCode {
+    if mentions_any(message, &["dimension"]) {
+        ErrorCode::VectorDimensionMismatch
+    } else if mentions_any(message, &["storage quota exceeded"]) {
+        ErrorCode::VectorQuotaExceeded
+    } else if mentions_any(message, &["circuit open"]) {
+        ErrorCode::VectorStoreUnavailable
+    } else if mentions_any(message, &["collection"])
+        && mentions_any(message, &["not found", "does not exist"])
+    {
+        ErrorCode::VectorCollectionNotFound
+    } else {
+        ErrorCode::VectorStore
+    }
+}
+
+impl Error {
+    /// Stable machine-readable code for this error.
+    ///
+    /// Provider errors that only carry a message (`Embedding`, `VectorDb`) are
+    /// refined by well-known markers so that rate limits and dimension
+    /// mismatches get their own codes.
+    #[must_use]
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::NotFound { .. } => ErrorCode::NotFound,
+            Self::InvalidArgument { .. } => ErrorCode::InvalidArgument,
+            Self::InvalidRegex { .. } => ErrorCode::InvalidRegex,
+            Self::RepositoryNotFound { .. } => ErrorCode::RepositoryNotFound,
+            Self::BranchNotFound { .. } => ErrorCode::BranchNotFound,
+            Self::Vcs { .. } => ErrorCode::Vcs,
+            Self::ObservationNotFound { .. } => ErrorCode::ObservationNotFound,
+            Self::DuplicateObservation { .. } => ErrorCode::DuplicateObservation,
+            Self::ObservationStorage { .. } => ErrorCode::ObservationStorage,
+            Self::Embedding { message } => embedding_code(message),
+            Self::VectorDb { message } => vector_db_code(message),
+            Self::Database { .. } => ErrorCode::Database,
+            Self::Network { .. } => ErrorCode::Network,
+            Self::Authentication { .. } => ErrorCode::Authentication,
+            Self::Config { .. }
+            | Self::Configuration { .. }
+            | Self::ConfigMissing(_)
+            | Self::ConfigInvalid { .. } => ErrorCode::Config,
+            Self::Cache { .. } => ErrorCode::Cache,
+            Self::IoSimple { .. } | Self::Io { .. } => ErrorCode::Io,
+            Self::Json { .. } | Self::Utf8(_) | Self::Base64(_) => ErrorCode::Encoding,
+            Self::Browse(_) | Self::Highlight(_) => ErrorCode::Browse,
+            Self::Infrastructure { .. } => ErrorCode::Infrastructure,
+            Self::Internal { .. } | Self::Generic(_) => ErrorCode::Internal,
+        }
+    }
+
+    /// Whether retrying the failed operation unchanged may succeed.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        self.code().is_retryable()
+    }
+}
@@ -3,6 +3,8 @@
 //!
 //! Error handling types organized by domain
 
+mod codes;
 mod types;
 
+pub use codes::ErrorCode;
 pub use types::*;
@@ -73,7 +73,8 @@ pub mod infra;
 pub mod ports;
 /// MCP JSON-RPC protocol types (domain-level contract)
 pub mod protocol;
-/// Provider auto-registration registry
+/// Provider auto-registration registry (linker sections, so not on wasm32)
+#[cfg(not(target_arch = "wasm32"))]
 pub mod registry;
 /// Common utilities
 pub mod utils;
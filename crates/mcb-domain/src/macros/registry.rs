@@ -50,7 +50,11 @@ macro_rules! impl_registry {
             pub name: &'static str,
             /// Human-readable description
             pub description: &'static str,
-            /// Constructor function to create provider instance
+            /// Constructor function to create provider instance.
+            ///
+            /// Runs synchronously, possibly on a current-thread runtime:
+            /// defer network I/O to the first operation instead of blocking
+            /// on it here.
             pub build: fn(&$config) -> $crate::error::Result<std::sync::Arc<dyn $trait>>,
         }
 
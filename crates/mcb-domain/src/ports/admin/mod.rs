@@ -7,6 +7,7 @@ pub mod dashboard;
 /// Indexing operation tracking ports.
 pub mod indexing;
 /// Provider admin interfaces (embedding, vector store, language).
+#[cfg(not(target_arch = "wasm32"))]
 pub mod provider_admin;
 /// Validation operation tracking ports.
 pub mod validation;
@@ -16,6 +17,7 @@ pub use dashboard::{
     AgentSessionStats, DailyCount, DashboardQueryPort, MonthlyCount, ToolCallCount,
 };
 pub use indexing::{IndexingOperation, IndexingOperationStatus, IndexingOperationsInterface};
+#[cfg(not(target_arch = "wasm32"))]
 pub use provider_admin::{
     EmbeddingAdminInterface, LanguageAdminInterface, ProviderInfo, VectorStoreAdminInterface,
 };
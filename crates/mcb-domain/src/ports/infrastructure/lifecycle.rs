@@ -62,6 +62,18 @@ pub struct ExtendedHealthResponse {
     pub dependencies_status: DependencyHealth,
 }
 
+/// A single dependency probed by readiness checks (`/readyz`).
+///
+/// Implementations should be cheap and side-effect free; callers apply their
+/// own timeout and caching.
+#[async_trait::async_trait]
+pub trait HealthProbe: Send + Sync {
+    /// Dependency name reported in readiness output (e.g. `database`).
+    fn name(&self) -> &str;
+    /// Check the dependency; `Ok` means it can serve requests.
+    async fn probe(&self) -> Result<()>;
+}
+
 /// Interface for graceful shutdown coordination
 pub trait ShutdownCoordinator: Send + Sync {
     /// Signal that a shutdown has been initiated.
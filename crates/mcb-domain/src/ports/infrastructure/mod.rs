@@ -21,7 +21,7 @@ pub use config::ConfigProvider;
 pub use events::{DomainEventStream, EventBusProvider};
 pub use graphql::{GraphQLSchemaProvider, SharedGraphQLSchemaProvider};
 pub use lifecycle::{
-    DependencyHealth, DependencyHealthCheck, ExtendedHealthResponse, LifecycleManaged,
+    DependencyHealth, DependencyHealthCheck, ExtendedHealthResponse, HealthProbe, LifecycleManaged,
     PortServiceState, ShutdownCoordinator,
 };
 pub use logging::{LogLevel, OperationLogger};
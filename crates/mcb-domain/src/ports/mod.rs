@@ -42,53 +42,75 @@ pub mod validation;
 
 // --- Admin ---
 pub use admin::{
-    AgentSessionStats, DailyCount, DashboardQueryPort, EmbeddingAdminInterface, IndexingOperation,
-    IndexingOperationStatus, IndexingOperationsInterface, LanguageAdminInterface, MonthlyCount,
-    ProviderInfo, ToolCallCount, ValidationOperation, ValidationOperationResult,
-    ValidationOperationsInterface, ValidationStatus, ValidatorJobRunner, VectorStoreAdminInterface,
+    AgentSessionStats, DailyCount, DashboardQueryPort, IndexingOperation, IndexingOperationStatus,
+    IndexingOperationsInterface, MonthlyCount, ToolCallCount, ValidationOperation,
+    ValidationOperationResult, ValidationOperationsInterface, ValidationStatus, ValidatorJobRunner,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use admin::{
+    EmbeddingAdminInterface, LanguageAdminInterface, ProviderInfo, VectorStoreAdminInterface,
 };
 
 // --- Infrastructure ---
 pub use infrastructure::{
     ConfigProvider, DependencyHealth, DependencyHealthCheck, DomainEventStream, EventBusProvider,
-    ExtendedHealthResponse, GraphQLSchemaProvider, LifecycleManaged, LogLevel, MigrationProvider,
-    OperationLogger, PortServiceState, ProviderContext, ProviderHealthStatus, ProviderRouter,
-    SharedGraphQLSchemaProvider, SharedMigrationProvider, ShutdownCoordinator,
+    ExtendedHealthResponse, GraphQLSchemaProvider, HealthProbe, LifecycleManaged, LogLevel,
+    MigrationProvider, OperationLogger, PortServiceState, ProviderContext, ProviderHealthStatus,
+    ProviderRouter, SharedGraphQLSchemaProvider, SharedMigrationProvider, ShutdownCoordinator,
 };
 
 // --- Providers ---
 pub use providers::{
     AnalysisFinding, CodeAnalyzer, CryptoProvider, EmbeddingProvider, EncryptedData,
-    HttpClientConfig, HttpClientProvider, HybridSearchProvider, HybridSearchResult,
-    LanguageChunkingProvider, MetricLabels, MetricsError, MetricsProvider, MetricsProviderExt,
-    MetricsResult, ProjectDetector, ProviderConfigManagerInterface, VcsProvider, VectorStoreAdmin,
-    VectorStoreBrowser, VectorStoreProvider,
+    HistogramSummary, HostPoolStats, HttpClientConfig, HttpClientProvider, HttpPoolStats,
+    HttpTransportConfig, HybridSearchProvider, HybridSearchResult, InsertBatchPolicy,
+    LanguageChunkingProvider, MetricLabels, MetricSample, MetricsError, MetricsProvider,
+    MetricsProviderExt, MetricsResult, MetricsSnapshot, MetricsSnapshotProvider, PersistencePolicy,
+    ProjectDetector, ProviderConfigManagerInterface, QueryGenerationProvider, RequestSigner,
+    RequestSigningConfig, ResiliencePolicy, SigV4Config, SignableRequest, StorageQuotaPolicy,
+    StorageUsage, TombstonePolicy, VcsProvider, VectorStoreAdmin, VectorStoreBrowser,
+    VectorStoreCapabilities, VectorStoreFeature, VectorStoreProvider,
 };
 
 // --- Repositories ---
 pub use repositories::{
     AgentAssignmentManager, AgentCheckpointRepository, AgentEventRepository, AgentRepository,
     AgentSessionQuery, AgentSessionRepository, ApiKeyInfo, ApiKeyRegistry, AuthRepositoryPort,
-    FileHashRepository, FtsSearchResult, IndexRepository, IndexStats, IssueCommentRegistry,
+    CollectionManifestRepository, FileHashRepository, FileSnapshotRepository, FtsSearchResult,
+    IdempotencyRepository, IndexRepository, IndexStats, IssueCommentRegistry,
     IssueEntityRepository, IssueLabelAssignmentManager, IssueLabelRegistry, IssueRegistry,
-    MemoryRepository, OrgEntityRepository, OrgRegistry, PlanEntityRepository, PlanRegistry,
-    PlanReviewRegistry, PlanVersionRegistry, ProjectRepository, TeamMemberManager, TeamRegistry,
-    TransitionRepository, UserRegistry, UserWithApiKey, VcsBranchRegistry, VcsEntityRepository,
-    VcsRepositoryRegistry, VcsWorktreeRegistry, WorkflowSessionRepository,
+    MemoryRepository, ModuleImportRepository, OrgEntityRepository, OrgRegistry,
+    PlanEntityRepository, PlanRegistry, PlanReviewRegistry, PlanVersionRegistry, ProjectRepository,
+    SearchFeedbackRepository, TeamMemberManager, TeamRegistry, TestLinkRepository,
+    TransitionRepository, UsageRepository, UserRegistry, UserWithApiKey, VcsBranchRegistry,
+    VcsEntityRepository, VcsRepositoryRegistry, VcsWorktreeRegistry, WorkflowSessionRepository,
+    WorkspaceRepository,
 };
 
 // --- Services ---
 pub use services::{
     AgentSessionManager, AgentSessionServiceInterface, BatchIndexingServiceInterface, BrowseError,
-    BrowseServiceInterface, CheckpointManager, ChunkingOptions, ChunkingOrchestratorInterface,
-    ChunkingResult, CodeChunker, ComplexityReport, ContextServiceInterface,
-    CreateSessionSummaryInput, DelegationTracker, ErrorPatternManager, FileHashService,
-    FunctionComplexity, HighlightError, HighlightServiceInterface, IndexingResult,
-    IndexingServiceInterface, IndexingStats, IndexingStatus, Job, JobCounts, JobId,
-    JobManagerInterface, JobProgressUpdate, JobResult, JobStatus, JobType, MemorySearcher,
-    MemoryServiceInterface, ObservationManager, ProjectDetectorService, RuleInfo, SearchFilters,
-    SearchServiceInterface, SessionSummaryManager, StoreObservationInput, ValidationReport,
-    ValidationServiceInterface, ViolationEntry,
+    BrowseServiceInterface, BulkheadInterface, BulkheadPolicy, ChangeBaseline, ChangeImpact,
+    ChangeSummary, ChangeSummaryServiceInterface, CheckpointManager, ChunkingOptions,
+    ChunkingOrchestratorInterface, ChunkingResult, CloneKind, CodeChunker, CodeFragment,
+    ComplexityReport, ConfirmedResult, ContextBatch, ContextServiceInterface,
+    CreateSessionSummaryInput, DelegationTracker, Diversification, DiversifyBy,
+    DuplicateDetectionServiceInterface, DuplicatePair, DuplicateQuery, DuplicateReport,
+    EmbeddingProjection, ErrorPatternManager, FileChangeSummary, FileContentServiceInterface,
+    FileContentSource, FileHashService, FileReadRequest, FileSlice, FunctionComplexity, GrepMatch,
+    GrepOutcome, GrepQuery, GrepServiceInterface, HighlightError, HighlightServiceInterface,
+    ImpactedModule, IndexEvaluationServiceInterface, IndexingEstimate, IndexingResult,
+    IndexingServiceInterface, IndexingStats, IndexingStatus, IndexingThrottlePolicy, Job,
+    JobCounts, JobId, JobManagerInterface, JobPermit, JobPriority, JobProgressUpdate, JobResult,
+    JobSchedulerInterface, JobSchedulerPolicy, JobStatus, JobType, MemorySearcher,
+    MemoryServiceInterface, ModuleDependencies, ModuleDependency, ModuleGraphServiceInterface,
+    ModuleImporter, ObservationManager, OrgUsage, ProjectDetectorService, RecordFeedbackInput,
+    RepoAnalysis, RepoAnalysisServiceInterface, RepoChurn, RepoProfile, RepoRecommendation,
+    RuleInfo, SearchFeedbackServiceInterface, SearchFilters, SearchServiceInterface,
+    SelfEvalOptions, SelfEvalReport, SessionContextServiceInterface, SessionSummaryManager,
+    StoreObservationInput, SymbolChange, SymbolChangeKind, UsageReport, UsageServiceInterface,
+    ValidationReport, ValidationServiceInterface, ViolationEntry, Workload, WorkspaceSearchResult,
+    WorkspaceServiceInterface,
 };
 
 // --- Validation abstractions ---
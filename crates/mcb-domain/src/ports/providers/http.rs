@@ -5,35 +5,338 @@ use std::time::Duration;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use mcb_utils::constants::http::{
+    CONNECTION_TIMEOUT_SECS, HTTP_CLIENT_IDLE_TIMEOUT_SECS, HTTP_KEEPALIVE_SECS,
+    HTTP_MAX_CONNECTIONS_PER_HOST, HTTP_MAX_IDLE_PER_HOST, HTTP_REQUEST_TIMEOUT_SECS,
+    HTTP2_KEEPALIVE_INTERVAL_SECS, PROVIDER_RETRY_BACKOFF_MS, PROVIDER_RETRY_COUNT,
+    PROVIDER_RETRY_JITTER, PROVIDER_RETRY_MAX_BACKOFF_MS,
+};
+
+use crate::error::{Error, Result};
+
+/// Timeout and retry policy for outbound provider requests.
+///
+/// Configured per provider under `providers.<kind>.resilience`; any field left
+/// out of the config keeps its default.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ResiliencePolicy {
+    /// TCP/TLS connect timeout in milliseconds.
+    pub connect_timeout_ms: u64,
+    /// Whole-request timeout in milliseconds.
+    pub request_timeout_ms: u64,
+    /// Retries after the first attempt for transient failures (0 disables retry).
+    pub max_retries: usize,
+    /// Delay before the first retry in milliseconds; doubled on each retry.
+    pub backoff_ms: u64,
+    /// Upper bound for a single backoff delay in milliseconds.
+    pub max_backoff_ms: u64,
+    /// Fraction (0.0..=1.0) of each backoff delay randomly subtracted.
+    pub jitter: f64,
+}
+
+impl ResiliencePolicy {
+    /// Connect timeout as a [`Duration`].
+    #[must_use]
+    pub const fn connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout_ms)
+    }
+
+    /// Request timeout as a [`Duration`].
+    #[must_use]
+    pub const fn request_timeout(&self) -> Duration {
+        Duration::from_millis(self.request_timeout_ms)
+    }
+
+    /// Initial backoff as a [`Duration`].
+    #[must_use]
+    pub const fn backoff(&self) -> Duration {
+        Duration::from_millis(self.backoff_ms)
+    }
+
+    /// Backoff cap as a [`Duration`].
+    #[must_use]
+    pub const fn max_backoff(&self) -> Duration {
+        Duration::from_millis(self.max_backoff_ms)
+    }
+
+    /// Total attempts including the first one.
+    #[must_use]
+    pub const fn max_attempts(&self) -> usize {
+        self.max_retries + 1
+    }
+
+    /// Validate ranges; `key` is the config path used in error messages.
+    ///
+    /// # Errors
+    /// Returns a configuration error for zero timeouts, a backoff cap below
+    /// the initial backoff, or a jitter outside `0.0..=1.0`.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        if self.connect_timeout_ms == 0 || self.request_timeout_ms == 0 {
+            return Err(Error::config_invalid(
+                key,
+                "timeouts must be greater than zero",
+            ));
+        }
+        if self.max_backoff_ms < self.backoff_ms {
+            return Err(Error::config_invalid(
+                format!("{key}.max_backoff_ms"),
+                "must be greater than or equal to backoff_ms",
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.jitter) {
+            return Err(Error::config_invalid(
+                format!("{key}.jitter"),
+                "must be between 0.0 and 1.0",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ResiliencePolicy {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: CONNECTION_TIMEOUT_SECS * 1000,
+            request_timeout_ms: HTTP_REQUEST_TIMEOUT_SECS * 1000,
+            max_retries: PROVIDER_RETRY_COUNT,
+            backoff_ms: PROVIDER_RETRY_BACKOFF_MS,
+            max_backoff_ms: PROVIDER_RETRY_MAX_BACKOFF_MS,
+            jitter: PROVIDER_RETRY_JITTER,
+        }
+    }
+}
+
+/// Outbound proxy and trust-store settings shared by all HTTP providers.
+///
+/// Without an explicit `proxy`, the standard `HTTPS_PROXY`/`HTTP_PROXY`/`NO_PROXY`
+/// environment variables still apply.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HttpTransportConfig {
+    /// Proxy URL for all outbound requests (e.g. `http://proxy.corp:3128`).
+    pub proxy: Option<String>,
+    /// Comma-separated hosts that bypass `proxy` (same syntax as `NO_PROXY`).
+    pub no_proxy: Option<String>,
+    /// PEM bundles with additional root CAs, trusted alongside the system roots.
+    pub ca_bundles: Vec<PathBuf>,
+}
+
+impl HttpTransportConfig {
+    /// Validate settings that can be checked without network access.
+    ///
+    /// # Errors
+    /// Returns a configuration error for an empty proxy URL or a missing CA bundle.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        if self.proxy.as_deref().is_some_and(|p| p.trim().is_empty()) {
+            return Err(Error::config_invalid(
+                format!("{key}.proxy"),
+                "must not be empty",
+            ));
+        }
+        if let Some(missing) = self.ca_bundles.iter().find(|path| !path.is_file()) {
+            return Err(Error::config_invalid(
+                format!("{key}.ca_bundles"),
+                format!("CA bundle not found: {}", missing.display()),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// AWS Signature Version 4 signing parameters.
+///
+/// Credentials left out of the config are read from `AWS_ACCESS_KEY_ID`,
+/// `AWS_SECRET_ACCESS_KEY` and `AWS_SESSION_TOKEN` when the signer is built.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SigV4Config {
+    /// Signing region (e.g. `eu-west-1`).
+    pub region: String,
+    /// Signing service name (e.g. `execute-api`, `bedrock`).
+    pub service: String,
+    /// Access key ID.
+    #[serde(default)]
+    pub access_key_id: Option<String>,
+    /// Secret access key.
+    #[serde(default)]
+    pub secret_access_key: Option<String>,
+    /// Session token for temporary credentials.
+    #[serde(default)]
+    pub session_token: Option<String>,
+}
+
+/// Request signing scheme for gateways that authenticate every request.
+///
+/// Selected with `scheme:`; signers are built by the provider layer and may
+/// require an optional feature (`signing-sigv4` for [`Self::Sigv4`]).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "scheme", rename_all = "snake_case")]
+pub enum RequestSigningConfig {
+    /// AWS Signature Version 4.
+    Sigv4(SigV4Config),
+}
+
+impl RequestSigningConfig {
+    /// Scheme name as written in the config.
+    #[must_use]
+    pub const fn scheme(&self) -> &'static str {
+        match self {
+            Self::Sigv4(_) => "sigv4",
+        }
+    }
+
+    /// Validate settings that can be checked without credentials lookup.
+    ///
+    /// # Errors
+    /// Returns a configuration error for an empty region or service, or when
+    /// only one half of an access key pair is configured.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        match self {
+            Self::Sigv4(config) => {
+                if config.region.trim().is_empty() {
+                    return Err(Error::config_invalid(
+                        format!("{key}.region"),
+                        "must not be empty",
+                    ));
+                }
+                if config.service.trim().is_empty() {
+                    return Err(Error::config_invalid(
+                        format!("{key}.service"),
+                        "must not be empty",
+                    ));
+                }
+                if config.access_key_id.is_some() != config.secret_access_key.is_some() {
+                    return Err(Error::config_invalid(
+                        key,
+                        "access_key_id and secret_access_key must be set together",
+                    ));
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// An outbound request as seen by a [`RequestSigner`].
+///
+/// The body is the exact byte payload that will be sent; signers append or
+/// replace entries in `headers`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SignableRequest {
+    /// Upper-case HTTP method.
+    pub method: String,
+    /// Absolute request URL.
+    pub url: String,
+    /// Request headers (names compared case-insensitively).
+    pub headers: Vec<(String, String)>,
+    /// Request body.
+    pub body: Vec<u8>,
+}
+
+impl SignableRequest {
+    /// Value of the first header named `name` (case-insensitive).
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Replace every header named `name` (case-insensitive) with a single value.
+    pub fn set_header(&mut self, name: &str, value: impl Into<String>) {
+        self.headers
+            .retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+        self.headers.push((name.to_owned(), value.into()));
+    }
+}
+
+/// Hook that authenticates an outbound request right before it is sent.
+///
+/// Invoked once per attempt, so retried requests carry fresh signatures.
+pub trait RequestSigner: Send + Sync {
+    /// Scheme name, for logs and diagnostics.
+    fn scheme(&self) -> &str;
+
+    /// Add authentication headers to `request`.
+    ///
+    /// # Errors
+    /// Returns an error if the request cannot be signed (e.g. invalid URL).
+    fn sign(&self, request: &mut SignableRequest) -> Result<()>;
+}
 
 /// HTTP client configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HttpClientConfig {
     /// Maximum number of idle connections per host.
     pub max_idle_per_host: usize,
+    /// Maximum number of concurrent requests per host (0 = unlimited).
+    pub max_connections_per_host: usize,
     /// Maximum idle time for a connection.
     pub idle_timeout: Duration,
     /// Connection keep-alive interval.
     pub keepalive: Duration,
+    /// HTTP/2 PING interval for idle pooled connections (negotiated via ALPN).
+    pub http2_keepalive: Duration,
     /// Request timeout.
     pub timeout: Duration,
     /// User agent string for outgoing requests.
     pub user_agent: String,
+    /// Timeout and retry policy applied to every request.
+    pub resilience: ResiliencePolicy,
+    /// Proxy and additional root CAs.
+    pub transport: HttpTransportConfig,
+    /// Optional per-request signing.
+    pub signing: Option<RequestSigningConfig>,
 }
 
 impl Default for HttpClientConfig {
     fn default() -> Self {
         Self {
-            max_idle_per_host: 10,
-            idle_timeout: Duration::from_secs(90),
-            keepalive: Duration::from_secs(60),
-            timeout: Duration::from_secs(30),
+            max_idle_per_host: HTTP_MAX_IDLE_PER_HOST,
+            max_connections_per_host: HTTP_MAX_CONNECTIONS_PER_HOST,
+            idle_timeout: Duration::from_secs(HTTP_CLIENT_IDLE_TIMEOUT_SECS),
+            keepalive: Duration::from_secs(HTTP_KEEPALIVE_SECS),
+            http2_keepalive: Duration::from_secs(HTTP2_KEEPALIVE_INTERVAL_SECS),
+            timeout: Duration::from_secs(HTTP_REQUEST_TIMEOUT_SECS),
             user_agent: "mcb/domain-client".to_owned(),
+            resilience: ResiliencePolicy::default(),
+            transport: HttpTransportConfig::default(),
+            signing: None,
         }
     }
 }
 
+/// Per-host connection pool counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HostPoolStats {
+    /// Requests currently holding a connection slot.
+    pub in_flight: usize,
+    /// Requests waiting for a free slot.
+    pub queued: usize,
+    /// Requests sent to this host, including retries.
+    pub requests_total: u64,
+}
+
+/// Snapshot of a pooled HTTP client's counters.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpPoolStats {
+    /// Configured per-host concurrency limit (0 = unlimited).
+    pub max_connections_per_host: usize,
+    /// Requests sent, including retries.
+    pub requests_total: u64,
+    /// Retries performed after transient failures.
+    pub retries_total: u64,
+    /// Requests that failed after exhausting retries.
+    pub failures_total: u64,
+    /// Counters keyed by `host:port`.
+    pub hosts: BTreeMap<String, HostPoolStats>,
+}
+
 /// HTTP client provider trait.
 ///
 /// Provides an abstract HTTP client interface without coupling to any
@@ -58,4 +361,9 @@ pub trait HttpClientProvider: Send + Sync {
 
     /// Return true if the HTTP client is configured and enabled.
     fn is_enabled(&self) -> bool;
+
+    /// Connection pool counters, for clients that track them.
+    fn pool_stats(&self) -> Option<HttpPoolStats> {
+        None
+    }
 }
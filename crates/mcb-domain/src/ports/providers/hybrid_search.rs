@@ -6,7 +6,7 @@ use async_trait::async_trait;
 
 use crate::entities::CodeChunk;
 use crate::error::Result;
-use crate::value_objects::SearchResult;
+use crate::value_objects::{SearchResult, SearchScope};
 
 /// Result of a hybrid search operation.
 #[derive(Debug, Clone)]
@@ -32,14 +32,17 @@ pub trait HybridSearchProvider: Send + Sync {
 
     /// Perform a hybrid search combining keyword and semantic matching.
     ///
+    /// Candidates outside `scope` are discarded before keyword scoring.
+    ///
     /// # Errors
-    /// Returns an error if search fails.
+    /// Returns an error if search fails or `scope` is invalid.
     async fn search(
         &self,
         collection: &str,
         query: &str,
         semantic_results: Vec<SearchResult>,
         limit: usize,
+        scope: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>>;
 
     /// Clear all data in the search collection.
@@ -4,6 +4,7 @@ use std::collections::HashMap;
 use std::time::Duration;
 
 use async_trait::async_trait;
+use serde::Serialize;
 
 /// Key-value pairs for metric categorization.
 pub type MetricLabels = HashMap<String, String>;
@@ -33,6 +34,20 @@ pub enum MetricsError {
     },
 }
 
+/// Histogram of embedding call latency, labelled by `provider`.
+pub const EMBEDDING_DURATION_METRIC: &str = "mcb_embedding_duration_seconds";
+/// Histogram of vector store call latency, labelled by `provider` and `operation`.
+pub const VECTOR_STORE_DURATION_METRIC: &str = "mcb_vector_store_duration_seconds";
+/// Counter of cache lookups, labelled by `cache_type` and `result` (`hit`/`miss`).
+pub const CACHE_ACCESSES_METRIC: &str = "mcb_cache_accesses_total";
+/// Histogram of tool call latency, labelled by `tool` and `result` (`ok`/`error`).
+pub const TOOL_CALL_DURATION_METRIC: &str = "mcb_tool_call_duration_seconds";
+/// Counter of failed tool calls, labelled by `tool` and, when known, `request_id`.
+///
+/// Only failures carry the request ID, so the number of series grows with
+/// failures rather than with traffic.
+pub const TOOL_CALL_FAILURES_METRIC: &str = "mcb_tool_call_failures_total";
+
 pub(crate) fn labels_from<const N: usize>(pairs: [(&str, &str); N]) -> MetricLabels {
     pairs
         .into_iter()
@@ -96,12 +111,8 @@ pub trait MetricsProviderExt: MetricsProvider + Send + Sync {
         provider: &str,
     ) -> MetricsResult<()> {
         let labels = labels_from([("provider", provider)]);
-        self.histogram(
-            "mcb_embedding_duration_seconds",
-            duration.as_secs_f64(),
-            &labels,
-        )
-        .await
+        self.histogram(EMBEDDING_DURATION_METRIC, duration.as_secs_f64(), &labels)
+            .await
     }
 
     /// Increment the count of indexed files in a collection.
@@ -134,15 +145,101 @@ pub trait MetricsProviderExt: MetricsProvider + Send + Sync {
             .await
     }
 
+    /// Record the latency of a vector store operation.
+    async fn record_vector_store_latency(
+        &self,
+        duration: Duration,
+        provider: &str,
+        operation: &str,
+    ) -> MetricsResult<()> {
+        let labels = labels_from([("provider", provider), ("operation", operation)]);
+        self.histogram(
+            VECTOR_STORE_DURATION_METRIC,
+            duration.as_secs_f64(),
+            &labels,
+        )
+        .await
+    }
+
     /// Record a cache hit or miss for a specific cache type.
     async fn record_cache_access(&self, hit: bool, cache_type: &str) -> MetricsResult<()> {
         let labels = labels_from([
             ("cache_type", cache_type),
             ("result", if hit { "hit" } else { "miss" }),
         ]);
-        self.increment("mcb_cache_accesses_total", &labels).await
+        self.increment(CACHE_ACCESSES_METRIC, &labels).await
+    }
+
+    /// Record the latency and outcome of a tool call.
+    async fn record_tool_call(
+        &self,
+        duration: Duration,
+        tool: &str,
+        request_id: Option<&str>,
+        failed: bool,
+    ) -> MetricsResult<()> {
+        let labels = labels_from([
+            ("tool", tool),
+            ("result", if failed { "error" } else { "ok" }),
+        ]);
+        self.histogram(TOOL_CALL_DURATION_METRIC, duration.as_secs_f64(), &labels)
+            .await?;
+        if failed {
+            let mut labels = labels_from([("tool", tool)]);
+            if let Some(request_id) = request_id {
+                labels.insert("request_id".to_owned(), request_id.to_owned());
+            }
+            self.increment(TOOL_CALL_FAILURES_METRIC, &labels).await?;
+        }
+        Ok(())
     }
 }
 
 // Implement extension trait for any type that implements MetricsProvider
 impl<T: ?Sized + MetricsProvider> MetricsProviderExt for T {}
+
+/// Current value of one counter or gauge series.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricSample {
+    /// Metric name (e.g. `mcb_search_requests_total`).
+    pub name: String,
+    /// Labels identifying the series.
+    pub labels: MetricLabels,
+    /// Current value.
+    pub value: f64,
+}
+
+/// Percentile summary of one histogram series over its retained window.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HistogramSummary {
+    /// Metric name (e.g. `mcb_embedding_duration_seconds`).
+    pub name: String,
+    /// Labels identifying the series.
+    pub labels: MetricLabels,
+    /// Number of observations in the window.
+    pub count: u64,
+    /// Median observation.
+    pub p50: f64,
+    /// 95th percentile observation.
+    pub p95: f64,
+}
+
+/// Point-in-time copy of everything a [`MetricsSnapshotProvider`] recorded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    /// Monotonic counters.
+    pub counters: Vec<MetricSample>,
+    /// Last-set gauges.
+    pub gauges: Vec<MetricSample>,
+    /// Histogram percentile summaries.
+    pub histograms: Vec<HistogramSummary>,
+}
+
+/// Metrics provider that can also report what it recorded.
+///
+/// Backs in-process introspection (the `server_stats` tool); exporters that
+/// only push to an external system implement [`MetricsProvider`] alone.
+pub trait MetricsSnapshotProvider: MetricsProvider {
+    /// Copy the current metric values.
+    fn snapshot(&self) -> MetricsSnapshot;
+}
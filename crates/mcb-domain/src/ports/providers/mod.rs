@@ -20,6 +20,8 @@ pub mod language_chunking;
 pub mod metrics;
 /// Project detection provider ports.
 pub mod project_detection;
+/// Query generation provider ports.
+pub mod query_generation;
 /// Version control system provider ports.
 pub mod vcs;
 /// Vector store provider ports.
@@ -30,10 +32,21 @@ pub use analysis::{AnalysisFinding, CodeAnalyzer};
 pub use config_manager::ProviderConfigManagerInterface;
 pub use crypto::{CryptoProvider, EncryptedData};
 pub use embedding::EmbeddingProvider;
-pub use http::{HttpClientConfig, HttpClientProvider};
+pub use http::{
+    HostPoolStats, HttpClientConfig, HttpClientProvider, HttpPoolStats, HttpTransportConfig,
+    RequestSigner, RequestSigningConfig, ResiliencePolicy, SigV4Config, SignableRequest,
+};
 pub use hybrid_search::{HybridSearchProvider, HybridSearchResult};
 pub use language_chunking::LanguageChunkingProvider;
-pub use metrics::{MetricLabels, MetricsError, MetricsProvider, MetricsProviderExt, MetricsResult};
+pub use metrics::{
+    HistogramSummary, MetricLabels, MetricSample, MetricsError, MetricsProvider,
+    MetricsProviderExt, MetricsResult, MetricsSnapshot, MetricsSnapshotProvider,
+};
 pub use project_detection::ProjectDetector;
+pub use query_generation::QueryGenerationProvider;
 pub use vcs::VcsProvider;
-pub use vector_store::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+pub use vector_store::{
+    InsertBatchPolicy, PersistencePolicy, StorageQuotaPolicy, StorageUsage, TombstonePolicy,
+    VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities, VectorStoreFeature,
+    VectorStoreProvider,
+};
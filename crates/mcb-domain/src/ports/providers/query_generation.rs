@@ -0,0 +1,24 @@
+//! Query generation provider ports.
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::value_objects::SearchResult;
+
+/// Writes search queries that an indexed chunk should answer.
+///
+/// Backed by a language model when one is configured; index self-evaluation
+/// falls back to doc comment and symbol name templates without it.
+#[async_trait]
+pub trait QueryGenerationProvider: Send + Sync {
+    /// Phrase a query a developer looking for `chunk` might ask.
+    ///
+    /// Returns `None` when the chunk does not suggest a useful query.
+    ///
+    /// # Errors
+    /// Returns an error if the model request fails.
+    async fn generate_query(&self, chunk: &SearchResult) -> Result<Option<String>>;
+
+    /// Get the name of this query generation provider.
+    fn provider_name(&self) -> &str;
+}
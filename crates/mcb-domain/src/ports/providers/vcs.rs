@@ -1,10 +1,13 @@
 //! Version control system provider ports.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 
-use crate::entities::vcs::{RefDiff, VcsBranch, VcsCommit, VcsRepository};
+use crate::entities::vcs::{
+    FileDiff, RefDiff, VcsBranch, VcsCommit, VcsRepository, WorkingTreeScope,
+};
 use crate::error::Result;
 use crate::value_objects::RepositoryId;
 
@@ -28,9 +31,19 @@ pub trait VcsProvider: Send + Sync {
         limit: Option<usize>,
     ) -> Result<Vec<VcsCommit>>;
 
-    /// List all tracked files in the given branch.
+    /// List all tracked files in the given branch or revision (tag, SHA, ...).
     async fn list_files(&self, repo: &VcsRepository, branch: &str) -> Result<Vec<PathBuf>>;
 
+    /// Unix time of the most recent commit touching each file on `branch`.
+    ///
+    /// Paths are relative to the repository root. Files whose last change lies
+    /// beyond the provider's history limit are omitted.
+    async fn file_last_modified(
+        &self,
+        repo: &VcsRepository,
+        branch: &str,
+    ) -> Result<HashMap<PathBuf, i64>>;
+
     /// Read the full content of a file from a specific commit/branch.
     async fn read_file(&self, repo: &VcsRepository, branch: &str, path: &Path) -> Result<String>;
 
@@ -45,6 +58,14 @@ pub trait VcsProvider: Send + Sync {
         head_ref: &str,
     ) -> Result<RefDiff>;
 
+    /// Files with uncommitted (or, for [`WorkingTreeScope::Since`], not yet
+    /// merged) changes in the repository's working tree, relative to its root.
+    async fn working_tree_changes(
+        &self,
+        repo: &VcsRepository,
+        scope: &WorkingTreeScope,
+    ) -> Result<Vec<FileDiff>>;
+
     /// Recursive search for all repositories within a root directory.
     async fn list_repositories(&self, root: &Path) -> Result<Vec<VcsRepository>>;
 }
@@ -1,11 +1,373 @@
 //! Vector store provider ports.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 
 use async_trait::async_trait;
+use mcb_utils::constants::vector_store::{
+    VECTOR_INSERT_BATCH_SIZE, VECTOR_INSERT_MAX_IN_FLIGHT, VECTOR_SNAPSHOT_INTERVAL_SECS,
+    VECTOR_SNAPSHOT_ZSTD_LEVEL, VECTOR_SNAPSHOT_ZSTD_MAX_LEVEL, VECTOR_STORAGE_WARN_PERCENT,
+    VECTOR_TOMBSTONE_RETENTION_SECS, VECTOR_WAL_FLUSH_INTERVAL_MS,
+};
+use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
-use crate::value_objects::{CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult};
+use crate::error::{Error, Result};
+use crate::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
+};
+
+/// How `insert_vectors` splits large inserts into concurrent batches.
+///
+/// Configured under `providers.vector_store.insert_batch`; any field left out
+/// keeps its default. Batches already written stay stored if a later batch
+/// fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct InsertBatchPolicy {
+    /// Vectors per insert request.
+    pub batch_size: usize,
+    /// Batches sent concurrently; further batches wait for a free slot.
+    pub max_in_flight: usize,
+}
+
+impl InsertBatchPolicy {
+    /// Validate ranges; `key` is the config path used in error messages.
+    ///
+    /// # Errors
+    /// Returns a configuration error when either limit is zero.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        if self.batch_size == 0 {
+            return Err(Error::config_invalid(
+                format!("{key}.batch_size"),
+                "must be greater than zero",
+            ));
+        }
+        if self.max_in_flight == 0 {
+            return Err(Error::config_invalid(
+                format!("{key}.max_in_flight"),
+                "must be greater than zero",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for InsertBatchPolicy {
+    fn default() -> Self {
+        Self {
+            batch_size: VECTOR_INSERT_BATCH_SIZE,
+            max_in_flight: VECTOR_INSERT_MAX_IN_FLIGHT,
+        }
+    }
+}
+
+/// How long deleted and replaced vectors stay queryable.
+///
+/// Configured under `providers.vector_store.tombstones`. Within the retention
+/// window, [`VectorStoreProvider::search_similar_as_of`] still sees a record as
+/// it was before it was deleted or overwritten. `retention_secs: 0` removes
+/// records immediately and disables point-in-time search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TombstonePolicy {
+    /// Seconds a tombstoned record is kept before it is purged.
+    pub retention_secs: u64,
+}
+
+impl TombstonePolicy {
+    /// Whether tombstones are kept at all.
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.retention_secs > 0
+    }
+}
+
+impl Default for TombstonePolicy {
+    fn default() -> Self {
+        Self {
+            retention_secs: VECTOR_TOMBSTONE_RETENTION_SECS,
+        }
+    }
+}
+
+/// On-disk persistence for embedded (in-process) vector stores.
+///
+/// Configured under `providers.vector_store.persistence`. Writes are appended
+/// to a write-ahead log under `data_dir`, which is flushed every
+/// `flush_interval_ms`; a full snapshot replaces the log every
+/// `snapshot_interval_secs`. On startup the store loads the snapshot and
+/// replays the log. Remote stores ignore this setting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct PersistencePolicy {
+    /// Directory holding the snapshot and write-ahead log.
+    pub data_dir: PathBuf,
+    /// Seconds between full snapshots.
+    pub snapshot_interval_secs: u64,
+    /// Milliseconds between write-ahead log flushes; writes acknowledged
+    /// within this window can be lost on a crash.
+    pub flush_interval_ms: u64,
+    /// zstd level (1-22) snapshots are compressed with; 0 writes plain JSON.
+    /// Snapshots are read either way.
+    pub compression_level: i32,
+}
+
+impl PersistencePolicy {
+    /// Persist under `data_dir` with the default intervals.
+    #[must_use]
+    pub fn new(data_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            data_dir: data_dir.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Validate the settings; `key` is the config path used in error messages.
+    ///
+    /// # Errors
+    /// Returns a configuration error when `data_dir` is empty, an interval
+    /// is zero or `compression_level` is out of range.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        if self.data_dir.as_os_str().is_empty() {
+            return Err(Error::config_invalid(
+                format!("{key}.data_dir"),
+                "must not be empty",
+            ));
+        }
+        if self.snapshot_interval_secs == 0 {
+            return Err(Error::config_invalid(
+                format!("{key}.snapshot_interval_secs"),
+                "must be greater than zero",
+            ));
+        }
+        if self.flush_interval_ms == 0 {
+            return Err(Error::config_invalid(
+                format!("{key}.flush_interval_ms"),
+                "must be greater than zero",
+            ));
+        }
+        if !(0..=VECTOR_SNAPSHOT_ZSTD_MAX_LEVEL).contains(&self.compression_level) {
+            return Err(Error::config_invalid(
+                format!("{key}.compression_level"),
+                format!("must be between 0 and {VECTOR_SNAPSHOT_ZSTD_MAX_LEVEL}"),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for PersistencePolicy {
+    fn default() -> Self {
+        Self {
+            data_dir: PathBuf::new(),
+            snapshot_interval_secs: VECTOR_SNAPSHOT_INTERVAL_SECS,
+            flush_interval_ms: VECTOR_WAL_FLUSH_INTERVAL_MS,
+            compression_level: VECTOR_SNAPSHOT_ZSTD_LEVEL,
+        }
+    }
+}
+
+/// Storage limits for embedded (in-process) vector stores.
+///
+/// Configured under `providers.vector_store.quota`. Usage is the bytes a store
+/// holds for its records: vector values plus serialized metadata, including
+/// tombstones until they are purged. Writes that would take a collection or
+/// the whole store past its limit are rejected; health checks warn once usage
+/// reaches `warn_percent` of a limit. A limit of 0 means unlimited. Remote
+/// stores ignore this setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(default, deny_unknown_fields)]
+pub struct StorageQuotaPolicy {
+    /// Bytes a single collection may hold (0 = unlimited).
+    pub max_collection_bytes: u64,
+    /// Bytes all collections together may hold (0 = unlimited).
+    pub max_total_bytes: u64,
+    /// Percentage of a limit at which health checks warn.
+    pub warn_percent: u8,
+}
+
+impl StorageQuotaPolicy {
+    /// Whether any limit is set.
+    #[must_use]
+    pub fn enforced(&self) -> bool {
+        self.max_collection_bytes > 0 || self.max_total_bytes > 0
+    }
+
+    /// Validate ranges; `key` is the config path used in error messages.
+    ///
+    /// # Errors
+    /// Returns a configuration error when `warn_percent` is not in 1..=100.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        if !(1..=100).contains(&self.warn_percent) {
+            return Err(Error::config_invalid(
+                format!("{key}.warn_percent"),
+                "must be between 1 and 100",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that writing `incoming` more bytes to `collection` stays within
+    /// both limits.
+    ///
+    /// # Errors
+    /// Returns a vector store error naming the exceeded limit.
+    pub fn check_write(&self, collection: &str, usage: &StorageUsage, incoming: u64) -> Result<()> {
+        let collection_bytes = usage.collections.get(collection).copied().unwrap_or(0);
+        if exceeds(collection_bytes, incoming, self.max_collection_bytes) {
+            return Err(Error::vector_db(format!(
+                "Storage quota exceeded for collection '{collection}': {collection_bytes} + {incoming} bytes is over the {} byte limit (providers.vector_store.quota.max_collection_bytes)",
+                self.max_collection_bytes
+            )));
+        }
+        if exceeds(usage.total_bytes, incoming, self.max_total_bytes) {
+            return Err(Error::vector_db(format!(
+                "Storage quota exceeded: {} + {incoming} bytes is over the {} byte limit for all collections (providers.vector_store.quota.max_total_bytes)",
+                usage.total_bytes, self.max_total_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+fn exceeds(used: u64, incoming: u64, limit: u64) -> bool {
+    limit > 0 && used.saturating_add(incoming) > limit
+}
+
+impl Default for StorageQuotaPolicy {
+    fn default() -> Self {
+        Self {
+            max_collection_bytes: 0,
+            max_total_bytes: 0,
+            warn_percent: VECTOR_STORAGE_WARN_PERCENT,
+        }
+    }
+}
+
+/// Bytes an embedded vector store currently holds, with its quota.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StorageUsage {
+    /// Bytes held across all collections.
+    pub total_bytes: u64,
+    /// Bytes held per collection.
+    pub collections: BTreeMap<String, u64>,
+    /// Limits the usage is checked against.
+    pub quota: StorageQuotaPolicy,
+}
+
+impl StorageUsage {
+    /// One message per limit whose usage reached `quota.warn_percent`.
+    #[must_use]
+    pub fn warnings(&self) -> Vec<String> {
+        let reached = |used: u64, limit: u64| {
+            limit > 0
+                && u128::from(used) * 100 >= u128::from(limit) * u128::from(self.quota.warn_percent)
+        };
+        let mut warnings: Vec<String> = self
+            .collections
+            .iter()
+            .filter(|(_, bytes)| reached(**bytes, self.quota.max_collection_bytes))
+            .map(|(collection, bytes)| {
+                format!(
+                    "collection '{collection}' uses {bytes} of {} bytes",
+                    self.quota.max_collection_bytes
+                )
+            })
+            .collect();
+        if reached(self.total_bytes, self.quota.max_total_bytes) {
+            warnings.push(format!(
+                "all collections use {} of {} bytes",
+                self.total_bytes, self.quota.max_total_bytes
+            ));
+        }
+        warnings
+    }
+}
+
+/// An optional vector store feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorStoreFeature {
+    /// Evaluates the language part of a [`SearchScope`] in the store
+    MetadataFilters,
+    /// Replaces a record by ID in a single write
+    AtomicUpsert,
+    /// [`VectorStoreProvider::swap_alias`] and [`VectorStoreProvider::delete_alias`]
+    Aliases,
+    /// [`VectorStoreProvider::search_similar_as_of`]
+    PointInTimeSearch,
+    /// [`VectorStoreProvider::inspect_chunk`]
+    ChunkInspection,
+    /// [`VectorStoreAdmin::storage_usage`] reports usage against a quota
+    StorageQuota,
+}
+
+impl std::fmt::Display for VectorStoreFeature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::MetadataFilters => "metadata filters",
+            Self::AtomicUpsert => "atomic upserts",
+            Self::Aliases => "collection aliases",
+            Self::PointInTimeSearch => "point-in-time search",
+            Self::ChunkInspection => "chunk inspection",
+            Self::StorageQuota => "storage quotas",
+        })
+    }
+}
+
+/// Optional features a vector store implements.
+///
+/// Use cases check these before calling an optional operation, so they can
+/// fall back or report the missing feature instead of failing mid-way.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VectorStoreCapabilities {
+    /// See [`VectorStoreFeature::MetadataFilters`]
+    pub metadata_filters: bool,
+    /// See [`VectorStoreFeature::AtomicUpsert`]; without it an upsert deletes
+    /// and re-inserts, so a failure in between loses the record
+    pub atomic_upsert: bool,
+    /// See [`VectorStoreFeature::Aliases`]
+    pub aliases: bool,
+    /// See [`VectorStoreFeature::PointInTimeSearch`]
+    pub point_in_time_search: bool,
+    /// See [`VectorStoreFeature::ChunkInspection`]
+    pub chunk_inspection: bool,
+    /// See [`VectorStoreFeature::StorageQuota`]
+    pub storage_quota: bool,
+}
+
+impl VectorStoreCapabilities {
+    /// Whether `feature` is supported.
+    #[must_use]
+    pub fn supports(&self, feature: VectorStoreFeature) -> bool {
+        match feature {
+            VectorStoreFeature::MetadataFilters => self.metadata_filters,
+            VectorStoreFeature::AtomicUpsert => self.atomic_upsert,
+            VectorStoreFeature::Aliases => self.aliases,
+            VectorStoreFeature::PointInTimeSearch => self.point_in_time_search,
+            VectorStoreFeature::ChunkInspection => self.chunk_inspection,
+            VectorStoreFeature::StorageQuota => self.storage_quota,
+        }
+    }
+
+    /// Check that `feature` is supported by the store named `provider`.
+    ///
+    /// # Errors
+    /// Returns a vector store error naming the provider and the feature when
+    /// it is not.
+    pub fn require(&self, provider: &str, feature: VectorStoreFeature) -> Result<()> {
+        if self.supports(feature) {
+            Ok(())
+        } else {
+            Err(unsupported(provider, feature))
+        }
+    }
+}
+
+/// Error for a call to `feature` on a store that does not implement it.
+fn unsupported(provider: &str, feature: VectorStoreFeature) -> Error {
+    Error::vector_db(format!("{provider} does not support {feature}"))
+}
 
 /// Administrative operations for vector database collections.
 #[async_trait]
@@ -19,18 +381,30 @@ pub trait VectorStoreAdmin: Send + Sync {
         collection: &CollectionId,
     ) -> Result<HashMap<String, serde_json::Value>>;
 
-    /// Ensure all pending writes are committed and searchable.
+    /// Ensure all pending writes are committed and searchable (and, for
+    /// persisted embedded stores, durable on disk).
     async fn flush(&self, collection: &CollectionId) -> Result<()>;
 
     /// Get the unique name of this vector store implementation.
     fn provider_name(&self) -> &str;
 
+    /// Optional features this store implements; none by default.
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        VectorStoreCapabilities::default()
+    }
+
     /// Perform a basic health check on the connection.
     async fn health_check(&self) -> Result<()> {
         let health_check_id = CollectionId::from_name("__health_check__");
         self.collection_exists(&health_check_id).await?;
         Ok(())
     }
+
+    /// Bytes held per collection and the quota they are checked against;
+    /// `None` for stores that do not enforce storage quotas.
+    async fn storage_usage(&self) -> Result<Option<StorageUsage>> {
+        Ok(None)
+    }
 }
 
 /// Read-only discovery and browsing of the vector database.
@@ -63,6 +437,35 @@ pub trait VectorStoreProvider: VectorStoreAdmin + VectorStoreBrowser + Send + Sy
     /// Permanently delete a collection and all its vectors.
     async fn delete_collection(&self, collection: &CollectionId) -> Result<()>;
 
+    /// Point `alias` at `collection`, creating the alias or atomically
+    /// moving it off the collection it named before.
+    ///
+    /// Every other operation given the alias acts on its current target, so
+    /// a re-index can fill `myrepo_v2` and then switch `myrepo` to it without
+    /// searches ever seeing a half-built collection. The previous target is
+    /// kept; delete it once nothing reads from it.
+    ///
+    /// # Errors
+    /// The default implementation reports that the provider has no aliases.
+    /// Implementations fail when `collection` does not exist.
+    async fn swap_alias(&self, _alias: &CollectionId, _collection: &CollectionId) -> Result<()> {
+        Err(unsupported(
+            self.provider_name(),
+            VectorStoreFeature::Aliases,
+        ))
+    }
+
+    /// Remove `alias`; the collection it pointed to is kept.
+    ///
+    /// # Errors
+    /// The default implementation reports that the provider has no aliases.
+    async fn delete_alias(&self, _alias: &CollectionId) -> Result<()> {
+        Err(unsupported(
+            self.provider_name(),
+            VectorStoreFeature::Aliases,
+        ))
+    }
+
     /// Insert a batch of vectors with associated metadata.
     async fn insert_vectors(
         &self,
@@ -71,16 +474,62 @@ pub trait VectorStoreProvider: VectorStoreAdmin + VectorStoreBrowser + Send + Sy
         metadata: Vec<HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>>;
 
+    /// Insert vectors under caller-chosen IDs, replacing any stored record
+    /// with the same ID.
+    ///
+    /// `ids`, `vectors` and `metadata` are parallel. Returns the record IDs in
+    /// input order: the given IDs, except for stores that key records by
+    /// number (Milvus), which return the numeric key derived from each ID.
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>>;
+
     /// Find vectors similar to the provided query vector.
+    ///
+    /// Providers translate whatever part of `filter` their query language
+    /// supports into a native metadata filter and ignore the rest, so callers
+    /// must re-check results against the scope.
     async fn search_similar(
         &self,
         collection: &CollectionId,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>>;
 
+    /// Find vectors similar to `query_vector` as the collection was at
+    /// `as_of` (Unix seconds).
+    ///
+    /// Records inserted after `as_of` are skipped, and records deleted or
+    /// replaced after it are returned with their old metadata, as long as
+    /// their tombstone is still within the [`TombstonePolicy`] retention.
+    ///
+    /// # Errors
+    /// The default implementation reports that the provider does not keep
+    /// tombstones. Implementations fail when `as_of` lies before the
+    /// retention window.
+    async fn search_similar_as_of(
+        &self,
+        _collection: &CollectionId,
+        _query_vector: &[f32],
+        _limit: usize,
+        _filter: Option<&SearchScope>,
+        _as_of: i64,
+    ) -> Result<Vec<SearchResult>> {
+        Err(unsupported(
+            self.provider_name(),
+            VectorStoreFeature::PointInTimeSearch,
+        ))
+    }
+
     /// Delete specific vectors by their unique IDs.
+    ///
+    /// Providers that keep tombstones leave the records visible to
+    /// [`Self::search_similar_as_of`] for the retention window.
     async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()>;
 
     /// Retrieve specific search results by their vector record IDs.
@@ -96,4 +545,21 @@ pub trait VectorStoreProvider: VectorStoreAdmin + VectorStoreBrowser + Send + Sy
         collection: &CollectionId,
         limit: usize,
     ) -> Result<Vec<SearchResult>>;
+
+    /// Read everything stored for one chunk: its metadata, its vector and
+    /// where the store keeps it. `None` when `id` is not in the collection.
+    ///
+    /// # Errors
+    /// The default implementation reports that the provider cannot inspect
+    /// records.
+    async fn inspect_chunk(
+        &self,
+        _collection: &CollectionId,
+        _id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        Err(unsupported(
+            self.provider_name(),
+            VectorStoreFeature::ChunkInspection,
+        ))
+    }
 }
@@ -0,0 +1,27 @@
+//! Collection manifest repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::collection_manifest::CollectionManifest;
+use crate::error::Result;
+use crate::value_objects::DistanceMetric;
+
+/// Persistence for collection manifests.
+#[async_trait]
+pub trait CollectionManifestRepository: Send + Sync {
+    /// Manifest of a collection, if one was recorded.
+    async fn get_manifest(&self, collection: &str) -> Result<Option<CollectionManifest>>;
+    /// Record that every chunk of `collection` was produced by `chunker_version`
+    /// from the files under `root_path`, embedded by `embedding_model` and
+    /// stored for `distance_metric`.
+    async fn record_build(
+        &self,
+        collection: &str,
+        chunker_version: u32,
+        distance_metric: DistanceMetric,
+        embedding_model: &str,
+        root_path: &str,
+    ) -> Result<()>;
+    /// Delete the manifest of a collection; returns the number removed.
+    async fn clear_collection(&self, collection: &str) -> Result<u64>;
+}
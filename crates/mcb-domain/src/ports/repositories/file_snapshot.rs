@@ -0,0 +1,20 @@
+//! File snapshot blob repository ports.
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+
+/// Content-addressable store for full file contents captured at index time.
+///
+/// Blobs are keyed by the same content hash that [`super::FileHashRepository`]
+/// records per file, so the snapshot of an indexed file is
+/// `get_snapshot(get_hash(collection, path))`.
+#[async_trait]
+pub trait FileSnapshotRepository: Send + Sync {
+    /// Store `content` under `hash`; a no-op when the blob already exists.
+    async fn store_snapshot(&self, hash: &str, content: &str) -> Result<()>;
+    /// Content stored under `hash`, if any.
+    async fn get_snapshot(&self, hash: &str) -> Result<Option<String>>;
+    /// Delete blobs no live file hash refers to; returns the number removed.
+    async fn prune_unreferenced(&self) -> Result<u64>;
+}
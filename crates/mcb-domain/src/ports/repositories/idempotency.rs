@@ -0,0 +1,24 @@
+//! Idempotency repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::idempotency::IdempotencyRecord;
+use crate::error::Result;
+
+/// Persistence for results of mutating tool calls by idempotency key.
+#[async_trait]
+pub trait IdempotencyRepository: Send + Sync {
+    /// Record stored for `key` of `tool_name` in `org_id`, if created at or
+    /// after `since` (Unix seconds).
+    async fn find_record(
+        &self,
+        org_id: &str,
+        tool_name: &str,
+        key: &str,
+        since: i64,
+    ) -> Result<Option<IdempotencyRecord>>;
+    /// Store `record`, replacing an expired record with the same key.
+    async fn save_record(&self, record: &IdempotencyRecord) -> Result<()>;
+    /// Delete records created before `before` (Unix seconds); returns how many.
+    async fn delete_expired(&self, before: i64) -> Result<u64>;
+}
@@ -10,24 +10,40 @@
 pub mod agent;
 /// Authentication repository ports.
 pub mod auth;
+/// Collection manifest repository ports.
+pub mod collection_manifest;
 /// File hash tracking repository ports.
 pub mod file_hash;
+/// File snapshot blob repository ports.
+pub mod file_snapshot;
+/// Idempotency key repository ports.
+pub mod idempotency;
 /// Indexing operation repository ports.
 pub mod index;
 /// Issue repository ports (issue, comment, label).
 pub mod issue;
 /// Memory/observation repository ports.
 pub mod memory;
+/// Module import repository ports.
+pub mod module_import;
 /// Organization repository ports (org, user, team, API key).
 pub mod org;
 /// Plan repository ports (plan, version, review).
 pub mod plan;
 /// Project repository ports.
 pub mod project;
+/// Search relevance feedback repository ports.
+pub mod search_feedback;
+/// Test link repository ports.
+pub mod test_link;
+/// Usage accounting repository ports.
+pub mod usage;
 /// VCS repository ports (repository, branch, worktree, agent assignments).
 pub mod vcs;
 /// Workflow session and transition repository ports.
 pub mod workflow;
+/// Workspace membership and shared file repository ports.
+pub mod workspace;
 
 // Re-exports for canonical access via `ports::repositories::{...}`
 pub use agent::{
@@ -35,20 +51,28 @@ pub use agent::{
     AgentSessionRepository,
 };
 pub use auth::{ApiKeyInfo, AuthRepositoryPort, UserWithApiKey};
+pub use collection_manifest::CollectionManifestRepository;
 pub use file_hash::FileHashRepository;
+pub use file_snapshot::FileSnapshotRepository;
+pub use idempotency::IdempotencyRepository;
 pub use index::{IndexRepository, IndexStats};
 pub use issue::{
     IssueCommentRegistry, IssueEntityRepository, IssueLabelAssignmentManager, IssueLabelRegistry,
     IssueRegistry,
 };
 pub use memory::{FtsSearchResult, MemoryRepository};
+pub use module_import::ModuleImportRepository;
 pub use org::{
     ApiKeyRegistry, OrgEntityRepository, OrgRegistry, TeamMemberManager, TeamRegistry, UserRegistry,
 };
 pub use plan::{PlanEntityRepository, PlanRegistry, PlanReviewRegistry, PlanVersionRegistry};
 pub use project::ProjectRepository;
+pub use search_feedback::SearchFeedbackRepository;
+pub use test_link::TestLinkRepository;
+pub use usage::UsageRepository;
 pub use vcs::{
     AgentAssignmentManager, VcsBranchRegistry, VcsEntityRepository, VcsRepositoryRegistry,
     VcsWorktreeRegistry,
 };
 pub use workflow::{TransitionRepository, WorkflowSessionRepository};
+pub use workspace::WorkspaceRepository;
@@ -0,0 +1,22 @@
+//! Module import repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::module_import::ModuleImport;
+use crate::error::Result;
+
+/// Persistence for the import edges of the module dependency graph.
+#[async_trait]
+pub trait ModuleImportRepository: Send + Sync {
+    /// Replace every import recorded for `source_file` with `imports`.
+    async fn replace_file_imports(
+        &self,
+        collection: &str,
+        source_file: &str,
+        imports: &[ModuleImport],
+    ) -> Result<()>;
+    /// All imports of a collection, ordered by file and line.
+    async fn list_imports(&self, collection: &str) -> Result<Vec<ModuleImport>>;
+    /// Delete all imports of a collection; returns the number removed.
+    async fn clear_collection(&self, collection: &str) -> Result<u64>;
+}
@@ -0,0 +1,19 @@
+//! Search feedback repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::memory::{FileBoost, FileFeedbackTally, SearchFeedback};
+use crate::error::Result;
+
+/// Persistence for search relevance feedback and the boosts learned from it.
+#[async_trait]
+pub trait SearchFeedbackRepository: Send + Sync {
+    /// Store one feedback record.
+    async fn record_feedback(&self, feedback: &SearchFeedback) -> Result<()>;
+    /// Count thumbs up/down per `(collection, file_path)`.
+    async fn tally_by_file(&self) -> Result<Vec<FileFeedbackTally>>;
+    /// Replace every stored file boost with `boosts`.
+    async fn replace_file_boosts(&self, boosts: &[FileBoost]) -> Result<()>;
+    /// Stored boosts for a collection.
+    async fn file_boosts(&self, collection: &str) -> Result<Vec<FileBoost>>;
+}
@@ -0,0 +1,27 @@
+//! Test link repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::test_link::TestLink;
+use crate::error::Result;
+
+/// Persistence for links between test functions and the symbols they exercise.
+#[async_trait]
+pub trait TestLinkRepository: Send + Sync {
+    /// Replace every link from tests in `test_file` with `links`.
+    async fn replace_file_links(
+        &self,
+        collection: &str,
+        test_file: &str,
+        links: &[TestLink],
+    ) -> Result<()>;
+    /// Tests linked to the symbol named `target`, call links first.
+    async fn find_tests(
+        &self,
+        collection: &str,
+        target: &str,
+        limit: usize,
+    ) -> Result<Vec<TestLink>>;
+    /// Delete all links of a collection; returns the number removed.
+    async fn clear_collection(&self, collection: &str) -> Result<u64>;
+}
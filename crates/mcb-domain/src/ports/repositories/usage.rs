@@ -0,0 +1,16 @@
+//! Usage accounting repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::usage::{UsageFilter, UsageRecord};
+use crate::error::Result;
+
+/// Persistence for monthly usage counters.
+#[async_trait]
+pub trait UsageRepository: Send + Sync {
+    /// Add the counters of `usage` to the record of its organization,
+    /// collection and month, creating the record if needed.
+    async fn add_usage(&self, usage: &UsageRecord) -> Result<()>;
+    /// Records matching `filter`, ordered by organization, month and collection.
+    async fn list_usage(&self, filter: &UsageFilter) -> Result<Vec<UsageRecord>>;
+}
@@ -0,0 +1,52 @@
+//! Workspace repository ports.
+
+use async_trait::async_trait;
+
+use crate::entities::workspace::{CollectionFile, SharedFile, Workspace, WorkspaceMember};
+use crate::error::Result;
+
+/// Persistence for workspace membership and the files shared between
+/// member collections.
+///
+/// Collections are keyed by their identifier, as in the other repositories.
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    /// Add a collection to `workspace`, creating the workspace if needed.
+    async fn add_collection(&self, workspace: &str, member: &WorkspaceMember) -> Result<()>;
+    /// Remove `collection` from `workspace`; returns whether it was a member.
+    ///
+    /// A workspace without collections no longer exists.
+    async fn remove_collection(&self, workspace: &str, collection: &str) -> Result<bool>;
+    /// A workspace and its collections, if it has any.
+    async fn get_workspace(&self, workspace: &str) -> Result<Option<Workspace>>;
+    /// Every workspace, in name order.
+    async fn list_workspaces(&self) -> Result<Vec<Workspace>>;
+    /// A live file with `content_hash` in another collection of a workspace
+    /// `collection` belongs to, whose chunks are stored (not itself a copy).
+    async fn find_origin(
+        &self,
+        collection: &str,
+        content_hash: &str,
+    ) -> Result<Option<CollectionFile>>;
+    /// Record that a file is served by an identical file of another collection.
+    async fn record_shared_file(&self, shared: &SharedFile) -> Result<()>;
+    /// Forget that `file_path` of `collection` is a copy, once it has its own chunks.
+    async fn forget_shared_file(&self, collection: &str, file_path: &str) -> Result<()>;
+    /// Delete and return the copies served by `origin_collection`, or only
+    /// by its `origin_path` when given; they need their own chunks again.
+    async fn release_copies(
+        &self,
+        origin_collection: &str,
+        origin_path: Option<&str>,
+    ) -> Result<Vec<SharedFile>>;
+    /// Copies served by the given files of `origin_collection`.
+    async fn list_copies(
+        &self,
+        origin_collection: &str,
+        origin_paths: &[String],
+    ) -> Result<Vec<SharedFile>>;
+    /// Delete the copy records of a collection; returns the number removed.
+    ///
+    /// Workspace membership is kept.
+    async fn clear_collection(&self, collection: &str) -> Result<u64>;
+}
@@ -0,0 +1,92 @@
+//! Symbol-level change summary ports.
+
+use std::path::Path;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::CollectionId;
+
+/// What a change summary compares against.
+#[derive(Debug, Clone)]
+pub enum ChangeBaseline {
+    /// Compare two revisions (branches, tags or commit SHAs).
+    Refs {
+        /// Older revision.
+        base_ref: String,
+        /// Newer revision.
+        head_ref: String,
+    },
+    /// Compare the content indexed in a collection with the working tree.
+    LastIndex(CollectionId),
+}
+
+/// How a symbol changed between the two versions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolChangeKind {
+    /// Only present in the newer version.
+    Added,
+    /// Only present in the older version.
+    Removed,
+    /// Present in both versions with different content.
+    Modified,
+}
+
+/// A function, class or other definition that changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct SymbolChange {
+    /// Symbol name.
+    pub name: String,
+    /// Syntax node type reported by the chunker (e.g. `function_item`).
+    pub kind: String,
+    /// How the symbol changed.
+    pub change: SymbolChangeKind,
+    /// First line (1-based) in the newer version, or the older one for removals.
+    pub start_line: u32,
+    /// Last line (1-based) in the same version as `start_line`.
+    pub end_line: u32,
+}
+
+/// Symbol changes within one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileChangeSummary {
+    /// Repository-relative file path.
+    pub path: String,
+    /// File-level status (`added`, `modified`, `deleted` or `renamed`).
+    pub status: String,
+    /// Changed symbols, in source order.
+    pub symbols: Vec<SymbolChange>,
+}
+
+/// Symbol-level summary of the changes between two versions of a repository.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeSummary {
+    /// Description of the older version.
+    pub base: String,
+    /// Description of the newer version.
+    pub head: String,
+    /// Changed files with their symbol changes.
+    pub files: Vec<FileChangeSummary>,
+    /// Number of added symbols.
+    pub added: usize,
+    /// Number of removed symbols.
+    pub removed: usize,
+    /// Number of modified symbols.
+    pub modified: usize,
+}
+
+/// Change Summary Service Interface
+///
+/// Maps file-level VCS changes onto the functions, classes and other
+/// definitions the language chunker extracts.
+#[async_trait]
+pub trait ChangeSummaryServiceInterface: Send + Sync {
+    /// Summarize symbol changes in the repository at `repo_root`.
+    async fn summarize_changes(
+        &self,
+        repo_root: &Path,
+        baseline: &ChangeBaseline,
+    ) -> Result<ChangeSummary>;
+}
@@ -4,7 +4,7 @@ use async_trait::async_trait;
 
 use crate::entities::CodeChunk;
 use crate::error::Result;
-use crate::value_objects::{CollectionId, Embedding, SearchResult};
+use crate::value_objects::{CollectionId, DistanceMetric, Embedding, SearchResult, SearchScope};
 
 /// Code Intelligence Service Interface
 ///
@@ -18,11 +18,24 @@ pub trait ContextServiceInterface: Send + Sync {
     async fn store_chunks(&self, collection: &CollectionId, chunks: &[CodeChunk]) -> Result<()>;
 
     /// Search for code similar to the query string.
+    ///
+    /// `scope` is pushed down to the vector store where supported; results
+    /// may still fall outside it.
     async fn search_similar(
         &self,
         collection: &CollectionId,
         query: &str,
         limit: usize,
+        scope: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>>;
+
+    /// Search for code similar to a precomputed query vector.
+    async fn search_by_vector(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        scope: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>>;
 
     /// Get embedding for the given text.
@@ -36,4 +49,15 @@ pub trait ContextServiceInterface: Send + Sync {
 
     /// Get the number of dimensions for embeddings produced by this service.
     fn embedding_dimensions(&self) -> usize;
+
+    /// Distance metric vectors are stored and searched with.
+    fn distance_metric(&self) -> DistanceMetric {
+        DistanceMetric::default()
+    }
+
+    /// Embedding model vectors are produced by, as recorded in collection
+    /// manifests.
+    fn embedding_model(&self) -> String {
+        String::new()
+    }
 }
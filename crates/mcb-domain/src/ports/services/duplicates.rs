@@ -0,0 +1,82 @@
+//! Duplicate code detection ports.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::{CollectionId, SearchScope};
+
+/// How closely two duplicate fragments match.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloneKind {
+    /// Identical token sequences.
+    Exact,
+    /// Identical once identifiers and literals are normalized away.
+    Renamed,
+    /// Similar after normalization, with statements added, removed or changed.
+    Gapped,
+}
+
+/// Clone detection request over an indexed collection.
+#[derive(Debug, Clone)]
+pub struct DuplicateQuery {
+    /// Minimum similarity (0.0 - 1.0) for a pair to be reported.
+    pub min_similarity: f64,
+    /// Minimum length, in lines, of both fragments of a pair.
+    pub min_lines: usize,
+    /// Restrict detection to these paths and languages.
+    pub scope: Option<SearchScope>,
+    /// Maximum number of pairs to return.
+    pub limit: usize,
+}
+
+/// One side of a duplicate pair.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CodeFragment {
+    /// Workspace-relative file path.
+    pub file_path: String,
+    /// First line of the fragment (1-based).
+    pub start_line: u32,
+    /// Last line of the fragment (1-based).
+    pub end_line: u32,
+}
+
+/// Two indexed fragments that duplicate each other.
+#[derive(Debug, Clone, Serialize)]
+pub struct DuplicatePair {
+    /// The fragment ordered first by path and line.
+    pub first: CodeFragment,
+    /// The fragment ordered second by path and line.
+    pub second: CodeFragment,
+    /// Similarity of the normalized token sequences (0.0 - 1.0).
+    pub similarity: f64,
+    /// Clone classification.
+    pub kind: CloneKind,
+}
+
+/// Outcome of clone detection over a collection.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DuplicateReport {
+    /// Duplicate pairs, most similar and longest first.
+    pub duplicates: Vec<DuplicatePair>,
+    /// Number of indexed files scanned.
+    pub files_scanned: usize,
+    /// Number of fragments long enough to be compared.
+    pub fragments_compared: usize,
+    /// `true` when more pairs passed the thresholds than `limit`.
+    pub truncated: bool,
+}
+
+/// Duplicate Detection Service Interface
+///
+/// Clone detection over the chunks of any indexed collection.
+#[async_trait]
+pub trait DuplicateDetectionServiceInterface: Send + Sync {
+    /// Find pairs of indexed fragments at least as similar as the query asks.
+    async fn find_duplicates(
+        &self,
+        collection: &CollectionId,
+        query: &DuplicateQuery,
+    ) -> Result<DuplicateReport>;
+}
@@ -0,0 +1,43 @@
+//! Index self-evaluation ports.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::{CollectionId, EvalReport, LabeledQuery};
+
+/// Self-evaluation request over an indexed collection.
+#[derive(Debug, Clone)]
+pub struct SelfEvalOptions {
+    /// Number of indexed files to sample; one query is generated per file.
+    pub sample_size: usize,
+    /// Rank cutoff for recall@k, MRR and nDCG@k.
+    pub k: usize,
+}
+
+/// Outcome of evaluating a collection against its own synthetic queries.
+#[derive(Debug, Clone, Serialize)]
+pub struct SelfEvalReport {
+    /// Index-quality score (0.0 - 1.0): mean of recall@k, MRR and nDCG@k.
+    pub quality_score: f64,
+    /// Number of indexed files sampled.
+    pub files_sampled: usize,
+    /// Generated queries; can be saved and curated as an `mcb eval` query set.
+    pub queries: Vec<LabeledQuery>,
+    /// Scores of the generated queries.
+    pub eval: EvalReport,
+}
+
+/// Index Evaluation Service Interface
+///
+/// Samples indexed chunks, derives queries they should answer and scores
+/// the collection's search results for those queries.
+#[async_trait]
+pub trait IndexEvaluationServiceInterface: Send + Sync {
+    /// Generate synthetic queries for `collection` and evaluate them.
+    async fn self_evaluate(
+        &self,
+        collection: &CollectionId,
+        options: &SelfEvalOptions,
+    ) -> Result<SelfEvalReport>;
+}
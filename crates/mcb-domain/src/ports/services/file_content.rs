@@ -0,0 +1,72 @@
+//! File content retrieval ports.
+
+use std::path::PathBuf;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::CollectionId;
+
+/// Request for a line range of one indexed file.
+#[derive(Debug, Clone)]
+pub struct FileReadRequest {
+    /// Workspace-relative file path, as reported by search results.
+    pub path: String,
+    /// First line to return (1-based, inclusive); defaults to the first line.
+    pub start_line: Option<u32>,
+    /// Last line to return (1-based, inclusive); defaults to the last line.
+    pub end_line: Option<u32>,
+    /// Maximum bytes of content to return.
+    pub max_bytes: usize,
+    /// Repository root the client is working in. Live content is read from
+    /// the root the collection was indexed from; this must match it.
+    pub repo_root: Option<PathBuf>,
+}
+
+/// Where returned file content came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileContentSource {
+    /// Read from the working tree.
+    Disk,
+    /// Full file snapshot stored at index time.
+    Snapshot,
+    /// Rebuilt from the chunks stored in the collection.
+    Chunks,
+}
+
+/// A line range of a file.
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSlice {
+    /// Workspace-relative file path.
+    pub path: String,
+    /// Language recorded at index time.
+    pub language: String,
+    /// First returned line (1-based).
+    pub start_line: u32,
+    /// Last returned line (1-based); below `start_line` when nothing was returned.
+    pub end_line: u32,
+    /// Number of lines in the file.
+    pub total_lines: u32,
+    /// Content of the returned lines, newline-separated.
+    pub content: String,
+    /// Source of `content`.
+    pub source: FileContentSource,
+    /// `true` when the byte limit cut the requested range short.
+    pub truncated: bool,
+}
+
+/// File Content Service Interface
+///
+/// Serves line ranges of files indexed in a collection. Only files present in
+/// the collection can be read.
+#[async_trait]
+pub trait FileContentServiceInterface: Send + Sync {
+    /// Read a line range of an indexed file.
+    async fn read_file(
+        &self,
+        collection: &CollectionId,
+        request: &FileReadRequest,
+    ) -> Result<FileSlice>;
+}
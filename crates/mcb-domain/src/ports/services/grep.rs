@@ -0,0 +1,74 @@
+//! Grep service ports.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::{CollectionId, SearchResult, SearchScope};
+
+/// Exact-text search request over an indexed collection.
+#[derive(Debug, Clone)]
+pub struct GrepQuery {
+    /// Text or regular expression to look for.
+    pub pattern: String,
+    /// Interpret `pattern` as a regular expression instead of a literal.
+    pub regex: bool,
+    /// Match letter case exactly.
+    pub case_sensitive: bool,
+    /// Restrict the scan to these paths and languages.
+    pub scope: Option<SearchScope>,
+    /// Stop after this many matching lines.
+    pub max_matches: usize,
+}
+
+/// One line that matched a grep pattern.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GrepMatch {
+    /// Workspace-relative file path.
+    pub file_path: String,
+    /// 1-based line number in the file.
+    pub line: u32,
+    /// Matching line, trimmed to a bounded length.
+    pub text: String,
+}
+
+/// Outcome of scanning a collection.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GrepOutcome {
+    /// Matching lines, ordered by file then line.
+    pub matches: Vec<GrepMatch>,
+    /// Number of indexed files scanned.
+    pub files_scanned: usize,
+    /// `true` when the scan stopped at `max_matches`.
+    pub truncated: bool,
+}
+
+/// A semantic search result confirmed by at least one exact match.
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfirmedResult {
+    /// The semantic search result.
+    #[serde(flatten)]
+    pub result: SearchResult,
+    /// Lines of the result's chunk matching the grep pattern.
+    pub matches: Vec<GrepMatch>,
+}
+
+/// Grep Service Interface
+///
+/// Literal and regular-expression search over the indexed file snapshot,
+/// optionally intersected with semantic search results.
+#[async_trait]
+pub trait GrepServiceInterface: Send + Sync {
+    /// Scan every indexed file in scope for lines matching the pattern.
+    async fn grep(&self, collection: &CollectionId, query: &GrepQuery) -> Result<GrepOutcome>;
+
+    /// Run a semantic search for `semantic_query` and keep only the results
+    /// whose chunk contains a line matching the grep pattern.
+    async fn grep_semantic(
+        &self,
+        collection: &CollectionId,
+        semantic_query: &str,
+        query: &GrepQuery,
+        limit: usize,
+    ) -> Result<Vec<ConfirmedResult>>;
+}
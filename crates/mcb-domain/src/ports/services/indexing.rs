@@ -1,8 +1,11 @@
 //! Indexing service ports.
 
+use std::collections::BTreeMap;
 use std::path::Path;
 
 use async_trait::async_trait;
+use mcb_utils::constants::limits::CHARS_PER_TOKEN_ESTIMATE;
+use serde::{Deserialize, Serialize};
 
 use crate::entities::CodeChunk;
 use crate::error::Result;
@@ -13,11 +16,12 @@ use crate::value_objects::{CollectionId, OperationId};
 /// Defines the contract for codebase indexing operations.
 #[async_trait]
 pub trait IndexingServiceInterface: Send + Sync {
-    /// Index a codebase at the given path.
+    /// Index a codebase at the given path on behalf of `org_id`.
     async fn index_codebase(
         &self,
         path: &Path,
         collection: &CollectionId,
+        org_id: &str,
     ) -> Result<IndexingResult>;
 
     /// Get the current indexing status.
@@ -59,6 +63,93 @@ pub struct IndexingStatus {
     pub processed_files: usize,
 }
 
+/// What indexing a codebase would produce, measured without embedding or
+/// storing anything (`mcb index --dry-run`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct IndexingEstimate {
+    /// Files that would be indexed
+    pub files: usize,
+    /// Files that could not be read
+    pub files_skipped: usize,
+    /// File count per extension
+    pub files_by_extension: BTreeMap<String, usize>,
+    /// Chunks the files split into
+    pub chunks: usize,
+    /// Chunk count per language
+    pub chunks_by_language: BTreeMap<String, usize>,
+    /// Bytes of chunk content to embed and store
+    pub content_bytes: u64,
+    /// Tokens sent to the embedding provider, at 4 bytes per token
+    pub estimated_tokens: u64,
+    /// Cost and storage per embedding model, cheapest first
+    pub projections: Vec<EmbeddingProjection>,
+}
+
+/// Projected cost and storage of embedding an [`IndexingEstimate`] with one model.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EmbeddingProjection {
+    /// Embedding provider name
+    pub provider: String,
+    /// Model name
+    pub model: String,
+    /// Vector dimensions
+    pub dimensions: usize,
+    /// Embedding cost in USD at the model's list price
+    pub cost_usd: f64,
+    /// Bytes for the vectors (f32) plus the stored chunk content
+    pub storage_bytes: u64,
+}
+
+impl IndexingEstimate {
+    /// Count one readable file with `extension` (without the dot).
+    pub fn record_file(&mut self, extension: &str) {
+        self.files += 1;
+        *self
+            .files_by_extension
+            .entry(extension.to_ascii_lowercase())
+            .or_default() += 1;
+    }
+
+    /// Count one chunk of `language` with `content_bytes` of content.
+    pub fn record_chunk(&mut self, language: &str, content_bytes: usize) {
+        self.chunks += 1;
+        *self
+            .chunks_by_language
+            .entry(language.to_owned())
+            .or_default() += 1;
+        let bytes = content_bytes as u64;
+        self.content_bytes += bytes;
+        self.estimated_tokens += bytes.div_ceil(CHARS_PER_TOKEN_ESTIMATE as u64);
+    }
+
+    /// Add cost and storage projections for `prices`, given as
+    /// `(provider, model, dimensions, USD per million tokens)`.
+    #[must_use]
+    pub fn with_projections(mut self, prices: &[(&str, &str, usize, f64)]) -> Self {
+        self.projections = prices.iter().map(|&price| self.project(price)).collect();
+        self.projections
+            .sort_by(|a, b| a.cost_usd.total_cmp(&b.cost_usd));
+        self
+    }
+
+    /// Cost and storage of embedding this estimate at one `price`, given as
+    /// `(provider, model, dimensions, USD per million tokens)`.
+    #[must_use]
+    pub fn project(
+        &self,
+        (provider, model, dimensions, usd_per_million): (&str, &str, usize, f64),
+    ) -> EmbeddingProjection {
+        let vector_bytes = (dimensions * std::mem::size_of::<f32>()) as u64;
+        EmbeddingProjection {
+            provider: provider.to_owned(),
+            model: model.to_owned(),
+            dimensions,
+            cost_usd: self.estimated_tokens as f64 / 1_000_000.0 * usd_per_million,
+            storage_bytes: self.chunks as u64 * vector_bytes + self.content_bytes,
+        }
+    }
+}
+
 /// Domain Service: Advanced Batch Indexing Operations
 ///
 /// Extended interface for batch indexing services that handle
@@ -90,3 +181,18 @@ pub struct IndexingStats {
     /// Average indexing throughput (chunks per second)
     pub avg_throughput: f64,
 }
+
+/// Pacing of background indexing, so a re-index leaves CPU, disk and the
+/// embedding provider to other work on a shared machine.
+///
+/// Configured under `mcp.indexing.throttle`; every limit is off at 0.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct IndexingThrottlePolicy {
+    /// Files started per second, across all running index jobs
+    pub max_files_per_sec: u32,
+    /// Embedding requests sent per second, across all running index jobs
+    pub max_embedding_requests_per_sec: u32,
+    /// Pause after reading each file, giving other processes the disk
+    pub io_pause_ms: u64,
+}
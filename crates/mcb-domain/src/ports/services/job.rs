@@ -2,9 +2,16 @@
 
 use std::collections::HashMap;
 
+use async_trait::async_trait;
 use derive_more::Display;
+use mcb_utils::constants::limits::{
+    BULKHEAD_ADMIN_MAX_CONCURRENT, BULKHEAD_INDEXING_MAX_CONCURRENT,
+    BULKHEAD_SEARCH_MAX_CONCURRENT, JOB_SCHEDULER_MAX_CONCURRENT,
+    JOB_SCHEDULER_RESERVED_INTERACTIVE,
+};
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
 use crate::value_objects::OperationId;
 
 /// Unique identifier for a job (wraps `OperationId` for domain consistency)
@@ -176,3 +183,177 @@ pub struct JobCounts {
     /// Number of cancelled jobs
     pub cancelled: usize,
 }
+
+/// Scheduling class of a unit of work; interactive work is admitted first
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum JobPriority {
+    /// Work a user is waiting on, such as a search
+    #[display("interactive")]
+    Interactive,
+    /// Work nobody waits on, such as (re-)indexing
+    #[display("background")]
+    Background,
+}
+
+/// Concurrency limits of the job scheduler.
+///
+/// Configured under `system.infrastructure.jobs`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JobSchedulerPolicy {
+    /// Units of work allowed to run at once
+    pub max_concurrent: usize,
+    /// Slots only interactive work may take (must be below `max_concurrent`)
+    pub reserved_interactive: usize,
+}
+
+impl JobSchedulerPolicy {
+    /// Slots background work may take.
+    #[must_use]
+    pub fn background_slots(&self) -> usize {
+        self.max_concurrent
+            .saturating_sub(self.reserved_interactive)
+    }
+
+    /// Validate limits; `key` is the config path used in error messages.
+    ///
+    /// # Errors
+    /// Returns a configuration error when `max_concurrent` is 0 or the
+    /// reserved slots leave none for background work.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        if self.max_concurrent == 0 {
+            return Err(Error::config_invalid(
+                format!("{key}.max_concurrent"),
+                "must be at least 1",
+            ));
+        }
+        if self.background_slots() == 0 {
+            return Err(Error::config_invalid(
+                format!("{key}.reserved_interactive"),
+                "must be below max_concurrent",
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for JobSchedulerPolicy {
+    fn default() -> Self {
+        Self {
+            max_concurrent: JOB_SCHEDULER_MAX_CONCURRENT,
+            reserved_interactive: JOB_SCHEDULER_RESERVED_INTERACTIVE,
+        }
+    }
+}
+
+/// Slot held while one unit of scheduled work runs; dropping it frees the
+/// slot for the next waiter.
+pub struct JobPermit {
+    _guard: Box<dyn Send + Sync>,
+}
+
+impl JobPermit {
+    /// Wrap the scheduler-specific guard that releases the slot on drop.
+    pub fn new(guard: impl Send + Sync + 'static) -> Self {
+        Self {
+            _guard: Box::new(guard),
+        }
+    }
+}
+
+impl std::fmt::Debug for JobPermit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JobPermit").finish_non_exhaustive()
+    }
+}
+
+/// Admission control for background jobs and interactive requests.
+///
+/// Limits how much work runs at once. Waiting interactive work is admitted
+/// before any background work, and background work of different
+/// organizations takes turns so one org's re-index cannot starve another's.
+#[async_trait]
+pub trait JobSchedulerInterface: Send + Sync {
+    /// Wait for a slot for one unit of `org_id`'s work in class `priority`.
+    async fn acquire(&self, org_id: &str, priority: JobPriority) -> JobPermit;
+}
+
+/// Resource pool a unit of work runs in; each pool has its own limit so one
+/// kind of work cannot exhaust the capacity of another
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+#[serde(rename_all = "snake_case")]
+pub enum Workload {
+    /// Semantic and hybrid searches
+    #[display("search")]
+    Search,
+    /// Files being indexed
+    #[display("indexing")]
+    Indexing,
+    /// Admin-scoped tool calls
+    #[display("admin")]
+    Admin,
+}
+
+/// Concurrency limit of each bulkhead pool.
+///
+/// Configured under `system.infrastructure.bulkheads`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BulkheadPolicy {
+    /// Searches allowed to run at once
+    pub search: usize,
+    /// Files allowed to be indexed at once
+    pub indexing: usize,
+    /// Admin tool calls allowed to run at once
+    pub admin: usize,
+}
+
+impl BulkheadPolicy {
+    /// Limit of the pool of `workload`.
+    #[must_use]
+    pub fn limit(&self, workload: Workload) -> usize {
+        match workload {
+            Workload::Search => self.search,
+            Workload::Indexing => self.indexing,
+            Workload::Admin => self.admin,
+        }
+    }
+
+    /// Validate limits; `key` is the config path used in error messages.
+    ///
+    /// # Errors
+    /// Returns a configuration error when a pool has a limit of 0.
+    pub fn validate(&self, key: &str) -> Result<()> {
+        for workload in [Workload::Search, Workload::Indexing, Workload::Admin] {
+            if self.limit(workload) == 0 {
+                return Err(Error::config_invalid(
+                    format!("{key}.{workload}"),
+                    "must be at least 1",
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for BulkheadPolicy {
+    fn default() -> Self {
+        Self {
+            search: BULKHEAD_SEARCH_MAX_CONCURRENT,
+            indexing: BULKHEAD_INDEXING_MAX_CONCURRENT,
+            admin: BULKHEAD_ADMIN_MAX_CONCURRENT,
+        }
+    }
+}
+
+/// Separate bounded pools for search, indexing and admin work.
+///
+/// Unlike the job scheduler, pools share nothing: a saturated indexing pool
+/// never delays a search.
+#[async_trait]
+pub trait BulkheadInterface: Send + Sync {
+    /// Wait for room in the pool of `workload`; the permit holds it until
+    /// dropped.
+    async fn enter(&self, workload: Workload) -> JobPermit;
+}
@@ -7,10 +7,20 @@
 pub mod agent;
 /// Browse and highlight operations.
 pub mod browse;
+/// Symbol-level change summaries.
+pub mod change_summary;
 /// Code chunking operations.
 pub mod chunking;
 /// Code intelligence / context operations.
 pub mod context;
+/// Clone detection over indexed collections.
+pub mod duplicates;
+/// Index self-evaluation with synthetic queries.
+pub mod evaluation;
+/// Line-range retrieval of indexed files.
+pub mod file_content;
+/// Literal and regex search over indexed files.
+pub mod grep;
 /// File hash state management.
 pub mod hash;
 /// Codebase indexing operations.
@@ -19,35 +29,73 @@ pub mod indexing;
 pub mod job;
 /// Memory / observation storage and search.
 pub mod memory;
+/// Module-level import graph queries.
+pub mod module_graph;
 /// Project detection operations.
 pub mod project;
+/// Repository onboarding analysis.
+pub mod repo_analysis;
 /// Semantic code search operations.
 pub mod search;
+/// Search relevance feedback and learned boosts.
+pub mod search_feedback;
+/// Session-scoped context tracking.
+pub mod session_context;
+/// Organization usage reports and billing exports.
+pub mod usage;
 /// Architecture validation operations.
 pub mod validation_service;
+/// Workspaces of related collections and federated search.
+pub mod workspace;
 
 // Re-exports for canonical access via `ports::services::{...}`
 pub use agent::{
     AgentSessionManager, AgentSessionServiceInterface, CheckpointManager, DelegationTracker,
 };
 pub use browse::{BrowseError, BrowseServiceInterface, HighlightError, HighlightServiceInterface};
+pub use change_summary::{
+    ChangeBaseline, ChangeSummary, ChangeSummaryServiceInterface, FileChangeSummary, SymbolChange,
+    SymbolChangeKind,
+};
 pub use chunking::{ChunkingOptions, ChunkingOrchestratorInterface, ChunkingResult, CodeChunker};
 pub use context::ContextServiceInterface;
+pub use duplicates::{
+    CloneKind, CodeFragment, DuplicateDetectionServiceInterface, DuplicatePair, DuplicateQuery,
+    DuplicateReport,
+};
+pub use evaluation::{IndexEvaluationServiceInterface, SelfEvalOptions, SelfEvalReport};
+pub use file_content::{
+    FileContentServiceInterface, FileContentSource, FileReadRequest, FileSlice,
+};
+pub use grep::{ConfirmedResult, GrepMatch, GrepOutcome, GrepQuery, GrepServiceInterface};
 pub use hash::FileHashService;
 pub use indexing::{
-    BatchIndexingServiceInterface, IndexingResult, IndexingServiceInterface, IndexingStats,
-    IndexingStatus,
+    BatchIndexingServiceInterface, EmbeddingProjection, IndexingEstimate, IndexingResult,
+    IndexingServiceInterface, IndexingStats, IndexingStatus, IndexingThrottlePolicy,
 };
 pub use job::{
-    Job, JobCounts, JobId, JobManagerInterface, JobProgressUpdate, JobResult, JobStatus, JobType,
+    BulkheadInterface, BulkheadPolicy, Job, JobCounts, JobId, JobManagerInterface, JobPermit,
+    JobPriority, JobProgressUpdate, JobResult, JobSchedulerInterface, JobSchedulerPolicy,
+    JobStatus, JobType, Workload,
 };
 pub use memory::{
     CreateSessionSummaryInput, ErrorPatternManager, MemorySearcher, MemoryServiceInterface,
     ObservationManager, SessionSummaryManager, StoreObservationInput,
 };
+pub use module_graph::{
+    ChangeImpact, ImpactedModule, ModuleDependencies, ModuleDependency,
+    ModuleGraphServiceInterface, ModuleImporter,
+};
 pub use project::ProjectDetectorService;
-pub use search::{SearchFilters, SearchServiceInterface};
+pub use repo_analysis::{
+    RepoAnalysis, RepoAnalysisServiceInterface, RepoChurn, RepoProfile, RepoRecommendation,
+};
+pub use search::{Diversification, DiversifyBy, SearchFilters, SearchServiceInterface};
+pub use search_feedback::{RecordFeedbackInput, SearchFeedbackServiceInterface};
+pub use session_context::{ContextBatch, SessionContextServiceInterface};
+pub use usage::{OrgUsage, UsageReport, UsageServiceInterface};
 pub use validation_service::{
     ComplexityReport, FunctionComplexity, RuleInfo, ValidationReport, ValidationServiceInterface,
     ViolationEntry,
 };
+pub use workspace::{WorkspaceSearchResult, WorkspaceServiceInterface};
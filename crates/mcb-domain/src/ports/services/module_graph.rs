@@ -0,0 +1,84 @@
+//! Module dependency graph ports.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::CollectionId;
+
+/// An import made by a file.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleDependency {
+    /// Import as written.
+    pub specifier: String,
+    /// Line of the import statement (1-based).
+    pub line: u32,
+    /// Indexed files the import resolves to; empty for external modules.
+    pub resolved: Vec<String>,
+}
+
+/// A file importing another.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleImporter {
+    /// Workspace-relative path of the importing file.
+    pub file: String,
+    /// Import as written.
+    pub specifier: String,
+    /// Line of the import statement (1-based).
+    pub line: u32,
+}
+
+/// Direct dependencies and dependents of one file.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModuleDependencies {
+    /// Workspace-relative file path.
+    pub path: String,
+    /// Imports made by the file, in source order.
+    pub imports: Vec<ModuleDependency>,
+    /// Indexed files importing the file.
+    pub imported_by: Vec<ModuleImporter>,
+}
+
+/// A file affected by a change through the import graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct ImpactedModule {
+    /// Workspace-relative file path.
+    pub path: String,
+    /// Import hops from the nearest changed file (1 = imports it directly).
+    pub depth: usize,
+    /// File one hop closer to the change that this file imports.
+    pub via: String,
+}
+
+/// Files transitively importing a set of changed files.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeImpact {
+    /// The changed files.
+    pub changed: Vec<String>,
+    /// Affected files, nearest first.
+    pub impacted: Vec<ImpactedModule>,
+    /// Whether files beyond the depth limit import an affected file.
+    pub truncated: bool,
+}
+
+/// Module Graph Service Interface
+///
+/// Resolves the import edges recorded at index time against the files of a
+/// collection.
+#[async_trait]
+pub trait ModuleGraphServiceInterface: Send + Sync {
+    /// Imports of `path` and the files importing it.
+    async fn module_dependencies(
+        &self,
+        collection: &CollectionId,
+        path: &str,
+    ) -> Result<ModuleDependencies>;
+    /// Files reached from `paths` by following imports backwards, up to
+    /// `max_depth` hops.
+    async fn impact_of_change(
+        &self,
+        collection: &CollectionId,
+        paths: &[String],
+        max_depth: usize,
+    ) -> Result<ChangeImpact>;
+}
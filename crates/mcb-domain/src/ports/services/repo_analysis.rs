@@ -0,0 +1,239 @@
+//! Repository onboarding analysis ports.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use async_trait::async_trait;
+use mcb_utils::constants::embedding::{EMBEDDING_PRICE_TABLE, OPENAI_API_KEY_ENV};
+use mcb_utils::constants::use_cases::{
+    ONBOARDING_DISTRIBUTED_STORE_MIN_CHUNKS, ONBOARDING_EMBEDDED_STORE_MAX_CHUNKS,
+    ONBOARDING_HIGH_CHURN_RATIO, ONBOARDING_HOSTED_CHUNKS_PER_SEC, ONBOARDING_HOSTED_EMBEDDING,
+    ONBOARDING_LOCAL_CHUNKS_PER_SEC, ONBOARDING_LOCAL_EMBEDDING,
+    ONBOARDING_LOCAL_EMBEDDING_MAX_CHUNKS,
+};
+use mcb_utils::constants::vector_store::{MILVUS_DEFAULT_PORT, QDRANT_DEFAULT_PORT};
+use mcb_utils::constants::{PROVIDER_SLUG_EDGEVEC, PROVIDER_SLUG_MILVUS, PROVIDER_SLUG_QDRANT};
+use serde::Serialize;
+
+use super::indexing::{EmbeddingProjection, IndexingEstimate};
+use crate::error::Result;
+
+/// Recent activity in a repository's history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RepoChurn {
+    /// Length of the window in days
+    pub window_days: u32,
+    /// Commits on the default branch within the window
+    pub commits: usize,
+    /// Files whose last change lies within the window
+    pub files_changed: usize,
+}
+
+/// Size, languages and churn of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepoProfile {
+    /// Files, chunks, languages and tokens, as `mcb index --dry-run` measures them
+    pub size: IndexingEstimate,
+    /// Recent history; `None` when the directory is not a repository
+    pub churn: Option<RepoChurn>,
+}
+
+impl RepoProfile {
+    /// Share of indexable files changed within the churn window.
+    #[must_use]
+    pub fn churn_ratio(&self) -> Option<f64> {
+        let churn = self.churn.as_ref()?;
+        (self.size.files > 0).then(|| churn.files_changed as f64 / self.size.files as f64)
+    }
+
+    /// Whether the churn ratio reaches [`ONBOARDING_HIGH_CHURN_RATIO`].
+    #[must_use]
+    pub fn is_high_churn(&self) -> bool {
+        self.churn_ratio()
+            .is_some_and(|ratio| ratio >= ONBOARDING_HIGH_CHURN_RATIO)
+    }
+}
+
+/// Configuration recommended for indexing a repository.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepoRecommendation {
+    /// Extensions to index (`mcp.indexing.supported_extensions`), most files first
+    pub supported_extensions: Vec<String>,
+    /// Whether to store title vectors (`mcp.indexing.title_vectors`)
+    pub title_vectors: bool,
+    /// Recommended embedding model with its projected cost and storage
+    pub embedding: EmbeddingProjection,
+    /// Recommended vector store provider
+    pub vector_store: String,
+    /// Estimated duration of a full index, in seconds
+    pub estimated_index_secs: u64,
+    /// One line per decision, explaining it
+    pub rationale: Vec<String>,
+    /// YAML overlay applying the recommendation, for a config profile
+    pub config_snippet: String,
+}
+
+impl RepoRecommendation {
+    /// Recommend a configuration for `profile`.
+    ///
+    /// Repositories up to [`ONBOARDING_LOCAL_EMBEDDING_MAX_CHUNKS`] chunks
+    /// get a local embedding model and title vectors; larger ones a hosted
+    /// model. The embedded store serves up to
+    /// [`ONBOARDING_EMBEDDED_STORE_MAX_CHUNKS`] chunks, Qdrant takes over
+    /// beyond that and Milvus from [`ONBOARDING_DISTRIBUTED_STORE_MIN_CHUNKS`],
+    /// unless churn is high: Milvus has no atomic upserts, so frequently
+    /// re-indexed repositories stay on Qdrant.
+    #[must_use]
+    pub fn for_profile(profile: &RepoProfile) -> Self {
+        let size = &profile.size;
+        let mut rationale = Vec::new();
+
+        let mut extensions: Vec<(&String, &usize)> = size.files_by_extension.iter().collect();
+        extensions.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+        let supported_extensions: Vec<String> =
+            extensions.into_iter().map(|(ext, _)| ext.clone()).collect();
+        rationale.push(format!(
+            "index the {} extension(s) found in {} file(s)",
+            supported_extensions.len(),
+            size.files
+        ));
+
+        let local = size.chunks <= ONBOARDING_LOCAL_EMBEDDING_MAX_CHUNKS;
+        let (provider, model) = if local {
+            ONBOARDING_LOCAL_EMBEDDING
+        } else {
+            ONBOARDING_HOSTED_EMBEDDING
+        };
+        let embedding = size.project(price(provider, model));
+        rationale.push(if local {
+            format!(
+                "{} chunks embed locally in reasonable time: {provider} needs no API key and costs nothing",
+                size.chunks
+            )
+        } else {
+            format!(
+                "{} chunks are too many for a local model on a CPU: {provider} {model} costs about ${:.2} per full index",
+                size.chunks, embedding.cost_usd
+            )
+        });
+
+        let title_vectors = local;
+        rationale.push(if title_vectors {
+            "title vectors double the embedding work, which stays cheap at this size".to_owned()
+        } else {
+            "title vectors would double the hosted embedding cost; left off".to_owned()
+        });
+
+        let high_churn = profile.is_high_churn();
+        let vector_store = if size.chunks <= ONBOARDING_EMBEDDED_STORE_MAX_CHUNKS {
+            rationale.push(format!(
+                "{PROVIDER_SLUG_EDGEVEC} runs in-process and holds {} chunks without a server",
+                size.chunks
+            ));
+            PROVIDER_SLUG_EDGEVEC
+        } else if size.chunks < ONBOARDING_DISTRIBUTED_STORE_MIN_CHUNKS || high_churn {
+            rationale.push(if high_churn {
+                format!(
+                    "{PROVIDER_SLUG_QDRANT} upserts atomically, which suits a repository re-indexed this often"
+                )
+            } else {
+                format!("{PROVIDER_SLUG_QDRANT} serves {} chunks from a single server", size.chunks)
+            });
+            PROVIDER_SLUG_QDRANT
+        } else {
+            rationale.push(format!(
+                "{PROVIDER_SLUG_MILVUS} distributes {} chunks across nodes",
+                size.chunks
+            ));
+            PROVIDER_SLUG_MILVUS
+        };
+        if let (Some(churn), Some(ratio)) = (&profile.churn, profile.churn_ratio()) {
+            rationale.push(format!(
+                "{} commit(s) touched {:.0}% of the files in the last {} days",
+                churn.commits,
+                ratio * 100.0,
+                churn.window_days
+            ));
+        }
+
+        let chunks_per_sec = if local {
+            ONBOARDING_LOCAL_CHUNKS_PER_SEC
+        } else {
+            ONBOARDING_HOSTED_CHUNKS_PER_SEC
+        };
+        let vectors_per_chunk = if title_vectors { 2.0 } else { 1.0 };
+        let estimated_index_secs =
+            (size.chunks as f64 * vectors_per_chunk / chunks_per_sec).ceil() as u64;
+
+        let mut recommendation = Self {
+            supported_extensions,
+            title_vectors,
+            embedding,
+            vector_store: vector_store.to_owned(),
+            estimated_index_secs,
+            rationale,
+            config_snippet: String::new(),
+        };
+        recommendation.config_snippet = recommendation.render_config();
+        recommendation
+    }
+
+    /// Render the recommendation as a `settings:` overlay.
+    fn render_config(&self) -> String {
+        let mut yaml = String::from("settings:\n  providers:\n    embedding:\n");
+        let _ = writeln!(yaml, "      provider: {}", self.embedding.provider);
+        let _ = writeln!(yaml, "      model: {}", self.embedding.model);
+        let _ = writeln!(yaml, "      dimensions: {}", self.embedding.dimensions);
+        if self.embedding.provider == ONBOARDING_HOSTED_EMBEDDING.0 {
+            let _ = writeln!(yaml, "      api_key: \"${{{OPENAI_API_KEY_ENV}}}\"");
+        }
+        yaml.push_str("    vector_store:\n");
+        let _ = writeln!(yaml, "      provider: {}", self.vector_store);
+        let _ = writeln!(yaml, "      dimensions: {}", self.embedding.dimensions);
+        let port = match self.vector_store.as_str() {
+            PROVIDER_SLUG_QDRANT => Some(QDRANT_DEFAULT_PORT),
+            PROVIDER_SLUG_MILVUS => Some(MILVUS_DEFAULT_PORT),
+            _ => None,
+        };
+        if let Some(port) = port {
+            let _ = writeln!(yaml, "      address: \"http://localhost:{port}\"");
+        }
+        let _ = writeln!(
+            yaml,
+            "  mcp:\n    indexing:\n      supported_extensions: [{}]\n      title_vectors: {}",
+            self.supported_extensions.join(", "),
+            self.title_vectors
+        );
+        yaml
+    }
+}
+
+/// Price row of `provider`/`model` in [`EMBEDDING_PRICE_TABLE`].
+fn price(provider: &'static str, model: &'static str) -> (&'static str, &'static str, usize, f64) {
+    EMBEDDING_PRICE_TABLE
+        .iter()
+        .copied()
+        .find(|&(p, m, _, _)| p == provider && m == model)
+        .unwrap_or((provider, model, 0, 0.0))
+}
+
+/// A repository profile with the configuration recommended for it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RepoAnalysis {
+    /// Analyzed directory
+    pub path: String,
+    /// What was measured
+    pub profile: RepoProfile,
+    /// What to configure
+    pub recommendation: RepoRecommendation,
+}
+
+/// Repository Analysis Service Interface
+///
+/// Profiles a repository before it is first indexed and recommends how to
+/// configure MCB for it, without calling any embedding or vector store API.
+#[async_trait]
+pub trait RepoAnalysisServiceInterface: Send + Sync {
+    /// Profile the repository at `path` and recommend a configuration.
+    async fn analyze_repository(&self, path: &Path) -> Result<RepoAnalysis>;
+}
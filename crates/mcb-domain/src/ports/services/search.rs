@@ -3,7 +3,7 @@
 use async_trait::async_trait;
 
 use crate::error::Result;
-use crate::value_objects::{CollectionId, SearchResult};
+use crate::value_objects::{CollectionId, SearchResult, SearchScope};
 
 /// Search Service Interface
 ///
@@ -26,6 +26,21 @@ pub trait SearchServiceInterface: Send + Sync {
         limit: usize,
         filters: Option<&SearchFilters>,
     ) -> Result<Vec<SearchResult>>;
+
+    /// Search with the query embedding blended toward conversation context.
+    ///
+    /// `context` holds recent conversation texts (e.g. session observations);
+    /// their mean embedding contributes `context_weight` (0.0-1.0) of the final
+    /// query vector. An empty `context` behaves like [`Self::search_with_filters`].
+    async fn search_with_context(
+        &self,
+        collection: &CollectionId,
+        query: &str,
+        context: &[String],
+        context_weight: f32,
+        limit: usize,
+        filters: Option<&SearchFilters>,
+    ) -> Result<Vec<SearchResult>>;
 }
 
 /// Filters for search queries
@@ -35,6 +50,56 @@ pub struct SearchFilters {
     pub file_extensions: Option<Vec<String>>,
     /// Filter by programming language
     pub languages: Option<Vec<String>>,
+    /// Only search files under this workspace-relative path prefix
+    pub path_prefix: Option<String>,
+    /// Only search files matching this workspace-relative glob
+    pub path_glob: Option<String>,
+    /// Skip files whose path starts with, or matches as a glob, any of these
+    pub exclude_paths: Option<Vec<String>>,
+    /// Skip chunks in any of these languages
+    pub exclude_languages: Option<Vec<String>>,
     /// Minimum relevance score threshold (0.0 to 1.0)
     pub min_score: Option<f32>,
+    /// Re-rank with Maximal Marginal Relevance to spread results across groups
+    pub diversify: Option<Diversification>,
+    /// Favor recently committed code; days for the recency boost to halve
+    pub recency_half_life_days: Option<f64>,
+    /// Attach a per-stage [`ScoreExplanation`](crate::value_objects::ScoreExplanation)
+    /// to every result
+    pub explain: bool,
+}
+
+impl SearchFilters {
+    /// Path and language restrictions and exclusions to push down to retrieval, if any.
+    #[must_use]
+    pub fn scope(&self) -> Option<SearchScope> {
+        let scope = SearchScope {
+            path_prefix: self.path_prefix.clone(),
+            path_glob: self.path_glob.clone(),
+            languages: self.languages.clone(),
+            exclude_paths: self.exclude_paths.clone(),
+            exclude_languages: self.exclude_languages.clone(),
+        };
+        (!scope.is_unrestricted()).then_some(scope)
+    }
+}
+
+/// Grouping used to judge whether two results are redundant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiversifyBy {
+    /// Results from the same file are redundant
+    File,
+    /// Results from the same directory are redundant
+    Directory,
+    /// Results covering the same symbol in the same file are redundant
+    Symbol,
+}
+
+/// Maximal Marginal Relevance (MMR) re-ranking options.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Diversification {
+    /// Grouping that defines redundancy between results
+    pub by: DiversifyBy,
+    /// Trade-off between relevance (1.0) and diversity (0.0)
+    pub lambda: f32,
 }
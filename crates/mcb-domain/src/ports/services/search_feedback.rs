@@ -0,0 +1,45 @@
+//! Search feedback service ports.
+
+use async_trait::async_trait;
+
+use crate::error::Result;
+use crate::value_objects::SearchResult;
+
+/// Input for recording relevance feedback on a search result.
+#[derive(Debug, Clone)]
+pub struct RecordFeedbackInput {
+    /// Query the result was returned for.
+    pub query: String,
+    /// Collection the result came from.
+    pub collection: String,
+    /// Identifier of the rated chunk.
+    pub chunk_id: String,
+    /// File containing the rated chunk.
+    pub file_path: String,
+    /// `true` for thumbs up, `false` for thumbs down.
+    pub relevant: bool,
+    /// Session giving the feedback, when known.
+    pub session_id: Option<String>,
+}
+
+/// Search Feedback Service Interface
+///
+/// Records relevance feedback and turns it into per-file score boosts that
+/// re-rank later searches.
+#[async_trait]
+pub trait SearchFeedbackServiceInterface: Send + Sync {
+    /// Record one judgement; returns the feedback id.
+    async fn record_feedback(&self, input: RecordFeedbackInput) -> Result<String>;
+
+    /// Recompute per-file boosts from all accumulated feedback.
+    ///
+    /// Returns the number of files that received a non-neutral boost.
+    async fn tune_file_boosts(&self) -> Result<usize>;
+
+    /// Multiply scores by the collection's learned file boosts and re-sort.
+    async fn apply_file_boosts(
+        &self,
+        collection: &str,
+        results: Vec<SearchResult>,
+    ) -> Result<Vec<SearchResult>>;
+}
@@ -0,0 +1,47 @@
+//! Session-scoped context ports.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::error::Result;
+use crate::value_objects::{CollectionId, SearchResult};
+
+/// One batch of not-yet-seen context for a session.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ContextBatch {
+    /// Chunks returned in this batch, best match first
+    pub results: Vec<SearchResult>,
+    /// Estimated tokens consumed by `results`
+    pub tokens_used: usize,
+    /// Matches skipped because the session has already seen them
+    pub skipped_seen: usize,
+    /// True when no unseen matches remain for the query
+    pub exhausted: bool,
+}
+
+/// Session Context Service Interface
+///
+/// Tracks which code chunks each MCP session has already received so follow-up
+/// requests return fresh context within a token budget.
+#[async_trait]
+pub trait SessionContextServiceInterface: Send + Sync {
+    /// Return the next unseen chunks for `query`, filling at most `token_budget` tokens.
+    ///
+    /// Returned chunks are recorded as seen for `session_id`.
+    async fn get_more_context(
+        &self,
+        session_id: &str,
+        collection: &CollectionId,
+        query: &str,
+        token_budget: usize,
+    ) -> Result<ContextBatch>;
+
+    /// Record chunks returned to a session by another path (e.g. `search`).
+    fn mark_returned(&self, session_id: &str, chunk_ids: &[String]);
+
+    /// Number of chunks recorded as seen for a session.
+    fn seen_count(&self, session_id: &str) -> usize;
+
+    /// Forget everything recorded for a session.
+    fn reset(&self, session_id: &str);
+}
@@ -0,0 +1,107 @@
+//! Organization usage reporting ports.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use crate::entities::usage::{UsageFilter, UsageRecord};
+use crate::error::Result;
+use crate::value_objects::CollectionId;
+
+/// Column order of [`UsageReport::to_csv`].
+const CSV_HEADER: &str = "org_id,month,collection,indexed_bytes,embedding_tokens,queries";
+
+/// Usage of one organization in one month, summed over its collections.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct OrgUsage {
+    /// Organization the usage is billed to
+    pub org_id: String,
+    /// Calendar month as `YYYY-MM`
+    pub month: String,
+    /// Collections with recorded usage in the month
+    pub collections: usize,
+    /// Bytes of file content indexed
+    pub indexed_bytes: u64,
+    /// Estimated embedding tokens
+    pub embedding_tokens: u64,
+    /// Code search queries
+    pub queries: u64,
+}
+
+/// Usage report with per-organization totals and per-collection lines.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UsageReport {
+    /// Filter the report was built for
+    pub filter: UsageFilter,
+    /// Totals per organization and month
+    pub orgs: Vec<OrgUsage>,
+    /// Per-collection lines used for billing exports
+    pub collections: Vec<UsageRecord>,
+}
+
+impl UsageReport {
+    /// Build a report from per-collection records.
+    #[must_use]
+    pub fn from_records(filter: UsageFilter, collections: Vec<UsageRecord>) -> Self {
+        let mut totals: BTreeMap<(&str, &str), OrgUsage> = BTreeMap::new();
+        for record in &collections {
+            let total = totals
+                .entry((record.org_id.as_str(), record.month.as_str()))
+                .or_insert_with(|| OrgUsage {
+                    org_id: record.org_id.clone(),
+                    month: record.month.clone(),
+                    ..OrgUsage::default()
+                });
+            total.collections += 1;
+            total.indexed_bytes += record.indexed_bytes;
+            total.embedding_tokens += record.embedding_tokens;
+            total.queries += record.queries;
+        }
+        let orgs = totals.into_values().collect();
+        Self {
+            filter,
+            orgs,
+            collections,
+        }
+    }
+
+    /// Render the per-collection lines as CSV with a header row.
+    #[must_use]
+    pub fn to_csv(&self) -> String {
+        let mut csv = format!("{CSV_HEADER}\n");
+        for record in &self.collections {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{}",
+                csv_field(&record.org_id),
+                csv_field(&record.month),
+                csv_field(&record.collection),
+                record.indexed_bytes,
+                record.embedding_tokens,
+                record.queries
+            );
+        }
+        csv
+    }
+}
+
+/// Quote a CSV field when it contains a separator, quote or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Records usage as it happens and reports it per organization and month.
+#[async_trait]
+pub trait UsageServiceInterface: Send + Sync {
+    /// Record a code search query against `collection`.
+    async fn record_query(&self, org_id: &str, collection: &CollectionId) -> Result<()>;
+
+    /// Usage matching `filter`, totalled per organization and month.
+    async fn report(&self, filter: &UsageFilter) -> Result<UsageReport>;
+}
@@ -0,0 +1,58 @@
+//! Workspace ports: collections of related repositories searched together.
+
+use async_trait::async_trait;
+use serde::Serialize;
+
+use super::search::SearchFilters;
+use crate::entities::workspace::Workspace;
+use crate::error::Result;
+use crate::value_objects::{CollectionId, SearchResult};
+
+/// A code search result from one collection of a workspace.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkspaceSearchResult {
+    /// Name of the collection the result was found in
+    pub collection: String,
+    /// The result, as a search of that collection returns it
+    #[serde(flatten)]
+    pub result: SearchResult,
+    /// Identical copies of the file in other collections, as `collection:path`
+    pub also_in: Vec<String>,
+}
+
+/// Workspace Service Interface
+///
+/// Groups collections into workspaces and searches all collections of a
+/// workspace at once. Files vendored into several member collections are
+/// embedded once; results report the other copies instead of repeating them.
+#[async_trait]
+pub trait WorkspaceServiceInterface: Send + Sync {
+    /// Add the collection `name` to `workspace`, creating it if needed.
+    ///
+    /// Files of the collection indexed afterwards that are identical to a
+    /// file of another member are recorded as copies instead of embedded.
+    async fn add_collection(
+        &self,
+        workspace: &str,
+        name: &str,
+        collection: &CollectionId,
+    ) -> Result<Workspace>;
+
+    /// Remove a collection from `workspace`; returns whether it was a member.
+    async fn remove_collection(&self, workspace: &str, collection: &CollectionId) -> Result<bool>;
+
+    /// Every workspace with its collections.
+    async fn list_workspaces(&self) -> Result<Vec<Workspace>>;
+
+    /// Search every collection of `workspace` and merge the results by score.
+    ///
+    /// Scores are comparable only when the collections were indexed with
+    /// the same embedding model.
+    async fn search(
+        &self,
+        workspace: &str,
+        query: &str,
+        limit: usize,
+        filters: Option<&SearchFilters>,
+    ) -> Result<Vec<WorkspaceSearchResult>>;
+}
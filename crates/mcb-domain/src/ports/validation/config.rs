@@ -13,6 +13,13 @@ pub struct ValidationConfig {
     pub additional_src_paths: Vec<PathBuf>,
     /// Glob patterns for excluding files or directories.
     pub exclude_patterns: Vec<String>,
+    /// Changed files, absolute or relative to the workspace root. When
+    /// set, file-local checks only scan these files and only their violations
+    /// are reported; `None` validates the whole workspace.
+    pub changed_files: Option<Vec<PathBuf>>,
+    /// Directory of the persistent fact cache; `None` re-extracts facts
+    /// and metrics from every file on each run.
+    pub cache_dir: Option<PathBuf>,
 }
 
 impl ValidationConfig {
@@ -24,6 +31,8 @@ impl ValidationConfig {
             workspace_root: canonical,
             additional_src_paths: Vec::new(),
             exclude_patterns: Vec::new(),
+            changed_files: None,
+            cache_dir: None,
         }
     }
 
@@ -41,6 +50,20 @@ impl ValidationConfig {
         self
     }
 
+    /// Limit the run to `files` (absolute or relative to the workspace root).
+    #[must_use]
+    pub fn with_changed_files(mut self, files: Vec<PathBuf>) -> Self {
+        self.changed_files = Some(files);
+        self
+    }
+
+    /// Persist extracted facts and metrics under `dir` across runs.
+    #[must_use]
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
     /// Check if a specific path should be excluded according to the configured patterns.
     #[must_use]
     pub fn should_exclude(&self, path: &Path) -> bool {
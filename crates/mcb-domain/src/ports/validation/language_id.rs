@@ -129,8 +129,17 @@ impl LanguageId {
         LanguageData {
             id: Self::Python,
             name: "python",
-            extensions: &["py", "pyi", "pyw"],
-            filenames: &[],
+            // Bazel and SCons build files are Starlark/Python.
+            extensions: &["py", "pyi", "pyw", "bzl"],
+            filenames: &[
+                "build",
+                "build.bazel",
+                "workspace",
+                "workspace.bazel",
+                "module.bazel",
+                "sconstruct",
+                "sconscript",
+            ],
         },
         LanguageData {
             id: Self::JavaScript,
@@ -184,13 +193,20 @@ impl LanguageId {
             id: Self::Ruby,
             name: "ruby",
             extensions: &["rb", "rake", "gemspec"],
-            filenames: &[],
+            filenames: &["rakefile", "gemfile", "vagrantfile", "podfile", "brewfile"],
         },
         LanguageData {
             id: Self::Shell,
             name: "shell",
             extensions: &["sh", "bash", "zsh", "ksh"],
-            filenames: &[],
+            filenames: &[
+                ".bashrc",
+                ".bash_profile",
+                ".bash_aliases",
+                ".zshrc",
+                ".zprofile",
+                ".profile",
+            ],
         },
         LanguageData {
             id: Self::Yaml,
@@ -238,7 +254,7 @@ impl LanguageId {
             id: Self::Dockerfile,
             name: "dockerfile",
             extensions: &["dockerfile"],
-            filenames: &["dockerfile"],
+            filenames: &["dockerfile", "containerfile"],
         },
         LanguageData {
             id: Self::Makefile,
@@ -303,9 +319,14 @@ impl LanguageId {
     }
 
     /// Infer the language from a specific filename (e.g., "Dockerfile").
+    ///
+    /// `Dockerfile.<variant>` and `Containerfile.<variant>` count as Dockerfiles.
     #[must_use]
     pub fn from_filename(filename: &str) -> Option<Self> {
         let lower = filename.to_ascii_lowercase();
+        if lower.starts_with("dockerfile.") || lower.starts_with("containerfile.") {
+            return Some(Self::Dockerfile);
+        }
         Self::LANGUAGE_DATA
             .iter()
             .find(|d| d.filenames.contains(&lower.as_str()))
@@ -313,27 +334,106 @@ impl LanguageId {
     }
 
     /// Identify the language by checking the file shebang line.
+    ///
+    /// The interpreter is the program the line runs, or the one `env` runs
+    /// (`#!/usr/bin/env -S deno run`); version suffixes (`python3.12`) are
+    /// ignored.
     #[must_use]
     pub fn from_shebang(first_line: &str) -> Option<Self> {
-        let line = first_line.trim().to_ascii_lowercase();
-        line.starts_with("#!")
-            .then(|| {
-                if line.contains("python") {
-                    Self::Python
-                } else if line.contains("ts-node") {
-                    Self::TypeScript
-                } else if line.contains("node") || line.contains("deno") {
-                    Self::JavaScript
-                } else if ["bash", "sh", "zsh", "ksh"]
-                    .iter()
-                    .any(|shell| line.contains(shell))
-                {
-                    Self::Shell
-                } else {
-                    Self::Ruby
-                }
+        let line = first_line.trim().strip_prefix("#!")?.to_ascii_lowercase();
+        let mut words = line.split_whitespace();
+        let mut program = words.next()?.rsplit('/').next()?;
+        if program == "env" {
+            program = words.find(|w| !w.starts_with('-') && !w.contains('='))?;
+        }
+        match program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.') {
+            "python" | "pypy" => Some(Self::Python),
+            "ts-node" | "tsx" => Some(Self::TypeScript),
+            "node" | "nodejs" | "deno" | "bun" => Some(Self::JavaScript),
+            "ruby" => Some(Self::Ruby),
+            "sh" | "bash" | "zsh" | "ksh" | "dash" | "ash" => Some(Self::Shell),
+            "php" => Some(Self::Php),
+            "make" => Some(Self::Makefile),
+            _ => None,
+        }
+    }
+
+    /// Guess the language of a file from its text alone, for files whose
+    /// name says nothing (no extension, unknown filename).
+    ///
+    /// Checks the shebang, then markers only one language starts with
+    /// (`<?php`, `<!DOCTYPE html>`, a Dockerfile `FROM`), then Makefile
+    /// rules and Starlark `load(...)` calls in the first lines.
+    #[must_use]
+    pub fn from_content(content: &str) -> Option<Self> {
+        const SCANNED_LINES: usize = 50;
+
+        let first_line = content.lines().next()?;
+        if let Some(lang) = Self::from_shebang(first_line) {
+            return Some(lang);
+        }
+        let head = content
+            .trim_start()
+            .chars()
+            .take(16)
+            .collect::<String>()
+            .to_ascii_lowercase();
+        if head.starts_with("<?php") {
+            return Some(Self::Php);
+        }
+        if head.starts_with("<!doctype html") || head.starts_with("<html") {
+            return Some(Self::Html);
+        }
+
+        let lines: Vec<&str> = content.lines().take(SCANNED_LINES).collect();
+        // A Dockerfile's first instruction is `FROM <image> [AS <stage>]`,
+        // optionally after `ARG`s; prose starting with "From" has more words.
+        let first_instruction = lines
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .find(|line| !line.starts_with("ARG "));
+        if first_instruction
+            .is_some_and(|line| line.starts_with("FROM ") && line.split_whitespace().count() <= 5)
+        {
+            return Some(Self::Dockerfile);
+        }
+        let is_rule = |line: &str| {
+            line.split_once(':').is_some_and(|(target, _)| {
+                !target.is_empty()
+                    && target
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || "_-./%".contains(c))
             })
-            .filter(|lang| *lang != Self::Ruby || line.contains("ruby"))
+        };
+        if lines.iter().any(|line| line.starts_with(".PHONY:"))
+            || lines
+                .windows(2)
+                .any(|pair| is_rule(pair[0]) && pair[1].starts_with('\t'))
+        {
+            return Some(Self::Makefile);
+        }
+        if lines
+            .iter()
+            .any(|line| line.starts_with("load(\"") || line.starts_with("workspace(name"))
+        {
+            return Some(Self::Python);
+        }
+        None
+    }
+
+    /// Detect the language of a file its extension does not identify.
+    ///
+    /// Without an extension, `content` is checked first so a `build` shell
+    /// script is not taken for a Bazel file; with one, only well-known names
+    /// (`BUILD.bazel`, `Dockerfile.dev`) are, as any text could follow.
+    #[must_use]
+    pub fn from_filename_or_content(file_name: &str, content: Option<&str>) -> Option<Self> {
+        let has_extension = std::path::Path::new(file_name).extension().is_some();
+        content
+            .filter(|_| !has_extension)
+            .and_then(Self::from_content)
+            .or_else(|| Self::from_filename(file_name))
     }
 }
 
@@ -11,7 +11,7 @@ pub type ValidatorError = Box<dyn std::error::Error + Send + Sync>;
 pub type ValidatorResult<T> = std::result::Result<T, ValidatorError>;
 
 /// Severity level of a code violation.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
 #[serde(rename_all = "UPPERCASE")]
 pub enum Severity {
     /// Blocking issue that must be fixed.
@@ -15,13 +15,21 @@ use crate::ports::admin::dashboard::DashboardQueryPort;
 use crate::ports::infrastructure::migrations::MigrationProvider;
 use crate::ports::repositories::agent::AgentRepository;
 use crate::ports::repositories::auth::AuthRepositoryPort;
+use crate::ports::repositories::collection_manifest::CollectionManifestRepository;
 use crate::ports::repositories::file_hash::FileHashRepository;
+use crate::ports::repositories::file_snapshot::FileSnapshotRepository;
+use crate::ports::repositories::idempotency::IdempotencyRepository;
 use crate::ports::repositories::issue::IssueEntityRepository;
 use crate::ports::repositories::memory::MemoryRepository;
+use crate::ports::repositories::module_import::ModuleImportRepository;
 use crate::ports::repositories::org::OrgEntityRepository;
 use crate::ports::repositories::plan::PlanEntityRepository;
 use crate::ports::repositories::project::ProjectRepository;
+use crate::ports::repositories::search_feedback::SearchFeedbackRepository;
+use crate::ports::repositories::test_link::TestLinkRepository;
+use crate::ports::repositories::usage::UsageRepository;
 use crate::ports::repositories::vcs::VcsEntityRepository;
+use crate::ports::repositories::workspace::WorkspaceRepository;
 
 // ---------------------------------------------------------------------------
 // Database connection provider (factory for opaque DB connections)
@@ -123,6 +131,22 @@ pub struct DatabaseRepositories {
     pub org_entity: Arc<dyn OrgEntityRepository>,
     /// Repository for file hash entities.
     pub file_hash: Arc<dyn FileHashRepository>,
+    /// Content-addressable store of indexed file snapshots.
+    pub file_snapshot: Arc<dyn FileSnapshotRepository>,
+    /// Repository for search relevance feedback.
+    pub search_feedback: Arc<dyn SearchFeedbackRepository>,
+    /// Links between tests and the symbols they exercise.
+    pub test_link: Arc<dyn TestLinkRepository>,
+    /// Import edges of the module dependency graph.
+    pub module_import: Arc<dyn ModuleImportRepository>,
+    /// Build manifests of indexed collections.
+    pub collection_manifest: Arc<dyn CollectionManifestRepository>,
+    /// Workspace membership and files shared between member collections.
+    pub workspace: Arc<dyn WorkspaceRepository>,
+    /// Monthly usage counters per organization and collection.
+    pub usage: Arc<dyn UsageRepository>,
+    /// Results of mutating tool calls by idempotency key.
+    pub idempotency: Arc<dyn IdempotencyRepository>,
 }
 
 /// Registry entry for a database repository provider.
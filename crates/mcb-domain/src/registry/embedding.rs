@@ -10,6 +10,8 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use crate::ports::providers::http::{HttpTransportConfig, RequestSigningConfig, ResiliencePolicy};
+
 /// Configuration for embedding provider creation
 ///
 /// Contains all configuration options that an embedding provider might need.
@@ -28,6 +30,12 @@ pub struct EmbeddingProviderConfig {
     pub dimensions: Option<usize>,
     /// Cache directory for local providers (`FastEmbed`)
     pub cache_dir: Option<PathBuf>,
+    /// Timeout and retry policy for outbound requests (`None` = provider default)
+    pub resilience: Option<ResiliencePolicy>,
+    /// Proxy and additional root CAs for outbound requests
+    pub transport: Option<HttpTransportConfig>,
+    /// Per-request signing for authenticating gateways
+    pub signing: Option<RequestSigningConfig>,
     /// Additional provider-specific configuration
     pub extra: HashMap<String, String>,
 }
@@ -43,6 +51,12 @@ crate::impl_config_builder!(EmbeddingProviderConfig {
     dimensions: with_dimensions(usize),
     /// Set the cache directory
     cache_dir: with_cache_dir(into PathBuf),
+    /// Set the timeout and retry policy
+    resilience: with_resilience(ResiliencePolicy),
+    /// Set the proxy and additional root CAs
+    transport: with_transport(HttpTransportConfig),
+    /// Set the request signing scheme
+    signing: with_signing(RequestSigningConfig),
 });
 
 crate::impl_registry!(
@@ -0,0 +1,311 @@
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../docs/modules/domain.md#di-report)
+//!
+//! DI introspection.
+//!
+//! Collects every `linkme` registration into one report next to the
+//! providers the composition root selected, so the admin API can show which
+//! implementation serves each port and why.
+
+use std::collections::BTreeMap;
+
+use mcb_utils::constants::{
+    REGISTRY_CODE_ANALYZERS, REGISTRY_CONFIG, REGISTRY_DATABASE, REGISTRY_EMBEDDING,
+    REGISTRY_EVENT_BUS, REGISTRY_GRAPHQL_SCHEMA, REGISTRY_HYBRID_SEARCH,
+    REGISTRY_INDEXING_OPERATIONS, REGISTRY_LANGUAGE, REGISTRY_METRICS, REGISTRY_MIGRATION,
+    REGISTRY_PROJECT_DETECTION, REGISTRY_PROJECT_DETECTORS, REGISTRY_SERVICES,
+    REGISTRY_VALIDATION_OPERATIONS, REGISTRY_VALIDATORS, REGISTRY_VCS, REGISTRY_VECTOR_STORE,
+};
+use serde::Serialize;
+
+use super::project_detector::PROJECT_DETECTORS;
+use super::services::SERVICES_REGISTRY;
+
+/// One registration in a registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegisteredProvider {
+    /// Name the registration is resolved by
+    pub name: String,
+    /// Human-readable description; empty when the registry has none
+    pub description: String,
+}
+
+/// A name registered more than once in the same registry.
+///
+/// Resolution takes the first entry in link order, so the others are
+/// unreachable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegistryConflict {
+    /// Registry holding the duplicates
+    pub registry: String,
+    /// The duplicated name
+    pub name: String,
+    /// Number of registrations under that name
+    pub count: usize,
+}
+
+/// Every registration of one registry, in link order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RegistryListing {
+    /// Registry name (one of the `REGISTRY_*` constants in `mcb_utils`)
+    pub registry: String,
+    /// Registered entries
+    pub providers: Vec<RegisteredProvider>,
+}
+
+impl RegistryListing {
+    /// Create a listing from `(name, description)` pairs.
+    #[must_use]
+    pub fn new(registry: impl Into<String>, entries: Vec<(&str, &str)>) -> Self {
+        Self {
+            registry: registry.into(),
+            providers: entries
+                .into_iter()
+                .map(|(name, description)| RegisteredProvider {
+                    name: name.to_owned(),
+                    description: description.to_owned(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Whether `name` is registered.
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.providers.iter().any(|p| p.name == name)
+    }
+
+    /// Names registered more than once.
+    #[must_use]
+    pub fn conflicts(&self) -> Vec<RegistryConflict> {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for provider in &self.providers {
+            *counts.entry(provider.name.as_str()).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .filter(|&(_, count)| count > 1)
+            .map(|(name, count)| RegistryConflict {
+                registry: self.registry.clone(),
+                name: name.to_owned(),
+                count,
+            })
+            .collect()
+    }
+}
+
+/// Where the name of a selected provider came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionSource {
+    /// Set by a configuration key
+    Config,
+    /// Built-in default, not configurable
+    Default,
+    /// Built-in default used because the configuration key is unset
+    DefaultFallback,
+}
+
+/// A provider the composition root resolves from a registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProviderSelection {
+    /// Registry the provider is resolved from
+    pub registry: String,
+    /// Requested provider name
+    pub provider: String,
+    /// Where the name came from
+    pub source: SelectionSource,
+    /// Configuration key that selects the provider, when there is one
+    pub config_key: Option<String>,
+    /// Whether the registry holds a provider of that name; filled in by
+    /// [`DiReport::from_listings`]
+    pub registered: bool,
+}
+
+impl ProviderSelection {
+    /// A provider named by the configuration key `config_key`.
+    #[must_use]
+    pub fn from_config(
+        registry: impl Into<String>,
+        provider: impl Into<String>,
+        config_key: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            registry,
+            provider,
+            SelectionSource::Config,
+            Some(config_key.into()),
+        )
+    }
+
+    /// A provider named by the optional configuration key `config_key`,
+    /// falling back to `default` when it is unset.
+    #[must_use]
+    pub fn from_optional_config(
+        registry: impl Into<String>,
+        configured: Option<&str>,
+        default: &str,
+        config_key: impl Into<String>,
+    ) -> Self {
+        let (provider, source) = match configured {
+            Some(provider) => (provider, SelectionSource::Config),
+            None => (default, SelectionSource::DefaultFallback),
+        };
+        Self::new(registry, provider, source, Some(config_key.into()))
+    }
+
+    /// A provider fixed by a built-in default.
+    #[must_use]
+    pub fn from_default(registry: impl Into<String>, provider: impl Into<String>) -> Self {
+        Self::new(registry, provider, SelectionSource::Default, None)
+    }
+
+    fn new(
+        registry: impl Into<String>,
+        provider: impl Into<String>,
+        source: SelectionSource,
+        config_key: Option<String>,
+    ) -> Self {
+        Self {
+            registry: registry.into(),
+            provider: provider.into(),
+            source,
+            config_key,
+            registered: false,
+        }
+    }
+}
+
+/// Snapshot of the DI registries and the selections made from them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DiReport {
+    /// Every registry with its registrations
+    pub registries: Vec<RegistryListing>,
+    /// Providers the composition root resolves
+    pub selections: Vec<ProviderSelection>,
+    /// Selections naming a provider that is not registered
+    pub unresolved: Vec<ProviderSelection>,
+    /// Names registered more than once within a registry
+    pub conflicts: Vec<RegistryConflict>,
+}
+
+impl DiReport {
+    /// Build a report of the linked registries for `selections`.
+    #[must_use]
+    pub fn new(selections: Vec<ProviderSelection>) -> Self {
+        Self::from_listings(registry_listings(), selections)
+    }
+
+    /// Build a report of `registries` for `selections`.
+    ///
+    /// A selection whose registry is not listed counts as unresolved.
+    #[must_use]
+    pub fn from_listings(
+        registries: Vec<RegistryListing>,
+        mut selections: Vec<ProviderSelection>,
+    ) -> Self {
+        for selection in &mut selections {
+            selection.registered = registries
+                .iter()
+                .find(|listing| listing.registry == selection.registry)
+                .is_some_and(|listing| listing.contains(&selection.provider));
+        }
+        let unresolved = selections
+            .iter()
+            .filter(|selection| !selection.registered)
+            .cloned()
+            .collect();
+        let conflicts = registries
+            .iter()
+            .flat_map(RegistryListing::conflicts)
+            .collect();
+        Self {
+            registries,
+            selections,
+            unresolved,
+            conflicts,
+        }
+    }
+
+    /// Whether every selection resolves and no registry has duplicates.
+    #[must_use]
+    pub fn is_consistent(&self) -> bool {
+        self.unresolved.is_empty() && self.conflicts.is_empty()
+    }
+}
+
+/// List the registrations of every linked registry.
+#[must_use]
+pub fn registry_listings() -> Vec<RegistryListing> {
+    vec![
+        RegistryListing::new(REGISTRY_CONFIG, super::config::list_config_providers()),
+        RegistryListing::new(
+            REGISTRY_EMBEDDING,
+            super::embedding::list_embedding_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_VECTOR_STORE,
+            super::vector_store::list_vector_store_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_DATABASE,
+            super::database::list_database_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_MIGRATION,
+            super::database::list_migration_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_EVENT_BUS,
+            super::events::list_event_bus_providers(),
+        ),
+        RegistryListing::new(REGISTRY_METRICS, super::metrics::list_metrics_providers()),
+        RegistryListing::new(
+            REGISTRY_HYBRID_SEARCH,
+            super::hybrid_search::list_hybrid_search_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_LANGUAGE,
+            super::language::list_language_providers(),
+        ),
+        RegistryListing::new(REGISTRY_VCS, super::vcs::list_vcs_providers()),
+        RegistryListing::new(
+            REGISTRY_GRAPHQL_SCHEMA,
+            super::graphql::list_graphql_schema_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_PROJECT_DETECTION,
+            super::project_detection::list_project_detection_services(),
+        ),
+        RegistryListing::new(
+            REGISTRY_PROJECT_DETECTORS,
+            PROJECT_DETECTORS
+                .iter()
+                .map(|entry| (entry.name, entry.description))
+                .collect(),
+        ),
+        RegistryListing::new(
+            REGISTRY_INDEXING_OPERATIONS,
+            super::admin_operations::list_indexing_operations_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_VALIDATION_OPERATIONS,
+            super::admin_operations::list_validation_operations_providers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_CODE_ANALYZERS,
+            super::code_analysis::list_code_analyzers(),
+        ),
+        RegistryListing::new(
+            REGISTRY_VALIDATORS,
+            super::validation::list_validator_entries(),
+        ),
+        RegistryListing::new(
+            REGISTRY_SERVICES,
+            SERVICES_REGISTRY
+                .iter()
+                .map(|entry| (entry.name, ""))
+                .collect(),
+        ),
+    ]
+}
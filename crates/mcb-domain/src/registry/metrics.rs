@@ -0,0 +1,28 @@
+//! Metrics provider registry.
+//!
+//! Auto-registration for metrics recorders that can report a snapshot of
+//! what they collected (see [`MetricsSnapshotProvider`](crate::ports::MetricsSnapshotProvider)).
+
+use std::collections::HashMap;
+
+/// Configuration for metrics provider resolution.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsProviderConfig {
+    /// Provider name (e.g. `mcb_utils::constants::DEFAULT_METRICS_PROVIDER`).
+    pub provider: String,
+    /// Additional provider-specific configuration.
+    pub extra: HashMap<String, String>,
+}
+
+crate::impl_config_builder!(MetricsProviderConfig {});
+
+crate::impl_registry!(
+    provider_trait: crate::ports::providers::metrics::MetricsSnapshotProvider,
+    config_type: MetricsProviderConfig,
+    entry_type: MetricsProviderEntry,
+    slice_name: METRICS_PROVIDERS,
+    resolve_fn: resolve_metrics_provider,
+    list_fn: list_metrics_providers,
+    register_macro: register_metrics_provider,
+    module: metrics
+);
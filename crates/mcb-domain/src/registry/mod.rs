@@ -79,6 +79,8 @@ pub mod embedding;
 pub mod events;
 /// Language services provider registry.
 pub mod language;
+/// Metrics provider registry.
+pub mod metrics;
 /// Project detection provider registry.
 pub mod project_detection;
 /// Project detector backend providers registry.
@@ -108,6 +110,12 @@ pub use validation::{
 pub mod graphql;
 /// Hybrid search provider registry.
 pub mod hybrid_search;
+/// DI introspection report (registrations, selections, conflicts).
+pub mod introspection;
+pub use introspection::{
+    DiReport, ProviderSelection, RegisteredProvider, RegistryConflict, RegistryListing,
+    SelectionSource, registry_listings,
+};
 /// DI resolution context (opaque DB/config, domain ports).
 pub mod resolution_context;
 /// VCS provider registry.
@@ -11,6 +11,7 @@ use std::sync::Arc;
 use crate::ports::infrastructure::events::EventBusProvider;
 use crate::ports::providers::embedding::EmbeddingProvider;
 use crate::ports::providers::vector_store::VectorStoreProvider;
+use crate::ports::services::job::{BulkheadInterface, JobSchedulerInterface};
 
 /// Context passed to service factory functions during DI resolution.
 ///
@@ -34,4 +35,8 @@ pub struct ServiceResolutionContext {
     pub embedding_provider: Arc<dyn EmbeddingProvider>,
     /// Shared vector store provider resolved once at startup.
     pub vector_store_provider: Arc<dyn VectorStoreProvider>,
+    /// Shared admission control for indexing and search work.
+    pub job_scheduler: Arc<dyn JobSchedulerInterface>,
+    /// Separate bounded pools for search, indexing and admin work.
+    pub bulkheads: Arc<dyn BulkheadInterface>,
 }
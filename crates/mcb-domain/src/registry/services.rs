@@ -4,11 +4,22 @@ use std::sync::Arc;
 use crate::error::{Error, Result};
 use crate::ports::services::agent::AgentSessionServiceInterface;
 use crate::ports::services::browse::HighlightServiceInterface;
+use crate::ports::services::change_summary::ChangeSummaryServiceInterface;
 use crate::ports::services::context::ContextServiceInterface;
+use crate::ports::services::duplicates::DuplicateDetectionServiceInterface;
+use crate::ports::services::evaluation::IndexEvaluationServiceInterface;
+use crate::ports::services::file_content::FileContentServiceInterface;
+use crate::ports::services::grep::GrepServiceInterface;
 use crate::ports::services::indexing::IndexingServiceInterface;
 use crate::ports::services::memory::MemoryServiceInterface;
+use crate::ports::services::module_graph::ModuleGraphServiceInterface;
+use crate::ports::services::repo_analysis::RepoAnalysisServiceInterface;
 use crate::ports::services::search::SearchServiceInterface;
+use crate::ports::services::search_feedback::SearchFeedbackServiceInterface;
+use crate::ports::services::session_context::SessionContextServiceInterface;
+use crate::ports::services::usage::UsageServiceInterface;
 use crate::ports::services::validation_service::ValidationServiceInterface;
+use crate::ports::services::workspace::WorkspaceServiceInterface;
 
 /// Typed factory enum for building domain services from a resolution context.
 #[derive(Clone, Copy)]
@@ -27,6 +38,28 @@ pub enum ServiceBuilder {
     Validation(fn(&dyn Any) -> Result<Arc<dyn ValidationServiceInterface>>),
     /// Build a highlight service.
     Highlight(fn(&dyn Any) -> Result<Arc<dyn HighlightServiceInterface>>),
+    /// Build a session context service.
+    SessionContext(fn(&dyn Any) -> Result<Arc<dyn SessionContextServiceInterface>>),
+    /// Build a search feedback service.
+    SearchFeedback(fn(&dyn Any) -> Result<Arc<dyn SearchFeedbackServiceInterface>>),
+    /// Build a grep service.
+    Grep(fn(&dyn Any) -> Result<Arc<dyn GrepServiceInterface>>),
+    /// Build a file content service.
+    FileContent(fn(&dyn Any) -> Result<Arc<dyn FileContentServiceInterface>>),
+    /// Build a change summary service.
+    ChangeSummary(fn(&dyn Any) -> Result<Arc<dyn ChangeSummaryServiceInterface>>),
+    /// Build a module graph service.
+    ModuleGraph(fn(&dyn Any) -> Result<Arc<dyn ModuleGraphServiceInterface>>),
+    /// Build a duplicate detection service.
+    DuplicateDetection(fn(&dyn Any) -> Result<Arc<dyn DuplicateDetectionServiceInterface>>),
+    /// Build an index evaluation service.
+    IndexEvaluation(fn(&dyn Any) -> Result<Arc<dyn IndexEvaluationServiceInterface>>),
+    /// Build a repository analysis service.
+    RepoAnalysis(fn(&dyn Any) -> Result<Arc<dyn RepoAnalysisServiceInterface>>),
+    /// Build a workspace service.
+    Workspace(fn(&dyn Any) -> Result<Arc<dyn WorkspaceServiceInterface>>),
+    /// Build a usage reporting service.
+    Usage(fn(&dyn Any) -> Result<Arc<dyn UsageServiceInterface>>),
 }
 
 /// Entry in the service registry pairing a name with its builder.
@@ -93,3 +126,69 @@ resolve_service!(
     Highlight,
     dyn HighlightServiceInterface
 );
+resolve_service!(
+    resolve_session_context_service,
+    mcb_utils::constants::SERVICE_NAME_SESSION_CONTEXT,
+    SessionContext,
+    dyn SessionContextServiceInterface
+);
+resolve_service!(
+    resolve_search_feedback_service,
+    mcb_utils::constants::SERVICE_NAME_SEARCH_FEEDBACK,
+    SearchFeedback,
+    dyn SearchFeedbackServiceInterface
+);
+resolve_service!(
+    resolve_grep_service,
+    mcb_utils::constants::SERVICE_NAME_GREP,
+    Grep,
+    dyn GrepServiceInterface
+);
+resolve_service!(
+    resolve_file_content_service,
+    mcb_utils::constants::SERVICE_NAME_FILE_CONTENT,
+    FileContent,
+    dyn FileContentServiceInterface
+);
+resolve_service!(
+    resolve_change_summary_service,
+    mcb_utils::constants::SERVICE_NAME_CHANGE_SUMMARY,
+    ChangeSummary,
+    dyn ChangeSummaryServiceInterface
+);
+resolve_service!(
+    resolve_module_graph_service,
+    mcb_utils::constants::SERVICE_NAME_MODULE_GRAPH,
+    ModuleGraph,
+    dyn ModuleGraphServiceInterface
+);
+resolve_service!(
+    resolve_duplicate_detection_service,
+    mcb_utils::constants::SERVICE_NAME_DUPLICATE_DETECTION,
+    DuplicateDetection,
+    dyn DuplicateDetectionServiceInterface
+);
+resolve_service!(
+    resolve_index_evaluation_service,
+    mcb_utils::constants::SERVICE_NAME_INDEX_EVALUATION,
+    IndexEvaluation,
+    dyn IndexEvaluationServiceInterface
+);
+resolve_service!(
+    resolve_repo_analysis_service,
+    mcb_utils::constants::SERVICE_NAME_REPO_ANALYSIS,
+    RepoAnalysis,
+    dyn RepoAnalysisServiceInterface
+);
+resolve_service!(
+    resolve_workspace_service,
+    mcb_utils::constants::SERVICE_NAME_WORKSPACE,
+    Workspace,
+    dyn WorkspaceServiceInterface
+);
+resolve_service!(
+    resolve_usage_service,
+    mcb_utils::constants::SERVICE_NAME_USAGE,
+    Usage,
+    dyn UsageServiceInterface
+);
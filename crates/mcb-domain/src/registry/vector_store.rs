@@ -9,6 +9,12 @@
 
 use std::collections::HashMap;
 
+use crate::ports::providers::http::{HttpTransportConfig, ResiliencePolicy};
+use crate::ports::providers::vector_store::{
+    InsertBatchPolicy, PersistencePolicy, StorageQuotaPolicy, TombstonePolicy,
+};
+use crate::value_objects::DistanceMetric;
+
 /// Configuration for vector store provider creation
 ///
 /// Contains all configuration options that a vector store provider might need.
@@ -29,6 +35,20 @@ pub struct VectorStoreProviderConfig {
     pub encrypted: Option<bool>,
     /// Encryption key (if encrypted)
     pub encryption_key: Option<String>,
+    /// Timeout and retry policy for outbound requests (`None` = provider default)
+    pub resilience: Option<ResiliencePolicy>,
+    /// Proxy and additional root CAs for outbound requests
+    pub transport: Option<HttpTransportConfig>,
+    /// Bulk insert batching (`None` = provider default)
+    pub insert_batch: Option<InsertBatchPolicy>,
+    /// Retention of deleted and replaced vectors (`None` = provider default)
+    pub tombstones: Option<TombstonePolicy>,
+    /// On-disk snapshot and write-ahead log for embedded stores (`None` = memory only)
+    pub persistence: Option<PersistencePolicy>,
+    /// Storage limits for embedded stores (`None` = unlimited)
+    pub quota: Option<StorageQuotaPolicy>,
+    /// Distance metric new collections are created for (`None` = cosine)
+    pub distance_metric: Option<DistanceMetric>,
     /// Additional provider-specific configuration
     pub extra: HashMap<String, String>,
 }
@@ -42,6 +62,20 @@ crate::impl_config_builder!(VectorStoreProviderConfig {
     dimensions: with_dimensions(usize),
     /// Set the API key
     api_key: with_api_key(into String),
+    /// Set the timeout and retry policy
+    resilience: with_resilience(ResiliencePolicy),
+    /// Set the proxy and additional root CAs
+    transport: with_transport(HttpTransportConfig),
+    /// Set the bulk insert batching policy
+    insert_batch: with_insert_batch(InsertBatchPolicy),
+    /// Set the tombstone retention policy
+    tombstones: with_tombstones(TombstonePolicy),
+    /// Set the on-disk persistence policy
+    persistence: with_persistence(PersistencePolicy),
+    /// Set the storage quota policy
+    quota: with_quota(StorageQuotaPolicy),
+    /// Set the distance metric
+    distance_metric: with_distance_metric(DistanceMetric),
 });
 
 impl VectorStoreProviderConfig {
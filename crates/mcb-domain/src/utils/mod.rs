@@ -9,6 +9,7 @@
 /// Complexity and analysis utilities.
 pub mod analysis;
 /// Configuration helpers — simplified CA/DI access.
+#[cfg(not(target_arch = "wasm32"))]
 pub mod config;
 /// Project type detection helpers.
 pub mod project_type;
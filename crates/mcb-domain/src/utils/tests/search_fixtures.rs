@@ -19,6 +19,8 @@ pub fn create_test_search_result(
         content: content.to_owned(),
         score,
         language: "rust".to_owned(),
+        last_modified: None,
+        explanation: None,
     }
 }
 
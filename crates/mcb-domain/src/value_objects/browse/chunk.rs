@@ -0,0 +1,71 @@
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../../docs/modules/domain.md#value-objects)
+//!
+use std::collections::HashMap;
+
+use mcb_utils::constants::vector_store::VECTOR_FIELD_CONTENT;
+use mcb_utils::utils::vector::l2_norm;
+use serde::{Deserialize, Serialize};
+
+/// Everything a vector store holds for one chunk, for debugging relevance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChunkInspection {
+    /// Record ID of the chunk
+    pub id: String,
+    /// Collection the chunk was read from (an alias resolves to its target)
+    pub collection: String,
+    /// Name of the vector store provider (e.g., "edgevec", "qdrant")
+    pub provider: String,
+    /// Indexed chunk text
+    pub content: String,
+    /// Every stored metadata field, including `content`
+    pub metadata: HashMap<String, serde_json::Value>,
+    /// Length of the stored vector, when the store returned it
+    pub dimensions: Option<usize>,
+    /// L2 norm of the stored vector; 1.0 for cosine collections
+    pub vector_norm: Option<f32>,
+    /// The stored vector itself
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vector: Option<Vec<f32>>,
+    /// Where the store keeps the record: provider-specific keys such as the
+    /// internal vector ID, shard or data directory
+    pub location: HashMap<String, serde_json::Value>,
+}
+
+impl ChunkInspection {
+    /// Create an inspection, taking `content` from the metadata and the
+    /// dimensions and norm from `vector`.
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        collection: impl Into<String>,
+        provider: impl Into<String>,
+        metadata: HashMap<String, serde_json::Value>,
+        vector: Option<Vec<f32>>,
+        location: HashMap<String, serde_json::Value>,
+    ) -> Self {
+        let content = metadata
+            .get(VECTOR_FIELD_CONTENT)
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default()
+            .to_owned();
+        Self {
+            id: id.into(),
+            collection: collection.into(),
+            provider: provider.into(),
+            content,
+            metadata,
+            dimensions: vector.as_ref().map(Vec::len),
+            vector_norm: vector.as_deref().map(l2_norm),
+            vector,
+            location,
+        }
+    }
+
+    /// Drop the raw vector, keeping its dimensions and norm.
+    #[must_use]
+    pub fn without_vector(mut self) -> Self {
+        self.vector = None;
+        self
+    }
+}
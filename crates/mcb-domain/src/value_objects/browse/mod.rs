@@ -3,16 +3,20 @@
 //!
 //! Browse value objects for code navigation and file tree representation.
 
+mod chunk;
 mod collection;
 mod file;
 mod highlight;
 mod node;
+mod report;
 mod tree;
 
+pub use chunk::ChunkInspection;
 pub use collection::CollectionInfo;
 pub use file::FileInfo;
 pub use highlight::{
     HIGHLIGHT_NAMES, HighlightCategory, HighlightSpan, HighlightedCode, map_highlight_to_category,
 };
 pub use node::FileNode;
+pub use report::CollectionReport;
 pub use tree::FileTreeNode;
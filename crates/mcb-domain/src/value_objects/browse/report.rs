@@ -0,0 +1,97 @@
+//!
+//! **Documentation**: [docs/modules/domain.md](../../../../../docs/modules/domain.md#value-objects)
+//!
+use std::collections::{BTreeMap, HashMap};
+
+use mcb_utils::constants::lang::CHUNKER_VERSION;
+use mcb_utils::constants::vector_store::{
+    STATS_FIELD_FILE_COUNT, STATS_FIELD_LANGUAGES, STATS_FIELD_LARGEST_FILES, STATS_FIELD_PROVIDER,
+    STATS_FIELD_VECTORS_COUNT,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::FileInfo;
+use crate::value_objects::DistanceMetric;
+
+/// Content breakdown and build state of one collection, for the admin dashboard
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionReport {
+    /// Collection the report describes
+    pub collection: String,
+    /// Name of the vector store provider, when the store reports it
+    pub provider: Option<String>,
+    /// Total number of vectors in the collection
+    pub vectors_count: u64,
+    /// Number of indexed files
+    pub file_count: u64,
+    /// Chunk count per language
+    pub languages: BTreeMap<String, u64>,
+    /// Files with the most chunks, largest first
+    pub largest_files: Vec<FileInfo>,
+    /// Unix timestamp (seconds) of the last complete index
+    pub last_indexed_at: Option<i64>,
+    /// Chunker version that produced the collection's chunks
+    pub chunker_version: Option<u32>,
+    /// Embedding model that produced the collection's vectors
+    pub embedding_model: Option<String>,
+    /// Distance metric the collection's vectors were stored for
+    pub distance_metric: Option<DistanceMetric>,
+    /// Why the collection should be re-indexed; empty when it is current
+    pub stale_reasons: Vec<String>,
+}
+
+impl CollectionReport {
+    /// Create a report from the `get_stats` map of a vector store, before any
+    /// build is known.
+    #[must_use]
+    pub fn from_stats(collection: impl Into<String>, stats: &HashMap<String, Value>) -> Self {
+        let field = |name: &str| stats.get(name).cloned().unwrap_or_default();
+        Self {
+            collection: collection.into(),
+            provider: stats
+                .get(STATS_FIELD_PROVIDER)
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+            vectors_count: field(STATS_FIELD_VECTORS_COUNT).as_u64().unwrap_or(0),
+            file_count: field(STATS_FIELD_FILE_COUNT).as_u64().unwrap_or(0),
+            languages: serde_json::from_value(field(STATS_FIELD_LANGUAGES)).unwrap_or_default(),
+            largest_files: serde_json::from_value(field(STATS_FIELD_LARGEST_FILES))
+                .unwrap_or_default(),
+            last_indexed_at: None,
+            chunker_version: None,
+            embedding_model: None,
+            distance_metric: None,
+            stale_reasons: vec!["no complete index has been recorded".to_owned()],
+        }
+    }
+
+    /// Add the build recorded for the collection, checking it against the
+    /// current chunker version.
+    #[must_use]
+    pub fn with_build(
+        mut self,
+        chunker_version: u32,
+        embedding_model: Option<String>,
+        distance_metric: DistanceMetric,
+        last_indexed_at: i64,
+    ) -> Self {
+        self.stale_reasons.clear();
+        if chunker_version < CHUNKER_VERSION {
+            self.stale_reasons.push(format!(
+                "indexed by chunker version {chunker_version} (current: {CHUNKER_VERSION})"
+            ));
+        }
+        self.chunker_version = Some(chunker_version);
+        self.embedding_model = embedding_model;
+        self.distance_metric = Some(distance_metric);
+        self.last_indexed_at = Some(last_indexed_at);
+        self
+    }
+
+    /// Whether the collection should be re-indexed.
+    #[must_use]
+    pub fn is_stale(&self) -> bool {
+        !self.stale_reasons.is_empty()
+    }
+}
@@ -6,6 +6,7 @@
 //! Value objects representing semantic embeddings and related
 //! concepts for similarity search and text understanding.
 
+use mcb_utils::utils::vector::l2_norm;
 use serde::{Deserialize, Serialize};
 
 /// Value Object: Semantic Text Embedding
@@ -40,3 +41,43 @@ pub struct Embedding {
     /// Dimensionality of the embedding vector
     pub dimensions: usize,
 }
+
+crate::define_string_enum! {
+    /// Value Object: Distance metric a collection's vectors are compared with
+    ///
+    /// Recorded in the collection manifest so a collection is never searched
+    /// with a different metric than it was built with.
+    #[derive(Copy, Default)]
+    pub enum DistanceMetric [strum = "lowercase", serde = "lowercase", schema] {
+        /// Cosine similarity; vectors are L2-normalized before they are stored
+        /// or searched, so stores may compare them by dot product.
+        #[default]
+        Cosine,
+        /// Raw dot product; vector magnitude is significant.
+        Dot,
+        /// Euclidean (L2) distance.
+        Euclidean,
+    }
+}
+
+impl DistanceMetric {
+    /// Whether vectors are L2-normalized before they are stored or searched.
+    #[must_use]
+    pub const fn normalizes(self) -> bool {
+        matches!(self, Self::Cosine)
+    }
+
+    /// Prepare `vector` for a store using this metric: L2-normalize it when
+    /// the metric expects unit vectors. Zero vectors are left unchanged.
+    pub fn prepare(self, vector: &mut [f32]) {
+        if !self.normalizes() {
+            return;
+        }
+        let norm = l2_norm(vector);
+        if norm > 0.0 {
+            for value in vector.iter_mut() {
+                *value /= norm;
+            }
+        }
+    }
+}
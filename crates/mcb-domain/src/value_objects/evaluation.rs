@@ -0,0 +1,169 @@
+//! Retrieval quality value objects
+//!
+//! A [`LabeledQuery`] names the files and symbols a good search should
+//! return; scoring ranked hits against it yields recall@k, reciprocal rank
+//! and nDCG@k, averaged over a query set by [`EvalReport`].
+
+use serde::{Deserialize, Serialize};
+
+use super::SearchResult;
+
+/// One query and the targets a good search should return for it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LabeledQuery {
+    /// Natural-language or code query
+    pub query: String,
+    /// Workspace-relative paths of files that answer the query
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Identifiers whose definitions or uses answer the query
+    #[serde(default)]
+    pub symbols: Vec<String>,
+}
+
+/// Scores of one query over the top `k` hits
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryScore {
+    /// The evaluated query
+    pub query: String,
+    /// Fraction of expected targets found
+    pub recall: f64,
+    /// `1 / rank` of the first relevant hit, 0 when none was found
+    pub reciprocal_rank: f64,
+    /// Discounted gain of the hits relative to an ideal ranking
+    pub ndcg: f64,
+    /// 1-based rank of the first relevant hit
+    pub first_relevant_rank: Option<usize>,
+}
+
+/// Aggregate result of evaluating a query set
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvalReport {
+    /// Searched collection
+    pub collection: String,
+    /// Rank cutoff
+    pub k: usize,
+    /// Mean recall@k over all queries
+    pub recall: f64,
+    /// Mean reciprocal rank over all queries
+    pub mrr: f64,
+    /// Mean nDCG@k over all queries
+    pub ndcg: f64,
+    /// Per-query scores, in query set order
+    pub queries: Vec<QueryScore>,
+}
+
+impl LabeledQuery {
+    /// Number of expected files and symbols.
+    #[must_use]
+    pub fn target_count(&self) -> usize {
+        self.files.len() + self.symbols.len()
+    }
+
+    /// Score the top `k` of `results` against this query's targets.
+    ///
+    /// A hit is relevant when its file is an expected file or its content
+    /// uses an expected symbol as a whole identifier. For nDCG a hit gains 1
+    /// only if it finds a target no earlier hit found, so many chunks of one
+    /// expected file do not outscore a ranking that surfaces every target.
+    #[must_use]
+    pub fn score(&self, results: &[SearchResult], k: usize) -> QueryScore {
+        let targets = self.target_count();
+        let mut found = vec![false; targets];
+        let mut first_relevant_rank = None;
+        let mut dcg = 0.0;
+        for (i, result) in results.iter().take(k).enumerate() {
+            let rank = i + 1;
+            let mut new_target = false;
+            for target in self.matched_targets(result) {
+                first_relevant_rank.get_or_insert(rank);
+                new_target |= !found[target];
+                found[target] = true;
+            }
+            if new_target {
+                dcg += discount(rank);
+            }
+        }
+        let ideal: f64 = (1..=targets.min(k)).map(discount).sum();
+        let found = found.iter().filter(|f| **f).count();
+        QueryScore {
+            query: self.query.clone(),
+            recall: if targets == 0 {
+                0.0
+            } else {
+                found as f64 / targets as f64
+            },
+            reciprocal_rank: first_relevant_rank.map_or(0.0, |rank| 1.0 / rank as f64),
+            ndcg: if ideal > 0.0 { dcg / ideal } else { 0.0 },
+            first_relevant_rank,
+        }
+    }
+
+    /// Indices of the targets `result` satisfies: files first, then symbols.
+    fn matched_targets(&self, result: &SearchResult) -> Vec<usize> {
+        let files = self
+            .files
+            .iter()
+            .map(|file| path_matches(&result.file_path, file));
+        let symbols = self
+            .symbols
+            .iter()
+            .map(|symbol| contains_identifier(&result.content, symbol));
+        files
+            .chain(symbols)
+            .enumerate()
+            .filter_map(|(i, hit)| hit.then_some(i))
+            .collect()
+    }
+}
+
+impl EvalReport {
+    /// Average per-query scores into a report.
+    #[must_use]
+    pub fn new(collection: String, k: usize, queries: Vec<QueryScore>) -> Self {
+        let mean = |f: fn(&QueryScore) -> f64| {
+            if queries.is_empty() {
+                0.0
+            } else {
+                queries.iter().map(f).sum::<f64>() / queries.len() as f64
+            }
+        };
+        Self {
+            collection,
+            k,
+            recall: mean(|q| q.recall),
+            mrr: mean(|q| q.reciprocal_rank),
+            ndcg: mean(|q| q.ndcg),
+            queries,
+        }
+    }
+}
+
+/// DCG discount for a 1-based rank.
+fn discount(rank: usize) -> f64 {
+    1.0 / ((rank + 1) as f64).log2()
+}
+
+/// Whether `path` is `expected`, ignoring a leading `./` and any prefix
+/// before a path separator (results may carry absolute paths).
+fn path_matches(path: &str, expected: &str) -> bool {
+    let expected = expected.trim_start_matches("./");
+    path == expected
+        || path
+            .strip_suffix(expected)
+            .is_some_and(|prefix| prefix.ends_with('/'))
+}
+
+/// Whether `content` contains `symbol` not embedded in a longer identifier.
+fn contains_identifier(content: &str, symbol: &str) -> bool {
+    if symbol.is_empty() {
+        return false;
+    }
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_';
+    content.match_indices(symbol).any(|(start, _)| {
+        let before = content[..start].chars().next_back();
+        let after = content[start + symbol.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
@@ -12,11 +12,17 @@
 //! | Value Object | Description |
 //! | -------------- | ------------- |
 //! | [`Embedding`] | Vector representation of text for semantic search |
+//! | [`DistanceMetric`] | Metric a collection's vectors are compared with |
 //! | [`SearchResult`] | Ranked result from semantic search operation |
+//! | [`ScoreExplanation`] | Per-stage components of a search result score |
+//! | [`LabeledQuery`] | Query with the files and symbols it should find |
+//! | [`EvalReport`] | Recall@k, MRR and nDCG@k over a labeled query set |
 //! | [`Language`] | Programming language identifier |
 //! | [`OperationType`] | Operation type for metrics and rate limiting |
 //! | [`CollectionInfo`] | Metadata about an indexed collection |
 //! | [`FileInfo`] | Metadata about an indexed file |
+//! | [`CollectionReport`] | Language breakdown, largest files and build state of a collection |
+//! | [`ChunkInspection`] | Stored metadata, vector norm and location of one chunk |
 
 /// Browse-related value objects for code navigation
 pub mod browse;
@@ -24,6 +30,8 @@ pub mod browse;
 pub mod config;
 /// Semantic embedding value objects
 pub mod embedding;
+/// Retrieval quality value objects
+pub mod evaluation;
 pub mod ids;
 /// Organization tenant context
 pub mod org_context;
@@ -38,16 +46,17 @@ pub mod types;
 
 // Re-export commonly used value objects
 pub use browse::{
-    CollectionInfo, FileInfo, FileNode, FileTreeNode, HighlightCategory, HighlightSpan,
-    HighlightedCode,
+    ChunkInspection, CollectionInfo, CollectionReport, FileInfo, FileNode, FileTreeNode,
+    HighlightCategory, HighlightSpan, HighlightedCode,
 };
 pub use config::{CacheConfig, EmbeddingConfig, VectorStoreConfig};
-pub use embedding::Embedding;
+pub use embedding::{DistanceMetric, Embedding};
+pub use evaluation::{EvalReport, LabeledQuery, QueryScore};
 pub use ids::*;
 pub use org_context::OrgContext;
 pub use project_context::ProjectContext;
 pub use project_settings::{
     ProjectEmbeddingConfig, ProjectProvidersSettings, ProjectSettings, ProjectVectorStoreConfig,
 };
-pub use search::SearchResult;
+pub use search::{ScoreExplanation, SearchResult, SearchScope};
 pub use types::{Language, OperationType, SupportedLanguage};
@@ -8,6 +8,7 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::error::{Error, Result};
 use crate::value_objects::Language;
 
 /// Value Object: Ranked Search Result
@@ -34,6 +35,8 @@ use crate::value_objects::Language;
 ///     content: "pub fn authenticate(token: &str) -> Result<User> { ... }".to_string(),
 ///     score: 0.92,
 ///     language: "rust".to_string(),
+///     last_modified: None,
+///     explanation: None,
 /// };
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -50,4 +53,145 @@ pub struct SearchResult {
     pub score: f64,
     /// Programming language of the matched code
     pub language: Language,
+    /// Unix time of the last commit touching the file, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<i64>,
+    /// Per-stage score components, filled only when explanation is requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub explanation: Option<ScoreExplanation>,
+}
+
+/// Value Object: Score Explanation
+///
+/// Components behind a [`SearchResult`] score, recorded by each ranking
+/// stage so weights can be tuned with evidence. Stages run in field order:
+/// `dense` is the vector similarity, the recency multiplier applies to it,
+/// the hybrid stage mixes the result with `bm25`, and the re-rank multiplier
+/// yields the final `score`. Stages that did not touch the result leave
+/// their field `None`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ScoreExplanation {
+    /// Vector similarity returned by the store (title-fused when enabled)
+    pub dense: f64,
+    /// Multiplier applied by the recency boost
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recency_boost: Option<f64>,
+    /// Normalized (0-1) BM25 keyword score
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bm25: Option<f64>,
+    /// Weighted mix of the boosted dense score and `bm25`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hybrid: Option<f64>,
+    /// Multiplier learned from search feedback for the result's file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rerank_boost: Option<f64>,
+}
+
+impl ScoreExplanation {
+    /// Explanation of a result that so far has only its vector similarity.
+    #[must_use]
+    pub fn dense(score: f64) -> Self {
+        Self {
+            dense: score,
+            ..Self::default()
+        }
+    }
+}
+
+/// Value Object: Search Scope
+///
+/// Restricts a search to part of a codebase by path and language, and
+/// excludes paths or languages from it. Vector stores and the BM25 index
+/// apply whatever part of the scope they support natively; callers re-check
+/// results with [`SearchScope::matcher`].
+///
+/// ## Example
+///
+/// ```rust
+/// use mcb_domain::value_objects::SearchScope;
+///
+/// let scope = SearchScope {
+///     path_prefix: Some("crates/mcb-server/".to_string()),
+///     path_glob: Some("**/*.rs".to_string()),
+///     exclude_paths: Some(vec!["crates/mcb-server/tests/".to_string()]),
+///     ..Default::default()
+/// };
+/// let matches = scope.matcher()?;
+/// assert!(matches("crates/mcb-server/src/lib.rs", "rust"));
+/// assert!(!matches("crates/mcb-server/tests/lib.rs", "rust"));
+/// assert!(!matches("crates/mcb-domain/src/lib.rs", "rust"));
+/// # Ok::<(), mcb_domain::error::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchScope {
+    /// Only match files whose workspace-relative path starts with this prefix
+    pub path_prefix: Option<String>,
+    /// Only match files whose workspace-relative path matches this glob
+    pub path_glob: Option<String>,
+    /// Only match chunks in one of these languages
+    pub languages: Option<Vec<String>>,
+    /// Skip files whose path starts with, or matches as a glob, any of these
+    pub exclude_paths: Option<Vec<String>>,
+    /// Skip chunks in any of these languages
+    pub exclude_languages: Option<Vec<String>>,
+}
+
+impl SearchScope {
+    /// Whether the scope places no restriction on results.
+    #[must_use]
+    pub fn is_unrestricted(&self) -> bool {
+        self.path_prefix.is_none()
+            && self.path_glob.is_none()
+            && self.languages.is_none()
+            && self.exclude_paths.is_none()
+            && self.exclude_languages.is_none()
+    }
+
+    /// Build a predicate over `(file_path, language)` for this scope.
+    ///
+    /// In `path_glob` and `exclude_paths`, `*` does not cross `/` while `**`
+    /// spans directories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-argument error if `path_glob` or an `exclude_paths`
+    /// entry is not a valid pattern.
+    pub fn matcher(&self) -> Result<impl Fn(&str, &str) -> bool + '_> {
+        let compile = |field: &str, pattern: &str| {
+            glob::Pattern::new(pattern)
+                .map_err(|e| Error::invalid_argument(format!("Invalid {field} '{pattern}': {e}")))
+        };
+        let glob = self
+            .path_glob
+            .as_deref()
+            .map(|p| compile("path_glob", p))
+            .transpose()?;
+        let excluded = self
+            .exclude_paths
+            .iter()
+            .flatten()
+            .map(|p| Ok((p.trim_start_matches("./"), compile("exclude_paths", p)?)))
+            .collect::<Result<Vec<_>>>()?;
+        let options = glob::MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+        Ok(move |file_path: &str, language: &str| {
+            let path = file_path.trim_start_matches("./");
+            let has_language =
+                |langs: &Vec<String>| langs.iter().any(|l| l.eq_ignore_ascii_case(language));
+            self.path_prefix
+                .as_deref()
+                .is_none_or(|prefix| path.starts_with(prefix.trim_start_matches("./")))
+                && glob
+                    .as_ref()
+                    .is_none_or(|pattern| pattern.matches_with(path, options))
+                && self.languages.as_ref().is_none_or(has_language)
+                && !self.exclude_languages.as_ref().is_some_and(has_language)
+                && !excluded.iter().any(|(prefix, pattern)| {
+                    path.starts_with(prefix) || pattern.matches_with(path, options)
+                })
+        })
+    }
 }
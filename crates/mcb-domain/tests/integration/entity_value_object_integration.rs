@@ -59,6 +59,8 @@ fn test_search_result_from_code_chunk() {
         content: chunk.content.clone(),
         score: 0.95,
         language: chunk.language.clone(),
+        last_modified: None,
+        explanation: None,
     };
 
     // Integration test: SearchResult preserves CodeChunk information
@@ -148,6 +150,8 @@ fn test_search_result_ranking() {
             content: "fn exact_match_function() {}".to_owned(),
             score: 1.0,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         },
         SearchResult {
             id: "high-match".to_owned(),
@@ -156,6 +160,8 @@ fn test_search_result_ranking() {
             content: "fn similar_function() {}".to_owned(),
             score: 0.85,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         },
         SearchResult {
             id: "medium-match".to_owned(),
@@ -164,6 +170,8 @@ fn test_search_result_ranking() {
             content: "fn somewhat_related() {}".to_owned(),
             score: 0.65,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         },
         SearchResult {
             id: "low-match".to_owned(),
@@ -172,6 +180,8 @@ fn test_search_result_ranking() {
             content: "fn barely_related() {}".to_owned(),
             score: 0.25,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         },
     ];
 
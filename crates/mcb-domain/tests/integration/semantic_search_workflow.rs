@@ -164,6 +164,8 @@ fn perform_semantic_search(
             // Scale score to always be in [0.0, 1.0] range
             score: 1.0 - (i as f64 / len.max(1.0)),
             language: chunk.language.clone(),
+            last_modified: None,
+            explanation: None,
         })
         .collect()
 }
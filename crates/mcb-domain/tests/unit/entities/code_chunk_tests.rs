@@ -45,3 +45,49 @@ fn test_code_chunk_variants(#[case] id: &str, #[case] content: &str, mut default
     assert_eq!(default_chunk.id, id);
     assert_eq!(default_chunk.content, content);
 }
+
+#[rstest]
+fn test_stable_id_is_deterministic_and_ignores_line_numbers(default_chunk: CodeChunk) {
+    let mut moved = default_chunk.clone();
+    moved.id = "other-chunk".to_owned();
+    moved.start_line = 40;
+    moved.end_line = 42;
+
+    assert_eq!(default_chunk.stable_id(), default_chunk.stable_id());
+    assert_eq!(default_chunk.stable_id(), moved.stable_id());
+    assert!(uuid::Uuid::parse_str(&default_chunk.stable_id()).is_ok());
+}
+
+#[rstest]
+#[case::content(|c: &mut CodeChunk| c.content = "fn goodbye() {}".to_owned())]
+#[case::path(|c: &mut CodeChunk| c.file_path = "src/lib.rs".to_owned())]
+#[case::symbol(|c: &mut CodeChunk| c.metadata = json!({"name": "hello"}))]
+fn test_stable_id_changes_with_path_symbol_and_content(
+    default_chunk: CodeChunk,
+    #[case] change: fn(&mut CodeChunk),
+) {
+    let mut changed = default_chunk.clone();
+    change(&mut changed);
+    assert_ne!(default_chunk.stable_id(), changed.stable_id());
+}
+
+#[rstest]
+fn test_stable_id_normalizes_path_separators(mut default_chunk: CodeChunk) {
+    let unix = default_chunk.stable_id();
+    default_chunk.file_path = "src\\main.rs".to_owned();
+    assert_eq!(default_chunk.stable_id(), unix);
+}
+
+#[rstest]
+#[case::symbol_name(json!({"symbol_name": "hello", "name": "other"}), Some("hello\nfn hello() {}"))]
+#[case::name_fallback(json!({"name": "hello"}), Some("hello\nfn hello() {}"))]
+#[case::no_symbol(json!({"node_type": "function_item"}), None)]
+fn test_title_pairs_symbol_with_signature(
+    mut default_chunk: CodeChunk,
+    #[case] metadata: serde_json::Value,
+    #[case] expected: Option<&str>,
+) {
+    default_chunk.content = "\n  fn hello() {}\n  body\n".to_owned();
+    default_chunk.metadata = metadata;
+    assert_eq!(default_chunk.title().as_deref(), expected);
+}
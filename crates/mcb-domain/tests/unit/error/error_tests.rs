@@ -1,6 +1,7 @@
 //! Unit tests for domain error types
 
 use mcb_domain::Error;
+use mcb_domain::error::ErrorCode;
 use rstest::rstest;
 
 #[rstest]
@@ -59,3 +60,39 @@ fn test_error_equality_discrimination() {
     assert!(matches!(invalid_arg, Error::InvalidArgument { .. }));
     assert!(!matches!(not_found, Error::InvalidArgument { .. }));
 }
+
+#[rstest]
+#[case::rate_limited(Error::embedding("HTTP 429 Too Many Requests"), "MCB-EMB-429", true)]
+#[case::embedding(Error::embedding("no model"), "MCB-EMB", false)]
+#[case::dimension(
+    Error::vector_db("dimension mismatch: expected 384, got 768"),
+    "MCB-VS-DIM-MISMATCH",
+    false
+)]
+#[case::quota(
+    Error::vector_db("Storage quota exceeded: 90 + 20 bytes is over the 100 byte limit"),
+    "MCB-VS-QUOTA",
+    false
+)]
+#[case::circuit_open(
+    Error::vector_db("vector store 'milvus' is unavailable (circuit open), retry in 30s"),
+    "MCB-VS-UNAVAILABLE",
+    true
+)]
+#[case::vector_db(Error::vector_db("conn failed"), "MCB-VS", true)]
+#[case::not_found(Error::not_found("user"), "MCB-NOT-FOUND", false)]
+#[case::config_missing(Error::ConfigMissing("api_key".to_owned()), "MCB-CONFIG", false)]
+fn error_codes_are_stable(#[case] error: Error, #[case] code: &str, #[case] retryable: bool) {
+    assert_eq!(error.code().as_str(), code);
+    assert_eq!(error.is_retryable(), retryable);
+}
+
+#[rstest]
+fn error_code_registry_is_unique_and_parseable() {
+    let mut seen = std::collections::HashSet::new();
+    for code in ErrorCode::ALL {
+        assert!(code.as_str().starts_with("MCB-"), "{code}");
+        assert!(seen.insert(code.as_str()), "duplicate code {code}");
+        assert_eq!(ErrorCode::parse(code.as_str()), Some(*code));
+    }
+}
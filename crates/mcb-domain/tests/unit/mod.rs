@@ -7,6 +7,7 @@ pub mod entities;
 pub mod error;
 pub mod events;
 pub mod ports;
+pub mod registry;
 pub mod repositories;
 pub mod utils;
 pub mod value_objects;
@@ -0,0 +1,41 @@
+//! Unit tests for the dry-run indexing estimate.
+
+use mcb_domain::ports::IndexingEstimate;
+use rstest::rstest;
+
+fn sample() -> IndexingEstimate {
+    let mut estimate = IndexingEstimate::default();
+    estimate.record_chunk("rust", 400);
+    estimate.record_chunk("rust", 3);
+    estimate.record_chunk("python", 1_000);
+    estimate
+}
+
+#[rstest]
+fn record_chunk_counts_chunks_bytes_and_tokens() {
+    let estimate = sample();
+    assert_eq!(estimate.chunks, 3);
+    assert_eq!(estimate.chunks_by_language["rust"], 2);
+    assert_eq!(estimate.chunks_by_language["python"], 1);
+    assert_eq!(estimate.content_bytes, 1_403);
+    // Partial tokens round up per chunk: 100 + 1 + 250.
+    assert_eq!(estimate.estimated_tokens, 351);
+}
+
+#[rstest]
+fn projections_price_tokens_and_size_vectors_cheapest_first() {
+    let estimate = sample().with_projections(&[
+        ("hosted", "large", 1_024, 1_000_000.0),
+        ("local", "small", 384, 0.0),
+    ]);
+
+    let [local, hosted] = estimate.projections.as_slice() else {
+        panic!("expected two projections, got {:?}", estimate.projections);
+    };
+    assert_eq!(local.provider, "local");
+    assert_eq!(local.cost_usd, 0.0);
+    assert_eq!(local.storage_bytes, 3 * 384 * 4 + 1_403);
+    assert_eq!(hosted.model, "large");
+    assert!((hosted.cost_usd - 351.0).abs() < f64::EPSILON);
+    assert_eq!(hosted.storage_bytes, 3 * 1_024 * 4 + 1_403);
+}
@@ -1,6 +1,8 @@
 //! Unit tests for jobs domain ports.
 
-use mcb_domain::ports::{Job, JobCounts, JobStatus, JobType};
+use mcb_domain::ports::{
+    BulkheadPolicy, Job, JobCounts, JobSchedulerPolicy, JobStatus, JobType, Workload,
+};
 use mcb_domain::value_objects::OperationId;
 use mcb_utils::utils::id;
 use rstest::rstest;
@@ -54,3 +56,53 @@ fn job_counts_default() {
     assert_eq!(counts.failed, 0);
     assert_eq!(counts.cancelled, 0);
 }
+
+#[rstest]
+#[case::no_slots(0, 0, "max_concurrent")]
+#[case::all_reserved(2, 2, "reserved_interactive")]
+fn invalid_scheduler_policy_is_rejected(
+    #[case] max_concurrent: usize,
+    #[case] reserved_interactive: usize,
+    #[case] field: &str,
+) {
+    let policy = JobSchedulerPolicy {
+        max_concurrent,
+        reserved_interactive,
+    };
+    let err = policy.validate("system.infrastructure.jobs").unwrap_err();
+    assert!(err.to_string().contains(field), "{err}");
+}
+
+#[rstest]
+fn default_scheduler_policy_leaves_background_slots() {
+    let policy = JobSchedulerPolicy::default();
+    assert!(policy.validate("system.infrastructure.jobs").is_ok());
+    assert!(policy.background_slots() > 0);
+}
+
+#[rstest]
+#[case::search(Workload::Search, "bulkheads.search")]
+#[case::indexing(Workload::Indexing, "bulkheads.indexing")]
+#[case::admin(Workload::Admin, "bulkheads.admin")]
+fn bulkhead_policy_rejects_empty_pool(#[case] workload: Workload, #[case] field: &str) {
+    let mut policy = BulkheadPolicy::default();
+    match workload {
+        Workload::Search => policy.search = 0,
+        Workload::Indexing => policy.indexing = 0,
+        Workload::Admin => policy.admin = 0,
+    }
+    assert_eq!(policy.limit(workload), 0);
+    let err = policy
+        .validate("system.infrastructure.bulkheads")
+        .unwrap_err();
+    assert!(err.to_string().contains(field), "{err}");
+}
+
+#[rstest]
+fn default_bulkhead_policy_is_valid() {
+    assert!(
+        BulkheadPolicy::default()
+            .validate("system.infrastructure.bulkheads")
+            .is_ok()
+    );
+}
@@ -1,5 +1,8 @@
 //! Unit tests.
 
+mod indexing_estimate_tests;
 mod jobs_port_tests;
 mod providers;
+mod repo_analysis_tests;
+mod usage_tests;
 mod validation_tests;
@@ -0,0 +1,122 @@
+//! Unit tests for the provider resilience policy, HTTP transport and signing settings.
+
+use std::time::Duration;
+
+use mcb_domain::ports::{
+    HttpTransportConfig, RequestSigningConfig, ResiliencePolicy, SigV4Config, SignableRequest,
+};
+use rstest::rstest;
+
+#[rstest]
+fn partial_config_keeps_defaults() {
+    let policy: ResiliencePolicy =
+        serde_json::from_value(serde_json::json!({ "max_retries": 5, "jitter": 0.5 }))
+            .expect("deserialize policy");
+
+    let defaults = ResiliencePolicy::default();
+    assert_eq!(policy.max_retries, 5);
+    assert_eq!(policy.max_attempts(), 6);
+    assert!((policy.jitter - 0.5).abs() < f64::EPSILON);
+    assert_eq!(policy.request_timeout(), defaults.request_timeout());
+    assert_eq!(policy.connect_timeout(), defaults.connect_timeout());
+}
+
+#[rstest]
+fn unknown_fields_are_rejected() {
+    let result: Result<ResiliencePolicy, _> =
+        serde_json::from_value(serde_json::json!({ "retries": 5 }));
+    assert!(result.is_err());
+}
+
+#[rstest]
+#[case::zero_timeout(ResiliencePolicy { request_timeout_ms: 0, ..ResiliencePolicy::default() })]
+#[case::cap_below_backoff(ResiliencePolicy {
+    backoff_ms: 1_000,
+    max_backoff_ms: 10,
+    ..ResiliencePolicy::default()
+})]
+#[case::jitter_out_of_range(ResiliencePolicy { jitter: 1.5, ..ResiliencePolicy::default() })]
+fn invalid_policies_are_rejected(#[case] policy: ResiliencePolicy) {
+    let err = policy
+        .validate("providers.embedding.resilience")
+        .unwrap_err();
+    assert!(err.to_string().contains("providers.embedding.resilience"));
+}
+
+#[rstest]
+fn default_policy_is_valid() {
+    let policy = ResiliencePolicy::default();
+    policy.validate("resilience").expect("default policy");
+    assert_eq!(policy.backoff(), Duration::from_millis(policy.backoff_ms));
+}
+
+#[rstest]
+fn transport_rejects_missing_ca_bundle() {
+    let transport = HttpTransportConfig {
+        ca_bundles: vec!["/nonexistent/corp-root.pem".into()],
+        ..HttpTransportConfig::default()
+    };
+    let err = transport.validate("providers.http").unwrap_err();
+    assert!(err.to_string().contains("providers.http.ca_bundles"));
+}
+
+#[rstest]
+fn transport_rejects_blank_proxy() {
+    let transport = HttpTransportConfig {
+        proxy: Some("  ".to_owned()),
+        ..HttpTransportConfig::default()
+    };
+    assert!(transport.validate("providers.http").is_err());
+    HttpTransportConfig::default()
+        .validate("providers.http")
+        .expect("default transport");
+}
+
+#[rstest]
+fn signing_config_is_tagged_by_scheme() {
+    let signing: RequestSigningConfig = serde_json::from_value(serde_json::json!({
+        "scheme": "sigv4",
+        "region": "eu-west-1",
+        "service": "execute-api",
+    }))
+    .expect("deserialize signing");
+
+    assert_eq!(signing.scheme(), "sigv4");
+    let RequestSigningConfig::Sigv4(sigv4) = &signing;
+    assert_eq!(sigv4.region, "eu-west-1");
+    assert!(sigv4.access_key_id.is_none());
+    signing
+        .validate("providers.embedding.signing")
+        .expect("valid signing");
+}
+
+#[rstest]
+#[case::blank_region(SigV4Config { service: "execute-api".to_owned(), ..SigV4Config::default() })]
+#[case::half_key_pair(SigV4Config {
+    region: "eu-west-1".to_owned(),
+    service: "execute-api".to_owned(),
+    access_key_id: Some("AKID".to_owned()),
+    ..SigV4Config::default()
+})]
+fn invalid_signing_configs_are_rejected(#[case] sigv4: SigV4Config) {
+    let err = RequestSigningConfig::Sigv4(sigv4)
+        .validate("providers.embedding.signing")
+        .unwrap_err();
+    assert!(err.to_string().contains("providers.embedding.signing"));
+}
+
+#[rstest]
+fn signable_request_headers_are_case_insensitive() {
+    let mut request = SignableRequest {
+        headers: vec![("Authorization".to_owned(), "Bearer key".to_owned())],
+        ..SignableRequest::default()
+    };
+    request.set_header("authorization", "AWS4-HMAC-SHA256 ...");
+
+    assert_eq!(request.headers.len(), 1);
+    assert_eq!(
+        request.header("AUTHORIZATION"),
+        Some("AWS4-HMAC-SHA256 ...")
+    );
+    assert_eq!(request.header("x-amz-date"), None);
+}
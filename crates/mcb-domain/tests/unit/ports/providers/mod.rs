@@ -1,3 +1,5 @@
 //! Unit tests.
 
+mod http_tests;
 mod metrics_tests;
+mod vector_store_tests;
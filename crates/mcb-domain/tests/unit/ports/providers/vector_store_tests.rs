@@ -0,0 +1,54 @@
+//! Unit tests for vector store capabilities.
+
+use mcb_domain::ports::{VectorStoreCapabilities, VectorStoreFeature};
+use rstest::rstest;
+
+#[rstest]
+#[case(VectorStoreFeature::MetadataFilters)]
+#[case(VectorStoreFeature::AtomicUpsert)]
+#[case(VectorStoreFeature::Aliases)]
+#[case(VectorStoreFeature::PointInTimeSearch)]
+#[case(VectorStoreFeature::ChunkInspection)]
+#[case(VectorStoreFeature::StorageQuota)]
+fn default_capabilities_support_nothing(#[case] feature: VectorStoreFeature) {
+    assert!(!VectorStoreCapabilities::default().supports(feature));
+}
+
+#[rstest]
+fn require_passes_for_supported_feature() {
+    let capabilities = VectorStoreCapabilities {
+        aliases: true,
+        ..VectorStoreCapabilities::default()
+    };
+
+    assert!(
+        capabilities
+            .require("milvus", VectorStoreFeature::Aliases)
+            .is_ok()
+    );
+}
+
+#[rstest]
+fn require_names_provider_and_missing_feature() {
+    let err = VectorStoreCapabilities::default()
+        .require("pinecone", VectorStoreFeature::ChunkInspection)
+        .expect_err("chunk inspection is unsupported");
+
+    assert!(
+        err.to_string()
+            .contains("pinecone does not support chunk inspection"),
+        "{err}"
+    );
+}
+
+#[rstest]
+fn capabilities_serialize_by_field_name() {
+    let json = serde_json::to_value(VectorStoreCapabilities {
+        point_in_time_search: true,
+        ..VectorStoreCapabilities::default()
+    })
+    .expect("serialization should succeed");
+
+    assert_eq!(json["point_in_time_search"], true);
+    assert_eq!(json["metadata_filters"], false);
+}
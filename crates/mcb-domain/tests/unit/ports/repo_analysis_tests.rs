@@ -0,0 +1,80 @@
+//! Unit tests for the repository onboarding recommendation.
+
+use mcb_domain::ports::{IndexingEstimate, RepoChurn, RepoProfile, RepoRecommendation};
+use mcb_utils::constants::embedding::EMBEDDING_PRICE_TABLE;
+use mcb_utils::constants::use_cases::{ONBOARDING_HOSTED_EMBEDDING, ONBOARDING_LOCAL_EMBEDDING};
+use rstest::rstest;
+
+fn profile(chunks: usize, files: usize, changed: Option<usize>) -> RepoProfile {
+    let mut size = IndexingEstimate::default();
+    for _ in 0..files {
+        size.record_file("rs");
+    }
+    size.record_file("py");
+    size.chunks = chunks;
+    size.estimated_tokens = chunks * 200;
+    RepoProfile {
+        size,
+        churn: changed.map(|files_changed| RepoChurn {
+            window_days: 30,
+            commits: 12,
+            files_changed,
+        }),
+    }
+}
+
+#[rstest]
+#[case(1_000, None, "fastembed", "edgevec", true)]
+#[case(150_000, None, "openai", "edgevec", false)]
+#[case(500_000, None, "openai", "qdrant", false)]
+#[case(3_000_000, Some(1), "openai", "milvus", false)]
+#[case(3_000_000, Some(90), "openai", "qdrant", false)]
+fn recommendation_scales_with_size_and_churn(
+    #[case] chunks: usize,
+    #[case] changed: Option<usize>,
+    #[case] provider: &str,
+    #[case] store: &str,
+    #[case] title_vectors: bool,
+) {
+    let recommendation = RepoRecommendation::for_profile(&profile(chunks, 99, changed));
+
+    assert_eq!(recommendation.embedding.provider, provider);
+    assert_eq!(recommendation.vector_store, store);
+    assert_eq!(recommendation.title_vectors, title_vectors);
+    assert!(
+        recommendation
+            .config_snippet
+            .contains(&format!("provider: {store}"))
+    );
+}
+
+#[rstest]
+fn recommendation_orders_extensions_and_renders_overlay() {
+    let recommendation = RepoRecommendation::for_profile(&profile(500_000, 3, Some(1)));
+
+    assert_eq!(recommendation.supported_extensions, vec!["rs", "py"]);
+    assert!(recommendation.estimated_index_secs > 0);
+    assert!(
+        recommendation
+            .rationale
+            .iter()
+            .any(|line| line.contains("30 days"))
+    );
+    let snippet = &recommendation.config_snippet;
+    assert!(snippet.starts_with("settings:\n"));
+    assert!(snippet.contains("api_key: \"${OPENAI_API_KEY}\""));
+    assert!(snippet.contains("address: \"http://localhost:"));
+    assert!(snippet.contains("supported_extensions: [rs, py]"));
+    assert!(snippet.contains("title_vectors: false"));
+}
+
+#[rstest]
+#[case(ONBOARDING_LOCAL_EMBEDDING)]
+#[case(ONBOARDING_HOSTED_EMBEDDING)]
+fn recommended_models_are_priced(#[case] (provider, model): (&str, &str)) {
+    assert!(
+        EMBEDDING_PRICE_TABLE
+            .iter()
+            .any(|&(p, m, dims, _)| p == provider && m == model && dims > 0)
+    );
+}
@@ -0,0 +1,74 @@
+//! Unit tests for usage report totals and the billing CSV export.
+
+use mcb_domain::entities::{UsageFilter, UsageRecord};
+use mcb_domain::ports::UsageReport;
+
+fn record(org_id: &str, collection: &str, month: &str, queries: u64) -> UsageRecord {
+    UsageRecord {
+        org_id: org_id.to_owned(),
+        collection: collection.to_owned(),
+        month: month.to_owned(),
+        indexed_bytes: 4_000,
+        embedding_tokens: 1_000,
+        queries,
+    }
+}
+
+#[test]
+fn report_totals_usage_per_org_and_month() {
+    let report = UsageReport::from_records(
+        UsageFilter::default(),
+        vec![
+            record("acme", "api", "2026-09", 3),
+            record("acme", "web", "2026-09", 4),
+            record("acme", "api", "2026-10", 1),
+            record("globex", "api", "2026-09", 2),
+        ],
+    );
+
+    let totals: Vec<(&str, &str, usize, u64, u64)> = report
+        .orgs
+        .iter()
+        .map(|o| {
+            (
+                o.org_id.as_str(),
+                o.month.as_str(),
+                o.collections,
+                o.indexed_bytes,
+                o.queries,
+            )
+        })
+        .collect();
+    assert_eq!(
+        totals,
+        [
+            ("acme", "2026-09", 2, 8_000, 7),
+            ("acme", "2026-10", 1, 4_000, 1),
+            ("globex", "2026-09", 1, 4_000, 2),
+        ]
+    );
+    assert_eq!(report.collections.len(), 4);
+}
+
+#[test]
+fn csv_export_has_header_and_quotes_fields() {
+    let report = UsageReport::from_records(
+        UsageFilter::default(),
+        vec![record("acme", "api,\"v2\"", "2026-09", 3)],
+    );
+
+    assert_eq!(
+        report.to_csv(),
+        "org_id,month,collection,indexed_bytes,embedding_tokens,queries\n\
+         acme,2026-09,\"api,\"\"v2\"\"\",4000,1000,3\n"
+    );
+}
+
+#[test]
+fn current_record_uses_year_and_month() {
+    let record = UsageRecord::current("acme", "api");
+
+    assert_eq!(record.month.len(), 7);
+    assert_eq!(record.month.as_bytes()[4], b'-');
+    assert_eq!(record.queries, 0);
+}
@@ -0,0 +1,99 @@
+//! Unit tests for the DI introspection report.
+
+use mcb_domain::registry::{
+    DiReport, ProviderSelection, RegistryConflict, RegistryListing, SelectionSource,
+};
+use rstest::rstest;
+
+fn listings() -> Vec<RegistryListing> {
+    vec![
+        RegistryListing::new(
+            "embedding",
+            vec![("null", "No-op"), ("ollama", "Ollama"), ("ollama", "Fork")],
+        ),
+        RegistryListing::new("vector_store", vec![("edgevec", "EdgeVec")]),
+    ]
+}
+
+#[rstest]
+fn report_marks_registered_selections() {
+    let report = DiReport::from_listings(
+        listings(),
+        vec![ProviderSelection::from_config(
+            "vector_store",
+            "edgevec",
+            "providers.vector_store.provider",
+        )],
+    );
+
+    assert!(report.selections[0].registered);
+    assert!(report.unresolved.is_empty());
+}
+
+#[rstest]
+#[case("vector_store", "milvus")]
+#[case("metrics", "in_memory")]
+fn report_lists_unresolved_selections(#[case] registry: &str, #[case] provider: &str) {
+    let report = DiReport::from_listings(
+        listings(),
+        vec![ProviderSelection::from_default(registry, provider)],
+    );
+
+    assert_eq!(report.unresolved.len(), 1);
+    assert_eq!(report.unresolved[0].provider, provider);
+    assert!(!report.is_consistent());
+}
+
+#[rstest]
+fn report_lists_duplicate_registrations() {
+    let report = DiReport::from_listings(listings(), Vec::new());
+
+    assert_eq!(
+        report.conflicts,
+        vec![RegistryConflict {
+            registry: "embedding".to_owned(),
+            name: "ollama".to_owned(),
+            count: 2,
+        }]
+    );
+}
+
+#[rstest]
+#[case(Some("ollama"), "ollama", SelectionSource::Config)]
+#[case(None, "null", SelectionSource::DefaultFallback)]
+fn optional_config_falls_back_to_default(
+    #[case] configured: Option<&str>,
+    #[case] expected: &str,
+    #[case] source: SelectionSource,
+) {
+    let selection = ProviderSelection::from_optional_config(
+        "embedding",
+        configured,
+        "null",
+        "providers.embedding.provider",
+    );
+
+    assert_eq!(selection.provider, expected);
+    assert_eq!(selection.source, source);
+    assert_eq!(
+        selection.config_key.as_deref(),
+        Some("providers.embedding.provider")
+    );
+}
+
+#[rstest]
+fn report_serializes_source_in_snake_case() {
+    let report = DiReport::from_listings(
+        listings(),
+        vec![ProviderSelection::from_optional_config(
+            "embedding",
+            None,
+            "null",
+            "providers.embedding.provider",
+        )],
+    );
+
+    let json = serde_json::to_value(&report).expect("serialize report");
+    assert_eq!(json["selections"][0]["source"], "default_fallback");
+    assert_eq!(json["selections"][0]["registered"], true);
+}
@@ -1,6 +1,10 @@
 //! Unit tests for browse value objects.
 
-use mcb_domain::value_objects::{CollectionId, CollectionInfo, FileInfo};
+use std::collections::HashMap;
+
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, CollectionReport, DistanceMetric, FileInfo,
+};
 use rstest::rstest;
 
 #[rstest]
@@ -51,6 +55,65 @@ fn test_file_info_serialization() {
     assert_eq!(info, deserialized);
 }
 
+#[rstest]
+fn test_chunk_inspection_derives_content_and_norm() {
+    let metadata = HashMap::from([("content".to_owned(), serde_json::json!("fn main() {}"))]);
+    let chunk = ChunkInspection::new(
+        "c1",
+        "repo",
+        "edgevec",
+        metadata,
+        Some(vec![3.0, 4.0]),
+        HashMap::new(),
+    );
+
+    assert_eq!(chunk.content, "fn main() {}");
+    assert_eq!(chunk.dimensions, Some(2));
+    assert_eq!(chunk.vector_norm, Some(5.0));
+
+    let json = serde_json::to_value(chunk.without_vector()).expect("serialization should succeed");
+    assert!(json.get("vector").is_none());
+    assert_eq!(json["vector_norm"], 5.0);
+}
+
+#[rstest]
+#[case(0, 1)]
+#[case(mcb_utils::constants::lang::CHUNKER_VERSION, 0)]
+fn test_collection_report_reads_stats_and_flags_old_chunker(
+    #[case] chunker_version: u32,
+    #[case] stale_reasons: usize,
+) {
+    let stats = HashMap::from([
+        ("provider".to_owned(), serde_json::json!("qdrant")),
+        ("vectors_count".to_owned(), serde_json::json!(7)),
+        ("file_count".to_owned(), serde_json::json!(2)),
+        (
+            "languages".to_owned(),
+            serde_json::json!({"rust": 5, "python": 2}),
+        ),
+        (
+            "largest_files".to_owned(),
+            serde_json::json!([FileInfo::new("src/lib.rs", 5, "rust", None)]),
+        ),
+    ]);
+
+    let report = CollectionReport::from_stats("repo", &stats);
+    assert_eq!(report.provider.as_deref(), Some("qdrant"));
+    assert_eq!(report.vectors_count, 7);
+    assert_eq!(report.languages["rust"], 5);
+    assert_eq!(report.largest_files[0].path, "src/lib.rs");
+    assert!(report.is_stale());
+
+    let report = report.with_build(
+        chunker_version,
+        Some("fastembed".to_owned()),
+        DistanceMetric::Cosine,
+        1_705_680_000,
+    );
+    assert_eq!(report.last_indexed_at, Some(1_705_680_000));
+    assert_eq!(report.stale_reasons.len(), stale_reasons);
+}
+
 // =============================================================================
 // map_highlight_to_category tests
 // =============================================================================
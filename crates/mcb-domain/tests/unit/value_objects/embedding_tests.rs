@@ -1,6 +1,6 @@
 //! Unit tests for Embedding value object
 
-use mcb_domain::value_objects::Embedding;
+use mcb_domain::value_objects::{DistanceMetric, Embedding};
 use rstest::rstest;
 
 #[rstest]
@@ -30,3 +30,35 @@ fn test_embedding_creation_and_properties(
         assert_eq!(embedding.dimensions, 0);
     }
 }
+
+#[rstest]
+#[case(DistanceMetric::Cosine, vec![3.0, 4.0], vec![0.6, 0.8])]
+#[case(DistanceMetric::Cosine, vec![0.0, 0.0], vec![0.0, 0.0])]
+#[case(DistanceMetric::Dot, vec![3.0, 4.0], vec![3.0, 4.0])]
+#[case(DistanceMetric::Euclidean, vec![3.0, 4.0], vec![3.0, 4.0])]
+fn test_distance_metric_prepare(
+    #[case] metric: DistanceMetric,
+    #[case] mut vector: Vec<f32>,
+    #[case] expected: Vec<f32>,
+) {
+    metric.prepare(&mut vector);
+    for (actual, expected) in vector.iter().zip(&expected) {
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "{vector:?} != {expected:?}"
+        );
+    }
+}
+
+#[rstest]
+#[case("cosine", DistanceMetric::Cosine)]
+#[case("Dot", DistanceMetric::Dot)]
+#[case("euclidean", DistanceMetric::Euclidean)]
+fn test_distance_metric_round_trips_through_strings(
+    #[case] text: &str,
+    #[case] metric: DistanceMetric,
+) {
+    assert_eq!(text.parse::<DistanceMetric>().ok(), Some(metric));
+    assert_eq!(metric.as_str(), text.to_lowercase());
+    assert_eq!(DistanceMetric::default(), DistanceMetric::Cosine);
+}
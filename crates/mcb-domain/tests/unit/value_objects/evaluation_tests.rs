@@ -0,0 +1,103 @@
+//! Unit tests for the `LabeledQuery` and `EvalReport` retrieval metrics
+
+use mcb_domain::value_objects::{EvalReport, LabeledQuery, SearchResult};
+use rstest::rstest;
+
+fn hit(file_path: &str, content: &str) -> SearchResult {
+    SearchResult {
+        id: format!("{file_path}#0"),
+        file_path: file_path.to_owned(),
+        start_line: 1,
+        content: content.to_owned(),
+        score: 0.5,
+        language: "rust".to_owned(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+fn labeled(files: &[&str], symbols: &[&str]) -> LabeledQuery {
+    LabeledQuery {
+        query: "refresh tokens".to_owned(),
+        files: files.iter().map(|f| (*f).to_owned()).collect(),
+        symbols: symbols.iter().map(|s| (*s).to_owned()).collect(),
+    }
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+}
+
+#[rstest]
+fn test_perfect_ranking_scores_one() {
+    let query = labeled(&["src/auth/refresh.rs"], &["rotate"]);
+    let results = [
+        hit("/repo/src/auth/refresh.rs", "fn other() {}"),
+        hit("src/auth/session.rs", "let t = rotate(old);"),
+    ];
+
+    let score = query.score(&results, 10);
+
+    assert_close(score.recall, 1.0);
+    assert_close(score.reciprocal_rank, 1.0);
+    assert_close(score.ndcg, 1.0);
+    assert_eq!(score.first_relevant_rank, Some(1));
+}
+
+#[rstest]
+fn test_late_and_duplicate_hits_are_discounted() {
+    let query = labeled(&["src/a.rs", "src/b.rs"], &[]);
+    let results = [
+        hit("src/x.rs", ""),
+        hit("src/a.rs", ""),
+        hit("src/a.rs", ""),
+        hit("src/b.rs", ""),
+    ];
+
+    let score = query.score(&results, 10);
+
+    let ideal = 1.0 + 1.0 / 3_f64.log2();
+    let dcg = 1.0 / 3_f64.log2() + 1.0 / 5_f64.log2();
+    assert_close(score.recall, 1.0);
+    assert_close(score.reciprocal_rank, 0.5);
+    assert_close(score.ndcg, dcg / ideal);
+}
+
+#[rstest]
+#[case("fn rotate_all() {}", false)]
+#[case("self.rotate(token)", true)]
+#[case("let rotated = true;", false)]
+fn test_symbols_match_whole_identifiers(#[case] content: &str, #[case] relevant: bool) {
+    let query = labeled(&[], &["rotate"]);
+
+    let score = query.score(&[hit("src/foo.rs", content)], 10);
+
+    assert_eq!(score.first_relevant_rank.is_some(), relevant);
+}
+
+#[rstest]
+fn test_hits_below_k_are_ignored() {
+    let query = labeled(&["src/a.rs"], &[]);
+    let results = [hit("src/x.rs", ""), hit("src/a.rs", "")];
+
+    let score = query.score(&results, 1);
+
+    assert_close(score.recall, 0.0);
+    assert_close(score.reciprocal_rank, 0.0);
+    assert_eq!(score.first_relevant_rank, None);
+}
+
+#[rstest]
+fn test_report_averages_queries() {
+    let results = [hit("src/a.rs", "")];
+    let scores = vec![
+        labeled(&["src/a.rs"], &[]).score(&results, 5),
+        labeled(&[], &["nope"]).score(&results, 5),
+    ];
+
+    let report = EvalReport::new("repo".to_owned(), 5, scores);
+
+    assert_close(report.recall, 0.5);
+    assert_close(report.mrr, 0.5);
+    assert_close(report.ndcg, 0.5);
+}
@@ -3,6 +3,7 @@
 mod browse_tests;
 mod config_tests;
 mod embedding_tests;
+mod evaluation_tests;
 mod ids_tests;
 mod org_context_tests;
 mod project_context_tests;
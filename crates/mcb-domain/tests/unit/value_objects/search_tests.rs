@@ -1,8 +1,8 @@
-//! Unit tests for `SearchResult` value object
+//! Unit tests for `SearchResult`, `ScoreExplanation` and `SearchScope` value objects
 
 #[cfg(test)]
 mod tests {
-    use mcb_domain::value_objects::SearchResult;
+    use mcb_domain::value_objects::{ScoreExplanation, SearchResult, SearchScope};
     use rstest::rstest;
 
     #[rstest]
@@ -14,6 +14,8 @@ mod tests {
             content: "impl SearchService for DefaultSearch { ... }".to_owned(),
             score: 0.87,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         assert_eq!(result.id, "chunk-123");
@@ -36,6 +38,8 @@ mod tests {
             content: "fn search_perfect_match() {}".to_owned(),
             score: 0.99,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         assert!(result.score > 0.95);
@@ -51,6 +55,8 @@ mod tests {
             content: "fn unrelated_function() {}".to_owned(),
             score: 0.12,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         assert!(result.score < 0.2);
@@ -66,6 +72,8 @@ mod tests {
             content: "pub fn process_data(data: &str) -> Result<String> { ... }".to_owned(),
             score: 0.85,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         let python_result = SearchResult {
@@ -75,6 +83,8 @@ mod tests {
             content: "def process_data(data: str) -> str:\n    return data.upper()".to_owned(),
             score: 0.82,
             language: "python".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         assert_eq!(rust_result.language, "rust");
@@ -91,6 +101,8 @@ mod tests {
             content: "unrelated content".to_owned(),
             score: 0.0,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         assert_eq!(result.score, 0.0);
@@ -105,8 +117,109 @@ mod tests {
             content: "exact match content".to_owned(),
             score: 1.0,
             language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
         };
 
         assert_eq!(result.score, 1.0);
     }
+
+    #[rstest]
+    #[case(
+        Some("crates/mcb-server/"),
+        None,
+        None,
+        "crates/mcb-server/src/lib.rs",
+        "rust",
+        true
+    )]
+    #[case(
+        Some("crates/mcb-server/"),
+        None,
+        None,
+        "crates/mcb-domain/src/lib.rs",
+        "rust",
+        false
+    )]
+    #[case(Some("./src/"), None, None, "src/main.rs", "rust", true)]
+    #[case(None, Some("src/*.rs"), None, "src/main.rs", "rust", true)]
+    #[case(None, Some("src/*.rs"), None, "src/nested/main.rs", "rust", false)]
+    #[case(None, Some("src/**/*.rs"), None, "src/nested/main.rs", "rust", true)]
+    #[case(None, None, Some("Python"), "app.py", "python", true)]
+    #[case(None, None, Some("python"), "main.rs", "rust", false)]
+    fn test_search_scope_matcher(
+        #[case] path_prefix: Option<&str>,
+        #[case] path_glob: Option<&str>,
+        #[case] language: Option<&str>,
+        #[case] file_path: &str,
+        #[case] file_language: &str,
+        #[case] expected: bool,
+    ) -> Result<(), mcb_domain::error::Error> {
+        let scope = SearchScope {
+            path_prefix: path_prefix.map(str::to_owned),
+            path_glob: path_glob.map(str::to_owned),
+            languages: language.map(|l| vec![l.to_owned()]),
+            ..Default::default()
+        };
+        let matches = scope.matcher()?;
+        assert_eq!(matches(file_path, file_language), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    #[case("src/generated/api.rs", "rust", false)]
+    #[case("src/api.rs", "rust", true)]
+    #[case("vendor/lib.rs", "rust", false)]
+    #[case("scripts/build.py", "python", false)]
+    #[case("src/gen.rs", "rust", true)]
+    fn test_search_scope_exclusions(
+        #[case] file_path: &str,
+        #[case] language: &str,
+        #[case] expected: bool,
+    ) -> Result<(), mcb_domain::error::Error> {
+        let scope = SearchScope {
+            exclude_paths: Some(vec!["vendor/".to_owned(), "**/generated/**".to_owned()]),
+            exclude_languages: Some(vec!["Python".to_owned()]),
+            ..Default::default()
+        };
+        let matches = scope.matcher()?;
+        assert_eq!(matches(file_path, language), expected);
+        Ok(())
+    }
+
+    #[rstest]
+    fn test_search_scope_rejects_invalid_glob() {
+        let scope = SearchScope {
+            path_glob: Some("src/[".to_owned()),
+            ..Default::default()
+        };
+        assert!(scope.matcher().is_err());
+        assert!(!scope.is_unrestricted());
+    }
+
+    #[rstest]
+    fn test_score_explanation_serializes_only_recorded_stages() {
+        let explanation = ScoreExplanation {
+            bm25: Some(0.5),
+            ..ScoreExplanation::dense(0.8)
+        };
+        let json = serde_json::to_value(&explanation).expect("serialize explanation");
+        assert_eq!(json, serde_json::json!({ "dense": 0.8, "bm25": 0.5 }));
+    }
+
+    #[rstest]
+    fn test_search_result_omits_missing_explanation() {
+        let result = SearchResult {
+            id: "chunk-1".to_owned(),
+            file_path: "src/lib.rs".to_owned(),
+            start_line: 1,
+            content: String::new(),
+            score: 0.5,
+            language: "rust".to_owned(),
+            last_modified: None,
+            explanation: None,
+        };
+        let json = serde_json::to_value(&result).expect("serialize result");
+        assert!(json.get("explanation").is_none());
+    }
 }
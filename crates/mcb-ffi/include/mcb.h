@@ -0,0 +1,63 @@
+/*
+ * C ABI for the MCB indexing and search engine (libmcb_ffi).
+ *
+ * Strings are NUL-terminated UTF-8. Functions returning `char *` return
+ * JSON owned by the caller, released with mcb_string_free(), or NULL on
+ * failure; mcb_last_error() then describes the error for the calling
+ * thread. An engine handle may be shared between threads.
+ *
+ * See docs/modules/bindings.md.
+ */
+#ifndef MCB_H
+#define MCB_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Value returned by mcb_abi_version() for this header. */
+#define MCB_FFI_ABI_VERSION 1
+
+typedef struct McbEngine McbEngine;
+
+/* Build an engine from the layered configuration; profile may be NULL. */
+McbEngine *mcb_engine_new(const char *profile);
+
+/* Release an engine; NULL is ignored. */
+void mcb_engine_free(McbEngine *engine);
+
+/* Index a directory and wait for completion; returns a JSON object
+ * {"collection", "path", "files", "chunks", "duration_ms"}. */
+char *mcb_index(const McbEngine *engine, const char *path, const char *collection);
+
+/* Semantic search; returns a JSON array of hits
+ * {"id", "file_path", "start_line", "content", "score", "language"}. */
+char *mcb_search(const McbEngine *engine, const char *collection, const char *query,
+                 size_t limit);
+
+/* Collections of the vector store, as a JSON array. */
+char *mcb_collections(const McbEngine *engine);
+
+/* Up to `limit` indexed files of a collection, as a JSON array. */
+char *mcb_browse(const McbEngine *engine, const char *collection, size_t limit);
+
+/* Release a string returned by this library; NULL is ignored. */
+void mcb_string_free(char *value);
+
+/* Last error on the calling thread, or NULL; valid until the next call. */
+const char *mcb_last_error(void);
+
+/* Library version, e.g. "0.3.1"; static. */
+const char *mcb_version(void);
+
+/* ABI version; compare with MCB_FFI_ABI_VERSION. */
+uint32_t mcb_abi_version(void);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* MCB_H */
@@ -0,0 +1,266 @@
+//! C ABI for the MCB engine.
+//!
+//! **Documentation**: [docs/modules/bindings.md](../../../docs/modules/bindings.md)
+//!
+//! Exposes [`mcb::Engine`] to editor plugins and other native callers
+//! through an opaque handle and functions returning JSON strings. The
+//! declarations are in `include/mcb.h`; [`MCB_FFI_ABI_VERSION`] changes
+//! whenever a signature does.
+//!
+//! Conventions shared by every function:
+//! - String arguments are NUL-terminated UTF-8.
+//! - Functions returning `char *` return JSON owned by the caller, to be
+//!   released with [`mcb_string_free`], or `NULL` on failure.
+//! - On failure [`mcb_last_error`] describes the error; it is per thread.
+//! - Panics are caught and reported as errors instead of unwinding into C.
+
+// The C ABI takes raw pointers; each `unsafe` block states what it relies on.
+#![allow(unsafe_code)]
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::ptr;
+
+use mcb::Engine;
+use serde::Serialize;
+use tokio::runtime::Runtime;
+
+/// Version of the C ABI declared in `include/mcb.h`.
+pub const MCB_FFI_ABI_VERSION: u32 = 1;
+
+const VERSION: &CStr =
+    match CStr::from_bytes_with_nul(concat!(env!("CARGO_PKG_VERSION"), "\0").as_bytes()) {
+        Ok(version) => version,
+        Err(_) => c"unknown",
+    };
+
+type FfiResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Opaque engine handle with the runtime its calls block on.
+pub struct McbEngine {
+    // Declared first so the engine is dropped before its runtime.
+    engine: Engine,
+    runtime: Runtime,
+}
+
+/// Build an engine from the layered configuration. `profile` is a profile
+/// overlay name or `NULL` for `MCB_PROFILE`. Returns `NULL` on failure.
+///
+/// # Safety
+///
+/// `profile` must be `NULL` or a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_engine_new(profile: *const c_char) -> *mut McbEngine {
+    ffi_call(ptr::null_mut(), || {
+        // SAFETY: upheld by the caller.
+        let profile = unsafe { optional_str(profile) }?;
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()?;
+        let engine = runtime.block_on(Engine::from_config(profile))?;
+        Ok(Box::into_raw(Box::new(McbEngine { engine, runtime })))
+    })
+}
+
+/// Release an engine. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `engine` must be `NULL` or a handle from [`mcb_engine_new`] that has not
+/// been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_engine_free(engine: *mut McbEngine) {
+    if !engine.is_null() {
+        // SAFETY: the handle came from `Box::into_raw` and is freed once.
+        drop(unsafe { Box::from_raw(engine) });
+    }
+}
+
+/// Index the directory `path` into `collection` and wait for completion.
+/// Returns the summary as a JSON object.
+///
+/// # Safety
+///
+/// `engine` must be a live handle; `path` and `collection` must be valid
+/// NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_index(
+    engine: *const McbEngine,
+    path: *const c_char,
+    collection: *const c_char,
+) -> *mut c_char {
+    ffi_call(ptr::null_mut(), || {
+        // SAFETY: upheld by the caller.
+        let (handle, path, collection) = unsafe {
+            (
+                engine_ref(engine)?,
+                required_str(path, "path")?,
+                required_str(collection, "collection")?,
+            )
+        };
+        let summary = handle
+            .runtime
+            .block_on(handle.engine.index_path(path, collection))?;
+        json_string(&summary)
+    })
+}
+
+/// Semantic search for `query` in `collection`. Returns up to `limit` hits
+/// as a JSON array, best first.
+///
+/// # Safety
+///
+/// `engine` must be a live handle; `collection` and `query` must be valid
+/// NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_search(
+    engine: *const McbEngine,
+    collection: *const c_char,
+    query: *const c_char,
+    limit: usize,
+) -> *mut c_char {
+    ffi_call(ptr::null_mut(), || {
+        // SAFETY: upheld by the caller.
+        let (handle, collection, query) = unsafe {
+            (
+                engine_ref(engine)?,
+                required_str(collection, "collection")?,
+                required_str(query, "query")?,
+            )
+        };
+        let results = handle
+            .runtime
+            .block_on(handle.engine.search(collection, query, limit))?;
+        json_string(&results)
+    })
+}
+
+/// Every collection in the vector store, as a JSON array.
+///
+/// # Safety
+///
+/// `engine` must be a live handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_collections(engine: *const McbEngine) -> *mut c_char {
+    ffi_call(ptr::null_mut(), || {
+        // SAFETY: upheld by the caller.
+        let handle = unsafe { engine_ref(engine) }?;
+        let collections = handle.runtime.block_on(handle.engine.collections())?;
+        json_string(&collections)
+    })
+}
+
+/// Up to `limit` indexed files of `collection`, as a JSON array.
+///
+/// # Safety
+///
+/// `engine` must be a live handle; `collection` must be a valid
+/// NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_browse(
+    engine: *const McbEngine,
+    collection: *const c_char,
+    limit: usize,
+) -> *mut c_char {
+    ffi_call(ptr::null_mut(), || {
+        // SAFETY: upheld by the caller.
+        let (handle, collection) =
+            unsafe { (engine_ref(engine)?, required_str(collection, "collection")?) };
+        let files = handle
+            .runtime
+            .block_on(handle.engine.browse(collection, limit))?;
+        json_string(&files)
+    })
+}
+
+/// Release a string returned by this library. `NULL` is ignored.
+///
+/// # Safety
+///
+/// `value` must be `NULL` or a string returned by this library that has not
+/// been freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_string_free(value: *mut c_char) {
+    if !value.is_null() {
+        // SAFETY: the string came from `CString::into_raw` and is freed once.
+        drop(unsafe { CString::from_raw(value) });
+    }
+}
+
+/// Message of the last error on the calling thread, or `NULL` when the
+/// last call succeeded. Valid until the next call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn mcb_last_error() -> *const c_char {
+    LAST_ERROR.with_borrow(|error| error.as_deref().map_or(ptr::null(), CStr::as_ptr))
+}
+
+/// Version of the library, e.g. `0.3.1`. The string is static.
+#[unsafe(no_mangle)]
+pub extern "C" fn mcb_version() -> *const c_char {
+    VERSION.as_ptr()
+}
+
+/// See [`MCB_FFI_ABI_VERSION`].
+#[unsafe(no_mangle)]
+pub extern "C" fn mcb_abi_version() -> u32 {
+    MCB_FFI_ABI_VERSION
+}
+
+/// Run `call`, recording its error or panic and returning `on_error` instead.
+fn ffi_call<T>(on_error: T, call: impl FnOnce() -> FfiResult<T>) -> T {
+    set_last_error(None);
+    let message = match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(value)) => return value,
+        Ok(Err(error)) => error.to_string(),
+        Err(panic) => panic
+            .downcast_ref::<&str>()
+            .map(|message| (*message).to_owned())
+            .or_else(|| panic.downcast_ref::<String>().cloned())
+            .map_or_else(|| "panic".to_owned(), |message| format!("panic: {message}")),
+    };
+    set_last_error(Some(message));
+    on_error
+}
+
+fn set_last_error(message: Option<String>) {
+    let message = message.map(|message| {
+        CString::new(message.replace('\0', " ")).unwrap_or_else(|_| c"error".to_owned())
+    });
+    LAST_ERROR.with_borrow_mut(|error| *error = message);
+}
+
+fn json_string(value: &impl Serialize) -> FfiResult<*mut c_char> {
+    Ok(CString::new(serde_json::to_string(value)?)?.into_raw())
+}
+
+/// # Safety
+///
+/// `engine` must be `NULL` or a live handle.
+unsafe fn engine_ref<'a>(engine: *const McbEngine) -> FfiResult<&'a McbEngine> {
+    // SAFETY: upheld by the caller.
+    unsafe { engine.as_ref() }.ok_or_else(|| "engine is NULL".into())
+}
+
+/// # Safety
+///
+/// `value` must be `NULL` or a valid NUL-terminated string.
+unsafe fn required_str<'a>(value: *const c_char, name: &str) -> FfiResult<&'a str> {
+    // SAFETY: upheld by the caller.
+    unsafe { optional_str(value) }?.ok_or_else(|| format!("{name} is NULL").into())
+}
+
+/// # Safety
+///
+/// `value` must be `NULL` or a valid NUL-terminated string.
+unsafe fn optional_str<'a>(value: *const c_char) -> FfiResult<Option<&'a str>> {
+    if value.is_null() {
+        return Ok(None);
+    }
+    // SAFETY: upheld by the caller.
+    Ok(Some(unsafe { CStr::from_ptr(value) }.to_str()?))
+}
@@ -0,0 +1,56 @@
+//! Tests for argument checking and error reporting of the C ABI.
+
+use std::ffi::CStr;
+use std::ptr;
+
+use mcb_ffi::{
+    MCB_FFI_ABI_VERSION, McbEngine, mcb_abi_version, mcb_browse, mcb_collections, mcb_engine_free,
+    mcb_index, mcb_last_error, mcb_search, mcb_string_free, mcb_version,
+};
+use rstest::rstest;
+
+fn last_error() -> Option<String> {
+    let error = mcb_last_error();
+    // SAFETY: `mcb_last_error` returns NULL or a live NUL-terminated string.
+    (!error.is_null()).then(|| {
+        unsafe { CStr::from_ptr(error) }
+            .to_string_lossy()
+            .into_owned()
+    })
+}
+
+#[rstest]
+fn test_version_matches_crate() {
+    // SAFETY: `mcb_version` returns a static NUL-terminated string.
+    let version = unsafe { CStr::from_ptr(mcb_version()) };
+
+    assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+    assert_eq!(mcb_abi_version(), MCB_FFI_ABI_VERSION);
+}
+
+#[rstest]
+fn test_calls_without_engine_fail_with_last_error() {
+    let engine: *const McbEngine = ptr::null();
+
+    // SAFETY: NULL handles are reported as errors.
+    let results = unsafe {
+        [
+            mcb_index(engine, c"/tmp".as_ptr(), c"docs".as_ptr()),
+            mcb_search(engine, c"docs".as_ptr(), c"query".as_ptr(), 5),
+            mcb_collections(engine),
+            mcb_browse(engine, c"docs".as_ptr(), 5),
+        ]
+    };
+
+    assert!(results.iter().all(|result| result.is_null()));
+    assert_eq!(last_error().as_deref(), Some("engine is NULL"));
+}
+
+#[rstest]
+fn test_free_functions_ignore_null() {
+    // SAFETY: NULL is documented as ignored.
+    unsafe {
+        mcb_string_free(ptr::null_mut());
+        mcb_engine_free(ptr::null_mut());
+    }
+}
@@ -0,0 +1,6 @@
+//! Unit tests — `cargo test -p mcb-ffi --test unit`
+
+// The tests call the C ABI directly.
+#![allow(unsafe_code)]
+
+mod ffi_tests;
@@ -6,11 +6,16 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
-use mcb_domain::value_objects::{EmbeddingConfig, VectorStoreConfig};
+use mcb_domain::ports::{
+    BulkheadPolicy, HttpTransportConfig, IndexingThrottlePolicy, InsertBatchPolicy,
+    JobSchedulerPolicy, PersistencePolicy, RequestSigningConfig, ResiliencePolicy,
+    StorageQuotaPolicy, TombstonePolicy,
+};
+use mcb_domain::value_objects::{DistanceMetric, EmbeddingConfig, VectorStoreConfig};
 use serde::{Deserialize, Serialize};
 
 use super::infrastructure::{
-    CacheSystemConfig, LimitsConfig, LoggingConfig, MetricsConfig, ResilienceConfig,
+    CacheSystemConfig, HealthConfig, LimitsConfig, LoggingConfig, MetricsConfig, ResilienceConfig,
 };
 use super::mode::ModeConfig;
 use super::system::{
@@ -33,6 +38,10 @@ pub struct EmbeddingConfigContainer {
     pub dimensions: Option<usize>,
     /// Cache directory for local embedding providers
     pub cache_dir: Option<PathBuf>,
+    /// Timeout and retry policy for the embedding provider
+    pub resilience: Option<ResiliencePolicy>,
+    /// Per-request signing (e.g. SigV4 for a self-hosted inference gateway)
+    pub signing: Option<RequestSigningConfig>,
     /// Named configs for YAML format
     pub configs: HashMap<String, EmbeddingConfig>,
 }
@@ -49,6 +58,18 @@ pub struct VectorStoreConfigContainer {
     pub dimensions: Option<usize>,
     /// Collection name
     pub collection: Option<String>,
+    /// Timeout and retry policy for the vector store provider
+    pub resilience: Option<ResiliencePolicy>,
+    /// Bulk insert batch size and concurrency
+    pub insert_batch: Option<InsertBatchPolicy>,
+    /// Retention of deleted and replaced vectors for point-in-time search
+    pub tombstones: Option<TombstonePolicy>,
+    /// Snapshot and write-ahead log directory for embedded stores
+    pub persistence: Option<PersistencePolicy>,
+    /// Storage limits for embedded stores
+    pub quota: Option<StorageQuotaPolicy>,
+    /// Distance metric vectors are stored and searched with (default cosine)
+    pub distance_metric: Option<DistanceMetric>,
     /// Named configs for YAML format
     pub configs: HashMap<String, VectorStoreConfig>,
 }
@@ -83,6 +104,8 @@ pub struct ProvidersConfig {
     pub embedding: EmbeddingConfigContainer,
     /// Vector store provider configuration
     pub vector_store: VectorStoreConfigContainer,
+    /// Proxy and additional root CAs for all outbound HTTP providers
+    pub http: HttpTransportConfig,
 }
 
 /// Indexing configuration for file discovery.
@@ -91,6 +114,17 @@ pub struct ProvidersConfig {
 pub struct IndexingConfig {
     /// File extensions to include during indexing.
     pub supported_extensions: Vec<String>,
+    /// Keep a content-addressed copy of every indexed file in the database,
+    /// so `read_file` can serve exact content without the working tree.
+    pub store_snapshots: bool,
+    /// Store a second "symbol name + signature" embedding per chunk and fuse
+    /// it into search scores, so exact-name queries rank higher.
+    pub title_vectors: bool,
+    /// Pacing of background indexing (files/sec, embedding requests/sec, IO pause).
+    pub throttle: IndexingThrottlePolicy,
+    /// Directories that may be indexed; a path must resolve under one of
+    /// them. Empty allows any path the process can read.
+    pub allowed_roots: Vec<PathBuf>,
 }
 
 /// MCP server feature configuration.
@@ -103,6 +137,12 @@ pub struct McpConfig {
     pub no_stdio: bool,
     /// Enable stdio-only mode (MCP over stdin/stdout, no HTTP).
     pub stdio_only: bool,
+    /// Reject tool calls and admin requests that write (indexing, memory,
+    /// sessions, entity CRUD, GraphQL mutations).
+    pub read_only: bool,
+    /// Seconds the result of a mutating tool call is kept for retries with
+    /// the same `idempotency_key`; `0` rejects idempotency keys.
+    pub idempotency_window_secs: u64,
 }
 
 /// Infrastructure configurations
@@ -115,10 +155,16 @@ pub struct InfrastructureConfig {
     pub event_bus: EventBusConfig,
     /// Metrics configuration
     pub metrics: MetricsConfig,
+    /// Readiness probe configuration
+    pub health: HealthConfig,
     /// Resilience configuration
     pub resilience: ResilienceConfig,
     /// Limits configuration
     pub limits: LimitsConfig,
+    /// Job scheduler concurrency limits
+    pub jobs: JobSchedulerPolicy,
+    /// Separate concurrency limits for search, indexing and admin work
+    pub bulkheads: BulkheadPolicy,
 }
 
 /// Data management configurations
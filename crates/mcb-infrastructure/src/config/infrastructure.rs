@@ -4,7 +4,7 @@
 //! Infrastructure configuration types
 //!
 //! configuration for infrastructure concerns:
-//! logging, limits, cache, metrics, and resilience.
+//! logging, limits, cache, metrics, health, and resilience.
 
 use std::path::PathBuf;
 
@@ -119,6 +119,24 @@ pub struct MetricsConfig {
     pub exporter_url: Option<String>,
 }
 
+// ============================================================================
+// Health Configuration
+// ============================================================================
+
+/// Readiness probe configuration (`/readyz`)
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct HealthConfig {
+    /// Seconds a readiness result is reused before dependencies are probed again
+    pub probe_cache_ttl_secs: u64,
+    /// Per-dependency probe timeout in milliseconds
+    pub probe_timeout_ms: u64,
+    /// Dependencies whose failure makes the server not ready
+    /// (`embedding`, `vector_store`, `storage`, `cache`, `database`); others only
+    /// degrade it
+    pub critical: Vec<String>,
+}
+
 // ============================================================================
 // Resilience Configuration
 // ============================================================================
@@ -127,11 +145,11 @@ pub struct MetricsConfig {
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(deny_unknown_fields)]
 pub struct ResilienceConfig {
-    /// Circuit breaker failure threshold
+    /// Consecutive failed vector store calls that open its circuit (0 disables)
     pub circuit_breaker_failure_threshold: u32,
-    /// Circuit breaker timeout in seconds
+    /// Seconds an open circuit fails fast before probing the vector store
     pub circuit_breaker_timeout_secs: u64,
-    /// Circuit breaker success threshold
+    /// Consecutive successful probes that close the circuit again
     pub circuit_breaker_success_threshold: u32,
     /// Rate limiter requests per second
     pub rate_limiter_rps: u32,
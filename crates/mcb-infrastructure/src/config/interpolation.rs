@@ -0,0 +1,162 @@
+//! Secret interpolation for `settings:` values.
+//!
+//! Runs on the settings tree **before** it is deserialized into `AppConfig`,
+//! so API keys and tokens never need to appear literally in config files.
+//!
+//! Supported forms inside any string value:
+//! - `${ENV_VAR}` — replaced by the environment variable (`$${...}` escapes)
+//! - `file:/path/to/secret` — whole value read from a file (trailing newline trimmed)
+//! - `vault:<path>#<field>` — whole value read from Vault KV v2 using
+//!   `VAULT_ADDR` / `VAULT_TOKEN`
+//!
+//! Every failure is reported as [`Error::ConfigInvalid`] carrying the dotted
+//! key path of the offending value (e.g. `providers.embedding.api_key`).
+
+use std::fmt::Write as _;
+
+use mcb_domain::error::{Error, Result};
+use mcb_utils::constants::{
+    CONFIG_SECRET_FILE_PREFIX, CONFIG_SECRET_VAULT_PREFIX, VAULT_ADDR_ENV, VAULT_TOKEN_ENV,
+};
+use serde_json::Value;
+
+/// Resolve all interpolations and secret indirections in a settings tree in place.
+///
+/// # Errors
+///
+/// Returns [`Error::ConfigInvalid`] naming the key whose value could not be resolved.
+pub(crate) fn resolve_settings(settings: &mut Value) -> Result<()> {
+    resolve_node(settings, "")
+}
+
+fn resolve_node(value: &mut Value, key: &str) -> Result<()> {
+    match value {
+        Value::String(raw) => {
+            *raw = resolve_string(raw, key)?;
+            Ok(())
+        }
+        Value::Object(map) => map.iter_mut().try_for_each(|(name, child)| {
+            let child_key = if key.is_empty() {
+                name.clone()
+            } else {
+                format!("{key}.{name}")
+            };
+            resolve_node(child, &child_key)
+        }),
+        Value::Array(items) => items
+            .iter_mut()
+            .enumerate()
+            .try_for_each(|(idx, child)| resolve_node(child, &format!("{key}[{idx}]"))),
+        Value::Null | Value::Bool(_) | Value::Number(_) => Ok(()),
+    }
+}
+
+fn resolve_string(raw: &str, key: &str) -> Result<String> {
+    let expanded = expand_env(raw, key)?;
+    if let Some(path) = expanded.strip_prefix(CONFIG_SECRET_FILE_PREFIX) {
+        return read_secret_file(path, key);
+    }
+    if let Some(reference) = expanded.strip_prefix(CONFIG_SECRET_VAULT_PREFIX) {
+        return read_vault_secret(reference, key);
+    }
+    Ok(expanded)
+}
+
+/// Expand `${NAME}` references; `$${NAME}` yields a literal `${NAME}`.
+fn expand_env(raw: &str, key: &str) -> Result<String> {
+    let mut out = String::with_capacity(raw.len());
+    let mut rest = raw;
+    while let Some(start) = rest.find("${") {
+        let escaped = start > 0 && rest.as_bytes()[start - 1] == b'$';
+        let (before, after) = rest.split_at(start);
+        let end = after.find('}').ok_or_else(|| {
+            Error::config_invalid(key, format!("unterminated '${{' in value '{raw}'"))
+        })?;
+        let name = &after[2..end];
+        if escaped {
+            out.push_str(&before[..before.len() - 1]);
+            out.push_str(&after[..=end]);
+        } else {
+            if !is_valid_env_name(name) {
+                return Err(Error::config_invalid(
+                    key,
+                    format!("invalid environment variable name '{name}'"),
+                ));
+            }
+            let resolved = std::env::var(name).map_err(|_| {
+                Error::config_invalid(key, format!("environment variable '{name}' is not set"))
+            })?;
+            out.push_str(before);
+            out.push_str(&resolved);
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn is_valid_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn read_secret_file(path: &str, key: &str) -> Result<String> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        Error::config_invalid(key, format!("cannot read secret file '{path}': {e}"))
+    })?;
+    Ok(content.trim_end_matches(['\r', '\n']).to_owned())
+}
+
+/// Read `<path>#<field>` from Vault KV v2 (`GET $VAULT_ADDR/v1/<path>`).
+fn read_vault_secret(reference: &str, key: &str) -> Result<String> {
+    let (path, field) = reference.split_once('#').ok_or_else(|| {
+        Error::config_invalid(
+            key,
+            format!("vault reference '{reference}' must have the form '<path>#<field>'"),
+        )
+    })?;
+    let addr = std::env::var(VAULT_ADDR_ENV).map_err(|_| {
+        Error::config_invalid(
+            key,
+            format!("{VAULT_ADDR_ENV} must be set to resolve vault:"),
+        )
+    })?;
+    let token = std::env::var(VAULT_TOKEN_ENV).map_err(|_| {
+        Error::config_invalid(
+            key,
+            format!("{VAULT_TOKEN_ENV} must be set to resolve vault:"),
+        )
+    })?;
+
+    let mut url = addr.trim_end_matches('/').to_owned();
+    let _ = write!(url, "/v1/{}", path.trim_start_matches('/'));
+
+    // The blocking client owns its own runtime; run it on a scoped thread so
+    // config resolution works both inside and outside a Tokio runtime.
+    let body = std::thread::scope(|s| {
+        s.spawn(|| -> std::result::Result<Value, reqwest::Error> {
+            reqwest::blocking::Client::new()
+                .get(&url)
+                .header("X-Vault-Token", &token)
+                .send()?
+                .error_for_status()?
+                .json::<Value>()
+        })
+        .join()
+    })
+    .map_err(|_| Error::config_invalid(key, "vault request thread panicked"))?
+    .map_err(|e| Error::config_invalid(key, format!("vault request to '{path}' failed: {e}")))?;
+
+    body.pointer(&format!("/data/data/{field}"))
+        .and_then(Value::as_str)
+        .map(str::to_owned)
+        .ok_or_else(|| {
+            Error::config_invalid(
+                key,
+                format!("vault secret '{path}' has no string field '{field}'"),
+            )
+        })
+}
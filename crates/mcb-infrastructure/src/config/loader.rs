@@ -20,8 +20,10 @@ use super::app::AppConfig;
 ///    `config/{env}.local.yaml` then `config/{env}.yaml`
 /// 3. Parses YAML, extracts `settings:` key
 /// 4. Converts YAML → JSON via `serde_json::to_value`
-/// 5. Deserializes via `serde_json::from_value::<AppConfig>()` — the production path
-/// 6. Validates via `validate_app_config()`
+/// 5. Applies the `MCB_PROFILE` overlay and `MCB__*` env overrides
+/// 6. Resolves `${ENV}` / `file:` / `vault:` secret indirections
+/// 7. Deserializes via `serde_json::from_value::<AppConfig>()` — the production path
+/// 8. Validates via `validate_app_config()`
 ///
 /// # Errors
 ///
@@ -60,8 +62,13 @@ fn load_from_path(path: &Path) -> Result<AppConfig> {
 
     // Production path: YAML value → JSON value → serde_json::from_value
     // Mirrors crates/mcb/src/initializers/mcp_server.rs:44-45
-    let json_settings = serde_json::to_value(settings)
+    let mut json_settings = serde_json::to_value(settings)
         .map_err(|e| Error::config_with_source("Failed to convert YAML settings to JSON", e))?;
+    if let Some(config_dir) = path.parent() {
+        let profile = super::profile::active_profile(None);
+        super::profile::apply_layers(&mut json_settings, config_dir, profile.as_deref())?;
+    }
+    super::interpolation::resolve_settings(&mut json_settings)?;
 
     let config: AppConfig = serde_json::from_value(json_settings)
         .map_err(|e| Error::config_with_source("Failed to deserialize AppConfig", e))?;
@@ -8,8 +8,10 @@
 
 pub mod app;
 pub mod infrastructure;
+mod interpolation;
 mod loader;
 pub mod mode;
+pub mod profile;
 mod provider;
 pub mod system;
 mod validation;
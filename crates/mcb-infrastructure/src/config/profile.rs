@@ -0,0 +1,176 @@
+//!
+//! **Documentation**: [docs/modules/infrastructure.md](../../../../../docs/modules/infrastructure.md#configuration)
+//!
+//! Configuration profiles and layered overrides
+//!
+//! The effective `settings:` tree is built in layers (lowest priority first):
+//!
+//! 1. Base file — `config/{env}.yaml` (selected by `LOCO_ENV`)
+//! 2. Profile overlay — `config/profiles/{profile}.yaml` (selected by `--profile` / `MCB_PROFILE`)
+//! 3. Environment overrides — `MCB__SECTION__KEY=value`
+//!
+//! Overlays are deep-merged: mappings merge key by key, every other value replaces.
+
+use std::path::{Path, PathBuf};
+
+use mcb_domain::error::{Error, Result};
+use mcb_utils::constants::{
+    CONFIG_ENV_OVERRIDE_PREFIX, CONFIG_ENV_OVERRIDE_SEPARATOR, CONFIG_PROFILE_ENV,
+    CONFIG_PROFILES_DIR,
+};
+use serde_json::Value;
+
+/// Resolve the active profile: an explicit CLI value wins over `MCB_PROFILE`.
+#[must_use]
+pub fn active_profile(cli_profile: Option<&str>) -> Option<String> {
+    cli_profile
+        .map(str::to_owned)
+        .or_else(|| std::env::var(CONFIG_PROFILE_ENV).ok())
+        .filter(|p| !p.is_empty())
+}
+
+/// Reject profile names outside `[A-Za-z0-9_-]+`, so a name can never point
+/// the overlay path outside the profiles directory.
+///
+/// # Errors
+///
+/// Returns [`Error::ConfigInvalid`] if `profile` is empty or contains any
+/// other character.
+pub fn validate_profile_name(profile: &str) -> Result<()> {
+    if !profile.is_empty()
+        && profile
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+    {
+        return Ok(());
+    }
+    Err(Error::config_invalid(
+        CONFIG_PROFILE_ENV,
+        format!("profile '{profile}' must match [A-Za-z0-9_-]+"),
+    ))
+}
+
+/// Path of the overlay file for `profile` inside `config_dir`.
+#[must_use]
+pub fn profile_path(config_dir: &Path, profile: &str) -> PathBuf {
+    config_dir
+        .join(CONFIG_PROFILES_DIR)
+        .join(format!("{profile}.yaml"))
+}
+
+/// Apply every layer on top of the base `settings` tree.
+///
+/// # Errors
+///
+/// Returns an error if the profile name is invalid, the profile overlay is
+/// missing or malformed, or if an
+/// environment override cannot be applied.
+pub fn apply_layers(settings: &mut Value, config_dir: &Path, profile: Option<&str>) -> Result<()> {
+    if let Some(profile) = profile {
+        apply_profile_overlay(settings, config_dir, profile)?;
+    }
+    apply_env_overrides(settings, std::env::vars())
+}
+
+/// Deep-merge the `settings:` section of a profile overlay file into `settings`.
+///
+/// # Errors
+///
+/// Returns an error if the profile name is invalid, or the overlay file does
+/// not exist or cannot be parsed.
+pub fn apply_profile_overlay(settings: &mut Value, config_dir: &Path, profile: &str) -> Result<()> {
+    validate_profile_name(profile)?;
+    let path = profile_path(config_dir, profile);
+    let content = std::fs::read_to_string(&path).map_err(|e| {
+        Error::config_with_source(
+            format!(
+                "Profile '{profile}' overlay not readable: {}",
+                path.display()
+            ),
+            e,
+        )
+    })?;
+    let overlay: serde_yaml::Value = serde_yaml::from_str(&content)
+        .map_err(|e| Error::config_with_source(format!("Failed to parse {}", path.display()), e))?;
+    let Some(overlay_settings) = overlay.get("settings") else {
+        return Err(Error::ConfigMissing(format!(
+            "No 'settings' key in profile overlay {}",
+            path.display()
+        )));
+    };
+    let overlay_json = serde_json::to_value(overlay_settings)
+        .map_err(|e| Error::config_with_source("Failed to convert profile overlay to JSON", e))?;
+    merge(settings, overlay_json);
+    Ok(())
+}
+
+/// Apply `MCB__A__B=value` overrides onto `settings.a.b`.
+///
+/// Values are parsed as YAML scalars, so `true`, `30` and `text` become a
+/// boolean, a number and a string respectively.
+///
+/// # Errors
+///
+/// Returns [`Error::ConfigInvalid`] if an override targets a non-mapping parent.
+pub fn apply_env_overrides(
+    settings: &mut Value,
+    vars: impl IntoIterator<Item = (String, String)>,
+) -> Result<()> {
+    for (name, raw) in vars {
+        let Some(path) = name.strip_prefix(CONFIG_ENV_OVERRIDE_PREFIX) else {
+            continue;
+        };
+        let segments: Vec<String> = path
+            .split(CONFIG_ENV_OVERRIDE_SEPARATOR)
+            .map(str::to_lowercase)
+            .collect();
+        if segments.iter().any(String::is_empty) {
+            return Err(Error::config_invalid(name, "empty key segment in override"));
+        }
+        let value = serde_yaml::from_str::<Value>(&raw).unwrap_or(Value::String(raw));
+        set_path(settings, &segments, value).map_err(|parent| {
+            Error::config_invalid(name, format!("'{parent}' is not a mapping"))
+        })?;
+    }
+    Ok(())
+}
+
+/// Deep-merge `overlay` into `base`.
+pub fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base_map), Value::Object(overlay_map)) => {
+            for (key, value) in overlay_map {
+                match base_map.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base_map.insert(key, value);
+                    }
+                }
+            }
+        }
+        (slot, value) => *slot = value,
+    }
+}
+
+/// Set `value` at `segments`, creating intermediate mappings. On failure
+/// returns the dotted path of the parent that is not a mapping.
+fn set_path(
+    root: &mut Value,
+    segments: &[String],
+    value: Value,
+) -> std::result::Result<(), String> {
+    let mut node = root;
+    for (depth, segment) in segments.iter().enumerate() {
+        let map = node
+            .as_object_mut()
+            .ok_or_else(|| segments[..depth].join("."))?;
+        if depth + 1 == segments.len() {
+            map.insert(segment.clone(), value);
+            return Ok(());
+        }
+        node = map
+            .entry(segment.clone())
+            .or_insert_with(|| Value::Object(serde_json::Map::new()));
+    }
+    Ok(())
+}
@@ -7,6 +7,7 @@ use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::ConfigProvider;
 
 use super::app;
+use super::interpolation;
 use super::loader;
 use super::validation;
 
@@ -30,7 +31,10 @@ impl ConfigProvider for LocoYamlConfigProvider {
                 )
             })?;
 
-        let config: app::AppConfig = serde_json::from_value(json_value.clone()).map_err(|e| {
+        let mut resolved = json_value.clone();
+        interpolation::resolve_settings(&mut resolved)?;
+
+        let config: app::AppConfig = serde_json::from_value(resolved).map_err(|e| {
             Error::config_with_source("Failed to deserialize AppConfig from JSON", e)
         })?;
 
@@ -236,6 +236,12 @@ pub struct DaemonConfig {
     pub max_restart_attempts: u32,
     /// Auto-start daemon
     pub auto_start: bool,
+    /// Pidfile written by `mcb daemon start`
+    pub pid_file: PathBuf,
+    /// File receiving the daemon's stdout/stderr (rotated per `logging.max_*` on start)
+    pub log_file: PathBuf,
+    /// Seconds `mcb daemon stop` waits after SIGTERM before giving up
+    pub shutdown_timeout_secs: u64,
 }
 
 // ============================================================================
@@ -254,4 +260,6 @@ pub struct OperationsConfig {
     pub retention_secs: u64,
     /// Maximum operations to keep in memory
     pub max_operations_in_memory: usize,
+    /// Interval in seconds between search feedback boost tuning runs (0 disables)
+    pub feedback_tuning_interval_secs: u64,
 }
@@ -14,6 +14,60 @@ pub fn validate_app_config(config: &AppConfig) -> Result<()> {
     validate_daemon_config(config)?;
     validate_backup_config(config)?;
     validate_operations_config(config)?;
+    validate_provider_http_config(config)?;
+    validate_resilience_config(config)?;
+    validate_indexing_config(config)?;
+    Ok(())
+}
+
+fn validate_indexing_config(config: &AppConfig) -> Result<()> {
+    for root in &config.mcp.indexing.allowed_roots {
+        if !root.is_dir() {
+            return Err(Error::config_invalid(
+                "mcp.indexing.allowed_roots",
+                format!(
+                    "allowed root '{}' is not an existing directory",
+                    root.display()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn validate_provider_http_config(config: &AppConfig) -> Result<()> {
+    if let Some(policy) = &config.providers.embedding.resilience {
+        policy.validate("providers.embedding.resilience")?;
+    }
+    if let Some(signing) = &config.providers.embedding.signing {
+        signing.validate("providers.embedding.signing")?;
+    }
+    if let Some(policy) = &config.providers.vector_store.resilience {
+        policy.validate("providers.vector_store.resilience")?;
+    }
+    if let Some(policy) = &config.providers.vector_store.insert_batch {
+        policy.validate("providers.vector_store.insert_batch")?;
+    }
+    if let Some(policy) = &config.providers.vector_store.persistence {
+        policy.validate("providers.vector_store.persistence")?;
+    }
+    if let Some(policy) = &config.providers.vector_store.quota {
+        policy.validate("providers.vector_store.quota")?;
+    }
+    config.providers.http.validate("providers.http")?;
+    Ok(())
+}
+
+fn validate_resilience_config(config: &AppConfig) -> Result<()> {
+    let resilience = &config.system.infrastructure.resilience;
+    if resilience.circuit_breaker_failure_threshold > 0
+        && resilience.circuit_breaker_timeout_secs == 0
+    {
+        return Err(Error::config_invalid(
+            "system.infrastructure.resilience.circuit_breaker_timeout_secs",
+            "must be greater than zero when the circuit breaker is enabled",
+        ));
+    }
     Ok(())
 }
 
@@ -60,6 +114,16 @@ fn validate_limits_config(config: &AppConfig) -> Result<()> {
             "CPU limit cannot be 0".to_owned(),
         ));
     }
+    config
+        .system
+        .infrastructure
+        .jobs
+        .validate("system.infrastructure.jobs")?;
+    config
+        .system
+        .infrastructure
+        .bulkheads
+        .validate("system.infrastructure.bulkheads")?;
     Ok(())
 }
 
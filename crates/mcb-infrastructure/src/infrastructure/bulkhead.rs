@@ -0,0 +1,61 @@
+//!
+//! **Documentation**: [docs/modules/infrastructure.md](../../../../docs/modules/infrastructure.md)
+//!
+//! Bulkheads — Default Implementation
+//!
+//! One semaphore per workload, so indexing, search and admin work each wait
+//! only for their own pool.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::{BulkheadInterface, BulkheadPolicy, JobPermit, Workload};
+use tokio::sync::Semaphore;
+
+/// Semaphore-backed bulkhead pools.
+pub struct SemaphoreBulkheads {
+    search: Arc<Semaphore>,
+    indexing: Arc<Semaphore>,
+    admin: Arc<Semaphore>,
+}
+
+impl SemaphoreBulkheads {
+    /// Create pools sized by `policy`.
+    ///
+    /// # Errors
+    /// Returns a configuration error when the policy is invalid.
+    pub fn new(policy: BulkheadPolicy) -> Result<Self> {
+        policy.validate("system.infrastructure.bulkheads")?;
+        Ok(Self {
+            search: Arc::new(Semaphore::new(policy.search)),
+            indexing: Arc::new(Semaphore::new(policy.indexing)),
+            admin: Arc::new(Semaphore::new(policy.admin)),
+        })
+    }
+
+    fn pool(&self, workload: Workload) -> &Arc<Semaphore> {
+        match workload {
+            Workload::Search => &self.search,
+            Workload::Indexing => &self.indexing,
+            Workload::Admin => &self.admin,
+        }
+    }
+
+    /// Free room in the pool of `workload`.
+    #[must_use]
+    pub fn available(&self, workload: Workload) -> usize {
+        self.pool(workload).available_permits()
+    }
+}
+
+#[async_trait]
+impl BulkheadInterface for SemaphoreBulkheads {
+    async fn enter(&self, workload: Workload) -> JobPermit {
+        match Arc::clone(self.pool(workload)).acquire_owned().await {
+            Ok(permit) => JobPermit::new(permit),
+            // Pools are never closed; run unthrottled rather than never.
+            Err(_) => JobPermit::new(()),
+        }
+    }
+}
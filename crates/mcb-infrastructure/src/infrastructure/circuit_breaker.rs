@@ -0,0 +1,413 @@
+//!
+//! **Documentation**: [docs/modules/infrastructure.md](../../../../docs/modules/infrastructure.md)
+//!
+//! Circuit breaker around the vector store.
+//!
+//! When the vector store backend is down, every call would otherwise wait for
+//! the full request timeout. After repeated failures the breaker opens and
+//! calls fail immediately with `MCB-VS-UNAVAILABLE`. Once the open period has
+//! passed, calls are let through one at a time as probes: enough successful
+//! probes close the circuit, a failed one opens it again.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    StorageUsage, VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities,
+    VectorStoreProvider,
+};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
+};
+use serde_json::Value;
+use tokio::time::Instant;
+
+/// Thresholds of a [`CircuitBreakerVectorStoreProvider`].
+///
+/// Built from `system.infrastructure.resilience`; a `failure_threshold` of 0
+/// disables the breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitBreakerPolicy {
+    /// Consecutive failed calls that open the circuit.
+    pub failure_threshold: u32,
+    /// How long an open circuit fails fast before probing.
+    pub open_duration: Duration,
+    /// Consecutive successful probes that close the circuit again.
+    pub success_threshold: u32,
+}
+
+impl CircuitBreakerPolicy {
+    /// Whether the breaker is active.
+    #[must_use]
+    pub fn enabled(&self) -> bool {
+        self.failure_threshold > 0
+    }
+}
+
+/// Breaker state.
+#[derive(Debug, Clone, Copy)]
+enum State {
+    /// Calls pass; counts consecutive failures.
+    Closed { failures: u32 },
+    /// Calls fail fast until `until`.
+    Open { until: Instant },
+    /// Calls are let through one at a time as probes; others fail fast.
+    HalfOpen { successes: u32, probing: bool },
+}
+
+/// Permission to run one call through the breaker.
+///
+/// A probe dropped before [`Call::finish`] (e.g. a cancelled request) counts
+/// as failed, so the circuit cannot stay half-open forever.
+struct Call<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    finished: bool,
+}
+
+impl Call<'_> {
+    fn finish(mut self, healthy: bool) {
+        self.finished = true;
+        self.breaker.record(self.probe, healthy);
+    }
+}
+
+impl Drop for Call<'_> {
+    fn drop(&mut self) {
+        if self.probe && !self.finished {
+            self.breaker.record(true, false);
+        }
+    }
+}
+
+/// Consecutive-failure circuit breaker for one dependency.
+struct CircuitBreaker {
+    dependency: String,
+    policy: CircuitBreakerPolicy,
+    state: Mutex<State>,
+}
+
+impl CircuitBreaker {
+    fn new(dependency: String, policy: CircuitBreakerPolicy) -> Self {
+        Self {
+            dependency,
+            policy,
+            state: Mutex::new(State::Closed { failures: 0 }),
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Let a call through, or fail fast while the circuit is open.
+    fn enter(&self) -> Result<Call<'_>> {
+        let mut state = self.state();
+        let probe = match *state {
+            State::Closed { .. } => false,
+            State::Open { until } if Instant::now() >= until => {
+                *state = State::HalfOpen {
+                    successes: 0,
+                    probing: true,
+                };
+                true
+            }
+            State::HalfOpen {
+                successes,
+                probing: false,
+            } => {
+                *state = State::HalfOpen {
+                    successes,
+                    probing: true,
+                };
+                true
+            }
+            State::Open { until } => {
+                return Err(self.unavailable(&format!(
+                    "retry in {}s",
+                    until
+                        .saturating_duration_since(Instant::now())
+                        .as_secs()
+                        .max(1)
+                )));
+            }
+            State::HalfOpen { probing: true, .. } => {
+                return Err(self.unavailable("a probe call is in progress"));
+            }
+        };
+        Ok(Call {
+            breaker: self,
+            probe,
+            finished: false,
+        })
+    }
+
+    fn record(&self, probe: bool, healthy: bool) {
+        let mut state = self.state();
+        match (*state, healthy) {
+            (State::Closed { .. }, true) => *state = State::Closed { failures: 0 },
+            (State::Closed { failures }, false) => {
+                let failures = failures.saturating_add(1);
+                *state = if failures >= self.policy.failure_threshold {
+                    self.open(&format!("{failures} consecutive failures"))
+                } else {
+                    State::Closed { failures }
+                };
+            }
+            (State::HalfOpen { successes, .. }, true) if probe => {
+                let successes = successes.saturating_add(1);
+                *state = if successes >= self.policy.success_threshold {
+                    mcb_domain::info!(
+                        "CircuitBreaker",
+                        "Vector store circuit closed",
+                        &format!("provider={}", self.dependency)
+                    );
+                    State::Closed { failures: 0 }
+                } else {
+                    State::HalfOpen {
+                        successes,
+                        probing: false,
+                    }
+                };
+            }
+            (State::HalfOpen { .. }, false) if probe => *state = self.open("failed probe"),
+            // Calls started before the circuit opened do not change it.
+            (State::HalfOpen { .. } | State::Open { .. }, _) => {}
+        }
+    }
+
+    fn open(&self, reason: &str) -> State {
+        mcb_domain::warn!(
+            "CircuitBreaker",
+            "Vector store circuit opened",
+            &format!(
+                "provider={} reason={reason} open_secs={}",
+                self.dependency,
+                self.policy.open_duration.as_secs()
+            )
+        );
+        State::Open {
+            until: Instant::now() + self.policy.open_duration,
+        }
+    }
+
+    fn unavailable(&self, retry: &str) -> Error {
+        Error::vector_db(format!(
+            "vector store '{}' is unavailable (circuit open), {retry}",
+            self.dependency
+        ))
+    }
+
+    /// Run `call` through the breaker.
+    ///
+    /// Only retryable errors (timeouts, connection and backend failures)
+    /// count against the dependency; `NotFound`, invalid arguments and the
+    /// like mean it answered.
+    async fn run<T>(&self, call: impl Future<Output = Result<T>>) -> Result<T> {
+        let permit = self.enter()?;
+        let result = call.await;
+        permit.finish(result.as_ref().map_or_else(|e| !e.is_retryable(), |_| true));
+        result
+    }
+}
+
+/// Vector store decorator that fails fast while the backend is down.
+///
+/// Every call except `health_check`, `provider_name` and `capabilities` goes
+/// through the breaker; health checks always reach the backend so readiness
+/// reports its real state.
+pub struct CircuitBreakerVectorStoreProvider {
+    inner: Arc<dyn VectorStoreProvider>,
+    breaker: CircuitBreaker,
+}
+
+impl CircuitBreakerVectorStoreProvider {
+    /// Wrap `inner` with a breaker configured by `policy`
+    #[must_use]
+    pub fn new(inner: Arc<dyn VectorStoreProvider>, policy: CircuitBreakerPolicy) -> Self {
+        let breaker = CircuitBreaker::new(inner.provider_name().to_owned(), policy);
+        Self { inner, breaker }
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for CircuitBreakerVectorStoreProvider {
+    async fn collection_exists(&self, collection: &CollectionId) -> Result<bool> {
+        self.breaker
+            .run(self.inner.collection_exists(collection))
+            .await
+    }
+
+    async fn get_stats(&self, collection: &CollectionId) -> Result<HashMap<String, Value>> {
+        self.breaker.run(self.inner.get_stats(collection)).await
+    }
+
+    async fn flush(&self, collection: &CollectionId) -> Result<()> {
+        self.breaker.run(self.inner.flush(collection)).await
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.inner.health_check().await
+    }
+
+    async fn storage_usage(&self) -> Result<Option<StorageUsage>> {
+        self.breaker.run(self.inner.storage_usage()).await
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for CircuitBreakerVectorStoreProvider {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        self.breaker.run(self.inner.list_collections()).await
+    }
+
+    async fn list_file_paths(
+        &self,
+        collection: &CollectionId,
+        limit: usize,
+    ) -> Result<Vec<FileInfo>> {
+        self.breaker
+            .run(self.inner.list_file_paths(collection, limit))
+            .await
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        collection: &CollectionId,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        self.breaker
+            .run(self.inner.get_chunks_by_file(collection, file_path))
+            .await
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for CircuitBreakerVectorStoreProvider {
+    async fn create_collection(&self, collection: &CollectionId, dimensions: usize) -> Result<()> {
+        self.breaker
+            .run(self.inner.create_collection(collection, dimensions))
+            .await
+    }
+
+    async fn delete_collection(&self, collection: &CollectionId) -> Result<()> {
+        self.breaker
+            .run(self.inner.delete_collection(collection))
+            .await
+    }
+
+    async fn swap_alias(&self, alias: &CollectionId, collection: &CollectionId) -> Result<()> {
+        self.breaker
+            .run(self.inner.swap_alias(alias, collection))
+            .await
+    }
+
+    async fn delete_alias(&self, alias: &CollectionId) -> Result<()> {
+        self.breaker.run(self.inner.delete_alias(alias)).await
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &CollectionId,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.breaker
+            .run(self.inner.insert_vectors(collection, vectors, metadata))
+            .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.breaker
+            .run(
+                self.inner
+                    .upsert_vectors(collection, ids, vectors, metadata),
+            )
+            .await
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>> {
+        self.breaker
+            .run(
+                self.inner
+                    .search_similar(collection, query_vector, limit, filter),
+            )
+            .await
+    }
+
+    async fn search_similar_as_of(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchScope>,
+        as_of: i64,
+    ) -> Result<Vec<SearchResult>> {
+        self.breaker
+            .run(
+                self.inner
+                    .search_similar_as_of(collection, query_vector, limit, filter, as_of),
+            )
+            .await
+    }
+
+    async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()> {
+        self.breaker
+            .run(self.inner.delete_vectors(collection, ids))
+            .await
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        self.breaker
+            .run(self.inner.get_vectors_by_ids(collection, ids))
+            .await
+    }
+
+    async fn list_vectors(
+        &self,
+        collection: &CollectionId,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.breaker
+            .run(self.inner.list_vectors(collection, limit))
+            .await
+    }
+
+    async fn inspect_chunk(
+        &self,
+        collection: &CollectionId,
+        id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        self.breaker
+            .run(self.inner.inspect_chunk(collection, id))
+            .await
+    }
+}
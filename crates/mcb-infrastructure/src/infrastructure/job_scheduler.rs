@@ -0,0 +1,171 @@
+//!
+//! **Documentation**: [docs/modules/infrastructure.md](../../../../docs/modules/infrastructure.md)
+//!
+//! Job Scheduler — Default Implementation
+//!
+//! In-process admission control: a global slot limit, interactive work ahead
+//! of background work, and round-robin between organizations within the
+//! background class.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::{JobPermit, JobPriority, JobSchedulerInterface, JobSchedulerPolicy};
+use tokio::sync::oneshot;
+
+/// Fair in-process job scheduler.
+///
+/// Every admitted unit of work holds a slot until its [`JobPermit`] is
+/// dropped. Background work never takes the slots reserved for interactive
+/// work. When a slot frees up, the oldest waiting interactive request gets
+/// it; otherwise the next organization in turn admits its oldest background
+/// job.
+pub struct FairJobScheduler {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    policy: JobSchedulerPolicy,
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+struct State {
+    running: usize,
+    running_background: usize,
+    interactive: VecDeque<oneshot::Sender<Slot>>,
+    /// Waiting background jobs per organization.
+    background: HashMap<String, VecDeque<oneshot::Sender<Slot>>>,
+    /// Organizations with waiting background jobs, next in turn first.
+    turns: VecDeque<String>,
+}
+
+/// Releases its slot when dropped.
+struct Slot {
+    inner: Arc<Inner>,
+    priority: JobPriority,
+}
+
+impl FairJobScheduler {
+    /// Create a scheduler enforcing `policy`.
+    ///
+    /// # Errors
+    /// Returns a configuration error when the policy is invalid.
+    pub fn new(policy: JobSchedulerPolicy) -> Result<Self> {
+        policy.validate("system.infrastructure.jobs")?;
+        Ok(Self {
+            inner: Arc::new(Inner {
+                policy,
+                state: Mutex::new(State::default()),
+            }),
+        })
+    }
+}
+
+impl Inner {
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn has_room(&self, state: &State, priority: JobPriority) -> bool {
+        state.running < self.policy.max_concurrent
+            && match priority {
+                JobPriority::Interactive => true,
+                JobPriority::Background => {
+                    state.running_background < self.policy.background_slots()
+                }
+            }
+    }
+
+    fn admit(self: &Arc<Self>, state: &mut State, priority: JobPriority) -> Slot {
+        state.running += 1;
+        if priority == JobPriority::Background {
+            state.running_background += 1;
+        }
+        Slot {
+            inner: Arc::clone(self),
+            priority,
+        }
+    }
+
+    /// Hand free slots to waiters. Slots whose waiter gave up come back so
+    /// the caller can drop (and so release) them after unlocking.
+    fn dispatch(self: &Arc<Self>, state: &mut State) -> Vec<Slot> {
+        let mut abandoned = Vec::new();
+        loop {
+            let waiter = if self.has_room(state, JobPriority::Interactive)
+                && let Some(waiter) = state.interactive.pop_front()
+            {
+                Some((waiter, JobPriority::Interactive))
+            } else if self.has_room(state, JobPriority::Background) {
+                Self::next_background(state).map(|waiter| (waiter, JobPriority::Background))
+            } else {
+                None
+            };
+            let Some((waiter, priority)) = waiter else {
+                return abandoned;
+            };
+            let slot = self.admit(state, priority);
+            if let Err(slot) = waiter.send(slot) {
+                abandoned.push(slot);
+            }
+        }
+    }
+
+    /// Oldest waiting job of the organization whose turn it is.
+    fn next_background(state: &mut State) -> Option<oneshot::Sender<Slot>> {
+        let org = state.turns.pop_front()?;
+        let queue = state.background.get_mut(&org)?;
+        let waiter = queue.pop_front();
+        if queue.is_empty() {
+            state.background.remove(&org);
+        } else {
+            state.turns.push_back(org);
+        }
+        waiter
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        let abandoned = {
+            let mut state = self.inner.lock();
+            state.running = state.running.saturating_sub(1);
+            if self.priority == JobPriority::Background {
+                state.running_background = state.running_background.saturating_sub(1);
+            }
+            self.inner.dispatch(&mut state)
+        };
+        drop(abandoned);
+    }
+}
+
+#[async_trait]
+impl JobSchedulerInterface for FairJobScheduler {
+    async fn acquire(&self, org_id: &str, priority: JobPriority) -> JobPermit {
+        let (sender, receiver) = oneshot::channel();
+        let abandoned = {
+            let mut state = self.inner.lock();
+            match priority {
+                JobPriority::Interactive => state.interactive.push_back(sender),
+                JobPriority::Background => {
+                    let queue = state.background.entry(org_id.to_owned()).or_default();
+                    if queue.is_empty() {
+                        state.turns.push_back(org_id.to_owned());
+                    }
+                    queue.push_back(sender);
+                }
+            }
+            self.inner.dispatch(&mut state)
+        };
+        drop(abandoned);
+        match receiver.await {
+            Ok(slot) => JobPermit::new(slot),
+            // Senders only go away with the scheduler itself; run
+            // unthrottled rather than never.
+            Err(_) => JobPermit::new(()),
+        }
+    }
+}
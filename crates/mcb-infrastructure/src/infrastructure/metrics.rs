@@ -0,0 +1,408 @@
+//!
+//! **Documentation**: [docs/modules/metrics.md](../../../../docs/modules/metrics.md)
+//!
+//! In-Memory Metrics — Default Implementation
+//!
+//! Process-local counters, gauges and windowed histograms using `DashMap`,
+//! plus decorators that time embedding and vector store calls into them.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use mcb_domain::error::Result;
+use mcb_domain::ports::{
+    EmbeddingProvider, HistogramSummary, MetricLabels, MetricSample, MetricsProvider,
+    MetricsProviderExt, MetricsResult, MetricsSnapshot, MetricsSnapshotProvider, StorageUsage,
+    VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
+};
+use mcb_utils::constants::limits::METRICS_HISTOGRAM_WINDOW;
+use serde_json::Value;
+
+/// Series identity: metric name plus labels sorted by key.
+type SeriesKey = (String, Vec<(String, String)>);
+
+fn series_key(name: &str, labels: &MetricLabels) -> SeriesKey {
+    let mut pairs: Vec<(String, String)> =
+        labels.iter().map(|(k, v)| (k.clone(), v.clone())).collect();
+    pairs.sort();
+    (name.to_owned(), pairs)
+}
+
+fn series_labels(pairs: &[(String, String)]) -> MetricLabels {
+    pairs.iter().cloned().collect()
+}
+
+/// Nearest-rank percentile over an ascending slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// In-memory metrics recorder
+///
+/// Histograms keep the most recent [`METRICS_HISTOGRAM_WINDOW`] observations
+/// per series, so percentiles reflect recent behaviour rather than uptime.
+pub struct InMemoryMetricsProvider {
+    counters: DashMap<SeriesKey, f64>,
+    gauges: DashMap<SeriesKey, f64>,
+    histograms: DashMap<SeriesKey, VecDeque<f64>>,
+    window: usize,
+}
+
+impl InMemoryMetricsProvider {
+    /// Create a recorder with the default histogram window
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_window(METRICS_HISTOGRAM_WINDOW)
+    }
+
+    /// Create a recorder keeping `window` observations per histogram series
+    #[must_use]
+    pub fn with_window(window: usize) -> Self {
+        Self {
+            counters: DashMap::new(),
+            gauges: DashMap::new(),
+            histograms: DashMap::new(),
+            window: window.max(1),
+        }
+    }
+
+    fn samples(map: &DashMap<SeriesKey, f64>) -> Vec<MetricSample> {
+        let mut samples: Vec<MetricSample> = map
+            .iter()
+            .map(|entry| MetricSample {
+                name: entry.key().0.clone(),
+                labels: series_labels(&entry.key().1),
+                value: *entry.value(),
+            })
+            .collect();
+        samples.sort_by(|a, b| a.name.cmp(&b.name));
+        samples
+    }
+}
+
+impl Default for InMemoryMetricsProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl MetricsProvider for InMemoryMetricsProvider {
+    fn name(&self) -> &str {
+        mcb_utils::constants::DEFAULT_METRICS_PROVIDER
+    }
+
+    async fn increment(&self, name: &str, labels: &MetricLabels) -> MetricsResult<()> {
+        self.increment_by(name, 1.0, labels).await
+    }
+
+    async fn increment_by(
+        &self,
+        name: &str,
+        value: f64,
+        labels: &MetricLabels,
+    ) -> MetricsResult<()> {
+        *self.counters.entry(series_key(name, labels)).or_insert(0.0) += value;
+        Ok(())
+    }
+
+    async fn gauge(&self, name: &str, value: f64, labels: &MetricLabels) -> MetricsResult<()> {
+        self.gauges.insert(series_key(name, labels), value);
+        Ok(())
+    }
+
+    async fn histogram(&self, name: &str, value: f64, labels: &MetricLabels) -> MetricsResult<()> {
+        let mut window = self.histograms.entry(series_key(name, labels)).or_default();
+        if window.len() == self.window {
+            window.pop_front();
+        }
+        window.push_back(value);
+        Ok(())
+    }
+}
+
+impl MetricsSnapshotProvider for InMemoryMetricsProvider {
+    fn snapshot(&self) -> MetricsSnapshot {
+        let mut histograms: Vec<HistogramSummary> = self
+            .histograms
+            .iter()
+            .map(|entry| {
+                let mut sorted: Vec<f64> = entry.value().iter().copied().collect();
+                sorted.sort_by(f64::total_cmp);
+                HistogramSummary {
+                    name: entry.key().0.clone(),
+                    labels: series_labels(&entry.key().1),
+                    count: sorted.len() as u64,
+                    p50: percentile(&sorted, 0.50),
+                    p95: percentile(&sorted, 0.95),
+                }
+            })
+            .collect();
+        histograms.sort_by(|a, b| a.name.cmp(&b.name));
+        MetricsSnapshot {
+            counters: Self::samples(&self.counters),
+            gauges: Self::samples(&self.gauges),
+            histograms,
+        }
+    }
+}
+
+mcb_domain::register_metrics_provider!(
+    mcb_utils::constants::DEFAULT_METRICS_PROVIDER,
+    "In-memory metrics recorder",
+    |_config| Ok(Arc::new(InMemoryMetricsProvider::default())),
+);
+
+/// Embedding provider decorator that records call latency.
+pub struct InstrumentedEmbeddingProvider {
+    inner: Arc<dyn EmbeddingProvider>,
+    metrics: Arc<dyn MetricsProvider>,
+}
+
+impl InstrumentedEmbeddingProvider {
+    /// Wrap `inner`, recording `mcb_embedding_duration_seconds` into `metrics`
+    #[must_use]
+    pub fn new(inner: Arc<dyn EmbeddingProvider>, metrics: Arc<dyn MetricsProvider>) -> Self {
+        Self { inner, metrics }
+    }
+
+    async fn observe(&self, started: Instant) {
+        if let Err(e) = self
+            .metrics
+            .record_embedding_latency(started.elapsed(), self.inner.provider_name())
+            .await
+        {
+            mcb_domain::warn!("metrics", "failed to record embedding latency", &e);
+        }
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for InstrumentedEmbeddingProvider {
+    async fn embed(&self, text: &str) -> Result<Embedding> {
+        let started = Instant::now();
+        let result = self.inner.embed(text).await;
+        self.observe(started).await;
+        result
+    }
+
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        let started = Instant::now();
+        let result = self.inner.embed_batch(texts).await;
+        self.observe(started).await;
+        result
+    }
+
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.inner.health_check().await
+    }
+}
+
+/// Vector store decorator that records latency of data-path calls.
+///
+/// Admin and browse calls are delegated untimed.
+pub struct InstrumentedVectorStoreProvider {
+    inner: Arc<dyn VectorStoreProvider>,
+    metrics: Arc<dyn MetricsProvider>,
+}
+
+impl InstrumentedVectorStoreProvider {
+    /// Wrap `inner`, recording `mcb_vector_store_duration_seconds` into `metrics`
+    #[must_use]
+    pub fn new(inner: Arc<dyn VectorStoreProvider>, metrics: Arc<dyn MetricsProvider>) -> Self {
+        Self { inner, metrics }
+    }
+
+    async fn observe(&self, operation: &str, started: Instant) {
+        if let Err(e) = self
+            .metrics
+            .record_vector_store_latency(started.elapsed(), self.inner.provider_name(), operation)
+            .await
+        {
+            mcb_domain::warn!("metrics", "failed to record vector store latency", &e);
+        }
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for InstrumentedVectorStoreProvider {
+    async fn collection_exists(&self, collection: &CollectionId) -> Result<bool> {
+        self.inner.collection_exists(collection).await
+    }
+
+    async fn get_stats(&self, collection: &CollectionId) -> Result<HashMap<String, Value>> {
+        self.inner.get_stats(collection).await
+    }
+
+    async fn flush(&self, collection: &CollectionId) -> Result<()> {
+        self.inner.flush(collection).await
+    }
+
+    fn provider_name(&self) -> &str {
+        self.inner.provider_name()
+    }
+
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn health_check(&self) -> Result<()> {
+        self.inner.health_check().await
+    }
+
+    async fn storage_usage(&self) -> Result<Option<StorageUsage>> {
+        self.inner.storage_usage().await
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for InstrumentedVectorStoreProvider {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        self.inner.list_collections().await
+    }
+
+    async fn list_file_paths(
+        &self,
+        collection: &CollectionId,
+        limit: usize,
+    ) -> Result<Vec<FileInfo>> {
+        self.inner.list_file_paths(collection, limit).await
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        collection: &CollectionId,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        self.inner.get_chunks_by_file(collection, file_path).await
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for InstrumentedVectorStoreProvider {
+    async fn create_collection(&self, collection: &CollectionId, dimensions: usize) -> Result<()> {
+        self.inner.create_collection(collection, dimensions).await
+    }
+
+    async fn delete_collection(&self, collection: &CollectionId) -> Result<()> {
+        self.inner.delete_collection(collection).await
+    }
+
+    async fn swap_alias(&self, alias: &CollectionId, collection: &CollectionId) -> Result<()> {
+        self.inner.swap_alias(alias, collection).await
+    }
+
+    async fn delete_alias(&self, alias: &CollectionId) -> Result<()> {
+        self.inner.delete_alias(alias).await
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &CollectionId,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        let started = Instant::now();
+        let result = self
+            .inner
+            .insert_vectors(collection, vectors, metadata)
+            .await;
+        self.observe("insert", started).await;
+        result
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        let started = Instant::now();
+        let result = self
+            .inner
+            .upsert_vectors(collection, ids, vectors, metadata)
+            .await;
+        self.observe("upsert", started).await;
+        result
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>> {
+        let started = Instant::now();
+        let result = self
+            .inner
+            .search_similar(collection, query_vector, limit, filter)
+            .await;
+        self.observe("search", started).await;
+        result
+    }
+
+    async fn search_similar_as_of(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchScope>,
+        as_of: i64,
+    ) -> Result<Vec<SearchResult>> {
+        let started = Instant::now();
+        let result = self
+            .inner
+            .search_similar_as_of(collection, query_vector, limit, filter, as_of)
+            .await;
+        self.observe("search", started).await;
+        result
+    }
+
+    async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()> {
+        self.inner.delete_vectors(collection, ids).await
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        self.inner.get_vectors_by_ids(collection, ids).await
+    }
+
+    async fn list_vectors(
+        &self,
+        collection: &CollectionId,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.inner.list_vectors(collection, limit).await
+    }
+
+    async fn inspect_chunk(
+        &self,
+        collection: &CollectionId,
+        id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        self.inner.inspect_chunk(collection, id).await
+    }
+}
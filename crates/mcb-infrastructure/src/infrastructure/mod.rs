@@ -4,15 +4,25 @@
 //! Infrastructure service implementations for port traits defined in mcb-domain.
 //! Concrete types are composed in the DI bootstrap module or `loco_app.rs`.
 
+pub mod bulkhead;
+pub mod circuit_breaker;
 pub mod events;
 pub mod indexing;
+pub mod job_scheduler;
+pub mod metrics;
 
 /// DI-resolved database migrator (CA pattern via domain registry).
 pub mod migration;
 pub mod validation_ops;
 pub mod validator_job_runner;
 
+pub use bulkhead::SemaphoreBulkheads;
+pub use circuit_breaker::{CircuitBreakerPolicy, CircuitBreakerVectorStoreProvider};
 pub use indexing::DefaultIndexingOperations;
+pub use job_scheduler::FairJobScheduler;
+pub use metrics::{
+    InMemoryMetricsProvider, InstrumentedEmbeddingProvider, InstrumentedVectorStoreProvider,
+};
 pub use migration::DynamicMigrator;
 pub use validation_ops::DefaultValidationOperations;
 pub use validator_job_runner::DefaultValidatorJobRunner;
@@ -0,0 +1,316 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Change Summary Service Use Case
+//!
+//! # Overview
+//! The `ChangeSummaryService` turns file-level changes into symbol-level ones.
+//! Both versions of every changed file are run through the language chunker
+//! and their definitions are matched by node type and name, so the summary
+//! lists the functions, classes and other definitions that were added,
+//! removed or modified.
+//!
+//! Two versions can be compared: two revisions, using the VCS diff to find
+//! changed files, or the content indexed in a collection against the working
+//! tree. In the latter case the indexed side is the stored snapshot when
+//! snapshots are enabled and is rebuilt from chunks otherwise.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::entities::vcs::DiffStatus;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    ChangeBaseline, ChangeSummary, ChangeSummaryServiceInterface, FileChangeSummary,
+    FileContentServiceInterface, FileReadRequest, LanguageChunkingProvider, SymbolChange,
+    SymbolChangeKind, VcsProvider, VectorStoreProvider,
+};
+use mcb_domain::value_objects::CollectionId;
+use mcb_utils::constants::search::READ_FILE_MAX_BYTES_LIMIT;
+use mcb_utils::constants::vcs::CHANGE_SUMMARY_MAX_FILES;
+
+use super::search_service::SearchServiceImpl;
+
+/// A definition extracted from one version of a file.
+struct Symbol<'a> {
+    name: &'a str,
+    kind: &'a str,
+    chunk: &'a CodeChunk,
+}
+
+/// Implementation of the `ChangeSummaryServiceInterface`.
+pub struct ChangeSummaryServiceImpl {
+    vcs: Arc<dyn VcsProvider>,
+    chunker: Arc<dyn LanguageChunkingProvider>,
+    vector_store: Arc<dyn VectorStoreProvider>,
+    file_content: Arc<dyn FileContentServiceInterface>,
+}
+
+impl ChangeSummaryServiceImpl {
+    /// Create new change summary service with injected dependencies
+    pub fn new(
+        vcs: Arc<dyn VcsProvider>,
+        chunker: Arc<dyn LanguageChunkingProvider>,
+        vector_store: Arc<dyn VectorStoreProvider>,
+        file_content: Arc<dyn FileContentServiceInterface>,
+    ) -> Self {
+        Self {
+            vcs,
+            chunker,
+            vector_store,
+            file_content,
+        }
+    }
+
+    /// Compare the definitions of two chunked versions of a file.
+    ///
+    /// Definitions are keyed by node type and name; repeated keys (e.g. `new`
+    /// in two `impl` blocks) are paired in source order. Chunks without a
+    /// node type or a recognizable name are ignored. Results are sorted by
+    /// start line.
+    #[must_use]
+    pub fn diff_symbols(old: &[CodeChunk], new: &[CodeChunk]) -> Vec<SymbolChange> {
+        let mut old_symbols = Self::symbols(old);
+        let mut changes = Vec::new();
+        for (key, symbol) in Self::symbols(new) {
+            let change = match old_symbols.remove(&key) {
+                None => SymbolChangeKind::Added,
+                Some(previous) if previous.chunk.content.trim() != symbol.chunk.content.trim() => {
+                    SymbolChangeKind::Modified
+                }
+                Some(_) => continue,
+            };
+            changes.push(Self::change(&symbol, change));
+        }
+        changes.extend(
+            old_symbols
+                .values()
+                .map(|symbol| Self::change(symbol, SymbolChangeKind::Removed)),
+        );
+        changes.sort_by_key(|c| (c.start_line, c.end_line));
+        changes
+    }
+
+    fn symbols(chunks: &[CodeChunk]) -> HashMap<(String, usize), Symbol<'_>> {
+        let mut seen: HashMap<(&str, &str), usize> = HashMap::new();
+        let mut symbols = HashMap::new();
+        for chunk in chunks {
+            let Some(kind) = chunk.metadata.get("node_type").and_then(|v| v.as_str()) else {
+                continue;
+            };
+            let Some(name) = SearchServiceImpl::symbol_name(&chunk.content) else {
+                continue;
+            };
+            let occurrence = seen.entry((kind, name)).or_default();
+            symbols.insert(
+                (format!("{kind}:{name}"), *occurrence),
+                Symbol { name, kind, chunk },
+            );
+            *occurrence += 1;
+        }
+        symbols
+    }
+
+    fn change(symbol: &Symbol<'_>, change: SymbolChangeKind) -> SymbolChange {
+        SymbolChange {
+            name: symbol.name.to_owned(),
+            kind: symbol.kind.to_owned(),
+            change,
+            start_line: symbol.chunk.start_line,
+            end_line: symbol.chunk.end_line,
+        }
+    }
+
+    fn supports(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|e| e.to_str())
+            .is_some_and(|ext| self.chunker.supports_extension(ext))
+    }
+
+    /// Chunk both versions of `path` and record the file if any symbol changed.
+    fn summarize_file(
+        &self,
+        files: &mut Vec<FileChangeSummary>,
+        path: String,
+        status: DiffStatus,
+        old: Option<&str>,
+        new: Option<&str>,
+    ) {
+        let old = old.map_or_else(Vec::new, |c| self.chunker.chunk(c, &path));
+        let new = new.map_or_else(Vec::new, |c| self.chunker.chunk(c, &path));
+        let symbols = Self::diff_symbols(&old, &new);
+        if !symbols.is_empty() {
+            files.push(FileChangeSummary {
+                path,
+                status: status.to_string(),
+                symbols,
+            });
+        }
+    }
+
+    async fn between_refs(
+        &self,
+        repo_root: &Path,
+        base_ref: &str,
+        head_ref: &str,
+    ) -> Result<Vec<FileChangeSummary>> {
+        let repo = self.vcs.open_repository(repo_root).await?;
+        let diff = self.vcs.diff_refs(&repo, base_ref, head_ref).await?;
+        let mut files = Vec::new();
+        for file in diff.files.iter().filter(|f| self.supports(&f.path)) {
+            // Binary or undecodable versions read as absent.
+            let old = if file.status == DiffStatus::Added {
+                None
+            } else {
+                self.vcs.read_file(&repo, base_ref, &file.path).await.ok()
+            };
+            let new = if file.status == DiffStatus::Deleted {
+                None
+            } else {
+                self.vcs.read_file(&repo, head_ref, &file.path).await.ok()
+            };
+            let path = file.path.to_string_lossy().into_owned();
+            self.summarize_file(
+                &mut files,
+                path,
+                file.status,
+                old.as_deref(),
+                new.as_deref(),
+            );
+        }
+        Ok(files)
+    }
+
+    async fn since_index(
+        &self,
+        repo_root: &Path,
+        collection: &CollectionId,
+    ) -> Result<Vec<FileChangeSummary>> {
+        let indexed = self
+            .vector_store
+            .list_file_paths(collection, CHANGE_SUMMARY_MAX_FILES)
+            .await?;
+        let mut indexed_paths = HashSet::new();
+        let mut files = Vec::new();
+        for info in indexed {
+            let request = FileReadRequest {
+                path: info.path.clone(),
+                start_line: None,
+                end_line: None,
+                max_bytes: READ_FILE_MAX_BYTES_LIMIT,
+                repo_root: None,
+            };
+            let old = self.file_content.read_file(collection, &request).await?;
+            let new = tokio::fs::read_to_string(repo_root.join(&info.path))
+                .await
+                .ok();
+            let status = if new.is_some() {
+                DiffStatus::Modified
+            } else {
+                DiffStatus::Deleted
+            };
+            indexed_paths.insert(info.path.clone());
+            self.summarize_file(
+                &mut files,
+                info.path,
+                status,
+                Some(&old.content),
+                new.as_deref(),
+            );
+        }
+
+        let repo = self.vcs.open_repository(repo_root).await?;
+        for path in self.vcs.list_files(&repo, repo.default_branch()).await? {
+            let relative = path.to_string_lossy().into_owned();
+            if indexed_paths.contains(&relative) || !self.supports(&path) {
+                continue;
+            }
+            let Ok(content) = tokio::fs::read_to_string(repo_root.join(&path)).await else {
+                continue;
+            };
+            self.summarize_file(
+                &mut files,
+                relative,
+                DiffStatus::Added,
+                None,
+                Some(&content),
+            );
+        }
+        Ok(files)
+    }
+}
+
+#[async_trait::async_trait]
+impl ChangeSummaryServiceInterface for ChangeSummaryServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be opened, a revision cannot
+    /// be resolved, or the collection cannot be read.
+    async fn summarize_changes(
+        &self,
+        repo_root: &Path,
+        baseline: &ChangeBaseline,
+    ) -> Result<ChangeSummary> {
+        let (base, head, mut files) = match baseline {
+            ChangeBaseline::Refs { base_ref, head_ref } => (
+                base_ref.clone(),
+                head_ref.clone(),
+                self.between_refs(repo_root, base_ref, head_ref).await?,
+            ),
+            ChangeBaseline::LastIndex(collection) => (
+                format!("index:{}", collection.as_str()),
+                "working_tree".to_owned(),
+                self.since_index(repo_root, collection).await?,
+            ),
+        };
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let count = |kind| {
+            files
+                .iter()
+                .flat_map(|f| &f.symbols)
+                .filter(|s| s.change == kind)
+                .count()
+        };
+        Ok(ChangeSummary {
+            added: count(SymbolChangeKind::Added),
+            removed: count(SymbolChangeKind::Removed),
+            modified: count(SymbolChangeKind::Modified),
+            base,
+            head,
+            files,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::language::{LanguageProviderConfig, resolve_language_provider};
+use mcb_domain::registry::services::{ServiceBuilder, resolve_file_content_service};
+use mcb_domain::registry::vcs::{VcsProviderConfig, resolve_vcs_provider};
+use mcb_utils::constants::{DEFAULT_LANGUAGE_PROVIDER, DEFAULT_VCS_PROVIDER};
+
+/// Build a `ChangeSummaryService` from the service resolution context.
+fn build_change_summary_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn ChangeSummaryServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Change summary service builder requires ServiceResolutionContext")
+        })?;
+    Ok(Arc::new(ChangeSummaryServiceImpl::new(
+        resolve_vcs_provider(&VcsProviderConfig::new(DEFAULT_VCS_PROVIDER))?,
+        resolve_language_provider(&LanguageProviderConfig::new(DEFAULT_LANGUAGE_PROVIDER))?,
+        Arc::clone(&ctx.vector_store_provider),
+        resolve_file_content_service(context)?,
+    )))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_CHANGE_SUMMARY,
+    ServiceBuilder::ChangeSummary(build_change_summary_service_from_registry),
+);
@@ -2,17 +2,32 @@
 //!
 //! Implements `ContextServiceInterface` using direct provider calls.
 //! No cache layer, no wrappers — embedding + vector store only.
+//!
+//! With title vectors enabled, each chunk that names a symbol also gets a
+//! short "symbol name + signature" embedding in a sibling collection, and
+//! searches fuse both similarities so exact-name queries rank higher.
+//!
+//! Vectors are prepared for the configured distance metric (normalized for
+//! cosine) before they are stored or searched, and a collection whose
+//! manifest records a different metric is rejected instead of mis-scored.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
 use mcb_domain::entities::CodeChunk;
-use mcb_domain::error::Result;
-use mcb_domain::ports::{ContextServiceInterface, EmbeddingProvider, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionId, Embedding, SearchResult};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    CollectionManifestRepository, ContextServiceInterface, EmbeddingProvider, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{
+    CollectionId, DistanceMetric, Embedding, SearchResult, SearchScope,
+};
 use mcb_utils::constants::keys::{
     METADATA_KEY_CONTENT, METADATA_KEY_END_LINE, METADATA_KEY_FILE_PATH, METADATA_KEY_LANGUAGE,
-    METADATA_KEY_START_LINE,
+    METADATA_KEY_LAST_MODIFIED, METADATA_KEY_START_LINE,
+};
+use mcb_utils::constants::search::{
+    TITLE_COLLECTION_SUFFIX, TITLE_VECTOR_OVERFETCH_MULTIPLIER, TITLE_VECTOR_WEIGHT,
 };
 use serde_json::Value;
 
@@ -20,6 +35,10 @@ use serde_json::Value;
 pub struct ContextServiceImpl {
     embedding_provider: Arc<dyn EmbeddingProvider>,
     vector_store_provider: Arc<dyn VectorStoreProvider>,
+    title_vectors: bool,
+    distance_metric: DistanceMetric,
+    embedding_model: Option<String>,
+    collection_manifest_repository: Option<Arc<dyn CollectionManifestRepository>>,
 }
 
 impl ContextServiceImpl {
@@ -31,13 +50,132 @@ impl ContextServiceImpl {
         Self {
             embedding_provider,
             vector_store_provider,
+            title_vectors: false,
+            distance_metric: DistanceMetric::default(),
+            embedding_model: None,
+            collection_manifest_repository: None,
         }
     }
-}
 
-#[async_trait::async_trait]
-impl ContextServiceInterface for ContextServiceImpl {
-    async fn initialize(&self, collection: &CollectionId) -> Result<()> {
+    /// Name the configured embedding model; defaults to the provider name.
+    #[must_use]
+    pub fn with_embedding_model(mut self, model: Option<String>) -> Self {
+        self.embedding_model = model;
+        self
+    }
+
+    /// Store and search vectors for `metric`.
+    #[must_use]
+    pub fn with_distance_metric(mut self, metric: DistanceMetric) -> Self {
+        self.distance_metric = metric;
+        self
+    }
+
+    /// Check each collection's recorded distance metric before using it.
+    #[must_use]
+    pub fn with_collection_manifest_repository(
+        mut self,
+        collection_manifest_repository: Arc<dyn CollectionManifestRepository>,
+    ) -> Self {
+        self.collection_manifest_repository = Some(collection_manifest_repository);
+        self
+    }
+
+    /// Fail if `collection` was built for a different distance metric.
+    async fn ensure_distance_metric(&self, collection: &CollectionId) -> Result<()> {
+        let Some(manifests) = &self.collection_manifest_repository else {
+            return Ok(());
+        };
+        let Some(manifest) = manifests.get_manifest(&collection.to_string()).await? else {
+            return Ok(());
+        };
+        if manifest.distance_metric == self.distance_metric {
+            return Ok(());
+        }
+        Err(Error::config_invalid(
+            "providers.vector_store.distance_metric",
+            format!(
+                "Collection '{collection}' was built for the {} metric but {} is configured; clear the index and re-index, or restore the previous metric",
+                manifest.distance_metric, self.distance_metric
+            ),
+        ))
+    }
+
+    /// Prepare every vector of `embeddings` for the configured metric.
+    fn prepare_embeddings(&self, embeddings: &mut [Embedding]) {
+        for embedding in embeddings {
+            self.distance_metric.prepare(&mut embedding.vector);
+        }
+    }
+
+    /// Also store and search a title (symbol name + signature) vector per chunk.
+    #[must_use]
+    pub fn with_title_vectors(mut self, enabled: bool) -> Self {
+        self.title_vectors = enabled;
+        self
+    }
+
+    /// Sibling collection holding the title vectors of `collection`.
+    #[must_use]
+    pub fn title_collection(collection: &CollectionId) -> CollectionId {
+        CollectionId::from_name(&format!("{collection}{TITLE_COLLECTION_SUFFIX}"))
+    }
+
+    /// Merge content and title hits by ID, scoring each chunk
+    /// `(content + weight * title) / (1 + weight)`.
+    ///
+    /// A chunk missing from one list scores 0 there. Content hits supply the
+    /// result payload; title-only hits keep their own.
+    #[must_use]
+    pub fn fuse_title_scores(
+        content: Vec<SearchResult>,
+        titles: Vec<SearchResult>,
+        weight: f64,
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        let mut title_scores: HashMap<String, f64> = HashMap::new();
+        let mut title_only: HashMap<String, SearchResult> = HashMap::new();
+        for hit in titles {
+            title_scores.insert(hit.id.clone(), hit.score);
+            title_only.insert(hit.id.clone(), hit);
+        }
+
+        let mut fused: Vec<SearchResult> = content
+            .into_iter()
+            .map(|mut hit| {
+                title_only.remove(&hit.id);
+                let title = title_scores.get(&hit.id).copied().unwrap_or(0.0);
+                hit.score = (hit.score + weight * title) / (1.0 + weight);
+                hit
+            })
+            .collect();
+        fused.extend(title_only.into_values().map(|mut hit| {
+            hit.score = weight * hit.score / (1.0 + weight);
+            hit
+        }));
+
+        fused.sort_by(|a, b| b.score.total_cmp(&a.score).then_with(|| a.id.cmp(&b.id)));
+        fused.truncate(limit);
+        fused
+    }
+
+    /// Title collection to search alongside `collection`, if there is one.
+    async fn searchable_title_collection(
+        &self,
+        collection: &CollectionId,
+    ) -> Result<Option<CollectionId>> {
+        if !self.title_vectors {
+            return Ok(None);
+        }
+        let titles = Self::title_collection(collection);
+        let exists = self
+            .vector_store_provider
+            .collection_exists(&titles)
+            .await?;
+        Ok(exists.then_some(titles))
+    }
+
+    async fn ensure_collection(&self, collection: &CollectionId) -> Result<()> {
         let exists = self
             .vector_store_provider
             .collection_exists(collection)
@@ -51,13 +189,93 @@ impl ContextServiceInterface for ContextServiceImpl {
         Ok(())
     }
 
+    /// Embed and upsert the titles of `chunks` that have one.
+    async fn store_titles(
+        &self,
+        collection: &CollectionId,
+        chunks: &[CodeChunk],
+        ids: &[String],
+        metadata: &[HashMap<String, Value>],
+    ) -> Result<()> {
+        let mut texts = Vec::new();
+        let mut title_ids = Vec::new();
+        let mut title_metadata = Vec::new();
+        for ((chunk, id), meta) in chunks.iter().zip(ids).zip(metadata) {
+            if let Some(title) = chunk.title() {
+                texts.push(title);
+                title_ids.push(id.clone());
+                title_metadata.push(meta.clone());
+            }
+        }
+        if texts.is_empty() {
+            return Ok(());
+        }
+
+        let titles = Self::title_collection(collection);
+        self.ensure_collection(&titles).await?;
+        let mut embeddings = self.embedding_provider.embed_batch(&texts).await?;
+        self.prepare_embeddings(&mut embeddings);
+        self.vector_store_provider
+            .upsert_vectors(&titles, &title_ids, &embeddings, title_metadata)
+            .await?;
+        Ok(())
+    }
+
+    async fn search_with_titles(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        scope: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>> {
+        self.ensure_distance_metric(collection).await?;
+        let mut query_vector = query_vector.to_vec();
+        self.distance_metric.prepare(&mut query_vector);
+        let query_vector = query_vector.as_slice();
+
+        let Some(titles) = self.searchable_title_collection(collection).await? else {
+            return self
+                .vector_store_provider
+                .search_similar(collection, query_vector, limit, scope)
+                .await;
+        };
+
+        let fetch = limit.saturating_mul(TITLE_VECTOR_OVERFETCH_MULTIPLIER);
+        let (content, title_hits) = tokio::try_join!(
+            self.vector_store_provider
+                .search_similar(collection, query_vector, fetch, scope),
+            self.vector_store_provider
+                .search_similar(&titles, query_vector, fetch, scope),
+        )?;
+        Ok(Self::fuse_title_scores(
+            content,
+            title_hits,
+            TITLE_VECTOR_WEIGHT,
+            limit,
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl ContextServiceInterface for ContextServiceImpl {
+    async fn initialize(&self, collection: &CollectionId) -> Result<()> {
+        self.ensure_collection(collection).await?;
+        if self.title_vectors {
+            self.ensure_collection(&Self::title_collection(collection))
+                .await?;
+        }
+        Ok(())
+    }
+
     async fn store_chunks(&self, collection: &CollectionId, chunks: &[CodeChunk]) -> Result<()> {
         if chunks.is_empty() {
             return Ok(());
         }
 
+        self.ensure_distance_metric(collection).await?;
         let texts: Vec<String> = chunks.iter().map(|c| c.content.clone()).collect();
-        let embeddings = self.embedding_provider.embed_batch(&texts).await?;
+        let mut embeddings = self.embedding_provider.embed_batch(&texts).await?;
+        self.prepare_embeddings(&mut embeddings);
 
         let metadata: Vec<HashMap<String, Value>> = chunks
             .iter()
@@ -85,12 +303,22 @@ impl ContextServiceInterface for ContextServiceImpl {
                         Value::String(chunk.language.clone()),
                     );
                 }
+                if let Some(time) = chunk.metadata.get(METADATA_KEY_LAST_MODIFIED) {
+                    m.insert(METADATA_KEY_LAST_MODIFIED.to_owned(), time.clone());
+                }
                 m
             })
             .collect();
 
+        // Stable IDs make re-indexing replace unchanged chunks instead of
+        // storing them twice.
+        let ids: Vec<String> = chunks.iter().map(CodeChunk::stable_id).collect();
+        if self.title_vectors {
+            self.store_titles(collection, chunks, &ids, &metadata)
+                .await?;
+        }
         self.vector_store_provider
-            .insert_vectors(collection, &embeddings, metadata)
+            .upsert_vectors(collection, &ids, &embeddings, metadata)
             .await?;
 
         Ok(())
@@ -101,10 +329,21 @@ impl ContextServiceInterface for ContextServiceImpl {
         collection: &CollectionId,
         query: &str,
         limit: usize,
+        scope: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
         let embedding = self.embedding_provider.embed(query).await?;
-        self.vector_store_provider
-            .search_similar(collection, &embedding.vector, limit, None)
+        self.search_with_titles(collection, &embedding.vector, limit, scope)
+            .await
+    }
+
+    async fn search_by_vector(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        scope: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>> {
+        self.search_with_titles(collection, query_vector, limit, scope)
             .await
     }
 
@@ -113,6 +352,17 @@ impl ContextServiceInterface for ContextServiceImpl {
     }
 
     async fn clear_collection(&self, collection: &CollectionId) -> Result<()> {
+        // Titles are dropped even when disabled now, so none outlive a re-index.
+        let titles = Self::title_collection(collection);
+        if self
+            .vector_store_provider
+            .collection_exists(&titles)
+            .await?
+        {
+            self.vector_store_provider
+                .delete_collection(&titles)
+                .await?;
+        }
         self.vector_store_provider
             .delete_collection(collection)
             .await
@@ -125,6 +375,18 @@ impl ContextServiceInterface for ContextServiceImpl {
     fn embedding_dimensions(&self) -> usize {
         self.embedding_provider.dimensions()
     }
+
+    fn distance_metric(&self) -> DistanceMetric {
+        self.distance_metric
+    }
+
+    fn embedding_model(&self) -> String {
+        let provider = self.embedding_provider.provider_name();
+        match &self.embedding_model {
+            Some(model) => format!("{provider}/{model}"),
+            None => provider.to_owned(),
+        }
+    }
 }
 
 mcb_domain::register_service!(
@@ -138,9 +400,36 @@ mcb_domain::register_service!(
                 )
             })?;
 
+        let app_config = ctx
+            .config
+            .downcast_ref::<crate::config::app::AppConfig>()
+            .ok_or_else(|| {
+                mcb_domain::error::Error::internal(
+                    "Context service requires AppConfig in resolution context",
+                )
+            })?;
+
         let embedding = Arc::clone(&ctx.embedding_provider);
         let vector_store = Arc::clone(&ctx.vector_store_provider);
+        // Use "seaorm" — the actual registry provider — not the user-facing config name.
+        let repositories = mcb_domain::registry::database::resolve_database_repositories(
+            mcb_utils::constants::DEFAULT_DATABASE_PROVIDER,
+            Arc::clone(&ctx.db),
+            mcb_utils::constants::DEFAULT_NAMESPACE.to_owned(),
+        )?;
 
-        Ok(Arc::new(ContextServiceImpl::new(embedding, vector_store)))
+        Ok(Arc::new(
+            ContextServiceImpl::new(embedding, vector_store)
+                .with_title_vectors(app_config.mcp.indexing.title_vectors)
+                .with_distance_metric(
+                    app_config
+                        .providers
+                        .vector_store
+                        .distance_metric
+                        .unwrap_or_default(),
+                )
+                .with_embedding_model(app_config.providers.embedding.model.clone())
+                .with_collection_manifest_repository(repositories.collection_manifest),
+        ))
     }),
 );
@@ -0,0 +1,270 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Duplicate Detection Service Use Case
+//!
+//! # Overview
+//! The `DuplicateDetectionService` finds cloned code in any indexed
+//! collection, using the clone taxonomy of `mcb-validate`'s duplication
+//! analysis but working on the chunks kept by the vector store instead of
+//! files on disk. Chunks go through the same tokenizer and Rabin-Karp
+//! fingerprinter as that analysis (`mcb_utils::utils::duplication`):
+//! comments and whitespace are dropped, identifiers and literals are
+//! normalized to placeholders, and every window of normalized tokens is
+//! hashed into a shingle. Fragments sharing a shingle become candidates and
+//! are scored by the Jaccard similarity of their shingle sets:
+//!
+//! | Kind | Match |
+//! | ---- | ----- |
+//! | `exact` | identical token sequences |
+//! | `renamed` | identical normalized sequences |
+//! | `gapped` | similar normalized sequences |
+//!
+//! A pair lying inside the fragments of a better pair (a method of two
+//! duplicated classes) is not reported separately.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    CloneKind, CodeFragment, DuplicateDetectionServiceInterface, DuplicatePair, DuplicateQuery,
+    DuplicateReport, VectorStoreProvider,
+};
+use mcb_domain::value_objects::CollectionId;
+use mcb_utils::constants::search::{
+    DUPLICATE_MAX_SHINGLE_POSTINGS, DUPLICATE_SHINGLE_TOKENS, GREP_MAX_FILES,
+};
+use mcb_utils::utils::duplication::{Fingerprint, Token, TokenFingerprinter, tokenize_source};
+
+/// A tokenized fragment ready for comparison.
+struct Tokenized<'a> {
+    fragment: &'a CodeFragment,
+    tokens: Vec<Token>,
+    shingles: HashSet<Fingerprint>,
+}
+
+impl Tokenized<'_> {
+    fn texts(&self) -> impl Iterator<Item = &str> {
+        self.tokens.iter().map(|token| token.text.as_str())
+    }
+
+    fn normalized(&self) -> impl Iterator<Item = &str> {
+        self.tokens.iter().map(Token::normalized_text)
+    }
+}
+
+fn contains(outer: &CodeFragment, inner: &CodeFragment) -> bool {
+    outer.file_path == inner.file_path
+        && outer.start_line <= inner.start_line
+        && inner.end_line <= outer.end_line
+}
+
+fn overlaps(a: &CodeFragment, b: &CodeFragment) -> bool {
+    a.file_path == b.file_path && a.start_line <= b.end_line && b.start_line <= a.end_line
+}
+
+/// Whether `pair` lies inside the fragments of `outer`, either way round.
+fn nested_in(pair: &DuplicatePair, outer: &DuplicatePair) -> bool {
+    (contains(&outer.first, &pair.first) && contains(&outer.second, &pair.second))
+        || (contains(&outer.first, &pair.second) && contains(&outer.second, &pair.first))
+}
+
+fn line_count(fragment: &CodeFragment) -> u32 {
+    fragment.end_line - fragment.start_line + 1
+}
+
+/// Implementation of the `DuplicateDetectionServiceInterface`.
+pub struct DuplicateDetectionServiceImpl {
+    vector_store: Arc<dyn VectorStoreProvider>,
+}
+
+impl DuplicateDetectionServiceImpl {
+    /// Create new duplicate detection service with injected dependencies
+    pub fn new(vector_store: Arc<dyn VectorStoreProvider>) -> Self {
+        Self { vector_store }
+    }
+
+    /// Compare `fragments` (each with its content and language) and return
+    /// the pairs passing the query thresholds, best first, together with
+    /// the number of fragments long enough to be compared. `limit` is not
+    /// applied.
+    #[must_use]
+    pub fn detect(
+        fragments: &[(CodeFragment, String, String)],
+        query: &DuplicateQuery,
+    ) -> (Vec<DuplicatePair>, usize) {
+        let fingerprinter = TokenFingerprinter::new(DUPLICATE_SHINGLE_TOKENS);
+        let tokenized: Vec<Tokenized<'_>> = fragments
+            .iter()
+            .filter(|(fragment, _, _)| line_count(fragment) as usize >= query.min_lines)
+            .filter_map(|(fragment, content, language)| {
+                let tokens = tokenize_source(content, language);
+                let normalized: Vec<&str> = tokens.iter().map(Token::normalized_text).collect();
+                let shingles: HashSet<Fingerprint> = fingerprinter
+                    .window_hashes(&normalized)
+                    .into_iter()
+                    .collect();
+                (!shingles.is_empty()).then_some(Tokenized {
+                    fragment,
+                    tokens,
+                    shingles,
+                })
+            })
+            .collect();
+
+        let mut postings: HashMap<Fingerprint, Vec<usize>> = HashMap::new();
+        for (i, item) in tokenized.iter().enumerate() {
+            for &shingle in &item.shingles {
+                postings.entry(shingle).or_default().push(i);
+            }
+        }
+        let mut candidates: HashSet<(usize, usize)> = HashSet::new();
+        for ids in postings.values() {
+            if ids.len() < 2 || ids.len() > DUPLICATE_MAX_SHINGLE_POSTINGS {
+                continue;
+            }
+            for (n, &a) in ids.iter().enumerate() {
+                for &b in &ids[n + 1..] {
+                    candidates.insert((a, b));
+                }
+            }
+        }
+
+        let mut pairs: Vec<DuplicatePair> = candidates
+            .into_iter()
+            .filter_map(|(a, b)| Self::compare(&tokenized[a], &tokenized[b], query))
+            .collect();
+        pairs.sort_by(|a, b| {
+            b.similarity
+                .total_cmp(&a.similarity)
+                .then_with(|| line_count(&b.first).cmp(&line_count(&a.first)))
+                .then_with(|| {
+                    (&a.first.file_path, a.first.start_line)
+                        .cmp(&(&b.first.file_path, b.first.start_line))
+                })
+                .then_with(|| {
+                    (&a.second.file_path, a.second.start_line)
+                        .cmp(&(&b.second.file_path, b.second.start_line))
+                })
+        });
+        let mut kept: Vec<DuplicatePair> = Vec::new();
+        for pair in pairs {
+            if !kept.iter().any(|outer| nested_in(&pair, outer)) {
+                kept.push(pair);
+            }
+        }
+        (kept, tokenized.len())
+    }
+
+    fn compare(
+        a: &Tokenized<'_>,
+        b: &Tokenized<'_>,
+        query: &DuplicateQuery,
+    ) -> Option<DuplicatePair> {
+        if overlaps(a.fragment, b.fragment) {
+            return None;
+        }
+        let (similarity, kind) = if a.texts().eq(b.texts()) {
+            (1.0, CloneKind::Exact)
+        } else if a.normalized().eq(b.normalized()) {
+            (1.0, CloneKind::Renamed)
+        } else {
+            let shared = a.shingles.intersection(&b.shingles).count();
+            let union = a.shingles.len() + b.shingles.len() - shared;
+            (shared as f64 / union as f64, CloneKind::Gapped)
+        };
+        if similarity < query.min_similarity {
+            return None;
+        }
+        let (first, second) = if (&a.fragment.file_path, a.fragment.start_line)
+            <= (&b.fragment.file_path, b.fragment.start_line)
+        {
+            (a.fragment, b.fragment)
+        } else {
+            (b.fragment, a.fragment)
+        };
+        Some(DuplicatePair {
+            first: first.clone(),
+            second: second.clone(),
+            similarity,
+            kind,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl DuplicateDetectionServiceInterface for DuplicateDetectionServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if the scope is invalid or the vector store cannot
+    /// list files or chunks.
+    async fn find_duplicates(
+        &self,
+        collection: &CollectionId,
+        query: &DuplicateQuery,
+    ) -> Result<DuplicateReport> {
+        let scope = query.scope.clone().unwrap_or_default();
+        let in_scope = scope.matcher()?;
+
+        let mut files = self
+            .vector_store
+            .list_file_paths(collection, GREP_MAX_FILES)
+            .await?;
+        files.retain(|f| in_scope(&f.path, &f.language));
+
+        let mut fragments = Vec::new();
+        for file in &files {
+            let chunks = self
+                .vector_store
+                .get_chunks_by_file(collection, &file.path)
+                .await?;
+            for chunk in chunks {
+                let lines = u32::try_from(chunk.content.lines().count()).unwrap_or(u32::MAX);
+                if lines == 0 {
+                    continue;
+                }
+                let fragment = CodeFragment {
+                    file_path: chunk.file_path,
+                    start_line: chunk.start_line,
+                    end_line: chunk.start_line.saturating_add(lines - 1),
+                };
+                fragments.push((fragment, chunk.content, chunk.language));
+            }
+        }
+
+        let (mut duplicates, fragments_compared) = Self::detect(&fragments, query);
+        let truncated = duplicates.len() > query.limit;
+        duplicates.truncate(query.limit);
+        Ok(DuplicateReport {
+            duplicates,
+            files_scanned: files.len(),
+            fragments_compared,
+            truncated,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+
+/// Build a `DuplicateDetectionService` from the service resolution context.
+fn build_duplicate_detection_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn DuplicateDetectionServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Duplicate detection service builder requires ServiceResolutionContext")
+        })?;
+    Ok(Arc::new(DuplicateDetectionServiceImpl::new(Arc::clone(
+        &ctx.vector_store_provider,
+    ))))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_DUPLICATE_DETECTION,
+    ServiceBuilder::DuplicateDetection(build_duplicate_detection_service_from_registry),
+);
@@ -0,0 +1,330 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! File Content Service Use Case
+//!
+//! # Overview
+//! The `FileContentService` serves line ranges of indexed files so agents can
+//! read the code around a search hit. A file is readable only if the
+//! collection holds chunks for it. Content comes from the working tree when
+//! the collection's manifest records the directory it was indexed from, that
+//! directory is still an allowed indexing root and the file still exists
+//! there; then from the full snapshot stored at index time (when snapshots
+//! are enabled), and finally is rebuilt from the chunks in the collection.
+//! The root a client sends is only checked against the recorded one, never
+//! read from.
+
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    CollectionManifestRepository, FileContentServiceInterface, FileContentSource,
+    FileHashRepository, FileReadRequest, FileSlice, FileSnapshotRepository, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_utils::constants::search::READ_FILE_MAX_BYTES_LIMIT;
+use mcb_utils::utils::path::strict_canonicalize;
+
+use super::indexing_service::resolve_allowed_path;
+
+/// Implementation of the `FileContentServiceInterface`.
+pub struct FileContentServiceImpl {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    snapshots: Option<(Arc<dyn FileHashRepository>, Arc<dyn FileSnapshotRepository>)>,
+    manifests: Option<Arc<dyn CollectionManifestRepository>>,
+    allowed_roots: Vec<PathBuf>,
+}
+
+impl FileContentServiceImpl {
+    /// Create new file content service with injected dependencies
+    pub fn new(vector_store: Arc<dyn VectorStoreProvider>) -> Self {
+        Self {
+            vector_store,
+            snapshots: None,
+            manifests: None,
+            allowed_roots: Vec::new(),
+        }
+    }
+
+    /// Read live files from the root each collection's manifest records,
+    /// while it lies under one of `allowed_roots` (empty allows any root).
+    #[must_use]
+    pub fn with_indexed_roots(
+        mut self,
+        manifests: Arc<dyn CollectionManifestRepository>,
+        allowed_roots: Vec<PathBuf>,
+    ) -> Self {
+        self.manifests = Some(manifests);
+        self.allowed_roots = allowed_roots;
+        self
+    }
+
+    /// Serve stored file snapshots, looked up by the file's indexed hash.
+    #[must_use]
+    pub fn with_snapshots(
+        mut self,
+        file_hash_repository: Arc<dyn FileHashRepository>,
+        file_snapshot_repository: Arc<dyn FileSnapshotRepository>,
+    ) -> Self {
+        self.snapshots = Some((file_hash_repository, file_snapshot_repository));
+        self
+    }
+
+    /// Reassemble a file's lines from its (possibly overlapping) chunks.
+    #[must_use]
+    pub fn chunk_lines(chunks: &[SearchResult]) -> BTreeMap<u32, &str> {
+        let mut lines = BTreeMap::new();
+        for chunk in chunks {
+            for (offset, text) in (0_u32..).zip(chunk.content.lines()) {
+                lines.entry(chunk.start_line + offset).or_insert(text);
+            }
+        }
+        lines
+    }
+
+    /// Check that `path` is workspace-relative and stays inside the workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-argument error for empty, absolute or `..` paths.
+    pub fn normalize_path(path: &str) -> Result<String> {
+        let trimmed = path.trim();
+        let trimmed = trimmed.strip_prefix("./").unwrap_or(trimmed);
+        let escapes = Path::new(trimmed)
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir));
+        if trimmed.is_empty() || escapes {
+            return Err(Error::invalid_argument(format!(
+                "path must be workspace-relative and must not contain '..': '{path}'"
+            )));
+        }
+        Ok(trimmed.to_owned())
+    }
+
+    /// Cut the requested line range out of `lines` (line 1 at index 0),
+    /// stopping before `max_bytes` would be exceeded. A single line longer
+    /// than the budget is cut at a character boundary. The budget is capped
+    /// at [`READ_FILE_MAX_BYTES_LIMIT`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-argument error for an empty or out-of-file range.
+    pub fn slice(lines: &[&str], request: &FileReadRequest) -> Result<(u32, u32, String, bool)> {
+        let total = u32::try_from(lines.len()).unwrap_or(u32::MAX);
+        let start = request.start_line.unwrap_or(1).max(1);
+        let end = request.end_line.unwrap_or(total).min(total);
+        if request.end_line.is_some_and(|e| e < start) {
+            return Err(Error::invalid_argument(format!(
+                "end_line must not be before start_line ({start})"
+            )));
+        }
+        if start > total.max(1) {
+            return Err(Error::invalid_argument(format!(
+                "start_line {start} is past the end of '{}' ({total} lines)",
+                request.path
+            )));
+        }
+
+        let max_bytes = request.max_bytes.min(READ_FILE_MAX_BYTES_LIMIT);
+        let mut content = String::new();
+        let mut last = start - 1;
+        let mut truncated = false;
+        for line in start..=end {
+            let text = lines[(line - 1) as usize];
+            let separator = usize::from(line > start);
+            if content.len() + separator + text.len() > max_bytes {
+                if line == start {
+                    let mut cut = max_bytes.min(text.len());
+                    while !text.is_char_boundary(cut) {
+                        cut -= 1;
+                    }
+                    content.push_str(&text[..cut]);
+                    last = line;
+                }
+                truncated = true;
+                break;
+            }
+            if separator == 1 {
+                content.push('\n');
+            }
+            content.push_str(text);
+            last = line;
+        }
+        Ok((start, last, content, truncated))
+    }
+
+    /// Read `path` from the working tree, or `None` when it is not there.
+    async fn read_from_disk(root: &Path, path: &str) -> Result<Option<String>> {
+        let Ok(root) = tokio::fs::canonicalize(root).await else {
+            return Ok(None);
+        };
+        let Ok(full) = tokio::fs::canonicalize(root.join(path)).await else {
+            return Ok(None);
+        };
+        if !full.starts_with(&root) {
+            return Err(Error::invalid_argument(format!(
+                "path resolves outside the repository: '{path}'"
+            )));
+        }
+        if !full.is_file() {
+            return Ok(None);
+        }
+        tokio::fs::read_to_string(&full)
+            .await
+            .map(Some)
+            .map_err(|e| Error::io_with_source(format!("Failed to read '{path}'"), e))
+    }
+
+    /// Directory to read live content of `collection` from: the root its
+    /// manifest records, or `None` when none is recorded or it is gone.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-argument error when the recorded root is outside
+    /// the allowed indexing roots, or `requested` is a different directory.
+    async fn disk_root(
+        &self,
+        collection: &CollectionId,
+        requested: Option<&Path>,
+    ) -> Result<Option<PathBuf>> {
+        let Some(manifests) = &self.manifests else {
+            return Ok(None);
+        };
+        let recorded = manifests
+            .get_manifest(&collection.to_string())
+            .await?
+            .and_then(|manifest| manifest.root_path);
+        let Some(root) = recorded.and_then(|root| strict_canonicalize(Path::new(&root)).ok())
+        else {
+            return Ok(None);
+        };
+        let root = resolve_allowed_path(&root, &self.allowed_roots)?;
+        if let Some(requested) = requested
+            && strict_canonicalize(requested).ok().as_ref() != Some(&root)
+        {
+            return Err(Error::invalid_argument(format!(
+                "repo_path '{}' is not the directory collection '{}' was indexed from",
+                requested.display(),
+                collection.as_str()
+            )));
+        }
+        Ok(Some(root))
+    }
+
+    /// Stored snapshot of `path` at its last indexed version, if any.
+    async fn read_snapshot(&self, collection: &CollectionId, path: &str) -> Result<Option<String>> {
+        let Some((hashes, blobs)) = &self.snapshots else {
+            return Ok(None);
+        };
+        match hashes.get_hash(&collection.to_string(), path).await? {
+            Some(hash) => blobs.get_snapshot(&hash).await,
+            None => Ok(None),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl FileContentServiceInterface for FileContentServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if the path or range is invalid, the file is not
+    /// indexed in the collection, or it cannot be read.
+    async fn read_file(
+        &self,
+        collection: &CollectionId,
+        request: &FileReadRequest,
+    ) -> Result<FileSlice> {
+        let path = Self::normalize_path(&request.path)?;
+        let chunks = self
+            .vector_store
+            .get_chunks_by_file(collection, &path)
+            .await?;
+        let Some(language) = chunks.first().map(|c| c.language.clone()) else {
+            return Err(Error::not_found(format!(
+                "file '{path}' in collection '{}'",
+                collection.as_str()
+            )));
+        };
+
+        let root = self
+            .disk_root(collection, request.repo_root.as_deref())
+            .await?;
+        let disk = match &root {
+            Some(root) => Self::read_from_disk(root, &path)
+                .await?
+                .map(|text| (FileContentSource::Disk, text)),
+            None => None,
+        };
+        let stored = match disk {
+            Some(found) => Some(found),
+            None => self
+                .read_snapshot(collection, &path)
+                .await?
+                .map(|text| (FileContentSource::Snapshot, text)),
+        };
+        let (source, lines): (_, Vec<&str>) = match &stored {
+            Some((source, text)) => (*source, text.lines().collect()),
+            None => {
+                let rebuilt = Self::chunk_lines(&chunks);
+                let total = rebuilt.keys().next_back().copied().unwrap_or(0);
+                let lines = (1..=total)
+                    .map(|n| rebuilt.get(&n).copied().unwrap_or(""))
+                    .collect();
+                (FileContentSource::Chunks, lines)
+            }
+        };
+
+        let (start_line, end_line, content, truncated) = Self::slice(&lines, request)?;
+        Ok(FileSlice {
+            path,
+            language,
+            start_line,
+            end_line,
+            total_lines: u32::try_from(lines.len()).unwrap_or(u32::MAX),
+            content,
+            source,
+            truncated,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+use mcb_utils::constants::{DEFAULT_DATABASE_PROVIDER, DEFAULT_NAMESPACE};
+
+/// Build a `FileContentService` from the service resolution context.
+fn build_file_content_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn FileContentServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("File content service builder requires ServiceResolutionContext")
+        })?;
+    let app_config = ctx
+        .config
+        .downcast_ref::<crate::config::app::AppConfig>()
+        .ok_or_else(|| Error::internal("File content service requires AppConfig"))?;
+    let repos = mcb_domain::registry::database::resolve_database_repositories(
+        DEFAULT_DATABASE_PROVIDER,
+        Arc::clone(&ctx.db),
+        DEFAULT_NAMESPACE.to_owned(),
+    )?;
+    Ok(Arc::new(
+        FileContentServiceImpl::new(Arc::clone(&ctx.vector_store_provider))
+            .with_snapshots(repos.file_hash, repos.file_snapshot)
+            .with_indexed_roots(
+                repos.collection_manifest,
+                app_config.mcp.indexing.allowed_roots.clone(),
+            ),
+    ))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_FILE_CONTENT,
+    ServiceBuilder::FileContent(build_file_content_service_from_registry),
+);
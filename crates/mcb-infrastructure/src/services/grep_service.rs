@@ -0,0 +1,192 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Grep Service Use Case
+//!
+//! # Overview
+//! The `GrepService` finds exact strings or regular expressions in the indexed
+//! file snapshot, i.e. the chunk text kept by the vector store. Files are
+//! rebuilt line by line from their chunks so hits carry real line numbers.
+//! The combined mode intersects these hits with semantic search results, so
+//! an agent can confirm that a semantically relevant chunk really contains
+//! the identifier it is after.
+
+use std::sync::Arc;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    ConfirmedResult, GrepMatch, GrepOutcome, GrepQuery, GrepServiceInterface, SearchFilters,
+    SearchServiceInterface, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_utils::constants::search::{
+    GREP_MAX_FILES, GREP_MAX_LINE_CHARS, GREP_REGEX_SIZE_LIMIT, GREP_SEMANTIC_CANDIDATE_MULTIPLIER,
+};
+use regex::{Regex, RegexBuilder};
+
+use super::file_content_service::FileContentServiceImpl;
+
+/// Implementation of the `GrepServiceInterface`.
+pub struct GrepServiceImpl {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    search_service: Arc<dyn SearchServiceInterface>,
+}
+
+impl GrepServiceImpl {
+    /// Create new grep service with injected dependencies
+    pub fn new(
+        vector_store: Arc<dyn VectorStoreProvider>,
+        search_service: Arc<dyn SearchServiceInterface>,
+    ) -> Self {
+        Self {
+            vector_store,
+            search_service,
+        }
+    }
+
+    /// Compile the query pattern; literals are escaped first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-argument error for an empty or invalid pattern.
+    pub fn compile(query: &GrepQuery) -> Result<Regex> {
+        if query.pattern.is_empty() {
+            return Err(Error::invalid_argument("grep pattern cannot be empty"));
+        }
+        let source = if query.regex {
+            query.pattern.clone()
+        } else {
+            regex::escape(&query.pattern)
+        };
+        RegexBuilder::new(&source)
+            .case_insensitive(!query.case_sensitive)
+            .size_limit(GREP_REGEX_SIZE_LIMIT)
+            .build()
+            .map_err(|e| Error::invalid_argument(format!("Invalid grep pattern: {e}")))
+    }
+
+    /// Lines of one chunk matching `pattern`.
+    fn chunk_matches(result: &SearchResult, pattern: &Regex) -> Vec<GrepMatch> {
+        (0_u32..)
+            .zip(result.content.lines())
+            .filter(|(_, text)| pattern.is_match(text))
+            .map(|(offset, text)| {
+                Self::grep_match(&result.file_path, result.start_line + offset, text)
+            })
+            .collect()
+    }
+
+    fn grep_match(file_path: &str, line: u32, text: &str) -> GrepMatch {
+        GrepMatch {
+            file_path: file_path.to_owned(),
+            line,
+            text: text.chars().take(GREP_MAX_LINE_CHARS).collect(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl GrepServiceInterface for GrepServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if the pattern or scope is invalid or the vector store
+    /// cannot list files or chunks.
+    async fn grep(&self, collection: &CollectionId, query: &GrepQuery) -> Result<GrepOutcome> {
+        let pattern = Self::compile(query)?;
+        let scope = query.scope.clone().unwrap_or_default();
+        let in_scope = scope.matcher()?;
+
+        let mut files = self
+            .vector_store
+            .list_file_paths(collection, GREP_MAX_FILES)
+            .await?;
+        files.retain(|f| in_scope(&f.path, &f.language));
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut outcome = GrepOutcome::default();
+        for file in files {
+            let chunks = self
+                .vector_store
+                .get_chunks_by_file(collection, &file.path)
+                .await?;
+            outcome.files_scanned += 1;
+            for (line, text) in FileContentServiceImpl::chunk_lines(&chunks) {
+                if !pattern.is_match(text) {
+                    continue;
+                }
+                if outcome.matches.len() >= query.max_matches {
+                    outcome.truncated = true;
+                    return Ok(outcome);
+                }
+                outcome
+                    .matches
+                    .push(Self::grep_match(&file.path, line, text));
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the pattern or scope is invalid or the semantic
+    /// search fails.
+    async fn grep_semantic(
+        &self,
+        collection: &CollectionId,
+        semantic_query: &str,
+        query: &GrepQuery,
+        limit: usize,
+    ) -> Result<Vec<ConfirmedResult>> {
+        let pattern = Self::compile(query)?;
+        let filters = query.scope.as_ref().map(|scope| SearchFilters {
+            languages: scope.languages.clone(),
+            path_prefix: scope.path_prefix.clone(),
+            path_glob: scope.path_glob.clone(),
+            exclude_paths: scope.exclude_paths.clone(),
+            exclude_languages: scope.exclude_languages.clone(),
+            ..Default::default()
+        });
+        let candidates = self
+            .search_service
+            .search_with_filters(
+                collection,
+                semantic_query,
+                limit * GREP_SEMANTIC_CANDIDATE_MULTIPLIER,
+                filters.as_ref(),
+            )
+            .await?;
+
+        Ok(candidates
+            .into_iter()
+            .filter_map(|result| {
+                let matches = Self::chunk_matches(&result, &pattern);
+                (!matches.is_empty()).then_some(ConfirmedResult { result, matches })
+            })
+            .take(limit)
+            .collect())
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+
+/// Build a `GrepService` from the service resolution context.
+fn build_grep_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn GrepServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| Error::internal("Grep service builder requires ServiceResolutionContext"))?;
+    let search_service = mcb_domain::registry::services::resolve_search_service(context)?;
+    Ok(Arc::new(GrepServiceImpl::new(
+        Arc::clone(&ctx.vector_store_provider),
+        search_service,
+    )))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_GREP,
+    ServiceBuilder::Grep(build_grep_service_from_registry),
+);
@@ -0,0 +1,322 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Index Evaluation Service Use Case
+//!
+//! # Overview
+//! The `IndexEvaluationService` scores a collection without a hand-labeled
+//! query set. It samples indexed files evenly by path, and for each one
+//! turns the first suitable chunk into a query that chunk should answer:
+//!
+//! | Source | Query |
+//! | ------ | ----- |
+//! | [`QueryGenerationProvider`] | the model's query, when one is configured |
+//! | doc comment | first sentence of the chunk's leading comment or docstring |
+//! | symbol name | words of the defined identifier (`parseConfigFile` → `parse config file`) |
+//!
+//! Each query expects its chunk's file and symbol back. The queries are run
+//! through the search use case and scored like `mcb eval`; the mean of
+//! recall@k, MRR and nDCG@k is the collection's index-quality score.
+
+use std::sync::Arc;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    IndexEvaluationServiceInterface, QueryGenerationProvider, SearchServiceInterface,
+    SelfEvalOptions, SelfEvalReport, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{CollectionId, EvalReport, LabeledQuery, SearchResult};
+use mcb_utils::constants::search::{
+    GREP_MAX_FILES, SELF_EVAL_DOC_SCAN_LINES, SELF_EVAL_MAX_QUERY_WORDS, SELF_EVAL_MIN_QUERY_WORDS,
+};
+
+/// Keywords whose following identifier names the symbol a chunk defines.
+const DEFINITION_KEYWORDS: &[&str] = &[
+    "fn",
+    "def",
+    "class",
+    "struct",
+    "enum",
+    "trait",
+    "interface",
+    "func",
+    "function",
+    "module",
+];
+
+/// Prefixes that open a comment or docstring line.
+const COMMENT_MARKERS: &[&str] = &["///", "//!", "//", "/**", "/*", "\"\"\"", "'''", "--"];
+
+/// Comment prefixes that only count when followed by a space (`#[derive]`
+/// and `*/` are code).
+const SPACED_COMMENT_MARKERS: &[&str] = &["#", "*"];
+
+fn is_ident_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+/// Comment text of `line` (trimmed), or `None` if it is not a comment line.
+fn comment_text(line: &str) -> Option<&str> {
+    let rest = COMMENT_MARKERS
+        .iter()
+        .find_map(|marker| line.strip_prefix(marker))
+        .or_else(|| {
+            SPACED_COMMENT_MARKERS.iter().find_map(|marker| {
+                line.strip_prefix(marker)
+                    .filter(|rest| rest.is_empty() || rest.starts_with(' '))
+            })
+        })?;
+    Some(
+        rest.trim()
+            .trim_end_matches("*/")
+            .trim_end_matches("\"\"\"")
+            .trim_end_matches("'''")
+            .trim(),
+    )
+}
+
+/// First sentence of the first comment paragraph in the chunk's leading lines.
+fn doc_sentence(content: &str) -> Option<String> {
+    let mut text = String::new();
+    for line in content.lines().take(SELF_EVAL_DOC_SCAN_LINES) {
+        match comment_text(line.trim()) {
+            Some(comment) if !comment.is_empty() => {
+                if !text.is_empty() {
+                    text.push(' ');
+                }
+                text.push_str(comment);
+            }
+            Some(_) | None if text.is_empty() => {}
+            Some(_) | None => break,
+        }
+    }
+    let sentence = text
+        .split_once(". ")
+        .map_or(text.as_str(), |(first, _)| first)
+        .trim_end_matches('.');
+    let words: Vec<&str> = sentence
+        .split_whitespace()
+        .take(SELF_EVAL_MAX_QUERY_WORDS)
+        .collect();
+    (words.len() >= SELF_EVAL_MIN_QUERY_WORDS).then(|| words.join(" "))
+}
+
+/// Name following the first definition keyword on a non-comment line.
+fn symbol_name(content: &str) -> Option<&str> {
+    content
+        .lines()
+        .filter(|line| comment_text(line.trim()).is_none())
+        .find_map(definition_name)
+}
+
+fn definition_name(line: &str) -> Option<&str> {
+    let mut rest = line;
+    loop {
+        rest = &rest[rest.find(is_ident_char)?..];
+        let end = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+        let (word, after) = rest.split_at(end);
+        rest = after;
+        if !DEFINITION_KEYWORDS.contains(&word) {
+            continue;
+        }
+        let mut after = after.trim_start();
+        // Go method receivers: `func (s *Server) Name(`
+        if let Some(receiver) = after.strip_prefix('(') {
+            after = receiver.split_once(')')?.1.trim_start();
+        }
+        let end = after
+            .find(|c: char| !is_ident_char(c))
+            .unwrap_or(after.len());
+        let name = &after[..end];
+        return (!name.is_empty() && !name.starts_with(|c: char| c.is_ascii_digit()))
+            .then_some(name);
+    }
+}
+
+/// Lowercase words of a `snake_case`, `kebab-case` or `camelCase` identifier.
+fn identifier_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in name.split(['_', '-']) {
+        let chars: Vec<char> = part.chars().collect();
+        let mut word = String::new();
+        for (i, &c) in chars.iter().enumerate() {
+            let boundary = i > 0
+                && c.is_uppercase()
+                && (!chars[i - 1].is_uppercase()
+                    || chars.get(i + 1).is_some_and(|next| next.is_lowercase()));
+            if boundary && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            word.extend(c.to_lowercase());
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+/// `query` labeled with the file and symbol of `chunk`.
+fn labeled(chunk: &SearchResult, query: String) -> LabeledQuery {
+    LabeledQuery {
+        query,
+        files: vec![chunk.file_path.clone()],
+        symbols: symbol_name(&chunk.content)
+            .map(str::to_owned)
+            .into_iter()
+            .collect(),
+    }
+}
+
+/// Up to `n` items spread evenly across `items`.
+fn sample_evenly<T>(items: &[T], n: usize) -> Vec<&T> {
+    let n = n.min(items.len());
+    (0..n).map(|i| &items[i * items.len() / n]).collect()
+}
+
+/// Implementation of the `IndexEvaluationServiceInterface`.
+pub struct IndexEvaluationServiceImpl {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    search_service: Arc<dyn SearchServiceInterface>,
+    query_generator: Option<Arc<dyn QueryGenerationProvider>>,
+}
+
+impl IndexEvaluationServiceImpl {
+    /// Create new index evaluation service with injected dependencies
+    pub fn new(
+        vector_store: Arc<dyn VectorStoreProvider>,
+        search_service: Arc<dyn SearchServiceInterface>,
+    ) -> Self {
+        Self {
+            vector_store,
+            search_service,
+            query_generator: None,
+        }
+    }
+
+    /// Phrase queries with `generator`, falling back to the templates when
+    /// it declines a chunk or fails.
+    #[must_use]
+    pub fn with_query_generator(mut self, generator: Arc<dyn QueryGenerationProvider>) -> Self {
+        self.query_generator = Some(generator);
+        self
+    }
+
+    /// Template query for `chunk`: its doc comment's first sentence, else the
+    /// words of the symbol it defines. `None` when neither is long enough.
+    #[must_use]
+    pub fn template_query(chunk: &SearchResult) -> Option<LabeledQuery> {
+        let query = doc_sentence(&chunk.content).or_else(|| {
+            let words = identifier_words(symbol_name(&chunk.content)?);
+            (words.len() >= SELF_EVAL_MIN_QUERY_WORDS).then(|| words.join(" "))
+        })?;
+        Some(labeled(chunk, query))
+    }
+
+    async fn generate(&self, chunk: &SearchResult) -> Option<LabeledQuery> {
+        let Some(generator) = &self.query_generator else {
+            return Self::template_query(chunk);
+        };
+        match generator.generate_query(chunk).await {
+            Ok(Some(query)) if !query.trim().is_empty() => {
+                Some(labeled(chunk, query.trim().to_owned()))
+            }
+            Ok(_) => Self::template_query(chunk),
+            Err(e) => {
+                mcb_domain::warn!(
+                    "index_evaluation",
+                    "Query generation failed, using template",
+                    &e
+                );
+                Self::template_query(chunk)
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IndexEvaluationServiceInterface for IndexEvaluationServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if the options are zero, no query can be generated
+    /// from the sampled files, or listing, reading or searching fails.
+    async fn self_evaluate(
+        &self,
+        collection: &CollectionId,
+        options: &SelfEvalOptions,
+    ) -> Result<SelfEvalReport> {
+        if options.sample_size == 0 || options.k == 0 {
+            return Err(Error::invalid_argument(
+                "Self-evaluation needs a sample size and k of at least 1",
+            ));
+        }
+        let mut files = self
+            .vector_store
+            .list_file_paths(collection, GREP_MAX_FILES)
+            .await?;
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+        let sampled = sample_evenly(&files, options.sample_size);
+
+        let mut queries = Vec::with_capacity(sampled.len());
+        for file in &sampled {
+            let chunks = self
+                .vector_store
+                .get_chunks_by_file(collection, &file.path)
+                .await?;
+            for chunk in &chunks {
+                if let Some(query) = self.generate(chunk).await {
+                    queries.push(query);
+                    break;
+                }
+            }
+        }
+        if queries.is_empty() {
+            return Err(Error::invalid_argument(format!(
+                "No queries could be generated from the {} sampled files of '{collection}'",
+                sampled.len()
+            )));
+        }
+
+        let mut scores = Vec::with_capacity(queries.len());
+        for query in &queries {
+            let results = self
+                .search_service
+                .search(collection, &query.query, options.k)
+                .await?;
+            scores.push(query.score(&results, options.k));
+        }
+        let eval = EvalReport::new(collection.to_string(), options.k, scores);
+        Ok(SelfEvalReport {
+            quality_score: (eval.recall + eval.mrr + eval.ndcg) / 3.0,
+            files_sampled: sampled.len(),
+            queries,
+            eval,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+
+/// Build an `IndexEvaluationService` from the service resolution context.
+fn build_index_evaluation_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn IndexEvaluationServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Index evaluation service builder requires ServiceResolutionContext")
+        })?;
+    let search_service = mcb_domain::registry::services::resolve_search_service(context)?;
+    Ok(Arc::new(IndexEvaluationServiceImpl::new(
+        Arc::clone(&ctx.vector_store_provider),
+        search_service,
+    )))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_INDEX_EVALUATION,
+    ServiceBuilder::IndexEvaluation(build_index_evaluation_service_from_registry),
+);
@@ -0,0 +1,42 @@
+//! Allowlist of directories that may be indexed.
+//!
+//! Paths are compared after canonicalization, so `..` components and
+//! symlinks cannot lead out of an allowed root.
+
+use std::path::{Path, PathBuf};
+
+use mcb_domain::error::{Error, Result};
+use mcb_utils::utils::path::strict_canonicalize;
+
+/// Resolve `path` to the canonical directory to index, checking it lies
+/// under one of `allowed_roots`.
+///
+/// An empty allowlist allows every path and returns `path` unchanged.
+/// Configuration validation rejects roots that do not exist.
+///
+/// # Errors
+///
+/// Returns an invalid-argument error when `path` cannot be canonicalized or
+/// is outside every allowed root, and a configuration error when an allowed
+/// root no longer resolves.
+pub fn resolve_allowed_path(path: &Path, allowed_roots: &[PathBuf]) -> Result<PathBuf> {
+    if allowed_roots.is_empty() {
+        return Ok(path.to_path_buf());
+    }
+    let canonical =
+        strict_canonicalize(path).map_err(|e| Error::invalid_argument(e.to_string()))?;
+    let mut allowed = false;
+    for root in allowed_roots {
+        let root = strict_canonicalize(root)
+            .map_err(|e| Error::config_invalid("mcp.indexing.allowed_roots", e.to_string()))?;
+        allowed |= canonical.starts_with(root);
+    }
+    if allowed {
+        Ok(canonical)
+    } else {
+        Err(Error::invalid_argument(format!(
+            "path '{}' is outside the allowed indexing roots",
+            path.display()
+        )))
+    }
+}
@@ -3,10 +3,13 @@
 //! This module handles recursive directory traversal, file filtering,
 //! and extension validation during the indexing process.
 
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 use ignore::WalkBuilder;
 
+use mcb_domain::ports::validation::LanguageId;
+use mcb_utils::constants::lang::LANGUAGE_SNIFF_BYTES;
 use mcb_utils::constants::use_cases::SKIP_DIRS;
 
 use super::{IndexingProgress, IndexingServiceImpl};
@@ -17,47 +20,80 @@ impl IndexingServiceImpl {
         &self,
         path: &Path,
         progress: &mut IndexingProgress,
-    ) -> Vec<std::path::PathBuf> {
-        let mut files = Vec::new();
-        let walker = WalkBuilder::new(path)
-            .hidden(false)
-            .filter_entry(|entry| {
-                if !entry.file_type().is_some_and(|ft| ft.is_dir()) {
-                    return true;
-                }
+    ) -> Vec<PathBuf> {
+        discover_supported_files(path, &self.supported_extensions, progress)
+    }
+}
 
-                entry
-                    .file_name()
-                    .to_str()
-                    .is_none_or(|name| !SKIP_DIRS.contains(&name))
-            })
-            .build();
-
-        for entry_result in walker {
-            match entry_result {
-                Ok(entry) => {
-                    if entry.file_type().is_some_and(|ft| ft.is_file())
-                        && self.is_supported_file(entry.path())
-                    {
-                        files.push(entry.path().to_path_buf());
-                    }
-                }
-                Err(e) => {
-                    progress.record_error("Failed to read directory entry", path, e);
+/// Walk `path` and collect the files whose extension is in
+/// `supported_extensions` (normalized: lowercase, no dot), plus files whose
+/// name or content identifies a language with a supported extension.
+pub(super) fn discover_supported_files(
+    path: &Path,
+    supported_extensions: &[String],
+    progress: &mut IndexingProgress,
+) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let walker = WalkBuilder::new(path)
+        .hidden(false)
+        .filter_entry(|entry| {
+            if !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                return true;
+            }
+
+            entry
+                .file_name()
+                .to_str()
+                .is_none_or(|name| !SKIP_DIRS.contains(&name))
+        })
+        .build();
+
+    for entry_result in walker {
+        match entry_result {
+            Ok(entry) => {
+                if entry.file_type().is_some_and(|ft| ft.is_file())
+                    && is_supported_file(entry.path(), supported_extensions)
+                {
+                    files.push(entry.path().to_path_buf());
                 }
             }
+            Err(e) => {
+                progress.record_error("Failed to read directory entry", path, e);
+            }
         }
-
-        files
     }
 
-    /// Check if file has a supported extension
-    fn is_supported_file(&self, path: &Path) -> bool {
-        path.extension()
-            .and_then(|ext| ext.to_str())
-            .is_some_and(|ext| {
-                let ext_lower = ext.to_ascii_lowercase();
-                self.supported_extensions.contains(&ext_lower)
-            })
+    files
+}
+
+/// Check if file has a supported extension, or is a `Dockerfile`, `BUILD`,
+/// shebang script etc. whose language has one.
+fn is_supported_file(path: &Path, supported_extensions: &[String]) -> bool {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    if extension.is_some_and(|ext| supported_extensions.contains(&ext.to_ascii_lowercase())) {
+        return true;
     }
+    let head = extension.is_none().then(|| read_head(path)).flatten();
+    let detected = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| LanguageId::from_filename_or_content(name, head.as_deref()));
+    detected.is_some_and(|lang| {
+        lang.extensions().iter().any(|ext| {
+            supported_extensions
+                .iter()
+                .any(|supported| supported == ext)
+        })
+    })
+}
+
+/// First bytes of `path`, enough to sniff its language.
+fn read_head(path: &Path) -> Option<String> {
+    let mut head = Vec::new();
+    std::fs::File::open(path)
+        .ok()?
+        .take(LANGUAGE_SNIFF_BYTES)
+        .read_to_end(&mut head)
+        .ok()?;
+    Some(String::from_utf8_lossy(&head).into_owned())
 }
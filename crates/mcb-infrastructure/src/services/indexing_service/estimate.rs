@@ -0,0 +1,47 @@
+//! Dry-run estimate of an indexing run.
+//!
+//! Walks and chunks a codebase exactly like indexing does, but stops before
+//! the embedding provider and the vector store: nothing leaves the process.
+
+use std::path::Path;
+
+use mcb_domain::ports::{IndexingEstimate, LanguageChunkingProvider};
+use mcb_utils::constants::embedding::EMBEDDING_PRICE_TABLE;
+
+use super::discovery::discover_supported_files;
+use super::{IndexingProgress, IndexingServiceImpl};
+
+/// Estimate what indexing `path` would produce, with cost and storage
+/// projections for every model in [`EMBEDDING_PRICE_TABLE`].
+///
+/// Unreadable files are counted in `files_skipped`.
+#[must_use]
+pub fn estimate_codebase(
+    path: &Path,
+    language_chunker: &dyn LanguageChunkingProvider,
+    supported_extensions: Vec<String>,
+) -> IndexingEstimate {
+    let supported_extensions =
+        IndexingServiceImpl::normalize_supported_extensions(supported_extensions);
+    let mut progress = IndexingProgress::new();
+    let files = discover_supported_files(path, &supported_extensions, &mut progress);
+
+    let mut estimate = IndexingEstimate::default();
+    for file in &files {
+        let Ok(content) = std::fs::read_to_string(file) else {
+            estimate.files_skipped += 1;
+            continue;
+        };
+        estimate.record_file(
+            file.extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or_default(),
+        );
+        let relative_path = IndexingServiceImpl::workspace_relative_path(file, path)
+            .unwrap_or_else(|_| file.display().to_string());
+        for chunk in language_chunker.chunk(&content, &relative_path) {
+            estimate.record_chunk(&chunk.language, chunk.content.len());
+        }
+    }
+    estimate.with_projections(EMBEDDING_PRICE_TABLE)
+}
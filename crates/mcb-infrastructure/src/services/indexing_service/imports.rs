@@ -0,0 +1,368 @@
+//! Import extraction for the module dependency graph.
+//!
+//! Reads the import statements of a file and turns each into a `/`-separated
+//! module path. Relative imports (`./utils`, `from .models import User`,
+//! `super::config`) and crate-local Rust paths are resolved against the
+//! importing file, so their module paths are workspace-relative. Other Rust
+//! crates map to `<crate-dir>/src/...`; everything else is kept as written
+//! (`react`, `github.com/acme/tool/pkg`, `com/acme/Parser`).
+//!
+//! Supported: Rust, Python, JavaScript/TypeScript, Go, Java and Kotlin.
+
+use std::path::Path;
+
+use mcb_domain::entities::module_import::ModuleImport;
+
+/// Rust crates that never resolve to indexed files.
+const RUST_STD_CRATES: &[&str] = &["std", "core", "alloc"];
+
+/// File extensions stripped from relative JavaScript/TypeScript imports.
+const JS_EXTENSIONS: &[&str] = &[".js", ".jsx", ".mjs", ".cjs", ".ts", ".tsx", ".mts", ".cts"];
+
+/// `(line, specifier, module_path)` of one import.
+type RawImport = (u32, String, String);
+
+/// Extract the imports of the file at workspace-relative `path`.
+#[must_use]
+pub fn extract_imports(collection: &str, path: &str, content: &str) -> Vec<ModuleImport> {
+    let ext = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("");
+    let raw = match ext {
+        "rs" => rust_imports(path, content),
+        "py" => python_imports(path, content),
+        "js" | "jsx" | "mjs" | "cjs" | "ts" | "tsx" | "mts" | "cts" => js_imports(path, content),
+        "go" => go_imports(content),
+        "java" | "kt" | "kts" => jvm_imports(content),
+        _ => Vec::new(),
+    };
+    raw.into_iter()
+        .map(|(line, specifier, module_path)| ModuleImport {
+            collection: collection.to_owned(),
+            source_file: path.to_owned(),
+            specifier,
+            module_path,
+            line,
+        })
+        .collect()
+}
+
+/// Directory part of a `/`-separated path.
+fn dir_of(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// Join `rel` onto `base`, applying `.` and `..` segments.
+fn join(base: &str, rel: &str) -> String {
+    let mut parts: Vec<&str> = base.split('/').filter(|p| !p.is_empty()).collect();
+    for segment in rel.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            _ => parts.push(segment),
+        }
+    }
+    parts.join("/")
+}
+
+/// 1-based line numbers paired with trimmed lines.
+fn numbered_lines(content: &str) -> impl Iterator<Item = (u32, &str)> {
+    (1u32..).zip(content.lines().map(str::trim))
+}
+
+/// First quoted string in `text`.
+fn quoted(text: &str) -> Option<&str> {
+    let start = text.find(['\'', '"', '`'])?;
+    let quote = text[start..].chars().next()?;
+    let rest = &text[start + 1..];
+    rest.find(quote).map(|end| &rest[..end])
+}
+
+// ---------------------------------------------------------------------------
+// Rust
+// ---------------------------------------------------------------------------
+
+/// Module directory of a Rust file: where its child modules live.
+fn rust_module_dir(path: &str) -> String {
+    let stem = path.strip_suffix(".rs").unwrap_or(path);
+    if let Some("mod" | "lib" | "main") = stem.rsplit('/').next() {
+        dir_of(path).to_owned()
+    } else {
+        stem.to_owned()
+    }
+}
+
+/// Source root of the crate containing `path` (the nearest `src` ancestor).
+fn rust_crate_root(path: &str) -> String {
+    let parts: Vec<&str> = path.split('/').collect();
+    match parts.iter().rposition(|p| *p == "src") {
+        Some(i) => parts[..=i].join("/"),
+        None => dir_of(path).to_owned(),
+    }
+}
+
+/// Strip a visibility modifier (`pub`, `pub(crate)`, ...) from a statement.
+fn strip_visibility(statement: &str) -> &str {
+    let Some(rest) = statement.strip_prefix("pub") else {
+        return statement;
+    };
+    let rest = match rest.strip_prefix('(') {
+        Some(scoped) => scoped.split_once(')').map_or("", |(_, r)| r),
+        None => rest,
+    };
+    rest.trim_start()
+}
+
+/// Expand a `use` tree into its individual paths, e.g.
+/// `a::{b, c::{d, self}}` → `a::b`, `a::c::d`, `a::c`.
+fn expand_use_tree(prefix: &str, tree: &str, out: &mut Vec<String>) {
+    let tree = tree.trim();
+    let join_path = |tail: &str| -> String {
+        match (prefix.is_empty(), tail.is_empty()) {
+            (true, _) => tail.to_owned(),
+            (false, true) => prefix.to_owned(),
+            (false, false) => format!("{prefix}::{tail}"),
+        }
+    };
+    if let Some(open) = tree.find('{') {
+        let head = tree[..open].trim().trim_end_matches("::");
+        let inner = tree[open + 1..].trim_end();
+        let inner = inner.strip_suffix('}').unwrap_or(inner);
+        let nested = join_path(head);
+        let mut depth = 0usize;
+        let mut start = 0usize;
+        for (i, c) in inner.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => {
+                    expand_use_tree(&nested, &inner[start..i], out);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        expand_use_tree(&nested, &inner[start..], out);
+        return;
+    }
+    let item = tree.split(" as ").next().unwrap_or(tree).trim();
+    if item.is_empty() {
+        return;
+    }
+    match item {
+        "self" | "*" => out.push(prefix.to_owned()),
+        _ => out.push(join_path(item.trim_end_matches("::*"))),
+    }
+}
+
+/// Module path of a Rust `use` path, or `None` for the standard library.
+fn rust_module_path(use_path: &str, module_dir: &str, crate_root: &str) -> Option<String> {
+    let mut segments = use_path.trim_start_matches("::").split("::");
+    let first = segments.next()?;
+    let mut base = match first {
+        "crate" => crate_root.to_owned(),
+        "self" => module_dir.to_owned(),
+        "super" => dir_of(module_dir).to_owned(),
+        _ if RUST_STD_CRATES.contains(&first) => return None,
+        _ => format!("{}/src", first.replace('_', "-")),
+    };
+    for segment in segments {
+        if segment == "super" {
+            base = dir_of(&base).to_owned();
+        } else {
+            base = join(&base, segment);
+        }
+    }
+    Some(base)
+}
+
+fn rust_imports(path: &str, content: &str) -> Vec<RawImport> {
+    let module_dir = rust_module_dir(path);
+    let crate_root = rust_crate_root(path);
+    let mut imports = Vec::new();
+    let mut lines = numbered_lines(content);
+    while let Some((line_no, line)) = lines.next() {
+        let statement = strip_visibility(line);
+        if let Some(name) = statement
+            .strip_prefix("mod ")
+            .and_then(|m| m.strip_suffix(';'))
+        {
+            let name = name.trim();
+            imports.push((line_no, name.to_owned(), join(&module_dir, name)));
+            continue;
+        }
+        let Some(body) = statement.strip_prefix("use ") else {
+            continue;
+        };
+        let mut body = body.to_owned();
+        while !body.contains(';') {
+            let Some((_, next)) = lines.next() else {
+                break;
+            };
+            body.push(' ');
+            body.push_str(next);
+        }
+        let body = body.split(';').next().unwrap_or_default();
+        let mut paths = Vec::new();
+        expand_use_tree("", body, &mut paths);
+        for use_path in paths {
+            if let Some(module_path) = rust_module_path(&use_path, &module_dir, &crate_root) {
+                imports.push((line_no, use_path, module_path));
+            }
+        }
+    }
+    imports
+}
+
+// ---------------------------------------------------------------------------
+// Python
+// ---------------------------------------------------------------------------
+
+/// Module path of a possibly relative dotted Python module.
+fn python_module_path(module: &str, path: &str) -> String {
+    let dots = module.len() - module.trim_start_matches('.').len();
+    let dotted = module[dots..].replace('.', "/");
+    if dots == 0 {
+        return dotted;
+    }
+    let mut base = dir_of(path).to_owned();
+    for _ in 1..dots {
+        base = dir_of(&base).to_owned();
+    }
+    join(&base, &dotted)
+}
+
+fn python_imports(path: &str, content: &str) -> Vec<RawImport> {
+    let mut imports = Vec::new();
+    for (line_no, line) in numbered_lines(content) {
+        if let Some(modules) = line.strip_prefix("import ") {
+            for module in modules.split(',') {
+                let module = module.split(" as ").next().unwrap_or_default().trim();
+                if !module.is_empty() {
+                    imports.push((line_no, module.to_owned(), module.replace('.', "/")));
+                }
+            }
+        } else if let Some((module, names)) = line
+            .strip_prefix("from ")
+            .and_then(|rest| rest.split_once(" import "))
+        {
+            let module = module.trim();
+            let base = python_module_path(module, path);
+            let names: Vec<&str> = names
+                .split(',')
+                .map(|n| {
+                    let n = n.trim().trim_matches(['(', ')', '\\']).trim();
+                    n.split(" as ").next().unwrap_or_default().trim()
+                })
+                .filter(|n| !n.is_empty() && *n != "*")
+                .collect();
+            if names.is_empty() {
+                imports.push((line_no, module.to_owned(), base));
+            }
+            for name in names {
+                let specifier = if module.ends_with('.') {
+                    format!("{module}{name}")
+                } else {
+                    format!("{module}.{name}")
+                };
+                imports.push((line_no, specifier, join(&base, name)));
+            }
+        }
+    }
+    imports
+}
+
+// ---------------------------------------------------------------------------
+// JavaScript / TypeScript
+// ---------------------------------------------------------------------------
+
+fn js_imports(path: &str, content: &str) -> Vec<RawImport> {
+    let dir = dir_of(path);
+    let mut imports = Vec::new();
+    for (line_no, line) in numbered_lines(content) {
+        let is_statement = ["import", "export", "}"]
+            .iter()
+            .any(|p| line.starts_with(p));
+        let specifier = if is_statement && let Some((_, source)) = line.rsplit_once(" from ") {
+            quoted(source)
+        } else if let Some(source) = line.strip_prefix("import ") {
+            source
+                .trim_start()
+                .starts_with(['\'', '"'])
+                .then(|| quoted(source))
+                .flatten()
+        } else {
+            line.find("require(")
+                .or_else(|| line.find("import("))
+                .and_then(|i| quoted(&line[i..]))
+        };
+        let Some(specifier) = specifier.filter(|s| !s.is_empty()) else {
+            continue;
+        };
+        let module_path = if specifier.starts_with('.') {
+            let mut joined = join(dir, specifier);
+            if let Some(ext) = JS_EXTENSIONS.iter().find(|ext| joined.ends_with(*ext)) {
+                joined.truncate(joined.len() - ext.len());
+            }
+            joined
+        } else {
+            specifier.to_owned()
+        };
+        imports.push((line_no, specifier.to_owned(), module_path));
+    }
+    imports
+}
+
+// ---------------------------------------------------------------------------
+// Go
+// ---------------------------------------------------------------------------
+
+fn go_imports(content: &str) -> Vec<RawImport> {
+    let mut imports = Vec::new();
+    let mut in_block = false;
+    for (line_no, line) in numbered_lines(content) {
+        let source = if in_block {
+            if line.starts_with(')') {
+                in_block = false;
+                continue;
+            }
+            line
+        } else if let Some(rest) = line
+            .strip_prefix("import")
+            .filter(|rest| rest.is_empty() || rest.starts_with([' ', '\t', '(', '"']))
+        {
+            let rest = rest.trim_start();
+            if rest.starts_with('(') {
+                in_block = true;
+                &rest[1..]
+            } else {
+                rest
+            }
+        } else {
+            continue;
+        };
+        if let Some(specifier) = quoted(source).filter(|s| !s.is_empty()) {
+            imports.push((line_no, specifier.to_owned(), specifier.to_owned()));
+        }
+    }
+    imports
+}
+
+// ---------------------------------------------------------------------------
+// Java / Kotlin
+// ---------------------------------------------------------------------------
+
+fn jvm_imports(content: &str) -> Vec<RawImport> {
+    numbered_lines(content)
+        .filter_map(|(line_no, line)| {
+            let rest = line.strip_prefix("import ")?.trim_start();
+            let rest = rest.strip_prefix("static ").unwrap_or(rest);
+            let name = rest.trim_end_matches(';').split(" as ").next()?.trim();
+            let module = name.trim_end_matches(".*");
+            (!module.is_empty()).then(|| (line_no, name.to_owned(), module.replace('.', "/")))
+        })
+        .collect()
+}
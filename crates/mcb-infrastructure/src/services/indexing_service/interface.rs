@@ -9,6 +9,7 @@ use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
 use mcb_domain::ports::{IndexingResult, IndexingServiceInterface, IndexingStatus};
 use mcb_domain::value_objects::CollectionId;
+use mcb_utils::constants::lang::CHUNKER_VERSION;
 
 use super::{IndexingProgress, IndexingServiceImpl};
 
@@ -60,19 +61,52 @@ impl IndexingServiceImpl {
 
         operation_id
     }
+
+    /// Warning for a collection whose manifest records an older chunker.
+    ///
+    /// Incremental indexing keeps the chunks of unchanged files, so such a
+    /// collection mixes old and new chunk boundaries until it is rebuilt.
+    async fn stale_chunker_warning(&self, collection: &CollectionId) -> Option<String> {
+        let manifests = self.collection_manifest_repository.as_ref()?;
+        let manifest = match manifests.get_manifest(&collection.to_string()).await {
+            Ok(manifest) => manifest?,
+            Err(e) => {
+                mcb_domain::warn!("indexing", "Failed to read collection manifest", &e);
+                return None;
+            }
+        };
+        if manifest.chunker_version >= CHUNKER_VERSION {
+            return None;
+        }
+        let message = format!(
+            "Collection '{collection}' was indexed by chunker version {} (current: {CHUNKER_VERSION}); clear the index and re-index to rebuild all chunks",
+            manifest.chunker_version
+        );
+        mcb_domain::warn!("indexing", &message);
+        Some(message)
+    }
 }
 
 #[async_trait::async_trait]
 impl IndexingServiceInterface for IndexingServiceImpl {
     /// # Errors
     ///
-    /// Returns an error if collection initialization fails.
+    /// Returns an error if `path` is outside the allowed indexing roots or
+    /// collection initialization fails.
     async fn index_codebase(
         &self,
         path: &Path,
         collection: &CollectionId,
+        org_id: &str,
     ) -> Result<IndexingResult> {
+        let path = super::resolve_allowed_path(path, &self.allowed_roots)?;
+        let path = path.as_path();
         self.context_service.initialize(collection).await?;
+        let warnings: Vec<String> = self
+            .stale_chunker_warning(collection)
+            .await
+            .into_iter()
+            .collect();
 
         let (files, _progress) = self.run_discovery(path).await;
         let total_files = files.len();
@@ -85,6 +119,7 @@ impl IndexingServiceInterface for IndexingServiceImpl {
         let collection_id = *collection;
         let op_id = operation_id;
         let workspace_root = path.to_path_buf();
+        let org_id = org_id.to_owned();
 
         // Fire-and-forget: caller gets operation_id immediately, polling for completion.
         // Sync execution path available via run_indexing_task() directly in tests.
@@ -95,6 +130,7 @@ impl IndexingServiceInterface for IndexingServiceImpl {
                 workspace_root,
                 collection_id,
                 op_id,
+                org_id,
             )
             .await;
         });
@@ -104,7 +140,7 @@ impl IndexingServiceInterface for IndexingServiceImpl {
             files_processed: 0,
             chunks_created: 0,
             files_skipped: 0,
-            errors: vec![],
+            errors: warnings,
             operation_id: Some(operation_id),
             status: mcb_utils::constants::INDEXING_STATUS_STARTED.to_owned(),
         })
@@ -129,14 +165,34 @@ impl IndexingServiceInterface for IndexingServiceImpl {
 
     /// # Errors
     ///
-    /// Returns an error if the context service or hash repository fails to clear
-    /// the collection.
+    /// Returns an error if the context service, hash repository, snapshot
+    /// store, test link, module import, collection manifest or workspace
+    /// repository fails to clear the collection.
     async fn clear_collection(&self, collection: &CollectionId) -> Result<()> {
         self.context_service.clear_collection(collection).await?;
         // Also clear stale hashes so next indexing re-processes all files
         if let Some(repo) = &self.file_hash_repository {
             repo.clear_collection(&collection.to_string()).await?;
         }
+        if let Some(snapshots) = &self.file_snapshot_repository {
+            snapshots.prune_unreferenced().await?;
+        }
+        if let Some(links) = &self.test_link_repository {
+            links.clear_collection(&collection.to_string()).await?;
+        }
+        if let Some(imports) = &self.module_import_repository {
+            imports.clear_collection(&collection.to_string()).await?;
+        }
+        if let Some(manifests) = &self.collection_manifest_repository {
+            manifests.clear_collection(&collection.to_string()).await?;
+        }
+        if let Some(workspaces) = &self.workspace_repository {
+            // Copies served by this collection must be embedded by their own.
+            let collection = collection.to_string();
+            workspaces.clear_collection(&collection).await?;
+            let released = workspaces.release_copies(&collection, None).await?;
+            self.invalidate_copies(&released).await?;
+        }
         Ok(())
     }
 }
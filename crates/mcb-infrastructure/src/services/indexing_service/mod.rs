@@ -19,15 +19,25 @@
 //! - `FileHashRepository`: For change detection.
 //! - `EventBusProvider`: For system-wide notifications.
 
+mod allowlist;
 mod discovery;
+mod estimate;
+mod imports;
 mod interface;
 mod processing;
 mod progress;
 mod registry;
 mod service;
+mod test_links;
+mod throttle;
 
+pub use allowlist::resolve_allowed_path;
+pub use estimate::estimate_codebase;
+pub use imports::extract_imports;
 pub use processing::*;
 pub use progress::IndexingProgress;
 pub use service::{
     IndexingServiceDeps, IndexingServiceImpl, IndexingServiceWithHashDeps, ProcessResult,
 };
+pub use test_links::extract_test_links;
+pub use throttle::IndexingThrottle;
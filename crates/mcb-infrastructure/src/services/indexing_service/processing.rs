@@ -3,13 +3,21 @@
 //! This module handles the core indexing work: processing individual files,
 //! computing hashes, chunking content, and publishing completion events.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+use mcb_domain::entities::usage::UsageRecord;
+use mcb_domain::entities::workspace::{CollectionFile, SharedFile};
 use mcb_domain::error::Result;
 use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::{JobPriority, Workload};
 use mcb_domain::value_objects::{CollectionId, OperationId};
 use mcb_utils::constants::INDEXING_STATUS_COMPLETED;
+use mcb_utils::constants::keys::METADATA_KEY_LAST_MODIFIED;
+use mcb_utils::constants::lang::CHUNKER_VERSION;
+use mcb_utils::constants::limits::CHARS_PER_TOKEN_ESTIMATE;
+use mcb_utils::utils::path::strict_canonicalize;
 
 use super::{IndexingProgress, IndexingServiceImpl, ProcessResult};
 
@@ -57,6 +65,48 @@ fn log_indexing_completion(
     }
 }
 
+/// Record the current chunker version, distance metric, embedding model and
+/// indexed root once every file of the collection was re-chunked; skipped or
+/// failed files may still hold older chunks.
+async fn record_build(service: &IndexingServiceImpl, collection: &CollectionId, root: &Path) {
+    let Some(manifests) = &service.collection_manifest_repository else {
+        return;
+    };
+    let root = strict_canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    if let Err(e) = manifests
+        .record_build(
+            &collection.to_string(),
+            CHUNKER_VERSION,
+            service.context_service.distance_metric(),
+            &service.context_service.embedding_model(),
+            &root.to_string_lossy(),
+        )
+        .await
+    {
+        mcb_domain::warn!("indexing", "Failed to record collection manifest", &e);
+    }
+}
+
+/// Add what the run chunked and embedded to the organization's monthly usage.
+async fn record_usage(
+    service: &IndexingServiceImpl,
+    ctx: &FileIndexContext<'_>,
+    totals: &ProcessTotals,
+) {
+    let Some(usage) = &service.usage_repository else {
+        return;
+    };
+    if totals.indexed_bytes == 0 {
+        return;
+    }
+    let mut record = UsageRecord::current(ctx.org_id, &ctx.collection.to_string());
+    record.indexed_bytes = totals.indexed_bytes;
+    record.embedding_tokens = totals.embedding_tokens;
+    if let Err(e) = usage.add_usage(&record).await {
+        mcb_domain::warn!("indexing", "Failed to record indexing usage", &e);
+    }
+}
+
 /// Aggregated outcome of an indexing run, consumed when finalizing the task.
 struct IndexingOutcome {
     total: usize,
@@ -70,6 +120,7 @@ async fn finish_indexing_task(
     service: &IndexingServiceImpl,
     operation_id: &OperationId,
     collection: &CollectionId,
+    workspace_root: &Path,
     outcome: IndexingOutcome,
 ) {
     let IndexingOutcome {
@@ -90,6 +141,10 @@ async fn finish_indexing_task(
     let files_skipped = total.saturating_sub(files_processed);
     let error_count = failed_files.len();
 
+    if files_processed == total && error_count == 0 {
+        record_build(service, collection, workspace_root).await;
+    }
+
     let result =
         IndexingProgress::with_counts(files_processed, chunks_created, files_skipped, failed_files)
             .into_result(Some(*operation_id), INDEXING_STATUS_COMPLETED);
@@ -106,12 +161,18 @@ pub struct FileIndexContext<'a> {
     pub collection: &'a CollectionId,
     /// Operation identifier used for progress reporting.
     pub operation_id: &'a OperationId,
+    /// Organization the job runs for; background slots rotate between orgs.
+    pub org_id: &'a str,
+    /// Last commit time per workspace-relative path (empty outside a VCS checkout).
+    pub last_modified: &'a HashMap<String, i64>,
 }
 
 /// Running totals accumulated while processing the file batch.
 struct ProcessTotals {
     chunks_created: usize,
     files_processed: usize,
+    indexed_bytes: u64,
+    embedding_tokens: u64,
     failed_files: Vec<String>,
 }
 
@@ -124,14 +185,34 @@ async fn process_files(
     let mut totals = ProcessTotals {
         chunks_created: 0,
         files_processed: 0,
+        indexed_bytes: 0,
+        embedding_tokens: 0,
         failed_files: Vec::new(),
     };
 
     for (i, file_path) in files.iter().enumerate() {
+        service.throttle.before_file().await;
+        let _pool = match &service.bulkheads {
+            Some(bulkheads) => Some(bulkheads.enter(Workload::Indexing).await),
+            None => None,
+        };
+        let _permit = match &service.job_scheduler {
+            Some(scheduler) => Some(scheduler.acquire(ctx.org_id, JobPriority::Background).await),
+            None => None,
+        };
         match service.process_file(ctx, file_path, i).await {
-            Ok(ProcessResult::Processed { chunks }) => {
+            Ok(ProcessResult::Processed {
+                chunks,
+                bytes,
+                tokens,
+            }) => {
                 totals.files_processed += 1;
                 totals.chunks_created += chunks;
+                totals.indexed_bytes += bytes as u64;
+                totals.embedding_tokens += tokens as u64;
+            }
+            Ok(ProcessResult::Shared) => {
+                totals.files_processed += 1;
             }
             Ok(ProcessResult::Skipped) => {
                 // File hasn't changed, increment skip count but don't record as processed
@@ -157,22 +238,28 @@ pub async fn run_indexing_task(
     workspace_root: PathBuf,
     collection: CollectionId,
     operation_id: OperationId,
+    org_id: String,
 ) {
     let start = Instant::now();
     let total = files.len();
+    let last_modified = service.resolve_last_modified(&workspace_root).await;
 
     let ctx = FileIndexContext {
         workspace_root: &workspace_root,
         collection: &collection,
         operation_id: &operation_id,
+        org_id: &org_id,
+        last_modified: &last_modified,
     };
 
     let totals = process_files(&service, &ctx, &files).await;
+    record_usage(&service, &ctx, &totals).await;
 
     finish_indexing_task(
         &service,
         &operation_id,
         &collection,
+        &workspace_root,
         IndexingOutcome {
             total,
             files_processed: totals.files_processed,
@@ -185,6 +272,38 @@ pub async fn run_indexing_task(
 }
 
 impl IndexingServiceImpl {
+    /// Last commit time of every tracked file, keyed by workspace-relative path.
+    ///
+    /// Best-effort: without a VCS provider, or outside a repository, the map is
+    /// empty and chunks are stored without a `last_modified` timestamp.
+    async fn resolve_last_modified(&self, workspace_root: &Path) -> HashMap<String, i64> {
+        let Some(vcs) = &self.vcs_provider else {
+            return HashMap::new();
+        };
+        let times = match vcs.open_repository(workspace_root).await {
+            Ok(repo) => vcs
+                .file_last_modified(&repo, repo.default_branch())
+                .await
+                .map(|times| (repo.path().clone(), times)),
+            Err(e) => Err(e),
+        };
+        match times {
+            Ok((repo_root, times)) => times
+                .into_iter()
+                .filter_map(|(path, time)| {
+                    let relative =
+                        Self::workspace_relative_path(&repo_root.join(path), workspace_root)
+                            .ok()?;
+                    Some((relative, time))
+                })
+                .collect(),
+            Err(e) => {
+                mcb_domain::debug!("indexing", "Commit times unavailable", &e);
+                HashMap::new()
+            }
+        }
+    }
+
     /// Process a single file: check for changes, chunk it, and store results.
     ///
     /// # Errors
@@ -216,21 +335,106 @@ impl IndexingServiceImpl {
         }
     }
 
+    /// Record the file as a copy when an identical file is indexed in another
+    /// collection of a workspace the collection belongs to.
+    async fn record_if_shared(
+        &self,
+        collection: &str,
+        relative_path: &str,
+        content_hash: &str,
+    ) -> Result<bool> {
+        let Some(workspaces) = &self.workspace_repository else {
+            return Ok(false);
+        };
+        let Some(origin) = workspaces.find_origin(collection, content_hash).await? else {
+            return Ok(false);
+        };
+        workspaces
+            .record_shared_file(&SharedFile {
+                copy: CollectionFile {
+                    collection: collection.to_owned(),
+                    file_path: relative_path.to_owned(),
+                },
+                origin,
+                content_hash: content_hash.to_owned(),
+            })
+            .await?;
+        Ok(true)
+    }
+
+    /// The file now holds its own chunks: drop its copy record and hand its
+    /// former copies back to their collections, which re-embed them on their
+    /// next index run.
+    async fn release_shared_file(&self, collection: &str, relative_path: &str) -> Result<()> {
+        let Some(workspaces) = &self.workspace_repository else {
+            return Ok(());
+        };
+        workspaces
+            .forget_shared_file(collection, relative_path)
+            .await?;
+        let released = workspaces
+            .release_copies(collection, Some(relative_path))
+            .await?;
+        self.invalidate_copies(&released).await
+    }
+
+    /// Tombstone the hashes of released copies so they count as changed.
+    pub(super) async fn invalidate_copies(&self, released: &[SharedFile]) -> Result<()> {
+        if let Some(hashes) = &self.file_hash_repository {
+            for shared in released {
+                hashes
+                    .mark_deleted(&shared.copy.collection, &shared.copy.file_path)
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Chunk and store `content`, returning the chunk count and the
+    /// estimated tokens embedded for them.
     async fn create_and_store_chunks(
         &self,
         content: &str,
         relative_path: &str,
-        collection: &CollectionId,
-    ) -> Result<usize> {
-        let chunks = self.language_chunker.chunk(content, relative_path);
+        ctx: &FileIndexContext<'_>,
+    ) -> Result<(usize, usize)> {
+        let mut chunks = self.language_chunker.chunk(content, relative_path);
         let chunk_count = chunks.len();
+        let tokens = chunks
+            .iter()
+            .map(|chunk| chunk.content.len().div_ceil(CHARS_PER_TOKEN_ESTIMATE))
+            .sum();
+
+        if let Some(&time) = ctx.last_modified.get(relative_path) {
+            for chunk in &mut chunks {
+                if !chunk.metadata.is_object() {
+                    chunk.metadata = serde_json::Value::Object(serde_json::Map::new());
+                }
+                if let Some(metadata) = chunk.metadata.as_object_mut() {
+                    metadata.insert(METADATA_KEY_LAST_MODIFIED.to_owned(), time.into());
+                }
+            }
+        }
 
         if !chunks.is_empty() {
+            self.throttle.before_embedding().await;
             self.context_service
-                .store_chunks(collection, &chunks)
+                .store_chunks(ctx.collection, &chunks)
+                .await?;
+        }
+        if let Some(repo) = &self.test_link_repository {
+            let collection = ctx.collection.to_string();
+            let links = super::extract_test_links(&collection, relative_path, &chunks);
+            repo.replace_file_links(&collection, relative_path, &links)
+                .await?;
+        }
+        if let Some(repo) = &self.module_import_repository {
+            let collection = ctx.collection.to_string();
+            let imports = super::extract_imports(&collection, relative_path, content);
+            repo.replace_file_imports(&collection, relative_path, &imports)
                 .await?;
         }
-        Ok(chunk_count)
+        Ok((chunk_count, tokens))
     }
 
     /// Process a single file: check for changes, chunk it, and store results.
@@ -251,6 +455,7 @@ impl IndexingServiceImpl {
 
         let content = std::fs::read_to_string(file_path)
             .map_err(|e| mcb_domain::error::Error::internal(format!("Failed to read file: {e}")))?;
+        self.throttle.after_read().await;
 
         let current_hash = match self
             .check_incremental(ctx.collection, &relative_path, &content)
@@ -260,17 +465,36 @@ impl IndexingServiceImpl {
             None => return Ok(ProcessResult::Skipped),
         };
 
-        let chunk_count = self
-            .create_and_store_chunks(&content, &relative_path, ctx.collection)
+        let collection = ctx.collection.to_string();
+        if self
+            .record_if_shared(&collection, &relative_path, &current_hash)
+            .await?
+        {
+            if let Some(repo) = &self.file_hash_repository {
+                repo.upsert_hash(&collection, &relative_path, &current_hash)
+                    .await?;
+            }
+            return Ok(ProcessResult::Shared);
+        }
+
+        let (chunk_count, tokens) = self
+            .create_and_store_chunks(&content, &relative_path, ctx)
+            .await?;
+        self.release_shared_file(&collection, &relative_path)
             .await?;
 
+        if let Some(snapshots) = &self.file_snapshot_repository {
+            snapshots.store_snapshot(&current_hash, &content).await?;
+        }
         if let Some(repo) = &self.file_hash_repository {
-            repo.upsert_hash(&ctx.collection.to_string(), &relative_path, &current_hash)
+            repo.upsert_hash(&collection, &relative_path, &current_hash)
                 .await?;
         }
 
         Ok(ProcessResult::Processed {
             chunks: chunk_count,
+            bytes: content.len(),
+            tokens,
         })
     }
 }
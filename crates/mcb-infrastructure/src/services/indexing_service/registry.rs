@@ -13,12 +13,13 @@ use mcb_domain::registry::admin_operations::{
 use mcb_domain::registry::database::resolve_database_repositories;
 use mcb_domain::registry::language::{LanguageProviderConfig, resolve_language_provider};
 use mcb_domain::registry::services::{ServiceBuilder, resolve_context_service};
+use mcb_domain::registry::vcs::{VcsProviderConfig, resolve_vcs_provider};
 
 use super::{IndexingServiceDeps, IndexingServiceImpl, IndexingServiceWithHashDeps};
 
 use mcb_utils::constants::{
     DEFAULT_DATABASE_PROVIDER, DEFAULT_INDEXING_OP_PROVIDER, DEFAULT_LANGUAGE_PROVIDER,
-    DEFAULT_NAMESPACE,
+    DEFAULT_NAMESPACE, DEFAULT_VCS_PROVIDER,
 };
 
 /// Build the `IndexingService` from the application registry.
@@ -60,19 +61,35 @@ fn build_indexing_service_from_registry(
             DEFAULT_INDEXING_OP_PROVIDER,
         ))?;
     let event_bus = Arc::clone(&ctx.event_bus);
+    let vcs_provider = resolve_vcs_provider(&VcsProviderConfig::new(DEFAULT_VCS_PROVIDER))?;
 
-    Ok(Arc::new(
-        IndexingServiceImpl::new_with_file_hash_repository(IndexingServiceWithHashDeps {
-            service: IndexingServiceDeps {
-                context_service,
-                language_chunker,
-                indexing_ops,
-                event_bus,
-                supported_extensions: app_config.mcp.indexing.supported_extensions.clone(),
-            },
-            file_hash_repository: repositories.file_hash,
-        }),
-    ))
+    let file_snapshot = Arc::clone(&repositories.file_snapshot);
+    let service = IndexingServiceImpl::new_with_file_hash_repository(IndexingServiceWithHashDeps {
+        service: IndexingServiceDeps {
+            context_service,
+            language_chunker,
+            indexing_ops,
+            event_bus,
+            supported_extensions: app_config.mcp.indexing.supported_extensions.clone(),
+        },
+        file_hash_repository: repositories.file_hash,
+    })
+    .with_vcs_provider(vcs_provider)
+    .with_test_link_repository(Arc::clone(&repositories.test_link))
+    .with_module_import_repository(Arc::clone(&repositories.module_import))
+    .with_collection_manifest_repository(Arc::clone(&repositories.collection_manifest))
+    .with_workspace_repository(Arc::clone(&repositories.workspace))
+    .with_usage_repository(Arc::clone(&repositories.usage))
+    .with_job_scheduler(Arc::clone(&ctx.job_scheduler))
+    .with_bulkheads(Arc::clone(&ctx.bulkheads))
+    .with_throttle(app_config.mcp.indexing.throttle)
+    .with_allowed_roots(app_config.mcp.indexing.allowed_roots.clone());
+
+    Ok(Arc::new(if app_config.mcp.indexing.store_snapshots {
+        service.with_file_snapshot_repository(file_snapshot)
+    } else {
+        service
+    }))
 }
 
 mcb_domain::register_service!(
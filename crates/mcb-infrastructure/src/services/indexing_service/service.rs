@@ -1,14 +1,18 @@
 //! Indexing service types and implementation.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use mcb_domain::error::Result;
 use mcb_domain::ports::{
-    ContextServiceInterface, EventBusProvider, FileHashRepository, IndexingOperationsInterface,
-    LanguageChunkingProvider,
+    BulkheadInterface, CollectionManifestRepository, ContextServiceInterface, EventBusProvider,
+    FileHashRepository, FileSnapshotRepository, IndexingOperationsInterface,
+    IndexingThrottlePolicy, JobSchedulerInterface, LanguageChunkingProvider,
+    ModuleImportRepository, TestLinkRepository, UsageRepository, VcsProvider, WorkspaceRepository,
 };
 
+use super::IndexingThrottle;
+
 /// Constructor dependency bundle for `IndexingServiceImpl`.
 pub struct IndexingServiceDeps {
     /// Embedding pipeline and chunk persistence.
@@ -42,7 +46,18 @@ pub struct IndexingServiceImpl {
     pub(super) indexing_ops: Arc<dyn IndexingOperationsInterface>,
     pub(super) event_bus: Arc<dyn EventBusProvider>,
     pub(super) file_hash_repository: Option<Arc<dyn FileHashRepository>>,
+    pub(super) vcs_provider: Option<Arc<dyn VcsProvider>>,
+    pub(super) file_snapshot_repository: Option<Arc<dyn FileSnapshotRepository>>,
+    pub(super) test_link_repository: Option<Arc<dyn TestLinkRepository>>,
+    pub(super) module_import_repository: Option<Arc<dyn ModuleImportRepository>>,
+    pub(super) collection_manifest_repository: Option<Arc<dyn CollectionManifestRepository>>,
+    pub(super) job_scheduler: Option<Arc<dyn JobSchedulerInterface>>,
+    pub(super) bulkheads: Option<Arc<dyn BulkheadInterface>>,
+    pub(super) workspace_repository: Option<Arc<dyn WorkspaceRepository>>,
+    pub(super) usage_repository: Option<Arc<dyn UsageRepository>>,
+    pub(super) throttle: Arc<IndexingThrottle>,
     pub(super) supported_extensions: Vec<String>,
+    pub(super) allowed_roots: Vec<PathBuf>,
 }
 
 impl IndexingServiceImpl {
@@ -72,7 +87,18 @@ impl IndexingServiceImpl {
             indexing_ops,
             event_bus,
             file_hash_repository: None,
+            vcs_provider: None,
+            file_snapshot_repository: None,
+            test_link_repository: None,
+            module_import_repository: None,
+            collection_manifest_repository: None,
+            job_scheduler: None,
+            bulkheads: None,
+            workspace_repository: None,
+            usage_repository: None,
+            throttle: Arc::default(),
             supported_extensions: Self::normalize_supported_extensions(supported_extensions),
+            allowed_roots: Vec::new(),
         }
     }
 
@@ -89,13 +115,121 @@ impl IndexingServiceImpl {
             indexing_ops: service.indexing_ops,
             event_bus: service.event_bus,
             file_hash_repository: Some(file_hash_repository),
+            vcs_provider: None,
+            file_snapshot_repository: None,
+            test_link_repository: None,
+            module_import_repository: None,
+            collection_manifest_repository: None,
+            job_scheduler: None,
+            bulkheads: None,
+            workspace_repository: None,
+            usage_repository: None,
+            throttle: Arc::default(),
             supported_extensions: Self::normalize_supported_extensions(
                 service.supported_extensions,
             ),
+            allowed_roots: Vec::new(),
         }
     }
 
-    fn normalize_supported_extensions(extensions: Vec<String>) -> Vec<String> {
+    /// Record each chunk's last commit time from `vcs_provider` for recency ranking.
+    #[must_use]
+    pub fn with_vcs_provider(mut self, vcs_provider: Arc<dyn VcsProvider>) -> Self {
+        self.vcs_provider = Some(vcs_provider);
+        self
+    }
+
+    /// Store a full snapshot of every indexed file in `file_snapshot_repository`.
+    #[must_use]
+    pub fn with_file_snapshot_repository(
+        mut self,
+        file_snapshot_repository: Arc<dyn FileSnapshotRepository>,
+    ) -> Self {
+        self.file_snapshot_repository = Some(file_snapshot_repository);
+        self
+    }
+
+    /// Record links from indexed test functions to the symbols they exercise.
+    #[must_use]
+    pub fn with_test_link_repository(
+        mut self,
+        test_link_repository: Arc<dyn TestLinkRepository>,
+    ) -> Self {
+        self.test_link_repository = Some(test_link_repository);
+        self
+    }
+
+    /// Record the imports of every indexed file for the module dependency graph.
+    #[must_use]
+    pub fn with_module_import_repository(
+        mut self,
+        module_import_repository: Arc<dyn ModuleImportRepository>,
+    ) -> Self {
+        self.module_import_repository = Some(module_import_repository);
+        self
+    }
+
+    /// Record the chunker version of each completely indexed collection and
+    /// warn when a collection was built by an older chunker.
+    #[must_use]
+    pub fn with_collection_manifest_repository(
+        mut self,
+        collection_manifest_repository: Arc<dyn CollectionManifestRepository>,
+    ) -> Self {
+        self.collection_manifest_repository = Some(collection_manifest_repository);
+        self
+    }
+
+    /// Take a background slot from `job_scheduler` for every file, so
+    /// concurrent index jobs share a global limit and yield to searches.
+    #[must_use]
+    pub fn with_job_scheduler(mut self, job_scheduler: Arc<dyn JobSchedulerInterface>) -> Self {
+        self.job_scheduler = Some(job_scheduler);
+        self
+    }
+
+    /// Index every file inside the indexing pool of `bulkheads`, so index
+    /// jobs cannot take capacity from searches or admin calls.
+    #[must_use]
+    pub fn with_bulkheads(mut self, bulkheads: Arc<dyn BulkheadInterface>) -> Self {
+        self.bulkheads = Some(bulkheads);
+        self
+    }
+
+    /// Record files identical to a file already indexed in another
+    /// collection of the same workspace as copies instead of embedding them.
+    #[must_use]
+    pub fn with_workspace_repository(
+        mut self,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+    ) -> Self {
+        self.workspace_repository = Some(workspace_repository);
+        self
+    }
+
+    /// Add the bytes and estimated embedding tokens of each run to the
+    /// monthly usage of the organization it runs for.
+    #[must_use]
+    pub fn with_usage_repository(mut self, usage_repository: Arc<dyn UsageRepository>) -> Self {
+        self.usage_repository = Some(usage_repository);
+        self
+    }
+
+    /// Pace background indexing according to `policy`.
+    #[must_use]
+    pub fn with_throttle(mut self, policy: IndexingThrottlePolicy) -> Self {
+        self.throttle = Arc::new(IndexingThrottle::new(policy));
+        self
+    }
+
+    /// Only index directories under one of `allowed_roots`; empty allows any path.
+    #[must_use]
+    pub fn with_allowed_roots(mut self, allowed_roots: Vec<PathBuf>) -> Self {
+        self.allowed_roots = allowed_roots;
+        self
+    }
+
+    pub(super) fn normalize_supported_extensions(extensions: Vec<String>) -> Vec<String> {
         extensions
             .into_iter()
             .map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase())
@@ -111,7 +245,14 @@ pub enum ProcessResult {
     Processed {
         /// Number of chunks created from this file.
         chunks: usize,
+        /// Bytes of file content chunked.
+        bytes: usize,
+        /// Estimated tokens embedded for the chunks.
+        tokens: usize,
     },
+    /// File is identical to a file of another workspace collection and was
+    /// recorded as a copy of it without being embedded.
+    Shared,
     /// File was skipped because it hasn't changed.
     Skipped,
 }
@@ -0,0 +1,185 @@
+//! Test-to-code link extraction.
+//!
+//! Finds the test functions among a file's chunks and links each one to the
+//! symbols it exercises: everything its body calls (`parse(`, `.render(`,
+//! `Config::load(`) or names as a type path (`Parser::`), plus the symbol its
+//! own name points at (`test_parse_config` → `parse_config`,
+//! `TestParse` → `Parse`, `testParse` → `parse`).
+//!
+//! A function counts as a test when its leading lines carry a test attribute
+//! or annotation, its name has a test prefix, or it lives in a test file
+//! (`tests/` directories, `test_*.py`, `*_test.go`, `*.spec.ts`, ...).
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::entities::test_link::{TestLink, TestLinkReason};
+use mcb_utils::constants::search::MMR_SYMBOL_SCAN_LINES;
+
+use crate::services::search_service::SearchServiceImpl;
+
+/// Attributes and annotations that mark a function as a test.
+const TEST_MARKERS: &[&str] = &[
+    "#[test]",
+    "#[tokio::test",
+    "#[rstest",
+    "#[test_case",
+    "@Test",
+    "@ParameterizedTest",
+    "@pytest",
+];
+
+/// Directory names whose files are all tests.
+const TEST_DIRS: &[&str] = &["tests", "test", "__tests__", "spec", "specs"];
+
+/// Called names too generic to be worth linking.
+const IGNORED_CALLS: &[&str] = &[
+    "if",
+    "while",
+    "for",
+    "match",
+    "return",
+    "fn",
+    "def",
+    "func",
+    "function",
+    "Ok",
+    "Err",
+    "Some",
+    "None",
+    "Box",
+    "Arc",
+    "Vec",
+    "String",
+    "len",
+    "unwrap",
+    "expect",
+    "clone",
+    "to_string",
+    "to_owned",
+    "into",
+    "iter",
+    "collect",
+    "map",
+    "push",
+    "print",
+    "println",
+    "str",
+    "int",
+    "assert",
+    "assertEquals",
+    "assertTrue",
+];
+
+/// Whether `path` is a test file by directory or file name convention.
+fn is_test_file(path: &str) -> bool {
+    let path = Path::new(path);
+    let in_test_dir = path.parent().is_some_and(|dir| {
+        dir.components().any(|c| {
+            c.as_os_str()
+                .to_str()
+                .is_some_and(|c| TEST_DIRS.contains(&c))
+        })
+    });
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    in_test_dir
+        || stem.starts_with("test_")
+        || [
+            "_test", "_tests", ".test", ".spec", "Test", "Tests", "_spec",
+        ]
+        .iter()
+        .any(|suffix| stem.ends_with(suffix))
+}
+
+/// Symbol a test name points at, e.g. `test_parse_config` → `parse_config`.
+fn name_target(test_name: &str) -> Option<String> {
+    if let Some(rest) = test_name.strip_prefix("test_") {
+        return (!rest.is_empty()).then(|| rest.to_owned());
+    }
+    if let Some(rest) = test_name.strip_prefix("Test") {
+        return rest
+            .starts_with(char::is_uppercase)
+            .then(|| rest.to_owned());
+    }
+    let rest = test_name.strip_prefix("test")?;
+    let mut chars = rest.chars();
+    let first = chars.next().filter(|c| c.is_uppercase())?;
+    Some(first.to_lowercase().chain(chars).collect())
+}
+
+/// Names the code calls, plus capitalized path segments (`Parser::`).
+fn referenced_symbols(content: &str) -> Vec<&str> {
+    let mut found = Vec::new();
+    let mut rest = content;
+    while let Some(start) = rest.find(|c: char| c.is_alphabetic() || c == '_') {
+        let tail = &rest[start..];
+        let len = tail
+            .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+            .unwrap_or(tail.len());
+        let (ident, after) = tail.split_at(len);
+        let after = after.trim_start();
+        let after_generics = after
+            .strip_prefix("::<")
+            .and_then(|g| g.find('>').map(|end| g[end + 1..].trim_start()))
+            .unwrap_or(after);
+        let called = after_generics.starts_with('(');
+        let type_path = after.starts_with("::") && ident.starts_with(char::is_uppercase);
+        if (called || type_path) && ident.len() > 1 && !IGNORED_CALLS.contains(&ident) {
+            found.push(ident);
+        }
+        rest = after;
+    }
+    found
+}
+
+/// Name of `chunk` if it is a test function.
+fn test_name(chunk: &CodeChunk, in_test_file: bool) -> Option<&str> {
+    let kind = chunk.metadata.get("node_type")?.as_str()?;
+    if !(kind.contains("function") || kind.contains("method")) {
+        return None;
+    }
+    let name = SearchServiceImpl::symbol_name(&chunk.content)?;
+    let marked = chunk
+        .content
+        .lines()
+        .take(MMR_SYMBOL_SCAN_LINES)
+        .any(|line| {
+            let line = line.trim_start();
+            TEST_MARKERS.iter().any(|m| line.starts_with(m))
+        });
+    (in_test_file || marked || name_target(name).is_some()).then_some(name)
+}
+
+/// Link every test function in `chunks` of `path` to the symbols it exercises.
+///
+/// A test linked to a symbol both ways keeps the [`TestLinkReason::Call`] link.
+#[must_use]
+pub fn extract_test_links(collection: &str, path: &str, chunks: &[CodeChunk]) -> Vec<TestLink> {
+    let in_test_file = is_test_file(path);
+    let mut links = Vec::new();
+    for chunk in chunks {
+        let Some(name) = test_name(chunk, in_test_file) else {
+            continue;
+        };
+        let mut targets: BTreeMap<String, TestLinkReason> = BTreeMap::new();
+        if let Some(target) = name_target(name) {
+            targets.insert(target, TestLinkReason::Name);
+        }
+        for target in referenced_symbols(&chunk.content) {
+            if target != name {
+                targets.insert(target.to_owned(), TestLinkReason::Call);
+            }
+        }
+        links.extend(targets.into_iter().map(|(target, reason)| TestLink {
+            collection: collection.to_owned(),
+            test_file: path.to_owned(),
+            test_name: name.to_owned(),
+            start_line: chunk.start_line,
+            end_line: chunk.end_line,
+            target,
+            reason,
+        }));
+    }
+    links
+}
@@ -0,0 +1,83 @@
+//! Pacing of background indexing work.
+//!
+//! Rates are shared by every index job of one service, so two concurrent
+//! re-indexes together stay within the configured limits.
+
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+use mcb_domain::ports::IndexingThrottlePolicy;
+use tokio::time::Instant;
+
+/// Spaces events evenly at a fixed rate.
+struct Pacer {
+    interval: Duration,
+    next: Mutex<Option<Instant>>,
+}
+
+impl Pacer {
+    fn per_sec(rate: u32) -> Option<Self> {
+        (rate > 0).then(|| Self {
+            interval: Duration::from_secs(1) / rate,
+            next: Mutex::new(None),
+        })
+    }
+
+    /// Wait for the next free slot and book the one after it.
+    async fn wait(&self) {
+        let slot = {
+            let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+            let now = Instant::now();
+            let slot = next.map_or(now, |next| next.max(now));
+            *next = Some(slot + self.interval);
+            slot
+        };
+        tokio::time::sleep_until(slot).await;
+    }
+}
+
+/// Applies an [`IndexingThrottlePolicy`] to a running index job.
+pub struct IndexingThrottle {
+    files: Option<Pacer>,
+    embeddings: Option<Pacer>,
+    io_pause: Duration,
+}
+
+impl IndexingThrottle {
+    /// Create a throttle enforcing `policy`.
+    #[must_use]
+    pub fn new(policy: IndexingThrottlePolicy) -> Self {
+        Self {
+            files: Pacer::per_sec(policy.max_files_per_sec),
+            embeddings: Pacer::per_sec(policy.max_embedding_requests_per_sec),
+            io_pause: Duration::from_millis(policy.io_pause_ms),
+        }
+    }
+
+    /// Wait until the next file may be started.
+    pub async fn before_file(&self) {
+        if let Some(pacer) = &self.files {
+            pacer.wait().await;
+        }
+    }
+
+    /// Wait until the next embedding request may be sent.
+    pub async fn before_embedding(&self) {
+        if let Some(pacer) = &self.embeddings {
+            pacer.wait().await;
+        }
+    }
+
+    /// Yield the disk to other processes after a file read.
+    pub async fn after_read(&self) {
+        if !self.io_pause.is_zero() {
+            tokio::time::sleep(self.io_pause).await;
+        }
+    }
+}
+
+impl Default for IndexingThrottle {
+    fn default() -> Self {
+        Self::new(IndexingThrottlePolicy::default())
+    }
+}
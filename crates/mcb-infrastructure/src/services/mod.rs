@@ -6,10 +6,21 @@
 //! ## Services
 //!
 //! - [`AgentSessionServiceImpl`] — Agent session lifecycle, tool history, checkpoints
+//! - [`ChangeSummaryServiceImpl`] — Symbol-level change summaries between revisions or since the last index
 //! - [`ContextServiceImpl`] — Embedding pipeline, vector lifecycle, semantic search
+//! - [`DuplicateDetectionServiceImpl`] — Clone detection (exact, renamed, gapped) over indexed chunks
+//! - [`FileContentServiceImpl`] — Line-range reads of indexed files (disk or snapshot)
+//! - [`GrepServiceImpl`] — Literal/regex scan of the indexed snapshot, semantic intersection
+//! - [`IndexEvaluationServiceImpl`] — Synthetic queries from sampled chunks, index-quality score
 //! - [`IndexingServiceImpl`] — File discovery, language-aware chunking, async indexing
+//! - [`ModuleGraphServiceImpl`] — Import graph queries: dependencies, dependents, change impact
 //! - [`MemoryServiceImpl`] — Hybrid storage (FTS + vector), RRF fusion, timeline
+//! - [`RepoAnalysisServiceImpl`] — Repository profiling and onboarding config recommendations
 //! - [`SearchServiceImpl`] — Semantic search with application-level filtering
+//! - [`SearchFeedbackServiceImpl`] — Relevance feedback, learned per-file score boosts
+//! - [`SessionContextServiceImpl`] — Per-session seen-chunk tracking, token-budgeted context
+//! - [`UsageServiceImpl`] — Query counting, monthly usage reports per organization and collection
+//! - [`WorkspaceServiceImpl`] — Workspaces of related collections, federated search
 //!
 //! ## Dependency Injection
 //!
@@ -17,14 +28,36 @@
 //! They are wired via linkme-based service registries.
 
 pub mod agent_session_service;
+pub mod change_summary_service;
 pub mod context_service;
+pub mod duplicate_detection_service;
+pub mod file_content_service;
+pub mod grep_service;
 pub mod highlight_service;
+pub mod index_evaluation_service;
 pub mod indexing_service;
 pub mod memory_service;
+pub mod module_graph_service;
+pub mod repo_analysis_service;
+pub mod search_feedback_service;
 pub mod search_service;
+pub mod session_context_service;
+pub mod usage_service;
+pub mod workspace_service;
 
 pub use agent_session_service::*;
+pub use change_summary_service::*;
 pub use context_service::*;
+pub use duplicate_detection_service::*;
+pub use file_content_service::*;
+pub use grep_service::*;
+pub use index_evaluation_service::*;
 pub use indexing_service::*;
 pub use memory_service::*;
+pub use module_graph_service::*;
+pub use repo_analysis_service::*;
+pub use search_feedback_service::*;
 pub use search_service::*;
+pub use session_context_service::*;
+pub use usage_service::*;
+pub use workspace_service::*;
@@ -0,0 +1,341 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Module Graph Service Use Case
+//!
+//! # Overview
+//! The `ModuleGraphService` answers dependency questions about indexed files
+//! from the import edges recorded at index time. Each import's module path is
+//! resolved against the collection's files: a file is known by its path
+//! without extension and, for `mod.rs`, `lib.rs`, `main.rs`, `__init__.py`,
+//! `index.*` and Go files, by its directory. A module path matches a file
+//! known by the same path, by a longer path ending in it (`utils` matches
+//! `src/utils`), or by a suffix of it (a Go import path ending in
+//! `internal/auth`). Rust, Python, Java and Kotlin imports that name an item
+//! inside a module fall back to the enclosing module.
+
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::Path;
+use std::sync::Arc;
+
+use mcb_domain::entities::module_import::ModuleImport;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    ChangeImpact, FileHashRepository, ImpactedModule, ModuleDependencies, ModuleDependency,
+    ModuleGraphServiceInterface, ModuleImportRepository, ModuleImporter,
+};
+use mcb_domain::value_objects::CollectionId;
+
+use super::file_content_service::FileContentServiceImpl;
+
+/// File stems that stand for their directory.
+const INDEX_FILE_STEMS: &[&str] = &["mod", "lib", "main", "__init__", "index"];
+
+/// Whether imports from files with `ext` may name items inside modules, and
+/// the fewest path segments to keep when falling back to the enclosing module.
+fn item_fallback_floor(ext: &str) -> Option<usize> {
+    match ext {
+        "py" => Some(1),
+        "rs" | "java" | "kt" | "kts" => Some(2),
+        _ => None,
+    }
+}
+
+fn extension(path: &str) -> &str {
+    Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+}
+
+fn dir_of(path: &str) -> &str {
+    path.rsplit_once('/').map_or("", |(dir, _)| dir)
+}
+
+/// Module paths a file is known by.
+fn module_keys(file: &str) -> Vec<&str> {
+    let ext = extension(file);
+    let stem_path = if ext.is_empty() {
+        file
+    } else {
+        &file[..file.len() - ext.len() - 1]
+    };
+    let stem = stem_path.rsplit('/').next().unwrap_or(stem_path);
+    let mut keys = vec![stem_path];
+    if INDEX_FILE_STEMS.contains(&stem) || ext == "go" {
+        keys.push(dir_of(file));
+    }
+    keys.retain(|k| !k.is_empty());
+    keys
+}
+
+/// Proper segment-wise suffixes of a `/`-separated path, longest first.
+fn suffixes(path: &str) -> impl Iterator<Item = &str> {
+    path.match_indices('/').map(move |(i, _)| &path[i + 1..])
+}
+
+/// Module dependency graph of one collection, with every import resolved to
+/// the indexed files it points at.
+pub struct ModuleGraph {
+    files: BTreeSet<String>,
+    imports: Vec<ModuleImport>,
+    resolved: Vec<Vec<String>>,
+    by_source: HashMap<String, Vec<usize>>,
+    importers: HashMap<String, Vec<usize>>,
+}
+
+impl ModuleGraph {
+    /// Build the graph from the indexed `files` and their `imports`.
+    #[must_use]
+    pub fn new(files: impl IntoIterator<Item = String>, mut imports: Vec<ModuleImport>) -> Self {
+        imports.sort_by(|a, b| (&a.source_file, a.line).cmp(&(&b.source_file, b.line)));
+        let mut files: BTreeSet<String> = files.into_iter().collect();
+        files.extend(imports.iter().map(|i| i.source_file.clone()));
+
+        let mut exact: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+        let mut by_suffix: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+        for file in &files {
+            for key in module_keys(file) {
+                exact.entry(key).or_default().insert(file);
+                for suffix in suffixes(key) {
+                    by_suffix.entry(suffix).or_default().insert(file);
+                }
+            }
+        }
+
+        let resolved: Vec<Vec<String>> = imports
+            .iter()
+            .map(|import| Self::resolve(&exact, &by_suffix, import))
+            .collect();
+        let mut by_source: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut importers: HashMap<String, Vec<usize>> = HashMap::new();
+        for (i, (import, targets)) in imports.iter().zip(&resolved).enumerate() {
+            by_source
+                .entry(import.source_file.clone())
+                .or_default()
+                .push(i);
+            for target in targets {
+                importers.entry(target.clone()).or_default().push(i);
+            }
+        }
+        Self {
+            files,
+            imports,
+            resolved,
+            by_source,
+            importers,
+        }
+    }
+
+    /// Files `module_path` resolves to, trying an exact key, then longer
+    /// keys ending in it, then its own suffixes.
+    fn lookup<'a>(
+        exact: &HashMap<&str, BTreeSet<&'a str>>,
+        by_suffix: &HashMap<&str, BTreeSet<&'a str>>,
+        module_path: &str,
+    ) -> Vec<&'a str> {
+        exact
+            .get(module_path)
+            .or_else(|| by_suffix.get(module_path))
+            .or_else(|| {
+                suffixes(module_path)
+                    .filter(|s| s.contains('/'))
+                    .find_map(|s| exact.get(s))
+            })
+            .map(|found| found.iter().copied().collect())
+            .unwrap_or_default()
+    }
+
+    fn resolve(
+        exact: &HashMap<&str, BTreeSet<&str>>,
+        by_suffix: &HashMap<&str, BTreeSet<&str>>,
+        import: &ModuleImport,
+    ) -> Vec<String> {
+        let floor = item_fallback_floor(extension(&import.source_file));
+        let mut candidate = import.module_path.as_str();
+        loop {
+            let found: Vec<String> = Self::lookup(exact, by_suffix, candidate)
+                .into_iter()
+                .filter(|f| *f != import.source_file)
+                .map(str::to_owned)
+                .collect();
+            let segments = candidate.split('/').count();
+            if found.is_empty() && floor.is_some_and(|floor| segments > floor) {
+                candidate = dir_of(candidate);
+            } else {
+                return found;
+            }
+        }
+    }
+
+    /// Whether `path` is an indexed file of the graph.
+    #[must_use]
+    pub fn contains(&self, path: &str) -> bool {
+        self.files.contains(path)
+    }
+
+    /// Imports of `path` and the files importing it.
+    #[must_use]
+    pub fn dependencies(&self, path: &str) -> ModuleDependencies {
+        let imports = self
+            .by_source
+            .get(path)
+            .map(|indices| {
+                indices
+                    .iter()
+                    .map(|&i| ModuleDependency {
+                        specifier: self.imports[i].specifier.clone(),
+                        line: self.imports[i].line,
+                        resolved: self.resolved[i].clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let imported_by = self
+            .importers
+            .get(path)
+            .map(|indices| {
+                indices
+                    .iter()
+                    .map(|&i| ModuleImporter {
+                        file: self.imports[i].source_file.clone(),
+                        specifier: self.imports[i].specifier.clone(),
+                        line: self.imports[i].line,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        ModuleDependencies {
+            path: path.to_owned(),
+            imports,
+            imported_by,
+        }
+    }
+
+    /// Files reached from `paths` by following imports backwards, up to
+    /// `max_depth` hops, nearest first.
+    #[must_use]
+    pub fn impact(&self, paths: &[String], max_depth: usize) -> ChangeImpact {
+        let mut seen: HashSet<&str> = paths.iter().map(String::as_str).collect();
+        let mut queue: VecDeque<(&str, usize)> = paths.iter().map(|p| (p.as_str(), 0)).collect();
+        let mut impacted = Vec::new();
+        let mut truncated = false;
+        while let Some((file, depth)) = queue.pop_front() {
+            for &i in self.importers.get(file).map_or(&[][..], Vec::as_slice) {
+                let source = self.imports[i].source_file.as_str();
+                if seen.contains(source) {
+                    continue;
+                }
+                if depth >= max_depth {
+                    truncated = true;
+                    continue;
+                }
+                seen.insert(source);
+                impacted.push(ImpactedModule {
+                    path: source.to_owned(),
+                    depth: depth + 1,
+                    via: file.to_owned(),
+                });
+                queue.push_back((source, depth + 1));
+            }
+        }
+        impacted.sort_by(|a, b| (a.depth, &a.path).cmp(&(b.depth, &b.path)));
+        ChangeImpact {
+            changed: paths.to_vec(),
+            impacted,
+            truncated,
+        }
+    }
+}
+
+/// Implementation of the `ModuleGraphServiceInterface`.
+pub struct ModuleGraphServiceImpl {
+    imports: Arc<dyn ModuleImportRepository>,
+    file_hash: Arc<dyn FileHashRepository>,
+}
+
+impl ModuleGraphServiceImpl {
+    /// Create new module graph service with injected dependencies
+    pub fn new(
+        imports: Arc<dyn ModuleImportRepository>,
+        file_hash: Arc<dyn FileHashRepository>,
+    ) -> Self {
+        Self { imports, file_hash }
+    }
+
+    async fn graph(&self, collection: &CollectionId) -> Result<ModuleGraph> {
+        let collection = collection.to_string();
+        let files = self.file_hash.get_indexed_files(&collection).await?;
+        let imports = self.imports.list_imports(&collection).await?;
+        Ok(ModuleGraph::new(files, imports))
+    }
+
+    fn indexed_path(graph: &ModuleGraph, path: &str) -> Result<String> {
+        let path = FileContentServiceImpl::normalize_path(path)?;
+        if graph.contains(&path) {
+            Ok(path)
+        } else {
+            Err(Error::not_found(format!(
+                "'{path}' is not indexed in this collection"
+            )))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ModuleGraphServiceInterface for ModuleGraphServiceImpl {
+    async fn module_dependencies(
+        &self,
+        collection: &CollectionId,
+        path: &str,
+    ) -> Result<ModuleDependencies> {
+        let graph = self.graph(collection).await?;
+        let path = Self::indexed_path(&graph, path)?;
+        Ok(graph.dependencies(&path))
+    }
+
+    async fn impact_of_change(
+        &self,
+        collection: &CollectionId,
+        paths: &[String],
+        max_depth: usize,
+    ) -> Result<ChangeImpact> {
+        let graph = self.graph(collection).await?;
+        let paths = paths
+            .iter()
+            .map(|p| Self::indexed_path(&graph, p))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(graph.impact(&paths, max_depth))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+use mcb_utils::constants::{DEFAULT_DATABASE_PROVIDER, DEFAULT_NAMESPACE};
+
+/// Build a `ModuleGraphService` from the service resolution context.
+fn build_module_graph_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn ModuleGraphServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Module graph service builder requires ServiceResolutionContext")
+        })?;
+    let repos = mcb_domain::registry::database::resolve_database_repositories(
+        DEFAULT_DATABASE_PROVIDER,
+        Arc::clone(&ctx.db),
+        DEFAULT_NAMESPACE.to_owned(),
+    )?;
+    Ok(Arc::new(ModuleGraphServiceImpl::new(
+        repos.module_import,
+        repos.file_hash,
+    )))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_MODULE_GRAPH,
+    ServiceBuilder::ModuleGraph(build_module_graph_service_from_registry),
+);
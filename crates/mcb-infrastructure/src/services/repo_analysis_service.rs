@@ -0,0 +1,155 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Repository Analysis Service Use Case
+//!
+//! # Overview
+//! The `RepoAnalysisService` profiles a repository before it is first
+//! indexed: it walks and chunks the files like `mcb index --dry-run`, counts
+//! recent churn from the VCS history, and turns the profile into a
+//! recommended configuration. Nothing is embedded or stored.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    LanguageChunkingProvider, RepoAnalysis, RepoAnalysisServiceInterface, RepoChurn, RepoProfile,
+    RepoRecommendation, VcsProvider,
+};
+use mcb_utils::constants::use_cases::{ONBOARDING_CHURN_MAX_COMMITS, ONBOARDING_CHURN_WINDOW_DAYS};
+use mcb_utils::utils::time;
+
+use super::indexing_service::{estimate_codebase, resolve_allowed_path};
+
+/// Implementation of the `RepoAnalysisServiceInterface`.
+pub struct RepoAnalysisServiceImpl {
+    vcs: Arc<dyn VcsProvider>,
+    chunker: Arc<dyn LanguageChunkingProvider>,
+    allowed_roots: Vec<PathBuf>,
+}
+
+impl RepoAnalysisServiceImpl {
+    /// Create new repository analysis service with injected dependencies
+    pub fn new(vcs: Arc<dyn VcsProvider>, chunker: Arc<dyn LanguageChunkingProvider>) -> Self {
+        Self {
+            vcs,
+            chunker,
+            allowed_roots: Vec::new(),
+        }
+    }
+
+    /// Only analyze directories under one of `allowed_roots`; empty allows any path.
+    #[must_use]
+    pub fn with_allowed_roots(mut self, allowed_roots: Vec<PathBuf>) -> Self {
+        self.allowed_roots = allowed_roots;
+        self
+    }
+
+    /// Commits and changed files with an extension in `extensions` within
+    /// the churn window on the repository's default branch.
+    async fn churn(&self, path: &Path, extensions: &BTreeMap<String, usize>) -> Result<RepoChurn> {
+        let repo = self.vcs.open_repository(path).await?;
+        let branch = repo.default_branch().to_owned();
+        let since =
+            time::epoch_secs_i64()? - i64::from(ONBOARDING_CHURN_WINDOW_DAYS) * 24 * 60 * 60;
+
+        let commits = self
+            .vcs
+            .commit_history(&repo, &branch, Some(ONBOARDING_CHURN_MAX_COMMITS))
+            .await?
+            .iter()
+            .filter(|commit| commit.timestamp() >= since)
+            .count();
+        let files_changed = self
+            .vcs
+            .file_last_modified(&repo, &branch)
+            .await?
+            .into_iter()
+            .filter(|(file, modified)| {
+                *modified >= since
+                    && file
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| extensions.contains_key(&ext.to_ascii_lowercase()))
+            })
+            .count();
+        Ok(RepoChurn {
+            window_days: ONBOARDING_CHURN_WINDOW_DAYS,
+            commits,
+            files_changed,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl RepoAnalysisServiceInterface for RepoAnalysisServiceImpl {
+    async fn analyze_repository(&self, path: &Path) -> Result<RepoAnalysis> {
+        let path = resolve_allowed_path(path, &self.allowed_roots)?;
+        let path = path.as_path();
+        if !path.is_dir() {
+            return Err(Error::invalid_argument(format!(
+                "not a directory: {}",
+                path.display()
+            )));
+        }
+
+        let root = path.to_path_buf();
+        let chunker = Arc::clone(&self.chunker);
+        let size = tokio::task::spawn_blocking(move || {
+            let extensions = chunker
+                .extensions()
+                .iter()
+                .map(|&ext| ext.to_owned())
+                .collect();
+            estimate_codebase(&root, chunker.as_ref(), extensions)
+        })
+        .await
+        .map_err(|e| Error::internal(format!("repository walk failed: {e}")))?;
+
+        // A directory outside version control has no churn to report.
+        let churn = self.churn(path, &size.files_by_extension).await.ok();
+        let profile = RepoProfile { size, churn };
+        Ok(RepoAnalysis {
+            path: path.display().to_string(),
+            recommendation: RepoRecommendation::for_profile(&profile),
+            profile,
+        })
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::language::{LanguageProviderConfig, resolve_language_provider};
+use mcb_domain::registry::services::ServiceBuilder;
+use mcb_domain::registry::vcs::{VcsProviderConfig, resolve_vcs_provider};
+use mcb_utils::constants::{DEFAULT_LANGUAGE_PROVIDER, DEFAULT_VCS_PROVIDER};
+
+/// Build a `RepoAnalysisService` limited to the configured indexing roots.
+fn build_repo_analysis_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn RepoAnalysisServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Repo analysis service builder requires ServiceResolutionContext")
+        })?;
+    let app_config = ctx
+        .config
+        .downcast_ref::<crate::config::app::AppConfig>()
+        .ok_or_else(|| Error::internal("Repo analysis service requires AppConfig"))?;
+    Ok(Arc::new(
+        RepoAnalysisServiceImpl::new(
+            resolve_vcs_provider(&VcsProviderConfig::new(DEFAULT_VCS_PROVIDER))?,
+            resolve_language_provider(&LanguageProviderConfig::new(DEFAULT_LANGUAGE_PROVIDER))?,
+        )
+        .with_allowed_roots(app_config.mcp.indexing.allowed_roots.clone()),
+    ))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_REPO_ANALYSIS,
+    ServiceBuilder::RepoAnalysis(build_repo_analysis_service_from_registry),
+);
@@ -0,0 +1,164 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Search Feedback Service Use Case
+//!
+//! # Overview
+//! The `SearchFeedbackService` stores thumbs-up/down judgements on search
+//! results and periodically turns them into per-file score multipliers.
+//! Boosts are damped by a pseudo-vote prior so a single vote cannot swing
+//! ranking, and are bounded by [`SEARCH_FEEDBACK_BOOST_SCALE`].
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use mcb_domain::entities::memory::{FileBoost, FileFeedbackTally, SearchFeedback};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    RecordFeedbackInput, SearchFeedbackRepository, SearchFeedbackServiceInterface,
+};
+use mcb_domain::value_objects::SearchResult;
+use mcb_utils::constants::search::{
+    SEARCH_FEEDBACK_BOOST_SCALE, SEARCH_FEEDBACK_MIN_VOTES, SEARCH_FEEDBACK_PRIOR_VOTES,
+};
+use mcb_utils::utils::{id, time};
+
+/// Implementation of the `SearchFeedbackServiceInterface`.
+pub struct SearchFeedbackServiceImpl {
+    repository: Arc<dyn SearchFeedbackRepository>,
+}
+
+impl SearchFeedbackServiceImpl {
+    /// Create new search feedback service with injected dependencies
+    pub fn new(repository: Arc<dyn SearchFeedbackRepository>) -> Self {
+        Self { repository }
+    }
+
+    /// Score multiplier learned from a file's votes, or `None` when it has too
+    /// few votes to count.
+    #[must_use]
+    pub fn boost_for(tally: &FileFeedbackTally) -> Option<f64> {
+        let total = tally.up + tally.down;
+        if total < SEARCH_FEEDBACK_MIN_VOTES {
+            return None;
+        }
+        let net = tally.up as f64 - tally.down as f64;
+        Some(1.0 + SEARCH_FEEDBACK_BOOST_SCALE * net / (total as f64 + SEARCH_FEEDBACK_PRIOR_VOTES))
+    }
+}
+
+#[async_trait::async_trait]
+impl SearchFeedbackServiceInterface for SearchFeedbackServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if a required field is empty or the repository write fails.
+    async fn record_feedback(&self, input: RecordFeedbackInput) -> Result<String> {
+        if input.chunk_id.trim().is_empty() || input.file_path.trim().is_empty() {
+            return Err(Error::invalid_argument(
+                "chunk_id and file_path are required for search feedback",
+            ));
+        }
+        let feedback = SearchFeedback {
+            id: id::generate().to_string(),
+            query: input.query,
+            collection: input.collection,
+            chunk_id: input.chunk_id,
+            file_path: input.file_path,
+            relevant: input.relevant,
+            session_id: input.session_id,
+            created_at: time::epoch_secs_i64()?,
+        };
+        self.repository.record_feedback(&feedback).await?;
+        Ok(feedback.id)
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the feedback tally or boost write fails.
+    async fn tune_file_boosts(&self) -> Result<usize> {
+        let updated_at = time::epoch_secs_i64()?;
+        let boosts: Vec<FileBoost> = self
+            .repository
+            .tally_by_file()
+            .await?
+            .into_iter()
+            .filter_map(|tally| {
+                let boost = Self::boost_for(&tally)?;
+                Some(FileBoost {
+                    collection: tally.collection,
+                    file_path: tally.file_path,
+                    boost,
+                    updated_at,
+                })
+            })
+            .collect();
+        self.repository.replace_file_boosts(&boosts).await?;
+        Ok(boosts
+            .iter()
+            .filter(|b| (b.boost - 1.0).abs() > f64::EPSILON)
+            .count())
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if the boosts cannot be loaded.
+    async fn apply_file_boosts(
+        &self,
+        collection: &str,
+        mut results: Vec<SearchResult>,
+    ) -> Result<Vec<SearchResult>> {
+        if results.is_empty() {
+            return Ok(results);
+        }
+        let boosts: HashMap<String, f64> = self
+            .repository
+            .file_boosts(collection)
+            .await?
+            .into_iter()
+            .map(|b| (b.file_path, b.boost))
+            .collect();
+        if boosts.is_empty() {
+            return Ok(results);
+        }
+        for result in &mut results {
+            if let Some(boost) = boosts.get(&result.file_path) {
+                result.score *= boost;
+                if let Some(explanation) = result.explanation.as_mut() {
+                    explanation.rerank_boost = Some(*boost);
+                }
+            }
+        }
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+        Ok(results)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+use mcb_utils::constants::{DEFAULT_DATABASE_PROVIDER, DEFAULT_NAMESPACE};
+
+/// Build a `SearchFeedbackService` from the service resolution context.
+fn build_search_feedback_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn SearchFeedbackServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Search feedback service builder requires ServiceResolutionContext")
+        })?;
+    let repos = mcb_domain::registry::database::resolve_database_repositories(
+        DEFAULT_DATABASE_PROVIDER,
+        Arc::clone(&ctx.db),
+        DEFAULT_NAMESPACE.to_owned(),
+    )?;
+    Ok(Arc::new(SearchFeedbackServiceImpl::new(
+        repos.search_feedback,
+    )))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_SEARCH_FEEDBACK,
+    ServiceBuilder::SearchFeedback(build_search_feedback_service_from_registry),
+);
@@ -5,7 +5,7 @@
 //!
 //! # Overview
 //! The `SearchService` executes semantic search queries against indexed codebases.
-//! It applies business logic like result ranking and post-filtering (e.g., by file type or language)
+//! It applies business logic like result ranking and post-filtering (e.g., by file type, path or language)
 //! to refine the raw results from the `ContextService`.
 //! This separation allows the search logic to evolve (e.g., hybrid search, re-ranking) without
 //! complicating the core context management.
@@ -13,9 +13,17 @@
 use std::sync::Arc;
 
 use mcb_domain::error::Result;
-use mcb_domain::ports::{ContextServiceInterface, SearchFilters, SearchServiceInterface};
-use mcb_domain::value_objects::{CollectionId, SearchResult};
-use mcb_utils::constants::search::SEARCH_OVERFETCH_MULTIPLIER;
+use mcb_domain::ports::{
+    BulkheadInterface, ContextServiceInterface, Diversification, DiversifyBy, JobPermit,
+    JobPriority, JobSchedulerInterface, SearchFilters, SearchServiceInterface, Workload,
+};
+use mcb_domain::value_objects::{CollectionId, OrgContext, ScoreExplanation, SearchResult};
+use mcb_utils::constants::search::{
+    MMR_CANDIDATE_MULTIPLIER, MMR_SYMBOL_SCAN_LINES, RECENCY_BOOST_WEIGHT, RECENCY_SECONDS_PER_DAY,
+    SEARCH_OVERFETCH_MULTIPLIER,
+};
+use mcb_utils::utils::time;
+use mcb_utils::utils::vector::l2_norm;
 
 /// Implementation of the `SearchServiceInterface`.
 ///
@@ -23,29 +31,273 @@ use mcb_utils::constants::search::SEARCH_OVERFETCH_MULTIPLIER;
 /// filtering logic.
 pub struct SearchServiceImpl {
     context_service: Arc<dyn ContextServiceInterface>,
+    job_scheduler: Option<Arc<dyn JobSchedulerInterface>>,
+    bulkheads: Option<Arc<dyn BulkheadInterface>>,
 }
 
 impl SearchServiceImpl {
     /// Create new search service with injected dependencies
     pub fn new(context_service: Arc<dyn ContextServiceInterface>) -> Self {
-        Self { context_service }
+        Self {
+            context_service,
+            job_scheduler: None,
+            bulkheads: None,
+        }
+    }
+
+    /// Run every search in an interactive slot of `job_scheduler`, ahead of
+    /// queued background indexing.
+    #[must_use]
+    pub fn with_job_scheduler(mut self, job_scheduler: Arc<dyn JobSchedulerInterface>) -> Self {
+        self.job_scheduler = Some(job_scheduler);
+        self
+    }
+
+    /// Run every search in the search pool of `bulkheads`, so it never
+    /// waits behind indexing or admin work.
+    #[must_use]
+    pub fn with_bulkheads(mut self, bulkheads: Arc<dyn BulkheadInterface>) -> Self {
+        self.bulkheads = Some(bulkheads);
+        self
+    }
+
+    /// Enter the search pool, then take an interactive scheduler slot.
+    async fn admit(&self) -> (Option<JobPermit>, Option<JobPermit>) {
+        let pool = match &self.bulkheads {
+            Some(bulkheads) => Some(bulkheads.enter(Workload::Search).await),
+            None => None,
+        };
+        let slot = match &self.job_scheduler {
+            Some(scheduler) => Some(
+                scheduler
+                    .acquire(&OrgContext::default().id_str(), JobPriority::Interactive)
+                    .await,
+            ),
+            None => None,
+        };
+        (pool, slot)
+    }
+
+    async fn filtered_search(
+        &self,
+        collection: &CollectionId,
+        query: &str,
+        limit: usize,
+        filters: Option<&SearchFilters>,
+    ) -> Result<Vec<SearchResult>> {
+        // Get more results initially to account for filtering
+        let fetch_limit = Self::fetch_limit(filters, limit);
+        let scope = filters.and_then(SearchFilters::scope);
+        let results = self
+            .context_service
+            .search_similar(collection, query, fetch_limit, scope.as_ref())
+            .await?;
+
+        Self::refine(results, filters, limit)
+    }
+
+    /// Blend a query vector toward the mean of `context` vectors.
+    ///
+    /// Every vector is L2-normalized first so one long observation cannot
+    /// dominate; the result is `(1 - weight) * query + weight * mean(context)`,
+    /// normalized again. `weight` is clamped to 0.0-1.0 and empty `context`
+    /// returns the normalized query.
+    #[must_use]
+    pub fn blend_query_vector(query: &[f32], context: &[Vec<f32>], weight: f32) -> Vec<f32> {
+        fn normalized(v: &[f32]) -> Vec<f32> {
+            let norm = l2_norm(v);
+            if norm == 0.0 {
+                v.to_vec()
+            } else {
+                v.iter().map(|x| x / norm).collect()
+            }
+        }
+
+        let query = normalized(query);
+        let usable: Vec<Vec<f32>> = context
+            .iter()
+            .filter(|c| c.len() == query.len())
+            .map(|c| normalized(c))
+            .collect();
+        if usable.is_empty() {
+            return query;
+        }
+
+        let weight = weight.clamp(0.0, 1.0);
+        let share = weight / usable.len() as f32;
+        let mut blended: Vec<f32> = query.iter().map(|q| q * (1.0 - weight)).collect();
+        for vector in &usable {
+            for (b, c) in blended.iter_mut().zip(vector) {
+                *b += c * share;
+            }
+        }
+        normalized(&blended)
+    }
+
+    /// Re-rank results with Maximal Marginal Relevance and keep the top `limit`.
+    ///
+    /// Each step picks the candidate maximizing
+    /// `lambda * relevance - (1 - lambda) * redundancy`, where relevance is the
+    /// score relative to the best candidate and redundancy is 1.0 when a result
+    /// from the same group (file, directory or symbol) was already picked.
+    /// `lambda` is clamped to 0.0-1.0; ties keep the original ranking.
+    #[must_use]
+    pub fn diversify(
+        results: Vec<SearchResult>,
+        diversification: Diversification,
+        limit: usize,
+    ) -> Vec<SearchResult> {
+        let lambda = f64::from(diversification.lambda.clamp(0.0, 1.0));
+        let max_score = results.iter().map(|r| r.score).fold(f64::EPSILON, f64::max);
+
+        let mut candidates: Vec<(String, SearchResult)> = results
+            .into_iter()
+            .map(|r| (Self::diversity_key(&r, diversification.by), r))
+            .collect();
+        let mut picked_keys: Vec<String> = Vec::new();
+        let mut picked = Vec::with_capacity(limit.min(candidates.len()));
+
+        while picked.len() < limit && !candidates.is_empty() {
+            let mut best_idx = 0;
+            let mut best_mmr = f64::NEG_INFINITY;
+            for (idx, (key, result)) in candidates.iter().enumerate() {
+                let redundancy = if picked_keys.contains(key) { 1.0 } else { 0.0 };
+                let mmr = lambda * (result.score / max_score) - (1.0 - lambda) * redundancy;
+                if mmr > best_mmr {
+                    best_mmr = mmr;
+                    best_idx = idx;
+                }
+            }
+            let (key, result) = candidates.remove(best_idx);
+            picked_keys.push(key);
+            picked.push(result);
+        }
+        picked
+    }
+
+    /// Group key used to detect redundant results.
+    fn diversity_key(result: &SearchResult, by: DiversifyBy) -> String {
+        match by {
+            DiversifyBy::File => result.file_path.clone(),
+            DiversifyBy::Directory => std::path::Path::new(&result.file_path)
+                .parent()
+                .map(|p| p.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            // Chunks without a recognizable definition only collide with themselves.
+            DiversifyBy::Symbol => match Self::symbol_name(&result.content) {
+                Some(symbol) => format!("{}::{symbol}", result.file_path),
+                None => format!("{}:{}", result.file_path, result.start_line),
+            },
+        }
+    }
+
+    /// Name following the first definition keyword in the chunk's leading lines.
+    pub(crate) fn symbol_name(content: &str) -> Option<&str> {
+        const DEFINITION_KEYWORDS: &[&str] = &[
+            "fn",
+            "def",
+            "class",
+            "struct",
+            "enum",
+            "trait",
+            "impl",
+            "interface",
+            "func",
+            "function",
+            "mod",
+            "type",
+        ];
+        content
+            .lines()
+            .take(MMR_SYMBOL_SCAN_LINES)
+            .find_map(|line| {
+                let mut tokens = line
+                    .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+                    .filter(|t| !t.is_empty());
+                tokens
+                    .by_ref()
+                    .find(|t| DEFINITION_KEYWORDS.contains(t))
+                    .and_then(|_| tokens.next())
+            })
+    }
+
+    /// Decay scores by commit age and re-sort, best first.
+    ///
+    /// A result's score becomes `score * (1 - w + w * 0.5^(age / half_life))`
+    /// with `w` = [`RECENCY_BOOST_WEIGHT`], so code committed `half_life_days`
+    /// ago keeps `1 - w/2` of its score and ancient code keeps `1 - w`. Results
+    /// without a known `last_modified` are left unchanged. The multiplier is
+    /// recorded in results that carry an explanation.
+    pub fn apply_recency(results: &mut [SearchResult], half_life_days: f64, now: i64) {
+        if half_life_days <= 0.0 {
+            return;
+        }
+        for result in results.iter_mut() {
+            let Some(modified) = result.last_modified else {
+                continue;
+            };
+            let age_days = (now - modified).max(0) as f64 / RECENCY_SECONDS_PER_DAY;
+            let freshness = 0.5_f64.powf(age_days / half_life_days);
+            let boost = 1.0 - RECENCY_BOOST_WEIGHT + RECENCY_BOOST_WEIGHT * freshness;
+            result.score *= boost;
+            if let Some(explanation) = result.explanation.as_mut() {
+                explanation.recency_boost = Some(boost);
+            }
+        }
+        results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    }
+
+    /// Apply filters, recency boosting, optional diversification and the final limit.
+    fn refine(
+        mut results: Vec<SearchResult>,
+        filters: Option<&SearchFilters>,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        if filters.is_some_and(|f| f.explain) {
+            for result in &mut results {
+                result.explanation = Some(ScoreExplanation::dense(result.score));
+            }
+        }
+        let mut filtered = Self::apply_filters(results, filters)?;
+        if let Some(half_life_days) = filters.and_then(|f| f.recency_half_life_days) {
+            Self::apply_recency(&mut filtered, half_life_days, time::epoch_secs_i64()?);
+        }
+        Ok(match filters.and_then(|f| f.diversify) {
+            Some(diversification) => Self::diversify(filtered, diversification, limit),
+            None => filtered.into_iter().take(limit).collect(),
+        })
+    }
+
+    /// Number of candidates to retrieve so `filters` can still fill `limit`.
+    fn fetch_limit(filters: Option<&SearchFilters>, limit: usize) -> usize {
+        match filters {
+            Some(f) if f.diversify.is_some() => limit * MMR_CANDIDATE_MULTIPLIER,
+            Some(_) => limit * SEARCH_OVERFETCH_MULTIPLIER,
+            None => limit,
+        }
     }
 
     /// Apply filters to search results in-memory after retrieval.
     ///
     /// # Design Note
-    /// Filters are applied in-memory after over-fetching (`limit * 2`) from the vector store.
-    /// For large-scale deployments, push filters down to the vector store level via
-    /// `ContextServiceInterface::search_similar` metadata filters parameter.
+    /// The path and language scope is also pushed down to the vector store, but
+    /// stores only evaluate the parts their query language supports, so every
+    /// filter is re-checked here on the over-fetched candidates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the scope's path glob is invalid.
     fn apply_filters(
         results: Vec<SearchResult>,
         filters: Option<&SearchFilters>,
-    ) -> Vec<SearchResult> {
+    ) -> Result<Vec<SearchResult>> {
         let Some(filters) = filters else {
-            return results;
+            return Ok(results);
         };
+        let scope = filters.scope().unwrap_or_default();
+        let in_scope = scope.matcher()?;
 
-        results
+        Ok(results
             .into_iter()
             .filter(|r| {
                 // Filter by minimum score
@@ -66,16 +318,10 @@ impl SearchServiceImpl {
                     }
                 }
 
-                // Filter by language
-                if let Some(ref langs) = filters.languages
-                    && !langs.iter().any(|l| l == &r.language)
-                {
-                    return false;
-                }
-
-                true
+                // Filter by path prefix, path glob and language
+                in_scope(&r.file_path, &r.language)
             })
-            .collect()
+            .collect())
     }
 }
 
@@ -90,8 +336,9 @@ impl SearchServiceInterface for SearchServiceImpl {
         query: &str,
         limit: usize,
     ) -> Result<Vec<SearchResult>> {
+        let _permit = self.admit().await;
         self.context_service
-            .search_similar(collection, query, limit)
+            .search_similar(collection, query, limit, None)
             .await
     }
 
@@ -105,20 +352,53 @@ impl SearchServiceInterface for SearchServiceImpl {
         limit: usize,
         filters: Option<&SearchFilters>,
     ) -> Result<Vec<SearchResult>> {
-        // Get more results initially to account for filtering
-        let fetch_limit = if filters.is_some() {
-            limit * SEARCH_OVERFETCH_MULTIPLIER
-        } else {
-            limit
-        };
+        let _permit = self.admit().await;
+        self.filtered_search(collection, query, limit, filters)
+            .await
+    }
+
+    /// # Errors
+    ///
+    /// Returns an error if embedding the query or the vector search fails.
+    async fn search_with_context(
+        &self,
+        collection: &CollectionId,
+        query: &str,
+        context: &[String],
+        context_weight: f32,
+        limit: usize,
+        filters: Option<&SearchFilters>,
+    ) -> Result<Vec<SearchResult>> {
+        let _permit = self.admit().await;
+        if context.is_empty() || context_weight <= 0.0 {
+            return self
+                .filtered_search(collection, query, limit, filters)
+                .await;
+        }
+
+        let query_embedding = self.context_service.embed_text(query).await?;
+        let mut context_vectors = Vec::with_capacity(context.len());
+        for text in context {
+            // Context is best-effort: a failed embedding drops that text only.
+            match self.context_service.embed_text(text).await {
+                Ok(embedding) => context_vectors.push(embedding.vector),
+                Err(e) => mcb_domain::warn!("search", "context embedding failed", &e),
+            }
+        }
+
+        let blended =
+            Self::blend_query_vector(&query_embedding.vector, &context_vectors, context_weight);
+        let scope = filters.and_then(SearchFilters::scope);
         let results = self
             .context_service
-            .search_similar(collection, query, fetch_limit)
+            .search_by_vector(
+                collection,
+                &blended,
+                Self::fetch_limit(filters, limit),
+                scope.as_ref(),
+            )
             .await?;
-
-        // Apply filters and limit
-        let filtered = Self::apply_filters(results, filters);
-        Ok(filtered.into_iter().take(limit).collect())
+        Self::refine(results, filters, limit)
     }
 }
 
@@ -131,6 +411,14 @@ mcb_domain::register_service!(
     mcb_utils::constants::SERVICE_NAME_SEARCH,
     ServiceBuilder::Search(|context| {
         let context_service = mcb_domain::registry::services::resolve_context_service(context)?;
-        Ok(Arc::new(SearchServiceImpl::new(context_service)))
+        let service = SearchServiceImpl::new(context_service);
+        Ok(Arc::new(
+            match context.downcast_ref::<mcb_domain::registry::ServiceResolutionContext>() {
+                Some(ctx) => service
+                    .with_job_scheduler(Arc::clone(&ctx.job_scheduler))
+                    .with_bulkheads(Arc::clone(&ctx.bulkheads)),
+                None => service,
+            },
+        ))
     })
 );
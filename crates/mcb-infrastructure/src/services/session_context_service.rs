@@ -0,0 +1,123 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Session Context Service Use Case
+//!
+//! # Overview
+//! The `SessionContextService` remembers which code chunks have already been
+//! handed to each MCP session. Follow-up requests skip those chunks and fill a
+//! caller-supplied token budget with the next-best unseen matches, so an agent
+//! can page through context without paying for repeats.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use mcb_domain::error::Result;
+use mcb_domain::ports::{ContextBatch, SearchServiceInterface, SessionContextServiceInterface};
+use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_utils::constants::limits::{CHARS_PER_TOKEN_ESTIMATE, SESSION_CONTEXT_FETCH_LIMIT};
+
+/// Implementation of the `SessionContextServiceInterface`.
+///
+/// Seen chunk ids are held in memory per session and live as long as the
+/// server process.
+pub struct SessionContextServiceImpl {
+    search_service: Arc<dyn SearchServiceInterface>,
+    seen: DashMap<String, HashSet<String>>,
+}
+
+impl SessionContextServiceImpl {
+    /// Create new session context service with injected dependencies
+    pub fn new(search_service: Arc<dyn SearchServiceInterface>) -> Self {
+        Self {
+            search_service,
+            seen: DashMap::new(),
+        }
+    }
+
+    /// Estimated token cost of a chunk.
+    #[must_use]
+    pub fn estimate_tokens(result: &SearchResult) -> usize {
+        result.content.len().div_ceil(CHARS_PER_TOKEN_ESTIMATE)
+    }
+}
+
+#[async_trait::async_trait]
+impl SessionContextServiceInterface for SessionContextServiceImpl {
+    /// # Errors
+    ///
+    /// Returns an error if the underlying search fails.
+    async fn get_more_context(
+        &self,
+        session_id: &str,
+        collection: &CollectionId,
+        query: &str,
+        token_budget: usize,
+    ) -> Result<ContextBatch> {
+        // Over-fetch by the number of chunks already seen so fresh ones remain
+        // after the seen set is filtered out.
+        let fetch_limit = self.seen_count(session_id) + SESSION_CONTEXT_FETCH_LIMIT;
+        let candidates = self
+            .search_service
+            .search(collection, query, fetch_limit)
+            .await?;
+        let store_exhausted = candidates.len() < fetch_limit;
+
+        let mut batch = ContextBatch::default();
+        let mut left_over = false;
+        {
+            let seen = self.seen.get(session_id);
+            for candidate in candidates {
+                if seen.as_ref().is_some_and(|s| s.contains(&candidate.id)) {
+                    batch.skipped_seen += 1;
+                    continue;
+                }
+                let tokens = Self::estimate_tokens(&candidate);
+                // Oversized chunks are passed over so smaller ones can still fill the budget.
+                if batch.tokens_used + tokens > token_budget {
+                    left_over = true;
+                    continue;
+                }
+                batch.tokens_used += tokens;
+                batch.results.push(candidate);
+            }
+        }
+        batch.exhausted = store_exhausted && !left_over;
+
+        let ids: Vec<String> = batch.results.iter().map(|r| r.id.clone()).collect();
+        self.mark_returned(session_id, &ids);
+        Ok(batch)
+    }
+
+    fn mark_returned(&self, session_id: &str, chunk_ids: &[String]) {
+        if chunk_ids.is_empty() {
+            return;
+        }
+        self.seen
+            .entry(session_id.to_owned())
+            .or_default()
+            .extend(chunk_ids.iter().cloned());
+    }
+
+    fn seen_count(&self, session_id: &str) -> usize {
+        self.seen.get(session_id).map_or(0, |s| s.len())
+    }
+
+    fn reset(&self, session_id: &str) {
+        self.seen.remove(session_id);
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_SESSION_CONTEXT,
+    ServiceBuilder::SessionContext(|context| {
+        let search_service = mcb_domain::registry::services::resolve_search_service(context)?;
+        Ok(Arc::new(SessionContextServiceImpl::new(search_service)))
+    })
+);
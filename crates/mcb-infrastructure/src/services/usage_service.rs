@@ -0,0 +1,70 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Usage Service Use Case
+//!
+//! # Overview
+//! The `UsageService` counts code search queries per organization and
+//! collection and reports monthly usage, including the bytes and embedding
+//! tokens recorded by indexing (see `IndexingServiceImpl::with_usage_repository`).
+
+use std::sync::Arc;
+
+use mcb_domain::entities::usage::{UsageFilter, UsageRecord};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{UsageReport, UsageRepository, UsageServiceInterface};
+use mcb_domain::value_objects::CollectionId;
+
+/// Implementation of the `UsageServiceInterface`.
+pub struct UsageServiceImpl {
+    repository: Arc<dyn UsageRepository>,
+}
+
+impl UsageServiceImpl {
+    /// Create new usage service with injected dependencies
+    pub fn new(repository: Arc<dyn UsageRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait::async_trait]
+impl UsageServiceInterface for UsageServiceImpl {
+    async fn record_query(&self, org_id: &str, collection: &CollectionId) -> Result<()> {
+        let mut record = UsageRecord::current(org_id, &collection.to_string());
+        record.queries = 1;
+        self.repository.add_usage(&record).await
+    }
+
+    async fn report(&self, filter: &UsageFilter) -> Result<UsageReport> {
+        let records = self.repository.list_usage(filter).await?;
+        Ok(UsageReport::from_records(filter.clone(), records))
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+use mcb_utils::constants::{DEFAULT_DATABASE_PROVIDER, DEFAULT_NAMESPACE};
+
+/// Build a `UsageService` from the service resolution context.
+fn build_usage_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn UsageServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Usage service builder requires ServiceResolutionContext")
+        })?;
+    let repos = mcb_domain::registry::database::resolve_database_repositories(
+        DEFAULT_DATABASE_PROVIDER,
+        Arc::clone(&ctx.db),
+        DEFAULT_NAMESPACE.to_owned(),
+    )?;
+    Ok(Arc::new(UsageServiceImpl::new(repos.usage)))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_USAGE,
+    ServiceBuilder::Usage(build_usage_service_from_registry),
+);
@@ -0,0 +1,222 @@
+//!
+//! **Documentation**: [docs/modules/application.md](../../../../docs/modules/application.md#use-cases)
+//!
+//! Workspace Service Use Case
+//!
+//! # Overview
+//! The `WorkspaceService` groups the collections of related repositories.
+//! Indexing records files identical to a file of another member as copies
+//! (see `IndexingServiceImpl::with_workspace_repository`); this service
+//! manages membership and fans a search out over every member collection.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+
+use futures::future::join_all;
+use mcb_domain::entities::workspace::{Workspace, WorkspaceMember};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    SearchFilters, SearchServiceInterface, WorkspaceRepository, WorkspaceSearchResult,
+    WorkspaceServiceInterface,
+};
+use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_utils::utils::id::compute_content_hash;
+
+/// Implementation of the `WorkspaceServiceInterface`.
+pub struct WorkspaceServiceImpl {
+    repository: Arc<dyn WorkspaceRepository>,
+    search: Arc<dyn SearchServiceInterface>,
+}
+
+impl WorkspaceServiceImpl {
+    /// Create new workspace service with injected dependencies
+    pub fn new(
+        repository: Arc<dyn WorkspaceRepository>,
+        search: Arc<dyn SearchServiceInterface>,
+    ) -> Self {
+        Self { repository, search }
+    }
+
+    /// Search every member, skipping members whose search fails unless all do.
+    async fn search_members(
+        &self,
+        members: &[WorkspaceMember],
+        query: &str,
+        limit: usize,
+        filters: Option<&SearchFilters>,
+    ) -> Result<Vec<(usize, SearchResult)>> {
+        let searches = members.iter().map(|member| async move {
+            let collection = CollectionId::from_string(&member.collection_id);
+            self.search
+                .search_with_filters(&collection, query, limit, filters)
+                .await
+        });
+        let mut hits = Vec::new();
+        let mut first_error = None;
+        for (index, outcome) in join_all(searches).await.into_iter().enumerate() {
+            match outcome {
+                Ok(results) => hits.extend(results.into_iter().map(|r| (index, r))),
+                Err(e) => {
+                    mcb_domain::warn!(
+                        "workspace",
+                        "Skipping collection in workspace search",
+                        &format!("collection={} error={e}", members[index].name)
+                    );
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        match first_error {
+            Some(e) if hits.is_empty() => Err(e),
+            _ => Ok(hits),
+        }
+    }
+
+    /// Report the recorded copies of each result's file in `also_in`.
+    async fn attach_copies(
+        &self,
+        members: &[WorkspaceMember],
+        results: &mut [WorkspaceSearchResult],
+        member_of: &[usize],
+    ) -> Result<()> {
+        let names: HashMap<&str, &str> = members
+            .iter()
+            .map(|m| (m.collection_id.as_str(), m.name.as_str()))
+            .collect();
+        let mut paths_by_member: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for (result, &member) in results.iter().zip(member_of) {
+            paths_by_member
+                .entry(member)
+                .or_default()
+                .push(result.result.file_path.clone());
+        }
+        for (member, paths) in paths_by_member {
+            let origin = &members[member].collection_id;
+            for shared in self.repository.list_copies(origin, &paths).await? {
+                let copy_collection = names
+                    .get(shared.copy.collection.as_str())
+                    .copied()
+                    .unwrap_or(shared.copy.collection.as_str());
+                let location = format!("{copy_collection}:{}", shared.copy.file_path);
+                let originals = results.iter_mut().zip(member_of).filter(|(r, m)| {
+                    **m == member && r.result.file_path == shared.origin.file_path
+                });
+                for (result, _) in originals {
+                    if !result.also_in.contains(&location) {
+                        result.also_in.push(location.clone());
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl WorkspaceServiceInterface for WorkspaceServiceImpl {
+    async fn add_collection(
+        &self,
+        workspace: &str,
+        name: &str,
+        collection: &CollectionId,
+    ) -> Result<Workspace> {
+        let member = WorkspaceMember {
+            name: name.to_owned(),
+            collection_id: collection.to_string(),
+        };
+        self.repository.add_collection(workspace, &member).await?;
+        self.repository
+            .get_workspace(workspace)
+            .await?
+            .ok_or_else(|| Error::internal(format!("workspace '{workspace}' was not created")))
+    }
+
+    async fn remove_collection(&self, workspace: &str, collection: &CollectionId) -> Result<bool> {
+        self.repository
+            .remove_collection(workspace, &collection.to_string())
+            .await
+    }
+
+    async fn list_workspaces(&self) -> Result<Vec<Workspace>> {
+        self.repository.list_workspaces().await
+    }
+
+    async fn search(
+        &self,
+        workspace: &str,
+        query: &str,
+        limit: usize,
+        filters: Option<&SearchFilters>,
+    ) -> Result<Vec<WorkspaceSearchResult>> {
+        let members = self
+            .repository
+            .get_workspace(workspace)
+            .await?
+            .ok_or_else(|| Error::not_found(format!("workspace '{workspace}'")))?
+            .collections;
+
+        let mut hits = self.search_members(&members, query, limit, filters).await?;
+        hits.sort_by(|a, b| b.1.score.total_cmp(&a.1.score));
+
+        // Files indexed before their collection joined the workspace may
+        // still be stored twice; keep the best-scoring chunk of each content.
+        let mut results: Vec<WorkspaceSearchResult> = Vec::new();
+        let mut member_of: Vec<usize> = Vec::new();
+        let mut seen: HashMap<String, usize> = HashMap::new();
+        for (member, result) in hits {
+            let hash = compute_content_hash(&result.content);
+            if let Some(&kept) = seen.get(&hash) {
+                let location = format!("{}:{}", members[member].name, result.file_path);
+                let same_file =
+                    member_of[kept] == member && results[kept].result.file_path == result.file_path;
+                if !same_file && !results[kept].also_in.contains(&location) {
+                    results[kept].also_in.push(location);
+                }
+                continue;
+            }
+            if results.len() == limit {
+                continue;
+            }
+            seen.insert(hash, results.len());
+            member_of.push(member);
+            results.push(WorkspaceSearchResult {
+                collection: members[member].name.clone(),
+                result,
+                also_in: Vec::new(),
+            });
+        }
+
+        self.attach_copies(&members, &mut results, &member_of)
+            .await?;
+        Ok(results)
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Linkme Registration
+// ---------------------------------------------------------------------------
+use mcb_domain::registry::services::ServiceBuilder;
+use mcb_utils::constants::{DEFAULT_DATABASE_PROVIDER, DEFAULT_NAMESPACE};
+
+/// Build a `WorkspaceService` from the service resolution context.
+fn build_workspace_service_from_registry(
+    context: &dyn std::any::Any,
+) -> Result<Arc<dyn WorkspaceServiceInterface>> {
+    let ctx = context
+        .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+        .ok_or_else(|| {
+            Error::internal("Workspace service builder requires ServiceResolutionContext")
+        })?;
+    let repos = mcb_domain::registry::database::resolve_database_repositories(
+        DEFAULT_DATABASE_PROVIDER,
+        Arc::clone(&ctx.db),
+        DEFAULT_NAMESPACE.to_owned(),
+    )?;
+    let search = mcb_domain::registry::services::resolve_search_service(context)?;
+    Ok(Arc::new(WorkspaceServiceImpl::new(repos.workspace, search)))
+}
+
+mcb_domain::register_service!(
+    mcb_utils::constants::SERVICE_NAME_WORKSPACE,
+    ServiceBuilder::Workspace(build_workspace_service_from_registry),
+);
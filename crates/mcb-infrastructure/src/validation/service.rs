@@ -14,7 +14,7 @@
 //! - **Rule Discovery**: Exposing available validation rules and their metadata.
 //! - **Complexity Metrics**: Calculating cyclomatic and cognitive complexity scores.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
 use mcb_domain::error::Result;
@@ -23,17 +23,35 @@ use mcb_domain::ports::{
     ViolationEntry,
 };
 
+use crate::services::resolve_allowed_path;
+
 /// Infrastructure validation service using mcb-validate.
 ///
-/// A stateless adapter that orchestrates the `mcb-validate` library to perform
-/// architectural compliance checks, code quality analysis, and rule enforcement.
-pub struct InfraValidationService;
+/// An adapter that orchestrates the `mcb-validate` library to perform
+/// architectural compliance checks, code quality analysis, and rule enforcement
+/// on paths under the allowed indexing roots.
+pub struct InfraValidationService {
+    allowed_roots: Vec<PathBuf>,
+}
 
 impl InfraValidationService {
     /// Create a new validation service
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            allowed_roots: Vec::new(),
+        }
+    }
+
+    /// Only validate paths under one of `allowed_roots`; empty allows any path.
+    #[must_use]
+    pub fn with_allowed_roots(mut self, allowed_roots: Vec<PathBuf>) -> Self {
+        self.allowed_roots = allowed_roots;
+        self
+    }
+
+    fn allowed(&self, path: &Path) -> Result<PathBuf> {
+        resolve_allowed_path(path, &self.allowed_roots)
     }
 }
 
@@ -51,7 +69,7 @@ impl ValidationServiceInterface for InfraValidationService {
         validators: Option<&[String]>,
         severity_filter: Option<&str>,
     ) -> Result<ValidationReport> {
-        run_validation(workspace_root, validators, severity_filter)
+        run_validation(&self.allowed(workspace_root)?, validators, severity_filter)
     }
 
     async fn list_validators(&self) -> Result<Vec<String>> {
@@ -65,7 +83,7 @@ impl ValidationServiceInterface for InfraValidationService {
         file_path: &Path,
         validators: Option<&[String]>,
     ) -> Result<ValidationReport> {
-        run_file_validation(file_path, validators)
+        run_file_validation(&self.allowed(file_path)?, validators)
     }
 
     async fn get_rules(&self, category: Option<&str>) -> Result<Vec<RuleInfo>> {
@@ -90,7 +108,7 @@ impl ValidationServiceInterface for InfraValidationService {
         file_path: &Path,
         include_functions: bool,
     ) -> Result<ComplexityReport> {
-        analyze_file_complexity(file_path, include_functions)
+        analyze_file_complexity(&self.allowed(file_path)?, include_functions)
     }
 }
 
@@ -256,9 +274,18 @@ fn analyze_file_complexity(file_path: &Path, include_functions: bool) -> Result<
 // Linkme Registration
 // ---------------------------------------------------------------------------
 
+// Limited to the configured indexing roots when resolved with the server's
+// `ServiceResolutionContext`; stand-alone resolution allows any path.
 mcb_domain::register_service!(
     mcb_utils::constants::SERVICE_NAME_VALIDATION,
-    mcb_domain::registry::services::ServiceBuilder::Validation(|_context| {
-        Ok(std::sync::Arc::new(InfraValidationService::new()))
+    mcb_domain::registry::services::ServiceBuilder::Validation(|context| {
+        let allowed_roots = context
+            .downcast_ref::<mcb_domain::registry::ServiceResolutionContext>()
+            .and_then(|ctx| ctx.config.downcast_ref::<crate::config::app::AppConfig>())
+            .map(|config| config.mcp.indexing.allowed_roots.clone())
+            .unwrap_or_default();
+        Ok(std::sync::Arc::new(
+            InfraValidationService::new().with_allowed_roots(allowed_roots),
+        ))
     }),
 );
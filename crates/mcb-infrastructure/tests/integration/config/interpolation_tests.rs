@@ -0,0 +1,132 @@
+//! Secret interpolation tests (`${ENV}`, `file:`, `vault:`).
+//!
+//! Drives the production deserialization path through `ConfigProvider`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::ConfigProvider;
+use mcb_domain::registry::config::{ConfigProviderConfig, resolve_config_provider};
+use mcb_domain::utils::tests::guards::EnvVarGuard;
+use mcb_infrastructure::config::app::AppConfig;
+use rstest::rstest;
+use serial_test::serial;
+use tempfile::TempDir;
+
+#[allow(clippy::expect_used)]
+fn config_provider() -> std::sync::Arc<dyn ConfigProvider> {
+    resolve_config_provider(&ConfigProviderConfig::new(
+        mcb_utils::constants::DEFAULT_CONFIG_PROVIDER,
+    ))
+    .expect("loco_yaml config provider must be registered")
+}
+
+fn test_settings() -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let manifest_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    let path = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join("config").join("test.yaml"))
+        .find(|candidate| candidate.exists())
+        .ok_or("workspace config/test.yaml not found")?;
+    let yaml: serde_yaml::Value = serde_yaml::from_str(&fs::read_to_string(path)?)?;
+    let settings = yaml.get("settings").ok_or("no settings key")?;
+    Ok(serde_json::to_value(settings)?)
+}
+
+fn with_api_key(value: &str) -> Result<serde_json::Value, Box<dyn std::error::Error>> {
+    let mut settings = test_settings()?;
+    let embedding = settings
+        .pointer_mut("/providers/embedding")
+        .and_then(serde_json::Value::as_object_mut)
+        .ok_or("providers.embedding missing")?;
+    embedding.insert("api_key".to_owned(), serde_json::json!(value));
+    Ok(settings)
+}
+
+fn resolve(settings: &serde_json::Value) -> mcb_domain::error::Result<AppConfig> {
+    let config = config_provider().deserialize_from_value(settings)?;
+    config
+        .downcast::<AppConfig>()
+        .map(|c| *c)
+        .map_err(|_| Error::internal("unexpected config type"))
+}
+
+#[rstest]
+#[serial]
+fn test_env_var_interpolated() -> Result<(), Box<dyn std::error::Error>> {
+    let _guard = EnvVarGuard::set("MCB_TEST_INTERP_KEY", "sk-from-env");
+    let config = resolve(&with_api_key("Bearer ${MCB_TEST_INTERP_KEY}")?)?;
+    assert_eq!(
+        config.providers.embedding.api_key.as_deref(),
+        Some("Bearer sk-from-env")
+    );
+    Ok(())
+}
+
+#[rstest]
+#[serial]
+fn test_missing_env_var_names_key() -> Result<(), Box<dyn std::error::Error>> {
+    EnvVarGuard::remove(&["MCB_TEST_INTERP_ABSENT"]);
+    let err = resolve(&with_api_key("${MCB_TEST_INTERP_ABSENT}")?)
+        .err()
+        .ok_or("unset variable must fail")?;
+    match err {
+        Error::ConfigInvalid { key, message } => {
+            assert_eq!(key, "providers.embedding.api_key");
+            assert!(message.contains("MCB_TEST_INTERP_ABSENT"), "{message}");
+        }
+        other => return Err(format!("unexpected error: {other}").into()),
+    }
+    Ok(())
+}
+
+#[rstest]
+#[serial]
+fn test_escaped_placeholder_is_literal() -> Result<(), Box<dyn std::error::Error>> {
+    let config = resolve(&with_api_key("$${NOT_EXPANDED}")?)?;
+    assert_eq!(
+        config.providers.embedding.api_key.as_deref(),
+        Some("${NOT_EXPANDED}")
+    );
+    Ok(())
+}
+
+#[rstest]
+fn test_file_indirection_reads_secret() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let secret = temp.path().join("api_key");
+    fs::write(&secret, "sk-from-file\n")?;
+
+    let config = resolve(&with_api_key(&format!("file:{}", secret.display()))?)?;
+    assert_eq!(
+        config.providers.embedding.api_key.as_deref(),
+        Some("sk-from-file")
+    );
+    Ok(())
+}
+
+#[rstest]
+fn test_missing_secret_file_names_key() -> Result<(), Box<dyn std::error::Error>> {
+    let err = resolve(&with_api_key("file:/nonexistent/mcb/secret")?)
+        .err()
+        .ok_or("missing file must fail")?;
+    assert!(
+        err.to_string().contains("providers.embedding.api_key"),
+        "error should name the key, got: {err}"
+    );
+    Ok(())
+}
+
+#[rstest]
+#[serial]
+fn test_vault_reference_requires_vault_addr() -> Result<(), Box<dyn std::error::Error>> {
+    EnvVarGuard::remove(&["VAULT_ADDR"]);
+    let err = resolve(&with_api_key("vault:secret/data/mcb#api_key")?)
+        .err()
+        .ok_or("vault without VAULT_ADDR must fail")?;
+    let message = err.to_string();
+    assert!(message.contains("providers.embedding.api_key"), "{message}");
+    assert!(message.contains("VAULT_ADDR"), "{message}");
+    Ok(())
+}
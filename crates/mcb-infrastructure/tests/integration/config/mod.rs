@@ -3,6 +3,8 @@
 //! Tests for configuration loading, validation, and management.
 
 mod config_repro_tests;
+mod interpolation_tests;
+mod profile_tests;
 mod strict_config_tests;
 pub mod test_builder;
 mod validation_tests;
@@ -0,0 +1,119 @@
+//! Configuration profile layering tests (overlay files + `MCB__*` env overrides).
+
+use std::fs;
+
+use mcb_domain::error::Error;
+use mcb_infrastructure::config::profile::{
+    apply_env_overrides, apply_profile_overlay, merge, profile_path,
+};
+use rstest::rstest;
+use serde_json::json;
+use tempfile::TempDir;
+
+fn base_settings() -> serde_json::Value {
+    json!({
+        "providers": {
+            "embedding": { "provider": "ollama", "model": "nomic-embed-text", "dimensions": 768 },
+            "vector_store": { "provider": "milvus" }
+        },
+        "mcp": { "no_stdio": false }
+    })
+}
+
+#[rstest]
+fn test_merge_is_deep_and_overlay_wins() {
+    let mut settings = base_settings();
+    merge(
+        &mut settings,
+        json!({ "providers": { "embedding": { "model": "bge-small" } } }),
+    );
+
+    assert_eq!(settings["providers"]["embedding"]["model"], "bge-small");
+    assert_eq!(settings["providers"]["embedding"]["provider"], "ollama");
+    assert_eq!(settings["providers"]["vector_store"]["provider"], "milvus");
+}
+
+#[rstest]
+fn test_profile_overlay_file_applied() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let overlay = profile_path(temp.path(), "staging");
+    fs::create_dir_all(overlay.parent().ok_or("no parent")?)?;
+    fs::write(
+        &overlay,
+        "settings:\n  providers:\n    vector_store:\n      provider: edgevec\n",
+    )?;
+
+    let mut settings = base_settings();
+    apply_profile_overlay(&mut settings, temp.path(), "staging")?;
+
+    assert_eq!(settings["providers"]["vector_store"]["provider"], "edgevec");
+    assert_eq!(settings["providers"]["embedding"]["dimensions"], 768);
+    Ok(())
+}
+
+#[rstest]
+fn test_missing_profile_fails_loud() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let mut settings = base_settings();
+    let err = apply_profile_overlay(&mut settings, temp.path(), "prod")
+        .err()
+        .ok_or("missing overlay must fail")?;
+    assert!(err.to_string().contains("prod"), "got: {err}");
+    Ok(())
+}
+
+#[rstest]
+#[case("../secrets")]
+#[case("staging/prod")]
+#[case("prod.v2")]
+#[case("")]
+fn test_profile_name_outside_charset_rejected(
+    #[case] profile: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let mut settings = base_settings();
+    let err = apply_profile_overlay(&mut settings, temp.path(), profile)
+        .err()
+        .ok_or("invalid profile name must fail")?;
+    assert!(matches!(err, Error::ConfigInvalid { .. }), "got: {err}");
+    Ok(())
+}
+
+#[rstest]
+fn test_env_overrides_typed_and_nested() -> Result<(), Box<dyn std::error::Error>> {
+    let mut settings = base_settings();
+    apply_env_overrides(
+        &mut settings,
+        [
+            (
+                "MCB__PROVIDERS__EMBEDDING__DIMENSIONS".to_owned(),
+                "384".to_owned(),
+            ),
+            ("MCB__MCP__NO_STDIO".to_owned(), "true".to_owned()),
+            (
+                "MCB__MODE__SERVER_URL".to_owned(),
+                "http://mcb:3000".to_owned(),
+            ),
+            ("UNRELATED".to_owned(), "ignored".to_owned()),
+        ],
+    )?;
+
+    assert_eq!(settings["providers"]["embedding"]["dimensions"], 384);
+    assert_eq!(settings["mcp"]["no_stdio"], true);
+    assert_eq!(settings["mode"]["server_url"], "http://mcb:3000");
+    assert!(settings.get("unrelated").is_none());
+    Ok(())
+}
+
+#[rstest]
+fn test_env_override_through_scalar_rejected() {
+    let mut settings = base_settings();
+    let result = apply_env_overrides(
+        &mut settings,
+        [(
+            "MCB__PROVIDERS__EMBEDDING__MODEL__NAME".to_owned(),
+            "x".to_owned(),
+        )],
+    );
+    assert!(result.is_err(), "cannot descend into a string value");
+}
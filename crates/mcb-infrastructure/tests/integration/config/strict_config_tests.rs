@@ -146,8 +146,7 @@ fn test_missing_required_key_fails() {
 
 // NOTE: test_mcp_env_override_port_works was deleted because Figment (which supported
 // MCP__ env var prefixes) was removed during the Figment→Loco YAML migration.
-// Environment variable override is no longer supported in the YAML-based config system.
-// If env override is needed in the future, it would need to be implemented as a separate
+// Env overrides now use the `MCB__` prefix and are covered in `profile_tests.rs`.
 
 // ── Enforcement: no config bypass ────────────────────────────────────────────
 
@@ -255,10 +254,10 @@ fn test_no_impl_default_in_config_types() -> Result<(), Box<dyn std::error::Erro
 #[rstest]
 fn test_no_serde_default_in_config_types() -> Result<(), Box<dyn std::error::Error>> {
     let root = workspace_root()?;
-    let types_dir = root.join("crates/mcb-infrastructure/src/config/types");
+    let config_dir = root.join("crates/mcb-infrastructure/src/config");
     let mut violations = Vec::new();
 
-    for file_path in scan_rs_files(&types_dir) {
+    for file_path in scan_rs_files(&config_dir) {
         let content = fs::read_to_string(&file_path).unwrap_or_default();
         let relative = file_path.strip_prefix(&root).unwrap_or(&file_path);
 
@@ -727,3 +726,28 @@ fn test_operations_tracking_disabled_allows_zero_values() {
         "tracking disabled should allow zero cleanup/retention"
     );
 }
+
+// ── Negative: validate_indexing_config ──────────────────────────────────────
+
+#[rstest]
+fn test_missing_allowed_root_fails() {
+    let mut config = load_valid_config_unvalidated();
+    config.mcp.indexing.allowed_roots = vec![PathBuf::from("/nonexistent/mcb-allowed-root")];
+
+    let result = config_provider().validate_config(&config);
+    let msg = result
+        .expect_err("missing allowed root must fail")
+        .to_string();
+    assert!(
+        msg.contains("mcp.indexing.allowed_roots"),
+        "error should name the key, got: {msg}"
+    );
+}
+
+#[rstest]
+fn test_existing_allowed_root_passes() {
+    let root = TempDir::new().expect("tempdir");
+    let mut config = load_valid_config_unvalidated();
+    config.mcp.indexing.allowed_roots = vec![root.path().to_path_buf()];
+    assert!(config_provider().validate_config(&config).is_ok());
+}
@@ -0,0 +1,63 @@
+//! Unit tests for `SemaphoreBulkheads`: per-pool limits and isolation
+//! between search, indexing and admin work.
+
+use std::time::Duration;
+
+use mcb_domain::ports::{BulkheadInterface, BulkheadPolicy, Workload};
+use mcb_infrastructure::infrastructure::SemaphoreBulkheads;
+
+fn bulkheads(search: usize, indexing: usize, admin: usize) -> SemaphoreBulkheads {
+    SemaphoreBulkheads::new(BulkheadPolicy {
+        search,
+        indexing,
+        admin,
+    })
+    .expect("valid policy")
+}
+
+#[test]
+fn rejects_empty_pool() {
+    let err = SemaphoreBulkheads::new(BulkheadPolicy {
+        search: 2,
+        indexing: 0,
+        admin: 1,
+    })
+    .err()
+    .expect("indexing pool of 0");
+    assert!(err.to_string().contains("bulkheads.indexing"), "{err}");
+}
+
+#[tokio::test]
+async fn pool_is_bounded_until_permit_drops() {
+    let pools = bulkheads(1, 1, 2);
+
+    let first = pools.enter(Workload::Admin).await;
+    let _second = pools.enter(Workload::Admin).await;
+    assert_eq!(pools.available(Workload::Admin), 0);
+
+    let third = tokio::time::timeout(Duration::from_millis(20), pools.enter(Workload::Admin));
+    assert!(third.await.is_err(), "full pool must make callers wait");
+
+    drop(first);
+    assert_eq!(pools.available(Workload::Admin), 1);
+    let _third = pools.enter(Workload::Admin).await;
+}
+
+#[tokio::test]
+async fn saturated_indexing_pool_does_not_delay_search() {
+    let pools = bulkheads(1, 2, 1);
+    let _indexing = [
+        pools.enter(Workload::Indexing).await,
+        pools.enter(Workload::Indexing).await,
+    ];
+    assert_eq!(pools.available(Workload::Indexing), 0);
+
+    let search = tokio::time::timeout(Duration::from_secs(1), pools.enter(Workload::Search))
+        .await
+        .expect("search pool is separate");
+    let admin = tokio::time::timeout(Duration::from_secs(1), pools.enter(Workload::Admin))
+        .await
+        .expect("admin pool is separate");
+    drop((search, admin));
+    assert_eq!(pools.available(Workload::Search), 1);
+}
@@ -0,0 +1,275 @@
+//! Unit tests for `CircuitBreakerVectorStoreProvider`: opening after repeated
+//! failures, failing fast while open, and probing before closing again.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
+use mcb_domain::value_objects::{
+    CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
+};
+use mcb_infrastructure::infrastructure::{CircuitBreakerPolicy, CircuitBreakerVectorStoreProvider};
+use serde_json::Value;
+
+const OPEN_DURATION: Duration = Duration::from_millis(50);
+
+/// Vector store whose backend can be switched down, counting the calls
+/// that reached it.
+#[derive(Default)]
+struct FlakyVectorStore {
+    down: AtomicBool,
+    calls: AtomicUsize,
+}
+
+impl FlakyVectorStore {
+    fn set_down(&self, down: bool) {
+        self.down.store(down, Ordering::SeqCst);
+    }
+
+    fn calls(&self) -> usize {
+        self.calls.load(Ordering::SeqCst)
+    }
+
+    fn answer<T>(&self, value: T) -> Result<T> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.down.load(Ordering::SeqCst) {
+            Err(Error::vector_db("connection refused"))
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for FlakyVectorStore {
+    async fn collection_exists(&self, _collection: &CollectionId) -> Result<bool> {
+        self.answer(true)
+    }
+
+    async fn get_stats(&self, _collection: &CollectionId) -> Result<HashMap<String, Value>> {
+        self.answer(HashMap::new())
+    }
+
+    async fn flush(&self, _collection: &CollectionId) -> Result<()> {
+        self.answer(())
+    }
+
+    fn provider_name(&self) -> &str {
+        "flaky"
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for FlakyVectorStore {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        self.answer(Vec::new())
+    }
+
+    async fn list_file_paths(
+        &self,
+        _collection: &CollectionId,
+        _limit: usize,
+    ) -> Result<Vec<FileInfo>> {
+        self.answer(Vec::new())
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        _collection: &CollectionId,
+        _file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        self.answer(Vec::new())
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for FlakyVectorStore {
+    async fn create_collection(
+        &self,
+        _collection: &CollectionId,
+        _dimensions: usize,
+    ) -> Result<()> {
+        self.answer(())
+    }
+
+    async fn delete_collection(&self, _collection: &CollectionId) -> Result<()> {
+        self.answer(())
+    }
+
+    async fn insert_vectors(
+        &self,
+        _collection: &CollectionId,
+        _vectors: &[Embedding],
+        _metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.answer(Vec::new())
+    }
+
+    async fn upsert_vectors(
+        &self,
+        _collection: &CollectionId,
+        ids: &[String],
+        _vectors: &[Embedding],
+        _metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.answer(ids.to_vec())
+    }
+
+    async fn search_similar(
+        &self,
+        _collection: &CollectionId,
+        _query_vector: &[f32],
+        _limit: usize,
+        _filter: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>> {
+        self.answer(Vec::new())
+    }
+
+    async fn delete_vectors(&self, _collection: &CollectionId, _ids: &[String]) -> Result<()> {
+        self.answer(())
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        _collection: &CollectionId,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        if ids.is_empty() {
+            return Err(Error::invalid_argument("ids must not be empty"));
+        }
+        self.answer(Vec::new())
+    }
+
+    async fn list_vectors(
+        &self,
+        _collection: &CollectionId,
+        _limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.answer(Vec::new())
+    }
+}
+
+fn guarded(
+    failure_threshold: u32,
+    success_threshold: u32,
+) -> (Arc<FlakyVectorStore>, CircuitBreakerVectorStoreProvider) {
+    let backend = Arc::new(FlakyVectorStore::default());
+    let provider = CircuitBreakerVectorStoreProvider::new(
+        Arc::clone(&backend) as Arc<dyn VectorStoreProvider>,
+        CircuitBreakerPolicy {
+            failure_threshold,
+            open_duration: OPEN_DURATION,
+            success_threshold,
+        },
+    );
+    (backend, provider)
+}
+
+async fn search(provider: &CircuitBreakerVectorStoreProvider) -> Result<Vec<SearchResult>> {
+    provider
+        .search_similar(&CollectionId::from_name("code"), &[0.1, 0.2], 5, None)
+        .await
+}
+
+#[tokio::test]
+async fn opens_after_consecutive_failures_and_fails_fast() {
+    let (backend, provider) = guarded(3, 1);
+    backend.set_down(true);
+
+    for _ in 0..3 {
+        assert!(search(&provider).await.is_err());
+    }
+    assert_eq!(backend.calls(), 3);
+
+    let err = search(&provider).await.expect_err("circuit is open");
+    assert_eq!(
+        backend.calls(),
+        3,
+        "open circuit must not reach the backend"
+    );
+    assert_eq!(err.code().as_str(), "MCB-VS-UNAVAILABLE");
+    assert!(err.is_retryable());
+    assert!(err.to_string().contains("'flaky'"), "{err}");
+}
+
+#[tokio::test]
+async fn success_resets_the_failure_count() {
+    let (backend, provider) = guarded(2, 1);
+
+    backend.set_down(true);
+    assert!(search(&provider).await.is_err());
+    backend.set_down(false);
+    assert!(search(&provider).await.is_ok());
+    backend.set_down(true);
+    assert!(search(&provider).await.is_err());
+
+    backend.set_down(false);
+    assert!(
+        search(&provider).await.is_ok(),
+        "circuit must still be closed"
+    );
+}
+
+#[tokio::test]
+async fn non_retryable_errors_do_not_open_the_circuit() {
+    let (backend, provider) = guarded(1, 1);
+    let collection = CollectionId::from_name("code");
+
+    for _ in 0..3 {
+        let err = provider
+            .get_vectors_by_ids(&collection, &[])
+            .await
+            .expect_err("invalid argument");
+        assert_eq!(err.code().as_str(), "MCB-INVALID-ARG");
+    }
+
+    assert!(search(&provider).await.is_ok());
+    assert_eq!(backend.calls(), 1);
+}
+
+#[tokio::test]
+async fn successful_probes_close_the_circuit() {
+    let (backend, provider) = guarded(1, 2);
+    backend.set_down(true);
+    assert!(search(&provider).await.is_err());
+
+    backend.set_down(false);
+    tokio::time::sleep(OPEN_DURATION).await;
+    assert!(search(&provider).await.is_ok(), "first probe");
+    assert!(search(&provider).await.is_ok(), "second probe");
+    assert_eq!(backend.calls(), 3);
+
+    backend.set_down(true);
+    assert!(search(&provider).await.is_err());
+    assert_eq!(backend.calls(), 4, "closed circuit reaches the backend");
+}
+
+#[tokio::test]
+async fn failed_probe_reopens_the_circuit() {
+    let (backend, provider) = guarded(1, 1);
+    backend.set_down(true);
+    assert!(search(&provider).await.is_err());
+
+    tokio::time::sleep(OPEN_DURATION).await;
+    assert!(search(&provider).await.is_err(), "failed probe");
+    assert_eq!(backend.calls(), 2);
+
+    let err = search(&provider).await.expect_err("circuit is open again");
+    assert_eq!(err.code().as_str(), "MCB-VS-UNAVAILABLE");
+    assert_eq!(backend.calls(), 2);
+}
+
+#[tokio::test]
+async fn health_check_bypasses_the_open_circuit() {
+    let (backend, provider) = guarded(1, 1);
+    backend.set_down(true);
+    assert!(search(&provider).await.is_err());
+
+    backend.set_down(false);
+    assert!(provider.health_check().await.is_ok());
+    assert_eq!(backend.calls(), 2);
+}
@@ -0,0 +1,160 @@
+//! Unit tests for `FairJobScheduler`: the global limit, interactive work
+//! ahead of background work, and round-robin between organizations.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use mcb_domain::ports::{JobPriority, JobSchedulerInterface, JobSchedulerPolicy};
+use mcb_infrastructure::infrastructure::FairJobScheduler;
+use rstest::rstest;
+
+fn scheduler(max_concurrent: usize, reserved_interactive: usize) -> Arc<FairJobScheduler> {
+    Arc::new(
+        FairJobScheduler::new(JobSchedulerPolicy {
+            max_concurrent,
+            reserved_interactive,
+        })
+        .expect("valid policy"),
+    )
+}
+
+/// Let spawned tasks run until they block.
+async fn settle() {
+    for _ in 0..10 {
+        tokio::task::yield_now().await;
+    }
+}
+
+/// Spawn a task that records `label` once admitted, then frees its slot.
+fn spawn_job(
+    scheduler: &Arc<FairJobScheduler>,
+    order: &Arc<Mutex<Vec<&'static str>>>,
+    org: &'static str,
+    priority: JobPriority,
+    label: &'static str,
+) -> tokio::task::JoinHandle<()> {
+    let scheduler = Arc::clone(scheduler);
+    let order = Arc::clone(order);
+    tokio::spawn(async move {
+        let _permit = scheduler.acquire(org, priority).await;
+        order.lock().expect("order").push(label);
+    })
+}
+
+#[rstest]
+#[tokio::test]
+async fn background_work_never_takes_the_reserved_slots() {
+    let scheduler = scheduler(2, 1);
+    let _running = scheduler.acquire("org", JobPriority::Background).await;
+
+    let blocked = tokio::time::timeout(
+        Duration::from_millis(50),
+        scheduler.acquire("org", JobPriority::Background),
+    )
+    .await;
+    assert!(blocked.is_err(), "second background job must wait");
+
+    let interactive = tokio::time::timeout(
+        Duration::from_millis(50),
+        scheduler.acquire("org", JobPriority::Interactive),
+    )
+    .await;
+    assert!(
+        interactive.is_ok(),
+        "interactive work uses the reserved slot"
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn freed_slots_go_to_waiting_interactive_work_first() {
+    let scheduler = scheduler(2, 1);
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let interactive = scheduler.acquire("org", JobPriority::Interactive).await;
+    let background = scheduler.acquire("org", JobPriority::Background).await;
+
+    let jobs = [
+        spawn_job(
+            &scheduler,
+            &order,
+            "org",
+            JobPriority::Background,
+            "reindex",
+        ),
+        spawn_job(
+            &scheduler,
+            &order,
+            "org",
+            JobPriority::Interactive,
+            "search",
+        ),
+    ];
+    settle().await;
+    assert!(order.lock().expect("order").is_empty());
+
+    drop(background);
+    settle().await;
+    drop(interactive);
+    for job in jobs {
+        job.await.expect("job");
+    }
+    assert_eq!(*order.lock().expect("order"), ["search", "reindex"]);
+}
+
+#[rstest]
+#[tokio::test]
+async fn background_jobs_of_different_orgs_take_turns() {
+    let scheduler = scheduler(2, 1);
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let running = scheduler.acquire("busy", JobPriority::Background).await;
+
+    let mut jobs = Vec::new();
+    for (org, label) in [
+        ("busy", "busy-1"),
+        ("busy", "busy-2"),
+        ("busy", "busy-3"),
+        ("quiet", "quiet-1"),
+    ] {
+        jobs.push(spawn_job(
+            &scheduler,
+            &order,
+            org,
+            JobPriority::Background,
+            label,
+        ));
+        settle().await;
+    }
+
+    drop(running);
+    for job in jobs {
+        job.await.expect("job");
+    }
+    assert_eq!(
+        *order.lock().expect("order"),
+        ["busy-1", "quiet-1", "busy-2", "busy-3"]
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn abandoned_waiters_do_not_hold_slots() {
+    let scheduler = scheduler(2, 1);
+    let running = scheduler.acquire("org", JobPriority::Background).await;
+    let gave_up = tokio::time::timeout(
+        Duration::from_millis(10),
+        scheduler.acquire("org", JobPriority::Background),
+    )
+    .await;
+    assert!(gave_up.is_err());
+
+    drop(running);
+    let admitted = tokio::time::timeout(
+        Duration::from_millis(50),
+        scheduler.acquire("org", JobPriority::Background),
+    )
+    .await;
+    assert!(
+        admitted.is_ok(),
+        "slot of the abandoned waiter must be freed"
+    );
+}
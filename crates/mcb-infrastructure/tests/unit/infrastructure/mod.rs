@@ -1,7 +1,10 @@
 //! Unit tests.
 
 mod admin_tests;
+mod bulkhead_tests;
+mod circuit_breaker_tests;
 /// Full-text search check tests.
 pub mod fts_check_tests;
+mod job_scheduler_tests;
 mod lifecycle_tests;
 mod validator_job_runner_tests;
@@ -0,0 +1,95 @@
+//! Tests for `ChangeSummaryServiceImpl` symbol matching
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::ports::SymbolChangeKind;
+use mcb_infrastructure::services::ChangeSummaryServiceImpl;
+use rstest::rstest;
+
+fn chunk(start_line: u32, node_type: Option<&str>, content: &str) -> CodeChunk {
+    let end_line = start_line + u32::try_from(content.lines().count()).unwrap_or(1) - 1;
+    CodeChunk {
+        id: format!("chunk-{start_line}"),
+        content: content.to_owned(),
+        file_path: "src/lib.rs".to_owned(),
+        start_line,
+        end_line,
+        language: "rust".to_owned(),
+        metadata: node_type.map_or(
+            serde_json::Value::Null,
+            |t| serde_json::json!({ "node_type": t }),
+        ),
+    }
+}
+
+fn function(start_line: u32, content: &str) -> CodeChunk {
+    chunk(start_line, Some("function_item"), content)
+}
+
+#[rstest]
+fn reports_added_removed_and_modified_symbols() {
+    let old = [
+        function(1, "fn keep() {\n    1\n}"),
+        function(5, "fn change() {\n    2\n}"),
+        function(9, "fn drop_me() {\n    3\n}"),
+    ];
+    let new = [
+        function(1, "fn keep() {\n    1\n}"),
+        function(5, "fn change() {\n    20\n}"),
+        function(9, "fn fresh() {\n    4\n}"),
+    ];
+
+    let changes = ChangeSummaryServiceImpl::diff_symbols(&old, &new);
+
+    let summary: Vec<_> = changes
+        .iter()
+        .map(|c| (c.name.as_str(), c.change, c.start_line))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("change", SymbolChangeKind::Modified, 5),
+            ("fresh", SymbolChangeKind::Added, 9),
+            ("drop_me", SymbolChangeKind::Removed, 9),
+        ]
+    );
+}
+
+#[rstest]
+fn moved_symbols_are_unchanged() {
+    let old = [function(1, "fn moved() {\n    1\n}")];
+    let new = [function(40, "fn moved() {\n    1\n}")];
+
+    assert!(ChangeSummaryServiceImpl::diff_symbols(&old, &new).is_empty());
+}
+
+#[rstest]
+fn pairs_repeated_names_in_source_order() {
+    let old = [
+        function(1, "fn new() -> A {\n    A\n}"),
+        function(9, "fn new() -> B {\n    B\n}"),
+    ];
+    let new = [
+        function(1, "fn new() -> A {\n    A\n}"),
+        function(9, "fn new() -> B {\n    B::default()\n}"),
+    ];
+
+    let changes = ChangeSummaryServiceImpl::diff_symbols(&old, &new);
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(
+        (changes[0].start_line, changes[0].change),
+        (9, SymbolChangeKind::Modified)
+    );
+}
+
+#[rstest]
+#[case(None, "fn untyped() {}")]
+#[case(Some("expression_statement"), "let x = compute();")]
+fn ignores_chunks_without_a_named_definition(
+    #[case] node_type: Option<&str>,
+    #[case] content: &str,
+) {
+    let new = [chunk(1, node_type, content)];
+
+    assert!(ChangeSummaryServiceImpl::diff_symbols(&[], &new).is_empty());
+}
@@ -0,0 +1,65 @@
+//! Tests for `ContextServiceImpl` title-vector score fusion
+
+use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_infrastructure::services::ContextServiceImpl;
+use rstest::rstest;
+
+fn hit(id: &str, score: f64) -> SearchResult {
+    SearchResult {
+        id: id.to_owned(),
+        file_path: format!("src/{id}.rs"),
+        start_line: 1,
+        content: format!("fn {id}() {{}}"),
+        score,
+        language: "rust".to_owned(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+fn assert_ranked(results: &[SearchResult], expected: &[(&str, f64)]) {
+    assert_eq!(results.len(), expected.len());
+    for (result, (id, score)) in results.iter().zip(expected) {
+        assert_eq!(result.id, *id);
+        assert!((result.score - score).abs() < 1e-9, "{results:?}");
+    }
+}
+
+#[rstest]
+fn title_match_lifts_chunk_above_content_only_match() {
+    let content = vec![hit("body", 0.8), hit("named", 0.7)];
+    let titles = vec![hit("named", 0.9)];
+
+    let fused = ContextServiceImpl::fuse_title_scores(content, titles, 1.0, 10);
+
+    assert_ranked(&fused, &[("named", 0.8), ("body", 0.4)]);
+}
+
+#[rstest]
+fn title_only_hits_are_kept_and_limit_applies() {
+    let content = vec![hit("a", 0.6)];
+    let titles = vec![hit("b", 0.9), hit("a", 0.6)];
+
+    let fused = ContextServiceImpl::fuse_title_scores(content, titles, 0.5, 1);
+
+    assert_ranked(&fused, &[("a", 0.6)]);
+}
+
+#[rstest]
+fn zero_weight_keeps_content_scores() {
+    let content = vec![hit("a", 0.5), hit("b", 0.4)];
+    let titles = vec![hit("b", 1.0)];
+
+    let fused = ContextServiceImpl::fuse_title_scores(content, titles, 0.0, 10);
+
+    assert_ranked(&fused, &[("a", 0.5), ("b", 0.4)]);
+}
+
+#[rstest]
+fn title_collection_is_distinct_and_stable() {
+    let collection = CollectionId::from_name("repo");
+    let titles = ContextServiceImpl::title_collection(&collection);
+
+    assert_ne!(titles, collection);
+    assert_eq!(titles, ContextServiceImpl::title_collection(&collection));
+}
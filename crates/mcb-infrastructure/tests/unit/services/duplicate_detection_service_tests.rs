@@ -0,0 +1,164 @@
+//! Tests for `DuplicateDetectionServiceImpl` clone detection
+
+use mcb_domain::ports::{CloneKind, CodeFragment, DuplicateQuery};
+use mcb_infrastructure::services::DuplicateDetectionServiceImpl;
+use rstest::rstest;
+
+const ORIGINAL: &str = "fn total(items: &[Item]) -> u64 {
+    let mut sum = 0;
+    for item in items {
+        if item.active {
+            sum += item.price * 2;
+        }
+    }
+    sum
+}";
+
+const RENAMED: &str = "fn sum_prices(orders: &[Order]) -> u64 {
+    let mut acc = 0;
+    for order in orders {
+        if order.paid {
+            acc += order.amount * 3;
+        }
+    }
+    acc
+}";
+
+const GAPPED: &str = "fn total(items: &[Item]) -> u64 {
+    let mut sum = 0;
+    for item in items {
+        if item.active {
+            sum += item.price * 2;
+            log(item);
+        }
+    }
+    sum
+}";
+
+const UNRELATED: &str = "pub struct Config {
+    pub name: String,
+    pub retries: u32,
+    pub timeout_ms: u64,
+    pub verbose: bool,
+    pub tags: Vec<String>,
+}";
+
+fn fragment(path: &str, start_line: u32, content: &str) -> (CodeFragment, String, String) {
+    fragment_in(path, start_line, content, "rust")
+}
+
+fn fragment_in(
+    path: &str,
+    start_line: u32,
+    content: &str,
+    language: &str,
+) -> (CodeFragment, String, String) {
+    let lines = content.lines().count() as u32;
+    (
+        CodeFragment {
+            file_path: path.to_owned(),
+            start_line,
+            end_line: start_line + lines - 1,
+        },
+        content.to_owned(),
+        language.to_owned(),
+    )
+}
+
+fn query(min_similarity: f64, min_lines: usize) -> DuplicateQuery {
+    DuplicateQuery {
+        min_similarity,
+        min_lines,
+        scope: None,
+        limit: 50,
+    }
+}
+
+#[rstest]
+#[case(ORIGINAL, CloneKind::Exact)]
+#[case(
+    "// copied\nfn total(items: &[Item]) -> u64 {\n    let mut sum = 0;\n    for item in items { if item.active { sum += item.price * 2; } }\n    sum\n}\n\n",
+    CloneKind::Exact
+)]
+#[case(RENAMED, CloneKind::Renamed)]
+fn classifies_whole_fragment_clones(#[case] copy: &str, #[case] kind: CloneKind) {
+    let fragments = vec![
+        fragment("src/a.rs", 1, ORIGINAL),
+        fragment("src/b.rs", 10, copy),
+    ];
+    let (pairs, compared) = DuplicateDetectionServiceImpl::detect(&fragments, &query(0.8, 3));
+    assert_eq!(compared, 2);
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].kind, kind);
+    assert!((pairs[0].similarity - 1.0).abs() < f64::EPSILON);
+    assert_eq!(pairs[0].first.file_path, "src/a.rs");
+    assert_eq!(pairs[0].second.start_line, 10);
+}
+
+#[rstest]
+#[case(0.5, true)]
+#[case(0.99, false)]
+fn gapped_clones_respect_the_similarity_threshold(
+    #[case] min_similarity: f64,
+    #[case] reported: bool,
+) {
+    let fragments = vec![
+        fragment("src/a.rs", 1, ORIGINAL),
+        fragment("src/b.rs", 1, GAPPED),
+    ];
+    let (pairs, _) = DuplicateDetectionServiceImpl::detect(&fragments, &query(min_similarity, 3));
+    assert_eq!(pairs.len(), usize::from(reported));
+    if let Some(pair) = pairs.first() {
+        assert_eq!(pair.kind, CloneKind::Gapped);
+        assert!(pair.similarity >= min_similarity && pair.similarity < 1.0);
+    }
+}
+
+#[rstest]
+fn ignores_unrelated_short_and_overlapping_fragments() {
+    let fragments = vec![
+        fragment("src/a.rs", 1, ORIGINAL),
+        fragment("src/a.rs", 3, ORIGINAL),
+        fragment("src/c.rs", 1, UNRELATED),
+    ];
+    let (pairs, _) = DuplicateDetectionServiceImpl::detect(&fragments, &query(0.5, 3));
+    assert!(pairs.is_empty());
+
+    let (pairs, compared) = DuplicateDetectionServiceImpl::detect(
+        &[
+            fragment("src/a.rs", 1, ORIGINAL),
+            fragment("src/b.rs", 1, ORIGINAL),
+        ],
+        &query(0.8, 20),
+    );
+    assert_eq!(compared, 0);
+    assert!(pairs.is_empty());
+}
+
+#[rstest]
+fn drops_pairs_nested_in_a_reported_pair() {
+    let outer = format!("impl Cart {{\n{ORIGINAL}\n}}");
+    let fragments = vec![
+        fragment("src/a.rs", 1, &outer),
+        fragment("src/a.rs", 2, ORIGINAL),
+        fragment("src/b.rs", 1, &outer),
+        fragment("src/b.rs", 2, ORIGINAL),
+    ];
+    let (pairs, _) = DuplicateDetectionServiceImpl::detect(&fragments, &query(0.8, 3));
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].first.start_line, 1);
+    assert_eq!(pairs[0].second.start_line, 1);
+}
+
+#[rstest]
+fn hash_comments_are_skipped_in_hash_comment_languages() {
+    let original = "def total(items):\n    sum = 0\n    for item in items:\n        sum += item.price\n    return sum\n";
+    let commented = "def total(items):\n    # running sum\n    sum = 0\n    for item in items:  # every item\n        sum += item.price\n    return sum\n";
+    let fragments = vec![
+        fragment_in("src/a.py", 1, original, "python"),
+        fragment_in("src/b.py", 1, commented, "python"),
+    ];
+    let (pairs, _) = DuplicateDetectionServiceImpl::detect(&fragments, &query(0.8, 3));
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].kind, CloneKind::Exact);
+}
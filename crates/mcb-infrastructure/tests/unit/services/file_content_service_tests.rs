@@ -0,0 +1,82 @@
+//! Tests for `FileContentServiceImpl` path checks, chunk rebuild and slicing
+
+use mcb_domain::ports::FileReadRequest;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::SearchResult;
+use mcb_infrastructure::services::FileContentServiceImpl;
+use rstest::rstest;
+
+fn chunk(start_line: u32, content: &str) -> SearchResult {
+    SearchResult {
+        id: format!("chunk-{start_line}"),
+        file_path: "src/lib.rs".into(),
+        start_line,
+        content: content.into(),
+        score: 1.0,
+        language: "rust".into(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+fn request(start_line: Option<u32>, end_line: Option<u32>, max_bytes: usize) -> FileReadRequest {
+    FileReadRequest {
+        path: "src/lib.rs".into(),
+        start_line,
+        end_line,
+        max_bytes,
+        repo_root: None,
+    }
+}
+
+const LINES: [&str; 4] = ["alpha", "beta", "gamma", "delta"];
+
+#[rstest]
+fn chunk_lines_merges_overlapping_chunks() {
+    let chunks = [chunk(1, "a\nb\nc"), chunk(3, "c\nd")];
+
+    let lines = FileContentServiceImpl::chunk_lines(&chunks);
+
+    assert_eq!(
+        lines.into_iter().collect::<Vec<_>>(),
+        [(1, "a"), (2, "b"), (3, "c"), (4, "d")]
+    );
+}
+
+#[rstest]
+#[case("src/lib.rs", Some("src/lib.rs"))]
+#[case("./src/lib.rs", Some("src/lib.rs"))]
+#[case("../secrets.txt", None)]
+#[case("src/../../etc/passwd", None)]
+#[case("/etc/passwd", None)]
+#[case("  ", None)]
+fn normalize_path_confines_to_workspace(#[case] path: &str, #[case] expected: Option<&str>) {
+    let normalized = FileContentServiceImpl::normalize_path(path).ok();
+    assert_eq!(normalized.as_deref(), expected);
+}
+
+#[rstest]
+#[case(None, None, 1024, (1, 4, "alpha\nbeta\ngamma\ndelta", false))]
+#[case(Some(2), Some(3), 1024, (2, 3, "beta\ngamma", false))]
+#[case(Some(3), Some(99), 1024, (3, 4, "gamma\ndelta", false))]
+#[case(None, None, 11, (1, 2, "alpha\nbeta", true))]
+#[case(None, None, 3, (1, 1, "alp", true))]
+fn slice_honours_range_and_byte_limit(
+    #[case] start: Option<u32>,
+    #[case] end: Option<u32>,
+    #[case] max_bytes: usize,
+    #[case] expected: (u32, u32, &str, bool),
+) -> TestResult {
+    let (first, last, content, truncated) =
+        FileContentServiceImpl::slice(&LINES, &request(start, end, max_bytes))?;
+
+    assert_eq!((first, last, content.as_str(), truncated), expected);
+    Ok(())
+}
+
+#[rstest]
+#[case(Some(3), Some(2))]
+#[case(Some(9), None)]
+fn slice_rejects_invalid_ranges(#[case] start: Option<u32>, #[case] end: Option<u32>) {
+    assert!(FileContentServiceImpl::slice(&LINES, &request(start, end, 1024)).is_err());
+}
@@ -0,0 +1,41 @@
+//! Tests for `GrepServiceImpl` pattern compilation
+
+use mcb_domain::ports::GrepQuery;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_infrastructure::services::GrepServiceImpl;
+use rstest::rstest;
+
+fn query(pattern: &str, regex: bool, case_sensitive: bool) -> GrepQuery {
+    GrepQuery {
+        pattern: pattern.to_owned(),
+        regex,
+        case_sensitive,
+        scope: None,
+        max_matches: 10,
+    }
+}
+
+#[rstest]
+#[case("fn main()", false, true, "fn main() {", true)]
+#[case("fn main()", false, true, "fn mainx {", false)]
+#[case("FN MAIN", false, false, "fn main() {", true)]
+#[case("FN MAIN", false, true, "fn main() {", false)]
+#[case(r"fn \w+\(", true, true, "fn parse(", true)]
+fn compiles_literal_and_regex_patterns(
+    #[case] pattern: &str,
+    #[case] regex: bool,
+    #[case] case_sensitive: bool,
+    #[case] line: &str,
+    #[case] expected: bool,
+) -> TestResult {
+    let compiled = GrepServiceImpl::compile(&query(pattern, regex, case_sensitive))?;
+    assert_eq!(compiled.is_match(line), expected);
+    Ok(())
+}
+
+#[rstest]
+#[case("")]
+#[case("(unclosed")]
+fn rejects_invalid_patterns(#[case] pattern: &str) {
+    assert!(GrepServiceImpl::compile(&query(pattern, true, true)).is_err());
+}
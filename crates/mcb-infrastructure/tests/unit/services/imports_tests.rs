@@ -0,0 +1,96 @@
+//! Tests for import extraction
+
+use mcb_infrastructure::services::extract_imports;
+use rstest::rstest;
+
+fn module_paths(path: &str, content: &str) -> Vec<(u32, String)> {
+    extract_imports("repo", path, content)
+        .into_iter()
+        .map(|i| (i.line, i.module_path))
+        .collect()
+}
+
+#[rstest]
+fn rust_use_trees_resolve_against_the_crate() {
+    let content = "use std::sync::Arc;\n\
+                   use crate::config::{Loader, self};\n\
+                   pub(crate) use super::util::*;\n\
+                   use mcb_domain::ports::{\n    A,\n    b::C,\n};\n\
+                   mod parser;\n";
+
+    assert_eq!(
+        module_paths("crates/app/src/server/mod.rs", content),
+        [
+            (2, "crates/app/src/config/Loader".to_owned()),
+            (2, "crates/app/src/config".to_owned()),
+            (3, "crates/app/src/util".to_owned()),
+            (4, "mcb-domain/src/ports/A".to_owned()),
+            (4, "mcb-domain/src/ports/b/C".to_owned()),
+            (8, "crates/app/src/server/parser".to_owned()),
+        ]
+    );
+}
+
+#[rstest]
+fn python_relative_and_absolute_imports() {
+    let content =
+        "import os, pkg.tools as t\nfrom ..models import User, Group\nfrom . import helpers\n";
+
+    assert_eq!(
+        module_paths("app/api/views.py", content),
+        [
+            (1, "os".to_owned()),
+            (1, "pkg/tools".to_owned()),
+            (2, "app/models/User".to_owned()),
+            (2, "app/models/Group".to_owned()),
+            (3, "app/api/helpers".to_owned()),
+        ]
+    );
+}
+
+#[rstest]
+#[case("import { a } from './utils.js';", "web/src/utils")]
+#[case("} from \"../lib/api\";", "web/lib/api")]
+#[case("import 'react';", "react")]
+#[case("const fs = require('node:fs');", "node:fs")]
+fn javascript_specifiers(#[case] line: &str, #[case] expected: &str) {
+    assert_eq!(
+        module_paths("web/src/app.ts", line),
+        [(1, expected.to_owned())]
+    );
+}
+
+#[rstest]
+fn go_import_blocks() {
+    let content =
+        "import (\n\t\"fmt\"\n\tauth \"github.com/acme/tool/internal/auth\"\n)\nimport \"os\"\n";
+
+    assert_eq!(
+        module_paths("cmd/main.go", content),
+        [
+            (2, "fmt".to_owned()),
+            (3, "github.com/acme/tool/internal/auth".to_owned()),
+            (5, "os".to_owned()),
+        ]
+    );
+}
+
+#[rstest]
+fn java_imports_use_slash_paths() {
+    let content =
+        "import com.acme.Parser;\nimport static com.acme.Util.max;\nimport com.acme.io.*;\n";
+
+    assert_eq!(
+        module_paths("src/Main.java", content),
+        [
+            (1, "com/acme/Parser".to_owned()),
+            (2, "com/acme/Util/max".to_owned()),
+            (3, "com/acme/io".to_owned()),
+        ]
+    );
+}
+
+#[rstest]
+fn unsupported_languages_have_no_imports() {
+    assert!(extract_imports("repo", "README.md", "import x from 'y'").is_empty());
+}
@@ -0,0 +1,65 @@
+//! Tests for `IndexEvaluationServiceImpl` template query generation
+
+use mcb_domain::value_objects::SearchResult;
+use mcb_infrastructure::services::IndexEvaluationServiceImpl;
+use rstest::rstest;
+
+fn chunk(language: &str, content: &str) -> SearchResult {
+    SearchResult {
+        id: "src/lib#0".to_owned(),
+        file_path: "src/lib".to_owned(),
+        start_line: 1,
+        content: content.to_owned(),
+        score: 1.0,
+        language: language.to_owned(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+#[rstest]
+#[case(
+    "rust",
+    "/// Rotate the refresh token. Old tokens are revoked.\npub fn rotate_refresh_token() {}",
+    "Rotate the refresh token",
+    Some("rotate_refresh_token")
+)]
+#[case(
+    "python",
+    "def load_config(path):\n    \"\"\"Read the YAML config from disk.\"\"\"\n",
+    "Read the YAML config from disk",
+    Some("load_config")
+)]
+#[case(
+    "go",
+    "func (s *Server) handleHTTPRequest(w http.ResponseWriter) {",
+    "handle http request",
+    Some("handleHTTPRequest")
+)]
+#[case(
+    "rust",
+    "#[derive(Debug)]\npub struct SessionTokenStore {",
+    "session token store",
+    Some("SessionTokenStore")
+)]
+fn template_query_uses_doc_comment_then_symbol_words(
+    #[case] language: &str,
+    #[case] content: &str,
+    #[case] query: &str,
+    #[case] symbol: Option<&str>,
+) {
+    let labeled = IndexEvaluationServiceImpl::template_query(&chunk(language, content))
+        .expect("query generated");
+
+    assert_eq!(labeled.query, query);
+    assert_eq!(labeled.files, ["src/lib"]);
+    assert_eq!(labeled.symbols.first().map(String::as_str), symbol);
+}
+
+#[rstest]
+#[case("fn run() {}")]
+#[case("let total = a + b;")]
+#[case("// TODO\nx = 1")]
+fn template_query_skips_chunks_without_enough_words(#[case] content: &str) {
+    assert!(IndexEvaluationServiceImpl::template_query(&chunk("rust", content)).is_none());
+}
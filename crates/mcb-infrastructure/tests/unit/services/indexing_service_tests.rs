@@ -1,6 +1,12 @@
-use mcb_infrastructure::services::indexing_service::IndexingServiceImpl;
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::ports::{IndexingThrottlePolicy, LanguageChunkingProvider};
+use mcb_domain::value_objects::Language;
+use mcb_infrastructure::services::indexing_service::{
+    IndexingServiceImpl, IndexingThrottle, estimate_codebase, resolve_allowed_path,
+};
 use rstest::rstest;
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 #[rstest]
 fn workspace_relative_path_normalizes_within_workspace() {
@@ -19,3 +25,112 @@ fn workspace_relative_path_rejects_outside_workspace() {
         .expect_err("outside path must fail");
     assert!(err.to_string().contains("is not under root"));
 }
+
+#[rstest]
+#[tokio::test]
+async fn throttle_spaces_files_at_configured_rate() {
+    let throttle = IndexingThrottle::new(IndexingThrottlePolicy {
+        max_files_per_sec: 20,
+        ..IndexingThrottlePolicy::default()
+    });
+    let start = Instant::now();
+    for _ in 0..3 {
+        throttle.before_file().await;
+    }
+    // First file starts at once, the next two wait 50ms each.
+    assert!(start.elapsed() >= Duration::from_millis(100));
+}
+
+#[rstest]
+#[tokio::test]
+async fn default_throttle_does_not_wait() {
+    let throttle = IndexingThrottle::default();
+    let start = Instant::now();
+    for _ in 0..100 {
+        throttle.before_file().await;
+        throttle.before_embedding().await;
+        throttle.after_read().await;
+    }
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[rstest]
+fn allowed_path_resolves_under_root() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let repo = root.path().join("repo");
+    std::fs::create_dir(&repo).expect("create repo");
+    let resolved = resolve_allowed_path(&repo.join("..").join("repo"), &[root.path().into()])
+        .expect("path under root");
+    assert_eq!(resolved, repo.canonicalize().expect("canonical repo"));
+}
+
+#[rstest]
+fn allowed_path_rejects_dot_dot_escape() {
+    let parent = tempfile::tempdir().expect("tempdir");
+    let root = parent.path().join("allowed");
+    std::fs::create_dir(&root).expect("create root");
+    let err = resolve_allowed_path(&root.join(".."), &[root]).expect_err("escape must fail");
+    assert!(
+        err.to_string()
+            .contains("outside the allowed indexing roots")
+    );
+}
+
+#[rstest]
+fn allowed_path_rejects_missing_root() {
+    let root = tempfile::tempdir().expect("tempdir");
+    let missing = root.path().join("missing");
+    let err = resolve_allowed_path(root.path(), &[missing]).expect_err("missing root must fail");
+    assert!(err.to_string().contains("mcp.indexing.allowed_roots"));
+}
+
+#[rstest]
+fn empty_allowlist_allows_any_path() {
+    let path = Path::new("/any/path");
+    let resolved = resolve_allowed_path(path, &[]).expect("no allowlist");
+    assert_eq!(resolved, path);
+}
+
+/// Chunker that returns no chunks; discovery tests only count files.
+struct NoChunks;
+
+impl LanguageChunkingProvider for NoChunks {
+    fn language(&self) -> Language {
+        "none".to_owned()
+    }
+
+    fn extensions(&self) -> &[&'static str] {
+        &[]
+    }
+
+    fn chunk(&self, _content: &str, _file_path: &str) -> Vec<CodeChunk> {
+        Vec::new()
+    }
+
+    fn provider_name(&self) -> &str {
+        "none"
+    }
+}
+
+#[rstest]
+fn discovery_includes_extensionless_files_of_supported_languages() {
+    let root = tempfile::tempdir().expect("tempdir");
+    for (name, content) in [
+        ("main.rs", "fn main() {}\n"),
+        ("Dockerfile", "FROM rust:1.85 AS build\nRUN cargo build\n"),
+        ("deploy", "#!/usr/bin/env bash\nset -e\n"),
+        ("README", "From the maintainers: read the docs first.\n"),
+        ("notes.txt", "FROM alpine\n"),
+    ] {
+        std::fs::write(root.path().join(name), content).expect("write file");
+    }
+
+    let estimate = estimate_codebase(
+        root.path(),
+        &NoChunks,
+        vec!["rs".to_owned(), "sh".to_owned(), "dockerfile".to_owned()],
+    );
+
+    assert_eq!(estimate.files, 3);
+    assert_eq!(estimate.files_by_extension.get(""), Some(&2));
+}
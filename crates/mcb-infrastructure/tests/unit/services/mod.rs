@@ -1,5 +1,17 @@
 //! Unit tests.
 
+mod change_summary_service_tests;
+mod context_service_tests;
+mod duplicate_detection_service_tests;
+mod file_content_service_tests;
+mod grep_service_tests;
 mod highlight_service_tests;
+mod imports_tests;
+mod index_evaluation_service_tests;
 mod indexing_service_tests;
+mod module_graph_service_tests;
+mod search_feedback_service_tests;
+mod search_service_tests;
 pub mod service_tests;
+mod session_context_service_tests;
+mod test_links_tests;
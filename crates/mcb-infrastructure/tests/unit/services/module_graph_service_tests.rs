@@ -0,0 +1,130 @@
+//! Tests for `ModuleGraph` import resolution and impact traversal
+
+use mcb_domain::entities::module_import::ModuleImport;
+use mcb_infrastructure::services::ModuleGraph;
+use rstest::rstest;
+
+fn import(source_file: &str, module_path: &str) -> ModuleImport {
+    ModuleImport {
+        collection: "repo".to_owned(),
+        source_file: source_file.to_owned(),
+        specifier: module_path.to_owned(),
+        module_path: module_path.to_owned(),
+        line: 1,
+    }
+}
+
+fn graph(files: &[&str], imports: Vec<ModuleImport>) -> ModuleGraph {
+    ModuleGraph::new(files.iter().map(|f| (*f).to_owned()), imports)
+}
+
+fn resolved(graph: &ModuleGraph, path: &str) -> Vec<Vec<String>> {
+    graph
+        .dependencies(path)
+        .imports
+        .into_iter()
+        .map(|d| d.resolved)
+        .collect()
+}
+
+#[rstest]
+#[case("src/main.rs", "src/config/Loader", "src/config.rs")]
+#[case("src/main.rs", "src", "src/lib.rs")]
+#[case("app/views.py", "app/models/User", "app/models/__init__.py")]
+#[case("web/app.ts", "web/utils", "web/utils/index.ts")]
+#[case(
+    "cmd/main.go",
+    "github.com/acme/tool/internal/auth",
+    "internal/auth/token.go"
+)]
+#[case(
+    "src/Main.java",
+    "com/acme/Parser",
+    "src/main/java/com/acme/Parser.java"
+)]
+fn resolves_module_paths_to_files(
+    #[case] source: &str,
+    #[case] module_path: &str,
+    #[case] expected: &str,
+) {
+    let files = [
+        "src/config.rs",
+        "src/lib.rs",
+        "app/models/__init__.py",
+        "web/utils/index.ts",
+        "internal/auth/token.go",
+        "src/main/java/com/acme/Parser.java",
+    ];
+    let graph = graph(&files, vec![import(source, module_path)]);
+
+    assert_eq!(resolved(&graph, source), [vec![expected.to_owned()]]);
+}
+
+#[rstest]
+fn external_modules_stay_unresolved() {
+    let graph = graph(
+        &["web/app.ts"],
+        vec![
+            import("web/app.ts", "react"),
+            import("web/app.ts", "web/missing"),
+        ],
+    );
+
+    assert_eq!(
+        resolved(&graph, "web/app.ts"),
+        [vec![], Vec::<String>::new()]
+    );
+}
+
+#[rstest]
+fn lists_importers() {
+    let graph = graph(
+        &["src/a.rs", "src/b.rs", "src/c.rs"],
+        vec![
+            import("src/a.rs", "src/c"),
+            import("src/b.rs", "src/c/Item"),
+        ],
+    );
+
+    let importers: Vec<_> = graph
+        .dependencies("src/c.rs")
+        .imported_by
+        .into_iter()
+        .map(|i| i.file)
+        .collect();
+    assert_eq!(importers, ["src/a.rs", "src/b.rs"]);
+}
+
+#[rstest]
+fn impact_follows_importers_up_to_max_depth() {
+    let graph = graph(
+        &["src/core.rs", "src/api.rs", "src/cli.rs", "src/main.rs"],
+        vec![
+            import("src/api.rs", "src/core"),
+            import("src/cli.rs", "src/api"),
+            import("src/main.rs", "src/cli"),
+            import("src/main.rs", "src/core"),
+        ],
+    );
+
+    let impact = graph.impact(&["src/core.rs".to_owned()], 2);
+
+    let reached: Vec<_> = impact
+        .impacted
+        .iter()
+        .map(|m| (m.path.as_str(), m.depth, m.via.as_str()))
+        .collect();
+    assert_eq!(
+        reached,
+        [
+            ("src/api.rs", 1, "src/core.rs"),
+            ("src/main.rs", 1, "src/core.rs"),
+            ("src/cli.rs", 2, "src/api.rs"),
+        ]
+    );
+    assert!(!impact.truncated);
+
+    let shallow = graph.impact(&["src/core.rs".to_owned()], 1);
+    assert_eq!(shallow.impacted.len(), 2);
+    assert!(shallow.truncated);
+}
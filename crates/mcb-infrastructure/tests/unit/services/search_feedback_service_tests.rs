@@ -0,0 +1,182 @@
+//! Tests for `SearchFeedbackServiceImpl`
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::memory::{FileBoost, FileFeedbackTally, SearchFeedback};
+use mcb_domain::error::Result;
+use mcb_domain::ports::{
+    RecordFeedbackInput, SearchFeedbackRepository, SearchFeedbackServiceInterface,
+};
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::{ScoreExplanation, SearchResult};
+use mcb_infrastructure::services::SearchFeedbackServiceImpl;
+use rstest::rstest;
+use tokio::sync::Mutex;
+
+/// In-memory repository that tallies recorded feedback directly.
+#[derive(Default)]
+struct MemoryRepo {
+    feedback: Mutex<Vec<SearchFeedback>>,
+    boosts: Mutex<Vec<FileBoost>>,
+}
+
+#[async_trait]
+impl SearchFeedbackRepository for MemoryRepo {
+    async fn record_feedback(&self, feedback: &SearchFeedback) -> Result<()> {
+        self.feedback.lock().await.push(feedback.clone());
+        Ok(())
+    }
+
+    async fn tally_by_file(&self) -> Result<Vec<FileFeedbackTally>> {
+        let mut tallies: Vec<FileFeedbackTally> = Vec::new();
+        for f in self.feedback.lock().await.iter() {
+            let idx = tallies
+                .iter()
+                .position(|t| t.collection == f.collection && t.file_path == f.file_path)
+                .unwrap_or_else(|| {
+                    tallies.push(FileFeedbackTally {
+                        collection: f.collection.clone(),
+                        file_path: f.file_path.clone(),
+                        up: 0,
+                        down: 0,
+                    });
+                    tallies.len() - 1
+                });
+            if f.relevant {
+                tallies[idx].up += 1;
+            } else {
+                tallies[idx].down += 1;
+            }
+        }
+        Ok(tallies)
+    }
+
+    async fn replace_file_boosts(&self, boosts: &[FileBoost]) -> Result<()> {
+        *self.boosts.lock().await = boosts.to_vec();
+        Ok(())
+    }
+
+    async fn file_boosts(&self, collection: &str) -> Result<Vec<FileBoost>> {
+        Ok(self
+            .boosts
+            .lock()
+            .await
+            .iter()
+            .filter(|b| b.collection == collection)
+            .cloned()
+            .collect())
+    }
+}
+
+fn vote(file_path: &str, relevant: bool) -> RecordFeedbackInput {
+    RecordFeedbackInput {
+        query: "auth".to_owned(),
+        collection: "repo".to_owned(),
+        chunk_id: format!("{file_path}#0"),
+        file_path: file_path.to_owned(),
+        relevant,
+        session_id: None,
+    }
+}
+
+fn result(file_path: &str, score: f64) -> SearchResult {
+    SearchResult {
+        id: format!("{file_path}#0"),
+        file_path: file_path.to_owned(),
+        start_line: 1,
+        content: String::new(),
+        score,
+        language: "rust".into(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+#[rstest]
+#[case(0, 1, None)]
+#[case(2, 0, Some(1.0 + 0.2 * 2.0 / 7.0))]
+#[case(1, 3, Some(1.0 - 0.2 * 2.0 / 9.0))]
+#[case(2, 2, Some(1.0))]
+fn boost_is_damped_and_bounded(#[case] up: u64, #[case] down: u64, #[case] expected: Option<f64>) {
+    let tally = FileFeedbackTally {
+        collection: "repo".to_owned(),
+        file_path: "a.rs".to_owned(),
+        up,
+        down,
+    };
+    let boost = SearchFeedbackServiceImpl::boost_for(&tally);
+    match (boost, expected) {
+        (Some(b), Some(e)) => assert!((b - e).abs() < 1e-9, "{b} != {e}"),
+        (b, e) => assert_eq!(b, e),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn tuned_boosts_rerank_results() -> TestResult {
+    let svc = SearchFeedbackServiceImpl::new(Arc::new(MemoryRepo::default()));
+    for _ in 0..3 {
+        svc.record_feedback(vote("src/good.rs", true)).await?;
+        svc.record_feedback(vote("src/bad.rs", false)).await?;
+    }
+
+    assert_eq!(svc.tune_file_boosts().await?, 2);
+
+    let ranked = svc
+        .apply_file_boosts(
+            "repo",
+            vec![result("src/bad.rs", 0.80), result("src/good.rs", 0.75)],
+        )
+        .await?;
+    assert_eq!(ranked[0].file_path, "src/good.rs");
+    assert!(ranked[1].score < 0.80);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn explained_results_record_the_rerank_boost() -> TestResult {
+    let svc = SearchFeedbackServiceImpl::new(Arc::new(MemoryRepo::default()));
+    for _ in 0..3 {
+        svc.record_feedback(vote("src/good.rs", true)).await?;
+    }
+    svc.tune_file_boosts().await?;
+
+    let mut explained = result("src/good.rs", 0.5);
+    explained.explanation = Some(ScoreExplanation::dense(0.5));
+    let ranked = svc.apply_file_boosts("repo", vec![explained]).await?;
+
+    let boost = ranked[0]
+        .explanation
+        .as_ref()
+        .and_then(|e| e.rerank_boost)
+        .expect("rerank boost recorded");
+    assert!(boost > 1.0);
+    assert!((ranked[0].score - 0.5 * boost).abs() < 1e-9);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn boosts_are_scoped_to_collection() -> TestResult {
+    let svc = SearchFeedbackServiceImpl::new(Arc::new(MemoryRepo::default()));
+    svc.record_feedback(vote("src/good.rs", true)).await?;
+    svc.record_feedback(vote("src/good.rs", true)).await?;
+    svc.tune_file_boosts().await?;
+
+    let other = svc
+        .apply_file_boosts("other", vec![result("src/good.rs", 0.5)])
+        .await?;
+    assert!((other[0].score - 0.5).abs() < f64::EPSILON);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn rejects_feedback_without_chunk() {
+    let svc = SearchFeedbackServiceImpl::new(Arc::new(MemoryRepo::default()));
+    let mut input = vote("src/a.rs", true);
+    input.chunk_id = String::new();
+    assert!(svc.record_feedback(input).await.is_err());
+}
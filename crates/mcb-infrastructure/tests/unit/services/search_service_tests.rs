@@ -0,0 +1,158 @@
+//! Tests for `SearchServiceImpl` query blending, diversification and recency
+
+use mcb_domain::ports::{Diversification, DiversifyBy};
+use mcb_domain::value_objects::{ScoreExplanation, SearchResult};
+use mcb_infrastructure::services::SearchServiceImpl;
+use rstest::rstest;
+
+fn assert_close(actual: &[f32], expected: &[f32]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-5, "{actual:?} != {expected:?}");
+    }
+}
+
+#[rstest]
+fn empty_context_returns_normalized_query() {
+    let blended = SearchServiceImpl::blend_query_vector(&[3.0, 4.0], &[], 0.5);
+    assert_close(&blended, &[0.6, 0.8]);
+}
+
+#[rstest]
+fn weight_shifts_query_toward_context() {
+    let query = [1.0, 0.0];
+    let context = vec![vec![0.0, 10.0]];
+
+    let half = SearchServiceImpl::blend_query_vector(&query, &context, 0.5);
+    let inv_sqrt2 = std::f32::consts::FRAC_1_SQRT_2;
+    assert_close(&half, &[inv_sqrt2, inv_sqrt2]);
+
+    let none = SearchServiceImpl::blend_query_vector(&query, &context, 0.0);
+    assert_close(&none, &[1.0, 0.0]);
+}
+
+#[rstest]
+fn mismatched_context_dimensions_are_ignored() {
+    let blended = SearchServiceImpl::blend_query_vector(&[0.0, 2.0], &[vec![1.0, 0.0, 0.0]], 0.9);
+    assert_close(&blended, &[0.0, 1.0]);
+}
+
+fn hit(file_path: &str, content: &str, score: f64) -> SearchResult {
+    SearchResult {
+        id: format!("{file_path}:{score}"),
+        file_path: file_path.to_owned(),
+        start_line: 1,
+        content: content.to_owned(),
+        score,
+        language: "rust".into(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+fn files(results: &[SearchResult]) -> Vec<&str> {
+    results.iter().map(|r| r.file_path.as_str()).collect()
+}
+
+#[rstest]
+#[case(DiversifyBy::File, ["src/a.rs", "src/b.rs", "lib/c.rs"])]
+#[case(DiversifyBy::Directory, ["src/a.rs", "lib/c.rs", "src/a.rs"])]
+fn diversify_spreads_results_across_groups(#[case] by: DiversifyBy, #[case] expected: [&str; 3]) {
+    let results = vec![
+        hit("src/a.rs", "fn one() {}", 0.95),
+        hit("src/a.rs", "fn two() {}", 0.94),
+        hit("src/a.rs", "fn three() {}", 0.93),
+        hit("src/b.rs", "fn four() {}", 0.90),
+        hit("lib/c.rs", "fn five() {}", 0.85),
+    ];
+
+    let diversified = SearchServiceImpl::diversify(results, Diversification { by, lambda: 0.5 }, 3);
+
+    assert_eq!(files(&diversified), expected);
+}
+
+#[rstest]
+fn diversify_by_symbol_collapses_overlapping_chunks() {
+    let results = vec![
+        hit("src/a.rs", "pub fn parse(input: &str) {", 0.9),
+        hit("src/a.rs", "/// docs\npub fn parse(input: &str) {", 0.89),
+        hit("src/a.rs", "fn render() {}", 0.8),
+    ];
+    let diversified = SearchServiceImpl::diversify(
+        results,
+        Diversification {
+            by: DiversifyBy::Symbol,
+            lambda: 0.5,
+        },
+        2,
+    );
+
+    assert_eq!(diversified[1].content, "fn render() {}");
+}
+
+#[rstest]
+fn diversify_with_lambda_one_keeps_relevance_order() {
+    let results = vec![
+        hit("src/a.rs", "", 0.9),
+        hit("src/a.rs", "", 0.8),
+        hit("src/b.rs", "", 0.7),
+    ];
+    let diversified = SearchServiceImpl::diversify(
+        results,
+        Diversification {
+            by: DiversifyBy::File,
+            lambda: 1.0,
+        },
+        3,
+    );
+
+    assert_eq!(files(&diversified), ["src/a.rs", "src/a.rs", "src/b.rs"]);
+}
+
+#[rstest]
+fn recency_boost_ranks_fresh_code_above_stale_code() {
+    const DAY: i64 = 86_400;
+    let now = 1_000 * DAY;
+    let mut stale = hit("src/legacy.rs", "", 0.90);
+    stale.last_modified = Some(now - 720 * DAY);
+    let mut fresh = hit("src/active.rs", "", 0.85);
+    fresh.last_modified = Some(now - DAY);
+    let unknown = hit("src/unknown.rs", "", 0.80);
+    let mut results = vec![stale, fresh, unknown];
+
+    SearchServiceImpl::apply_recency(&mut results, 90.0, now);
+
+    assert_eq!(
+        files(&results),
+        ["src/active.rs", "src/unknown.rs", "src/legacy.rs"]
+    );
+    assert!((results[1].score - 0.80).abs() < f64::EPSILON);
+}
+
+#[rstest]
+fn recency_at_one_half_life_halves_the_boost() {
+    let mut results = vec![hit("src/a.rs", "", 1.0)];
+    results[0].last_modified = Some(0);
+
+    SearchServiceImpl::apply_recency(&mut results, 1.0, 86_400);
+
+    assert!((results[0].score - (1.0 - 0.3 / 2.0)).abs() < 1e-9);
+}
+
+#[rstest]
+fn recency_records_its_multiplier_only_on_explained_results() {
+    let mut explained = hit("src/a.rs", "", 1.0);
+    explained.last_modified = Some(0);
+    explained.explanation = Some(ScoreExplanation::dense(1.0));
+    let mut plain = hit("src/b.rs", "", 0.5);
+    plain.last_modified = Some(0);
+    let mut results = vec![explained, plain];
+
+    SearchServiceImpl::apply_recency(&mut results, 1.0, 86_400);
+
+    let explanation = results[0].explanation.as_ref().expect("explanation kept");
+    let boost = explanation.recency_boost.expect("recency recorded");
+    assert!((explanation.dense - 1.0).abs() < f64::EPSILON);
+    assert!((results[0].score - explanation.dense * boost).abs() < 1e-9);
+    assert!(results[1].explanation.is_none());
+}
@@ -0,0 +1,115 @@
+//! Tests for `SessionContextServiceImpl`
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::{SearchFilters, SearchServiceInterface, SessionContextServiceInterface};
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_infrastructure::services::SessionContextServiceImpl;
+use rstest::rstest;
+
+/// Search stub returning `count` chunks of 40 chars (10 tokens) each, best first.
+struct FixedSearch {
+    count: usize,
+}
+
+#[async_trait]
+impl SearchServiceInterface for FixedSearch {
+    async fn search(
+        &self,
+        _collection: &CollectionId,
+        _query: &str,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        Ok((0..self.count.min(limit))
+            .map(|i| SearchResult {
+                id: format!("chunk-{i}"),
+                file_path: format!("src/file_{i}.rs"),
+                start_line: 1,
+                content: "x".repeat(40),
+                score: 1.0 - i as f64 / 100.0,
+                language: "rust".into(),
+                last_modified: None,
+                explanation: None,
+            })
+            .collect())
+    }
+
+    async fn search_with_filters(
+        &self,
+        collection: &CollectionId,
+        query: &str,
+        limit: usize,
+        _filters: Option<&SearchFilters>,
+    ) -> Result<Vec<SearchResult>> {
+        self.search(collection, query, limit).await
+    }
+
+    async fn search_with_context(
+        &self,
+        collection: &CollectionId,
+        query: &str,
+        _context: &[String],
+        _context_weight: f32,
+        limit: usize,
+        _filters: Option<&SearchFilters>,
+    ) -> Result<Vec<SearchResult>> {
+        self.search(collection, query, limit).await
+    }
+}
+
+fn service(count: usize) -> SessionContextServiceImpl {
+    SessionContextServiceImpl::new(Arc::new(FixedSearch { count }))
+}
+
+fn ids(results: &[SearchResult]) -> Vec<&str> {
+    results.iter().map(|r| r.id.as_str()).collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn respects_token_budget() -> TestResult {
+    let svc = service(10);
+    let collection = CollectionId::from_name("repo");
+
+    let batch = svc.get_more_context("s1", &collection, "q", 25).await?;
+
+    assert_eq!(ids(&batch.results), ["chunk-0", "chunk-1"]);
+    assert_eq!(batch.tokens_used, 20);
+    assert!(!batch.exhausted);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn follow_up_calls_skip_returned_chunks() -> TestResult {
+    let svc = service(5);
+    let collection = CollectionId::from_name("repo");
+
+    let first = svc.get_more_context("s1", &collection, "q", 30).await?;
+    let second = svc.get_more_context("s1", &collection, "q", 30).await?;
+
+    assert_eq!(ids(&first.results), ["chunk-0", "chunk-1", "chunk-2"]);
+    assert_eq!(ids(&second.results), ["chunk-3", "chunk-4"]);
+    assert_eq!(second.skipped_seen, 3);
+    assert!(second.exhausted);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn sessions_are_isolated_and_resettable() -> TestResult {
+    let svc = service(3);
+    let collection = CollectionId::from_name("repo");
+    svc.mark_returned("s1", &["chunk-0".to_owned()]);
+
+    let other = svc.get_more_context("s2", &collection, "q", 10).await?;
+    assert_eq!(ids(&other.results), ["chunk-0"]);
+
+    svc.reset("s1");
+    assert_eq!(svc.seen_count("s1"), 0);
+    assert_eq!(svc.seen_count("s2"), 1);
+    Ok(())
+}
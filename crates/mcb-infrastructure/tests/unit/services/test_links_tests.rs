@@ -0,0 +1,65 @@
+//! Tests for test-to-code link extraction
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::entities::test_link::TestLinkReason;
+use mcb_infrastructure::services::extract_test_links;
+use rstest::rstest;
+
+fn function(path: &str, content: &str) -> CodeChunk {
+    CodeChunk {
+        id: "chunk-1".to_owned(),
+        content: content.to_owned(),
+        file_path: path.to_owned(),
+        start_line: 3,
+        end_line: 3 + u32::try_from(content.lines().count()).unwrap_or(1) - 1,
+        language: "rust".to_owned(),
+        metadata: serde_json::json!({ "node_type": "function_item" }),
+    }
+}
+
+fn targets(path: &str, content: &str) -> Vec<(String, TestLinkReason)> {
+    extract_test_links("repo", path, &[function(path, content)])
+        .into_iter()
+        .map(|link| (link.target, link.reason))
+        .collect()
+}
+
+#[rstest]
+fn links_calls_and_type_paths_in_test_body() {
+    let links = targets(
+        "tests/config.rs",
+        "#[test]\nfn reads_config() {\n    let c = Config::load(\"a\");\n    assert!(parse_config(&c).is_ok());\n}",
+    );
+
+    assert_eq!(
+        links,
+        [
+            ("Config".to_owned(), TestLinkReason::Call),
+            ("is_ok".to_owned(), TestLinkReason::Call),
+            ("load".to_owned(), TestLinkReason::Call),
+            ("parse_config".to_owned(), TestLinkReason::Call),
+        ]
+    );
+}
+
+#[rstest]
+#[case("src/lib.rs", "fn test_render() {\n    let out = draw();\n}", "render")]
+#[case("src/widget.py", "def test_render():\n    pass", "render")]
+#[case("src/widget.js", "function testRender() {\n}", "render")]
+fn links_test_name_to_symbol(#[case] path: &str, #[case] content: &str, #[case] target: &str) {
+    let links = targets(path, content);
+
+    assert!(links.contains(&(target.to_owned(), TestLinkReason::Name)));
+}
+
+#[rstest]
+fn call_link_wins_over_name_link() {
+    let links = targets("tests/render.rs", "fn test_render() {\n    render();\n}");
+
+    assert_eq!(links, [("render".to_owned(), TestLinkReason::Call)]);
+}
+
+#[rstest]
+fn ignores_production_functions() {
+    assert!(targets("src/lib.rs", "fn render() {\n    draw();\n}").is_empty());
+}
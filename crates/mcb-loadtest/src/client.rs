@@ -0,0 +1,260 @@
+//! Simulated MCP client sessions.
+
+use std::time::{Duration, Instant};
+
+use mcb_domain::protocol::{McpRequest, McpResponse};
+use mcb_utils::constants::FALLBACK_UNKNOWN;
+use mcb_utils::constants::headers::{
+    HEADER_AGENT_PROGRAM, HEADER_DELEGATED, HEADER_MACHINE_ID, HEADER_MODEL_ID, HEADER_OPERATOR_ID,
+    HEADER_REPO_ID, HEADER_REPO_PATH, HEADER_SESSION_ID, HEADER_WORKSPACE_ROOT,
+};
+use mcb_utils::constants::http::{CONTENT_TYPE_JSON, HTTP_HEADER_CONTENT_TYPE};
+use mcb_utils::constants::protocol::{
+    EXECUTION_FLOW_HYBRID, HTTP_HEADER_EXECUTION_FLOW, JSONRPC_VERSION, MCP_ENDPOINT_PATH,
+};
+use mcb_utils::utils::id;
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::config::LoadTestArgs;
+
+/// Agent program reported in the provenance headers.
+pub const AGENT_PROGRAM: &str = "mcb-loadtest";
+
+/// Kind of tool call issued by a simulated client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    /// `search_code`
+    Search,
+    /// `index_repo`, or `index_status` when no index path is configured
+    Index,
+}
+
+impl Operation {
+    /// Lower-case name used in reports.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Search => "search",
+            Self::Index => "index",
+        }
+    }
+}
+
+/// How a tool call ended.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    /// The tool returned a result.
+    Success,
+    /// The request could not be sent or the response not read, timeouts
+    /// included.
+    Transport,
+    /// The server answered with a non-success HTTP status.
+    Http(u16),
+    /// The server answered with a JSON-RPC error.
+    Rpc(i32),
+    /// The tool ran but reported `isError`.
+    Tool,
+}
+
+impl Outcome {
+    /// Whether the call failed.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        *self != Self::Success
+    }
+
+    /// Error category used to group failures in reports; `None` on success.
+    #[must_use]
+    pub fn error_kind(&self) -> Option<String> {
+        match self {
+            Self::Success => None,
+            Self::Transport => Some("transport".to_owned()),
+            Self::Http(status) => Some(format!("http {status}")),
+            Self::Rpc(code) => Some(format!("json-rpc {code}")),
+            Self::Tool => Some("tool error".to_owned()),
+        }
+    }
+
+    /// Classify a JSON-RPC response.
+    #[must_use]
+    pub fn from_response(response: &McpResponse) -> Self {
+        if let Some(error) = &response.error {
+            return Self::Rpc(error.code);
+        }
+        let is_tool_error = response
+            .result
+            .as_ref()
+            .and_then(|result| result.get("isError"))
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+        if is_tool_error {
+            Self::Tool
+        } else {
+            Self::Success
+        }
+    }
+}
+
+/// One completed tool call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sample {
+    /// Kind of call
+    pub operation: Operation,
+    /// Time from sending the request to reading the whole response
+    pub latency: Duration,
+    /// How the call ended
+    pub outcome: Outcome,
+}
+
+/// Whether call number `call` of a client goes to the index tools.
+///
+/// Index calls are spread evenly through each client's sequence, so any
+/// window of 100 calls holds `index_percent` of them (±1).
+#[must_use]
+pub fn is_index_call(call: usize, index_percent: u8) -> bool {
+    let percent = usize::from(index_percent.min(100));
+    (call + 1) * percent / 100 > call * percent / 100
+}
+
+/// The traffic every simulated client replays.
+#[derive(Debug, Clone)]
+pub struct Workload {
+    endpoint: String,
+    requests: usize,
+    index_percent: u8,
+    index_path: Option<String>,
+    collection: Option<String>,
+    queries: Vec<String>,
+    limit: u32,
+    repo_path: String,
+    repo_id: String,
+    operator_id: String,
+    machine_id: String,
+}
+
+impl Workload {
+    /// Build the workload described by `args`.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the URL is neither HTTPS nor loopback HTTP, or when no
+    /// search query is given.
+    pub fn from_args(args: &LoadTestArgs) -> Result<Self, String> {
+        mcb_server::transport::HttpClientTransport::require_secure_transport(&args.url)?;
+        if args.queries.is_empty() {
+            return Err("at least one --query is required".to_owned());
+        }
+        let repo_path = args.repo_path.clone().unwrap_or_else(|| {
+            std::env::current_dir()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_else(|_| ".".to_owned())
+        });
+        let machine_id = hostname::get()
+            .ok()
+            .and_then(|h| h.into_string().ok())
+            .unwrap_or_else(|| FALLBACK_UNKNOWN.to_owned());
+        Ok(Self {
+            endpoint: format!("{}{MCP_ENDPOINT_PATH}", args.url.trim_end_matches('/')),
+            requests: args.requests,
+            index_percent: args.index_percent,
+            index_path: args.index_path.clone(),
+            collection: args.collection.clone(),
+            queries: args.queries.clone(),
+            limit: args.limit,
+            repo_path,
+            repo_id: args.repo_id.clone(),
+            operator_id: std::env::var("USER").unwrap_or_else(|_| AGENT_PROGRAM.to_owned()),
+            machine_id,
+        })
+    }
+
+    /// URL the tool calls are posted to.
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Tool call number `call` of client `client`.
+    #[must_use]
+    pub fn request(&self, client: usize, call: usize) -> (Operation, McpRequest) {
+        let (operation, name, mut arguments) = if is_index_call(call, self.index_percent) {
+            match &self.index_path {
+                Some(path) => (Operation::Index, "index_repo", json!({ "path": path })),
+                None => (Operation::Index, "index_status", json!({})),
+            }
+        } else {
+            let query = &self.queries[(client + call) % self.queries.len()];
+            (
+                Operation::Search,
+                "search_code",
+                json!({ "query": query, "limit": self.limit }),
+            )
+        };
+        if let (Some(collection), Some(arguments)) = (&self.collection, arguments.as_object_mut()) {
+            arguments.insert("collection".to_owned(), json!(collection));
+        }
+        let request = McpRequest {
+            jsonrpc: JSONRPC_VERSION.to_owned(),
+            method: "tools/call".to_owned(),
+            params: Some(json!({ "name": name, "arguments": arguments })),
+            id: Some(json!(call + 1)),
+        };
+        (operation, request)
+    }
+
+    /// Run one simulated session: its calls are sent one after the other
+    /// under a fresh session ID. `client` only selects the queries.
+    pub async fn run_session(&self, http: &reqwest::Client, client: usize) -> Vec<Sample> {
+        let session_id = id::generate_string();
+        let mut samples = Vec::with_capacity(self.requests);
+        for call in 0..self.requests {
+            let (operation, request) = self.request(client, call);
+            let started = Instant::now();
+            let outcome = self.send(http, &session_id, &request).await;
+            samples.push(Sample {
+                operation,
+                latency: started.elapsed(),
+                outcome,
+            });
+        }
+        samples
+    }
+
+    async fn send(
+        &self,
+        http: &reqwest::Client,
+        session_id: &str,
+        request: &McpRequest,
+    ) -> Outcome {
+        let sent = http
+            .post(&self.endpoint)
+            .header(HTTP_HEADER_CONTENT_TYPE, CONTENT_TYPE_JSON)
+            .header(HTTP_HEADER_EXECUTION_FLOW, EXECUTION_FLOW_HYBRID)
+            .header(HEADER_WORKSPACE_ROOT, &self.repo_path)
+            .header(HEADER_REPO_PATH, &self.repo_path)
+            .header(HEADER_REPO_ID, &self.repo_id)
+            .header(HEADER_SESSION_ID, session_id)
+            .header(HEADER_OPERATOR_ID, &self.operator_id)
+            .header(HEADER_MACHINE_ID, &self.machine_id)
+            .header(HEADER_AGENT_PROGRAM, AGENT_PROGRAM)
+            .header(HEADER_MODEL_ID, FALLBACK_UNKNOWN)
+            .header(HEADER_DELEGATED, "false")
+            .json(request)
+            .send()
+            .await;
+        let response = match sent {
+            Ok(response) => response,
+            Err(_) => return Outcome::Transport,
+        };
+        let status = response.status();
+        if !status.is_success() {
+            return Outcome::Http(status.as_u16());
+        }
+        match response.json::<McpResponse>().await {
+            Ok(response) => Outcome::from_response(&response),
+            Err(_) => Outcome::Transport,
+        }
+    }
+}
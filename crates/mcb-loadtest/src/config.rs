@@ -0,0 +1,91 @@
+//! Command-line options of `mcb-loadtest`.
+
+use std::time::Duration;
+
+use clap::Parser;
+
+/// Simulate concurrent MCP sessions against a running MCB server
+#[derive(Parser, Debug, Clone)]
+#[command(name = "mcb-loadtest")]
+#[command(about = "Simulate concurrent MCP sessions against a running MCB server")]
+#[command(version)]
+pub struct LoadTestArgs {
+    /// Server base URL; cleartext HTTP is only accepted for loopback hosts
+    #[arg(long, default_value = "http://127.0.0.1:8080")]
+    pub url: String,
+
+    /// Number of concurrent simulated clients, each with its own session
+    #[arg(long, short = 'c', default_value_t = 100)]
+    pub clients: usize,
+
+    /// Tool calls issued by each client, one after the other
+    #[arg(long, short = 'n', default_value_t = 20)]
+    pub requests: usize,
+
+    /// Share of the calls, in percent, that go to the index tools
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u8).range(0..=100))]
+    pub index_percent: u8,
+
+    /// Directory re-indexed by the index calls (`index_repo`); without it
+    /// they only poll `index_status`
+    #[arg(long)]
+    pub index_path: Option<String>,
+
+    /// Collection searched and indexed; defaults to the server's resolution
+    /// from the repository provenance
+    #[arg(long)]
+    pub collection: Option<String>,
+
+    /// Search query, repeatable; clients cycle through them
+    #[arg(
+        long = "query",
+        default_values = ["error handling", "parse configuration", "database connection"]
+    )]
+    pub queries: Vec<String>,
+
+    /// Results requested per search
+    #[arg(long, default_value_t = 10)]
+    pub limit: u32,
+
+    /// Repository path sent as provenance; defaults to the current directory
+    #[arg(long)]
+    pub repo_path: Option<String>,
+
+    /// Repository identifier sent as provenance
+    #[arg(long, default_value = "mcb-loadtest")]
+    pub repo_id: String,
+
+    /// Spread client start-up evenly over this many seconds
+    #[arg(long, default_value_t = 0)]
+    pub ramp_up_secs: u64,
+
+    /// Per-call timeout in seconds
+    #[arg(long, default_value_t = 30)]
+    pub timeout_secs: u64,
+
+    /// Print the report as JSON instead of a table
+    #[arg(long)]
+    pub json: bool,
+
+    /// Fail when the share of failed calls (0.0-1.0) exceeds this value
+    #[arg(long)]
+    pub max_error_rate: Option<f64>,
+}
+
+impl LoadTestArgs {
+    /// Delay before client `client` starts, spreading start-up over the
+    /// ramp-up period.
+    #[must_use]
+    pub fn start_delay(&self, client: usize) -> Duration {
+        if self.clients == 0 {
+            return Duration::ZERO;
+        }
+        Duration::from_secs(self.ramp_up_secs).mul_f64(client as f64 / self.clients as f64)
+    }
+
+    /// Per-call timeout.
+    #[must_use]
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
@@ -0,0 +1,61 @@
+//! Load-test harness for a running MCB server.
+//!
+//! **Documentation**: [docs/modules/server.md](../../../docs/modules/server.md#load-testing)
+//!
+//! Spawns simulated MCP clients, each with its own session and provenance
+//! headers, that post a mix of `search_code` and index tool calls to the
+//! server's JSON-RPC endpoint. Every call is timed and classified, and the
+//! run is summarized as latency percentiles, throughput and error counts.
+//!
+//! ```text
+//! mcb-loadtest --url http://127.0.0.1:8080 --clients 1000 --requests 20
+//! ```
+
+pub mod client;
+pub mod config;
+pub mod report;
+
+use std::sync::Arc;
+use std::time::Instant;
+
+use tokio::task::JoinSet;
+
+pub use client::{Operation, Outcome, Sample, Workload};
+pub use config::LoadTestArgs;
+pub use report::{LatencySummary, LoadReport, OperationReport};
+
+/// Run the load test described by `args` and summarize it.
+///
+/// All clients start together, or spread over the ramp-up period, and share
+/// one connection pool.
+///
+/// # Errors
+///
+/// Fails when the workload is invalid, the HTTP client cannot be built or a
+/// client task panics. Failed calls are counted in the report, not returned.
+pub async fn run(args: &LoadTestArgs) -> Result<LoadReport, Box<dyn std::error::Error>> {
+    let workload = Arc::new(Workload::from_args(args)?);
+    let http = reqwest::Client::builder().timeout(args.timeout()).build()?;
+
+    let started = Instant::now();
+    let mut sessions = JoinSet::new();
+    for client in 0..args.clients {
+        let workload = Arc::clone(&workload);
+        let http = http.clone();
+        let delay = args.start_delay(client);
+        sessions.spawn(async move {
+            tokio::time::sleep(delay).await;
+            workload.run_session(&http, client).await
+        });
+    }
+
+    let mut samples = Vec::with_capacity(args.clients * args.requests);
+    while let Some(session) = sessions.join_next().await {
+        samples.extend(session?);
+    }
+    Ok(LoadReport::from_samples(
+        args.clients,
+        &samples,
+        started.elapsed(),
+    ))
+}
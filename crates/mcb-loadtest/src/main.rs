@@ -0,0 +1,30 @@
+//! `mcb-loadtest` — simulate concurrent MCP sessions against a running server.
+
+use std::io::Write;
+
+use clap::Parser;
+use mcb_loadtest::LoadTestArgs;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args = LoadTestArgs::parse();
+    let report = mcb_loadtest::run(&args).await?;
+
+    let mut out = std::io::stdout().lock();
+    if args.json {
+        writeln!(out, "{}", serde_json::to_string_pretty(&report)?)?;
+    } else {
+        report.write_table(&mut out)?;
+    }
+
+    if let Some(max) = args.max_error_rate
+        && report.error_rate > max
+    {
+        return Err(format!(
+            "error rate {:.4} exceeds --max-error-rate {max}",
+            report.error_rate
+        )
+        .into());
+    }
+    Ok(())
+}
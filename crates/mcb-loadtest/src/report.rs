@@ -0,0 +1,196 @@
+//! Latency percentiles and error counts of a load-test run.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::client::{Operation, Sample};
+
+/// Latency distribution of successful calls, in milliseconds.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencySummary {
+    /// Median
+    pub p50_ms: f64,
+    /// 90th percentile
+    pub p90_ms: f64,
+    /// 99th percentile
+    pub p99_ms: f64,
+    /// Slowest call
+    pub max_ms: f64,
+    /// Arithmetic mean
+    pub mean_ms: f64,
+}
+
+impl LatencySummary {
+    /// Summarize `latencies`; `None` when there are none.
+    #[must_use]
+    pub fn from_latencies(mut latencies: Vec<Duration>) -> Option<Self> {
+        let max = *latencies.iter().max()?;
+        latencies.sort_unstable();
+        let total: Duration = latencies.iter().sum();
+        Some(Self {
+            p50_ms: millis(percentile(&latencies, 50.0)),
+            p90_ms: millis(percentile(&latencies, 90.0)),
+            p99_ms: millis(percentile(&latencies, 99.0)),
+            max_ms: millis(max),
+            mean_ms: millis(total) / latencies.len() as f64,
+        })
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted`; zero when it is empty.
+#[must_use]
+pub fn percentile(sorted: &[Duration], percent: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    let rank = (percent / 100.0 * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+/// Results of one kind of call.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OperationReport {
+    /// Kind of call
+    pub operation: Operation,
+    /// Calls issued
+    pub calls: usize,
+    /// Calls that failed
+    pub errors: usize,
+    /// Latency of the successful calls
+    pub latency: Option<LatencySummary>,
+}
+
+/// Results of a whole run.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LoadReport {
+    /// Concurrent simulated clients
+    pub clients: usize,
+    /// Calls issued by all clients
+    pub calls: usize,
+    /// Calls that failed
+    pub errors: usize,
+    /// `errors / calls`, 0 when no call was issued
+    pub error_rate: f64,
+    /// Wall-clock duration of the run, in seconds
+    pub elapsed_secs: f64,
+    /// Completed calls per second
+    pub throughput_rps: f64,
+    /// Latency of all successful calls
+    pub latency: Option<LatencySummary>,
+    /// Breakdown per kind of call
+    pub operations: Vec<OperationReport>,
+    /// Failed calls per error category
+    pub error_kinds: BTreeMap<String, usize>,
+}
+
+impl LoadReport {
+    /// Aggregate the samples of a run that took `elapsed`.
+    #[must_use]
+    pub fn from_samples(clients: usize, samples: &[Sample], elapsed: Duration) -> Self {
+        let mut error_kinds = BTreeMap::new();
+        let mut per_operation: BTreeMap<Operation, (usize, usize, Vec<Duration>)> = BTreeMap::new();
+        for sample in samples {
+            let entry = per_operation.entry(sample.operation).or_default();
+            entry.0 += 1;
+            match sample.outcome.error_kind() {
+                Some(kind) => {
+                    entry.1 += 1;
+                    *error_kinds.entry(kind).or_insert(0) += 1;
+                }
+                None => entry.2.push(sample.latency),
+            }
+        }
+
+        let successful: Vec<Duration> = per_operation
+            .values()
+            .flat_map(|(_, _, latencies)| latencies.iter().copied())
+            .collect();
+        let operations: Vec<OperationReport> = per_operation
+            .into_iter()
+            .map(|(operation, (calls, errors, latencies))| OperationReport {
+                operation,
+                calls,
+                errors,
+                latency: LatencySummary::from_latencies(latencies),
+            })
+            .collect();
+
+        let calls = samples.len();
+        let errors: usize = error_kinds.values().sum();
+        let elapsed_secs = elapsed.as_secs_f64();
+        Self {
+            clients,
+            calls,
+            errors,
+            error_rate: ratio(errors as f64, calls as f64),
+            elapsed_secs,
+            throughput_rps: ratio(calls as f64, elapsed_secs),
+            latency: LatencySummary::from_latencies(successful),
+            operations,
+            error_kinds,
+        }
+    }
+
+    /// Render the report as a plain-text table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to `out` fails.
+    pub fn write_table(&self, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "{} clients, {} calls in {:.1}s ({:.1} calls/s), {} errors ({:.2}%)",
+            self.clients,
+            self.calls,
+            self.elapsed_secs,
+            self.throughput_rps,
+            self.errors,
+            self.error_rate * 100.0
+        )?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "{:<10} {:>8} {:>8} {:>10} {:>10} {:>10} {:>10}",
+            "operation", "calls", "errors", "p50 ms", "p90 ms", "p99 ms", "max ms"
+        )?;
+        let rows = self
+            .operations
+            .iter()
+            .map(|op| (op.operation.as_str(), op.calls, op.errors, &op.latency))
+            .chain([("total", self.calls, self.errors, &self.latency)]);
+        for (name, calls, errors, latency) in rows {
+            write!(out, "{name:<10} {calls:>8} {errors:>8}")?;
+            match latency {
+                Some(l) => writeln!(
+                    out,
+                    " {:>10.1} {:>10.1} {:>10.1} {:>10.1}",
+                    l.p50_ms, l.p90_ms, l.p99_ms, l.max_ms
+                )?,
+                None => writeln!(out, " {:>10} {:>10} {:>10} {:>10}", "-", "-", "-", "-")?,
+            }
+        }
+        if !self.error_kinds.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "errors:")?;
+            for (kind, count) in &self.error_kinds {
+                writeln!(out, "  {kind:<20} {count:>8}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+fn ratio(numerator: f64, denominator: f64) -> f64 {
+    if denominator > 0.0 {
+        numerator / denominator
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,4 @@
+//! Unit tests — `cargo test -p mcb-loadtest --test unit`
+
+mod report_tests;
+mod workload_tests;
@@ -0,0 +1,114 @@
+//! Tests for percentile and report aggregation.
+
+use std::time::Duration;
+
+use mcb_loadtest::report::percentile;
+use mcb_loadtest::{LoadReport, Operation, Outcome, Sample};
+use rstest::rstest;
+
+fn ms(value: u64) -> Duration {
+    Duration::from_millis(value)
+}
+
+fn sample(operation: Operation, latency_ms: u64, outcome: Outcome) -> Sample {
+    Sample {
+        operation,
+        latency: ms(latency_ms),
+        outcome,
+    }
+}
+
+#[rstest]
+#[case(50.0, 50)]
+#[case(90.0, 90)]
+#[case(99.0, 99)]
+#[case(100.0, 100)]
+#[case(0.0, 1)]
+fn test_percentile_uses_nearest_rank(#[case] percent: f64, #[case] expected_ms: u64) {
+    let sorted: Vec<Duration> = (1..=100).map(ms).collect();
+
+    assert_eq!(percentile(&sorted, percent), ms(expected_ms));
+}
+
+#[rstest]
+fn test_percentile_of_nothing_is_zero() {
+    assert_eq!(percentile(&[], 99.0), Duration::ZERO);
+}
+
+#[rstest]
+fn test_report_counts_errors_and_excludes_them_from_latency() {
+    let samples = [
+        sample(Operation::Search, 10, Outcome::Success),
+        sample(Operation::Search, 30, Outcome::Success),
+        sample(Operation::Search, 5000, Outcome::Transport),
+        sample(Operation::Index, 20, Outcome::Rpc(-32602)),
+        sample(Operation::Index, 40, Outcome::Tool),
+        sample(Operation::Index, 50, Outcome::Http(503)),
+    ];
+
+    let report = LoadReport::from_samples(2, &samples, Duration::from_secs(2));
+
+    assert_eq!(report.calls, 6);
+    assert_eq!(report.errors, 4);
+    assert!((report.error_rate - 4.0 / 6.0).abs() < 1e-9);
+    assert!((report.throughput_rps - 3.0).abs() < 1e-9);
+    let latency = report.latency.unwrap();
+    assert!((latency.max_ms - 30.0).abs() < 1e-9);
+    assert!((latency.mean_ms - 20.0).abs() < 1e-9);
+
+    let per_op: Vec<_> = report
+        .operations
+        .iter()
+        .map(|op| (op.operation, op.calls, op.errors, op.latency.is_some()))
+        .collect();
+    assert_eq!(
+        per_op,
+        [
+            (Operation::Search, 3, 1, true),
+            (Operation::Index, 3, 3, false),
+        ]
+    );
+    let kinds: Vec<_> = report
+        .error_kinds
+        .iter()
+        .map(|(kind, count)| (kind.as_str(), *count))
+        .collect();
+    assert_eq!(
+        kinds,
+        [
+            ("http 503", 1),
+            ("json-rpc -32602", 1),
+            ("tool error", 1),
+            ("transport", 1),
+        ]
+    );
+}
+
+#[rstest]
+fn test_empty_run_reports_zero_rates() {
+    let report = LoadReport::from_samples(0, &[], Duration::ZERO);
+
+    assert_eq!(report.error_rate, 0.0);
+    assert_eq!(report.throughput_rps, 0.0);
+    assert!(report.latency.is_none());
+    assert!(report.operations.is_empty());
+}
+
+#[rstest]
+fn test_table_lists_operations_total_and_errors() {
+    let samples = [
+        sample(Operation::Search, 12, Outcome::Success),
+        sample(Operation::Index, 8, Outcome::Rpc(-32602)),
+    ];
+    let report = LoadReport::from_samples(1, &samples, Duration::from_secs(1));
+
+    let mut out = Vec::new();
+    report.write_table(&mut out).unwrap();
+    let table = String::from_utf8(out).unwrap();
+
+    assert!(table.starts_with("1 clients, 2 calls in 1.0s (2.0 calls/s), 1 errors (50.00%)"));
+    assert!(table.contains("\nsearch "));
+    assert!(table.contains("\nindex "));
+    assert!(table.contains("\ntotal "));
+    assert!(table.contains("json-rpc -32602"));
+}
@@ -0,0 +1,95 @@
+//! Tests for the traffic mix and request shapes of the simulated clients.
+
+use clap::Parser;
+use mcb_loadtest::client::is_index_call;
+use mcb_loadtest::{LoadTestArgs, Operation, Workload};
+use rstest::rstest;
+use serde_json::json;
+
+fn args(extra: &[&str]) -> LoadTestArgs {
+    LoadTestArgs::parse_from(["mcb-loadtest"].iter().chain(extra))
+}
+
+#[rstest]
+#[case(0, 0)]
+#[case(10, 10)]
+#[case(25, 25)]
+#[case(100, 100)]
+fn test_index_calls_match_percentage(#[case] percent: u8, #[case] expected: usize) {
+    let index_calls = (0..100)
+        .filter(|&call| is_index_call(call, percent))
+        .count();
+
+    assert_eq!(index_calls, expected);
+}
+
+#[rstest]
+fn test_search_requests_cycle_queries_and_carry_collection() {
+    let workload = Workload::from_args(&args(&[
+        "--index-percent",
+        "0",
+        "--collection",
+        "docs",
+        "--query",
+        "alpha",
+        "--query",
+        "beta",
+    ]))
+    .unwrap();
+
+    let (operation, request) = workload.request(0, 1);
+
+    assert_eq!(operation, Operation::Search);
+    assert_eq!(request.method, "tools/call");
+    assert_eq!(
+        request.params,
+        Some(json!({
+            "name": "search_code",
+            "arguments": {"query": "beta", "limit": 10, "collection": "docs"},
+        }))
+    );
+}
+
+#[rstest]
+#[case(&[], "index_status", json!({}))]
+#[case(&["--index-path", "/srv/repo"], "index_repo", json!({"path": "/srv/repo"}))]
+fn test_index_requests_depend_on_index_path(
+    #[case] extra: &[&str],
+    #[case] tool: &str,
+    #[case] arguments: serde_json::Value,
+) {
+    let mut flags = vec!["--index-percent", "100"];
+    flags.extend_from_slice(extra);
+    let workload = Workload::from_args(&args(&flags)).unwrap();
+
+    let (operation, request) = workload.request(3, 0);
+
+    assert_eq!(operation, Operation::Index);
+    assert_eq!(
+        request.params,
+        Some(json!({"name": tool, "arguments": arguments}))
+    );
+}
+
+#[rstest]
+#[case("http://127.0.0.1:8080/", true)]
+#[case("https://mcb.example.com", true)]
+#[case("http://mcb.example.com", false)]
+fn test_workload_requires_secure_transport(#[case] url: &str, #[case] accepted: bool) {
+    let workload = Workload::from_args(&args(&["--url", url]));
+
+    assert_eq!(workload.is_ok(), accepted);
+    if let Ok(workload) = workload {
+        assert!(workload.endpoint().ends_with("/mcp"));
+        assert!(!workload.endpoint().contains("//mcp"));
+    }
+}
+
+#[rstest]
+fn test_ramp_up_spreads_client_starts() {
+    let args = args(&["--clients", "4", "--ramp-up-secs", "8"]);
+
+    let delays: Vec<u64> = (0..4).map(|c| args.start_delay(c).as_secs()).collect();
+
+    assert_eq!(delays, [0, 2, 4, 6]);
+}
@@ -0,0 +1,74 @@
+//! Chunking throughput per language.
+//!
+//! Run with: `make bench` or `cargo bench -p mcb-providers --features testkit --bench chunking`
+
+mod support;
+
+use std::error::Error;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use mcb_providers::language::{IntelligentChunker, language_from_extension};
+use support::Threshold;
+
+/// The golden-test fixtures, one per tree-sitter language.
+const FIXTURES: &[&str] = &[
+    "sample.rs",
+    "sample.py",
+    "sample.ts",
+    "sample.go",
+    "sample.java",
+];
+
+const GENERIC_LINES: usize = 2_000;
+
+const THRESHOLDS: &[Threshold] = &[
+    ("chunking/rust", Duration::from_millis(10)),
+    ("chunking/python", Duration::from_millis(10)),
+    ("chunking/typescript", Duration::from_millis(10)),
+    ("chunking/go", Duration::from_millis(10)),
+    ("chunking/java", Duration::from_millis(10)),
+    ("chunking/unknown", Duration::from_millis(20)),
+];
+
+fn bench_languages(c: &mut Criterion) -> Result<(), Box<dyn Error>> {
+    let fixtures_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/chunking");
+    let chunker = IntelligentChunker::new();
+    let mut group = c.benchmark_group("chunking");
+
+    for fixture in FIXTURES {
+        let content = std::fs::read_to_string(fixtures_dir.join(fixture))?;
+        let extension = Path::new(fixture)
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default();
+        let language = language_from_extension(extension);
+        group.throughput(Throughput::Bytes(content.len() as u64));
+        group.bench_function(BenchmarkId::from_parameter(&language), |b| {
+            b.iter(|| chunker.chunk_code(black_box(&content), fixture, &language));
+        });
+    }
+
+    // Languages without a tree-sitter processor fall back to line windows.
+    let content = (0..GENERIC_LINES / 4)
+        .map(support::synthetic_text)
+        .collect::<Vec<_>>()
+        .join("\n");
+    let language = language_from_extension("txt");
+    group.throughput(Throughput::Bytes(content.len() as u64));
+    group.bench_function(BenchmarkId::from_parameter(&language), |b| {
+        b.iter(|| chunker.chunk_code(black_box(&content), "synthetic.txt", &language));
+    });
+
+    group.finish();
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let started = SystemTime::now();
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_languages(&mut criterion)?;
+    support::finish(&criterion, started, THRESHOLDS)
+}
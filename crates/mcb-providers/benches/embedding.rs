@@ -0,0 +1,68 @@
+//! Embedding batching: the same texts embedded through `embed_batch` at
+//! several batch sizes.
+//!
+//! Uses the deterministic test-kit provider, so the numbers show the cost of
+//! the async provider call per batch without network latency.
+//!
+//! Run with: `make bench` or `cargo bench -p mcb-providers --features testkit --bench embedding`
+
+mod support;
+
+use std::error::Error;
+use std::hint::black_box;
+use std::time::{Duration, SystemTime};
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use mcb_domain::ports::EmbeddingProvider;
+use mcb_providers::testkit::FakeEmbeddingProvider;
+use support::Threshold;
+use tokio::runtime::Runtime;
+
+const TEXTS: usize = 1_024;
+const BATCH_SIZES: &[usize] = &[1, 16, 64, 256, 1_024];
+const DIMENSIONS: usize = 384;
+
+const THRESHOLDS: &[Threshold] = &[
+    ("embedding/batch/1", Duration::from_millis(50)),
+    ("embedding/batch/16", Duration::from_millis(20)),
+    ("embedding/batch/64", Duration::from_millis(20)),
+    ("embedding/batch/256", Duration::from_millis(20)),
+    ("embedding/batch/1024", Duration::from_millis(20)),
+];
+
+fn bench_batching(c: &mut Criterion, runtime: &Runtime) -> Result<(), Box<dyn Error>> {
+    let texts: Vec<String> = (0..TEXTS).map(support::synthetic_text).collect();
+    let mut group = c.benchmark_group("embedding");
+    group.throughput(Throughput::Elements(TEXTS as u64));
+
+    for &batch_size in BATCH_SIZES {
+        // A fresh provider per size keeps its batch log small.
+        let provider = FakeEmbeddingProvider::new(DIMENSIONS);
+        runtime.block_on(provider.embed_batch(&texts[..batch_size]))?;
+        group.bench_with_input(
+            BenchmarkId::new("batch", batch_size),
+            &batch_size,
+            |b, &size| {
+                b.iter(|| {
+                    runtime.block_on(async {
+                        for batch in black_box(&texts).chunks(size) {
+                            black_box(provider.embed_batch(batch).await)?;
+                        }
+                        Ok::<_, mcb_domain::error::Error>(())
+                    })
+                });
+            },
+        );
+    }
+
+    group.finish();
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let started = SystemTime::now();
+    let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_batching(&mut criterion, &runtime)?;
+    support::finish(&criterion, started, THRESHOLDS)
+}
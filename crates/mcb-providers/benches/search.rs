@@ -0,0 +1,202 @@
+//! Search benchmarks: brute-force vs HNSW-indexed vector search at several
+//! collection sizes, BM25 scoring, and the SIMD cosine kernel against a
+//! scalar loop.
+//!
+//! Run with: `make bench` or `cargo bench -p mcb-providers --features testkit --bench search`
+
+mod support;
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::hint::black_box;
+use std::time::{Duration, SystemTime};
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::ports::VectorStoreProvider;
+use mcb_domain::value_objects::{CollectionId, Embedding};
+use mcb_providers::hybrid_search::{BM25Params, BM25Scorer};
+use mcb_providers::testkit::{FakeEmbeddingProvider, RecordingVectorStore};
+use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+use mcb_utils::constants::vector_store::{
+    VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_START_LINE,
+};
+use mcb_utils::utils::vector::cosine_similarity;
+use serde_json::Value;
+use support::Threshold;
+use tokio::runtime::Runtime;
+
+const COLLECTION_SIZES: &[usize] = &[100, 1_000, 10_000];
+/// Common embedding model dimensions.
+const KERNEL_DIMENSIONS: &[usize] = &[384, 768, 1_536];
+const DIMENSIONS: usize = 128;
+const LIMIT: usize = 10;
+const QUERY: &str = "parse session token from request";
+
+const THRESHOLDS: &[Threshold] = &[
+    ("search/brute_force/100", Duration::from_millis(1)),
+    ("search/brute_force/1000", Duration::from_millis(5)),
+    ("search/brute_force/10000", Duration::from_millis(50)),
+    ("search/hnsw/100", Duration::from_millis(1)),
+    ("search/hnsw/1000", Duration::from_millis(2)),
+    ("search/hnsw/10000", Duration::from_millis(5)),
+    ("bm25/score_batch/100", Duration::from_millis(1)),
+    ("bm25/score_batch/1000", Duration::from_millis(5)),
+    ("bm25/score_batch/10000", Duration::from_millis(50)),
+    ("cosine/simd/384", Duration::from_micros(1)),
+    ("cosine/simd/768", Duration::from_micros(2)),
+    ("cosine/simd/1536", Duration::from_micros(4)),
+];
+
+fn embeddings(embedder: &FakeEmbeddingProvider, count: usize) -> Vec<Embedding> {
+    (0..count)
+        .map(|i| embedder.embed_text(&support::synthetic_text(i)))
+        .collect()
+}
+
+fn metadata(count: usize) -> Vec<HashMap<String, Value>> {
+    (0..count)
+        .map(|i| {
+            HashMap::from([
+                (
+                    VECTOR_FIELD_FILE_PATH.to_owned(),
+                    Value::from(format!("src/file_{}.rs", i / 20)),
+                ),
+                (VECTOR_FIELD_START_LINE.to_owned(), Value::from(i % 20 * 10)),
+                (
+                    VECTOR_FIELD_CONTENT.to_owned(),
+                    Value::from(support::synthetic_text(i)),
+                ),
+            ])
+        })
+        .collect()
+}
+
+/// Fill `store` with `size` vectors in a fresh collection.
+fn populate(
+    runtime: &Runtime,
+    store: &dyn VectorStoreProvider,
+    embedder: &FakeEmbeddingProvider,
+    size: usize,
+) -> Result<CollectionId, Box<dyn Error>> {
+    let collection = CollectionId::from_name(&format!("bench-{size}"));
+    runtime.block_on(async {
+        store.create_collection(&collection, DIMENSIONS).await?;
+        store
+            .insert_vectors(&collection, &embeddings(embedder, size), metadata(size))
+            .await
+    })?;
+    Ok(collection)
+}
+
+fn bench_vector_search(c: &mut Criterion, runtime: &Runtime) -> Result<(), Box<dyn Error>> {
+    let embedder = FakeEmbeddingProvider::new(DIMENSIONS);
+    let query = embedder.embed_text(QUERY).vector;
+    let brute_force = RecordingVectorStore::new();
+    let hnsw = {
+        let _guard = runtime.enter();
+        EdgeVecVectorStoreProvider::new(&EdgeVecConfig {
+            dimensions: DIMENSIONS,
+            ..EdgeVecConfig::default()
+        })?
+    };
+    let stores: [(&str, &dyn VectorStoreProvider); 2] =
+        [("brute_force", &brute_force), ("hnsw", &hnsw)];
+
+    let mut group = c.benchmark_group("search");
+    for &size in COLLECTION_SIZES {
+        for (name, store) in stores {
+            let collection = populate(runtime, store, &embedder, size)?;
+            // Fail before measuring rather than benchmark an error path.
+            runtime.block_on(store.search_similar(&collection, &query, LIMIT, None))?;
+            group.bench_with_input(
+                BenchmarkId::new(name, size),
+                &collection,
+                |b, collection| {
+                    b.iter(|| {
+                        runtime.block_on(store.search_similar(
+                            collection,
+                            black_box(&query),
+                            LIMIT,
+                            None,
+                        ))
+                    });
+                },
+            );
+        }
+        // Drop the calls recorded while measuring this size.
+        brute_force.clear_calls();
+    }
+    group.finish();
+    Ok(())
+}
+
+fn bench_bm25(c: &mut Criterion) {
+    let mut group = c.benchmark_group("bm25");
+    for &size in COLLECTION_SIZES {
+        let chunks: Vec<CodeChunk> = (0..size)
+            .map(|i| CodeChunk {
+                id: format!("chunk-{i}"),
+                content: support::synthetic_text(i),
+                file_path: format!("src/file_{}.rs", i / 20),
+                start_line: 1,
+                end_line: 4,
+                language: "rust".to_owned(),
+                metadata: Value::Null,
+            })
+            .collect();
+        let scorer = BM25Scorer::new(&chunks, BM25Params::default());
+        let documents: Vec<&CodeChunk> = chunks.iter().collect();
+        group.throughput(Throughput::Elements(size as u64));
+        group.bench_with_input(
+            BenchmarkId::new("score_batch", size),
+            &documents,
+            |b, documents| {
+                b.iter(|| scorer.score_batch(documents, black_box(QUERY)));
+            },
+        );
+    }
+    group.finish();
+}
+
+/// Single-accumulator loop, as the stores computed cosine before the kernel.
+fn scalar_cosine(a: &[f32], b: &[f32]) -> f32 {
+    let (dot, norm_a, norm_b) = a
+        .iter()
+        .zip(b)
+        .fold((0.0_f32, 0.0_f32, 0.0_f32), |(dot, na, nb), (x, y)| {
+            (dot + x * y, na + x * x, nb + y * y)
+        });
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+fn bench_cosine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("cosine");
+    for &dimensions in KERNEL_DIMENSIONS {
+        let embedder = FakeEmbeddingProvider::new(dimensions);
+        let pair = (
+            embedder.embed_text(QUERY).vector,
+            embedder.embed_text(&support::synthetic_text(0)).vector,
+        );
+        let kernels: [(&str, fn(&[f32], &[f32]) -> f32); 2] =
+            [("scalar", scalar_cosine), ("simd", cosine_similarity)];
+        for (name, kernel) in kernels {
+            group.bench_with_input(BenchmarkId::new(name, dimensions), &pair, |b, (x, y)| {
+                b.iter(|| kernel(black_box(x), black_box(y)));
+            });
+        }
+    }
+    group.finish();
+}
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let started = SystemTime::now();
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()?;
+    let mut criterion = Criterion::default().configure_from_args();
+    bench_vector_search(&mut criterion, &runtime)?;
+    bench_bm25(&mut criterion);
+    bench_cosine(&mut criterion);
+    support::finish(&criterion, started, THRESHOLDS)
+}
@@ -0,0 +1,115 @@
+//! Shared harness for the provider benchmarks: synthetic input and
+//! regression thresholds.
+//!
+//! Each benchmark binary runs its groups, then compares the mean time of
+//! every benchmark measured in this run against its threshold. Criterion
+//! saves no estimates in `--test` mode or for filtered-out benchmarks, so
+//! those are not checked.
+
+use std::error::Error;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use criterion::Criterion;
+use serde_json::Value;
+
+const VOCABULARY: &[&str] = &[
+    "parse",
+    "token",
+    "session",
+    "cache",
+    "index",
+    "vector",
+    "query",
+    "request",
+    "handler",
+    "config",
+    "error",
+    "retry",
+    "stream",
+    "buffer",
+    "schema",
+    "client",
+    "server",
+    "chunk",
+    "embedding",
+    "collection",
+];
+
+/// Benchmark ID as reported by Criterion (e.g. `search/brute_force/1000`)
+/// and the largest acceptable mean time per iteration.
+pub type Threshold = (&'static str, Duration);
+
+/// Deterministic code-like text for document `index`.
+///
+/// Documents share vocabulary words in a rotating pattern, so both BM25 and
+/// embedding similarity have non-trivial matches.
+#[must_use]
+pub fn synthetic_text(index: usize) -> String {
+    let word = |offset: usize| VOCABULARY[(index * 7 + offset * 3) % VOCABULARY.len()];
+    format!(
+        "fn {a}_{b}_{index}(input: &str) -> Result<{c}> {{\n    let {d} = {a}::{b}(input)?;\n    {c}::from_{d}({d})\n}}",
+        a = word(0),
+        b = word(1),
+        c = word(2),
+        d = word(3),
+    )
+}
+
+/// Print the run summary and check `thresholds`.
+///
+/// # Errors
+///
+/// Lists every benchmark whose mean exceeded its threshold.
+pub fn finish(
+    criterion: &Criterion,
+    started: SystemTime,
+    thresholds: &[Threshold],
+) -> Result<(), Box<dyn Error>> {
+    criterion.final_summary();
+    let mut regressions = Vec::new();
+    for &(id, max_mean) in thresholds {
+        let Some(mean) = measured_mean(id, started)? else {
+            continue;
+        };
+        if mean > max_mean {
+            regressions.push(format!(
+                "{id}: mean {mean:?} exceeds threshold {max_mean:?}"
+            ));
+        }
+    }
+    if regressions.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("benchmark regressions:\n{}", regressions.join("\n")).into())
+    }
+}
+
+/// Where Criterion writes its results; mirrors Criterion's own lookup.
+fn criterion_home() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map_or_else(
+            || PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../target"),
+            PathBuf::from,
+        )
+        .join("criterion")
+}
+
+/// Mean of benchmark `id`, when it was measured after `started`.
+fn measured_mean(id: &str, started: SystemTime) -> Result<Option<Duration>, Box<dyn Error>> {
+    let path = criterion_home().join(id).join("new/estimates.json");
+    let Ok(metadata) = std::fs::metadata(&path) else {
+        return Ok(None);
+    };
+    if metadata.modified()? < started {
+        return Ok(None);
+    }
+    let estimates: Value = serde_json::from_str(&std::fs::read_to_string(&path)?)?;
+    let nanos = estimates["mean"]["point_estimate"]
+        .as_f64()
+        .ok_or_else(|| format!("no mean estimate in {}", path.display()))?;
+    Ok(Some(Duration::from_secs_f64(nanos / 1e9)))
+}
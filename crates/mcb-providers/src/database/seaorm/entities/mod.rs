@@ -10,15 +10,19 @@ pub mod agent_worktree_assignments;
 pub mod api_keys;
 pub mod branches;
 pub mod checkpoints;
+pub mod collection_manifests;
 pub mod collections;
 pub mod delegations;
 pub mod error_pattern_matches;
 pub mod error_patterns;
+pub mod file_blobs;
 pub mod file_hashes;
+pub mod idempotency_records;
 pub mod index_operations;
 pub mod issue_comments;
 pub mod issue_label_assignments;
 pub mod issue_labels;
+pub mod module_imports;
 pub mod observations;
 pub mod organizations;
 pub mod plan_reviews;
@@ -30,11 +34,17 @@ pub mod project_issues;
 pub mod project_phases;
 pub mod projects;
 pub mod repositories;
+pub mod search_feedback;
+pub mod search_file_boosts;
 pub mod session_summaries;
 pub mod team_members;
 pub mod teams;
+pub mod test_links;
 pub mod tool_calls;
+pub mod usage_records;
 pub mod users;
+pub mod workspace_collections;
+pub mod workspace_shared_files;
 pub mod worktrees;
 
 pub use agent_sessions as agent_session;
@@ -42,15 +52,19 @@ pub use agent_worktree_assignments as agent_worktree_assignment;
 pub use api_keys as api_key;
 pub use branches as branch;
 pub use checkpoints as checkpoint;
+pub use collection_manifests as collection_manifest;
 pub use collections as collection;
 pub use delegations as delegation;
 pub use error_pattern_matches as error_pattern_match;
 pub use error_patterns as error_pattern;
+pub use file_blobs as file_blob;
 pub use file_hashes as file_hash;
+pub use idempotency_records as idempotency_record;
 pub use index_operations as index_operation;
 pub use issue_comments as issue_comment;
 pub use issue_label_assignments as issue_label_assignment;
 pub use issue_labels as issue_label;
+pub use module_imports as module_import;
 pub use observations as observation;
 pub use organizations as organization;
 pub use plan_reviews as plan_review;
@@ -62,11 +76,16 @@ pub use project_issues as project_issue;
 pub use project_phases as project_phase;
 pub use projects as project;
 pub use repositories as repository;
+pub use search_file_boosts as search_file_boost;
 pub use session_summaries as session_summary;
 pub use team_members as team_member;
 pub use teams as team;
+pub use test_links as test_link;
 pub use tool_calls as tool_call;
+pub use usage_records as usage_record;
 pub use users as user;
+pub use workspace_collections as workspace_collection;
+pub use workspace_shared_files as workspace_shared_file;
 pub use worktrees as worktree;
 
 seaography::register_entity_modules!([
@@ -75,15 +94,19 @@ seaography::register_entity_modules!([
     api_keys,
     branches,
     checkpoints,
+    collection_manifests,
     collections,
     delegations,
     error_pattern_matches,
     error_patterns,
+    file_blobs,
     file_hashes,
+    idempotency_records,
     index_operations,
     issue_comments,
     issue_label_assignments,
     issue_labels,
+    module_imports,
     observations,
     organizations,
     plan_reviews,
@@ -95,10 +118,16 @@ seaography::register_entity_modules!([
     project_phases,
     projects,
     repositories,
+    search_feedback,
+    search_file_boosts,
     session_summaries,
     team_members,
     teams,
+    test_links,
     tool_calls,
+    usage_records,
     users,
+    workspace_collections,
+    workspace_shared_files,
     worktrees,
 ]);
@@ -5,15 +5,19 @@ pub use super::agent_worktree_assignments::Entity as AgentWorktreeAssignments;
 pub use super::api_keys::Entity as ApiKeys;
 pub use super::branches::Entity as Branches;
 pub use super::checkpoints::Entity as Checkpoints;
+pub use super::collection_manifests::Entity as CollectionManifests;
 pub use super::collections::Entity as Collections;
 pub use super::delegations::Entity as Delegations;
 pub use super::error_pattern_matches::Entity as ErrorPatternMatches;
 pub use super::error_patterns::Entity as ErrorPatterns;
+pub use super::file_blobs::Entity as FileBlobs;
 pub use super::file_hashes::Entity as FileHashes;
+pub use super::idempotency_records::Entity as IdempotencyRecords;
 pub use super::index_operations::Entity as IndexOperations;
 pub use super::issue_comments::Entity as IssueComments;
 pub use super::issue_label_assignments::Entity as IssueLabelAssignments;
 pub use super::issue_labels::Entity as IssueLabels;
+pub use super::module_imports::Entity as ModuleImports;
 pub use super::observations::Entity as Observations;
 pub use super::organizations::Entity as Organizations;
 pub use super::plan_reviews::Entity as PlanReviews;
@@ -25,9 +29,15 @@ pub use super::project_issues::Entity as ProjectIssues;
 pub use super::project_phases::Entity as ProjectPhases;
 pub use super::projects::Entity as Projects;
 pub use super::repositories::Entity as Repositories;
+pub use super::search_feedback::Entity as SearchFeedback;
+pub use super::search_file_boosts::Entity as SearchFileBoosts;
 pub use super::session_summaries::Entity as SessionSummaries;
 pub use super::team_members::Entity as TeamMembers;
 pub use super::teams::Entity as Teams;
+pub use super::test_links::Entity as TestLinks;
 pub use super::tool_calls::Entity as ToolCalls;
+pub use super::usage_records::Entity as UsageRecords;
 pub use super::users::Entity as Users;
+pub use super::workspace_collections::Entity as WorkspaceCollections;
+pub use super::workspace_shared_files::Entity as WorkspaceSharedFiles;
 pub use super::worktrees::Entity as Worktrees;
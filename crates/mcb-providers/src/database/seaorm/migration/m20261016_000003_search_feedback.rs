@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+/// Search feedback migration: relevance votes and the file boosts learned from them.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS search_feedback (
+                id TEXT PRIMARY KEY,
+                query TEXT NOT NULL,
+                collection TEXT NOT NULL,
+                chunk_id TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                relevant BOOLEAN NOT NULL,
+                session_id TEXT,
+                created_at INTEGER NOT NULL
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS search_file_boosts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                collection TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                boost REAL NOT NULL,
+                updated_at INTEGER NOT NULL,
+                UNIQUE (collection, file_path)
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_search_feedback_file ON search_feedback(collection, file_path)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS search_file_boosts")
+            .await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS search_feedback")
+            .await?;
+        Ok(())
+    }
+}
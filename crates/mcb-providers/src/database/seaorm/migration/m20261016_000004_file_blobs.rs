@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+/// File blob migration: content-addressed snapshots of indexed files.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS file_blobs (
+                content_hash TEXT PRIMARY KEY,
+                content TEXT NOT NULL,
+                size_bytes INTEGER NOT NULL,
+                created_at INTEGER NOT NULL
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_file_hashes_content_hash ON file_hashes(content_hash)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP INDEX IF EXISTS idx_file_hashes_content_hash")
+            .await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS file_blobs")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,38 @@
+use sea_orm_migration::prelude::*;
+
+/// Module import migration: import edges of the module dependency graph.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS module_imports (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                collection TEXT NOT NULL,
+                source_file TEXT NOT NULL,
+                specifier TEXT NOT NULL,
+                module_path TEXT NOT NULL,
+                line INTEGER NOT NULL
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_module_imports_file ON module_imports(collection, source_file)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS module_imports")
+            .await?;
+        Ok(())
+    }
+}
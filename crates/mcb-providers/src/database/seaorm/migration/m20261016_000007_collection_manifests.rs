@@ -0,0 +1,30 @@
+use sea_orm_migration::prelude::*;
+
+/// Collection manifest migration: how each indexed collection was built.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS collection_manifests (
+                collection TEXT PRIMARY KEY,
+                chunker_version INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS collection_manifests")
+            .await?;
+        Ok(())
+    }
+}
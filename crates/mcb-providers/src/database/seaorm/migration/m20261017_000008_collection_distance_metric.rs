@@ -0,0 +1,29 @@
+use sea_orm_migration::prelude::*;
+
+/// Collection manifest migration: record the distance metric of each collection.
+///
+/// Collections indexed before this migration were built for cosine similarity.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE collection_manifests
+                ADD COLUMN distance_metric TEXT NOT NULL DEFAULT 'cosine'",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("ALTER TABLE collection_manifests DROP COLUMN distance_metric")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use sea_orm_migration::prelude::*;
+
+/// Collection manifest migration: record the embedding model of each collection.
+///
+/// The model of collections indexed before this migration is unknown (NULL).
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("ALTER TABLE collection_manifests ADD COLUMN embedding_model TEXT")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("ALTER TABLE collection_manifests DROP COLUMN embedding_model")
+            .await?;
+        Ok(())
+    }
+}
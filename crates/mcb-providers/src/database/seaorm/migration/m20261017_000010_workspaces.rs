@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+/// Workspace migration: collections grouped into workspaces and the files
+/// one member collection serves for another.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS workspace_collections (
+                workspace TEXT NOT NULL,
+                collection TEXT NOT NULL,
+                name TEXT NOT NULL,
+                added_at INTEGER NOT NULL,
+                PRIMARY KEY (workspace, collection)
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS workspace_shared_files (
+                collection TEXT NOT NULL,
+                file_path TEXT NOT NULL,
+                content_hash TEXT NOT NULL,
+                origin_collection TEXT NOT NULL,
+                origin_path TEXT NOT NULL,
+                PRIMARY KEY (collection, file_path)
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_workspace_shared_files_origin ON workspace_shared_files(origin_collection, origin_path)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS workspace_shared_files")
+            .await?;
+        db.execute_unprepared("DROP TABLE IF EXISTS workspace_collections")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+/// Usage migration: monthly indexing and query counters per organization
+/// and collection.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS usage_records (
+                org_id TEXT NOT NULL,
+                collection TEXT NOT NULL,
+                month TEXT NOT NULL,
+                indexed_bytes INTEGER NOT NULL DEFAULT 0,
+                embedding_tokens INTEGER NOT NULL DEFAULT 0,
+                queries INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL,
+                PRIMARY KEY (org_id, collection, month)
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_usage_records_month ON usage_records(month)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS usage_records")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use sea_orm_migration::prelude::*;
+
+/// Idempotency migration: results of mutating tool calls by organization,
+/// tool and idempotency key.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "CREATE TABLE IF NOT EXISTS idempotency_records (
+                org_id TEXT NOT NULL,
+                tool_name TEXT NOT NULL,
+                key TEXT NOT NULL,
+                response TEXT NOT NULL,
+                created_at INTEGER NOT NULL,
+                PRIMARY KEY (org_id, tool_name, key)
+            )",
+        )
+        .await?;
+
+        db.execute_unprepared(
+            "CREATE INDEX IF NOT EXISTS idx_idempotency_records_created_at ON idempotency_records(created_at)",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("DROP TABLE IF EXISTS idempotency_records")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use sea_orm_migration::prelude::*;
+
+/// Collection manifest migration: record the directory each collection was indexed from.
+///
+/// The root of collections indexed before this migration is unknown (NULL).
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared("ALTER TABLE collection_manifests ADD COLUMN root_path TEXT")
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("ALTER TABLE collection_manifests DROP COLUMN root_path")
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use sea_orm_migration::prelude::*;
+
+/// Idempotency migration: record a hash of the arguments of each stored call.
+///
+/// Records stored before this migration have an empty hash, so a retry of
+/// them runs into an argument mismatch instead of replaying their result.
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(
+            "ALTER TABLE idempotency_records ADD COLUMN request_hash TEXT NOT NULL DEFAULT ''",
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared("ALTER TABLE idempotency_records DROP COLUMN request_hash")
+            .await?;
+        Ok(())
+    }
+}
@@ -4,6 +4,18 @@ use sea_orm_migration::prelude::*;
 
 mod m20260301_000001_initial_schema;
 mod m20260301_000002_workflow_schema;
+mod m20261016_000003_search_feedback;
+mod m20261016_000004_file_blobs;
+mod m20261016_000005_test_links;
+mod m20261016_000006_module_imports;
+mod m20261016_000007_collection_manifests;
+mod m20261017_000008_collection_distance_metric;
+mod m20261017_000009_collection_embedding_model;
+mod m20261017_000010_workspaces;
+mod m20261017_000011_usage_records;
+mod m20261017_000012_idempotency_records;
+mod m20261017_000013_collection_root_path;
+mod m20261017_000014_idempotency_request_hash;
 mod provider;
 
 /// Returns the ordered list of migrations for the MCB database.
@@ -11,6 +23,18 @@ fn mcb_migrations() -> Vec<Box<dyn MigrationTrait>> {
     vec![
         Box::new(m20260301_000001_initial_schema::Migration),
         Box::new(m20260301_000002_workflow_schema::Migration),
+        Box::new(m20261016_000003_search_feedback::Migration),
+        Box::new(m20261016_000004_file_blobs::Migration),
+        Box::new(m20261016_000005_test_links::Migration),
+        Box::new(m20261016_000006_module_imports::Migration),
+        Box::new(m20261016_000007_collection_manifests::Migration),
+        Box::new(m20261017_000008_collection_distance_metric::Migration),
+        Box::new(m20261017_000009_collection_embedding_model::Migration),
+        Box::new(m20261017_000010_workspaces::Migration),
+        Box::new(m20261017_000011_usage_records::Migration),
+        Box::new(m20261017_000012_idempotency_records::Migration),
+        Box::new(m20261017_000013_collection_root_path::Migration),
+        Box::new(m20261017_000014_idempotency_request_hash::Migration),
     ]
 }
 
@@ -0,0 +1,105 @@
+//! SeaORM-backed collection manifest repository.
+//!
+//! Stores one row per collection in `collection_manifests`, overwritten
+//! after each complete index.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::collection_manifest::CollectionManifest;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::CollectionManifestRepository;
+use mcb_domain::value_objects::DistanceMetric;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::collection_manifest;
+
+/// `SeaORM` `CollectionManifestRepository` implementation.
+pub struct SeaOrmCollectionManifestRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmCollectionManifestRepository {
+    /// Create a new `SeaOrmCollectionManifestRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+}
+
+#[async_trait]
+impl CollectionManifestRepository for SeaOrmCollectionManifestRepository {
+    async fn get_manifest(&self, collection: &str) -> Result<Option<CollectionManifest>> {
+        let model = collection_manifest::Entity::find_by_id(collection.to_owned())
+            .one(self.db())
+            .await
+            .map_err(db_error("get collection manifest"))?;
+        model
+            .map(|m| {
+                let distance_metric = m.distance_metric.parse::<DistanceMetric>().map_err(|e| {
+                    Error::internal(format!(
+                        "Invalid distance metric '{}': {e}",
+                        m.distance_metric
+                    ))
+                })?;
+                Ok(CollectionManifest {
+                    collection: m.collection,
+                    chunker_version: u32::try_from(m.chunker_version).unwrap_or(0),
+                    distance_metric,
+                    embedding_model: m.embedding_model,
+                    root_path: m.root_path,
+                    updated_at: m.updated_at,
+                })
+            })
+            .transpose()
+    }
+
+    async fn record_build(
+        &self,
+        collection: &str,
+        chunker_version: u32,
+        distance_metric: DistanceMetric,
+        embedding_model: &str,
+        root_path: &str,
+    ) -> Result<()> {
+        let active = collection_manifest::ActiveModel {
+            collection: Set(collection.to_owned()),
+            chunker_version: Set(i64::from(chunker_version)),
+            distance_metric: Set(distance_metric.as_str().to_owned()),
+            embedding_model: Set(Some(embedding_model.to_owned())),
+            root_path: Set(Some(root_path.to_owned())),
+            updated_at: Set(mcb_utils::utils::time::epoch_secs_i64()?),
+        };
+        collection_manifest::Entity::insert(active)
+            .on_conflict(
+                OnConflict::column(collection_manifest::Column::Collection)
+                    .update_columns([
+                        collection_manifest::Column::ChunkerVersion,
+                        collection_manifest::Column::DistanceMetric,
+                        collection_manifest::Column::EmbeddingModel,
+                        collection_manifest::Column::RootPath,
+                        collection_manifest::Column::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(self.db())
+            .await
+            .map_err(db_error("record collection manifest"))?;
+        Ok(())
+    }
+
+    async fn clear_collection(&self, collection: &str) -> Result<u64> {
+        let result = collection_manifest::Entity::delete_many()
+            .filter(collection_manifest::Column::Collection.eq(collection))
+            .exec(self.db())
+            .await
+            .map_err(db_error("clear collection manifest"))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,78 @@
+//! SeaORM-backed file snapshot repository.
+//!
+//! Stores full file contents in `file_blobs`, keyed by the content hash kept in
+//! `file_hashes`. Identical files share one blob across collections.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::FileSnapshotRepository;
+use sea_orm::sea_query::{OnConflict, Query};
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, Set};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::{file_blob, file_hash};
+
+/// `SeaORM` `FileSnapshotRepository` implementation.
+pub struct SeaOrmFileSnapshotRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmFileSnapshotRepository {
+    /// Create a new `SeaOrmFileSnapshotRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+}
+
+#[async_trait]
+impl FileSnapshotRepository for SeaOrmFileSnapshotRepository {
+    async fn store_snapshot(&self, hash: &str, content: &str) -> Result<()> {
+        let active = file_blob::ActiveModel {
+            content_hash: Set(hash.to_owned()),
+            content: Set(content.to_owned()),
+            size_bytes: Set(i64::try_from(content.len()).unwrap_or(i64::MAX)),
+            created_at: Set(mcb_utils::utils::time::epoch_secs_i64()?),
+        };
+        match file_blob::Entity::insert(active)
+            .on_conflict(
+                OnConflict::column(file_blob::Column::ContentHash)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec(self.db())
+            .await
+        {
+            Ok(_) | Err(DbErr::RecordNotInserted) => Ok(()),
+            Err(e) => Err(db_error("store file snapshot")(e)),
+        }
+    }
+
+    async fn get_snapshot(&self, hash: &str) -> Result<Option<String>> {
+        let model = file_blob::Entity::find_by_id(hash.to_owned())
+            .one(self.db())
+            .await
+            .map_err(db_error("get file snapshot"))?;
+        Ok(model.map(|m| m.content))
+    }
+
+    async fn prune_unreferenced(&self) -> Result<u64> {
+        let live_hashes = Query::select()
+            .column(file_hash::Column::ContentHash)
+            .from(file_hash::Entity)
+            .and_where(file_hash::Column::DeletedAt.is_null())
+            .to_owned();
+        let result = file_blob::Entity::delete_many()
+            .filter(file_blob::Column::ContentHash.not_in_subquery(live_hashes))
+            .exec(self.db())
+            .await
+            .map_err(db_error("prune file snapshots"))?;
+        Ok(result.rows_affected)
+    }
+}
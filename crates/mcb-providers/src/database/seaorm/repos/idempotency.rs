@@ -0,0 +1,100 @@
+//! SeaORM-backed idempotency repository.
+//!
+//! Stores one row per organization, tool and idempotency key in
+//! `idempotency_records`.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::idempotency::IdempotencyRecord;
+use mcb_domain::error::Result;
+use mcb_domain::ports::IdempotencyRepository;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::idempotency_record;
+
+/// `SeaORM` `IdempotencyRepository` implementation.
+pub struct SeaOrmIdempotencyRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmIdempotencyRepository {
+    /// Create a new `SeaOrmIdempotencyRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+}
+
+#[async_trait]
+impl IdempotencyRepository for SeaOrmIdempotencyRepository {
+    async fn find_record(
+        &self,
+        org_id: &str,
+        tool_name: &str,
+        key: &str,
+        since: i64,
+    ) -> Result<Option<IdempotencyRecord>> {
+        let model = idempotency_record::Entity::find_by_id((
+            org_id.to_owned(),
+            tool_name.to_owned(),
+            key.to_owned(),
+        ))
+        .filter(idempotency_record::Column::CreatedAt.gte(since))
+        .one(self.db())
+        .await
+        .map_err(db_error("find idempotency record"))?;
+        Ok(model.map(|model| IdempotencyRecord {
+            org_id: model.org_id,
+            tool_name: model.tool_name,
+            key: model.key,
+            request_hash: model.request_hash,
+            response: model.response,
+            created_at: model.created_at,
+        }))
+    }
+
+    async fn save_record(&self, record: &IdempotencyRecord) -> Result<()> {
+        let active = idempotency_record::ActiveModel {
+            org_id: Set(record.org_id.clone()),
+            tool_name: Set(record.tool_name.clone()),
+            key: Set(record.key.clone()),
+            request_hash: Set(record.request_hash.clone()),
+            response: Set(record.response.clone()),
+            created_at: Set(record.created_at),
+        };
+        idempotency_record::Entity::insert(active)
+            .on_conflict(
+                OnConflict::columns([
+                    idempotency_record::Column::OrgId,
+                    idempotency_record::Column::ToolName,
+                    idempotency_record::Column::Key,
+                ])
+                .update_columns([
+                    idempotency_record::Column::RequestHash,
+                    idempotency_record::Column::Response,
+                    idempotency_record::Column::CreatedAt,
+                ])
+                .to_owned(),
+            )
+            .exec(self.db())
+            .await
+            .map_err(db_error("save idempotency record"))?;
+        Ok(())
+    }
+
+    async fn delete_expired(&self, before: i64) -> Result<u64> {
+        let result = idempotency_record::Entity::delete_many()
+            .filter(idempotency_record::Column::CreatedAt.lt(before))
+            .exec(self.db())
+            .await
+            .map_err(db_error("delete expired idempotency records"))?;
+        Ok(result.rows_affected)
+    }
+}
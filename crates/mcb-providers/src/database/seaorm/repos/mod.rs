@@ -7,27 +7,59 @@
 mod common;
 /// Agent repository implementation.
 pub mod agent;
+/// Collection manifest repository implementation.
+pub mod collection_manifest;
 /// Entity repository bundle.
 mod entity_repository;
+/// File snapshot blob repository implementation.
+pub mod file_snapshot;
+/// Idempotency key repository implementation.
+pub mod idempotency;
 /// Indexing repository implementation.
 pub mod index;
+/// Module import repository implementation.
+pub mod module_import;
 /// Observation repository implementation.
 pub mod observation;
 /// Project repository implementation.
 pub mod project;
 /// Database repository bundle registry integration.
 pub mod registry;
+/// Search feedback repository implementation.
+pub mod search_feedback;
+/// Test link repository implementation.
+pub mod test_link;
+/// Usage accounting repository implementation.
+pub mod usage;
+/// Workspace repository implementation.
+pub mod workspace;
 
 /// `SeaORM` agent repository.
 pub use agent::SeaOrmAgentRepository;
+/// `SeaORM` collection manifest repository.
+pub use collection_manifest::SeaOrmCollectionManifestRepository;
 /// Unified entity repository.
 pub use entity_repository::SeaOrmEntityRepository;
+/// `SeaORM` file snapshot repository.
+pub use file_snapshot::SeaOrmFileSnapshotRepository;
+/// `SeaORM` idempotency repository.
+pub use idempotency::SeaOrmIdempotencyRepository;
 /// `SeaORM` indexing repository.
 pub use index::SeaOrmIndexRepository;
+/// `SeaORM` module import repository.
+pub use module_import::SeaOrmModuleImportRepository;
 /// `SeaORM` observation repository.
 pub use observation::SeaOrmObservationRepository;
 /// `SeaORM` project repository.
 pub use project::SeaOrmProjectRepository;
+/// `SeaORM` search feedback repository.
+pub use search_feedback::SeaOrmSearchFeedbackRepository;
+/// `SeaORM` test link repository.
+pub use test_link::SeaOrmTestLinkRepository;
+/// `SeaORM` usage repository.
+pub use usage::SeaOrmUsageRepository;
+/// `SeaORM` workspace repository.
+pub use workspace::SeaOrmWorkspaceRepository;
 
 // Sub-modules containing the macro-generated trait implementations.
 mod issues;
@@ -0,0 +1,112 @@
+//! SeaORM-backed module import repository.
+//!
+//! Stores the import edges of the module dependency graph in
+//! `module_imports`, replaced file by file as files are re-indexed.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::module_import::ModuleImport;
+use mcb_domain::error::Result;
+use mcb_domain::ports::ModuleImportRepository;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::module_import;
+
+/// `SeaORM` `ModuleImportRepository` implementation.
+pub struct SeaOrmModuleImportRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmModuleImportRepository {
+    /// Create a new `SeaOrmModuleImportRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+
+    fn to_domain(model: module_import::Model) -> ModuleImport {
+        ModuleImport {
+            collection: model.collection,
+            source_file: model.source_file,
+            specifier: model.specifier,
+            module_path: model.module_path,
+            line: u32::try_from(model.line).unwrap_or(0),
+        }
+    }
+}
+
+#[async_trait]
+impl ModuleImportRepository for SeaOrmModuleImportRepository {
+    async fn replace_file_imports(
+        &self,
+        collection: &str,
+        source_file: &str,
+        imports: &[ModuleImport],
+    ) -> Result<()> {
+        let collection = collection.to_owned();
+        let source_file = source_file.to_owned();
+        let models: Vec<module_import::ActiveModel> = imports
+            .iter()
+            .map(|i| module_import::ActiveModel {
+                id: sea_orm::ActiveValue::NotSet,
+                collection: Set(collection.clone()),
+                source_file: Set(source_file.clone()),
+                specifier: Set(i.specifier.clone()),
+                module_path: Set(i.module_path.clone()),
+                line: Set(i64::from(i.line)),
+            })
+            .collect();
+
+        self.db()
+            .transaction::<_, (), sea_orm::DbErr>(|txn| {
+                Box::pin(async move {
+                    module_import::Entity::delete_many()
+                        .filter(module_import::Column::Collection.eq(&collection))
+                        .filter(module_import::Column::SourceFile.eq(&source_file))
+                        .exec(txn)
+                        .await?;
+                    if !models.is_empty() {
+                        module_import::Entity::insert_many(models).exec(txn).await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e| match e {
+                sea_orm::TransactionError::Connection(err)
+                | sea_orm::TransactionError::Transaction(err) => {
+                    db_error("replace module imports")(err)
+                }
+            })
+    }
+
+    async fn list_imports(&self, collection: &str) -> Result<Vec<ModuleImport>> {
+        Ok(module_import::Entity::find()
+            .filter(module_import::Column::Collection.eq(collection))
+            .order_by_asc(module_import::Column::SourceFile)
+            .order_by_asc(module_import::Column::Line)
+            .all(self.db())
+            .await
+            .map_err(db_error("list module imports"))?
+            .into_iter()
+            .map(Self::to_domain)
+            .collect())
+    }
+
+    async fn clear_collection(&self, collection: &str) -> Result<u64> {
+        let result = module_import::Entity::delete_many()
+            .filter(module_import::Column::Collection.eq(collection))
+            .exec(self.db())
+            .await
+            .map_err(db_error("clear module imports"))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -9,8 +9,11 @@ use sea_orm::DatabaseConnection;
 use crate::database::seaorm::auth_repository::SeaOrmAuthRepositoryAdapter;
 use crate::database::seaorm::dashboard::SeaOrmDashboardAdapter;
 use crate::database::seaorm::repos::{
-    SeaOrmAgentRepository, SeaOrmEntityRepository, SeaOrmIndexRepository,
-    SeaOrmObservationRepository, SeaOrmProjectRepository,
+    SeaOrmAgentRepository, SeaOrmCollectionManifestRepository, SeaOrmEntityRepository,
+    SeaOrmFileSnapshotRepository, SeaOrmIdempotencyRepository, SeaOrmIndexRepository,
+    SeaOrmModuleImportRepository, SeaOrmObservationRepository, SeaOrmProjectRepository,
+    SeaOrmSearchFeedbackRepository, SeaOrmTestLinkRepository, SeaOrmUsageRepository,
+    SeaOrmWorkspaceRepository,
 };
 
 /// Creates the complete SeaORM-backed repository bundle for the database registry.
@@ -46,6 +49,14 @@ fn create_seaorm_repositories(
         issue_entity: Arc::clone(&entity_repo) as _,
         org_entity: Arc::clone(&entity_repo) as _,
         file_hash: Arc::new(index_repo),
+        file_snapshot: Arc::new(SeaOrmFileSnapshotRepository::new(Arc::clone(&db))),
+        search_feedback: Arc::new(SeaOrmSearchFeedbackRepository::new(Arc::clone(&db))),
+        test_link: Arc::new(SeaOrmTestLinkRepository::new(Arc::clone(&db))),
+        module_import: Arc::new(SeaOrmModuleImportRepository::new(Arc::clone(&db))),
+        collection_manifest: Arc::new(SeaOrmCollectionManifestRepository::new(Arc::clone(&db))),
+        workspace: Arc::new(SeaOrmWorkspaceRepository::new(Arc::clone(&db))),
+        usage: Arc::new(SeaOrmUsageRepository::new(Arc::clone(&db))),
+        idempotency: Arc::new(SeaOrmIdempotencyRepository::new(Arc::clone(&db))),
     })
 }
 
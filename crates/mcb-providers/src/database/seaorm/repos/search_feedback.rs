@@ -0,0 +1,143 @@
+//! SeaORM-backed search feedback repository.
+//!
+//! Stores relevance votes in `search_feedback` and learned multipliers in
+//! `search_file_boosts`.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::memory::{FileBoost, FileFeedbackTally, SearchFeedback};
+use mcb_domain::error::Result;
+use mcb_domain::ports::SearchFeedbackRepository;
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QuerySelect, Set, TransactionTrait,
+};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::{search_feedback, search_file_boost};
+
+/// `SeaORM` `SearchFeedbackRepository` implementation.
+pub struct SeaOrmSearchFeedbackRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmSearchFeedbackRepository {
+    /// Create a new `SeaOrmSearchFeedbackRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+}
+
+#[async_trait]
+impl SearchFeedbackRepository for SeaOrmSearchFeedbackRepository {
+    async fn record_feedback(&self, feedback: &SearchFeedback) -> Result<()> {
+        let active = search_feedback::ActiveModel {
+            id: Set(feedback.id.clone()),
+            query: Set(feedback.query.clone()),
+            collection: Set(feedback.collection.clone()),
+            chunk_id: Set(feedback.chunk_id.clone()),
+            file_path: Set(feedback.file_path.clone()),
+            relevant: Set(feedback.relevant),
+            session_id: Set(feedback.session_id.clone()),
+            created_at: Set(feedback.created_at),
+        };
+        search_feedback::Entity::insert(active)
+            .exec(self.db())
+            .await
+            .map_err(db_error("record search feedback"))?;
+        Ok(())
+    }
+
+    async fn tally_by_file(&self) -> Result<Vec<FileFeedbackTally>> {
+        let rows: Vec<(String, String, bool, i64)> = search_feedback::Entity::find()
+            .select_only()
+            .column(search_feedback::Column::Collection)
+            .column(search_feedback::Column::FilePath)
+            .column(search_feedback::Column::Relevant)
+            .column_as(Expr::col(search_feedback::Column::Id).count(), "votes")
+            .group_by(search_feedback::Column::Collection)
+            .group_by(search_feedback::Column::FilePath)
+            .group_by(search_feedback::Column::Relevant)
+            .into_tuple()
+            .all(self.db())
+            .await
+            .map_err(db_error("tally search feedback"))?;
+
+        let mut tallies: BTreeMap<(String, String), (u64, u64)> = BTreeMap::new();
+        for (collection, file_path, relevant, votes) in rows {
+            let entry = tallies.entry((collection, file_path)).or_default();
+            let votes = u64::try_from(votes).unwrap_or(0);
+            if relevant {
+                entry.0 += votes;
+            } else {
+                entry.1 += votes;
+            }
+        }
+        Ok(tallies
+            .into_iter()
+            .map(|((collection, file_path), (up, down))| FileFeedbackTally {
+                collection,
+                file_path,
+                up,
+                down,
+            })
+            .collect())
+    }
+
+    async fn replace_file_boosts(&self, boosts: &[FileBoost]) -> Result<()> {
+        let models: Vec<search_file_boost::ActiveModel> = boosts
+            .iter()
+            .map(|b| search_file_boost::ActiveModel {
+                id: sea_orm::ActiveValue::NotSet,
+                collection: Set(b.collection.clone()),
+                file_path: Set(b.file_path.clone()),
+                boost: Set(b.boost),
+                updated_at: Set(b.updated_at),
+            })
+            .collect();
+
+        self.db()
+            .transaction::<_, (), sea_orm::DbErr>(|txn| {
+                Box::pin(async move {
+                    search_file_boost::Entity::delete_many().exec(txn).await?;
+                    if !models.is_empty() {
+                        search_file_boost::Entity::insert_many(models)
+                            .exec(txn)
+                            .await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e| match e {
+                sea_orm::TransactionError::Connection(err)
+                | sea_orm::TransactionError::Transaction(err) => {
+                    db_error("replace search file boosts")(err)
+                }
+            })
+    }
+
+    async fn file_boosts(&self, collection: &str) -> Result<Vec<FileBoost>> {
+        let models = search_file_boost::Entity::find()
+            .filter(search_file_boost::Column::Collection.eq(collection))
+            .all(self.db())
+            .await
+            .map_err(db_error("load search file boosts"))?;
+        Ok(models
+            .into_iter()
+            .map(|m| FileBoost {
+                collection: m.collection,
+                file_path: m.file_path,
+                boost: m.boost,
+                updated_at: m.updated_at,
+            })
+            .collect())
+    }
+}
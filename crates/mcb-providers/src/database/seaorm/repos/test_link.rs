@@ -0,0 +1,127 @@
+//! SeaORM-backed test link repository.
+//!
+//! Stores links between test functions and the symbols they exercise in
+//! `test_links`, replaced file by file as test files are re-indexed.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::test_link::{TestLink, TestLinkReason};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::TestLinkRepository;
+use sea_orm::{
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, QuerySelect, Set,
+    TransactionTrait,
+};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::test_link;
+
+/// `SeaORM` `TestLinkRepository` implementation.
+pub struct SeaOrmTestLinkRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmTestLinkRepository {
+    /// Create a new `SeaOrmTestLinkRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+
+    fn to_domain(model: test_link::Model) -> Result<TestLink> {
+        Ok(TestLink {
+            reason: model.reason.parse::<TestLinkReason>().map_err(|e| {
+                Error::internal(format!("Invalid test link reason '{}': {e}", model.reason))
+            })?,
+            collection: model.collection,
+            test_file: model.test_file,
+            test_name: model.test_name,
+            start_line: u32::try_from(model.start_line).unwrap_or(0),
+            end_line: u32::try_from(model.end_line).unwrap_or(0),
+            target: model.target,
+        })
+    }
+}
+
+#[async_trait]
+impl TestLinkRepository for SeaOrmTestLinkRepository {
+    async fn replace_file_links(
+        &self,
+        collection: &str,
+        test_file: &str,
+        links: &[TestLink],
+    ) -> Result<()> {
+        let collection = collection.to_owned();
+        let test_file = test_file.to_owned();
+        let models: Vec<test_link::ActiveModel> = links
+            .iter()
+            .map(|l| test_link::ActiveModel {
+                id: sea_orm::ActiveValue::NotSet,
+                collection: Set(collection.clone()),
+                test_file: Set(test_file.clone()),
+                test_name: Set(l.test_name.clone()),
+                start_line: Set(i64::from(l.start_line)),
+                end_line: Set(i64::from(l.end_line)),
+                target: Set(l.target.clone()),
+                reason: Set(l.reason.as_str().to_owned()),
+            })
+            .collect();
+
+        self.db()
+            .transaction::<_, (), sea_orm::DbErr>(|txn| {
+                Box::pin(async move {
+                    test_link::Entity::delete_many()
+                        .filter(test_link::Column::Collection.eq(&collection))
+                        .filter(test_link::Column::TestFile.eq(&test_file))
+                        .exec(txn)
+                        .await?;
+                    if !models.is_empty() {
+                        test_link::Entity::insert_many(models).exec(txn).await?;
+                    }
+                    Ok(())
+                })
+            })
+            .await
+            .map_err(|e| match e {
+                sea_orm::TransactionError::Connection(err)
+                | sea_orm::TransactionError::Transaction(err) => {
+                    db_error("replace test links")(err)
+                }
+            })
+    }
+
+    async fn find_tests(
+        &self,
+        collection: &str,
+        target: &str,
+        limit: usize,
+    ) -> Result<Vec<TestLink>> {
+        test_link::Entity::find()
+            .filter(test_link::Column::Collection.eq(collection))
+            .filter(test_link::Column::Target.eq(target))
+            .order_by_asc(test_link::Column::Reason)
+            .order_by_asc(test_link::Column::TestFile)
+            .order_by_asc(test_link::Column::StartLine)
+            .limit(u64::try_from(limit).unwrap_or(u64::MAX))
+            .all(self.db())
+            .await
+            .map_err(db_error("find linked tests"))?
+            .into_iter()
+            .map(Self::to_domain)
+            .collect()
+    }
+
+    async fn clear_collection(&self, collection: &str) -> Result<u64> {
+        let result = test_link::Entity::delete_many()
+            .filter(test_link::Column::Collection.eq(collection))
+            .exec(self.db())
+            .await
+            .map_err(db_error("clear test links"))?;
+        Ok(result.rows_affected)
+    }
+}
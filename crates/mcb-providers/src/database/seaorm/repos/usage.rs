@@ -0,0 +1,111 @@
+//! SeaORM-backed usage repository.
+//!
+//! Stores one row per organization, collection and month in
+//! `usage_records`; recorded usage is added to the row's counters.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::usage::{UsageFilter, UsageRecord};
+use mcb_domain::error::Result;
+use mcb_domain::ports::UsageRepository;
+use sea_orm::sea_query::{Expr, ExprTrait, OnConflict};
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::usage_record;
+
+/// `SeaORM` `UsageRepository` implementation.
+pub struct SeaOrmUsageRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmUsageRepository {
+    /// Create a new `SeaOrmUsageRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+
+    fn to_domain(model: usage_record::Model) -> UsageRecord {
+        UsageRecord {
+            org_id: model.org_id,
+            collection: model.collection,
+            month: model.month,
+            indexed_bytes: u64::try_from(model.indexed_bytes).unwrap_or(0),
+            embedding_tokens: u64::try_from(model.embedding_tokens).unwrap_or(0),
+            queries: u64::try_from(model.queries).unwrap_or(0),
+        }
+    }
+
+    /// `column = column + excluded.column` for the upsert of a counter.
+    fn accumulate(column: usage_record::Column, name: &str) -> Expr {
+        Expr::col((usage_record::Entity, column)).add(Expr::cust(format!("excluded.{name}")))
+    }
+}
+
+#[async_trait]
+impl UsageRepository for SeaOrmUsageRepository {
+    async fn add_usage(&self, usage: &UsageRecord) -> Result<()> {
+        let active = usage_record::ActiveModel {
+            org_id: Set(usage.org_id.clone()),
+            collection: Set(usage.collection.clone()),
+            month: Set(usage.month.clone()),
+            indexed_bytes: Set(i64::try_from(usage.indexed_bytes).unwrap_or(i64::MAX)),
+            embedding_tokens: Set(i64::try_from(usage.embedding_tokens).unwrap_or(i64::MAX)),
+            queries: Set(i64::try_from(usage.queries).unwrap_or(i64::MAX)),
+            updated_at: Set(mcb_utils::utils::time::epoch_secs_i64()?),
+        };
+        usage_record::Entity::insert(active)
+            .on_conflict(
+                OnConflict::columns([
+                    usage_record::Column::OrgId,
+                    usage_record::Column::Collection,
+                    usage_record::Column::Month,
+                ])
+                .value(
+                    usage_record::Column::IndexedBytes,
+                    Self::accumulate(usage_record::Column::IndexedBytes, "indexed_bytes"),
+                )
+                .value(
+                    usage_record::Column::EmbeddingTokens,
+                    Self::accumulate(usage_record::Column::EmbeddingTokens, "embedding_tokens"),
+                )
+                .value(
+                    usage_record::Column::Queries,
+                    Self::accumulate(usage_record::Column::Queries, "queries"),
+                )
+                .update_column(usage_record::Column::UpdatedAt)
+                .to_owned(),
+            )
+            .exec(self.db())
+            .await
+            .map_err(db_error("add usage"))?;
+        Ok(())
+    }
+
+    async fn list_usage(&self, filter: &UsageFilter) -> Result<Vec<UsageRecord>> {
+        let mut query = usage_record::Entity::find();
+        if let Some(org_id) = &filter.org_id {
+            query = query.filter(usage_record::Column::OrgId.eq(org_id.as_str()));
+        }
+        if let Some(from) = &filter.from_month {
+            query = query.filter(usage_record::Column::Month.gte(from.as_str()));
+        }
+        if let Some(to) = &filter.to_month {
+            query = query.filter(usage_record::Column::Month.lte(to.as_str()));
+        }
+        let rows = query
+            .order_by_asc(usage_record::Column::OrgId)
+            .order_by_asc(usage_record::Column::Month)
+            .order_by_asc(usage_record::Column::Collection)
+            .all(self.db())
+            .await
+            .map_err(db_error("list usage"))?;
+        Ok(rows.into_iter().map(Self::to_domain).collect())
+    }
+}
@@ -0,0 +1,284 @@
+//! SeaORM-backed workspace repository.
+//!
+//! Stores workspace membership in `workspace_collections` and the files one
+//! member collection serves for another in `workspace_shared_files`. Origins
+//! are looked up among the live rows of `file_hashes`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use mcb_domain::entities::workspace::{CollectionFile, SharedFile, Workspace, WorkspaceMember};
+use mcb_domain::error::Result;
+use mcb_domain::ports::WorkspaceRepository;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, DbErr, EntityTrait, QueryFilter, QueryOrder, Set};
+
+use super::common::db_error;
+use crate::database::seaorm::entities::{file_hash, workspace_collection, workspace_shared_file};
+
+/// `SeaORM` `WorkspaceRepository` implementation.
+pub struct SeaOrmWorkspaceRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SeaOrmWorkspaceRepository {
+    /// Create a new `SeaOrmWorkspaceRepository`.
+    #[must_use]
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    fn db(&self) -> &DatabaseConnection {
+        self.db.as_ref()
+    }
+
+    fn to_domain(model: workspace_shared_file::Model) -> SharedFile {
+        SharedFile {
+            copy: CollectionFile {
+                collection: model.collection,
+                file_path: model.file_path,
+            },
+            origin: CollectionFile {
+                collection: model.origin_collection,
+                file_path: model.origin_path,
+            },
+            content_hash: model.content_hash,
+        }
+    }
+
+    /// Group membership rows by workspace, both in name order.
+    fn group(rows: Vec<workspace_collection::Model>) -> Vec<Workspace> {
+        let mut workspaces: BTreeMap<String, Vec<WorkspaceMember>> = BTreeMap::new();
+        for row in rows {
+            workspaces
+                .entry(row.workspace)
+                .or_default()
+                .push(WorkspaceMember {
+                    name: row.name,
+                    collection_id: row.collection,
+                });
+        }
+        workspaces
+            .into_iter()
+            .map(|(name, mut collections)| {
+                collections.sort_by(|a, b| a.name.cmp(&b.name));
+                Workspace { name, collections }
+            })
+            .collect()
+    }
+
+    /// Collections sharing at least one workspace with `collection`.
+    async fn siblings(&self, collection: &str) -> Result<Vec<String>> {
+        let workspaces: Vec<String> = workspace_collection::Entity::find()
+            .filter(workspace_collection::Column::Collection.eq(collection))
+            .all(self.db())
+            .await
+            .map_err(db_error("find collection workspaces"))?
+            .into_iter()
+            .map(|row| row.workspace)
+            .collect();
+        if workspaces.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut siblings: Vec<String> = workspace_collection::Entity::find()
+            .filter(workspace_collection::Column::Workspace.is_in(workspaces))
+            .filter(workspace_collection::Column::Collection.ne(collection))
+            .all(self.db())
+            .await
+            .map_err(db_error("find workspace siblings"))?
+            .into_iter()
+            .map(|row| row.collection)
+            .collect();
+        siblings.sort();
+        siblings.dedup();
+        Ok(siblings)
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for SeaOrmWorkspaceRepository {
+    async fn add_collection(&self, workspace: &str, member: &WorkspaceMember) -> Result<()> {
+        let active = workspace_collection::ActiveModel {
+            workspace: Set(workspace.to_owned()),
+            collection: Set(member.collection_id.clone()),
+            name: Set(member.name.clone()),
+            added_at: Set(mcb_utils::utils::time::epoch_secs_i64()?),
+        };
+        match workspace_collection::Entity::insert(active)
+            .on_conflict(
+                OnConflict::columns([
+                    workspace_collection::Column::Workspace,
+                    workspace_collection::Column::Collection,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .exec(self.db())
+            .await
+        {
+            Ok(_) | Err(DbErr::RecordNotInserted) => Ok(()),
+            Err(e) => Err(db_error("add workspace collection")(e)),
+        }
+    }
+
+    async fn remove_collection(&self, workspace: &str, collection: &str) -> Result<bool> {
+        let result = workspace_collection::Entity::delete_many()
+            .filter(workspace_collection::Column::Workspace.eq(workspace))
+            .filter(workspace_collection::Column::Collection.eq(collection))
+            .exec(self.db())
+            .await
+            .map_err(db_error("remove workspace collection"))?;
+        Ok(result.rows_affected > 0)
+    }
+
+    async fn get_workspace(&self, workspace: &str) -> Result<Option<Workspace>> {
+        let rows = workspace_collection::Entity::find()
+            .filter(workspace_collection::Column::Workspace.eq(workspace))
+            .all(self.db())
+            .await
+            .map_err(db_error("get workspace"))?;
+        Ok(Self::group(rows).into_iter().next())
+    }
+
+    async fn list_workspaces(&self) -> Result<Vec<Workspace>> {
+        let rows = workspace_collection::Entity::find()
+            .all(self.db())
+            .await
+            .map_err(db_error("list workspaces"))?;
+        Ok(Self::group(rows))
+    }
+
+    async fn find_origin(
+        &self,
+        collection: &str,
+        content_hash: &str,
+    ) -> Result<Option<CollectionFile>> {
+        let siblings = self.siblings(collection).await?;
+        if siblings.is_empty() {
+            return Ok(None);
+        }
+        let copies: HashSet<(String, String)> = workspace_shared_file::Entity::find()
+            .filter(workspace_shared_file::Column::ContentHash.eq(content_hash))
+            .filter(workspace_shared_file::Column::Collection.is_in(siblings.clone()))
+            .all(self.db())
+            .await
+            .map_err(db_error("find shared file copies"))?
+            .into_iter()
+            .map(|row| (row.collection, row.file_path))
+            .collect();
+        let candidates = file_hash::Entity::find()
+            .filter(file_hash::Column::ContentHash.eq(content_hash))
+            .filter(file_hash::Column::Collection.is_in(siblings))
+            .filter(file_hash::Column::DeletedAt.is_null())
+            .order_by_asc(file_hash::Column::Collection)
+            .order_by_asc(file_hash::Column::FilePath)
+            .all(self.db())
+            .await
+            .map_err(db_error("find shared file origin"))?;
+        Ok(candidates
+            .into_iter()
+            .map(|row| (row.collection, row.file_path))
+            .find(|key| !copies.contains(key))
+            .map(|(collection, file_path)| CollectionFile {
+                collection,
+                file_path,
+            }))
+    }
+
+    async fn record_shared_file(&self, shared: &SharedFile) -> Result<()> {
+        let active = workspace_shared_file::ActiveModel {
+            collection: Set(shared.copy.collection.clone()),
+            file_path: Set(shared.copy.file_path.clone()),
+            content_hash: Set(shared.content_hash.clone()),
+            origin_collection: Set(shared.origin.collection.clone()),
+            origin_path: Set(shared.origin.file_path.clone()),
+        };
+        workspace_shared_file::Entity::insert(active)
+            .on_conflict(
+                OnConflict::columns([
+                    workspace_shared_file::Column::Collection,
+                    workspace_shared_file::Column::FilePath,
+                ])
+                .update_columns([
+                    workspace_shared_file::Column::ContentHash,
+                    workspace_shared_file::Column::OriginCollection,
+                    workspace_shared_file::Column::OriginPath,
+                ])
+                .to_owned(),
+            )
+            .exec(self.db())
+            .await
+            .map_err(db_error("record shared file"))?;
+        Ok(())
+    }
+
+    async fn forget_shared_file(&self, collection: &str, file_path: &str) -> Result<()> {
+        workspace_shared_file::Entity::delete_many()
+            .filter(workspace_shared_file::Column::Collection.eq(collection))
+            .filter(workspace_shared_file::Column::FilePath.eq(file_path))
+            .exec(self.db())
+            .await
+            .map_err(db_error("forget shared file"))?;
+        Ok(())
+    }
+
+    async fn release_copies(
+        &self,
+        origin_collection: &str,
+        origin_path: Option<&str>,
+    ) -> Result<Vec<SharedFile>> {
+        let mut query = workspace_shared_file::Entity::find()
+            .filter(workspace_shared_file::Column::OriginCollection.eq(origin_collection));
+        if let Some(path) = origin_path {
+            query = query.filter(workspace_shared_file::Column::OriginPath.eq(path));
+        }
+        let copies = query
+            .all(self.db())
+            .await
+            .map_err(db_error("find copies to release"))?;
+        if copies.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut delete = workspace_shared_file::Entity::delete_many()
+            .filter(workspace_shared_file::Column::OriginCollection.eq(origin_collection));
+        if let Some(path) = origin_path {
+            delete = delete.filter(workspace_shared_file::Column::OriginPath.eq(path));
+        }
+        delete
+            .exec(self.db())
+            .await
+            .map_err(db_error("release copies"))?;
+        Ok(copies.into_iter().map(Self::to_domain).collect())
+    }
+
+    async fn list_copies(
+        &self,
+        origin_collection: &str,
+        origin_paths: &[String],
+    ) -> Result<Vec<SharedFile>> {
+        if origin_paths.is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(workspace_shared_file::Entity::find()
+            .filter(workspace_shared_file::Column::OriginCollection.eq(origin_collection))
+            .filter(workspace_shared_file::Column::OriginPath.is_in(origin_paths.to_vec()))
+            .order_by_asc(workspace_shared_file::Column::Collection)
+            .order_by_asc(workspace_shared_file::Column::FilePath)
+            .all(self.db())
+            .await
+            .map_err(db_error("list shared file copies"))?
+            .into_iter()
+            .map(Self::to_domain)
+            .collect())
+    }
+
+    async fn clear_collection(&self, collection: &str) -> Result<u64> {
+        let result = workspace_shared_file::Entity::delete_many()
+            .filter(workspace_shared_file::Column::Collection.eq(collection))
+            .exec(self.db())
+            .await
+            .map_err(db_error("clear shared files"))?;
+        Ok(result.rows_affected)
+    }
+}
@@ -9,7 +9,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::ports::EmbeddingProvider;
+use mcb_domain::ports::{EmbeddingProvider, RequestSigner, ResiliencePolicy};
 use mcb_domain::value_objects::Embedding;
 use mcb_utils::constants::embedding::{
     EMBEDDING_DIMENSION_OLLAMA_ARCTIC, EMBEDDING_DIMENSION_OLLAMA_DEFAULT,
@@ -26,6 +26,14 @@ use mcb_utils::constants::embedding::{EMBEDDING_OPERATION_NAME, EMBEDDING_PARAM_
 use mcb_utils::constants::http::CONTENT_TYPE_JSON;
 use mcb_utils::constants::http::HTTP_HEADER_CONTENT_TYPE;
 
+/// Local server: fail fast unless retries are configured explicitly.
+fn ollama_default_resilience() -> ResiliencePolicy {
+    ResiliencePolicy {
+        max_retries: 0,
+        ..ResiliencePolicy::default()
+    }
+}
+
 define_http_embedding_provider!(
     /// Ollama embedding provider
     ///
@@ -53,10 +61,25 @@ impl OllamaEmbeddingProvider {
                 model,
                 timeout,
                 client: http_client,
+                resilience: ollama_default_resilience(),
             }),
         }
     }
 
+    /// Apply a timeout and retry policy to every request
+    #[must_use]
+    pub fn with_resilience(mut self, policy: ResiliencePolicy) -> Self {
+        self.client.set_resilience(policy);
+        self
+    }
+
+    /// Sign every request with `signer` (e.g. for an authenticating gateway)
+    #[must_use]
+    pub fn with_signer(mut self, signer: Option<Arc<dyn RequestSigner>>) -> Self {
+        self.client.set_signer(signer);
+        self
+    }
+
     /// Get the model name for this provider
     #[must_use]
     pub fn model(&self) -> &str {
@@ -97,7 +120,8 @@ impl OllamaEmbeddingProvider {
             kind: RequestErrorKind::Embedding,
             headers: &headers,
             body: Some(&payload),
-            retry: None,
+            retry: Some(self.client.retry_config()),
+            signer: self.client.signer(),
         })
         .await
         .map_err(|e| {
@@ -157,7 +181,7 @@ impl EmbeddingProvider for OllamaEmbeddingProvider {
     }
 
     fn provider_name(&self) -> &str {
-        "ollama"
+        mcb_utils::constants::PROVIDER_SLUG_OLLAMA
     }
 }
 
@@ -172,7 +196,8 @@ use mcb_domain::registry::embedding::EmbeddingProviderConfig;
 
 /// Factory function for creating Ollama embedding provider instances.
 fn ollama_factory(config: &EmbeddingProviderConfig) -> Result<Arc<dyn EmbeddingProviderPort>> {
-    use crate::utils::http::{DEFAULT_HTTP_TIMEOUT, create_default_client};
+    use crate::utils::http::create_provider_client;
+    use crate::utils::signing::build_request_signer;
 
     let base_url = config.base_url.clone().ok_or_else(|| {
         Error::configuration(format!(
@@ -184,18 +209,19 @@ fn ollama_factory(config: &EmbeddingProviderConfig) -> Result<Arc<dyn EmbeddingP
         .model
         .clone()
         .unwrap_or_else(|| mcb_utils::constants::embedding::OLLAMA_DEFAULT_MODEL.to_owned());
-    let http_client = create_default_client()?;
-
-    Ok(Arc::new(OllamaEmbeddingProvider::new(
-        base_url,
-        model,
-        DEFAULT_HTTP_TIMEOUT,
-        http_client,
-    )))
+    let resilience = config.resilience.unwrap_or_else(ollama_default_resilience);
+    let http_client = create_provider_client(&resilience, config.transport.as_ref())?;
+    let signer = build_request_signer(config.signing.as_ref())?;
+
+    Ok(Arc::new(
+        OllamaEmbeddingProvider::new(base_url, model, resilience.request_timeout(), http_client)
+            .with_resilience(resilience)
+            .with_signer(signer),
+    ))
 }
 
 mcb_domain::register_embedding_provider!(
-    "ollama",
+    mcb_utils::constants::PROVIDER_SLUG_OLLAMA,
     "Ollama local embedding provider (nomic-embed-text, all-minilm, etc.)",
     ollama_factory
 );
@@ -31,8 +31,10 @@
 use std::collections::HashMap;
 
 use async_trait::async_trait;
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::error::Result;
 use mcb_domain::ports::HybridSearchProvider;
-use mcb_domain::{entities::CodeChunk, error::Result, value_objects::SearchResult};
+use mcb_domain::value_objects::{SearchResult, SearchScope};
 use mcb_utils::constants::search::{HYBRID_SEARCH_BM25_WEIGHT, HYBRID_SEARCH_SEMANTIC_WEIGHT};
 use serde_json::Value;
 use tokio::sync::RwLock;
@@ -110,20 +112,25 @@ impl HybridSearchEngine {
 
     /// Combine the BM25 and semantic scores for one result. Falls back to the
     /// semantic score alone when the document is absent from the BM25 index.
+    ///
+    /// Returns the hybrid score and the normalized BM25 score, if any.
     fn hybrid_score_for(
         &self,
         index: &CollectionIndex,
         result: &SearchResult,
         query_terms: &[String],
-    ) -> f64 {
+    ) -> (f64, Option<f64>) {
         let doc_key = format!("{}:{}", result.file_path, result.start_line);
         let Some(&doc_idx) = index.document_index.get(&doc_key) else {
-            return self.semantic_weight * result.score;
+            return (self.semantic_weight * result.score, None);
         };
         let document = &index.documents[doc_idx];
         let bm25_score = index.scorer.score_with_tokens(document, query_terms);
         let normalized_bm25 = Self::normalize_bm25_score(bm25_score);
-        self.bm25_weight * normalized_bm25 + self.semantic_weight * result.score
+        (
+            self.bm25_weight * normalized_bm25 + self.semantic_weight * result.score,
+            Some(normalized_bm25),
+        )
     }
 }
 
@@ -181,14 +188,27 @@ impl HybridSearchProvider for HybridSearchEngine {
     /// Perform hybrid search combining BM25 and semantic scores
     ///
     /// Takes semantic search results (from a vector store) and re-ranks them
-    /// using a combination of BM25 and semantic similarity scores.
+    /// using a combination of BM25 and semantic similarity scores. Results
+    /// outside `scope` are filtered out before BM25 scoring. Results carrying
+    /// an explanation get their BM25 and hybrid scores recorded.
     async fn search(
         &self,
         collection: &str,
         query: &str,
         semantic_results: Vec<SearchResult>,
         limit: usize,
+        scope: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
+        let semantic_results = match scope {
+            Some(scope) => {
+                let in_scope = scope.matcher()?;
+                semantic_results
+                    .into_iter()
+                    .filter(|r| in_scope(&r.file_path, &r.language))
+                    .collect()
+            }
+            None => semantic_results,
+        };
         let collections = self.collections.read().await;
 
         // If collection doesn't exist, return semantic results as-is
@@ -202,8 +222,12 @@ impl HybridSearchProvider for HybridSearchEngine {
         // Calculate hybrid scores for semantic results
         let mut scored_results: Vec<(SearchResult, f64)> = semantic_results
             .into_iter()
-            .map(|result| {
-                let hybrid_score = self.hybrid_score_for(index, &result, &query_terms);
+            .map(|mut result| {
+                let (hybrid_score, bm25) = self.hybrid_score_for(index, &result, &query_terms);
+                if let Some(explanation) = result.explanation.as_mut() {
+                    explanation.bm25 = bm25;
+                    explanation.hybrid = Some(hybrid_score);
+                }
                 (result, hybrid_score)
             })
             .collect();
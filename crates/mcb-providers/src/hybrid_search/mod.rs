@@ -66,6 +66,7 @@
 
 pub mod bm25;
 pub mod engine;
+#[cfg(feature = "native")]
 mod registry;
 
 // Re-export main types
@@ -3,10 +3,11 @@
 //!
 //! Language Detection
 //!
-//! Provides functions to detect programming languages from file extensions,
-//! check language support for AST chunking, and retrieve language-specific
-//! configuration values.
+//! Provides functions to detect programming languages from file extensions
+//! or content, check language support for AST chunking, and retrieve
+//! language-specific configuration values.
 
+use mcb_domain::ports::validation::LanguageId;
 use mcb_utils::constants::lang::*;
 use mcb_utils::constants::lang::{CHUNK_SIZE_GENERIC, EXTENSION_LANG_MAP, LANG_CHUNK_SIZE_MAP};
 
@@ -23,6 +24,26 @@ pub fn language_from_extension(ext: &str) -> String {
         .map_or_else(|| LANG_UNKNOWN.to_owned(), |(_, lang)| (*lang).to_owned())
 }
 
+/// Detect the language of `file_path` from its extension, falling back to
+/// `content` (shebang and other heuristics) for files without one and to
+/// well-known file names (`Dockerfile`, `BUILD.bazel`).
+#[must_use]
+pub fn detect_language(file_path: &str, content: &str) -> String {
+    let path = std::path::Path::new(file_path);
+    let by_extension = language_from_extension(
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default(),
+    );
+    if by_extension != LANG_UNKNOWN {
+        return by_extension;
+    }
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| LanguageId::from_filename_or_content(name, Some(content)))
+        .map_or(by_extension, |lang| lang.name().to_owned())
+}
+
 /// Check if a language is supported for AST-based chunking
 #[must_use]
 pub fn is_language_supported(language: &str) -> bool {
@@ -7,8 +7,8 @@ use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::{ChunkingOptions, ChunkingResult, CodeChunker};
 use mcb_domain::value_objects::Language;
 
-use super::super::detection::{is_language_supported, language_from_extension};
-use super::processors::LANGUAGE_PROCESSORS;
+use super::super::detection::{detect_language, is_language_supported};
+use super::processors::{LANGUAGE_PROCESSORS, LINE_CHUNKERS};
 use mcb_utils::constants::lang::CHUNK_SIZE_GENERIC;
 
 /// Intelligent chunking engine using tree-sitter
@@ -45,10 +45,16 @@ impl IntelligentChunker {
                     );
                 }
             }
+        } else if let Some(chunker) = LINE_CHUNKERS.get(language) {
+            let chunks = chunker.extract_chunks(content, file_name, language);
+            if !chunks.is_empty() {
+                return chunks;
+            }
         }
         Self::chunk_generic(content, file_name, language)
     }
     /// Chunk code asynchronously (offloads to blocking thread)
+    #[cfg(not(target_arch = "wasm32"))]
     pub async fn chunk_code_async(
         &self,
         content: String,
@@ -126,14 +132,16 @@ impl CodeChunker for IntelligentChunker {
         file_path: &std::path::Path,
         _options: ChunkingOptions,
     ) -> Result<ChunkingResult> {
-        let content = tokio::fs::read_to_string(file_path)
-            .await
-            .map_err(|e| Error::io(e.to_string()))?;
+        #[cfg(not(target_arch = "wasm32"))]
+        let content = tokio::fs::read_to_string(file_path).await;
+        // No Tokio fs on wasm32; WASI hosts still provide `std::fs`.
+        #[cfg(target_arch = "wasm32")]
+        let content = std::fs::read_to_string(file_path);
+        let content = content.map_err(|e| Error::io(e.to_string()))?;
 
         let file_name = mcb_utils::utils::path::path_to_utf8_string(file_path)
             .map_err(|e| Error::io(e.to_string()))?;
-        let ext = file_path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        let language = language_from_extension(ext);
+        let language = detect_language(&file_name, &content);
 
         self.chunk_content(&content, &file_name, language, _options)
             .await
@@ -166,6 +174,10 @@ impl CodeChunker for IntelligentChunker {
         Ok(results)
     }
     fn supported_languages(&self) -> Vec<Language> {
-        LANGUAGE_PROCESSORS.keys().cloned().collect()
+        LANGUAGE_PROCESSORS
+            .keys()
+            .chain(LINE_CHUNKERS.keys())
+            .cloned()
+            .collect()
     }
 }
@@ -3,15 +3,16 @@
 //!
 //! Language processor registry
 //!
-//! Provides the static registry of language-specific processors.
+//! Provides the static registries of language-specific processors and
+//! line-structured chunkers.
 
 use std::collections::HashMap;
 use std::sync::LazyLock;
 
 use crate::language::{
-    CProcessor, CSharpProcessor, CppProcessor, GoProcessor, JavaProcessor, JavaScriptProcessor,
-    KotlinProcessor, LanguageProcessor, PhpProcessor, PythonProcessor, RubyProcessor,
-    RustProcessor, SwiftProcessor,
+    CProcessor, CSharpProcessor, CppProcessor, DockerfileChunker, GoProcessor, JavaProcessor,
+    JavaScriptProcessor, KotlinProcessor, LanguageProcessor, LineChunker, MakefileChunker,
+    PhpProcessor, PythonProcessor, RubyProcessor, RustProcessor, ShellChunker, SwiftProcessor,
 };
 
 /// Language processor registry
@@ -42,3 +43,17 @@ fn build_processors() -> HashMap<String, BoxedProcessor> {
         .map(|(name, processor)| (name.to_owned(), processor))
         .collect()
 }
+
+/// Line-structured chunker registry, for languages without a tree-sitter grammar
+pub(crate) static LINE_CHUNKERS: LazyLock<HashMap<String, Box<dyn LineChunker>>> =
+    LazyLock::new(|| {
+        let entries: [(&str, Box<dyn LineChunker>); 3] = [
+            ("dockerfile", Box::new(DockerfileChunker::new())),
+            ("makefile", Box::new(MakefileChunker::new())),
+            ("shell", Box::new(ShellChunker::new())),
+        ];
+        entries
+            .into_iter()
+            .map(|(name, chunker)| (name.to_owned(), chunker))
+            .collect()
+    });
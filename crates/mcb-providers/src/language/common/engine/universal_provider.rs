@@ -5,9 +5,12 @@
 //!
 //! Provides a single provider that supports all languages by delegating to the `IntelligentChunker`.
 
+#[cfg(feature = "native")]
 use std::sync::Arc;
 
+#[cfg(feature = "native")]
 use mcb_domain::ports::LanguageChunkingProvider as LanguageProviderPort;
+#[cfg(feature = "native")]
 use mcb_domain::registry::language::LanguageProviderConfig;
 
 use super::IntelligentChunker;
@@ -44,14 +47,27 @@ impl mcb_domain::ports::LanguageChunkingProvider for UniversalLanguageChunkingPr
 
     fn extensions(&self) -> &[&'static str] {
         &[
-            "rs", "py", "js", "ts", "java", "go", "c", "cpp", "cs", "rb", "php", "swift", "kt",
+            "rs",
+            "py",
+            "js",
+            "ts",
+            "java",
+            "go",
+            "c",
+            "cpp",
+            "cs",
+            "rb",
+            "php",
+            "swift",
+            "kt",
+            "sh",
+            "dockerfile",
+            "mk",
         ]
     }
 
     fn chunk(&self, content: &str, file_path: &str) -> Vec<mcb_domain::entities::CodeChunk> {
-        let path = std::path::Path::new(file_path);
-        let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
-        let language = super::super::detection::language_from_extension(ext);
+        let language = super::super::detection::detect_language(file_path, content);
         self.chunker.chunk_code(content, file_path, &language)
     }
 
@@ -65,12 +81,14 @@ impl mcb_domain::ports::LanguageChunkingProvider for UniversalLanguageChunkingPr
 // ============================================================================
 
 /// Factory function for creating universal language chunking provider instances.
+#[cfg(feature = "native")]
 fn universal_language_factory(
     _config: &LanguageProviderConfig,
 ) -> mcb_domain::error::Result<Arc<dyn LanguageProviderPort>> {
     Ok(Arc::new(UniversalLanguageChunkingProvider::new()))
 }
 
+#[cfg(feature = "native")]
 mcb_domain::register_language_provider!(
     mcb_utils::constants::DEFAULT_LANGUAGE_PROVIDER,
     "Universal language chunker supporting all languages via tree-sitter",
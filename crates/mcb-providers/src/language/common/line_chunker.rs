@@ -0,0 +1,156 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../../docs/modules/providers.md)
+//!
+//! Line-structured chunking
+//!
+//! Dockerfiles, Makefiles and shell scripts have no tree-sitter grammar in
+//! the workspace; their chunkers split them on their own line structure
+//! (stages, rules, functions) instead of fixed-size windows.
+
+use std::collections::HashMap;
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::value_objects::Language;
+use mcb_utils::constants::keys::METADATA_KEY_SYMBOL_NAME;
+use mcb_utils::constants::lang::LINE_CHUNK_MAX_LINES;
+
+/// A run of lines forming one unit of a file: a stage, a rule, a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Section {
+    /// First line (0-based).
+    pub start: usize,
+    /// Last line (0-based, inclusive).
+    pub end: usize,
+    /// Unit kind, stored as the chunk's `node_type`.
+    pub kind: &'static str,
+    /// Stage, target or function name, stored as its `symbol_name`.
+    pub name: Option<String>,
+}
+
+/// Trait for chunkers that split a file by its line structure
+pub trait LineChunker: Send + Sync {
+    /// Split `lines` into ordered, non-overlapping sections.
+    ///
+    /// Returning no sections makes the caller fall back to generic chunking.
+    fn sections(&self, lines: &[&str]) -> Vec<Section>;
+
+    /// Chunk `content` along [`Self::sections`].
+    ///
+    /// Blank lines around a section are dropped, and a section longer than
+    /// [`LINE_CHUNK_MAX_LINES`] is cut at blank lines into several chunks
+    /// sharing its kind and name.
+    fn extract_chunks(
+        &self,
+        content: &str,
+        file_name: &str,
+        language: &Language,
+    ) -> Vec<CodeChunk> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut chunks = Vec::new();
+        for section in self.sections(&lines) {
+            let Some((start, end)) = trim_blank(&lines, section.start, section.end) else {
+                continue;
+            };
+            let pieces = split_long(&lines, start, end, LINE_CHUNK_MAX_LINES);
+            for (start, end) in pieces
+                .into_iter()
+                .filter_map(|(start, end)| trim_blank(&lines, start, end))
+            {
+                let chunk_index = chunks.len();
+                let mut meta = HashMap::new();
+                meta.insert("file".to_owned(), serde_json::json!(file_name));
+                meta.insert("node_type".to_owned(), serde_json::json!(section.kind));
+                if let Some(name) = &section.name {
+                    meta.insert(METADATA_KEY_SYMBOL_NAME.to_owned(), serde_json::json!(name));
+                }
+                chunks.push(CodeChunk {
+                    id: format!("{file_name}_{}_{start}_{end}_{chunk_index}", section.kind),
+                    content: lines[start..=end].join("\n"),
+                    file_path: file_name.to_owned(),
+                    start_line: start as u32,
+                    end_line: end as u32,
+                    language: language.clone(),
+                    metadata: serde_json::to_value(meta).unwrap_or(serde_json::json!({})),
+                });
+            }
+        }
+        chunks
+    }
+}
+
+/// Move `start` up over the `#` comment lines directly above it (not a
+/// shebang), staying at or below `floor`, so a unit keeps its comment.
+#[must_use]
+pub fn with_leading_comments(lines: &[&str], start: usize, floor: usize) -> usize {
+    let is_comment = |line: &str| {
+        let line = line.trim_start();
+        line.starts_with('#') && !line.starts_with("#!")
+    };
+    let mut first = start;
+    while first > floor && is_comment(lines[first - 1]) {
+        first -= 1;
+    }
+    first
+}
+
+/// Cover the lines between `units` (and around them) with sections of
+/// `kind`, so top-level code outside every unit is still indexed.
+#[must_use]
+pub fn fill_gaps(units: Vec<Section>, line_count: usize, kind: &'static str) -> Vec<Section> {
+    let mut sections = Vec::with_capacity(units.len() * 2 + 1);
+    let mut next = 0;
+    for unit in units {
+        if unit.start > next {
+            sections.push(Section {
+                start: next,
+                end: unit.start - 1,
+                kind,
+                name: None,
+            });
+        }
+        next = unit.end + 1;
+        sections.push(unit);
+    }
+    if next < line_count {
+        sections.push(Section {
+            start: next,
+            end: line_count - 1,
+            kind,
+            name: None,
+        });
+    }
+    sections
+}
+
+fn trim_blank(lines: &[&str], start: usize, end: usize) -> Option<(usize, usize)> {
+    let is_text = |i: &usize| !lines[*i].trim().is_empty();
+    let first = (start..=end).find(is_text)?;
+    let last = (first..=end).rev().find(is_text)?;
+    Some((first, last))
+}
+
+fn split_long(lines: &[&str], start: usize, end: usize, max_lines: usize) -> Vec<(usize, usize)> {
+    let mut pieces = Vec::new();
+    let mut start = start;
+    while end + 1 - start > max_lines {
+        let limit = start + max_lines - 1;
+        match (start + 1..=limit)
+            .rev()
+            .find(|&i| lines[i].trim().is_empty())
+        {
+            Some(blank) => {
+                pieces.push((start, blank - 1));
+                start = blank + 1;
+            }
+            None => {
+                pieces.push((start, limit));
+                start = limit + 1;
+            }
+        }
+        while start < end && lines[start].trim().is_empty() {
+            start += 1;
+        }
+    }
+    pieces.push((start, end));
+    pieces
+}
@@ -8,10 +8,12 @@
 pub mod config;
 pub mod detection;
 pub mod engine;
+pub mod line_chunker;
 pub mod processor;
 pub mod traverser;
 
 // Re-export commonly used types
 pub use config::{LanguageConfig, NodeExtractionRule, NodeExtractionRuleBuilder};
+pub use line_chunker::{LineChunker, Section};
 pub use processor::{BaseProcessor, LanguageProcessor};
 pub use traverser::AstTraverser;
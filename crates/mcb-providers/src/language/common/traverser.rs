@@ -11,6 +11,7 @@ use std::collections::HashMap;
 use mcb_domain::entities::CodeChunk;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::value_objects::Language;
+use mcb_utils::constants::keys::METADATA_KEY_SYMBOL_NAME;
 
 use super::config::NodeExtractionRule;
 
@@ -23,6 +24,7 @@ struct ChunkParams<'a> {
     depth: usize,
     priority: i32,
     chunk_index: usize,
+    symbol_name: Option<String>,
 }
 
 /// Context for extracting a chunk from a node
@@ -231,6 +233,10 @@ impl<'a> AstTraverser<'a> {
             depth: ctx.depth,
             priority: ctx.rule.priority,
             chunk_index: ctx.chunk_index,
+            symbol_name: node
+                .child_by_field_name("name")
+                .and_then(|name| name.utf8_text(ctx.content.as_bytes()).ok())
+                .map(str::to_owned),
         };
         let mut chunk = self.create_chunk_from_node(node, chunk_params);
 
@@ -269,6 +275,12 @@ impl<'a> AstTraverser<'a> {
                 meta.insert("node_type".to_owned(), serde_json::json!(params.node_type));
                 meta.insert("depth".to_owned(), serde_json::json!(params.depth));
                 meta.insert("priority".to_owned(), serde_json::json!(params.priority));
+                if let Some(symbol_name) = params.symbol_name {
+                    meta.insert(
+                        METADATA_KEY_SYMBOL_NAME.to_owned(),
+                        serde_json::json!(symbol_name),
+                    );
+                }
                 serde_json::to_value(meta).unwrap_or(serde_json::json!({}))
             },
         }
@@ -0,0 +1,63 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md)
+//!
+//! Dockerfile chunker: one chunk per build stage.
+
+use super::common::line_chunker::{LineChunker, Section, with_leading_comments};
+
+/// Splits a Dockerfile at every `FROM`, so each build stage is one chunk
+/// named after its `AS` alias (or its base image).
+///
+/// Lines before the first `FROM` (`ARG`s, the `syntax` directive) belong to
+/// the first stage; long stages are cut at the blank lines between their
+/// instruction groups.
+#[derive(Debug, Default)]
+pub struct DockerfileChunker;
+
+impl DockerfileChunker {
+    /// Create a new Dockerfile chunker
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl LineChunker for DockerfileChunker {
+    fn sections(&self, lines: &[&str]) -> Vec<Section> {
+        let mut sections: Vec<Section> = Vec::new();
+        for (i, line) in lines.iter().enumerate() {
+            let Some(name) = stage_name(line) else {
+                continue;
+            };
+            let start = match sections.last_mut() {
+                None => 0,
+                Some(previous) => {
+                    let start = with_leading_comments(lines, i, previous.start + 1);
+                    previous.end = start - 1;
+                    start
+                }
+            };
+            sections.push(Section {
+                start,
+                end: lines.len() - 1,
+                kind: "stage",
+                name: Some(name),
+            });
+        }
+        sections
+    }
+}
+
+/// Stage name of a `FROM [--platform=...] <image> [AS <name>]` line.
+fn stage_name(line: &str) -> Option<String> {
+    let mut words = line.split_whitespace();
+    if !words.next()?.eq_ignore_ascii_case("from") {
+        return None;
+    }
+    let mut words = words.skip_while(|word| word.starts_with("--"));
+    let image = words.next()?;
+    Some(match (words.next(), words.next()) {
+        (Some(keyword), Some(alias)) if keyword.eq_ignore_ascii_case("as") => alias.to_owned(),
+        _ => image.to_owned(),
+    })
+}
@@ -0,0 +1,72 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md)
+//!
+//! Makefile chunker: one chunk per rule.
+
+use super::common::line_chunker::{LineChunker, Section, fill_gaps, with_leading_comments};
+
+/// Splits a Makefile into its rules (target line plus recipe), named after
+/// their targets.
+///
+/// Variables, `include`s, conditionals, `define` blocks and special targets
+/// such as `.PHONY` between the rules are chunked as `variables`.
+#[derive(Debug, Default)]
+pub struct MakefileChunker;
+
+impl MakefileChunker {
+    /// Create a new Makefile chunker
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl LineChunker for MakefileChunker {
+    fn sections(&self, lines: &[&str]) -> Vec<Section> {
+        let mut rules: Vec<Section> = Vec::new();
+        let mut in_define = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let line = lines[i];
+            let word = line.split_whitespace().next();
+            if in_define {
+                in_define = word != Some("endef");
+            } else if matches!(word, Some("define")) {
+                in_define = true;
+            } else if let Some(targets) = rule_targets(line) {
+                let floor = rules.last().map_or(0, |rule| rule.end + 1);
+                let start = with_leading_comments(lines, i, floor);
+                let mut end = i;
+                while end + 1 < lines.len()
+                    && (lines[end + 1].starts_with('\t') || lines[end].ends_with('\\'))
+                {
+                    end += 1;
+                }
+                rules.push(Section {
+                    start,
+                    end,
+                    kind: "target",
+                    name: Some(targets.to_owned()),
+                });
+                i = end;
+            }
+            i += 1;
+        }
+        if rules.is_empty() {
+            return rules;
+        }
+        fill_gaps(rules, lines.len(), "variables")
+    }
+}
+
+/// Targets of a rule line (`build test: deps`), or `None` for recipes,
+/// comments, assignments (`CC := gcc`) and special targets (`.PHONY`).
+fn rule_targets(line: &str) -> Option<&str> {
+    if line.starts_with(char::is_whitespace) || line.starts_with('#') {
+        return None;
+    }
+    let (targets, rest) = line.split_once(':')?;
+    let targets = targets.trim();
+    let assignment = targets.contains('=') || rest.starts_with('=') || rest.starts_with(":=");
+    (!assignment && !targets.is_empty() && !targets.starts_with('.')).then_some(targets)
+}
@@ -29,6 +29,14 @@
 //! | PhpProcessor | PHP | Complete |
 //! | SwiftProcessor | Swift | Complete |
 //! | KotlinProcessor | Kotlin | Complete |
+//!
+//! Languages without a tree-sitter grammar are split by their line structure:
+//!
+//! | Chunker | Language | Unit |
+//! | --------- | ---------- | ------ |
+//! | DockerfileChunker | Dockerfile | Build stage |
+//! | MakefileChunker | Makefile | Rule |
+//! | ShellChunker | Shell | Function |
 
 /// Common utilities and base types for language processors
 pub mod common;
@@ -47,11 +55,19 @@ pub mod ruby;
 pub mod rust;
 pub mod swift;
 
+// Line-structured chunkers
+pub mod dockerfile;
+pub mod makefile;
+pub mod shell;
+
 pub use common::detection::{
-    get_chunk_size, is_language_supported, language_from_extension, supported_languages,
+    detect_language, get_chunk_size, is_language_supported, language_from_extension,
+    supported_languages,
 };
 pub use common::engine::{IntelligentChunker, UniversalLanguageChunkingProvider};
-pub use common::{BaseProcessor, LanguageConfig, LanguageProcessor, NodeExtractionRule};
+pub use common::{
+    BaseProcessor, LanguageConfig, LanguageProcessor, LineChunker, NodeExtractionRule,
+};
 // Languages
 pub use c::CProcessor;
 pub use cpp::CppProcessor;
@@ -65,3 +81,7 @@ pub use python::PythonProcessor;
 pub use ruby::RubyProcessor;
 pub use rust::RustProcessor;
 pub use swift::SwiftProcessor;
+// Line-structured languages
+pub use dockerfile::DockerfileChunker;
+pub use makefile::MakefileChunker;
+pub use shell::ShellChunker;
@@ -0,0 +1,95 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md)
+//!
+//! Shell script chunker: one chunk per function.
+
+use super::common::line_chunker::{LineChunker, Section, fill_gaps, with_leading_comments};
+
+/// Splits a shell script into its functions (`name() {`, `function name {`),
+/// each with the comment above it.
+///
+/// A function ends at the first `}` line indented no deeper than its header.
+/// Top-level commands between functions are chunked as `script`.
+#[derive(Debug, Default)]
+pub struct ShellChunker;
+
+impl ShellChunker {
+    /// Create a new shell script chunker
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl LineChunker for ShellChunker {
+    fn sections(&self, lines: &[&str]) -> Vec<Section> {
+        let mut functions: Vec<Section> = Vec::new();
+        let mut i = 0;
+        while i < lines.len() {
+            let Some(name) = function_name(lines, i) else {
+                i += 1;
+                continue;
+            };
+            let floor = functions.last().map_or(0, |function| function.end + 1);
+            let end = function_end(lines, i);
+            functions.push(Section {
+                start: with_leading_comments(lines, i, floor),
+                end,
+                kind: "function",
+                name: Some(name.to_owned()),
+            });
+            i = end + 1;
+        }
+        if functions.is_empty() {
+            return functions;
+        }
+        fill_gaps(functions, lines.len(), "script")
+    }
+}
+
+/// Name of the function whose header is line `i`: `name() {`,
+/// `function name {` or `function name()`, with `{` here or on the next line.
+fn function_name<'a>(lines: &[&'a str], i: usize) -> Option<&'a str> {
+    let header = lines[i].trim();
+    let (name, rest) = match header.strip_prefix("function ") {
+        Some(rest) => {
+            let rest = rest.trim_start();
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '(' || c == '{')
+                .unwrap_or(rest.len());
+            let after = rest[end..].trim_start();
+            (&rest[..end], after.strip_prefix("()").unwrap_or(after))
+        }
+        None => {
+            let (name, rest) = header.split_once('(')?;
+            (name.trim_end(), rest.trim_start().strip_prefix(')')?)
+        }
+    };
+    let is_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-:.".contains(c));
+    let rest = rest.trim_start();
+    let opens = rest.starts_with('{')
+        || (rest.is_empty()
+            && lines
+                .get(i + 1)
+                .is_some_and(|next| next.trim_start().starts_with('{')));
+    (is_name && opens).then_some(name)
+}
+
+/// Last line of the function whose header is line `i`.
+fn function_end(lines: &[&str], i: usize) -> usize {
+    let header = lines[i].trim_end();
+    if header.ends_with('}') && header.contains('{') {
+        return i;
+    }
+    let indent = indentation(lines[i]);
+    (i + 1..lines.len())
+        .find(|&j| lines[j].trim_start().starts_with('}') && indentation(lines[j]) <= indent)
+        .unwrap_or(lines.len() - 1)
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
@@ -27,6 +27,14 @@
 //! mcb-providers = { version = "0.1", default-features = false, features = ["embedding-ollama", "cache-moka"] }
 //! ```
 //!
+//! The default `native` feature enables every module that needs the network,
+//! a database, git or the provider registry. Without it only `language` and
+//! `hybrid_search` are built, which also compile to `wasm32-unknown-unknown`
+//! (see `mcb-wasm`).
+//!
+//! The `testkit` feature adds [`testkit`], deterministic port implementations
+//! for downstream integration tests.
+//!
 //! ## Usage
 //!
 //! ```no_run
@@ -40,19 +48,23 @@
 pub mod macros;
 
 /// Shared utilities for provider implementations
+#[cfg(feature = "native")]
 pub mod utils;
 
 /// Embedding provider implementations
 ///
 /// Implements `EmbeddingProvider` trait for various embedding APIs.
+#[cfg(feature = "native")]
 pub mod embedding;
 
 /// Vector store provider implementations
 ///
 /// Implements `VectorStoreProvider` trait for vector storage backends.
+#[cfg(feature = "native")]
 pub mod vector_store;
 
 /// Native PMAT-style analysis provider implementations.
+#[cfg(feature = "native")]
 pub mod analysis;
 
 /// Language chunking provider implementations
@@ -71,18 +83,29 @@ pub mod hybrid_search;
 
 /// Database providers — `SeaORM` repositories for structured persistence.
 /// Database-agnostic (`SQLite` + `PostgreSQL` via connection string).
+#[cfg(feature = "native")]
 pub mod database;
 
 // database::migration re-exported at crate root via exports.rs
 
 /// Project type detection providers
+#[cfg(feature = "native")]
 pub mod project_detection;
 
 /// Git-related providers for repository operations
 ///
 /// Provides submodule discovery with recursive traversal.
+#[cfg(feature = "native")]
 pub mod vcs;
 /// Workflow FSM provider for ADR-034
 ///
 /// Implements state machine transitions and session management
+#[cfg(feature = "native")]
 pub mod workflow;
+
+/// Deterministic provider fixtures for integration tests
+///
+/// Fake embeddings, a recording in-memory vector store and a flaky HTTP
+/// client, usable without network access.
+#[cfg(feature = "testkit")]
+pub mod testkit;
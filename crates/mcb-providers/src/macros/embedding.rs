@@ -24,7 +24,8 @@ macro_rules! define_http_embedding_provider {
 #[macro_export]
 /// Implement standard base methods for HTTP embedding providers.
 ///
-/// Adds `new()`, `base_url()`, and `model()` methods that delegate to `HttpEmbeddingClient`.
+/// Adds `new()`, `with_resilience()`, `with_signer()`, `base_url()`, and `model()` methods
+/// that delegate to `HttpEmbeddingClient`.
 macro_rules! impl_http_provider_base {
     (
         $struct_name:ident,
@@ -49,11 +50,29 @@ macro_rules! impl_http_provider_base {
                             model,
                             timeout,
                             client: http_client,
+                            resilience: mcb_domain::ports::ResiliencePolicy::default(),
                         },
                     ),
                 }
             }
 
+            /// Apply a timeout and retry policy to every request
+            #[must_use]
+            pub fn with_resilience(mut self, policy: mcb_domain::ports::ResiliencePolicy) -> Self {
+                self.client.set_resilience(policy);
+                self
+            }
+
+            /// Sign every request with `signer` (e.g. for an authenticating gateway)
+            #[must_use]
+            pub fn with_signer(
+                mut self,
+                signer: Option<std::sync::Arc<dyn mcb_domain::ports::RequestSigner>>,
+            ) -> Self {
+                self.client.set_signer(signer);
+                self
+            }
+
             /// Get the base URL for this provider
             #[must_use]
             pub fn base_url(&self) -> &str {
@@ -124,13 +143,17 @@ macro_rules! register_http_provider {
 
             let cfg = create_http_provider_config(config, $config_name, $default_model)?;
 
-            Ok(std::sync::Arc::new($struct_name::new(
-                &cfg.api_key,
-                cfg.base_url,
-                cfg.model,
-                cfg.timeout,
-                cfg.client,
-            )))
+            Ok(std::sync::Arc::new(
+                $struct_name::new(
+                    &cfg.api_key,
+                    cfg.base_url,
+                    cfg.model,
+                    cfg.timeout,
+                    cfg.client,
+                )
+                .with_resilience(cfg.resilience)
+                .with_signer(cfg.signer),
+            ))
         }
 
         mcb_domain::register_embedding_provider!($provider_slug, $description, $factory_fn);
@@ -249,12 +272,8 @@ macro_rules! define_standard_embedding_provider {
                     kind: $crate::utils::http::RequestErrorKind::Embedding,
                     headers: &headers,
                     body: Some(&payload),
-                    retry: Some($crate::utils::http::RetryConfig::new(
-                        mcb_utils::constants::http::PROVIDER_RETRY_COUNT,
-                        std::time::Duration::from_millis(
-                            mcb_utils::constants::http::PROVIDER_RETRY_BACKOFF_MS,
-                        ),
-                    )),
+                    retry: Some(self.client.retry_config()),
+                    signer: self.client.signer(),
                 })
                 .await
             }
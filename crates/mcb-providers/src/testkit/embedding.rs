@@ -0,0 +1,95 @@
+//! Hash-based embedding provider.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use mcb_domain::error::Result;
+use mcb_domain::ports::EmbeddingProvider;
+use mcb_domain::value_objects::Embedding;
+
+use super::lock;
+use crate::hybrid_search::BM25Scorer;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Deterministic embedding provider for tests.
+///
+/// Each token of the text (as split by [`BM25Scorer::tokenize`]) is hashed
+/// with FNV-1a into one dimension with a hash-derived sign, and the sum is
+/// L2-normalized. Vectors are stable across runs, platforms and Rust
+/// versions, and texts sharing words have a positive cosine similarity.
+/// Text without any token embeds to the zero vector.
+#[derive(Debug)]
+pub struct FakeEmbeddingProvider {
+    dimensions: usize,
+    batches: Mutex<Vec<usize>>,
+}
+
+impl FakeEmbeddingProvider {
+    /// Model name reported in every [`Embedding`].
+    pub const MODEL_NAME: &'static str = "fake-embedding";
+
+    /// Create a provider producing `dimensions`-dimensional vectors.
+    #[must_use]
+    pub fn new(dimensions: usize) -> Self {
+        Self {
+            dimensions,
+            batches: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Embed `text` without recording a batch.
+    #[must_use]
+    pub fn embed_text(&self, text: &str) -> Embedding {
+        let mut vector = vec![0.0_f32; self.dimensions];
+        if self.dimensions > 0 {
+            for token in BM25Scorer::tokenize(text) {
+                let hash = fnv1a(token.as_bytes());
+                let slot = (hash % self.dimensions as u64) as usize;
+                vector[slot] += if hash >> 63 == 0 { 1.0 } else { -1.0 };
+            }
+        }
+
+        let norm = vector.iter().map(|value| value * value).sum::<f32>().sqrt();
+        if norm > 0.0 {
+            for value in &mut vector {
+                *value /= norm;
+            }
+        }
+
+        Embedding {
+            vector,
+            model: Self::MODEL_NAME.to_owned(),
+            dimensions: self.dimensions,
+        }
+    }
+
+    /// Size of every `embed_batch` call so far, in call order.
+    #[must_use]
+    pub fn batches(&self) -> Vec<usize> {
+        lock(&self.batches).clone()
+    }
+}
+
+#[async_trait]
+impl EmbeddingProvider for FakeEmbeddingProvider {
+    async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Embedding>> {
+        lock(&self.batches).push(texts.len());
+        Ok(texts.iter().map(|text| self.embed_text(text)).collect())
+    }
+
+    fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    fn provider_name(&self) -> &str {
+        Self::MODEL_NAME
+    }
+}
+
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(FNV_OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(FNV_PRIME)
+    })
+}
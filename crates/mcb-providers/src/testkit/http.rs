@@ -0,0 +1,95 @@
+//! Scripted HTTP client.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{HttpClientConfig, HttpClientProvider};
+
+use super::lock;
+
+/// A request received by [`FlakyHttpClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequestRecord {
+    /// `GET` or `POST`
+    pub method: &'static str,
+    /// Requested URL
+    pub url: String,
+    /// Request body; empty for `GET`
+    pub body: Vec<u8>,
+}
+
+/// HTTP client that fails its first requests, for exercising retry paths.
+///
+/// The first [`failing_first`](Self::failing_first) requests return a
+/// network error; every later one returns the configured response body.
+/// All requests, failed or not, are recorded.
+#[derive(Debug)]
+pub struct FlakyHttpClient {
+    config: HttpClientConfig,
+    response: Vec<u8>,
+    failures: usize,
+    requests: Mutex<Vec<HttpRequestRecord>>,
+}
+
+impl FlakyHttpClient {
+    /// Create a client answering every request with `response`.
+    #[must_use]
+    pub fn new(response: impl Into<Vec<u8>>) -> Self {
+        Self {
+            config: HttpClientConfig::default(),
+            response: response.into(),
+            failures: 0,
+            requests: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Fail the first `failures` requests with a network error.
+    #[must_use]
+    pub fn failing_first(mut self, failures: usize) -> Self {
+        self.failures = failures;
+        self
+    }
+
+    /// Every request received so far, in order.
+    #[must_use]
+    pub fn requests(&self) -> Vec<HttpRequestRecord> {
+        lock(&self.requests).clone()
+    }
+
+    fn respond(&self, method: &'static str, url: &str, body: &[u8]) -> Result<Vec<u8>> {
+        let mut requests = lock(&self.requests);
+        requests.push(HttpRequestRecord {
+            method,
+            url: url.to_owned(),
+            body: body.to_vec(),
+        });
+        let attempt = requests.len();
+        if attempt <= self.failures {
+            return Err(Error::network(format!(
+                "simulated failure {attempt}/{} for {method} {url}",
+                self.failures
+            )));
+        }
+        Ok(self.response.clone())
+    }
+}
+
+#[async_trait]
+impl HttpClientProvider for FlakyHttpClient {
+    fn config(&self) -> &HttpClientConfig {
+        &self.config
+    }
+
+    async fn get(&self, url: &str) -> Result<Vec<u8>> {
+        self.respond("GET", url, &[])
+    }
+
+    async fn post(&self, url: &str, body: &[u8]) -> Result<Vec<u8>> {
+        self.respond("POST", url, body)
+    }
+
+    fn is_enabled(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,39 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md#test-kit)
+//!
+//! Test Kit
+//!
+//! Deterministic, in-process implementations of the provider ports for
+//! integration tests that must not touch the network. Enabled by the
+//! `testkit` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! mcb-providers = { version = "0.3", features = ["testkit"] }
+//! ```
+//!
+//! - [`FakeEmbeddingProvider`]: hash-based embeddings; equal text always gives
+//!   the same vector and texts sharing words are similar.
+//! - [`RecordingVectorStore`]: in-memory brute-force vector store that logs
+//!   every port call.
+//! - [`FlakyHttpClient`]: scripted HTTP client that fails a configurable
+//!   number of requests before answering.
+
+/// Hash-based embedding provider.
+pub mod embedding;
+/// Scripted HTTP client.
+pub mod http;
+/// In-memory recording vector store.
+pub mod vector_store;
+
+pub use embedding::FakeEmbeddingProvider;
+pub use http::{FlakyHttpClient, HttpRequestRecord};
+pub use vector_store::{RecordingVectorStore, VectorStoreCall};
+
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Lock `mutex`, ignoring poisoning: a panicking test must not hide the
+/// state it left behind from the assertions of the next one.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,516 @@
+//! In-memory recording vector store.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
+};
+use mcb_utils::constants::vector_store::{
+    STATS_FIELD_COLLECTION, STATS_FIELD_PROVIDER, STATS_FIELD_VECTORS_COUNT, STATS_FILE_SCAN_LIMIT,
+    VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LANGUAGE,
+};
+use mcb_utils::utils::vector::cosine_similarity;
+use serde_json::Value;
+
+use super::lock;
+use crate::utils::vector_store::{insert_content_stats, search_result_from_json_metadata};
+
+const PROVIDER_NAME: &str = "recording";
+
+/// A port call received by [`RecordingVectorStore`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VectorStoreCall {
+    /// `collection_exists`
+    CollectionExists(String),
+    /// `get_stats`
+    GetStats(String),
+    /// `flush`
+    Flush(String),
+    /// `list_collections`
+    ListCollections,
+    /// `list_file_paths`
+    ListFilePaths {
+        /// Target collection
+        collection: String,
+        /// Requested page size
+        limit: usize,
+    },
+    /// `get_chunks_by_file`
+    GetChunksByFile {
+        /// Target collection
+        collection: String,
+        /// Requested file
+        file_path: String,
+    },
+    /// `create_collection`
+    CreateCollection {
+        /// Target collection
+        collection: String,
+        /// Requested dimensions
+        dimensions: usize,
+    },
+    /// `delete_collection`
+    DeleteCollection(String),
+    /// `insert_vectors`
+    InsertVectors {
+        /// Target collection
+        collection: String,
+        /// Number of vectors in the batch
+        count: usize,
+    },
+    /// `upsert_vectors`
+    UpsertVectors {
+        /// Target collection
+        collection: String,
+        /// Upserted IDs
+        ids: Vec<String>,
+    },
+    /// `search_similar`
+    SearchSimilar {
+        /// Target collection
+        collection: String,
+        /// Requested result count
+        limit: usize,
+        /// Whether a scope filter was passed
+        filtered: bool,
+    },
+    /// `delete_vectors`
+    DeleteVectors {
+        /// Target collection
+        collection: String,
+        /// Deleted IDs
+        ids: Vec<String>,
+    },
+    /// `get_vectors_by_ids`
+    GetVectorsByIds {
+        /// Target collection
+        collection: String,
+        /// Requested IDs
+        ids: Vec<String>,
+    },
+    /// `list_vectors`
+    ListVectors {
+        /// Target collection
+        collection: String,
+        /// Requested page size
+        limit: usize,
+    },
+    /// `inspect_chunk`
+    InspectChunk {
+        /// Target collection
+        collection: String,
+        /// Requested ID
+        id: String,
+    },
+}
+
+#[derive(Debug)]
+struct StoredVector {
+    id: String,
+    vector: Vec<f32>,
+    metadata: Value,
+}
+
+/// Group `vectors` by file path, in path order, keeping the first `limit` files.
+fn file_infos(vectors: &[StoredVector], limit: usize) -> Vec<FileInfo> {
+    let mut files: BTreeMap<&str, (u32, &str)> = BTreeMap::new();
+    for stored in vectors {
+        let Some(path) = stored
+            .metadata
+            .get(VECTOR_FIELD_FILE_PATH)
+            .and_then(Value::as_str)
+        else {
+            continue;
+        };
+        let language = stored
+            .metadata
+            .get(VECTOR_FIELD_LANGUAGE)
+            .and_then(Value::as_str)
+            .unwrap_or("unknown");
+        files.entry(path).or_insert((0, language)).0 += 1;
+    }
+    files
+        .into_iter()
+        .take(limit)
+        .map(|(path, (chunks, language))| FileInfo::new(path, chunks, language, None))
+        .collect()
+}
+
+#[derive(Debug, Default)]
+struct State {
+    collections: BTreeMap<String, Vec<StoredVector>>,
+    next_id: u64,
+}
+
+/// In-memory vector store that records every port call.
+///
+/// Search is brute-force cosine similarity, so results are exact and ties
+/// keep insertion order. Inserted vectors get IDs `vec-1`, `vec-2`, … in
+/// insertion order across all collections; upserts replace the record with
+/// the same ID in place. Scope filters are recorded but not applied,
+/// which the port allows. Operations on a missing collection fail like the
+/// real providers do.
+#[derive(Debug, Default)]
+pub struct RecordingVectorStore {
+    state: Mutex<State>,
+    calls: Mutex<Vec<VectorStoreCall>>,
+}
+
+impl RecordingVectorStore {
+    /// Create an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Every call received so far, in order.
+    #[must_use]
+    pub fn calls(&self) -> Vec<VectorStoreCall> {
+        lock(&self.calls).clone()
+    }
+
+    /// Forget the recorded calls, keeping the stored vectors.
+    pub fn clear_calls(&self) {
+        lock(&self.calls).clear();
+    }
+
+    /// Number of vectors stored in `collection`, or 0 when it does not exist.
+    #[must_use]
+    pub fn vector_count(&self, collection: &CollectionId) -> usize {
+        lock(&self.state)
+            .collections
+            .get(&collection.to_string())
+            .map_or(0, Vec::len)
+    }
+
+    fn record(&self, call: VectorStoreCall) {
+        lock(&self.calls).push(call);
+    }
+
+    /// Run `f` on the vectors of an existing collection.
+    fn with_collection<T>(
+        &self,
+        collection: &CollectionId,
+        f: impl FnOnce(&mut Vec<StoredVector>) -> T,
+    ) -> Result<T> {
+        let name = collection.to_string();
+        let mut state = lock(&self.state);
+        let vectors = state
+            .collections
+            .get_mut(&name)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{name}' not found")))?;
+        Ok(f(vectors))
+    }
+}
+
+#[async_trait]
+impl VectorStoreAdmin for RecordingVectorStore {
+    async fn collection_exists(&self, collection: &CollectionId) -> Result<bool> {
+        self.record(VectorStoreCall::CollectionExists(collection.to_string()));
+        Ok(lock(&self.state)
+            .collections
+            .contains_key(&collection.to_string()))
+    }
+
+    async fn get_stats(&self, collection: &CollectionId) -> Result<HashMap<String, Value>> {
+        self.record(VectorStoreCall::GetStats(collection.to_string()));
+        let (count, files) = self.with_collection(collection, |vectors| {
+            (vectors.len(), file_infos(vectors, STATS_FILE_SCAN_LIMIT))
+        })?;
+        let mut stats = HashMap::from([
+            (
+                STATS_FIELD_COLLECTION.to_owned(),
+                Value::from(collection.to_string()),
+            ),
+            (STATS_FIELD_PROVIDER.to_owned(), Value::from(PROVIDER_NAME)),
+            (STATS_FIELD_VECTORS_COUNT.to_owned(), Value::from(count)),
+        ]);
+        insert_content_stats(&mut stats, &files);
+        Ok(stats)
+    }
+
+    async fn flush(&self, collection: &CollectionId) -> Result<()> {
+        self.record(VectorStoreCall::Flush(collection.to_string()));
+        Ok(())
+    }
+
+    fn provider_name(&self) -> &str {
+        PROVIDER_NAME
+    }
+
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        VectorStoreCapabilities {
+            atomic_upsert: true,
+            chunk_inspection: true,
+            ..VectorStoreCapabilities::default()
+        }
+    }
+}
+
+#[async_trait]
+impl VectorStoreBrowser for RecordingVectorStore {
+    async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        self.record(VectorStoreCall::ListCollections);
+        let state = lock(&self.state);
+        Ok(state
+            .collections
+            .iter()
+            .map(|(name, vectors)| {
+                let files: HashSet<&str> = vectors
+                    .iter()
+                    .filter_map(|v| v.metadata.get(VECTOR_FIELD_FILE_PATH)?.as_str())
+                    .collect();
+                CollectionInfo::new(
+                    name.clone(),
+                    vectors.len() as u64,
+                    files.len() as u64,
+                    None,
+                    PROVIDER_NAME,
+                )
+            })
+            .collect())
+    }
+
+    async fn list_file_paths(
+        &self,
+        collection: &CollectionId,
+        limit: usize,
+    ) -> Result<Vec<FileInfo>> {
+        self.record(VectorStoreCall::ListFilePaths {
+            collection: collection.to_string(),
+            limit,
+        });
+        self.with_collection(collection, |vectors| file_infos(vectors, limit))
+    }
+
+    async fn get_chunks_by_file(
+        &self,
+        collection: &CollectionId,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        self.record(VectorStoreCall::GetChunksByFile {
+            collection: collection.to_string(),
+            file_path: file_path.to_owned(),
+        });
+        let mut results = self.with_collection(collection, |vectors| {
+            vectors
+                .iter()
+                .filter(|v| {
+                    v.metadata
+                        .get(VECTOR_FIELD_FILE_PATH)
+                        .and_then(Value::as_str)
+                        == Some(file_path)
+                })
+                .map(|v| search_result_from_json_metadata(v.id.clone(), &v.metadata, 1.0))
+                .collect::<Vec<_>>()
+        })?;
+        results.sort_by_key(|r| r.start_line);
+        Ok(results)
+    }
+}
+
+#[async_trait]
+impl VectorStoreProvider for RecordingVectorStore {
+    async fn create_collection(&self, collection: &CollectionId, dimensions: usize) -> Result<()> {
+        self.record(VectorStoreCall::CreateCollection {
+            collection: collection.to_string(),
+            dimensions,
+        });
+        lock(&self.state)
+            .collections
+            .entry(collection.to_string())
+            .or_default();
+        Ok(())
+    }
+
+    async fn delete_collection(&self, collection: &CollectionId) -> Result<()> {
+        self.record(VectorStoreCall::DeleteCollection(collection.to_string()));
+        lock(&self.state)
+            .collections
+            .remove(&collection.to_string());
+        Ok(())
+    }
+
+    async fn insert_vectors(
+        &self,
+        collection: &CollectionId,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.record(VectorStoreCall::InsertVectors {
+            collection: collection.to_string(),
+            count: vectors.len(),
+        });
+        if vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(format!(
+                "{} vectors but {} metadata entries",
+                vectors.len(),
+                metadata.len()
+            )));
+        }
+        let name = collection.to_string();
+        let mut guard = lock(&self.state);
+        let state = &mut *guard;
+        let stored = state
+            .collections
+            .get_mut(&name)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{name}' not found")))?;
+        let mut ids = Vec::with_capacity(vectors.len());
+        for (embedding, metadata) in vectors.iter().zip(metadata) {
+            state.next_id += 1;
+            let id = format!("vec-{}", state.next_id);
+            stored.push(StoredVector {
+                id: id.clone(),
+                vector: embedding.vector.clone(),
+                metadata: Value::Object(metadata.into_iter().collect()),
+            });
+            ids.push(id);
+        }
+        Ok(ids)
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        self.record(VectorStoreCall::UpsertVectors {
+            collection: collection.to_string(),
+            ids: ids.to_vec(),
+        });
+        if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+            return Err(Error::invalid_argument(format!(
+                "{} ids, {} vectors and {} metadata entries",
+                ids.len(),
+                vectors.len(),
+                metadata.len()
+            )));
+        }
+        self.with_collection(collection, |stored| {
+            for ((id, embedding), metadata) in ids.iter().zip(vectors).zip(metadata) {
+                let record = StoredVector {
+                    id: id.clone(),
+                    vector: embedding.vector.clone(),
+                    metadata: Value::Object(metadata.into_iter().collect()),
+                };
+                match stored.iter_mut().find(|v| v.id == *id) {
+                    Some(existing) => *existing = record,
+                    None => stored.push(record),
+                }
+            }
+            ids.to_vec()
+        })
+    }
+
+    async fn search_similar(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchScope>,
+    ) -> Result<Vec<SearchResult>> {
+        self.record(VectorStoreCall::SearchSimilar {
+            collection: collection.to_string(),
+            limit,
+            filtered: filter.is_some(),
+        });
+        self.with_collection(collection, |vectors| {
+            let mut scored: Vec<(f64, &StoredVector)> = vectors
+                .iter()
+                .map(|v| (f64::from(cosine_similarity(query_vector, &v.vector)), v))
+                .collect();
+            // Stable sort: equal scores keep insertion order.
+            scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+            scored
+                .into_iter()
+                .take(limit)
+                .map(|(score, v)| {
+                    search_result_from_json_metadata(v.id.clone(), &v.metadata, score)
+                })
+                .collect()
+        })
+    }
+
+    async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()> {
+        self.record(VectorStoreCall::DeleteVectors {
+            collection: collection.to_string(),
+            ids: ids.to_vec(),
+        });
+        self.with_collection(collection, |vectors| {
+            vectors.retain(|v| !ids.contains(&v.id));
+        })
+    }
+
+    async fn get_vectors_by_ids(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+    ) -> Result<Vec<SearchResult>> {
+        self.record(VectorStoreCall::GetVectorsByIds {
+            collection: collection.to_string(),
+            ids: ids.to_vec(),
+        });
+        self.with_collection(collection, |vectors| {
+            vectors
+                .iter()
+                .filter(|v| ids.contains(&v.id))
+                .map(|v| search_result_from_json_metadata(v.id.clone(), &v.metadata, 1.0))
+                .collect()
+        })
+    }
+
+    async fn list_vectors(
+        &self,
+        collection: &CollectionId,
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        self.record(VectorStoreCall::ListVectors {
+            collection: collection.to_string(),
+            limit,
+        });
+        self.with_collection(collection, |vectors| {
+            vectors
+                .iter()
+                .take(limit)
+                .map(|v| search_result_from_json_metadata(v.id.clone(), &v.metadata, 1.0))
+                .collect()
+        })
+    }
+
+    /// Location is the record's position in its collection.
+    async fn inspect_chunk(
+        &self,
+        collection: &CollectionId,
+        id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        self.record(VectorStoreCall::InspectChunk {
+            collection: collection.to_string(),
+            id: id.to_owned(),
+        });
+        self.with_collection(collection, |vectors| {
+            let position = vectors.iter().position(|v| v.id == id)?;
+            let stored = &vectors[position];
+            let metadata = stored
+                .metadata
+                .as_object()
+                .map(|fields| fields.clone().into_iter().collect())
+                .unwrap_or_default();
+            Some(ChunkInspection::new(
+                id,
+                collection.to_string(),
+                PROVIDER_NAME,
+                metadata,
+                Some(stored.vector.clone()),
+                HashMap::from([("position".to_owned(), Value::from(position))]),
+            ))
+        })
+    }
+}
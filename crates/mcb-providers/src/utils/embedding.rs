@@ -2,9 +2,11 @@
 //! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md)
 //!
 use std::future::Future;
+use std::sync::Arc;
 use std::time::Duration;
 
 use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{RequestSigner, ResiliencePolicy};
 use mcb_domain::value_objects::Embedding;
 use reqwest::Client;
 use serde_json::Value;
@@ -24,6 +26,10 @@ pub(crate) struct HttpEmbeddingClient {
     pub(crate) timeout: Duration,
     /// Shared reqwest HTTP client.
     pub(crate) client: Client,
+    /// Retry policy for embedding requests.
+    pub(crate) resilience: ResiliencePolicy,
+    /// Optional per-request signer (e.g. SigV4 for an authenticating gateway).
+    pub(crate) signer: Option<Arc<dyn RequestSigner>>,
 }
 
 pub(crate) struct HttpEmbeddingClientConfig {
@@ -33,6 +39,7 @@ pub(crate) struct HttpEmbeddingClientConfig {
     pub(crate) model: String,
     pub(crate) timeout: Duration,
     pub(crate) client: Client,
+    pub(crate) resilience: ResiliencePolicy,
 }
 
 impl HttpEmbeddingClient {
@@ -46,8 +53,33 @@ impl HttpEmbeddingClient {
             model: config.model,
             timeout: config.timeout,
             client: config.client,
+            resilience: config.resilience,
+            signer: None,
         }
     }
+
+    /// Replace the retry policy; the request timeout follows the policy.
+    pub(crate) fn set_resilience(&mut self, policy: ResiliencePolicy) {
+        self.timeout = policy.request_timeout();
+        self.resilience = policy;
+    }
+
+    /// Replace the per-request signer.
+    pub(crate) fn set_signer(&mut self, signer: Option<Arc<dyn RequestSigner>>) {
+        self.signer = signer;
+    }
+
+    /// The configured signer, as passed to `JsonRequestParams::signer`.
+    #[must_use]
+    pub(crate) fn signer(&self) -> Option<&dyn RequestSigner> {
+        self.signer.as_deref()
+    }
+
+    /// Retry configuration derived from the current policy.
+    #[must_use]
+    pub(crate) fn retry_config(&self) -> super::http::RetryConfig {
+        super::http::retry_config(&self.resilience)
+    }
 }
 
 /// Extracts the `data` array from a standard embedding API response.
@@ -6,14 +6,16 @@
 //! Shared HTTP client creation and error handling utilities
 //! used across embedding and vector store providers (DRY principle).
 
+use std::sync::Arc;
 use std::time::Duration;
 
 use mcb_domain::error::Error;
+use mcb_domain::ports::{HttpClientConfig, HttpTransportConfig, RequestSigner, ResiliencePolicy};
 use reqwest::Client;
 use serde_json::Value;
 
 use super::http_response::HttpResponseUtils;
-pub(crate) use mcb_utils::constants::http::{DEFAULT_HTTP_TIMEOUT, ERROR_MSG_REQUEST_TIMEOUT};
+pub(crate) use mcb_utils::constants::http::ERROR_MSG_REQUEST_TIMEOUT;
 use mcb_utils::utils::retry::retry_with_backoff;
 
 // Re-export so callers of `send_json_request` can build `JsonRequestParams.retry`.
@@ -28,30 +30,28 @@ pub(crate) enum RequestErrorKind {
     VectorDb,
 }
 
-/// Create an HTTP client with the specified timeout
+/// Create a pooled, keep-alive HTTP client for a provider.
 ///
-/// # Arguments
-/// * `timeout_secs` - Request timeout in seconds
-///
-/// # Returns
-/// Configured reqwest Client or error message string
-///
-/// # Example
-/// ```ignore
-/// use mcb_providers::utils::http::create_client;
-///
-/// let client = create_client(30)?;
-/// ```
-pub(crate) fn create_client(timeout_secs: u64) -> mcb_domain::error::Result<Client> {
-    Client::builder()
-        .timeout(Duration::from_secs(timeout_secs))
-        .build()
-        .map_err(|e| Error::network(format!("Failed to create HTTP client: {e}")))
+/// Enforces the timeouts of `resilience` and routes through the proxy / extra
+/// root CAs of `transport` (defaults when the provider config has none).
+pub(crate) fn create_provider_client(
+    resilience: &ResiliencePolicy,
+    transport: Option<&HttpTransportConfig>,
+) -> mcb_domain::error::Result<Client> {
+    let config = HttpClientConfig {
+        resilience: *resilience,
+        transport: transport.cloned().unwrap_or_default(),
+        ..HttpClientConfig::default()
+    };
+    super::http_client::build_pooled_client(&config)
 }
 
-/// Create an HTTP client with the default 30-second timeout
-pub(crate) fn create_default_client() -> mcb_domain::error::Result<Client> {
-    create_client(mcb_utils::constants::http::HTTP_REQUEST_TIMEOUT_SECS)
+/// Retry configuration (exponential backoff with jitter) derived from `policy`.
+#[must_use]
+pub(crate) fn retry_config(policy: &ResiliencePolicy) -> RetryConfig {
+    RetryConfig::new(policy.max_attempts(), policy.backoff())
+        .with_exponential_backoff(policy.max_backoff())
+        .with_jitter(policy.jitter)
 }
 
 pub(crate) fn handle_request_error_with_kind(
@@ -125,6 +125,10 @@ pub(crate) struct HttpProviderConfig {
     pub timeout: Duration,
     /// Configured HTTP client
     pub client: Client,
+    /// Timeout and retry policy
+    pub resilience: ResiliencePolicy,
+    /// Per-request signer, if the config enables signing
+    pub signer: Option<Arc<dyn RequestSigner>>,
 }
 
 /// Create configuration for an HTTP-based embedding provider
@@ -168,14 +172,18 @@ pub(crate) fn create_http_provider_config(
         .clone()
         .unwrap_or_else(|| default_model.to_owned());
 
-    let client = create_default_client()?;
+    let resilience = config.resilience.unwrap_or_default();
+    let client = create_provider_client(&resilience, config.transport.as_ref())?;
+    let signer = super::signing::build_request_signer(config.signing.as_ref())?;
 
     Ok(HttpProviderConfig {
         api_key,
         base_url,
         model,
-        timeout: DEFAULT_HTTP_TIMEOUT,
+        timeout: resilience.request_timeout(),
         client,
+        resilience,
+        signer,
     })
 }
 
@@ -201,6 +209,8 @@ pub(crate) struct JsonRequestParams<'a> {
     pub body: Option<&'a Value>,
     /// Optional retry configuration for transient errors (rate limits, 5xx, timeouts).
     pub retry: Option<RetryConfig>,
+    /// Optional signer applied to every attempt.
+    pub signer: Option<&'a dyn RequestSigner>,
 }
 
 /// Check whether a domain error represents a transient HTTP failure worth retrying.
@@ -227,17 +237,28 @@ pub(crate) async fn send_json_request(
         headers,
         body,
         retry,
+        signer,
     } = params;
 
     let execute = || async {
         let mut builder = client.request(method.clone(), &url).timeout(timeout);
 
-        for (key, value) in headers {
-            builder = builder.header(*key, value);
-        }
+        if let Some(signer) = signer {
+            let signed = super::signing::sign_json_request(signer, &method, &url, headers, body)?;
+            for (key, value) in &signed.headers {
+                builder = builder.header(key.as_str(), value);
+            }
+            if body.is_some() {
+                builder = builder.body(signed.body);
+            }
+        } else {
+            for (key, value) in headers {
+                builder = builder.header(*key, value);
+            }
 
-        if let Some(payload) = body {
-            builder = builder.json(payload);
+            if let Some(payload) = body {
+                builder = builder.json(payload);
+            }
         }
 
         let response = builder
@@ -263,8 +284,7 @@ pub(crate) struct VectorDbRequestParams<'a> {
     pub operation: &'a str,
     pub headers: &'a [(&'a str, String)],
     pub body: Option<&'a Value>,
-    pub retry_attempts: usize,
-    pub retry_backoff_ms: u64,
+    pub resilience: &'a ResiliencePolicy,
 }
 
 pub(crate) async fn send_vector_db_request(
@@ -279,8 +299,7 @@ pub(crate) async fn send_vector_db_request(
         operation,
         headers,
         body,
-        retry_attempts,
-        retry_backoff_ms,
+        resilience,
     } = params;
 
     send_json_request(JsonRequestParams {
@@ -293,10 +312,8 @@ pub(crate) async fn send_vector_db_request(
         kind: RequestErrorKind::VectorDb,
         headers,
         body,
-        retry: Some(RetryConfig::new(
-            retry_attempts,
-            Duration::from_millis(retry_backoff_ms),
-        )),
+        retry: Some(retry_config(resilience)),
+        signer: None,
     })
     .await
 }
@@ -0,0 +1,312 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md)
+//!
+//! Shared, pooled reqwest-backed [`HttpClientProvider`].
+//!
+//! Every request honours the [`ResiliencePolicy`] in the client's
+//! [`HttpClientConfig`]: connect/request timeouts are set on the underlying
+//! client and transient failures (timeouts, 429, 5xx) are retried with
+//! exponential backoff and jitter.
+//!
+//! Connections are pooled and kept alive (TCP keep-alive plus HTTP/2 PINGs
+//! when the server negotiates h2 via ALPN). Concurrent requests per host are
+//! capped by `max_connections_per_host`; [`HttpClientProvider::pool_stats`]
+//! exposes the resulting counters.
+//!
+//! An explicit proxy and additional root CA bundles come from
+//! [`HttpClientConfig::transport`]; otherwise the standard proxy environment
+//! variables apply.
+//!
+//! When [`HttpClientConfig::signing`] is set (or a signer is attached with
+//! [`ReqwestHttpClient::with_signer`]) every attempt is signed right before
+//! it is sent.
+//!
+//! [`ResiliencePolicy`]: mcb_domain::ports::ResiliencePolicy
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{
+    HostPoolStats, HttpClientConfig, HttpClientProvider, HttpPoolStats, RequestSigner,
+    SignableRequest,
+};
+use mcb_utils::utils::retry::retry_with_backoff;
+use reqwest::{Certificate, Client, Method, NoProxy, Proxy, StatusCode, Url};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use super::http::retry_config;
+use super::signing::build_request_signer;
+
+/// Build a client with the pooling, keep-alive, timeout, proxy and CA settings of `config`.
+pub(crate) fn build_pooled_client(config: &HttpClientConfig) -> Result<Client> {
+    let mut builder = Client::builder()
+        .connect_timeout(config.resilience.connect_timeout())
+        .timeout(config.resilience.request_timeout())
+        .pool_max_idle_per_host(config.max_idle_per_host)
+        .pool_idle_timeout(config.idle_timeout)
+        .tcp_keepalive(config.keepalive)
+        .http2_keep_alive_interval(config.http2_keepalive)
+        .http2_keep_alive_while_idle(true)
+        .http2_adaptive_window(true)
+        .user_agent(config.user_agent.clone());
+
+    let transport = &config.transport;
+    if let Some(proxy_url) = &transport.proxy {
+        let proxy = Proxy::all(proxy_url)
+            .map_err(|e| Error::config_invalid("http.proxy", format!("invalid proxy URL: {e}")))?
+            .no_proxy(transport.no_proxy.as_deref().and_then(NoProxy::from_string));
+        builder = builder.proxy(proxy);
+    }
+    for path in &transport.ca_bundles {
+        let pem = std::fs::read(path).map_err(|e| {
+            Error::config_with_source(format!("Failed to read CA bundle {}", path.display()), e)
+        })?;
+        let certs = Certificate::from_pem_bundle(&pem).map_err(|e| {
+            Error::config_invalid(
+                "http.ca_bundles",
+                format!("invalid PEM bundle {}: {e}", path.display()),
+            )
+        })?;
+        for cert in certs {
+            builder = builder.add_root_certificate(cert);
+        }
+    }
+
+    builder
+        .build()
+        .map_err(|e| Error::network(format!("Failed to create HTTP client: {e}")))
+}
+
+#[derive(Default)]
+struct HostSlot {
+    limiter: Option<Arc<Semaphore>>,
+    in_flight: AtomicUsize,
+    queued: AtomicUsize,
+    requests: AtomicU64,
+}
+
+/// Releases the host slot counters when a request finishes.
+struct HostGuard {
+    slot: Arc<HostSlot>,
+    _permit: Option<OwnedSemaphorePermit>,
+}
+
+impl Drop for HostGuard {
+    fn drop(&mut self) {
+        self.slot.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Shared pooled HTTP client enforcing the configured resilience policy.
+pub struct ReqwestHttpClient {
+    config: HttpClientConfig,
+    client: Client,
+    signer: Option<Arc<dyn RequestSigner>>,
+    hosts: DashMap<String, Arc<HostSlot>>,
+    requests: AtomicU64,
+    retries: AtomicU64,
+    failures: AtomicU64,
+}
+
+impl ReqwestHttpClient {
+    /// Build a client from `config`.
+    ///
+    /// # Errors
+    /// Returns a configuration error for an invalid policy, proxy, CA bundle or
+    /// signing scheme, or a network error if the underlying client cannot be built.
+    pub fn new(config: HttpClientConfig) -> Result<Self> {
+        config.resilience.validate("http.resilience")?;
+        let client = build_pooled_client(&config)?;
+        let signer = build_request_signer(config.signing.as_ref())?;
+        Ok(Self {
+            config,
+            client,
+            signer,
+            hosts: DashMap::new(),
+            requests: AtomicU64::new(0),
+            retries: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+        })
+    }
+
+    /// Replace the request signer built from the config with a custom hook.
+    #[must_use]
+    pub fn with_signer(mut self, signer: Arc<dyn RequestSigner>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    fn host_slot(&self, host: &str) -> Arc<HostSlot> {
+        let limit = self.config.max_connections_per_host;
+        Arc::clone(
+            self.hosts
+                .entry(host.to_owned())
+                .or_insert_with(|| {
+                    Arc::new(HostSlot {
+                        limiter: (limit > 0).then(|| Arc::new(Semaphore::new(limit))),
+                        ..HostSlot::default()
+                    })
+                })
+                .value(),
+        )
+    }
+
+    async fn acquire(&self, host: &str) -> Result<HostGuard> {
+        let slot = self.host_slot(host);
+        let permit = match &slot.limiter {
+            Some(limiter) => {
+                slot.queued.fetch_add(1, Ordering::Relaxed);
+                let permit = Arc::clone(limiter).acquire_owned().await;
+                slot.queued.fetch_sub(1, Ordering::Relaxed);
+                Some(permit.map_err(|_| Error::network("HTTP connection pool closed"))?)
+            }
+            None => None,
+        };
+        slot.in_flight.fetch_add(1, Ordering::Relaxed);
+        slot.requests.fetch_add(1, Ordering::Relaxed);
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        Ok(HostGuard {
+            slot,
+            _permit: permit,
+        })
+    }
+
+    async fn send_once(
+        &self,
+        method: Method,
+        url: &Url,
+        host: &str,
+        body: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        let _guard = self.acquire(host).await?;
+        let mut request = self.client.request(method.clone(), url.clone());
+        if let Some(signer) = &self.signer {
+            let mut signable = SignableRequest {
+                method: method.as_str().to_owned(),
+                url: url.to_string(),
+                headers: Vec::new(),
+                body: body.map(<[u8]>::to_vec).unwrap_or_default(),
+            };
+            signer.sign(&mut signable).map_err(|e| {
+                Error::network(format!("{} request signing failed: {e}", signer.scheme()))
+            })?;
+            for (key, value) in signable.headers {
+                request = request.header(key, value);
+            }
+        }
+        if let Some(body) = body {
+            request = request.body(body.to_vec());
+        }
+        let response = request.send().await.map_err(|e| {
+            if e.is_timeout() {
+                Error::network(format!(
+                    "request to {host} timed out after {:?}",
+                    self.config.resilience.request_timeout()
+                ))
+            } else {
+                Error::network(format!("request to {host} failed: {e}"))
+            }
+        })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(status_error(status, host));
+        }
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .map_err(|e| Error::network(format!("reading response from {host} failed: {e}")))
+    }
+
+    async fn send(&self, method: Method, url: &str, body: Option<&[u8]>) -> Result<Vec<u8>> {
+        let parsed =
+            Url::parse(url).map_err(|e| Error::invalid_argument(format!("invalid URL: {e}")))?;
+        let host = host_key(&parsed);
+        let result = retry_with_backoff(
+            retry_config(&self.config.resilience),
+            |attempt| {
+                if attempt > 0 {
+                    self.retries.fetch_add(1, Ordering::Relaxed);
+                }
+                self.send_once(method.clone(), &parsed, &host, body)
+            },
+            is_transient,
+        )
+        .await;
+        if result.is_err() {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+        result
+    }
+}
+
+fn host_key(url: &Url) -> String {
+    let host = url.host_str().unwrap_or_default();
+    match url.port_or_known_default() {
+        Some(port) => format!("{host}:{port}"),
+        None => host.to_owned(),
+    }
+}
+
+fn status_error(status: StatusCode, host: &str) -> Error {
+    match status.as_u16() {
+        429 => Error::network(format!("rate limit exceeded (429) for {host}")),
+        code @ 500..=599 => Error::network(format!("server error ({code}) for {host}")),
+        code => Error::network(format!("request failed ({code}) for {host}")),
+    }
+}
+
+fn is_transient(error: &Error) -> bool {
+    let msg = error.to_string();
+    msg.contains("rate limit exceeded")
+        || msg.contains("server error (5")
+        || msg.contains("timed out")
+}
+
+#[async_trait]
+impl HttpClientProvider for ReqwestHttpClient {
+    fn config(&self) -> &HttpClientConfig {
+        &self.config
+    }
+
+    async fn get(&self, url: &str) -> Result<Vec<u8>> {
+        self.send(Method::GET, url, None).await
+    }
+
+    async fn post(&self, url: &str, body: &[u8]) -> Result<Vec<u8>> {
+        self.send(Method::POST, url, Some(body)).await
+    }
+
+    fn is_enabled(&self) -> bool {
+        true
+    }
+
+    fn pool_stats(&self) -> Option<HttpPoolStats> {
+        let hosts = self
+            .hosts
+            .iter()
+            .map(|entry| {
+                let slot = entry.value();
+                (
+                    entry.key().clone(),
+                    HostPoolStats {
+                        in_flight: slot.in_flight.load(Ordering::Relaxed),
+                        queued: slot.queued.load(Ordering::Relaxed),
+                        requests_total: slot.requests.load(Ordering::Relaxed),
+                    },
+                )
+            })
+            .collect();
+        Some(HttpPoolStats {
+            max_connections_per_host: self.config.max_connections_per_host,
+            requests_total: self.requests.load(Ordering::Relaxed),
+            retries_total: self.retries.load(Ordering::Relaxed),
+            failures_total: self.failures.load(Ordering::Relaxed),
+            hosts,
+        })
+    }
+}
@@ -9,6 +9,10 @@
 pub mod embedding;
 /// HTTP request utilities for provider implementations.
 pub mod http;
+/// Shared `HttpClientProvider` enforcing the configured resilience policy.
+pub mod http_client;
 pub(crate) mod http_response;
+/// Pluggable request signing (SigV4 behind the `signing-sigv4` feature).
+pub mod signing;
 /// Vector store shared utilities.
 pub mod vector_store;
@@ -0,0 +1,275 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md#request-signing)
+//!
+//! Request signing for authenticating gateways.
+//!
+//! [`build_request_signer`] turns a [`RequestSigningConfig`] into a
+//! [`RequestSigner`] that HTTP providers invoke on every attempt. Custom
+//! signers can be attached directly to [`ReqwestHttpClient`] or HTTP embedding
+//! providers via `with_signer`.
+//!
+//! The AWS SigV4 signer is compiled only with the `signing-sigv4` feature.
+//!
+//! [`ReqwestHttpClient`]: super::http_client::ReqwestHttpClient
+
+use std::sync::Arc;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{RequestSigner, RequestSigningConfig, SignableRequest};
+use mcb_utils::constants::http::{CONTENT_TYPE_JSON, HTTP_HEADER_CONTENT_TYPE};
+use serde_json::Value;
+
+/// Build the signer for `config`, or `None` when signing is not configured.
+///
+/// # Errors
+/// Returns a configuration error when the scheme is invalid, its credentials
+/// are missing, or it was not compiled in.
+pub fn build_request_signer(
+    config: Option<&RequestSigningConfig>,
+) -> Result<Option<Arc<dyn RequestSigner>>> {
+    let Some(config) = config else {
+        return Ok(None);
+    };
+    config.validate("signing")?;
+    match config {
+        #[cfg(feature = "signing-sigv4")]
+        RequestSigningConfig::Sigv4(sigv4) => {
+            Ok(Some(Arc::new(sigv4::SigV4Signer::from_config(sigv4)?)))
+        }
+        #[cfg(not(feature = "signing-sigv4"))]
+        RequestSigningConfig::Sigv4(_) => Err(Error::config_invalid(
+            "signing.scheme",
+            "sigv4 signing requires mcb-providers to be built with the `signing-sigv4` feature",
+        )),
+    }
+}
+
+/// Serialize a JSON request and run it through `signer`.
+///
+/// Adds a JSON content type when the caller did not set one, so the signed
+/// headers match what is sent.
+pub(crate) fn sign_json_request(
+    signer: &dyn RequestSigner,
+    method: &reqwest::Method,
+    url: &str,
+    headers: &[(&str, String)],
+    body: Option<&Value>,
+) -> Result<SignableRequest> {
+    let mut request = SignableRequest {
+        method: method.as_str().to_owned(),
+        url: url.to_owned(),
+        headers: headers
+            .iter()
+            .map(|(key, value)| ((*key).to_owned(), value.clone()))
+            .collect(),
+        body: body
+            .map(serde_json::to_vec)
+            .transpose()?
+            .unwrap_or_default(),
+    };
+    if body.is_some() && request.header(HTTP_HEADER_CONTENT_TYPE).is_none() {
+        request.set_header(HTTP_HEADER_CONTENT_TYPE, CONTENT_TYPE_JSON);
+    }
+    signer
+        .sign(&mut request)
+        .map_err(|e| Error::network(format!("{} request signing failed: {e}", signer.scheme())))?;
+    Ok(request)
+}
+
+#[cfg(feature = "signing-sigv4")]
+pub use sigv4::SigV4Signer;
+
+#[cfg(feature = "signing-sigv4")]
+mod sigv4 {
+    use chrono::{DateTime, Utc};
+    use mcb_domain::error::{Error, Result};
+    use mcb_domain::ports::{RequestSigner, SigV4Config, SignableRequest};
+    use mcb_utils::utils::crypto::{HashUtils, bytes_to_hex};
+    use reqwest::Url;
+    use sha2::{Digest, Sha256};
+
+    const ALGORITHM: &str = "AWS4-HMAC-SHA256";
+    const HEADER_AMZ_DATE: &str = "x-amz-date";
+    const HEADER_AMZ_SECURITY_TOKEN: &str = "x-amz-security-token";
+    const HEADER_AUTHORIZATION: &str = "authorization";
+
+    /// AWS Signature Version 4 request signer.
+    ///
+    /// Signs `host`, `content-type` and every `x-amz-*` header and replaces
+    /// any existing `Authorization` header. The URL path is used as already
+    /// percent-encoded by the URL parser (no second encoding pass).
+    pub struct SigV4Signer {
+        region: String,
+        service: String,
+        access_key_id: String,
+        secret_access_key: String,
+        session_token: Option<String>,
+    }
+
+    impl SigV4Signer {
+        /// Build a signer, falling back to the standard AWS environment
+        /// variables for credentials not present in `config`.
+        ///
+        /// # Errors
+        /// Returns a configuration error when no credentials are available.
+        pub fn from_config(config: &SigV4Config) -> Result<Self> {
+            let env = |name: &str| std::env::var(name).ok().filter(|v| !v.is_empty());
+            let (access_key_id, secret_access_key, session_token) =
+                match (&config.access_key_id, &config.secret_access_key) {
+                    (Some(id), Some(secret)) => {
+                        (id.clone(), secret.clone(), config.session_token.clone())
+                    }
+                    _ => (
+                        env("AWS_ACCESS_KEY_ID").ok_or_else(|| missing("access_key_id"))?,
+                        env("AWS_SECRET_ACCESS_KEY").ok_or_else(|| missing("secret_access_key"))?,
+                        config
+                            .session_token
+                            .clone()
+                            .or_else(|| env("AWS_SESSION_TOKEN")),
+                    ),
+                };
+            Ok(Self {
+                region: config.region.clone(),
+                service: config.service.clone(),
+                access_key_id,
+                secret_access_key,
+                session_token,
+            })
+        }
+
+        /// Sign `request` as if sent at `now` (the clock is injectable for tests).
+        ///
+        /// # Errors
+        /// Returns an error for an unparsable URL.
+        pub fn sign_at(&self, request: &mut SignableRequest, now: DateTime<Utc>) -> Result<()> {
+            let url = Url::parse(&request.url)
+                .map_err(|e| Error::invalid_argument(format!("invalid URL: {e}")))?;
+            let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+            let date = &amz_date[..8];
+
+            request.set_header(HEADER_AMZ_DATE, amz_date.as_str());
+            if let Some(token) = &self.session_token {
+                request.set_header(HEADER_AMZ_SECURITY_TOKEN, token.as_str());
+            }
+
+            let (canonical_headers, signed_headers) = canonical_headers(request, &url);
+            let canonical_request = format!(
+                "{}\n{}\n{}\n{canonical_headers}\n{signed_headers}\n{}",
+                request.method.to_ascii_uppercase(),
+                canonical_path(&url),
+                canonical_query(&url),
+                sha256_hex(&request.body),
+            );
+
+            let scope = format!("{date}/{}/{}/aws4_request", self.region, self.service);
+            let string_to_sign = format!(
+                "{ALGORITHM}\n{amz_date}\n{scope}\n{}",
+                sha256_hex(canonical_request.as_bytes())
+            );
+
+            let secret = format!("AWS4{}", self.secret_access_key);
+            let mut key = HashUtils::hmac_sha256(secret.as_bytes(), date.as_bytes())?;
+            for part in [self.region.as_str(), self.service.as_str(), "aws4_request"] {
+                key = HashUtils::hmac_sha256(&key, part.as_bytes())?;
+            }
+            let signature = bytes_to_hex(&HashUtils::hmac_sha256(&key, string_to_sign.as_bytes())?);
+
+            request.set_header(
+                HEADER_AUTHORIZATION,
+                format!(
+                    "{ALGORITHM} Credential={}/{scope}, SignedHeaders={signed_headers}, \
+                     Signature={signature}",
+                    self.access_key_id
+                ),
+            );
+            Ok(())
+        }
+    }
+
+    impl RequestSigner for SigV4Signer {
+        fn scheme(&self) -> &str {
+            "sigv4"
+        }
+
+        fn sign(&self, request: &mut SignableRequest) -> Result<()> {
+            self.sign_at(request, Utc::now())
+        }
+    }
+
+    fn missing(field: &str) -> Error {
+        Error::config_invalid(
+            format!("signing.{field}"),
+            "not configured and not available from the AWS environment variables",
+        )
+    }
+
+    fn sha256_hex(data: &[u8]) -> String {
+        bytes_to_hex(&Sha256::digest(data))
+    }
+
+    fn canonical_path(url: &Url) -> &str {
+        match url.path() {
+            "" => "/",
+            path => path,
+        }
+    }
+
+    fn canonical_query(url: &Url) -> String {
+        let mut pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(key, value)| (uri_encode(&key), uri_encode(&value)))
+            .collect();
+        pairs.sort();
+        pairs
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>()
+            .join("&")
+    }
+
+    fn canonical_headers(request: &SignableRequest, url: &Url) -> (String, String) {
+        let host = request.header("host").map_or_else(
+            || match (url.host_str(), url.port()) {
+                (Some(host), Some(port)) => format!("{host}:{port}"),
+                (Some(host), None) => host.to_owned(),
+                (None, _) => String::new(),
+            },
+            |value| value.trim().to_owned(),
+        );
+        let mut headers: Vec<(String, String)> = request
+            .headers
+            .iter()
+            .map(|(key, value)| (key.to_ascii_lowercase(), value.trim().to_owned()))
+            .filter(|(key, _)| key == "content-type" || key.starts_with("x-amz-"))
+            .collect();
+        headers.push(("host".to_owned(), host));
+        headers.sort();
+
+        let canonical = headers
+            .iter()
+            .map(|(key, value)| format!("{key}:{value}\n"))
+            .collect::<String>();
+        let signed = headers
+            .iter()
+            .map(|(key, _)| key.as_str())
+            .collect::<Vec<_>>()
+            .join(";");
+        (canonical, signed)
+    }
+
+    /// RFC 3986 encoding of everything but unreserved characters (upper-case hex).
+    fn uri_encode(value: &str) -> String {
+        const HEX: &[u8; 16] = b"0123456789ABCDEF";
+        let mut encoded = String::with_capacity(value.len());
+        for byte in value.bytes() {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+                encoded.push(char::from(byte));
+            } else {
+                encoded.push('%');
+                encoded.push(char::from(HEX[usize::from(byte >> 4)]));
+                encoded.push(char::from(HEX[usize::from(byte & 0x0f)]));
+            }
+        }
+        encoded
+    }
+}
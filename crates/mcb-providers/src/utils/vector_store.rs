@@ -4,19 +4,25 @@
 //! Vector Store Provider Utilities
 //!
 //! Shared utilities for vector store provider implementations (DRY principle).
-//! Contains common HTTP error handling and response parsing patterns.
+//! Contains common HTTP error handling, response parsing and bulk insert
+//! batching patterns.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::ops::Range;
 use std::time::Duration;
 
-use mcb_domain::error::Error;
-use mcb_domain::value_objects::{FileInfo, SearchResult};
+use futures::{StreamExt, TryStreamExt, stream};
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::InsertBatchPolicy;
+use mcb_domain::value_objects::{Embedding, FileInfo, SearchResult};
 use serde_json::Value;
 
 use super::http::{RequestErrorKind, handle_request_error_with_kind};
 use mcb_utils::constants::vector_store::{
-    VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LANGUAGE, VECTOR_FIELD_LINE_NUMBER,
-    VECTOR_FIELD_START_LINE,
+    STATS_FIELD_FILE_COUNT, STATS_FIELD_LANGUAGES, STATS_FIELD_LARGEST_FILES,
+    STATS_LARGEST_FILES_LIMIT, VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LANGUAGE,
+    VECTOR_FIELD_LAST_MODIFIED, VECTOR_FIELD_LINE_NUMBER, VECTOR_FIELD_START_LINE,
 };
 
 /// Handle HTTP request errors for vector store operations
@@ -40,7 +46,8 @@ pub fn handle_vector_request_error(
 
 /// Build a `SearchResult` from a JSON metadata/payload object.
 ///
-/// Extracts `file_path`, `start_line`, `content`, and `language` fields using
+/// Extracts `file_path`, `start_line`, `content`, `language` and
+/// `last_modified` fields using
 /// the standard `VECTOR_FIELD_*` constants. Falls back to `line_number` when
 /// `start_line` is absent.
 ///
@@ -75,9 +82,19 @@ pub fn search_result_from_json_metadata(id: String, metadata: &Value, score: f64
             .and_then(Value::as_str)
             .unwrap_or("unknown")
             .to_owned(),
+        last_modified: metadata
+            .get(VECTOR_FIELD_LAST_MODIFIED)
+            .and_then(Value::as_i64),
+        explanation: None,
     }
 }
 
+/// Similarity score (higher is better, at most 1.0) for a Euclidean distance.
+#[must_use]
+pub fn euclidean_similarity(distance: f64) -> f64 {
+    1.0 / (1.0 + distance)
+}
+
 /// Build a list of `FileInfo` from search results by grouping on `file_path`.
 ///
 /// This logic is shared across vector store providers (Pinecone, Qdrant, etc.)
@@ -98,3 +115,142 @@ pub fn build_file_info_from_results(results: Vec<SearchResult>) -> Vec<FileInfo>
         .map(|(path, (chunk_count, language))| FileInfo::new(path, chunk_count, language, None))
         .collect()
 }
+
+/// Add the file count, per-language chunk counts and largest files of
+/// `files` to a `get_stats` map.
+///
+/// Shared by every provider so `get_stats` reports the same content
+/// breakdown whatever the backend.
+pub fn insert_content_stats(stats: &mut HashMap<String, Value>, files: &[FileInfo]) {
+    let mut languages: BTreeMap<&str, u64> = BTreeMap::new();
+    for file in files {
+        *languages.entry(file.language.as_str()).or_default() += u64::from(file.chunk_count);
+    }
+
+    let mut largest: Vec<&FileInfo> = files.iter().collect();
+    largest.sort_by(|a, b| {
+        b.chunk_count
+            .cmp(&a.chunk_count)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+    largest.truncate(STATS_LARGEST_FILES_LIMIT);
+
+    stats.insert(
+        STATS_FIELD_FILE_COUNT.to_owned(),
+        serde_json::json!(files.len()),
+    );
+    stats.insert(
+        STATS_FIELD_LANGUAGES.to_owned(),
+        serde_json::json!(languages),
+    );
+    stats.insert(
+        STATS_FIELD_LARGEST_FILES.to_owned(),
+        serde_json::json!(largest),
+    );
+}
+
+/// Check that `ids`, `vectors` and `metadata` of an upsert are parallel.
+///
+/// # Errors
+/// Returns an error naming the lengths when they differ.
+pub(crate) fn ensure_upsert_lengths<T>(
+    ids: &[String],
+    vectors: &[Embedding],
+    metadata: &[T],
+) -> Result<()> {
+    if ids.len() != vectors.len() || vectors.len() != metadata.len() {
+        return Err(Error::vector_db(format!(
+            "Upsert length mismatch: ids={}, vectors={}, metadata={}",
+            ids.len(),
+            vectors.len(),
+            metadata.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Insert `vectors` in batches of `policy.batch_size`, keeping at most
+/// `policy.max_in_flight` batches outstanding.
+///
+/// Batches are started lazily, so a slow backend holds back the producer
+/// instead of queueing every batch at once. IDs are returned in input order.
+/// On error, batches that already completed remain stored.
+///
+/// # Errors
+/// Returns an error when `vectors` and `metadata` differ in length or when
+/// any batch insert fails.
+pub(crate) async fn insert_in_batches<'a, F, Fut>(
+    policy: InsertBatchPolicy,
+    vectors: &'a [Embedding],
+    metadata: Vec<HashMap<String, Value>>,
+    insert: F,
+) -> Result<Vec<String>>
+where
+    F: Fn(&'a [Embedding], Vec<HashMap<String, Value>>) -> Fut,
+    Fut: Future<Output = Result<Vec<String>>>,
+{
+    if vectors.len() != metadata.len() {
+        return Err(Error::vector_db(format!(
+            "Vectors/metadata length mismatch: vectors={}, metadata={}",
+            vectors.len(),
+            metadata.len()
+        )));
+    }
+    run_in_batches(policy, metadata, |range, batch_metadata| {
+        insert(&vectors[range], batch_metadata)
+    })
+    .await
+}
+
+/// Upsert in batches like [`insert_in_batches`], splitting `ids` alongside
+/// the vectors they key.
+///
+/// # Errors
+/// Returns an error when the inputs differ in length or when any batch
+/// upsert fails.
+pub(crate) async fn upsert_in_batches<'a, F, Fut>(
+    policy: InsertBatchPolicy,
+    ids: &'a [String],
+    vectors: &'a [Embedding],
+    metadata: Vec<HashMap<String, Value>>,
+    upsert: F,
+) -> Result<Vec<String>>
+where
+    F: Fn(&'a [String], &'a [Embedding], Vec<HashMap<String, Value>>) -> Fut,
+    Fut: Future<Output = Result<Vec<String>>>,
+{
+    ensure_upsert_lengths(ids, vectors, &metadata)?;
+    run_in_batches(policy, metadata, |range, batch_metadata| {
+        upsert(&ids[range.clone()], &vectors[range], batch_metadata)
+    })
+    .await
+}
+
+/// Drive `write` over consecutive index ranges of at most `policy.batch_size`.
+async fn run_in_batches<F, Fut>(
+    policy: InsertBatchPolicy,
+    metadata: Vec<HashMap<String, Value>>,
+    write: F,
+) -> Result<Vec<String>>
+where
+    F: Fn(Range<usize>, Vec<HashMap<String, Value>>) -> Fut,
+    Fut: Future<Output = Result<Vec<String>>>,
+{
+    let len = metadata.len();
+    let batch_size = policy.batch_size.max(1);
+    if len <= batch_size {
+        return write(0..len, metadata).await;
+    }
+
+    let mut metadata = metadata.into_iter();
+    let batches = (0..len).step_by(batch_size).map(|start| {
+        let range = start..usize::min(start + batch_size, len);
+        let batch_metadata = metadata.by_ref().take(range.len()).collect();
+        write(range, batch_metadata)
+    });
+    let ids: Vec<Vec<String>> = stream::iter(batches)
+        .buffered(policy.max_in_flight.max(1))
+        .try_collect()
+        .await?;
+    Ok(ids.into_iter().flatten().collect())
+}
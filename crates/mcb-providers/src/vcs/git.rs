@@ -3,6 +3,7 @@
 //!
 //! Git repository provider.
 
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
@@ -10,11 +11,13 @@ use git2::{BranchType, Repository, Sort};
 use mcb_domain::{
     entities::vcs::{
         DiffStatus, FileDiff, RefDiff, VcsBranch, VcsCommit, VcsCommitInput, VcsRepository,
+        WorkingTreeScope,
     },
     error::{Error, Result},
     ports::VcsProvider,
     value_objects::RepositoryId,
 };
+use mcb_utils::constants::vcs::VCS_LAST_MODIFIED_MAX_COMMITS;
 use mcb_utils::utils::id;
 
 /// Git repository provider.
@@ -106,6 +109,19 @@ impl GitProvider {
         Ok(branch_ref.into_reference())
     }
 
+    /// Resolve a local branch or, failing that, any revision (tag, SHA,
+    /// `HEAD~1`, ...) to its tree.
+    fn resolve_tree<'r>(repo: &'r Repository, rev: &str) -> Result<git2::Tree<'r>> {
+        let tree = match Self::find_branch_ref(repo, rev) {
+            Ok(branch_ref) => branch_ref.peel_to_tree(),
+            Err(branch_err) => match repo.revparse_single(rev) {
+                Ok(object) => object.peel_to_tree(),
+                Err(_) => return Err(branch_err),
+            },
+        };
+        tree.map_err(|e| Error::vcs_with_source("Failed to get branch tree", e))
+    }
+
     /// Convert a git2 delta status to our domain `DiffStatus`.
     fn delta_to_status(delta: git2::Delta) -> DiffStatus {
         #[allow(clippy::wildcard_enum_match_arm)]
@@ -310,11 +326,7 @@ impl VcsProvider for GitProvider {
     async fn list_files(&self, repo: &VcsRepository, branch: &str) -> Result<Vec<PathBuf>> {
         let git_repo = Self::open_repo(repo.path())?;
 
-        let branch_ref = Self::find_branch_ref(&git_repo, branch)?;
-
-        let tree = branch_ref
-            .peel_to_tree()
-            .map_err(|e| Error::vcs_with_source("Failed to get branch tree", e))?;
+        let tree = Self::resolve_tree(&git_repo, branch)?;
 
         let mut files = Vec::new();
         tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
@@ -335,14 +347,66 @@ impl VcsProvider for GitProvider {
         Ok(files)
     }
 
-    async fn read_file(&self, repo: &VcsRepository, branch: &str, path: &Path) -> Result<String> {
+    async fn file_last_modified(
+        &self,
+        repo: &VcsRepository,
+        branch: &str,
+    ) -> Result<HashMap<PathBuf, i64>> {
         let git_repo = Self::open_repo(repo.path())?;
+        let branch_commit = Self::find_branch_ref(&git_repo, branch)?
+            .peel_to_commit()
+            .map_err(|e| Error::vcs_with_source("Failed to get branch commit", e))?;
+        let tracked = self.list_files(repo, branch).await?.len();
 
-        let branch_ref = Self::find_branch_ref(&git_repo, branch)?;
+        let mut revwalk = git_repo
+            .revwalk()
+            .map_err(|e| Error::vcs_with_source("Failed to create revwalk", e))?;
+        revwalk
+            .push(branch_commit.id())
+            .map_err(|e| Error::vcs_with_source("Failed to push commit to revwalk", e))?;
+        // INTENTIONAL: Sorting preference is non-critical; default ordering is acceptable
+        revwalk.set_sorting(Sort::TIME).ok();
 
-        let tree = branch_ref
-            .peel_to_tree()
-            .map_err(|e| Error::vcs_with_source("Failed to get branch tree", e))?;
+        let mut last_modified = HashMap::new();
+        // Newest first: the first commit that touches a path is its last modification.
+        for oid_result in revwalk.take(VCS_LAST_MODIFIED_MAX_COMMITS) {
+            if last_modified.len() >= tracked {
+                break;
+            }
+            let oid =
+                oid_result.map_err(|e| Error::vcs_with_source("Failed to iterate commits", e))?;
+            let commit = git_repo
+                .find_commit(oid)
+                .map_err(|e| Error::vcs_with_source("Failed to find commit", e))?;
+            let tree = commit
+                .tree()
+                .map_err(|e| Error::vcs_with_source("Failed to get commit tree", e))?;
+            let parent_tree = match commit.parent(0) {
+                Ok(parent) => Some(
+                    parent
+                        .tree()
+                        .map_err(|e| Error::vcs_with_source("Failed to get parent tree", e))?,
+                ),
+                Err(_) => None,
+            };
+            let diff = git_repo
+                .diff_tree_to_tree(parent_tree.as_ref(), Some(&tree), None)
+                .map_err(|e| Error::vcs_with_source("Failed to diff commit", e))?;
+            let time = commit.time().seconds();
+            for delta in diff.deltas() {
+                if let Some(path) = delta.new_file().path() {
+                    last_modified.entry(path.to_path_buf()).or_insert(time);
+                }
+            }
+        }
+
+        Ok(last_modified)
+    }
+
+    async fn read_file(&self, repo: &VcsRepository, branch: &str, path: &Path) -> Result<String> {
+        let git_repo = Self::open_repo(repo.path())?;
+
+        let tree = Self::resolve_tree(&git_repo, branch)?;
 
         let path_str = mcb_utils::utils::path::path_to_utf8_string(path)
             .map_err(|e| Error::vcs_with_source("non-UTF-8 path", e))?;
@@ -398,6 +462,31 @@ impl VcsProvider for GitProvider {
         })
     }
 
+    async fn working_tree_changes(
+        &self,
+        repo: &VcsRepository,
+        scope: &WorkingTreeScope,
+    ) -> Result<Vec<FileDiff>> {
+        let git_repo = Self::open_repo(repo.path())?;
+        let diff = match scope {
+            WorkingTreeScope::Staged => {
+                // INTENTIONAL: An unborn HEAD has no tree; every staged file is then an addition
+                let head_tree = git_repo.head().and_then(|h| h.peel_to_tree()).ok();
+                git_repo.diff_tree_to_index(head_tree.as_ref(), None, None)
+            }
+            WorkingTreeScope::Since(rev) => {
+                let tree = Self::resolve_tree(&git_repo, rev)?;
+                let mut options = git2::DiffOptions::new();
+                options.include_untracked(true).recurse_untracked_dirs(true);
+                git_repo.diff_tree_to_workdir_with_index(Some(&tree), Some(&mut options))
+            }
+        }
+        .map_err(|e| Error::vcs_with_source("Failed to diff working tree", e))?;
+
+        let (files, _, _) = Self::collect_diff_files(&diff)?;
+        Ok(files)
+    }
+
     async fn list_repositories(&self, root: &Path) -> Result<Vec<VcsRepository>> {
         use walkdir::WalkDir;
 
@@ -0,0 +1,75 @@
+//! Collections and the aliases that point at them.
+
+use crate::vector_store::edgevec::persistence::WalEntry;
+
+use super::*;
+
+impl EdgeVecActor {
+    pub(super) fn handle_create_collection(&mut self, name: String) -> Result<()> {
+        self.write(WalEntry::CreateCollection { name })?;
+        Ok(())
+    }
+
+    pub(super) fn handle_delete_collection(&mut self, name: String) -> Result<()> {
+        self.write(WalEntry::DeleteCollection { name })?;
+        Ok(())
+    }
+
+    /// Point `alias` at `collection`; later messages naming the alias see
+    /// the new target, so the switch is atomic for every reader.
+    pub(super) fn handle_swap_alias(&mut self, alias: String, collection: String) -> Result<()> {
+        if !self.metadata_store.contains_key(&collection) {
+            return Err(Error::vector_db(format!(
+                "Collection '{collection}' not found"
+            )));
+        }
+        if self.metadata_store.contains_key(&alias) {
+            return Err(Error::vector_db(format!(
+                "Alias '{alias}' is already the name of a collection"
+            )));
+        }
+        self.write(WalEntry::SwapAlias { alias, collection })?;
+        Ok(())
+    }
+
+    pub(super) fn handle_delete_alias(&mut self, alias: String) -> Result<()> {
+        if !self.aliases.contains_key(&alias) {
+            return Err(Error::vector_db(format!("Alias '{alias}' not found")));
+        }
+        self.write(WalEntry::DeleteAlias { alias })?;
+        Ok(())
+    }
+
+    /// The collection `name` refers to: the target of an alias, otherwise
+    /// `name` itself.
+    pub(super) fn resolve(&self, name: String) -> String {
+        self.aliases
+            .get(&name)
+            .map_or(name, |target| target.value().clone())
+    }
+
+    pub(super) fn apply_delete_collection(&mut self, name: &str) {
+        self.aliases.retain(|_, target| target.as_str() != name);
+        if let Some((_, collection_metadata)) = self.metadata_store.remove(name) {
+            for external_id in collection_metadata.into_keys() {
+                if let Some((_, vector_id)) = self.id_map.remove(&(name.to_owned(), external_id)) {
+                    self.purge(vector_id);
+                }
+            }
+        }
+        let tombstoned: Vec<VectorId> = self
+            .tombstones
+            .iter()
+            .filter(|entry| entry.collection == name)
+            .map(|entry| *entry.key())
+            .collect();
+        for vector_id in tombstoned {
+            self.purge(vector_id);
+        }
+        self.collection_bytes.remove(name);
+    }
+
+    pub(super) fn handle_collection_exists(&self, name: &str) -> Result<bool> {
+        Ok(self.metadata_store.contains_key(name))
+    }
+}
@@ -0,0 +1,137 @@
+//! Collection statistics and file browsing.
+
+use mcb_utils::constants::vector_store::{
+    STATS_FIELD_COLLECTION, STATS_FIELD_VECTORS_COUNT, STATS_FILE_SCAN_LIMIT,
+    VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LANGUAGE,
+};
+
+use crate::utils::vector_store::{insert_content_stats, search_result_from_json_metadata};
+
+use super::*;
+
+impl EdgeVecActor {
+    pub(super) fn handle_get_stats(&self, collection: &str) -> HashMap<String, serde_json::Value> {
+        let vector_count = self.collection_metadata_len(collection);
+        let mut stats = HashMap::new();
+        stats.insert(
+            STATS_FIELD_COLLECTION.to_owned(),
+            serde_json::json!(collection),
+        );
+        stats.insert(
+            STATS_FIELD_VECTORS_COUNT.to_owned(),
+            serde_json::json!(vector_count),
+        );
+        stats.insert(
+            "tombstoned_vectors".to_owned(),
+            serde_json::json!(self.tombstone_count(collection)),
+        );
+        stats.insert(
+            "total_indexed_vectors".to_owned(),
+            serde_json::json!(self.index.len()),
+        );
+        stats.insert(
+            "dimensions".to_owned(),
+            serde_json::json!(self.config.dimensions),
+        );
+        stats.insert(
+            "storage_bytes".to_owned(),
+            serde_json::json!(
+                self.collection_bytes
+                    .get(collection)
+                    .map_or(0, |used| *used.value())
+            ),
+        );
+        if let Ok(files) = self.handle_list_file_paths(collection, STATS_FILE_SCAN_LIMIT) {
+            insert_content_stats(&mut stats, &files);
+        }
+        stats
+    }
+
+    pub(super) fn handle_list_collections(&self) -> Vec<CollectionInfo> {
+        self.metadata_store
+            .iter()
+            .map(|entry| {
+                let name = entry.key().clone();
+                let vector_count = entry.value().len() as u64;
+
+                // Count unique file paths
+                let file_paths: std::collections::HashSet<&str> = entry
+                    .value()
+                    .values()
+                    .filter_map(|v| {
+                        v.as_object()
+                            .and_then(|o| o.get(VECTOR_FIELD_FILE_PATH))
+                            .and_then(|v| v.as_str())
+                    })
+                    .collect();
+                let file_count = file_paths.len() as u64;
+
+                CollectionInfo::new(name, vector_count, file_count, None, "edgevec")
+            })
+            .collect()
+    }
+
+    pub(super) fn handle_list_file_paths(
+        &self,
+        collection: &str,
+        limit: usize,
+    ) -> Result<Vec<FileInfo>> {
+        let collection_metadata = self
+            .get_collection_metadata(collection)
+            .ok_or_else(|| Error::vector_db(format!("Collection '{collection}' not found")))?;
+
+        let mut file_map: HashMap<String, (u32, String)> = HashMap::new();
+
+        for meta_val in collection_metadata.values() {
+            if let Some(meta) = meta_val.as_object()
+                && let Some(file_path) = meta.get(VECTOR_FIELD_FILE_PATH).and_then(|v| v.as_str())
+            {
+                let language = meta
+                    .get(VECTOR_FIELD_LANGUAGE)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown")
+                    .to_owned();
+
+                let entry = file_map
+                    .entry(file_path.to_owned())
+                    .or_insert((0, language));
+                entry.0 += 1;
+            }
+        }
+
+        let files = file_map
+            .into_iter()
+            .take(limit)
+            .map(|(path, (chunk_count, language))| FileInfo::new(path, chunk_count, language, None))
+            .collect();
+        Ok(files)
+    }
+
+    pub(super) fn handle_get_chunks_by_file(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> Result<Vec<SearchResult>> {
+        let mut results = Vec::new();
+        // Normalize to forward slashes for cross-platform path matching
+        let normalized_query = file_path.replace('\\', "/");
+        if let Some(collection_metadata) = self.get_collection_metadata(collection) {
+            for (ext_id, meta_val) in collection_metadata.iter() {
+                if let Some(meta) = meta_val.as_object()
+                    && meta
+                        .get(VECTOR_FIELD_FILE_PATH)
+                        .and_then(|v| v.as_str())
+                        .is_some_and(|p| p.replace('\\', "/") == normalized_query)
+                {
+                    let mut result =
+                        search_result_from_json_metadata(ext_id.to_owned(), meta_val, 1.0);
+                    result.file_path = file_path.to_owned();
+                    results.push(result);
+                }
+            }
+        }
+        // Sort by start_line
+        results.sort_by_key(|r| r.start_line);
+        Ok(results)
+    }
+}
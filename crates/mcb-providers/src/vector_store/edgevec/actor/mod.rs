@@ -0,0 +1,277 @@
+//! Single-threaded actor that owns the `EdgeVec` index.
+//!
+//! The actor is split by concern: [`replay`] logs writes and recovers them,
+//! [`aliases`] manages collections and aliases, [`records`] applies writes,
+//! [`retention`] keeps tombstones for point-in-time search, [`quota`] counts
+//! stored bytes, and [`search`] and [`browse`] answer reads.
+
+mod aliases;
+mod browse;
+mod quota;
+mod records;
+mod replay;
+mod retention;
+mod search;
+
+use std::time::Duration;
+
+use super::persistence::Persistence;
+use super::*;
+
+/// A deleted or replaced record kept in the index for point-in-time search.
+struct Tombstone {
+    collection: String,
+    external_id: String,
+    metadata: serde_json::Value,
+    deleted_at: i64,
+}
+
+/// Single-threaded owner of the `EdgeVec` index, storage, and metadata.
+///
+/// Runs in its own task and serializes all vector-store operations by
+/// processing [`EdgeVecMessage`] values received on `receiver`.
+pub struct EdgeVecActor {
+    receiver: mpsc::Receiver<EdgeVecMessage>,
+    index: edgevec::HnswIndex,
+    storage: edgevec::VectorStorage,
+    metadata_store: DashMap<String, CollectionMetadata>,
+    /// HNSW vector id of each record, keyed by `(collection, external id)`.
+    id_map: DashMap<(String, String), VectorId>,
+    /// Insertion time (Unix seconds) of every vector still in the index.
+    inserted_at: DashMap<VectorId, i64>,
+    /// Deleted or replaced vectors, kept in the index until retention expires.
+    tombstones: DashMap<VectorId, Tombstone>,
+    /// Alias name to the collection it currently points at.
+    aliases: DashMap<String, String>,
+    /// Raw vectors for snapshots; only kept when persistence is enabled.
+    vectors: DashMap<VectorId, Vec<f32>>,
+    /// Collection and stored size (bytes) of every vector still in the index.
+    record_bytes: DashMap<VectorId, (String, u64)>,
+    /// Bytes held per collection, live records and tombstones together.
+    collection_bytes: DashMap<String, u64>,
+    persistence: Option<Persistence>,
+    config: EdgeVecConfig,
+}
+
+impl EdgeVecActor {
+    pub fn new(receiver: mpsc::Receiver<EdgeVecMessage>, config: EdgeVecConfig) -> Result<Self> {
+        let hnsw_config = edgevec::HnswConfig {
+            m: config.hnsw_config.m,
+            m0: config.hnsw_config.m0,
+            ef_construction: config.hnsw_config.ef_construction,
+            ef_search: config.hnsw_config.ef_search,
+            dimensions: config.dimensions as u32,
+            metric: match config.metric {
+                MetricType::L2Squared => edgevec::HnswConfig::METRIC_L2_SQUARED,
+                MetricType::Cosine => edgevec::HnswConfig::METRIC_COSINE,
+                MetricType::DotProduct => edgevec::HnswConfig::METRIC_DOT_PRODUCT,
+            },
+            _reserved: [0; 2],
+        };
+
+        let storage = edgevec::VectorStorage::new(&hnsw_config, None);
+        let index = edgevec::HnswIndex::new(hnsw_config, &storage)
+            .map_err(|e| Error::vector_db(format!("Failed to create EdgeVec HNSW index: {e}")))?;
+
+        config.quota.validate("quota")?;
+        let persistence = config.persistence.clone();
+        let mut actor = Self {
+            receiver,
+            index,
+            storage,
+            metadata_store: DashMap::new(),
+            id_map: DashMap::new(),
+            inserted_at: DashMap::new(),
+            tombstones: DashMap::new(),
+            aliases: DashMap::new(),
+            vectors: DashMap::new(),
+            record_bytes: DashMap::new(),
+            collection_bytes: DashMap::new(),
+            persistence: None,
+            config,
+        };
+        if let Some(policy) = persistence {
+            policy.validate("persistence")?;
+            let (persistence, snapshot, entries) = Persistence::open(&policy)?;
+            actor.persistence = Some(persistence);
+            actor.recover(snapshot, entries)?;
+        }
+        Ok(actor)
+    }
+
+    /// Process messages until every sender is dropped, flushing the log on
+    /// the persistence interval and writing a final snapshot on exit.
+    pub async fn run(mut self) {
+        let mut flush_timer =
+            self.config.persistence.as_ref().map(|policy| {
+                tokio::time::interval(Duration::from_millis(policy.flush_interval_ms))
+            });
+        loop {
+            tokio::select! {
+                msg = self.receiver.recv() => match msg {
+                    Some(EdgeVecMessage::Core(core)) => self.handle_core_message(core),
+                    Some(EdgeVecMessage::Query(query)) => self.handle_query_message(query),
+                    Some(EdgeVecMessage::Browse(browse)) => self.handle_browse_message(browse),
+                    None => break,
+                },
+                () = tick(&mut flush_timer) => {
+                    if let Err(e) = self.sync_to_disk(false) {
+                        mcb_domain::error!("edgevec", "Background persistence flush failed", &e);
+                    }
+                }
+            }
+        }
+        if let Err(e) = self.sync_to_disk(true) {
+            mcb_domain::error!("edgevec", "Final persistence snapshot failed", &e);
+        }
+    }
+
+    fn handle_core_message(&mut self, msg: CoreMessage) {
+        match msg {
+            CoreMessage::CreateCollection { name, tx } => {
+                let _ = tx.send(self.handle_create_collection(name));
+            }
+            CoreMessage::DeleteCollection { name, tx } => {
+                let _ = tx.send(self.handle_delete_collection(name));
+            }
+            CoreMessage::SwapAlias {
+                alias,
+                collection,
+                tx,
+            } => {
+                let _ = tx.send(self.handle_swap_alias(alias, collection));
+            }
+            CoreMessage::DeleteAlias { alias, tx } => {
+                let _ = tx.send(self.handle_delete_alias(alias));
+            }
+            CoreMessage::InsertVectors {
+                collection,
+                vectors,
+                metadata,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_insert_vectors(&collection, vectors, metadata));
+            }
+            CoreMessage::UpsertVectors {
+                collection,
+                ids,
+                vectors,
+                metadata,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_upsert_vectors(&collection, ids, vectors, metadata));
+            }
+            CoreMessage::SearchSimilar {
+                collection,
+                query_vector,
+                limit,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_search_similar(&collection, &query_vector, limit));
+            }
+            CoreMessage::SearchSimilarAsOf {
+                collection,
+                query_vector,
+                limit,
+                as_of,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_search_similar_as_of(
+                    &collection,
+                    &query_vector,
+                    limit,
+                    as_of,
+                ));
+            }
+            CoreMessage::DeleteVectors {
+                collection,
+                ids,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_delete_vectors(&collection, ids));
+            }
+            CoreMessage::Flush { tx } => {
+                let _ = tx.send(self.sync_to_disk(true));
+            }
+        }
+    }
+
+    fn handle_query_message(&mut self, msg: QueryMessage) {
+        match msg {
+            QueryMessage::GetStats { collection, tx } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(Ok(self.handle_get_stats(&collection)));
+            }
+            QueryMessage::ListVectors {
+                collection,
+                limit,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(Ok(self.handle_list_vectors(&collection, limit)));
+            }
+            QueryMessage::GetVectorsByIds {
+                collection,
+                ids,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(Ok(self.handle_get_vectors_by_ids(&collection, ids)));
+            }
+            QueryMessage::CollectionExists { name, tx } => {
+                let name = self.resolve(name);
+                let _ = tx.send(self.handle_collection_exists(&name));
+            }
+            QueryMessage::InspectChunk { collection, id, tx } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(Ok(self.handle_inspect_chunk(&collection, id)));
+            }
+            QueryMessage::StorageUsage { tx } => {
+                let _ = tx.send(Ok(self.handle_storage_usage()));
+            }
+            QueryMessage::HealthCheck { tx } => {
+                let _ = tx.send(self.handle_health_check());
+            }
+        }
+    }
+
+    fn handle_browse_message(&mut self, msg: BrowseMessage) {
+        match msg {
+            BrowseMessage::ListCollections { tx } => {
+                let _ = tx.send(Ok(self.handle_list_collections()));
+            }
+            BrowseMessage::ListFilePaths {
+                collection,
+                limit,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_list_file_paths(&collection, limit));
+            }
+            BrowseMessage::GetChunksByFile {
+                collection,
+                file_path,
+                tx,
+            } => {
+                let collection = self.resolve(collection);
+                let _ = tx.send(self.handle_get_chunks_by_file(&collection, &file_path));
+            }
+        }
+    }
+}
+
+/// Wait for the next persistence flush; never completes when persistence is
+/// disabled.
+async fn tick(timer: &mut Option<tokio::time::Interval>) {
+    match timer {
+        Some(timer) => {
+            timer.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
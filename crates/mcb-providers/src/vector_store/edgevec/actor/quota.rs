@@ -0,0 +1,36 @@
+//! Storage accounting behind the storage quota.
+
+use std::collections::BTreeMap;
+
+use super::*;
+
+impl EdgeVecActor {
+    /// Count `size` bytes of `vector_id` against `collection`.
+    pub(super) fn track_bytes(&self, vector_id: VectorId, collection: &str, size: u64) {
+        self.record_bytes
+            .insert(vector_id, (collection.to_owned(), size));
+        *self
+            .collection_bytes
+            .entry(collection.to_owned())
+            .or_default() += size;
+    }
+
+    pub(super) fn handle_storage_usage(&self) -> StorageUsage {
+        let collections: BTreeMap<String, u64> = self
+            .collection_bytes
+            .iter()
+            .map(|entry| (entry.key().clone(), *entry.value()))
+            .collect();
+        StorageUsage {
+            total_bytes: collections.values().sum(),
+            collections,
+            quota: self.config.quota,
+        }
+    }
+}
+
+/// Bytes a record occupies: its `f32` components plus its metadata as JSON.
+pub(super) fn record_size(dimensions: usize, metadata: &impl serde::Serialize) -> u64 {
+    let metadata_len = serde_json::to_vec(metadata).map_or(0, |bytes| bytes.len());
+    (dimensions * size_of::<f32>() + metadata_len) as u64
+}
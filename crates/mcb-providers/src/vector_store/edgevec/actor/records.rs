@@ -0,0 +1,180 @@
+//! Inserting, upserting and deleting records.
+
+use mcb_utils::utils::time::epoch_secs_i64;
+
+use crate::vector_store::edgevec::persistence::WalEntry;
+
+use super::quota::record_size;
+use super::*;
+
+impl EdgeVecActor {
+    pub(super) fn get_collection_metadata(
+        &self,
+        name: &str,
+    ) -> Option<dashmap::mapref::one::Ref<'_, String, CollectionMetadata>> {
+        self.metadata_store.get(name)
+    }
+
+    pub(super) fn collection_metadata_len(&self, name: &str) -> usize {
+        self.get_collection_metadata(name).map_or(0, |m| m.len())
+    }
+
+    pub(super) fn handle_insert_vectors(
+        &mut self,
+        collection: &str,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>> {
+        let ids = vectors
+            .iter()
+            .map(|_| format!("{}_{}", collection, id::generate()))
+            .collect();
+        self.handle_upsert_vectors(collection, ids, vectors, metadata)
+    }
+
+    pub(super) fn handle_upsert_vectors(
+        &mut self,
+        collection: &str,
+        ids: Vec<String>,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>> {
+        self.validate_upsert(&ids, &vectors, &metadata)?;
+        if self.config.quota.enforced() {
+            let incoming = vectors
+                .iter()
+                .zip(&metadata)
+                .map(|(embedding, meta)| record_size(embedding.vector.len(), meta))
+                .sum();
+            self.config
+                .quota
+                .check_write(collection, &self.handle_storage_usage(), incoming)?;
+        }
+        self.write(WalEntry::Upsert {
+            collection: collection.to_owned(),
+            ids,
+            vectors: vectors.into_iter().map(|e| e.vector).collect(),
+            metadata,
+            at: epoch_secs_i64()?,
+        })
+    }
+
+    /// Reject an upsert the index would fail part-way through, before it is
+    /// logged.
+    fn validate_upsert(
+        &self,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: &[HashMap<String, serde_json::Value>],
+    ) -> Result<()> {
+        if ids.len() != vectors.len() || metadata.len() != vectors.len() {
+            return Err(Error::vector_db(format!(
+                "Upsert has {} ids, {} vectors and {} metadata entries",
+                ids.len(),
+                vectors.len(),
+                metadata.len()
+            )));
+        }
+        for (id, embedding) in ids.iter().zip(vectors) {
+            if embedding.vector.len() != self.config.dimensions {
+                return Err(Error::vector_db(format!(
+                    "Vector '{id}' has {} dimensions, expected {}",
+                    embedding.vector.len(),
+                    self.config.dimensions
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Store each vector under its external id at time `now`, retiring the
+    /// vector it replaces (if any).
+    pub(super) fn apply_upsert(
+        &mut self,
+        collection: &str,
+        ids: Vec<String>,
+        vectors: Vec<Vec<f32>>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+        now: i64,
+    ) -> Result<Vec<String>> {
+        self.purge_expired_tombstones(now);
+
+        let mut stored = Vec::with_capacity(ids.len());
+        let mut replaced = Vec::new();
+        let mut failure = None;
+        {
+            let mut collection_metadata = self
+                .metadata_store
+                .entry(collection.to_owned())
+                .or_default();
+
+            for ((external_id, vector), meta) in ids.into_iter().zip(vectors).zip(metadata) {
+                let dimensions = vector.len();
+                let vector_id = match self.index.insert(&vector, &mut self.storage) {
+                    Ok(vector_id) => vector_id,
+                    Err(e) => {
+                        failure = Some(Error::vector_db(format!("Failed to insert vector: {e}")));
+                        break;
+                    }
+                };
+                self.inserted_at.insert(vector_id, now);
+                if self.persistence.is_some() {
+                    self.vectors.insert(vector_id, vector);
+                }
+                let previous = self
+                    .id_map
+                    .insert((collection.to_owned(), external_id.clone()), vector_id);
+                let mut enriched_metadata = meta;
+                enriched_metadata.insert("id".to_owned(), serde_json::json!(external_id));
+                self.track_bytes(
+                    vector_id,
+                    collection,
+                    record_size(dimensions, &enriched_metadata),
+                );
+                let previous_metadata = collection_metadata
+                    .insert(external_id.clone(), serde_json::json!(enriched_metadata));
+                if let Some(previous) = previous {
+                    replaced.push((external_id.clone(), previous, previous_metadata));
+                }
+                stored.push(external_id);
+            }
+        }
+
+        for (external_id, vector_id, previous_metadata) in replaced {
+            self.retire(collection, external_id, vector_id, previous_metadata, now);
+        }
+        failure.map_or(Ok(stored), Err)
+    }
+
+    pub(super) fn handle_delete_vectors(
+        &mut self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> Result<()> {
+        self.write(WalEntry::Delete {
+            collection: collection.to_owned(),
+            ids,
+            at: epoch_secs_i64()?,
+        })?;
+        Ok(())
+    }
+
+    pub(super) fn apply_delete(&mut self, collection: &str, ids: Vec<String>, now: i64) {
+        self.purge_expired_tombstones(now);
+
+        let mut removed = Vec::new();
+        if let Some(mut collection_metadata) = self.metadata_store.get_mut(collection) {
+            for id in ids {
+                let metadata = collection_metadata.remove(&id);
+                if let Some((_, vector_id)) =
+                    self.id_map.remove(&(collection.to_owned(), id.clone()))
+                {
+                    removed.push((id, vector_id, metadata));
+                }
+            }
+        }
+        for (external_id, vector_id, metadata) in removed {
+            self.retire(collection, external_id, vector_id, metadata, now);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Write-ahead logging, disk sync and crash recovery of the actor.
+
+use crate::vector_store::edgevec::persistence::{Snapshot, StoredRecord, WalEntry};
+
+use super::quota::record_size;
+use super::*;
+
+impl EdgeVecActor {
+    /// Rebuild the store from the snapshot, then replay the log written
+    /// after it.
+    pub(super) fn recover(
+        &mut self,
+        snapshot: Option<Snapshot>,
+        entries: Vec<WalEntry>,
+    ) -> Result<()> {
+        if let Some(snapshot) = snapshot {
+            for name in snapshot.collections {
+                self.metadata_store.entry(name).or_default();
+            }
+            self.aliases.extend(snapshot.aliases);
+            for record in snapshot.records {
+                self.restore_record(record)?;
+            }
+        }
+        for entry in entries {
+            if let Err(e) = self.apply(entry) {
+                mcb_domain::warn!("edgevec", "Skipping write-ahead log entry", &e);
+            }
+        }
+        Ok(())
+    }
+
+    fn restore_record(&mut self, record: StoredRecord) -> Result<()> {
+        let vector_id = self
+            .index
+            .insert(&record.vector, &mut self.storage)
+            .map_err(|e| Error::vector_db(format!("Failed to restore vector: {e}")))?;
+        self.inserted_at.insert(vector_id, record.inserted_at);
+        self.track_bytes(
+            vector_id,
+            &record.collection,
+            record_size(record.vector.len(), &record.metadata),
+        );
+        self.vectors.insert(vector_id, record.vector);
+        match record.deleted_at {
+            Some(deleted_at) => {
+                self.tombstones.insert(
+                    vector_id,
+                    Tombstone {
+                        collection: record.collection,
+                        external_id: record.id,
+                        metadata: record.metadata,
+                        deleted_at,
+                    },
+                );
+            }
+            None => {
+                self.metadata_store
+                    .entry(record.collection.clone())
+                    .or_default()
+                    .insert(record.id.clone(), record.metadata);
+                self.id_map
+                    .insert((record.collection, record.id), vector_id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Log `entry` when persistence is enabled, then apply it.
+    ///
+    /// The entry is only buffered here: it reaches the disk at the next
+    /// `sync_to_disk`, so a crash in between loses the write even though it
+    /// was acknowledged. Callers validate entries first, so everything logged
+    /// applies cleanly on replay.
+    pub(super) fn write(&mut self, entry: WalEntry) -> Result<Vec<String>> {
+        if let Some(persistence) = self.persistence.as_mut() {
+            persistence.append(&entry)?;
+        }
+        self.apply(entry)
+    }
+
+    /// Apply a write to the in-memory store; shared by live writes and
+    /// log replay.
+    fn apply(&mut self, entry: WalEntry) -> Result<Vec<String>> {
+        match entry {
+            WalEntry::CreateCollection { name } => {
+                self.metadata_store.entry(name).or_default();
+                Ok(Vec::new())
+            }
+            WalEntry::DeleteCollection { name } => {
+                self.apply_delete_collection(&name);
+                Ok(Vec::new())
+            }
+            WalEntry::SwapAlias { alias, collection } => {
+                self.aliases.insert(alias, collection);
+                Ok(Vec::new())
+            }
+            WalEntry::DeleteAlias { alias } => {
+                self.aliases.remove(&alias);
+                Ok(Vec::new())
+            }
+            WalEntry::Upsert {
+                collection,
+                ids,
+                vectors,
+                metadata,
+                at,
+            } => self.apply_upsert(&collection, ids, vectors, metadata, at),
+            WalEntry::Delete {
+                collection,
+                ids,
+                at,
+            } => {
+                self.apply_delete(&collection, ids, at);
+                Ok(Vec::new())
+            }
+        }
+    }
+
+    /// Fail with the startup integrity findings, if there were any.
+    pub(super) fn handle_health_check(&self) -> Result<()> {
+        let problems = self
+            .persistence
+            .as_ref()
+            .and_then(|persistence| persistence.integrity().problems());
+        match problems {
+            Some(problems) => Err(Error::vector_db(format!(
+                "EdgeVec startup integrity check: {problems}"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Write buffered log entries to disk and, when `force_snapshot` is set or the
+    /// snapshot interval has passed, compact them into a new snapshot.
+    pub(super) fn sync_to_disk(&mut self, force_snapshot: bool) -> Result<()> {
+        let Some(persistence) = self.persistence.as_mut() else {
+            return Ok(());
+        };
+        persistence.flush()?;
+        let snapshot_due = if force_snapshot {
+            persistence.is_dirty()
+        } else {
+            persistence.snapshot_due()
+        };
+        if snapshot_due {
+            let snapshot = self.snapshot();
+            if let Some(persistence) = self.persistence.as_mut() {
+                persistence.write_snapshot(&snapshot)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Copy of every live record and tombstone, with its raw vector.
+    fn snapshot(&self) -> Snapshot {
+        let collections = self
+            .metadata_store
+            .iter()
+            .map(|entry| entry.key().clone())
+            .collect();
+        let mut records = Vec::with_capacity(self.inserted_at.len());
+        for entry in &self.id_map {
+            let (collection, id) = entry.key();
+            let metadata = self
+                .get_collection_metadata(collection)
+                .and_then(|m| m.get(id).cloned());
+            if let Some(metadata) = metadata {
+                records.extend(self.stored_record(
+                    *entry.value(),
+                    collection.clone(),
+                    id.clone(),
+                    metadata,
+                    None,
+                ));
+            }
+        }
+        for entry in &self.tombstones {
+            records.extend(self.stored_record(
+                *entry.key(),
+                entry.collection.clone(),
+                entry.external_id.clone(),
+                entry.metadata.clone(),
+                Some(entry.deleted_at),
+            ));
+        }
+        let aliases = self
+            .aliases
+            .iter()
+            .map(|entry| (entry.key().clone(), entry.value().clone()))
+            .collect();
+        Snapshot::new(collections, aliases, records)
+    }
+
+    fn stored_record(
+        &self,
+        vector_id: VectorId,
+        collection: String,
+        id: String,
+        metadata: serde_json::Value,
+        deleted_at: Option<i64>,
+    ) -> Option<StoredRecord> {
+        Some(StoredRecord {
+            collection,
+            id,
+            vector: self.vectors.get(&vector_id)?.clone(),
+            metadata,
+            inserted_at: self.inserted_at.get(&vector_id).map_or(0, |at| *at),
+            deleted_at,
+        })
+    }
+}
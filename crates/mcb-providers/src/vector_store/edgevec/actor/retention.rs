@@ -0,0 +1,76 @@
+//! Tombstones of deleted and replaced records and their retention window.
+
+use super::*;
+
+impl EdgeVecActor {
+    /// Take a record out of the live maps: keep it as a tombstone while
+    /// retention is enabled, otherwise drop it from the index right away.
+    pub(super) fn retire(
+        &mut self,
+        collection: &str,
+        external_id: String,
+        vector_id: VectorId,
+        metadata: Option<serde_json::Value>,
+        deleted_at: i64,
+    ) {
+        match metadata {
+            Some(metadata) if self.config.tombstone_retention_secs > 0 => {
+                self.tombstones.insert(
+                    vector_id,
+                    Tombstone {
+                        collection: collection.to_owned(),
+                        external_id,
+                        metadata,
+                        deleted_at,
+                    },
+                );
+            }
+            _ => self.purge(vector_id),
+        }
+    }
+
+    /// Remove a vector from the index and forget its history.
+    pub(super) fn purge(&mut self, vector_id: VectorId) {
+        if let Err(e) = self.index.soft_delete(vector_id) {
+            mcb_domain::warn!(
+                "edgevec",
+                "Failed to delete vector from index",
+                &format!("vector_id = {}, error = {e}", vector_id.0)
+            );
+        }
+        self.inserted_at.remove(&vector_id);
+        self.tombstones.remove(&vector_id);
+        self.vectors.remove(&vector_id);
+        if let Some((_, (collection, size))) = self.record_bytes.remove(&vector_id)
+            && let Some(mut used) = self.collection_bytes.get_mut(&collection)
+        {
+            *used = used.saturating_sub(size);
+        }
+    }
+
+    /// Start of the point-in-time window: the oldest `as_of` still answerable.
+    pub(super) fn retention_window_start(&self, now: i64) -> i64 {
+        let retention = i64::try_from(self.config.tombstone_retention_secs).unwrap_or(i64::MAX);
+        now.saturating_sub(retention)
+    }
+
+    pub(super) fn purge_expired_tombstones(&mut self, now: i64) {
+        let cutoff = self.retention_window_start(now);
+        let expired: Vec<VectorId> = self
+            .tombstones
+            .iter()
+            .filter(|entry| entry.deleted_at <= cutoff)
+            .map(|entry| *entry.key())
+            .collect();
+        for vector_id in expired {
+            self.purge(vector_id);
+        }
+    }
+
+    pub(super) fn tombstone_count(&self, collection: &str) -> usize {
+        self.tombstones
+            .iter()
+            .filter(|entry| entry.collection == collection)
+            .count()
+    }
+}
@@ -0,0 +1,204 @@
+//! Similarity search, including point-in-time search, and lookups by id.
+
+use mcb_utils::utils::time::epoch_secs_i64;
+
+use crate::utils::vector_store::search_result_from_json_metadata;
+
+use super::*;
+
+impl EdgeVecActor {
+    pub(super) fn handle_get_vectors_by_ids(
+        &self,
+        collection: &str,
+        ids: Vec<String>,
+    ) -> Vec<SearchResult> {
+        let mut final_results = Vec::new();
+        if let Some(collection_metadata) = self.get_collection_metadata(collection) {
+            for id in ids {
+                if let Some(meta_val) = collection_metadata.get(&id) {
+                    final_results.push(search_result_from_json_metadata(id, meta_val, 1.0));
+                }
+            }
+        }
+        final_results
+    }
+
+    /// Stored record of `id`; the raw vector is only kept (and returned)
+    /// when persistence is enabled.
+    pub(super) fn handle_inspect_chunk(
+        &self,
+        collection: &str,
+        id: String,
+    ) -> Option<ChunkInspection> {
+        let vector_id = *self.id_map.get(&(collection.to_owned(), id.clone()))?;
+        let metadata = self
+            .get_collection_metadata(collection)?
+            .get(&id)?
+            .as_object()
+            .map(|fields| fields.clone().into_iter().collect())
+            .unwrap_or_default();
+        let mut location = HashMap::from([
+            ("vector_id".to_owned(), serde_json::json!(vector_id.0)),
+            (
+                "inserted_at".to_owned(),
+                serde_json::json!(self.inserted_at.get(&vector_id).map(|at| *at)),
+            ),
+        ]);
+        if let Some(policy) = &self.config.persistence {
+            location.insert(
+                "data_dir".to_owned(),
+                serde_json::json!(policy.data_dir.display().to_string()),
+            );
+        }
+        Some(ChunkInspection::new(
+            id,
+            collection,
+            "edgevec",
+            metadata,
+            self.vectors.get(&vector_id).map(|v| v.clone()),
+            location,
+        ))
+    }
+
+    pub(super) fn handle_list_vectors(&self, collection: &str, limit: usize) -> Vec<SearchResult> {
+        let mut final_results = Vec::new();
+        if let Some(collection_metadata) = self.get_collection_metadata(collection) {
+            for (ext_id, meta_val) in collection_metadata.iter().take(limit) {
+                final_results.push(search_result_from_json_metadata(
+                    ext_id.to_owned(),
+                    meta_val,
+                    1.0,
+                ));
+            }
+        }
+        final_results
+    }
+
+    pub(super) fn handle_search_similar(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+    ) -> Result<Vec<SearchResult>> {
+        let fetch_limit = self.fetch_limit(limit, self.collection_metadata_len(collection));
+        let results = self
+            .index
+            .search(query_vector, fetch_limit, &self.storage)
+            .map_err(|e| Error::vector_db(format!("Search failed: {e}")))?;
+
+        let Some(collection_metadata) = self.get_collection_metadata(collection) else {
+            return Ok(Vec::new());
+        };
+
+        let mut final_results = Vec::with_capacity(limit);
+        for res in results {
+            if final_results.len() >= limit {
+                break;
+            }
+            let Some(ext_id) = self.external_id_for(collection, res.vector_id) else {
+                continue;
+            };
+            if let Some(meta_val) = collection_metadata.get(&ext_id) {
+                final_results.push(search_result_from_json_metadata(
+                    ext_id,
+                    meta_val,
+                    res.distance as f64,
+                ));
+            }
+        }
+        Ok(final_results)
+    }
+
+    /// Search as of `as_of`: skip vectors inserted later and resurrect
+    /// tombstones retired after it.
+    pub(super) fn handle_search_similar_as_of(
+        &self,
+        collection: &str,
+        query_vector: &[f32],
+        limit: usize,
+        as_of: i64,
+    ) -> Result<Vec<SearchResult>> {
+        if self.config.tombstone_retention_secs == 0 {
+            return Err(Error::vector_db(
+                "Point-in-time search is disabled (tombstone retention is 0)",
+            ));
+        }
+        let window_start = self.retention_window_start(epoch_secs_i64()?);
+        if as_of < window_start {
+            return Err(Error::vector_db(format!(
+                "as_of {as_of} is before the tombstone retention window (starts at {window_start})"
+            )));
+        }
+
+        let collection_size =
+            self.collection_metadata_len(collection) + self.tombstone_count(collection);
+        let results = self
+            .index
+            .search(
+                query_vector,
+                self.fetch_limit(limit, collection_size),
+                &self.storage,
+            )
+            .map_err(|e| Error::vector_db(format!("Search failed: {e}")))?;
+        let collection_metadata = self.get_collection_metadata(collection);
+
+        let mut final_results = Vec::with_capacity(limit);
+        for res in results {
+            if final_results.len() >= limit {
+                break;
+            }
+            let existed = self
+                .inserted_at
+                .get(&res.vector_id)
+                .is_some_and(|inserted_at| *inserted_at <= as_of);
+            if !existed {
+                continue;
+            }
+            let score = res.distance as f64;
+            let hit = match self.tombstones.get(&res.vector_id) {
+                Some(tombstone) => (tombstone.collection == collection
+                    && tombstone.deleted_at > as_of)
+                    .then(|| {
+                        search_result_from_json_metadata(
+                            tombstone.external_id.clone(),
+                            &tombstone.metadata,
+                            score,
+                        )
+                    }),
+                None => self
+                    .external_id_for(collection, res.vector_id)
+                    .and_then(|ext_id| {
+                        let meta_val = collection_metadata.as_ref()?.get(&ext_id)?;
+                        Some(search_result_from_json_metadata(ext_id, meta_val, score))
+                    }),
+            };
+            final_results.extend(hit);
+        }
+        Ok(final_results)
+    }
+
+    /// Candidates to fetch from the shared index for `limit` results.
+    ///
+    /// The HNSW index is global (shared across all collections and
+    /// tombstones), so when it holds more than `collection_size` vectors we
+    /// must over-fetch to ensure enough results survive the per-collection
+    /// filter.
+    fn fetch_limit(&self, limit: usize, collection_size: usize) -> usize {
+        let total_vectors = self.index.len();
+        if collection_size > 0 && total_vectors > collection_size {
+            let ratio = (total_vectors as f64 / collection_size as f64).ceil() as usize;
+            (limit * ratio).min(total_vectors)
+        } else {
+            limit
+        }
+    }
+
+    /// Resolve the external string id in `collection` for an internal HNSW
+    /// vector id.
+    fn external_id_for(&self, collection: &str, vector_id: VectorId) -> Option<String> {
+        self.id_map
+            .iter()
+            .find(|entry| *entry.value() == vector_id && entry.key().0 == collection)
+            .map(|entry| entry.key().1.clone())
+    }
+}
@@ -3,7 +3,10 @@
 use std::collections::HashMap;
 
 use mcb_domain::error::{Error, Result};
-use mcb_domain::value_objects::{CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::ports::{InsertBatchPolicy, StorageUsage};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult,
+};
 use mcb_utils::utils::id;
 use tokio::sync::{mpsc, oneshot};
 
@@ -20,23 +23,49 @@ pub(super) enum CoreMessage {
         name: String,
         tx: oneshot::Sender<Result<()>>,
     },
+    SwapAlias {
+        alias: String,
+        collection: String,
+        tx: oneshot::Sender<Result<()>>,
+    },
+    DeleteAlias {
+        alias: String,
+        tx: oneshot::Sender<Result<()>>,
+    },
     InsertVectors {
         collection: String,
         vectors: Vec<Embedding>,
         metadata: Vec<HashMap<String, serde_json::Value>>,
         tx: oneshot::Sender<Result<Vec<String>>>,
     },
+    UpsertVectors {
+        collection: String,
+        ids: Vec<String>,
+        vectors: Vec<Embedding>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+        tx: oneshot::Sender<Result<Vec<String>>>,
+    },
     SearchSimilar {
         collection: String,
         query_vector: Vec<f32>,
         limit: usize,
         tx: oneshot::Sender<Result<Vec<SearchResult>>>,
     },
+    SearchSimilarAsOf {
+        collection: String,
+        query_vector: Vec<f32>,
+        limit: usize,
+        as_of: i64,
+        tx: oneshot::Sender<Result<Vec<SearchResult>>>,
+    },
     DeleteVectors {
         collection: String,
         ids: Vec<String>,
         tx: oneshot::Sender<Result<()>>,
     },
+    Flush {
+        tx: oneshot::Sender<Result<()>>,
+    },
 }
 
 /// Query and stats messages
@@ -59,6 +88,17 @@ pub(super) enum QueryMessage {
         name: String,
         tx: oneshot::Sender<Result<bool>>,
     },
+    InspectChunk {
+        collection: String,
+        id: String,
+        tx: oneshot::Sender<Result<Option<ChunkInspection>>>,
+    },
+    StorageUsage {
+        tx: oneshot::Sender<Result<StorageUsage>>,
+    },
+    HealthCheck {
+        tx: oneshot::Sender<Result<()>>,
+    },
 }
 
 /// Browse API messages
@@ -91,6 +131,7 @@ pub(super) type CollectionMetadata = HashMap<String, serde_json::Value>;
 pub struct EdgeVecVectorStoreProvider {
     pub(super) sender: mpsc::Sender<EdgeVecMessage>,
     pub(super) _collection: CollectionId,
+    pub(super) insert_batch: InsertBatchPolicy,
 }
 
 impl EdgeVecVectorStoreProvider {
@@ -144,6 +185,7 @@ impl EdgeVecVectorStoreProvider {
         Ok(Self {
             sender: tx,
             _collection: generated_collection,
+            insert_batch: InsertBatchPolicy::default(),
         })
     }
 
@@ -164,6 +206,15 @@ impl EdgeVecVectorStoreProvider {
         Ok(Self {
             sender: tx,
             _collection: collection,
+            insert_batch: InsertBatchPolicy::default(),
         })
     }
+
+    /// Split large inserts into batches per `policy`, so searches and other
+    /// actor messages interleave with a long initial index.
+    #[must_use]
+    pub fn with_insert_batch(mut self, policy: InsertBatchPolicy) -> Self {
+        self.insert_batch = policy;
+        self
+    }
 }
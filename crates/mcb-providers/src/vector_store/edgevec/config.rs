@@ -1,6 +1,8 @@
+use mcb_domain::ports::{PersistencePolicy, StorageQuotaPolicy};
+use mcb_domain::value_objects::DistanceMetric;
 use mcb_utils::constants::vector_store::{
     EDGEVEC_DEFAULT_DIMENSIONS, EDGEVEC_HNSW_EF_CONSTRUCTION, EDGEVEC_HNSW_EF_SEARCH,
-    EDGEVEC_HNSW_M, EDGEVEC_HNSW_M0, EDGEVEC_QUANTIZATION_TYPE,
+    EDGEVEC_HNSW_M, EDGEVEC_HNSW_M0, EDGEVEC_QUANTIZATION_TYPE, VECTOR_TOMBSTONE_RETENTION_SECS,
 };
 
 /// `EdgeVec` vector store configuration
@@ -25,12 +27,30 @@ pub struct EdgeVecConfig {
     /// Quantization configuration
     #[serde(default)]
     pub quantizer_config: QuantizerConfig,
+
+    /// Seconds deleted and replaced vectors stay searchable with `as_of`
+    /// (0 removes them immediately)
+    #[serde(default = "default_tombstone_retention_secs")]
+    pub tombstone_retention_secs: u64,
+
+    /// Snapshot and write-ahead log location (`None` keeps vectors in memory
+    /// only)
+    #[serde(default)]
+    pub persistence: Option<PersistencePolicy>,
+
+    /// Storage limits; writes past a limit are rejected
+    #[serde(default)]
+    pub quota: StorageQuotaPolicy,
 }
 
 fn default_dimensions() -> usize {
     EDGEVEC_DEFAULT_DIMENSIONS
 }
 
+fn default_tombstone_retention_secs() -> u64 {
+    VECTOR_TOMBSTONE_RETENTION_SECS
+}
+
 /// HNSW configuration for `EdgeVec`
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct HnswConfig {
@@ -87,6 +107,16 @@ pub enum MetricType {
     DotProduct,
 }
 
+impl From<DistanceMetric> for MetricType {
+    fn from(metric: DistanceMetric) -> Self {
+        match metric {
+            DistanceMetric::Cosine => Self::Cosine,
+            DistanceMetric::Dot => Self::DotProduct,
+            DistanceMetric::Euclidean => Self::L2Squared,
+        }
+    }
+}
+
 /// Quantization configuration for memory optimization
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize, schemars::JsonSchema)]
 pub struct QuantizerConfig {
@@ -111,6 +141,9 @@ impl Default for EdgeVecConfig {
             metric: MetricType::default(),
             use_quantization: false,
             quantizer_config: QuantizerConfig::default(),
+            tombstone_retention_secs: default_tombstone_retention_secs(),
+            persistence: None,
+            quota: StorageQuotaPolicy::default(),
         }
     }
 }
@@ -8,6 +8,7 @@ mod actor;
 mod client;
 /// `EdgeVec` provider configuration types.
 pub mod config;
+mod persistence;
 mod provider;
 mod registry;
 
@@ -22,7 +23,12 @@ use client::*;
 use dashmap::DashMap;
 use edgevec::hnsw::VectorId;
 use mcb_domain::error::{Error, Result};
-use mcb_domain::ports::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider};
-use mcb_domain::value_objects::{CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult};
+use mcb_domain::ports::{
+    StorageUsage, VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities,
+    VectorStoreProvider,
+};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
+};
 use mcb_utils::utils::id;
 use tokio::sync::mpsc;
@@ -0,0 +1,418 @@
+//! Snapshot and write-ahead log storage for the `EdgeVec` actor.
+//!
+//! Every write is appended to the log before it is applied. A snapshot holds
+//! the full store (live records and tombstones, with their raw vectors) and
+//! replaces the log when written. Recovery loads the snapshot and replays the
+//! log; replaying a write the snapshot already contains is harmless because
+//! every logged write carries explicit record IDs.
+//!
+//! Opening the store checks its integrity: a snapshot whose checksum does not
+//! match (or that does not parse) is quarantined next to the original, and a
+//! torn log tail is truncated. Both findings are kept in an
+//! [`IntegrityReport`] that the provider's health check reports.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use mcb_domain::ports::PersistencePolicy;
+use mcb_utils::constants::vector_store::{EDGEVEC_SNAPSHOT_FILE, EDGEVEC_WAL_FILE};
+use mcb_utils::utils::crypto::bytes_to_hex;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::*;
+
+/// Snapshot file format version.
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// Leading bytes of a zstd frame; snapshots without them are plain JSON.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+/// A logged write, replayed on startup with its original timestamp.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub(super) enum WalEntry {
+    CreateCollection {
+        name: String,
+    },
+    DeleteCollection {
+        name: String,
+    },
+    SwapAlias {
+        alias: String,
+        collection: String,
+    },
+    DeleteAlias {
+        alias: String,
+    },
+    Upsert {
+        collection: String,
+        ids: Vec<String>,
+        vectors: Vec<Vec<f32>>,
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+        at: i64,
+    },
+    Delete {
+        collection: String,
+        ids: Vec<String>,
+        at: i64,
+    },
+}
+
+/// One stored vector; `deleted_at` is set for tombstones.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct StoredRecord {
+    pub collection: String,
+    pub id: String,
+    pub vector: Vec<f32>,
+    pub metadata: serde_json::Value,
+    pub inserted_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+/// Full store contents at the time it was written.
+#[derive(Debug, Serialize, Deserialize)]
+pub(super) struct Snapshot {
+    pub version: u32,
+    pub collections: Vec<String>,
+    /// Alias name to target collection.
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
+    pub records: Vec<StoredRecord>,
+}
+
+/// First line of a snapshot file: the checksum of the JSON body after it.
+///
+/// Snapshots written before checksums were added are a bare body with no
+/// header line, and load unverified.
+#[derive(Serialize, Deserialize)]
+struct SnapshotHeader {
+    sha256: String,
+}
+
+/// What the integrity check found when the store was opened.
+#[derive(Debug, Default)]
+pub(super) struct IntegrityReport {
+    /// Where a snapshot that failed its checksum or did not parse was moved.
+    pub quarantined_snapshot: Option<PathBuf>,
+    /// Bytes of torn log tail dropped from the end of the write-ahead log.
+    pub wal_truncated_bytes: u64,
+}
+
+/// Open write-ahead log plus snapshot bookkeeping.
+pub(super) struct Persistence {
+    dir: PathBuf,
+    wal: BufWriter<File>,
+    snapshot_interval: Duration,
+    compression_level: i32,
+    last_snapshot: Instant,
+    /// Writes logged since the last snapshot.
+    dirty: bool,
+    integrity: IntegrityReport,
+}
+
+impl Persistence {
+    /// Open (creating if needed) the data directory, returning the stored
+    /// snapshot and the log entries written after it.
+    ///
+    /// A final log line that is unterminated or does not parse is the tail of
+    /// a write interrupted by a crash: it is dropped and the log truncated
+    /// after the last complete entry, so later writes are not appended to it.
+    /// Any other line that does not parse fails the open. A snapshot that
+    /// fails its checksum or does not parse is quarantined and the store
+    /// recovers from the log alone; see [`Self::integrity`].
+    pub(super) fn open(
+        policy: &PersistencePolicy,
+    ) -> Result<(Self, Option<Snapshot>, Vec<WalEntry>)> {
+        let dir = policy.data_dir.clone();
+        fs::create_dir_all(&dir).map_err(|e| io_error("create data directory", &dir, &e))?;
+
+        let mut integrity = IntegrityReport::default();
+        let snapshot_path = dir.join(EDGEVEC_SNAPSHOT_FILE);
+        let snapshot = match File::open(&snapshot_path) {
+            Ok(file) => match read_snapshot(file) {
+                Ok(snapshot) if snapshot.version != SNAPSHOT_VERSION => {
+                    return Err(Error::vector_db(format!(
+                        "Unsupported EdgeVec snapshot version {} in {}",
+                        snapshot.version,
+                        snapshot_path.display()
+                    )));
+                }
+                Ok(snapshot) => Some(snapshot),
+                Err(e) => {
+                    integrity.quarantined_snapshot = Some(quarantine(&snapshot_path, &e)?);
+                    None
+                }
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => None,
+            Err(e) => return Err(io_error("open snapshot", &snapshot_path, &e)),
+        };
+
+        let wal_path = dir.join(EDGEVEC_WAL_FILE);
+        let wal = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(&wal_path)
+            .map_err(|e| io_error("open write-ahead log", &wal_path, &e))?;
+        let (entries, complete_len) = read_wal(&wal, &wal_path)?;
+        let wal_len = wal
+            .metadata()
+            .map_err(|e| io_error("read write-ahead log", &wal_path, &e))?
+            .len();
+        if complete_len < wal_len {
+            mcb_domain::warn!(
+                "edgevec",
+                "Dropping truncated write-ahead log tail",
+                &format!("{} bytes", wal_len - complete_len)
+            );
+            wal.set_len(complete_len)
+                .and_then(|()| wal.sync_all())
+                .map_err(|e| io_error("truncate write-ahead log", &wal_path, &e))?;
+            integrity.wal_truncated_bytes = wal_len - complete_len;
+        }
+
+        let persistence = Self {
+            dir,
+            wal: BufWriter::new(wal),
+            snapshot_interval: Duration::from_secs(policy.snapshot_interval_secs),
+            compression_level: policy.compression_level,
+            last_snapshot: Instant::now(),
+            dirty: !entries.is_empty(),
+            integrity,
+        };
+        Ok((persistence, snapshot, entries))
+    }
+
+    /// Findings of the integrity check run by [`Self::open`].
+    pub(super) fn integrity(&self) -> &IntegrityReport {
+        &self.integrity
+    }
+
+    /// Buffer `entry` for the next flush.
+    pub(super) fn append(&mut self, entry: &WalEntry) -> Result<()> {
+        let wal_path = self.dir.join(EDGEVEC_WAL_FILE);
+        serde_json::to_writer(&mut self.wal, entry)?;
+        self.wal
+            .write_all(b"\n")
+            .map_err(|e| io_error("append to write-ahead log", &wal_path, &e))?;
+        self.dirty = true;
+        Ok(())
+    }
+
+    /// Write buffered log entries through to disk.
+    pub(super) fn flush(&mut self) -> Result<()> {
+        let wal_path = self.dir.join(EDGEVEC_WAL_FILE);
+        self.wal
+            .flush()
+            .and_then(|()| self.wal.get_ref().sync_data())
+            .map_err(|e| io_error("flush write-ahead log", &wal_path, &e))
+    }
+
+    /// Whether there are logged writes and the snapshot interval has passed.
+    pub(super) fn snapshot_due(&self) -> bool {
+        self.dirty && self.last_snapshot.elapsed() >= self.snapshot_interval
+    }
+
+    /// Whether anything was logged since the last snapshot.
+    pub(super) fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Atomically replace the snapshot, then truncate the log it covers.
+    pub(super) fn write_snapshot(&mut self, snapshot: &Snapshot) -> Result<()> {
+        let snapshot_path = self.dir.join(EDGEVEC_SNAPSHOT_FILE);
+        let tmp_path = snapshot_path.with_extension("json.tmp");
+        let body = serde_json::to_vec(snapshot)?;
+        let header = serde_json::to_vec(&SnapshotHeader {
+            sha256: bytes_to_hex(&Sha256::digest(&body)),
+        })?;
+        {
+            let file =
+                File::create(&tmp_path).map_err(|e| io_error("create snapshot", &tmp_path, &e))?;
+            let mut writer = BufWriter::new(file);
+            if self.compression_level > 0 {
+                let mut encoder = zstd::Encoder::new(&mut writer, self.compression_level)
+                    .map_err(|e| io_error("compress snapshot", &tmp_path, &e))?;
+                write_snapshot_file(&mut encoder, &header, &body)
+                    .map_err(|e| io_error("compress snapshot", &tmp_path, &e))?;
+                encoder
+                    .finish()
+                    .map_err(|e| io_error("compress snapshot", &tmp_path, &e))?;
+            } else {
+                write_snapshot_file(&mut writer, &header, &body)
+                    .map_err(|e| io_error("write snapshot", &tmp_path, &e))?;
+            }
+            writer
+                .flush()
+                .and_then(|()| writer.get_ref().sync_all())
+                .map_err(|e| io_error("write snapshot", &tmp_path, &e))?;
+        }
+        fs::rename(&tmp_path, &snapshot_path)
+            .map_err(|e| io_error("replace snapshot", &snapshot_path, &e))?;
+
+        let wal_path = self.dir.join(EDGEVEC_WAL_FILE);
+        self.wal
+            .flush()
+            .and_then(|()| self.wal.get_ref().set_len(0))
+            .map_err(|e| io_error("truncate write-ahead log", &wal_path, &e))?;
+        self.last_snapshot = Instant::now();
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+impl Snapshot {
+    pub(super) fn new(
+        collections: Vec<String>,
+        aliases: HashMap<String, String>,
+        records: Vec<StoredRecord>,
+    ) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            collections,
+            aliases,
+            records,
+        }
+    }
+}
+
+impl IntegrityReport {
+    /// Every finding in one line, or `None` when the check found nothing.
+    pub(super) fn problems(&self) -> Option<String> {
+        let mut problems = Vec::new();
+        if let Some(path) = &self.quarantined_snapshot {
+            problems.push(format!(
+                "snapshot failed verification and was moved to {}",
+                path.display()
+            ));
+        }
+        if self.wal_truncated_bytes > 0 {
+            problems.push(format!(
+                "{} bytes of torn write-ahead log tail were dropped",
+                self.wal_truncated_bytes
+            ));
+        }
+        (!problems.is_empty()).then(|| problems.join("; "))
+    }
+}
+
+/// Move an unusable snapshot aside so the next snapshot does not overwrite
+/// it, returning where it went.
+fn quarantine(snapshot_path: &Path, reason: &dyn std::fmt::Display) -> Result<PathBuf> {
+    let quarantined = snapshot_path.with_extension("json.corrupt");
+    mcb_domain::error!(
+        "edgevec",
+        "Quarantining unusable snapshot",
+        &format!(
+            "{} -> {}: {reason}",
+            snapshot_path.display(),
+            quarantined.display()
+        )
+    );
+    fs::rename(snapshot_path, &quarantined)
+        .map_err(|e| io_error("quarantine snapshot", snapshot_path, &e))?;
+    Ok(quarantined)
+}
+
+/// Write the header line, then the body it checksums.
+fn write_snapshot_file(out: &mut impl Write, header: &[u8], body: &[u8]) -> std::io::Result<()> {
+    out.write_all(header)?;
+    out.write_all(b"\n")?;
+    out.write_all(body)
+}
+
+/// Parse a snapshot, decompressing it first when it is a zstd frame, and
+/// check its body against the checksum in the header line.
+fn read_snapshot(file: File) -> std::result::Result<Snapshot, Box<dyn std::error::Error>> {
+    let mut reader = BufReader::new(file);
+    let compressed = reader.fill_buf()?.starts_with(&ZSTD_MAGIC);
+    let mut reader: Box<dyn BufRead> = if compressed {
+        Box::new(BufReader::new(zstd::Decoder::with_buffer(reader)?))
+    } else {
+        Box::new(reader)
+    };
+    let mut first_line = Vec::new();
+    reader.read_until(b'\n', &mut first_line)?;
+    if !first_line.ends_with(b"\n") {
+        // No header: a snapshot from before checksums were added.
+        return Ok(serde_json::from_slice(&first_line)?);
+    }
+    let header: SnapshotHeader = serde_json::from_slice(&first_line)?;
+    let mut body = HashingReader {
+        inner: reader,
+        hasher: Sha256::new(),
+    };
+    let snapshot = serde_json::from_reader(&mut body)?;
+    let actual = bytes_to_hex(&body.hasher.finalize());
+    if actual != header.sha256 {
+        return Err(format!(
+            "checksum mismatch: header has {}, body hashes to {actual}",
+            header.sha256
+        )
+        .into());
+    }
+    Ok(snapshot)
+}
+
+/// Reader that hashes every byte read through it.
+struct HashingReader<R> {
+    inner: R,
+    hasher: Sha256,
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Parse the log, returning its entries and the byte length they span.
+///
+/// Parsing stops at a final line that is unterminated or does not parse; a
+/// line that does not parse with more log after it is corruption, not a torn
+/// write, and is an error.
+fn read_wal(wal: &File, wal_path: &Path) -> Result<(Vec<WalEntry>, u64)> {
+    let mut reader = BufReader::new(wal);
+    let mut entries = Vec::new();
+    let mut complete_len = 0;
+    let mut line = Vec::new();
+    loop {
+        line.clear();
+        let read = reader
+            .read_until(b'\n', &mut line)
+            .map_err(|e| io_error("read write-ahead log", wal_path, &e))?;
+        if read == 0 || !line.ends_with(b"\n") {
+            break;
+        }
+        match serde_json::from_slice(&line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => {
+                let at_end = reader
+                    .fill_buf()
+                    .map_err(|e| io_error("read write-ahead log", wal_path, &e))?
+                    .is_empty();
+                if at_end {
+                    break;
+                }
+                return Err(Error::vector_db(format!(
+                    "Corrupt EdgeVec write-ahead log {} at byte {complete_len}: {e}",
+                    wal_path.display()
+                )));
+            }
+        }
+        complete_len += read as u64;
+    }
+    Ok((entries, complete_len))
+}
+
+fn io_error(action: &str, path: &Path, e: &std::io::Error) -> Error {
+    Error::vector_db(format!(
+        "EdgeVec persistence failed to {action} {}: {e}",
+        path.display()
+    ))
+}
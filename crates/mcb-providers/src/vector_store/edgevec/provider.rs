@@ -1,4 +1,5 @@
 use super::*;
+use crate::utils::vector_store::{insert_in_batches, upsert_in_batches};
 
 #[async_trait]
 impl VectorStoreAdmin for EdgeVecVectorStoreProvider {
@@ -22,13 +23,39 @@ impl VectorStoreAdmin for EdgeVecVectorStoreProvider {
     }
 
     async fn flush(&self, _collection: &CollectionId) -> Result<()> {
-        // EdgeVec uses synchronous in-memory writes — flush is a no-op
-        Ok(())
+        // Writes are searchable immediately; with persistence enabled this
+        // syncs the log and compacts it into a snapshot (for all collections).
+        self.send_core(|tx| CoreMessage::Flush { tx }).await
     }
 
     fn provider_name(&self) -> &str {
         "edgevec"
     }
+
+    /// Searches ignore the scope; callers filter the results.
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        VectorStoreCapabilities {
+            atomic_upsert: true,
+            aliases: true,
+            point_in_time_search: true,
+            chunk_inspection: true,
+            storage_quota: true,
+            ..VectorStoreCapabilities::default()
+        }
+    }
+
+    /// Fails while the store carries findings from its startup integrity
+    /// check (a quarantined snapshot or a dropped log tail); they clear on
+    /// the next restart.
+    async fn health_check(&self) -> Result<()> {
+        self.send_query(|tx| QueryMessage::HealthCheck { tx }).await
+    }
+
+    async fn storage_usage(&self) -> Result<Option<StorageUsage>> {
+        self.send_query(|tx| QueryMessage::StorageUsage { tx })
+            .await
+            .map(Some)
+    }
 }
 
 #[async_trait]
@@ -82,18 +109,67 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
         .await
     }
 
+    async fn swap_alias(&self, alias: &CollectionId, collection: &CollectionId) -> Result<()> {
+        self.send_core(|tx| CoreMessage::SwapAlias {
+            alias: alias.to_string(),
+            collection: collection.to_string(),
+            tx,
+        })
+        .await
+    }
+
+    async fn delete_alias(&self, alias: &CollectionId) -> Result<()> {
+        self.send_core(|tx| CoreMessage::DeleteAlias {
+            alias: alias.to_string(),
+            tx,
+        })
+        .await
+    }
+
     async fn insert_vectors(
         &self,
         collection: &CollectionId,
         vectors: &[Embedding],
         metadata: Vec<HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
-        self.send_core(|tx| CoreMessage::InsertVectors {
-            collection: collection.to_string(),
-            vectors: vectors.to_vec(),
+        insert_in_batches(
+            self.insert_batch,
+            vectors,
             metadata,
-            tx,
-        })
+            |batch, batch_metadata| {
+                self.send_core(move |tx| CoreMessage::InsertVectors {
+                    collection: collection.to_string(),
+                    vectors: batch.to_vec(),
+                    metadata: batch_metadata,
+                    tx,
+                })
+            },
+        )
+        .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>> {
+        upsert_in_batches(
+            self.insert_batch,
+            ids,
+            vectors,
+            metadata,
+            |batch_ids, batch, batch_metadata| {
+                self.send_core(move |tx| CoreMessage::UpsertVectors {
+                    collection: collection.to_string(),
+                    ids: batch_ids.to_vec(),
+                    vectors: batch.to_vec(),
+                    metadata: batch_metadata,
+                    tx,
+                })
+            },
+        )
         .await
     }
 
@@ -102,7 +178,7 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
         collection: &CollectionId,
         query_vector: &[f32],
         limit: usize,
-        _filter: Option<&str>,
+        _filter: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
         self.send_core(|tx| CoreMessage::SearchSimilar {
             collection: collection.to_string(),
@@ -113,6 +189,24 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
         .await
     }
 
+    async fn search_similar_as_of(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        _filter: Option<&SearchScope>,
+        as_of: i64,
+    ) -> Result<Vec<SearchResult>> {
+        self.send_core(|tx| CoreMessage::SearchSimilarAsOf {
+            collection: collection.to_string(),
+            query_vector: query_vector.to_vec(),
+            limit,
+            as_of,
+            tx,
+        })
+        .await
+    }
+
     async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()> {
         self.send_core(|tx| CoreMessage::DeleteVectors {
             collection: collection.to_string(),
@@ -147,4 +241,17 @@ impl VectorStoreProvider for EdgeVecVectorStoreProvider {
         })
         .await
     }
+
+    async fn inspect_chunk(
+        &self,
+        collection: &CollectionId,
+        id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        self.send_query(|tx| QueryMessage::InspectChunk {
+            collection: collection.to_string(),
+            id: id.to_owned(),
+            tx,
+        })
+        .await
+    }
 }
@@ -20,13 +20,18 @@ fn edgevec_factory(config: &VectorStoreProviderConfig) -> Result<Arc<dyn VectorS
     })?;
     let edgevec_config = EdgeVecConfig {
         dimensions,
+        metric: config.distance_metric.unwrap_or_default().into(),
+        tombstone_retention_secs: config.tombstones.unwrap_or_default().retention_secs,
+        persistence: config.persistence.clone(),
+        quota: config.quota.unwrap_or_default(),
         ..Default::default()
     };
     let provider = EdgeVecVectorStoreProvider::with_collection(
         &edgevec_config,
         CollectionId::from_name(&collection_name),
     )
-    .map_err(|e| Error::vector_db(format!("Failed to create EdgeVec provider: {e}")))?;
+    .map_err(|e| Error::vector_db(format!("Failed to create EdgeVec provider: {e}")))?
+    .with_insert_batch(config.insert_batch.unwrap_or_default());
     Ok(Arc::new(provider))
 }
 
@@ -20,14 +20,17 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use mcb_domain::error::{Error, Result};
 use mcb_domain::ports::{
-    CryptoProvider, EncryptedData, VectorStoreAdmin, VectorStoreBrowser, VectorStoreProvider,
+    CryptoProvider, EncryptedData, StorageUsage, VectorStoreAdmin, VectorStoreBrowser,
+    VectorStoreCapabilities, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult, SearchScope,
 };
-use mcb_domain::value_objects::{CollectionId, CollectionInfo, Embedding, FileInfo, SearchResult};
 use serde_json::Value;
 
 use mcb_utils::constants::vector_store::{
-    VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LANGUAGE, VECTOR_FIELD_LINE_NUMBER,
-    VECTOR_FIELD_START_LINE,
+    VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LANGUAGE,
+    VECTOR_FIELD_LAST_MODIFIED, VECTOR_FIELD_LINE_NUMBER, VECTOR_FIELD_START_LINE,
 };
 
 /// Encrypted vector store provider
@@ -98,6 +101,7 @@ impl<P: VectorStoreProvider> EncryptedVectorStoreProvider<P> {
             VECTOR_FIELD_CONTENT,
             VECTOR_FIELD_FILE_PATH,
             VECTOR_FIELD_LANGUAGE,
+            VECTOR_FIELD_LAST_MODIFIED,
         ] {
             if let Some(val) = meta.get(key) {
                 processed.insert(key.to_owned(), val.clone());
@@ -137,6 +141,16 @@ impl<P: VectorStoreProvider> VectorStoreAdmin for EncryptedVectorStoreProvider<P
     fn provider_name(&self) -> &str {
         "encrypted"
     }
+
+    /// Filterable fields stay in plaintext, so the inner store's features
+    /// carry over.
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn storage_usage(&self) -> Result<Option<StorageUsage>> {
+        self.inner.storage_usage().await
+    }
 }
 
 #[async_trait]
@@ -172,6 +186,14 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
         self.inner.delete_collection(name).await
     }
 
+    async fn swap_alias(&self, alias: &CollectionId, collection: &CollectionId) -> Result<()> {
+        self.inner.swap_alias(alias, collection).await
+    }
+
+    async fn delete_alias(&self, alias: &CollectionId) -> Result<()> {
+        self.inner.delete_alias(alias).await
+    }
+
     async fn insert_vectors(
         &self,
         collection: &CollectionId,
@@ -195,12 +217,29 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
             .await
     }
 
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        let processed_metadata: Vec<_> = metadata
+            .iter()
+            .map(|meta| self.encrypt_metadata(meta))
+            .collect::<Result<Vec<_>>>()?;
+
+        self.inner
+            .upsert_vectors(collection, ids, vectors, processed_metadata)
+            .await
+    }
+
     async fn search_similar(
         &self,
         collection: &CollectionId,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
         // Search using the inner provider (vectors are unencrypted)
         // Note: The inner provider returns results with partial metadata (unencrypted fields only)
@@ -210,6 +249,19 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
             .await
     }
 
+    async fn search_similar_as_of(
+        &self,
+        collection: &CollectionId,
+        query_vector: &[f32],
+        limit: usize,
+        filter: Option<&SearchScope>,
+        as_of: i64,
+    ) -> Result<Vec<SearchResult>> {
+        self.inner
+            .search_similar_as_of(collection, query_vector, limit, filter, as_of)
+            .await
+    }
+
     async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()> {
         self.inner.delete_vectors(collection, ids).await
     }
@@ -231,6 +283,21 @@ impl<P: VectorStoreProvider> VectorStoreProvider for EncryptedVectorStoreProvide
         // Delegate to inner provider - SearchResult fields are extracted from stored metadata
         self.inner.list_vectors(collection, limit).await
     }
+
+    async fn inspect_chunk(
+        &self,
+        collection: &CollectionId,
+        id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        let Some(mut chunk) = self.inner.inspect_chunk(collection, id).await? else {
+            return Ok(None);
+        };
+        // Show the metadata as it was written, not its encrypted envelope
+        if chunk.metadata.contains_key("encrypted_metadata") {
+            chunk.metadata = decrypt_metadata(self.crypto.as_ref(), &chunk.metadata)?;
+        }
+        Ok(Some(chunk))
+    }
 }
 
 /// Decrypts metadata payloads stored under `encrypted_metadata`.
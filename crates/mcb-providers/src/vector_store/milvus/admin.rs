@@ -1,14 +1,15 @@
 use super::*;
 use async_trait::async_trait;
 use mcb_domain::error::Error;
-use mcb_domain::ports::VectorStoreAdmin;
+use mcb_domain::ports::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities};
 use mcb_domain::value_objects::CollectionId;
 use std::collections::HashMap;
 
+use crate::utils::vector_store::insert_content_stats;
 use mcb_utils::constants::http::{PROVIDER_RETRY_BACKOFF_MS, PROVIDER_RETRY_COUNT};
 use mcb_utils::constants::vector_store::{
     MILVUS_ERROR_RATE_LIMIT, STATS_FIELD_COLLECTION, STATS_FIELD_PROVIDER, STATS_FIELD_STATUS,
-    STATS_FIELD_VECTORS_COUNT, STATUS_ACTIVE,
+    STATS_FIELD_VECTORS_COUNT, STATS_FILE_SCAN_LIMIT, STATUS_ACTIVE,
 };
 use mcb_utils::utils::retry::{RetryConfig, retry_with_backoff};
 
@@ -17,27 +18,22 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
     // --- Admin Methods ---
 
     async fn collection_exists(&self, name: &CollectionId) -> Result<bool> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(name);
-        Self::map_milvus_error(
-            self.client.has_collection(&name_str).await,
-            "check collection",
-        )
+        Self::map_milvus_error(client.has_collection(&name_str).await, "check collection")
     }
 
     async fn get_stats(
         &self,
         collection: &CollectionId,
     ) -> Result<HashMap<String, serde_json::Value>> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
-        let stats = self
-            .client
-            .get_collection_stats(&name_str)
-            .await
-            .map_err(|e| {
-                Error::vector_db(format!(
-                    "Failed to get stats for collection '{collection}': {e}"
-                ))
-            })?;
+        let stats = client.get_collection_stats(&name_str).await.map_err(|e| {
+            Error::vector_db(format!(
+                "Failed to get stats for collection '{collection}': {e}"
+            ))
+        })?;
 
         let mut result = HashMap::new();
         result.insert(
@@ -62,17 +58,24 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
             STATS_FIELD_PROVIDER.to_owned(),
             serde_json::json!(mcb_utils::constants::PROVIDER_SLUG_MILVUS),
         );
+        if let Ok(files) = self
+            .list_file_paths(collection, STATS_FILE_SCAN_LIMIT)
+            .await
+        {
+            insert_content_stats(&mut result, &files);
+        }
         Ok(result)
     }
 
     async fn flush(&self, collection: &CollectionId) -> Result<()> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
         let result = retry_with_backoff(
             RetryConfig::new(
                 PROVIDER_RETRY_COUNT,
                 std::time::Duration::from_millis(PROVIDER_RETRY_BACKOFF_MS),
             ),
-            |_| self.client.flush_collections(vec![&name_str]),
+            |_| client.flush_collections(vec![&name_str]),
             |e| {
                 let err_str = e.to_string();
                 err_str.contains(MILVUS_ERROR_RATE_LIMIT) || err_str.contains("rate limit")
@@ -93,4 +96,12 @@ impl VectorStoreAdmin for MilvusVectorStoreProvider {
     fn provider_name(&self) -> &str {
         mcb_utils::constants::PROVIDER_SLUG_MILVUS
     }
+
+    /// Upserts delete and re-insert by primary key, so they are not atomic.
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        VectorStoreCapabilities {
+            aliases: true,
+            ..VectorStoreCapabilities::default()
+        }
+    }
 }
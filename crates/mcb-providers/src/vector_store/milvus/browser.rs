@@ -46,6 +46,8 @@ pub fn convert_query_results(
             content: extract_string_field(query_results, VECTOR_FIELD_CONTENT, index)?,
             score: 1.0,
             language: "unknown".to_owned(),
+            last_modified: None,
+            explanation: None,
         });
     }
     Ok(results)
@@ -80,15 +82,16 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
     // --- Browser Methods ---
 
     async fn list_collections(&self) -> Result<Vec<CollectionInfo>> {
+        let client = self.client().await?;
         let collection_names =
-            Self::map_milvus_error(self.client.list_collections().await, "list collections")?;
+            Self::map_milvus_error(client.list_collections().await, "list collections")?;
 
         let mut collections = Vec::new();
 
         for name in collection_names {
             let _collection_id = CollectionId::from_name(&name);
             // Get stats for each collection
-            let stats = self.client.get_collection_stats(&name).await.map_err(|e| {
+            let stats = client.get_collection_stats(&name).await.map_err(|e| {
                 Error::vector_db(format!("Failed to get stats for collection '{name}': {e}"))
             })?;
             let vector_count = stats
@@ -122,10 +125,11 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
         if limit == 0 {
             return Ok(Vec::new());
         }
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
 
         // Ensure collection is loaded
-        if let Err(e) = self.client.load_collection(&name_str, None).await {
+        if let Err(e) = client.load_collection(&name_str, None).await {
             let err_str = e.to_string();
             if is_collection_not_found(&err_str) {
                 return Err(Error::vector_db(format!(
@@ -144,7 +148,7 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
             .limit(mcb_utils::constants::vector_store::MILVUS_DEFAULT_QUERY_LIMIT)
             .output_fields(vec![VECTOR_FIELD_FILE_PATH.to_owned()]);
 
-        let query_results = match self.client.query(&name_str, &expr, &query_options).await {
+        let query_results = match client.query(&name_str, &expr, &query_options).await {
             Ok(results) => results,
             Err(e) => {
                 let msg = format!("Failed to query file paths in collection '{collection}': {e}");
@@ -161,9 +165,10 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
         collection: &CollectionId,
         file_path: &str,
     ) -> Result<Vec<SearchResult>> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
         // Ensure collection is loaded
-        if let Err(e) = self.client.load_collection(&name_str, None).await {
+        if let Err(e) = client.load_collection(&name_str, None).await {
             let err_str = e.to_string();
             if is_collection_not_found(&err_str) {
                 return Err(Error::vector_db(format!(
@@ -187,7 +192,7 @@ impl VectorStoreBrowser for MilvusVectorStoreProvider {
             ) // Reasonable limit for chunks per file
             .output_fields(Self::default_output_fields());
 
-        let query_results = match self.client.query(&name_str, &expr, &query_options).await {
+        let query_results = match client.query(&name_str, &expr, &query_options).await {
             Ok(results) => results,
             Err(e) => {
                 let msg =
@@ -1,13 +1,27 @@
 //! Milvus vector store client implementation.
 
+use std::time::Duration;
+
 use mcb_domain::error::Result;
-use mcb_domain::value_objects::CollectionId;
-use mcb_utils::constants::vector_store::MILVUS_DEFAULT_OUTPUT_FIELDS;
+use mcb_domain::ports::InsertBatchPolicy;
+use mcb_domain::value_objects::{CollectionId, DistanceMetric};
+use mcb_utils::constants::vector_store::{
+    MILVUS_DEFAULT_OUTPUT_FIELDS, MILVUS_METRIC_IP, MILVUS_METRIC_L2,
+};
 use milvus::client::Client;
+use tokio::sync::OnceCell;
 
 /// Milvus vector store provider implementation
+///
+/// Construction only records the endpoint; the gRPC connection is opened by
+/// the first operation and shared afterwards, so the registry factory never
+/// blocks on the network.
 pub struct MilvusVectorStoreProvider {
-    pub(super) client: Client,
+    endpoint: String,
+    timeout: Duration,
+    connection: OnceCell<Client>,
+    pub(super) insert_batch: InsertBatchPolicy,
+    pub(super) distance_metric: DistanceMetric,
 }
 
 /// Convert a `CollectionId` to a valid Milvus collection name.
@@ -49,37 +63,75 @@ impl MilvusVectorStoreProvider {
     /// * `token` - Optional authentication token
     /// * `timeout_secs` - Connection timeout in seconds (default: 10)
     ///
-    /// # Errors
-    ///
-    /// Returns an error if the connection to Milvus server fails.
-    pub async fn new(
-        address: String,
-        _token: Option<String>,
-        timeout_secs: Option<u64>,
-    ) -> Result<Self> {
+    /// The server is not contacted until the first operation.
+    #[must_use]
+    pub fn new(address: String, _token: Option<String>, timeout_secs: Option<u64>) -> Self {
         // Ensure the address has a scheme (required by tonic transport)
         let endpoint = if address.starts_with("http://") || address.starts_with("https://") {
             address
         } else {
             format!("http://{address}")
         };
-
         let timeout =
             timeout_secs.unwrap_or(mcb_utils::constants::vector_store::MILVUS_DEFAULT_TIMEOUT_SECS);
-        let timeout_duration = std::time::Duration::from_secs(timeout);
 
-        let client = tokio::time::timeout(timeout_duration, Client::new(endpoint.clone()))
+        Self {
+            endpoint,
+            timeout: Duration::from_secs(timeout),
+            connection: OnceCell::new(),
+            insert_batch: InsertBatchPolicy::default(),
+            distance_metric: DistanceMetric::default(),
+        }
+    }
+
+    /// The connected client, connecting on first use.
+    ///
+    /// A failed attempt is not cached; the next operation retries.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection to Milvus server fails or times out.
+    pub(super) async fn client(&self) -> Result<&Client> {
+        self.connection
+            .get_or_try_init(|| async {
+                tokio::time::timeout(self.timeout, Client::new(self.endpoint.clone()))
+                    .await
+                    .map_err(|_| {
+                        mcb_domain::error::Error::vector_db(format!(
+                            "Milvus connection timed out after {} seconds",
+                            self.timeout.as_secs()
+                        ))
+                    })?
+                    .map_err(|e| {
+                        mcb_domain::error::Error::vector_db(format!(
+                            "Failed to connect to Milvus: {e}"
+                        ))
+                    })
+            })
             .await
-            .map_err(|_| {
-                mcb_domain::error::Error::vector_db(format!(
-                    "Milvus connection timed out after {timeout} seconds"
-                ))
-            })?
-            .map_err(|e| {
-                mcb_domain::error::Error::vector_db(format!("Failed to connect to Milvus: {e}"))
-            })?;
+    }
+
+    /// Split large inserts into concurrent batches per `policy`
+    #[must_use]
+    pub fn with_insert_batch(mut self, policy: InsertBatchPolicy) -> Self {
+        self.insert_batch = policy;
+        self
+    }
+
+    /// Index and search collections with `metric`
+    #[must_use]
+    pub fn with_distance_metric(mut self, metric: DistanceMetric) -> Self {
+        self.distance_metric = metric;
+        self
+    }
 
-        Ok(Self { client })
+    /// Milvus metric type for the configured distance. Cosine uses inner
+    /// product because vectors are normalized before they reach the store.
+    pub(super) fn metric_type_name(&self) -> &'static str {
+        match self.distance_metric {
+            DistanceMetric::Euclidean => MILVUS_METRIC_L2,
+            DistanceMetric::Cosine | DistanceMetric::Dot => MILVUS_METRIC_IP,
+        }
     }
 
     pub(super) fn default_output_fields() -> Vec<String> {
@@ -8,12 +8,18 @@ use std::collections::HashMap;
 
 use mcb_utils::constants::vector_store::{
     MILVUS_FIELD_VARCHAR_MAX_LENGTH, MILVUS_METADATA_VARCHAR_MAX_LENGTH, VECTOR_FIELD_CONTENT,
-    VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_LINE_NUMBER, VECTOR_FIELD_START_LINE, VECTOR_FIELD_VECTOR,
+    VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_ID, VECTOR_FIELD_LINE_NUMBER, VECTOR_FIELD_START_LINE,
+    VECTOR_FIELD_VECTOR,
 };
+use mcb_utils::utils::id;
+
+/// UUID v5 namespace for deriving Milvus primary keys from record IDs.
+const MILVUS_KEY_NAMESPACE: &str = "mcb.milvus.key";
 
 #[derive(Debug)]
 pub(super) struct InsertPayload {
     pub(super) expected_dims: usize,
+    pub(super) keys: Vec<i64>,
     pub(super) vectors_flat: Vec<f32>,
     pub(super) file_paths: Vec<String>,
     pub(super) start_lines: Vec<i64>,
@@ -48,7 +54,17 @@ pub(super) fn validate_insert_input(vectors: &[Embedding], metadata_len: usize)
     Ok(expected_dims)
 }
 
+/// Non-negative int64 primary key for a string record ID.
+///
+/// Collections use explicit primary keys so that upserts can replace a record
+/// by deleting the key derived from its ID before re-inserting it.
+pub(super) fn milvus_primary_key(record_id: &str) -> i64 {
+    let (high, _) = id::deterministic(MILVUS_KEY_NAMESPACE, record_id).as_u64_pair();
+    (high & i64::MAX as u64) as i64
+}
+
 pub(super) fn prepare_insert_data(
+    keys: Vec<i64>,
     vectors: &[Embedding],
     metadata: &[HashMap<String, serde_json::Value>],
     expected_dims: usize,
@@ -56,6 +72,7 @@ pub(super) fn prepare_insert_data(
     let capacity = vectors.len();
     let mut payload = InsertPayload {
         expected_dims,
+        keys,
         vectors_flat: Vec::with_capacity(capacity * expected_dims),
         file_paths: Vec::with_capacity(capacity),
         start_lines: Vec::with_capacity(capacity),
@@ -121,6 +138,12 @@ pub(super) fn build_insert_columns(payload: InsertPayload) -> Vec<FieldColumn> {
     };
 
     vec![
+        build_field_column(
+            VECTOR_FIELD_ID,
+            DataType::Int64,
+            ValueVec::Long(payload.keys),
+            0,
+        ),
         vector_column,
         build_field_column(
             VECTOR_FIELD_FILE_PATH,
@@ -13,14 +13,14 @@ impl MilvusVectorStoreProvider {
     ) -> Result<Option<Vec<milvus::data::FieldColumn>>> {
         use milvus::query::QueryOptions;
 
+        let client = self.client().await?;
         let batch_limit = remaining.min(MILVUS_QUERY_BATCH_SIZE) as i64;
         let query_options = QueryOptions::new()
             .limit(batch_limit)
             .offset(offset)
             .output_fields(Self::default_output_fields());
 
-        match self
-            .client
+        match client
             .query(to_milvus_name(collection), "id >= 0", &query_options)
             .await
         {
@@ -43,15 +43,13 @@ impl MilvusVectorStoreProvider {
     }
 
     async fn ensure_collection_loaded(&self, collection: &CollectionId) -> Result<()> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
-        self.client
-            .load_collection(&name_str, None)
-            .await
-            .map_err(|e| {
-                mcb_domain::error::Error::vector_db(format!(
-                    "Failed to load collection '{collection}': {e}"
-                ))
-            })?;
+        client.load_collection(&name_str, None).await.map_err(|e| {
+            mcb_domain::error::Error::vector_db(format!(
+                "Failed to load collection '{collection}': {e}"
+            ))
+        })?;
         Ok(())
     }
 
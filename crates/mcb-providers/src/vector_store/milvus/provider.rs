@@ -3,21 +3,29 @@ use std::collections::HashMap;
 use async_trait::async_trait;
 use mcb_domain::error::Error;
 use mcb_domain::ports::VectorStoreProvider;
-use mcb_domain::value_objects::{CollectionId, Embedding, SearchResult};
+use mcb_domain::value_objects::{
+    CollectionId, DistanceMetric, Embedding, SearchResult, SearchScope,
+};
 use mcb_utils::constants::http::{PROVIDER_RETRY_BACKOFF_MS, PROVIDER_RETRY_COUNT};
 use mcb_utils::constants::vector_store::{
     MILVUS_ERROR_COLLECTION_NOT_EXISTS, MILVUS_IVFFLAT_NLIST, MILVUS_PARAM_NLIST,
     MILVUS_VECTOR_INDEX_NAME, VECTOR_FIELD_VECTOR,
 };
+use mcb_utils::utils::id;
 use mcb_utils::utils::retry::{RetryConfig, retry_with_backoff};
 
 use super::*;
-use helpers::{build_insert_columns, parse_milvus_ids, prepare_insert_data, validate_insert_input};
+use crate::utils::vector_store::{ensure_upsert_lengths, insert_in_batches, upsert_in_batches};
+use helpers::{
+    build_insert_columns, milvus_primary_key, parse_milvus_ids, prepare_insert_data,
+    validate_insert_input,
+};
 use schema::build_collection_schema;
 
 impl MilvusVectorStoreProvider {
     async fn create_vector_index_with_retry(&self, name: &CollectionId) -> Result<()> {
         use milvus::index::{IndexParams, IndexType, MetricType};
+        let client = self.client().await?;
         let name_str = to_milvus_name(name);
 
         let index_result: std::result::Result<(), milvus::error::Error> = retry_with_backoff(
@@ -30,13 +38,17 @@ impl MilvusVectorStoreProvider {
                     MILVUS_PARAM_NLIST.to_owned(),
                     MILVUS_IVFFLAT_NLIST.to_string(),
                 )]);
+                let metric_type = match self.distance_metric {
+                    DistanceMetric::Euclidean => MetricType::L2,
+                    DistanceMetric::Cosine | DistanceMetric::Dot => MetricType::IP,
+                };
                 let index_params = IndexParams::new(
                     MILVUS_VECTOR_INDEX_NAME.to_owned(),
                     IndexType::IvfFlat,
-                    MetricType::L2,
+                    metric_type,
                     nlist_params,
                 );
-                self.client
+                client
                     .create_index(&name_str, VECTOR_FIELD_VECTOR, index_params)
                     .await
             },
@@ -65,14 +77,48 @@ impl MilvusVectorStoreProvider {
 
         Ok(())
     }
+
+    /// Delete rows by primary key; a no-op for an empty list.
+    async fn delete_keys(&self, name_str: &str, keys: Vec<i64>) -> Result<()> {
+        use milvus::mutate::DeleteOptions;
+        use milvus::value::ValueVec;
+
+        if keys.is_empty() {
+            return Ok(());
+        }
+        let client = self.client().await?;
+        let options = DeleteOptions::with_ids(ValueVec::Long(keys));
+        Self::map_milvus_error(client.delete(name_str, &options).await, "delete vectors")?;
+        Ok(())
+    }
+
+    /// Insert one batch of already validated vectors under `keys`.
+    async fn insert_chunk(
+        &self,
+        name_str: &str,
+        keys: Vec<i64>,
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+        expected_dims: usize,
+    ) -> Result<Vec<String>> {
+        let client = self.client().await?;
+        let payload = prepare_insert_data(keys, vectors, &metadata, expected_dims)?;
+        let columns = build_insert_columns(payload);
+        let res = Self::map_milvus_error(
+            client.insert(name_str, columns, None).await,
+            "insert vectors",
+        )?;
+        parse_milvus_ids(&res)
+    }
 }
 
 #[async_trait]
 impl VectorStoreProvider for MilvusVectorStoreProvider {
     async fn create_collection(&self, name: &CollectionId, dimensions: usize) -> Result<()> {
+        let client = self.client().await?;
         let schema = build_collection_schema(name, dimensions)?;
         Self::map_milvus_error(
-            self.client.create_collection(schema, None).await,
+            client.create_collection(schema, None).await,
             "create collection",
         )?;
         tokio::time::sleep(std::time::Duration::from_millis(
@@ -84,14 +130,39 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
     }
 
     async fn delete_collection(&self, name: &CollectionId) -> Result<()> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(name);
-        Self::map_milvus_error(
-            self.client.drop_collection(&name_str).await,
-            "delete collection",
-        )?;
+        Self::map_milvus_error(client.drop_collection(&name_str).await, "delete collection")?;
         Ok(())
     }
 
+    async fn swap_alias(&self, alias: &CollectionId, collection: &CollectionId) -> Result<()> {
+        let client = self.client().await?;
+        let alias_str = to_milvus_name(alias);
+        let name_str = to_milvus_name(collection);
+        // `alter_alias` moves an existing alias atomically; it fails for a new
+        // alias, which is created instead.
+        if client
+            .alter_alias(name_str.as_str(), alias_str.as_str())
+            .await
+            .is_ok()
+        {
+            return Ok(());
+        }
+        Self::map_milvus_error(
+            client
+                .create_alias(name_str.as_str(), alias_str.as_str())
+                .await,
+            "create alias",
+        )
+    }
+
+    async fn delete_alias(&self, alias: &CollectionId) -> Result<()> {
+        let client = self.client().await?;
+        let alias_str = to_milvus_name(alias);
+        Self::map_milvus_error(client.drop_alias(alias_str.as_str()).await, "drop alias")
+    }
+
     async fn insert_vectors(
         &self,
         collection: &CollectionId,
@@ -99,14 +170,51 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         metadata: Vec<HashMap<String, serde_json::Value>>,
     ) -> Result<Vec<String>> {
         let expected_dims = validate_insert_input(vectors, metadata.len())?;
-        let payload = prepare_insert_data(vectors, &metadata, expected_dims)?;
-        let columns = build_insert_columns(payload);
         let name_str = to_milvus_name(collection);
-        let res = Self::map_milvus_error(
-            self.client.insert(&name_str, columns, None).await,
-            "insert vectors",
-        )?;
-        Ok(parse_milvus_ids(&res)?)
+        insert_in_batches(
+            self.insert_batch,
+            vectors,
+            metadata,
+            |batch, batch_metadata| {
+                let keys = batch
+                    .iter()
+                    .map(|_| milvus_primary_key(&id::generate_string()))
+                    .collect();
+                self.insert_chunk(&name_str, keys, batch, batch_metadata, expected_dims)
+            },
+        )
+        .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, serde_json::Value>>,
+    ) -> Result<Vec<String>> {
+        ensure_upsert_lengths(ids, vectors, &metadata)?;
+        let expected_dims = validate_insert_input(vectors, metadata.len())?;
+        let name_str = to_milvus_name(collection);
+        upsert_in_batches(
+            self.insert_batch,
+            ids,
+            vectors,
+            metadata,
+            |batch_ids, batch, batch_metadata| {
+                let name_str = &name_str;
+                async move {
+                    let keys: Vec<i64> = batch_ids
+                        .iter()
+                        .map(|record_id| milvus_primary_key(record_id))
+                        .collect();
+                    self.delete_keys(name_str, keys.clone()).await?;
+                    self.insert_chunk(name_str, keys, batch, batch_metadata, expected_dims)
+                        .await
+                }
+            },
+        )
+        .await
     }
 
     async fn search_similar(
@@ -114,30 +222,18 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         collection: &CollectionId,
         query_vector: &[f32],
         limit: usize,
-        _filter: Option<&str>,
+        _filter: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
         Self::validate_search_params(query_vector, limit)?;
         self.load_collection_safe(collection).await?;
         let search_results = self.perform_search(collection, query_vector, limit).await?;
-        Self::convert_search_results(&search_results)
+        self.convert_search_results(&search_results)
     }
 
     async fn delete_vectors(&self, collection: &CollectionId, ids: &[String]) -> Result<()> {
-        use milvus::mutate::DeleteOptions;
-        use milvus::value::ValueVec;
-
         let id_numbers: Vec<i64> = ids.iter().filter_map(|id| id.parse::<i64>().ok()).collect();
-        if id_numbers.is_empty() {
-            return Ok(());
-        }
-
-        let options = DeleteOptions::with_ids(ValueVec::Long(id_numbers));
-        let name_str = to_milvus_name(collection);
-        Self::map_milvus_error(
-            self.client.delete(&name_str, &options).await,
-            "delete vectors",
-        )?;
-        Ok(())
+        self.delete_keys(&to_milvus_name(collection), id_numbers)
+            .await
     }
 
     async fn get_vectors_by_ids(
@@ -148,13 +244,11 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         if ids.is_empty() {
             return Ok(Vec::new());
         }
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
-        self.client
-            .load_collection(&name_str, None)
-            .await
-            .map_err(|e| {
-                Error::vector_db(format!("Failed to load collection '{collection}': {e}"))
-            })?;
+        client.load_collection(&name_str, None).await.map_err(|e| {
+            Error::vector_db(format!("Failed to load collection '{collection}': {e}"))
+        })?;
 
         // Validate that all IDs are numeric to prevent expression injection
         let id_numbers: Vec<i64> = ids.iter().filter_map(|id| id.parse::<i64>().ok()).collect();
@@ -166,7 +260,7 @@ impl VectorStoreProvider for MilvusVectorStoreProvider {
         use milvus::query::QueryOptions;
         let query_options = QueryOptions::new().output_fields(Self::default_output_fields());
         let query_results = Self::map_milvus_error(
-            self.client.query(&name_str, &expr, &query_options).await,
+            client.query(&name_str, &expr, &query_options).await,
             "query by IDs",
         )?;
         browser::convert_query_results(&query_results, None)
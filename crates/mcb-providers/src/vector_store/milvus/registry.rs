@@ -19,14 +19,12 @@ fn milvus_factory(
     })?;
     let token = config.api_key.clone();
 
-    // Create Milvus client synchronously using block_on
-    let provider = tokio::task::block_in_place(|| {
-        tokio::runtime::Handle::current()
-            .block_on(async { MilvusVectorStoreProvider::new(uri, token, None).await })
-    })
-    .map_err(|e| {
-        mcb_domain::error::Error::vector_db(format!("Failed to create Milvus provider: {e}"))
-    })?;
+    // Connects on first use, so building never blocks the runtime.
+    let provider = MilvusVectorStoreProvider::new(uri, token, None);
 
-    Ok(std::sync::Arc::new(provider))
+    Ok(std::sync::Arc::new(
+        provider
+            .with_insert_batch(config.insert_batch.unwrap_or_default())
+            .with_distance_metric(config.distance_metric.unwrap_or_default()),
+    ))
 }
@@ -72,7 +72,7 @@ pub(super) fn build_collection_schema(
         .add_field(FieldSchema::new_primary_int64(
             VECTOR_FIELD_ID,
             "primary key field",
-            true,
+            false,
         ))
         .add_field(FieldSchema::new_float_vector(
             VECTOR_FIELD_VECTOR,
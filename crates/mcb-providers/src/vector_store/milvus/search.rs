@@ -1,15 +1,16 @@
 use super::*;
 use mcb_domain::error::Error;
-use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_domain::value_objects::{CollectionId, DistanceMetric, SearchResult};
 use milvus::value::Value;
 use std::borrow::Cow;
 
 use mcb_utils::constants::vector_store::{
-    MILVUS_DISTANCE_METRIC, MILVUS_PARAM_METRIC_TYPE, VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH,
-    VECTOR_FIELD_START_LINE,
+    MILVUS_PARAM_METRIC_TYPE, VECTOR_FIELD_CONTENT, VECTOR_FIELD_FILE_PATH, VECTOR_FIELD_START_LINE,
 };
 use schema::{extract_long_field, extract_string_field};
 
+use crate::utils::vector_store::euclidean_similarity;
+
 impl MilvusVectorStoreProvider {
     /// Validate search parameters
     pub(super) fn validate_search_params(query_vector: &[f32], limit: usize) -> Result<()> {
@@ -24,8 +25,9 @@ impl MilvusVectorStoreProvider {
 
     /// Load collection with graceful error handling
     pub(super) async fn load_collection_safe(&self, collection: &CollectionId) -> Result<()> {
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
-        if let Err(e) = self.client.load_collection(&name_str, None).await {
+        if let Err(e) = client.load_collection(&name_str, None).await {
             let err_str = e.to_string();
             if is_collection_not_found(&err_str) {
                 mcb_domain::debug!(
@@ -51,14 +53,15 @@ impl MilvusVectorStoreProvider {
         limit: usize,
     ) -> Result<Vec<milvus::collection::SearchResult<'_>>> {
         use milvus::query::SearchOptions;
+        let client = self.client().await?;
         let name_str = to_milvus_name(collection);
 
         let search_options = SearchOptions::new()
             .limit(limit)
             .output_fields(Self::default_output_fields())
-            .add_param(MILVUS_PARAM_METRIC_TYPE, MILVUS_DISTANCE_METRIC);
+            .add_param(MILVUS_PARAM_METRIC_TYPE, self.metric_type_name());
 
-        self.client
+        client
             .search(
                 &name_str,
                 vec![Value::FloatArray(Cow::Borrowed(query_vector))],
@@ -84,18 +87,22 @@ impl MilvusVectorStoreProvider {
     }
 
     pub(super) fn convert_search_results(
+        &self,
         search_results: &[milvus::collection::SearchResult<'_>],
     ) -> Result<Vec<SearchResult>> {
         let mut results = Vec::new();
         for search_result in search_results {
             for (index, id_value) in search_result.id.iter().enumerate() {
-                let distance_squared =
-                    search_result.score.get(index).copied().ok_or_else(|| {
-                        Error::vector_db(format!(
-                            "Milvus search result missing score at index {index}"
-                        ))
-                    })?;
-                let score = 1.0 / (1.0 + distance_squared.sqrt());
+                let raw_score = search_result.score.get(index).copied().ok_or_else(|| {
+                    Error::vector_db(format!(
+                        "Milvus search result missing score at index {index}"
+                    ))
+                })?;
+                // L2 reports squared distance; IP reports the similarity itself.
+                let score = match self.distance_metric {
+                    DistanceMetric::Euclidean => euclidean_similarity(f64::from(raw_score).sqrt()),
+                    DistanceMetric::Cosine | DistanceMetric::Dot => f64::from(raw_score),
+                };
                 let fields = &search_result.field;
                 let start_line = extract_long_field(fields, VECTOR_FIELD_START_LINE, index)? as u32;
 
@@ -104,8 +111,10 @@ impl MilvusVectorStoreProvider {
                     file_path: extract_string_field(fields, VECTOR_FIELD_FILE_PATH, index)?,
                     start_line,
                     content: extract_string_field(fields, VECTOR_FIELD_CONTENT, index)?,
-                    score: score as f64,
+                    score,
                     language: "unknown".to_owned(),
+                    last_modified: None,
+                    explanation: None,
                 });
             }
         }
@@ -4,16 +4,17 @@ use std::collections::HashMap;
 
 use async_trait::async_trait;
 use mcb_domain::error::Result;
-use mcb_domain::ports::VectorStoreAdmin;
+use mcb_domain::ports::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities};
 use mcb_domain::value_objects::CollectionId;
 use serde_json::Value;
 
 use mcb_utils::constants::vector_store::{
     STATS_FIELD_COLLECTION, STATS_FIELD_PROVIDER, STATS_FIELD_STATUS, STATS_FIELD_VECTORS_COUNT,
-    STATUS_ACTIVE, STATUS_UNKNOWN,
+    STATS_FILE_SCAN_LIMIT, STATUS_ACTIVE, STATUS_UNKNOWN,
 };
 
 use super::PineconeVectorStoreProvider;
+use crate::utils::vector_store::insert_content_stats;
 
 #[async_trait]
 impl VectorStoreAdmin for PineconeVectorStoreProvider {
@@ -84,6 +85,12 @@ impl VectorStoreAdmin for PineconeVectorStoreProvider {
         };
         stats.insert(STATS_FIELD_STATUS.to_owned(), serde_json::json!(status));
         stats.insert(STATS_FIELD_VECTORS_COUNT.to_owned(), count);
+        if let Ok(files) = self
+            .list_file_paths(collection, STATS_FILE_SCAN_LIMIT)
+            .await
+        {
+            insert_content_stats(&mut stats, &files);
+        }
 
         Ok(stats)
     }
@@ -96,4 +103,12 @@ impl VectorStoreAdmin for PineconeVectorStoreProvider {
     fn provider_name(&self) -> &str {
         "pinecone"
     }
+
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        VectorStoreCapabilities {
+            metadata_filters: true,
+            atomic_upsert: true,
+            ..VectorStoreCapabilities::default()
+        }
+    }
 }
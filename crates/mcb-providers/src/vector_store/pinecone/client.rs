@@ -5,18 +5,17 @@ use std::time::Duration;
 
 use dashmap::DashMap;
 use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::ResiliencePolicy;
 use mcb_utils::constants::http::CONTENT_TYPE_JSON;
 
-use mcb_domain::value_objects::SearchResult;
+use mcb_domain::value_objects::{SearchResult, SearchScope};
+use mcb_utils::constants::vector_store::VECTOR_FIELD_LANGUAGE;
 use reqwest::Client;
 use serde_json::Value;
 
 use crate::utils::http::{VectorDbRequestParams, send_vector_db_request};
 use crate::utils::vector_store::search_result_from_json_metadata;
-use mcb_utils::constants::http::{
-    HTTP_HEADER_CONTENT_TYPE, PINECONE_API_KEY_HEADER, PROVIDER_RETRY_BACKOFF_MS,
-    PROVIDER_RETRY_COUNT,
-};
+use mcb_utils::constants::http::{HTTP_HEADER_CONTENT_TYPE, PINECONE_API_KEY_HEADER};
 
 /// Pinecone vector store provider
 ///
@@ -27,6 +26,7 @@ pub struct PineconeVectorStoreProvider {
     pub(super) host: String,
     pub(super) timeout: Duration,
     pub(super) http_client: Client,
+    pub(super) resilience: ResiliencePolicy,
     /// Track collections (namespaces) locally with their dimensions
     pub(super) collections: Arc<DashMap<String, usize>>,
     /// Default dimensions sourced from provider config (embedding model).
@@ -56,11 +56,20 @@ impl PineconeVectorStoreProvider {
             host: host.trim_end_matches('/').to_owned(),
             timeout,
             http_client,
+            resilience: ResiliencePolicy::default(),
             collections: Arc::new(DashMap::new()),
             default_dimensions,
         }
     }
 
+    /// Apply a timeout and retry policy to every request.
+    #[must_use]
+    pub fn with_resilience(mut self, policy: ResiliencePolicy) -> Self {
+        self.timeout = policy.request_timeout();
+        self.resilience = policy;
+        self
+    }
+
     /// Build a URL for the Pinecone API
     pub(crate) fn api_url(&self, path: &str) -> String {
         format!("{}{}", self.host, path)
@@ -87,8 +96,7 @@ impl PineconeVectorStoreProvider {
             operation: path,
             headers: &headers,
             body: body.as_ref(),
-            retry_attempts: PROVIDER_RETRY_COUNT,
-            retry_backoff_ms: PROVIDER_RETRY_BACKOFF_MS,
+            resilience: &self.resilience,
         })
         .await
     }
@@ -138,6 +146,22 @@ impl PineconeVectorStoreProvider {
         })
     }
 
+    /// Pinecone metadata filter for the parts of `scope` it can evaluate.
+    ///
+    /// Pinecone has no prefix or pattern operators, so only languages and
+    /// excluded languages are pushed down.
+    pub(crate) fn scope_filter(scope: &SearchScope) -> Option<Value> {
+        let mut condition = serde_json::Map::new();
+        if let Some(languages) = &scope.languages {
+            condition.insert("$in".to_owned(), serde_json::json!(languages));
+        }
+        if let Some(excluded) = &scope.exclude_languages {
+            condition.insert("$nin".to_owned(), serde_json::json!(excluded));
+        }
+        (!condition.is_empty())
+            .then(|| serde_json::json!({ (VECTOR_FIELD_LANGUAGE): Value::Object(condition) }))
+    }
+
     /// Convert Pinecone match result to domain `SearchResult`.
     ///
     /// # Errors
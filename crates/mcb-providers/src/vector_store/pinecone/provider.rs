@@ -6,11 +6,12 @@ use async_trait::async_trait;
 use mcb_domain::error::Error;
 use mcb_domain::error::Result;
 use mcb_domain::ports::VectorStoreProvider;
-use mcb_domain::value_objects::{CollectionId, Embedding, SearchResult};
+use mcb_domain::value_objects::{CollectionId, Embedding, SearchResult, SearchScope};
 use mcb_utils::utils::id;
 use serde_json::Value;
 
 use super::PineconeVectorStoreProvider;
+use crate::utils::vector_store::ensure_upsert_lengths;
 
 impl PineconeVectorStoreProvider {
     async fn upsert_vector_batch(
@@ -72,36 +73,47 @@ impl VectorStoreProvider for PineconeVectorStoreProvider {
                 "Cannot insert empty vectors array".to_owned(),
             ));
         }
-        if vectors.len() != metadata.len() {
-            return Err(Error::vector_db(format!(
-                "Vectors/metadata length mismatch: vectors={}, metadata={}",
-                vectors.len(),
-                metadata.len()
-            )));
-        }
+        let ids: Vec<String> = vectors
+            .iter()
+            .map(|_| format!("vec_{}", id::generate()))
+            .collect();
+        self.upsert_vectors(collection, &ids, vectors, metadata)
+            .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        ensure_upsert_lengths(ids, vectors, &metadata)?;
 
         let collection_str = collection.to_string();
-        let mut ids = Vec::with_capacity(vectors.len());
-        let mut pinecone_vectors = Vec::with_capacity(vectors.len());
         let batch_size = mcb_utils::constants::vector_store::PINECONE_UPSERT_BATCH_SIZE;
-
-        for (i, (embedding, meta)) in vectors.iter().zip(metadata.iter()).enumerate() {
-            let id = format!("vec_{}", id::generate());
-            pinecone_vectors.push(serde_json::json!({
-                "id": &id,
-                "values": embedding.vector,
-                "metadata": meta
-            }));
-            ids.push(id);
-
-            if pinecone_vectors.len() >= batch_size || i == vectors.len() - 1 {
-                self.upsert_vector_batch(&collection_str, &pinecone_vectors)
-                    .await?;
-                pinecone_vectors.clear();
-            }
+        for ((batch_ids, batch), batch_metadata) in ids
+            .chunks(batch_size)
+            .zip(vectors.chunks(batch_size))
+            .zip(metadata.chunks(batch_size))
+        {
+            let pinecone_vectors: Vec<Value> = batch_ids
+                .iter()
+                .zip(batch)
+                .zip(batch_metadata)
+                .map(|((id, embedding), meta)| {
+                    serde_json::json!({
+                        "id": id,
+                        "values": embedding.vector,
+                        "metadata": meta
+                    })
+                })
+                .collect();
+            self.upsert_vector_batch(&collection_str, &pinecone_vectors)
+                .await?;
         }
 
-        Ok(ids)
+        Ok(ids.to_vec())
     }
 
     async fn search_similar(
@@ -109,7 +121,7 @@ impl VectorStoreProvider for PineconeVectorStoreProvider {
         collection: &CollectionId,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
         let collection_str = collection.to_string();
         let mut payload = serde_json::json!({
@@ -119,9 +131,7 @@ impl VectorStoreProvider for PineconeVectorStoreProvider {
             "includeMetadata": true
         });
 
-        if let Some(filter_str) = filter
-            && let Ok(filter_val) = serde_json::from_str::<Value>(filter_str)
-        {
+        if let Some(filter_val) = filter.and_then(Self::scope_filter) {
             payload["filter"] = filter_val;
         }
 
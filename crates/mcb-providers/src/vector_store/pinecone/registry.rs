@@ -16,7 +16,7 @@ use super::PineconeVectorStoreProvider;
 pub fn pinecone_factory(
     config: &VectorStoreProviderConfig,
 ) -> Result<Arc<dyn VectorStoreProvider>> {
-    use crate::utils::http::{DEFAULT_HTTP_TIMEOUT, create_default_client};
+    use crate::utils::http::create_provider_client;
 
     let api_key = config
         .api_key
@@ -26,15 +26,19 @@ pub fn pinecone_factory(
         .uri
         .clone()
         .ok_or_else(|| Error::configuration("Pinecone requires uri (index host URL)"))?;
-    let http_client = create_default_client()?;
+    let resilience = config.resilience.unwrap_or_default();
+    let http_client = create_provider_client(&resilience, config.transport.as_ref())?;
 
-    Ok(Arc::new(PineconeVectorStoreProvider::new(
-        &api_key,
-        &host,
-        DEFAULT_HTTP_TIMEOUT,
-        http_client,
-        config.dimensions,
-    )))
+    Ok(Arc::new(
+        PineconeVectorStoreProvider::new(
+            &api_key,
+            &host,
+            resilience.request_timeout(),
+            http_client,
+            config.dimensions,
+        )
+        .with_resilience(resilience),
+    ))
 }
 
 mcb_domain::register_vector_store_provider!(
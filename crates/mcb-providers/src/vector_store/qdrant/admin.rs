@@ -6,15 +6,16 @@ use async_trait::async_trait;
 use serde_json::Value;
 
 use mcb_domain::error::Result;
-use mcb_domain::ports::VectorStoreAdmin;
+use mcb_domain::ports::{VectorStoreAdmin, VectorStoreBrowser, VectorStoreCapabilities};
 use mcb_domain::value_objects::CollectionId;
 
 use mcb_utils::constants::vector_store::{
     STATS_FIELD_COLLECTION, STATS_FIELD_PROVIDER, STATS_FIELD_STATUS, STATS_FIELD_VECTORS_COUNT,
-    STATUS_UNKNOWN,
+    STATS_FILE_SCAN_LIMIT, STATUS_UNKNOWN,
 };
 
 use super::QdrantVectorStoreProvider;
+use crate::utils::vector_store::insert_content_stats;
 
 #[async_trait]
 impl VectorStoreAdmin for QdrantVectorStoreProvider {
@@ -53,6 +54,12 @@ impl VectorStoreAdmin for QdrantVectorStoreProvider {
                         stats.insert(STATS_FIELD_STATUS.to_owned(), status.clone());
                     }
                 }
+                if let Ok(files) = self
+                    .list_file_paths(collection, STATS_FILE_SCAN_LIMIT)
+                    .await
+                {
+                    insert_content_stats(&mut stats, &files);
+                }
             }
             Err(_) => {
                 stats.insert(
@@ -74,4 +81,14 @@ impl VectorStoreAdmin for QdrantVectorStoreProvider {
     fn provider_name(&self) -> &str {
         "qdrant"
     }
+
+    fn capabilities(&self) -> VectorStoreCapabilities {
+        VectorStoreCapabilities {
+            metadata_filters: true,
+            atomic_upsert: true,
+            aliases: true,
+            chunk_inspection: true,
+            ..VectorStoreCapabilities::default()
+        }
+    }
 }
@@ -12,13 +12,15 @@ use reqwest::Client;
 use serde_json::Value;
 
 use mcb_domain::error::{Error, Result};
-use mcb_domain::value_objects::{CollectionId, SearchResult};
+use mcb_domain::ports::ResiliencePolicy;
+use mcb_domain::value_objects::{CollectionId, DistanceMetric, SearchResult, SearchScope};
 use mcb_utils::constants::http::CONTENT_TYPE_JSON;
 
 use crate::utils::http::{VectorDbRequestParams, send_vector_db_request};
 use crate::utils::vector_store::search_result_from_json_metadata;
-use mcb_utils::constants::http::{
-    HTTP_HEADER_CONTENT_TYPE, PROVIDER_RETRY_BACKOFF_MS, PROVIDER_RETRY_COUNT,
+use mcb_utils::constants::http::HTTP_HEADER_CONTENT_TYPE;
+use mcb_utils::constants::vector_store::{
+    QDRANT_DISTANCE_COSINE, QDRANT_DISTANCE_DOT, QDRANT_DISTANCE_EUCLID, VECTOR_FIELD_LANGUAGE,
 };
 
 /// Qdrant vector search engine client.
@@ -27,6 +29,8 @@ pub struct QdrantVectorStoreProvider {
     pub(super) api_key: Option<String>,
     pub(super) timeout: Duration,
     pub(super) http_client: Client,
+    pub(super) resilience: ResiliencePolicy,
+    pub(super) distance_metric: DistanceMetric,
     pub(super) collections: Arc<DashMap<String, usize>>,
 }
 
@@ -60,10 +64,36 @@ impl QdrantVectorStoreProvider {
             api_key: api_key.map(|k| k.trim().to_owned()),
             timeout,
             http_client,
+            resilience: ResiliencePolicy::default(),
+            distance_metric: DistanceMetric::default(),
             collections: Arc::new(DashMap::new()),
         }
     }
 
+    /// Apply a timeout and retry policy to every request.
+    #[must_use]
+    pub fn with_resilience(mut self, policy: ResiliencePolicy) -> Self {
+        self.timeout = policy.request_timeout();
+        self.resilience = policy;
+        self
+    }
+
+    /// Create collections for, and score results by, `metric`.
+    #[must_use]
+    pub fn with_distance_metric(mut self, metric: DistanceMetric) -> Self {
+        self.distance_metric = metric;
+        self
+    }
+
+    /// Qdrant name of the configured distance.
+    pub(super) fn distance_name(&self) -> &'static str {
+        match self.distance_metric {
+            DistanceMetric::Cosine => QDRANT_DISTANCE_COSINE,
+            DistanceMetric::Dot => QDRANT_DISTANCE_DOT,
+            DistanceMetric::Euclidean => QDRANT_DISTANCE_EUCLID,
+        }
+    }
+
     pub(super) fn api_url(&self, path: &str) -> String {
         format!("{}{}", self.base_url, path)
     }
@@ -115,6 +145,30 @@ impl QdrantVectorStoreProvider {
         .await
     }
 
+    /// Whether `alias` is currently defined.
+    pub(super) async fn alias_exists(&self, alias: &CollectionId) -> Result<bool> {
+        let response = self.request(reqwest::Method::GET, "/aliases", None).await?;
+        let alias = alias.to_string();
+        Ok(response["result"]["aliases"]
+            .as_array()
+            .is_some_and(|aliases| {
+                aliases
+                    .iter()
+                    .any(|entry| entry["alias_name"].as_str() == Some(alias.as_str()))
+            }))
+    }
+
+    /// Apply alias `actions` in one atomic request.
+    pub(super) async fn update_aliases(&self, actions: Vec<Value>) -> Result<()> {
+        self.request(
+            reqwest::Method::POST,
+            "/collections/aliases",
+            Some(serde_json::json!({ "actions": actions })),
+        )
+        .await?;
+        Ok(())
+    }
+
     pub(super) fn map_result_items(
         items: &Value,
         warn_message: &'static str,
@@ -165,12 +219,32 @@ impl QdrantVectorStoreProvider {
             operation: path,
             headers: &headers,
             body: body.as_ref(),
-            retry_attempts: PROVIDER_RETRY_COUNT,
-            retry_backoff_ms: PROVIDER_RETRY_BACKOFF_MS,
+            resilience: &self.resilience,
         })
         .await
     }
 
+    /// Qdrant payload filter for the parts of `scope` it can evaluate.
+    ///
+    /// Only languages and excluded languages are pushed down; path prefixes
+    /// and globs need a full-text payload index and are left to the caller.
+    pub(super) fn scope_filter(scope: &SearchScope) -> Option<Value> {
+        let mut filter = serde_json::Map::new();
+        if let Some(languages) = &scope.languages {
+            filter.insert(
+                "must".to_owned(),
+                serde_json::json!([{ "key": VECTOR_FIELD_LANGUAGE, "match": { "any": languages } }]),
+            );
+        }
+        if let Some(excluded) = &scope.exclude_languages {
+            filter.insert(
+                "must_not".to_owned(),
+                serde_json::json!([{ "key": VECTOR_FIELD_LANGUAGE, "match": { "any": excluded } }]),
+            );
+        }
+        (!filter.is_empty()).then_some(Value::Object(filter))
+    }
+
     pub(super) fn point_to_search_result(item: &Value, score: f64) -> SearchResult {
         let id = match &item["id"] {
             Value::String(s) => s.clone(),
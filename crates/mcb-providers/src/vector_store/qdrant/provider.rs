@@ -7,10 +7,13 @@ use serde_json::Value;
 
 use mcb_domain::error::Result;
 use mcb_domain::ports::VectorStoreProvider;
-use mcb_domain::value_objects::{CollectionId, Embedding, SearchResult};
+use mcb_domain::value_objects::{
+    ChunkInspection, CollectionId, DistanceMetric, Embedding, SearchResult, SearchScope,
+};
 use mcb_utils::utils::id;
 
 use super::QdrantVectorStoreProvider;
+use crate::utils::vector_store::{ensure_upsert_lengths, euclidean_similarity};
 
 #[async_trait]
 impl VectorStoreProvider for QdrantVectorStoreProvider {
@@ -21,7 +24,7 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
             Some(serde_json::json!({
                 "vectors": {
                     "size": dimensions,
-                    "distance": mcb_utils::constants::vector_store::QDRANT_DISTANCE_METRIC
+                    "distance": self.distance_name()
                 }
             })),
         )
@@ -39,28 +42,68 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
         Ok(())
     }
 
+    async fn swap_alias(&self, alias: &CollectionId, collection: &CollectionId) -> Result<()> {
+        // Qdrant applies all actions of one request atomically; the delete is
+        // only sent when the alias exists, since deleting a missing alias fails.
+        let mut actions = Vec::with_capacity(2);
+        if self.alias_exists(alias).await? {
+            actions.push(serde_json::json!({
+                "delete_alias": { "alias_name": alias.to_string() }
+            }));
+        }
+        actions.push(serde_json::json!({
+            "create_alias": {
+                "collection_name": collection.to_string(),
+                "alias_name": alias.to_string()
+            }
+        }));
+        self.update_aliases(actions).await
+    }
+
+    async fn delete_alias(&self, alias: &CollectionId) -> Result<()> {
+        self.update_aliases(vec![serde_json::json!({
+            "delete_alias": { "alias_name": alias.to_string() }
+        })])
+        .await
+    }
+
     async fn insert_vectors(
         &self,
         collection: &CollectionId,
         vectors: &[Embedding],
         metadata: Vec<HashMap<String, Value>>,
     ) -> Result<Vec<String>> {
+        let ids: Vec<String> = vectors.iter().map(|_| id::generate().to_string()).collect();
+        self.upsert_vectors(collection, &ids, vectors, metadata)
+            .await
+    }
+
+    async fn upsert_vectors(
+        &self,
+        collection: &CollectionId,
+        ids: &[String],
+        vectors: &[Embedding],
+        metadata: Vec<HashMap<String, Value>>,
+    ) -> Result<Vec<String>> {
+        ensure_upsert_lengths(ids, vectors, &metadata)?;
         if vectors.is_empty() {
             return Ok(Vec::new());
         }
 
-        let mut ids = Vec::with_capacity(vectors.len());
-        let mut points = Vec::with_capacity(vectors.len());
-
-        for (embedding, meta) in vectors.iter().zip(metadata.iter()) {
-            let id = id::generate().to_string();
-            points.push(serde_json::json!({
-                "id": id,
-                "vector": embedding.vector,
-                "payload": meta
-            }));
-            ids.push(id);
-        }
+        // Qdrant point ids must be unsigned integers or UUIDs; writing an
+        // existing id overwrites that point.
+        let points: Vec<Value> = ids
+            .iter()
+            .zip(vectors)
+            .zip(&metadata)
+            .map(|((id, embedding), meta)| {
+                serde_json::json!({
+                    "id": id,
+                    "vector": embedding.vector,
+                    "payload": meta
+                })
+            })
+            .collect();
 
         self.request_points(
             reqwest::Method::PUT,
@@ -69,7 +112,7 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
         )
         .await?;
 
-        Ok(ids)
+        Ok(ids.to_vec())
     }
 
     async fn search_similar(
@@ -77,7 +120,7 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
         collection: &CollectionId,
         query_vector: &[f32],
         limit: usize,
-        filter: Option<&str>,
+        filter: Option<&SearchScope>,
     ) -> Result<Vec<SearchResult>> {
         let mut payload = serde_json::json!({
             "vector": query_vector,
@@ -85,9 +128,7 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
             "with_payload": true
         });
 
-        if let Some(filter_str) = filter
-            && let Ok(filter_val) = serde_json::from_str::<Value>(filter_str)
-        {
+        if let Some(filter_val) = filter.and_then(Self::scope_filter) {
             payload["filter"] = filter_val;
         }
 
@@ -95,7 +136,13 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
             .request_points_operation(reqwest::Method::POST, collection, "search", Some(payload))
             .await?;
 
-        let results = Self::map_scored_search_results(&response)?;
+        let mut results = Self::map_scored_search_results(&response)?;
+        // Qdrant reports Euclidean distance (lower is better) as the score.
+        if self.distance_metric == DistanceMetric::Euclidean {
+            for result in &mut results {
+                result.score = euclidean_similarity(result.score);
+            }
+        }
 
         Ok(results)
     }
@@ -170,4 +217,53 @@ impl VectorStoreProvider for QdrantVectorStoreProvider {
 
         Ok(results)
     }
+
+    async fn inspect_chunk(
+        &self,
+        collection: &CollectionId,
+        id: &str,
+    ) -> Result<Option<ChunkInspection>> {
+        let response = self
+            .request_points(
+                reqwest::Method::POST,
+                collection,
+                Some(serde_json::json!({
+                    "ids": [id],
+                    "with_payload": true,
+                    "with_vector": true
+                })),
+            )
+            .await?;
+        let Some(point) = response["result"]
+            .as_array()
+            .and_then(|points| points.first())
+        else {
+            return Ok(None);
+        };
+
+        let metadata = point["payload"]
+            .as_object()
+            .map(|fields| fields.clone().into_iter().collect())
+            .unwrap_or_default();
+        let vector = point["vector"].as_array().map(|values| {
+            values
+                .iter()
+                .filter_map(Value::as_f64)
+                .map(|v| v as f32)
+                .collect()
+        });
+        let mut location = HashMap::from([("point_id".to_owned(), point["id"].clone())]);
+        // Only present on collections created with custom sharding
+        if !point["shard_key"].is_null() {
+            location.insert("shard_key".to_owned(), point["shard_key"].clone());
+        }
+        Ok(Some(ChunkInspection::new(
+            id,
+            collection.to_string(),
+            "qdrant",
+            metadata,
+            vector,
+            location,
+        )))
+    }
 }
@@ -12,19 +12,24 @@ mcb_domain::register_vector_store_provider!(
 fn qdrant_factory(
     config: &mcb_domain::registry::vector_store::VectorStoreProviderConfig,
 ) -> mcb_domain::error::Result<std::sync::Arc<dyn mcb_domain::ports::VectorStoreProvider>> {
-    use crate::utils::http::{DEFAULT_HTTP_TIMEOUT, create_default_client};
+    use crate::utils::http::create_provider_client;
 
     let base_url = config
         .uri
         .clone()
         .unwrap_or_else(|| format!("http://localhost:{QDRANT_DEFAULT_PORT}"));
     let api_key = config.api_key.clone();
-    let http_client = create_default_client()?;
+    let resilience = config.resilience.unwrap_or_default();
+    let http_client = create_provider_client(&resilience, config.transport.as_ref())?;
 
-    Ok(std::sync::Arc::new(QdrantVectorStoreProvider::new(
-        &base_url,
-        api_key,
-        DEFAULT_HTTP_TIMEOUT,
-        http_client,
-    )))
+    Ok(std::sync::Arc::new(
+        QdrantVectorStoreProvider::new(
+            &base_url,
+            api_key,
+            resilience.request_timeout(),
+            http_client,
+        )
+        .with_resilience(resilience)
+        .with_distance_metric(config.distance_metric.unwrap_or_default()),
+    ))
 }
@@ -0,0 +1,65 @@
+//! Integration tests for `SeaORM` Collection Manifest Repository.
+//!
+//! Tests recording, overwriting and clearing the chunker version,
+//! distance metric, embedding model and indexed root.
+
+use std::sync::Arc;
+
+use mcb_domain::ports::CollectionManifestRepository;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::DistanceMetric;
+use mcb_providers::database::seaorm::repos::SeaOrmCollectionManifestRepository;
+use rstest::rstest;
+use sea_orm::{Database, DatabaseConnection};
+
+async fn setup_db() -> TestResult<Arc<DatabaseConnection>> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+    Ok(Arc::new(db))
+}
+
+#[rstest]
+#[tokio::test]
+async fn record_build_overwrites_previous() -> TestResult {
+    let repo = SeaOrmCollectionManifestRepository::new(setup_db().await?);
+    assert!(repo.get_manifest("repo").await?.is_none());
+
+    repo.record_build("repo", 1, DistanceMetric::Cosine, "fastembed", "/old")
+        .await?;
+    repo.record_build(
+        "repo",
+        2,
+        DistanceMetric::Dot,
+        "openai/text-embedding-3-small",
+        "/src/repo",
+    )
+    .await?;
+
+    let manifest = repo.get_manifest("repo").await?.ok_or("manifest missing")?;
+    assert_eq!(manifest.collection, "repo");
+    assert_eq!(manifest.chunker_version, 2);
+    assert_eq!(manifest.distance_metric, DistanceMetric::Dot);
+    assert_eq!(
+        manifest.embedding_model.as_deref(),
+        Some("openai/text-embedding-3-small")
+    );
+    assert_eq!(manifest.root_path.as_deref(), Some("/src/repo"));
+    assert!(manifest.updated_at > 0);
+    assert!(repo.get_manifest("other").await?.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn clear_collection_removes_manifest() -> TestResult {
+    let repo = SeaOrmCollectionManifestRepository::new(setup_db().await?);
+    repo.record_build("repo", 1, DistanceMetric::Cosine, "fastembed", "/a")
+        .await?;
+    repo.record_build("other", 1, DistanceMetric::Cosine, "fastembed", "/b")
+        .await?;
+
+    assert_eq!(repo.clear_collection("repo").await?, 1);
+    assert!(repo.get_manifest("repo").await?.is_none());
+    assert!(repo.get_manifest("other").await?.is_some());
+    Ok(())
+}
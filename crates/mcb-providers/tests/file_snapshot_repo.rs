@@ -0,0 +1,84 @@
+//! Integration tests for `SeaORM` File Snapshot Repository.
+//!
+//! Tests content-addressed storage and pruning of blobs no file hash refers to.
+
+use std::sync::Arc;
+
+use mcb_domain::ports::{FileHashRepository, FileSnapshotRepository};
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::entities::{organization, project};
+use mcb_providers::database::seaorm::repos::{SeaOrmFileSnapshotRepository, SeaOrmIndexRepository};
+use mcb_utils::constants::values::DEFAULT_ORG_ID;
+use rstest::rstest;
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, Database, DatabaseConnection};
+
+const PROJECT_ID: &str = "proj-blob-001";
+
+async fn setup_db() -> TestResult<Arc<DatabaseConnection>> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+
+    organization::ActiveModel {
+        id: Set(DEFAULT_ORG_ID.to_owned()),
+        name: Set("Default Org".to_owned()),
+        slug: Set("default-org".to_owned()),
+        settings_json: Set("{}".to_owned()),
+        created_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+        updated_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+    }
+    .insert(&db)
+    .await?;
+    project::ActiveModel {
+        id: Set(PROJECT_ID.to_owned()),
+        org_id: Set(DEFAULT_ORG_ID.to_owned()),
+        name: Set("Blob Test Project".to_owned()),
+        path: Set("/tmp/blob-test".to_owned()),
+        created_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+        updated_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+    }
+    .insert(&db)
+    .await?;
+
+    Ok(Arc::new(db))
+}
+
+#[rstest]
+#[tokio::test]
+async fn store_is_idempotent_and_content_addressed() -> TestResult {
+    let db = setup_db().await?;
+    let repo = SeaOrmFileSnapshotRepository::new(Arc::clone(&db));
+
+    repo.store_snapshot("hash-a", "fn main() {}\n").await?;
+    repo.store_snapshot("hash-a", "fn main() {}\n").await?;
+
+    assert_eq!(
+        repo.get_snapshot("hash-a").await?.as_deref(),
+        Some("fn main() {}\n")
+    );
+    assert_eq!(repo.get_snapshot("hash-missing").await?, None);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn prune_keeps_only_live_hashes() -> TestResult {
+    let db = setup_db().await?;
+    let blobs = SeaOrmFileSnapshotRepository::new(Arc::clone(&db));
+    let hashes = SeaOrmIndexRepository::new(Arc::clone(&db), PROJECT_ID.to_owned());
+
+    blobs.store_snapshot("hash-live", "live").await?;
+    blobs.store_snapshot("hash-deleted", "deleted").await?;
+    blobs.store_snapshot("hash-orphan", "orphan").await?;
+    hashes
+        .upsert_hash("repo", "src/live.rs", "hash-live")
+        .await?;
+    hashes
+        .upsert_hash("repo", "src/gone.rs", "hash-deleted")
+        .await?;
+    hashes.mark_deleted("repo", "src/gone.rs").await?;
+
+    assert_eq!(blobs.prune_unreferenced().await?, 2);
+    assert!(blobs.get_snapshot("hash-live").await?.is_some());
+    assert!(blobs.get_snapshot("hash-deleted").await?.is_none());
+    Ok(())
+}
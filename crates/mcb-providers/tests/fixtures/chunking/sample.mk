@@ -0,0 +1,23 @@
+CARGO ?= cargo
+PROFILE := release
+FEATURES = full
+
+.PHONY: all build test clean
+
+all: build test
+
+# Build every workspace binary.
+build:
+	$(CARGO) build --profile $(PROFILE) --features $(FEATURES)
+
+test: build
+	$(CARGO) test --workspace \
+		--features $(FEATURES)
+
+define RELEASE_NOTES
+Release $(VERSION)
+endef
+
+clean:
+	$(CARGO) clean
+	rm -rf dist/
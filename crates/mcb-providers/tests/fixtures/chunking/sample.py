@@ -0,0 +1,31 @@
+import hashlib
+from dataclasses import dataclass
+
+
+@dataclass
+class Document:
+    path: str
+    content: str
+
+
+class DocumentStore:
+    """Content-addressed document store."""
+
+    def __init__(self):
+        self._documents = {}
+
+    def put(self, document):
+        digest = hashlib.sha256(document.content.encode()).hexdigest()
+        self._documents[digest] = document
+        return digest
+
+    def get(self, digest):
+        return self._documents.get(digest)
+
+
+def load_documents(paths):
+    documents = []
+    for path in paths:
+        with open(path, encoding="utf-8") as handle:
+            documents.append(Document(path=path, content=handle.read()))
+    return documents
@@ -0,0 +1,43 @@
+use std::collections::HashMap;
+
+/// In-memory session cache keyed by token.
+pub struct SessionCache {
+    sessions: HashMap<String, Session>,
+    ttl_secs: u64,
+}
+
+/// A logged-in user session.
+#[derive(Debug, Clone)]
+pub struct Session {
+    pub user_id: u64,
+    pub expires_at: u64,
+}
+
+impl SessionCache {
+    pub fn new(ttl_secs: u64) -> Self {
+        Self {
+            sessions: HashMap::new(),
+            ttl_secs,
+        }
+    }
+
+    pub fn insert(&mut self, token: &str, user_id: u64, now: u64) {
+        let session = Session {
+            user_id,
+            expires_at: now + self.ttl_secs,
+        };
+        self.sessions.insert(token.to_owned(), session);
+    }
+
+    pub fn get(&self, token: &str, now: u64) -> Option<&Session> {
+        self.sessions
+            .get(token)
+            .filter(|session| session.expires_at > now)
+    }
+}
+
+pub fn evict_expired(cache: &mut SessionCache, now: u64) -> usize {
+    let before = cache.sessions.len();
+    cache.sessions.retain(|_, session| session.expires_at > now);
+    before - cache.sessions.len()
+}
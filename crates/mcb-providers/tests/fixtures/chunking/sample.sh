@@ -0,0 +1,22 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+BACKUP_DIR="${BACKUP_DIR:-/var/backups/mcb}"
+
+# Print a timestamped message to stderr.
+log() {
+    printf '%s %s\n' "$(date -u +%FT%TZ)" "$*" >&2
+}
+
+function backup_database {
+    local target="$BACKUP_DIR/mcb-$(date +%F).db"
+    mkdir -p "$BACKUP_DIR"
+    sqlite3 "$MCB_DB" ".backup '$target'"
+    log "database saved to $target"
+}
+
+prune_old() { find "$BACKUP_DIR" -mtime +14 -delete; }
+
+backup_database
+prune_old
+log "backup complete"
@@ -0,0 +1,25 @@
+export interface RetryOptions {
+  attempts: number;
+  delayMs: number;
+}
+
+export class RetryPolicy {
+  constructor(private readonly options: RetryOptions) {}
+
+  async run<T>(operation: () => Promise<T>): Promise<T> {
+    let lastError: unknown;
+    for (let attempt = 1; attempt <= this.options.attempts; attempt++) {
+      try {
+        return await operation();
+      } catch (error) {
+        lastError = error;
+        await sleep(this.options.delayMs * attempt);
+      }
+    }
+    throw lastError;
+  }
+}
+
+export function sleep(ms: number): Promise<void> {
+  return new Promise((resolve) => setTimeout(resolve, ms));
+}
@@ -0,0 +1,120 @@
+//! Chunk boundary snapshots for the fixtures in `tests/fixtures/chunking`.
+//!
+//! Each fixture `<name>.<ext>` has a snapshot `<name>.<ext>.json` holding the
+//! chunker version and the lines, kind and content of every chunk. Stored
+//! collections keep the chunks they were indexed with, so a changed boundary
+//! must come with a `CHUNKER_VERSION` bump, which flags older collections for
+//! re-indexing.
+//!
+//! A missing snapshot fails the test; record it, or re-record every snapshot
+//! after bumping the version, with `MCB_UPDATE_GOLDEN=1`.
+
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::language::{IntelligentChunker, language_from_extension};
+use mcb_utils::constants::lang::CHUNKER_VERSION;
+use rstest::rstest;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+const UPDATE_ENV: &str = "MCB_UPDATE_GOLDEN";
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct ChunkSnapshot {
+    start_line: u32,
+    end_line: u32,
+    kind: String,
+    content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Snapshot {
+    chunker_version: u32,
+    chunks: Vec<ChunkSnapshot>,
+}
+
+fn fixtures_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/chunking")
+}
+
+fn chunk_fixture(fixture: &str, content: &str) -> Vec<ChunkSnapshot> {
+    let extension = Path::new(fixture)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    let language = language_from_extension(extension);
+    IntelligentChunker::new()
+        .chunk_code(content, fixture, &language)
+        .into_iter()
+        .map(|chunk| ChunkSnapshot {
+            kind: ["node_type", "chunk_type"]
+                .iter()
+                .find_map(|key| chunk.metadata.get(key).and_then(Value::as_str))
+                .unwrap_or_default()
+                .to_owned(),
+            start_line: chunk.start_line,
+            end_line: chunk.end_line,
+            content: chunk.content,
+        })
+        .collect()
+}
+
+fn read_snapshot(path: &Path) -> TestResult<Option<Snapshot>> {
+    match std::fs::read_to_string(path) {
+        Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+fn write_snapshot(path: &Path, chunks: Vec<ChunkSnapshot>) -> TestResult {
+    let snapshot = Snapshot {
+        chunker_version: CHUNKER_VERSION,
+        chunks,
+    };
+    std::fs::write(path, serde_json::to_string_pretty(&snapshot)? + "\n")?;
+    Ok(())
+}
+
+#[rstest]
+#[case("sample.rs")]
+#[case("sample.py")]
+#[case("sample.ts")]
+#[case("sample.go")]
+#[case("sample.java")]
+#[case("sample.txt")]
+#[case("sample.sh")]
+#[case("sample.dockerfile")]
+#[case("sample.mk")]
+fn chunking_matches_golden_snapshot(#[case] fixture: &str) -> TestResult {
+    let content = std::fs::read_to_string(fixtures_dir().join(fixture))?;
+    let actual = chunk_fixture(fixture, &content);
+    assert!(!actual.is_empty(), "{fixture} produced no chunks");
+
+    let path = fixtures_dir().join(format!("{fixture}.json"));
+    let update = std::env::var(UPDATE_ENV).is_ok_and(|value| value == "1");
+    match read_snapshot(&path)? {
+        None if update => write_snapshot(&path, actual),
+        None => panic!("{fixture}.json is missing: record it with {UPDATE_ENV}=1"),
+        Some(stored) if update => {
+            assert!(
+                stored.chunks == actual || stored.chunker_version < CHUNKER_VERSION,
+                "chunk boundaries of {fixture} changed: bump CHUNKER_VERSION in mcb-utils before re-recording"
+            );
+            write_snapshot(&path, actual)
+        }
+        Some(stored) => {
+            assert_eq!(
+                stored.chunks, actual,
+                "chunk boundaries of {fixture} changed: bump CHUNKER_VERSION in mcb-utils and re-record with {UPDATE_ENV}=1"
+            );
+            assert_eq!(
+                stored.chunker_version, CHUNKER_VERSION,
+                "{fixture}.json was recorded by an older chunker: re-record with {UPDATE_ENV}=1"
+            );
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Golden snapshot tests for provider output persisted in collections.
+//!
+//! Run: `cargo test -p mcb-providers --test golden` or `make test SCOPE=golden`.
+
+mod chunking_golden;
@@ -0,0 +1,88 @@
+//! Integration tests for `SeaORM` Idempotency Repository.
+//!
+//! Tests storing tool results by key, the lookup window and expiry.
+
+use std::sync::Arc;
+
+use mcb_domain::entities::idempotency::IdempotencyRecord;
+use mcb_domain::ports::IdempotencyRepository;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::repos::SeaOrmIdempotencyRepository;
+use rstest::rstest;
+use sea_orm::Database;
+
+async fn setup_repo() -> TestResult<SeaOrmIdempotencyRepository> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+    Ok(SeaOrmIdempotencyRepository::new(Arc::new(db)))
+}
+
+fn record(org_id: &str, key: &str, response: &str, created_at: i64) -> IdempotencyRecord {
+    IdempotencyRecord {
+        org_id: org_id.to_owned(),
+        tool_name: "index_repo".to_owned(),
+        key: key.to_owned(),
+        request_hash: "hash".to_owned(),
+        response: response.to_owned(),
+        created_at,
+    }
+}
+
+#[rstest]
+#[case("acme", "retry-1", 100, Some("first"))]
+#[case("acme", "retry-1", 101, None)]
+#[case("globex", "retry-1", 0, None)]
+#[case("acme", "retry-2", 0, None)]
+#[tokio::test]
+async fn find_record_matches_org_key_and_window(
+    #[case] org_id: &str,
+    #[case] key: &str,
+    #[case] since: i64,
+    #[case] expected: Option<&str>,
+) -> TestResult {
+    let repo = setup_repo().await?;
+    repo.save_record(&record("acme", "retry-1", "first", 100))
+        .await?;
+
+    let found = repo
+        .find_record(org_id, "index_repo", key, since)
+        .await?
+        .map(|r| r.response);
+
+    assert_eq!(found.as_deref(), expected);
+    Ok(())
+}
+
+#[tokio::test]
+async fn save_record_replaces_expired_record() -> TestResult {
+    let repo = setup_repo().await?;
+    repo.save_record(&record("acme", "retry-1", "old", 100))
+        .await?;
+    repo.save_record(&record("acme", "retry-1", "new", 500))
+        .await?;
+
+    let found = repo.find_record("acme", "index_repo", "retry-1", 0).await?;
+
+    assert_eq!(found, Some(record("acme", "retry-1", "new", 500)));
+    Ok(())
+}
+
+#[tokio::test]
+async fn delete_expired_removes_old_records() -> TestResult {
+    let repo = setup_repo().await?;
+    repo.save_record(&record("acme", "old", "a", 100)).await?;
+    repo.save_record(&record("acme", "new", "b", 500)).await?;
+
+    assert_eq!(repo.delete_expired(300).await?, 1);
+    assert!(
+        repo.find_record("acme", "index_repo", "old", 0)
+            .await?
+            .is_none()
+    );
+    assert!(
+        repo.find_record("acme", "index_repo", "new", 0)
+            .await?
+            .is_some()
+    );
+    Ok(())
+}
@@ -36,15 +36,19 @@ async fn migration_creates_all_tables() -> TestResult {
         "api_keys",
         "branches",
         "checkpoints",
+        "collection_manifests",
         "collections",
         "delegations",
         "error_pattern_matches",
         "error_patterns",
+        "file_blobs",
         "file_hashes",
+        "idempotency_records",
         "index_operations",
         "issue_comments",
         "issue_label_assignments",
         "issue_labels",
+        "module_imports",
         "observations",
         "observations_fts",
         "organizations",
@@ -54,11 +58,17 @@ async fn migration_creates_all_tables() -> TestResult {
         "project_issues",
         "projects",
         "repositories",
+        "search_feedback",
+        "search_file_boosts",
         "session_summaries",
         "team_members",
         "teams",
+        "test_links",
         "tool_calls",
+        "usage_records",
         "users",
+        "workspace_collections",
+        "workspace_shared_files",
         "worktrees",
     ];
 
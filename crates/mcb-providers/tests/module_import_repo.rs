@@ -0,0 +1,79 @@
+//! Integration tests for `SeaORM` Module Import Repository.
+//!
+//! Tests per-file replacement, listing order and collection clearing.
+
+use std::sync::Arc;
+
+use mcb_domain::entities::module_import::ModuleImport;
+use mcb_domain::ports::ModuleImportRepository;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::repos::SeaOrmModuleImportRepository;
+use rstest::rstest;
+use sea_orm::{Database, DatabaseConnection};
+
+async fn setup_db() -> TestResult<Arc<DatabaseConnection>> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+    Ok(Arc::new(db))
+}
+
+fn import(source_file: &str, module_path: &str, line: u32) -> ModuleImport {
+    ModuleImport {
+        collection: "repo".to_owned(),
+        source_file: source_file.to_owned(),
+        specifier: module_path.replace('/', "::"),
+        module_path: module_path.to_owned(),
+        line,
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn list_imports_orders_by_file_and_line() -> TestResult {
+    let repo = SeaOrmModuleImportRepository::new(setup_db().await?);
+    repo.replace_file_imports(
+        "repo",
+        "src/b.rs",
+        &[
+            import("src/b.rs", "src/c", 3),
+            import("src/b.rs", "src/a", 1),
+        ],
+    )
+    .await?;
+    repo.replace_file_imports("repo", "src/a.rs", &[import("src/a.rs", "src/c", 2)])
+        .await?;
+
+    let imports = repo.list_imports("repo").await?;
+
+    let edges: Vec<_> = imports
+        .iter()
+        .map(|i| (i.source_file.as_str(), i.module_path.as_str(), i.line))
+        .collect();
+    assert_eq!(
+        edges,
+        [
+            ("src/a.rs", "src/c", 2),
+            ("src/b.rs", "src/a", 1),
+            ("src/b.rs", "src/c", 3),
+        ]
+    );
+    assert!(repo.list_imports("other").await?.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn replace_and_clear_drop_stale_imports() -> TestResult {
+    let repo = SeaOrmModuleImportRepository::new(setup_db().await?);
+    repo.replace_file_imports("repo", "src/a.rs", &[import("src/a.rs", "src/old", 1)])
+        .await?;
+    repo.replace_file_imports("repo", "src/a.rs", &[import("src/a.rs", "src/new", 1)])
+        .await?;
+
+    let imports = repo.list_imports("repo").await?;
+    assert_eq!(imports.len(), 1);
+    assert_eq!(imports[0].module_path, "src/new");
+    assert_eq!(repo.clear_collection("repo").await?, 1);
+    assert!(repo.list_imports("repo").await?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Integration tests for `SeaORM` Test Link Repository.
+//!
+//! Tests per-file replacement, lookup ordering and collection clearing.
+
+use std::sync::Arc;
+
+use mcb_domain::entities::test_link::{TestLink, TestLinkReason};
+use mcb_domain::ports::TestLinkRepository;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::repos::SeaOrmTestLinkRepository;
+use rstest::rstest;
+use sea_orm::{Database, DatabaseConnection};
+
+async fn setup_db() -> TestResult<Arc<DatabaseConnection>> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+    Ok(Arc::new(db))
+}
+
+fn link(test_file: &str, test_name: &str, target: &str, reason: TestLinkReason) -> TestLink {
+    TestLink {
+        collection: "repo".to_owned(),
+        test_file: test_file.to_owned(),
+        test_name: test_name.to_owned(),
+        start_line: 10,
+        end_line: 20,
+        target: target.to_owned(),
+        reason,
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn find_tests_returns_call_links_first() -> TestResult {
+    let repo = SeaOrmTestLinkRepository::new(setup_db().await?);
+    repo.replace_file_links(
+        "repo",
+        "tests/a.rs",
+        &[link(
+            "tests/a.rs",
+            "test_parse",
+            "parse",
+            TestLinkReason::Name,
+        )],
+    )
+    .await?;
+    repo.replace_file_links(
+        "repo",
+        "tests/b.rs",
+        &[
+            link("tests/b.rs", "reads_config", "parse", TestLinkReason::Call),
+            link("tests/b.rs", "reads_config", "load", TestLinkReason::Call),
+        ],
+    )
+    .await?;
+
+    let tests = repo.find_tests("repo", "parse", 10).await?;
+
+    let names: Vec<_> = tests
+        .iter()
+        .map(|t| (t.test_name.as_str(), &t.reason))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            ("reads_config", &TestLinkReason::Call),
+            ("test_parse", &TestLinkReason::Name),
+        ]
+    );
+    assert!(repo.find_tests("other", "parse", 10).await?.is_empty());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn replace_and_clear_drop_stale_links() -> TestResult {
+    let repo = SeaOrmTestLinkRepository::new(setup_db().await?);
+    repo.replace_file_links(
+        "repo",
+        "tests/a.rs",
+        &[link("tests/a.rs", "test_old", "old", TestLinkReason::Call)],
+    )
+    .await?;
+    repo.replace_file_links(
+        "repo",
+        "tests/a.rs",
+        &[link(
+            "tests/a.rs",
+            "test_new",
+            "new_fn",
+            TestLinkReason::Call,
+        )],
+    )
+    .await?;
+
+    assert!(repo.find_tests("repo", "old", 10).await?.is_empty());
+    assert_eq!(repo.find_tests("repo", "new_fn", 10).await?.len(), 1);
+    assert_eq!(repo.clear_collection("repo").await?, 1);
+    assert!(repo.find_tests("repo", "new_fn", 10).await?.is_empty());
+    Ok(())
+}
@@ -6,6 +6,7 @@ use mcb_domain::entities::CodeChunk;
 use mcb_domain::ports::HybridSearchProvider;
 use mcb_domain::utils::tests::chunk_fixtures::create_test_chunk;
 use mcb_domain::utils::tests::search_fixtures::create_test_search_result;
+use mcb_domain::value_objects::SearchScope;
 use mcb_providers::hybrid_search::{BM25Params, BM25Scorer, HybridSearchEngine};
 use mcb_utils::constants::search::{HYBRID_SEARCH_BM25_WEIGHT, HYBRID_SEARCH_SEMANTIC_WEIGHT};
 use rstest::rstest;
@@ -181,6 +182,7 @@ async fn test_hybrid_search() -> Result<(), Box<dyn std::error::Error>> {
             "authenticate user validate credentials",
             semantic_results,
             10,
+            None,
         )
         .await?;
 
@@ -207,10 +209,60 @@ async fn search_without_index(#[case] limit: usize) -> Result<(), Box<dyn std::e
     ];
 
     let results = engine
-        .search("nonexistent", "query", semantic_results.clone(), limit)
+        .search(
+            "nonexistent",
+            "query",
+            semantic_results.clone(),
+            limit,
+            None,
+        )
         .await?;
 
     assert_eq!(results.len(), semantic_results.len().min(limit));
     assert_eq!(results[0].file_path, "a.rs");
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn search_drops_results_outside_scope() -> Result<(), Box<dyn std::error::Error>> {
+    let engine = HybridSearchEngine::new();
+    let semantic_results = vec![
+        create_test_search_result("src/server/a.rs", "Content of a.rs:1", 0.9, 1),
+        create_test_search_result("src/domain/b.rs", "Content of b.rs:1", 0.8, 2),
+    ];
+    let scope = SearchScope {
+        path_prefix: Some("src/server/".to_owned()),
+        ..Default::default()
+    };
+
+    let results = engine
+        .search("nonexistent", "query", semantic_results, 10, Some(&scope))
+        .await?;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].file_path, "src/server/a.rs");
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn search_drops_excluded_results() -> Result<(), Box<dyn std::error::Error>> {
+    let engine = HybridSearchEngine::new();
+    let semantic_results = vec![
+        create_test_search_result("vendor/a.rs", "Content of a.rs:1", 0.9, 1),
+        create_test_search_result("src/b.rs", "Content of b.rs:1", 0.8, 2),
+    ];
+    let scope = SearchScope {
+        exclude_paths: Some(vec!["vendor/".to_owned()]),
+        ..Default::default()
+    };
+
+    let results = engine
+        .search("nonexistent", "query", semantic_results, 10, Some(&scope))
+        .await?;
+
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].file_path, "src/b.rs");
+    Ok(())
+}
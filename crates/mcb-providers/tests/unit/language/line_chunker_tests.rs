@@ -0,0 +1,104 @@
+//! Tests for the Dockerfile, Makefile and shell script chunkers
+
+use mcb_providers::language::{IntelligentChunker, detect_language};
+use rstest::rstest;
+use serde_json::Value;
+
+/// `(node_type or chunk_type, symbol_name)` of every chunk of `content`.
+fn chunk_kinds(file_name: &str, content: &str) -> Vec<(String, Option<String>)> {
+    let language = detect_language(file_name, content);
+    IntelligentChunker::new()
+        .chunk_code(content, file_name, &language)
+        .into_iter()
+        .map(|chunk| {
+            let field = |key: &str| {
+                chunk
+                    .metadata
+                    .get(key)
+                    .and_then(Value::as_str)
+                    .map(str::to_owned)
+            };
+            (
+                field("node_type")
+                    .or_else(|| field("chunk_type"))
+                    .unwrap_or_default(),
+                field("symbol_name"),
+            )
+        })
+        .collect()
+}
+
+fn named(kind: &str, name: &str) -> (String, Option<String>) {
+    (kind.to_owned(), Some(name.to_owned()))
+}
+
+#[rstest]
+#[case("Dockerfile")]
+#[case("Containerfile")]
+#[case("release.dockerfile")]
+fn dockerfile_stages_are_named_by_alias_or_image(#[case] file_name: &str) {
+    let content = "FROM --platform=$BUILDPLATFORM golang:1.23 AS build\n\
+                   RUN go build ./...\n\
+                   \n\
+                   FROM scratch\n\
+                   COPY --from=build /app /app\n";
+
+    assert_eq!(
+        chunk_kinds(file_name, content),
+        vec![named("stage", "build"), named("stage", "scratch")]
+    );
+}
+
+#[rstest]
+fn makefile_rules_are_chunked_per_target() {
+    let content = "CC := gcc\n\
+                   \n\
+                   app: main.o util.o\n\
+                   \t$(CC) -o app main.o util.o\n\
+                   \n\
+                   %.o: %.c\n\
+                   \t$(CC) -c $<\n";
+
+    assert_eq!(
+        chunk_kinds("Makefile", content),
+        vec![
+            ("variables".to_owned(), None),
+            named("target", "app"),
+            named("target", "%.o"),
+        ]
+    );
+}
+
+#[rstest]
+#[case("deploy.sh")]
+#[case("deploy")]
+fn shell_functions_are_chunked_per_function(#[case] file_name: &str) {
+    let content = "#!/bin/sh\n\
+                   build() {\n\
+                   \x20   make all\n\
+                   }\n\
+                   function ship {\n\
+                   \x20   scp app host:/srv\n\
+                   }\n\
+                   build && ship\n";
+
+    assert_eq!(
+        chunk_kinds(file_name, content),
+        vec![
+            ("script".to_owned(), None),
+            named("function", "build"),
+            named("function", "ship"),
+            ("script".to_owned(), None),
+        ]
+    );
+}
+
+#[rstest]
+fn shell_script_without_functions_uses_generic_chunks() {
+    let content = "#!/bin/sh\nset -e\ncargo build --release\ncargo test --workspace\n";
+
+    assert_eq!(
+        chunk_kinds("ci.sh", content),
+        vec![("generic".to_owned(), None)]
+    );
+}
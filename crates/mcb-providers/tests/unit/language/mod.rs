@@ -0,0 +1,3 @@
+//! Unit tests.
+
+mod line_chunker_tests;
@@ -5,7 +5,10 @@
 mod analysis;
 mod database;
 mod hybrid_search;
+mod language;
 mod project_detection;
+mod testkit;
+mod utils;
 mod vcs;
 mod vector_store;
 mod workflow;
@@ -0,0 +1,3 @@
+//! Unit tests.
+
+mod testkit_tests;
@@ -0,0 +1,239 @@
+//! Tests for the deterministic test-kit providers
+
+#![cfg(feature = "testkit")]
+
+use std::collections::HashMap;
+
+use mcb_domain::ports::{
+    EmbeddingProvider, HttpClientProvider, VectorStoreAdmin, VectorStoreProvider,
+};
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::CollectionId;
+use mcb_providers::testkit::{
+    FakeEmbeddingProvider, FlakyHttpClient, HttpRequestRecord, RecordingVectorStore,
+    VectorStoreCall,
+};
+use mcb_utils::constants::vector_store::{
+    STATS_FIELD_FILE_COUNT, STATS_FIELD_LANGUAGES, STATS_FIELD_LARGEST_FILES, VECTOR_FIELD_CONTENT,
+    VECTOR_FIELD_FILE_PATH,
+};
+use rstest::rstest;
+use serde_json::Value;
+
+fn dot(a: &[f32], b: &[f32]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+fn chunk_metadata(file_path: &str, content: &str) -> HashMap<String, Value> {
+    HashMap::from([
+        (VECTOR_FIELD_FILE_PATH.to_owned(), Value::from(file_path)),
+        (VECTOR_FIELD_CONTENT.to_owned(), Value::from(content)),
+    ])
+}
+
+// ============================================================================
+// FakeEmbeddingProvider
+// ============================================================================
+
+#[rstest]
+fn test_fake_embedding_is_deterministic_and_normalized() {
+    let provider = FakeEmbeddingProvider::new(64);
+
+    let first = provider.embed_text("fn refresh_token(token: Token)");
+    let second = FakeEmbeddingProvider::new(64).embed_text("fn refresh_token(token: Token)");
+
+    assert_eq!(first.vector, second.vector);
+    assert_eq!(first.dimensions, 64);
+    assert!((dot(&first.vector, &first.vector) - 1.0).abs() < 1e-5);
+}
+
+#[rstest]
+fn test_fake_embedding_shared_words_are_closer() {
+    let provider = FakeEmbeddingProvider::new(256);
+    let query = provider.embed_text("refresh the access token");
+    let related = provider.embed_text("fn refresh_token(token: Token) -> Token");
+    let unrelated = provider.embed_text("parse yaml configuration file");
+
+    assert!(dot(&query.vector, &related.vector) > dot(&query.vector, &unrelated.vector));
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_fake_embedding_records_batches() -> TestResult {
+    let provider = FakeEmbeddingProvider::new(8);
+
+    provider
+        .embed_batch(&["a b".to_owned(), "c d".to_owned()])
+        .await?;
+    provider.embed("single").await?;
+
+    assert_eq!(provider.batches(), [2, 1]);
+    Ok(())
+}
+
+// ============================================================================
+// RecordingVectorStore
+// ============================================================================
+
+#[rstest]
+#[tokio::test]
+async fn test_recording_store_search_ranks_by_similarity() -> TestResult {
+    let embedder = FakeEmbeddingProvider::new(128);
+    let store = RecordingVectorStore::new();
+    let collection = CollectionId::from_name("testkit");
+    let texts = ["refresh the session token", "render the html template"];
+
+    store.create_collection(&collection, 128).await?;
+    let ids = store
+        .insert_vectors(
+            &collection,
+            &embedder.embed_batch(&texts.map(str::to_owned)).await?,
+            texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| chunk_metadata(&format!("src/{i}.rs"), text))
+                .collect(),
+        )
+        .await?;
+    let query = embedder.embed_text("token refresh");
+    let results = store
+        .search_similar(&collection, &query.vector, 1, None)
+        .await?;
+
+    assert_eq!(ids, ["vec-1", "vec-2"]);
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].id, "vec-1");
+    assert_eq!(results[0].file_path, "src/0.rs");
+    assert_eq!(
+        store.calls(),
+        [
+            VectorStoreCall::CreateCollection {
+                collection: collection.to_string(),
+                dimensions: 128,
+            },
+            VectorStoreCall::InsertVectors {
+                collection: collection.to_string(),
+                count: 2,
+            },
+            VectorStoreCall::SearchSimilar {
+                collection: collection.to_string(),
+                limit: 1,
+                filtered: false,
+            },
+        ]
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_recording_store_delete_and_missing_collection() -> TestResult {
+    let store = RecordingVectorStore::new();
+    let collection = CollectionId::from_name("testkit");
+    let embedding = FakeEmbeddingProvider::new(4).embed_text("alpha beta");
+
+    store.create_collection(&collection, 4).await?;
+    let ids = store
+        .insert_vectors(
+            &collection,
+            &[embedding],
+            vec![chunk_metadata("src/lib.rs", "alpha beta")],
+        )
+        .await?;
+    store.delete_vectors(&collection, &ids).await?;
+
+    assert_eq!(store.vector_count(&collection), 0);
+    let missing = CollectionId::from_name("missing");
+    assert!(store.list_vectors(&missing, 10).await.is_err());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_recording_store_inspects_stored_chunk() -> TestResult {
+    let store = RecordingVectorStore::new();
+    let collection = CollectionId::from_name("testkit");
+    let embedding = FakeEmbeddingProvider::new(8).embed_text("rotate token");
+
+    store.create_collection(&collection, 8).await?;
+    store
+        .upsert_vectors(
+            &collection,
+            &["chunk-1".to_owned()],
+            &[embedding],
+            vec![chunk_metadata("src/auth.rs", "rotate token")],
+        )
+        .await?;
+    let chunk = store
+        .inspect_chunk(&collection, "chunk-1")
+        .await?
+        .ok_or("chunk missing")?;
+
+    assert_eq!(chunk.content, "rotate token");
+    assert_eq!(chunk.metadata[VECTOR_FIELD_FILE_PATH], "src/auth.rs");
+    assert_eq!(chunk.dimensions, Some(8));
+    assert!(
+        chunk
+            .vector_norm
+            .is_some_and(|norm| (norm - 1.0).abs() < 1e-5)
+    );
+    assert_eq!(chunk.location["position"], 0);
+    assert!(store.inspect_chunk(&collection, "missing").await?.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_recording_store_stats_break_down_files() -> TestResult {
+    let store = RecordingVectorStore::new();
+    let collection = CollectionId::from_name("testkit");
+    let provider = FakeEmbeddingProvider::new(4);
+
+    store.create_collection(&collection, 4).await?;
+    store
+        .insert_vectors(
+            &collection,
+            &[
+                provider.embed_text("alpha"),
+                provider.embed_text("beta"),
+                provider.embed_text("gamma"),
+            ],
+            vec![
+                chunk_metadata("src/lib.rs", "alpha"),
+                chunk_metadata("src/main.rs", "beta"),
+                chunk_metadata("src/main.rs", "gamma"),
+            ],
+        )
+        .await?;
+    let stats = store.get_stats(&collection).await?;
+
+    assert_eq!(stats[STATS_FIELD_FILE_COUNT], 2);
+    assert_eq!(stats[STATS_FIELD_LANGUAGES]["unknown"], 3);
+    assert_eq!(stats[STATS_FIELD_LARGEST_FILES][0]["path"], "src/main.rs");
+    assert_eq!(stats[STATS_FIELD_LARGEST_FILES][0]["chunk_count"], 2);
+    Ok(())
+}
+
+// ============================================================================
+// FlakyHttpClient
+// ============================================================================
+
+#[rstest]
+#[tokio::test]
+async fn test_flaky_http_client_fails_first_requests() -> TestResult {
+    let client = FlakyHttpClient::new("ok").failing_first(2);
+
+    assert!(client.get("http://fake/a").await.is_err());
+    assert!(client.post("http://fake/b", b"body").await.is_err());
+    assert_eq!(client.get("http://fake/c").await?, b"ok");
+    assert_eq!(
+        client.requests()[1],
+        HttpRequestRecord {
+            method: "POST",
+            url: "http://fake/b".to_owned(),
+            body: b"body".to_vec(),
+        }
+    );
+    assert_eq!(client.requests().len(), 3);
+    Ok(())
+}
@@ -0,0 +1,121 @@
+//! Pooled HTTP client: retries per resilience policy, pool counters and transport.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use mcb_domain::ports::{
+    HttpClientConfig, HttpClientProvider, HttpTransportConfig, ResiliencePolicy,
+};
+use mcb_providers::utils::http_client::ReqwestHttpClient;
+use rstest::rstest;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Serve `statuses` in order (repeating the last one), one response per connection.
+async fn scripted_server(statuses: Vec<u16>) -> (String, Arc<AtomicUsize>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&hits);
+    tokio::spawn(async move {
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let index = counter.fetch_add(1, Ordering::SeqCst);
+            let status = statuses[index.min(statuses.len() - 1)];
+            let mut buf = [0_u8; 4096];
+            let _ = socket.read(&mut buf).await;
+            let response =
+                format!("HTTP/1.1 {status} X\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok");
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    (format!("http://{addr}/"), hits)
+}
+
+fn client(max_retries: usize) -> ReqwestHttpClient {
+    ReqwestHttpClient::new(HttpClientConfig {
+        resilience: ResiliencePolicy {
+            max_retries,
+            backoff_ms: 1,
+            max_backoff_ms: 2,
+            jitter: 0.0,
+            ..ResiliencePolicy::default()
+        },
+        ..HttpClientConfig::default()
+    })
+    .expect("client")
+}
+
+#[rstest]
+#[tokio::test]
+async fn transient_errors_are_retried_per_policy() {
+    let (url, hits) = scripted_server(vec![503, 429, 200]).await;
+    let http = client(2);
+
+    let body = http.get(&url).await.expect("eventual success");
+
+    assert_eq!(body, b"ok");
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+    let stats = http.pool_stats().expect("pool stats");
+    assert_eq!(stats.requests_total, 3);
+    assert_eq!(stats.retries_total, 2);
+    assert_eq!(stats.failures_total, 0);
+    let host = stats.hosts.values().next().expect("host entry");
+    assert_eq!(host.requests_total, 3);
+    assert_eq!(host.in_flight, 0);
+}
+
+#[rstest]
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let (url, hits) = scripted_server(vec![404]).await;
+    let http = client(3);
+
+    assert!(http.post(&url, b"{}").await.is_err());
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert_eq!(http.pool_stats().expect("pool stats").failures_total, 1);
+}
+
+#[rstest]
+fn invalid_policy_is_rejected() {
+    let config = HttpClientConfig {
+        resilience: ResiliencePolicy {
+            jitter: 2.0,
+            ..ResiliencePolicy::default()
+        },
+        ..HttpClientConfig::default()
+    };
+    assert!(ReqwestHttpClient::new(config).is_err());
+}
+
+#[rstest]
+#[tokio::test]
+async fn explicit_proxy_receives_requests() {
+    let (proxy_url, hits) = scripted_server(vec![200]).await;
+    let http = ReqwestHttpClient::new(HttpClientConfig {
+        transport: HttpTransportConfig {
+            proxy: Some(proxy_url),
+            ..HttpTransportConfig::default()
+        },
+        ..HttpClientConfig::default()
+    })
+    .expect("client");
+
+    let body = http.get("http://upstream.invalid/").await.expect("proxied");
+
+    assert_eq!(body, b"ok");
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[rstest]
+fn missing_ca_bundle_is_rejected() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let bundle = dir.path().join("missing.pem");
+    let config = HttpClientConfig {
+        transport: HttpTransportConfig {
+            ca_bundles: vec![bundle],
+            ..HttpTransportConfig::default()
+        },
+        ..HttpClientConfig::default()
+    };
+    assert!(ReqwestHttpClient::new(config).is_err());
+}
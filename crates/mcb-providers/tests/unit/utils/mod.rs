@@ -0,0 +1,4 @@
+//! Unit tests for shared provider utilities.
+
+mod http_client_tests;
+mod signing_tests;
@@ -0,0 +1,187 @@
+//! Request signing hook: custom signers on the pooled client and the SigV4 signer.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use mcb_domain::error::Result;
+use mcb_domain::ports::{
+    HttpClientConfig, HttpClientProvider, RequestSigner, ResiliencePolicy, SigV4Config,
+    SignableRequest,
+};
+use mcb_providers::utils::http_client::ReqwestHttpClient;
+use mcb_providers::utils::signing::build_request_signer;
+use rstest::rstest;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+/// Adds a header carrying the attempt number and body length.
+#[derive(Default)]
+struct CountingSigner {
+    calls: AtomicUsize,
+}
+
+impl RequestSigner for CountingSigner {
+    fn scheme(&self) -> &str {
+        "test"
+    }
+
+    fn sign(&self, request: &mut SignableRequest) -> Result<()> {
+        let call = self.calls.fetch_add(1, Ordering::SeqCst);
+        request.set_header("x-test-signature", format!("{call}-{}", request.body.len()));
+        Ok(())
+    }
+}
+
+/// Serve `statuses` in order, recording the raw request head of each connection.
+async fn recording_server(statuses: Vec<u16>) -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+    let addr = listener.local_addr().expect("addr");
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+    tokio::spawn(async move {
+        let mut index = 0;
+        while let Ok((mut socket, _)) = listener.accept().await {
+            let status = statuses[usize::min(index, statuses.len() - 1)];
+            index += 1;
+            let mut buf = [0_u8; 4096];
+            let read = socket.read(&mut buf).await.unwrap_or(0);
+            recorded
+                .lock()
+                .expect("lock")
+                .push(String::from_utf8_lossy(&buf[..read]).to_ascii_lowercase());
+            let response =
+                format!("HTTP/1.1 {status} X\r\nContent-Length: 2\r\nConnection: close\r\n\r\nok");
+            let _ = socket.write_all(response.as_bytes()).await;
+        }
+    });
+    (format!("http://{addr}/embed"), requests)
+}
+
+#[rstest]
+#[tokio::test]
+async fn every_attempt_is_signed() {
+    let (url, requests) = recording_server(vec![503, 200]).await;
+    let signer = Arc::new(CountingSigner::default());
+    let http = ReqwestHttpClient::new(HttpClientConfig {
+        resilience: ResiliencePolicy {
+            max_retries: 1,
+            backoff_ms: 1,
+            max_backoff_ms: 1,
+            jitter: 0.0,
+            ..ResiliencePolicy::default()
+        },
+        ..HttpClientConfig::default()
+    })
+    .expect("client")
+    .with_signer(Arc::clone(&signer) as Arc<dyn RequestSigner>);
+
+    http.post(&url, b"{\"input\":[]}")
+        .await
+        .expect("signed post");
+
+    assert_eq!(signer.calls.load(Ordering::SeqCst), 2);
+    let requests = requests.lock().expect("lock");
+    assert!(requests[0].contains("x-test-signature: 0-12"));
+    assert!(requests[1].contains("x-test-signature: 1-12"));
+}
+
+#[rstest]
+fn no_signing_config_builds_no_signer() {
+    assert!(build_request_signer(None).expect("no signer").is_none());
+}
+
+/// Example credentials from the AWS SigV4 test suite.
+fn example_keys() -> SigV4Config {
+    SigV4Config {
+        region: "us-east-1".to_owned(),
+        service: "service".to_owned(),
+        access_key_id: Some("AKIDEXAMPLE".to_owned()),
+        secret_access_key: Some("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY".to_owned()),
+        session_token: None,
+    }
+}
+
+#[cfg(not(feature = "signing-sigv4"))]
+#[rstest]
+fn sigv4_requires_feature() {
+    let config = mcb_domain::ports::RequestSigningConfig::Sigv4(example_keys());
+    let Err(err) = build_request_signer(Some(&config)) else {
+        panic!("sigv4 must be rejected without the feature");
+    };
+    assert!(err.to_string().contains("signing-sigv4"));
+}
+
+#[cfg(feature = "signing-sigv4")]
+mod sigv4 {
+    use chrono::{TimeZone, Utc};
+    use mcb_domain::ports::{SigV4Config, SignableRequest};
+    use mcb_providers::utils::signing::SigV4Signer;
+    use rstest::rstest;
+
+    use super::example_keys;
+
+    fn signer(config: &SigV4Config) -> SigV4Signer {
+        SigV4Signer::from_config(config).expect("signer")
+    }
+
+    fn vanilla_request() -> SignableRequest {
+        SignableRequest {
+            method: "GET".to_owned(),
+            url: "https://example.amazonaws.com/".to_owned(),
+            ..SignableRequest::default()
+        }
+    }
+
+    /// `get-vanilla` from the AWS SigV4 test suite.
+    #[rstest]
+    fn matches_aws_get_vanilla_vector() {
+        let mut request = vanilla_request();
+        let now = Utc
+            .with_ymd_and_hms(2015, 8, 30, 12, 36, 0)
+            .single()
+            .expect("valid timestamp");
+
+        signer(&example_keys())
+            .sign_at(&mut request, now)
+            .expect("sign");
+
+        assert_eq!(request.header("x-amz-date"), Some("20150830T123600Z"));
+        assert_eq!(
+            request.header("authorization"),
+            Some(
+                "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+                 SignedHeaders=host;x-amz-date, \
+                 Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+            )
+        );
+    }
+
+    #[rstest]
+    fn session_token_is_signed_and_bearer_replaced() {
+        let config = SigV4Config {
+            session_token: Some("token".to_owned()),
+            ..example_keys()
+        };
+        let mut request = SignableRequest {
+            headers: vec![("Authorization".to_owned(), "Bearer key".to_owned())],
+            ..vanilla_request()
+        };
+
+        signer(&config)
+            .sign_at(&mut request, Utc::now())
+            .expect("sign");
+
+        assert_eq!(request.header("x-amz-security-token"), Some("token"));
+        let authorization = request.header("authorization").expect("authorization");
+        assert!(authorization.starts_with("AWS4-HMAC-SHA256 "));
+        assert!(authorization.contains("SignedHeaders=host;x-amz-date;x-amz-security-token"));
+        assert_eq!(
+            request
+                .headers
+                .iter()
+                .filter(|(key, _)| key.eq_ignore_ascii_case("authorization"))
+                .count(),
+            1
+        );
+    }
+}
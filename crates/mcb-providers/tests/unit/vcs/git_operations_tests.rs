@@ -3,6 +3,7 @@
 use rstest::rstest;
 use std::path::Path;
 
+use mcb_domain::entities::vcs::WorkingTreeScope;
 use mcb_domain::utils::tests::utils::TestResult;
 use tokio::fs::write as tokio_write;
 
@@ -83,6 +84,50 @@ async fn read_file_variants(
     Ok(())
 }
 
+#[rstest]
+#[tokio::test]
+async fn read_file_at_commit() -> TestResult<()> {
+    let dir = create_test_repo()?;
+    let provider = vcs_provider()?;
+    let repo = provider.open_repository(dir.path()).await?;
+    let initial = provider
+        .commit_history(&repo, repo.default_branch(), Some(1))
+        .await?;
+
+    tokio_write(dir.path().join("README.md"), "# Rewritten\n").await?;
+    run_git(dir.path(), &["commit", "-am", "Rewrite readme"])?;
+
+    let old = provider
+        .read_file(&repo, initial[0].hash(), Path::new("README.md"))
+        .await?;
+    let new = provider
+        .read_file(&repo, "HEAD", Path::new("README.md"))
+        .await?;
+
+    assert!(old.contains("# Test Repo"));
+    assert_eq!(new, "# Rewritten\n");
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn list_files_at_tag() -> TestResult<()> {
+    let dir = create_test_repo()?;
+    run_git(dir.path(), &["tag", "v0.1.0"])?;
+    tokio_write(dir.path().join("added.txt"), "Added\n").await?;
+    run_git(dir.path(), &["add", "."])?;
+    run_git(dir.path(), &["commit", "-m", "Add file"])?;
+    let provider = vcs_provider()?;
+    let repo = provider.open_repository(dir.path()).await?;
+
+    let tagged = provider.list_files(&repo, "v0.1.0").await?;
+    let head = provider.list_files(&repo, "HEAD").await?;
+
+    assert!(!tagged.iter().any(|f| f.to_string_lossy() == "added.txt"));
+    assert!(head.iter().any(|f| f.to_string_lossy() == "added.txt"));
+    Ok(())
+}
+
 #[rstest]
 #[tokio::test]
 async fn diff_refs() -> TestResult<()> {
@@ -149,3 +194,62 @@ async fn list_branches_skips_invalid_entries() -> TestResult<()> {
 
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn file_last_modified_reports_latest_commit_per_file() -> TestResult<()> {
+    let dir = create_test_repo()?;
+    tokio_write(dir.path().join("new_file.txt"), "New content\n").await?;
+    run_git(dir.path(), &["add", "."])?;
+    run_git(dir.path(), &["commit", "-m", "Second commit"])?;
+
+    let provider = vcs_provider()?;
+    let repo = provider.open_repository(dir.path()).await?;
+    let times = provider
+        .file_last_modified(&repo, repo.default_branch())
+        .await?;
+
+    let readme = times[Path::new("README.md")];
+    let new_file = times[Path::new("new_file.txt")];
+    assert!(readme > 0);
+    assert!(new_file >= readme);
+    assert_eq!(times.len(), 2);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn working_tree_changes_by_scope() -> TestResult<()> {
+    let dir = create_test_repo()?;
+    tokio_write(dir.path().join("committed.txt"), "one\n").await?;
+    run_git(dir.path(), &["add", "."])?;
+    run_git(dir.path(), &["commit", "-m", "Second commit"])?;
+    tokio_write(dir.path().join("staged.txt"), "two\n").await?;
+    run_git(dir.path(), &["add", "staged.txt"])?;
+    tokio_write(dir.path().join("untracked.txt"), "three\n").await?;
+
+    let provider = vcs_provider()?;
+    let repo = provider.open_repository(dir.path()).await?;
+    let paths = |files: Vec<mcb_domain::entities::vcs::FileDiff>| {
+        let mut paths: Vec<String> = files
+            .into_iter()
+            .map(|f| f.path.to_string_lossy().into_owned())
+            .collect();
+        paths.sort();
+        paths
+    };
+
+    let staged = provider
+        .working_tree_changes(&repo, &WorkingTreeScope::Staged)
+        .await?;
+    assert_eq!(paths(staged), ["staged.txt"]);
+
+    let since = provider
+        .working_tree_changes(&repo, &WorkingTreeScope::Since("HEAD~1".to_owned()))
+        .await?;
+    assert_eq!(
+        paths(since),
+        ["committed.txt", "staged.txt", "untracked.txt"]
+    );
+    Ok(())
+}
@@ -0,0 +1,244 @@
+//! Property tests for the `EdgeVec` on-disk formats: random writes must come
+//! back unchanged from the write-ahead log and from the snapshot, and a log or
+//! snapshot cut at any byte must still open, losing only what was cut.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::time::Duration;
+
+use mcb_domain::ports::{PersistencePolicy, VectorStoreAdmin, VectorStoreProvider};
+use mcb_domain::value_objects::{CollectionId, Embedding};
+use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+use mcb_utils::constants::vector_store::{
+    EDGEVEC_SNAPSHOT_FILE, EDGEVEC_WAL_FILE, VECTOR_FIELD_FILE_PATH,
+};
+use proptest::prelude::*;
+
+const DIMENSIONS: usize = 4;
+
+/// Long enough for the background flush to reach the log.
+const FLUSH_WAIT: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Debug)]
+enum Op {
+    Upsert {
+        id: String,
+        vector: Vec<f32>,
+        file_path: String,
+    },
+    Delete {
+        id: String,
+    },
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    // A handful of IDs so upserts replace and deletes hit existing records.
+    let id = "[a-d]";
+    prop_oneof![
+        3 => (
+            id,
+            prop::collection::vec(0.01f32..100.0, DIMENSIONS),
+            // Quotes, backslashes and newlines must survive the line-based log.
+            "[a-z/._\"\\\\\n é]{0,16}",
+        )
+            .prop_map(|(id, vector, file_path)| Op::Upsert {
+                id,
+                vector,
+                file_path,
+            }),
+        1 => id.prop_map(|id| Op::Delete { id }),
+    ]
+}
+
+/// File path per live ID after applying `ops` in order.
+fn expected(ops: &[Op]) -> BTreeMap<String, String> {
+    let mut live = BTreeMap::new();
+    for op in ops {
+        match op {
+            Op::Upsert { id, file_path, .. } => {
+                live.insert(id.clone(), file_path.clone());
+            }
+            Op::Delete { id } => {
+                live.remove(id);
+            }
+        }
+    }
+    live
+}
+
+fn policy(dir: &Path, compression_level: i32) -> PersistencePolicy {
+    PersistencePolicy {
+        flush_interval_ms: 10,
+        compression_level,
+        ..PersistencePolicy::new(dir)
+    }
+}
+
+fn open(policy: PersistencePolicy) -> (EdgeVecVectorStoreProvider, CollectionId) {
+    let config = EdgeVecConfig {
+        dimensions: DIMENSIONS,
+        persistence: Some(policy),
+        ..EdgeVecConfig::default()
+    };
+    let collection = CollectionId::from_name("roundtrip");
+    let store =
+        EdgeVecVectorStoreProvider::with_collection(&config, collection).expect("edgevec provider");
+    (store, collection)
+}
+
+async fn write_all(store: &EdgeVecVectorStoreProvider, collection: &CollectionId, ops: &[Op]) {
+    store
+        .create_collection(collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    for op in ops {
+        let written = match op {
+            Op::Upsert {
+                id,
+                vector,
+                file_path,
+            } => {
+                let embedding = Embedding {
+                    vector: vector.clone(),
+                    model: "test".to_owned(),
+                    dimensions: DIMENSIONS,
+                };
+                let metadata = HashMap::from([(
+                    VECTOR_FIELD_FILE_PATH.to_owned(),
+                    serde_json::json!(file_path),
+                )]);
+                store
+                    .upsert_vectors(collection, &[id.clone()], &[embedding], vec![metadata])
+                    .await
+                    .map(drop)
+            }
+            Op::Delete { id } => store.delete_vectors(collection, &[id.clone()]).await,
+        };
+        written.expect("write");
+    }
+}
+
+async fn stored(
+    store: &EdgeVecVectorStoreProvider,
+    collection: &CollectionId,
+) -> BTreeMap<String, String> {
+    store
+        .list_vectors(collection, 100)
+        .await
+        .expect("list")
+        .into_iter()
+        .map(|r| (r.id, r.file_path))
+        .collect()
+}
+
+fn run<F: Future<Output = ()>>(test: F) {
+    tokio::runtime::Runtime::new()
+        .expect("runtime")
+        .block_on(test);
+}
+
+fn tempdir() -> tempfile::TempDir {
+    tempfile::tempdir().expect("tempdir")
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(24))]
+
+    #[test]
+    fn writes_round_trip_through_the_write_ahead_log(ops in prop::collection::vec(op(), 0..12)) {
+        run(async {
+            let dir = tempdir();
+            // The first store stays alive, so only the log is written.
+            let (store, collection) = open(policy(dir.path(), 0));
+            write_all(&store, &collection, &ops).await;
+            tokio::time::sleep(FLUSH_WAIT).await;
+            assert!(!dir.path().join(EDGEVEC_SNAPSHOT_FILE).exists());
+
+            let (restarted, collection) = open(policy(dir.path(), 0));
+            assert_eq!(stored(&restarted, &collection).await, expected(&ops));
+            assert!(restarted.health_check().await.is_ok());
+        });
+    }
+
+    #[test]
+    fn writes_round_trip_through_the_snapshot(
+        ops in prop::collection::vec(op(), 0..12),
+        compression_level in 0i32..=3,
+    ) {
+        run(async {
+            let dir = tempdir();
+            let (store, collection) = open(policy(dir.path(), compression_level));
+            write_all(&store, &collection, &ops).await;
+            store.flush(&collection).await.expect("flush");
+            drop(store);
+            let wal = std::fs::read(dir.path().join(EDGEVEC_WAL_FILE)).expect("wal");
+            assert!(wal.is_empty());
+
+            let (restarted, collection) = open(policy(dir.path(), compression_level));
+            assert_eq!(stored(&restarted, &collection).await, expected(&ops));
+            assert!(restarted.health_check().await.is_ok());
+        });
+    }
+
+    /// Every logged write is one line, so a log cut at any byte replays the
+    /// writes whose lines survived whole and reports the torn rest.
+    #[test]
+    fn write_ahead_log_cut_at_any_byte_replays_its_complete_entries(
+        ops in prop::collection::vec(op(), 1..8),
+        cut in any::<prop::sample::Index>(),
+    ) {
+        run(async {
+            let dir = tempdir();
+            let (store, collection) = open(policy(dir.path(), 0));
+            write_all(&store, &collection, &ops).await;
+            tokio::time::sleep(FLUSH_WAIT).await;
+            let wal_path = dir.path().join(EDGEVEC_WAL_FILE);
+            let wal = std::fs::read(&wal_path).expect("wal");
+            let cut = cut.index(wal.len() + 1);
+            std::fs::write(&wal_path, &wal[..cut]).expect("cut");
+
+            let (restarted, collection) = open(policy(dir.path(), 0));
+            let complete = wal[..cut].iter().filter(|&&b| b == b'\n').count();
+            // The first line creates the collection; each op adds one more.
+            let replayed = &ops[..complete.saturating_sub(1)];
+            assert_eq!(stored(&restarted, &collection).await, expected(replayed));
+            assert_eq!(
+                restarted.collection_exists(&collection).await.expect("exists"),
+                complete > 0
+            );
+            let torn = cut > 0 && wal[cut - 1] != b'\n';
+            assert_eq!(restarted.health_check().await.is_err(), torn);
+        });
+    }
+
+    /// A snapshot cut short anywhere never loads partially: it is either whole
+    /// or quarantined and reported.
+    #[test]
+    fn snapshot_cut_at_any_byte_loads_whole_or_is_quarantined(
+        ops in prop::collection::vec(op(), 1..8),
+        compression_level in 0i32..=3,
+        cut in any::<prop::sample::Index>(),
+    ) {
+        run(async {
+            let dir = tempdir();
+            let (store, collection) = open(policy(dir.path(), compression_level));
+            write_all(&store, &collection, &ops).await;
+            store.flush(&collection).await.expect("flush");
+            drop(store);
+            let snapshot_path = dir.path().join(EDGEVEC_SNAPSHOT_FILE);
+            let snapshot = std::fs::read(&snapshot_path).expect("snapshot");
+            let cut = cut.index(snapshot.len() + 1);
+            std::fs::write(&snapshot_path, &snapshot[..cut]).expect("cut");
+
+            let (restarted, collection) = open(policy(dir.path(), compression_level));
+            if cut == snapshot.len() {
+                assert_eq!(stored(&restarted, &collection).await, expected(&ops));
+                assert!(restarted.health_check().await.is_ok());
+            } else {
+                assert!(stored(&restarted, &collection).await.is_empty());
+                assert!(snapshot_path.with_extension("json.corrupt").exists());
+                assert!(restarted.health_check().await.is_err());
+            }
+        });
+    }
+}
@@ -0,0 +1,746 @@
+//! `EdgeVec` bulk inserts split into batches by `InsertBatchPolicy`, upserts
+//! keyed by caller IDs, tombstones for point-in-time search, recovery from the
+//! on-disk snapshot and write-ahead log, collection aliases, and storage
+//! quotas.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use mcb_domain::ports::{
+    InsertBatchPolicy, PersistencePolicy, StorageQuotaPolicy, VectorStoreAdmin, VectorStoreProvider,
+};
+use mcb_domain::value_objects::{CollectionId, Embedding};
+use mcb_providers::vector_store::{EdgeVecConfig, EdgeVecVectorStoreProvider};
+use mcb_utils::constants::vector_store::{
+    EDGEVEC_SNAPSHOT_FILE, EDGEVEC_WAL_FILE, VECTOR_FIELD_FILE_PATH,
+};
+use mcb_utils::utils::time::epoch_secs_i64;
+use rstest::rstest;
+
+const DIMENSIONS: usize = 4;
+
+fn provider(policy: InsertBatchPolicy) -> (EdgeVecVectorStoreProvider, CollectionId) {
+    provider_with_config(
+        policy,
+        EdgeVecConfig {
+            dimensions: DIMENSIONS,
+            ..EdgeVecConfig::default()
+        },
+    )
+}
+
+fn provider_with_config(
+    policy: InsertBatchPolicy,
+    config: EdgeVecConfig,
+) -> (EdgeVecVectorStoreProvider, CollectionId) {
+    let collection = CollectionId::from_name("batched");
+    let provider = EdgeVecVectorStoreProvider::with_collection(&config, collection)
+        .expect("edgevec provider")
+        .with_insert_batch(policy);
+    (provider, collection)
+}
+
+fn chunk(i: usize) -> (Embedding, HashMap<String, serde_json::Value>) {
+    let embedding = Embedding {
+        vector: vec![i as f32 + 1.0; DIMENSIONS],
+        model: "test".to_owned(),
+        dimensions: DIMENSIONS,
+    };
+    let metadata = HashMap::from([(
+        VECTOR_FIELD_FILE_PATH.to_owned(),
+        serde_json::json!(format!("src/file_{i}.rs")),
+    )]);
+    (embedding, metadata)
+}
+
+#[rstest]
+#[case::single_batch(InsertBatchPolicy { batch_size: 16, max_in_flight: 1 })]
+#[case::sequential_batches(InsertBatchPolicy { batch_size: 2, max_in_flight: 1 })]
+#[case::concurrent_batches(InsertBatchPolicy { batch_size: 2, max_in_flight: 3 })]
+#[tokio::test]
+async fn batched_insert_returns_ids_in_input_order(#[case] policy: InsertBatchPolicy) {
+    let (store, collection) = provider(policy);
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    let (vectors, metadata): (Vec<_>, Vec<_>) = (0..7).map(chunk).unzip();
+
+    let ids = store
+        .insert_vectors(&collection, &vectors, metadata)
+        .await
+        .expect("insert");
+
+    assert_eq!(ids.len(), 7);
+    for (i, id) in ids.iter().enumerate() {
+        let found = store
+            .get_vectors_by_ids(&collection, std::slice::from_ref(id))
+            .await
+            .expect("lookup");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].file_path, format!("src/file_{i}.rs"));
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn batched_insert_rejects_metadata_mismatch() {
+    let (store, collection) = provider(InsertBatchPolicy {
+        batch_size: 2,
+        max_in_flight: 2,
+    });
+    let (vectors, mut metadata): (Vec<_>, Vec<_>) = (0..5).map(chunk).unzip();
+    metadata.pop();
+
+    let err = store
+        .insert_vectors(&collection, &vectors, metadata)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("mismatch"));
+}
+
+#[rstest]
+#[case::zero_batch(InsertBatchPolicy { batch_size: 0, ..InsertBatchPolicy::default() })]
+#[case::zero_in_flight(InsertBatchPolicy { max_in_flight: 0, ..InsertBatchPolicy::default() })]
+fn zero_limits_are_rejected(#[case] policy: InsertBatchPolicy) {
+    let err = policy
+        .validate("providers.vector_store.insert_batch")
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("providers.vector_store.insert_batch")
+    );
+}
+
+#[rstest]
+#[tokio::test]
+async fn upsert_replaces_records_with_the_same_id() {
+    let (store, collection) = provider(InsertBatchPolicy {
+        batch_size: 2,
+        max_in_flight: 2,
+    });
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    let ids: Vec<String> = (0..3).map(|i| format!("chunk-{i}")).collect();
+    let (vectors, metadata): (Vec<_>, Vec<_>) = (0..3).map(chunk).unzip();
+    store
+        .upsert_vectors(&collection, &ids, &vectors, metadata)
+        .await
+        .expect("first upsert");
+
+    let (vectors, metadata): (Vec<_>, Vec<_>) = (3..6).map(chunk).unzip();
+    let stored = store
+        .upsert_vectors(&collection, &ids, &vectors, metadata)
+        .await
+        .expect("second upsert");
+
+    assert_eq!(stored, ids);
+    assert_eq!(
+        store
+            .list_vectors(&collection, 100)
+            .await
+            .expect("list")
+            .len(),
+        3
+    );
+    let found = store
+        .get_vectors_by_ids(&collection, &ids[..1])
+        .await
+        .expect("lookup");
+    assert_eq!(found[0].file_path, "src/file_3.rs");
+    let nearest = store
+        .search_similar(&collection, &[1.0; DIMENSIONS], 10, None)
+        .await
+        .expect("search");
+    assert_eq!(nearest.len(), 3);
+}
+
+#[rstest]
+#[tokio::test]
+async fn upsert_ids_are_scoped_to_their_collection() {
+    let (store, first) = provider(InsertBatchPolicy::default());
+    let second = CollectionId::from_name("other");
+    for collection in [&first, &second] {
+        store
+            .create_collection(collection, DIMENSIONS)
+            .await
+            .expect("create collection");
+        let (vectors, metadata): (Vec<_>, Vec<_>) = (0..1).map(chunk).unzip();
+        store
+            .upsert_vectors(collection, &["shared".to_owned()], &vectors, metadata)
+            .await
+            .expect("upsert");
+    }
+
+    for collection in [&first, &second] {
+        let nearest = store
+            .search_similar(collection, &[1.0; DIMENSIONS], 10, None)
+            .await
+            .expect("search");
+        assert_eq!(nearest.len(), 1);
+        assert_eq!(nearest[0].id, "shared");
+    }
+}
+
+async fn store_one(
+    store: &EdgeVecVectorStoreProvider,
+    collection: &CollectionId,
+    id: &str,
+    i: usize,
+) {
+    let (vectors, metadata): (Vec<_>, Vec<_>) = (i..=i).map(chunk).unzip();
+    store
+        .upsert_vectors(collection, &[id.to_owned()], &vectors, metadata)
+        .await
+        .expect("upsert");
+}
+
+#[rstest]
+#[tokio::test]
+async fn deleted_and_replaced_records_stay_visible_as_of_earlier_times() {
+    let (store, collection) = provider(InsertBatchPolicy::default());
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "deleted", 0).await;
+    store_one(&store, &collection, "replaced", 1).await;
+    let before = epoch_secs_i64().expect("clock");
+
+    // Timestamps have one-second resolution.
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    store
+        .delete_vectors(&collection, &["deleted".to_owned()])
+        .await
+        .expect("delete");
+    store_one(&store, &collection, "replaced", 2).await;
+    store_one(&store, &collection, "added", 3).await;
+
+    let query = [1.0; DIMENSIONS];
+    let mut now: Vec<_> = store
+        .search_similar(&collection, &query, 10, None)
+        .await
+        .expect("search")
+        .into_iter()
+        .map(|r| (r.id, r.file_path))
+        .collect();
+    now.sort();
+    assert_eq!(
+        now,
+        [
+            ("added".to_owned(), "src/file_3.rs".to_owned()),
+            ("replaced".to_owned(), "src/file_2.rs".to_owned()),
+        ]
+    );
+
+    let mut then: Vec<_> = store
+        .search_similar_as_of(&collection, &query, 10, None, before)
+        .await
+        .expect("search as of")
+        .into_iter()
+        .map(|r| (r.id, r.file_path))
+        .collect();
+    then.sort();
+    assert_eq!(
+        then,
+        [
+            ("deleted".to_owned(), "src/file_0.rs".to_owned()),
+            ("replaced".to_owned(), "src/file_1.rs".to_owned()),
+        ]
+    );
+
+    let stats = store.get_stats(&collection).await.expect("stats");
+    assert_eq!(stats["tombstoned_vectors"], 2);
+}
+
+#[rstest]
+#[tokio::test]
+async fn as_of_before_the_retention_window_is_rejected() {
+    let (store, collection) = provider(InsertBatchPolicy::default());
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+
+    let err = store
+        .search_similar_as_of(&collection, &[1.0; DIMENSIONS], 10, None, 0)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("retention window"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn zero_retention_drops_deleted_records_immediately() {
+    let (store, collection) = provider_with_config(
+        InsertBatchPolicy::default(),
+        EdgeVecConfig {
+            dimensions: DIMENSIONS,
+            tombstone_retention_secs: 0,
+            ..EdgeVecConfig::default()
+        },
+    );
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "deleted", 0).await;
+    store
+        .delete_vectors(&collection, &["deleted".to_owned()])
+        .await
+        .expect("delete");
+
+    let stats = store.get_stats(&collection).await.expect("stats");
+    assert_eq!(stats["tombstoned_vectors"], 0);
+    let err = store
+        .search_similar_as_of(&collection, &[1.0; DIMENSIONS], 10, None, 0)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("disabled"));
+}
+
+fn persisted_provider(
+    persistence: PersistencePolicy,
+) -> (EdgeVecVectorStoreProvider, CollectionId) {
+    provider_with_config(
+        InsertBatchPolicy::default(),
+        EdgeVecConfig {
+            dimensions: DIMENSIONS,
+            persistence: Some(persistence),
+            ..EdgeVecConfig::default()
+        },
+    )
+}
+
+async fn sorted_ids(store: &EdgeVecVectorStoreProvider, collection: &CollectionId) -> Vec<String> {
+    let mut ids: Vec<String> = store
+        .list_vectors(collection, 100)
+        .await
+        .expect("list")
+        .into_iter()
+        .map(|r| r.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[rstest]
+#[tokio::test]
+async fn flushed_store_is_recovered_from_its_snapshot() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (store, collection) = persisted_provider(PersistencePolicy::new(dir.path()));
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "kept", 0).await;
+    store_one(&store, &collection, "deleted", 1).await;
+    store
+        .delete_vectors(&collection, &["deleted".to_owned()])
+        .await
+        .expect("delete");
+    let alias = CollectionId::from_name("current");
+    store.swap_alias(&alias, &collection).await.expect("alias");
+    store.flush(&collection).await.expect("flush");
+    drop(store);
+
+    assert!(dir.path().join(EDGEVEC_SNAPSHOT_FILE).exists());
+    let wal = std::fs::read(dir.path().join(EDGEVEC_WAL_FILE)).expect("wal");
+    assert!(wal.is_empty());
+
+    let (restarted, collection) = persisted_provider(PersistencePolicy::new(dir.path()));
+    assert!(
+        restarted
+            .collection_exists(&collection)
+            .await
+            .expect("exists")
+    );
+    assert_eq!(sorted_ids(&restarted, &collection).await, ["kept"]);
+    assert_eq!(sorted_ids(&restarted, &alias).await, ["kept"]);
+    let nearest = restarted
+        .search_similar(&collection, &[1.0; DIMENSIONS], 10, None)
+        .await
+        .expect("search");
+    assert_eq!(nearest.len(), 1);
+    assert_eq!(nearest[0].file_path, "src/file_0.rs");
+    let stats = restarted.get_stats(&collection).await.expect("stats");
+    assert_eq!(stats["tombstoned_vectors"], 1);
+    restarted.health_check().await.expect("healthy");
+}
+
+#[rstest]
+#[tokio::test]
+async fn unflushed_writes_are_replayed_from_the_write_ahead_log() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let policy = PersistencePolicy {
+        flush_interval_ms: 10,
+        ..PersistencePolicy::new(dir.path())
+    };
+    // The first store stays alive, so no shutdown snapshot is written.
+    let (store, collection) = persisted_provider(policy.clone());
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "first", 0).await;
+    store_one(&store, &collection, "second", 1).await;
+    store_one(&store, &collection, "first", 2).await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    assert!(!dir.path().join(EDGEVEC_SNAPSHOT_FILE).exists());
+    let (restarted, collection) = persisted_provider(policy);
+    assert_eq!(
+        sorted_ids(&restarted, &collection).await,
+        ["first", "second"]
+    );
+    let found = restarted
+        .get_vectors_by_ids(&collection, &["first".to_owned()])
+        .await
+        .expect("lookup");
+    assert_eq!(found[0].file_path, "src/file_2.rs");
+}
+
+#[rstest]
+#[tokio::test]
+async fn rejected_upserts_are_not_logged() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (store, collection) = persisted_provider(PersistencePolicy {
+        flush_interval_ms: 10,
+        ..PersistencePolicy::new(dir.path())
+    });
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    let wrong = Embedding {
+        vector: vec![1.0; DIMENSIONS + 1],
+        model: "test".to_owned(),
+        dimensions: DIMENSIONS + 1,
+    };
+
+    let err = store
+        .upsert_vectors(
+            &collection,
+            &["wrong".to_owned()],
+            &[wrong],
+            vec![HashMap::new()],
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("dimensions"));
+    store_one(&store, &collection, "kept", 0).await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let wal = std::fs::read_to_string(dir.path().join(EDGEVEC_WAL_FILE)).expect("wal");
+    assert!(wal.contains("kept"));
+    assert!(!wal.contains("wrong"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn torn_write_ahead_log_tail_is_dropped_before_new_writes() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let policy = PersistencePolicy {
+        flush_interval_ms: 10,
+        ..PersistencePolicy::new(dir.path())
+    };
+    let (store, collection) = persisted_provider(policy.clone());
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "first", 0).await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let wal_path = dir.path().join(EDGEVEC_WAL_FILE);
+    let mut wal = std::fs::read(&wal_path).expect("wal");
+    wal.extend_from_slice(br#"{"op":"upsert","collection":"#);
+    std::fs::write(&wal_path, wal).expect("tear wal");
+
+    let (restarted, collection) = persisted_provider(policy.clone());
+    assert_eq!(sorted_ids(&restarted, &collection).await, ["first"]);
+    let err = restarted.health_check().await.unwrap_err();
+    assert!(err.to_string().contains("torn write-ahead log tail"));
+    store_one(&restarted, &collection, "second", 1).await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+
+    let (recovered, collection) = persisted_provider(policy);
+    assert_eq!(
+        sorted_ids(&recovered, &collection).await,
+        ["first", "second"]
+    );
+    recovered.health_check().await.expect("healthy");
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshot_failing_its_checksum_is_quarantined_and_fails_health() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let plain = PersistencePolicy {
+        compression_level: 0,
+        ..PersistencePolicy::new(dir.path())
+    };
+    let (store, collection) = persisted_provider(plain.clone());
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "kept", 0).await;
+    store.flush(&collection).await.expect("flush");
+    drop(store);
+    let snapshot_path = dir.path().join(EDGEVEC_SNAPSHOT_FILE);
+    let snapshot = std::fs::read_to_string(&snapshot_path).expect("snapshot");
+    std::fs::write(
+        &snapshot_path,
+        snapshot.replace("src/file_0.rs", "src/file_9.rs"),
+    )
+    .expect("tamper snapshot");
+
+    let (restarted, collection) = persisted_provider(plain.clone());
+    let err = restarted.health_check().await.unwrap_err();
+    assert!(err.to_string().contains("snapshot failed verification"));
+    assert!(!snapshot_path.exists());
+    let quarantined = std::fs::read_to_string(snapshot_path.with_extension("json.corrupt"))
+        .expect("quarantined snapshot");
+    assert!(quarantined.contains("src/file_9.rs"));
+    assert!(
+        !restarted
+            .collection_exists(&collection)
+            .await
+            .expect("exists")
+    );
+    restarted.flush(&collection).await.expect("flush");
+    drop(restarted);
+
+    let (reopened, _) = persisted_provider(plain);
+    reopened.health_check().await.expect("healthy");
+}
+
+#[rstest]
+#[tokio::test]
+async fn corrupt_write_ahead_log_entry_fails_recovery() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    std::fs::write(
+        dir.path().join(EDGEVEC_WAL_FILE),
+        "not json\n{\"op\":\"create_collection\",\"name\":\"batched\"}\n",
+    )
+    .expect("write wal");
+
+    let config = EdgeVecConfig {
+        dimensions: DIMENSIONS,
+        persistence: Some(PersistencePolicy::new(dir.path())),
+        ..EdgeVecConfig::default()
+    };
+    let Err(err) =
+        EdgeVecVectorStoreProvider::with_collection(&config, CollectionId::from_name("batched"))
+    else {
+        panic!("a corrupt log entry must fail recovery");
+    };
+    assert!(err.to_string().contains("Corrupt EdgeVec write-ahead log"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn snapshots_are_compressed_and_plain_snapshots_still_load() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let plain = PersistencePolicy {
+        compression_level: 0,
+        ..PersistencePolicy::new(dir.path())
+    };
+    let (store, collection) = persisted_provider(plain);
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "kept", 0).await;
+    store.flush(&collection).await.expect("flush");
+    drop(store);
+    let snapshot = std::fs::read(dir.path().join(EDGEVEC_SNAPSHOT_FILE)).expect("snapshot");
+    assert_eq!(snapshot.first(), Some(&b'{'));
+
+    // A compressing store reads the plain snapshot and rewrites it as zstd.
+    let (restarted, collection) = persisted_provider(PersistencePolicy::new(dir.path()));
+    assert_eq!(sorted_ids(&restarted, &collection).await, ["kept"]);
+    restarted.flush(&collection).await.expect("flush");
+    drop(restarted);
+    let snapshot = std::fs::read(dir.path().join(EDGEVEC_SNAPSHOT_FILE)).expect("snapshot");
+    assert!(snapshot.starts_with(&[0x28, 0xB5, 0x2F, 0xFD]));
+
+    let (reloaded, collection) = persisted_provider(PersistencePolicy::new(dir.path()));
+    assert_eq!(sorted_ids(&reloaded, &collection).await, ["kept"]);
+}
+
+#[rstest]
+#[case::empty_dir(PersistencePolicy::default(), "data_dir")]
+#[case::zero_snapshot_interval(
+    PersistencePolicy { snapshot_interval_secs: 0, ..PersistencePolicy::new("data") },
+    "snapshot_interval_secs"
+)]
+#[case::zero_flush_interval(
+    PersistencePolicy { flush_interval_ms: 0, ..PersistencePolicy::new("data") },
+    "flush_interval_ms"
+)]
+#[case::compression_level_too_high(
+    PersistencePolicy { compression_level: 23, ..PersistencePolicy::new("data") },
+    "compression_level"
+)]
+fn invalid_persistence_is_rejected(#[case] policy: PersistencePolicy, #[case] field: &str) {
+    let err = policy
+        .validate("providers.vector_store.persistence")
+        .unwrap_err();
+    assert!(err.to_string().contains(field));
+}
+
+async fn nearest_file(
+    store: &EdgeVecVectorStoreProvider,
+    collection: &CollectionId,
+) -> Vec<String> {
+    store
+        .search_similar(collection, &[1.0; DIMENSIONS], 10, None)
+        .await
+        .expect("search")
+        .into_iter()
+        .map(|r| r.file_path)
+        .collect()
+}
+
+#[rstest]
+#[tokio::test]
+async fn swapping_an_alias_switches_reads_and_writes_to_the_new_collection() {
+    let (store, v1) = provider(InsertBatchPolicy::default());
+    let v2 = CollectionId::from_name("batched_v2");
+    let alias = CollectionId::from_name("myrepo");
+    for (collection, i) in [(&v1, 0), (&v2, 1)] {
+        store
+            .create_collection(collection, DIMENSIONS)
+            .await
+            .expect("create collection");
+        store_one(&store, collection, "chunk", i).await;
+    }
+
+    store.swap_alias(&alias, &v1).await.expect("create alias");
+    assert!(store.collection_exists(&alias).await.expect("exists"));
+    assert_eq!(nearest_file(&store, &alias).await, ["src/file_0.rs"]);
+
+    store.swap_alias(&alias, &v2).await.expect("swap alias");
+    assert_eq!(nearest_file(&store, &alias).await, ["src/file_1.rs"]);
+    store_one(&store, &alias, "written", 2).await;
+    assert_eq!(sorted_ids(&store, &v2).await, ["chunk", "written"]);
+    assert_eq!(sorted_ids(&store, &v1).await, ["chunk"]);
+
+    store.delete_alias(&alias).await.expect("delete alias");
+    assert!(!store.collection_exists(&alias).await.expect("exists"));
+    assert!(store.collection_exists(&v2).await.expect("exists"));
+}
+
+#[rstest]
+#[tokio::test]
+async fn aliases_need_an_existing_target_and_go_away_with_it() {
+    let (store, collection) = provider(InsertBatchPolicy::default());
+    let alias = CollectionId::from_name("myrepo");
+    let err = store.swap_alias(&alias, &collection).await.unwrap_err();
+    assert!(err.to_string().contains("not found"));
+
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store.swap_alias(&alias, &collection).await.expect("alias");
+    store
+        .delete_collection(&collection)
+        .await
+        .expect("delete collection");
+
+    assert!(!store.collection_exists(&alias).await.expect("exists"));
+    assert!(store.delete_alias(&alias).await.is_err());
+}
+
+fn quota_provider(quota: StorageQuotaPolicy) -> (EdgeVecVectorStoreProvider, CollectionId) {
+    provider_with_config(
+        InsertBatchPolicy::default(),
+        EdgeVecConfig {
+            dimensions: DIMENSIONS,
+            tombstone_retention_secs: 0,
+            quota,
+            ..EdgeVecConfig::default()
+        },
+    )
+}
+
+#[rstest]
+#[tokio::test]
+async fn writes_over_the_collection_quota_are_rejected_until_space_is_freed() {
+    let (store, collection) = quota_provider(StorageQuotaPolicy {
+        max_collection_bytes: 100,
+        warn_percent: 50,
+        ..StorageQuotaPolicy::default()
+    });
+    store
+        .create_collection(&collection, DIMENSIONS)
+        .await
+        .expect("create collection");
+    store_one(&store, &collection, "first", 0).await;
+
+    let usage = store
+        .storage_usage()
+        .await
+        .expect("usage")
+        .expect("edgevec tracks usage");
+    assert!(usage.total_bytes > 50);
+    assert_eq!(usage.warnings().len(), 1);
+
+    let (vectors, metadata): (Vec<_>, Vec<_>) = (1..=1).map(chunk).unzip();
+    let err = store
+        .upsert_vectors(&collection, &["second".to_owned()], &vectors, metadata)
+        .await
+        .unwrap_err();
+    assert_eq!(err.code().as_str(), "MCB-VS-QUOTA");
+    assert_eq!(sorted_ids(&store, &collection).await, ["first"]);
+
+    store
+        .delete_vectors(&collection, &["first".to_owned()])
+        .await
+        .expect("delete");
+    let usage = store.storage_usage().await.expect("usage").expect("usage");
+    assert_eq!(usage.total_bytes, 0);
+    store_one(&store, &collection, "second", 1).await;
+}
+
+#[rstest]
+#[tokio::test]
+async fn total_quota_spans_collections() {
+    let (store, first) = quota_provider(StorageQuotaPolicy {
+        max_total_bytes: 100,
+        ..StorageQuotaPolicy::default()
+    });
+    let second = CollectionId::from_name("other");
+    for collection in [&first, &second] {
+        store
+            .create_collection(collection, DIMENSIONS)
+            .await
+            .expect("create collection");
+    }
+    store_one(&store, &first, "chunk", 0).await;
+
+    let (vectors, metadata): (Vec<_>, Vec<_>) = (1..=1).map(chunk).unzip();
+    let err = store
+        .upsert_vectors(&second, &["chunk".to_owned()], &vectors, metadata)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("all collections"), "{err}");
+
+    store.delete_collection(&first).await.expect("delete");
+    store_one(&store, &second, "chunk", 1).await;
+}
+
+#[rstest]
+#[case::zero(0)]
+#[case::over_hundred(101)]
+fn invalid_quota_warn_percent_is_rejected(#[case] warn_percent: u8) {
+    let policy = StorageQuotaPolicy {
+        warn_percent,
+        ..StorageQuotaPolicy::default()
+    };
+    let err = policy.validate("providers.vector_store.quota").unwrap_err();
+    assert!(err.to_string().contains("warn_percent"));
+}
@@ -227,3 +227,29 @@ fn test_default_output_fields_contains_field(#[case] field: &str) {
         "MILVUS_DEFAULT_OUTPUT_FIELDS must contain '{field}' for extraction to work"
     );
 }
+
+// ---------------------------------------------------------------------------
+// Lazy connection
+// ---------------------------------------------------------------------------
+
+/// `#[tokio::test]` runs on a current-thread runtime, where blocking on the
+/// connection during construction would panic.
+#[rstest]
+#[tokio::test]
+async fn test_construction_defers_connection_to_first_use() {
+    use mcb_domain::ports::{VectorStoreAdmin, VectorStoreProvider};
+    use mcb_domain::registry::vector_store::{
+        VectorStoreProviderConfig, resolve_vector_store_provider,
+    };
+
+    let store: std::sync::Arc<dyn VectorStoreProvider> = resolve_vector_store_provider(
+        &VectorStoreProviderConfig::new(mcb_utils::constants::PROVIDER_SLUG_MILVUS)
+            .with_uri("http://127.0.0.1:1"),
+    )
+    .expect("construction must not connect");
+
+    let exists = store
+        .collection_exists(&CollectionId::from_name("lazy"))
+        .await;
+    assert!(exists.is_err(), "nothing listens on port 1");
+}
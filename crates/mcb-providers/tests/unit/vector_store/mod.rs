@@ -1,2 +1,5 @@
+mod edgevec_roundtrip_tests;
+mod edgevec_tests;
 mod milvus_tests;
 mod pinecone_tests;
+mod similarity_tests;
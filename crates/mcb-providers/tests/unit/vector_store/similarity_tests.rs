@@ -0,0 +1,61 @@
+//! Unit tests for the SIMD similarity kernels.
+
+use mcb_utils::utils::vector::{cosine_similarity, dot, l2_norm};
+use rstest::rstest;
+
+/// Scalar f64 reference the kernels are checked against.
+fn reference_cosine(a: &[f32], b: &[f32]) -> f64 {
+    let dot: f64 = a
+        .iter()
+        .zip(b)
+        .map(|(x, y)| f64::from(*x) * f64::from(*y))
+        .sum();
+    let norm = |v: &[f32]| v.iter().map(|x| f64::from(*x).powi(2)).sum::<f64>().sqrt();
+    dot / (norm(a) * norm(b))
+}
+
+fn vector(len: usize, seed: usize) -> Vec<f32> {
+    (0..len)
+        .map(|i| ((i * 31 + seed * 17) % 97) as f32 / 97.0 - 0.5)
+        .collect()
+}
+
+#[rstest]
+#[case::shorter_than_a_lane(5)]
+#[case::exact_lanes(16)]
+#[case::lanes_and_tail(13)]
+#[case::embedding_size(384)]
+fn cosine_matches_scalar_reference(#[case] len: usize) {
+    let (a, b) = (vector(len, 1), vector(len, 2));
+    let expected = reference_cosine(&a, &b);
+    assert!((f64::from(cosine_similarity(&a, &b)) - expected).abs() < 1e-5);
+    assert!((f64::from(cosine_similarity(&a, &a)) - 1.0).abs() < 1e-5);
+}
+
+#[rstest]
+#[case::lanes_and_tail(13)]
+#[case::embedding_size(384)]
+#[case::large_embedding(1536)]
+fn dot_matches_scalar_reference(#[case] len: usize) {
+    let (a, b) = (vector(len, 1), vector(len, 2));
+    let expected: f64 = a
+        .iter()
+        .zip(&b)
+        .map(|(x, y)| f64::from(*x) * f64::from(*y))
+        .sum();
+    assert!((f64::from(dot(&a, &b)) - expected).abs() < 1e-3);
+}
+
+#[rstest]
+fn dot_and_norm_cover_the_tail() {
+    let v: Vec<f32> = (1..=11).map(|i| i as f32).collect();
+    assert!((dot(&v, &v) - 506.0).abs() < 1e-3);
+    assert!((l2_norm(&[3.0, 4.0]) - 5.0).abs() < 1e-6);
+}
+
+#[rstest]
+#[case::length_mismatch(vec![1.0, 0.0], vec![1.0, 0.0, 0.0])]
+#[case::zero_vector(vec![0.0; 9], vec![1.0; 9])]
+fn degenerate_inputs_score_zero(#[case] a: Vec<f32>, #[case] b: Vec<f32>) {
+    assert!(cosine_similarity(&a, &b).abs() < f32::EPSILON);
+}
@@ -0,0 +1,103 @@
+//! Integration tests for `SeaORM` Usage Repository.
+//!
+//! Tests adding usage to monthly counters and listing them by filter.
+
+use std::sync::Arc;
+
+use mcb_domain::entities::usage::{UsageFilter, UsageRecord};
+use mcb_domain::ports::UsageRepository;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::repos::SeaOrmUsageRepository;
+use rstest::rstest;
+use sea_orm::Database;
+
+async fn setup_repo() -> TestResult<SeaOrmUsageRepository> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+    Ok(SeaOrmUsageRepository::new(Arc::new(db)))
+}
+
+fn usage(org_id: &str, collection: &str, month: &str) -> UsageRecord {
+    UsageRecord {
+        org_id: org_id.to_owned(),
+        collection: collection.to_owned(),
+        month: month.to_owned(),
+        ..UsageRecord::default()
+    }
+}
+
+#[tokio::test]
+async fn add_usage_accumulates_counters() -> TestResult {
+    let repo = setup_repo().await?;
+    let mut indexing = usage("acme", "api", "2026-10");
+    indexing.indexed_bytes = 4_096;
+    indexing.embedding_tokens = 1_024;
+    let mut query = usage("acme", "api", "2026-10");
+    query.queries = 1;
+
+    repo.add_usage(&indexing).await?;
+    repo.add_usage(&indexing).await?;
+    repo.add_usage(&query).await?;
+
+    let records = repo.list_usage(&UsageFilter::default()).await?;
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].indexed_bytes, 8_192);
+    assert_eq!(records[0].embedding_tokens, 2_048);
+    assert_eq!(records[0].queries, 1);
+    Ok(())
+}
+
+#[rstest]
+#[case(
+    None,
+    None,
+    None,
+    &["acme/2026-08/api", "acme/2026-09/api", "acme/2026-09/web", "globex/2026-09/api"]
+)]
+#[case(
+    Some("acme"),
+    None,
+    None,
+    &["acme/2026-08/api", "acme/2026-09/api", "acme/2026-09/web"]
+)]
+#[case(
+    None,
+    Some("2026-09"),
+    Some("2026-09"),
+    &["acme/2026-09/api", "acme/2026-09/web", "globex/2026-09/api"]
+)]
+#[case(Some("globex"), None, Some("2026-08"), &[])]
+#[tokio::test]
+async fn list_usage_filters_by_org_and_month(
+    #[case] org_id: Option<&str>,
+    #[case] from_month: Option<&str>,
+    #[case] to_month: Option<&str>,
+    #[case] expected: &[&str],
+) -> TestResult {
+    let repo = setup_repo().await?;
+    for (org, collection, month) in [
+        ("globex", "api", "2026-09"),
+        ("acme", "web", "2026-09"),
+        ("acme", "api", "2026-09"),
+        ("acme", "api", "2026-08"),
+    ] {
+        let mut record = usage(org, collection, month);
+        record.queries = 1;
+        repo.add_usage(&record).await?;
+    }
+    let filter = UsageFilter {
+        org_id: org_id.map(str::to_owned),
+        from_month: from_month.map(str::to_owned),
+        to_month: to_month.map(str::to_owned),
+    };
+
+    let listed: Vec<String> = repo
+        .list_usage(&filter)
+        .await?
+        .into_iter()
+        .map(|r| format!("{}/{}/{}", r.org_id, r.month, r.collection))
+        .collect();
+
+    assert_eq!(listed, expected);
+    Ok(())
+}
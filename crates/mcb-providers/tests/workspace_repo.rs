@@ -0,0 +1,158 @@
+//! Integration tests for `SeaORM` Workspace Repository.
+//!
+//! Tests workspace membership and finding, recording and releasing files
+//! shared between member collections.
+
+use std::sync::Arc;
+
+use mcb_domain::entities::workspace::{CollectionFile, SharedFile, WorkspaceMember};
+use mcb_domain::ports::{FileHashRepository, WorkspaceRepository};
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::entities::{organization, project};
+use mcb_providers::database::seaorm::repos::{SeaOrmIndexRepository, SeaOrmWorkspaceRepository};
+use mcb_utils::constants::values::DEFAULT_ORG_ID;
+use rstest::rstest;
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, Database, DatabaseConnection};
+
+const PROJECT_ID: &str = "proj-ws-001";
+
+async fn setup_db() -> TestResult<Arc<DatabaseConnection>> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+
+    organization::ActiveModel {
+        id: Set(DEFAULT_ORG_ID.to_owned()),
+        name: Set("Default Org".to_owned()),
+        slug: Set("default-org".to_owned()),
+        settings_json: Set("{}".to_owned()),
+        created_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+        updated_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+    }
+    .insert(&db)
+    .await?;
+    project::ActiveModel {
+        id: Set(PROJECT_ID.to_owned()),
+        org_id: Set(DEFAULT_ORG_ID.to_owned()),
+        name: Set("Workspace Test Project".to_owned()),
+        path: Set("/tmp/workspace-test".to_owned()),
+        created_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+        updated_at: Set(mcb_utils::constants::testing::TEST_TIMESTAMP),
+    }
+    .insert(&db)
+    .await?;
+
+    Ok(Arc::new(db))
+}
+
+fn member(name: &str) -> WorkspaceMember {
+    WorkspaceMember {
+        name: name.to_owned(),
+        collection_id: name.to_owned(),
+    }
+}
+
+fn file(collection: &str, file_path: &str) -> CollectionFile {
+    CollectionFile {
+        collection: collection.to_owned(),
+        file_path: file_path.to_owned(),
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn membership_groups_collections_by_workspace() -> TestResult {
+    let repo = SeaOrmWorkspaceRepository::new(setup_db().await?);
+    repo.add_collection("shop", &member("orders")).await?;
+    repo.add_collection("shop", &member("billing")).await?;
+    repo.add_collection("shop", &member("billing")).await?;
+    repo.add_collection("infra", &member("billing")).await?;
+
+    let shop = repo
+        .get_workspace("shop")
+        .await?
+        .ok_or("workspace missing")?;
+    let names: Vec<&str> = shop.collections.iter().map(|m| m.name.as_str()).collect();
+    assert_eq!(names, vec!["billing", "orders"]);
+    let names: Vec<String> = repo
+        .list_workspaces()
+        .await?
+        .into_iter()
+        .map(|w| w.name)
+        .collect();
+    assert_eq!(names, vec!["infra", "shop"]);
+
+    assert!(repo.remove_collection("infra", "billing").await?);
+    assert!(!repo.remove_collection("infra", "billing").await?);
+    assert!(repo.get_workspace("infra").await?.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn find_origin_skips_other_workspaces_and_copies() -> TestResult {
+    let db = setup_db().await?;
+    let repo = SeaOrmWorkspaceRepository::new(Arc::clone(&db));
+    let hashes = SeaOrmIndexRepository::new(Arc::clone(&db), PROJECT_ID.to_owned());
+    repo.add_collection("shop", &member("orders")).await?;
+    repo.add_collection("shop", &member("billing")).await?;
+    repo.add_collection("shop", &member("users")).await?;
+    hashes
+        .upsert_hash("outsider", "vendor/lib.go", "h1")
+        .await?;
+    hashes.upsert_hash("billing", "vendor/lib.go", "h1").await?;
+    hashes
+        .upsert_hash("orders", "third_party/lib.go", "h1")
+        .await?;
+
+    // Both siblings hold the file; the first in collection order wins.
+    let origin = repo.find_origin("users", "h1").await?;
+    assert_eq!(origin, Some(file("billing", "vendor/lib.go")));
+
+    // A recorded copy is never an origin.
+    repo.record_shared_file(&SharedFile {
+        copy: file("billing", "vendor/lib.go"),
+        origin: file("orders", "third_party/lib.go"),
+        content_hash: "h1".to_owned(),
+    })
+    .await?;
+    let origin = repo.find_origin("users", "h1").await?;
+    assert_eq!(origin, Some(file("orders", "third_party/lib.go")));
+
+    assert!(repo.find_origin("users", "h2").await?.is_none());
+    assert!(repo.find_origin("outsider", "h1").await?.is_none());
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn release_copies_returns_and_deletes_them() -> TestResult {
+    let repo = SeaOrmWorkspaceRepository::new(setup_db().await?);
+    for (copy, origin_path) in [
+        ("billing", "lib.go"),
+        ("users", "lib.go"),
+        ("users", "util.go"),
+    ] {
+        repo.record_shared_file(&SharedFile {
+            copy: file(copy, origin_path),
+            origin: file("orders", origin_path),
+            content_hash: format!("hash-{origin_path}"),
+        })
+        .await?;
+    }
+
+    let copies = repo.list_copies("orders", &["lib.go".to_owned()]).await?;
+    let collections: Vec<&str> = copies.iter().map(|c| c.copy.collection.as_str()).collect();
+    assert_eq!(collections, vec!["billing", "users"]);
+
+    let released = repo.release_copies("orders", Some("lib.go")).await?;
+    assert_eq!(released.len(), 2);
+    assert!(
+        repo.list_copies("orders", &["lib.go".to_owned()])
+            .await?
+            .is_empty()
+    );
+
+    assert_eq!(repo.clear_collection("users").await?, 1);
+    assert!(repo.release_copies("orders", None).await?.is_empty());
+    Ok(())
+}
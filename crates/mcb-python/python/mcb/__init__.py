@@ -0,0 +1,20 @@
+"""Semantic code indexing and search from the MCP Context Browser.
+
+``Engine`` reads the same layered configuration as the ``mcb`` CLI. Every
+method has a blocking form and an ``*_async`` form for ``asyncio``::
+
+    import asyncio
+    import mcb
+
+    async def main():
+        engine = await mcb.Engine.open_async()
+        await engine.index_async("./my-project", "my-project")
+        for hit in await engine.search_async("my-project", "retry policy", limit=5):
+            print(hit.score, hit.file_path, hit.start_line)
+
+    asyncio.run(main())
+"""
+
+from ._mcb import Collection, Engine, IndexReport, SearchHit
+
+__all__ = ["Collection", "Engine", "IndexReport", "SearchHit"]
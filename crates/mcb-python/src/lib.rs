@@ -0,0 +1,258 @@
+//! Python bindings for the MCB engine.
+//!
+//! **Documentation**: [docs/modules/bindings.md](../../../docs/modules/bindings.md)
+//!
+//! Wraps [`mcb::Engine`] as the `mcb.Engine` Python class. Every operation
+//! has a blocking form, which releases the GIL while it runs, and an
+//! `*_async` form returning an awaitable for `asyncio` code. Both run on
+//! the shared Tokio runtime of `pyo3-async-runtimes`.
+//!
+//! ```python
+//! import mcb
+//!
+//! engine = mcb.Engine.open()
+//! engine.index("./my-project", "my-project")
+//! for hit in engine.search("my-project", "where are tokens refreshed", limit=5):
+//!     print(hit.score, hit.file_path, hit.start_line)
+//! ```
+
+use std::future::Future;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use mcb::{Engine, IndexSummary};
+use mcb_domain::value_objects::{CollectionInfo, SearchResult};
+use mcb_utils::constants::limits::DEFAULT_SEARCH_LIMIT;
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::{future_into_py, get_runtime};
+
+/// A search hit.
+#[pyclass(module = "mcb._mcb", frozen, get_all)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    /// Path of the matched file
+    pub file_path: String,
+    /// First line of the matched chunk (1-based)
+    pub start_line: u32,
+    /// Matched code
+    pub content: String,
+    /// Similarity score, higher is better
+    pub score: f64,
+    /// Language of the matched code
+    pub language: String,
+}
+
+#[pymethods]
+impl SearchHit {
+    fn __repr__(&self) -> String {
+        format!(
+            "SearchHit(score={:.3}, file_path={:?}, start_line={})",
+            self.score, self.file_path, self.start_line
+        )
+    }
+}
+
+impl From<SearchResult> for SearchHit {
+    fn from(result: SearchResult) -> Self {
+        Self {
+            file_path: result.file_path,
+            start_line: result.start_line,
+            content: result.content,
+            score: result.score,
+            language: result.language,
+        }
+    }
+}
+
+/// A collection of the vector store.
+#[pyclass(module = "mcb._mcb", frozen, get_all)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collection {
+    /// Collection name
+    pub name: String,
+    /// Stored vectors
+    pub vector_count: u64,
+    /// Indexed files
+    pub file_count: u64,
+    /// Unix time of the last indexing run, when known
+    pub last_indexed: Option<u64>,
+    /// Vector store provider
+    pub provider: String,
+}
+
+impl From<CollectionInfo> for Collection {
+    fn from(info: CollectionInfo) -> Self {
+        Self {
+            name: info.name,
+            vector_count: info.vector_count,
+            file_count: info.file_count,
+            last_indexed: info.last_indexed,
+            provider: info.provider,
+        }
+    }
+}
+
+/// Summary of a completed indexing run.
+#[pyclass(module = "mcb._mcb", frozen, get_all)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexReport {
+    /// Normalized collection name
+    pub collection: String,
+    /// Indexed root directory
+    pub path: String,
+    /// Files discovered for indexing
+    pub files: usize,
+    /// Chunks written to the vector store
+    pub chunks: usize,
+    /// Duration reported by the indexing task
+    pub duration_ms: u64,
+}
+
+impl From<IndexSummary> for IndexReport {
+    fn from(summary: IndexSummary) -> Self {
+        Self {
+            collection: summary.collection,
+            path: summary.path,
+            files: summary.files,
+            chunks: summary.chunks,
+            duration_ms: summary.duration_ms,
+        }
+    }
+}
+
+/// Indexing and search engine built from the MCB configuration.
+#[pyclass(module = "mcb._mcb", name = "Engine", frozen)]
+pub struct PyEngine {
+    engine: Arc<Engine>,
+}
+
+#[pymethods]
+impl PyEngine {
+    /// Build an engine from the layered configuration, with an optional
+    /// profile overlay.
+    #[staticmethod]
+    #[pyo3(signature = (profile = None))]
+    fn open(py: Python<'_>, profile: Option<String>) -> PyResult<Self> {
+        block_on(py, open(profile))
+    }
+
+    /// Awaitable form of `open`.
+    #[staticmethod]
+    #[pyo3(signature = (profile = None))]
+    fn open_async(py: Python<'_>, profile: Option<String>) -> PyResult<Bound<'_, PyAny>> {
+        future_into_py(py, open(profile))
+    }
+
+    /// Index the directory at `path` into `collection` and wait for completion.
+    fn index(&self, py: Python<'_>, path: PathBuf, collection: String) -> PyResult<IndexReport> {
+        block_on(py, index(Arc::clone(&self.engine), path, collection))
+    }
+
+    /// Awaitable form of `index`.
+    fn index_async<'py>(
+        &self,
+        py: Python<'py>,
+        path: PathBuf,
+        collection: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        future_into_py(py, index(Arc::clone(&self.engine), path, collection))
+    }
+
+    /// Semantic search for `query` in `collection`, best hits first.
+    #[pyo3(signature = (collection, query, limit = DEFAULT_SEARCH_LIMIT))]
+    fn search(
+        &self,
+        py: Python<'_>,
+        collection: String,
+        query: String,
+        limit: usize,
+    ) -> PyResult<Vec<SearchHit>> {
+        block_on(
+            py,
+            search(Arc::clone(&self.engine), collection, query, limit),
+        )
+    }
+
+    /// Awaitable form of `search`.
+    #[pyo3(signature = (collection, query, limit = DEFAULT_SEARCH_LIMIT))]
+    fn search_async<'py>(
+        &self,
+        py: Python<'py>,
+        collection: String,
+        query: String,
+        limit: usize,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        future_into_py(
+            py,
+            search(Arc::clone(&self.engine), collection, query, limit),
+        )
+    }
+
+    /// Every collection in the vector store.
+    fn collections(&self, py: Python<'_>) -> PyResult<Vec<Collection>> {
+        block_on(py, collections(Arc::clone(&self.engine)))
+    }
+
+    /// Awaitable form of `collections`.
+    fn collections_async<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        future_into_py(py, collections(Arc::clone(&self.engine)))
+    }
+}
+
+async fn open(profile: Option<String>) -> PyResult<PyEngine> {
+    let engine = Engine::from_config(profile.as_deref())
+        .await
+        .map_err(engine_error)?;
+    Ok(PyEngine {
+        engine: Arc::new(engine),
+    })
+}
+
+async fn index(engine: Arc<Engine>, path: PathBuf, collection: String) -> PyResult<IndexReport> {
+    engine
+        .index_path(path, &collection)
+        .await
+        .map(IndexReport::from)
+        .map_err(engine_error)
+}
+
+async fn search(
+    engine: Arc<Engine>,
+    collection: String,
+    query: String,
+    limit: usize,
+) -> PyResult<Vec<SearchHit>> {
+    let results = engine
+        .search(&collection, &query, limit)
+        .await
+        .map_err(engine_error)?;
+    Ok(results.into_iter().map(SearchHit::from).collect())
+}
+
+async fn collections(engine: Arc<Engine>) -> PyResult<Vec<Collection>> {
+    let collections = engine.collections().await.map_err(engine_error)?;
+    Ok(collections.into_iter().map(Collection::from).collect())
+}
+
+/// Run `future` on the shared runtime with the GIL released.
+fn block_on<T: Send>(
+    py: Python<'_>,
+    future: impl Future<Output = PyResult<T>> + Send,
+) -> PyResult<T> {
+    py.allow_threads(|| get_runtime().block_on(future))
+}
+
+fn engine_error(error: Box<dyn std::error::Error>) -> PyErr {
+    PyRuntimeError::new_err(error.to_string())
+}
+
+/// The `mcb._mcb` extension module.
+#[pymodule]
+fn _mcb(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyEngine>()?;
+    m.add_class::<SearchHit>()?;
+    m.add_class::<Collection>()?;
+    m.add_class::<IndexReport>()?;
+    Ok(())
+}
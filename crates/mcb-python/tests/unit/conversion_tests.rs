@@ -0,0 +1,63 @@
+//! Tests for the conversions from engine results to Python classes.
+
+use mcb::IndexSummary;
+use mcb_domain::value_objects::{CollectionId, CollectionInfo, SearchResult};
+use mcb_python::{Collection, IndexReport, SearchHit};
+use rstest::rstest;
+
+#[rstest]
+fn test_search_hit_keeps_location_and_score() {
+    let hit = SearchHit::from(SearchResult {
+        id: "chunk_1".to_owned(),
+        file_path: "src/auth/login.rs".to_owned(),
+        start_line: 42,
+        content: "pub fn authenticate() {}".to_owned(),
+        score: 0.92,
+        language: "rust".to_owned(),
+        last_modified: Some(1_700_000_000),
+        explanation: None,
+    });
+
+    assert_eq!(
+        hit,
+        SearchHit {
+            file_path: "src/auth/login.rs".to_owned(),
+            start_line: 42,
+            content: "pub fn authenticate() {}".to_owned(),
+            score: 0.92,
+            language: "rust".to_owned(),
+        }
+    );
+}
+
+#[rstest]
+fn test_collection_keeps_counts() {
+    let collection = Collection::from(CollectionInfo {
+        name: "myproject".to_owned(),
+        id: CollectionId::from_name("myproject"),
+        vector_count: 120,
+        file_count: 8,
+        last_indexed: None,
+        provider: "edgevec".to_owned(),
+    });
+
+    assert_eq!(collection.name, "myproject");
+    assert_eq!(collection.vector_count, 120);
+    assert_eq!(collection.file_count, 8);
+    assert_eq!(collection.provider, "edgevec");
+}
+
+#[rstest]
+fn test_index_report_copies_summary() {
+    let report = IndexReport::from(IndexSummary {
+        collection: "myproject".to_owned(),
+        path: "/src/myproject".to_owned(),
+        files: 8,
+        chunks: 120,
+        duration_ms: 1500,
+    });
+
+    assert_eq!(report.files, 8);
+    assert_eq!(report.chunks, 120);
+    assert_eq!(report.duration_ms, 1500);
+}
@@ -0,0 +1,3 @@
+//! Unit tests — `cargo test -p mcb-python --test unit`
+
+mod conversion_tests;
@@ -65,7 +65,7 @@ pub struct OrgEntityArgs {
     pub email: Option<String>,
     /// Data payload for create/update (JSON object)
     #[schemars(description = "Data payload for create/update (JSON object)")]
-    #[schemars(with = "ObjectDataSchema")]
+    #[schemars(with = "Option<ObjectDataSchema>")]
     pub data: Option<serde_json::Value>,
 }
 }
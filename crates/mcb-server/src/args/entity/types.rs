@@ -73,7 +73,7 @@ pub struct EntityArgs {
     #[schemars(description = "Resource type")]
     pub resource: EntityResource,
     /// JSON payload for create/update actions.
-    #[schemars(description = "Data payload for create/update (JSON object)", with = "ObjectDataSchema")]
+    #[schemars(description = "Data payload for create/update (JSON object)", with = "Option<ObjectDataSchema>")]
     pub data: Option<serde_json::Value>,
     /// Resource ID (for get/update/delete/release).
     #[schemars(description = "Resource ID (for get/update/delete)")]
@@ -27,15 +27,12 @@ pub struct IndexArgs {
     pub action: IndexAction,
 
     /// Path to codebase directory (required for 'start' action).
-    #[schemars(
-        description = "Path to codebase directory (required for 'start' action)",
-        with = "String"
-    )]
+    #[schemars(description = "Path to codebase directory (required for 'start' action)")]
     #[validate(custom(function = "super::validate_file_path", message = "Invalid file path"))]
     pub path: Option<String>,
 
     /// Collection name for the index.
-    #[schemars(description = "Collection name for the index", with = "String")]
+    #[schemars(description = "Collection name for the index")]
     #[validate(custom(
         function = "super::validate_collection_name",
         message = "Invalid collection name"
@@ -46,37 +43,32 @@ pub struct IndexArgs {
     #[schemars(skip)]
     pub repo_id: Option<String>,
 
+    /// Organization the index job runs for (injected by context, hidden from MCP schema).
+    #[schemars(skip)]
+    pub org_id: Option<String>,
+
     /// File extensions to include (for 'start' action).
-    #[schemars(
-        description = "File extensions to include (for 'start' action)",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "File extensions to include (for 'start' action)")]
     pub extensions: Option<Vec<String>>,
 
     /// Directories to exclude (for 'start' action).
-    #[schemars(
-        description = "Directories to exclude (for 'start' action)",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "Directories to exclude (for 'start' action)")]
     pub exclude_dirs: Option<Vec<String>>,
 
     /// Glob patterns for files/directories to exclude.
-    #[schemars(
-        description = "Glob patterns for files/directories to exclude",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "Glob patterns for files/directories to exclude")]
     pub ignore_patterns: Option<Vec<String>>,
 
     /// Maximum file size to index (bytes).
-    #[schemars(description = "Maximum file size to index (bytes)", with = "u64")]
+    #[schemars(description = "Maximum file size to index (bytes)")]
     pub max_file_size: Option<u64>,
 
     /// Follow symbolic links during indexing.
-    #[schemars(description = "Follow symbolic links during indexing", with = "bool")]
+    #[schemars(description = "Follow symbolic links during indexing")]
     pub follow_symlinks: Option<bool>,
 
     /// JWT token for authenticated requests.
-    #[schemars(description = "JWT token for authenticated requests", with = "String")]
+    #[schemars(description = "JWT token for authenticated requests")]
     pub token: Option<String>,
 }
 }
@@ -88,18 +80,21 @@ pub struct IndexArgs {
 tool_action! {
     /// Arguments for the `index_repo` tool.
     pub struct IndexRepoArgs => IndexArgs {
-        #[schemars(description = "File extensions to include", with = "Vec<String>")]
+        #[schemars(description = "File extensions to include")]
         extensions: Option<Vec<String>>,
-        #[schemars(description = "Directories to exclude", with = "Vec<String>")]
+        #[schemars(description = "Directories to exclude")]
         exclude_dirs: Option<Vec<String>>,
-        #[schemars(description = "Glob patterns to ignore", with = "Vec<String>")]
+        #[schemars(description = "Glob patterns to ignore")]
         ignore_patterns: Option<Vec<String>>,
-        #[schemars(description = "Maximum file size in bytes", with = "u64")]
+        #[schemars(description = "Maximum file size in bytes")]
         max_file_size: Option<u64>,
-        #[schemars(description = "Follow symbolic links", with = "bool")]
+        #[schemars(description = "Follow symbolic links")]
         follow_symlinks: Option<bool>
         ;
-        hidden { path: Option<String>, collection: Option<String>, repo_id: Option<String>, token: Option<String> }
+        hidden {
+            path: Option<String>, collection: Option<String>, repo_id: Option<String>,
+            org_id: Option<String>, token: Option<String>,
+        }
         ;
         convert |a| {
             action: IndexAction::Start, extensions: a.extensions,
@@ -113,7 +108,10 @@ tool_action! {
     /// Arguments for the `index_status` tool.
     pub struct IndexStatusArgs => IndexArgs {
         ;
-        hidden { path: Option<String>, collection: Option<String>, repo_id: Option<String>, token: Option<String> }
+        hidden {
+            path: Option<String>, collection: Option<String>, repo_id: Option<String>,
+            org_id: Option<String>, token: Option<String>,
+        }
         ;
         convert |a| {
             action: IndexAction::Status, extensions: None, exclude_dirs: None,
@@ -126,7 +124,10 @@ tool_action! {
     /// Arguments for the `clear_index` tool.
     pub struct ClearIndexArgs => IndexArgs {
         ;
-        hidden { path: Option<String>, collection: Option<String>, repo_id: Option<String>, token: Option<String> }
+        hidden {
+            path: Option<String>, collection: Option<String>, repo_id: Option<String>,
+            org_id: Option<String>, token: Option<String>,
+        }
         ;
         convert |a| {
             action: IndexAction::Clear, extensions: None, exclude_dirs: None,
@@ -134,3 +135,36 @@ tool_action! {
         }
     }
 }
+
+tool_schema! {
+/// Arguments for the `analyze_repo` tool.
+pub struct AnalyzeRepoArgs {
+    /// Repository directory to analyze.
+    #[schemars(description = "Repository directory to analyze (default: the current repository)")]
+    #[validate(custom(function = "super::validate_file_path", message = "Invalid file path"))]
+    pub path: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `rebuild_text_index` tool.
+pub struct RebuildTextIndexArgs {
+    /// Collection whose keyword index is rebuilt.
+    #[schemars(
+        description = "Collection whose keyword index to rebuild (default: the current repository's)"
+    )]
+    #[validate(custom(
+        function = "super::validate_collection_name",
+        message = "Invalid collection name"
+    ))]
+    pub collection: Option<String>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+}
+}
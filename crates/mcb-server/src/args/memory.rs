@@ -60,82 +60,64 @@ pub struct MemoryArgs {
     /// Data payload for store actions (JSON object).
     #[schemars(
         description = "Data payload for store action. observation: {content, type?, tags?, metadata?}; execution: {command, output?, status?}; quality_gate: {gate_name, status, details?}; error_pattern: {error_type, message, fix?}; session: {session_id, topics?, decisions?, next_steps?, key_files?}",
-        with = "ObjectDataSchema"
+        with = "Option<ObjectDataSchema>"
     )]
     pub data: Option<serde_json::Value>,
 
     /// Resource IDs for get action.
-    #[schemars(description = "Resource IDs for get action", with = "Vec<String>")]
+    #[schemars(description = "Resource IDs for get action")]
     pub ids: Option<Vec<String>>,
 
     /// Filter by project ID.
-    #[schemars(description = "Filter by project ID", with = "String")]
+    #[schemars(description = "Filter by project ID")]
     pub project_id: Option<String>,
 
     /// Filter by repository ID.
-    #[schemars(description = "Filter by repository ID", with = "String")]
+    #[schemars(description = "Filter by repository ID")]
     pub repo_id: Option<String>,
 
     /// Filter by session ID.
-    #[schemars(description = "Filter by session ID", with = "SessionId")]
+    #[schemars(description = "Filter by session ID")]
     pub session_id: Option<SessionId>,
 
     /// Filter by parent session ID.
-    #[schemars(description = "Filter by parent session ID", with = "String")]
+    #[schemars(description = "Filter by parent session ID")]
     pub parent_session_id: Option<String>,
 
     /// Filter by tags.
-    #[schemars(description = "Filter by tags", with = "Vec<String>")]
+    #[schemars(description = "Filter by tags")]
     pub tags: Option<Vec<String>>,
 
     /// Query string for list/search actions.
-    #[schemars(description = "Query string for list/search actions", with = "String")]
+    #[schemars(description = "Query string for list/search actions")]
     pub query: Option<String>,
 
     /// Anchor observation ID (for timeline action).
-    #[schemars(
-        description = "Anchor observation ID (for timeline action)",
-        with = "String"
-    )]
+    #[schemars(description = "Anchor observation ID (for timeline action)")]
     pub anchor_id: Option<String>,
 
     /// Timeline depth before the anchor (default: 5).
-    #[schemars(
-        description = "Timeline depth before the anchor (default: 5)",
-        with = "usize"
-    )]
+    #[schemars(description = "Timeline depth before the anchor (default: 5)")]
     pub depth_before: Option<usize>,
 
     /// Timeline depth after the anchor (default: 5).
-    #[schemars(
-        description = "Timeline depth after the anchor (default: 5)",
-        with = "usize"
-    )]
+    #[schemars(description = "Timeline depth after the anchor (default: 5)")]
     pub depth_after: Option<usize>,
 
     /// Time window in seconds (for timeline action).
-    #[schemars(
-        description = "Time window in seconds (for timeline action)",
-        with = "i64"
-    )]
+    #[schemars(description = "Time window in seconds (for timeline action)")]
     pub window_secs: Option<i64>,
 
     /// Observation types to include (inject action).
-    #[schemars(
-        description = "Observation types to include (inject action)",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "Observation types to include (inject action)")]
     pub observation_types: Option<Vec<String>>,
 
     /// Maximum token budget for injected context.
-    #[schemars(
-        description = "Maximum token budget for injected context",
-        with = "usize"
-    )]
+    #[schemars(description = "Maximum token budget for injected context")]
     pub max_tokens: Option<usize>,
 
     /// Maximum results.
-    #[schemars(description = "Maximum results", with = "u32")]
+    #[schemars(description = "Maximum results")]
     pub limit: Option<u32>,
 }
 }
@@ -147,9 +129,9 @@ pub struct MemoryArgs {
 tool_action! {
     /// Arguments for the `store_memory` tool.
     pub struct StoreMemoryArgs => MemoryArgs {
-        #[schemars(description = "Content: plain text or JSON {content, type?, tags?, metadata?}", with = "ObjectDataSchema")]
+        #[schemars(description = "Content: plain text or JSON {content, type?, tags?, metadata?}", with = "Option<ObjectDataSchema>")]
         data: Option<serde_json::Value>,
-        #[schemars(description = "Tags for categorization", with = "Vec<String>")]
+        #[schemars(description = "Tags for categorization")]
         tags: Option<Vec<String>>
         ;
         hidden {
@@ -169,7 +151,7 @@ tool_action! {
 tool_action! {
     /// Arguments for the `get_memories` tool.
     pub struct GetMemoriesArgs => MemoryArgs {
-        #[schemars(description = "Memory IDs to retrieve", with = "Vec<String>")]
+        #[schemars(description = "Memory IDs to retrieve")]
         ids: Option<Vec<String>>
         ;
         hidden {
@@ -189,13 +171,13 @@ tool_action! {
 tool_action! {
     /// Arguments for the `list_memories` tool.
     pub struct ListMemoriesArgs => MemoryArgs {
-        #[schemars(description = "Search query to filter", with = "String")]
+        #[schemars(description = "Search query to filter")]
         query: Option<String>,
-        #[schemars(description = "Filter by tags", with = "Vec<String>")]
+        #[schemars(description = "Filter by tags")]
         tags: Option<Vec<String>>,
-        #[schemars(description = "Maximum results", with = "u32")]
+        #[schemars(description = "Maximum results")]
         limit: Option<u32>,
-        #[schemars(description = "Time window in seconds", with = "i64")]
+        #[schemars(description = "Time window in seconds")]
         window_secs: Option<i64>
         ;
         hidden {
@@ -217,9 +199,9 @@ tool_action! {
     pub struct MemoryTimelineArgs => MemoryArgs {
         #[schemars(description = "Anchor observation ID to center timeline on")]
         anchor_id: String,
-        #[schemars(description = "Items before anchor (default: 5)", with = "usize")]
+        #[schemars(description = "Items before anchor (default: 5)")]
         depth_before: Option<usize>,
-        #[schemars(description = "Items after anchor (default: 5)", with = "usize")]
+        #[schemars(description = "Items after anchor (default: 5)")]
         depth_after: Option<usize>
         ;
         hidden {
@@ -239,9 +221,9 @@ tool_action! {
 tool_action! {
     /// Arguments for the `inject_context` tool.
     pub struct InjectContextArgs => MemoryArgs {
-        #[schemars(description = "Observation types to include", with = "Vec<String>")]
+        #[schemars(description = "Observation types to include")]
         observation_types: Option<Vec<String>>,
-        #[schemars(description = "Maximum token budget", with = "usize")]
+        #[schemars(description = "Maximum token budget")]
         max_tokens: Option<usize>
         ;
         hidden {
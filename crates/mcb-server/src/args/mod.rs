@@ -23,10 +23,14 @@ pub mod project;
 pub mod search;
 /// Session lifecycle argument types.
 pub mod session;
+/// Server introspection argument types.
+pub mod stats;
 /// Validation and analysis argument types.
 pub mod validate;
 /// Version control operations argument types.
 pub mod vcs;
+/// Workspace management argument types.
+pub mod workspace;
 
 // Re-export all types directly (no consolidated.rs indirection)
 pub use agent::{AgentAction, AgentArgs, LogDelegationArgs, LogToolCallArgs};
@@ -35,18 +39,30 @@ pub use entity::{
     IssueEntityResource, OrgEntityAction, OrgEntityArgs, OrgEntityResource, PlanEntityAction,
     PlanEntityArgs, PlanEntityResource, VcsEntityAction, VcsEntityArgs, VcsEntityResource,
 };
-pub use index::{ClearIndexArgs, IndexAction, IndexArgs, IndexRepoArgs, IndexStatusArgs};
+pub use index::{
+    AnalyzeRepoArgs, ClearIndexArgs, IndexAction, IndexArgs, IndexRepoArgs, IndexStatusArgs,
+    RebuildTextIndexArgs,
+};
 pub use memory::{
     GetMemoriesArgs, InjectContextArgs, ListMemoriesArgs, MemoryAction, MemoryArgs, MemoryResource,
     MemoryTimelineArgs, StoreMemoryArgs,
 };
 pub use project::{ProjectAction, ProjectArgs, ProjectResource};
-pub use search::{SearchArgs, SearchCodeArgs, SearchMemoryArgs, SearchResource};
+pub use search::{
+    DiversifyBy, FindDuplicatesArgs, FindTestsArgs, GetMoreContextArgs, GrepArgs,
+    ImpactOfChangeArgs, ModuleDependenciesArgs, ReadFileArgs, SearchArgs, SearchCodeArgs,
+    SearchFeedbackArgs, SearchMemoryArgs, SearchResource,
+};
 pub use session::{
     GetSessionArgs, ListSessionsArgs, SessionAction, SessionArgs, StartSessionArgs,
     SummarizeSessionArgs,
 };
+pub use stats::{GetChunkArgs, ServerStatsArgs};
 pub use validate::{
-    AnalyzeCodeArgs, ListRulesArgs, ValidateAction, ValidateArgs, ValidateCodeArgs, ValidateScope,
+    AdrCheckArgs, AnalyzeCodeArgs, ListRulesArgs, ValidateAction, ValidateArchitectureArgs,
+    ValidateArgs, ValidateCodeArgs, ValidateScope, ValidateSeverity,
+};
+pub use vcs::{
+    AnalyzeImpactArgs, CompareBranchesArgs, ListReposArgs, SummarizeChangesArgs, VcsAction, VcsArgs,
 };
-pub use vcs::{AnalyzeImpactArgs, CompareBranchesArgs, ListReposArgs, VcsAction, VcsArgs};
+pub use workspace::{WorkspaceAction, WorkspaceArgs};
@@ -65,14 +65,14 @@ pub struct ProjectArgs {
     /// Data payload for create/update (JSON object)
     #[schemars(
         description = "Data payload for create/update (JSON object)",
-        with = "ObjectDataSchema"
+        with = "Option<ObjectDataSchema>"
     )]
     pub data: Option<serde_json::Value>,
 
     /// Additional filters for list action
     #[schemars(
         description = "Additional filters for list action",
-        with = "ObjectDataSchema"
+        with = "Option<ObjectDataSchema>"
     )]
     pub filters: Option<serde_json::Value>,
 
@@ -5,5 +5,6 @@
 /// Generates `{"type": "object", "additionalProperties": {}}` in JSON Schema,
 /// instead of the empty schema `{}` produced by `serde_json::Value`.
 ///
-/// Use via `#[schemars(with = "ObjectDataSchema")]` on `Option<serde_json::Value>` fields.
+/// Use via `#[schemars(with = "Option<ObjectDataSchema>")]` on `Option<serde_json::Value>`
+/// fields; the `Option` keeps the field out of the schema's `required` list.
 pub type ObjectDataSchema = std::collections::HashMap<String, serde_json::Value>;
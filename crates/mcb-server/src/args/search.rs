@@ -18,6 +18,18 @@ pub enum SearchResource {
 }
 }
 
+tool_enum! {
+/// Grouping used to diversify code search results.
+pub enum DiversifyBy {
+    /// At most one near-top result per file.
+    File,
+    /// Spread results across directories.
+    Directory,
+    /// Collapse chunks covering the same symbol.
+    Symbol,
+}
+}
+
 tool_schema! {
 /// Arguments for the search tool.
 pub struct SearchArgs {
@@ -35,45 +47,97 @@ pub struct SearchArgs {
     pub org_id: Option<String>,
 
     /// Collection name.
-    #[schemars(description = "Collection name", with = "String")]
+    #[schemars(description = "Collection name")]
     pub collection: Option<String>,
 
     /// File extensions to include (code search only).
-    #[schemars(
-        description = "File extensions to include (code search only)",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "File extensions to include (code search only)")]
     pub extensions: Option<Vec<String>>,
 
     /// Additional search filters.
-    #[schemars(description = "Additional search filters", with = "Vec<String>")]
+    #[schemars(description = "Additional search filters")]
     pub filters: Option<Vec<String>>,
 
     /// Maximum results to return.
-    #[schemars(description = "Maximum results to return", with = "u32")]
+    #[schemars(description = "Maximum results to return")]
     pub limit: Option<u32>,
 
     /// Minimum similarity score (0.0-1.0).
-    #[schemars(description = "Minimum similarity score (0.0-1.0)", with = "f32")]
+    #[schemars(description = "Minimum similarity score (0.0-1.0)")]
     #[validate(range(min = 0.0, max = 1.0, message = "Min score must be 0.0-1.0"))]
     pub min_score: Option<f32>,
 
-    /// Filter by tags (for memory search).
+    /// Weight (0.0-1.0) of recent session observations blended into the query (code search only).
     #[schemars(
-        description = "Filter by tags (for memory search)",
-        with = "Vec<String>"
+        description = "Blend recent session observations into the query with this weight (0.0-1.0, code search only); omit to search the query alone"
     )]
-    pub tags: Option<Vec<String>>,
+    #[validate(range(min = 0.0, max = 1.0, message = "Conversation weight must be 0.0-1.0"))]
+    pub conversation_weight: Option<f32>,
 
-    /// Filter by session ID (for memory search).
+    /// Spread code results across files, directories or symbols (MMR re-ranking).
+    #[schemars(
+        description = "Diversify code results by file, directory or symbol (MMR re-ranking, code search only)"
+    )]
+    pub diversify_by: Option<DiversifyBy>,
+
+    /// MMR trade-off between relevance (1.0) and diversity (0.0).
+    #[schemars(
+        description = "Relevance/diversity trade-off for diversify_by, 0.0-1.0 (default: 0.7; lower = more diverse)"
+    )]
+    #[validate(range(min = 0.0, max = 1.0, message = "Diversity lambda must be 0.0-1.0"))]
+    pub diversity_lambda: Option<f32>,
+
+    /// Favor recently committed code; days for the recency boost to halve.
     #[schemars(
-        description = "Filter by session ID (for memory search)",
-        with = "SessionId"
+        description = "Favor recently committed code: half-life in days of the recency boost (code search only); omit for no recency boost"
     )]
+    #[validate(range(exclusive_min = 0.0, message = "Recency half-life must be positive"))]
+    pub recency_half_life_days: Option<f32>,
+
+    /// Only search files under this workspace-relative path prefix (code search only).
+    #[schemars(
+        description = "Only search files under this workspace-relative path prefix, e.g. \"crates/mcb-server/\" (code search only)"
+    )]
+    pub path_prefix: Option<String>,
+
+    /// Only search files matching this workspace-relative glob (code search only).
+    #[schemars(
+        description = "Only search files matching this workspace-relative glob, e.g. \"src/**/*.rs\" (code search only)"
+    )]
+    pub path_glob: Option<String>,
+
+    /// Only search code in this language (code search only).
+    #[schemars(description = "Only search code in this language, e.g. \"rust\" (code search only)")]
+    pub language: Option<String>,
+
+    /// Skip files under these path prefixes or matching these globs (code search only).
+    #[schemars(
+        description = "Skip files under these workspace-relative path prefixes or matching these globs, e.g. [\"vendor/\", \"**/*_test.go\"] (code search only)"
+    )]
+    pub exclude_paths: Option<Vec<String>>,
+
+    /// Skip code in these languages (code search only).
+    #[schemars(
+        description = "Skip code in these languages, e.g. [\"javascript\"] (code search only)"
+    )]
+    pub exclude_languages: Option<Vec<String>>,
+
+    /// Report per-result score components (code search only).
+    #[schemars(
+        description = "Report each result's score components: dense similarity, recency boost, BM25, hybrid and re-rank boost (code search only)"
+    )]
+    pub explain: Option<bool>,
+
+    /// Filter by tags (for memory search).
+    #[schemars(description = "Filter by tags (for memory search)")]
+    pub tags: Option<Vec<String>>,
+
+    /// Filter by session ID (for memory search).
+    #[schemars(description = "Filter by session ID (for memory search)")]
     pub session_id: Option<SessionId>,
 
     /// JWT token for authenticated requests.
-    #[schemars(description = "JWT token for authenticated requests", with = "String")]
+    #[schemars(description = "JWT token for authenticated requests")]
     pub token: Option<String>,
 
     /// Repository ID injected by execution context (hidden from MCP schema).
@@ -96,13 +160,36 @@ tool_action! {
         #[schemars(description = "What you're looking for, in plain English")]
         #[validate(length(min = 1))]
         query: String,
-        #[schemars(description = "Maximum results (default: 10)", with = "u32")]
+        #[schemars(description = "Maximum results (default: 10)")]
         limit: Option<u32>,
-        #[schemars(description = "Filter by file extensions (e.g. [\"rs\", \"py\"])", with = "Vec<String>")]
+        #[schemars(description = "Filter by file extensions (e.g. [\"rs\", \"py\"])")]
         extensions: Option<Vec<String>>,
-        #[schemars(description = "Minimum relevance score from 0.0 to 1.0", with = "f32")]
+        #[schemars(description = "Minimum relevance score from 0.0 to 1.0")]
         #[validate(range(min = 0.0, max = 1.0))]
-        min_score: Option<f32>
+        min_score: Option<f32>,
+        #[schemars(description = "Resolve follow-ups like \"and the error handling?\" by blending recent session context into the query with this weight (0.0-1.0, e.g. 0.3)")]
+        #[validate(range(min = 0.0, max = 1.0))]
+        conversation_weight: Option<f32>,
+        #[schemars(description = "Avoid near-duplicate hits: spread results across \"file\", \"directory\" or \"symbol\"")]
+        diversify_by: Option<DiversifyBy>,
+        #[schemars(description = "Relevance/diversity trade-off for diversify_by, 0.0-1.0 (default: 0.7; lower = more diverse)")]
+        #[validate(range(min = 0.0, max = 1.0))]
+        diversity_lambda: Option<f32>,
+        #[schemars(description = "Rank actively maintained code higher: half-life in days of the recency boost (e.g. 90)")]
+        #[validate(range(exclusive_min = 0.0))]
+        recency_half_life_days: Option<f32>,
+        #[schemars(description = "Search within a subsystem: workspace-relative path prefix (e.g. \"crates/mcb-server/\")")]
+        path_prefix: Option<String>,
+        #[schemars(description = "Search files matching a workspace-relative glob (e.g. \"src/**/*.rs\"; `*` stays within one directory)")]
+        path_glob: Option<String>,
+        #[schemars(description = "Search code in one language only (e.g. \"rust\")")]
+        language: Option<String>,
+        #[schemars(description = "Leave out generated or vendored code: path prefixes or globs to skip (e.g. [\"vendor/\", \"**/generated/**\"])")]
+        exclude_paths: Option<Vec<String>>,
+        #[schemars(description = "Languages to leave out of the results (e.g. [\"javascript\"])")]
+        exclude_languages: Option<Vec<String>>,
+        #[schemars(description = "Show why each result ranked where it did: dense similarity, recency boost, BM25, hybrid and re-rank boost")]
+        explain: Option<bool>
         ;
         hidden {
             org_id: Option<String>, collection: Option<String>,
@@ -114,6 +201,12 @@ tool_action! {
             query: a.query, resource: SearchResource::Code,
             extensions: a.extensions, filters: None,
             limit: a.limit, min_score: a.min_score, tags: None,
+            conversation_weight: a.conversation_weight,
+            diversify_by: a.diversify_by, diversity_lambda: a.diversity_lambda,
+            recency_half_life_days: a.recency_half_life_days,
+            path_prefix: a.path_prefix, path_glob: a.path_glob, language: a.language,
+            exclude_paths: a.exclude_paths, exclude_languages: a.exclude_languages,
+            explain: a.explain,
         }
     }
 }
@@ -124,11 +217,11 @@ tool_action! {
         #[schemars(description = "What you're looking for in stored memories")]
         #[validate(length(min = 1))]
         query: String,
-        #[schemars(description = "Maximum results (default: 10)", with = "u32")]
+        #[schemars(description = "Maximum results (default: 10)")]
         limit: Option<u32>,
-        #[schemars(description = "Filter by tags", with = "Vec<String>")]
+        #[schemars(description = "Filter by tags")]
         tags: Option<Vec<String>>,
-        #[schemars(description = "Minimum relevance score from 0.0 to 1.0", with = "f32")]
+        #[schemars(description = "Minimum relevance score from 0.0 to 1.0")]
         #[validate(range(min = 0.0, max = 1.0))]
         min_score: Option<f32>
         ;
@@ -142,6 +235,337 @@ tool_action! {
             query: a.query, resource: SearchResource::Memory,
             extensions: None, filters: None,
             limit: a.limit, min_score: a.min_score, tags: a.tags,
+            conversation_weight: None, diversify_by: None, diversity_lambda: None,
+            recency_half_life_days: None, path_prefix: None, path_glob: None, language: None,
+            exclude_paths: None, exclude_languages: None, explain: None,
         }
     }
 }
+
+tool_schema! {
+/// Arguments for the `get_more_context` tool.
+pub struct GetMoreContextArgs {
+    /// Natural language query the context should match.
+    #[schemars(description = "What you need more context about, in plain English")]
+    #[validate(length(min = 1))]
+    pub query: String,
+
+    /// Maximum tokens of code to return.
+    #[schemars(description = "Maximum tokens of code to return (default: 2000)")]
+    #[validate(range(min = 1))]
+    pub token_budget: Option<usize>,
+
+    /// Forget chunks already returned to this session before searching.
+    #[schemars(description = "Forget chunks already returned to this session before searching")]
+    pub reset: Option<bool>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `search_feedback` tool.
+pub struct SearchFeedbackArgs {
+    /// Query the rated result was returned for.
+    #[schemars(description = "The search query that produced the result")]
+    #[validate(length(min = 1))]
+    pub query: String,
+
+    /// Chunk id from the search result.
+    #[schemars(description = "Result id as returned by search")]
+    #[validate(length(min = 1))]
+    pub chunk_id: String,
+
+    /// File path from the search result.
+    #[schemars(description = "Result file_path as returned by search")]
+    #[validate(length(min = 1))]
+    pub file_path: String,
+
+    /// Thumbs up (`true`) or thumbs down (`false`).
+    #[schemars(description = "true if the result was relevant (thumbs up), false otherwise")]
+    pub relevant: bool,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `grep` tool.
+pub struct GrepArgs {
+    /// Exact text (or regular expression) to find.
+    #[schemars(description = "Exact text to find, e.g. an identifier or error message")]
+    #[validate(length(min = 1))]
+    pub pattern: String,
+
+    /// Treat `pattern` as a regular expression.
+    #[schemars(
+        description = "Treat pattern as a regular expression (default: false, literal match)"
+    )]
+    pub regex: Option<bool>,
+
+    /// Match letter case exactly.
+    #[schemars(description = "Match letter case exactly (default: true)")]
+    pub case_sensitive: Option<bool>,
+
+    /// Natural language query whose results must contain the pattern.
+    #[schemars(
+        description = "Combined mode: run this semantic query and keep only results containing the pattern"
+    )]
+    pub semantic_query: Option<String>,
+
+    /// Only scan files under this path prefix.
+    #[schemars(
+        description = "Only scan files under this workspace-relative path prefix, e.g. \"crates/mcb-server/\""
+    )]
+    pub path_prefix: Option<String>,
+
+    /// Only scan files matching this glob.
+    #[schemars(
+        description = "Only scan files matching this workspace-relative glob, e.g. \"src/**/*.rs\""
+    )]
+    pub path_glob: Option<String>,
+
+    /// Only scan files in this language.
+    #[schemars(description = "Only scan files in this language, e.g. \"rust\"")]
+    pub language: Option<String>,
+
+    /// Skip files under these prefixes or matching these globs.
+    #[schemars(
+        description = "Skip files under these path prefixes or matching these globs, e.g. [\"vendor/\"]"
+    )]
+    pub exclude_paths: Option<Vec<String>>,
+
+    /// Stop after this many matching lines.
+    #[schemars(description = "Stop after this many matching lines (default: 100, max: 1000)")]
+    #[validate(range(min = 1, max = 1000))]
+    pub max_matches: Option<u32>,
+
+    /// Maximum confirmed results in combined mode.
+    #[schemars(description = "Maximum confirmed results in combined mode (default: 10)")]
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<u32>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `read_file` tool.
+pub struct ReadFileArgs {
+    /// Workspace-relative path of an indexed file.
+    #[schemars(description = "Workspace-relative path of an indexed file, e.g. a search result's file_path")]
+    #[validate(length(min = 1))]
+    pub path: String,
+
+    /// First line to return (1-based, inclusive).
+    #[schemars(description = "First line to return, 1-based and inclusive (default: 1)")]
+    #[validate(range(min = 1))]
+    pub start_line: Option<u32>,
+
+    /// Last line to return (1-based, inclusive).
+    #[schemars(description = "Last line to return, 1-based and inclusive (default: end of file)")]
+    #[validate(range(min = 1))]
+    pub end_line: Option<u32>,
+
+    /// Maximum bytes of content to return.
+    #[schemars(description = "Maximum bytes of content to return (default: 65536, max: 1048576)")]
+    #[validate(range(min = 1, max = 1_048_576))]
+    pub max_bytes: Option<usize>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `find_tests` tool.
+pub struct FindTestsArgs {
+    /// Name of the symbol to find tests for.
+    #[schemars(description = "Name of the function, method or type to find tests for, e.g. parse_config")]
+    #[validate(length(min = 1))]
+    pub symbol: String,
+
+    /// Maximum number of linked tests to return.
+    #[schemars(description = "Maximum number of linked tests to return (default: 20, max: 100)")]
+    #[validate(range(min = 1, max = 100))]
+    pub limit: Option<usize>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `module_dependencies` tool.
+pub struct ModuleDependenciesArgs {
+    /// Workspace-relative path of an indexed file.
+    #[schemars(description = "Workspace-relative path of an indexed file, e.g. src/config.rs")]
+    #[validate(length(min = 1))]
+    pub path: String,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `impact_of_change` tool.
+pub struct ImpactOfChangeArgs {
+    /// Workspace-relative paths of the changed files.
+    #[schemars(description = "Workspace-relative paths of the changed (indexed) files")]
+    #[validate(length(min = 1, max = 100))]
+    pub paths: Vec<String>,
+
+    /// Maximum import hops to follow from the changed files.
+    #[schemars(
+        description = "Maximum import hops to follow from the changed files (default: 3, max: 10)"
+    )]
+    #[validate(range(min = 1, max = 10))]
+    pub max_depth: Option<usize>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `find_duplicates` tool.
+pub struct FindDuplicatesArgs {
+    /// Minimum similarity for a pair to be reported.
+    #[schemars(
+        description = "Minimum similarity (0.0-1.0) of the normalized code for a pair to be reported (default: 0.8; 1.0 = exact or renamed clones only)"
+    )]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub min_similarity: Option<f64>,
+
+    /// Minimum length, in lines, of both fragments of a pair.
+    #[schemars(description = "Minimum length in lines of both fragments of a pair (default: 6)")]
+    #[validate(range(min = 1))]
+    pub min_lines: Option<usize>,
+
+    /// Only look for duplicates in files under this path prefix.
+    #[schemars(
+        description = "Only look for duplicates in files under this workspace-relative path prefix, e.g. \"crates/mcb-server/\""
+    )]
+    pub path_prefix: Option<String>,
+
+    /// Only look for duplicates in files matching this glob.
+    #[schemars(
+        description = "Only look for duplicates in files matching this workspace-relative glob, e.g. \"src/**/*.rs\""
+    )]
+    pub path_glob: Option<String>,
+
+    /// Only look for duplicates in files in this language.
+    #[schemars(description = "Only look for duplicates in files in this language, e.g. \"rust\"")]
+    pub language: Option<String>,
+
+    /// Skip files under these prefixes or matching these globs.
+    #[schemars(
+        description = "Skip files under these path prefixes or matching these globs, e.g. [\"tests/\"]"
+    )]
+    pub exclude_paths: Option<Vec<String>>,
+
+    /// Maximum number of duplicate pairs to return.
+    #[schemars(description = "Maximum number of duplicate pairs to return (default: 50, max: 500)")]
+    #[validate(range(min = 1, max = 500))]
+    pub limit: Option<usize>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Session ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub session_id: Option<SessionId>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
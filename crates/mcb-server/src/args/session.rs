@@ -36,41 +36,38 @@ pub struct SessionArgs {
     pub org_id: Option<String>,
 
     /// Session ID (required for get, update, summarize).
-    #[schemars(
-        description = "Session ID (required for get, update, summarize)",
-        with = "SessionId"
-    )]
+    #[schemars(description = "Session ID (required for get, update, summarize)")]
     pub session_id: Option<SessionId>,
 
     /// Data payload for create/update (JSON object).
     #[schemars(
         description = "Data payload for create/update. create requires model and accepts session_summary_id?, agent_type? (or top-level args.agent_type), parent_session_id?, prompt_summary?, project_id?, worktree_id?; update accepts mutable session fields",
-        with = "ObjectDataSchema"
+        with = "Option<ObjectDataSchema>"
     )]
     pub data: Option<serde_json::Value>,
 
     /// Filter by project ID.
-    #[schemars(description = "Filter by project ID", with = "String")]
+    #[schemars(description = "Filter by project ID")]
     pub project_id: Option<String>,
 
     /// Filter by worktree ID.
-    #[schemars(description = "Filter by worktree ID", with = "String")]
+    #[schemars(description = "Filter by worktree ID")]
     pub worktree_id: Option<String>,
 
     /// Filter by parent session ID.
-    #[schemars(description = "Filter by parent session ID", with = "String")]
+    #[schemars(description = "Filter by parent session ID")]
     pub parent_session_id: Option<String>,
 
     /// Filter by agent type.
-    #[schemars(description = "Filter by agent type", with = "String")]
+    #[schemars(description = "Filter by agent type")]
     pub agent_type: Option<String>,
 
     /// Filter by status.
-    #[schemars(description = "Filter by status", with = "String")]
+    #[schemars(description = "Filter by status")]
     pub status: Option<String>,
 
     /// Maximum results for list.
-    #[schemars(description = "Maximum results for list", with = "u32")]
+    #[schemars(description = "Maximum results for list")]
     pub limit: Option<u32>,
 }
 }
@@ -82,11 +79,11 @@ pub struct SessionArgs {
 tool_action! {
     /// Arguments for the `start_session` tool.
     pub struct StartSessionArgs => SessionArgs {
-        #[schemars(description = "AI model identifier", with = "String")]
+        #[schemars(description = "AI model identifier")]
         model: Option<String>,
-        #[schemars(description = "Agent type label", with = "String")]
+        #[schemars(description = "Agent type label")]
         agent_type: Option<String>,
-        #[schemars(description = "Brief summary of session purpose", with = "ObjectDataSchema")]
+        #[schemars(description = "Brief summary of session purpose", with = "Option<ObjectDataSchema>")]
         data: Option<serde_json::Value>
         ;
         hidden {
@@ -105,7 +102,7 @@ tool_action! {
 tool_action! {
     /// Arguments for the `get_session` tool.
     pub struct GetSessionArgs => SessionArgs {
-        #[schemars(description = "Session ID to retrieve", with = "SessionId")]
+        #[schemars(description = "Session ID to retrieve")]
         session_id: Option<SessionId>
         ;
         hidden {
@@ -123,11 +120,11 @@ tool_action! {
 tool_action! {
     /// Arguments for the `list_sessions` tool.
     pub struct ListSessionsArgs => SessionArgs {
-        #[schemars(description = "Filter by status", with = "String")]
+        #[schemars(description = "Filter by status")]
         status: Option<String>,
-        #[schemars(description = "Filter by agent type", with = "String")]
+        #[schemars(description = "Filter by agent type")]
         agent_type: Option<String>,
-        #[schemars(description = "Maximum results", with = "u32")]
+        #[schemars(description = "Maximum results")]
         limit: Option<u32>
         ;
         hidden {
@@ -146,7 +143,7 @@ tool_action! {
 tool_action! {
     /// Arguments for the `summarize_session` tool.
     pub struct SummarizeSessionArgs => SessionArgs {
-        #[schemars(description = "Session ID to summarize", with = "SessionId")]
+        #[schemars(description = "Session ID to summarize")]
         session_id: Option<SessionId>
         ;
         hidden {
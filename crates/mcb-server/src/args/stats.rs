@@ -0,0 +1,39 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+use schemars::JsonSchema;
+use serde::Deserialize;
+use validator::Validate;
+
+tool_schema! {
+/// Arguments for the `server_stats` tool.
+pub struct ServerStatsArgs {
+    /// Also return every raw counter, gauge and histogram series.
+    #[schemars(description = "Also return every raw counter, gauge and histogram series")]
+    pub include_raw: Option<bool>,
+}
+}
+
+tool_schema! {
+/// Arguments for the `get_chunk` tool.
+pub struct GetChunkArgs {
+    /// Record ID of the chunk.
+    #[schemars(description = "Record ID of the chunk, e.g. a search_code result's id")]
+    #[validate(length(min = 1))]
+    pub id: String,
+
+    /// Collection holding the chunk.
+    #[schemars(description = "Collection holding the chunk (default: the current repository's)")]
+    pub collection: Option<String>,
+
+    /// Also return the raw vector.
+    #[schemars(
+        description = "Also return the stored vector values, not just its norm and dimensions"
+    )]
+    pub include_vector: Option<bool>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+}
+}
@@ -1,6 +1,7 @@
 //!
 //! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
 //!
+use mcb_utils::constants::validate::VALIDATOR_ADR;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
@@ -35,22 +36,19 @@ pub struct ValidateArgs {
     pub action: ValidateAction,
 
     /// Scope: file or project.
-    #[schemars(description = "Scope: file or project", with = "ValidateScope")]
+    #[schemars(description = "Scope: file or project")]
     pub scope: Option<ValidateScope>,
 
     /// Path to file or project directory.
-    #[schemars(description = "Path to file or project directory", with = "String")]
+    #[schemars(description = "Path to file or project directory")]
     pub path: Option<String>,
 
     /// Specific rules to run (empty = all).
-    #[schemars(
-        description = "Specific rules to run (empty = all)",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "Specific rules to run (empty = all)")]
     pub rules: Option<Vec<String>>,
 
     /// Rule category filter.
-    #[schemars(description = "Rule category filter", with = "String")]
+    #[schemars(description = "Rule category filter")]
     pub category: Option<String>,
 }
 }
@@ -62,11 +60,11 @@ pub struct ValidateArgs {
 tool_action! {
     /// Arguments for the `validate_code` tool.
     pub struct ValidateCodeArgs => ValidateArgs {
-        #[schemars(description = "Scope: file or project (default: project)", with = "ValidateScope")]
+        #[schemars(description = "Scope: file or project (default: project)")]
         scope: Option<ValidateScope>,
-        #[schemars(description = "Specific rules to run (empty = all)", with = "Vec<String>")]
+        #[schemars(description = "Specific rules to run (empty = all)")]
         rules: Option<Vec<String>>,
-        #[schemars(description = "Rule category filter", with = "String")]
+        #[schemars(description = "Rule category filter")]
         category: Option<String>
         ;
         hidden { path: Option<String> }
@@ -78,7 +76,7 @@ tool_action! {
 tool_action! {
     /// Arguments for the `analyze_code` tool.
     pub struct AnalyzeCodeArgs => ValidateArgs {
-        #[schemars(description = "Path to file or directory", with = "String")]
+        #[schemars(description = "Path to file or directory")]
         path: Option<String>
         ;
         hidden { }
@@ -90,7 +88,7 @@ tool_action! {
 tool_action! {
     /// Arguments for the `list_rules` tool.
     pub struct ListRulesArgs => ValidateArgs {
-        #[schemars(description = "Filter by category", with = "String")]
+        #[schemars(description = "Filter by category")]
         category: Option<String>
         ;
         hidden { }
@@ -98,3 +96,75 @@ tool_action! {
         convert |a| { action: ValidateAction::ListRules, scope: None, path: None, rules: None, category: a.category }
     }
 }
+
+tool_enum! {
+/// Minimum severity reported by `validate_architecture`.
+pub enum ValidateSeverity {
+    /// Errors only.
+    Error,
+    /// Errors and warnings.
+    Warning,
+    /// Every violation.
+    Info,
+}
+}
+
+tool_schema! {
+/// Arguments for the `validate_architecture` tool.
+pub struct ValidateArchitectureArgs {
+    /// Workspace directory to validate.
+    #[schemars(description = "Workspace directory to validate (default: the current repository)")]
+    #[validate(length(min = 1))]
+    pub path: Option<String>,
+
+    /// Collection whose repository should be validated.
+    #[schemars(
+        description = "Collection of the repository to validate; must be the current repository's collection"
+    )]
+    #[validate(length(min = 1))]
+    pub collection: Option<String>,
+
+    /// Validators to run.
+    #[schemars(description = "Validators to run, as listed by list_rules (empty = all enabled)")]
+    pub validators: Option<Vec<String>>,
+
+    /// Violation IDs or ID prefixes to report.
+    #[schemars(
+        description = "Only report these violation IDs or ID prefixes, e.g. [\"LAYER001\", \"CA\"] (empty = all)"
+    )]
+    pub rules: Option<Vec<String>>,
+
+    /// Minimum severity to report.
+    #[schemars(description = "Minimum severity to report: error, warning or info (default: info)")]
+    pub min_severity: Option<ValidateSeverity>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
+
+tool_action! {
+    /// Arguments for the `adr_check` tool.
+    pub struct AdrCheckArgs => ValidateArchitectureArgs {
+        #[schemars(description = "Workspace directory whose ADRs to check (default: the current repository)")]
+        #[validate(length(min = 1))]
+        path: Option<String>,
+        #[schemars(description = "Only report these ADR rule IDs or ID prefixes (empty = all)")]
+        rules: Option<Vec<String>>,
+        #[schemars(description = "Minimum severity to report: error, warning or info (default: info)")]
+        min_severity: Option<ValidateSeverity>
+        ;
+        hidden { repo_id: Option<String>, repo_path: Option<String> }
+        ;
+        convert |a| {
+            path: a.path, collection: None,
+            validators: Some(vec![VALIDATOR_ADR.to_owned()]),
+            rules: a.rules, min_severity: a.min_severity,
+        }
+    }
+}
@@ -35,49 +35,42 @@ pub struct VcsArgs {
     pub org_id: Option<String>,
 
     /// Repository identifier
-    #[schemars(description = "Repository identifier", with = "String")]
+    #[schemars(description = "Repository identifier")]
     pub repo_id: Option<String>,
 
     /// Local file system path to the repository
-    #[schemars(description = "Repository path on disk", with = "String")]
+    #[schemars(description = "Repository path on disk")]
     #[validate(custom(function = "super::validate_file_path", message = "Invalid file path"))]
     pub repo_path: Option<String>,
 
     /// Base branch for comparison
-    #[schemars(description = "Base branch name", with = "String")]
+    #[schemars(description = "Base branch name")]
     pub base_branch: Option<String>,
 
     /// Target branch for comparison
-    #[schemars(description = "Compare/target branch name", with = "String")]
+    #[schemars(description = "Compare/target branch name")]
     pub target_branch: Option<String>,
 
     /// Search query string
-    #[schemars(description = "Search query for branch search", with = "String")]
+    #[schemars(description = "Search query for branch search")]
     pub query: Option<String>,
 
     /// Specific branches to index
-    #[schemars(
-        description = "Branches to index (default: repo default branch)",
-        with = "Vec<String>"
-    )]
+    #[schemars(description = "Branches to index (default: repo default branch)")]
     pub branches: Option<Vec<String>>,
 
     /// Whether to index commit history
-    #[schemars(
-        description = "Whether to include commit history when indexing",
-        with = "bool"
-    )]
+    #[schemars(description = "Whether to include commit history when indexing")]
     pub include_commits: Option<bool>,
 
     /// Depth of history to index
     #[schemars(
-        description = "Commit history depth (default: 50 from config, or 1000 if no config)",
-        with = "usize"
+        description = "Commit history depth (default: 50 from config, or 1000 if no config)"
     )]
     pub depth: Option<usize>,
 
     /// Result limit for list/search operations
-    #[schemars(description = "Limit for search or list actions", with = "u32")]
+    #[schemars(description = "Limit for search or list actions")]
     pub limit: Option<u32>,
 }
 }
@@ -89,7 +82,7 @@ pub struct VcsArgs {
 tool_action! {
     /// Arguments for the `list_repos` tool.
     pub struct ListReposArgs => VcsArgs {
-        #[schemars(description = "Maximum results", with = "u32")]
+        #[schemars(description = "Maximum results")]
         limit: Option<u32>
         ;
         hidden {
@@ -111,9 +104,9 @@ tool_action! {
         base_branch: String,
         #[schemars(description = "Target branch name")]
         target_branch: String,
-        #[schemars(description = "Include commit history", with = "bool")]
+        #[schemars(description = "Include commit history")]
         include_commits: Option<bool>,
-        #[schemars(description = "Commit history depth (default: 50)", with = "usize")]
+        #[schemars(description = "Commit history depth (default: 50)")]
         depth: Option<usize>
         ;
         hidden {
@@ -133,11 +126,11 @@ tool_action! {
 tool_action! {
     /// Arguments for the `analyze_impact` tool.
     pub struct AnalyzeImpactArgs => VcsArgs {
-        #[schemars(description = "Branches to analyze", with = "Vec<String>")]
+        #[schemars(description = "Branches to analyze")]
         branches: Option<Vec<String>>,
-        #[schemars(description = "Analysis depth (default: 1000)", with = "usize")]
+        #[schemars(description = "Analysis depth (default: 1000)")]
         depth: Option<usize>,
-        #[schemars(description = "Maximum results", with = "u32")]
+        #[schemars(description = "Maximum results")]
         limit: Option<u32>
         ;
         hidden {
@@ -152,3 +145,32 @@ tool_action! {
         }
     }
 }
+
+tool_schema! {
+/// Arguments for the `summarize_changes` tool.
+pub struct SummarizeChangesArgs {
+    /// Older revision to compare from.
+    #[schemars(
+        description = "Older branch, tag or commit SHA; omit to compare the last index with the working tree"
+    )]
+    #[validate(length(min = 1))]
+    pub base_ref: Option<String>,
+
+    /// Newer revision to compare to.
+    #[schemars(description = "Newer branch, tag or commit SHA (default: HEAD; requires base_ref)")]
+    #[validate(length(min = 1))]
+    pub head_ref: Option<String>,
+
+    /// Collection name injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub collection: Option<String>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+
+    /// Workspace/repo path injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_path: Option<String>,
+}
+}
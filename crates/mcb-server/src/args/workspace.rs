@@ -0,0 +1,58 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+tool_enum! {
+/// Actions available for workspace management.
+pub enum WorkspaceAction {
+    /// Add a collection to a workspace, creating the workspace if needed.
+    Add,
+    /// Remove a collection from a workspace.
+    Remove,
+    /// List workspaces and their collections.
+    List,
+    /// Search every collection of a workspace.
+    Search,
+}
+}
+
+tool_schema! {
+/// Arguments for the `workspace` tool.
+pub struct WorkspaceArgs {
+    /// Action: add, remove, list, search.
+    #[schemars(description = "Action: add, remove, list, search")]
+    pub action: WorkspaceAction,
+
+    /// Workspace name (required except for list).
+    #[schemars(description = "Workspace name (required except for list)")]
+    #[validate(length(min = 1))]
+    pub workspace: Option<String>,
+
+    /// Collection to add or remove.
+    #[schemars(
+        description = "Collection to add or remove (default: the current repository's collection)"
+    )]
+    #[validate(custom(
+        function = "super::validate_collection_name",
+        message = "Invalid collection name"
+    ))]
+    pub collection: Option<String>,
+
+    /// Natural language search query (search only).
+    #[schemars(description = "Natural language search query (search only)")]
+    #[validate(length(min = 1))]
+    pub query: Option<String>,
+
+    /// Maximum results to return.
+    #[schemars(description = "Maximum results to return (search only)")]
+    #[validate(range(min = 1))]
+    pub limit: Option<u32>,
+
+    /// Repository ID injected by execution context (hidden from MCP schema).
+    #[schemars(skip)]
+    pub repo_id: Option<String>,
+}
+}
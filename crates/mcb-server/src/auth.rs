@@ -17,6 +17,10 @@ use mcb_utils::constants::http::HTTP_HEADER_AUTHORIZATION;
 // Support both direct app routes (`/alive`) and prefixed ingress rewrites (`/api/alive`).
 const ADMIN_AUTH_EXEMPT_PATHS: &[&str] = &["/alive", "/api/alive"];
 
+// MCP tools exposing or rebuilding raw index internals; HTTP callers need an
+// admin API key.
+const ADMIN_TOOLS: &[&str] = &["get_chunk", "rebuild_text_index"];
+
 /// Authenticated admin principal.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct AdminPrincipal {
@@ -87,6 +91,12 @@ pub fn is_admin_auth_exempt_path(path: &str) -> bool {
     ADMIN_AUTH_EXEMPT_PATHS.contains(&path)
 }
 
+/// Returns whether an MCP tool requires admin API-key authentication.
+#[must_use]
+pub fn is_admin_tool(tool_name: &str) -> bool {
+    ADMIN_TOOLS.contains(&tool_name)
+}
+
 /// Extract API key from headers, checking both custom header and Authorization bearer.
 ///
 /// # Arguments
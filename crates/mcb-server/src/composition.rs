@@ -11,9 +11,10 @@
 use std::sync::Arc;
 
 use mcb_domain::ports::{
-    EmbeddingProvider, HybridSearchProvider, IndexingOperationsInterface,
+    EmbeddingProvider, HybridSearchProvider, IndexingOperationsInterface, MetricsSnapshotProvider,
     ValidationOperationsInterface, VectorStoreProvider,
 };
+use mcb_domain::registry::ServiceResolutionContext;
 use mcb_domain::registry::admin_operations::{
     IndexingOperationsProviderConfig, ValidationOperationsProviderConfig,
     resolve_indexing_operations_provider, resolve_validation_operations_provider,
@@ -23,14 +24,18 @@ use mcb_domain::registry::project_detection::{
     ProjectDetectionServiceConfig, resolve_project_detection_service,
 };
 use mcb_domain::registry::services::{
-    resolve_agent_session_service, resolve_context_service, resolve_indexing_service,
-    resolve_memory_service, resolve_search_service, resolve_validation_service,
+    resolve_agent_session_service, resolve_change_summary_service, resolve_context_service,
+    resolve_duplicate_detection_service, resolve_file_content_service, resolve_grep_service,
+    resolve_indexing_service, resolve_memory_service, resolve_module_graph_service,
+    resolve_repo_analysis_service, resolve_search_feedback_service, resolve_search_service,
+    resolve_session_context_service, resolve_usage_service, resolve_validation_service,
+    resolve_workspace_service,
 };
 use mcb_domain::registry::vcs::{VcsProviderConfig, resolve_vcs_provider};
 
 use crate::mcp_server::{McpEntityRepositories, McpServer, McpServices};
 use crate::state::McpServerBootstrap;
-use crate::tools::ExecutionFlow;
+use crate::tools::{ExecutionFlow, compile_tool_schemas};
 use mcb_utils::constants::{
     DEFAULT_DATABASE_PROVIDER, DEFAULT_LANGUAGE_PROVIDER, DEFAULT_NAMESPACE, DEFAULT_VCS_PROVIDER,
 };
@@ -48,11 +53,15 @@ use mcb_utils::constants::{
 /// * `embedding_provider` - Shared embedding provider resolved at startup.
 /// * `vector_store_provider` - Shared vector store provider resolved at startup.
 /// * `hybrid_search` - Hybrid search provider for combined BM25/semantic search.
+/// * `metrics` - Metrics recorder the shared providers report into; backs `server_stats`.
 /// * `execution_flow` - Whether to run in stdio-only or hybrid mode.
+/// * `read_only` - Reject tool calls and admin requests that write.
+/// * `idempotency_window_secs` - How long results of mutating calls are kept by idempotency key.
 ///
 /// # Errors
 ///
-/// Returns a domain error if any service or repository resolution fails.
+/// Returns a domain error if a tool input schema does not compile or any
+/// service or repository resolution fails.
 #[allow(clippy::too_many_arguments)]
 pub fn build_mcp_server_bootstrap(
     registry_ctx: &dyn std::any::Any,
@@ -60,8 +69,14 @@ pub fn build_mcp_server_bootstrap(
     embedding_provider: Arc<dyn EmbeddingProvider>,
     vector_store_provider: Arc<dyn VectorStoreProvider>,
     hybrid_search: Arc<dyn HybridSearchProvider>,
+    metrics: Arc<dyn MetricsSnapshotProvider>,
     execution_flow: ExecutionFlow,
+    read_only: bool,
+    idempotency_window_secs: u64,
 ) -> mcb_domain::Result<McpServerBootstrap> {
+    // 0. Reject tools whose input schema does not compile
+    compile_tool_schemas()?;
+
     // 1. Resolve DB repos
     let repos = resolve_database_repositories(
         DEFAULT_DATABASE_PROVIDER,
@@ -73,14 +88,20 @@ pub fn build_mcp_server_bootstrap(
     let (indexing_ops, validation_ops) = resolve_admin_operation_trackers()?;
 
     // 3. Build MCP services struct from registry-resolved services
-    let mcp_services = build_mcp_services(registry_ctx, &repos, hybrid_search)?;
+    let mcp_services = build_mcp_services(
+        registry_ctx,
+        &repos,
+        hybrid_search,
+        Arc::clone(&vector_store_provider),
+        metrics,
+    )?;
 
     let vcs_for_defaults = Arc::clone(&mcp_services.vcs);
-    let mcp_server = Arc::new(McpServer::new(
-        mcp_services,
-        &vcs_for_defaults,
-        Some(execution_flow),
-    ));
+    let mcp_server = Arc::new(
+        McpServer::new(mcp_services, &vcs_for_defaults, Some(execution_flow))
+            .with_read_only(read_only)
+            .with_idempotency_window(idempotency_window_secs),
+    );
 
     // 5. Build bootstrap with shared ports from context
     Ok(McpServerBootstrap {
@@ -91,6 +112,7 @@ pub fn build_mcp_server_bootstrap(
         vector_store: vector_store_provider,
         indexing_ops,
         validation_ops,
+        collection_manifests: repos.collection_manifest,
     })
 }
 
@@ -114,7 +136,15 @@ fn build_mcp_services(
     registry_ctx: &dyn std::any::Any,
     repos: &DatabaseRepositories,
     hybrid_search: Arc<dyn HybridSearchProvider>,
+    vector_store: Arc<dyn VectorStoreProvider>,
+    metrics: Arc<dyn MetricsSnapshotProvider>,
 ) -> mcb_domain::Result<McpServices> {
+    let bulkheads = registry_ctx
+        .downcast_ref::<ServiceResolutionContext>()
+        .map(|ctx| Arc::clone(&ctx.bulkheads))
+        .ok_or_else(|| {
+            mcb_domain::error::Error::internal("MCP services require ServiceResolutionContext")
+        })?;
     Ok(McpServices {
         indexing: resolve_indexing_service(registry_ctx)?,
         context: resolve_context_service(registry_ctx)?,
@@ -122,12 +152,28 @@ fn build_mcp_services(
         validation: resolve_validation_service(registry_ctx)?,
         memory: resolve_memory_service(registry_ctx)?,
         agent_session: resolve_agent_session_service(registry_ctx)?,
+        session_context: resolve_session_context_service(registry_ctx)?,
+        search_feedback: resolve_search_feedback_service(registry_ctx)?,
+        grep: resolve_grep_service(registry_ctx)?,
+        file_content: resolve_file_content_service(registry_ctx)?,
+        change_summary: resolve_change_summary_service(registry_ctx)?,
+        module_graph: resolve_module_graph_service(registry_ctx)?,
+        duplicates: resolve_duplicate_detection_service(registry_ctx)?,
+        repo_analysis: resolve_repo_analysis_service(registry_ctx)?,
+        workspace: resolve_workspace_service(registry_ctx)?,
+        usage: resolve_usage_service(registry_ctx)?,
         project: resolve_project_detection_service(&ProjectDetectionServiceConfig::new(
             DEFAULT_LANGUAGE_PROVIDER,
         ))?,
         project_workflow: Arc::clone(&repos.project),
+        test_links: Arc::clone(&repos.test_link),
+        idempotency: Arc::clone(&repos.idempotency),
         vcs: resolve_vcs_provider(&VcsProviderConfig::new(DEFAULT_VCS_PROVIDER))?,
         hybrid_search,
+        vector_store,
+        metrics,
+        bulkheads,
+        auth: Arc::clone(&repos.auth),
         entities: McpEntityRepositories {
             vcs: Arc::clone(&repos.vcs_entity),
             plan: Arc::clone(&repos.plan_entity),
@@ -1,10 +1,13 @@
 //! Collections API controller — returns vector store collection info as JSON.
 
-use mcb_domain::value_objects::CollectionId;
+use mcb_domain::ports::VectorStoreFeature;
+use mcb_domain::value_objects::{CollectionId, CollectionReport};
 
 use crate::state::McbState;
-use axum::extract::Extension;
+use axum::extract::{Extension, Path, Query};
+use axum::http::StatusCode;
 use loco_rs::prelude::*;
+use serde::Deserialize;
 
 /// Returns a list of all vector store collections.
 ///
@@ -53,6 +56,88 @@ pub async fn chunks(Extension(state): Extension<McbState>) -> Result<Response> {
     format::json(all_chunks)
 }
 
+/// Query parameters of [`chunk`].
+#[derive(Debug, Default, Deserialize)]
+pub struct ChunkQuery {
+    /// Also return the stored vector values
+    #[serde(default)]
+    pub include_vector: bool,
+}
+
+/// Returns everything stored for one chunk — used to debug relevance.
+///
+/// Calls `VectorStoreProvider::inspect_chunk()` and returns the chunk's
+/// content, metadata, vector norm and store location; the vector itself only
+/// with `?include_vector=true`.
+///
+/// # Errors
+///
+/// Returns 501 when the provider cannot inspect records, 404 when the chunk
+/// does not exist, or an error when the lookup fails.
+pub async fn chunk(
+    Extension(state): Extension<McbState>,
+    Path((collection, id)): Path<(String, String)>,
+    Query(query): Query<ChunkQuery>,
+) -> Result<Response> {
+    if let Err(e) = state.vector_store.capabilities().require(
+        state.vector_store.provider_name(),
+        VectorStoreFeature::ChunkInspection,
+    ) {
+        let body = format::json(serde_json::json!({ "error": e.to_string() }))?;
+        return Ok((StatusCode::NOT_IMPLEMENTED, body).into_response());
+    }
+    let chunk = state
+        .vector_store
+        .inspect_chunk(&CollectionId::from_string(&collection), &id)
+        .await
+        .map_err(|e| loco_rs::Error::string(&e.to_string()))?
+        .ok_or(loco_rs::Error::NotFound)?;
+
+    if query.include_vector {
+        format::json(chunk)
+    } else {
+        format::json(chunk.without_vector())
+    }
+}
+
+/// Returns the content breakdown and build state of one collection.
+///
+/// Combines `VectorStoreAdmin::get_stats()` (vector count, chunks per
+/// language, largest files) with the collection manifest (last index time,
+/// chunker version, embedding model) and lists why the collection is stale.
+///
+/// # Errors
+///
+/// Returns an error when the provider or the manifest repository fails.
+pub async fn collection_report(
+    Extension(state): Extension<McbState>,
+    Path(collection): Path<String>,
+) -> Result<Response> {
+    let id = CollectionId::from_string(&collection);
+    let stats = state
+        .vector_store
+        .get_stats(&id)
+        .await
+        .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
+    let manifest = state
+        .collection_manifests
+        .get_manifest(&id.to_string())
+        .await
+        .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
+
+    let report = CollectionReport::from_stats(collection, &stats);
+    let report = match manifest {
+        Some(manifest) => report.with_build(
+            manifest.chunker_version,
+            manifest.embedding_model,
+            manifest.distance_metric,
+            manifest.updated_at,
+        ),
+        None => report,
+    };
+    format::json(report)
+}
+
 /// Registers collections API routes.
 #[must_use]
 pub fn routes() -> Routes {
@@ -0,0 +1,20 @@
+//! DI API controller — returns the provider registry report as JSON.
+
+use std::sync::Arc;
+
+use axum::extract::Extension;
+use loco_rs::prelude::*;
+use mcb_domain::registry::DiReport;
+
+/// Returns every registered provider, the selections resolved at startup
+/// with their config sources, and unresolved or conflicting registrations.
+///
+/// The report is built once by the composition root; registrations are
+/// fixed at link time.
+///
+/// # Errors
+///
+/// Returns an error if JSON response serialization fails.
+pub async fn di_report(Extension(report): Extension<Arc<DiReport>>) -> Result<Response> {
+    format::json(report.as_ref())
+}
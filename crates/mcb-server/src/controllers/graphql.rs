@@ -10,6 +10,7 @@
 use async_graphql::{
     dynamic::Schema,
     http::{GraphQLPlaygroundConfig, playground_source},
+    parser::{parse_query, types::OperationType},
 };
 use async_graphql_axum::GraphQLRequest;
 use axum::{extract::Extension, http::HeaderMap};
@@ -29,6 +30,17 @@ async fn graphql_playground() -> Result<Response> {
     Ok(Response::new(res.into()))
 }
 
+/// Whether `query` contains a mutation; unparsable queries are left to the
+/// schema to reject.
+fn is_mutation(query: &str) -> bool {
+    parse_query(query).is_ok_and(|document| {
+        document
+            .operations
+            .iter()
+            .any(|(_, operation)| operation.node.ty == OperationType::Mutation)
+    })
+}
+
 async fn graphql_handler(
     Extension(state): Extension<McbState>,
     State(ctx): State<AppContext>,
@@ -45,6 +57,12 @@ async fn graphql_handler(
     .map_err(|_| (axum::http::StatusCode::UNAUTHORIZED, "Unauthorized"))?;
 
     let mut gql_req = gql_req.into_inner();
+    if state.mcp_server.is_read_only() && is_mutation(&gql_req.query) {
+        return Err((
+            axum::http::StatusCode::FORBIDDEN,
+            "Mutations are disabled in read-only mode",
+        ));
+    }
     gql_req = gql_req.data(seaography::UserContext { user_id: 0 });
 
     let schema: Schema = ctx.shared_store.get().ok_or((
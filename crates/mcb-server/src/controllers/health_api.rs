@@ -1,13 +1,21 @@
 //! Health API controller — returns provider health status as JSON.
+//!
+//! `/healthz` is liveness (the process answers HTTP), `/readyz` is readiness
+//! (critical dependencies respond) and `/health` is the admin summary.
 
+use std::sync::Arc;
+
+use crate::health::ReadinessChecker;
 use crate::state::McbState;
 use axum::extract::Extension;
+use axum::http::StatusCode;
 use loco_rs::prelude::*;
 
 /// Returns health status of embedding and vector store providers.
 ///
 /// Calls `EmbeddingProvider::health_check()` and `VectorStoreAdmin::health_check()`
-/// on the shared providers from `McbState`.
+/// on the shared providers from `McbState`, and lists the optional features
+/// the vector store supports.
 ///
 /// # Errors
 ///
@@ -30,7 +38,9 @@ pub async fn health(Extension(state): Extension<McbState>) -> Result<Response> {
             "healthy": embedding_healthy,
         },
         "vector_store": {
+            "provider": state.vector_store.provider_name(),
             "healthy": vector_store_healthy,
+            "capabilities": state.vector_store.capabilities(),
         },
     }))
 }
@@ -46,6 +56,23 @@ pub async fn alive() -> Result<Response> {
     }))
 }
 
+/// Returns per-dependency readiness; responds 503 when a critical dependency fails.
+///
+/// Results are cached by [`ReadinessChecker`] for the configured TTL.
+///
+/// # Errors
+///
+/// Returns an error if JSON response serialization fails.
+pub async fn readyz(Extension(checker): Extension<Arc<ReadinessChecker>>) -> Result<Response> {
+    let report = checker.check().await;
+    let code = if report.ready {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+    Ok((code, format::json(report)?).into_response())
+}
+
 /// Registers health API routes.
 #[must_use]
 pub fn routes() -> Routes {
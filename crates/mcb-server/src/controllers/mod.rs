@@ -6,11 +6,15 @@ pub mod admin;
 pub mod admin_config;
 /// Collections API (vector store browser).
 pub mod collections_api;
+/// DI API (provider registry report).
+pub mod di_api;
 /// GraphQL API.
 pub mod graphql;
 /// Health API (provider health checks).
 pub mod health_api;
 /// Jobs API (indexing and validation operations).
 pub mod jobs_api;
+/// Usage API (organization usage reports and billing export).
+pub mod usage_api;
 /// Web UI pages (dashboard, config, health, jobs, browse, 404).
 pub mod web;
@@ -0,0 +1,68 @@
+//! Usage API controller — returns organization usage as JSON or CSV.
+
+use axum::extract::{Extension, Query};
+use axum::http::{StatusCode, header};
+use loco_rs::prelude::*;
+use mcb_domain::entities::UsageFilter;
+use serde::Deserialize;
+
+use crate::state::McbState;
+
+/// Query parameters of [`usage_report`].
+#[derive(Debug, Default, Deserialize)]
+pub struct UsageQuery {
+    /// Only this organization
+    pub org_id: Option<String>,
+    /// First month included, as `YYYY-MM`
+    pub from: Option<String>,
+    /// Last month included, as `YYYY-MM`
+    pub to: Option<String>,
+    /// `json` (default) or `csv`
+    pub format: Option<String>,
+}
+
+/// Returns indexed bytes, embedding tokens and query counts per
+/// organization and month, with one line per collection for billing.
+///
+/// `?format=csv` downloads the per-collection lines as `usage.csv`.
+///
+/// # Errors
+///
+/// Returns 400 for an unknown format, or an error when the usage
+/// repository fails.
+pub async fn usage_report(
+    Extension(state): Extension<McbState>,
+    Query(query): Query<UsageQuery>,
+) -> Result<Response> {
+    let filter = UsageFilter {
+        org_id: query.org_id,
+        from_month: query.from,
+        to_month: query.to,
+    };
+    let report = state
+        .mcp_server
+        .usage_service()
+        .report(&filter)
+        .await
+        .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
+
+    match query.format.as_deref() {
+        None | Some("json") => format::json(report),
+        Some("csv") => Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+                (
+                    header::CONTENT_DISPOSITION,
+                    "attachment; filename=\"usage.csv\"",
+                ),
+            ],
+            report.to_csv(),
+        )
+            .into_response()),
+        Some(other) => {
+            let error = format!("unknown usage format '{other}', expected json or csv");
+            let body = format::json(serde_json::json!({ "error": error }))?;
+            Ok((StatusCode::BAD_REQUEST, body).into_response())
+        }
+    }
+}
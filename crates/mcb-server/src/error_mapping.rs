@@ -4,8 +4,21 @@
 mod groups;
 
 use mcb_domain::error;
-use mcb_domain::error::Error;
+use mcb_domain::error::{Error, ErrorCode};
 use rmcp::model::{CallToolResult, Content, ErrorData as McpError};
+use serde_json::{Value, json};
+
+/// Machine-readable error payload attached to MCP errors and tool results.
+///
+/// Shape: `{"code": "MCB-EMB-429", "retryable": true}`. Only the stable code
+/// and retry hint are exposed; messages stay sanitized.
+#[must_use]
+pub fn error_code_payload(code: ErrorCode) -> Value {
+    json!({
+        "code": code.as_str(),
+        "retryable": code.is_retryable(),
+    })
+}
 
 /// Logs the underlying error server-side and returns a generic internal error.
 ///
@@ -20,17 +33,19 @@ pub fn safe_internal_error(context: &str, err: &dyn std::fmt::Display) -> McpErr
 ///
 /// # Behavior
 /// Returns client-fixable errors for `NotFound` and `InvalidArgument`; all
-/// other variants are converted to a generic internal error.
+/// other variants are converted to a generic internal error. Both carry the
+/// stable [`ErrorCode`] in the error `data` field.
 ///
 /// # Security
 /// Never exposes provider/internal details to external callers.
 #[must_use]
 pub fn to_opaque_mcp_error(e: &Error) -> McpError {
     error!("McpError", "operation failed", e);
+    let data = Some(error_code_payload(e.code()));
     if matches!(e, Error::NotFound { .. } | Error::InvalidArgument { .. }) {
-        McpError::invalid_params(e.to_string(), None)
+        McpError::invalid_params(e.to_string(), data)
     } else {
-        McpError::internal_error("internal server error", None)
+        McpError::internal_error("internal server error", data)
     }
 }
 
@@ -38,7 +53,8 @@ pub fn to_opaque_mcp_error(e: &Error) -> McpError {
 ///
 /// # Behavior
 /// Applies categorized mappers (client, provider, config, system, encoding,
-/// IO, generic) and returns the first matching sanitized message.
+/// IO, generic) and returns the first matching sanitized message. The stable
+/// [`ErrorCode`] and retry hint are returned as structured content.
 ///
 /// # Security
 /// Logs internal details server-side and returns safe text only.
@@ -48,5 +64,7 @@ pub fn to_contextual_tool_error(e: impl Into<Error>) -> CallToolResult {
         error!("ErrorMapping", "unmapped error variant", &error);
         "Internal error".to_owned()
     });
-    CallToolResult::error(vec![Content::text(message)])
+    let mut result = CallToolResult::error(vec![Content::text(message)]);
+    result.structured_content = Some(error_code_payload(error.code()));
+    result
 }
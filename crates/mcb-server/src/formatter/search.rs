@@ -4,7 +4,7 @@ use std::fmt::Write;
 use std::path::Path;
 use std::time::Duration;
 
-use mcb_domain::value_objects::SearchResult;
+use mcb_domain::value_objects::{ScoreExplanation, SearchResult};
 use mcb_utils::constants::display::CODE_PREVIEW_MAX_LINES;
 use mcb_utils::constants::search::SEARCH_SLOW_THRESHOLD_MS;
 
@@ -63,7 +63,11 @@ fn append_search_results(
         );
 
         append_code_preview(message, result);
-        let _ = write!(message, "🎯 **Relevance Score:** {:.3}\n\n", result.score);
+        let _ = writeln!(message, "🎯 **Relevance Score:** {:.3}", result.score);
+        if let Some(explanation) = &result.explanation {
+            append_score_explanation(message, explanation);
+        }
+        message.push('\n');
     }
 
     if results.len() == limit {
@@ -85,6 +89,28 @@ fn append_search_results(
     }
 }
 
+/// One line listing the score components, in pipeline order.
+fn append_score_explanation(message: &mut String, explanation: &ScoreExplanation) {
+    let _ = write!(
+        message,
+        "🧮 **Score Breakdown:** dense {:.3}",
+        explanation.dense
+    );
+    if let Some(boost) = explanation.recency_boost {
+        let _ = write!(message, " · recency ×{boost:.3}");
+    }
+    if let Some(bm25) = explanation.bm25 {
+        let _ = write!(message, " · bm25 {bm25:.3}");
+    }
+    if let Some(hybrid) = explanation.hybrid {
+        let _ = write!(message, " · hybrid {hybrid:.3}");
+    }
+    if let Some(boost) = explanation.rerank_boost {
+        let _ = write!(message, " · re-rank ×{boost:.3}");
+    }
+    message.push('\n');
+}
+
 fn append_code_preview(message: &mut String, result: &SearchResult) {
     let lines: Vec<&str> = result.content.lines().collect();
     let preview_lines = if lines.len() > CODE_PREVIEW_MAX_LINES {
@@ -0,0 +1,56 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Analyze-repo handler recommending an indexing configuration.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::RepoAnalysisServiceInterface;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::AnalyzeRepoArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+
+/// Handler for the `analyze_repo` MCP tool.
+#[derive(Clone)]
+pub struct AnalyzeRepoHandler {
+    repo_analysis: Arc<dyn RepoAnalysisServiceInterface>,
+}
+
+handler_new!(AnalyzeRepoHandler {
+    repo_analysis: Arc<dyn RepoAnalysisServiceInterface>,
+});
+
+impl AnalyzeRepoHandler {
+    /// Handle an `analyze_repo` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<AnalyzeRepoArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(path) = args.path.as_deref().or(args.repo_path.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "path could not be resolved: pass path or ensure a repository is detected",
+            )));
+        };
+
+        match self.repo_analysis.analyze_repository(Path::new(path)).await {
+            Ok(analysis) => ResponseFormatter::json_success(&analysis),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Context handler for session-scoped, token-budgeted code context.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{ContextBatch, SessionContextServiceInterface};
+use mcb_domain::value_objects::ids::SessionId;
+use mcb_utils::constants::limits::DEFAULT_MAX_CONTEXT_TOKENS;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use serde::Serialize;
+use validator::Validate;
+
+use crate::args::GetMoreContextArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// `get_more_context` response body.
+#[derive(Serialize)]
+struct MoreContextResponse<'a> {
+    query: &'a str,
+    token_budget: usize,
+    /// Chunks this session has received so far, including this batch
+    session_seen: usize,
+    #[serde(flatten)]
+    batch: ContextBatch,
+}
+
+/// Handler for the `get_more_context` MCP tool.
+#[derive(Clone)]
+pub struct ContextHandler {
+    session_context: Arc<dyn SessionContextServiceInterface>,
+}
+
+handler_new!(ContextHandler {
+    session_context: Arc<dyn SessionContextServiceInterface>,
+});
+
+impl ContextHandler {
+    /// Handle a `get_more_context` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<GetMoreContextArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let query = args.query.trim();
+        if query.is_empty() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "Query cannot be empty",
+            )));
+        }
+        let Some(session_id) = args.session_id.as_ref().map(SessionId::as_str) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "session_id could not be resolved: get_more_context requires an MCP session",
+            )));
+        };
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        if args.reset.unwrap_or(false) {
+            self.session_context.reset(&session_id);
+        }
+
+        let token_budget = args.token_budget.unwrap_or(DEFAULT_MAX_CONTEXT_TOKENS);
+        match self
+            .session_context
+            .get_more_context(&session_id, &collection_id, query, token_budget)
+            .await
+        {
+            Ok(batch) => ResponseFormatter::json_success(&MoreContextResponse {
+                query,
+                token_budget,
+                session_seen: self.session_context.seen_count(&session_id),
+                batch,
+            }),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
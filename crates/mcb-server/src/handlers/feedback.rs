@@ -0,0 +1,82 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Feedback handler for search result relevance judgements.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{RecordFeedbackInput, SearchFeedbackServiceInterface};
+use mcb_domain::value_objects::ids::SessionId;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use serde::Serialize;
+use validator::Validate;
+
+use crate::args::SearchFeedbackArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// `search_feedback` response body.
+#[derive(Serialize)]
+struct FeedbackResponse<'a> {
+    feedback_id: String,
+    chunk_id: &'a str,
+    relevant: bool,
+}
+
+/// Handler for the `search_feedback` MCP tool.
+#[derive(Clone)]
+pub struct FeedbackHandler {
+    search_feedback: Arc<dyn SearchFeedbackServiceInterface>,
+}
+
+handler_new!(FeedbackHandler {
+    search_feedback: Arc<dyn SearchFeedbackServiceInterface>,
+});
+
+impl FeedbackHandler {
+    /// Handle a `search_feedback` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<SearchFeedbackArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        let input = RecordFeedbackInput {
+            query: args.query.trim().to_owned(),
+            collection: collection_id.as_str(),
+            chunk_id: args.chunk_id.clone(),
+            file_path: args.file_path.clone(),
+            relevant: args.relevant,
+            session_id: args.session_id.as_ref().map(SessionId::as_str),
+        };
+        match self.search_feedback.record_feedback(input).await {
+            Ok(feedback_id) => ResponseFormatter::json_success(&FeedbackResponse {
+                feedback_id,
+                chunk_id: &args.chunk_id,
+                relevant: args.relevant,
+            }),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Find-duplicates handler for clone detection over an indexed collection.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{DuplicateDetectionServiceInterface, DuplicateQuery};
+use mcb_domain::value_objects::SearchScope;
+use mcb_utils::constants::search::FIND_DUPLICATES_DEFAULT_LIMIT;
+use mcb_utils::constants::validate::{DEFAULT_MIN_LINES, DEFAULT_SIMILARITY_THRESHOLD};
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::FindDuplicatesArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the `find_duplicates` MCP tool.
+#[derive(Clone)]
+pub struct FindDuplicatesHandler {
+    duplicates: Arc<dyn DuplicateDetectionServiceInterface>,
+}
+
+handler_new!(FindDuplicatesHandler {
+    duplicates: Arc<dyn DuplicateDetectionServiceInterface>,
+});
+
+impl FindDuplicatesHandler {
+    /// Handle a `find_duplicates` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<FindDuplicatesArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        match self
+            .duplicates
+            .find_duplicates(&collection_id, &Self::duplicate_query(&args))
+            .await
+        {
+            Ok(report) => ResponseFormatter::json_success(&report),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+
+    fn duplicate_query(args: &FindDuplicatesArgs) -> DuplicateQuery {
+        let scope = SearchScope {
+            path_prefix: args.path_prefix.clone(),
+            path_glob: args.path_glob.clone(),
+            languages: args.language.as_ref().map(|l| vec![l.to_lowercase()]),
+            exclude_paths: args.exclude_paths.clone(),
+            exclude_languages: None,
+        };
+        DuplicateQuery {
+            min_similarity: args.min_similarity.unwrap_or(DEFAULT_SIMILARITY_THRESHOLD),
+            min_lines: args.min_lines.unwrap_or(DEFAULT_MIN_LINES),
+            scope: (!scope.is_unrestricted()).then_some(scope),
+            limit: args.limit.unwrap_or(FIND_DUPLICATES_DEFAULT_LIMIT),
+        }
+    }
+}
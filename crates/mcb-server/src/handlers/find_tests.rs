@@ -0,0 +1,70 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Find-tests handler listing the tests linked to a symbol at index time.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::TestLinkRepository;
+use mcb_utils::constants::search::FIND_TESTS_DEFAULT_LIMIT;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::FindTestsArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the `find_tests` MCP tool.
+#[derive(Clone)]
+pub struct FindTestsHandler {
+    test_links: Arc<dyn TestLinkRepository>,
+}
+
+handler_new!(FindTestsHandler {
+    test_links: Arc<dyn TestLinkRepository>,
+});
+
+impl FindTestsHandler {
+    /// Handle a `find_tests` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<FindTestsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        let limit = args.limit.unwrap_or(FIND_TESTS_DEFAULT_LIMIT);
+        match self
+            .test_links
+            .find_tests(&collection_id.to_string(), args.symbol.trim(), limit)
+            .await
+        {
+            Ok(tests) => ResponseFormatter::json_success(&serde_json::json!({
+                "symbol": args.symbol,
+                "total": tests.len(),
+                "tests": tests,
+            })),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
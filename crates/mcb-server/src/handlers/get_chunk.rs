@@ -0,0 +1,77 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Get-chunk handler returning everything stored for one chunk.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{VectorStoreFeature, VectorStoreProvider};
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::GetChunkArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the admin-scoped `get_chunk` MCP tool.
+#[derive(Clone)]
+pub struct GetChunkHandler {
+    vector_store: Arc<dyn VectorStoreProvider>,
+}
+
+handler_new!(GetChunkHandler {
+    vector_store: Arc<dyn VectorStoreProvider>,
+});
+
+impl GetChunkHandler {
+    /// Handle a `get_chunk` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<GetChunkArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: pass collection or open a repository",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+        if let Err(e) = self.vector_store.capabilities().require(
+            self.vector_store.provider_name(),
+            VectorStoreFeature::ChunkInspection,
+        ) {
+            return Ok(to_contextual_tool_error(e));
+        }
+
+        match self
+            .vector_store
+            .inspect_chunk(&collection_id, &args.id)
+            .await
+        {
+            Ok(Some(chunk)) if args.include_vector.unwrap_or(false) => {
+                ResponseFormatter::json_success(&chunk)
+            }
+            Ok(Some(chunk)) => ResponseFormatter::json_success(&chunk.without_vector()),
+            Ok(None) => Ok(to_contextual_tool_error(Error::not_found(format!(
+                "chunk '{}' in collection '{collection_id}'",
+                args.id
+            )))),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
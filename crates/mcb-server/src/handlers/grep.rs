@@ -0,0 +1,129 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Grep handler for exact-text search over the indexed snapshot.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{ConfirmedResult, GrepMatch, GrepQuery, GrepServiceInterface};
+use mcb_domain::value_objects::SearchScope;
+use mcb_utils::constants::limits::DEFAULT_SEARCH_LIMIT;
+use mcb_utils::constants::search::GREP_DEFAULT_MAX_MATCHES;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use serde::Serialize;
+use validator::Validate;
+
+use crate::args::GrepArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// `grep` response body for a plain scan.
+#[derive(Serialize)]
+struct GrepResponse<'a> {
+    pattern: &'a str,
+    matches: Vec<GrepMatch>,
+    files_scanned: usize,
+    truncated: bool,
+}
+
+/// `grep` response body for the combined semantic mode.
+#[derive(Serialize)]
+struct ConfirmedResponse<'a> {
+    pattern: &'a str,
+    semantic_query: &'a str,
+    results: Vec<ConfirmedResult>,
+}
+
+/// Handler for the `grep` MCP tool.
+#[derive(Clone)]
+pub struct GrepHandler {
+    grep: Arc<dyn GrepServiceInterface>,
+}
+
+handler_new!(GrepHandler {
+    grep: Arc<dyn GrepServiceInterface>,
+});
+
+impl GrepHandler {
+    /// Handle a `grep` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<GrepArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        let query = Self::grep_query(&args);
+        let semantic_query = args
+            .semantic_query
+            .as_deref()
+            .map(str::trim)
+            .filter(|q| !q.is_empty());
+
+        match semantic_query {
+            Some(semantic_query) => {
+                let limit = args.limit.unwrap_or(DEFAULT_SEARCH_LIMIT as u32) as usize;
+                match self
+                    .grep
+                    .grep_semantic(&collection_id, semantic_query, &query, limit)
+                    .await
+                {
+                    Ok(results) => ResponseFormatter::json_success(&ConfirmedResponse {
+                        pattern: &args.pattern,
+                        semantic_query,
+                        results,
+                    }),
+                    Err(e) => Ok(to_contextual_tool_error(e)),
+                }
+            }
+            None => match self.grep.grep(&collection_id, &query).await {
+                Ok(outcome) => ResponseFormatter::json_success(&GrepResponse {
+                    pattern: &args.pattern,
+                    matches: outcome.matches,
+                    files_scanned: outcome.files_scanned,
+                    truncated: outcome.truncated,
+                }),
+                Err(e) => Ok(to_contextual_tool_error(e)),
+            },
+        }
+    }
+
+    fn grep_query(args: &GrepArgs) -> GrepQuery {
+        let scope = SearchScope {
+            path_prefix: args.path_prefix.clone(),
+            path_glob: args.path_glob.clone(),
+            languages: args.language.as_ref().map(|l| vec![l.to_lowercase()]),
+            exclude_paths: args.exclude_paths.clone(),
+            exclude_languages: None,
+        };
+        GrepQuery {
+            pattern: args.pattern.clone(),
+            regex: args.regex.unwrap_or(false),
+            case_sensitive: args.case_sensitive.unwrap_or(true),
+            scope: (!scope.is_unrestricted()).then_some(scope),
+            max_matches: args
+                .max_matches
+                .map_or(GREP_DEFAULT_MAX_MATCHES, |m| m as usize),
+        }
+    }
+}
@@ -18,6 +18,7 @@ use crate::args::{IndexAction, IndexArgs};
 use crate::error_mapping::to_contextual_tool_error;
 use crate::formatter::ResponseFormatter;
 use crate::utils::collections::normalize_collection_name;
+use crate::utils::mcp::resolve_org_id;
 
 /// Handler for codebase indexing MCP tool operations.
 #[derive(Clone)]
@@ -96,7 +97,11 @@ impl IndexHandler {
         let timer = Instant::now();
         match self
             .indexing_service
-            .index_codebase(&path, &collection_id)
+            .index_codebase(
+                &path,
+                &collection_id,
+                &resolve_org_id(args.org_id.as_deref()),
+            )
             .await
         {
             Ok(result) => Ok(ResponseFormatter::format_indexing_success(
@@ -3,25 +3,53 @@
 //! **Documentation**: [`docs/modules/server.md#key-areas`](../../../../docs/modules/server.md#key-areas)
 //!
 pub mod agent;
+pub mod analyze_repo;
+pub mod context;
 pub mod entities;
+pub mod feedback;
+pub mod find_duplicates;
+pub mod find_tests;
+pub mod get_chunk;
+pub mod grep;
 pub mod index;
 pub mod memory;
+pub mod module_graph;
 pub mod project;
+pub mod read_file;
+pub mod rebuild_text_index;
 pub mod search;
 pub mod session;
+pub mod stats;
+pub mod summarize_changes;
 pub mod validate;
+pub mod validate_architecture;
 pub mod vcs;
+pub mod workspace;
 
 pub use agent::AgentHandler;
+pub use analyze_repo::AnalyzeRepoHandler;
+pub use context::ContextHandler;
 pub use entities::EntityHandler;
 pub use entities::IssueEntityHandler;
 pub use entities::OrgEntityHandler;
 pub use entities::PlanEntityHandler;
 pub use entities::VcsEntityHandler;
+pub use feedback::FeedbackHandler;
+pub use find_duplicates::FindDuplicatesHandler;
+pub use find_tests::FindTestsHandler;
+pub use get_chunk::GetChunkHandler;
+pub use grep::GrepHandler;
 pub use index::IndexHandler;
 pub use memory::MemoryHandler;
+pub use module_graph::{ImpactOfChangeHandler, ModuleDependenciesHandler};
 pub use project::ProjectHandler;
+pub use read_file::ReadFileHandler;
+pub use rebuild_text_index::RebuildTextIndexHandler;
 pub use search::SearchHandler;
 pub use session::SessionHandler;
+pub use stats::StatsHandler;
+pub use summarize_changes::SummarizeChangesHandler;
 pub use validate::ValidateHandler;
+pub use validate_architecture::ValidateArchitectureHandler;
 pub use vcs::VcsHandler;
+pub use workspace::WorkspaceHandler;
@@ -0,0 +1,118 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Module graph handlers answering dependency and change-impact questions
+//! from the import graph recorded at index time.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::ModuleGraphServiceInterface;
+use mcb_domain::value_objects::CollectionId;
+use mcb_utils::constants::search::IMPACT_OF_CHANGE_DEFAULT_DEPTH;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::{ImpactOfChangeArgs, ModuleDependenciesArgs};
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Resolve the collection injected into a module graph request.
+fn resolve_collection(
+    collection: Option<&str>,
+    repo_id: Option<&str>,
+) -> Result<CollectionId, CallToolResult> {
+    let Some(collection_name) = collection.or(repo_id) else {
+        return Err(to_contextual_tool_error(Error::invalid_argument(
+            "collection could not be resolved: ensure a repository is detected",
+        )));
+    };
+    normalize_collection_name(collection_name)
+        .map_err(|reason| to_contextual_tool_error(Error::invalid_argument(reason)))
+}
+
+/// Handler for the `module_dependencies` MCP tool.
+#[derive(Clone)]
+pub struct ModuleDependenciesHandler {
+    module_graph: Arc<dyn ModuleGraphServiceInterface>,
+}
+
+handler_new!(ModuleDependenciesHandler {
+    module_graph: Arc<dyn ModuleGraphServiceInterface>,
+});
+
+impl ModuleDependenciesHandler {
+    /// Handle a `module_dependencies` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<ModuleDependenciesArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let collection_id =
+            match resolve_collection(args.collection.as_deref(), args.repo_id.as_deref()) {
+                Ok(id) => id,
+                Err(error_result) => return Ok(error_result),
+            };
+        match self
+            .module_graph
+            .module_dependencies(&collection_id, &args.path)
+            .await
+        {
+            Ok(dependencies) => ResponseFormatter::json_success(&dependencies),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
+
+/// Handler for the `impact_of_change` MCP tool.
+#[derive(Clone)]
+pub struct ImpactOfChangeHandler {
+    module_graph: Arc<dyn ModuleGraphServiceInterface>,
+}
+
+handler_new!(ImpactOfChangeHandler {
+    module_graph: Arc<dyn ModuleGraphServiceInterface>,
+});
+
+impl ImpactOfChangeHandler {
+    /// Handle an `impact_of_change` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<ImpactOfChangeArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let collection_id =
+            match resolve_collection(args.collection.as_deref(), args.repo_id.as_deref()) {
+                Ok(id) => id,
+                Err(error_result) => return Ok(error_result),
+            };
+        let max_depth = args.max_depth.unwrap_or(IMPACT_OF_CHANGE_DEFAULT_DEPTH);
+        match self
+            .module_graph
+            .impact_of_change(&collection_id, &args.paths, max_depth)
+            .await
+        {
+            Ok(impact) => ResponseFormatter::json_success(&impact),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Read-file handler serving line ranges of indexed files.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{FileContentServiceInterface, FileReadRequest};
+use mcb_utils::constants::search::READ_FILE_DEFAULT_MAX_BYTES;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::ReadFileArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the `read_file` MCP tool.
+#[derive(Clone)]
+pub struct ReadFileHandler {
+    file_content: Arc<dyn FileContentServiceInterface>,
+}
+
+handler_new!(ReadFileHandler {
+    file_content: Arc<dyn FileContentServiceInterface>,
+});
+
+impl ReadFileHandler {
+    /// Handle a `read_file` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<ReadFileArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        let request = FileReadRequest {
+            path: args.path.clone(),
+            start_line: args.start_line,
+            end_line: args.end_line,
+            max_bytes: args.max_bytes.unwrap_or(READ_FILE_DEFAULT_MAX_BYTES),
+            repo_root: args.repo_path.as_deref().map(PathBuf::from),
+        };
+        match self.file_content.read_file(&collection_id, &request).await {
+            Ok(slice) => ResponseFormatter::json_success(&slice),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
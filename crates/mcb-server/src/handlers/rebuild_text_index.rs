@@ -0,0 +1,177 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Rebuild-text-index handler restoring the BM25 keyword index of a
+//! collection from the chunks already in the vector store.
+
+use std::sync::Arc;
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::error::Error;
+use mcb_domain::ports::{HybridSearchProvider, VectorStoreProvider};
+use mcb_domain::value_objects::{CollectionId, FileInfo, SearchResult};
+use mcb_utils::constants::search::{REBUILD_TEXT_INDEX_MAX_FILES, REBUILD_TEXT_INDEX_PAGE_FILES};
+use mcb_utils::constants::vector_store::STATS_FIELD_VECTORS_COUNT;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use serde::Serialize;
+use validator::Validate;
+
+use crate::args::RebuildTextIndexArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the admin-scoped `rebuild_text_index` MCP tool.
+#[derive(Clone)]
+pub struct RebuildTextIndexHandler {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    hybrid_search: Arc<dyn HybridSearchProvider>,
+}
+
+handler_new!(RebuildTextIndexHandler {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    hybrid_search: Arc<dyn HybridSearchProvider>,
+});
+
+/// Outcome of a rebuild.
+#[derive(Debug, Serialize)]
+struct TextIndexRebuild<'a> {
+    collection: &'a str,
+    files: usize,
+    chunks: usize,
+}
+
+impl RebuildTextIndexHandler {
+    /// Handle a `rebuild_text_index` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<RebuildTextIndexArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: pass collection or open a repository",
+            )));
+        };
+        let collection_id = match normalize_collection_name(collection_name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        match self.rebuild(collection_name, &collection_id).await {
+            Ok(rebuild) => ResponseFormatter::json_success(&rebuild),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+
+    /// Read every stored chunk file by file, then replace the keyword index
+    /// of `collection_name`. Nothing is re-embedded; searches running while
+    /// the index is swapped fall back to semantic scores.
+    ///
+    /// The index is only replaced once every stored chunk was read; a failed
+    /// or incomplete read leaves it untouched.
+    async fn rebuild<'a>(
+        &self,
+        collection_name: &'a str,
+        collection_id: &CollectionId,
+    ) -> mcb_domain::Result<TextIndexRebuild<'a>> {
+        let stored_chunks = self
+            .vector_store
+            .get_stats(collection_id)
+            .await?
+            .get(STATS_FIELD_VECTORS_COUNT)
+            .and_then(serde_json::Value::as_u64);
+        let files = self.list_all_files(collection_id, stored_chunks).await?;
+        let mut chunks = Vec::new();
+        for file in &files {
+            let stored = self
+                .vector_store
+                .get_chunks_by_file(collection_id, &file.path)
+                .await?;
+            chunks.extend(stored.into_iter().map(Self::code_chunk));
+        }
+        if let Some(total) = stored_chunks
+            && (chunks.len() as u64) < total
+        {
+            return Err(Error::vector_db(format!(
+                "read {} of {total} stored chunks of '{collection_name}'; the keyword index was left unchanged",
+                chunks.len()
+            )));
+        }
+
+        self.hybrid_search.clear_collection(collection_name).await?;
+        self.hybrid_search
+            .index_chunks(collection_name, &chunks)
+            .await?;
+        mcb_domain::info!(
+            "RebuildTextIndex",
+            "Keyword index rebuilt from the vector store",
+            &format!(
+                "collection={collection_name} files={} chunks={}",
+                files.len(),
+                chunks.len()
+            )
+        );
+        Ok(TextIndexRebuild {
+            collection: collection_name,
+            files: files.len(),
+            chunks: chunks.len(),
+        })
+    }
+
+    /// List every stored file, doubling the page until the listed chunk
+    /// counts cover `stored_chunks` (or, when the store does not report it,
+    /// until a page comes back short).
+    async fn list_all_files(
+        &self,
+        collection_id: &CollectionId,
+        stored_chunks: Option<u64>,
+    ) -> mcb_domain::Result<Vec<FileInfo>> {
+        let mut limit = REBUILD_TEXT_INDEX_PAGE_FILES;
+        loop {
+            let files = self
+                .vector_store
+                .list_file_paths(collection_id, limit)
+                .await?;
+            let listed: u64 = files.iter().map(|f| u64::from(f.chunk_count)).sum();
+            let complete = match stored_chunks {
+                Some(total) => listed >= total,
+                None => files.len() < limit,
+            };
+            if complete {
+                return Ok(files);
+            }
+            if limit >= REBUILD_TEXT_INDEX_MAX_FILES {
+                return Err(Error::vector_db(format!(
+                    "collection '{collection_id}' holds more than {REBUILD_TEXT_INDEX_MAX_FILES} files; the keyword index was left unchanged"
+                )));
+            }
+            limit = limit.saturating_mul(2).min(REBUILD_TEXT_INDEX_MAX_FILES);
+        }
+    }
+
+    /// Keyword scoring only needs the text and the `file_path:start_line`
+    /// key that search results are matched by.
+    fn code_chunk(stored: SearchResult) -> CodeChunk {
+        let lines = u32::try_from(stored.content.lines().count()).unwrap_or(u32::MAX);
+        CodeChunk {
+            end_line: stored.start_line.saturating_add(lines.saturating_sub(1)),
+            id: stored.id,
+            content: stored.content,
+            file_path: stored.file_path,
+            start_line: stored.start_line,
+            language: stored.language,
+            metadata: serde_json::Value::Null,
+        }
+    }
+}
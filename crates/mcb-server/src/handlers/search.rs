@@ -12,7 +12,12 @@ use mcb_domain::error::Error;
 use mcb_domain::ports::HybridSearchProvider;
 use mcb_domain::ports::IndexingServiceInterface;
 use mcb_domain::ports::MemoryServiceInterface;
+use mcb_domain::ports::SearchFeedbackServiceInterface;
 use mcb_domain::ports::SearchServiceInterface;
+use mcb_domain::ports::SessionContextServiceInterface;
+use mcb_domain::ports::UsageServiceInterface;
+use mcb_domain::ports::{Diversification, SearchFilters};
+use mcb_domain::value_objects::SearchScope;
 use mcb_utils::utils::id as domain_id;
 use rmcp::ErrorData as McpError;
 use rmcp::handler::server::wrapper::Parameters;
@@ -21,21 +26,26 @@ use validator::Validate;
 
 use crate::error_mapping::safe_internal_error;
 
-use crate::args::{SearchArgs, SearchResource};
+use crate::args::{DiversifyBy, SearchArgs, SearchResource};
 use crate::error_mapping::to_contextual_tool_error;
 use crate::formatter::ResponseFormatter;
 use crate::utils::collections::normalize_collection_name;
+use crate::utils::mcp::resolve_org_id;
 use mcb_utils::constants::keys::{
     FIELD_BRANCH, FIELD_COMMIT, FIELD_COUNT, FIELD_OBSERVATION_ID, FIELD_OBSERVATION_TYPE,
     FIELD_QUERY, FIELD_RESULTS,
 };
 use mcb_utils::constants::limits::DEFAULT_SEARCH_LIMIT;
+use mcb_utils::constants::search::{
+    CONVERSATION_CONTEXT_CANDIDATES, CONVERSATION_CONTEXT_OBSERVATIONS, MMR_DEFAULT_LAMBDA,
+};
 
 /// Inputs for the hybrid-search fallback path after a primary vector search fails.
 struct HybridFallbackSpec<'a> {
     collection_name: &'a str,
     query: &'a str,
     limit: usize,
+    scope: Option<&'a SearchScope>,
     timer: Instant,
     original_error: Error,
 }
@@ -47,6 +57,9 @@ pub struct SearchHandler {
     memory_service: Arc<dyn MemoryServiceInterface>,
     hybrid_search: Arc<dyn HybridSearchProvider>,
     indexing_service: Arc<dyn IndexingServiceInterface>,
+    session_context: Arc<dyn SessionContextServiceInterface>,
+    search_feedback: Arc<dyn SearchFeedbackServiceInterface>,
+    usage: Arc<dyn UsageServiceInterface>,
 }
 
 handler_new!(SearchHandler {
@@ -54,6 +67,9 @@ handler_new!(SearchHandler {
     memory_service: Arc<dyn MemoryServiceInterface>,
     hybrid_search: Arc<dyn HybridSearchProvider>,
     indexing_service: Arc<dyn IndexingServiceInterface>,
+    session_context: Arc<dyn SessionContextServiceInterface>,
+    search_feedback: Arc<dyn SearchFeedbackServiceInterface>,
+    usage: Arc<dyn UsageServiceInterface>,
 });
 
 impl SearchHandler {
@@ -120,16 +136,23 @@ impl SearchHandler {
 
         let timer = Instant::now();
         let limit = args.limit.unwrap_or(DEFAULT_SEARCH_LIMIT as u32) as usize;
+        let filters = Self::code_search_filters(args);
+        let scope = filters.as_ref().and_then(SearchFilters::scope);
+        if let Some(Err(e)) = scope.as_ref().map(SearchScope::matcher) {
+            return Ok(to_contextual_tool_error(e));
+        }
 
         match self
-            .search_service
-            .search(&collection_id, query, limit)
+            .run_code_search(&collection_id, query, limit, args, filters.as_ref())
             .await
         {
             Ok(results) => {
-                let final_results = self
-                    .try_hybrid_enhance(collection_name, query, results, limit)
+                let enhanced = self
+                    .try_hybrid_enhance(collection_name, query, results, limit, scope.as_ref())
                     .await;
+                let final_results = self.apply_feedback_boosts(&collection_id, enhanced).await;
+                self.record_returned(args, &final_results, limit);
+                self.record_query(args, &collection_id).await;
                 ResponseFormatter::format_search_response(
                     query,
                     &final_results,
@@ -143,11 +166,12 @@ impl SearchHandler {
                     error = %e,
                     "Vector search failed, attempting hybrid fallback"
                 );
-                self.trigger_auto_indexing(args.repo_path.as_deref(), collection_id);
+                self.trigger_auto_indexing(args, collection_id);
                 self.hybrid_fallback_or_error(HybridFallbackSpec {
                     collection_name,
                     query,
                     limit,
+                    scope: scope.as_ref(),
                     timer,
                     original_error: e,
                 })
@@ -156,16 +180,110 @@ impl SearchHandler {
         }
     }
 
+    /// Run the vector search, blending recent session context into the query
+    /// when `conversation_weight` is set.
+    async fn run_code_search(
+        &self,
+        collection_id: &mcb_domain::value_objects::CollectionId,
+        query: &str,
+        limit: usize,
+        args: &SearchArgs,
+        filters: Option<&SearchFilters>,
+    ) -> mcb_domain::error::Result<Vec<mcb_domain::value_objects::SearchResult>> {
+        let weight = args.conversation_weight.unwrap_or(0.0);
+        let Some(session_id) = args.session_id.as_ref().filter(|_| weight > 0.0) else {
+            return self
+                .search_service
+                .search_with_filters(collection_id, query, limit, filters)
+                .await;
+        };
+        let context = self
+            .recent_session_context(&session_id.as_str(), query)
+            .await;
+        self.search_service
+            .search_with_context(collection_id, query, &context, weight, limit, filters)
+            .await
+    }
+
+    /// Search-use-case filters requested by the code search arguments.
+    fn code_search_filters(args: &SearchArgs) -> Option<SearchFilters> {
+        let diversify = args.diversify_by.map(|by| Diversification {
+            by: match by {
+                DiversifyBy::File => mcb_domain::ports::DiversifyBy::File,
+                DiversifyBy::Directory => mcb_domain::ports::DiversifyBy::Directory,
+                DiversifyBy::Symbol => mcb_domain::ports::DiversifyBy::Symbol,
+            },
+            lambda: args.diversity_lambda.unwrap_or(MMR_DEFAULT_LAMBDA),
+        });
+        let explain = args.explain.unwrap_or(false);
+        if args.extensions.is_none()
+            && args.min_score.is_none()
+            && diversify.is_none()
+            && args.recency_half_life_days.is_none()
+            && args.path_prefix.is_none()
+            && args.path_glob.is_none()
+            && args.language.is_none()
+            && args.exclude_paths.is_none()
+            && args.exclude_languages.is_none()
+            && !explain
+        {
+            return None;
+        }
+        Some(SearchFilters {
+            file_extensions: args.extensions.clone(),
+            languages: args.language.as_ref().map(|l| vec![l.to_lowercase()]),
+            path_prefix: args.path_prefix.clone(),
+            path_glob: args.path_glob.clone(),
+            exclude_paths: args.exclude_paths.clone(),
+            exclude_languages: args
+                .exclude_languages
+                .as_ref()
+                .map(|langs| langs.iter().map(|l| l.to_lowercase()).collect()),
+            min_score: args.min_score,
+            diversify,
+            recency_half_life_days: args.recency_half_life_days.map(f64::from),
+            explain,
+        })
+    }
+
+    /// Contents of the session's most recent observations related to `query`.
+    ///
+    /// Best-effort: a failed memory lookup yields no context.
+    async fn recent_session_context(&self, session_id: &str, query: &str) -> Vec<String> {
+        let filter = MemoryFilter {
+            session_id: Some(session_id.to_owned()),
+            ..Default::default()
+        };
+        let mut observations = match self
+            .memory_service
+            .search_memories(query, Some(filter), CONVERSATION_CONTEXT_CANDIDATES)
+            .await
+        {
+            Ok(results) => results,
+            Err(e) => {
+                tracing::debug!(error = %e, "Conversation context lookup failed");
+                return Vec::new();
+            }
+        };
+        observations.sort_by(|a, b| b.observation.created_at.cmp(&a.observation.created_at));
+        observations
+            .into_iter()
+            .take(CONVERSATION_CONTEXT_OBSERVATIONS)
+            .map(|r| r.observation.content)
+            .collect()
+    }
+
     async fn try_hybrid_enhance(
         &self,
         collection_name: &str,
         query: &str,
         results: Vec<mcb_domain::value_objects::SearchResult>,
         limit: usize,
+        scope: Option<&SearchScope>,
     ) -> Vec<mcb_domain::value_objects::SearchResult> {
         match self
             .hybrid_search
-            .search(collection_name, query, results.clone(), limit)
+            .search(collection_name, query, results.clone(), limit, scope)
             .await
         {
             Ok(enhanced) if !enhanced.is_empty() => {
@@ -180,22 +298,77 @@ impl SearchHandler {
         }
     }
 
+    /// Re-rank with per-file boosts learned from `search_feedback`.
+    ///
+    /// Best-effort: if boosts cannot be loaded the results are returned as-is.
+    async fn apply_feedback_boosts(
+        &self,
+        collection_id: &mcb_domain::value_objects::CollectionId,
+        results: Vec<mcb_domain::value_objects::SearchResult>,
+    ) -> Vec<mcb_domain::value_objects::SearchResult> {
+        match self
+            .search_feedback
+            .apply_file_boosts(&collection_id.as_str(), results.clone())
+            .await
+        {
+            Ok(boosted) => boosted,
+            Err(e) => {
+                tracing::debug!(error = %e, "Search feedback boosts unavailable");
+                results
+            }
+        }
+    }
+
+    /// Remember returned chunks so `get_more_context` does not repeat them.
+    fn record_returned(
+        &self,
+        args: &SearchArgs,
+        results: &[mcb_domain::value_objects::SearchResult],
+        limit: usize,
+    ) {
+        if let Some(session_id) = args.session_id.as_ref() {
+            let ids: Vec<String> = results.iter().take(limit).map(|r| r.id.clone()).collect();
+            self.session_context
+                .mark_returned(&session_id.as_str(), &ids);
+        }
+    }
+
+    /// Count the query towards the organization's monthly usage.
+    async fn record_query(
+        &self,
+        args: &SearchArgs,
+        collection_id: &mcb_domain::value_objects::CollectionId,
+    ) {
+        let org_id = resolve_org_id(args.org_id.as_deref());
+        if let Err(e) = self.usage.record_query(&org_id, collection_id).await {
+            tracing::warn!(
+                collection = collection_id.as_str(),
+                error = %e,
+                "Failed to record search usage"
+            );
+        }
+    }
+
     fn trigger_auto_indexing(
         &self,
-        repo_path: Option<&str>,
+        args: &SearchArgs,
         collection_id: mcb_domain::value_objects::CollectionId,
     ) {
-        if let Some(repo_path) = repo_path {
+        if let Some(repo_path) = args.repo_path.as_deref() {
             let path = PathBuf::from(repo_path);
             if path.is_dir() {
                 let indexing = Arc::clone(&self.indexing_service);
+                let org_id = resolve_org_id(args.org_id.as_deref());
                 // Fire-and-forget background indexing; failures are logged, not awaited.
                 let _handle = tokio::spawn(async move {
                     tracing::info!(
                         collection = collection_id.as_str(),
                         "Auto-indexing triggered"
                     );
-                    if let Err(idx_err) = indexing.index_codebase(&path, &collection_id).await {
+                    if let Err(idx_err) = indexing
+                        .index_codebase(&path, &collection_id, &org_id)
+                        .await
+                    {
                         tracing::warn!(error = %idx_err, "Auto-indexing failed (non-fatal)");
                     }
                 });
@@ -211,12 +384,13 @@ impl SearchHandler {
             collection_name,
             query,
             limit,
+            scope,
             timer,
             original_error,
         } = spec;
         match self
             .hybrid_search
-            .search(collection_name, query, vec![], limit)
+            .search(collection_name, query, vec![], limit, scope)
             .await
         {
             Ok(fallback) if !fallback.is_empty() => {
@@ -0,0 +1,209 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Stats handler for server self-introspection.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use mcb_domain::ports::providers::metrics::{
+    CACHE_ACCESSES_METRIC, EMBEDDING_DURATION_METRIC, VECTOR_STORE_DURATION_METRIC,
+};
+use mcb_domain::ports::{MetricsSnapshot, MetricsSnapshotProvider, VectorStoreProvider};
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use serde::Serialize;
+use validator::Validate;
+
+use crate::args::ServerStatsArgs;
+use crate::formatter::ResponseFormatter;
+
+/// Index size and freshness for one collection.
+#[derive(Debug, Clone, Serialize)]
+pub struct CollectionStats {
+    /// Collection name
+    pub name: String,
+    /// Stored vectors (chunks)
+    pub vectors: u64,
+    /// Distinct indexed files
+    pub files: u64,
+    /// Unix timestamp of the last indexing run, when the store tracks it
+    pub last_indexed: Option<u64>,
+    /// Seconds since `last_indexed`
+    pub age_secs: Option<u64>,
+}
+
+/// Hit rate for one cache type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CacheHitRate {
+    /// Cache type label
+    pub cache_type: String,
+    /// Recorded hits
+    pub hits: u64,
+    /// Recorded misses
+    pub misses: u64,
+    /// `hits / (hits + misses)`, absent before the first lookup
+    pub hit_rate: Option<f64>,
+}
+
+/// Recent latency percentiles for one provider call type.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProviderLatency {
+    /// `embedding` or `vector_store`
+    pub kind: &'static str,
+    /// Provider name
+    pub provider: String,
+    /// Operation label (vector store only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    /// Observations in the window
+    pub samples: u64,
+    /// Median latency in milliseconds
+    pub p50_ms: f64,
+    /// 95th percentile latency in milliseconds
+    pub p95_ms: f64,
+}
+
+/// Full `server_stats` response.
+#[derive(Debug, Clone, Serialize)]
+pub struct ServerStats {
+    /// Number of collections in the vector store
+    pub collection_count: usize,
+    /// Per-collection size and freshness
+    pub collections: Vec<CollectionStats>,
+    /// Cache hit rates by cache type
+    pub cache: Vec<CacheHitRate>,
+    /// Provider latency percentiles
+    pub latency: Vec<ProviderLatency>,
+    /// Resident set size of the server process in bytes (Linux only)
+    pub memory_rss_bytes: Option<u64>,
+    /// Raw metric series, when requested
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw: Option<MetricsSnapshot>,
+}
+
+/// Handler for the `server_stats` MCP tool.
+#[derive(Clone)]
+pub struct StatsHandler {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    metrics: Arc<dyn MetricsSnapshotProvider>,
+}
+
+handler_new!(StatsHandler {
+    vector_store: Arc<dyn VectorStoreProvider>,
+    metrics: Arc<dyn MetricsSnapshotProvider>,
+});
+
+impl StatsHandler {
+    /// Handle a `server_stats` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when arguments are invalid or the report cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<ServerStatsArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        args.validate()
+            .map_err(|_| McpError::invalid_params("invalid arguments", None))?;
+
+        let now = mcb_utils::utils::time::epoch_secs_u64().unwrap_or(0);
+        let collections: Vec<CollectionStats> = self
+            .vector_store
+            .list_collections()
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|c| CollectionStats {
+                age_secs: c.last_indexed.map(|at| now.saturating_sub(at)),
+                name: c.name,
+                vectors: c.vector_count,
+                files: c.file_count,
+                last_indexed: c.last_indexed,
+            })
+            .collect();
+
+        let snapshot = self.metrics.snapshot();
+        let stats = ServerStats {
+            collection_count: collections.len(),
+            collections,
+            cache: cache_hit_rates(&snapshot),
+            latency: provider_latencies(&snapshot),
+            memory_rss_bytes: resident_memory_bytes(),
+            raw: args.include_raw.unwrap_or(false).then_some(snapshot),
+        };
+        ResponseFormatter::json_success(&stats)
+    }
+}
+
+/// Fold `mcb_cache_accesses_total` counters into per-cache hit rates.
+#[must_use]
+pub fn cache_hit_rates(snapshot: &MetricsSnapshot) -> Vec<CacheHitRate> {
+    let mut by_type: BTreeMap<&str, (u64, u64)> = BTreeMap::new();
+    for sample in snapshot
+        .counters
+        .iter()
+        .filter(|s| s.name == CACHE_ACCESSES_METRIC)
+    {
+        let Some(cache_type) = sample.labels.get("cache_type") else {
+            continue;
+        };
+        let entry = by_type.entry(cache_type).or_default();
+        let count = sample.value as u64;
+        if sample.labels.get("result").map(String::as_str) == Some("hit") {
+            entry.0 += count;
+        } else {
+            entry.1 += count;
+        }
+    }
+    by_type
+        .into_iter()
+        .map(|(cache_type, (hits, misses))| CacheHitRate {
+            cache_type: cache_type.to_owned(),
+            hits,
+            misses,
+            hit_rate: (hits + misses > 0).then(|| hits as f64 / (hits + misses) as f64),
+        })
+        .collect()
+}
+
+/// Convert embedding and vector store histograms into millisecond percentiles.
+#[must_use]
+pub fn provider_latencies(snapshot: &MetricsSnapshot) -> Vec<ProviderLatency> {
+    snapshot
+        .histograms
+        .iter()
+        .filter_map(|h| {
+            let kind = if h.name == EMBEDDING_DURATION_METRIC {
+                "embedding"
+            } else if h.name == VECTOR_STORE_DURATION_METRIC {
+                "vector_store"
+            } else {
+                return None;
+            };
+            Some(ProviderLatency {
+                kind,
+                provider: h.labels.get("provider").cloned().unwrap_or_default(),
+                operation: h.labels.get("operation").cloned(),
+                samples: h.count,
+                p50_ms: h.p50 * 1000.0,
+                p95_ms: h.p95 * 1000.0,
+            })
+        })
+        .collect()
+}
+
+/// Resident set size from `/proc/self/status`; `None` off Linux.
+fn resident_memory_bytes() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kib * 1024)
+}
@@ -0,0 +1,88 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Summarize-changes handler reporting symbol-level changes.
+
+use std::path::Path;
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{ChangeBaseline, ChangeSummaryServiceInterface};
+use mcb_utils::constants::vcs::GIT_REF_HEAD;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::SummarizeChangesArgs;
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the `summarize_changes` MCP tool.
+#[derive(Clone)]
+pub struct SummarizeChangesHandler {
+    change_summary: Arc<dyn ChangeSummaryServiceInterface>,
+}
+
+handler_new!(SummarizeChangesHandler {
+    change_summary: Arc<dyn ChangeSummaryServiceInterface>,
+});
+
+impl SummarizeChangesHandler {
+    /// Handle a `summarize_changes` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<SummarizeChangesArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let Some(repo_path) = args.repo_path.as_deref() else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "repo_path could not be resolved: ensure a repository is detected",
+            )));
+        };
+
+        let baseline = match (args.base_ref, args.head_ref) {
+            (Some(base_ref), head_ref) => ChangeBaseline::Refs {
+                base_ref,
+                head_ref: head_ref.unwrap_or_else(|| GIT_REF_HEAD.to_owned()),
+            },
+            (None, Some(_)) => {
+                return Ok(to_contextual_tool_error(Error::invalid_argument(
+                    "head_ref requires base_ref",
+                )));
+            }
+            (None, None) => {
+                let Some(collection_name) = args.collection.as_deref().or(args.repo_id.as_deref())
+                else {
+                    return Ok(to_contextual_tool_error(Error::invalid_argument(
+                        "collection could not be resolved: ensure a repository is detected",
+                    )));
+                };
+                match normalize_collection_name(collection_name) {
+                    Ok(id) => ChangeBaseline::LastIndex(id),
+                    Err(reason) => {
+                        return Ok(to_contextual_tool_error(Error::invalid_argument(reason)));
+                    }
+                }
+            }
+        };
+
+        match self
+            .change_summary
+            .summarize_changes(Path::new(repo_path), &baseline)
+            .await
+        {
+            Ok(summary) => ResponseFormatter::json_success(&summary),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Validate-architecture handler returning structured violations.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::{ValidationServiceInterface, ViolationEntry};
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use validator::Validate;
+
+use crate::args::{ValidateArchitectureArgs, ValidateSeverity};
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the `validate_architecture` MCP tool.
+#[derive(Clone)]
+pub struct ValidateArchitectureHandler {
+    validation_service: Arc<dyn ValidationServiceInterface>,
+}
+
+handler_new!(ValidateArchitectureHandler {
+    validation_service: Arc<dyn ValidationServiceInterface>,
+});
+
+impl ValidateArchitectureHandler {
+    /// Handle a `validate_architecture` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<ValidateArchitectureArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        let workspace = match resolve_workspace(&args) {
+            Ok(workspace) => workspace,
+            Err(e) => return Ok(to_contextual_tool_error(e)),
+        };
+        if !workspace.is_dir() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(format!(
+                "workspace is not a directory: {}",
+                workspace.display()
+            ))));
+        }
+
+        let validators = args.validators.as_deref().filter(|v| !v.is_empty());
+        let severity = args.min_severity.map(severity_filter);
+        let report = match self
+            .validation_service
+            .validate(&workspace, validators, severity)
+            .await
+        {
+            Ok(report) => report,
+            Err(e) => return Ok(to_contextual_tool_error(e)),
+        };
+
+        let rules = args.rules.unwrap_or_default();
+        let violations: Vec<ViolationEntry> = report
+            .violations
+            .into_iter()
+            .filter(|v| rules.is_empty() || rules.iter().any(|rule| v.id.starts_with(rule)))
+            .collect();
+        let count = |severity: &str| violations.iter().filter(|v| v.severity == severity).count();
+        let errors = count("ERROR");
+        ResponseFormatter::json_success(&serde_json::json!({
+            "workspace": workspace.display().to_string(),
+            "passed": errors == 0,
+            "total_violations": violations.len(),
+            "errors": errors,
+            "warnings": count("WARNING"),
+            "infos": count("INFO"),
+            "violations": violations,
+        }))
+    }
+}
+
+/// Workspace named by `path`, or the current repository when only a
+/// `collection` (or nothing) is given.
+fn resolve_workspace(args: &ValidateArchitectureArgs) -> Result<PathBuf, Error> {
+    if let Some(path) = args.path.as_deref() {
+        return Ok(PathBuf::from(path));
+    }
+    let Some(repo_path) = args.repo_path.as_deref() else {
+        return Err(Error::invalid_argument(
+            "path could not be resolved: pass path or ensure a repository is detected",
+        ));
+    };
+    if let Some(collection) = args.collection.as_deref() {
+        let requested = normalize_collection_name(collection).map_err(Error::invalid_argument)?;
+        let current = args
+            .repo_id
+            .as_deref()
+            .map(normalize_collection_name)
+            .transpose()
+            .map_err(Error::invalid_argument)?;
+        if current.as_ref() != Some(&requested) {
+            return Err(Error::invalid_argument(format!(
+                "collection '{collection}' is not the current repository: pass its workspace as path"
+            )));
+        }
+    }
+    Ok(PathBuf::from(repo_path))
+}
+
+/// Severity filter understood by the validation service.
+fn severity_filter(severity: ValidateSeverity) -> &'static str {
+    match severity {
+        ValidateSeverity::Error => "error",
+        ValidateSeverity::Warning => "warning",
+        ValidateSeverity::Info => "info",
+    }
+}
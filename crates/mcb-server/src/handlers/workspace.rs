@@ -0,0 +1,127 @@
+//!
+//! **Documentation**: [docs/modules/server.md](../../../../docs/modules/server.md)
+//!
+//! Workspace handler: collection membership and federated search.
+
+use std::sync::Arc;
+
+use mcb_domain::error::Error;
+use mcb_domain::ports::WorkspaceServiceInterface;
+use mcb_utils::constants::limits::DEFAULT_SEARCH_LIMIT;
+use rmcp::ErrorData as McpError;
+use rmcp::handler::server::wrapper::Parameters;
+use rmcp::model::CallToolResult;
+use serde_json::json;
+use validator::Validate;
+
+use crate::args::{WorkspaceAction, WorkspaceArgs};
+use crate::error_mapping::to_contextual_tool_error;
+use crate::formatter::ResponseFormatter;
+use crate::utils::collections::normalize_collection_name;
+
+/// Handler for the `workspace` MCP tool.
+#[derive(Clone)]
+pub struct WorkspaceHandler {
+    workspaces: Arc<dyn WorkspaceServiceInterface>,
+}
+
+handler_new!(WorkspaceHandler {
+    workspaces: Arc<dyn WorkspaceServiceInterface>,
+});
+
+impl WorkspaceHandler {
+    /// Handle a `workspace` tool request.
+    ///
+    /// # Errors
+    /// Returns an error when the response cannot be serialized.
+    #[tracing::instrument(skip_all)]
+    pub async fn handle(
+        &self,
+        Parameters(args): Parameters<WorkspaceArgs>,
+    ) -> Result<CallToolResult, McpError> {
+        if let Err(e) = args.validate() {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                e.to_string(),
+            )));
+        }
+        if args.action == WorkspaceAction::List {
+            return match self.workspaces.list_workspaces().await {
+                Ok(workspaces) => ResponseFormatter::json_success(&workspaces),
+                Err(e) => Ok(to_contextual_tool_error(e)),
+            };
+        }
+        let Some(workspace) = args.workspace.as_deref() else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "workspace is required for add, remove and search",
+            )));
+        };
+
+        if args.action == WorkspaceAction::Search {
+            self.search(workspace, &args).await
+        } else {
+            self.change_membership(workspace, &args).await
+        }
+    }
+
+    async fn change_membership(
+        &self,
+        workspace: &str,
+        args: &WorkspaceArgs,
+    ) -> Result<CallToolResult, McpError> {
+        let Some(name) = args.collection.as_deref().or(args.repo_id.as_deref()) else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "collection could not be resolved: pass collection or ensure a repository is detected",
+            )));
+        };
+        let collection_id = match normalize_collection_name(name) {
+            Ok(id) => id,
+            Err(reason) => return Ok(to_contextual_tool_error(Error::invalid_argument(reason))),
+        };
+
+        if args.action == WorkspaceAction::Add {
+            return match self
+                .workspaces
+                .add_collection(workspace, name, &collection_id)
+                .await
+            {
+                Ok(workspace) => ResponseFormatter::json_success(&workspace),
+                Err(e) => Ok(to_contextual_tool_error(e)),
+            };
+        }
+        match self
+            .workspaces
+            .remove_collection(workspace, &collection_id)
+            .await
+        {
+            Ok(removed) => ResponseFormatter::json_success(&json!({
+                "workspace": workspace,
+                "collection": name,
+                "removed": removed,
+            })),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+
+    async fn search(
+        &self,
+        workspace: &str,
+        args: &WorkspaceArgs,
+    ) -> Result<CallToolResult, McpError> {
+        let Some(query) = args.query.as_deref() else {
+            return Ok(to_contextual_tool_error(Error::invalid_argument(
+                "query is required for search",
+            )));
+        };
+        let limit = args.limit.unwrap_or(DEFAULT_SEARCH_LIMIT as u32) as usize;
+
+        match self.workspaces.search(workspace, query, limit, None).await {
+            Ok(results) => ResponseFormatter::json_success(&json!({
+                "workspace": workspace,
+                "query": query,
+                "count": results.len(),
+                "results": results,
+            })),
+            Err(e) => Ok(to_contextual_tool_error(e)),
+        }
+    }
+}
@@ -0,0 +1,184 @@
+//! Readiness checks over registered dependency probes.
+//!
+//! **Documentation**: [docs/modules/server.md](../../../docs/modules/server.md)
+//!
+//! [`ReadinessChecker`] runs every [`HealthProbe`] concurrently with a timeout,
+//! marks each result critical or not, and caches the aggregate report so
+//! frequent orchestrator polls do not hammer providers. Liveness (`/healthz`)
+//! never touches dependencies; readiness (`/readyz`) is served from here.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use mcb_domain::ports::{
+    DependencyHealth, DependencyHealthCheck, EmbeddingProvider, HealthProbe, VectorStoreProvider,
+};
+use serde::Serialize;
+use tokio::sync::Mutex;
+
+/// Probe result plus whether its failure blocks readiness.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyReadiness {
+    /// Probe outcome
+    #[serde(flatten)]
+    pub check: DependencyHealthCheck,
+    /// Whether an unhealthy result makes the server not ready
+    pub critical: bool,
+}
+
+/// Aggregate readiness across all probes.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadinessReport {
+    /// True when every critical dependency is healthy
+    pub ready: bool,
+    /// `ready`, `degraded` (a non-critical dependency failed) or `not_ready`
+    pub status: &'static str,
+    /// Per-dependency results in registration order
+    pub dependencies: Vec<DependencyReadiness>,
+}
+
+struct RegisteredProbe {
+    probe: Arc<dyn HealthProbe>,
+    critical: bool,
+}
+
+/// Runs dependency probes and caches the aggregate result.
+pub struct ReadinessChecker {
+    probes: Vec<RegisteredProbe>,
+    timeout: Duration,
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, ReadinessReport)>>,
+}
+
+impl ReadinessChecker {
+    /// Create a checker with a per-probe `timeout` and a result cache `ttl`.
+    #[must_use]
+    pub fn new(timeout: Duration, ttl: Duration) -> Self {
+        Self {
+            probes: Vec::new(),
+            timeout,
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// Register a probe; `critical` probes gate readiness.
+    #[must_use]
+    pub fn with_probe(mut self, probe: Arc<dyn HealthProbe>, critical: bool) -> Self {
+        self.probes.push(RegisteredProbe { probe, critical });
+        self
+    }
+
+    /// Current readiness, served from cache while younger than the TTL.
+    pub async fn check(&self) -> ReadinessReport {
+        let mut cached = self.cached.lock().await;
+        if let Some((at, ref report)) = *cached
+            && at.elapsed() < self.ttl
+        {
+            return report.clone();
+        }
+        let report = self.run().await;
+        *cached = Some((Instant::now(), report.clone()));
+        report
+    }
+
+    async fn run(&self) -> ReadinessReport {
+        let dependencies = join_all(self.probes.iter().map(|p| async move {
+            DependencyReadiness {
+                check: self.probe_one(p.probe.as_ref()).await,
+                critical: p.critical,
+            }
+        }))
+        .await;
+
+        let failed = |critical: bool| {
+            dependencies
+                .iter()
+                .any(|d| d.critical == critical && d.check.status != DependencyHealth::Healthy)
+        };
+        let (ready, status) = if failed(true) {
+            (false, "not_ready")
+        } else if failed(false) {
+            (true, "degraded")
+        } else {
+            (true, "ready")
+        };
+        ReadinessReport {
+            ready,
+            status,
+            dependencies,
+        }
+    }
+
+    async fn probe_one(&self, probe: &dyn HealthProbe) -> DependencyHealthCheck {
+        let started = Instant::now();
+        let outcome = tokio::time::timeout(self.timeout, probe.probe()).await;
+        let (status, message) = match outcome {
+            Ok(Ok(())) => (DependencyHealth::Healthy, None),
+            Ok(Err(e)) => (DependencyHealth::Unhealthy, Some(e.to_string())),
+            Err(_) => (
+                DependencyHealth::Unhealthy,
+                Some(format!("timed out after {}ms", self.timeout.as_millis())),
+            ),
+        };
+        DependencyHealthCheck {
+            name: probe.name().to_owned(),
+            status,
+            message,
+            latency_ms: u64::try_from(started.elapsed().as_millis()).ok(),
+            last_check: mcb_utils::utils::time::epoch_secs_u64().unwrap_or(0),
+        }
+    }
+}
+
+/// Probe backed by [`EmbeddingProvider::health_check`].
+pub struct EmbeddingProbe(pub Arc<dyn EmbeddingProvider>);
+
+#[async_trait::async_trait]
+impl HealthProbe for EmbeddingProbe {
+    fn name(&self) -> &str {
+        "embedding"
+    }
+
+    async fn probe(&self) -> mcb_domain::error::Result<()> {
+        self.0.health_check().await
+    }
+}
+
+/// Probe backed by [`VectorStoreProvider`]'s `health_check`.
+pub struct VectorStoreProbe(pub Arc<dyn VectorStoreProvider>);
+
+#[async_trait::async_trait]
+impl HealthProbe for VectorStoreProbe {
+    fn name(&self) -> &str {
+        "vector_store"
+    }
+
+    async fn probe(&self) -> mcb_domain::error::Result<()> {
+        self.0.health_check().await
+    }
+}
+
+/// Probe that fails once the vector store's storage use reaches its quota
+/// warning threshold. Stores without quota accounting always pass.
+pub struct StorageQuotaProbe(pub Arc<dyn VectorStoreProvider>);
+
+#[async_trait::async_trait]
+impl HealthProbe for StorageQuotaProbe {
+    fn name(&self) -> &str {
+        "storage"
+    }
+
+    async fn probe(&self) -> mcb_domain::error::Result<()> {
+        let Some(usage) = self.0.storage_usage().await? else {
+            return Ok(());
+        };
+        let warnings = usage.warnings();
+        if warnings.is_empty() {
+            Ok(())
+        } else {
+            Err(mcb_domain::error::Error::vector_db(warnings.join("; ")))
+        }
+    }
+}
@@ -202,6 +202,7 @@ fn build_post_tool_use_metadata(
                 .agent_program(context.metadata.get("agent_program").cloned())
                 .model_id(context.metadata.get("model_id").cloned())
                 .delegated(delegated)
+                .request_id(context.metadata.get("request_id").cloned())
                 .build(),
         ),
         ..Default::default()
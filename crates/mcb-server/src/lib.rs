@@ -23,6 +23,7 @@ pub mod error_mapping;
 pub mod formatter;
 pub mod graphql_store;
 pub mod handlers;
+pub mod health;
 pub mod hooks;
 
 /// Loco app hooks and MCP server composition root.
@@ -157,7 +157,7 @@ macro_rules! entity_args_schema {
                 #[doc = "Data payload for create/update (JSON object)"]
                 #[schemars(
                     description = "Data payload for create/update (JSON object)",
-                    with = "crate::args::schema_helpers::ObjectDataSchema"
+                    with = "Option<crate::args::schema_helpers::ObjectDataSchema>"
                 )]
                 pub data: Option<serde_json::Value>,
             }
@@ -9,12 +9,16 @@
 /// - **Direct**: args type matches handler signature.
 /// - **Mapped**: args convert via `From` before dispatch (`$args => $target`).
 ///
+/// Every tool states after its name whether it writes server state (a
+/// [`ToolAccess`](crate::tools::read_only::ToolAccess)); read-only mode and
+/// idempotency keys are derived from it.
+///
 /// Must be invoked in a context where `CallToolRequestParams`, `ToolHandlers`,
 /// `ToolCallFuture`, `ToolDescriptor`, `TOOL_DESCRIPTORS`, and `parse_args`
 /// are all in scope.
 macro_rules! register_tool {
     // Direct dispatch — args go straight to handler
-    ($schema_fn:ident, $call_fn:ident, $descriptor:ident, $handler:ident, $args:ty, $name:literal, $desc:expr) => {
+    ($schema_fn:ident, $call_fn:ident, $descriptor:ident, $handler:ident, $args:ty, $name:literal, $access:expr, $desc:expr) => {
         fn $schema_fn() -> schemars::Schema {
             schemars::schema_for!($args)
         }
@@ -30,13 +34,14 @@ macro_rules! register_tool {
         #[linkme::distributed_slice(TOOL_DESCRIPTORS)]
         static $descriptor: ToolDescriptor = ToolDescriptor {
             name: $name,
+            access: $access,
             description: $desc,
             schema: $schema_fn,
             call: $call_fn,
         };
     };
     // Mapped dispatch — args convert via From<A> for B before handler
-    ($schema_fn:ident, $call_fn:ident, $descriptor:ident, $handler:ident, $args:ty => $target:ty, $name:literal, $desc:expr) => {
+    ($schema_fn:ident, $call_fn:ident, $descriptor:ident, $handler:ident, $args:ty => $target:ty, $name:literal, $access:expr, $desc:expr) => {
         fn $schema_fn() -> schemars::Schema {
             schemars::schema_for!($args)
         }
@@ -53,6 +58,7 @@ macro_rules! register_tool {
         #[linkme::distributed_slice(TOOL_DESCRIPTORS)]
         static $descriptor: ToolDescriptor = ToolDescriptor {
             name: $name,
+            access: $access,
             description: $desc,
             schema: $schema_fn,
             call: $call_fn,
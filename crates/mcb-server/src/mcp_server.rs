@@ -16,12 +16,20 @@ use mcb_domain::ports::AgentSessionServiceInterface;
 use mcb_domain::ports::HybridSearchProvider;
 use mcb_domain::ports::VcsProvider;
 use mcb_domain::ports::{
-    ContextServiceInterface, IndexingServiceInterface, MemoryServiceInterface,
-    ProjectDetectorService, SearchServiceInterface, ValidationServiceInterface,
+    AuthRepositoryPort, BulkheadInterface, MetricsProvider, MetricsSnapshotProvider,
+    VectorStoreProvider, Workload,
 };
 use mcb_domain::ports::{
-    IssueEntityRepository, OrgEntityRepository, PlanEntityRepository, ProjectRepository,
-    VcsEntityRepository,
+    ChangeSummaryServiceInterface, ContextServiceInterface, DuplicateDetectionServiceInterface,
+    FileContentServiceInterface, GrepServiceInterface, IndexingServiceInterface,
+    MemoryServiceInterface, ModuleGraphServiceInterface, ProjectDetectorService,
+    RepoAnalysisServiceInterface, SearchFeedbackServiceInterface, SearchServiceInterface,
+    SessionContextServiceInterface, UsageServiceInterface, ValidationServiceInterface,
+    WorkspaceServiceInterface,
+};
+use mcb_domain::ports::{
+    IdempotencyRepository, IssueEntityRepository, OrgEntityRepository, PlanEntityRepository,
+    ProjectRepository, TestLinkRepository, VcsEntityRepository,
 };
 use rmcp::ErrorData as McpError;
 use rmcp::ServerHandler;
@@ -31,15 +39,20 @@ use rmcp::model::{
 };
 
 use crate::handlers::{
-    AgentHandler, EntityHandler, IndexHandler, IssueEntityHandler, MemoryHandler, OrgEntityHandler,
-    PlanEntityHandler, ProjectHandler, SearchHandler, SessionHandler, ValidateHandler,
-    VcsEntityHandler, VcsHandler,
+    AgentHandler, AnalyzeRepoHandler, ContextHandler, EntityHandler, FeedbackHandler,
+    FindDuplicatesHandler, FindTestsHandler, GetChunkHandler, GrepHandler, ImpactOfChangeHandler,
+    IndexHandler, IssueEntityHandler, MemoryHandler, ModuleDependenciesHandler, OrgEntityHandler,
+    PlanEntityHandler, ProjectHandler, ReadFileHandler, RebuildTextIndexHandler, SearchHandler,
+    SessionHandler, StatsHandler, SummarizeChangesHandler, ValidateArchitectureHandler,
+    ValidateHandler, VcsEntityHandler, VcsHandler, WorkspaceHandler,
 };
 use crate::hooks::HookProcessor;
+use crate::tools::idempotency::IdempotencyStore;
 use crate::tools::{
     ExecutionFlow, RuntimeDefaults, ToolExecutionContext, ToolHandlers, create_tool_list,
     route_tool_call,
 };
+use crate::transport::resolve_request_id;
 
 /// Core MCP server implementation
 ///
@@ -93,14 +106,46 @@ pub struct McpServices {
     pub memory: Arc<dyn MemoryServiceInterface>,
     /// Agent session service
     pub agent_session: Arc<dyn AgentSessionServiceInterface>,
+    /// Per-session seen-chunk tracking
+    pub session_context: Arc<dyn SessionContextServiceInterface>,
+    /// Search relevance feedback and learned file boosts
+    pub search_feedback: Arc<dyn SearchFeedbackServiceInterface>,
+    /// Literal/regex scan of the indexed snapshot
+    pub grep: Arc<dyn GrepServiceInterface>,
+    /// Line-range reads of indexed files
+    pub file_content: Arc<dyn FileContentServiceInterface>,
+    /// Symbol-level change summaries
+    pub change_summary: Arc<dyn ChangeSummaryServiceInterface>,
+    /// Module import graph queries
+    pub module_graph: Arc<dyn ModuleGraphServiceInterface>,
+    /// Clone detection over indexed chunks
+    pub duplicates: Arc<dyn DuplicateDetectionServiceInterface>,
+    /// Repository profiling and onboarding recommendations
+    pub repo_analysis: Arc<dyn RepoAnalysisServiceInterface>,
+    /// Workspaces of related collections and federated search
+    pub workspace: Arc<dyn WorkspaceServiceInterface>,
+    /// Query counting and monthly usage reports
+    pub usage: Arc<dyn UsageServiceInterface>,
     /// Project detector service
     pub project: Arc<dyn ProjectDetectorService>,
     /// Project workflow repository
     pub project_workflow: Arc<dyn ProjectRepository>,
+    /// Test-to-symbol links recorded at index time
+    pub test_links: Arc<dyn TestLinkRepository>,
+    /// Results of mutating tool calls by idempotency key
+    pub idempotency: Arc<dyn IdempotencyRepository>,
     /// VCS provider
     pub vcs: Arc<dyn VcsProvider>,
     /// Hybrid search provider for BM25+semantic re-ranking.
     pub hybrid_search: Arc<dyn HybridSearchProvider>,
+    /// Shared vector store provider (collection stats).
+    pub vector_store: Arc<dyn VectorStoreProvider>,
+    /// Metrics recorder backing `server_stats`.
+    pub metrics: Arc<dyn MetricsSnapshotProvider>,
+    /// Pools bounding concurrent admin-scoped tool calls.
+    pub bulkheads: Arc<dyn BulkheadInterface>,
+    /// API keys checked before admin-scoped tools run over HTTP.
+    pub auth: Arc<dyn AuthRepositoryPort>,
     /// Entity repositories shared by CRUD handlers.
     pub entities: McpEntityRepositories,
 }
@@ -126,6 +171,30 @@ impl McpServer {
         }
     }
 
+    /// Reject tool calls that write server state (`mcp.read_only`).
+    #[must_use]
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.handlers.read_only = read_only;
+        self
+    }
+
+    /// Keep results of mutating calls with an idempotency key for
+    /// `window_secs` (`mcp.idempotency_window_secs`); `0` rejects keys.
+    #[must_use]
+    pub fn with_idempotency_window(mut self, window_secs: u64) -> Self {
+        self.handlers.idempotency = Arc::new(IdempotencyStore::new(
+            Arc::clone(&self.services.idempotency),
+            window_secs,
+        ));
+        self
+    }
+
+    /// Whether tool calls and admin requests that write are rejected.
+    #[must_use]
+    pub fn is_read_only(&self) -> bool {
+        self.handlers.read_only
+    }
+
     impl_arc_accessors! {
         /// Access to indexing service
         indexing_service -> dyn IndexingServiceInterface => services.indexing,
@@ -139,10 +208,32 @@ impl McpServer {
         memory_service -> dyn MemoryServiceInterface => services.memory,
         /// Access to agent session service
         agent_session_service -> dyn AgentSessionServiceInterface => services.agent_session,
+        /// Access to session context service
+        session_context_service -> dyn SessionContextServiceInterface => services.session_context,
+        /// Access to search feedback service
+        search_feedback_service -> dyn SearchFeedbackServiceInterface => services.search_feedback,
+        /// Access to grep service
+        grep_service -> dyn GrepServiceInterface => services.grep,
+        /// Access to file content service
+        file_content_service -> dyn FileContentServiceInterface => services.file_content,
+        /// Access to change summary service
+        change_summary_service -> dyn ChangeSummaryServiceInterface => services.change_summary,
+        /// Access to module graph service
+        module_graph_service -> dyn ModuleGraphServiceInterface => services.module_graph,
+        /// Access to duplicate detection service
+        duplicate_detection_service -> dyn DuplicateDetectionServiceInterface => services.duplicates,
+        /// Access to repository analysis service
+        repo_analysis_service -> dyn RepoAnalysisServiceInterface => services.repo_analysis,
+        /// Access to workspace service
+        workspace_service -> dyn WorkspaceServiceInterface => services.workspace,
+        /// Access to usage reporting service
+        usage_service -> dyn UsageServiceInterface => services.usage,
         /// Access to project service
         project_service -> dyn ProjectDetectorService => services.project,
         /// Access to project workflow repository
         project_workflow_repository -> dyn ProjectRepository => services.project_workflow,
+        /// Access to test link repository
+        test_link_repository -> dyn TestLinkRepository => services.test_links,
         /// Access to VCS provider
         vcs_provider -> dyn VcsProvider => services.vcs,
         /// Access to VCS entity repository
@@ -155,10 +246,32 @@ impl McpServer {
         org_entity_repository -> dyn OrgEntityRepository => services.entities.org,
         /// Access to index handler (for HTTP transport)
         index_handler -> IndexHandler => handlers.index,
+        /// Access to analyze-repo handler (for HTTP transport)
+        analyze_repo_handler -> AnalyzeRepoHandler => handlers.analyze_repo,
         /// Access to search handler (for HTTP transport)
         search_handler -> SearchHandler => handlers.search,
+        /// Access to context handler (for HTTP transport)
+        context_handler -> ContextHandler => handlers.context,
+        /// Access to feedback handler (for HTTP transport)
+        feedback_handler -> FeedbackHandler => handlers.feedback,
+        /// Access to grep handler (for HTTP transport)
+        grep_handler -> GrepHandler => handlers.grep,
+        /// Access to find-tests handler (for HTTP transport)
+        find_tests_handler -> FindTestsHandler => handlers.find_tests,
+        /// Access to module-dependencies handler (for HTTP transport)
+        module_dependencies_handler -> ModuleDependenciesHandler => handlers.module_dependencies,
+        /// Access to impact-of-change handler (for HTTP transport)
+        impact_of_change_handler -> ImpactOfChangeHandler => handlers.impact_of_change,
+        /// Access to find-duplicates handler (for HTTP transport)
+        find_duplicates_handler -> FindDuplicatesHandler => handlers.find_duplicates,
+        /// Access to read-file handler (for HTTP transport)
+        read_file_handler -> ReadFileHandler => handlers.read_file,
+        /// Access to summarize-changes handler (for HTTP transport)
+        summarize_changes_handler -> SummarizeChangesHandler => handlers.summarize_changes,
         /// Access to validate handler (for HTTP transport)
         validate_handler -> ValidateHandler => handlers.validate,
+        /// Access to validate-architecture handler (for HTTP transport)
+        validate_architecture_handler -> ValidateArchitectureHandler => handlers.validate_architecture,
         /// Access to memory handler (for HTTP transport)
         memory_handler -> MemoryHandler => handlers.memory,
         /// Access to session handler (for HTTP transport)
@@ -167,10 +280,18 @@ impl McpServer {
         agent_handler -> AgentHandler => handlers.agent,
         /// Access to VCS handler (for HTTP transport)
         vcs_handler -> VcsHandler => handlers.vcs,
+        /// Access to workspace handler (for HTTP transport)
+        workspace_handler -> WorkspaceHandler => handlers.workspace,
         /// Access to unified entity handler (for HTTP transport)
         entity_handler -> EntityHandler => handlers.entity,
         /// Access to project handler (for HTTP transport)
         project_handler -> ProjectHandler => handlers.project,
+        /// Access to stats handler (for HTTP transport)
+        stats_handler -> StatsHandler => handlers.stats,
+        /// Access to get-chunk handler (for HTTP transport)
+        get_chunk_handler -> GetChunkHandler => handlers.get_chunk,
+        /// Access to rebuild-text-index handler (for HTTP transport)
+        rebuild_text_index_handler -> RebuildTextIndexHandler => handlers.rebuild_text_index,
         /// Access to VCS entity handler (for HTTP transport)
         vcs_entity_handler -> VcsEntityHandler => handlers.vcs_entity,
         /// Access to plan entity handler (for HTTP transport)
@@ -256,6 +377,9 @@ tools:
 
         let mut execution_context =
             ToolExecutionContext::resolve(&self.runtime_defaults, &overrides);
+        let http_parts = context.extensions.get::<axum::http::request::Parts>();
+        execution_context.request_id =
+            Some(resolve_request_id(http_parts.map(|parts| &parts.headers)));
 
         if let Some(path_str) = execution_context.repo_path.as_deref()
             && execution_context
@@ -269,6 +393,21 @@ tools:
 
         execution_context.apply_to_request_if_missing(&mut request);
 
+        // Admin-scoped tools need an admin API key on HTTP requests; stdio
+        // callers already own the server process and its data.
+        if crate::auth::is_admin_tool(request.name.as_ref())
+            && let Some(parts) = http_parts
+        {
+            crate::auth::authorize_admin_api_key(self.services.auth.as_ref(), &parts.headers, None)
+                .await
+                .map_err(|_| {
+                    McpError::invalid_request(
+                        format!("'{}' requires an admin API key", request.name),
+                        None,
+                    )
+                })?;
+        }
+
         // T10 + T11: Lazy auto-creation of session and project per unique context.
         auto_create_session_and_project(
             &self.services,
@@ -279,6 +418,13 @@ tools:
         )
         .await;
 
+        // Admin tools run in their own pool so they cannot crowd out
+        // searches and indexing.
+        let _pool = if crate::auth::is_admin_tool(request.name.as_ref()) {
+            Some(self.services.bulkheads.enter(Workload::Admin).await)
+        } else {
+            None
+        };
         route_tool_call(request, &self.handlers, execution_context).await
     }
 }
@@ -301,13 +447,35 @@ fn build_tool_handlers(services: &McpServices) -> ToolHandlers {
 
     ToolHandlers {
         index: Arc::new(IndexHandler::new(Arc::clone(&services.indexing))),
+        analyze_repo: Arc::new(AnalyzeRepoHandler::new(Arc::clone(&services.repo_analysis))),
         search: Arc::new(SearchHandler::new(
             Arc::clone(&services.search),
             Arc::clone(&services.memory),
             Arc::clone(&services.hybrid_search),
             Arc::clone(&services.indexing),
+            Arc::clone(&services.session_context),
+            Arc::clone(&services.search_feedback),
+            Arc::clone(&services.usage),
         )),
+        context: Arc::new(ContextHandler::new(Arc::clone(&services.session_context))),
+        feedback: Arc::new(FeedbackHandler::new(Arc::clone(&services.search_feedback))),
+        grep: Arc::new(GrepHandler::new(Arc::clone(&services.grep))),
+        find_tests: Arc::new(FindTestsHandler::new(Arc::clone(&services.test_links))),
+        module_dependencies: Arc::new(ModuleDependenciesHandler::new(Arc::clone(
+            &services.module_graph,
+        ))),
+        impact_of_change: Arc::new(ImpactOfChangeHandler::new(Arc::clone(
+            &services.module_graph,
+        ))),
+        find_duplicates: Arc::new(FindDuplicatesHandler::new(Arc::clone(&services.duplicates))),
+        read_file: Arc::new(ReadFileHandler::new(Arc::clone(&services.file_content))),
+        summarize_changes: Arc::new(SummarizeChangesHandler::new(Arc::clone(
+            &services.change_summary,
+        ))),
         validate: Arc::new(ValidateHandler::new(Arc::clone(&services.validation))),
+        validate_architecture: Arc::new(ValidateArchitectureHandler::new(Arc::clone(
+            &services.validation,
+        ))),
         memory: Arc::new(MemoryHandler::new(Arc::clone(&services.memory))),
         session: Arc::new(SessionHandler::new(
             Arc::clone(&services.agent_session),
@@ -316,12 +484,25 @@ fn build_tool_handlers(services: &McpServices) -> ToolHandlers {
         agent: Arc::new(AgentHandler::new(Arc::clone(&services.agent_session))),
         project: Arc::new(ProjectHandler::new(Arc::clone(&services.project_workflow))),
         vcs: Arc::new(VcsHandler::new(Arc::clone(&services.vcs))),
+        workspace: Arc::new(WorkspaceHandler::new(Arc::clone(&services.workspace))),
         vcs_entity: vcs_entity_handler,
         plan_entity: plan_entity_handler,
         issue_entity: issue_entity_handler,
         org_entity: org_entity_handler,
         entity: entity_handler,
+        stats: Arc::new(StatsHandler::new(
+            Arc::clone(&services.vector_store),
+            Arc::clone(&services.metrics),
+        )),
+        get_chunk: Arc::new(GetChunkHandler::new(Arc::clone(&services.vector_store))),
+        rebuild_text_index: Arc::new(RebuildTextIndexHandler::new(
+            Arc::clone(&services.vector_store),
+            Arc::clone(&services.hybrid_search),
+        )),
         hook_processor: Arc::new(hook_processor),
+        read_only: false,
+        idempotency: Arc::new(IdempotencyStore::new(Arc::clone(&services.idempotency), 0)),
+        metrics: Arc::clone(&services.metrics) as Arc<dyn MetricsProvider>,
     }
 }
 
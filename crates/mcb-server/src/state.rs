@@ -8,8 +8,8 @@
 use std::sync::Arc;
 
 use mcb_domain::ports::{
-    AuthRepositoryPort, DashboardQueryPort, EmbeddingProvider, IndexingOperationsInterface,
-    ValidationOperationsInterface, VectorStoreProvider,
+    AuthRepositoryPort, CollectionManifestRepository, DashboardQueryPort, EmbeddingProvider,
+    IndexingOperationsInterface, ValidationOperationsInterface, VectorStoreProvider,
 };
 
 use crate::mcp_server::McpServer;
@@ -33,6 +33,8 @@ pub struct McpServerBootstrap {
     pub indexing_ops: Arc<dyn IndexingOperationsInterface>,
     /// Shared validation operations tracker for jobs admin (single-resolution DI)
     pub validation_ops: Arc<dyn ValidationOperationsInterface>,
+    /// Collection manifest repository for collection reports (built via bridge from Loco DB)
+    pub collection_manifests: Arc<dyn CollectionManifestRepository>,
 }
 
 impl McpServerBootstrap {
@@ -47,6 +49,7 @@ impl McpServerBootstrap {
             vector_store: self.vector_store,
             indexing_ops: self.indexing_ops,
             validation_ops: self.validation_ops,
+            collection_manifests: self.collection_manifests,
         }
     }
 }
@@ -76,4 +79,6 @@ pub struct McbState {
     pub indexing_ops: Arc<dyn IndexingOperationsInterface>,
     /// Shared validation operations tracker for jobs admin
     pub validation_ops: Arc<dyn ValidationOperationsInterface>,
+    /// Collection manifest repository for collection reports
+    pub collection_manifests: Arc<dyn CollectionManifestRepository>,
 }
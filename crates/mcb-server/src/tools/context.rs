@@ -45,6 +45,8 @@ pub struct ToolExecutionContext {
     pub timestamp: Option<i64>,
     /// Optional execution flow identifier for tracing.
     pub execution_flow: Option<String>,
+    /// Correlation ID assigned to the call at the transport boundary.
+    pub request_id: Option<String>,
 }
 
 impl ToolExecutionContext {
@@ -116,6 +118,7 @@ impl ToolExecutionContext {
             delegated,
             timestamp: mcb_utils::utils::time::epoch_secs_i64().ok(),
             execution_flow,
+            request_id: None,
         }
     }
 
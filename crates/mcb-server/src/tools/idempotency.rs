@@ -0,0 +1,236 @@
+//! Idempotency keys for mutating tool calls.
+//!
+//! A mutating call may carry an `idempotency_key` argument. The first
+//! successful result for a key is stored for `mcp.idempotency_window_secs`,
+//! and retries with the same key get that result back instead of running
+//! the tool again. Keys are scoped by organization and tool, and a retry
+//! with other arguments than the stored call is rejected.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use dashmap::DashMap;
+use mcb_domain::entities::IdempotencyRecord;
+use mcb_domain::ports::IdempotencyRepository;
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolRequestParams, CallToolResult, JsonObject};
+use serde_json::Value;
+use tokio::sync::Mutex;
+
+use crate::error_mapping::{safe_internal_error, to_opaque_mcp_error};
+use crate::tools::read_only::tool_may_write;
+
+/// Argument carrying the client's idempotency key.
+pub const IDEMPOTENCY_KEY_ARGUMENT: &str = "idempotency_key";
+
+/// Organization, tool and key of a call.
+type CallKey = (String, String, String);
+
+/// Stores results of mutating calls by idempotency key.
+pub struct IdempotencyStore {
+    repository: Arc<dyn IdempotencyRepository>,
+    window_secs: u64,
+    /// Calls running per key, so concurrent retries wait for the first.
+    in_flight: DashMap<CallKey, Arc<Mutex<()>>>,
+}
+
+impl IdempotencyStore {
+    /// Keep results in `repository` for `window_secs`; `0` rejects keys.
+    #[must_use]
+    pub fn new(repository: Arc<dyn IdempotencyRepository>, window_secs: u64) -> Self {
+        Self {
+            repository,
+            window_secs,
+            in_flight: DashMap::new(),
+        }
+    }
+
+    /// Whether idempotency keys are honoured.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.window_secs > 0
+    }
+
+    /// Reject a key that would be ignored: keys are disabled, or no call of
+    /// `tool_name` changes server state.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-params error naming the reason.
+    pub fn check_key(&self, tool_name: &str) -> Result<(), McpError> {
+        if !tool_may_write(tool_name) {
+            return Err(McpError::invalid_params(
+                format!(
+                    "'{tool_name}' does not change server state and takes no {IDEMPOTENCY_KEY_ARGUMENT}"
+                ),
+                None,
+            ));
+        }
+        if !self.is_enabled() {
+            return Err(McpError::invalid_params(
+                format!(
+                    "{IDEMPOTENCY_KEY_ARGUMENT} is not accepted: mcp.idempotency_window_secs is 0"
+                ),
+                None,
+            ));
+        }
+        Ok(())
+    }
+
+    /// SHA-256 of `arguments` with object keys sorted, so the order the
+    /// client sent them in does not matter.
+    #[must_use]
+    pub fn request_hash(arguments: Option<&JsonObject>) -> String {
+        let arguments = arguments.map_or(Value::Null, |args| sorted(&Value::Object(args.clone())));
+        mcb_utils::utils::id::compute_content_hash(&arguments.to_string())
+    }
+
+    /// Remove the idempotency key from the arguments of `request`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an invalid-params error when the key is not a non-empty string.
+    pub fn take_key(request: &mut CallToolRequestParams) -> Result<Option<String>, McpError> {
+        let Some(value) = request
+            .arguments
+            .as_mut()
+            .and_then(|args| args.remove(IDEMPOTENCY_KEY_ARGUMENT))
+        else {
+            return Ok(None);
+        };
+        match value {
+            serde_json::Value::Null => Ok(None),
+            serde_json::Value::String(key) if !key.trim().is_empty() => Ok(Some(key)),
+            _ => Err(McpError::invalid_params(
+                format!("{IDEMPOTENCY_KEY_ARGUMENT} must be a non-empty string"),
+                None,
+            )),
+        }
+    }
+
+    /// Run `call` unless a result is already stored for `key`, and store
+    /// its result if it succeeds. `request_hash` is the
+    /// [`request_hash`](Self::request_hash) of the call's arguments.
+    ///
+    /// Tool error results are not stored, so a retry runs the call again.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `call`, an invalid-params error when `key` was
+    /// stored for other arguments, or an internal error when the stored
+    /// results cannot be read.
+    pub async fn run<F>(
+        &self,
+        org_id: &str,
+        tool_name: &str,
+        key: &str,
+        request_hash: &str,
+        call: F,
+    ) -> Result<CallToolResult, McpError>
+    where
+        F: Future<Output = Result<CallToolResult, McpError>>,
+    {
+        let call_key = (org_id.to_owned(), tool_name.to_owned(), key.to_owned());
+        let lock = Arc::clone(self.in_flight.entry(call_key.clone()).or_default().value());
+        let result = {
+            let _running = lock.lock().await;
+            self.run_once(org_id, tool_name, key, request_hash, call)
+                .await
+        };
+        // The map and this call hold the only references when nobody waits.
+        self.in_flight
+            .remove_if(&call_key, |_, lock| Arc::strong_count(lock) <= 2);
+        result
+    }
+
+    async fn run_once<F>(
+        &self,
+        org_id: &str,
+        tool_name: &str,
+        key: &str,
+        request_hash: &str,
+        call: F,
+    ) -> Result<CallToolResult, McpError>
+    where
+        F: Future<Output = Result<CallToolResult, McpError>>,
+    {
+        let now = mcb_utils::utils::time::epoch_secs_i64()
+            .map_err(|e| safe_internal_error("read clock", &e))?;
+        let since = now.saturating_sub(i64::try_from(self.window_secs).unwrap_or(i64::MAX));
+        let stored = self
+            .repository
+            .find_record(org_id, tool_name, key, since)
+            .await
+            .map_err(|e| to_opaque_mcp_error(&e))?;
+        if let Some(record) = stored {
+            if record.request_hash != request_hash {
+                return Err(McpError::invalid_params(
+                    format!(
+                        "{IDEMPOTENCY_KEY_ARGUMENT} '{key}' was already used with other arguments"
+                    ),
+                    None,
+                ));
+            }
+            return serde_json::from_str(&record.response)
+                .map_err(|e| safe_internal_error("decode stored tool result", &e));
+        }
+
+        let result = call.await?;
+        if !result.is_error.unwrap_or(false) {
+            self.save(org_id, tool_name, key, request_hash, now, &result)
+                .await;
+        }
+        if let Err(e) = self.repository.delete_expired(since).await {
+            mcb_domain::warn!("Idempotency", "Failed to delete expired results", &e);
+        }
+        Ok(result)
+    }
+
+    /// Store `result`; the call already ran, so failures are only logged.
+    async fn save(
+        &self,
+        org_id: &str,
+        tool_name: &str,
+        key: &str,
+        request_hash: &str,
+        created_at: i64,
+        result: &CallToolResult,
+    ) {
+        let response = match serde_json::to_string(result) {
+            Ok(response) => response,
+            Err(e) => {
+                mcb_domain::warn!("Idempotency", "Failed to encode tool result", &e);
+                return;
+            }
+        };
+        let record = IdempotencyRecord {
+            org_id: org_id.to_owned(),
+            tool_name: tool_name.to_owned(),
+            key: key.to_owned(),
+            request_hash: request_hash.to_owned(),
+            response,
+            created_at,
+        };
+        if let Err(e) = self.repository.save_record(&record).await {
+            mcb_domain::warn!("Idempotency", "Failed to store tool result", &e);
+        }
+    }
+}
+
+/// `value` with the keys of every object in sorted order.
+fn sorted(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by_key(|(key, _)| *key);
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key.clone(), sorted(value)))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.iter().map(sorted).collect()),
+        other => other.clone(),
+    }
+}
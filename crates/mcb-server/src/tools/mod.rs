@@ -4,20 +4,27 @@
 //!
 //! - registry.rs - Tool definitions and schema management
 //! - router.rs - Tool dispatch and routing
+//! - `schema_validation.rs` - JSON Schema validation of tool arguments
 //! - context.rs - Execution context extraction and resolution
 //! - defaults.rs - Runtime defaults and execution flow configuration
 //! - `field_aliases.rs` - Field alias resolution for metadata
+//! - `idempotency.rs` - Stored results of mutating calls by idempotency key
+//! - `read_only.rs` - Rejection of mutating calls in read-only mode
 //! - validation.rs - Execution context validation and hook processing
 
 pub mod context;
 pub mod defaults;
 pub mod field_aliases;
+pub mod idempotency;
+pub mod read_only;
 pub mod registry;
 pub mod router;
+pub mod schema_validation;
 pub mod validation;
 
 pub use context::ToolExecutionContext;
 pub use defaults::{ExecutionFlow, RuntimeDefaults};
 pub use registry::{create_tool_list, dispatch_tool_call, tool_by_name};
 pub use router::{ToolHandlers, route_tool_call};
+pub use schema_validation::{compile_tool_schemas, validate_tool_arguments};
 pub use validation::validate_execution_context;
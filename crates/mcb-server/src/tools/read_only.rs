@@ -0,0 +1,119 @@
+//! Read-only mode enforcement.
+//!
+//! With `mcp.read_only` (`mcb serve --read-only`) the router rejects tool
+//! calls that write server state before they are dispatched, so search can
+//! be exposed to untrusted agents. Whether a call writes comes from the
+//! [`ToolAccess`] every tool declares when it is registered; a tool that is
+//! not registered counts as writing.
+
+use rmcp::ErrorData as McpError;
+use rmcp::model::{CallToolRequestParams, JsonObject};
+
+use crate::tools::registry::TOOL_DESCRIPTORS;
+
+/// Argument holding a path on the server's disk: `search_code` starts
+/// background indexing of it, `read_file` checks it against the indexed root.
+const DISK_PATH_ARGUMENT: &str = "repo_path";
+
+/// Tools whose [`DISK_PATH_ARGUMENT`] is dropped in read-only mode.
+const DISK_PATH_TOOLS: &[&str] = &["search_code", "read_file"];
+
+/// Whether calls to a tool write server state.
+#[derive(Debug, Clone, Copy)]
+pub enum ToolAccess {
+    /// No call writes server state.
+    ReadOnly,
+    /// Every call writes server state.
+    Mutating,
+    /// Whether a call writes depends on its arguments.
+    ByArguments(fn(Option<&JsonObject>) -> bool),
+}
+
+impl ToolAccess {
+    /// Whether a call with `arguments` writes server state.
+    #[must_use]
+    pub fn writes(self, arguments: Option<&JsonObject>) -> bool {
+        match self {
+            Self::ReadOnly => false,
+            Self::Mutating => true,
+            Self::ByArguments(writes) => writes(arguments),
+        }
+    }
+
+    /// Whether some call can write server state.
+    #[must_use]
+    pub const fn may_write(self) -> bool {
+        !matches!(self, Self::ReadOnly)
+    }
+}
+
+fn action(arguments: Option<&JsonObject>) -> Option<&str> {
+    arguments
+        .and_then(|args| args.get("action"))
+        .and_then(serde_json::Value::as_str)
+}
+
+/// Without data, `summarize_session` only reads the stored summary.
+#[must_use]
+pub fn summarize_session_writes(arguments: Option<&JsonObject>) -> bool {
+    arguments.is_some_and(|args| args.contains_key("data"))
+}
+
+/// `workspace` writes unless its action is `list` or `search`.
+#[must_use]
+pub fn workspace_writes(arguments: Option<&JsonObject>) -> bool {
+    !matches!(action(arguments), Some("list" | "search"))
+}
+
+/// `project` and `entity` write unless their action is `get` or `list`; a
+/// missing action counts as a write.
+#[must_use]
+pub fn crud_writes(arguments: Option<&JsonObject>) -> bool {
+    !matches!(action(arguments), Some("get" | "list"))
+}
+
+/// Whether a call to `tool_name` with `arguments` writes server state.
+///
+/// Unknown tools count as writing, so a tool can never be read-only by
+/// omission.
+#[must_use]
+pub fn is_mutating_call(tool_name: &str, arguments: Option<&JsonObject>) -> bool {
+    TOOL_DESCRIPTORS
+        .iter()
+        .find(|descriptor| descriptor.name == tool_name)
+        .is_none_or(|descriptor| descriptor.access.writes(arguments))
+}
+
+/// Whether some call to `tool_name` can write server state; unknown tools
+/// count as writing.
+#[must_use]
+pub fn tool_may_write(tool_name: &str) -> bool {
+    TOOL_DESCRIPTORS
+        .iter()
+        .find(|descriptor| descriptor.name == tool_name)
+        .is_none_or(|descriptor| descriptor.access.may_write())
+}
+
+/// Reject `request` if it writes, and drop the disk paths untrusted agents
+/// could use to start indexing or pick a directory to read.
+///
+/// # Errors
+///
+/// Returns an invalid-request error for mutating calls.
+pub fn enforce_read_only(request: &mut CallToolRequestParams) -> Result<(), McpError> {
+    if is_mutating_call(request.name.as_ref(), request.arguments.as_ref()) {
+        return Err(McpError::invalid_request(
+            format!(
+                "'{}' is disabled: the server is running in read-only mode",
+                request.name
+            ),
+            None,
+        ));
+    }
+    if DISK_PATH_TOOLS.contains(&request.name.as_ref())
+        && let Some(args) = request.arguments.as_mut()
+    {
+        args.remove(DISK_PATH_ARGUMENT);
+    }
+    Ok(())
+}
@@ -28,15 +28,21 @@ use rmcp::model::{CallToolRequestParams, CallToolResult, Tool};
 use validator::Validate;
 
 use crate::args::{
-    AgentArgs, AnalyzeCodeArgs, AnalyzeImpactArgs, ClearIndexArgs, CompareBranchesArgs, EntityArgs,
-    GetMemoriesArgs, GetSessionArgs, IndexArgs, IndexRepoArgs, IndexStatusArgs, InjectContextArgs,
-    ListMemoriesArgs, ListReposArgs, ListRulesArgs, ListSessionsArgs, LogDelegationArgs,
-    LogToolCallArgs, MemoryArgs, MemoryTimelineArgs, ProjectArgs, SearchArgs, SearchCodeArgs,
-    SearchMemoryArgs, SessionArgs, StartSessionArgs, StoreMemoryArgs, SummarizeSessionArgs,
-    ValidateArgs, ValidateCodeArgs, VcsArgs,
+    AdrCheckArgs, AgentArgs, AnalyzeCodeArgs, AnalyzeImpactArgs, AnalyzeRepoArgs, ClearIndexArgs,
+    CompareBranchesArgs, EntityArgs, FindDuplicatesArgs, FindTestsArgs, GetChunkArgs,
+    GetMemoriesArgs, GetMoreContextArgs, GetSessionArgs, GrepArgs, ImpactOfChangeArgs, IndexArgs,
+    IndexRepoArgs, IndexStatusArgs, InjectContextArgs, ListMemoriesArgs, ListReposArgs,
+    ListRulesArgs, ListSessionsArgs, LogDelegationArgs, LogToolCallArgs, MemoryArgs,
+    MemoryTimelineArgs, ModuleDependenciesArgs, ProjectArgs, ReadFileArgs, RebuildTextIndexArgs,
+    SearchArgs, SearchCodeArgs, SearchFeedbackArgs, SearchMemoryArgs, ServerStatsArgs, SessionArgs,
+    StartSessionArgs, StoreMemoryArgs, SummarizeChangesArgs, SummarizeSessionArgs,
+    ValidateArchitectureArgs, ValidateArgs, ValidateCodeArgs, VcsArgs, WorkspaceArgs,
 };
 use crate::error_mapping::safe_internal_error;
+use crate::tools::idempotency::IDEMPOTENCY_KEY_ARGUMENT;
+use crate::tools::read_only::{self, ToolAccess};
 use crate::tools::router::ToolHandlers;
+use crate::tools::schema_validation::validate_tool_arguments;
 
 /// Async future returned by a descriptor-based tool call.
 pub type ToolCallFuture<'a> =
@@ -48,6 +54,8 @@ pub type ToolCallFn = for<'a> fn(&'a CallToolRequestParams, &'a ToolHandlers) ->
 pub struct ToolDescriptor {
     /// Unique tool name exposed via MCP.
     pub name: &'static str,
+    /// Whether calls write server state.
+    pub access: ToolAccess,
     /// Human-readable tool description.
     pub description: &'static str,
     /// Factory that produces the JSON schema for tool arguments.
@@ -84,6 +92,7 @@ register_tool!(
     schema_search_code, call_search_code, SEARCH_CODE_DESCRIPTOR,
     search, SearchCodeArgs => SearchArgs,
     "search_code",
+    ToolAccess::ReadOnly,
     "Search for code in your project using natural language.\n\
      The repository is automatically detected and indexed.\n\
      If not yet indexed, indexing starts in the background.\n\n\
@@ -98,6 +107,7 @@ register_tool!(
     schema_search_memory, call_search_memory, SEARCH_MEMORY_DESCRIPTOR,
     search, SearchMemoryArgs => SearchArgs,
     "search_memory",
+    ToolAccess::ReadOnly,
     "Search through stored memories and observations.\n\
      Finds previously stored knowledge, decisions, patterns,\n\
      and context using semantic similarity.\n\n\
@@ -107,6 +117,142 @@ register_tool!(
      Filter by tags for precision. Returns ranked matches\n\
      with content, timestamps, and metadata."
 );
+register_tool!(
+    schema_get_more_context,
+    call_get_more_context,
+    GET_MORE_CONTEXT_DESCRIPTOR,
+    context,
+    GetMoreContextArgs,
+    "get_more_context",
+    ToolAccess::ReadOnly,
+    "Fetch additional code context for this session without repeats.\n\
+     Chunks already returned to the session (by search_code or\n\
+     earlier get_more_context calls) are skipped, and results are\n\
+     packed into token_budget tokens (default 2000).\n\n\
+     Call again with the same query to page further; the response\n\
+     reports exhausted=true when nothing unseen remains.\n\
+     Set reset to start the session's context over."
+);
+register_tool!(
+    schema_search_feedback,
+    call_search_feedback,
+    SEARCH_FEEDBACK_DESCRIPTOR,
+    feedback,
+    SearchFeedbackArgs,
+    "search_feedback",
+    ToolAccess::Mutating,
+    "Rate a search_code result as relevant (thumbs up) or not.\n\
+     Pass the query plus the result's id and file_path from search_code.\n\
+     Feedback is stored in the memory database and periodically turned\n\
+     into per-file boosts that re-rank future code searches."
+);
+register_tool!(
+    schema_grep,
+    call_grep,
+    GREP_DESCRIPTOR,
+    grep,
+    GrepArgs,
+    "grep",
+    ToolAccess::ReadOnly,
+    "Find exact text or a regular expression in the indexed code.\n\
+     Use it for identifiers, error messages and config keys where\n\
+     search_code's semantic ranking is too fuzzy. Returns file, line\n\
+     and text for each match; scope with path_prefix, path_glob,\n\
+     language or exclude_paths.\n\n\
+     Pass semantic_query to combine both: only search_code results\n\
+     whose chunk contains the pattern are returned."
+);
+register_tool!(
+    schema_read_file,
+    call_read_file,
+    READ_FILE_DESCRIPTOR,
+    read_file,
+    ReadFileArgs,
+    "read_file",
+    ToolAccess::ReadOnly,
+    "Read lines of an indexed file, e.g. the code around a search hit.\n\
+     Pass the result's file_path with start_line/end_line (1-based,\n\
+     inclusive). Content comes from the working tree when available,\n\
+     otherwise from the indexed snapshot; only files indexed in the\n\
+     current collection can be read. Output is capped at max_bytes\n\
+     (default 64 KiB) and reports truncated=true when cut short."
+);
+register_tool!(
+    schema_find_tests,
+    call_find_tests,
+    FIND_TESTS_DESCRIPTOR,
+    find_tests,
+    FindTestsArgs,
+    "find_tests",
+    ToolAccess::ReadOnly,
+    "Find the tests that exercise a function, method or type.\n\
+     Pass the symbol name (e.g. parse_config). Indexing links each\n\
+     test function to the symbols its body calls and to the symbol\n\
+     its name points at (test_parse_config -> parse_config); tests\n\
+     that call the symbol are listed before name-only matches.\n\
+     Returns test file, test name and line range for each link."
+);
+register_tool!(
+    schema_module_dependencies,
+    call_module_dependencies,
+    MODULE_DEPENDENCIES_DESCRIPTOR,
+    module_dependencies,
+    ModuleDependenciesArgs,
+    "module_dependencies",
+    ToolAccess::ReadOnly,
+    "List what an indexed file imports and which indexed files import it.\n\
+     Imports are recorded while indexing (Rust, Python, JS/TS, Go,\n\
+     Java, Kotlin) and resolved to files of the current collection;\n\
+     an import with no resolved files is external (a package or the\n\
+     standard library)."
+);
+register_tool!(
+    schema_impact_of_change,
+    call_impact_of_change,
+    IMPACT_OF_CHANGE_DESCRIPTOR,
+    impact_of_change,
+    ImpactOfChangeArgs,
+    "impact_of_change",
+    ToolAccess::ReadOnly,
+    "Find the indexed files affected by changing the given files.\n\
+     Follows the import graph backwards: files importing a changed\n\
+     file (depth 1), files importing those (depth 2), and so on up\n\
+     to max_depth (default 3). Each affected file names the file it\n\
+     imports on the way (via); truncated=true means the graph goes on."
+);
+register_tool!(
+    schema_find_duplicates,
+    call_find_duplicates,
+    FIND_DUPLICATES_DESCRIPTOR,
+    find_duplicates,
+    FindDuplicatesArgs,
+    "find_duplicates",
+    ToolAccess::ReadOnly,
+    "Find duplicated code among the indexed chunks of the current\n\
+     collection. Pairs are classified as exact, renamed (same code\n\
+     with identifiers/literals changed) or gapped (similar code with\n\
+     statements added or removed) and reported when their similarity\n\
+     reaches min_similarity (default 0.8) and both sides span at least\n\
+     min_lines (default 6). Narrow with path_prefix, path_glob,\n\
+     language or exclude_paths; most similar pairs come first."
+);
+register_tool!(
+    schema_workspace,
+    call_workspace,
+    WORKSPACE_DESCRIPTOR,
+    workspace,
+    WorkspaceArgs,
+    "workspace",
+    ToolAccess::ByArguments(read_only::workspace_writes),
+    "Group the collections of related repositories into a workspace\n\
+     and search them together. add/remove take the workspace and a\n\
+     collection (default: the current repository's); list shows every\n\
+     workspace. search takes workspace and query (and limit) and merges\n\
+     the results of all member collections by score.\n\n\
+     Once collections share a workspace, files indexed afterwards that\n\
+     are identical to a file of another member (vendored dependencies)\n\
+     are embedded once; results list the other copies in also_in."
+);
 
 // ---------------------------------------------------------------------------
 // Index tools (mapped → IndexArgs)
@@ -115,6 +261,7 @@ register_tool!(
     schema_index_repo, call_index_repo, INDEX_REPO_DESCRIPTOR,
     index, IndexRepoArgs => IndexArgs,
     "index_repo",
+    ToolAccess::Mutating,
     "Index (or re-index) the current repository for code search.\n\
      The repository path is detected automatically.\n\n\
      Scans source files, generates embeddings, and stores them\n\
@@ -126,6 +273,7 @@ register_tool!(
     schema_index_status, call_index_status, INDEX_STATUS_DESCRIPTOR,
     index, IndexStatusArgs => IndexArgs,
     "index_status",
+    ToolAccess::ReadOnly,
     "Check the current indexing status.\n\
      Returns whether indexing is in progress, complete, or idle,\n\
      along with file counts and timestamps."
@@ -134,10 +282,27 @@ register_tool!(
     schema_clear_index, call_clear_index, CLEAR_INDEX_DESCRIPTOR,
     index, ClearIndexArgs => IndexArgs,
     "clear_index",
+    ToolAccess::Mutating,
     "Clear the search index for the current repository.\n\
      Removes all indexed embeddings. You will need to re-index\n\
      before code search works again."
 );
+register_tool!(
+    schema_analyze_repo,
+    call_analyze_repo,
+    ANALYZE_REPO_DESCRIPTOR,
+    analyze_repo,
+    AnalyzeRepoArgs,
+    "analyze_repo",
+    ToolAccess::ReadOnly,
+    "Profile a repository before indexing it and recommend a setup.\n\
+     Defaults to the current repository; pass path for another one.\n\
+     Measures files, chunks, languages and tokens without embedding\n\
+     anything, and counts the last 30 days of commits and changed files.\n\n\
+     Returns the profile plus recommended extensions, embedding model,\n\
+     vector store, estimated cost and index time, the reasoning behind\n\
+     each choice, and a YAML config snippet ready for a profile file."
+);
 
 // ---------------------------------------------------------------------------
 // Memory tools (mapped → MemoryArgs)
@@ -146,6 +311,7 @@ register_tool!(
     schema_store_memory, call_store_memory, STORE_MEMORY_DESCRIPTOR,
     memory, StoreMemoryArgs => MemoryArgs,
     "store_memory",
+    ToolAccess::Mutating,
     "Store a new observation or piece of knowledge.\n\
      Persists information across sessions so it can be\n\
      retrieved later via search or timeline.\n\n\
@@ -157,6 +323,7 @@ register_tool!(
     schema_get_memories, call_get_memories, GET_MEMORIES_DESCRIPTOR,
     memory, GetMemoriesArgs => MemoryArgs,
     "get_memories",
+    ToolAccess::ReadOnly,
     "Retrieve specific memory items by their IDs.\n\
      Returns full content and metadata for each requested item."
 );
@@ -164,6 +331,7 @@ register_tool!(
     schema_list_memories, call_list_memories, LIST_MEMORIES_DESCRIPTOR,
     memory, ListMemoriesArgs => MemoryArgs,
     "list_memories",
+    ToolAccess::ReadOnly,
     "List and filter stored memories.\n\
      Supports filtering by tags, text query, and time window.\n\
      Returns a paginated list of matching observations."
@@ -172,6 +340,7 @@ register_tool!(
     schema_memory_timeline, call_memory_timeline, MEMORY_TIMELINE_DESCRIPTOR,
     memory, MemoryTimelineArgs => MemoryArgs,
     "memory_timeline",
+    ToolAccess::ReadOnly,
     "View a chronological timeline of memories around an anchor point.\n\
      Centers on a specific observation and shows items before/after\n\
      to provide temporal context for decisions and events."
@@ -180,6 +349,7 @@ register_tool!(
     schema_inject_context, call_inject_context, INJECT_CONTEXT_DESCRIPTOR,
     memory, InjectContextArgs => MemoryArgs,
     "inject_context",
+    ToolAccess::ReadOnly,
     "Inject relevant memories into the current context.\n\
      Automatically selects the most relevant observations\n\
      within a token budget for context enrichment.\n\
@@ -194,6 +364,7 @@ register_tool!(
     schema_start_session, call_start_session, START_SESSION_DESCRIPTOR,
     session, StartSessionArgs => SessionArgs,
     "start_session",
+    ToolAccess::Mutating,
     "Start a new agent session.\n\
      Creates a session record to track tool usage, decisions,\n\
      and delegations. Returns a session ID for subsequent calls.\n\n\
@@ -203,6 +374,7 @@ register_tool!(
     schema_get_session, call_get_session, GET_SESSION_DESCRIPTOR,
     session, GetSessionArgs => SessionArgs,
     "get_session",
+    ToolAccess::ReadOnly,
     "Retrieve details of an existing session by ID.\n\
      Returns session metadata, status, and associated data."
 );
@@ -210,6 +382,7 @@ register_tool!(
     schema_list_sessions, call_list_sessions, LIST_SESSIONS_DESCRIPTOR,
     session, ListSessionsArgs => SessionArgs,
     "list_sessions",
+    ToolAccess::ReadOnly,
     "List available sessions with optional filters.\n\
      Filter by status, agent type, or limit the result count.\n\
      Returns session summaries sorted by recency."
@@ -218,6 +391,7 @@ register_tool!(
     schema_summarize_session, call_summarize_session, SUMMARIZE_SESSION_DESCRIPTOR,
     session, SummarizeSessionArgs => SessionArgs,
     "summarize_session",
+    ToolAccess::ByArguments(read_only::summarize_session_writes),
     "Generate a summary of a session's activity.\n\
      Produces a structured overview of tool calls, decisions,\n\
      delegations, and outcomes for the given session."
@@ -230,6 +404,7 @@ register_tool!(
     schema_log_tool_call, call_log_tool_call, LOG_TOOL_CALL_DESCRIPTOR,
     agent, LogToolCallArgs => AgentArgs,
     "log_tool_call",
+    ToolAccess::Mutating,
     "Log a tool execution event for the current session.\n\
      Records tool name, parameters summary, success/failure,\n\
      error message, and duration for observability and replay."
@@ -238,6 +413,7 @@ register_tool!(
     schema_log_delegation, call_log_delegation, LOG_DELEGATION_DESCRIPTOR,
     agent, LogDelegationArgs => AgentArgs,
     "log_delegation",
+    ToolAccess::Mutating,
     "Log a delegation event (spawning a child agent).\n\
      Records the child session ID, prompt, result, success,\n\
      and duration for tracing multi-agent workflows."
@@ -250,6 +426,7 @@ register_tool!(
     schema_validate_code, call_validate_code, VALIDATE_CODE_DESCRIPTOR,
     validate, ValidateCodeArgs => ValidateArgs,
     "validate_code",
+    ToolAccess::ReadOnly,
     "Run architectural validation rules against the codebase.\n\
      Checks layer violations, circular dependencies, naming\n\
      conventions, and other configurable rules.\n\n\
@@ -260,6 +437,7 @@ register_tool!(
     schema_analyze_code, call_analyze_code, ANALYZE_CODE_DESCRIPTOR,
     validate, AnalyzeCodeArgs => ValidateArgs,
     "analyze_code",
+    ToolAccess::ReadOnly,
     "Analyze code complexity metrics.\n\
      Computes cyclomatic complexity, cognitive complexity,\n\
      and other metrics for the specified path.\n\
@@ -269,10 +447,39 @@ register_tool!(
     schema_list_rules, call_list_rules, LIST_RULES_DESCRIPTOR,
     validate, ListRulesArgs => ValidateArgs,
     "list_rules",
+    ToolAccess::ReadOnly,
     "List available validation rules.\n\
      Shows all configured architecture and code quality rules\n\
      with descriptions. Optionally filter by category."
 );
+register_tool!(
+    schema_validate_architecture,
+    call_validate_architecture,
+    VALIDATE_ARCHITECTURE_DESCRIPTOR,
+    validate_architecture,
+    ValidateArchitectureArgs,
+    "validate_architecture",
+    ToolAccess::ReadOnly,
+    "Run mcb-validate architecture checks on a workspace.\n\
+     Defaults to the current repository; pass path for another\n\
+     workspace. Optionally restrict the validators to run, the\n\
+     violation IDs or ID prefixes to report, and the minimum\n\
+     severity (error, warning, info).\n\n\
+     Returns pass/fail, per-severity counts and structured\n\
+     violations with ID, category, file, line and suggestion."
+);
+register_tool!(
+    schema_adr_check, call_adr_check, ADR_CHECK_DESCRIPTOR,
+    validate_architecture, AdrCheckArgs => ValidateArchitectureArgs,
+    "adr_check",
+    ToolAccess::ReadOnly,
+    "Check the workspace's ADRs against the ADR compliance rules.\n\
+     Verifies file names, numbered titles, statuses, required\n\
+     sections and unique numbers using the YAML rule set from\n\
+     [rules.adr] in .mcb-validate.toml.\n\n\
+     Returns pass/fail, per-severity counts and structured\n\
+     violations with rule ID, file, line and suggestion."
+);
 
 // ---------------------------------------------------------------------------
 // VCS tools (mapped → VcsArgs)
@@ -281,6 +488,7 @@ register_tool!(
     schema_list_repos, call_list_repos, LIST_REPOS_DESCRIPTOR,
     vcs, ListReposArgs => VcsArgs,
     "list_repos",
+    ToolAccess::ReadOnly,
     "List all repositories tracked by the project.\n\
      Returns repository metadata including ID, path, and\n\
      default branch information."
@@ -289,6 +497,7 @@ register_tool!(
     schema_compare_branches, call_compare_branches, COMPARE_BRANCHES_DESCRIPTOR,
     vcs, CompareBranchesArgs => VcsArgs,
     "compare_branches",
+    ToolAccess::ReadOnly,
     "Compare two branches and show their differences.\n\
      Returns a diff summary between the base and target branches,\n\
      optionally including commit history and depth control."
@@ -297,10 +506,72 @@ register_tool!(
     schema_analyze_impact, call_analyze_impact, ANALYZE_IMPACT_DESCRIPTOR,
     vcs, AnalyzeImpactArgs => VcsArgs,
     "analyze_impact",
+    ToolAccess::ReadOnly,
     "Analyze the impact of changes across branches.\n\
      Examines which files and modules are affected by changes,\n\
      helping assess risk and scope of modifications."
 );
+register_tool!(
+    schema_summarize_changes,
+    call_summarize_changes,
+    SUMMARIZE_CHANGES_DESCRIPTOR,
+    summarize_changes,
+    SummarizeChangesArgs,
+    "summarize_changes",
+    ToolAccess::ReadOnly,
+    "List the functions, classes and other definitions that changed.\n\
+     Pass base_ref (and optionally head_ref, default HEAD) to compare\n\
+     two branches, tags or commits. Omit both to compare the last\n\
+     index of the current collection with the working tree.\n\n\
+     Returns per-file symbol changes (added, removed, modified) with\n\
+     name, syntax kind and line range."
+);
+
+// ---------------------------------------------------------------------------
+// Introspection tools (direct dispatch)
+// ---------------------------------------------------------------------------
+register_tool!(
+    schema_server_stats,
+    call_server_stats,
+    SERVER_STATS_DESCRIPTOR,
+    stats,
+    ServerStatsArgs,
+    "server_stats",
+    ToolAccess::ReadOnly,
+    "Report server health and performance statistics.\n\
+     Returns collection counts, index freshness per collection,\n\
+     cache hit rates, embedding and vector store latency\n\
+     percentiles (p50/p95), and process memory usage.\n\n\
+     Set include_raw to also receive every recorded metric series."
+);
+register_tool!(
+    schema_get_chunk,
+    call_get_chunk,
+    GET_CHUNK_DESCRIPTOR,
+    get_chunk,
+    GetChunkArgs,
+    "get_chunk",
+    ToolAccess::ReadOnly,
+    "Inspect exactly what the index stored for one chunk (admin).\n\
+     Pass a search_code result's id. Returns the chunk content, every\n\
+     metadata field, the vector's dimensions and L2 norm, and where the\n\
+     vector store keeps the record. Set include_vector for the raw values.\n\n\
+     Over HTTP this tool requires an admin API key."
+);
+register_tool!(
+    schema_rebuild_text_index,
+    call_rebuild_text_index,
+    REBUILD_TEXT_INDEX_DESCRIPTOR,
+    rebuild_text_index,
+    RebuildTextIndexArgs,
+    "rebuild_text_index",
+    ToolAccess::Mutating,
+    "Rebuild a collection's BM25 keyword index from the vector store (admin).\n\
+     Use when keyword ranking is out of sync with the stored chunks, e.g.\n\
+     after a crash or restart. Reads every stored chunk; nothing is\n\
+     re-embedded. Returns the files and chunks indexed.\n\n\
+     Over HTTP this tool requires an admin API key."
+);
 
 // ---------------------------------------------------------------------------
 // Compound tools (direct dispatch, kept as-is)
@@ -312,6 +583,7 @@ register_tool!(
     project,
     ProjectArgs,
     "project",
+    ToolAccess::ByArguments(read_only::crud_writes),
     "Project workflow management.\n\
      CRUD operations for project resources: phases, issues,\n\
      dependencies, and decisions.\n\n\
@@ -325,6 +597,7 @@ register_tool!(
     entity,
     EntityArgs,
     "entity",
+    ToolAccess::ByArguments(read_only::crud_writes),
     "Unified entity CRUD for all resource types.\n\
      Manages VCS (repos, branches, worktrees, assignments),\n\
      plans (plans, versions, reviews), issues (issues, comments,\n\
@@ -335,7 +608,7 @@ register_tool!(
 fn create_tool_from_descriptor(descriptor: &ToolDescriptor) -> Result<Tool, McpError> {
     let schema_value = serde_json::to_value((descriptor.schema)())
         .map_err(|e| safe_internal_error("serialize tool schema", &e))?;
-    let input_schema = schema_value
+    let mut input_schema = schema_value
         .as_object()
         .ok_or_else(|| {
             safe_internal_error(
@@ -344,6 +617,19 @@ fn create_tool_from_descriptor(descriptor: &ToolDescriptor) -> Result<Tool, McpE
             )
         })?
         .clone();
+    if descriptor.access.may_write()
+        && let Some(properties) = input_schema
+            .get_mut("properties")
+            .and_then(serde_json::Value::as_object_mut)
+    {
+        properties.insert(
+            IDEMPOTENCY_KEY_ARGUMENT.to_owned(),
+            serde_json::json!({
+                "type": "string",
+                "description": "Unique key for this change; a retry with the same key returns the first result instead of repeating the change",
+            }),
+        );
+    }
     // rmcp 1.x marks Tool #[non_exhaustive]; build via its constructor.
     Ok(Tool::new(
         Cow::Borrowed(descriptor.name),
@@ -398,9 +684,12 @@ pub fn create_tool_list() -> Result<Vec<Tool>, McpError> {
 
 /// Dispatch to the tool call function from the shared descriptor registry.
 ///
+/// Arguments are validated against the tool's input schema first.
+///
 /// # Errors
 ///
-/// Returns an error if the tool name is unknown or the handler fails.
+/// Returns an error if the tool name is unknown, the arguments do not match
+/// the tool's schema, or the handler fails.
 pub async fn dispatch_tool_call(
     request: &CallToolRequestParams,
     handlers: &ToolHandlers,
@@ -408,5 +697,6 @@ pub async fn dispatch_tool_call(
     validate_registry_unique_tool_names()?;
     let descriptor = descriptor_by_name(request.name.as_ref())
         .ok_or_else(|| McpError::invalid_params(format!("Unknown tool: {}", request.name), None))?;
+    validate_tool_arguments(descriptor.name, request.arguments.as_ref())?;
     (descriptor.call)(request, handlers).await
 }
@@ -6,28 +6,63 @@
 //! This module provides a centralized dispatch mechanism for MCP tool calls.
 
 use std::sync::Arc;
+use std::time::Instant;
 
+use mcb_domain::ports::{MetricsProvider, MetricsProviderExt};
 use rmcp::ErrorData as McpError;
-use rmcp::model::{CallToolRequestParams, CallToolResult};
+use rmcp::model::{CallToolRequestParams, CallToolResult, Meta};
+use serde_json::Value;
 
 use crate::handlers::{
-    AgentHandler, EntityHandler, IndexHandler, IssueEntityHandler, MemoryHandler, OrgEntityHandler,
-    PlanEntityHandler, ProjectHandler, SearchHandler, SessionHandler, ValidateHandler,
-    VcsEntityHandler, VcsHandler,
+    AgentHandler, AnalyzeRepoHandler, ContextHandler, EntityHandler, FeedbackHandler,
+    FindDuplicatesHandler, FindTestsHandler, GetChunkHandler, GrepHandler, ImpactOfChangeHandler,
+    IndexHandler, IssueEntityHandler, MemoryHandler, ModuleDependenciesHandler, OrgEntityHandler,
+    PlanEntityHandler, ProjectHandler, ReadFileHandler, RebuildTextIndexHandler, SearchHandler,
+    SessionHandler, StatsHandler, SummarizeChangesHandler, ValidateArchitectureHandler,
+    ValidateHandler, VcsEntityHandler, VcsHandler, WorkspaceHandler,
 };
 use crate::hooks::HookProcessor;
 use crate::tools::context::ToolExecutionContext;
 use crate::tools::dispatch_tool_call;
+use crate::tools::idempotency::IdempotencyStore;
+use crate::tools::read_only::{enforce_read_only, is_mutating_call};
 use crate::tools::validation::{trigger_post_tool_use_hook, validate_execution_context};
+use crate::utils::mcp::resolve_org_id;
+
+/// Key of the request ID in response `_meta` and error `data`.
+pub const REQUEST_ID_FIELD: &str = "request_id";
+
 /// Handler references for tool routing
 #[derive(Clone)]
 pub struct ToolHandlers {
     /// Handler for coding implementation tasks.
     pub index: Arc<IndexHandler>,
+    /// Handler for repository onboarding recommendations.
+    pub analyze_repo: Arc<AnalyzeRepoHandler>,
     /// Handler for search operations.
     pub search: Arc<SearchHandler>,
+    /// Handler for session-scoped context retrieval.
+    pub context: Arc<ContextHandler>,
+    /// Handler for search relevance feedback.
+    pub feedback: Arc<FeedbackHandler>,
+    /// Handler for exact-text search.
+    pub grep: Arc<GrepHandler>,
+    /// Handler for test-to-symbol lookups.
+    pub find_tests: Arc<FindTestsHandler>,
+    /// Handler for per-file import graph lookups.
+    pub module_dependencies: Arc<ModuleDependenciesHandler>,
+    /// Handler for import graph change impact.
+    pub impact_of_change: Arc<ImpactOfChangeHandler>,
+    /// Handler for clone detection.
+    pub find_duplicates: Arc<FindDuplicatesHandler>,
+    /// Handler for line-range file reads.
+    pub read_file: Arc<ReadFileHandler>,
+    /// Handler for symbol-level change summaries.
+    pub summarize_changes: Arc<SummarizeChangesHandler>,
     /// Handler for validation operations.
     pub validate: Arc<ValidateHandler>,
+    /// Handler for structured architecture validation.
+    pub validate_architecture: Arc<ValidateArchitectureHandler>,
     /// Handler for memory operations.
     pub memory: Arc<MemoryHandler>,
     /// Handler for session management.
@@ -38,6 +73,8 @@ pub struct ToolHandlers {
     pub project: Arc<ProjectHandler>,
     /// Handler for VCS operations.
     pub vcs: Arc<VcsHandler>,
+    /// Handler for workspaces and federated search.
+    pub workspace: Arc<WorkspaceHandler>,
     /// Handler for VCS entity CRUD.
     pub vcs_entity: Arc<VcsEntityHandler>,
     /// Handler for plan entity CRUD.
@@ -48,25 +85,101 @@ pub struct ToolHandlers {
     pub org_entity: Arc<OrgEntityHandler>,
     /// Handler for unified entity CRUD.
     pub entity: Arc<EntityHandler>,
+    /// Handler for server self-introspection.
+    pub stats: Arc<StatsHandler>,
+    /// Handler for admin chunk inspection.
+    pub get_chunk: Arc<GetChunkHandler>,
+    /// Handler for admin keyword index rebuilds.
+    pub rebuild_text_index: Arc<RebuildTextIndexHandler>,
     /// Processor for tool execution hooks.
     pub hook_processor: Arc<HookProcessor>,
+    /// Reject tool calls that write server state.
+    pub read_only: bool,
+    /// Stored results of mutating calls by idempotency key.
+    pub idempotency: Arc<IdempotencyStore>,
+    /// Recorder for tool call latency and failures.
+    pub metrics: Arc<dyn MetricsProvider>,
 }
 
 /// Route a tool call request to the appropriate handler
 ///
 /// Parses the request arguments and delegates to the matching handler.
+/// In read-only mode, mutating calls are rejected before dispatch. A
+/// mutating call with an `idempotency_key` runs once per key; retries with the
+/// same arguments get the stored result.
 /// After tool execution, automatically triggers `PostToolUse` hook for memory operations.
 ///
+/// Logs of the call carry its request ID, which is also recorded in metrics
+/// and returned in the result `_meta` (or the error `data`).
+///
 /// # Errors
-/// Returns an error when execution context validation or tool dispatch fails.
+/// Returns an error when a mutating call is made in read-only mode, when an
+/// idempotency key is not accepted or was used with other arguments, or when
+/// execution context validation or tool dispatch fails.
+#[tracing::instrument(
+    skip_all,
+    fields(tool = %request.name, request_id = execution_context.request_id.as_deref())
+)]
 pub async fn route_tool_call(
     request: CallToolRequestParams,
     handlers: &ToolHandlers,
     execution_context: ToolExecutionContext,
 ) -> Result<CallToolResult, McpError> {
+    let started = Instant::now();
+    let tool_name = request.name.to_string();
+    let request_id = execution_context.request_id.clone();
+
+    let result = execute_tool_call(request, handlers, execution_context).await;
+
+    let failed = match &result {
+        Ok(result) => result.is_error.unwrap_or(false),
+        Err(_) => true,
+    };
+    if let Err(e) = handlers
+        .metrics
+        .record_tool_call(started.elapsed(), &tool_name, request_id.as_deref(), failed)
+        .await
+    {
+        mcb_domain::warn!("ToolRouter", "Failed to record tool call metrics", &e);
+    }
+
+    match request_id {
+        Some(request_id) => attach_request_id(result, &request_id),
+        None => result,
+    }
+}
+
+async fn execute_tool_call(
+    mut request: CallToolRequestParams,
+    handlers: &ToolHandlers,
+    execution_context: ToolExecutionContext,
+) -> Result<CallToolResult, McpError> {
+    if handlers.read_only {
+        enforce_read_only(&mut request)?;
+    }
     validate_execution_context(request.name.as_ref(), &execution_context)?;
 
-    let result = dispatch_tool_call(&request, handlers).await?;
+    let idempotency_key = IdempotencyStore::take_key(&mut request)?;
+    if idempotency_key.is_some() {
+        handlers.idempotency.check_key(request.name.as_ref())?;
+    }
+    let result = match idempotency_key {
+        Some(key) if is_mutating_call(request.name.as_ref(), request.arguments.as_ref()) => {
+            let org_id = resolve_org_id(execution_context.org_id.as_deref());
+            let request_hash = IdempotencyStore::request_hash(request.arguments.as_ref());
+            handlers
+                .idempotency
+                .run(
+                    &org_id,
+                    request.name.as_ref(),
+                    &key,
+                    &request_hash,
+                    dispatch_tool_call(&request, handlers),
+                )
+                .await?
+        }
+        _ => dispatch_tool_call(&request, handlers).await?,
+    };
 
     if let Err(e) = trigger_post_tool_use_hook(
         request.name.as_ref(),
@@ -81,3 +194,30 @@ pub async fn route_tool_call(
 
     Ok(result)
 }
+
+/// Return `request_id` to the client with the result or the error.
+fn attach_request_id(
+    result: Result<CallToolResult, McpError>,
+    request_id: &str,
+) -> Result<CallToolResult, McpError> {
+    let value = Value::String(request_id.to_owned());
+    match result {
+        Ok(mut result) => {
+            result
+                .meta
+                .get_or_insert_with(Meta::new)
+                .insert(REQUEST_ID_FIELD.to_owned(), value);
+            Ok(result)
+        }
+        Err(mut error) => {
+            // Non-object data is kept as is; the request ID is still in the logs.
+            if let Value::Object(data) = error
+                .data
+                .get_or_insert_with(|| Value::Object(Default::default()))
+            {
+                data.insert(REQUEST_ID_FIELD.to_owned(), value);
+            }
+            Err(error)
+        }
+    }
+}
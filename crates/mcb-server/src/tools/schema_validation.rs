@@ -0,0 +1,132 @@
+//! JSON Schema validation of tool arguments.
+//!
+//! Every call is checked against the input schema its tool publishes in
+//! `tools/list` before the arguments are parsed, and all invalid fields are
+//! reported together in the error `data`:
+//! `{"code": "MCB-INVALID-ARG", "retryable": false, "fields": [{"field": "limit", "message": "..."}]}`.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use mcb_domain::error::ErrorCode;
+use rmcp::ErrorData as McpError;
+use rmcp::model::JsonObject;
+use serde_json::{Value, json};
+
+use crate::error_mapping::{error_code_payload, safe_internal_error};
+use crate::tools::registry::TOOL_DESCRIPTORS;
+
+/// Compiled validators by tool name, built by [`compile_tool_schemas`].
+static VALIDATORS: OnceLock<HashMap<&'static str, jsonschema::Validator>> = OnceLock::new();
+
+fn validators() -> mcb_domain::Result<&'static HashMap<&'static str, jsonschema::Validator>> {
+    if let Some(validators) = VALIDATORS.get() {
+        return Ok(validators);
+    }
+    let validators = TOOL_DESCRIPTORS
+        .iter()
+        .map(|descriptor| {
+            compile_tool_schema(descriptor.name, &(descriptor.schema)().to_value())
+                .map(|validator| (descriptor.name, validator))
+        })
+        .collect::<mcb_domain::Result<_>>()?;
+    Ok(VALIDATORS.get_or_init(|| validators))
+}
+
+/// Compile the input schema of every registered tool.
+///
+/// Run while the server is composed, so a tool whose schema does not compile
+/// stops startup instead of going unvalidated.
+///
+/// # Errors
+///
+/// Returns a configuration error naming the first schema that does not compile.
+pub fn compile_tool_schemas() -> mcb_domain::Result<()> {
+    validators().map(|_| ())
+}
+
+/// Compile the input `schema` published by `tool_name`.
+///
+/// # Errors
+///
+/// Returns a configuration error if `schema` is not a valid JSON Schema.
+pub fn compile_tool_schema(
+    tool_name: &str,
+    schema: &Value,
+) -> mcb_domain::Result<jsonschema::Validator> {
+    jsonschema::validator_for(schema).map_err(|e| {
+        mcb_domain::error::Error::configuration(format!(
+            "input schema of tool '{tool_name}' does not compile: {e}"
+        ))
+    })
+}
+
+/// Dotted field name of a JSON pointer, e.g. `/data/name` → `data.name`.
+fn field_name(pointer: &str) -> String {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Validate `arguments` against the input schema of `tool_name`.
+///
+/// Null fields are skipped: they deserialize to `None` like absent ones.
+///
+/// # Errors
+///
+/// Returns an invalid-params error listing every invalid field, or an
+/// internal error if the tool schemas do not compile.
+pub fn validate_tool_arguments(
+    tool_name: &str,
+    arguments: Option<&JsonObject>,
+) -> Result<(), McpError> {
+    let validators = validators().map_err(|e| safe_internal_error("compile tool schemas", &e))?;
+    let Some(validator) = validators.get(tool_name) else {
+        return Ok(());
+    };
+    let instance = Value::Object(
+        arguments
+            .into_iter()
+            .flatten()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+    );
+    let errors: Vec<(String, String)> = validator
+        .iter_errors(&instance)
+        .map(|error| {
+            (
+                field_name(&error.instance_path().to_string()),
+                error.to_string(),
+            )
+        })
+        .collect();
+    if errors.is_empty() {
+        return Ok(());
+    }
+
+    let summary = errors
+        .iter()
+        .map(|(field, message)| {
+            if field.is_empty() {
+                message.clone()
+            } else {
+                format!("{field}: {message}")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    let fields = errors
+        .into_iter()
+        .map(|(field, message)| json!({ "field": field, "message": message }))
+        .collect();
+    let mut data = error_code_payload(ErrorCode::InvalidArgument);
+    data["fields"] = Value::Array(fields);
+    Err(McpError::invalid_params(
+        format!("Invalid arguments for '{tool_name}': {summary}"),
+        Some(data),
+    ))
+}
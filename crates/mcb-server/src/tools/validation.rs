@@ -36,6 +36,14 @@ pub fn validate_execution_context(
             | "clear_index"
             | "search_code"
             | "search_memory"
+            | "get_more_context"
+            | "search_feedback"
+            | "grep"
+            | "read_file"
+            | "find_tests"
+            | "module_dependencies"
+            | "impact_of_change"
+            | "find_duplicates"
             | "store_memory"
             | "get_memories"
             | "list_memories"
@@ -102,16 +110,23 @@ fn validate_operation_mode_matrix(
 ) -> Result<(), McpError> {
     let flow = normalize_execution_flow(execution_context.execution_flow.as_deref())?;
 
-    let allowed: &[ExecutionFlow] =
-        if matches!(tool_name, "validate_code" | "analyze_code" | "list_rules") {
-            &[ExecutionFlow::StdioOnly, ExecutionFlow::ClientHybrid]
-        } else {
-            &[
-                ExecutionFlow::StdioOnly,
-                ExecutionFlow::ClientHybrid,
-                ExecutionFlow::ServerHybrid,
-            ]
-        };
+    let allowed: &[ExecutionFlow] = if matches!(
+        tool_name,
+        "validate_code"
+            | "validate_architecture"
+            | "adr_check"
+            | "analyze_code"
+            | "list_rules"
+            | "analyze_repo"
+    ) {
+        &[ExecutionFlow::StdioOnly, ExecutionFlow::ClientHybrid]
+    } else {
+        &[
+            ExecutionFlow::StdioOnly,
+            ExecutionFlow::ClientHybrid,
+            ExecutionFlow::ServerHybrid,
+        ]
+    };
 
     if allowed.contains(&flow) {
         Ok(())
@@ -169,6 +184,7 @@ pub async fn trigger_post_tool_use_hook(
         ("machine_id", execution_context.machine_id.as_deref()),
         ("agent_program", execution_context.agent_program.as_deref()),
         ("model_id", execution_context.model_id.as_deref()),
+        ("request_id", execution_context.request_id.as_deref()),
     ] {
         if let Some(v) = value {
             context = context.with_metadata(key, v);
@@ -1,9 +1,11 @@
 //! Transport layer implementations for the MCB server.
 pub mod http_client;
+pub mod request_id;
 pub mod stdio;
 pub mod streamable_http;
 pub mod types;
 
 pub use http_client::{HttpClientTransport, McpClientConfig};
+pub use request_id::resolve_request_id;
 pub use stdio::StdioServerExt;
 pub use streamable_http::{build_overrides, extract_override};
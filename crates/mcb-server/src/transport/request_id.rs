@@ -0,0 +1,31 @@
+//! Request correlation IDs.
+//!
+//! Every tool call gets an ID at the transport boundary. HTTP clients may
+//! send their own in `X-Request-Id` so one call can be followed from the
+//! client through server logs, metrics, audit entries and the response.
+
+use axum::http::HeaderMap;
+use mcb_utils::constants::headers::HEADER_REQUEST_ID;
+
+use crate::transport::streamable_http::extract_override;
+
+/// Longest client-supplied request ID that is kept.
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+/// Resolve the request ID of a call.
+///
+/// A valid `X-Request-Id` header is reused; otherwise (stdio, missing or
+/// malformed header) a new UUID v4 is generated.
+#[must_use]
+pub fn resolve_request_id(headers: Option<&HeaderMap>) -> String {
+    headers
+        .and_then(|headers| extract_override(headers, HEADER_REQUEST_ID))
+        .filter(|id| is_valid_request_id(id))
+        .unwrap_or_else(mcb_utils::utils::id::generate_string)
+}
+
+/// Client IDs are echoed into logs and responses, so only short IDs made of
+/// visible ASCII characters are accepted.
+fn is_valid_request_id(id: &str) -> bool {
+    id.len() <= MAX_REQUEST_ID_LEN && id.bytes().all(|b| b.is_ascii_graphic())
+}
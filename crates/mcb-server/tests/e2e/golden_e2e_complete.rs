@@ -29,6 +29,7 @@ fn index_args(action: IndexAction, path: Option<String>, collection: Option<Stri
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     }
 }
 
@@ -42,6 +43,16 @@ fn search_args(query: &str, collection: Option<String>, limit: Option<u32>) -> S
         filters: None,
         limit,
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
@@ -151,6 +151,16 @@ async fn test_golden_context_search_basic() -> TestResult {
         filters: None,
         limit: Some(5),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
@@ -42,6 +42,7 @@ async fn test_golden_e2e_complete_workflow() -> TestResult {
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
     let r = index_h.handle(Parameters(clear_args)).await;
     assert!(r.is_ok(), "index clear should succeed");
@@ -65,6 +66,7 @@ async fn test_golden_e2e_complete_workflow() -> TestResult {
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
     let r = index_h.handle(Parameters(status_args)).await;
     assert!(r.is_ok());
@@ -87,6 +89,7 @@ async fn test_golden_e2e_complete_workflow() -> TestResult {
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
     let r = index_h.handle(Parameters(index_args)).await;
     assert!(r.is_ok(), "index should succeed");
@@ -112,6 +115,16 @@ async fn test_golden_e2e_complete_workflow() -> TestResult {
         filters: None,
         limit: Some(5),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
@@ -140,6 +153,7 @@ async fn test_golden_e2e_complete_workflow() -> TestResult {
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
     let r = index_h.handle(Parameters(clear_args)).await;
     assert!(r.is_ok());
@@ -170,6 +184,7 @@ async fn test_golden_index_variants(
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -206,6 +221,7 @@ async fn test_golden_search_returns_relevant_results() -> TestResult {
             follow_symlinks: None,
             token: None,
             repo_id: None,
+            org_id: None,
         }))
         .await
         .expect("index");
@@ -221,6 +237,16 @@ async fn test_golden_search_returns_relevant_results() -> TestResult {
             filters: None,
             limit: Some(10),
             min_score: None,
+            conversation_weight: None,
+            diversify_by: None,
+            diversity_lambda: None,
+            recency_half_life_days: None,
+            path_prefix: None,
+            path_glob: None,
+            language: None,
+            exclude_paths: None,
+            exclude_languages: None,
+            explain: None,
             tags: None,
             session_id: None,
             token: None,
@@ -246,6 +272,16 @@ async fn test_golden_search_handles_empty_query() -> TestResult {
         filters: None,
         limit: Some(5),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
@@ -286,6 +322,7 @@ async fn test_golden_search_respects_limit_parameter() -> TestResult {
             follow_symlinks: None,
             token: None,
             repo_id: None,
+            org_id: None,
         }))
         .await
         .expect("index for limit test");
@@ -301,6 +338,16 @@ async fn test_golden_search_respects_limit_parameter() -> TestResult {
             filters: None,
             limit: Some(2),
             min_score: None,
+            conversation_weight: None,
+            diversify_by: None,
+            diversity_lambda: None,
+            recency_half_life_days: None,
+            path_prefix: None,
+            path_glob: None,
+            language: None,
+            exclude_paths: None,
+            exclude_languages: None,
+            explain: None,
             tags: None,
             session_id: None,
             token: None,
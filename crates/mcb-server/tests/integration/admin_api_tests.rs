@@ -4,8 +4,12 @@
 //! `SQLite` database, `FastEmbed` embeddings, and in-memory vector store. This matches
 //! the existing handler integration test pattern.
 
-use axum::extract::Extension;
+use std::collections::HashMap;
+
+use axum::extract::{Extension, Path, Query};
 use http_body_util::BodyExt;
+use mcb_domain::value_objects::{CollectionId, DistanceMetric};
+use mcb_utils::constants::lang::CHUNKER_VERSION;
 use serde_json::Value;
 
 use crate::utils::test_fixtures::create_test_mcb_state;
@@ -221,3 +225,135 @@ async fn test_collections_endpoint_graceful_on_fresh_server()
     );
     Ok(())
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_collection_report_unindexed_collection_is_stale()
+-> Result<(), Box<dyn std::error::Error>> {
+    let Some((state, _tmp)) = create_test_mcb_state().await else {
+        return Ok(());
+    };
+
+    let response = mcb_server::controllers::collections_api::collection_report(
+        Extension(state),
+        Path("report-never-indexed".to_owned()),
+    )
+    .await?;
+    let body = json_body(response).await?;
+
+    assert_eq!(body["collection"], "report-never-indexed");
+    assert!(body["last_indexed_at"].is_null());
+    assert_eq!(body["stale_reasons"].as_array().map(Vec::len), Some(1));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn test_collection_report_breaks_down_languages_and_build()
+-> Result<(), Box<dyn std::error::Error>> {
+    let Some((state, _tmp)) = create_test_mcb_state().await else {
+        return Ok(());
+    };
+    let collection = CollectionId::from_string("report-repo");
+    let embedding = state.embedding_provider.embed("fn main() {}").await?;
+    state
+        .vector_store
+        .create_collection(&collection, embedding.dimensions)
+        .await?;
+    let chunk = |path: &str, language: &str| {
+        HashMap::from([
+            ("content".to_owned(), serde_json::json!("fn main() {}")),
+            ("file_path".to_owned(), serde_json::json!(path)),
+            ("language".to_owned(), serde_json::json!(language)),
+        ])
+    };
+    state
+        .vector_store
+        .insert_vectors(
+            &collection,
+            &[embedding.clone(), embedding.clone(), embedding],
+            vec![
+                chunk("src/main.rs", "rust"),
+                chunk("src/main.rs", "rust"),
+                chunk("scripts/build.py", "python"),
+            ],
+        )
+        .await?;
+    state
+        .collection_manifests
+        .record_build(
+            &collection.to_string(),
+            CHUNKER_VERSION,
+            DistanceMetric::Cosine,
+            "fastembed",
+            "/src/report-repo",
+        )
+        .await?;
+
+    let response = mcb_server::controllers::collections_api::collection_report(
+        Extension(state),
+        Path("report-repo".to_owned()),
+    )
+    .await?;
+    let body = json_body(response).await?;
+
+    assert_eq!(body["vectors_count"], 3);
+    assert_eq!(body["file_count"], 2);
+    assert_eq!(body["languages"]["rust"], 2);
+    assert_eq!(body["languages"]["python"], 1);
+    assert_eq!(body["largest_files"][0]["path"], "src/main.rs");
+    assert_eq!(body["chunker_version"], CHUNKER_VERSION);
+    assert_eq!(body["embedding_model"], "fastembed");
+    assert!(body["last_indexed_at"].is_i64());
+    assert_eq!(body["stale_reasons"].as_array().map(Vec::len), Some(0));
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Usage API
+// ---------------------------------------------------------------------------
+
+#[rstest]
+#[tokio::test]
+async fn test_usage_report_totals_queries_and_exports_csv() -> Result<(), Box<dyn std::error::Error>>
+{
+    let Some((state, _tmp)) = create_test_mcb_state().await else {
+        return Ok(());
+    };
+    let usage = state.mcp_server.usage_service();
+    let collection = CollectionId::from_name("usage-repo");
+    usage.record_query("usage-org", &collection).await?;
+    usage.record_query("usage-org", &collection).await?;
+
+    let query = |format: Option<&str>| {
+        Query(mcb_server::controllers::usage_api::UsageQuery {
+            org_id: Some("usage-org".to_owned()),
+            format: format.map(str::to_owned),
+            ..Default::default()
+        })
+    };
+    let response =
+        mcb_server::controllers::usage_api::usage_report(Extension(state.clone()), query(None))
+            .await?;
+    let body = json_body(response).await?;
+    assert_eq!(body["orgs"].as_array().map(Vec::len), Some(1));
+    assert_eq!(body["orgs"][0]["queries"], 2);
+    assert_eq!(body["collections"][0]["collection"], collection.to_string());
+
+    let response =
+        mcb_server::controllers::usage_api::usage_report(Extension(state), query(Some("csv")))
+            .await?;
+    assert_eq!(
+        response.headers()["content-type"],
+        "text/csv; charset=utf-8"
+    );
+    let bytes = response.into_body().collect().await?.to_bytes();
+    let csv = String::from_utf8(bytes.to_vec())?;
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next(),
+        Some("org_id,month,collection,indexed_bytes,embedding_tokens,queries")
+    );
+    assert!(lines.next().is_some_and(|line| line.ends_with(",0,0,2")));
+    Ok(())
+}
@@ -207,6 +207,9 @@ async fn test_search_without_collection_auto_resolves() {
         state.mcp_server.memory_service(),
         hybrid_search,
         state.mcp_server.indexing_service(),
+        state.mcp_server.session_context_service(),
+        state.mcp_server.search_feedback_service(),
+        state.mcp_server.usage_service(),
     );
 
     let args = SearchArgs {
@@ -216,6 +219,16 @@ async fn test_search_without_collection_auto_resolves() {
         collection: None,
         limit: Some(10),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         extensions: None,
@@ -246,6 +259,9 @@ async fn test_search_with_explicit_collection_still_works() {
         state.mcp_server.memory_service(),
         hybrid_search,
         state.mcp_server.indexing_service(),
+        state.mcp_server.session_context_service(),
+        state.mcp_server.search_feedback_service(),
+        state.mcp_server.usage_service(),
     );
 
     let args = SearchArgs {
@@ -255,6 +271,16 @@ async fn test_search_with_explicit_collection_still_works() {
         collection: Some("invalid/collection".to_owned()),
         limit: Some(10),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         extensions: None,
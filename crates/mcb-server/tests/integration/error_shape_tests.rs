@@ -11,6 +11,7 @@ use std::sync::Arc;
 
 use rstest::{fixture, rstest};
 
+use mcb_domain::ports::{BulkheadPolicy, JobSchedulerPolicy};
 use mcb_domain::registry::ServiceResolutionContext;
 use mcb_domain::registry::database::{DatabaseProviderConfig, resolve_database_provider};
 use mcb_domain::registry::embedding::{EmbeddingProviderConfig, resolve_embedding_provider};
@@ -24,6 +25,7 @@ use mcb_domain::registry::vector_store::{
 use mcb_domain::utils::tests::mcp_assertions::assert_error_shape;
 use mcb_domain::value_objects::SessionId;
 
+use mcb_infrastructure::infrastructure::{FairJobScheduler, SemaphoreBulkheads};
 use mcb_server::args::{MemoryAction, MemoryArgs, MemoryResource, SessionAction, SessionArgs};
 use mcb_server::build_mcp_server_bootstrap;
 use mcb_server::handlers::{MemoryHandler, SessionHandler};
@@ -107,6 +109,8 @@ async fn create_test_mcb_state() -> Option<(McbState, tempfile::TempDir)> {
         event_bus,
         embedding_provider,
         vector_store_provider,
+        job_scheduler: Arc::new(FairJobScheduler::new(JobSchedulerPolicy::default()).ok()?),
+        bulkheads: Arc::new(SemaphoreBulkheads::new(BulkheadPolicy::default()).ok()?),
     };
 
     let bootstrap = build_mcp_server_bootstrap(
@@ -115,7 +119,15 @@ async fn create_test_mcb_state() -> Option<(McbState, tempfile::TempDir)> {
         Arc::clone(&resolution_ctx.embedding_provider),
         Arc::clone(&resolution_ctx.vector_store_provider),
         hybrid_search,
+        mcb_domain::registry::metrics::resolve_metrics_provider(
+            &mcb_domain::registry::metrics::MetricsProviderConfig::new(
+                mcb_utils::constants::DEFAULT_METRICS_PROVIDER,
+            ),
+        )
+        .ok()?,
         ExecutionFlow::ServerHybrid,
+        false,
+        0,
     )
     .ok()?;
     let state = bootstrap.into_mcb_state();
@@ -26,6 +26,7 @@ async fn test_clear_index(#[case] collection: Option<String>, #[case] should_suc
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -24,6 +24,7 @@ async fn test_get_indexing_status_success() {
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
@@ -46,6 +46,7 @@ async fn test_index_codebase(
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     };
 
     let result = handler.handle(Parameters(args)).await;
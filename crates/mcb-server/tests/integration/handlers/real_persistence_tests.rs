@@ -424,6 +424,16 @@ async fn test_real_search_empty_project_returns_empty_not_error() -> TestResult
         filters: None,
         limit: Some(10),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
@@ -504,6 +514,16 @@ async fn test_real_search_memory_enriches_origin_context_fields() -> TestResult
         filters: None,
         limit: Some(10),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
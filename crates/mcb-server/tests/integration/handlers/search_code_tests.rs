@@ -32,6 +32,9 @@ async fn test_search_code_success() {
         state.mcp_server.memory_service(),
         hybrid_search,
         state.mcp_server.indexing_service(),
+        state.mcp_server.session_context_service(),
+        state.mcp_server.search_feedback_service(),
+        state.mcp_server.usage_service(),
     );
 
     let args = SearchArgs {
@@ -41,6 +44,16 @@ async fn test_search_code_success() {
         collection: Some("test".to_owned()),
         limit: Some(10),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         extensions: None,
@@ -71,6 +84,9 @@ async fn test_search_code_empty_query() {
         state.mcp_server.memory_service(),
         hybrid_search,
         state.mcp_server.indexing_service(),
+        state.mcp_server.session_context_service(),
+        state.mcp_server.search_feedback_service(),
+        state.mcp_server.usage_service(),
     );
 
     let args = SearchArgs {
@@ -80,6 +96,16 @@ async fn test_search_code_empty_query() {
         collection: Some("test".to_owned()),
         limit: Some(10),
         min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         extensions: None,
@@ -95,3 +121,54 @@ async fn test_search_code_empty_query() {
     assert!(!response.content.is_empty(), "response should have content");
     assert!(response.is_error.unwrap_or(false));
 }
+
+#[rstest]
+#[tokio::test]
+async fn test_search_code_invalid_path_glob() {
+    let Some((state, _services_temp_dir)) = create_test_mcb_state().await else {
+        return;
+    };
+    let Some(hybrid_search) = resolve_default_hybrid_search() else {
+        return;
+    };
+    let handler = SearchHandler::new(
+        state.mcp_server.search_service(),
+        state.mcp_server.memory_service(),
+        hybrid_search,
+        state.mcp_server.indexing_service(),
+        state.mcp_server.session_context_service(),
+        state.mcp_server.search_feedback_service(),
+        state.mcp_server.usage_service(),
+    );
+
+    let args = SearchArgs {
+        query: "test query".to_owned(),
+        org_id: None,
+        resource: SearchResource::Code,
+        collection: Some("test".to_owned()),
+        limit: Some(10),
+        min_score: None,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: Some("src/".to_owned()),
+        path_glob: Some("src/[".to_owned()),
+        language: Some("rust".to_owned()),
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
+        tags: None,
+        session_id: None,
+        extensions: None,
+        filters: None,
+        token: None,
+        repo_id: None,
+        repo_path: None,
+    };
+
+    let result = handler.handle(Parameters(args)).await;
+
+    let response = result.expect("search handler should return structured error response");
+    assert!(response.is_error.unwrap_or(false));
+}
@@ -1,5 +1,5 @@
 use axum::http::{HeaderMap, HeaderValue};
-use mcb_server::auth::{extract_api_key, is_admin_auth_exempt_path};
+use mcb_server::auth::{extract_api_key, is_admin_auth_exempt_path, is_admin_tool};
 use mcb_utils::constants::http::HTTP_HEADER_AUTHORIZATION;
 use rstest::rstest;
 
@@ -43,3 +43,12 @@ fn admin_auth_exempt_path_keeps_health_protected() {
     assert!(!is_admin_auth_exempt_path("/health"));
     assert!(!is_admin_auth_exempt_path("/api/health"));
 }
+
+#[rstest]
+#[case("get_chunk", true)]
+#[case("rebuild_text_index", true)]
+#[case("search_code", false)]
+#[case("server_stats", false)]
+fn admin_tools_are_limited_to_index_internals(#[case] tool: &str, #[case] admin: bool) {
+    assert_eq!(is_admin_tool(tool), admin);
+}
@@ -49,3 +49,20 @@ fn safe_internal_error_never_leaks_sensitive_detail(#[case] detail: &str) {
     assert_eq!(mcp.message, "internal server error");
     assert!(!mcp.message.contains(detail));
 }
+
+// ─── Stable error codes are machine-readable ─────────────────────────
+
+#[rstest]
+#[case(Error::embedding("rate limit exceeded"), "MCB-EMB-429", true)]
+#[case(Error::not_found("item"), "MCB-NOT-FOUND", false)]
+fn error_codes_are_attached(#[case] err: Error, #[case] code: &str, #[case] retryable: bool) {
+    let mcp = to_opaque_mcp_error(&err);
+    let data = mcp.data.expect("error data");
+    assert_eq!(data["code"], code);
+    assert_eq!(data["retryable"], retryable);
+
+    let result = to_contextual_tool_error(err);
+    let structured = result.structured_content.expect("structured content");
+    assert_eq!(structured["code"], code);
+    assert_eq!(structured["retryable"], retryable);
+}
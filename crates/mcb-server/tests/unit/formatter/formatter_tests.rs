@@ -10,6 +10,8 @@ use mcb_domain::ports::{IndexingResult, IndexingStatus};
 use mcb_domain::utils::tests::search_fixtures::{
     create_test_search_result, create_test_search_results,
 };
+use mcb_domain::utils::text::extract_text_from;
+use mcb_domain::value_objects::ScoreExplanation;
 use mcb_server::formatter::ResponseFormatter;
 use rstest::rstest;
 
@@ -65,6 +67,32 @@ fn search_result_with_long_code_is_truncated_in_preview() {
     assert!(resp.is_ok());
 }
 
+#[rstest]
+#[case(None, false)]
+#[case(Some(ScoreExplanation { recency_boost: Some(0.95), ..ScoreExplanation::dense(0.8) }), true)]
+fn search_result_shows_breakdown_only_when_explained(
+    #[case] explanation: Option<ScoreExplanation>,
+    #[case] expect_breakdown: bool,
+) {
+    let mut result = create_test_search_result("src/auth.rs", "fn login() {}", 0.76, 1);
+    result.explanation = explanation;
+
+    let resp = ResponseFormatter::format_search_response(
+        "login",
+        &[result],
+        Duration::from_millis(10),
+        10,
+    )
+    .expect("format search response");
+    let text = extract_text_from(&resp.content);
+
+    assert_eq!(text.contains("Score Breakdown"), expect_breakdown);
+    if expect_breakdown {
+        assert!(text.contains("dense 0.800 · recency ×0.950"), "{text}");
+        assert!(!text.contains("bm25"), "{text}");
+    }
+}
+
 // ─── Indexing responses ──────────────────────────────────────────────
 
 #[rstest]
@@ -0,0 +1,66 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::AnalyzeRepoArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+use tempfile::TempDir;
+
+#[rstest]
+#[case(None, "path could not be resolved")]
+#[case(Some(""), "path")]
+#[case(Some("/nonexistent/mcb-repo"), "not a directory")]
+#[tokio::test]
+async fn analyze_repo_rejects_invalid_requests(
+    #[case] path: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.analyze_repo_handler();
+    let request = AnalyzeRepoArgs {
+        path: path.map(str::to_owned),
+        repo_path: None,
+    };
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(expected));
+    Ok(())
+}
+
+#[tokio::test]
+async fn analyze_repo_recommends_local_setup_for_small_directory() -> TestResult {
+    let temp = TempDir::new()?;
+    std::fs::write(
+        temp.path().join("lib.rs"),
+        "pub fn one() -> u32 {\n    1\n}\n",
+    )?;
+    std::fs::write(temp.path().join("main.rs"), "fn main() {}\n")?;
+    std::fs::write(temp.path().join("tool.py"), "def run():\n    return 1\n")?;
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.analyze_repo_handler();
+    let request = AnalyzeRepoArgs {
+        path: Some(temp.path().display().to_string()),
+        repo_path: None,
+    };
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(!result.is_error.unwrap_or(false));
+    let analysis: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    assert_eq!(analysis["profile"]["size"]["files"], 3);
+    assert!(analysis["profile"]["churn"].is_null());
+    let recommendation = &analysis["recommendation"];
+    assert_eq!(recommendation["vector_store"], "edgevec");
+    assert_eq!(recommendation["title_vectors"], true);
+    assert_eq!(
+        recommendation["supported_extensions"],
+        serde_json::json!(["rs", "py"])
+    );
+    let snippet = recommendation["config_snippet"]
+        .as_str()
+        .unwrap_or_default();
+    assert!(snippet.starts_with("settings:"));
+    assert!(snippet.contains("supported_extensions: [rs, py]"));
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::ids::SessionId;
+use mcb_server::args::GetMoreContextArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(session_id: Option<&str>, collection: Option<&str>) -> GetMoreContextArgs {
+    GetMoreContextArgs {
+        query: "error handling".to_owned(),
+        token_budget: Some(500),
+        reset: None,
+        collection: collection.map(str::to_owned),
+        session_id: session_id.map(SessionId::from),
+        repo_id: None,
+    }
+}
+
+#[rstest]
+#[case(None, Some("repo"), "session_id")]
+#[case(Some("s-ctx-1"), None, "collection")]
+#[tokio::test]
+async fn get_more_context_requires_session_and_collection(
+    #[case] session_id: Option<&str>,
+    #[case] collection: Option<&str>,
+    #[case] missing: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.context_handler();
+
+    let result = handler
+        .handle(Parameters(args(session_id, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(missing));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn session_context_tracks_and_resets_seen_chunks() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let service = state.mcp_server.session_context_service();
+    let session = "s-ctx-shared";
+
+    service.mark_returned(session, &["a".to_owned(), "b".to_owned(), "a".to_owned()]);
+    assert_eq!(service.seen_count(session), 2);
+
+    service.reset(session);
+    assert_eq!(service.seen_count(session), 0);
+    Ok(())
+}
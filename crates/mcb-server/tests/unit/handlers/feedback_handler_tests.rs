@@ -0,0 +1,56 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::ids::SessionId;
+use mcb_server::args::SearchFeedbackArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(chunk_id: &str, collection: Option<&str>) -> SearchFeedbackArgs {
+    SearchFeedbackArgs {
+        query: "retry policy".to_owned(),
+        chunk_id: chunk_id.to_owned(),
+        file_path: "src/retry.rs".to_owned(),
+        relevant: true,
+        collection: collection.map(str::to_owned),
+        session_id: Some(SessionId::from("s-feedback-1")),
+        repo_id: None,
+    }
+}
+
+#[rstest]
+#[case("chunk-1", None, "collection")]
+#[case("", Some("repo"), "chunk_id")]
+#[tokio::test]
+async fn search_feedback_rejects_incomplete_requests(
+    #[case] chunk_id: &str,
+    #[case] collection: Option<&str>,
+    #[case] missing: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.feedback_handler();
+
+    let result = handler
+        .handle(Parameters(args(chunk_id, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(missing));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn search_feedback_records_judgement() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.feedback_handler();
+
+    let result = handler
+        .handle(Parameters(args("chunk-1", Some("feedback-repo"))))
+        .await?;
+
+    assert!(!result.is_error.unwrap_or(false));
+    let text = extract_text(&result);
+    assert!(text.contains("feedback_id"));
+    assert!(text.contains("chunk-1"));
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::FindDuplicatesArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(
+    min_similarity: Option<f64>,
+    min_lines: Option<usize>,
+    limit: Option<usize>,
+    collection: Option<&str>,
+) -> FindDuplicatesArgs {
+    FindDuplicatesArgs {
+        min_similarity,
+        min_lines,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        limit,
+        collection: collection.map(str::to_owned),
+        session_id: None,
+        repo_id: None,
+        repo_path: None,
+    }
+}
+
+#[rstest]
+#[case(None, None, None, None, "collection")]
+#[case(Some(1.5), None, None, Some("repo"), "min_similarity")]
+#[case(Some(-0.1), None, None, Some("repo"), "min_similarity")]
+#[case(None, Some(0), None, Some("repo"), "min_lines")]
+#[case(None, None, Some(0), Some("repo"), "limit")]
+#[case(None, None, Some(501), Some("repo"), "limit")]
+#[tokio::test]
+async fn find_duplicates_rejects_invalid_requests(
+    #[case] min_similarity: Option<f64>,
+    #[case] min_lines: Option<usize>,
+    #[case] limit: Option<usize>,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.find_duplicates_handler();
+
+    let result = handler
+        .handle(Parameters(args(
+            min_similarity,
+            min_lines,
+            limit,
+            collection,
+        )))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).to_lowercase().contains(expected));
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use mcb_domain::entities::test_link::{TestLink, TestLinkReason};
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::CollectionId;
+use mcb_server::args::FindTestsArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(symbol: &str, limit: Option<usize>, collection: Option<&str>) -> FindTestsArgs {
+    FindTestsArgs {
+        symbol: symbol.to_owned(),
+        limit,
+        collection: collection.map(str::to_owned),
+        session_id: None,
+        repo_id: None,
+        repo_path: None,
+    }
+}
+
+#[rstest]
+#[case("parse", None, None, "collection")]
+#[case("", None, Some("repo"), "symbol")]
+#[case("parse", Some(0), Some("repo"), "limit")]
+#[case("parse", Some(101), Some("repo"), "limit")]
+#[tokio::test]
+async fn find_tests_rejects_invalid_requests(
+    #[case] symbol: &str,
+    #[case] limit: Option<usize>,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.find_tests_handler();
+
+    let result = handler
+        .handle(Parameters(args(symbol, limit, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).to_lowercase().contains(expected));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn find_tests_lists_linked_tests() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let collection = CollectionId::from_name("find_tests_repo").to_string();
+    let link = TestLink {
+        collection: collection.clone(),
+        test_file: "tests/config.rs".to_owned(),
+        test_name: "reads_config".to_owned(),
+        start_line: 4,
+        end_line: 9,
+        target: "parse_config".to_owned(),
+        reason: TestLinkReason::Call,
+    };
+    state
+        .mcp_server
+        .test_link_repository()
+        .replace_file_links(&collection, "tests/config.rs", &[link])
+        .await?;
+
+    let result = state
+        .mcp_server
+        .find_tests_handler()
+        .handle(Parameters(args(
+            "parse_config",
+            None,
+            Some("find_tests_repo"),
+        )))
+        .await?;
+
+    assert!(!result.is_error.unwrap_or(false));
+    let body: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    assert_eq!(body["total"], 1);
+    assert_eq!(body["tests"][0]["test_name"], "reads_config");
+    assert_eq!(body["tests"][0]["reason"], "call");
+    Ok(())
+}
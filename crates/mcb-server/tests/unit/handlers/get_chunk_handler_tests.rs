@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::CollectionId;
+use mcb_server::args::GetChunkArgs;
+use mcb_server::handlers::GetChunkHandler;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(id: &str, collection: Option<&str>, include_vector: bool) -> GetChunkArgs {
+    GetChunkArgs {
+        id: id.to_owned(),
+        collection: collection.map(str::to_owned),
+        include_vector: Some(include_vector),
+        repo_id: None,
+    }
+}
+
+#[rstest]
+#[case("", Some("repo"), "id")]
+#[case("chunk-1", None, "collection")]
+#[case("missing", Some("get-chunk-missing"), "not found")]
+#[tokio::test]
+async fn get_chunk_rejects_invalid_or_unknown_chunks(
+    #[case] id: &str,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = GetChunkHandler::new(Arc::clone(&state.vector_store));
+
+    let result = handler
+        .handle(Parameters(args(id, collection, false)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).to_lowercase().contains(expected));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn get_chunk_returns_stored_metadata_and_location() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let content = "fn rotate_refresh_token() {}";
+    let embedding = state.embedding_provider.embed(content).await?;
+    let collection = CollectionId::from_name("get_chunk_repo");
+    state
+        .vector_store
+        .create_collection(&collection, embedding.dimensions)
+        .await?;
+    state
+        .vector_store
+        .upsert_vectors(
+            &collection,
+            &["chunk-1".to_owned()],
+            &[embedding],
+            vec![HashMap::from([
+                ("content".to_owned(), serde_json::json!(content)),
+                ("file_path".to_owned(), serde_json::json!("src/auth.rs")),
+            ])],
+        )
+        .await?;
+    let handler = GetChunkHandler::new(Arc::clone(&state.vector_store));
+
+    let result = handler
+        .handle(Parameters(args("chunk-1", Some("get-chunk-repo"), false)))
+        .await?;
+    let chunk: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+
+    assert_eq!(chunk["content"], content);
+    assert_eq!(chunk["metadata"]["file_path"], "src/auth.rs");
+    assert!(
+        chunk
+            .get("location")
+            .is_some_and(serde_json::Value::is_object)
+    );
+    assert!(
+        chunk.get("vector").is_none(),
+        "vector only on request: {chunk}"
+    );
+    Ok(())
+}
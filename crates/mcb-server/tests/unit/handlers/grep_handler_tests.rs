@@ -0,0 +1,46 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::GrepArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(pattern: &str, regex: bool, collection: Option<&str>) -> GrepArgs {
+    GrepArgs {
+        pattern: pattern.to_owned(),
+        regex: Some(regex),
+        case_sensitive: None,
+        semantic_query: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        max_matches: None,
+        limit: None,
+        collection: collection.map(str::to_owned),
+        session_id: None,
+        repo_id: None,
+    }
+}
+
+#[rstest]
+#[case("fn main", false, None, "collection")]
+#[case("", false, Some("repo"), "pattern")]
+#[case("(unclosed", true, Some("repo"), "Invalid grep pattern")]
+#[tokio::test]
+async fn grep_rejects_invalid_requests(
+    #[case] pattern: &str,
+    #[case] regex: bool,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.grep_handler();
+
+    let result = handler
+        .handle(Parameters(args(pattern, regex, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(expected));
+    Ok(())
+}
@@ -1,4 +1,32 @@
 //! Handler unit tests.
+/// Analyze-repo handler unit tests.
+pub mod analyze_repo_handler_tests;
+/// Context handler unit tests.
+pub mod context_handler_tests;
 pub mod entities;
+/// Feedback handler unit tests.
+pub mod feedback_handler_tests;
+/// Find-duplicates handler unit tests.
+pub mod find_duplicates_handler_tests;
+/// Find-tests handler unit tests.
+pub mod find_tests_handler_tests;
+/// Get-chunk handler unit tests.
+pub mod get_chunk_handler_tests;
+/// Grep handler unit tests.
+pub mod grep_handler_tests;
+/// Module graph handler unit tests.
+pub mod module_graph_handler_tests;
 /// Project handler unit tests.
 pub mod project_handler_tests;
+/// Read-file handler unit tests.
+pub mod read_file_handler_tests;
+/// Rebuild-text-index handler unit tests.
+pub mod rebuild_text_index_handler_tests;
+/// Stats handler unit tests.
+pub mod stats_handler_tests;
+/// Summarize-changes handler unit tests.
+pub mod summarize_changes_handler_tests;
+/// Validate-architecture handler unit tests.
+pub mod validate_architecture_handler_tests;
+/// Workspace handler unit tests.
+pub mod workspace_handler_tests;
@@ -0,0 +1,78 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::{ImpactOfChangeArgs, ModuleDependenciesArgs};
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn dependencies_args(path: &str, collection: Option<&str>) -> ModuleDependenciesArgs {
+    ModuleDependenciesArgs {
+        path: path.to_owned(),
+        collection: collection.map(str::to_owned),
+        session_id: None,
+        repo_id: None,
+        repo_path: None,
+    }
+}
+
+fn impact_args(
+    paths: &[&str],
+    max_depth: Option<usize>,
+    collection: Option<&str>,
+) -> ImpactOfChangeArgs {
+    ImpactOfChangeArgs {
+        paths: paths.iter().map(|p| (*p).to_owned()).collect(),
+        max_depth,
+        collection: collection.map(str::to_owned),
+        session_id: None,
+        repo_id: None,
+        repo_path: None,
+    }
+}
+
+#[rstest]
+#[case("src/lib.rs", None, "collection")]
+#[case("", Some("repo"), "path")]
+#[case("../outside.rs", Some("repo"), "workspace-relative")]
+#[case("src/never_indexed.rs", Some("module-graph-repo"), "not indexed")]
+#[tokio::test]
+async fn module_dependencies_rejects_invalid_requests(
+    #[case] path: &str,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.module_dependencies_handler();
+
+    let result = handler
+        .handle(Parameters(dependencies_args(path, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).to_lowercase().contains(expected));
+    Ok(())
+}
+
+#[rstest]
+#[case(&["src/lib.rs"], None, None, "collection")]
+#[case(&[], None, Some("repo"), "paths")]
+#[case(&["src/lib.rs"], Some(0), Some("repo"), "max_depth")]
+#[case(&["src/lib.rs"], Some(11), Some("repo"), "max_depth")]
+#[case(&["src/never_indexed.rs"], None, Some("module-graph-repo"), "not indexed")]
+#[tokio::test]
+async fn impact_of_change_rejects_invalid_requests(
+    #[case] paths: &[&str],
+    #[case] max_depth: Option<usize>,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.impact_of_change_handler();
+
+    let result = handler
+        .handle(Parameters(impact_args(paths, max_depth, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).to_lowercase().contains(expected));
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::{CollectionId, DistanceMetric};
+use mcb_server::args::ReadFileArgs;
+use mcb_utils::constants::lang::CHUNKER_VERSION;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(path: &str, start_line: Option<u32>, collection: Option<&str>) -> ReadFileArgs {
+    ReadFileArgs {
+        path: path.to_owned(),
+        start_line,
+        end_line: None,
+        max_bytes: None,
+        collection: collection.map(str::to_owned),
+        session_id: None,
+        repo_id: None,
+        repo_path: None,
+    }
+}
+
+#[rstest]
+#[case("src/lib.rs", None, None, "collection")]
+#[case("src/lib.rs", Some(0), Some("repo"), "start_line")]
+#[case("../outside.rs", None, Some("repo"), "workspace-relative")]
+#[case("src/never_indexed.rs", None, Some("read-file-repo"), "not found")]
+#[tokio::test]
+async fn read_file_rejects_invalid_or_unauthorized_requests(
+    #[case] path: &str,
+    #[case] start_line: Option<u32>,
+    #[case] collection: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.read_file_handler();
+
+    let result = handler
+        .handle(Parameters(args(path, start_line, collection)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).to_lowercase().contains(expected));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn read_file_reads_disk_only_from_the_indexed_root() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let root = tempfile::tempdir()?;
+    std::fs::create_dir(root.path().join("src"))?;
+    std::fs::write(root.path().join("src/lib.rs"), "pub fn live() {}\n")?;
+    let collection = CollectionId::from_name("read_file_root_repo");
+    let embedding = state.embedding_provider.embed("pub fn stored() {}").await?;
+    state
+        .vector_store
+        .create_collection(&collection, embedding.dimensions)
+        .await?;
+    state
+        .vector_store
+        .insert_vectors(
+            &collection,
+            &[embedding],
+            vec![HashMap::from([
+                (
+                    "content".to_owned(),
+                    serde_json::json!("pub fn stored() {}"),
+                ),
+                ("file_path".to_owned(), serde_json::json!("src/lib.rs")),
+                ("start_line".to_owned(), serde_json::json!(1)),
+                ("language".to_owned(), serde_json::json!("rust")),
+            ])],
+        )
+        .await?;
+    state
+        .collection_manifests
+        .record_build(
+            &collection.to_string(),
+            CHUNKER_VERSION,
+            DistanceMetric::Cosine,
+            "fastembed",
+            &root.path().to_string_lossy(),
+        )
+        .await?;
+    let handler = state.mcp_server.read_file_handler();
+
+    let result = handler
+        .handle(Parameters(args(
+            "src/lib.rs",
+            None,
+            Some("read-file-root-repo"),
+        )))
+        .await?;
+    assert!(
+        !result.is_error.unwrap_or(false),
+        "{}",
+        extract_text(&result)
+    );
+    let slice: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    assert_eq!(slice["source"], "disk");
+    assert_eq!(slice["content"], "pub fn live() {}");
+
+    // A client-chosen root outside the indexed one is refused, not read from.
+    let mut outside = args("src/lib.rs", None, Some("read-file-root-repo"));
+    outside.repo_path = Some("/".to_owned());
+    let result = handler.handle(Parameters(outside)).await?;
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains("was indexed from"));
+    Ok(())
+}
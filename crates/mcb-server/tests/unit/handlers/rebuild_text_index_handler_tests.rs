@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use mcb_domain::entities::CodeChunk;
+use mcb_domain::ports::HybridSearchProvider;
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_domain::value_objects::CollectionId;
+use mcb_providers::hybrid_search::HybridSearchEngine;
+use mcb_server::args::RebuildTextIndexArgs;
+use mcb_server::handlers::RebuildTextIndexHandler;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn handler(
+    state: &mcb_server::state::McbState,
+) -> (RebuildTextIndexHandler, Arc<HybridSearchEngine>) {
+    let hybrid_search = Arc::new(HybridSearchEngine::new());
+    let handler = RebuildTextIndexHandler::new(
+        Arc::clone(&state.vector_store),
+        Arc::clone(&hybrid_search) as Arc<dyn HybridSearchProvider>,
+    );
+    (handler, hybrid_search)
+}
+
+fn args(collection: Option<&str>) -> RebuildTextIndexArgs {
+    RebuildTextIndexArgs {
+        collection: collection.map(str::to_owned),
+        repo_id: None,
+    }
+}
+
+#[rstest]
+#[tokio::test]
+async fn rebuild_text_index_requires_a_collection() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let (handler, _) = handler(state);
+
+    let result = handler.handle(Parameters(args(None))).await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains("collection"));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn rebuild_text_index_reads_stored_chunks_without_embedding() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let chunks = [
+        ("src/auth.rs", 1, "fn rotate_refresh_token() {}"),
+        ("src/auth.rs", 10, "fn revoke_session() {}"),
+        ("src/db.rs", 1, "fn open_pool() {}"),
+    ];
+    let mut embeddings = Vec::new();
+    for (_, _, content) in &chunks {
+        embeddings.push(state.embedding_provider.embed(content).await?);
+    }
+    let collection = CollectionId::from_name("rebuild_text_index_repo");
+    state
+        .vector_store
+        .create_collection(&collection, embeddings[0].dimensions)
+        .await?;
+    state
+        .vector_store
+        .upsert_vectors(
+            &collection,
+            &[
+                "chunk-1".to_owned(),
+                "chunk-2".to_owned(),
+                "chunk-3".to_owned(),
+            ],
+            &embeddings,
+            chunks
+                .iter()
+                .map(|(path, line, content)| {
+                    HashMap::from([
+                        ("file_path".to_owned(), serde_json::json!(path)),
+                        ("start_line".to_owned(), serde_json::json!(line)),
+                        ("content".to_owned(), serde_json::json!(content)),
+                    ])
+                })
+                .collect(),
+        )
+        .await?;
+    let (handler, hybrid_search) = handler(state);
+
+    // A second rebuild replaces the index instead of adding to it.
+    for _ in 0..2 {
+        let result = handler
+            .handle(Parameters(args(Some("rebuild-text-index-repo"))))
+            .await?;
+        assert!(
+            !result.is_error.unwrap_or(false),
+            "{}",
+            extract_text(&result)
+        );
+        let rebuild: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+        assert_eq!(rebuild["files"], 2);
+        assert_eq!(rebuild["chunks"], 3);
+    }
+
+    let stats = hybrid_search.get_stats().await;
+    assert_eq!(
+        stats["collections"]["rebuild-text-index-repo"]["total_documents"],
+        3
+    );
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn rebuild_text_index_keeps_the_index_when_chunks_cannot_be_read() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let embedding = state.embedding_provider.embed("fn open_pool() {}").await?;
+    let collection = CollectionId::from_name("rebuild_text_index_partial");
+    state
+        .vector_store
+        .create_collection(&collection, embedding.dimensions)
+        .await?;
+    // The second record has no file path, so listing files cannot reach it.
+    state
+        .vector_store
+        .insert_vectors(
+            &collection,
+            &[embedding.clone(), embedding],
+            vec![
+                HashMap::from([
+                    ("file_path".to_owned(), serde_json::json!("src/db.rs")),
+                    ("start_line".to_owned(), serde_json::json!(1)),
+                    ("content".to_owned(), serde_json::json!("fn open_pool() {}")),
+                ]),
+                HashMap::from([("content".to_owned(), serde_json::json!("fn orphan() {}"))]),
+            ],
+        )
+        .await?;
+    let (handler, hybrid_search) = handler(state);
+    let previous = CodeChunk {
+        id: "previous".to_owned(),
+        content: "fn previous() {}".to_owned(),
+        file_path: "src/old.rs".to_owned(),
+        start_line: 1,
+        end_line: 1,
+        language: "rust".to_owned(),
+        metadata: serde_json::Value::Null,
+    };
+    hybrid_search
+        .index_chunks("rebuild-text-index-partial", &[previous])
+        .await?;
+
+    let result = handler
+        .handle(Parameters(args(Some("rebuild-text-index-partial"))))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains("left unchanged"));
+    let stats = hybrid_search.get_stats().await;
+    assert_eq!(
+        stats["collections"]["rebuild-text-index-partial"]["total_documents"],
+        1
+    );
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use mcb_domain::ports::{MetricsProviderExt, MetricsSnapshotProvider};
+use mcb_domain::registry::metrics::{MetricsProviderConfig, resolve_metrics_provider};
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::ServerStatsArgs;
+use mcb_server::handlers::stats::{StatsHandler, cache_hit_rates, provider_latencies};
+use mcb_utils::constants::DEFAULT_METRICS_PROVIDER;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn recorder() -> TestResult<Arc<dyn MetricsSnapshotProvider>> {
+    Ok(resolve_metrics_provider(&MetricsProviderConfig::new(
+        DEFAULT_METRICS_PROVIDER,
+    ))?)
+}
+
+#[rstest]
+#[tokio::test]
+async fn cache_hit_rate_folds_hits_and_misses() -> TestResult {
+    let metrics = recorder()?;
+    for hit in [true, true, true, false] {
+        metrics.record_cache_access(hit, "embedding").await?;
+    }
+
+    let rates = cache_hit_rates(&metrics.snapshot());
+    assert_eq!(rates.len(), 1);
+    assert_eq!(rates[0].cache_type, "embedding");
+    assert_eq!((rates[0].hits, rates[0].misses), (3, 1));
+    assert_eq!(rates[0].hit_rate, Some(0.75));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn latency_percentiles_are_reported_in_milliseconds() -> TestResult {
+    let metrics = recorder()?;
+    for ms in 1..=100 {
+        metrics
+            .record_embedding_latency(Duration::from_millis(ms), "ollama")
+            .await?;
+    }
+    metrics
+        .record_vector_store_latency(Duration::from_millis(4), "edgevec", "search")
+        .await?;
+    metrics
+        .record_search_latency(Duration::from_millis(9), "ignored")
+        .await?;
+
+    let latencies = provider_latencies(&metrics.snapshot());
+    assert_eq!(latencies.len(), 2, "only provider histograms are reported");
+
+    let embedding = latencies
+        .iter()
+        .find(|l| l.kind == "embedding")
+        .ok_or("embedding latency missing")?;
+    assert_eq!(embedding.provider, "ollama");
+    assert_eq!(embedding.samples, 100);
+    assert!((embedding.p50_ms - 50.0).abs() < 1e-6);
+    assert!((embedding.p95_ms - 95.0).abs() < 1e-6);
+
+    let store = latencies
+        .iter()
+        .find(|l| l.kind == "vector_store")
+        .ok_or("vector store latency missing")?;
+    assert_eq!(store.operation.as_deref(), Some("search"));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn server_stats_returns_json_report() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = StatsHandler::new(Arc::clone(&state.vector_store), recorder()?);
+
+    let result = handler
+        .handle(Parameters(ServerStatsArgs {
+            include_raw: Some(true),
+        }))
+        .await?;
+    let report: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+
+    for key in ["collection_count", "collections", "cache", "latency", "raw"] {
+        assert!(report.get(key).is_some(), "missing `{key}` in {report}");
+    }
+    Ok(())
+}
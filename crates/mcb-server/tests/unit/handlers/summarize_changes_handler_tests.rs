@@ -0,0 +1,100 @@
+use mcb_domain::utils::tests::git_helpers::{create_test_repo, run_git};
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::SummarizeChangesArgs;
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+const BEFORE: &str = "\
+fn parse_config(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    Some(trimmed.to_owned())
+}
+
+fn old_helper(values: &[u32]) -> u32 {
+    values.iter().copied().sum::<u32>()
+}
+";
+
+const AFTER: &str = "\
+fn parse_config(input: &str) -> Option<String> {
+    let trimmed = input.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_owned())
+}
+
+fn render_report(lines: &[String]) -> String {
+    lines.join(\"\\n\")
+}
+";
+
+fn args(
+    base_ref: Option<&str>,
+    head_ref: Option<&str>,
+    repo_path: Option<&str>,
+) -> SummarizeChangesArgs {
+    SummarizeChangesArgs {
+        base_ref: base_ref.map(str::to_owned),
+        head_ref: head_ref.map(str::to_owned),
+        collection: None,
+        repo_id: None,
+        repo_path: repo_path.map(str::to_owned),
+    }
+}
+
+#[rstest]
+#[case(Some("main"), None, None, "repo_path")]
+#[case(None, Some("HEAD"), Some("."), "requires base_ref")]
+#[case(Some(""), None, Some("."), "base_ref")]
+#[case(None, None, Some("."), "collection")]
+#[tokio::test]
+async fn summarize_changes_rejects_invalid_requests(
+    #[case] base_ref: Option<&str>,
+    #[case] head_ref: Option<&str>,
+    #[case] repo_path: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.summarize_changes_handler();
+
+    let result = handler
+        .handle(Parameters(args(base_ref, head_ref, repo_path)))
+        .await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(expected));
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn summarize_changes_reports_symbol_changes_between_commits() -> TestResult {
+    let dir = create_test_repo()?;
+    std::fs::write(dir.path().join("lib.rs"), BEFORE)?;
+    run_git(dir.path(), &["add", "."])?;
+    run_git(dir.path(), &["commit", "-m", "Add lib"])?;
+    std::fs::write(dir.path().join("lib.rs"), AFTER)?;
+    run_git(dir.path(), &["commit", "-am", "Rework lib"])?;
+
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.summarize_changes_handler();
+    let repo_path = dir.path().to_string_lossy();
+
+    let result = handler
+        .handle(Parameters(args(Some("HEAD~1"), None, Some(&repo_path))))
+        .await?;
+
+    assert!(!result.is_error.unwrap_or(false));
+    let summary: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    let symbols = &summary["files"][0]["symbols"];
+    let change_of = |name: &str| {
+        symbols
+            .as_array()
+            .and_then(|s| s.iter().find(|s| s["name"] == name))
+            .map(|s| s["change"].clone())
+    };
+    assert_eq!(summary["files"][0]["path"], "lib.rs");
+    assert_eq!(change_of("parse_config"), Some("modified".into()));
+    assert_eq!(change_of("old_helper"), Some("removed".into()));
+    assert_eq!(change_of("render_report"), Some("added".into()));
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::{AdrCheckArgs, ValidateArchitectureArgs, ValidateSeverity};
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+use tempfile::TempDir;
+
+const LIB_RS: &str = "\
+pub fn first(values: &[u32]) -> u32 {
+    *values.first().unwrap()
+}
+
+pub fn last(values: &[u32]) -> u32 {
+    *values.last().expect(\"values must not be empty\")
+}
+";
+
+fn args(path: Option<&str>) -> ValidateArchitectureArgs {
+    ValidateArchitectureArgs {
+        path: path.map(str::to_owned),
+        collection: None,
+        validators: Some(vec!["quality".to_owned()]),
+        rules: None,
+        min_severity: None,
+        repo_id: None,
+        repo_path: None,
+    }
+}
+
+fn workspace() -> TestResult<TempDir> {
+    let temp = TempDir::new()?;
+    std::fs::write(
+        temp.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    )?;
+    let src = temp.path().join("crates/app/src");
+    std::fs::create_dir_all(&src)?;
+    std::fs::write(
+        temp.path().join("crates/app/Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )?;
+    std::fs::write(src.join("lib.rs"), LIB_RS)?;
+    Ok(temp)
+}
+
+#[rstest]
+#[case(None, None, None, "path could not be resolved")]
+#[case(Some(""), None, None, "path")]
+#[case(Some("/nonexistent/mcb-workspace"), None, None, "not a directory")]
+#[case(None, Some("other-repo"), Some("mcb"), "not the current repository")]
+#[tokio::test]
+async fn validate_architecture_rejects_invalid_requests(
+    #[case] path: Option<&str>,
+    #[case] collection: Option<&str>,
+    #[case] repo_id: Option<&str>,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.validate_architecture_handler();
+    let request = ValidateArchitectureArgs {
+        collection: collection.map(str::to_owned),
+        repo_id: repo_id.map(str::to_owned),
+        repo_path: repo_id.map(|_| ".".to_owned()),
+        ..args(path)
+    };
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(expected));
+    Ok(())
+}
+
+#[rstest]
+#[case(None, None, &["QUAL001", "QUAL002"])]
+#[case(Some(vec!["QUAL002"]), None, &["QUAL002"])]
+#[case(Some(vec!["QUAL"]), Some(ValidateSeverity::Error), &[])]
+#[tokio::test]
+async fn validate_architecture_filters_rules_and_severity(
+    #[case] rules: Option<Vec<&str>>,
+    #[case] min_severity: Option<ValidateSeverity>,
+    #[case] expected: &[&str],
+) -> TestResult {
+    let temp = workspace()?;
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.validate_architecture_handler();
+    let request = ValidateArchitectureArgs {
+        rules: rules.map(|rules| rules.into_iter().map(str::to_owned).collect()),
+        min_severity,
+        ..args(Some(&temp.path().to_string_lossy()))
+    };
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(!result.is_error.unwrap_or(false));
+    let report: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    let mut ids: Vec<&str> = report["violations"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v["id"].as_str())
+        .collect();
+    ids.sort_unstable();
+    ids.dedup();
+    assert_eq!(ids, expected);
+    assert_eq!(report["passed"], true);
+    assert_eq!(report["errors"], 0);
+    Ok(())
+}
+
+#[rstest]
+#[tokio::test]
+async fn adr_check_runs_the_adr_validator() -> TestResult {
+    let temp = TempDir::new()?;
+    let adr_dir = temp.path().join("docs/adr");
+    std::fs::create_dir_all(&adr_dir)?;
+    std::fs::write(
+        adr_dir.join("01-cache.md"),
+        "# ADR 1: Cache\n\n## Status\n\nProposed\n\n## Decision\n\nCache.\n",
+    )?;
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.validate_architecture_handler();
+    let request: ValidateArchitectureArgs = AdrCheckArgs {
+        path: Some(temp.path().to_string_lossy().into_owned()),
+        rules: None,
+        min_severity: None,
+        repo_id: None,
+        repo_path: None,
+    }
+    .into();
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(!result.is_error.unwrap_or(false));
+    let report: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    let mut ids: Vec<&str> = report["violations"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v["id"].as_str())
+        .collect();
+    ids.sort_unstable();
+    assert_eq!(ids, ["ADR001", "ADR004"]);
+    assert_eq!(report["passed"], false);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use mcb_domain::utils::tests::mcp_assertions::extract_text;
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::args::{WorkspaceAction, WorkspaceArgs};
+use rmcp::handler::server::wrapper::Parameters;
+use rstest::rstest;
+
+fn args(
+    action: WorkspaceAction,
+    workspace: Option<&str>,
+    collection: Option<&str>,
+) -> WorkspaceArgs {
+    WorkspaceArgs {
+        action,
+        workspace: workspace.map(str::to_owned),
+        collection: collection.map(str::to_owned),
+        query: None,
+        limit: None,
+        repo_id: None,
+    }
+}
+
+#[rstest]
+#[case(
+    args(WorkspaceAction::Add, None, Some("svc-a")),
+    "workspace is required"
+)]
+#[case(
+    args(WorkspaceAction::Add, Some("ws-handler"), None),
+    "collection could not be resolved"
+)]
+#[case(
+    args(WorkspaceAction::Search, Some("ws-handler"), None),
+    "query is required"
+)]
+#[case(
+    args(WorkspaceAction::Add, Some("ws-handler"), Some("bad/name")),
+    "collection"
+)]
+#[tokio::test]
+async fn workspace_rejects_invalid_requests(
+    #[case] request: WorkspaceArgs,
+    #[case] expected: &str,
+) -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.workspace_handler();
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains(expected));
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_add_list_and_remove_collections() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.workspace_handler();
+
+    for name in ["ws-handler-api", "ws-handler-web"] {
+        let request = args(
+            WorkspaceAction::Add,
+            Some("ws-handler-roundtrip"),
+            Some(name),
+        );
+        let result = handler.handle(Parameters(request)).await?;
+        assert!(!result.is_error.unwrap_or(false));
+    }
+    let result = handler
+        .handle(Parameters(args(WorkspaceAction::List, None, None)))
+        .await?;
+    let workspaces: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    let workspace = workspaces
+        .as_array()
+        .and_then(|all| all.iter().find(|w| w["name"] == "ws-handler-roundtrip"))
+        .ok_or("workspace not listed")?;
+    let names: Vec<&str> = workspace["collections"]
+        .as_array()
+        .ok_or("collections missing")?
+        .iter()
+        .filter_map(|member| member["name"].as_str())
+        .collect();
+    assert_eq!(names, ["ws-handler-api", "ws-handler-web"]);
+
+    let request = args(
+        WorkspaceAction::Remove,
+        Some("ws-handler-roundtrip"),
+        Some("ws-handler-web"),
+    );
+    let result = handler.handle(Parameters(request)).await?;
+    let removed: serde_json::Value = serde_json::from_str(&extract_text(&result))?;
+    assert_eq!(removed["removed"], true);
+    Ok(())
+}
+
+#[tokio::test]
+async fn workspace_search_reports_unknown_workspace() -> TestResult {
+    let state = crate::utils::test_fixtures::shared_mcb_state()?;
+    let handler = state.mcp_server.workspace_handler();
+    let mut request = args(WorkspaceAction::Search, Some("ws-handler-missing"), None);
+    request.query = Some("parse config".to_owned());
+
+    let result = handler.handle(Parameters(request)).await?;
+
+    assert!(result.is_error.unwrap_or(false));
+    assert!(extract_text(&result).contains("ws-handler-missing"));
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use mcb_domain::error::{Error, Result};
+use mcb_domain::ports::{DependencyHealth, HealthProbe};
+use mcb_server::health::ReadinessChecker;
+use rstest::rstest;
+
+struct FakeProbe {
+    name: &'static str,
+    healthy: bool,
+    calls: AtomicUsize,
+}
+
+impl FakeProbe {
+    fn new(name: &'static str, healthy: bool) -> Arc<Self> {
+        Arc::new(Self {
+            name,
+            healthy,
+            calls: AtomicUsize::new(0),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl HealthProbe for FakeProbe {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    async fn probe(&self) -> Result<()> {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        if self.healthy {
+            Ok(())
+        } else {
+            Err(Error::internal(format!("{} down", self.name)))
+        }
+    }
+}
+
+struct SlowProbe;
+
+#[async_trait::async_trait]
+impl HealthProbe for SlowProbe {
+    fn name(&self) -> &str {
+        "slow"
+    }
+
+    async fn probe(&self) -> Result<()> {
+        tokio::time::sleep(Duration::from_secs(5)).await;
+        Ok(())
+    }
+}
+
+fn checker(ttl: Duration) -> ReadinessChecker {
+    ReadinessChecker::new(Duration::from_millis(200), ttl)
+}
+
+#[rstest]
+#[case(true, true, true, "ready")]
+#[case(true, false, true, "degraded")]
+#[case(false, true, false, "not_ready")]
+#[tokio::test]
+async fn readiness_depends_on_critical_probes_only(
+    #[case] critical_healthy: bool,
+    #[case] optional_healthy: bool,
+    #[case] ready: bool,
+    #[case] status: &str,
+) {
+    let checker = checker(Duration::ZERO)
+        .with_probe(FakeProbe::new("database", critical_healthy), true)
+        .with_probe(FakeProbe::new("cache", optional_healthy), false);
+
+    let report = checker.check().await;
+
+    assert_eq!(report.ready, ready);
+    assert_eq!(report.status, status);
+    assert_eq!(report.dependencies.len(), 2);
+    assert!(report.dependencies[0].critical);
+    assert_eq!(report.dependencies[1].check.name, "cache");
+}
+
+#[rstest]
+#[tokio::test]
+async fn readiness_results_are_cached_within_ttl() {
+    let probe = FakeProbe::new("embedding", true);
+    let checker = checker(Duration::from_secs(60)).with_probe(probe.clone(), true);
+
+    let _ = checker.check().await;
+    let _ = checker.check().await;
+
+    assert_eq!(probe.calls.load(Ordering::SeqCst), 1);
+}
+
+#[rstest]
+#[tokio::test]
+async fn slow_probe_times_out_as_unhealthy() {
+    let checker = checker(Duration::ZERO).with_probe(Arc::new(SlowProbe), true);
+
+    let report = checker.check().await;
+
+    assert!(!report.ready);
+    let dep = &report.dependencies[0];
+    assert_eq!(dep.check.status, DependencyHealth::Unhealthy);
+    assert!(
+        dep.check
+            .message
+            .as_deref()
+            .is_some_and(|m| m.contains("timed out")),
+        "got: {:?}",
+        dep.check.message
+    );
+}
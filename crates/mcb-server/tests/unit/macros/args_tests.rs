@@ -17,6 +17,16 @@ fn search(query: &str, min_score: Option<f32>, collection: Option<&str>) -> Sear
         filters: None,
         limit: Some(10),
         min_score,
+        conversation_weight: None,
+        diversify_by: None,
+        diversity_lambda: None,
+        recency_half_life_days: None,
+        path_prefix: None,
+        path_glob: None,
+        language: None,
+        exclude_paths: None,
+        exclude_languages: None,
+        explain: None,
         tags: None,
         session_id: None,
         token: None,
@@ -37,6 +47,7 @@ fn index(path: Option<&str>, collection: Option<&str>) -> IndexArgs {
         follow_symlinks: None,
         token: None,
         repo_id: None,
+        org_id: None,
     }
 }
 
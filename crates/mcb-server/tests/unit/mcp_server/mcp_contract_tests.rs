@@ -21,13 +21,21 @@ use mcb_utils::constants::protocol::{
 use crate::utils::http_mcp::{McpTestContext, post_mcp_str};
 
 const EXPECTED_TOOLS: &[&str] = &[
+    "adr_check",
     "analyze_code",
     "analyze_impact",
+    "analyze_repo",
     "clear_index",
     "compare_branches",
     "entity",
+    "find_duplicates",
+    "find_tests",
+    "get_chunk",
     "get_memories",
+    "get_more_context",
     "get_session",
+    "grep",
+    "impact_of_change",
     "index_repo",
     "index_status",
     "inject_context",
@@ -38,13 +46,21 @@ const EXPECTED_TOOLS: &[&str] = &[
     "log_delegation",
     "log_tool_call",
     "memory_timeline",
+    "module_dependencies",
     "project",
+    "read_file",
+    "rebuild_text_index",
     "search_code",
+    "search_feedback",
     "search_memory",
+    "server_stats",
     "start_session",
     "store_memory",
+    "summarize_changes",
     "summarize_session",
+    "validate_architecture",
     "validate_code",
+    "workspace",
 ];
 
 async fn fetch_tool_list() -> Result<Vec<serde_json::Value>, Box<dyn std::error::Error>> {
@@ -93,9 +109,9 @@ fn provenance_context(delegated: bool) -> ToolExecutionContext {
 
 #[rstest]
 #[tokio::test]
-async fn exactly_24_tools_registered() -> Result<(), Box<dyn std::error::Error>> {
+async fn exactly_39_tools_registered() -> Result<(), Box<dyn std::error::Error>> {
     let tools = fetch_tool_list().await?;
-    assert_eq!(tools.len(), 24, "tool count contract changed");
+    assert_eq!(tools.len(), 39, "tool count contract changed");
     Ok(())
 }
 
@@ -152,6 +152,7 @@ mod handler_error {
             follow_symlinks: None,
             token: None,
             repo_id: None,
+            org_id: None,
         };
 
         let err = handler
@@ -123,6 +123,19 @@ fn schema_required_fields_include_contract(
     }
 }
 
+#[rstest]
+#[case("search", required_names(&schema_json::<SearchArgs>()), vec!["query", "resource"])]
+#[case("index", required_names(&schema_json::<IndexArgs>()), vec!["action"])]
+#[case("vcs", required_names(&schema_json::<VcsArgs>()), vec!["action"])]
+fn schema_required_fields_exclude_optional_fields(
+    #[case] schema_name: &str,
+    #[case] mut required: Vec<String>,
+    #[case] expected: Vec<&str>,
+) {
+    required.sort();
+    assert_eq!(required, expected, "schema '{schema_name}' required fields");
+}
+
 #[rstest]
 #[case("memory", schema_json::<MemoryArgs>(), vec!["action", "resource", "data", "session_id"])]
 #[case("session", schema_json::<SessionArgs>(), vec!["action", "session_id", "data"])]
@@ -15,6 +15,8 @@ pub mod utils;
 
 /// Auth unit tests.
 pub mod auth_tests;
+/// Readiness checker unit tests.
+pub mod health_tests;
 /// `McbState` unit tests.
 pub mod state_tests;
 
@@ -0,0 +1,212 @@
+//! Idempotency keys: retried mutating calls return the stored result.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_providers::database::seaorm::repos::SeaOrmIdempotencyRepository;
+use mcb_server::tools::idempotency::IdempotencyStore;
+use rmcp::model::{CallToolRequestParams, CallToolResult, Content};
+use rstest::rstest;
+use sea_orm::Database;
+
+async fn store(window_secs: u64) -> TestResult<IdempotencyStore> {
+    let db = Database::connect(mcb_utils::constants::SQLITE_MEMORY_DSN).await?;
+    mcb_domain::registry::database::migrate_up(Box::new(db.clone()), None).await?;
+    Ok(IdempotencyStore::new(
+        Arc::new(SeaOrmIdempotencyRepository::new(Arc::new(db))),
+        window_secs,
+    ))
+}
+
+async fn counted_call(
+    calls: &AtomicUsize,
+    is_error: bool,
+) -> Result<CallToolResult, rmcp::ErrorData> {
+    let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+    let content = vec![Content::text(format!("call {call}"))];
+    Ok(if is_error {
+        CallToolResult::error(content)
+    } else {
+        CallToolResult::success(content)
+    })
+}
+
+#[tokio::test]
+async fn retry_with_same_key_returns_first_result() -> TestResult {
+    let store = store(3600).await?;
+    let calls = AtomicUsize::new(0);
+
+    let first = store
+        .run(
+            "acme",
+            "index_repo",
+            "k1",
+            "h1",
+            counted_call(&calls, false),
+        )
+        .await?;
+    let retry = store
+        .run(
+            "acme",
+            "index_repo",
+            "k1",
+            "h1",
+            counted_call(&calls, false),
+        )
+        .await?;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(retry, first);
+    Ok(())
+}
+
+#[rstest]
+#[case("globex", "index_repo", "k1")]
+#[case("acme", "clear_index", "k1")]
+#[case("acme", "index_repo", "k2")]
+#[tokio::test]
+async fn keys_are_scoped_by_org_and_tool(
+    #[case] org_id: &str,
+    #[case] tool_name: &str,
+    #[case] key: &str,
+) -> TestResult {
+    let store = store(3600).await?;
+    let calls = AtomicUsize::new(0);
+
+    store
+        .run(
+            "acme",
+            "index_repo",
+            "k1",
+            "h1",
+            counted_call(&calls, false),
+        )
+        .await?;
+    store
+        .run(org_id, tool_name, key, "h1", counted_call(&calls, false))
+        .await?;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    Ok(())
+}
+
+#[tokio::test]
+async fn tool_errors_are_not_stored() -> TestResult {
+    let store = store(3600).await?;
+    let calls = AtomicUsize::new(0);
+
+    store
+        .run("acme", "index_repo", "k1", "h1", counted_call(&calls, true))
+        .await?;
+    let retry = store
+        .run(
+            "acme",
+            "index_repo",
+            "k1",
+            "h1",
+            counted_call(&calls, false),
+        )
+        .await?;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(retry.is_error, Some(false));
+    Ok(())
+}
+
+#[tokio::test]
+async fn retry_with_other_arguments_is_rejected() -> TestResult {
+    let store = store(3600).await?;
+    let calls = AtomicUsize::new(0);
+
+    store
+        .run(
+            "acme",
+            "index_repo",
+            "k1",
+            "h1",
+            counted_call(&calls, false),
+        )
+        .await?;
+    let err = store
+        .run(
+            "acme",
+            "index_repo",
+            "k1",
+            "h2",
+            counted_call(&calls, false),
+        )
+        .await
+        .expect_err("reused key with other arguments must fail");
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(err.code.0, -32602);
+    Ok(())
+}
+
+#[rstest]
+fn request_hash_ignores_argument_order() {
+    let a = serde_json::json!({ "path": ".", "options": { "a": 1, "b": 2 } });
+    let b = serde_json::json!({ "options": { "b": 2, "a": 1 }, "path": "." });
+    let c = serde_json::json!({ "path": "src" });
+
+    let hash = |v: &serde_json::Value| IdempotencyStore::request_hash(v.as_object());
+    assert_eq!(hash(&a), hash(&b));
+    assert_ne!(hash(&a), hash(&c));
+}
+
+#[rstest]
+#[case::read_only_tool(3600, "search_code")]
+#[case::keys_disabled(0, "index_repo")]
+#[tokio::test]
+async fn keys_that_would_be_ignored_are_rejected(
+    #[case] window_secs: u64,
+    #[case] tool_name: &str,
+) -> TestResult {
+    let store = store(window_secs).await?;
+
+    let err = store
+        .check_key(tool_name)
+        .expect_err("ignored key must be rejected");
+
+    assert_eq!(err.code.0, -32602);
+    Ok(())
+}
+
+#[rstest]
+#[case("index_repo")]
+#[case("workspace")]
+#[tokio::test]
+async fn keys_are_accepted_by_tools_that_write(#[case] tool_name: &str) -> TestResult {
+    store(3600).await?.check_key(tool_name)?;
+    Ok(())
+}
+
+#[rstest]
+#[case(serde_json::json!({ "path": ".", "idempotency_key": "k1" }), Some("k1"))]
+#[case(serde_json::json!({ "path": "." }), None)]
+#[case(serde_json::json!({ "idempotency_key": null }), None)]
+fn take_key_removes_key_from_arguments(
+    #[case] args: serde_json::Value,
+    #[case] expected: Option<&str>,
+) -> TestResult {
+    let mut request = CallToolRequestParams::new("index_repo")
+        .with_arguments(args.as_object().cloned().unwrap_or_default());
+
+    let key = IdempotencyStore::take_key(&mut request)?;
+
+    assert_eq!(key.as_deref(), expected);
+    let args = request.arguments.ok_or("arguments dropped")?;
+    assert!(!args.contains_key("idempotency_key"));
+    Ok(())
+}
+
+#[rstest]
+#[case(serde_json::json!({ "idempotency_key": 42 }))]
+#[case(serde_json::json!({ "idempotency_key": "  " }))]
+fn take_key_rejects_invalid_keys(#[case] args: serde_json::Value) {
+    let mut request = CallToolRequestParams::new("index_repo")
+        .with_arguments(args.as_object().cloned().unwrap_or_default());
+
+    assert!(IdempotencyStore::take_key(&mut request).is_err());
+}
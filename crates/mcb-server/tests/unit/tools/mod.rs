@@ -3,7 +3,15 @@ pub mod context_tests;
 /// Defaults and execution flow tests.
 pub mod defaults_tests;
 pub mod field_aliases_tests;
+/// Idempotency key tests.
+pub mod idempotency_tests;
+/// Read-only mode tests.
+pub mod read_only_tests;
+/// Request ID propagation tests.
+pub mod request_tracing_tests;
 /// Router dispatch tests.
 pub mod router_tests;
+/// Tool argument schema validation tests.
+pub mod schema_validation_tests;
 /// Tool invariant matrix tests.
 pub mod tool_invariant_matrix_tests;
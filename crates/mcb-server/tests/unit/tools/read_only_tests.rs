@@ -0,0 +1,96 @@
+//! Read-only mode: mutating tool calls are rejected before dispatch.
+
+use std::sync::Arc;
+
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_server::tools::read_only::{enforce_read_only, is_mutating_call};
+use mcb_server::tools::{ToolExecutionContext, route_tool_call};
+use rmcp::model::{CallToolRequestParams, JsonObject};
+use rstest::rstest;
+
+use crate::utils::test_fixtures::create_test_mcp_server;
+
+fn arguments(value: serde_json::Value) -> JsonObject {
+    value.as_object().cloned().unwrap_or_default()
+}
+
+#[rstest]
+#[case("index_repo", serde_json::json!({}), true)]
+#[case("clear_index", serde_json::json!({}), true)]
+#[case("store_memory", serde_json::json!({}), true)]
+#[case("summarize_session", serde_json::json!({ "session_id": "s1" }), false)]
+#[case("summarize_session", serde_json::json!({ "data": {} }), true)]
+#[case("workspace", serde_json::json!({ "action": "search" }), false)]
+#[case("workspace", serde_json::json!({ "action": "add" }), true)]
+#[case("entity", serde_json::json!({ "action": "list" }), false)]
+#[case("entity", serde_json::json!({ "action": "delete" }), true)]
+#[case("project", serde_json::json!({}), true)]
+#[case("rebuild_text_index", serde_json::json!({ "collection": "repo" }), true)]
+#[case("search_code", serde_json::json!({ "query": "parse" }), false)]
+#[case("index_status", serde_json::json!({}), false)]
+#[case("not_a_registered_tool", serde_json::json!({}), true)]
+fn classifies_mutating_calls(
+    #[case] tool_name: &str,
+    #[case] args: serde_json::Value,
+    #[case] mutating: bool,
+) {
+    assert_eq!(
+        is_mutating_call(tool_name, Some(&arguments(args))),
+        mutating
+    );
+}
+
+#[rstest]
+#[case("search_code", serde_json::json!({ "query": "parse", "repo_path": "/srv/untrusted" }))]
+#[case("read_file", serde_json::json!({ "path": "src/lib.rs", "repo_path": "/" }))]
+fn read_only_calls_drop_disk_paths(
+    #[case] tool_name: &str,
+    #[case] args: serde_json::Value,
+) -> TestResult {
+    let mut request =
+        CallToolRequestParams::new(tool_name.to_owned()).with_arguments(arguments(args));
+
+    enforce_read_only(&mut request)?;
+
+    let args = request.arguments.ok_or("arguments dropped")?;
+    assert_eq!(args.len(), 1);
+    assert!(!args.contains_key("repo_path"));
+    Ok(())
+}
+
+#[rstest]
+#[case("index_repo", serde_json::json!({ "path": "." }))]
+#[case("entity", serde_json::json!({ "action": "create", "resource": "org" }))]
+#[tokio::test]
+async fn read_only_router_rejects_mutating_calls(
+    #[case] tool_name: &str,
+    #[case] args: serde_json::Value,
+) -> TestResult {
+    let (server, _temp) = create_test_mcp_server().await?;
+    let mut handlers = Arc::new(server.with_read_only(true)).tool_handlers();
+    assert!(handlers.read_only);
+    let request =
+        CallToolRequestParams::new(tool_name.to_owned()).with_arguments(arguments(args.clone()));
+
+    let error = route_tool_call(request, &handlers, ToolExecutionContext::default())
+        .await
+        .expect_err(&format!(
+            "{tool_name}: should be rejected in read-only mode"
+        ));
+    assert!(
+        error.message.contains("read-only mode"),
+        "{}",
+        error.message
+    );
+
+    handlers.read_only = false;
+    let request = CallToolRequestParams::new(tool_name.to_owned()).with_arguments(arguments(args));
+    if let Err(error) = route_tool_call(request, &handlers, ToolExecutionContext::default()).await {
+        assert!(
+            !error.message.contains("read-only mode"),
+            "{}",
+            error.message
+        );
+    }
+    Ok(())
+}
@@ -0,0 +1,94 @@
+//! Request IDs are returned with tool results and errors and recorded in metrics.
+
+use std::sync::Arc;
+
+use mcb_domain::ports::MetricsSnapshotProvider;
+use mcb_domain::ports::providers::metrics::{TOOL_CALL_DURATION_METRIC, TOOL_CALL_FAILURES_METRIC};
+use mcb_domain::utils::tests::utils::TestResult;
+use mcb_infrastructure::infrastructure::InMemoryMetricsProvider;
+use mcb_server::tools::router::REQUEST_ID_FIELD;
+use mcb_server::tools::{ToolExecutionContext, ToolHandlers, route_tool_call};
+use rmcp::model::CallToolRequestParams;
+
+use crate::utils::test_fixtures::create_test_mcp_server;
+
+fn traced_context(request_id: &str) -> ToolExecutionContext {
+    ToolExecutionContext {
+        request_id: Some(request_id.to_owned()),
+        ..ToolExecutionContext::default()
+    }
+}
+
+async fn handlers_with_metrics() -> TestResult<(ToolHandlers, Arc<InMemoryMetricsProvider>)> {
+    let (server, _temp) = create_test_mcp_server().await?;
+    let metrics = Arc::new(InMemoryMetricsProvider::new());
+    let mut handlers = server.tool_handlers();
+    handlers.metrics = Arc::clone(&metrics) as _;
+    Ok((handlers, metrics))
+}
+
+#[tokio::test]
+async fn successful_call_returns_request_id_in_meta() -> TestResult {
+    let (handlers, metrics) = handlers_with_metrics().await?;
+    let request = CallToolRequestParams::new("server_stats");
+
+    let result = route_tool_call(request, &handlers, traced_context("req-ok")).await?;
+
+    let meta = result.meta.ok_or("missing _meta")?;
+    assert_eq!(
+        meta.get(REQUEST_ID_FIELD),
+        Some(&serde_json::json!("req-ok"))
+    );
+    let snapshot = metrics.snapshot();
+    assert!(snapshot.histograms.iter().any(|h| {
+        h.name == TOOL_CALL_DURATION_METRIC
+            && h.labels.get("tool").map(String::as_str) == Some("server_stats")
+            && h.labels.get("result").map(String::as_str) == Some("ok")
+    }));
+    assert!(
+        snapshot
+            .counters
+            .iter()
+            .all(|c| c.name != TOOL_CALL_FAILURES_METRIC)
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn failed_call_returns_request_id_in_error_data() -> TestResult {
+    let (handlers, metrics) = handlers_with_metrics().await?;
+    let request = CallToolRequestParams::new("no_such_tool");
+
+    let error = route_tool_call(request, &handlers, traced_context("req-err"))
+        .await
+        .expect_err("unknown tool should fail");
+
+    let data = error.data.ok_or("missing error data")?;
+    assert_eq!(data[REQUEST_ID_FIELD], "req-err");
+    let failures = metrics
+        .snapshot()
+        .counters
+        .into_iter()
+        .find(|c| c.name == TOOL_CALL_FAILURES_METRIC)
+        .ok_or("failure not counted")?;
+    assert_eq!(
+        failures.labels.get("request_id").map(String::as_str),
+        Some("req-err")
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn untraced_call_has_no_request_id() -> TestResult {
+    let (handlers, _metrics) = handlers_with_metrics().await?;
+    let request = CallToolRequestParams::new("server_stats");
+
+    let result = route_tool_call(request, &handlers, ToolExecutionContext::default()).await?;
+
+    assert!(
+        result
+            .meta
+            .is_none_or(|meta| !meta.contains_key(REQUEST_ID_FIELD))
+    );
+    Ok(())
+}
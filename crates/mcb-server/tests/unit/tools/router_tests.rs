@@ -7,7 +7,9 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use async_trait::async_trait;
-use mcb_domain::entities::vcs::{RefDiff, VcsBranch, VcsCommit, VcsRepository};
+use mcb_domain::entities::vcs::{
+    FileDiff, RefDiff, VcsBranch, VcsCommit, VcsRepository, WorkingTreeScope,
+};
 use mcb_domain::error::{Error, Result};
 use mcb_domain::value_objects::RepositoryId;
 use mcb_server::tools::{
@@ -56,6 +58,13 @@ impl mcb_domain::ports::VcsProvider for StubVcs {
     async fn list_files(&self, _: &VcsRepository, _: &str) -> Result<Vec<PathBuf>> {
         Ok(vec![])
     }
+    async fn file_last_modified(
+        &self,
+        _: &VcsRepository,
+        _: &str,
+    ) -> Result<HashMap<PathBuf, i64>> {
+        Ok(HashMap::new())
+    }
     async fn read_file(&self, _: &VcsRepository, _: &str, _: &Path) -> Result<String> {
         Ok(String::new())
     }
@@ -65,6 +74,13 @@ impl mcb_domain::ports::VcsProvider for StubVcs {
     async fn diff_refs(&self, _: &VcsRepository, _: &str, _: &str) -> Result<RefDiff> {
         Err(Error::vcs("not implemented"))
     }
+    async fn working_tree_changes(
+        &self,
+        _: &VcsRepository,
+        _: &WorkingTreeScope,
+    ) -> Result<Vec<FileDiff>> {
+        Ok(vec![])
+    }
     async fn list_repositories(&self, _: &Path) -> Result<Vec<VcsRepository>> {
         Ok(vec![])
     }
@@ -90,6 +106,7 @@ fn valid_ctx() -> ToolExecutionContext {
         delegated: Some(false),
         timestamp: Some(1),
         execution_flow: Some(ExecutionFlow::StdioOnly.to_string()),
+        request_id: None,
     }
 }
 
@@ -129,6 +146,7 @@ fn non_data_plane_tools_skip_provenance_check() {
         delegated: None,
         timestamp: None,
         execution_flow: Some(ExecutionFlow::StdioOnly.to_string()),
+        request_id: None,
     };
 
     assert!(validate_execution_context("validate_code", &empty).is_ok());
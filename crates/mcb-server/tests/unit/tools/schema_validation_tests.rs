@@ -0,0 +1,68 @@
+//! Tool arguments are validated against the published input schemas.
+
+use mcb_server::tools::schema_validation::compile_tool_schema;
+use mcb_server::tools::{compile_tool_schemas, validate_tool_arguments};
+use rmcp::model::JsonObject;
+use rstest::rstest;
+use serde_json::{Value, json};
+
+fn arguments(value: Value) -> JsonObject {
+    value.as_object().cloned().unwrap_or_default()
+}
+
+#[rstest]
+#[case("search_code", json!({ "query": "parse config", "limit": 5 }))]
+#[case("list_repos", json!({ "limit": null }))]
+#[case("index_status", json!({}))]
+#[case("unknown_tool", json!({ "limit": "many" }))]
+fn accepts_arguments_matching_schema(#[case] tool_name: &str, #[case] args: Value) {
+    assert!(validate_tool_arguments(tool_name, Some(&arguments(args))).is_ok());
+}
+
+#[rstest]
+fn lists_every_invalid_field() {
+    let args = arguments(json!({ "query": 7, "limit": "many" }));
+    let error = validate_tool_arguments("search_code", Some(&args)).expect_err("invalid args");
+
+    assert_eq!(error.code.0, -32602);
+    assert!(
+        error
+            .message
+            .starts_with("Invalid arguments for 'search_code'")
+    );
+    let data = error.data.expect("error data");
+    assert_eq!(data["code"], "MCB-INVALID-ARG");
+    let mut fields: Vec<&str> = data["fields"]
+        .as_array()
+        .expect("fields")
+        .iter()
+        .filter_map(|field| field["field"].as_str())
+        .collect();
+    fields.sort_unstable();
+    assert_eq!(fields, vec!["limit", "query"]);
+}
+
+#[rstest]
+fn reports_missing_required_fields() {
+    let args = arguments(json!({ "limit": 5 }));
+    let error = validate_tool_arguments("search_code", Some(&args)).expect_err("missing query");
+
+    assert!(error.message.contains("\"query\" is a required property"));
+}
+
+#[rstest]
+fn every_registered_schema_compiles() {
+    assert!(compile_tool_schemas().is_ok());
+}
+
+#[rstest]
+fn schema_that_does_not_compile_is_a_configuration_error() {
+    let error =
+        compile_tool_schema("broken_tool", &json!({ "type": 12 })).expect_err("invalid schema");
+
+    assert!(
+        error
+            .to_string()
+            .contains("input schema of tool 'broken_tool' does not compile")
+    );
+}
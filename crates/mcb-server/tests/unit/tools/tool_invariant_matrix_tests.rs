@@ -41,6 +41,7 @@ fn full_provenance_context() -> ToolExecutionContext {
         delegated: Some(false),
         timestamp: Some(1700000000),
         execution_flow: Some(EXECUTION_FLOW_STDIO_ONLY.to_owned()),
+        request_id: None,
     }
 }
 
@@ -1,6 +1,8 @@
 //! Transport unit tests.
 /// HTTP client transport tests.
 pub mod http_client_tests;
+/// Request correlation ID tests.
+pub mod request_id_tests;
 /// Stdio transport tests.
 pub mod stdio_tests;
 /// Streamable HTTP transport tests.
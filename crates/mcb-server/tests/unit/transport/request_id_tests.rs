@@ -0,0 +1,44 @@
+use axum::http::{HeaderMap, HeaderValue};
+use mcb_server::transport::resolve_request_id;
+use mcb_utils::constants::headers::HEADER_REQUEST_ID;
+use rstest::rstest;
+
+#[rstest]
+fn client_request_id_is_reused() {
+    let mut headers = HeaderMap::new();
+    headers.insert(HEADER_REQUEST_ID, HeaderValue::from_static(" req-42 "));
+
+    assert_eq!(resolve_request_id(Some(&headers)), "req-42");
+}
+
+#[rstest]
+#[case(None)]
+#[case(Some("has space"))]
+#[case(Some(""))]
+fn missing_or_invalid_request_id_is_generated(#[case] header: Option<&'static str>) {
+    let mut headers = HeaderMap::new();
+    if let Some(value) = header {
+        headers.insert(HEADER_REQUEST_ID, HeaderValue::from_static(value));
+    }
+
+    let id = resolve_request_id(Some(&headers));
+
+    assert!(uuid::Uuid::parse_str(&id).is_ok(), "{id}");
+}
+
+#[rstest]
+fn oversized_request_id_is_replaced() {
+    let mut headers = HeaderMap::new();
+    let long = "x".repeat(200);
+    headers.insert(
+        HEADER_REQUEST_ID,
+        HeaderValue::from_str(&long).expect("ascii header"),
+    );
+
+    assert_ne!(resolve_request_id(Some(&headers)), long);
+}
+
+#[rstest]
+fn stdio_calls_get_unique_ids() {
+    assert_ne!(resolve_request_id(None), resolve_request_id(None));
+}
@@ -113,16 +113,36 @@ pub async fn create_real_domain_services() -> Option<(McbState, tempfile::TempDi
         event_bus,
         embedding_provider: Arc::clone(&embedding_provider),
         vector_store_provider: Arc::clone(&vector_store_provider),
+        job_scheduler: Arc::new(
+            mcb_infrastructure::infrastructure::FairJobScheduler::new(
+                mcb_domain::ports::JobSchedulerPolicy::default(),
+            )
+            .ok()?,
+        ),
+        bulkheads: Arc::new(
+            mcb_infrastructure::infrastructure::SemaphoreBulkheads::new(
+                mcb_domain::ports::BulkheadPolicy::default(),
+            )
+            .ok()?,
+        ),
     };
 
-    // 7. Compose MCP server via Loco-style bootstrap (6-arg pure DI)
+    // 7. Compose MCP server via Loco-style bootstrap (7-arg pure DI)
     let bootstrap = build_mcp_server_bootstrap(
         &resolution_ctx,
         db,
         embedding_provider,
         vector_store_provider,
         hybrid_search,
+        mcb_domain::registry::metrics::resolve_metrics_provider(
+            &mcb_domain::registry::metrics::MetricsProviderConfig::new(
+                mcb_utils::constants::DEFAULT_METRICS_PROVIDER,
+            ),
+        )
+        .ok()?,
         ExecutionFlow::ServerHybrid,
+        false,
+        0,
     )
     .ok()?;
 
@@ -9,7 +9,9 @@
 
 use std::sync::Arc;
 
-use mcb_domain::ports::{EmbeddingProvider, VectorStoreProvider};
+use mcb_domain::ports::{
+    BulkheadPolicy, EmbeddingProvider, JobSchedulerPolicy, VectorStoreProvider,
+};
 use mcb_domain::registry::ServiceResolutionContext;
 use mcb_domain::registry::database::{DatabaseProviderConfig, resolve_database_provider};
 use mcb_domain::registry::embedding::{EmbeddingProviderConfig, resolve_embedding_provider};
@@ -21,6 +23,7 @@ use mcb_domain::registry::vector_store::{
     VectorStoreProviderConfig, resolve_vector_store_provider,
 };
 use mcb_domain::value_objects::Embedding;
+use mcb_infrastructure::infrastructure::{FairJobScheduler, SemaphoreBulkheads};
 use mcb_server::build_mcp_server_bootstrap;
 use mcb_server::mcp_server::McpServer;
 use mcb_server::state::McbState;
@@ -279,6 +282,8 @@ pub async fn create_test_mcp_server() -> Result<(McpServer, TempDir), Box<dyn st
         event_bus,
         embedding_provider: Arc::clone(&embedding_provider),
         vector_store_provider: Arc::clone(&vector_store_provider),
+        job_scheduler: Arc::new(FairJobScheduler::new(JobSchedulerPolicy::default())?),
+        bulkheads: Arc::new(SemaphoreBulkheads::new(BulkheadPolicy::default())?),
     };
 
     let bootstrap = build_mcp_server_bootstrap(
@@ -287,7 +292,14 @@ pub async fn create_test_mcp_server() -> Result<(McpServer, TempDir), Box<dyn st
         embedding_provider,
         vector_store_provider,
         hybrid_search,
+        mcb_domain::registry::metrics::resolve_metrics_provider(
+            &mcb_domain::registry::metrics::MetricsProviderConfig::new(
+                mcb_utils::constants::DEFAULT_METRICS_PROVIDER,
+            ),
+        )?,
         ExecutionFlow::ServerHybrid,
+        false,
+        0,
     )?;
     let server = Arc::unwrap_or_clone(bootstrap.mcp_server);
 
@@ -18,3 +18,6 @@ pub const DEFAULT_ADMIN_KEY_HEADER: &str = "X-Admin-Key";
 
 /// Constant value for `MIN_JWT_SECRET_LENGTH`.
 pub const MIN_JWT_SECRET_LENGTH: usize = 32;
+
+/// Environment variable holding the admin API key used by `mcb daemon status`.
+pub const ADMIN_API_KEY_ENV: &str = "MCB_ADMIN_API_KEY";
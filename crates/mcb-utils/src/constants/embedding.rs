@@ -85,6 +85,60 @@ pub const FASTEMBED_MAX_TOKENS: usize = 512;
 /// `FastEmbed` actor channel capacity.
 pub const FASTEMBED_ACTOR_CHANNEL_CAPACITY: usize = 100;
 
+// ============================================================================
+// Cost Projection (`mcb index --dry-run`)
+// ============================================================================
+
+/// Models priced by `mcb index --dry-run`, as
+/// `(provider, model, dimensions, USD per million input tokens)`.
+///
+/// List prices of the hosted APIs when this table was last updated; local
+/// providers cost nothing per token.
+pub const EMBEDDING_PRICE_TABLE: &[(&str, &str, usize, f64)] = &[
+    (
+        "fastembed",
+        FASTEMBED_DEFAULT_MODEL,
+        EMBEDDING_DIMENSION_FASTEMBED_DEFAULT,
+        0.0,
+    ),
+    (
+        "ollama",
+        OLLAMA_DEFAULT_MODEL,
+        EMBEDDING_DIMENSION_OLLAMA_NOMIC,
+        0.0,
+    ),
+    (
+        "openai",
+        "text-embedding-3-small",
+        EMBEDDING_DIMENSION_OPENAI_SMALL,
+        0.02,
+    ),
+    (
+        "openai",
+        "text-embedding-3-large",
+        EMBEDDING_DIMENSION_OPENAI_LARGE,
+        0.13,
+    ),
+    (
+        "voyageai",
+        "voyage-code-3",
+        EMBEDDING_DIMENSION_VOYAGEAI_CODE,
+        0.18,
+    ),
+    (
+        "anthropic",
+        "voyage-3",
+        EMBEDDING_DIMENSION_ANTHROPIC_DEFAULT,
+        0.06,
+    ),
+    (
+        "gemini",
+        "gemini-embedding-001",
+        EMBEDDING_DIMENSION_GEMINI,
+        0.15,
+    ),
+];
+
 // ============================================================================
 // Embedding API Field Names
 // ============================================================================
@@ -103,3 +157,19 @@ pub const EMBEDDING_PARAM_MODEL: &str = "model";
 
 /// Embedding API request field: input text.
 pub const EMBEDDING_PARAM_INPUT: &str = "input";
+
+// ============================================================================
+// First-run Setup (`mcb init`)
+// ============================================================================
+
+/// Environment variable holding the `OpenAI` API key.
+pub const OPENAI_API_KEY_ENV: &str = "OPENAI_API_KEY";
+
+/// `OpenAI` model written by `mcb init`.
+pub const OPENAI_INIT_MODEL: &str = "text-embedding-3-small";
+
+/// Ollama endpoint listing local models (used to detect a running server).
+pub const OLLAMA_TAGS_ENDPOINT: &str = "/api/tags";
+
+/// Timeout in seconds for provider detection probes during `mcb init`.
+pub const INIT_PROBE_TIMEOUT_SECS: u64 = 2;
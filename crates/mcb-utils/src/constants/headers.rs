@@ -33,6 +33,8 @@ define_str_consts! {
     HEADER_DELEGATED = "X-Delegated";
     /// HTTP header: org identifier.
     HEADER_ORG_ID = "X-Org-Id";
+    /// HTTP header: request correlation identifier.
+    HEADER_REQUEST_ID = "X-Request-Id";
 }
 
 /// All provenance header-to-context-key mappings.
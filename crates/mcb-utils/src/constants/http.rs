@@ -42,6 +42,12 @@ pub const HTTP_KEEPALIVE_SECS: u64 = 60;
 /// Maximum number of idle connections per host.
 pub const HTTP_MAX_IDLE_PER_HOST: usize = 10;
 
+/// Maximum number of concurrent requests per host (0 = unlimited).
+pub const HTTP_MAX_CONNECTIONS_PER_HOST: usize = 32;
+
+/// HTTP/2 PING interval used to keep pooled connections alive, in seconds.
+pub const HTTP2_KEEPALIVE_INTERVAL_SECS: u64 = 30;
+
 /// HTTP header name for Accept.
 pub const HTTP_HEADER_ACCEPT: &str = "Accept";
 
@@ -68,3 +74,9 @@ pub const PROVIDER_RETRY_COUNT: usize = 3;
 
 /// Default retry backoff for all provider API requests (milliseconds).
 pub const PROVIDER_RETRY_BACKOFF_MS: u64 = 500;
+
+/// Default cap for a single provider retry backoff (milliseconds).
+pub const PROVIDER_RETRY_MAX_BACKOFF_MS: u64 = 8_000;
+
+/// Default fraction of each provider retry backoff randomly subtracted.
+pub const PROVIDER_RETRY_JITTER: f64 = 0.2;
@@ -82,6 +82,12 @@ define_str_consts! {
     METADATA_KEY_SESSION_ID = "session_id";
     /// Metadata key for "`line_number`".
     METADATA_KEY_LINE_NUMBER = "line_number";
+    /// Metadata key for "`last_modified`".
+    METADATA_KEY_LAST_MODIFIED = "last_modified";
+    /// Metadata key for "`node_type`".
+    METADATA_KEY_NODE_TYPE = "node_type";
+    /// Metadata key for "`symbol_name`".
+    METADATA_KEY_SYMBOL_NAME = "symbol_name";
 }
 
 // ============================================================================
@@ -31,15 +31,49 @@ pub const LANG_PHP: &str = "php";
 pub const LANG_SWIFT: &str = "swift";
 /// Kotlin language identifier
 pub const LANG_KOTLIN: &str = "kotlin";
+/// Shell script language identifier
+pub const LANG_SHELL: &str = "shell";
+/// Dockerfile language identifier
+pub const LANG_DOCKERFILE: &str = "dockerfile";
+/// Makefile language identifier
+pub const LANG_MAKEFILE: &str = "makefile";
 /// Unknown/unsupported language identifier
 pub const LANG_UNKNOWN: &str = "unknown";
 
+/// Languages whose line comments start with `#`.
+pub const HASH_COMMENT_LANGUAGES: &[&str] = &[
+    LANG_PYTHON,
+    LANG_RUBY,
+    LANG_SHELL,
+    LANG_DOCKERFILE,
+    LANG_MAKEFILE,
+];
+
 /// Maximum chunks per file for language processing.
 pub const LANGUAGE_MAX_CHUNKS_PER_FILE: usize = 75;
 
 /// Priority threshold for chunk filtering.
 pub const LANGUAGE_PRIORITY_THRESHOLD: usize = 50;
 
+/// Longest chunk of a line-structured language (Dockerfile stage, Makefile
+/// rule, shell function); longer units are cut at blank lines.
+pub const LINE_CHUNK_MAX_LINES: usize = 50;
+
+/// Bytes read from a file without an extension to detect its language
+/// (shebang, Dockerfile `FROM`, Makefile rules).
+pub const LANGUAGE_SNIFF_BYTES: u64 = 1024;
+
+/// Version of the chunker output, recorded in each collection's manifest.
+///
+/// Bump it whenever a change moves chunk boundaries or alters chunk content
+/// (the golden snapshots in `mcb-providers/tests/fixtures/chunking` fail in
+/// that case), so collections indexed by an older chunker are flagged for
+/// re-indexing.
+pub const CHUNKER_VERSION: u32 = 2;
+
+/// UUID v5 namespace for stable chunk IDs (see `CodeChunk::stable_id`).
+pub const CHUNK_ID_NAMESPACE: &str = "mcb.chunk";
+
 // ============================================================================
 // Default Chunk Sizes
 // ============================================================================
@@ -121,6 +155,9 @@ pub const EXTENSION_LANG_MAP: &[(&[&str], &str)] = &[
     (&["php", "phtml"], LANG_PHP),
     (&["swift"], LANG_SWIFT),
     (&["kt", "kts"], LANG_KOTLIN),
+    (&["sh", "bash", "zsh", "ksh"], LANG_SHELL),
+    (&["dockerfile"], LANG_DOCKERFILE),
+    (&["mk"], LANG_MAKEFILE),
 ];
 
 /// Language to chunk size mapping (used by detection).
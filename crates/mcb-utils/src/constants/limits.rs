@@ -40,6 +40,9 @@ pub const DEFAULT_MAX_CONTEXT_TOKENS: usize = 2000;
 /// Estimated characters per token for size calculations.
 pub const CHARS_PER_TOKEN_ESTIMATE: usize = 4;
 
+/// Unseen candidates fetched per `get_more_context` call, on top of the session's seen count.
+pub const SESSION_CONTEXT_FETCH_LIMIT: usize = 20;
+
 /// Internal fetch multiplier applied to limit before filtering.
 pub const MEMORY_FETCH_MULTIPLIER: usize = 5;
 
@@ -48,3 +51,48 @@ pub const DEFAULT_TIMELINE_DEPTH: usize = 5;
 
 /// Maximum limit for observation list queries (pagination cap).
 pub const OBSERVATION_LIST_MAX_LIMIT: usize = 1000;
+
+/// Free space (bytes) under a store path below which `mcb doctor` warns (1 GiB).
+pub const DOCTOR_DISK_WARN_BYTES: u64 = 1024 * 1024 * 1024;
+
+/// Free space (bytes) under a store path below which `mcb doctor` fails (100 MiB).
+pub const DOCTOR_DISK_FAIL_BYTES: u64 = 100 * 1024 * 1024;
+
+/// TCP connect timeout (milliseconds) for `mcb doctor` reachability probes.
+pub const DOCTOR_PROBE_TIMEOUT_MS: u64 = 2000;
+
+/// Interval (milliseconds) at which `mcb index` polls indexing progress.
+pub const CLI_INDEX_PROGRESS_POLL_MS: u64 = 500;
+
+/// Interval (milliseconds) at which `mcb daemon stop` checks whether the process exited.
+pub const DAEMON_STOP_POLL_MS: u64 = 100;
+
+/// Grace period (milliseconds) after spawn before `mcb daemon start` checks the child is alive.
+pub const DAEMON_START_GRACE_MS: u64 = 500;
+
+/// Observations kept per histogram series by the in-memory metrics recorder.
+pub const METRICS_HISTOGRAM_WINDOW: usize = 1024;
+
+// ============================================================================
+// Job Scheduler
+// ============================================================================
+
+/// Units of scheduled work (searches, indexed files) allowed to run at once.
+pub const JOB_SCHEDULER_MAX_CONCURRENT: usize = 4;
+
+/// Slots only interactive work may take, so background indexing never
+/// occupies every slot.
+pub const JOB_SCHEDULER_RESERVED_INTERACTIVE: usize = 1;
+
+// ============================================================================
+// Bulkheads
+// ============================================================================
+
+/// Searches allowed to run at once in the search pool.
+pub const BULKHEAD_SEARCH_MAX_CONCURRENT: usize = 8;
+
+/// Files allowed to be indexed at once in the indexing pool.
+pub const BULKHEAD_INDEXING_MAX_CONCURRENT: usize = 3;
+
+/// Admin tool calls allowed to run at once in the admin pool.
+pub const BULKHEAD_ADMIN_MAX_CONCURRENT: usize = 2;
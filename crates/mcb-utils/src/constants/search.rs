@@ -24,6 +24,22 @@ pub const HYBRID_SEARCH_MULTIPLIER: usize = 3;
 /// Over-fetch multiplier for search filtering
 pub const SEARCH_OVERFETCH_MULTIPLIER: usize = 2;
 
+// ============================================================================
+// Title vectors (second embedding per chunk)
+// ============================================================================
+
+/// Weight of the title similarity when fused with the chunk similarity
+pub const TITLE_VECTOR_WEIGHT: f64 = 0.5;
+
+/// Candidates fetched per vector kind before fusion (limit * N)
+pub const TITLE_VECTOR_OVERFETCH_MULTIPLIER: usize = 2;
+
+/// Maximum characters of the signature line in a chunk title
+pub const TITLE_SIGNATURE_MAX_CHARS: usize = 200;
+
+/// Suffix of the companion collection name that holds title vectors
+pub const TITLE_COLLECTION_SUFFIX: &str = ":titles";
+
 // ============================================================================
 // RRF (Reciprocal Rank Fusion)
 // ============================================================================
@@ -42,3 +58,140 @@ pub const RRF_NORMALIZED_MAX: f32 = 1.0;
 
 /// Search duration threshold (milliseconds) for showing a slow-query warning.
 pub const SEARCH_SLOW_THRESHOLD_MS: u128 = 1000;
+
+// ============================================================================
+// Conversation-aware search
+// ============================================================================
+
+/// Recent session observations blended into a conversation-aware query
+pub const CONVERSATION_CONTEXT_OBSERVATIONS: usize = 3;
+
+/// Session observations considered before picking the most recent ones
+pub const CONVERSATION_CONTEXT_CANDIDATES: usize = 20;
+
+// ============================================================================
+// Search feedback (learning-to-rank)
+// ============================================================================
+
+/// Maximum fraction a file's score can move up or down from feedback
+pub const SEARCH_FEEDBACK_BOOST_SCALE: f64 = 0.2;
+
+/// Pseudo-vote prior that damps boosts for files with few judgements
+pub const SEARCH_FEEDBACK_PRIOR_VOTES: f64 = 5.0;
+
+/// Minimum judgements on a file before it receives a boost
+pub const SEARCH_FEEDBACK_MIN_VOTES: u64 = 2;
+
+// ============================================================================
+// Result diversification (MMR)
+// ============================================================================
+
+/// Default MMR trade-off between relevance (1.0) and diversity (0.0)
+pub const MMR_DEFAULT_LAMBDA: f32 = 0.7;
+
+/// Candidates fetched per requested result when diversifying
+pub const MMR_CANDIDATE_MULTIPLIER: usize = 5;
+
+/// Leading chunk lines scanned for a symbol definition
+pub const MMR_SYMBOL_SCAN_LINES: usize = 5;
+
+// ============================================================================
+// Recency boosting
+// ============================================================================
+
+/// Share of a result's score subject to recency decay
+pub const RECENCY_BOOST_WEIGHT: f64 = 0.3;
+
+/// Seconds per day, for converting commit ages to half-lives
+pub const RECENCY_SECONDS_PER_DAY: f64 = 86_400.0;
+
+// ============================================================================
+// Grep
+// ============================================================================
+
+/// Default cap on matching lines returned by `grep`
+pub const GREP_DEFAULT_MAX_MATCHES: usize = 100;
+
+/// Upper bound on indexed files scanned by one `grep`
+pub const GREP_MAX_FILES: usize = 10_000;
+
+/// Compiled size limit for user-supplied grep regexes, in bytes
+pub const GREP_REGEX_SIZE_LIMIT: usize = 1 << 20;
+
+/// Matching lines longer than this many characters are truncated
+pub const GREP_MAX_LINE_CHARS: usize = 300;
+
+/// Semantic candidates examined per requested result in combined grep mode
+pub const GREP_SEMANTIC_CANDIDATE_MULTIPLIER: usize = 5;
+
+// ============================================================================
+// Keyword index rebuild
+// ============================================================================
+
+/// Files listed by the first page of a `rebuild_text_index` read; each
+/// further page doubles it until every stored chunk is covered
+pub const REBUILD_TEXT_INDEX_PAGE_FILES: usize = 1_000;
+
+/// Upper bound on files one `rebuild_text_index` reads; larger collections
+/// fail instead of being partly re-indexed
+pub const REBUILD_TEXT_INDEX_MAX_FILES: usize = 1_000_000;
+
+// ============================================================================
+// File content retrieval
+// ============================================================================
+
+/// Default byte budget for `read_file` content (64 KiB)
+pub const READ_FILE_DEFAULT_MAX_BYTES: usize = 64 * 1024;
+
+/// Upper bound on the `read_file` byte budget (1 MiB)
+pub const READ_FILE_MAX_BYTES_LIMIT: usize = 1024 * 1024;
+
+// ============================================================================
+// Test links
+// ============================================================================
+
+/// Default number of linked tests returned by `find_tests`
+pub const FIND_TESTS_DEFAULT_LIMIT: usize = 20;
+
+/// Upper bound on linked tests returned by `find_tests`
+pub const FIND_TESTS_MAX_LIMIT: usize = 100;
+
+// ============================================================================
+// Module graph
+// ============================================================================
+
+/// Default import hops followed by `impact_of_change`
+pub const IMPACT_OF_CHANGE_DEFAULT_DEPTH: usize = 3;
+
+/// Upper bound on import hops followed by `impact_of_change`
+pub const IMPACT_OF_CHANGE_MAX_DEPTH: usize = 10;
+
+// ============================================================================
+// Duplicate detection
+// ============================================================================
+
+/// Default number of duplicate pairs returned by `find_duplicates`
+pub const FIND_DUPLICATES_DEFAULT_LIMIT: usize = 50;
+
+/// Upper bound on duplicate pairs returned by `find_duplicates`
+pub const FIND_DUPLICATES_MAX_LIMIT: usize = 500;
+
+/// Length, in normalized tokens, of the shingles compared between fragments
+pub const DUPLICATE_SHINGLE_TOKENS: usize = 5;
+
+/// Shingles shared by more fragments than this are treated as boilerplate
+/// and do not make fragments candidates for comparison
+pub const DUPLICATE_MAX_SHINGLE_POSTINGS: usize = 64;
+
+// ============================================================================
+// Index self-evaluation
+// ============================================================================
+
+/// Leading lines of a chunk searched for a doc comment to turn into a query
+pub const SELF_EVAL_DOC_SCAN_LINES: usize = 12;
+
+/// Fewest words a template-generated query may have
+pub const SELF_EVAL_MIN_QUERY_WORDS: usize = 2;
+
+/// Most words kept from a doc comment sentence
+pub const SELF_EVAL_MAX_QUERY_WORDS: usize = 16;
@@ -3,6 +3,9 @@
 //! Constants used by the application-layer use case services that live
 //! in service registry builders.
 
+use super::embedding::FASTEMBED_DEFAULT_MODEL;
+use super::values::PROVIDER_SLUG_FASTEMBED;
+
 // ============================================================================
 // MEMORY / SEARCH
 // ============================================================================
@@ -19,3 +22,41 @@ pub const MEMORY_COLLECTION_NAME: &str = "memories";
 
 /// Directories to skip during codebase indexing.
 pub const SKIP_DIRS: &[&str] = &[".git", "node_modules", "target", "__pycache__"];
+
+// ============================================================================
+// REPOSITORY ONBOARDING (`analyze_repo`)
+// ============================================================================
+
+/// Days of history counted as recent churn.
+pub const ONBOARDING_CHURN_WINDOW_DAYS: u32 = 30;
+
+/// Maximum commits walked when counting recent churn.
+pub const ONBOARDING_CHURN_MAX_COMMITS: usize = 5_000;
+
+/// Share of files changed within the churn window above which churn is high.
+pub const ONBOARDING_HIGH_CHURN_RATIO: f64 = 0.2;
+
+/// Largest repository (in chunks) recommended a local embedding model.
+pub const ONBOARDING_LOCAL_EMBEDDING_MAX_CHUNKS: usize = 20_000;
+
+/// Largest repository (in chunks) recommended the embedded vector store.
+pub const ONBOARDING_EMBEDDED_STORE_MAX_CHUNKS: usize = 200_000;
+
+/// Smallest repository (in chunks) recommended a distributed vector store.
+pub const ONBOARDING_DISTRIBUTED_STORE_MIN_CHUNKS: usize = 2_000_000;
+
+/// Local embedding model recommended for small repositories, as
+/// `(provider, model)`; must have a row in `EMBEDDING_PRICE_TABLE`.
+pub const ONBOARDING_LOCAL_EMBEDDING: (&str, &str) =
+    (PROVIDER_SLUG_FASTEMBED, FASTEMBED_DEFAULT_MODEL);
+
+/// Hosted embedding model recommended for large repositories, as
+/// `(provider, model)`; must have a row in `EMBEDDING_PRICE_TABLE`. The
+/// recommended config reads its API key from `OPENAI_API_KEY`.
+pub const ONBOARDING_HOSTED_EMBEDDING: (&str, &str) = ("openai", "text-embedding-3-small");
+
+/// Chunks per second embedded by a local model on a typical CPU.
+pub const ONBOARDING_LOCAL_CHUNKS_PER_SEC: f64 = 40.0;
+
+/// Chunks per second embedded by a hosted API with batched requests.
+pub const ONBOARDING_HOSTED_CHUNKS_PER_SEC: f64 = 400.0;
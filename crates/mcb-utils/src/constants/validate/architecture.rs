@@ -106,5 +106,15 @@ pub const CARGO_ARG_SEPARATOR: &str = "--";
 /// Cargo manifest filename.
 pub const CARGO_TOML_FILENAME: &str = "Cargo.toml";
 
+/// Cargo lockfile filename.
+pub const CARGO_LOCK_FILENAME: &str = "Cargo.lock";
+
+/// Version requirement matching any version.
+pub const CARGO_WILDCARD_VERSION: &str = "*";
+
+/// Manifest tables that declare dependencies.
+pub const CARGO_DEPENDENCY_TABLES: &[&str] =
+    &["dependencies", "dev-dependencies", "build-dependencies"];
+
 /// Clippy compiler-message reason string.
 pub const CLIPPY_REASON_COMPILER_MESSAGE: &str = "compiler-message";
@@ -132,4 +132,48 @@ define_str_consts! {
     VALIDATOR_LAYER_FLOW = "layer_flow";
     /// Validator: test quality.
     VALIDATOR_TEST_QUALITY = "test_quality";
+    /// Validator: public API surface drift.
+    VALIDATOR_API_SURFACE = "api_surface";
+    /// Validator: compiled WebAssembly plugins.
+    VALIDATOR_WASM_PLUGINS = "wasm_plugins";
+    /// Validator: module and crate dependency cycles.
+    VALIDATOR_CYCLES = "cycles";
+    /// Validator: public items unused outside their crate.
+    VALIDATOR_DEAD_API = "dead_api";
+    /// Validator: Cargo feature flag hygiene.
+    VALIDATOR_FEATURES = "features";
+    /// Validator: dependency policy (bans, licenses, versions).
+    VALIDATOR_DEPENDENCY_POLICY = "dependency_policy";
+    /// Validator: unsafe code and FFI boundaries.
+    VALIDATOR_UNSAFE_CODE = "unsafe_code";
+    /// Validator: public functions reached by tests.
+    VALIDATOR_TEST_COVERAGE = "test_coverage";
+    /// Validator: naming, size, complexity and pending comments of Python and TypeScript.
+    VALIDATOR_POLYGLOT = "polyglot";
+    /// Validator: function complexity growth against the complexity baseline.
+    VALIDATOR_COMPLEXITY_DELTA = "complexity_delta";
+    /// Validator: ADR documents against the ADR compliance rules.
+    VALIDATOR_ADR = "adr";
 }
+
+/// Validators whose findings depend on files other than the one flagged
+/// (duplicates, dependency graphs, cross-module usage). Incremental runs
+/// still scan the whole workspace for them and only filter their results.
+pub const CROSS_FILE_VALIDATORS: &[&str] = &[
+    VALIDATOR_ADR,
+    VALIDATOR_API_SURFACE,
+    VALIDATOR_CLEAN_ARCHITECTURE,
+    VALIDATOR_CYCLES,
+    VALIDATOR_DEAD_API,
+    VALIDATOR_DEPENDENCY,
+    VALIDATOR_DEPENDENCY_POLICY,
+    VALIDATOR_FEATURES,
+    VALIDATOR_LAYER_FLOW,
+    VALIDATOR_ORGANIZATION,
+    VALIDATOR_PORT_ADAPTER,
+    VALIDATOR_REFACTORING,
+    VALIDATOR_SSOT,
+    VALIDATOR_TEST_COVERAGE,
+    VALIDATOR_VISIBILITY,
+    VALIDATOR_WASM_PLUGINS,
+];
@@ -134,6 +134,78 @@ pub const PUB_TRAIT_REGEX: &str = r"pub\s+trait\s+([A-Z][a-zA-Z0-9_]*)";
 /// Regex for detecting `pub fn` / `pub async fn` declarations.
 pub const PUB_FN_REGEX: &str = r"pub\s+(?:async\s+)?fn\s+([a-z_][a-z0-9_]*)";
 
+/// Regex for `pub` item declarations in the public API surface
+/// (captures: 1 = item kind, 2 = name).
+pub const API_PUB_ITEM_REGEX: &str = r#"^\s*pub\s+(?:(?:async|const|unsafe|default|extern\s+"[^"]*")\s+)*(fn|struct|enum|union|trait|type|const|static|mod)\s+(\w+)"#;
+
+/// Regex for the start of a `pub use` re-export.
+pub const API_PUB_USE_REGEX: &str = r"^\s*pub\s+use\s";
+
+/// Regex for trait member functions, which carry no `pub` of their own
+/// (captures: 1 = name).
+pub const API_TRAIT_FN_REGEX: &str =
+    r#"^\s*(?:(?:async|const|unsafe|extern\s+"[^"]*")\s+)*fn\s+(\w+)"#;
+
+/// Regex for inherent and trait `impl` headers (captures: 1 = self type name).
+pub const API_IMPL_REGEX: &str =
+    r"^\s*(?:unsafe\s+)?impl(?:<[^{]*?>)?\s+(?:[^{]*?\s+for\s+)?(?:\w+::)*(\w+)";
+
+/// Regex for trait `impl` headers (captures: 1 = trait without its path,
+/// 2 = self type name).
+pub const API_TRAIT_IMPL_REGEX: &str =
+    r"^\s*(?:unsafe\s+)?impl(?:<[^{]*?>)?\s+(?:\w+::)*(\w+(?:<[^{]*?>)?)\s+for\s+(?:\w+::)*(\w+)";
+
+/// Regex for a `#[derive(...)]` attribute (captures: 1 = derived traits).
+pub const API_DERIVE_REGEX: &str = r"#\[derive\(([^\]]*)\)\]";
+
+/// Regex for the start of a `use` declaration of any visibility
+/// (captures: 1 = the use tree, possibly continued on later lines).
+pub const USE_DECLARATION_REGEX: &str = r"^\s*(?:pub(?:\s*\([^)]*\))?\s+)?use\s+(.*)$";
+
+/// Regex for a feature predicate inside `cfg`, `cfg_attr` or `cfg!`
+/// (captures: 1 = feature name).
+pub const FEATURE_PREDICATE_REGEX: &str = r#"\bfeature\s*=\s*"([^"]+)""#;
+
+/// Feature Cargo enables unless `default-features = false`.
+pub const CARGO_DEFAULT_FEATURE: &str = "default";
+
+/// Build-script environment variable prefix for enabled features.
+pub const CARGO_FEATURE_ENV_PREFIX: &str = "CARGO_FEATURE_";
+
+/// cfg flag set by docs.rs builds.
+pub const DOCSRS_CFG: &str = "docsrs";
+
+/// Crate-level nightly feature that documents every cfg automatically.
+pub const DOC_AUTO_CFG_FEATURE: &str = "doc_auto_cfg";
+
+/// Regex for an `unsafe` block (not an `unsafe fn`, `impl` or `trait`).
+pub const UNSAFE_BLOCK_REGEX: &str = r"\bunsafe\s*\{";
+
+/// Regex for `mem::transmute` and `transmute_copy` usage.
+pub const TRANSMUTE_REGEX: &str = r"\btransmute(?:_copy)?\b";
+
+/// Regex for an exported `extern` function (captures: 1 = function name).
+pub const EXTERN_FN_REGEX: &str = r#"^\s*pub\s+(?:unsafe\s+)?extern\s+(?:"[^"]*"\s+)?fn\s+(\w+)"#;
+
+/// Regex for a single-line string literal, raw or escaped.
+pub const STRING_LITERAL_REGEX: &str = r##"r#+"(?s:.*?)"#+|r"[^"]*"|"(?:[^"\\]|\\.)*""##;
+
+/// Doc section describing how a function reports failure.
+pub const ERRORS_DOC_SECTION: &str = "# Errors";
+
+/// Regex for a call site, including turbofish calls (captures: 1 = callee name).
+pub const CALL_SITE_REGEX: &str = r"\b([A-Za-z_][A-Za-z0-9_]*)\s*(?:::\s*<[^()]*>\s*)?\(";
+
+/// Comment opener of Python sources.
+pub const PYTHON_COMMENT_REGEX: &str = r"#";
+
+/// Comment openers of JavaScript/TypeScript sources: line, block, and the
+/// leading `*` of block continuation lines.
+pub const JS_COMMENT_REGEX: &str = r"//+|/\*+|^\s*\*";
+
+/// Markdown code fence delimiting doc-test examples.
+pub const DOC_TEST_FENCE: &str = "```";
+
 /// Regex for detecting example sections in documentation.
 pub const EXAMPLE_SECTION_REGEX: &str = r"#\s*Example";
 
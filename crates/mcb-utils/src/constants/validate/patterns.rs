@@ -160,3 +160,130 @@ pub const MCB_CRATE_PREFIX: &str = "mcb-";
 
 /// MCB dependency name prefix (without hyphen).
 pub const MCB_DEPENDENCY_PREFIX: &str = "mcb";
+
+// --- Project Configuration & Rule Plugins ---
+
+/// Workspace-root configuration file layered over the built-in defaults.
+pub const VALIDATE_PROJECT_CONFIG_FILE: &str = ".mcb-validate.toml";
+
+/// JSON Schema written next to the project configuration file.
+pub const VALIDATE_CONFIG_SCHEMA_FILE: &str = ".mcb-validate.schema.json";
+
+/// Separator between a rule plugin namespace and the rule ID (`ACME::SEC001`).
+pub const RULE_NAMESPACE_SEPARATOR: &str = "::";
+
+/// Input format version sent to WebAssembly validator plugins.
+pub const WASM_PLUGIN_API_VERSION: u32 = 1;
+
+/// Linear memory a WebAssembly plugin must export.
+pub const WASM_PLUGIN_EXPORT_MEMORY: &str = "memory";
+
+/// Plugin export `(len: i32) -> i32` allocating `len` bytes for the input.
+pub const WASM_PLUGIN_EXPORT_ALLOC: &str = "mcb_alloc";
+
+/// Plugin export `(ptr: i32, len: i32) -> i64` returning `(out_ptr << 32) | out_len`.
+pub const WASM_PLUGIN_EXPORT_VALIDATE: &str = "mcb_validate";
+
+/// Fuel (roughly, WebAssembly instructions) a plugin may consume per run.
+pub const WASM_PLUGIN_DEFAULT_FUEL: u64 = 10_000_000_000;
+
+// --- Public API Snapshot ---
+
+/// Default public API snapshot file, relative to the workspace root.
+pub const DEFAULT_API_SNAPSHOT_FILE: &str = ".mcb-api.json";
+
+/// Version written to (and accepted from) public API snapshot files.
+pub const API_SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+// --- Complexity Baseline ---
+
+/// Default function complexity baseline file, relative to the workspace root.
+pub const DEFAULT_COMPLEXITY_BASELINE_FILE: &str = ".mcb-complexity.json";
+
+/// Version written to (and accepted from) complexity baseline files.
+pub const COMPLEXITY_BASELINE_FORMAT_VERSION: u32 = 1;
+
+// --- ADR Compliance ---
+
+/// Default directory of ADR documents, relative to the workspace root.
+pub const DEFAULT_ADR_DIR: &str = "docs/adr";
+
+/// Schema tag of ADR compliance rule files.
+pub const ADR_COMPLIANCE_SCHEMA: &str = "adr-compliance/v1";
+
+// --- Baseline & Inline Suppressions ---
+
+/// Default baseline file name, relative to the workspace root.
+pub const DEFAULT_BASELINE_FILE: &str = ".mcb-baseline.json";
+
+/// Version written to (and accepted from) baseline files.
+pub const BASELINE_FORMAT_VERSION: u32 = 1;
+
+/// Hex digits kept from the SHA-256 of a violation fingerprint.
+pub const BASELINE_FINGERPRINT_LENGTH: usize = 16;
+
+/// Inline suppression comment: `// mcb-allow(RULE-ID): justification`.
+pub const SUPPRESSION_REGEX: &str = r"(?://|#|/\*|<!--)\s*mcb-allow\(([^)]*)\)\s*[:\-—]?\s*(.*)";
+
+/// Minimum justification length, in characters, for a suppression to apply.
+pub const SUPPRESSION_MIN_JUSTIFICATION_LEN: usize = 10;
+
+// --- Persistent Fact Cache ---
+
+/// Default fact cache directory, relative to the workspace root.
+pub const DEFAULT_FACT_CACHE_DIR: &str = ".mcb-validate-cache";
+
+/// Fact cache layout version; bumping it invalidates every cached entry.
+pub const FACT_CACHE_FORMAT_VERSION: u32 = 2;
+
+/// Fact attribute holding an item's visibility modifier (`pub`, `pub(crate)`);
+/// absent for private items.
+pub const FACT_ATTR_VISIBILITY: &str = "visibility";
+
+/// Fact cache entry kind for facts extracted from the syntax tree.
+pub const FACT_CACHE_KIND_FACTS: &str = "facts";
+
+/// Fact cache entry kind for per-function code metrics.
+pub const FACT_CACHE_KIND_METRICS: &str = "metrics";
+
+// --- Auto-Fix ---
+
+/// Fix type: replace every `pattern` match on the flagged line with `replacement`.
+pub const FIX_TYPE_REPLACE_PATTERN: &str = "replace_pattern";
+
+/// Fix type: insert `replacement` as a new line above the flagged line.
+pub const FIX_TYPE_INSERT_BEFORE: &str = "insert_before";
+
+/// Fix type: delete the flagged line.
+pub const FIX_TYPE_DELETE_LINE: &str = "delete_line";
+
+/// Fix type used by import rules; applied like [`FIX_TYPE_DELETE_LINE`].
+pub const FIX_TYPE_REMOVE_IMPORT: &str = "remove_import";
+
+// --- HTML Report ---
+
+/// Default report history file, relative to the workspace root.
+pub const DEFAULT_REPORT_HISTORY_FILE: &str = ".mcb-validate-history.json";
+
+/// Most recent runs kept in the report history.
+pub const REPORT_HISTORY_MAX_RUNS: usize = 500;
+
+/// Group name for violations outside any `crates/<name>/` directory.
+pub const REPORT_WORKSPACE_GROUP: &str = "(workspace)";
+
+// --- Dependency Diagrams ---
+
+/// Default output directory of `mcb validate graph`, relative to the workspace root.
+pub const DEFAULT_DIAGRAM_DIR: &str = "docs/architecture/diagrams/generated";
+
+/// File stem of the workspace crate diagram.
+pub const DIAGRAM_CRATES_STEM: &str = "crates";
+
+/// Suffix of the file stem of a crate's module diagram (`<crate>-modules`).
+pub const DIAGRAM_MODULES_SUFFIX: &str = "-modules";
+
+/// Node name of a crate's root module in module diagrams.
+pub const DIAGRAM_ROOT_MODULE: &str = "crate";
+
+/// Label of edges created by a `pub use`.
+pub const DIAGRAM_REEXPORT_LABEL: &str = "re-export";
@@ -46,6 +46,8 @@ define_str_consts! {
     YAML_FIELD_FIX_TYPE = "type";
     /// YAML field: pattern match string.
     YAML_FIELD_PATTERN = "pattern";
+    /// YAML field: fix replacement text.
+    YAML_FIELD_REPLACEMENT = "replacement";
     /// YAML field: violation message.
     YAML_FIELD_MESSAGE = "message";
     /// YAML field: lint select rules.
@@ -74,9 +74,15 @@ pub const DEFAULT_INDEXING_OP_PROVIDER: &str = "default";
 /// Registry provider name for validation operations.
 pub const DEFAULT_VALIDATION_OP_PROVIDER: &str = "default";
 
+/// Registry provider name for the in-process metrics recorder.
+pub const DEFAULT_METRICS_PROVIDER: &str = "in_memory";
+
 /// Registry provider name for the null/no-op fallback when no provider is configured.
 pub const DEFAULT_NULL_PROVIDER: &str = "null";
 
+/// Registry provider name for the `SeaORM`-backed GraphQL schema.
+pub const DEFAULT_GRAPHQL_SCHEMA_PROVIDER: &str = "seaography";
+
 /// Default namespace for database repositories.
 pub const DEFAULT_NAMESPACE: &str = "default";
 
@@ -93,6 +99,9 @@ pub const DEFAULT_BROWSE_LIMIT: usize = 50;
 /// Embedding provider slug: `FastEmbed` (local).
 pub const PROVIDER_SLUG_FASTEMBED: &str = "fastembed";
 
+/// Embedding provider slug: Ollama (local server).
+pub const PROVIDER_SLUG_OLLAMA: &str = "ollama";
+
 /// Vector-store provider slug: `EdgeVec` (local).
 pub const PROVIDER_SLUG_EDGEVEC: &str = "edgevec";
 
@@ -109,6 +118,31 @@ pub const PROVIDER_SLUG_QDRANT: &str = "qdrant";
 /// Default configuration provider name (YAML file-based).
 pub const DEFAULT_CONFIG_PROVIDER: &str = "loco_yaml";
 
+/// Prefix marking a config value that is read from a file (`file:/run/secrets/key`).
+pub const CONFIG_SECRET_FILE_PREFIX: &str = "file:";
+
+/// Prefix marking a config value that is read from Vault KV v2 (`vault:secret/data/mcb#api_key`).
+pub const CONFIG_SECRET_VAULT_PREFIX: &str = "vault:";
+
+/// Environment variable holding the Vault server address for `vault:` indirection.
+pub const VAULT_ADDR_ENV: &str = "VAULT_ADDR";
+
+/// Environment variable holding the Vault token for `vault:` indirection.
+pub const VAULT_TOKEN_ENV: &str = "VAULT_TOKEN";
+
+/// Environment variable selecting the configuration profile overlay.
+pub const CONFIG_PROFILE_ENV: &str = "MCB_PROFILE";
+
+/// Sub-directory of the config folder holding profile overlays (`profiles/<name>.yaml`).
+pub const CONFIG_PROFILES_DIR: &str = "profiles";
+
+/// Prefix for environment variables overriding `settings:` keys
+/// (`MCB__PROVIDERS__EMBEDDING__MODEL=...` → `providers.embedding.model`).
+pub const CONFIG_ENV_OVERRIDE_PREFIX: &str = "MCB__";
+
+/// Separator between nested keys in config override environment variables.
+pub const CONFIG_ENV_OVERRIDE_SEPARATOR: &str = "__";
+
 // ============================================================================
 // SERVICE NAMES (CA/DI registry)
 // ============================================================================
@@ -134,6 +168,97 @@ pub const SERVICE_NAME_VALIDATION: &str = "validation";
 /// Registry name for the highlight service.
 pub const SERVICE_NAME_HIGHLIGHT: &str = "highlight";
 
+/// Registry name for the session context service.
+pub const SERVICE_NAME_SESSION_CONTEXT: &str = "session_context";
+
+/// Registry name for the search feedback service.
+pub const SERVICE_NAME_SEARCH_FEEDBACK: &str = "search_feedback";
+
+/// Registry name for the grep service.
+pub const SERVICE_NAME_GREP: &str = "grep";
+
+/// Registry name for the file content service.
+pub const SERVICE_NAME_FILE_CONTENT: &str = "file_content";
+
+/// Registry name for the change summary service.
+pub const SERVICE_NAME_CHANGE_SUMMARY: &str = "change_summary";
+
+/// Registry name for the module graph service.
+pub const SERVICE_NAME_MODULE_GRAPH: &str = "module_graph";
+
+/// Registry name for the duplicate detection service.
+pub const SERVICE_NAME_DUPLICATE_DETECTION: &str = "duplicate_detection";
+
+/// Registry name for the index evaluation service.
+pub const SERVICE_NAME_INDEX_EVALUATION: &str = "index_evaluation";
+
+/// Registry name for the repository onboarding analysis service.
+pub const SERVICE_NAME_REPO_ANALYSIS: &str = "repo_analysis";
+
+/// Registry name for the workspace service.
+pub const SERVICE_NAME_WORKSPACE: &str = "workspace";
+
+/// Registry name for the usage reporting service.
+pub const SERVICE_NAME_USAGE: &str = "usage";
+
+// ============================================================================
+// REGISTRY NAMES (DI introspection)
+// ============================================================================
+
+/// Report name of the embedding provider registry.
+pub const REGISTRY_EMBEDDING: &str = "embedding";
+
+/// Report name of the vector store provider registry.
+pub const REGISTRY_VECTOR_STORE: &str = "vector_store";
+
+/// Report name of the configuration provider registry.
+pub const REGISTRY_CONFIG: &str = "config";
+
+/// Report name of the database repository provider registry.
+pub const REGISTRY_DATABASE: &str = "database";
+
+/// Report name of the database migration provider registry.
+pub const REGISTRY_MIGRATION: &str = "migration";
+
+/// Report name of the event bus provider registry.
+pub const REGISTRY_EVENT_BUS: &str = "event_bus";
+
+/// Report name of the metrics provider registry.
+pub const REGISTRY_METRICS: &str = "metrics";
+
+/// Report name of the hybrid search provider registry.
+pub const REGISTRY_HYBRID_SEARCH: &str = "hybrid_search";
+
+/// Report name of the language chunking provider registry.
+pub const REGISTRY_LANGUAGE: &str = "language";
+
+/// Report name of the VCS provider registry.
+pub const REGISTRY_VCS: &str = "vcs";
+
+/// Report name of the GraphQL schema provider registry.
+pub const REGISTRY_GRAPHQL_SCHEMA: &str = "graphql_schema";
+
+/// Report name of the project detection service registry.
+pub const REGISTRY_PROJECT_DETECTION: &str = "project_detection";
+
+/// Report name of the project detector backend registry.
+pub const REGISTRY_PROJECT_DETECTORS: &str = "project_detectors";
+
+/// Report name of the indexing operations provider registry.
+pub const REGISTRY_INDEXING_OPERATIONS: &str = "indexing_operations";
+
+/// Report name of the validation operations provider registry.
+pub const REGISTRY_VALIDATION_OPERATIONS: &str = "validation_operations";
+
+/// Report name of the code analyzer registry.
+pub const REGISTRY_CODE_ANALYZERS: &str = "code_analyzers";
+
+/// Report name of the validator registry.
+pub const REGISTRY_VALIDATORS: &str = "validators";
+
+/// Report name of the service registry.
+pub const REGISTRY_SERVICES: &str = "services";
+
 // ============================================================================
 // DATABASE
 // ============================================================================
@@ -14,3 +14,9 @@ pub const IMPACT_CHANGE_COUNT_WEIGHT: f64 = 5.0;
 
 /// Maximum impact score (clamped upper bound).
 pub const MAX_IMPACT_SCORE: f64 = 100.0;
+
+/// Maximum commits walked when resolving per-file last-modified times.
+pub const VCS_LAST_MODIFIED_MAX_COMMITS: usize = 10_000;
+
+/// Upper bound on indexed files compared by one `summarize_changes` call.
+pub const CHANGE_SUMMARY_MAX_FILES: usize = 10_000;
@@ -3,6 +3,49 @@
 //!
 //! Vector store constants -- Single Source of Truth
 
+// ============================================================================
+// Bulk Insert Batching
+// ============================================================================
+
+/// Default number of vectors per insert request.
+pub const VECTOR_INSERT_BATCH_SIZE: usize = 256;
+
+/// Default number of insert batches in flight at once.
+pub const VECTOR_INSERT_MAX_IN_FLIGHT: usize = 4;
+
+/// Default seconds a deleted or replaced vector stays queryable for
+/// point-in-time search (7 days).
+pub const VECTOR_TOMBSTONE_RETENTION_SECS: u64 = 7 * 24 * 60 * 60;
+
+// ============================================================================
+// Storage Quotas (embedded stores)
+// ============================================================================
+
+/// Default percentage of a storage quota at which health checks warn.
+pub const VECTOR_STORAGE_WARN_PERCENT: u8 = 80;
+
+// ============================================================================
+// Persistence (embedded stores)
+// ============================================================================
+
+/// Default seconds between full snapshots of a persisted embedded store.
+pub const VECTOR_SNAPSHOT_INTERVAL_SECS: u64 = 300;
+
+/// Default milliseconds between write-ahead log flushes to disk.
+pub const VECTOR_WAL_FLUSH_INTERVAL_MS: u64 = 1_000;
+
+/// Default zstd level for embedded store snapshots; 0 writes plain JSON.
+pub const VECTOR_SNAPSHOT_ZSTD_LEVEL: i32 = 3;
+
+/// Highest zstd compression level.
+pub const VECTOR_SNAPSHOT_ZSTD_MAX_LEVEL: i32 = 22;
+
+/// `EdgeVec` snapshot file name inside the persistence data directory.
+pub const EDGEVEC_SNAPSHOT_FILE: &str = "edgevec.snapshot.json";
+
+/// `EdgeVec` write-ahead log file name inside the persistence data directory.
+pub const EDGEVEC_WAL_FILE: &str = "edgevec.wal";
+
 // ============================================================================
 // EdgeVec Configuration
 // ============================================================================
@@ -59,8 +102,11 @@ pub const MILVUS_COLLECTION_CREATE_DELAY_MS: u64 = 500;
 /// Milvus pagination batch size (keeps gRPC responses under 4MB limit).
 pub const MILVUS_QUERY_BATCH_SIZE: usize = 100;
 
-/// Milvus distance metric type for search.
-pub const MILVUS_DISTANCE_METRIC: &str = "L2";
+/// Milvus metric type for Euclidean distance.
+pub const MILVUS_METRIC_L2: &str = "L2";
+
+/// Milvus metric type for inner product (cosine on normalized vectors).
+pub const MILVUS_METRIC_IP: &str = "IP";
 
 /// Milvus vector index name.
 pub const MILVUS_VECTOR_INDEX_NAME: &str = "vector_index";
@@ -101,8 +147,14 @@ pub const MILVUS_DEFAULT_OUTPUT_FIELDS: &[&str] = &[
 /// Qdrant default server port.
 pub const QDRANT_DEFAULT_PORT: u16 = 6333;
 
-/// Qdrant distance metric.
-pub const QDRANT_DISTANCE_METRIC: &str = "Cosine";
+/// Qdrant distance name for cosine similarity.
+pub const QDRANT_DISTANCE_COSINE: &str = "Cosine";
+
+/// Qdrant distance name for dot product.
+pub const QDRANT_DISTANCE_DOT: &str = "Dot";
+
+/// Qdrant distance name for Euclidean distance.
+pub const QDRANT_DISTANCE_EUCLID: &str = "Euclid";
 
 // ============================================================================
 // Pinecone Configuration
@@ -130,6 +182,21 @@ pub const STATS_FIELD_VECTORS_COUNT: &str = "vectors_count";
 /// Stats JSON field: row count.
 pub const STATS_FIELD_ROW_COUNT: &str = "row_count";
 
+/// Stats JSON field: number of indexed files.
+pub const STATS_FIELD_FILE_COUNT: &str = "file_count";
+
+/// Stats JSON field: chunk count per language.
+pub const STATS_FIELD_LANGUAGES: &str = "languages";
+
+/// Stats JSON field: files with the most chunks.
+pub const STATS_FIELD_LARGEST_FILES: &str = "largest_files";
+
+/// Number of files listed under [`STATS_FIELD_LARGEST_FILES`].
+pub const STATS_LARGEST_FILES_LIMIT: usize = 10;
+
+/// Maximum number of files scanned for the language breakdown.
+pub const STATS_FILE_SCAN_LIMIT: usize = 10_000;
+
 /// Status value: active/ready.
 pub const STATUS_ACTIVE: &str = "active";
 
@@ -161,5 +228,8 @@ pub const VECTOR_FIELD_VECTOR: &str = "vector";
 /// Vector store field: programming language.
 pub const VECTOR_FIELD_LANGUAGE: &str = "language";
 
+/// Vector store field: Unix time of the last commit touching the file.
+pub const VECTOR_FIELD_LAST_MODIFIED: &str = "last_modified";
+
 /// Vector store field: metadata JSON blob.
 pub const VECTOR_FIELD_METADATA: &str = "metadata";
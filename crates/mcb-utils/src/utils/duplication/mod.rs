@@ -0,0 +1,18 @@
+//!
+//! **Documentation**: [docs/modules/utils.md](../../../../../docs/modules/utils.md)
+//!
+//! Clone detection primitives: the source tokenizer and the Rabin-Karp
+//! token fingerprinter.
+//!
+//! `mcb-validate`'s duplication analysis and the duplicate search over
+//! indexed collections both build on these, so the same code yields the same
+//! tokens and window hashes in either place.
+
+pub mod fingerprint;
+pub mod tokenizer;
+
+pub use self::fingerprint::{
+    Fingerprint, FingerprintLocation, FingerprintMatch, FingerprintStats, Token,
+    TokenFingerprinter, TokenType,
+};
+pub use self::tokenizer::tokenize_source;
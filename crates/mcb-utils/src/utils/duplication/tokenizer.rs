@@ -0,0 +1,279 @@
+//!
+//! **Documentation**: [docs/modules/utils.md](../../../../../docs/modules/utils.md)
+//!
+//! Source tokenizer shared by every clone detector
+//!
+//! Splits source into words, numbers, quoted literals, operators and
+//! punctuation, dropping whitespace and comments, so that `mcb-validate` and
+//! the indexed-collection duplicate search see the same token streams.
+
+use std::str::Chars;
+
+use super::fingerprint::{Token, TokenType};
+use crate::constants::lang::HASH_COMMENT_LANGUAGES;
+use crate::constants::validate::{DUPLICATION_KEYWORDS, OPERATOR_CHARS, PUNCTUATION_CHARS};
+
+fn consume_while(
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    mut predicate: impl FnMut(char) -> bool,
+) -> String {
+    let mut out = String::new();
+    while let Some(next) = chars.peek().copied() {
+        if !predicate(next) {
+            break;
+        }
+        let Some(next_char) = chars.next() else {
+            break;
+        };
+        out.push(next_char);
+    }
+    out
+}
+
+fn consume_quoted_literal(
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    quote: char,
+    current_line: &mut usize,
+) -> String {
+    let mut literal = String::new();
+    literal.push(quote);
+
+    for next in chars.by_ref() {
+        literal.push(next);
+        if next == quote && !literal.ends_with('\\') {
+            break;
+        }
+        if next == '\n' {
+            *current_line += 1;
+        }
+    }
+
+    literal
+}
+
+fn skip_line_comment(chars: &mut std::iter::Peekable<Chars<'_>>) {
+    while let Some(next) = chars.peek().copied() {
+        if next == '\n' {
+            break;
+        }
+        let _ = chars.next();
+    }
+}
+
+fn skip_block_comment(chars: &mut std::iter::Peekable<Chars<'_>>, current_line: &mut usize) {
+    while let Some(next) = chars.next() {
+        if next == '\n' {
+            *current_line += 1;
+            continue;
+        }
+        if next == '*' && chars.peek() == Some(&'/') {
+            let _ = chars.next();
+            break;
+        }
+    }
+}
+
+fn push_token(
+    tokens: &mut Vec<Token>,
+    text: String,
+    line: usize,
+    column: usize,
+    token_type: TokenType,
+) {
+    tokens.push(Token::new(text, line, column, token_type));
+}
+
+/// Tokenize source code for fingerprinting
+///
+/// This is a simplified tokenizer. A full implementation would use
+/// tree-sitter for language-aware tokenization. `language` only decides
+/// whether `#` starts a line comment (see [`HASH_COMMENT_LANGUAGES`]).
+#[must_use]
+pub fn tokenize_source(source: &str, language: &str) -> Vec<Token> {
+    let hash_comments = HASH_COMMENT_LANGUAGES.contains(&language);
+    let mut tokens = Vec::new();
+    let mut current_line = 1;
+    let mut current_column = 1;
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if hash_comments && c == '#' {
+            skip_line_comment(&mut chars);
+            continue;
+        }
+        tokenize_char(
+            c,
+            &mut chars,
+            &mut tokens,
+            &mut current_line,
+            &mut current_column,
+        );
+    }
+
+    tokens
+}
+
+/// Dispatch a single character to the appropriate tokenizer, updating position counters.
+fn tokenize_char(
+    c: char,
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    tokens: &mut Vec<Token>,
+    current_line: &mut usize,
+    current_column: &mut usize,
+) {
+    match c {
+        '\n' => {
+            *current_line += 1;
+            *current_column = 1;
+        }
+        c if c.is_whitespace() => {
+            *current_column += 1;
+        }
+        c if c.is_alphabetic() || c == '_' => {
+            *current_column += tokenize_word(chars, tokens, c, *current_line, *current_column);
+        }
+        c if c.is_ascii_digit() => {
+            *current_column += tokenize_number(chars, tokens, c, *current_line, *current_column);
+        }
+        '"' | '\'' | '`' => {
+            *current_column += tokenize_quoted(chars, tokens, c, current_line, *current_column);
+        }
+        '/' => {
+            *current_column += tokenize_slash(chars, tokens, current_line, *current_column);
+        }
+        c if OPERATOR_CHARS.contains(c) => {
+            push_single_char(
+                c,
+                tokens,
+                *current_line,
+                current_column,
+                TokenType::Operator,
+            );
+        }
+        c if PUNCTUATION_CHARS.contains(c) => {
+            push_single_char(
+                c,
+                tokens,
+                *current_line,
+                current_column,
+                TokenType::Punctuation,
+            );
+        }
+        _ => {
+            *current_column += 1;
+        }
+    }
+}
+
+/// Push a single-character token of `token_type` and advance the column counter.
+fn push_single_char(
+    c: char,
+    tokens: &mut Vec<Token>,
+    current_line: usize,
+    current_column: &mut usize,
+    token_type: TokenType,
+) {
+    push_token(
+        tokens,
+        c.to_string(),
+        current_line,
+        *current_column,
+        token_type,
+    );
+    *current_column += 1;
+}
+
+/// Check if a word is a common keyword (simplified, multi-language)
+fn is_keyword(word: &str) -> bool {
+    DUPLICATION_KEYWORDS.contains(&word)
+}
+
+/// Tokenize a quoted string/char literal starting at `quote`, returning the column advance.
+///
+/// `current_line` is advanced for newlines embedded in the literal, and the emitted token is
+/// recorded at the post-consumption line — preserving the original tokenizer's behavior.
+fn tokenize_quoted(
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    tokens: &mut Vec<Token>,
+    quote: char,
+    current_line: &mut usize,
+    column: usize,
+) -> usize {
+    let string = consume_quoted_literal(chars, quote, current_line);
+    let len = string.len();
+    push_token(tokens, string, *current_line, column, TokenType::Literal);
+    len
+}
+
+/// Tokenize an identifier or keyword starting at `first`, returning the column advance.
+fn tokenize_word(
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    tokens: &mut Vec<Token>,
+    first: char,
+    line: usize,
+    column: usize,
+) -> usize {
+    let mut word = String::from(first);
+    word.push_str(&consume_while(chars, |next| {
+        next.is_alphanumeric() || next == '_'
+    }));
+
+    let token_type = if is_keyword(&word) {
+        TokenType::Keyword
+    } else {
+        TokenType::Identifier
+    };
+
+    let len = word.len();
+    push_token(tokens, word, line, column, token_type);
+    len
+}
+
+/// Tokenize a numeric literal starting at `first`, returning the column advance.
+fn tokenize_number(
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    tokens: &mut Vec<Token>,
+    first: char,
+    line: usize,
+    column: usize,
+) -> usize {
+    let mut number = String::from(first);
+    number.push_str(&consume_while(chars, |next| {
+        next.is_ascii_digit() || next == '.' || next == '_'
+    }));
+
+    let len = number.len();
+    push_token(tokens, number, line, column, TokenType::Literal);
+    len
+}
+
+/// Handle a `/` character: line/block comment skip or operator token. Returns column advance.
+fn tokenize_slash(
+    chars: &mut std::iter::Peekable<Chars<'_>>,
+    tokens: &mut Vec<Token>,
+    current_line: &mut usize,
+    column: usize,
+) -> usize {
+    match chars.peek().copied() {
+        Some('/') => {
+            let _ = chars.next();
+            skip_line_comment(chars);
+            0
+        }
+        Some('*') => {
+            let _ = chars.next();
+            skip_block_comment(chars, current_line);
+            0
+        }
+        _ => {
+            push_token(
+                tokens,
+                "/".to_owned(),
+                *current_line,
+                column,
+                TokenType::Operator,
+            );
+            1
+        }
+    }
+}
@@ -19,10 +19,15 @@ pub mod path;
 /// Canonical time utilities (epoch seconds/nanos, strict).
 pub mod time;
 
+/// Clone detection tokenizer and Rabin-Karp token fingerprinter.
+pub mod duplication;
 /// Range and interval utilities (line overlap checks).
 pub mod range;
-/// Retry utilities with exponential backoff.
+/// Retry utilities with exponential backoff (needs the Tokio timer, so not on wasm32).
+#[cfg(not(target_arch = "wasm32"))]
 pub mod retry;
+/// SIMD dot product, norm and cosine similarity kernels.
+pub mod vector;
 
 /// Cryptographic hashing and token utilities.
 pub mod crypto;
@@ -3,7 +3,8 @@
 //!
 //! Naming convention utilities for the MCB workspace.
 //!
-//! Provides checks for `CamelCase`, `snake_case`, and `SCREAMING_SNAKE_CASE`.
+//! Provides checks for `CamelCase`, `lowerCamelCase`, `snake_case`, and
+//! `SCREAMING_SNAKE_CASE`.
 
 /// Checks if a name follows CamelCase convention.
 #[must_use]
@@ -63,6 +64,13 @@ pub fn is_screaming_snake_case(name: &str) -> bool {
     is_valid_snake_case(name, true)
 }
 
+/// Checks if a name follows `lowerCamelCase` convention (`parseInput`, `run`).
+#[must_use]
+pub fn is_lower_camel_case(name: &str) -> bool {
+    name.chars().next().is_some_and(|c| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
 /// Extracts the suffix from a file name (part after the last underscore).
 #[must_use]
 pub fn get_suffix(name: &str) -> &str {
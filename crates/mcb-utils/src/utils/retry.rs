@@ -12,20 +12,64 @@ pub struct RetryConfig {
     pub max_attempts: usize,
     /// Base delay used by backoff between retries.
     pub base_delay: Duration,
+    /// Upper bound for a single backoff delay (`None` = unbounded).
+    pub max_delay: Option<Duration>,
+    /// Double the delay on every attempt instead of growing linearly.
+    pub exponential: bool,
+    /// Fraction of each delay (0.0..=1.0) randomly subtracted to spread retries.
+    pub jitter: f64,
 }
 
 impl RetryConfig {
-    /// Create a new retry configuration.
+    /// Create a new retry configuration with linear backoff and no jitter.
     #[must_use]
     pub const fn new(max_attempts: usize, base_delay: Duration) -> Self {
         Self {
             max_attempts,
             base_delay,
+            max_delay: None,
+            exponential: false,
+            jitter: 0.0,
         }
     }
+
+    /// Switch to exponential backoff capped at `max_delay`.
+    #[must_use]
+    pub const fn with_exponential_backoff(mut self, max_delay: Duration) -> Self {
+        self.exponential = true;
+        self.max_delay = Some(max_delay);
+        self
+    }
+
+    /// Set the jitter fraction (clamped to `0.0..=1.0`).
+    #[must_use]
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Delay before retrying after the zero-based `attempt` failed, before jitter.
+    #[must_use]
+    pub fn base_delay_for(&self, attempt: usize) -> Duration {
+        let delay = if self.exponential {
+            let factor = 2_u32.saturating_pow(u32::try_from(attempt).unwrap_or(u32::MAX));
+            self.base_delay.saturating_mul(factor)
+        } else {
+            self.base_delay.mul_f64((attempt + 1) as f64)
+        };
+        self.max_delay.map_or(delay, |max| delay.min(max))
+    }
+
+    fn delay_for(&self, attempt: usize) -> Duration {
+        let delay = self.base_delay_for(attempt);
+        if self.jitter <= 0.0 {
+            return delay;
+        }
+        delay.mul_f64(1.0 - self.jitter * rand::random::<f64>())
+    }
 }
 
-/// Retry an async operation with linear backoff based on attempt count.
+/// Retry an async operation, backing off between attempts per [`RetryConfig`].
 ///
 /// # Errors
 ///
@@ -50,7 +94,7 @@ where
                     return Err(error);
                 }
 
-                tokio::time::sleep(config.base_delay.mul_f64((attempt + 1) as f64)).await;
+                tokio::time::sleep(config.delay_for(attempt)).await;
             }
         }
     }
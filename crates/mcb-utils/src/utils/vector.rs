@@ -0,0 +1,140 @@
+//!
+//! **Documentation**: [docs/modules/providers.md](../../../../docs/modules/providers.md#similarity-kernel)
+//!
+//! Vector similarity kernels.
+//!
+//! Sums run over 8-lane `wide::f32x8` accumulators, which lower to SSE/AVX
+//! on x86_64, NEON on aarch64 and simd128 on wasm32. On x86_64 the kernels
+//! check at runtime for AVX2 and FMA and, when present, run a copy compiled
+//! for them, so a baseline build still uses 256-bit fused multiply-adds.
+
+use wide::f32x8;
+
+/// Lanes per SIMD step.
+const LANES: usize = 8;
+
+/// Dot product of `a` and `b` over their common length.
+#[must_use]
+#[allow(unsafe_code)]
+pub fn dot(a: &[f32], b: &[f32]) -> f32 {
+    #[cfg(target_arch = "x86_64")]
+    if avx2::detected() {
+        // SAFETY: the CPU supports AVX2 and FMA, checked just above.
+        return unsafe { avx2::dot(a, b) };
+    }
+    dot_portable(a, b)
+}
+
+/// Euclidean length of `v`.
+#[must_use]
+pub fn l2_norm(v: &[f32]) -> f32 {
+    dot(v, v).sqrt()
+}
+
+/// Cosine similarity of `a` and `b` in one pass; 0 when the lengths differ
+/// or either vector is zero.
+#[must_use]
+#[allow(unsafe_code)]
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    #[cfg(target_arch = "x86_64")]
+    if avx2::detected() {
+        // SAFETY: the CPU supports AVX2 and FMA, checked just above.
+        return unsafe { avx2::cosine_similarity(a, b) };
+    }
+    cosine_portable(a, b)
+}
+
+/// Dot product over the common length with the instruction set of the build.
+fn dot_portable(a: &[f32], b: &[f32]) -> f32 {
+    let len = a.len().min(b.len());
+    let (a_chunks, a_tail) = a[..len].as_chunks::<LANES>();
+    let (b_chunks, b_tail) = b[..len].as_chunks::<LANES>();
+    let mut acc = f32x8::ZERO;
+    for (x, y) in a_chunks.iter().zip(b_chunks) {
+        acc = f32x8::from(*x).mul_add(f32x8::from(*y), acc);
+    }
+    acc.reduce_add() + a_tail.iter().zip(b_tail).map(|(x, y)| x * y).sum::<f32>()
+}
+
+/// Cosine similarity of equal-length `a` and `b`.
+fn cosine_portable(a: &[f32], b: &[f32]) -> f32 {
+    let (a_chunks, a_tail) = a.as_chunks::<LANES>();
+    let (b_chunks, b_tail) = b.as_chunks::<LANES>();
+    let (mut dot, mut norm_a, mut norm_b) = (f32x8::ZERO, f32x8::ZERO, f32x8::ZERO);
+    for (x, y) in a_chunks.iter().zip(b_chunks) {
+        let (x, y) = (f32x8::from(*x), f32x8::from(*y));
+        dot = x.mul_add(y, dot);
+        norm_a = x.mul_add(x, norm_a);
+        norm_b = y.mul_add(y, norm_b);
+    }
+    let (mut dot, mut norm_a, mut norm_b) =
+        (dot.reduce_add(), norm_a.reduce_add(), norm_b.reduce_add());
+    for (x, y) in a_tail.iter().zip(b_tail) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// Scalar 8-lane kernels compiled for AVX2 and FMA, which LLVM vectorizes
+/// into 256-bit fused multiply-adds.
+#[cfg(target_arch = "x86_64")]
+mod avx2 {
+    use super::LANES;
+
+    /// Whether the running CPU supports AVX2 and FMA; the standard library
+    /// caches the answer.
+    pub(super) fn detected() -> bool {
+        std::is_x86_feature_detected!("avx2") && std::is_x86_feature_detected!("fma")
+    }
+
+    /// `acc[i] += x[i] * y[i]` for every lane.
+    #[target_feature(enable = "avx2,fma")]
+    fn fma_lanes(acc: &mut [f32; LANES], x: &[f32; LANES], y: &[f32; LANES]) {
+        for ((acc, x), y) in acc.iter_mut().zip(x).zip(y) {
+            *acc = x.mul_add(*y, *acc);
+        }
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) fn dot(a: &[f32], b: &[f32]) -> f32 {
+        let len = a.len().min(b.len());
+        let (a_chunks, a_tail) = a[..len].as_chunks::<LANES>();
+        let (b_chunks, b_tail) = b[..len].as_chunks::<LANES>();
+        let mut acc = [0.0; LANES];
+        for (x, y) in a_chunks.iter().zip(b_chunks) {
+            fma_lanes(&mut acc, x, y);
+        }
+        acc.iter().sum::<f32>() + a_tail.iter().zip(b_tail).map(|(x, y)| x * y).sum::<f32>()
+    }
+
+    #[target_feature(enable = "avx2,fma")]
+    pub(super) fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+        let (a_chunks, a_tail) = a.as_chunks::<LANES>();
+        let (b_chunks, b_tail) = b.as_chunks::<LANES>();
+        let (mut dot, mut norm_a, mut norm_b) = ([0.0; LANES], [0.0; LANES], [0.0; LANES]);
+        for (x, y) in a_chunks.iter().zip(b_chunks) {
+            fma_lanes(&mut dot, x, y);
+            fma_lanes(&mut norm_a, x, x);
+            fma_lanes(&mut norm_b, y, y);
+        }
+        let sum = |lanes: [f32; LANES]| lanes.iter().sum::<f32>();
+        let (mut dot, mut norm_a, mut norm_b) = (sum(dot), sum(norm_a), sum(norm_b));
+        for (x, y) in a_tail.iter().zip(b_tail) {
+            dot += x * y;
+            norm_a += x * x;
+            norm_b += y * y;
+        }
+        if norm_a == 0.0 || norm_b == 0.0 {
+            return 0.0;
+        }
+        dot / (norm_a.sqrt() * norm_b.sqrt())
+    }
+}
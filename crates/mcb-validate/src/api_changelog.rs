@@ -0,0 +1,149 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! API Changelog
+//!
+//! Compares the public API snapshots of two revisions and lists the items
+//! added, removed and changed between them, as JSON for release tooling or
+//! as a Markdown section for release notes. Removed and changed items are
+//! breaking changes.
+
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::validators::api_surface::{ApiItem, ApiSnapshot};
+
+/// A public item added, removed or changed between two revisions.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiChange {
+    /// Crate directory name.
+    pub crate_name: String,
+    /// Item path within the crate (`module::Type::method`).
+    pub path: String,
+    /// Item kind (`fn`, `struct`, `impl`, ...).
+    pub kind: String,
+    /// Signature at the older revision; absent for added items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub old_signature: Option<String>,
+    /// Signature at the newer revision; absent for removed items.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub new_signature: Option<String>,
+}
+
+/// Public API changes between two revisions, sorted by crate and path.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiChangelog {
+    /// Older revision.
+    pub from: String,
+    /// Newer revision.
+    pub to: String,
+    /// Items only in the newer revision.
+    pub added: Vec<ApiChange>,
+    /// Items only in the older revision.
+    pub removed: Vec<ApiChange>,
+    /// Items whose signature differs.
+    pub changed: Vec<ApiChange>,
+}
+
+impl ApiChangelog {
+    /// Changes from the `old` snapshot (at revision `from`) to the `new`
+    /// snapshot (at revision `to`).
+    #[must_use]
+    pub fn between(
+        from: impl Into<String>,
+        old: &ApiSnapshot,
+        to: impl Into<String>,
+        new: &ApiSnapshot,
+    ) -> Self {
+        let mut changelog = Self {
+            from: from.into(),
+            to: to.into(),
+            ..Self::default()
+        };
+        for (crate_name, items) in &new.crates {
+            let old_items = old.crates.get(crate_name);
+            for (path, item) in items {
+                match old_items.and_then(|old_items| old_items.get(path)) {
+                    None => changelog
+                        .added
+                        .push(change(crate_name, path, None, Some(item))),
+                    Some(old_item) if old_item.signature != item.signature => changelog
+                        .changed
+                        .push(change(crate_name, path, Some(old_item), Some(item))),
+                    Some(_) => {}
+                }
+            }
+        }
+        for (crate_name, items) in &old.crates {
+            let new_items = new.crates.get(crate_name);
+            for (path, item) in items {
+                if new_items.is_none_or(|new_items| !new_items.contains_key(path)) {
+                    changelog
+                        .removed
+                        .push(change(crate_name, path, Some(item), None));
+                }
+            }
+        }
+        changelog
+    }
+
+    /// Whether the public API is unchanged.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Whether any item was removed or changed.
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        !self.removed.is_empty() || !self.changed.is_empty()
+    }
+
+    /// Render as a Markdown release-notes section.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!("## Public API changes ({}..{})\n", self.from, self.to);
+        if self.is_empty() {
+            out.push_str("\nNo public API changes.\n");
+            return out;
+        }
+        let sections = [
+            ("Removed (breaking)", &self.removed),
+            ("Changed (breaking)", &self.changed),
+            ("Added", &self.added),
+        ];
+        for (title, changes) in sections {
+            if changes.is_empty() {
+                continue;
+            }
+            let _ = writeln!(out, "\n### {title}\n");
+            for change in changes {
+                let signatures = match (&change.old_signature, &change.new_signature) {
+                    (Some(old), Some(new)) => format!("`{old}` → `{new}`"),
+                    (Some(signature), None) | (None, Some(signature)) => format!("`{signature}`"),
+                    (None, None) => String::new(),
+                };
+                let _ = writeln!(
+                    out,
+                    "- `{}::{}`: {signatures}",
+                    change.crate_name, change.path
+                );
+            }
+        }
+        out
+    }
+}
+
+fn change(crate_name: &str, path: &str, old: Option<&ApiItem>, new: Option<&ApiItem>) -> ApiChange {
+    ApiChange {
+        crate_name: crate_name.to_owned(),
+        path: path.to_owned(),
+        kind: new
+            .or(old)
+            .map(|item| item.kind.clone())
+            .unwrap_or_default(),
+        old_signature: old.map(|item| item.signature.clone()),
+        new_signature: new.map(|item| item.signature.clone()),
+    }
+}
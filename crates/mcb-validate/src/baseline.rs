@@ -0,0 +1,178 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! Violation Baseline
+//!
+//! A baseline records the violations that already exist in a codebase so
+//! that later runs only report new ones. Each violation is fingerprinted by
+//! its rule, its workspace-relative file and a hash of the flagged source
+//! line (or of the message when there is no line), so unrelated edits that
+//! shift line numbers do not resurface baselined violations. Identical
+//! fingerprints are counted: adding a second copy of a baselined violation
+//! reports the new copy.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::generic_reporter::workspace_relative;
+use crate::{Result, ValidationError, Violation};
+use mcb_utils::constants::validate::{BASELINE_FINGERPRINT_LENGTH, BASELINE_FORMAT_VERSION};
+use mcb_utils::utils::id::compute_content_hash;
+
+/// One baselined violation.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Rule ID (e.g. `QUAL001`).
+    pub rule: String,
+    /// Workspace-relative file, if the violation has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Hash of rule, file and flagged content.
+    pub fingerprint: String,
+}
+
+/// Violations accepted as pre-existing.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// Baseline format version.
+    pub version: u32,
+    /// Baselined violations, sorted for stable diffs.
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// Result of filtering violations against a baseline.
+#[derive(Debug, Default)]
+pub struct BaselineOutcome {
+    /// Violations not present in the baseline.
+    pub violations: Vec<Box<dyn Violation>>,
+    /// Number of violations matched by a baseline entry.
+    pub baselined: usize,
+}
+
+/// Reads flagged source lines, caching each file.
+struct LineReader<'a> {
+    workspace_root: &'a Path,
+    files: HashMap<String, Vec<String>>,
+}
+
+impl<'a> LineReader<'a> {
+    fn new(workspace_root: &'a Path) -> Self {
+        Self {
+            workspace_root,
+            files: HashMap::new(),
+        }
+    }
+
+    /// Trimmed content of `line` (1-based) in the workspace-relative `file`.
+    fn line(&mut self, file: &str, line: usize) -> Option<&str> {
+        let lines = self.files.entry(file.to_owned()).or_insert_with(|| {
+            std::fs::read_to_string(self.workspace_root.join(file))
+                .map(|content| content.lines().map(|l| l.trim().to_owned()).collect())
+                .unwrap_or_default()
+        });
+        lines.get(line.checked_sub(1)?).map(String::as_str)
+    }
+
+    fn entry(&mut self, violation: &dyn Violation) -> BaselineEntry {
+        let file = violation
+            .file()
+            .map(|f| workspace_relative(&f.to_string_lossy(), self.workspace_root));
+        let content = match (&file, violation.line()) {
+            (Some(file), Some(line)) => self.line(file, line).map(str::to_owned),
+            _ => None,
+        }
+        .unwrap_or_else(|| violation.message());
+        let mut fingerprint = compute_content_hash(&format!(
+            "{}\0{}\0{content}",
+            violation.id(),
+            file.as_deref().unwrap_or_default()
+        ));
+        fingerprint.truncate(BASELINE_FINGERPRINT_LENGTH);
+        BaselineEntry {
+            rule: violation.id().to_owned(),
+            file,
+            fingerprint,
+        }
+    }
+}
+
+impl Baseline {
+    /// Record `violations` as the accepted baseline.
+    #[must_use]
+    pub fn from_violations(violations: &[Box<dyn Violation>], workspace_root: &Path) -> Self {
+        let mut reader = LineReader::new(workspace_root);
+        let mut entries: Vec<BaselineEntry> = violations
+            .iter()
+            .map(|v| reader.entry(v.as_ref()))
+            .collect();
+        entries.sort();
+        Self {
+            version: BASELINE_FORMAT_VERSION,
+            entries,
+        }
+    }
+
+    /// Load a baseline file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, is not a baseline, or has
+    /// an unsupported version.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let baseline: Self =
+            serde_json::from_str(&content).map_err(|e| ValidationError::Parse {
+                file: path.to_path_buf(),
+                message: e.to_string(),
+            })?;
+        if baseline.version != BASELINE_FORMAT_VERSION {
+            return Err(ValidationError::Parse {
+                file: path.to_path_buf(),
+                message: format!(
+                    "unsupported baseline version {} (expected {BASELINE_FORMAT_VERSION})",
+                    baseline.version
+                ),
+            });
+        }
+        Ok(baseline)
+    }
+
+    /// Write the baseline as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| ValidationError::Config(format!("cannot serialize baseline: {e}")))?;
+        std::fs::write(path, format!("{json}\n"))?;
+        Ok(())
+    }
+
+    /// Keep only the violations the baseline does not account for.
+    #[must_use]
+    pub fn filter_new(
+        &self,
+        violations: Vec<Box<dyn Violation>>,
+        workspace_root: &Path,
+    ) -> BaselineOutcome {
+        let mut remaining: HashMap<&str, usize> = HashMap::new();
+        for entry in &self.entries {
+            *remaining.entry(entry.fingerprint.as_str()).or_default() += 1;
+        }
+
+        let mut reader = LineReader::new(workspace_root);
+        let mut outcome = BaselineOutcome::default();
+        for violation in violations {
+            let entry = reader.entry(violation.as_ref());
+            match remaining.get_mut(entry.fingerprint.as_str()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    outcome.baselined += 1;
+                }
+                _ => outcome.violations.push(violation),
+            }
+        }
+        outcome
+    }
+}
@@ -10,7 +10,8 @@
 //!
 //! 1. `config/mcb-validate.toml` (embedded in binary — ALL defaults)
 //! 2. `config/mcb-validate-internal.toml` (filesystem — project overrides)
-//! 3. Environment variables with `MCB_VALIDATE__` prefix
+//! 3. `.mcb-validate.toml` (filesystem — workspace root overrides)
+//! 4. Environment variables with `MCB_VALIDATE__` prefix
 //!
 //! # Example Configuration
 //!
@@ -19,6 +20,9 @@
 //! workspace_root = "."
 //! exclude_patterns = ["target/", "tests/fixtures/"]
 //!
+//! [general.language_filenames]
+//! Jenkinsfile = "shell"
+//!
 //! [rules.architecture]
 //! enabled = true
 //! severity = "ERROR"
@@ -32,15 +36,25 @@
 //! dependency = true
 //! organization = true
 //! quality = true
+//!
+//! [[plugins.rule_dirs]]
+//! path = "tools/validate-rules"
+//! namespace = "ACME"
+//!
+//! [[plugins.wasm]]
+//! path = "tools/acme_validator.wasm"
 //! ```
 
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 use figment::Figment;
 use figment::providers::{Env, Format, Toml};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::Severity;
+use mcb_utils::constants::validate::VALIDATE_PROJECT_CONFIG_FILE;
 use mcb_utils::constants::validate::{
     VALIDATOR_ARCHITECTURE, VALIDATOR_ASYNC_PATTERNS, VALIDATOR_CLEAN_ARCHITECTURE,
     VALIDATOR_CONFIG_QUALITY, VALIDATOR_DECLARATIVE, VALIDATOR_DEPENDENCY, VALIDATOR_DOCUMENTATION,
@@ -51,10 +65,11 @@ use mcb_utils::constants::validate::{
 };
 
 /// Embedded default configuration (baked into binary at compile time)
-const EMBEDDED_VALIDATE_DEFAULTS: &str = include_str!("../../../../config/mcb-validate.toml");
+pub(super) const EMBEDDED_VALIDATE_DEFAULTS: &str =
+    include_str!("../../../../config/mcb-validate.toml");
 
 /// Root configuration loaded via figment provider chain
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct FileConfig {
     /// General settings
     pub general: GeneralConfig,
@@ -64,6 +79,10 @@ pub struct FileConfig {
 
     /// Validator enable/disable flags
     pub validators: ValidatorsConfig,
+
+    /// Additional YAML rule directories
+    #[serde(default)]
+    pub plugins: PluginsConfig,
 }
 
 impl FileConfig {
@@ -72,7 +91,8 @@ impl FileConfig {
     /// Provider chain (later sources override earlier):
     /// 1. `config/mcb-validate.toml` (embedded in binary)
     /// 2. `config/mcb-validate-internal.toml` (filesystem, project overrides)
-    /// 3. Environment variables with `MCB_VALIDATE__` prefix
+    /// 3. `.mcb-validate.toml` (filesystem, workspace root overrides)
+    /// 4. Environment variables with `MCB_VALIDATE__` prefix
     ///
     pub fn load(workspace_root: impl Into<PathBuf>) -> Self {
         let root = workspace_root.into();
@@ -82,7 +102,9 @@ impl FileConfig {
             .merge(Toml::string(EMBEDDED_VALIDATE_DEFAULTS))
             // Layer 2: Project-specific overrides (filesystem)
             .merge(Toml::file(root.join("config/mcb-validate-internal.toml")))
-            // Layer 3: Runtime env overrides
+            // Layer 3: Workspace root overrides (filesystem)
+            .merge(Toml::file(root.join(VALIDATE_PROJECT_CONFIG_FILE)))
+            // Layer 4: Runtime env overrides
             .merge(Env::prefixed("MCB_VALIDATE__").split("__").lowercase(true));
 
         let mut config: Self = match figment.extract() {
@@ -161,7 +183,7 @@ impl FileConfig {
 }
 
 /// General configuration settings
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct GeneralConfig {
     /// Workspace root path (auto-detected if not set)
     pub workspace_root: Option<PathBuf>,
@@ -186,10 +208,15 @@ pub struct GeneralConfig {
 
     /// Prefix for detecting internal workspace dependencies (e.g., "myapp-")
     pub internal_dep_prefix: String,
+
+    /// File names (case-insensitive) mapped to a language name, for files
+    /// that neither their extension nor their content identifies
+    #[serde(default)]
+    pub language_filenames: HashMap<String, String>,
 }
 
 /// Rule-specific configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct RulesConfig {
     /// Architecture validation rules
     pub architecture: ArchitectureRulesConfig,
@@ -238,10 +265,37 @@ pub struct RulesConfig {
 
     /// Dependency rules
     pub dependency: DependencyRulesConfig,
+
+    /// Module and crate cycle rules
+    pub cycles: CyclesRulesConfig,
+
+    /// Unused public API rules
+    pub dead_api: DeadApiRulesConfig,
+
+    /// Feature flag hygiene rules
+    pub features: FeaturesRulesConfig,
+
+    /// Dependency policy rules (bans, licenses, versions)
+    pub dependency_policy: DependencyPolicyRulesConfig,
+
+    /// Unsafe code and FFI boundary rules
+    pub unsafe_code: UnsafeCodeRulesConfig,
+
+    /// Public function test coverage rules
+    pub test_coverage: TestCoverageRulesConfig,
+
+    /// Python and TypeScript source rules
+    pub polyglot: PolyglotRulesConfig,
+
+    /// Function complexity growth rules
+    pub complexity_delta: ComplexityDeltaRulesConfig,
+
+    /// ADR document compliance rules
+    pub adr: AdrRulesConfig,
 }
 
 /// Architecture validation rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ArchitectureRulesConfig {
     /// Whether architecture validation is enabled
     pub enabled: bool,
@@ -254,7 +308,7 @@ pub struct ArchitectureRulesConfig {
 }
 
 /// Layer boundary configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct LayerBoundariesConfig {
     /// Allowed internal dependencies for domain layer
     pub domain_internal_deps: Vec<String>,
@@ -273,7 +327,7 @@ pub struct LayerBoundariesConfig {
 }
 
 /// Code quality rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct QualityRulesConfig {
     /// Whether quality validation is enabled
     pub enabled: bool,
@@ -298,7 +352,7 @@ pub struct QualityRulesConfig {
 }
 
 /// Organization rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct OrganizationRulesConfig {
     /// Whether organization validation is enabled
     pub enabled: bool,
@@ -315,7 +369,7 @@ pub struct OrganizationRulesConfig {
 }
 
 /// SOLID principles rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct SolidRulesConfig {
     /// Whether SOLID validation is enabled
     pub enabled: bool,
@@ -334,7 +388,7 @@ pub struct SolidRulesConfig {
 }
 
 /// Visibility rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct VisibilityRulesConfig {
     /// Whether visibility validation is enabled
     pub enabled: bool,
@@ -356,7 +410,7 @@ pub struct VisibilityRulesConfig {
 }
 
 /// Layer Flow rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct LayerFlowRulesConfig {
     /// Whether layer flow validation is enabled
     pub enabled: bool,
@@ -366,10 +420,37 @@ pub struct LayerFlowRulesConfig {
 
     /// List of crates to check for circular dependencies
     pub circular_dependency_check_crates: Vec<String>,
+
+    /// Architecture layers and the layers each one may depend on
+    #[serde(default)]
+    pub layers: Vec<LayerConfig>,
+}
+
+/// One architecture layer: `[[rules.layer_flow.layers]]`.
+///
+/// Code belongs to the first layer whose `paths` match the file, otherwise to
+/// the first layer whose `crates` match its package. Dependencies inside one
+/// layer are always allowed.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct LayerConfig {
+    /// Layer name referenced by `may_depend_on`
+    pub name: String,
+
+    /// Package name globs belonging to this layer (`mcb-domain`, `*-api`)
+    #[serde(default)]
+    pub crates: Vec<String>,
+
+    /// Workspace-relative path globs belonging to this layer (`src/domain/**`)
+    #[serde(default)]
+    pub paths: Vec<String>,
+
+    /// Names of the other layers this layer may depend on
+    #[serde(default)]
+    pub may_depend_on: Vec<String>,
 }
 
 /// Port/Adapter rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct PortAdapterRulesConfig {
     /// Whether port/adapter validation is enabled
     pub enabled: bool,
@@ -388,7 +469,7 @@ pub struct PortAdapterRulesConfig {
 }
 
 /// Clean Architecture rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CleanArchitectureRulesConfig {
     /// Whether clean architecture validation is enabled
     pub enabled: bool,
@@ -419,7 +500,7 @@ pub struct CleanArchitectureRulesConfig {
 }
 
 /// Naming rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct NamingRulesConfig {
     /// Whether naming validation is enabled
     pub enabled: bool,
@@ -447,7 +528,7 @@ pub struct NamingRulesConfig {
 }
 
 /// KISS rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct KISSRulesConfig {
     /// Whether KISS validation is enabled
     pub enabled: bool,
@@ -461,7 +542,7 @@ pub struct KISSRulesConfig {
 }
 
 /// Refactoring rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct RefactoringRulesConfig {
     /// Whether refactoring validation is enabled
     pub enabled: bool,
@@ -486,7 +567,7 @@ pub struct RefactoringRulesConfig {
 }
 
 /// Performance rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct PerformanceRulesConfig {
     /// Whether performance validation is enabled
     pub enabled: bool,
@@ -496,7 +577,7 @@ pub struct PerformanceRulesConfig {
 }
 
 /// Pattern rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct PatternRulesConfig {
     /// Whether pattern validation is enabled
     pub enabled: bool,
@@ -518,7 +599,7 @@ pub struct PatternRulesConfig {
 }
 
 /// Test Quality rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct TestQualityRulesConfig {
     /// Whether test quality validation is enabled
     pub enabled: bool,
@@ -528,7 +609,7 @@ pub struct TestQualityRulesConfig {
 }
 
 /// Implementation rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ImplementationRulesConfig {
     /// Whether implementation validation is enabled
     pub enabled: bool,
@@ -538,7 +619,7 @@ pub struct ImplementationRulesConfig {
 }
 
 /// Dependency validation rules configuration
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct DependencyRulesConfig {
     /// Whether dependency validation is enabled
     pub enabled: bool,
@@ -549,9 +630,134 @@ pub struct DependencyRulesConfig {
     pub bypass_boundaries: Vec<BypassBoundaryConfig>,
 }
 
+/// Module and crate cycle rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct CyclesRulesConfig {
+    /// Whether cycle detection is enabled
+    pub enabled: bool,
+
+    /// Accepted cycles, each given by its members (package names, or
+    /// `crate-dir::module::path` for modules) in any order
+    #[serde(default)]
+    pub allowed_cycles: Vec<Vec<String>>,
+}
+
+/// Unused public API rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct DeadApiRulesConfig {
+    /// Whether unused public item detection is enabled
+    pub enabled: bool,
+
+    /// Crates (directory names) whose public items are intentional API
+    /// for consumers outside the workspace
+    #[serde(default)]
+    pub public_api_crates: Vec<String>,
+
+    /// Item names that stay public even when unused outside their crate
+    #[serde(default)]
+    pub allowed_items: Vec<String>,
+}
+
+/// Feature flag hygiene rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct FeaturesRulesConfig {
+    /// Whether feature flag validation is enabled
+    pub enabled: bool,
+
+    /// Require `#[cfg_attr(docsrs, doc(cfg(...)))]` on feature-gated pub items
+    pub require_docsrs: bool,
+}
+
+/// Dependency policy rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct DependencyPolicyRulesConfig {
+    /// Whether dependency policy validation is enabled
+    pub enabled: bool,
+
+    /// Crates that must not appear anywhere in the dependency graph
+    #[serde(default)]
+    pub banned_crates: Vec<String>,
+
+    /// SPDX license identifiers accepted for third-party crates; an empty
+    /// list disables the license check
+    #[serde(default)]
+    pub allowed_licenses: Vec<String>,
+
+    /// Crates allowed to be locked at more than one version
+    #[serde(default)]
+    pub allowed_duplicates: Vec<String>,
+}
+
+/// Unsafe code and FFI boundary rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct UnsafeCodeRulesConfig {
+    /// Whether unsafe code auditing is enabled
+    pub enabled: bool,
+
+    /// Violation IDs (e.g. `UNSAFE002`) accepted per crate directory name
+    #[serde(default)]
+    pub allow: std::collections::BTreeMap<String, Vec<String>>,
+}
+
+/// Public function test coverage rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct TestCoverageRulesConfig {
+    /// Whether test coverage heuristics are enabled
+    pub enabled: bool,
+
+    /// Minimum percentage of a crate's public functions called from tests
+    /// or doc-tests; crates below it report their untested functions
+    pub min_coverage_percent: u8,
+
+    /// Crates (directory names) excluded from the check
+    #[serde(default)]
+    pub excluded_crates: Vec<String>,
+}
+
+/// Python and TypeScript source rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct PolyglotRulesConfig {
+    /// Whether non-Rust sources are validated
+    pub enabled: bool,
+
+    /// Languages checked: `python`, `typescript` (with `.tsx`), `javascript`
+    pub languages: Vec<String>,
+
+    /// Path substrings of files to skip
+    #[serde(default)]
+    pub excluded_paths: Vec<String>,
+}
+
+/// Function complexity growth rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ComplexityDeltaRulesConfig {
+    /// Whether functions are compared against the complexity baseline
+    pub enabled: bool,
+
+    /// Cyclomatic complexity a baselined function may gain before failing
+    pub max_cyclomatic_increase: u32,
+
+    /// Cognitive complexity a baselined function may gain before failing
+    pub max_cognitive_increase: u32,
+}
+
+/// ADR document compliance rules configuration
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct AdrRulesConfig {
+    /// Whether ADR documents are checked
+    pub enabled: bool,
+
+    /// Directory of ADR documents, relative to the workspace root
+    pub adr_dir: String,
+
+    /// Compliance rule file (relative to the workspace root); empty uses the
+    /// embedded default rules
+    pub rules_file: String,
+}
+
 /// A single bypass boundary check: scan files under `scan_root` for `pattern`
 /// and flag violations except in files listed in `allowed_files`.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct BypassBoundaryConfig {
     /// Violation ID to emit (e.g., "DEP004", "DEP005")
     pub violation_id: String,
@@ -568,7 +774,7 @@ pub struct BypassBoundaryConfig {
 }
 
 /// Validator enable/disable flags
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ValidatorsConfig {
     /// Enable dependency validation
     pub dependency: bool,
@@ -627,6 +833,39 @@ pub struct ValidatorsConfig {
     pub declarative: bool,
 }
 
+/// Rule plugin configuration
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+pub struct PluginsConfig {
+    /// YAML rule directories loaded next to the built-in rules
+    #[serde(default)]
+    pub rule_dirs: Vec<RulePluginConfig>,
+
+    /// Compiled WebAssembly validators
+    #[serde(default)]
+    pub wasm: Vec<WasmPluginConfig>,
+}
+
+/// One directory of user YAML rules
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct RulePluginConfig {
+    /// Rule directory (relative to workspace root); may hold a `templates/` subdirectory
+    pub path: PathBuf,
+    /// Prefix for the directory's rule IDs (`NAMESPACE::ID`); without one the
+    /// IDs are used as written and must not clash with built-in rules
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
+/// One compiled WebAssembly validator
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct WasmPluginConfig {
+    /// Module file (relative to workspace root)
+    pub path: PathBuf,
+    /// Prefix for the plugin's rule IDs (`NAMESPACE::ID`)
+    #[serde(default)]
+    pub namespace: Option<String>,
+}
+
 fn default_true() -> bool {
     true
 }
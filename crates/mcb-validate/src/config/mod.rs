@@ -4,15 +4,21 @@
 //! Configuration Module
 //!
 //! Provides file-based configuration for mcb-validate via figment
-//! layered providers (embedded TOML + filesystem overrides + env vars).
+//! layered providers (embedded TOML + filesystem overrides + env vars),
+//! and its JSON Schema.
 
 mod file_config;
+mod schema;
 
 pub use file_config::{
-    ArchitectureRulesConfig, BypassBoundaryConfig, CleanArchitectureRulesConfig,
-    DependencyRulesConfig, FileConfig, GeneralConfig, ImplementationRulesConfig, KISSRulesConfig,
-    LayerBoundariesConfig, LayerFlowRulesConfig, NamingRulesConfig, OrganizationRulesConfig,
-    PatternRulesConfig, PerformanceRulesConfig, PortAdapterRulesConfig, QualityRulesConfig,
-    RefactoringRulesConfig, RulesConfig, SolidRulesConfig, TestQualityRulesConfig,
-    ValidatorsConfig, VisibilityRulesConfig,
+    AdrRulesConfig, ArchitectureRulesConfig, BypassBoundaryConfig, CleanArchitectureRulesConfig,
+    ComplexityDeltaRulesConfig, CyclesRulesConfig, DeadApiRulesConfig, DependencyPolicyRulesConfig,
+    DependencyRulesConfig, FeaturesRulesConfig, FileConfig, GeneralConfig,
+    ImplementationRulesConfig, KISSRulesConfig, LayerBoundariesConfig, LayerConfig,
+    LayerFlowRulesConfig, NamingRulesConfig, OrganizationRulesConfig, PatternRulesConfig,
+    PerformanceRulesConfig, PluginsConfig, PolyglotRulesConfig, PortAdapterRulesConfig,
+    QualityRulesConfig, RefactoringRulesConfig, RulePluginConfig, RulesConfig, SolidRulesConfig,
+    TestCoverageRulesConfig, TestQualityRulesConfig, UnsafeCodeRulesConfig, ValidatorsConfig,
+    VisibilityRulesConfig, WasmPluginConfig,
 };
+pub use schema::ConfigIssue;
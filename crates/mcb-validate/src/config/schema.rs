@@ -0,0 +1,343 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Configuration Schema
+//!
+//! JSON Schema of the configuration files, derived from [`FileConfig`];
+//! a check of a configuration file against it that reports the line of
+//! each offending key; and the commented starter file written by
+//! `mcb validate init`.
+
+use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+
+use serde_json::Value;
+
+use super::file_config::{EMBEDDED_VALIDATE_DEFAULTS, FileConfig};
+use crate::{Result, ValidationError};
+use mcb_utils::constants::validate::VALIDATE_CONFIG_SCHEMA_FILE;
+
+/// Banner of the starter configuration file.
+const SCAFFOLD_HEADER: &str = "\
+# ==============================================================================
+# mcb-validate — Workspace Configuration
+# ==============================================================================
+# Layered over the built-in defaults, which are listed below commented out.
+# Uncomment a key to override it. `mcb validate` rejects unknown keys and
+# values of the wrong type, reporting the line of the offending key.
+";
+
+/// Problem found in a configuration file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// 1-based line of the offending key, or of the syntax error
+    pub line: usize,
+    /// Dotted path of the offending key; empty for syntax errors
+    pub key: String,
+    /// What is wrong
+    pub message: String,
+}
+
+/// `key: message`, or the bare message for syntax errors; callers prefix
+/// the file and line.
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.key, self.message)
+        }
+    }
+}
+
+impl FileConfig {
+    /// JSON Schema of a configuration file.
+    ///
+    /// Every file is layered over the embedded defaults, so no key is
+    /// required; undeclared keys are rejected so editors flag typos.
+    #[must_use]
+    pub fn json_schema() -> Value {
+        let mut schema = schemars::schema_for!(FileConfig).to_value();
+        relax_schema(&mut schema, true);
+        schema
+    }
+
+    /// Check the TOML `source` of a configuration file against
+    /// [`json_schema`](Self::json_schema).
+    ///
+    /// # Errors
+    /// Returns an error if the schema cannot be compiled.
+    pub fn check(source: &str) -> Result<Vec<ConfigIssue>> {
+        let table: toml::Table = match toml::from_str(source) {
+            Ok(table) => table,
+            Err(e) => {
+                return Ok(vec![ConfigIssue {
+                    line: e.span().map_or(1, |span| line_of(source, span.start)),
+                    key: String::new(),
+                    message: e.message().trim().to_owned(),
+                }]);
+            }
+        };
+        let instance = serde_json::to_value(&table)
+            .map_err(|e| ValidationError::Config(format!("cannot convert configuration: {e}")))?;
+
+        // Undeclared keys are collected below, each at its own line, instead
+        // of as one `additionalProperties` error on the enclosing table.
+        let mut schema = schemars::schema_for!(FileConfig).to_value();
+        relax_schema(&mut schema, false);
+        let validator = jsonschema::validator_for(&schema)
+            .map_err(|e| ValidationError::Config(format!("Schema compilation error: {e:?}")))?;
+
+        let lines = KeyLines::new(source);
+        let mut unknown = Vec::new();
+        unknown_keys(&schema, &schema, &instance, &mut Vec::new(), &mut unknown);
+        let mut issues: Vec<ConfigIssue> = unknown
+            .into_iter()
+            .map(|path| ConfigIssue {
+                line: lines.find(&path),
+                key: path.join("."),
+                message: "unknown key".to_owned(),
+            })
+            .collect();
+        issues.extend(validator.iter_errors(&instance).map(|error| {
+            let path = pointer_segments(&error.instance_path().to_string());
+            ConfigIssue {
+                line: lines.find(&path),
+                key: path.join("."),
+                message: error.to_string(),
+            }
+        }));
+        issues.sort_by_key(|issue| issue.line);
+        Ok(issues)
+    }
+
+    /// Starter configuration file: every default setting, commented out
+    /// below its description, under a `#:schema` directive that points TOML
+    /// language servers at the schema file.
+    #[must_use]
+    pub fn scaffold() -> String {
+        let defaults: toml::Table = toml::from_str(EMBEDDED_VALIDATE_DEFAULTS).unwrap_or_default();
+        let schema = Self::json_schema();
+        let mut out = format!("#:schema ./{VALIDATE_CONFIG_SCHEMA_FILE}\n{SCAFFOLD_HEADER}");
+        write_table(&mut out, &schema, &schema, &defaults, &mut Vec::new());
+        out
+    }
+}
+
+/// Drop `required` everywhere (each file is partial) and, when `strict`,
+/// close every struct to undeclared keys.
+fn relax_schema(schema: &mut Value, strict: bool) {
+    match schema {
+        Value::Object(map) => {
+            map.remove("required");
+            if strict && map.contains_key("properties") && !map.contains_key("additionalProperties")
+            {
+                map.insert("additionalProperties".to_owned(), Value::Bool(false));
+            }
+            map.values_mut()
+                .for_each(|value| relax_schema(value, strict));
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| relax_schema(item, strict)),
+        _ => {}
+    }
+}
+
+/// Follow a local `$ref` to its definition.
+fn resolve<'a>(root: &'a Value, node: &'a Value) -> &'a Value {
+    node.get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|reference| reference.strip_prefix('#'))
+        .and_then(|pointer| root.pointer(pointer))
+        .unwrap_or(node)
+}
+
+/// Schema of `key` in the object schema `node`: a declared property or the
+/// value schema of a map.
+fn property<'a>(root: &'a Value, node: &'a Value, key: &str) -> Option<&'a Value> {
+    let node = resolve(root, node);
+    node.get("properties")
+        .and_then(|properties| properties.get(key))
+        .or_else(|| node.get("additionalProperties").filter(|s| s.is_object()))
+        .or_else(|| {
+            node.get("anyOf")?
+                .as_array()?
+                .iter()
+                .find_map(|variant| property(root, variant, key))
+        })
+}
+
+/// Whether `node` describes a struct (declared properties) rather than a map.
+fn is_struct(root: &Value, node: &Value) -> bool {
+    resolve(root, node).get("properties").is_some()
+}
+
+/// Collect the paths of keys in `instance` that `node` does not declare.
+fn unknown_keys(
+    root: &Value,
+    node: &Value,
+    instance: &Value,
+    path: &mut Vec<String>,
+    found: &mut Vec<Vec<String>>,
+) {
+    match instance {
+        Value::Object(map) => {
+            for (key, value) in map {
+                path.push(key.clone());
+                match property(root, node, key) {
+                    Some(child) => unknown_keys(root, child, value, path, found),
+                    None if is_struct(root, node) => found.push(path.clone()),
+                    None => {}
+                }
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            let Some(item_schema) = resolve(root, node).get("items") else {
+                return;
+            };
+            for (index, item) in items.iter().enumerate() {
+                path.push(index.to_string());
+                unknown_keys(root, item_schema, item, path, found);
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Append the keys of `table` as commented-out settings, then its
+/// sub-tables as sections.
+fn write_table(
+    out: &mut String,
+    root: &Value,
+    node: &Value,
+    table: &toml::Table,
+    path: &mut Vec<String>,
+) {
+    let mut sections = Vec::new();
+    for (key, value) in table {
+        let schema = property(root, node, key);
+        match value {
+            toml::Value::Table(child) if schema.is_some_and(|s| is_struct(root, s)) => {
+                sections.push((key, child, schema));
+            }
+            _ => {
+                write_description(out, schema);
+                let _ = writeln!(out, "# {key} = {value}");
+            }
+        }
+    }
+    for (key, child, schema) in sections {
+        path.push(key.clone());
+        out.push('\n');
+        write_description(out, schema);
+        let _ = writeln!(out, "[{}]", path.join("."));
+        write_table(out, root, schema.unwrap_or(&Value::Null), child, path);
+        path.pop();
+    }
+}
+
+/// Append the `description` of `schema` as comment lines.
+fn write_description(out: &mut String, schema: Option<&Value>) {
+    let Some(description) = schema
+        .and_then(|s| s.get("description"))
+        .and_then(Value::as_str)
+    else {
+        return;
+    };
+    for line in description.lines() {
+        out.push_str(format!("# {line}").trim_end());
+        out.push('\n');
+    }
+}
+
+/// 1-based line of byte `offset` in `source`.
+fn line_of(source: &str, offset: usize) -> usize {
+    let end = offset.min(source.len());
+    source.as_bytes()[..end]
+        .iter()
+        .filter(|byte| **byte == b'\n')
+        .count()
+        + 1
+}
+
+/// Segments of a JSON pointer such as `/rules/quality/max_file_lines`.
+fn pointer_segments(pointer: &str) -> Vec<String> {
+    pointer
+        .split('/')
+        .skip(1)
+        .map(|segment| segment.replace("~1", "/").replace("~0", "~"))
+        .collect()
+}
+
+/// Segments of a dotted TOML key or table header, unquoted.
+fn split_key(key: &str) -> Vec<String> {
+    key.split('.')
+        .map(|part| part.trim().trim_matches('"').trim_matches('\'').to_owned())
+        .collect()
+}
+
+/// Line of every table header and key in a TOML source, by key path.
+struct KeyLines(HashMap<Vec<String>, usize>);
+
+impl KeyLines {
+    fn new(source: &str) -> Self {
+        let mut lines = HashMap::new();
+        let mut table: Vec<String> = Vec::new();
+        let mut array_counts: HashMap<Vec<String>, usize> = HashMap::new();
+        // Open brackets of a multi-line array value.
+        let mut open_brackets = 0_i64;
+        for (index, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if open_brackets > 0 {
+                open_brackets += bracket_balance(trimmed);
+                continue;
+            }
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let path = if let Some(header) = trimmed
+                .strip_prefix("[[")
+                .and_then(|rest| rest.split("]]").next())
+            {
+                let mut path = split_key(header);
+                let count = array_counts.entry(path.clone()).or_insert(0);
+                path.push(count.to_string());
+                *count += 1;
+                table = path.clone();
+                path
+            } else if let Some(header) = trimmed
+                .strip_prefix('[')
+                .and_then(|rest| rest.split(']').next())
+            {
+                table = split_key(header);
+                table.clone()
+            } else if let Some((key, value)) = trimmed.split_once('=') {
+                open_brackets = bracket_balance(value).max(0);
+                table.iter().cloned().chain(split_key(key)).collect()
+            } else {
+                continue;
+            };
+            lines.entry(path).or_insert(index + 1);
+        }
+        Self(lines)
+    }
+
+    /// Line of the longest prefix of `path` found in the source.
+    fn find(&self, path: &[String]) -> usize {
+        (0..=path.len())
+            .rev()
+            .find_map(|len| self.0.get(&path[..len]))
+            .copied()
+            .unwrap_or(1)
+    }
+}
+
+/// Opened minus closed square brackets on a line.
+fn bracket_balance(line: &str) -> i64 {
+    line.chars().fold(0, |balance, c| match c {
+        '[' => balance + 1,
+        ']' => balance - 1,
+        _ => balance,
+    })
+}
@@ -0,0 +1,245 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! Dependency Diagrams
+//!
+//! Renders the crate graph of a workspace and the module graph of each
+//! crate as Mermaid and Graphviz DOT, from the same `Cargo.toml` and `use`
+//! analysis the cycle validator runs. Edges created by a `pub use` are
+//! labelled as re-exports.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::validators::cycles::{
+    ModuleGraph, crate_graph, crate_sources, expand_use_tree, is_pub_use, use_declarations,
+};
+use crate::{Result, ValidationConfig};
+use mcb_utils::constants::validate::{
+    DIAGRAM_CRATES_STEM, DIAGRAM_MODULES_SUFFIX, DIAGRAM_REEXPORT_LABEL, DIAGRAM_ROOT_MODULE,
+    USE_DECLARATION_REGEX,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+/// A directed dependency graph ready to be rendered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DependencyDiagram {
+    /// Diagram title.
+    pub title: String,
+    /// Node names, including nodes without edges.
+    pub nodes: BTreeSet<String>,
+    /// Edges as `(from, to)`, mapped to whether `from` re-exports `to`.
+    pub edges: BTreeMap<(String, String), bool>,
+}
+
+impl DependencyDiagram {
+    /// Create an empty diagram.
+    #[must_use]
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Add a node.
+    pub fn add_node(&mut self, name: impl Into<String>) {
+        self.nodes.insert(name.into());
+    }
+
+    /// Add an edge and its nodes; an edge added once as a re-export stays one.
+    pub fn add_edge(&mut self, from: &str, to: &str, reexport: bool) {
+        self.add_node(from);
+        self.add_node(to);
+        *self
+            .edges
+            .entry((from.to_owned(), to.to_owned()))
+            .or_default() |= reexport;
+    }
+
+    /// Diagram of the workspace crates and their `[dependencies]` and
+    /// `[build-dependencies]` on each other. An edge is a re-export when
+    /// the dependent crate has a `pub use` of the dependency.
+    ///
+    /// # Errors
+    /// Returns an error if a manifest or source file cannot be read.
+    pub fn crates(config: &ValidationConfig) -> Result<Self> {
+        let use_declaration = compile_regex(USE_DECLARATION_REGEX)?;
+        let (edges, manifests) = crate_graph(config)?;
+        let sources = crate_sources(config)?;
+
+        let mut diagram = Self::new("Workspace crates");
+        for (name, manifest) in &manifests {
+            diagram.add_node(name);
+            let reexported = manifest
+                .parent()
+                .and_then(Path::file_name)
+                .and_then(|dir| sources.get(dir.to_string_lossy().as_ref()))
+                .map(|files| reexported_roots(&use_declaration, files))
+                .unwrap_or_default();
+            for dependency in edges.get(name).into_iter().flatten() {
+                let reexport = reexported.contains(&dependency.replace('-', "_"));
+                diagram.add_edge(name, dependency, reexport);
+            }
+        }
+        Ok(diagram)
+    }
+
+    /// One diagram per crate (keyed by crate directory name) of its modules
+    /// and the `use` imports between them. Re-export edges include modules
+    /// re-exporting their own submodules; the crate root is
+    /// [`DIAGRAM_ROOT_MODULE`].
+    ///
+    /// # Errors
+    /// Returns an error if a source file cannot be read.
+    pub fn modules(config: &ValidationConfig) -> Result<BTreeMap<String, Self>> {
+        let mut diagrams = BTreeMap::new();
+        for (crate_name, files) in crate_sources(config)? {
+            let graph =
+                ModuleGraph::build(files.iter().map(|(module, file, content)| {
+                    (module.clone(), file.clone(), content.as_str())
+                }))?;
+            let mut diagram = Self::new(format!("{crate_name} modules"));
+            for (module, _, _) in &files {
+                diagram.add_node(module_node(module));
+            }
+            for (module, targets) in &graph.edges {
+                for target in targets {
+                    let reexport = graph.reexports.contains(&(module.clone(), target.clone()));
+                    diagram.add_edge(module_node(module), module_node(target), reexport);
+                }
+            }
+            for (module, target) in &graph.reexports {
+                diagram.add_edge(module_node(module), module_node(target), true);
+            }
+            diagrams.insert(crate_name, diagram);
+        }
+        Ok(diagrams)
+    }
+
+    /// Write the crate diagram and every module diagram to `out_dir` as
+    /// `.mmd` and `.dot` files, returning the written paths.
+    ///
+    /// # Errors
+    /// Returns an error if the analysis fails or a file cannot be written.
+    pub fn write_all(config: &ValidationConfig, out_dir: &Path) -> Result<Vec<PathBuf>> {
+        std::fs::create_dir_all(out_dir)?;
+        let mut diagrams = vec![(DIAGRAM_CRATES_STEM.to_owned(), Self::crates(config)?)];
+        diagrams.extend(
+            Self::modules(config)?
+                .into_iter()
+                .map(|(name, diagram)| (format!("{name}{DIAGRAM_MODULES_SUFFIX}"), diagram)),
+        );
+
+        let mut written = Vec::new();
+        for (stem, diagram) in diagrams {
+            for (extension, content) in [("mmd", diagram.to_mermaid()), ("dot", diagram.to_dot())] {
+                let path = out_dir.join(format!("{stem}.{extension}"));
+                std::fs::write(&path, content)?;
+                written.push(path);
+            }
+        }
+        Ok(written)
+    }
+
+    /// Render as a Mermaid flowchart.
+    #[must_use]
+    pub fn to_mermaid(&self) -> String {
+        let ids: BTreeMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.as_str(), format!("n{index}")))
+            .collect();
+        let mut out = format!(
+            "---\ntitle: {}\n---\nflowchart LR\n",
+            mermaid_escape(&self.title)
+        );
+        for (node, id) in &ids {
+            let _ = writeln!(out, "    {id}[\"{}\"]", mermaid_escape(node));
+        }
+        for ((from, to), reexport) in &self.edges {
+            let arrow = if *reexport {
+                format!("-->|{DIAGRAM_REEXPORT_LABEL}|")
+            } else {
+                "-->".to_owned()
+            };
+            let _ = writeln!(
+                out,
+                "    {} {arrow} {}",
+                ids[from.as_str()],
+                ids[to.as_str()]
+            );
+        }
+        out
+    }
+
+    /// Render as a Graphviz DOT digraph.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut out = format!(
+            "digraph \"{}\" {{\n    rankdir=LR;\n    node [shape=box];\n",
+            dot_escape(&self.title)
+        );
+        for node in &self.nodes {
+            let _ = writeln!(out, "    \"{}\";", dot_escape(node));
+        }
+        for ((from, to), reexport) in &self.edges {
+            let label = if *reexport {
+                format!(" [label=\"{DIAGRAM_REEXPORT_LABEL}\"]")
+            } else {
+                String::new()
+            };
+            let _ = writeln!(
+                out,
+                "    \"{}\" -> \"{}\"{label};",
+                dot_escape(from),
+                dot_escape(to)
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+}
+
+/// Diagram node of a module path.
+fn module_node(module: &str) -> &str {
+    if module.is_empty() {
+        DIAGRAM_ROOT_MODULE
+    } else {
+        module
+    }
+}
+
+/// First path segments (crate names) of the `pub use` declarations in `files`.
+fn reexported_roots(
+    use_declaration: &Regex,
+    files: &[(String, PathBuf, String)],
+) -> BTreeSet<String> {
+    let mut roots = BTreeSet::new();
+    for (_, _, content) in files {
+        let lines: Vec<&str> = content.lines().collect();
+        for (line, tree) in use_declarations(use_declaration, content) {
+            if !lines.get(line - 1).is_some_and(|text| is_pub_use(text)) {
+                continue;
+            }
+            for path in expand_use_tree(&tree) {
+                if let Some(root) = path.trim_start_matches("::").split("::").next() {
+                    roots.insert(root.trim().to_owned());
+                }
+            }
+        }
+    }
+    roots
+}
+
+fn mermaid_escape(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
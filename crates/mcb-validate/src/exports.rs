@@ -3,17 +3,30 @@
 //! All symbols that are part of the crate's public API are re-exported here.
 //! lib.rs then does `pub use exports::*` so there is a single place for the re-export list.
 
+pub use crate::api_changelog::{ApiChange, ApiChangelog};
 pub use crate::ast::*;
+pub use crate::baseline::{Baseline, BaselineEntry, BaselineOutcome};
 pub use crate::config::*;
+pub use crate::dependency_diagram::DependencyDiagram;
 pub use crate::embedded_rules::EmbeddedRules;
 pub use crate::engines::{HybridRuleEngine, RuleEngineType};
+pub use crate::fact_cache::FactCache;
+pub use crate::fix::{FileFix, FixEngine, FixPlan, TextEdit};
 pub use crate::generic_reporter::{GenericReport, GenericReporter, GenericSummary};
+pub use crate::github_reporter::GithubReporter;
+pub use crate::html_reporter::{HtmlReporter, ReportHistory, RunSummary};
+pub use crate::junit_reporter::JunitReporter;
 pub use crate::linters::{
     ClippyLinter, LintViolation, LinterEngine, LinterType, RuffLinter, YamlRuleExecutor,
 };
+pub use crate::lsp::LspServer;
 pub use crate::metrics::*;
 pub use crate::rules::*;
 pub use crate::run_context::{FileInventorySource, InventoryEntry, ValidationRunContext};
+pub use crate::sarif_reporter::{SarifLog, SarifReporter};
+pub use crate::suppression::{
+    Suppression, SuppressionOutcome, SuppressionParser, SuppressionViolation, apply_suppressions,
+};
 pub use crate::thresholds::{ValidationThresholds, thresholds};
 
 pub use crate::validators::*;
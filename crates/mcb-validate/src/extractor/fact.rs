@@ -17,6 +17,8 @@ pub enum FactType {
     Module,
     /// A struct definition.
     Struct,
+    /// An enum definition.
+    Enum,
     /// A function definition.
     Function,
     /// A `use` import declaration.
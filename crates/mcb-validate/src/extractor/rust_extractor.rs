@@ -4,10 +4,13 @@
 //! Rust-specific AST fact extractor.
 //!
 //! Uses `rust-code-analysis` (which wraps `tree-sitter`) to parse Rust source
-//! files and extract [`Fact`]s for modules, imports, structs, and functions.
+//! files and extract [`Fact`]s for modules, imports, structs, enums, traits
+//! and functions.
 
 use super::fact::{Fact, FactType, Location};
 use crate::Result;
+use crate::run_context::ValidationRunContext;
+use mcb_utils::constants::validate::{FACT_ATTR_VISIBILITY, FACT_CACHE_KIND_FACTS};
 use rust_code_analysis::{Node, ParserTrait, RustParser};
 use std::fs;
 use std::path::Path;
@@ -31,13 +34,27 @@ impl RustExtractor {
     /// Currently extracts:
     /// - One `Module` fact per file (derived from the file stem).
     /// - `Import` facts for every `use` declaration.
-    /// - `Struct` facts for every `struct` item.
-    /// - `Function` facts for every `fn` item.
+    /// - `Struct`, `Enum`, `Trait` and `Function` facts for every `struct`,
+    ///   `enum`, `trait` and `fn` item, with a `visibility` attribute when
+    ///   the item has a visibility modifier.
     /// # Errors
     ///
     /// Returns an error if the file cannot be read or parsed.
+    ///
+    /// Facts are served from the run's persistent fact cache when the file
+    /// is unchanged since they were last extracted.
     pub fn extract_facts(&self, path: &Path) -> Result<Vec<Fact>> {
         let code = fs::read(path)?;
+        let Some(cache) = ValidationRunContext::active().and_then(|ctx| ctx.fact_cache()) else {
+            return Ok(Self::parse_facts(path, code));
+        };
+        let content = String::from_utf8_lossy(&code).into_owned();
+        cache.get_or_extract(FACT_CACHE_KIND_FACTS, path, &content, || {
+            Ok(Self::parse_facts(path, code))
+        })
+    }
+
+    fn parse_facts(path: &Path, code: Vec<u8>) -> Vec<Fact> {
         // Use RustParser which is a public type alias for Parser<RustCode>
         let parser = RustParser::new(code, path, None);
         let root = parser.get_root();
@@ -69,6 +86,8 @@ impl RustExtractor {
         };
         Self::collect_imports(&ctx, &root, &mut facts);
         Self::collect_named(&ctx, &root, "struct_item", &FactType::Struct, &mut facts);
+        Self::collect_named(&ctx, &root, "enum_item", &FactType::Enum, &mut facts);
+        Self::collect_named(&ctx, &root, "trait_item", &FactType::Trait, &mut facts);
         Self::collect_named(
             &ctx,
             &root,
@@ -77,7 +96,7 @@ impl RustExtractor {
             &mut facts,
         );
 
-        Ok(facts)
+        facts
     }
 
     /// Collect every AST node of `kind` reachable from `root`.
@@ -124,12 +143,23 @@ impl RustExtractor {
             if let Some(name_node) = node.0.child_by_field_name("name")
                 && let Ok(name) = name_node.utf8_text(ctx.code_ref)
             {
-                facts.push(Fact::new(
+                let mut fact = Fact::new(
                     name.to_owned(),
                     fact_type.clone(),
                     Self::location_for(ctx.path, &node),
                     Some(ctx.module_id.to_owned()),
-                ));
+                );
+                let mut cursor = node.0.walk();
+                if let Some(visibility) = node
+                    .0
+                    .children(&mut cursor)
+                    .find(|child| child.kind() == "visibility_modifier")
+                    .and_then(|child| child.utf8_text(ctx.code_ref).ok())
+                {
+                    fact.attributes
+                        .insert(FACT_ATTR_VISIBILITY.to_owned(), visibility.to_owned());
+                }
+                facts.push(fact);
             }
         }
     }
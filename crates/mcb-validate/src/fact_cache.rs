@@ -0,0 +1,146 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! Persistent Fact Cache
+//!
+//! Stores facts and AST-derived metrics extracted from source files on disk
+//! (`.mcb-validate-cache/` by default), so unchanged files are not parsed
+//! again on the next run. Entries are keyed by a hash of the cache layout
+//! version, the `mcb-validate` version, the entry kind, the file path and
+//! the file content: editing a file or upgrading the validator misses the
+//! old entry without any explicit invalidation. The cache is best effort —
+//! unreadable, corrupt or unwritable entries fall back to re-extracting.
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Result;
+use mcb_utils::constants::validate::FACT_CACHE_FORMAT_VERSION;
+use mcb_utils::utils::id::compute_content_hash;
+
+/// Distinguishes temporary files written concurrently by the same process.
+static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// On-disk cache of per-file extraction results.
+#[derive(Debug, Clone)]
+pub struct FactCache {
+    dir: PathBuf,
+}
+
+impl FactCache {
+    /// Cache rooted at `dir`; the directory is created on first write.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Cache root directory.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cached `kind` entry for `path` with `content`, if present and readable.
+    #[must_use]
+    pub fn get<T: DeserializeOwned>(&self, kind: &str, path: &Path, content: &str) -> Option<T> {
+        let entry = self.entry_path(kind, path, content);
+        let bytes = std::fs::read(&entry).ok()?;
+        match serde_json::from_slice(&bytes) {
+            Ok(value) => Some(value),
+            Err(e) => {
+                mcb_domain::debug!(
+                    "fact_cache",
+                    "Ignoring corrupt cache entry",
+                    &format!("entry={} error={e}", entry.display())
+                );
+                None
+            }
+        }
+    }
+
+    /// Store `value` as the `kind` entry for `path` with `content`.
+    ///
+    /// Entries are written to a temporary file and renamed into place, so
+    /// concurrent readers never see a partial entry. Failures are logged and
+    /// otherwise ignored.
+    pub fn put<T: Serialize>(&self, kind: &str, path: &Path, content: &str, value: &T) {
+        let entry = self.entry_path(kind, path, content);
+        if let Err(e) = Self::write_entry(&entry, value) {
+            mcb_domain::debug!(
+                "fact_cache",
+                "Failed to write cache entry",
+                &format!("entry={} error={e}", entry.display())
+            );
+        }
+    }
+
+    /// Cached `kind` entry for `path`, or the result of `extract`, which is
+    /// cached on success.
+    ///
+    /// # Errors
+    /// Returns the error from `extract`.
+    pub fn get_or_extract<T, F>(
+        &self,
+        kind: &str,
+        path: &Path,
+        content: &str,
+        extract: F,
+    ) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: FnOnce() -> Result<T>,
+    {
+        if let Some(value) = self.get(kind, path, content) {
+            return Ok(value);
+        }
+        let value = extract()?;
+        self.put(kind, path, content, &value);
+        Ok(value)
+    }
+
+    /// Delete every cached entry.
+    ///
+    /// # Errors
+    /// Returns an error if the cache directory exists but cannot be removed.
+    pub fn clear(&self) -> Result<()> {
+        match std::fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+
+    fn entry_path(&self, kind: &str, path: &Path, content: &str) -> PathBuf {
+        let key = compute_content_hash(&format!(
+            "{FACT_CACHE_FORMAT_VERSION}\0{}\0{kind}\0{}\0{content}",
+            env!("CARGO_PKG_VERSION"),
+            path.display()
+        ));
+        self.dir
+            .join(format!("v{FACT_CACHE_FORMAT_VERSION}"))
+            .join(kind)
+            .join(&key[..2])
+            .join(format!("{key}.json"))
+    }
+
+    fn write_entry<T: Serialize>(entry: &Path, value: &T) -> Result<()> {
+        let Some(parent) = entry.parent() else {
+            return Ok(());
+        };
+        std::fs::create_dir_all(parent)?;
+        let json = serde_json::to_vec(value).map_err(std::io::Error::other)?;
+        let temp = entry.with_extension(format!(
+            "tmp-{}-{}",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::write(&temp, json)?;
+        if let Err(e) = std::fs::rename(&temp, entry) {
+            let _ = std::fs::remove_file(&temp);
+            return Err(e.into());
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::path::Path;
 
 use rust_code_analysis::{LANG, guess_language};
@@ -52,7 +53,8 @@ pub fn language_from_rca(lang: LANG) -> Option<LanguageId> {
 
 /// Component for detecting the programming language of a file.
 pub struct LanguageDetector {
-    _private: (),
+    /// Project filename mappings (lowercase), checked before anything else.
+    filenames: HashMap<String, LanguageId>,
 }
 
 impl Default for LanguageDetector {
@@ -65,19 +67,35 @@ impl LanguageDetector {
     /// Create a new language detector instance.
     #[must_use]
     pub fn new() -> Self {
-        Self { _private: () }
+        Self {
+            filenames: HashMap::new(),
+        }
+    }
+
+    /// Map exact file names (case-insensitive, e.g. `Jenkinsfile`) to a
+    /// language, overriding extension and content detection for them.
+    #[must_use]
+    pub fn with_filenames(mut self, filenames: HashMap<String, LanguageId>) -> Self {
+        self.filenames = filenames
+            .into_iter()
+            .map(|(name, lang)| (name.to_ascii_lowercase(), lang))
+            .collect();
+        self
     }
 
     /// Detect the language of a file at the given path, optionally using its content.
+    ///
+    /// Tries the configured filenames and the extension, then
+    /// [`LanguageId::from_filename_or_content`], the content heuristics and
+    /// finally `rust-code-analysis` guessing.
     pub fn detect(&self, path: &Path, content: Option<&str>) -> Option<LanguageId> {
-        if let Some(ext) = path.extension().and_then(|ext| ext.to_str())
-            && let Some(lang) = LanguageId::from_extension(ext)
+        let filename = path.file_name().and_then(|name| name.to_str());
+        if let Some(lang) = filename.and_then(|name| self.filenames.get(&name.to_ascii_lowercase()))
         {
-            return Some(lang);
+            return Some(*lang);
         }
-
-        if let Some(filename) = path.file_name().and_then(|name| name.to_str())
-            && let Some(lang) = LanguageId::from_filename(filename)
+        if let Some(ext) = path.extension().and_then(|ext| ext.to_str())
+            && let Some(lang) = LanguageId::from_extension(ext)
         {
             return Some(lang);
         }
@@ -86,18 +104,16 @@ impl LanguageDetector {
             || std::fs::read(path).unwrap_or_default(),
             |c| c.as_bytes().to_vec(),
         );
-        let (rca_lang, _) = guess_language(&source, path);
-        if let Some(lang) = rca_lang.and_then(language_from_rca) {
+        let text = std::str::from_utf8(&source).ok();
+        if let Some(lang) = filename
+            .and_then(|name| LanguageId::from_filename_or_content(name, text))
+            .or_else(|| text.and_then(LanguageId::from_content))
+        {
             return Some(lang);
         }
 
-        let first_line = content.and_then(|body| body.lines().next()).or_else(|| {
-            std::str::from_utf8(&source)
-                .ok()
-                .and_then(|body| body.lines().next())
-        });
-
-        first_line.and_then(LanguageId::from_shebang)
+        let (rca_lang, _) = guess_language(&source, path);
+        rca_lang.and_then(language_from_rca)
     }
 
     /// Detect language and return its name.
@@ -0,0 +1,387 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! Auto-Fix Engine
+//!
+//! Applies the mechanical `fixes` declared on YAML rules to the lines their
+//! violations point at:
+//!
+//! - `replace_pattern` — replace every `pattern` match on the line with
+//!   `replacement` (regex syntax, `${1}` refers to capture groups)
+//! - `insert_before` — insert `replacement` as a new line above, indented
+//!   like the flagged line
+//! - `delete_line` / `remove_import` — remove the flagged line
+//!
+//! Other fix types (`refactor`, `documentation`, ...) are advice for humans
+//! and are never applied. Fixes are planned as byte-span edits against each
+//! file's current content; when two edits in a file overlap, the first is
+//! kept and the other skipped. A [`FixPlan`] renders as a unified diff for
+//! dry runs or is written back with [`FixPlan::apply`].
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::generic_reporter::workspace_relative;
+use crate::rules::yaml_loader::{RuleFix, ValidatedRule};
+use crate::validators::DeclarativeValidator;
+use crate::{Result, Violation};
+use mcb_utils::constants::validate::{
+    FIX_TYPE_DELETE_LINE, FIX_TYPE_INSERT_BEFORE, FIX_TYPE_REMOVE_IMPORT, FIX_TYPE_REPLACE_PATTERN,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+/// A mechanical fix compiled from a [`RuleFix`].
+enum FixAction {
+    Replace { pattern: Regex, replacement: String },
+    InsertBefore(String),
+    DeleteLine,
+}
+
+impl FixAction {
+    /// The applicable action for `fix`, or `None` for advisory fix types.
+    fn compile(fix: &RuleFix) -> Result<Option<Self>> {
+        Ok(match fix.fix_type.as_str() {
+            FIX_TYPE_REPLACE_PATTERN => match (&fix.pattern, &fix.replacement) {
+                (Some(pattern), Some(replacement)) => Some(Self::Replace {
+                    pattern: compile_regex(pattern)?,
+                    replacement: replacement.clone(),
+                }),
+                _ => None,
+            },
+            FIX_TYPE_INSERT_BEFORE => fix.replacement.clone().map(Self::InsertBefore),
+            FIX_TYPE_DELETE_LINE | FIX_TYPE_REMOVE_IMPORT => Some(Self::DeleteLine),
+            _ => None,
+        })
+    }
+
+    /// Edits for the line spanning `start..end` (without its newline) of
+    /// `content`; `next` is where the following line starts.
+    fn edits(
+        &self,
+        rule_id: &str,
+        content: &str,
+        (start, end, next): (usize, usize, usize),
+    ) -> Vec<TextEdit> {
+        let line = &content[start..end];
+        let edit = |span_start: usize, span_end: usize, replacement: String| TextEdit {
+            rule_id: rule_id.to_owned(),
+            start: span_start,
+            end: span_end,
+            replacement,
+        };
+        match self {
+            Self::Replace {
+                pattern,
+                replacement,
+            } => pattern
+                .captures_iter(line)
+                .filter_map(|captures| {
+                    let matched = captures.get(0)?;
+                    let mut text = String::new();
+                    captures.expand(replacement, &mut text);
+                    Some(edit(start + matched.start(), start + matched.end(), text))
+                })
+                .collect(),
+            Self::InsertBefore(text) => {
+                let indent = &line[..line.len() - line.trim_start().len()];
+                vec![edit(start, start, format!("{indent}{text}\n"))]
+            }
+            Self::DeleteLine => vec![edit(start, next, String::new())],
+        }
+    }
+}
+
+/// Replacement of the bytes `start..end` of a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextEdit {
+    /// Rule whose fix produced the edit.
+    pub rule_id: String,
+    /// Byte offset where the replaced span starts.
+    pub start: usize,
+    /// Byte offset where the replaced span ends (exclusive).
+    pub end: usize,
+    /// Text written in place of the span.
+    pub replacement: String,
+}
+
+impl TextEdit {
+    /// Whether the spans share a byte; an insertion overlaps a span only
+    /// when it falls strictly inside it.
+    fn overlaps(&self, other: &Self) -> bool {
+        self.start < other.end && other.start < self.end
+    }
+}
+
+/// Planned edits for one file.
+#[derive(Debug, Clone)]
+pub struct FileFix {
+    /// File to rewrite.
+    pub path: PathBuf,
+    /// Content the edits were planned against.
+    pub original: String,
+    /// Non-overlapping edits, ordered by position.
+    pub edits: Vec<TextEdit>,
+}
+
+impl FileFix {
+    /// Content with every edit applied.
+    #[must_use]
+    pub fn fixed(&self) -> String {
+        Self::apply_edits(&self.original, 0, &self.edits)
+    }
+
+    /// Apply `edits` (ordered, offsets relative to the file) to `text`, which
+    /// starts at byte `base` of the file.
+    fn apply_edits(text: &str, base: usize, edits: &[TextEdit]) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for edit in edits {
+            out.push_str(&text[cursor..edit.start - base]);
+            out.push_str(&edit.replacement);
+            cursor = edit.end - base;
+        }
+        out.push_str(&text[cursor..]);
+        out
+    }
+
+    /// Unified diff hunks (without context lines) for this file.
+    fn write_diff(&self, out: &mut String, display_path: &str) {
+        let line_starts = line_starts(&self.original);
+        let line_of = |offset: usize| line_starts.partition_point(|&s| s <= offset) - 1;
+        let line_end = |line: usize| {
+            line_starts
+                .get(line + 1)
+                .copied()
+                .unwrap_or(self.original.len())
+        };
+
+        // Group edits touching the same lines into one hunk.
+        let mut groups: Vec<(usize, usize, Vec<TextEdit>)> = Vec::new();
+        for edit in &self.edits {
+            let first = line_of(edit.start);
+            let last = if edit.end > edit.start {
+                line_of(edit.end - 1)
+            } else {
+                first
+            };
+            match groups.last_mut() {
+                Some((_, group_last, group_edits)) if first <= *group_last => {
+                    *group_last = (*group_last).max(last);
+                    group_edits.push(edit.clone());
+                }
+                _ => groups.push((first, last, vec![edit.clone()])),
+            }
+        }
+
+        let _ = writeln!(out, "--- a/{display_path}\n+++ b/{display_path}");
+        let mut delta: isize = 0;
+        for (first, last, edits) in groups {
+            let start = line_starts[first];
+            let old = &self.original[start..line_end(last)];
+            let new = Self::apply_edits(old, start, &edits);
+            let old_count = old.split_inclusive('\n').count();
+            let new_count = new.split_inclusive('\n').count();
+            let old_start = first + 1;
+            let new_start = old_start.saturating_add_signed(delta);
+            let _ = writeln!(
+                out,
+                "@@ -{old_start},{old_count} +{},{new_count} @@",
+                if new_count == 0 {
+                    new_start - 1
+                } else {
+                    new_start
+                }
+            );
+            for line in old.split_inclusive('\n') {
+                let _ = writeln!(out, "-{}", line.trim_end_matches('\n'));
+            }
+            for line in new.split_inclusive('\n') {
+                let _ = writeln!(out, "+{}", line.trim_end_matches('\n'));
+            }
+            delta += new_count as isize - old_count as isize;
+        }
+    }
+}
+
+/// Fixes planned for a set of violations.
+#[derive(Debug, Clone, Default)]
+pub struct FixPlan {
+    /// Files with at least one edit, ordered by path.
+    pub files: Vec<FileFix>,
+    /// Indexes of the violations resolved by the plan.
+    pub fixed: Vec<usize>,
+    /// Edits dropped because they overlapped an earlier edit.
+    pub skipped: usize,
+}
+
+impl FixPlan {
+    /// Whether the plan changes nothing.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    /// Total number of edits.
+    #[must_use]
+    pub fn edit_count(&self) -> usize {
+        self.files.iter().map(|file| file.edits.len()).sum()
+    }
+
+    /// Unified diff of the plan, with paths relative to `workspace_root`.
+    #[must_use]
+    pub fn diff(&self, workspace_root: &Path) -> String {
+        let mut out = String::new();
+        for file in &self.files {
+            file.write_diff(
+                &mut out,
+                &workspace_relative(&file.path.to_string_lossy(), workspace_root),
+            );
+        }
+        out
+    }
+
+    /// Write every fixed file back to disk.
+    ///
+    /// # Errors
+    /// Returns an error if a file cannot be written.
+    pub fn apply(&self) -> Result<()> {
+        for file in &self.files {
+            std::fs::write(&file.path, file.fixed())?;
+        }
+        Ok(())
+    }
+}
+
+/// Plans fixes for violations of rules that declare mechanical fixes.
+pub struct FixEngine {
+    actions: HashMap<String, Vec<FixAction>>,
+}
+
+impl FixEngine {
+    /// Compile the mechanical fixes of `rules`.
+    ///
+    /// # Errors
+    /// Returns an error if a `replace_pattern` fix has an invalid regex.
+    pub fn from_rules(rules: &[ValidatedRule]) -> Result<Self> {
+        let mut actions: HashMap<String, Vec<FixAction>> = HashMap::new();
+        for rule in rules.iter().filter(|rule| rule.enabled) {
+            for fix in &rule.fixes {
+                if let Some(action) = FixAction::compile(fix)? {
+                    actions.entry(rule.id.clone()).or_default().push(action);
+                }
+            }
+        }
+        Ok(Self { actions })
+    }
+
+    /// Engine for the rules `mcb validate` runs in `workspace_root`.
+    ///
+    /// # Errors
+    /// Returns an error if the rules cannot be loaded or a fix fails to compile.
+    pub fn for_workspace(workspace_root: &Path) -> Result<Self> {
+        Self::from_rules(&DeclarativeValidator::new(workspace_root).load_rules()?)
+    }
+
+    /// Whether violations of `rule_id` can be fixed.
+    #[must_use]
+    pub fn is_fixable(&self, rule_id: &str) -> bool {
+        self.actions.contains_key(rule_id)
+    }
+
+    /// Plan the fixes for `violations`. Relative violation paths are
+    /// resolved against `workspace_root`; violations without a line, and
+    /// files that cannot be read, are left alone.
+    #[must_use]
+    pub fn plan(&self, violations: &[Box<dyn Violation>], workspace_root: &Path) -> FixPlan {
+        let mut files: BTreeMap<PathBuf, (String, Vec<TextEdit>)> = BTreeMap::new();
+        let mut plan = FixPlan::default();
+
+        for (index, violation) in violations.iter().enumerate() {
+            let (Some(actions), Some(file), Some(line)) = (
+                self.actions.get(violation.id()),
+                violation.file(),
+                violation.line(),
+            ) else {
+                continue;
+            };
+            let path = if file.is_absolute() {
+                file.clone()
+            } else {
+                workspace_root.join(file)
+            };
+            if !files.contains_key(&path) {
+                let Ok(content) = std::fs::read_to_string(&path) else {
+                    continue;
+                };
+                files.insert(path.clone(), (content, Vec::new()));
+            }
+            let Some((content, planned)) = files.get_mut(&path) else {
+                continue;
+            };
+            let content = content.as_str();
+            let Some(span) = line_span(content, line) else {
+                continue;
+            };
+
+            let edits: Vec<TextEdit> = actions
+                .iter()
+                .map(|action| action.edits(violation.id(), content, span))
+                .find(|edits| !edits.is_empty())
+                .unwrap_or_default();
+            let mut resolved = false;
+            for edit in edits {
+                if planned.contains(&edit) {
+                    resolved = true;
+                } else if planned.iter().any(|other| other.overlaps(&edit)) {
+                    plan.skipped += 1;
+                } else {
+                    planned.push(edit);
+                    resolved = true;
+                }
+            }
+            if resolved {
+                plan.fixed.push(index);
+            }
+        }
+
+        plan.files = files
+            .into_iter()
+            .filter(|(_, (_, edits))| !edits.is_empty())
+            .map(|(path, (original, mut edits))| {
+                edits.sort_by_key(|edit| (edit.start, edit.end));
+                FileFix {
+                    path,
+                    original,
+                    edits,
+                }
+            })
+            .collect();
+        plan
+    }
+}
+
+/// Byte offset of the start of every line.
+fn line_starts(content: &str) -> Vec<usize> {
+    std::iter::once(0)
+        .chain(content.match_indices('\n').map(|(i, _)| i + 1))
+        .filter(|&start| start < content.len() || start == 0)
+        .collect()
+}
+
+/// `(start, end, next)` of 1-based `line`: its bytes without the line
+/// terminator, and where the following line starts.
+fn line_span(content: &str, line: usize) -> Option<(usize, usize, usize)> {
+    let start = *line_starts(content).get(line.checked_sub(1)?)?;
+    let next = content[start..]
+        .find('\n')
+        .map_or(content.len(), |i| start + i + 1);
+    let end = content[start..next]
+        .trim_end_matches('\n')
+        .trim_end_matches('\r')
+        .len()
+        + start;
+    Some((start, end, next))
+}
@@ -9,7 +9,7 @@
 
 use std::collections::HashMap;
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use serde::Serialize;
 
@@ -66,6 +66,25 @@ impl GenericReporter {
 /// Generic reporter for violations
 pub struct GenericReporter;
 
+/// All entries of `report`, ordered by file, line, rule and message.
+pub(crate) fn sorted_entries(report: &GenericReport) -> Vec<&ViolationEntry> {
+    let mut entries: Vec<&ViolationEntry> =
+        report.violations_by_category.values().flatten().collect();
+    entries.sort_by(|a, b| {
+        (&a.file, a.line, &a.id, &a.message).cmp(&(&b.file, b.line, &b.id, &b.message))
+    });
+    entries
+}
+
+/// `file` relative to `workspace_root` with forward slashes, or unchanged
+/// when it lies outside the root.
+pub(crate) fn workspace_relative(file: &str, workspace_root: &Path) -> String {
+    Path::new(file).strip_prefix(workspace_root).map_or_else(
+        |_| file.to_owned(),
+        |p| p.to_string_lossy().replace('\\', "/"),
+    )
+}
+
 fn violation_location(v: &ViolationEntry) -> String {
     match (&v.file, v.line) {
         (Some(file), Some(line)) => format!("{file}:{line}"),
@@ -0,0 +1,79 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! GitHub Actions Reporter
+//!
+//! Renders a [`GenericReport`] as GitHub Actions workflow commands
+//! (`::error file=...,line=...::message`), which the runner turns into
+//! inline annotations on the pull request diff. Errors and warnings keep
+//! their level; info violations become `::notice` annotations. File paths
+//! are made relative to the workspace root so they match the checkout.
+
+use std::fmt::Write;
+
+use crate::generic_reporter::{GenericReport, sorted_entries, workspace_relative};
+use mcb_domain::ports::ViolationEntry;
+
+/// GitHub Actions annotation reporter for violations
+pub struct GithubReporter;
+
+/// Annotation command for a report severity (`ERROR`, `WARNING`, `INFO`).
+fn annotation_level(severity: &str) -> &'static str {
+    if severity.eq_ignore_ascii_case("error") {
+        "error"
+    } else if severity.eq_ignore_ascii_case("warning") {
+        "warning"
+    } else {
+        "notice"
+    }
+}
+
+/// Escape the message part of a workflow command.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a `key=value` property of a workflow command.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+impl GithubReporter {
+    /// One annotation line per violation, ordered by file and line.
+    #[must_use]
+    pub fn to_annotations(report: &GenericReport) -> String {
+        let mut output = String::new();
+        for entry in sorted_entries(report) {
+            let _ = writeln!(output, "{}", Self::annotation(entry, report));
+        }
+        output
+    }
+
+    fn annotation(entry: &ViolationEntry, report: &GenericReport) -> String {
+        let mut properties = Vec::with_capacity(3);
+        if let Some(file) = &entry.file {
+            properties.push(format!(
+                "file={}",
+                escape_property(&workspace_relative(file, &report.workspace_root))
+            ));
+            if let Some(line) = entry.line.filter(|&line| line > 0) {
+                properties.push(format!("line={line}"));
+            }
+        }
+        properties.push(format!("title={}", escape_property(&entry.id)));
+
+        let message = match &entry.suggestion {
+            Some(suggestion) => format!("[{}] {} ({suggestion})", entry.id, entry.message),
+            None => format!("[{}] {}", entry.id, entry.message),
+        };
+        format!(
+            "::{} {}::{}",
+            annotation_level(&entry.severity),
+            properties.join(","),
+            escape_data(&message)
+        )
+    }
+}
@@ -0,0 +1,87 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Cycle paths in name-keyed dependency graphs.
+//!
+//! Reports one concrete cycle per strongly-connected component, so every
+//! tangle is surfaced once with a path a reader can follow edge by edge.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+
+use petgraph::algo::tarjan_scc;
+use petgraph::graph::{DiGraph, NodeIndex};
+
+/// One cycle per strongly-connected component of `edges` (node → its
+/// dependencies), including self-loops.
+///
+/// Each cycle starts and ends at the component's smallest node name and is a
+/// shortest way back to it, e.g. `["a", "b", "a"]`. Cycles are sorted.
+#[must_use]
+pub fn cycle_paths(edges: &BTreeMap<String, BTreeSet<String>>) -> Vec<Vec<String>> {
+    let mut graph: DiGraph<&str, ()> = DiGraph::new();
+    let names: BTreeSet<&str> = edges
+        .iter()
+        .flat_map(|(source, targets)| std::iter::once(source).chain(targets))
+        .map(String::as_str)
+        .collect();
+    let indices: HashMap<&str, NodeIndex> = names
+        .into_iter()
+        .map(|name| (name, graph.add_node(name)))
+        .collect();
+    for (source, targets) in edges {
+        for target in targets {
+            graph.add_edge(indices[source.as_str()], indices[target.as_str()], ());
+        }
+    }
+
+    let mut cycles: Vec<Vec<String>> = tarjan_scc(&graph)
+        .into_iter()
+        .filter_map(|component| {
+            let members: BTreeSet<&str> = component.iter().map(|idx| graph[*idx]).collect();
+            let start = *members.first()?;
+            let self_loop = edges
+                .get(start)
+                .is_some_and(|targets| targets.contains(start));
+            if members.len() == 1 && !self_loop {
+                return None;
+            }
+            shortest_cycle(edges, &members, start)
+        })
+        .collect();
+    cycles.sort();
+    cycles
+}
+
+/// Breadth-first search from `start` back to itself, staying inside `members`.
+fn shortest_cycle(
+    edges: &BTreeMap<String, BTreeSet<String>>,
+    members: &BTreeSet<&str>,
+    start: &str,
+) -> Option<Vec<String>> {
+    let mut previous: HashMap<&str, &str> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(node) = queue.pop_front() {
+        for next in edges.get(node).into_iter().flatten() {
+            let next = next.as_str();
+            if !members.contains(next) {
+                continue;
+            }
+            if next == start {
+                let mut path = vec![start.to_owned()];
+                let mut current = node;
+                while current != start {
+                    path.push(current.to_owned());
+                    current = previous.get(current)?;
+                }
+                path.push(start.to_owned());
+                path.reverse();
+                return Some(path);
+            }
+            if !previous.contains_key(next) {
+                previous.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
@@ -75,6 +75,21 @@ impl DependencyGraph {
         cycles
     }
 
+    /// `DependsOn` edges as `(source id, target id)` pairs, sorted.
+    #[must_use]
+    pub fn dependency_edges(&self) -> Vec<(String, String)> {
+        let mut edges: Vec<(String, String)> = self
+            .graph
+            .edge_indices()
+            .filter(|edge| self.graph[*edge] == EdgeType::DependsOn)
+            .filter_map(|edge| self.graph.edge_endpoints(edge))
+            .map(|(source, target)| (self.graph[source].id.clone(), self.graph[target].id.clone()))
+            .collect();
+        edges.sort();
+        edges.dedup();
+        edges
+    }
+
     /// Check for forbidden dependencies between architectural layers.
     ///
     /// An edge from a node whose name contains `source_pattern` to a node
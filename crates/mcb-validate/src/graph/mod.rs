@@ -6,6 +6,8 @@
 //! Builds a directed graph from extracted code facts using `petgraph`,
 //! enabling cycle detection and layer-boundary validation.
 
+pub mod cycles;
 pub mod dep_graph;
 
+pub use cycles::cycle_paths;
 pub use dep_graph::DependencyGraph;
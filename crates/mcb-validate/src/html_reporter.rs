@@ -0,0 +1,452 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! HTML Reporter
+//!
+//! Renders a [`GenericReport`] as a self-contained static HTML page: summary
+//! cards, a severity chart, a per-crate breakdown whose rows filter the
+//! violation table (drill-down), and a filterable violation table. Run
+//! summaries are appended to a JSON [`ReportHistory`] so the page can also
+//! chart the trend across runs.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::generic_reporter::{GenericReport, sorted_entries, workspace_relative};
+use crate::{Result, ValidationError};
+use mcb_domain::ports::ViolationEntry;
+use mcb_utils::constants::validate::{REPORT_HISTORY_MAX_RUNS, REPORT_WORKSPACE_GROUP};
+
+/// Severity order used for columns, charts and filters.
+const SEVERITIES: [&str; 3] = ["ERROR", "WARNING", "INFO"];
+
+/// Chart colour per entry of [`SEVERITIES`].
+const SEVERITY_COLORS: [&str; 3] = ["#d73a49", "#dbab09", "#0366d6"];
+
+/// Trend chart width in pixels.
+const TREND_WIDTH: usize = 640;
+
+/// Trend chart height in pixels.
+const TREND_HEIGHT: usize = 160;
+
+/// Page stylesheet.
+const STYLE: &str = r"
+body { font-family: system-ui, sans-serif; margin: 2rem; color: #24292e; }
+h1 { margin-bottom: 0.2rem; }
+.meta { color: #586069; margin-bottom: 1.5rem; }
+.cards { display: flex; gap: 1rem; margin-bottom: 1.5rem; }
+.card { border: 1px solid #e1e4e8; border-radius: 6px; padding: 0.8rem 1.2rem; min-width: 7rem; }
+.card b { display: block; font-size: 1.6rem; }
+.passed { color: #22863a; } .failed { color: #d73a49; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1.5rem; }
+th, td { border-bottom: 1px solid #e1e4e8; padding: 0.35rem 0.6rem; text-align: left; vertical-align: top; }
+tr.crate { cursor: pointer; } tr.crate:hover { background: #f6f8fa; }
+.sev-ERROR { color: #d73a49; font-weight: 600; } .sev-WARNING { color: #b08800; font-weight: 600; }
+.sev-INFO { color: #0366d6; }
+.filters { display: flex; gap: 0.6rem; margin-bottom: 0.8rem; }
+details summary { cursor: pointer; }
+";
+
+/// Table filtering and crate drill-down.
+const SCRIPT: &str = r"
+const filters = ['text', 'severity', 'category', 'crate'].map(id => document.getElementById('filter-' + id));
+function applyFilters() {
+  const [text, severity, category, crate] = filters.map(f => f.value.toLowerCase());
+  let shown = 0;
+  for (const row of document.querySelectorAll('#violations tbody tr')) {
+    const d = row.dataset;
+    const visible = (!severity || d.severity.toLowerCase() === severity)
+      && (!category || d.category.toLowerCase() === category)
+      && (!crate || d.crate.toLowerCase() === crate)
+      && (!text || row.textContent.toLowerCase().includes(text));
+    row.hidden = !visible;
+    if (visible) shown++;
+  }
+  document.getElementById('shown').textContent = shown;
+}
+filters.forEach(f => f.addEventListener('input', applyFilters));
+for (const row of document.querySelectorAll('tr.crate')) {
+  row.addEventListener('click', () => {
+    filters[3].value = row.dataset.crate;
+    applyFilters();
+    document.getElementById('violations').scrollIntoView();
+  });
+}
+applyFilters();
+";
+
+/// Summary of one validation run, as stored in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunSummary {
+    /// Timestamp of the run
+    pub timestamp: String,
+    /// Total number of violations
+    pub total_violations: usize,
+    /// Number of errors
+    pub errors: usize,
+    /// Number of warnings
+    pub warnings: usize,
+    /// Number of info messages
+    pub infos: usize,
+    /// Violations per category
+    pub by_category: BTreeMap<String, usize>,
+}
+
+impl RunSummary {
+    /// Summary of `report`.
+    #[must_use]
+    pub fn from_report(report: &GenericReport) -> Self {
+        Self {
+            timestamp: report.timestamp.clone(),
+            total_violations: report.summary.total_violations,
+            errors: report.summary.errors,
+            warnings: report.summary.warnings,
+            infos: report.summary.infos,
+            by_category: report
+                .summary
+                .by_category
+                .iter()
+                .map(|(category, count)| (category.clone(), *count))
+                .collect(),
+        }
+    }
+}
+
+/// Run summaries of past validations, oldest first.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReportHistory {
+    /// Recorded runs
+    pub runs: Vec<RunSummary>,
+}
+
+impl ReportHistory {
+    /// Read the history at `path`; a missing file is an empty history.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path)?;
+        serde_json::from_str(&content).map_err(|e| ValidationError::Parse {
+            file: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Write the history as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| ValidationError::Config(format!("cannot serialize history: {e}")))?;
+        std::fs::write(path, format!("{json}\n"))?;
+        Ok(())
+    }
+
+    /// Append the summary of `report`, keeping the most recent
+    /// [`REPORT_HISTORY_MAX_RUNS`] runs.
+    pub fn record(&mut self, report: &GenericReport) {
+        self.runs.push(RunSummary::from_report(report));
+        let excess = self.runs.len().saturating_sub(REPORT_HISTORY_MAX_RUNS);
+        self.runs.drain(..excess);
+    }
+
+    /// Load the history at `path`, record `report` and save it back.
+    ///
+    /// # Errors
+    /// Returns an error if the history cannot be read or written.
+    pub fn append(path: &Path, report: &GenericReport) -> Result<Self> {
+        let mut history = Self::load(path)?;
+        history.record(report);
+        history.save(path)?;
+        Ok(history)
+    }
+}
+
+/// HTML reporter for violations
+pub struct HtmlReporter;
+
+/// A violation with its workspace-relative file and owning crate.
+struct Row<'a> {
+    entry: &'a ViolationEntry,
+    file: Option<String>,
+    crate_name: String,
+}
+
+/// Escape text for use in HTML content and attribute values.
+fn html_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Index of `severity` in [`SEVERITIES`]; unknown severities count as info.
+fn severity_index(severity: &str) -> usize {
+    SEVERITIES
+        .iter()
+        .position(|known| known.eq_ignore_ascii_case(severity))
+        .unwrap_or(SEVERITIES.len() - 1)
+}
+
+impl HtmlReporter {
+    /// Crate owning a workspace-relative path: `<name>` for
+    /// `crates/<name>/...`, otherwise [`REPORT_WORKSPACE_GROUP`].
+    #[must_use]
+    pub fn crate_of(relative_file: &str) -> String {
+        let mut parts = relative_file.split('/');
+        match (parts.next(), parts.next(), parts.next()) {
+            (Some("crates"), Some(name), Some(_)) => name.to_owned(),
+            _ => REPORT_WORKSPACE_GROUP.to_owned(),
+        }
+    }
+
+    /// Generate the HTML page for `report`, charting `history` when it holds
+    /// more than one run.
+    #[must_use]
+    pub fn to_html(report: &GenericReport, history: &ReportHistory) -> String {
+        let rows: Vec<Row<'_>> = sorted_entries(report)
+            .into_iter()
+            .map(|entry| {
+                let file = entry
+                    .file
+                    .as_deref()
+                    .map(|file| workspace_relative(file, &report.workspace_root));
+                let crate_name = file
+                    .as_deref()
+                    .map_or_else(|| REPORT_WORKSPACE_GROUP.to_owned(), Self::crate_of);
+                Row {
+                    entry,
+                    file,
+                    crate_name,
+                }
+            })
+            .collect();
+
+        let mut html = String::from("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
+        html.push_str("<meta charset=\"utf-8\">\n<title>mcb-validate report</title>\n");
+        let _ = writeln!(html, "<style>{STYLE}</style>\n</head>\n<body>");
+        let _ = writeln!(
+            html,
+            "<h1>Validation Report</h1>\n<div class=\"meta\">{} &middot; {}</div>",
+            html_escape(&report.workspace_root.display().to_string()),
+            html_escape(&report.timestamp)
+        );
+
+        Self::write_summary(&mut html, report);
+        Self::write_severity_chart(&mut html, report);
+        if history.runs.len() > 1 {
+            Self::write_trend(&mut html, history);
+        }
+        Self::write_crates(&mut html, &rows);
+        Self::write_violations(&mut html, &rows);
+
+        let _ = writeln!(html, "<script>{SCRIPT}</script>\n</body>\n</html>");
+        html
+    }
+
+    fn write_summary(html: &mut String, report: &GenericReport) {
+        let summary = &report.summary;
+        let (status_class, status) = if summary.passed {
+            ("passed", "PASSED")
+        } else {
+            ("failed", "FAILED")
+        };
+        let _ = writeln!(
+            html,
+            "<div class=\"cards\">\
+             <div class=\"card\"><b class=\"{status_class}\">{status}</b>status</div>\
+             <div class=\"card\"><b>{}</b>violations</div>\
+             <div class=\"card\"><b class=\"sev-ERROR\">{}</b>errors</div>\
+             <div class=\"card\"><b class=\"sev-WARNING\">{}</b>warnings</div>\
+             <div class=\"card\"><b class=\"sev-INFO\">{}</b>info</div></div>",
+            summary.total_violations, summary.errors, summary.warnings, summary.infos
+        );
+    }
+
+    fn write_severity_chart(html: &mut String, report: &GenericReport) {
+        let counts = [
+            report.summary.errors,
+            report.summary.warnings,
+            report.summary.infos,
+        ];
+        let max = counts.iter().copied().max().unwrap_or_default().max(1);
+        html.push_str("<h2>By severity</h2>\n<svg width=\"420\" height=\"84\" role=\"img\">\n");
+        for (index, count) in counts.iter().enumerate() {
+            let y = index * 28;
+            let width = count * 300 / max;
+            let _ = writeln!(
+                html,
+                "<text x=\"0\" y=\"{}\">{}</text>\
+                 <rect x=\"80\" y=\"{y}\" width=\"{width}\" height=\"20\" fill=\"{}\"/>\
+                 <text x=\"{}\" y=\"{}\">{count}</text>",
+                y + 15,
+                SEVERITIES[index],
+                SEVERITY_COLORS[index],
+                86 + width,
+                y + 15
+            );
+        }
+        html.push_str("</svg>\n");
+    }
+
+    fn write_trend(html: &mut String, history: &ReportHistory) {
+        let runs = &history.runs;
+        let max = runs
+            .iter()
+            .map(|run| run.total_violations)
+            .max()
+            .unwrap_or_default()
+            .max(1);
+        let step = TREND_WIDTH / (runs.len() - 1).max(1);
+        let points = |value: fn(&RunSummary) -> usize| {
+            runs.iter()
+                .enumerate()
+                .map(|(index, run)| {
+                    format!(
+                        "{},{}",
+                        index * step,
+                        TREND_HEIGHT - value(run) * TREND_HEIGHT / max
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+
+        let _ = writeln!(
+            html,
+            "<h2>Trend ({} runs, {} &rarr; {})</h2>\n\
+             <svg width=\"{}\" height=\"{}\" role=\"img\">",
+            runs.len(),
+            html_escape(&runs[0].timestamp),
+            html_escape(&runs[runs.len() - 1].timestamp),
+            TREND_WIDTH + 10,
+            TREND_HEIGHT + 10
+        );
+        let series: [(&str, fn(&RunSummary) -> usize); 3] = [
+            ("#586069", |run| run.total_violations),
+            (SEVERITY_COLORS[0], |run| run.errors),
+            (SEVERITY_COLORS[1], |run| run.warnings),
+        ];
+        for (color, value) in series {
+            let _ = writeln!(
+                html,
+                "<polyline fill=\"none\" stroke=\"{color}\" stroke-width=\"2\" points=\"{}\"/>",
+                points(value)
+            );
+        }
+        html.push_str(
+            "</svg>\n<div class=\"meta\">total (grey), errors (red), warnings (yellow)</div>\n",
+        );
+    }
+
+    fn write_crates(html: &mut String, rows: &[Row<'_>]) {
+        let mut crates: BTreeMap<&str, [usize; 3]> = BTreeMap::new();
+        for row in rows {
+            crates.entry(row.crate_name.as_str()).or_default()
+                [severity_index(&row.entry.severity)] += 1;
+        }
+
+        html.push_str(
+            "<h2>By crate</h2>\n<table id=\"crates\">\n<thead><tr><th>Crate</th>\
+             <th>Errors</th><th>Warnings</th><th>Info</th><th>Total</th></tr></thead>\n<tbody>\n",
+        );
+        for (crate_name, counts) in &crates {
+            let _ = writeln!(
+                html,
+                "<tr class=\"crate\" data-crate=\"{0}\"><td>{0}</td><td>{1}</td><td>{2}</td>\
+                 <td>{3}</td><td>{4}</td></tr>",
+                html_escape(crate_name),
+                counts[0],
+                counts[1],
+                counts[2],
+                counts.iter().sum::<usize>()
+            );
+        }
+        html.push_str("</tbody>\n</table>\n");
+    }
+
+    fn write_violations(html: &mut String, rows: &[Row<'_>]) {
+        let options = |values: Vec<&str>| {
+            values
+                .into_iter()
+                .map(|value| {
+                    let value = html_escape(value);
+                    format!("<option value=\"{value}\">{value}</option>")
+                })
+                .collect::<String>()
+        };
+        let mut categories: Vec<&str> =
+            rows.iter().map(|row| row.entry.category.as_str()).collect();
+        categories.sort_unstable();
+        categories.dedup();
+        let mut crates: Vec<&str> = rows.iter().map(|row| row.crate_name.as_str()).collect();
+        crates.sort_unstable();
+        crates.dedup();
+
+        let _ = writeln!(
+            html,
+            "<h2>Violations (<span id=\"shown\">{}</span>/{})</h2>\n<div class=\"filters\">\
+             <input id=\"filter-text\" type=\"search\" placeholder=\"Filter...\">\
+             <select id=\"filter-severity\"><option value=\"\">All severities</option>{}</select>\
+             <select id=\"filter-category\"><option value=\"\">All categories</option>{}</select>\
+             <select id=\"filter-crate\"><option value=\"\">All crates</option>{}</select></div>",
+            rows.len(),
+            rows.len(),
+            options(SEVERITIES.to_vec()),
+            options(categories),
+            options(crates)
+        );
+        html.push_str(
+            "<table id=\"violations\">\n<thead><tr><th>Severity</th><th>Rule</th>\
+             <th>Category</th><th>Location</th><th>Message</th></tr></thead>\n<tbody>\n",
+        );
+        for Row {
+            entry,
+            file,
+            crate_name,
+        } in rows
+        {
+            let severity = SEVERITIES[severity_index(&entry.severity)];
+            let location = match (file, entry.line) {
+                (Some(file), Some(line)) => format!("{file}:{line}"),
+                (Some(file), None) => file.clone(),
+                (None, _) => String::new(),
+            };
+            let message = match &entry.suggestion {
+                Some(suggestion) => format!(
+                    "<details><summary>{}</summary>{}</details>",
+                    html_escape(&entry.message),
+                    html_escape(suggestion)
+                ),
+                None => html_escape(&entry.message),
+            };
+            let _ = writeln!(
+                html,
+                "<tr data-severity=\"{severity}\" data-category=\"{}\" data-crate=\"{}\">\
+                 <td class=\"sev-{severity}\">{severity}</td><td>{}</td><td>{}</td>\
+                 <td>{}</td><td>{message}</td></tr>",
+                html_escape(&entry.category),
+                html_escape(crate_name),
+                html_escape(&entry.id),
+                html_escape(&entry.category),
+                html_escape(&location)
+            );
+        }
+        html.push_str("</tbody>\n</table>\n");
+    }
+}
@@ -0,0 +1,143 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! JUnit Reporter
+//!
+//! Renders a [`GenericReport`] as JUnit XML so test dashboards (GitLab,
+//! Jenkins, GitHub test reporters) list validation results next to the test
+//! suite. Each category becomes a `<testsuite>` and each violation a
+//! `<testcase>`; errors and warnings carry a `<failure>`, info violations
+//! only a `<system-out>` note. A clean run produces a single passing case so
+//! dashboards still record the check.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use crate::generic_reporter::{GenericReport, sorted_entries, workspace_relative};
+use mcb_domain::ports::ViolationEntry;
+
+/// Name of the root `<testsuites>` element and of the clean-run suite.
+const SUITE_NAME: &str = "mcb-validate";
+
+/// JUnit XML reporter for violations
+pub struct JunitReporter;
+
+/// Escape text for use in XML content and attribute values.
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' | '\r' | '\t' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn is_failure(entry: &ViolationEntry) -> bool {
+    entry.severity.eq_ignore_ascii_case("error") || entry.severity.eq_ignore_ascii_case("warning")
+}
+
+impl JunitReporter {
+    /// Generate the JUnit XML document for `report`.
+    #[must_use]
+    pub fn to_junit(report: &GenericReport) -> String {
+        let mut suites: BTreeMap<&str, Vec<&ViolationEntry>> = BTreeMap::new();
+        for entry in sorted_entries(report) {
+            suites
+                .entry(entry.category.as_str())
+                .or_default()
+                .push(entry);
+        }
+        let tests: usize = suites.values().map(Vec::len).sum();
+        let failures = suites.values().flatten().filter(|e| is_failure(e)).count();
+
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        if suites.is_empty() {
+            let _ = writeln!(
+                xml,
+                "<testsuites name=\"{SUITE_NAME}\" tests=\"1\" failures=\"0\">\n  \
+                 <testsuite name=\"{SUITE_NAME}\" tests=\"1\" failures=\"0\" timestamp=\"{}\">\n    \
+                 <testcase classname=\"{SUITE_NAME}\" name=\"workspace\"/>\n  \
+                 </testsuite>\n</testsuites>",
+                xml_escape(&report.timestamp)
+            );
+            return xml;
+        }
+
+        let _ = writeln!(
+            xml,
+            "<testsuites name=\"{SUITE_NAME}\" tests=\"{tests}\" failures=\"{failures}\">"
+        );
+        for (category, entries) in &suites {
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" timestamp=\"{}\">",
+                xml_escape(category),
+                entries.len(),
+                entries.iter().filter(|e| is_failure(e)).count(),
+                xml_escape(&report.timestamp)
+            );
+            for entry in entries {
+                Self::write_case(&mut xml, entry, report);
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    fn write_case(xml: &mut String, entry: &ViolationEntry, report: &GenericReport) {
+        let file = entry
+            .file
+            .as_deref()
+            .map(|file| workspace_relative(file, &report.workspace_root));
+        let name = match (&file, entry.line) {
+            (Some(file), Some(line)) => format!("{} {file}:{line}", entry.id),
+            (Some(file), None) => format!("{} {file}", entry.id),
+            (None, _) => entry.id.clone(),
+        };
+        let _ = write!(
+            xml,
+            "    <testcase classname=\"{}.{}\" name=\"{}\"",
+            xml_escape(&entry.category),
+            xml_escape(&entry.id),
+            xml_escape(&name)
+        );
+        if let Some(file) = &file {
+            let _ = write!(xml, " file=\"{}\"", xml_escape(file));
+        }
+        if let Some(line) = entry.line {
+            let _ = write!(xml, " line=\"{line}\"");
+        }
+        xml.push_str(">\n");
+
+        let mut details = format!("severity: {}", entry.severity);
+        if let Some(suggestion) = &entry.suggestion {
+            let _ = write!(details, "\nsuggestion: {suggestion}");
+        }
+        if is_failure(entry) {
+            let _ = writeln!(
+                xml,
+                "      <failure type=\"{}\" message=\"{}\">{}</failure>",
+                xml_escape(&entry.id),
+                xml_escape(&entry.message),
+                xml_escape(&details)
+            );
+        } else {
+            let _ = writeln!(
+                xml,
+                "      <system-out>{}\n{}</system-out>",
+                xml_escape(&entry.message),
+                xml_escape(&details)
+            );
+        }
+        xml.push_str("    </testcase>\n");
+    }
+}
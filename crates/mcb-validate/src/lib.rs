@@ -43,9 +43,20 @@ pub mod thresholds;
 /// Violation runtime types (field formatting, file path extraction).
 pub mod macros;
 
+pub mod api_changelog;
+pub mod baseline;
+pub mod dependency_diagram;
+pub mod fact_cache;
+pub mod fix;
 pub mod generic_reporter;
+pub mod github_reporter;
+pub mod html_reporter;
+pub mod junit_reporter;
+pub mod lsp;
 pub mod reporter;
 pub mod run_context;
+pub mod sarif_reporter;
+pub mod suppression;
 /// Validator implementations
 pub mod validators;
 
@@ -0,0 +1,371 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! Language Server
+//!
+//! Serves validation results over the Language Server Protocol (JSON-RPC
+//! with `Content-Length` framing on stdin/stdout) so editors show violations
+//! while the file is open instead of only in CI. Opening or saving a file
+//! validates just that file — reusing the incremental scope, the persistent
+//! fact cache, inline suppressions and the baseline — and publishes the
+//! result as diagnostics. Violations with a mechanical rule fix are offered
+//! as quick-fix code actions built from the [`FixEngine`].
+
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value, json};
+
+use crate::fix::FixEngine;
+use crate::{Baseline, Result, ValidationError, Violation, apply_suppressions};
+use mcb_domain::ports::validation::{Severity, ValidationConfig};
+use mcb_utils::constants::validate::{DEFAULT_BASELINE_FILE, DEFAULT_FACT_CACHE_DIR};
+
+/// Diagnostic source and server name reported to the client.
+const SERVER_NAME: &str = "mcb-validate";
+
+/// JSON-RPC error code for requests the server does not implement.
+const METHOD_NOT_FOUND: i64 = -32601;
+
+/// Read one framed JSON-RPC message, or `None` at end of input.
+///
+/// # Errors
+/// Returns an error if the headers lack `Content-Length`, the body is
+/// truncated, or the body is not JSON.
+pub fn read_message<R: BufRead>(reader: &mut R) -> Result<Option<Value>> {
+    let mut length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("Content-Length")
+        {
+            length = value.trim().parse::<usize>().ok();
+        }
+    }
+    let length = length.ok_or_else(|| {
+        ValidationError::Config("LSP message without Content-Length header".to_owned())
+    })?;
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| ValidationError::Config(format!("invalid LSP message: {e}")))
+}
+
+/// Write `message` as one framed JSON-RPC message.
+///
+/// # Errors
+/// Returns an error if the message cannot be written.
+pub fn write_message<W: Write>(writer: &mut W, message: &Value) -> Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{body}", body.len())?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Local path of a `file://` URI, with percent-escapes decoded.
+#[must_use]
+pub fn uri_to_path(uri: &str) -> Option<PathBuf> {
+    let encoded = uri.strip_prefix("file://")?;
+    let bytes = encoded.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| encoded.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        if let Some(byte) = escaped {
+            decoded.push(byte);
+            index += 3;
+        } else {
+            decoded.push(bytes[index]);
+            index += 1;
+        }
+    }
+    String::from_utf8(decoded).ok().map(PathBuf::from)
+}
+
+/// LSP position (0-based line, UTF-16 column) of byte `offset` in `content`.
+#[must_use]
+pub fn position(content: &str, offset: usize) -> Value {
+    let offset = offset.min(content.len());
+    let before = content.get(..offset).unwrap_or(content);
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    json!({
+        "line": before.matches('\n').count(),
+        "character": before[line_start..].encode_utf16().count(),
+    })
+}
+
+fn lsp_severity(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => 1,
+        Severity::Warning => 2,
+        Severity::Info => 3,
+    }
+}
+
+/// LSP diagnostic for `violation` in a file with `content`, spanning the
+/// whole flagged line (the first line when the violation has none).
+#[must_use]
+pub fn to_diagnostic(violation: &dyn Violation, content: &str) -> Value {
+    let line = violation.line().unwrap_or(1).saturating_sub(1);
+    let width = content
+        .lines()
+        .nth(line)
+        .map_or(0, |text| text.encode_utf16().count());
+    let message = match violation.suggestion() {
+        Some(suggestion) => format!("{}\n{suggestion}", violation.message()),
+        None => violation.message(),
+    };
+    json!({
+        "range": {
+            "start": { "line": line, "character": 0 },
+            "end": { "line": line, "character": width },
+        },
+        "severity": lsp_severity(violation.severity()),
+        "code": violation.id(),
+        "source": SERVER_NAME,
+        "message": message,
+    })
+}
+
+/// Language server publishing validation diagnostics to `writer`.
+pub struct LspServer<W: Write> {
+    writer: W,
+    workspace_root: PathBuf,
+    fixes: Option<FixEngine>,
+    violations: HashMap<String, Vec<Box<dyn Violation>>>,
+}
+
+impl<W: Write> LspServer<W> {
+    /// Server for `workspace_root`; the client's `rootUri` overrides it.
+    pub fn new(workspace_root: impl Into<PathBuf>, writer: W) -> Self {
+        Self {
+            writer,
+            workspace_root: workspace_root.into(),
+            fixes: None,
+            violations: HashMap::new(),
+        }
+    }
+
+    /// Serve messages from `reader` until `exit` or end of input.
+    ///
+    /// # Errors
+    /// Returns an error if a message cannot be read or a reply cannot be
+    /// written. Validation failures are logged and publish no diagnostics.
+    pub fn run<R: BufRead>(&mut self, reader: &mut R) -> Result<()> {
+        while let Some(message) = read_message(reader)? {
+            if !self.handle(&message)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Handle one message; returns `false` once the client sent `exit`.
+    fn handle(&mut self, message: &Value) -> Result<bool> {
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // Responses to server-initiated requests are not used.
+            return Ok(true);
+        };
+        let params = message.get("params").unwrap_or(&Value::Null);
+        let uri = params
+            .pointer("/textDocument/uri")
+            .and_then(Value::as_str)
+            .map(str::to_owned);
+
+        match (method, message.get("id").cloned()) {
+            ("exit", _) => return Ok(false),
+            ("initialize", Some(id)) => {
+                self.initialize(params);
+                self.respond(id, &Self::capabilities())?;
+            }
+            ("shutdown", Some(id)) => self.respond(id, &Value::Null)?,
+            ("textDocument/codeAction", Some(id)) => {
+                let actions = uri.map_or_else(Vec::new, |uri| self.code_actions(&uri, params));
+                self.respond(id, &Value::Array(actions))?;
+            }
+            (_, Some(id)) => self.send(&json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": METHOD_NOT_FOUND, "message": format!("method not found: {method}") },
+            }))?,
+            ("textDocument/didOpen" | "textDocument/didSave", None) => {
+                if let Some(uri) = uri {
+                    self.publish(&uri)?;
+                }
+            }
+            ("textDocument/didClose", None) => {
+                if let Some(uri) = uri {
+                    self.violations.remove(&uri);
+                    self.publish_diagnostics(&uri, Vec::new())?;
+                }
+            }
+            _ => {}
+        }
+        Ok(true)
+    }
+
+    fn capabilities() -> Value {
+        json!({
+            "capabilities": {
+                "textDocumentSync": { "openClose": true, "change": 0, "save": true },
+                "codeActionProvider": true,
+            },
+            "serverInfo": { "name": SERVER_NAME, "version": env!("CARGO_PKG_VERSION") },
+        })
+    }
+
+    fn initialize(&mut self, params: &Value) {
+        let root = params
+            .get("rootUri")
+            .and_then(Value::as_str)
+            .and_then(uri_to_path)
+            .or_else(|| {
+                params
+                    .get("rootPath")
+                    .and_then(Value::as_str)
+                    .map(PathBuf::from)
+            });
+        if let Some(root) = root {
+            self.workspace_root = root;
+        }
+        self.fixes = match FixEngine::for_workspace(&self.workspace_root) {
+            Ok(engine) => Some(engine),
+            Err(e) => {
+                mcb_domain::warn!(
+                    "lsp",
+                    "Quick fixes disabled: rules failed to load",
+                    &e.to_string()
+                );
+                None
+            }
+        };
+    }
+
+    /// Validate the file behind `uri` and publish its diagnostics.
+    fn publish(&mut self, uri: &str) -> Result<()> {
+        let Some(path) = uri_to_path(uri) else {
+            return Ok(());
+        };
+        let violations = self.validate_file(&path).unwrap_or_else(|e| {
+            mcb_domain::warn!(
+                "lsp",
+                "Validation failed",
+                &format!("file={} error={e}", path.display())
+            );
+            Vec::new()
+        });
+        let content = std::fs::read_to_string(&path).unwrap_or_default();
+        let diagnostics = violations
+            .iter()
+            .map(|v| to_diagnostic(v.as_ref(), &content))
+            .collect();
+        self.violations.insert(uri.to_owned(), violations);
+        self.publish_diagnostics(uri, diagnostics)
+    }
+
+    /// Violations reported in `path` after suppressions and the baseline.
+    fn validate_file(&self, path: &Path) -> Result<Vec<Box<dyn Violation>>> {
+        let root = &self.workspace_root;
+        let config = ValidationConfig::new(root)
+            .with_changed_files(vec![path.to_path_buf()])
+            .with_cache_dir(root.join(DEFAULT_FACT_CACHE_DIR));
+        let violations = crate::validators::validate_all(&config)?;
+        let mut violations = apply_suppressions(violations, root)?.violations;
+        let baseline_path = root.join(DEFAULT_BASELINE_FILE);
+        if baseline_path.is_file() {
+            violations = Baseline::load(&baseline_path)?
+                .filter_new(violations, root)
+                .violations;
+        }
+
+        let canonical = |file: &Path| {
+            let file = if file.is_absolute() {
+                file.to_path_buf()
+            } else {
+                root.join(file)
+            };
+            std::fs::canonicalize(&file).unwrap_or(file)
+        };
+        let target = canonical(path);
+        violations.retain(|v| v.file().is_some_and(|file| canonical(file) == target));
+        Ok(violations)
+    }
+
+    /// Quick fixes for the published violations of `uri` within the
+    /// requested line range.
+    fn code_actions(&self, uri: &str, params: &Value) -> Vec<Value> {
+        let (Some(engine), Some(violations)) = (&self.fixes, self.violations.get(uri)) else {
+            return Vec::new();
+        };
+        let first = params
+            .pointer("/range/start/line")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        let last = params
+            .pointer("/range/end/line")
+            .and_then(Value::as_u64)
+            .unwrap_or(first);
+
+        violations
+            .iter()
+            .filter(|v| engine.is_fixable(v.id()))
+            .filter(|v| {
+                v.line()
+                    .is_some_and(|line| (first..=last).contains(&(line.saturating_sub(1) as u64)))
+            })
+            .filter_map(|violation| {
+                let plan = engine.plan(std::slice::from_ref(violation), &self.workspace_root);
+                let file = plan.files.first()?;
+                let edits: Vec<Value> = file
+                    .edits
+                    .iter()
+                    .map(|edit| {
+                        json!({
+                            "range": {
+                                "start": position(&file.original, edit.start),
+                                "end": position(&file.original, edit.end),
+                            },
+                            "newText": edit.replacement,
+                        })
+                    })
+                    .collect();
+                let mut changes = Map::new();
+                changes.insert(uri.to_owned(), Value::Array(edits));
+                Some(json!({
+                    "title": format!("Fix {}: {}", violation.id(), violation.message()),
+                    "kind": "quickfix",
+                    "diagnostics": [to_diagnostic(violation.as_ref(), &file.original)],
+                    "edit": { "changes": changes },
+                }))
+            })
+            .collect()
+    }
+
+    fn publish_diagnostics(&mut self, uri: &str, diagnostics: Vec<Value>) -> Result<()> {
+        self.send(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        }))
+    }
+
+    fn respond(&mut self, id: Value, result: &Value) -> Result<()> {
+        self.send(&json!({ "jsonrpc": "2.0", "id": id, "result": result }))
+    }
+
+    fn send(&mut self, message: &Value) -> Result<()> {
+        write_message(&mut self.writer, message)
+    }
+}
@@ -16,14 +16,16 @@
 use std::path::Path;
 
 use crate::filters::LanguageDetector;
+use mcb_utils::constants::validate::FACT_CACHE_KIND_METRICS;
 use rust_code_analysis::{FuncSpace, LANG, SpaceKind, get_function_spaces};
+use serde::{Deserialize, Serialize};
 
 use super::MetricViolation;
 use super::thresholds::{MetricThresholds, MetricType};
 use crate::{Result, ValidationError};
 
 /// Comprehensive metrics from rust-code-analysis
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RcaMetrics {
     /// Cyclomatic complexity - number of linearly independent paths
     pub cyclomatic: f64,
@@ -56,7 +58,7 @@ pub struct RcaMetrics {
 }
 
 /// Function-level metrics from rust-code-analysis
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RcaFunctionMetrics {
     /// Function name
     pub name: String,
@@ -101,6 +103,9 @@ impl RcaAnalyzer {
 
     /// Analyze a file and return all function metrics
     ///
+    /// Inside a validation run, metrics come from the persistent fact cache
+    /// when the file is unchanged, otherwise from the shared RCA parse.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be read or its language is unsupported.
@@ -108,11 +113,22 @@ impl RcaAnalyzer {
         // Try RCA cache first (avoids redundant file reads + tree-sitter parses)
         if let Some(ctx) = crate::run_context::ValidationRunContext::active()
             && let Ok(content) = ctx.read_cached(path)
-            && let Some(root) = ctx.parse_rca_cached(path, &content)
         {
-            let mut results = Vec::new();
-            Self::extract_function_metrics(&root, &mut results);
-            return Ok(results);
+            let cache = ctx.fact_cache();
+            if let Some(cached) = cache
+                .as_ref()
+                .and_then(|cache| cache.get(FACT_CACHE_KIND_METRICS, path, &content))
+            {
+                return Ok(cached);
+            }
+            if let Some(root) = ctx.parse_rca_cached(path, &content) {
+                let mut results = Vec::new();
+                Self::extract_function_metrics(&root, &mut results);
+                if let Some(cache) = &cache {
+                    cache.put(FACT_CACHE_KIND_METRICS, path, &content, &results);
+                }
+                return Ok(results);
+            }
         }
 
         // Fallback: uncached path
@@ -5,10 +5,12 @@
 //!
 //! Provides declarative rule definitions and registry management.
 
+pub mod plugins;
 pub mod templates;
 pub mod yaml_loader;
 pub mod yaml_validator;
 
+pub use plugins::{is_valid_namespace, load_rule_plugins, merge_plugin_rules};
 pub use templates::TemplateEngine;
 pub use yaml_loader::{
     AstSelector, MetricThresholdConfig, MetricsConfig, RuleFix, ValidatedRule, YamlRuleLoader,
@@ -0,0 +1,104 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Rule Plugins
+//!
+//! Loads the user YAML rule directories listed under `[plugins]` in the
+//! project configuration. Plugin rules go through the same template
+//! expansion and schema validation as the built-in rules. A directory with a
+//! `namespace` has its rule IDs prefixed (`ACME::SEC001`), so it can never
+//! shadow a built-in rule; a directory without one keeps its IDs as written,
+//! and any ID already taken by a built-in or another plugin rule is rejected
+//! instead of silently replacing it.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use super::yaml_loader::{ValidatedRule, YamlRuleLoader};
+use crate::config::RulePluginConfig;
+use crate::{Result, ValidationError};
+use mcb_utils::constants::validate::RULE_NAMESPACE_SEPARATOR;
+
+/// Whether `namespace` is usable as a rule ID prefix: an ASCII letter
+/// followed by ASCII letters, digits, `_` or `-`.
+#[must_use]
+pub fn is_valid_namespace(namespace: &str) -> bool {
+    let mut chars = namespace.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Load every configured plugin directory, resolving relative paths against
+/// `workspace_root`.
+///
+/// Returns each directory with its rules, namespaced where configured.
+///
+/// # Errors
+/// Returns an error if a directory is missing, a namespace is invalid, or a
+/// rule fails to load or validate against the rule schema.
+pub fn load_rule_plugins(
+    workspace_root: &Path,
+    plugins: &[RulePluginConfig],
+    variables: Option<&serde_yaml::Value>,
+) -> Result<Vec<(PathBuf, Vec<ValidatedRule>)>> {
+    plugins
+        .iter()
+        .map(|plugin| {
+            let dir = workspace_root.join(&plugin.path);
+            if !dir.is_dir() {
+                return Err(ValidationError::Config(format!(
+                    "rule plugin directory not found: {}",
+                    dir.display()
+                )));
+            }
+            if let Some(namespace) = &plugin.namespace
+                && !is_valid_namespace(namespace)
+            {
+                return Err(ValidationError::Config(format!(
+                    "invalid rule plugin namespace '{namespace}' for {}: use letters, digits, '_' or '-'",
+                    dir.display()
+                )));
+            }
+
+            let mut rules = YamlRuleLoader::with_variables(dir.clone(), variables.cloned())?
+                .load_all_rules_sync()?;
+            if let Some(namespace) = &plugin.namespace {
+                for rule in &mut rules {
+                    rule.id = format!("{namespace}{RULE_NAMESPACE_SEPARATOR}{}", rule.id);
+                }
+            }
+            Ok((dir, rules))
+        })
+        .collect()
+}
+
+/// Append plugin rules to the built-in `rules`.
+///
+/// # Errors
+/// Returns an error naming both sources when a plugin rule ID is already
+/// used by a built-in rule or by another plugin rule.
+pub fn merge_plugin_rules(
+    mut rules: Vec<ValidatedRule>,
+    plugins: Vec<(PathBuf, Vec<ValidatedRule>)>,
+) -> Result<Vec<ValidatedRule>> {
+    let mut owners: HashMap<String, Option<PathBuf>> =
+        rules.iter().map(|rule| (rule.id.clone(), None)).collect();
+    for (dir, plugin_rules) in plugins {
+        for rule in plugin_rules {
+            if let Some(owner) = owners.get(&rule.id) {
+                let owner = owner.as_ref().map_or_else(
+                    || "a built-in rule".to_owned(),
+                    |other| format!("a rule in {}", other.display()),
+                );
+                return Err(ValidationError::Config(format!(
+                    "rule plugin {} defines '{}', which conflicts with {owner}; set a namespace for the plugin",
+                    dir.display(),
+                    rule.id
+                )));
+            }
+            owners.insert(rule.id.clone(), Some(dir.clone()));
+            rules.push(rule);
+        }
+    }
+    Ok(rules)
+}
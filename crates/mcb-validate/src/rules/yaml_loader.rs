@@ -23,7 +23,8 @@ use mcb_utils::constants::validate::{
     YAML_FIELD_EXTENDS, YAML_FIELD_FILTERS, YAML_FIELD_FIX_TYPE, YAML_FIELD_FIXES, YAML_FIELD_ID,
     YAML_FIELD_LANGUAGE, YAML_FIELD_LINT_SELECT, YAML_FIELD_MESSAGE, YAML_FIELD_METRICS,
     YAML_FIELD_NAME, YAML_FIELD_NODE_TYPE, YAML_FIELD_PATTERN, YAML_FIELD_RATIONALE,
-    YAML_FIELD_RULE, YAML_FIELD_SELECTORS, YAML_FIELD_SEVERITY, YAML_FIELD_TEMPLATE,
+    YAML_FIELD_REPLACEMENT, YAML_FIELD_RULE, YAML_FIELD_SELECTORS, YAML_FIELD_SEVERITY,
+    YAML_FIELD_TEMPLATE,
 };
 
 /// Loaded and validated YAML rule
@@ -107,6 +108,8 @@ pub struct RuleFix {
     pub fix_type: String,
     /// Pattern to replace (if applicable).
     pub pattern: Option<String>,
+    /// Replacement text for mechanical fixes (if applicable).
+    pub replacement: Option<String>,
     /// Message describing the fix.
     pub message: String,
 }
@@ -481,6 +484,10 @@ impl YamlRuleLoader {
                                 .get(YAML_FIELD_PATTERN)
                                 .and_then(|v| v.as_str())
                                 .map(str::to_owned),
+                            replacement: fix_obj
+                                .get(YAML_FIELD_REPLACEMENT)
+                                .and_then(|v| v.as_str())
+                                .map(str::to_owned),
                             message: fix_obj.get(YAML_FIELD_MESSAGE)?.as_str()?.to_owned(),
                         })
                     })
@@ -4,6 +4,7 @@
 //!
 //! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
 
+use rayon::prelude::*;
 use rust_code_analysis::FuncSpace;
 use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
@@ -14,8 +15,10 @@ use std::sync::{Arc, Mutex};
 use walkdir::WalkDir;
 
 use crate::config::FileConfig;
+use crate::fact_cache::FactCache;
 use crate::filters::{LanguageDetector, LanguageId};
-use crate::{Result, ValidationConfig};
+use crate::{Result, ValidationConfig, ValidationError, Violation};
+use mcb_utils::constants::validate::DEFAULT_FACT_CACHE_DIR;
 
 /// Source used to build the file inventory.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -57,8 +60,10 @@ pub struct ValidationRunContext {
     trace_id: String,
     file_inventory: Arc<Vec<InventoryEntry>>,
     file_inventory_source: FileInventorySource,
-    content_cache: Mutex<HashMap<PathBuf, Arc<str>>>,
-    rca_cache: Mutex<HashMap<PathBuf, Option<FuncSpace>>>,
+    changed_files: Option<Arc<HashSet<PathBuf>>>,
+    content_cache: Arc<Mutex<HashMap<PathBuf, Arc<str>>>>,
+    rca_cache: Arc<Mutex<HashMap<PathBuf, Option<FuncSpace>>>>,
+    fact_cache: Option<Arc<FactCache>>,
 }
 
 thread_local! {
@@ -74,19 +79,88 @@ impl ValidationRunContext {
         let file_config = FileConfig::load(&config.workspace_root);
         let mut ignore_patterns = file_config.general.exclude_patterns.clone();
         ignore_patterns.extend(config.exclude_patterns.iter().cloned());
-
-        let (entries, source) = enumerate_inventory(&config.workspace_root, &ignore_patterns)?;
+        ignore_patterns.push(DEFAULT_FACT_CACHE_DIR.to_owned());
+
+        let detector = language_detector(&file_config.general.language_filenames)?;
+
+        let (entries, source) =
+            enumerate_inventory(&config.workspace_root, &ignore_patterns, &detector)?;
+        // Deleted files cannot be canonicalized and have nothing left to scan.
+        let changed_files = config.changed_files.as_ref().map(|files| {
+            Arc::new(
+                files
+                    .iter()
+                    .filter_map(|f| normalize_path(&config.workspace_root.join(f)).ok())
+                    .collect::<HashSet<_>>(),
+            )
+        });
 
         Ok(Self {
             workspace_root: config.workspace_root.clone(),
             trace_id: build_trace_id(),
             file_inventory: Arc::new(entries),
             file_inventory_source: source,
-            content_cache: Mutex::new(HashMap::new()),
-            rca_cache: Mutex::new(HashMap::new()),
+            changed_files,
+            content_cache: Arc::new(Mutex::new(HashMap::new())),
+            rca_cache: Arc::new(Mutex::new(HashMap::new())),
+            fact_cache: config
+                .cache_dir
+                .as_ref()
+                .map(|dir| Arc::new(FactCache::new(dir))),
         })
     }
 
+    /// A view of this context that scans the whole workspace, sharing the
+    /// inventory and caches. Cross-file validators run under it during
+    /// incremental runs so they still see facts from unchanged files.
+    #[must_use]
+    pub fn unscoped(&self) -> Self {
+        Self {
+            workspace_root: self.workspace_root.clone(),
+            trace_id: self.trace_id.clone(),
+            file_inventory: Arc::clone(&self.file_inventory),
+            file_inventory_source: self.file_inventory_source,
+            changed_files: None,
+            content_cache: Arc::clone(&self.content_cache),
+            rca_cache: Arc::clone(&self.rca_cache),
+            fact_cache: self.fact_cache.clone(),
+        }
+    }
+
+    /// Whether this run is limited to changed files.
+    #[must_use]
+    pub fn is_incremental(&self) -> bool {
+        self.changed_files.is_some()
+    }
+
+    /// Whether `path` should be scanned: always, unless the run is
+    /// incremental and `path` did not change. `path` must be canonical, as
+    /// inventory paths are.
+    #[must_use]
+    pub fn in_scope(&self, path: &Path) -> bool {
+        self.changed_files
+            .as_ref()
+            .is_none_or(|changed| changed.contains(path))
+    }
+
+    /// Drop violations located in files outside the incremental scope.
+    /// Violations without a file are kept.
+    #[must_use]
+    pub fn retain_in_scope(&self, violations: Vec<Box<dyn Violation>>) -> Vec<Box<dyn Violation>> {
+        if !self.is_incremental() {
+            return violations;
+        }
+        violations
+            .into_iter()
+            .filter(|v| {
+                v.file().is_none_or(|file| {
+                    normalize_path(&self.workspace_root.join(file))
+                        .is_ok_and(|path| self.in_scope(&path))
+                })
+            })
+            .collect()
+    }
+
     /// Get the absolute path to the workspace root
     #[must_use]
     pub fn workspace_root(&self) -> &Path {
@@ -171,6 +245,32 @@ impl ValidationRunContext {
         Ok(value)
     }
 
+    /// Persistent fact cache of this run, if enabled.
+    #[must_use]
+    pub fn fact_cache(&self) -> Option<Arc<FactCache>> {
+        self.fact_cache.clone()
+    }
+
+    /// Read every in-scope source file into the content cache in parallel,
+    /// so validators running afterwards hit memory instead of disk.
+    /// Unreadable files are skipped; validators report them when they read.
+    pub fn preload(&self) {
+        let contents: Vec<(PathBuf, Arc<str>)> = self
+            .file_inventory
+            .par_iter()
+            .filter(|entry| {
+                entry.detected_language.is_some() && self.in_scope(&entry.absolute_path)
+            })
+            .filter_map(|entry| {
+                let content = std::fs::read_to_string(&entry.absolute_path).ok()?;
+                Some((entry.absolute_path.clone(), Arc::from(content)))
+            })
+            .collect();
+        if let Ok(mut cache) = self.content_cache.lock() {
+            cache.extend(contents);
+        }
+    }
+
     /// Parse file with RCA, using cache if available.
     /// Returns cached `FuncSpace` clone on cache hit, otherwise parses and caches.
     #[must_use]
@@ -232,19 +332,35 @@ impl ValidationRunContext {
     }
 }
 
+/// Build the inventory's detector from `[general.language_filenames]`.
+fn language_detector(filenames: &HashMap<String, String>) -> Result<LanguageDetector> {
+    let filenames = filenames
+        .iter()
+        .map(|(name, language)| {
+            LanguageId::from_name(language)
+                .map(|lang| (name.clone(), lang))
+                .ok_or_else(|| {
+                    ValidationError::Config(format!(
+                        "general.language_filenames.{name}: unknown language '{language}'"
+                    ))
+                })
+        })
+        .collect::<Result<HashMap<_, _>>>()?;
+    Ok(LanguageDetector::new().with_filenames(filenames))
+}
+
 fn enumerate_inventory(
     workspace_root: &Path,
     ignore_patterns: &[String],
+    detector: &LanguageDetector,
 ) -> Result<(Vec<InventoryEntry>, FileInventorySource)> {
-    let detector = LanguageDetector::new();
-
     if workspace_root.join(".git").exists()
-        && let Ok(Some(entries)) = enumerate_with_git(workspace_root, ignore_patterns, &detector)
+        && let Ok(Some(entries)) = enumerate_with_git(workspace_root, ignore_patterns, detector)
     {
         return Ok((entries, FileInventorySource::Git));
     }
 
-    let entries = enumerate_with_walkdir(workspace_root, ignore_patterns, &detector)?;
+    let entries = enumerate_with_walkdir(workspace_root, ignore_patterns, detector)?;
     Ok((entries, FileInventorySource::WalkDir))
 }
 
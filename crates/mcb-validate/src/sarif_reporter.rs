@@ -0,0 +1,302 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! SARIF Reporter
+//!
+//! Renders a [`GenericReport`] as a SARIF 2.1.0 log, the format consumed by
+//! GitHub code scanning and most CI annotation tools. Every rule that fired
+//! is listed in the tool driver with its description, category and default
+//! level; each violation becomes a result pointing at its rule by index.
+//! File paths under the workspace root are made relative to the
+//! `%SRCROOT%` base so results line up with the checked-out repository.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::generic_reporter::{GenericReport, sorted_entries};
+use crate::utils::yaml::get_validation_rules;
+use mcb_domain::ports::ViolationEntry;
+
+/// SARIF schema location.
+pub const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// SARIF specification version.
+pub const SARIF_VERSION: &str = "2.1.0";
+
+/// Base URI id that relative artifact locations resolve against.
+const SRCROOT: &str = "%SRCROOT%";
+
+/// Top-level SARIF log.
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifLog {
+    /// JSON schema of the log.
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    /// SARIF version.
+    pub version: &'static str,
+    /// One run per validation.
+    pub runs: Vec<SarifRun>,
+}
+
+/// One analysis run.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRun {
+    /// The analysis tool.
+    pub tool: SarifTool,
+    /// Base URIs artifact locations are relative to.
+    pub original_uri_base_ids: BTreeMap<&'static str, SarifArtifactLocation>,
+    /// Reported violations.
+    pub results: Vec<SarifResult>,
+}
+
+/// Tool wrapper.
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifTool {
+    /// The tool component that produced the results.
+    pub driver: SarifDriver,
+}
+
+/// Tool component description.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifDriver {
+    /// Tool name.
+    pub name: &'static str,
+    /// Tool version.
+    pub semantic_version: &'static str,
+    /// Rules that produced at least one result.
+    pub rules: Vec<SarifRule>,
+}
+
+/// Rule metadata.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRule {
+    /// Rule ID (e.g. `CA001`).
+    pub id: String,
+    /// One-line rule description.
+    pub short_description: SarifMessage,
+    /// Level used when a result does not set one.
+    pub default_configuration: SarifConfiguration,
+    /// Rule category.
+    pub properties: SarifRuleProperties,
+}
+
+/// Default rule configuration.
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifConfiguration {
+    /// `error`, `warning` or `note`.
+    pub level: &'static str,
+}
+
+/// Extra rule properties.
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifRuleProperties {
+    /// Validation category of the rule.
+    pub category: String,
+    /// Tags shown by SARIF viewers (the category).
+    pub tags: Vec<String>,
+}
+
+/// A plain-text message.
+#[derive(Debug, Clone, Serialize)]
+pub struct SarifMessage {
+    /// Message text.
+    pub text: String,
+}
+
+/// One violation.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifResult {
+    /// ID of the violated rule.
+    pub rule_id: String,
+    /// Index of the rule in the driver's `rules`.
+    pub rule_index: usize,
+    /// `error`, `warning` or `note`.
+    pub level: &'static str,
+    /// Violation message, followed by the suggested fix when there is one.
+    pub message: SarifMessage,
+    /// Where the violation was found; empty for workspace-wide violations.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<SarifLocation>,
+}
+
+/// Result location.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifLocation {
+    /// File and line of the violation.
+    pub physical_location: SarifPhysicalLocation,
+}
+
+/// File and region of a result.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifPhysicalLocation {
+    /// The file.
+    pub artifact_location: SarifArtifactLocation,
+    /// The line, when known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<SarifRegion>,
+}
+
+/// A file or directory URI, optionally relative to a base URI id.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifArtifactLocation {
+    /// File URI.
+    pub uri: String,
+    /// Base URI id `uri` is relative to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uri_base_id: Option<&'static str>,
+}
+
+/// Line region of a result.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SarifRegion {
+    /// 1-based line.
+    pub start_line: usize,
+}
+
+/// SARIF reporter for violations
+pub struct SarifReporter;
+
+/// SARIF level for a report severity (`ERROR`, `WARNING`, `INFO`).
+fn sarif_level(severity: &str) -> &'static str {
+    if severity.eq_ignore_ascii_case("error") {
+        "error"
+    } else if severity.eq_ignore_ascii_case("warning") {
+        "warning"
+    } else {
+        "note"
+    }
+}
+
+/// Forward-slash path with spaces and `%` escaped for use in a URI.
+fn uri_path(path: &Path) -> String {
+    path.to_string_lossy()
+        .replace('\\', "/")
+        .replace('%', "%25")
+        .replace(' ', "%20")
+}
+
+impl SarifReporter {
+    /// Build the SARIF log for `report`.
+    #[must_use]
+    pub fn create_log(report: &GenericReport) -> SarifLog {
+        let descriptions: HashMap<String, String> = get_validation_rules(None)
+            .into_iter()
+            .map(|rule| (rule.id, rule.description))
+            .collect();
+
+        let entries = sorted_entries(report);
+
+        let mut rules: Vec<SarifRule> = Vec::new();
+        let mut rule_index: HashMap<&str, usize> = HashMap::new();
+        let mut results = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let index = *rule_index.entry(entry.id.as_str()).or_insert_with(|| {
+                rules.push(Self::rule(entry, descriptions.get(&entry.id)));
+                rules.len() - 1
+            });
+            results.push(SarifResult {
+                rule_id: entry.id.clone(),
+                rule_index: index,
+                level: sarif_level(&entry.severity),
+                message: SarifMessage {
+                    text: match &entry.suggestion {
+                        Some(suggestion) => format!("{} ({suggestion})", entry.message),
+                        None => entry.message.clone(),
+                    },
+                },
+                locations: Self::location(entry, &report.workspace_root)
+                    .into_iter()
+                    .collect(),
+            });
+        }
+
+        let root_uri = format!(
+            "file://{}/",
+            uri_path(&report.workspace_root).trim_end_matches('/')
+        );
+        SarifLog {
+            schema: SARIF_SCHEMA,
+            version: SARIF_VERSION,
+            runs: vec![SarifRun {
+                tool: SarifTool {
+                    driver: SarifDriver {
+                        name: "mcb-validate",
+                        semantic_version: env!("CARGO_PKG_VERSION"),
+                        rules,
+                    },
+                },
+                original_uri_base_ids: BTreeMap::from([(
+                    SRCROOT,
+                    SarifArtifactLocation {
+                        uri: root_uri,
+                        uri_base_id: None,
+                    },
+                )]),
+                results,
+            }],
+        }
+    }
+
+    /// Generate the SARIF log as pretty-printed JSON.
+    #[must_use]
+    pub fn to_sarif(report: &GenericReport) -> String {
+        serde_json::to_string_pretty(&Self::create_log(report)).unwrap_or_else(|_| "{}".to_owned())
+    }
+
+    fn rule(entry: &ViolationEntry, description: Option<&String>) -> SarifRule {
+        SarifRule {
+            id: entry.id.clone(),
+            short_description: SarifMessage {
+                text: description.map_or_else(
+                    || format!("{} rule {}", entry.category, entry.id),
+                    Clone::clone,
+                ),
+            },
+            default_configuration: SarifConfiguration {
+                level: sarif_level(&entry.severity),
+            },
+            properties: SarifRuleProperties {
+                category: entry.category.clone(),
+                tags: vec![entry.category.clone()],
+            },
+        }
+    }
+
+    /// Location of `entry`, relative to `%SRCROOT%` when inside `workspace_root`.
+    fn location(entry: &ViolationEntry, workspace_root: &Path) -> Option<SarifLocation> {
+        let file = Path::new(entry.file.as_deref()?);
+        let artifact_location = match file.strip_prefix(workspace_root) {
+            Ok(relative) => SarifArtifactLocation {
+                uri: uri_path(relative),
+                uri_base_id: Some(SRCROOT),
+            },
+            Err(_) if file.is_absolute() => SarifArtifactLocation {
+                uri: format!("file://{}", uri_path(file)),
+                uri_base_id: None,
+            },
+            Err(_) => SarifArtifactLocation {
+                uri: uri_path(file),
+                uri_base_id: Some(SRCROOT),
+            },
+        };
+        Some(SarifLocation {
+            physical_location: SarifPhysicalLocation {
+                artifact_location,
+                region: entry
+                    .line
+                    .filter(|&line| line > 0)
+                    .map(|start_line| SarifRegion { start_line }),
+            },
+        })
+    }
+}
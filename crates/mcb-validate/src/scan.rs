@@ -5,6 +5,8 @@
 //!
 //! Provides generic language-aware scan functions. All filtering uses
 //! `InventoryEntry::detected_language` from the single-pass file inventory.
+//! In incremental runs only files in the active context's change scope are
+//! yielded.
 
 use std::path::Path;
 
@@ -67,7 +69,10 @@ where
         let src_dir = std::fs::canonicalize(&src_dir).unwrap_or(src_dir);
 
         for entry in inventory {
-            if entry.absolute_path.starts_with(&src_dir) && matches_language(entry, language) {
+            if entry.absolute_path.starts_with(&src_dir)
+                && matches_language(entry, language)
+                && context.in_scope(&entry.absolute_path)
+            {
                 f(entry, &src_dir, crate_name)?;
             }
         }
@@ -110,6 +115,7 @@ where
             if entry.absolute_path.starts_with(&src_dir)
                 && matches_language(entry, language)
                 && !is_validator_pattern_data(&entry.absolute_path)
+                && context.in_scope(&entry.absolute_path)
             {
                 f(entry, &src_dir)?;
             }
@@ -158,7 +164,7 @@ where
         if !entry.absolute_path.starts_with(&normalized_root) {
             continue;
         }
-        if !matches_language(entry, language) {
+        if !matches_language(entry, language) || !context.in_scope(&entry.absolute_path) {
             continue;
         }
         f(entry)?;
@@ -167,6 +173,37 @@ where
     Ok(())
 }
 
+/// Read a source file through the active run context's content cache,
+/// which the runner fills in parallel before validators start. Reads from
+/// disk when no validation run is active.
+///
+/// # Errors
+/// Returns an error if the file cannot be read.
+pub fn read_source(path: &Path) -> Result<String> {
+    match ValidationRunContext::active() {
+        Some(context) => Ok(context.read_cached(path)?.to_string()),
+        None => Ok(std::fs::read_to_string(path)?),
+    }
+}
+
+/// Module path of a Rust `file` within the crate source directory
+/// `src_dir` (`validators::dependency`, empty for the crate root), or `None`
+/// for binary targets.
+pub(crate) fn rust_module_path(file: &Path, src_dir: &Path) -> Option<String> {
+    let relative = file.strip_prefix(src_dir).ok()?.with_extension("");
+    let mut segments: Vec<String> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    if segments.first().is_some_and(|s| s == "bin") || segments == ["main"] {
+        return None;
+    }
+    if segments.last().is_some_and(|s| s == "lib" || s == "mod") {
+        segments.pop();
+    }
+    Some(segments.join("::"))
+}
+
 // ---------------------------------------------------------------------------
 // Shared helpers
 // ---------------------------------------------------------------------------
@@ -0,0 +1,187 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../docs/modules/validate.md)
+//!
+//! Inline Suppressions
+//!
+//! A violation can be silenced where it occurs with a comment naming the
+//! rule and explaining why the code is acceptable:
+//!
+//! ```text
+//! let value = cache.get(key).unwrap(); // mcb-allow(QUAL001): key inserted above
+//!
+//! // mcb-allow(KISS002, SOLID001): generated bindings mirror the C API
+//! pub fn bindings(...) { ... }
+//! ```
+//!
+//! A suppression covers its own line and the line after it, so it works both
+//! as a trailing comment and on the line above the flagged code. Suppressions
+//! whose justification is missing or shorter than
+//! [`SUPPRESSION_MIN_JUSTIFICATION_LEN`] characters are ignored and reported
+//! as `SUPP001` so exceptions stay documented.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::define_violations;
+use crate::{Result, Violation};
+use mcb_domain::ports::validation::ViolationCategory;
+use mcb_utils::constants::validate::{SUPPRESSION_MIN_JUSTIFICATION_LEN, SUPPRESSION_REGEX};
+use mcb_utils::utils::regex::compile_regex;
+
+define_violations! {
+    ViolationCategory::Quality,
+    pub enum SuppressionViolation {
+        /// `mcb-allow` comment without a usable justification
+        #[violation(
+            id = "SUPP001",
+            severity = Warning,
+            message = "Unjustified suppression of {rule_id}: {file}:{line} - the violation is still reported",
+            suggestion = "Explain why the code is acceptable: // mcb-allow({rule_id}): <reason>"
+        )]
+        MissingJustification {
+            file: PathBuf,
+            line: usize,
+            rule_id: String,
+        },
+    }
+}
+
+/// One `mcb-allow` comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    /// 1-based line of the comment.
+    pub line: usize,
+    /// Rule IDs it names.
+    pub rule_ids: Vec<String>,
+    /// Text after the rule list, trimmed.
+    pub justification: String,
+}
+
+impl Suppression {
+    /// Whether this suppression applies to `rule_id` at `line`.
+    #[must_use]
+    pub fn covers(&self, rule_id: &str, line: usize) -> bool {
+        (line == self.line || line == self.line + 1)
+            && self
+                .rule_ids
+                .iter()
+                .any(|id| id.eq_ignore_ascii_case(rule_id))
+    }
+
+    /// Whether the justification is long enough for the suppression to apply.
+    #[must_use]
+    pub fn is_justified(&self) -> bool {
+        self.justification.chars().count() >= SUPPRESSION_MIN_JUSTIFICATION_LEN
+    }
+}
+
+/// Result of applying inline suppressions.
+#[derive(Debug, Default)]
+pub struct SuppressionOutcome {
+    /// Violations still reported, including `SUPP001` for unjustified suppressions.
+    pub violations: Vec<Box<dyn Violation>>,
+    /// Number of violations silenced by a justified suppression.
+    pub suppressed: usize,
+}
+
+/// Finds `mcb-allow` comments in source files.
+pub struct SuppressionParser {
+    pattern: Regex,
+}
+
+impl SuppressionParser {
+    /// Compile the suppression comment pattern.
+    ///
+    /// # Errors
+    /// Returns an error if [`SUPPRESSION_REGEX`] fails to compile.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            pattern: compile_regex(SUPPRESSION_REGEX)?,
+        })
+    }
+
+    /// Parse every `mcb-allow` comment in `content`.
+    #[must_use]
+    pub fn parse(&self, content: &str) -> Vec<Suppression> {
+        content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, text)| {
+                let captures = self.pattern.captures(text)?;
+                let rule_ids: Vec<String> = captures
+                    .get(1)?
+                    .as_str()
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(str::to_owned)
+                    .collect();
+                let justification = captures.get(2).map_or("", |m| m.as_str());
+                let justification = justification
+                    .trim()
+                    .trim_end_matches("*/")
+                    .trim_end_matches("-->")
+                    .trim();
+                (!rule_ids.is_empty()).then(|| Suppression {
+                    line: index + 1,
+                    rule_ids,
+                    justification: justification.to_owned(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Drop the violations covered by a justified `mcb-allow` comment.
+///
+/// Only files that have violations with a line number are read; relative
+/// violation paths are resolved against `workspace_root`. Unreadable files
+/// suppress nothing.
+///
+/// # Errors
+/// Returns an error if the suppression pattern fails to compile.
+pub fn apply_suppressions(
+    violations: Vec<Box<dyn Violation>>,
+    workspace_root: &Path,
+) -> Result<SuppressionOutcome> {
+    let parser = SuppressionParser::new()?;
+    let mut by_file: HashMap<PathBuf, Vec<Suppression>> = HashMap::new();
+    let mut outcome = SuppressionOutcome::default();
+    let mut unjustified = Vec::new();
+
+    for violation in violations {
+        let (Some(file), Some(line)) = (violation.file(), violation.line()) else {
+            outcome.violations.push(violation);
+            continue;
+        };
+        let suppressions = by_file.entry(file.clone()).or_insert_with(|| {
+            let path = if file.is_absolute() {
+                file.clone()
+            } else {
+                workspace_root.join(file)
+            };
+            std::fs::read_to_string(path)
+                .map(|content| parser.parse(&content))
+                .unwrap_or_default()
+        });
+        match suppressions.iter().find(|s| s.covers(violation.id(), line)) {
+            Some(suppression) if suppression.is_justified() => outcome.suppressed += 1,
+            Some(suppression) => {
+                unjustified.push(SuppressionViolation::MissingJustification {
+                    file: file.clone(),
+                    line: suppression.line,
+                    rule_id: violation.id().to_owned(),
+                });
+                outcome.violations.push(violation);
+            }
+            None => outcome.violations.push(violation),
+        }
+    }
+
+    outcome
+        .violations
+        .extend(unjustified.into_iter().map(Violation::boxed));
+    Ok(outcome)
+}
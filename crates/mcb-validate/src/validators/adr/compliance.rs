@@ -0,0 +1,345 @@
+//! ADR compliance rules and their evaluation.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use derive_more::Display;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::document::AdrDocument;
+use crate::{Result, ValidationError};
+use mcb_domain::ports::validation::{Severity, Violation, ViolationCategory};
+use mcb_utils::constants::validate::ADR_COMPLIANCE_SCHEMA;
+
+/// Default rule set, used when `[rules.adr] rules_file` is empty.
+const DEFAULT_RULES_YAML: &str = include_str!("../../../../../config/adr-compliance.yml");
+
+/// What a compliance rule checks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "check", rename_all = "snake_case")]
+pub enum ComplianceCheck {
+    /// The file name matches `pattern` (a regex).
+    FileName {
+        /// Regex the file name must match.
+        pattern: String,
+    },
+    /// The first `ADR NNN` heading carries the number of the file name.
+    TitleNumber,
+    /// The status starts with one of `allowed` (case-insensitive).
+    Status {
+        /// Accepted status prefixes.
+        allowed: Vec<String>,
+    },
+    /// A `##` heading starts with `section` (case-insensitive).
+    RequiredSection {
+        /// Required heading.
+        section: String,
+        /// Only check ADRs whose status starts with one of these; empty
+        /// checks every ADR.
+        #[serde(default)]
+        when_status: Vec<String>,
+    },
+    /// No other ADR has the same number.
+    UniqueNumber,
+}
+
+/// A structured compliance rule.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceRule {
+    /// Violation ID reported for the rule.
+    pub id: String,
+    /// What the rule requires.
+    pub description: String,
+    /// Severity of its violations.
+    pub severity: Severity,
+    /// The check.
+    #[serde(flatten)]
+    pub check: ComplianceCheck,
+}
+
+/// A rule set for the ADRs of a workspace (schema `adr-compliance/v1`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AdrComplianceRules {
+    /// Schema tag, `adr-compliance/v1`.
+    pub schema: String,
+    /// Markdown files of the ADR directory that are not ADRs.
+    #[serde(default)]
+    pub ignore_files: Vec<String>,
+    /// The rules.
+    pub rules: Vec<ComplianceRule>,
+}
+
+/// An ADR that breaks a compliance rule.
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+#[display("[{rule_id}] {message}: {}", file.display())]
+pub struct AdrViolation {
+    /// ID of the broken rule.
+    pub rule_id: String,
+    /// Severity of the rule.
+    pub severity: Severity,
+    /// The ADR.
+    pub file: PathBuf,
+    /// 1-based line of the offending text, when there is one.
+    pub line: Option<usize>,
+    /// Rule description and what was found.
+    pub message: String,
+    /// How to comply.
+    pub suggestion: String,
+}
+
+impl Violation for AdrViolation {
+    fn id(&self) -> &str {
+        &self.rule_id
+    }
+
+    fn category(&self) -> ViolationCategory {
+        ViolationCategory::Documentation
+    }
+
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    fn file(&self) -> Option<&PathBuf> {
+        Some(&self.file)
+    }
+
+    fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        Some(self.suggestion.clone())
+    }
+}
+
+impl AdrComplianceRules {
+    /// The embedded default rule set.
+    ///
+    /// # Errors
+    /// Returns an error if the embedded rules are invalid.
+    pub fn embedded() -> Result<Self> {
+        Self::from_yaml(DEFAULT_RULES_YAML, Path::new("config/adr-compliance.yml"))
+    }
+
+    /// Load a rule set file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read or is not a valid rule set.
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::from_yaml(&std::fs::read_to_string(path)?, path)
+    }
+
+    /// Parse a rule set; `source` names it in errors.
+    ///
+    /// # Errors
+    /// Returns an error if the YAML is malformed, the schema tag is not
+    /// `adr-compliance/v1`, or a `file_name` pattern is not a valid regex.
+    pub fn from_yaml(content: &str, source: &Path) -> Result<Self> {
+        let rules: Self = serde_yaml::from_str(content).map_err(|e| ValidationError::Parse {
+            file: source.to_path_buf(),
+            message: e.to_string(),
+        })?;
+        if rules.schema != ADR_COMPLIANCE_SCHEMA {
+            return Err(ValidationError::Parse {
+                file: source.to_path_buf(),
+                message: format!(
+                    "unsupported ADR compliance schema '{}' (expected {ADR_COMPLIANCE_SCHEMA})",
+                    rules.schema
+                ),
+            });
+        }
+        rules.file_name_patterns()?;
+        Ok(rules)
+    }
+
+    /// Whether `file_name` is an ADR: a Markdown file not in `ignore_files`.
+    #[must_use]
+    pub fn is_adr_file(&self, file_name: &str) -> bool {
+        Path::new(file_name)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+            && !self.ignore_files.iter().any(|ignored| ignored == file_name)
+    }
+
+    /// Parse the ADRs directly inside `adr_dir`, sorted by file name; empty
+    /// when the directory does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the directory or an ADR cannot be read.
+    pub fn load_documents(&self, adr_dir: &Path) -> Result<Vec<AdrDocument>> {
+        if !adr_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(adr_dir)? {
+            let path = entry?.path();
+            let is_adr = path
+                .file_name()
+                .is_some_and(|name| self.is_adr_file(&name.to_string_lossy()));
+            if path.is_file() && is_adr {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        paths.iter().map(|path| AdrDocument::load(path)).collect()
+    }
+
+    /// Violations of every rule by `documents`.
+    ///
+    /// # Errors
+    /// Returns an error if a `file_name` pattern is not a valid regex.
+    pub fn check(&self, documents: &[AdrDocument]) -> Result<Vec<AdrViolation>> {
+        let patterns = self.file_name_patterns()?;
+        let mut by_number: BTreeMap<u32, Vec<&AdrDocument>> = BTreeMap::new();
+        for document in documents {
+            if let Some(number) = document.number {
+                by_number.entry(number).or_default().push(document);
+            }
+        }
+
+        let mut violations = Vec::new();
+        for rule in &self.rules {
+            for document in documents {
+                let Some(finding) = evaluate(&rule.check, document, &patterns, &by_number) else {
+                    continue;
+                };
+                violations.push(AdrViolation {
+                    rule_id: rule.id.clone(),
+                    severity: rule.severity,
+                    file: document.path.clone(),
+                    line: finding.line,
+                    message: format!("{} ({})", rule.description, finding.detail),
+                    suggestion: finding.suggestion,
+                });
+            }
+        }
+        Ok(violations)
+    }
+
+    /// Compiled `file_name` patterns, keyed by pattern.
+    fn file_name_patterns(&self) -> Result<BTreeMap<&str, Regex>> {
+        let mut patterns = BTreeMap::new();
+        for rule in &self.rules {
+            if let ComplianceCheck::FileName { pattern } = &rule.check {
+                let regex = Regex::new(pattern).map_err(|e| {
+                    ValidationError::Config(format!("ADR rule {}: invalid pattern: {e}", rule.id))
+                })?;
+                patterns.insert(pattern.as_str(), regex);
+            }
+        }
+        Ok(patterns)
+    }
+}
+
+/// What a check found wrong with one ADR.
+struct Finding {
+    line: Option<usize>,
+    detail: String,
+    suggestion: String,
+}
+
+impl Finding {
+    fn new(line: Option<usize>, detail: String, suggestion: impl Into<String>) -> Self {
+        Self {
+            line,
+            detail,
+            suggestion: suggestion.into(),
+        }
+    }
+}
+
+/// What `check` finds wrong with `document`, if anything.
+fn evaluate(
+    check: &ComplianceCheck,
+    document: &AdrDocument,
+    patterns: &BTreeMap<&str, Regex>,
+    by_number: &BTreeMap<u32, Vec<&AdrDocument>>,
+) -> Option<Finding> {
+    match check {
+        ComplianceCheck::FileName { pattern } => patterns
+            .get(pattern.as_str())
+            .filter(|regex| !regex.is_match(&document.file_name))
+            .map(|_| {
+                Finding::new(
+                    None,
+                    format!("file name '{}' does not match", document.file_name),
+                    "Rename the ADR to its zero-padded number and a kebab-case title, e.g. 042-use-postgres.md",
+                )
+            }),
+        ComplianceCheck::TitleNumber => check_title_number(document),
+        ComplianceCheck::Status { allowed } => check_status(document, allowed),
+        ComplianceCheck::RequiredSection {
+            section,
+            when_status,
+        } => {
+            let applies = when_status.is_empty() || document.status_is(when_status);
+            (applies && !document.has_section(section)).then(|| {
+                Finding::new(
+                    None,
+                    format!("missing `## {section}` section"),
+                    format!("Add a `## {section}` section"),
+                )
+            })
+        }
+        ComplianceCheck::UniqueNumber => check_unique_number(document, by_number),
+    }
+}
+
+fn check_title_number(document: &AdrDocument) -> Option<Finding> {
+    let suggestion = "Start the ADR with an `# ADR NNN: Title` heading using the file's number";
+    match (document.title_number, document.number) {
+        (None, _) => Some(Finding::new(
+            None,
+            "no `ADR NNN` title heading".to_owned(),
+            suggestion,
+        )),
+        (Some(title), Some(file)) if title != file => Some(Finding::new(
+            document.title_line,
+            format!("title says ADR {title}, file name says {file}"),
+            suggestion,
+        )),
+        _ => None,
+    }
+}
+
+fn check_status(document: &AdrDocument, allowed: &[String]) -> Option<Finding> {
+    let suggestion = format!(
+        "Declare one of these statuses in a `## Status` section or the front matter: {}",
+        allowed.join(", ")
+    );
+    match document.status.as_deref() {
+        None => Some(Finding::new(None, "no status".to_owned(), suggestion)),
+        Some(status) if !document.status_is(allowed) => Some(Finding::new(
+            document.status_line,
+            format!("unknown status '{status}'"),
+            suggestion,
+        )),
+        Some(_) => None,
+    }
+}
+
+fn check_unique_number(
+    document: &AdrDocument,
+    by_number: &BTreeMap<u32, Vec<&AdrDocument>>,
+) -> Option<Finding> {
+    let number = document.number?;
+    let others: Vec<&str> = by_number
+        .get(&number)?
+        .iter()
+        .filter(|other| other.path != document.path)
+        .map(|other| other.file_name.as_str())
+        .collect();
+    (!others.is_empty()).then(|| {
+        Finding::new(
+            None,
+            format!("ADR {number} is also used by {}", others.join(", ")),
+            "Renumber one of the ADRs to the next free number",
+        )
+    })
+}
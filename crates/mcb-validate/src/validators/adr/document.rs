@@ -0,0 +1,221 @@
+//! ADR document parsing.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::Result;
+
+/// A `##` heading of an ADR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdrSection {
+    /// Heading text without the `##` marker.
+    pub title: String,
+    /// 1-based line of the heading.
+    pub line: usize,
+}
+
+/// An ADR Markdown document, reduced to the parts compliance rules check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdrDocument {
+    /// Path of the document.
+    pub path: PathBuf,
+    /// File name of the document.
+    pub file_name: String,
+    /// Number from the leading digits of the file name.
+    pub number: Option<u32>,
+    /// Number of the first `ADR NNN` heading (levels 1-3).
+    pub title_number: Option<u32>,
+    /// 1-based line of that heading.
+    pub title_line: Option<usize>,
+    /// Declared status, without Markdown emphasis.
+    pub status: Option<String>,
+    /// 1-based line the status was read from.
+    pub status_line: Option<usize>,
+    /// `key: value` pairs of the `---` front matter block.
+    pub front_matter: BTreeMap<String, String>,
+    /// `##` headings outside code blocks, in document order.
+    pub sections: Vec<AdrSection>,
+}
+
+impl AdrDocument {
+    /// Read and parse the ADR at `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read.
+    pub fn load(path: &Path) -> Result<Self> {
+        Ok(Self::parse(path, &std::fs::read_to_string(path)?))
+    }
+
+    /// Parse `content` as the ADR at `path`.
+    ///
+    /// The status comes from the front matter `status:` key, else from the
+    /// first plain line of a `## Status` section, else from a `**Status**:`
+    /// metadata line.
+    #[must_use]
+    pub fn parse(path: &Path, content: &str) -> Self {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let number = leading_number(&file_name);
+        let lines: Vec<&str> = content.lines().collect();
+        let (front_matter_entries, body_start) = parse_front_matter(&lines);
+        let front_matter_status = front_matter_entries
+            .iter()
+            .find(|(_, key, value)| key == "status" && !value.is_empty())
+            .map(|(line, _, value)| (strip_emphasis(value), *line));
+        let front_matter = front_matter_entries
+            .into_iter()
+            .map(|(_, key, value)| (key, value))
+            .collect();
+
+        let mut document = Self {
+            path: path.to_path_buf(),
+            file_name,
+            number,
+            title_number: None,
+            title_line: None,
+            status: None,
+            status_line: None,
+            front_matter,
+            sections: Vec::new(),
+        };
+
+        let mut section_status: Option<(String, usize)> = None;
+        let mut metadata_status: Option<(String, usize)> = None;
+        let mut in_status_section = false;
+        let mut in_fence = false;
+        for (index, raw) in lines.iter().enumerate().skip(body_start) {
+            let line_number = index + 1;
+            let line = raw.trim();
+            if line.starts_with("```") || line.starts_with("~~~") {
+                in_fence = !in_fence;
+                continue;
+            }
+            if in_fence {
+                continue;
+            }
+            if document.title_number.is_none()
+                && let Some(title_number) = title_heading_number(line)
+            {
+                document.title_number = Some(title_number);
+                document.title_line = Some(line_number);
+            }
+            if let Some(title) = line.strip_prefix("## ") {
+                let title = title.trim().to_owned();
+                in_status_section =
+                    section_status.is_none() && title.eq_ignore_ascii_case("status");
+                document.sections.push(AdrSection {
+                    title,
+                    line: line_number,
+                });
+                continue;
+            }
+            if line.starts_with('#') {
+                in_status_section = false;
+                continue;
+            }
+            if in_status_section
+                && !line.is_empty()
+                && !line.starts_with('>')
+                && !line.starts_with("<!--")
+            {
+                section_status = Some((strip_emphasis(line), line_number));
+                in_status_section = false;
+            }
+            if metadata_status.is_none() && line.starts_with("**Status") {
+                let plain = strip_emphasis(line);
+                if let Some(value) = plain.strip_prefix("Status:").map(str::trim)
+                    && !value.is_empty()
+                {
+                    metadata_status = Some((value.to_owned(), line_number));
+                }
+            }
+        }
+
+        if let Some((status, line)) = front_matter_status.or(section_status).or(metadata_status) {
+            document.status = Some(status);
+            document.status_line = Some(line);
+        }
+        document
+    }
+
+    /// Whether a `##` heading starts with `section` (case-insensitive).
+    #[must_use]
+    pub fn has_section(&self, section: &str) -> bool {
+        self.sections
+            .iter()
+            .any(|s| starts_with_ignore_case(&s.title, section))
+    }
+
+    /// Whether the status starts with one of `statuses` (case-insensitive).
+    #[must_use]
+    pub fn status_is(&self, statuses: &[String]) -> bool {
+        self.status.as_deref().is_some_and(|status| {
+            statuses
+                .iter()
+                .any(|allowed| starts_with_ignore_case(status, allowed))
+        })
+    }
+}
+
+/// `(line, key, value)` entries of the front matter block and the index of
+/// the first line after it.
+fn parse_front_matter(lines: &[&str]) -> (Vec<(usize, String, String)>, usize) {
+    let mut entries = Vec::new();
+    let Some(start) = lines
+        .iter()
+        .position(|line| !line.trim().is_empty() && !line.trim().starts_with("<!--"))
+        .filter(|&start| lines[start].trim() == "---")
+    else {
+        return (entries, 0);
+    };
+    let Some(end) = lines[start + 1..]
+        .iter()
+        .position(|line| line.trim() == "---")
+        .map(|offset| start + 1 + offset)
+    else {
+        return (entries, 0);
+    };
+    for (index, line) in lines.iter().enumerate().take(end).skip(start + 1) {
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let key = key.trim();
+        if key.is_empty() || key.starts_with('#') || key.starts_with("<!--") {
+            continue;
+        }
+        let value = value.trim().trim_matches(|c| c == '"' || c == '\'');
+        entries.push((index + 1, key.to_owned(), value.to_owned()));
+    }
+    (entries, end + 1)
+}
+
+/// Number of an `ADR NNN` heading (`# ADR 001: ...`, `## ADR-12 ...`).
+fn title_heading_number(line: &str) -> Option<u32> {
+    let hashes = line.len() - line.trim_start_matches('#').len();
+    if !(1..=3).contains(&hashes) {
+        return None;
+    }
+    let rest = line[hashes..].trim_start();
+    if rest.len() == line[hashes..].len() {
+        return None;
+    }
+    let rest = rest.strip_prefix("ADR")?;
+    leading_number(rest.trim_start_matches(|c: char| c == '-' || c.is_whitespace()))
+}
+
+/// Leading decimal digits of `text`.
+fn leading_number(text: &str) -> Option<u32> {
+    let digits: String = text.chars().take_while(char::is_ascii_digit).collect();
+    digits.parse().ok()
+}
+
+fn strip_emphasis(text: &str) -> String {
+    text.replace('*', "").trim().to_owned()
+}
+
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    text.get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+}
@@ -0,0 +1,27 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../../docs/modules/validate.md)
+//!
+//! ADR Compliance
+//!
+//! Parses the Markdown ADRs of a workspace into [`AdrDocument`]s and checks
+//! them against a structured YAML rule set ([`AdrComplianceRules`], schema
+//! `adr-compliance/v1`): file name, numbered title, status, required
+//! sections and unique numbers. The engine works on its own
+//! ([`AdrComplianceRules::check`]) and backs the `adr` validator, which the
+//! MCB server exposes as the `adr_check` tool.
+
+mod compliance;
+mod document;
+mod validator;
+
+pub use self::compliance::{AdrComplianceRules, AdrViolation, ComplianceCheck, ComplianceRule};
+pub use self::document::{AdrDocument, AdrSection};
+pub use self::validator::AdrValidator;
+
+mcb_domain::register_validator!(
+    mcb_utils::constants::validate::VALIDATOR_ADR,
+    "Checks ADR documents against the ADR compliance rules",
+    |root| {
+        Ok(Box::new(AdrValidator::new(root)) as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
@@ -0,0 +1,74 @@
+//! `adr` validator running the compliance rules on the workspace's ADRs.
+
+use std::path::Path;
+
+use super::compliance::{AdrComplianceRules, AdrViolation};
+use crate::config::AdrRulesConfig;
+use crate::{Result, ValidationConfig};
+use mcb_domain::ports::validation::{Validator, Violation};
+use mcb_utils::constants::validate::VALIDATOR_ADR;
+
+/// Checks the ADRs of the configured directory against the compliance rules.
+pub struct AdrValidator {
+    rules: AdrRulesConfig,
+}
+
+crate::impl_config_only_validator_new!(AdrValidator, adr);
+
+impl AdrValidator {
+    /// Creates an ADR validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &AdrRulesConfig) -> Self {
+        Self {
+            rules: config.clone(),
+        }
+    }
+
+    /// The configured rule set: `rules_file` under `workspace_root`, or the
+    /// embedded defaults when it is empty.
+    ///
+    /// # Errors
+    /// Returns an error if the rule set cannot be loaded.
+    pub fn compliance_rules(&self, workspace_root: &Path) -> Result<AdrComplianceRules> {
+        if self.rules.rules_file.is_empty() {
+            AdrComplianceRules::embedded()
+        } else {
+            AdrComplianceRules::load(&workspace_root.join(&self.rules.rules_file))
+        }
+    }
+
+    /// Compliance violations of the workspace's ADRs; empty when disabled or
+    /// when the ADR directory does not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the rule set or an ADR cannot be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<AdrViolation>> {
+        let adr_dir = config.workspace_root.join(&self.rules.adr_dir);
+        if !self.rules.enabled || !adr_dir.is_dir() {
+            return Ok(Vec::new());
+        }
+        let compliance = self.compliance_rules(&config.workspace_root)?;
+        compliance.check(&compliance.load_documents(&adr_dir)?)
+    }
+}
+
+impl Validator for AdrValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_ADR
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks ADR documents against the ADR compliance rules"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
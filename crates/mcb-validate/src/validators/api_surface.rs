@@ -0,0 +1,602 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Public API Surface Drift
+//!
+//! Records every crate's public API — `pub` items, trait members and
+//! `pub use` re-exports, with their normalized signatures — in a snapshot
+//! file (`.mcb-api.json`), and reports additions, removals and signature
+//! changes against it on later runs. `mcb validate --bless` rewrites the
+//! snapshot to accept intentional changes. Without a snapshot the validator
+//! reports nothing.
+//!
+//! Trait implementations are recorded under their self type as
+//! `Type::<impl Trait>`, whether written as an `impl` block or derived, so
+//! the snapshot lists what each public type can do. Implementations for
+//! types no crate item declares `pub` are left out.
+//!
+//! Items are found line by line with brace tracking rather than a full
+//! parse: declarations nested in function bodies or private inline modules
+//! are ignored, and visibility is taken from the item itself, not from the
+//! path it is reachable through.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::define_violations;
+use crate::filters::LanguageId;
+use crate::scan::{for_each_scan_file, read_source, rust_module_path};
+use crate::{Result, ValidationConfig, ValidationError};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    API_DERIVE_REGEX, API_IMPL_REGEX, API_PUB_ITEM_REGEX, API_PUB_USE_REGEX,
+    API_SNAPSHOT_FORMAT_VERSION, API_TRAIT_FN_REGEX, API_TRAIT_IMPL_REGEX,
+    DEFAULT_API_SNAPSHOT_FILE, VALIDATOR_API_SURFACE,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+/// Lines a single declaration may span before its signature is cut off.
+const MAX_SIGNATURE_LINES: usize = 40;
+
+/// Item kinds that declare a type trait implementations can attach to.
+const TYPE_KINDS: &[&str] = &["struct", "enum", "union", "type"];
+
+/// Separator between a self type and its trait in implementation paths.
+const IMPL_PATH_MARKER: &str = "::<impl ";
+
+define_violations! {
+    ViolationCategory::Architecture,
+    pub enum ApiSurfaceViolation {
+        /// Public item not in the API snapshot.
+        #[violation(
+            id = "API001",
+            severity = Info,
+            message = "New public API in {crate_name}: {item} at {file}:{line}",
+            suggestion = "Run `mcb validate --bless` to accept the new API, or narrow it to pub(crate)"
+        )]
+        PublicItemAdded {
+            crate_name: String,
+            item: String,
+            file: PathBuf,
+            line: usize,
+        },
+        /// Snapshot item no longer public.
+        #[violation(
+            id = "API002",
+            severity = Error,
+            message = "Public API removed from {crate_name}: {item} (`{signature}`) - breaking change",
+            suggestion = "Restore {item}, or run `mcb validate --bless` to accept the breaking change"
+        )]
+        PublicItemRemoved {
+            crate_name: String,
+            item: String,
+            signature: String,
+        },
+        /// Public item whose signature differs from the snapshot.
+        #[violation(
+            id = "API003",
+            severity = Error,
+            message = "Public API changed in {crate_name}: {item} at {file}:{line}: `{old_signature}` -> `{new_signature}`",
+            suggestion = "Keep the old signature, or run `mcb validate --bless` to accept the breaking change"
+        )]
+        SignatureChanged {
+            crate_name: String,
+            item: String,
+            old_signature: String,
+            new_signature: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// One public item as recorded in the snapshot.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiItem {
+    /// Item kind (`fn`, `struct`, `trait`, `use`, `impl`, ...).
+    pub kind: String,
+    /// Declaration up to its body, whitespace-normalized.
+    pub signature: String,
+}
+
+/// A public item declared in a source file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiDeclaration {
+    /// Path within the file (`Type::method`, `inline_mod::Item`,
+    /// `Type::<impl Trait>`).
+    pub path: String,
+    /// Kind and signature.
+    pub item: ApiItem,
+    /// 1-based declaration line.
+    pub line: usize,
+}
+
+/// A public item of a workspace crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiEntry {
+    /// Crate directory name.
+    pub crate_name: String,
+    /// Item path within the crate (`module::Type::method`).
+    pub path: String,
+    /// Kind and signature.
+    pub item: ApiItem,
+    /// Declaring file.
+    pub file: PathBuf,
+    /// 1-based declaration line.
+    pub line: usize,
+}
+
+/// Accepted public API of every crate, keyed by crate and item path.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiSnapshot {
+    /// Snapshot format version.
+    pub version: u32,
+    /// Items per crate, sorted for stable diffs.
+    pub crates: BTreeMap<String, BTreeMap<String, ApiItem>>,
+}
+
+/// Block the scanner is inside of.
+struct Scope {
+    depth: usize,
+    name: String,
+    is_trait: bool,
+}
+
+/// Finds public declarations in Rust source.
+pub struct ApiScanner {
+    item: Regex,
+    reexport: Regex,
+    trait_fn: Regex,
+    impl_header: Regex,
+    trait_impl: Regex,
+    derive: Regex,
+    string_literal: Regex,
+    char_literal: Regex,
+}
+
+impl ApiScanner {
+    /// Compile the declaration patterns.
+    ///
+    /// # Errors
+    /// Returns an error if a pattern fails to compile.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            item: compile_regex(API_PUB_ITEM_REGEX)?,
+            reexport: compile_regex(API_PUB_USE_REGEX)?,
+            trait_fn: compile_regex(API_TRAIT_FN_REGEX)?,
+            impl_header: compile_regex(API_IMPL_REGEX)?,
+            trait_impl: compile_regex(API_TRAIT_IMPL_REGEX)?,
+            derive: compile_regex(API_DERIVE_REGEX)?,
+            string_literal: compile_regex(r#""(?:[^"\\]|\\.)*""#)?,
+            char_literal: compile_regex(r"'(?:[^'\\]|\\.)'")?,
+        })
+    }
+
+    /// Public declarations in `content`, in source order.
+    #[must_use]
+    pub fn scan(&self, content: &str) -> Vec<ApiDeclaration> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut declarations = Vec::new();
+        let mut scopes: Vec<Scope> = Vec::new();
+        let mut pending: Option<(String, bool)> = None;
+        let mut depth = 0;
+
+        for (index, line) in lines.iter().enumerate() {
+            let code = self.code_only(line);
+            let scope_depth = scopes.last().map_or(0, |s| s.depth);
+            if depth == scope_depth && pending.is_none() {
+                if let Some(declaration) = self.declaration(&lines, index, &scopes) {
+                    let derived = if TYPE_KINDS.contains(&declaration.item.kind.as_str()) {
+                        let type_name = declaration.path.rsplit("::").next().unwrap_or_default();
+                        self.derived_impls(&lines, index, &scopes, type_name)
+                    } else {
+                        Vec::new()
+                    };
+                    declarations.push(declaration);
+                    declarations.extend(derived);
+                }
+                declarations.extend(self.trait_impl(&code, index, &scopes));
+                pending = self.scope_header(&code);
+            }
+            for c in code.chars() {
+                match c {
+                    '{' => {
+                        depth += 1;
+                        if let Some((name, is_trait)) = pending.take() {
+                            scopes.push(Scope {
+                                depth,
+                                name,
+                                is_trait,
+                            });
+                        }
+                    }
+                    '}' => {
+                        if scopes.last().is_some_and(|s| s.depth == depth) {
+                            scopes.pop();
+                        }
+                        depth = depth.saturating_sub(1);
+                    }
+                    ';' => pending = None,
+                    _ => {}
+                }
+            }
+        }
+        declarations
+    }
+
+    /// `line` without string and char literal contents or `//` comments.
+    fn code_only(&self, line: &str) -> String {
+        let line = self.string_literal.replace_all(line, "\"\"");
+        let line = self.char_literal.replace_all(&line, "' '");
+        line.split("//").next().unwrap_or_default().to_owned()
+    }
+
+    /// Name of the block opened by `code`, if it is an `impl`, trait or
+    /// inline module whose members belong to the API path.
+    fn scope_header(&self, code: &str) -> Option<(String, bool)> {
+        if let Some(captures) = self.impl_header.captures(code) {
+            return Some((captures[1].to_owned(), false));
+        }
+        let captures = self.item.captures(code)?;
+        match &captures[1] {
+            "trait" => Some((captures[2].to_owned(), true)),
+            "mod" => Some((captures[2].to_owned(), false)),
+            _ => None,
+        }
+    }
+
+    /// Trait implemented by the `impl` block opened on `code`.
+    fn trait_impl(&self, code: &str, index: usize, scopes: &[Scope]) -> Option<ApiDeclaration> {
+        let captures = self.trait_impl.captures(code)?;
+        let trait_name = captures[1].split_whitespace().collect::<Vec<_>>().join(" ");
+        Some(impl_declaration(scopes, &captures[2], &trait_name, index))
+    }
+
+    /// Traits derived by the attributes directly above the type declared on
+    /// `lines[index]`.
+    fn derived_impls(
+        &self,
+        lines: &[&str],
+        index: usize,
+        scopes: &[Scope],
+        type_name: &str,
+    ) -> Vec<ApiDeclaration> {
+        let start = lines[..index]
+            .iter()
+            .rposition(|line| {
+                let line = line.trim();
+                line.is_empty() || line.ends_with([';', '{', '}'])
+            })
+            .map_or(0, |end| end + 1);
+        let attributes = lines[start..index].join(" ");
+        let mut derived = Vec::new();
+        for captures in self.derive.captures_iter(&attributes) {
+            for trait_path in captures[1].split(',') {
+                let trait_name = trait_path.rsplit("::").next().unwrap_or_default().trim();
+                if !trait_name.is_empty() {
+                    derived.push(impl_declaration(scopes, type_name, trait_name, index));
+                }
+            }
+        }
+        derived
+    }
+
+    fn declaration(
+        &self,
+        lines: &[&str],
+        index: usize,
+        scopes: &[Scope],
+    ) -> Option<ApiDeclaration> {
+        let line = lines[index];
+        let in_trait = scopes.last().is_some_and(|s| s.is_trait);
+        let (kind, name) = if self.reexport.is_match(line) {
+            ("use".to_owned(), None)
+        } else if let Some(captures) = self.item.captures(line) {
+            (captures[1].to_owned(), Some(captures[2].to_owned()))
+        } else if in_trait && let Some(captures) = self.trait_fn.captures(line) {
+            ("fn".to_owned(), Some(captures[1].to_owned()))
+        } else {
+            return None;
+        };
+
+        let signature = signature(lines, index, &kind);
+        let name = name.unwrap_or_else(|| signature.trim_start_matches("pub use ").to_owned());
+        let path = scopes
+            .iter()
+            .map(|s| s.name.as_str())
+            .chain(std::iter::once(name.as_str()))
+            .collect::<Vec<_>>()
+            .join("::");
+        Some(ApiDeclaration {
+            path,
+            item: ApiItem { kind, signature },
+            line: index + 1,
+        })
+    }
+}
+
+/// A trait implementation, recorded as `Type::<impl Trait>` with the
+/// signature `impl Trait for Type` whether it is written or derived.
+fn impl_declaration(
+    scopes: &[Scope],
+    type_name: &str,
+    trait_name: &str,
+    index: usize,
+) -> ApiDeclaration {
+    let type_path = scopes
+        .iter()
+        .map(|s| s.name.as_str())
+        .chain(std::iter::once(type_name))
+        .collect::<Vec<_>>()
+        .join("::");
+    ApiDeclaration {
+        path: format!("{type_path}{IMPL_PATH_MARKER}{trait_name}>"),
+        item: ApiItem {
+            kind: "impl".to_owned(),
+            signature: format!("impl {trait_name} for {type_name}"),
+        },
+        line: index + 1,
+    }
+}
+
+/// Declaration starting at `lines[index]` up to its body or terminating
+/// `;`, whitespace-normalized; values of constants and statics are dropped.
+/// Braces of `pub use` groups are part of the signature.
+fn signature(lines: &[&str], index: usize, kind: &str) -> String {
+    let is_use = kind == "use";
+    let mut text = String::new();
+    let mut nesting = 0usize;
+    'lines: for line in lines.iter().skip(index).take(MAX_SIGNATURE_LINES) {
+        let code = line.split("//").next().unwrap_or_default();
+        for c in code.chars() {
+            match c {
+                '(' | '[' => nesting += 1,
+                '{' if is_use => nesting += 1,
+                ')' | ']' => nesting = nesting.saturating_sub(1),
+                '}' if is_use => nesting = nesting.saturating_sub(1),
+                '{' | ';' if nesting == 0 => break 'lines,
+                '=' if nesting == 0 && matches!(kind, "const" | "static") => break 'lines,
+                _ => {}
+            }
+            text.push(c);
+        }
+        text.push(' ');
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Public API of every crate in the workspace scan directories.
+///
+/// # Errors
+/// Returns an error if the workspace cannot be scanned or a file cannot be
+/// read.
+pub fn collect_api_surface(config: &ValidationConfig) -> Result<Vec<ApiEntry>> {
+    let scanner = ApiScanner::new()?;
+    let mut entries = Vec::new();
+    for_each_scan_file(config, Some(LanguageId::Rust), true, |entry, src_dir| {
+        let file = &entry.absolute_path;
+        let Some(module) = rust_module_path(file, src_dir) else {
+            return Ok(());
+        };
+        let crate_name = src_dir
+            .parent()
+            .and_then(Path::file_name)
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        for declaration in scanner.scan(&read_source(file)?) {
+            let path = if module.is_empty() {
+                declaration.path
+            } else {
+                format!("{module}::{}", declaration.path)
+            };
+            entries.push(ApiEntry {
+                crate_name: crate_name.clone(),
+                path,
+                item: declaration.item,
+                file: file.clone(),
+                line: declaration.line,
+            });
+        }
+        Ok(())
+    })?;
+    retain_public_type_impls(&mut entries);
+    Ok(entries)
+}
+
+/// Drop trait implementations whose self type no item of the crate
+/// declares `pub`.
+fn retain_public_type_impls(entries: &mut Vec<ApiEntry>) {
+    let public_types: BTreeSet<(String, String)> = entries
+        .iter()
+        .filter(|entry| TYPE_KINDS.contains(&entry.item.kind.as_str()))
+        .filter_map(|entry| {
+            let name = entry.path.rsplit("::").next()?;
+            Some((entry.crate_name.clone(), name.to_owned()))
+        })
+        .collect();
+    entries.retain(|entry| {
+        let Some((type_path, _)) = entry.path.rsplit_once(IMPL_PATH_MARKER) else {
+            return true;
+        };
+        let type_name = type_path.rsplit("::").next().unwrap_or(type_path);
+        public_types.contains(&(entry.crate_name.clone(), type_name.to_owned()))
+    });
+}
+
+impl ApiSnapshot {
+    /// Snapshot of `entries`; the first declaration of a path wins.
+    #[must_use]
+    pub fn from_entries(entries: &[ApiEntry]) -> Self {
+        let mut crates: BTreeMap<String, BTreeMap<String, ApiItem>> = BTreeMap::new();
+        for entry in entries {
+            crates
+                .entry(entry.crate_name.clone())
+                .or_default()
+                .entry(entry.path.clone())
+                .or_insert_with(|| entry.item.clone());
+        }
+        Self {
+            version: API_SNAPSHOT_FORMAT_VERSION,
+            crates,
+        }
+    }
+
+    /// Snapshot of the workspace's current public API.
+    ///
+    /// # Errors
+    /// Returns an error if the workspace cannot be scanned.
+    pub fn collect(config: &ValidationConfig) -> Result<Self> {
+        Ok(Self::from_entries(&collect_api_surface(config)?))
+    }
+
+    /// Number of recorded items.
+    #[must_use]
+    pub fn item_count(&self) -> usize {
+        self.crates.values().map(BTreeMap::len).sum()
+    }
+
+    /// Load a snapshot file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, is not a snapshot, or
+    /// has an unsupported version.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let snapshot: Self =
+            serde_json::from_str(&content).map_err(|e| ValidationError::Parse {
+                file: path.to_path_buf(),
+                message: e.to_string(),
+            })?;
+        if snapshot.version != API_SNAPSHOT_FORMAT_VERSION {
+            return Err(ValidationError::Parse {
+                file: path.to_path_buf(),
+                message: format!(
+                    "unsupported API snapshot version {} (expected {API_SNAPSHOT_FORMAT_VERSION})",
+                    snapshot.version
+                ),
+            });
+        }
+        Ok(snapshot)
+    }
+
+    /// Write the snapshot as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)
+            .map_err(|e| ValidationError::Config(format!("cannot serialize API snapshot: {e}")))?;
+        std::fs::write(path, format!("{json}\n"))?;
+        Ok(())
+    }
+
+    /// Differences between the snapshot and the `current` API.
+    #[must_use]
+    pub fn drift(&self, current: &[ApiEntry]) -> Vec<ApiSurfaceViolation> {
+        let mut seen: BTreeMap<(&str, &str), &ApiEntry> = BTreeMap::new();
+        for entry in current {
+            seen.entry((entry.crate_name.as_str(), entry.path.as_str()))
+                .or_insert(entry);
+        }
+
+        let mut violations = Vec::new();
+        for ((crate_name, path), entry) in &seen {
+            match self
+                .crates
+                .get(*crate_name)
+                .and_then(|items| items.get(*path))
+            {
+                None => violations.push(ApiSurfaceViolation::PublicItemAdded {
+                    crate_name: (*crate_name).to_owned(),
+                    item: (*path).to_owned(),
+                    file: entry.file.clone(),
+                    line: entry.line,
+                }),
+                Some(old) if old.signature != entry.item.signature => {
+                    violations.push(ApiSurfaceViolation::SignatureChanged {
+                        crate_name: (*crate_name).to_owned(),
+                        item: (*path).to_owned(),
+                        old_signature: old.signature.clone(),
+                        new_signature: entry.item.signature.clone(),
+                        file: entry.file.clone(),
+                        line: entry.line,
+                    });
+                }
+                Some(_) => {}
+            }
+        }
+        for (crate_name, items) in &self.crates {
+            for (path, item) in items {
+                if !seen.contains_key(&(crate_name.as_str(), path.as_str())) {
+                    violations.push(ApiSurfaceViolation::PublicItemRemoved {
+                        crate_name: crate_name.clone(),
+                        item: path.clone(),
+                        signature: item.signature.clone(),
+                    });
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Reports public API drift against the workspace snapshot.
+pub struct ApiSurfaceValidator {
+    workspace_root: PathBuf,
+}
+
+impl ApiSurfaceValidator {
+    /// Create an API surface validator rooted at `workspace_root`.
+    pub fn new(workspace_root: impl Into<PathBuf>) -> Self {
+        Self {
+            workspace_root: workspace_root.into(),
+        }
+    }
+
+    /// Drift between the snapshot and the current API; empty when the
+    /// workspace has no snapshot.
+    ///
+    /// # Errors
+    /// Returns an error if the snapshot is unreadable or the workspace
+    /// cannot be scanned.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<ApiSurfaceViolation>> {
+        let snapshot_path = self.workspace_root.join(DEFAULT_API_SNAPSHOT_FILE);
+        if !snapshot_path.is_file() {
+            return Ok(Vec::new());
+        }
+        let snapshot = ApiSnapshot::load(&snapshot_path)?;
+        Ok(snapshot.drift(&collect_api_surface(config)?))
+    }
+}
+
+impl Validator for ApiSurfaceValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_API_SURFACE
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports public API drift against the API snapshot"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_API_SURFACE,
+    "Reports public API drift against the API snapshot",
+    |root| {
+        Ok(Box::new(ApiSurfaceValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
@@ -31,7 +31,7 @@ pub fn validate_block_on_usage(config: &ValidationConfig) -> Result<Vec<AsyncVio
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(path)?;
+        let content = crate::scan::read_source(path)?;
 
         for_each_async_fn_line(&content, async_fn_pattern, |line_num, line, trimmed| {
             for pattern in &compiled_block_on {
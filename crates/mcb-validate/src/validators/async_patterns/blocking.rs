@@ -74,7 +74,7 @@ pub fn validate_blocking_in_async(config: &ValidationConfig) -> Result<Vec<Async
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(path)?;
+        let content = crate::scan::read_source(path)?;
 
         for_each_async_fn_line(&content, async_fn_pattern, |line_num, line, _trimmed| {
             for (pattern, desc, sugg) in &compiled_blocking {
@@ -27,7 +27,7 @@ pub fn validate_mutex_types(config: &ValidationConfig) -> Result<Vec<AsyncViolat
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(path)?;
+        let content = crate::scan::read_source(path)?;
 
         // Only check files that have async code
         if !async_indicator.is_match(&content) {
@@ -39,7 +39,7 @@ pub fn validate_spawn_patterns(config: &ValidationConfig) -> Result<Vec<AsyncVio
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(path)?;
+        let content = crate::scan::read_source(path)?;
         let mut current_fn_name = String::new();
 
         crate::validators::for_each_non_test_non_comment_line(
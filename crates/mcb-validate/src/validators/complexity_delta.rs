@@ -0,0 +1,320 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Complexity Delta Gate
+//!
+//! Records the cyclomatic and cognitive complexity of every function in a
+//! baseline file (`.mcb-complexity.json`) and, on later runs, reports only
+//! the functions whose complexity grew by more than the configured increase.
+//! Functions missing from the baseline are new and left to the absolute
+//! thresholds. `mcb validate --bless` rewrites the baseline to accept the
+//! current complexity. Without a baseline the validator reports nothing.
+//!
+//! Functions are keyed by workspace-relative file and name; repeated names
+//! in one file (`new` in several `impl` blocks) are numbered in source
+//! order (`new`, `new#2`).
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::ComplexityDeltaRulesConfig;
+use crate::define_violations;
+use crate::metrics::RcaAnalyzer;
+use crate::scan::for_each_scan_file;
+use crate::{Result, ValidationConfig, ValidationError};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    COMPLEXITY_BASELINE_FORMAT_VERSION, DEFAULT_COMPLEXITY_BASELINE_FILE,
+    VALIDATOR_COMPLEXITY_DELTA,
+};
+
+define_violations! {
+    ViolationCategory::Quality,
+    pub enum ComplexityDeltaViolation {
+        /// Baselined function whose cyclomatic complexity grew too much.
+        #[violation(
+            id = "CPLX001",
+            severity = Error,
+            message = "Cyclomatic complexity of {function} rose from {baseline} to {current} (allowed +{max_increase}) at {file}:{line}",
+            suggestion = "Extract branches of {function} into helpers, or run `mcb validate --bless` to accept the new complexity"
+        )]
+        CyclomaticIncrease {
+            function: String,
+            baseline: u32,
+            current: u32,
+            max_increase: u32,
+            file: PathBuf,
+            line: usize,
+        },
+        /// Baselined function whose cognitive complexity grew too much.
+        #[violation(
+            id = "CPLX002",
+            severity = Error,
+            message = "Cognitive complexity of {function} rose from {baseline} to {current} (allowed +{max_increase}) at {file}:{line}",
+            suggestion = "Flatten the nesting of {function} with early returns, or run `mcb validate --bless` to accept the new complexity"
+        )]
+        CognitiveIncrease {
+            function: String,
+            baseline: u32,
+            current: u32,
+            max_increase: u32,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// Complexity of one function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FunctionComplexity {
+    /// Cyclomatic complexity.
+    pub cyclomatic: u32,
+    /// Cognitive complexity.
+    pub cognitive: u32,
+}
+
+/// A function of the workspace with its current complexity.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplexityEntry {
+    /// Workspace-relative file, `/`-separated.
+    pub file: String,
+    /// Function key within the file (`name` or `name#n`).
+    pub function: String,
+    /// Current complexity.
+    pub complexity: FunctionComplexity,
+    /// Declaring file.
+    pub path: PathBuf,
+    /// 1-based first line of the function.
+    pub line: usize,
+}
+
+/// Accepted function complexity, keyed by file and function.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplexityBaseline {
+    /// Baseline format version.
+    pub version: u32,
+    /// Functions per file, sorted for stable diffs.
+    pub files: BTreeMap<String, BTreeMap<String, FunctionComplexity>>,
+}
+
+/// Complexity of every function in the files of the active run that
+/// rust-code-analysis understands.
+///
+/// # Errors
+/// Returns an error if the workspace cannot be scanned or a file cannot be
+/// analyzed.
+pub fn collect_complexity(config: &ValidationConfig) -> Result<Vec<ComplexityEntry>> {
+    let analyzer = RcaAnalyzer::new();
+    let to_u32_metric = |x: f64| x.round().max(0.0) as u32;
+    let mut entries = Vec::new();
+    for_each_scan_file(config, None, true, |entry, _src_dir| {
+        let path = &entry.absolute_path;
+        if analyzer.detect_language(path).is_none() {
+            return Ok(());
+        }
+        let file = entry.relative_path.to_string_lossy().replace('\\', "/");
+        let mut occurrences: HashMap<String, usize> = HashMap::new();
+        for function in analyzer.analyze_file(path)? {
+            let occurrence = occurrences.entry(function.name.clone()).or_default();
+            *occurrence += 1;
+            let key = if *occurrence == 1 {
+                function.name
+            } else {
+                format!("{}#{occurrence}", function.name)
+            };
+            entries.push(ComplexityEntry {
+                file: file.clone(),
+                function: key,
+                complexity: FunctionComplexity {
+                    cyclomatic: to_u32_metric(function.metrics.cyclomatic),
+                    cognitive: to_u32_metric(function.metrics.cognitive),
+                },
+                path: path.clone(),
+                line: function.start_line,
+            });
+        }
+        Ok(())
+    })?;
+    Ok(entries)
+}
+
+impl ComplexityBaseline {
+    /// Baseline of `entries`.
+    #[must_use]
+    pub fn from_entries(entries: &[ComplexityEntry]) -> Self {
+        let mut files: BTreeMap<String, BTreeMap<String, FunctionComplexity>> = BTreeMap::new();
+        for entry in entries {
+            files
+                .entry(entry.file.clone())
+                .or_default()
+                .insert(entry.function.clone(), entry.complexity);
+        }
+        Self {
+            version: COMPLEXITY_BASELINE_FORMAT_VERSION,
+            files,
+        }
+    }
+
+    /// Baseline of the workspace's current function complexity.
+    ///
+    /// # Errors
+    /// Returns an error if the workspace cannot be scanned.
+    pub fn collect(config: &ValidationConfig) -> Result<Self> {
+        Ok(Self::from_entries(&collect_complexity(config)?))
+    }
+
+    /// Number of recorded functions.
+    #[must_use]
+    pub fn function_count(&self) -> usize {
+        self.files.values().map(BTreeMap::len).sum()
+    }
+
+    /// Load a baseline file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, is not a complexity
+    /// baseline, or has an unsupported version.
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)?;
+        let baseline: Self =
+            serde_json::from_str(&content).map_err(|e| ValidationError::Parse {
+                file: path.to_path_buf(),
+                message: e.to_string(),
+            })?;
+        if baseline.version != COMPLEXITY_BASELINE_FORMAT_VERSION {
+            return Err(ValidationError::Parse {
+                file: path.to_path_buf(),
+                message: format!(
+                    "unsupported complexity baseline version {} (expected {COMPLEXITY_BASELINE_FORMAT_VERSION})",
+                    baseline.version
+                ),
+            });
+        }
+        Ok(baseline)
+    }
+
+    /// Write the baseline as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            ValidationError::Config(format!("cannot serialize complexity baseline: {e}"))
+        })?;
+        std::fs::write(path, format!("{json}\n"))?;
+        Ok(())
+    }
+
+    /// Baselined functions in `current` whose complexity grew by more than
+    /// the allowed increase.
+    #[must_use]
+    pub fn regressions(
+        &self,
+        current: &[ComplexityEntry],
+        rules: &ComplexityDeltaRulesConfig,
+    ) -> Vec<ComplexityDeltaViolation> {
+        let mut violations = Vec::new();
+        for entry in current {
+            let Some(baseline) = self
+                .files
+                .get(&entry.file)
+                .and_then(|functions| functions.get(&entry.function))
+            else {
+                continue;
+            };
+            if entry.complexity.cyclomatic
+                > baseline
+                    .cyclomatic
+                    .saturating_add(rules.max_cyclomatic_increase)
+            {
+                violations.push(ComplexityDeltaViolation::CyclomaticIncrease {
+                    function: entry.function.clone(),
+                    baseline: baseline.cyclomatic,
+                    current: entry.complexity.cyclomatic,
+                    max_increase: rules.max_cyclomatic_increase,
+                    file: entry.path.clone(),
+                    line: entry.line,
+                });
+            }
+            if entry.complexity.cognitive
+                > baseline
+                    .cognitive
+                    .saturating_add(rules.max_cognitive_increase)
+            {
+                violations.push(ComplexityDeltaViolation::CognitiveIncrease {
+                    function: entry.function.clone(),
+                    baseline: baseline.cognitive,
+                    current: entry.complexity.cognitive,
+                    max_increase: rules.max_cognitive_increase,
+                    file: entry.path.clone(),
+                    line: entry.line,
+                });
+            }
+        }
+        violations
+    }
+}
+
+/// Reports function complexity growth against the complexity baseline.
+pub struct ComplexityDeltaValidator {
+    rules: ComplexityDeltaRulesConfig,
+}
+
+crate::impl_config_only_validator_new!(ComplexityDeltaValidator, complexity_delta);
+
+impl ComplexityDeltaValidator {
+    /// Creates a complexity delta validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &ComplexityDeltaRulesConfig) -> Self {
+        Self {
+            rules: config.clone(),
+        }
+    }
+
+    /// Complexity regressions of baselined functions; empty when disabled
+    /// or when the workspace has no baseline.
+    ///
+    /// # Errors
+    /// Returns an error if the baseline is unreadable or the workspace
+    /// cannot be analyzed.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<ComplexityDeltaViolation>> {
+        let baseline_path = config.workspace_root.join(DEFAULT_COMPLEXITY_BASELINE_FILE);
+        if !self.rules.enabled || !baseline_path.is_file() {
+            return Ok(Vec::new());
+        }
+        let baseline = ComplexityBaseline::load(&baseline_path)?;
+        Ok(baseline.regressions(&collect_complexity(config)?, &self.rules))
+    }
+}
+
+impl Validator for ComplexityDeltaValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_COMPLEXITY_DELTA
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports functions whose complexity grew past the complexity baseline"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_COMPLEXITY_DELTA,
+    "Reports functions whose complexity grew past the complexity baseline",
+    |root| {
+        Ok(Box::new(ComplexityDeltaValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
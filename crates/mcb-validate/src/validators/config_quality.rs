@@ -151,7 +151,7 @@ impl ConfigQualityValidator {
                     return Ok(());
                 }
 
-                let content = std::fs::read_to_string(&entry.absolute_path)?;
+                let content = crate::scan::read_source(&entry.absolute_path)?;
                 let lines: Vec<&str> = content.lines().collect();
                 Self::scan_config_file(&entry.absolute_path, &lines, &patterns, &mut violations);
                 Ok(())
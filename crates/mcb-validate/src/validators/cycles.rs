@@ -0,0 +1,447 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Dependency Cycles
+//!
+//! Reports cycles between the modules of a crate (through `use` imports)
+//! and between workspace crates (through `[dependencies]` and
+//! `[build-dependencies]`), printing one cycle path per tangle. Imports
+//! between a module and its own ancestors — `super::` access, parent
+//! re-exports — are structural and not counted. Cycles listed under
+//! `[rules.cycles] allowed_cycles` are accepted.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::ValidationConfigExt;
+use crate::config::FileConfig;
+use crate::define_violations;
+use crate::filters::LanguageId;
+use crate::graph::cycle_paths;
+use crate::scan::{for_each_scan_file, read_source, rust_module_path};
+use crate::validators::dependency::DependencyCycle;
+use crate::{Result, ValidationConfig};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    CARGO_TOML_FILENAME, CFG_TEST_MARKER, USE_DECLARATION_REGEX, VALIDATOR_CYCLES,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+/// Manifest tables whose entries form the crate graph. Dev-dependencies may
+/// legitimately point back at a dependent crate and are left out.
+const CRATE_GRAPH_TABLES: &[&str] = &["dependencies", "build-dependencies"];
+
+define_violations! {
+    ViolationCategory::Architecture,
+    pub enum CycleViolation {
+        /// Modules of one crate importing each other in a loop.
+        #[violation(
+            id = "CYC001",
+            severity = Error,
+            message = "Module dependency cycle in {crate_name}: {cycle:?} (first import at {file}:{line})",
+            suggestion = "Move the items both sides need into a module neither imports, or accept the cycle in [rules.cycles] allowed_cycles"
+        )]
+        ModuleCycle {
+            crate_name: String,
+            cycle: DependencyCycle,
+            file: PathBuf,
+            line: usize,
+        },
+        /// Workspace crates depending on each other in a loop.
+        #[violation(
+            id = "CYC002",
+            severity = Error,
+            message = "Crate dependency cycle: {cycle:?} (in {location})",
+            suggestion = "Extract the shared code into a crate lower in the graph, or accept the cycle in [rules.cycles] allowed_cycles"
+        )]
+        CrateCycle {
+            cycle: DependencyCycle,
+            location: PathBuf,
+        },
+    }
+}
+
+/// Import graph of one crate's modules.
+#[derive(Debug, Default)]
+pub struct ModuleGraph {
+    /// Module path → modules it imports.
+    pub edges: BTreeMap<String, BTreeSet<String>>,
+    /// First import of each edge, as file and 1-based line.
+    pub sites: BTreeMap<(String, String), (PathBuf, usize)>,
+    /// Module pairs joined by a `pub use`, including modules re-exporting
+    /// their own submodules, which `edges` leaves out.
+    pub reexports: BTreeSet<(String, String)>,
+}
+
+impl ModuleGraph {
+    /// Build the graph from `(module path, file, content)` triples.
+    ///
+    /// # Errors
+    /// Returns an error if the `use` pattern fails to compile.
+    pub fn build<'a>(files: impl IntoIterator<Item = (String, PathBuf, &'a str)>) -> Result<Self> {
+        let use_declaration = compile_regex(USE_DECLARATION_REGEX)?;
+        let files: Vec<(String, PathBuf, &str)> = files.into_iter().collect();
+        let modules: BTreeSet<&str> = files.iter().map(|(module, _, _)| module.as_str()).collect();
+
+        let mut graph = Self::default();
+        for (module, file, content) in &files {
+            let lines: Vec<&str> = content.lines().collect();
+            for (line, tree) in use_declarations(&use_declaration, content) {
+                let reexport = lines.get(line - 1).is_some_and(|text| is_pub_use(text));
+                for path in expand_use_tree(&tree) {
+                    let Some(target) = resolve_module(module, &path, &modules) else {
+                        continue;
+                    };
+                    if reexport && !is_ancestor_or_self(&target, module) {
+                        graph.reexports.insert((module.clone(), target.clone()));
+                    }
+                    if is_ancestor_or_self(&target, module) || is_ancestor_or_self(module, &target)
+                    {
+                        continue;
+                    }
+                    graph
+                        .sites
+                        .entry((module.clone(), target.clone()))
+                        .or_insert_with(|| (file.clone(), line));
+                    graph
+                        .edges
+                        .entry(module.clone())
+                        .or_default()
+                        .insert(target);
+                }
+            }
+        }
+        Ok(graph)
+    }
+}
+
+/// `use` declarations in `content` as their start line and use tree,
+/// joined across lines. Scanning stops at the first `#[cfg(test)]`, as test
+/// modules may reach into any module without creating a real dependency.
+pub(crate) fn use_declarations(use_declaration: &Regex, content: &str) -> Vec<(usize, String)> {
+    let mut declarations = Vec::new();
+    let mut lines = content.lines().enumerate();
+    while let Some((index, line)) = lines.next() {
+        if line.trim_start().starts_with(CFG_TEST_MARKER) {
+            break;
+        }
+        let Some(captures) = use_declaration.captures(strip_comment(line)) else {
+            continue;
+        };
+        let mut tree = captures[1].to_owned();
+        while !tree.contains(';') {
+            let Some((_, next)) = lines.next() else {
+                break;
+            };
+            tree.push(' ');
+            tree.push_str(strip_comment(next));
+        }
+        let tree = tree.split(';').next().unwrap_or_default();
+        declarations.push((
+            index + 1,
+            tree.split_whitespace().collect::<Vec<_>>().join(" "),
+        ));
+    }
+    declarations
+}
+
+/// Whether the `use` declaration starting on `line` is public.
+pub(crate) fn is_pub_use(line: &str) -> bool {
+    line.trim_start().starts_with("pub")
+}
+
+fn strip_comment(line: &str) -> &str {
+    line.split("//").next().unwrap_or_default()
+}
+
+/// Paths named by a use tree: `a::{b, c::{d, self}}` → `a::b`, `a::c::d`,
+/// `a::c`. Aliases are dropped.
+#[must_use]
+pub fn expand_use_tree(tree: &str) -> Vec<String> {
+    let mut paths = Vec::new();
+    expand_into("", tree, &mut paths);
+    paths
+}
+
+fn expand_into(prefix: &str, tree: &str, paths: &mut Vec<String>) {
+    let tree = tree.trim();
+    let join = |path: &str| {
+        let path = path.trim().trim_end_matches("::").trim();
+        match (prefix.is_empty(), path.is_empty() || path == "self") {
+            (true, _) => path.to_owned(),
+            (false, true) => prefix.to_owned(),
+            (false, false) => format!("{prefix}::{path}"),
+        }
+    };
+
+    let (Some(open), Some(close)) = (tree.find('{'), tree.rfind('}')) else {
+        let path = tree.split(" as ").next().unwrap_or_default();
+        if !path.is_empty() {
+            paths.push(join(path));
+        }
+        return;
+    };
+    let group_prefix = join(&tree[..open]);
+    let mut depth = 0usize;
+    let mut start = open + 1;
+    for (offset, c) in tree[open + 1..close].char_indices() {
+        let at = open + 1 + offset;
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                expand_into(&group_prefix, &tree[start..at], paths);
+                start = at + 1;
+            }
+            _ => {}
+        }
+    }
+    if !tree[start..close].trim().is_empty() {
+        expand_into(&group_prefix, &tree[start..close], paths);
+    }
+}
+
+/// The longest known module named by the start of the import `path`, seen
+/// from `module`. Paths not starting with `crate`, `super` or `self` name
+/// other crates and resolve to nothing.
+pub(crate) fn resolve_module(module: &str, path: &str, modules: &BTreeSet<&str>) -> Option<String> {
+    let mut segments = path.split("::").map(str::trim);
+    let mut base: Vec<&str> = match segments.next()? {
+        "crate" => Vec::new(),
+        "self" => split_module(module),
+        "super" => {
+            let mut base = split_module(module);
+            base.pop()?;
+            base
+        }
+        _ => return None,
+    };
+    let mut rest: Vec<&str> = segments.collect();
+    while rest.first() == Some(&"super") {
+        base.pop()?;
+        rest.remove(0);
+    }
+    base.extend(rest);
+
+    (1..=base.len())
+        .rev()
+        .map(|len| base[..len].join("::"))
+        .find(|candidate| modules.contains(candidate.as_str()))
+}
+
+fn split_module(module: &str) -> Vec<&str> {
+    if module.is_empty() {
+        Vec::new()
+    } else {
+        module.split("::").collect()
+    }
+}
+
+/// Whether `ancestor` is `module` or one of its parent modules.
+fn is_ancestor_or_self(ancestor: &str, module: &str) -> bool {
+    ancestor.is_empty()
+        || module == ancestor
+        || module
+            .strip_prefix(ancestor)
+            .is_some_and(|rest| rest.starts_with("::"))
+}
+
+/// Workspace crate dependency graph read from each crate's `Cargo.toml`,
+/// keyed by package name.
+///
+/// # Errors
+/// Returns an error if a manifest cannot be read or parsed.
+pub fn crate_graph(
+    config: &ValidationConfig,
+) -> Result<(
+    BTreeMap<String, BTreeSet<String>>,
+    BTreeMap<String, PathBuf>,
+)> {
+    let mut manifests: BTreeMap<String, (PathBuf, toml::Value)> = BTreeMap::new();
+    for crate_dir in config.get_source_dirs()? {
+        let manifest = crate_dir.join(CARGO_TOML_FILENAME);
+        if !manifest.is_file() {
+            continue;
+        }
+        let parsed: toml::Value = toml::from_str(&std::fs::read_to_string(&manifest)?)?;
+        let name = parsed
+            .get("package")
+            .and_then(|package| package.get("name"))
+            .and_then(toml::Value::as_str)
+            .map(str::to_owned)
+            .or_else(|| {
+                crate_dir
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+            })
+            .unwrap_or_default();
+        manifests.insert(name, (manifest, parsed));
+    }
+
+    let mut edges: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (name, (_, parsed)) in &manifests {
+        for table in CRATE_GRAPH_TABLES {
+            let Some(dependencies) = parsed.get(*table).and_then(toml::Value::as_table) else {
+                continue;
+            };
+            for (key, spec) in dependencies {
+                let package = spec
+                    .get("package")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or(key);
+                if package != name && manifests.contains_key(package) {
+                    edges
+                        .entry(name.clone())
+                        .or_default()
+                        .insert(package.to_owned());
+                }
+            }
+        }
+    }
+    let locations = manifests
+        .into_iter()
+        .map(|(name, (manifest, _))| (name, manifest))
+        .collect();
+    Ok((edges, locations))
+}
+
+/// `(module path, file, content)` of every library source file, keyed by
+/// crate directory name.
+///
+/// # Errors
+/// Returns an error if a source file cannot be read.
+pub(crate) fn crate_sources(
+    config: &ValidationConfig,
+) -> Result<BTreeMap<String, Vec<(String, PathBuf, String)>>> {
+    let mut crates: BTreeMap<String, Vec<(String, PathBuf, String)>> = BTreeMap::new();
+    for_each_scan_file(config, Some(LanguageId::Rust), true, |entry, src_dir| {
+        let file = &entry.absolute_path;
+        let Some(module) = rust_module_path(file, src_dir) else {
+            return Ok(());
+        };
+        let crate_name = src_dir
+            .parent()
+            .and_then(Path::file_name)
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        crates
+            .entry(crate_name)
+            .or_default()
+            .push((module, file.clone(), read_source(file)?));
+        Ok(())
+    })?;
+    Ok(crates)
+}
+
+/// Whether `cycle` (closed path) has exactly the members of an allowed entry.
+fn is_allowed(cycle: &[String], allowed: &[BTreeSet<&str>]) -> bool {
+    let members: BTreeSet<&str> = cycle.iter().map(String::as_str).collect();
+    allowed.contains(&members)
+}
+
+/// Reports module and crate dependency cycles.
+pub struct CycleValidator {
+    workspace_root: PathBuf,
+}
+
+impl CycleValidator {
+    /// Create a cycle validator rooted at `workspace_root`.
+    pub fn new(workspace_root: impl Into<PathBuf>) -> Self {
+        Self {
+            workspace_root: workspace_root.into(),
+        }
+    }
+
+    /// Module and crate cycles that are not explicitly allowed.
+    ///
+    /// # Errors
+    /// Returns an error if a source file or manifest cannot be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<CycleViolation>> {
+        let rules = FileConfig::load(&self.workspace_root).rules.cycles;
+        if !rules.enabled {
+            return Ok(Vec::new());
+        }
+        let allowed: Vec<BTreeSet<&str>> = rules
+            .allowed_cycles
+            .iter()
+            .map(|members| members.iter().map(String::as_str).collect())
+            .collect();
+
+        let mut violations = Self::module_cycles(config, &allowed)?;
+        let (edges, manifests) = crate_graph(config)?;
+        for cycle in cycle_paths(&edges) {
+            if is_allowed(&cycle, &allowed) {
+                continue;
+            }
+            violations.push(CycleViolation::CrateCycle {
+                location: manifests.get(&cycle[0]).cloned().unwrap_or_default(),
+                cycle: DependencyCycle(cycle),
+            });
+        }
+        Ok(violations)
+    }
+
+    fn module_cycles(
+        config: &ValidationConfig,
+        allowed: &[BTreeSet<&str>],
+    ) -> Result<Vec<CycleViolation>> {
+        let mut violations = Vec::new();
+        for (crate_name, files) in &crate_sources(config)? {
+            let graph =
+                ModuleGraph::build(files.iter().map(|(module, file, content)| {
+                    (module.clone(), file.clone(), content.as_str())
+                }))?;
+            for cycle in cycle_paths(&graph.edges) {
+                let qualified: Vec<String> = cycle
+                    .iter()
+                    .map(|module| format!("{crate_name}::{module}"))
+                    .collect();
+                if is_allowed(&qualified, allowed) {
+                    continue;
+                }
+                let Some((file, line)) = graph.sites.get(&(cycle[0].clone(), cycle[1].clone()))
+                else {
+                    continue;
+                };
+                violations.push(CycleViolation::ModuleCycle {
+                    crate_name: crate_name.clone(),
+                    cycle: DependencyCycle(qualified),
+                    file: file.clone(),
+                    line: *line,
+                });
+            }
+        }
+        Ok(violations)
+    }
+}
+
+impl Validator for CycleValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_CYCLES
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects module and crate dependency cycles"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_CYCLES,
+    "Detects module and crate dependency cycles",
+    |root| {
+        Ok(Box::new(CycleValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
@@ -0,0 +1,262 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Unused Public API Detection
+//!
+//! Flags `pub` structs, enums, traits and functions whose name never appears
+//! in Rust code outside their crate's `src/` — not in other crates, and not
+//! in the crate's own integration tests, benches or examples — and suggests
+//! `pub(crate)`. Items come from the fact extractor; references are matched
+//! by identifier, so a name shared with anything used elsewhere counts as
+//! used. Crates published for outside consumers are listed in
+//! `[rules.dead_api] public_api_crates`.
+
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+
+use rayon::prelude::*;
+use regex::Regex;
+
+use crate::ValidationConfigExt;
+use crate::config::DeadApiRulesConfig;
+use crate::define_violations;
+use crate::extractor::{Fact, FactType, RustExtractor};
+use crate::filters::LanguageId;
+use crate::run_context::ValidationRunContext;
+use crate::scan::{read_source, rust_module_path};
+use crate::{Result, ValidationConfig};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{FACT_ATTR_VISIBILITY, VALIDATOR_DEAD_API};
+use mcb_utils::utils::regex::compile_regex;
+
+/// Functions that are public by convention rather than for callers.
+const ENTRY_POINT_FUNCTIONS: &[&str] = &["main"];
+
+define_violations! {
+    ViolationCategory::Organization,
+    pub enum DeadApiViolation {
+        /// Public item never referenced outside its crate.
+        #[violation(
+            id = "VIS004",
+            severity = Info,
+            message = "Public {item_kind} {item_name} in {crate_name} is not used outside the crate at {file}:{line} - consider pub(crate)",
+            suggestion = "Make {item_name} pub(crate), or list it under [rules.dead_api] allowed_items if it is intentional API"
+        )]
+        UnusedPublicItem {
+            crate_name: String,
+            item_kind: String,
+            item_name: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// Public item extracted from a crate source file.
+struct PublicItem {
+    kind: &'static str,
+    name: String,
+    line: usize,
+}
+
+/// Per-file scan result.
+struct ScannedFile {
+    path: PathBuf,
+    /// Index of the crate whose `src/` holds the file.
+    owner: Option<usize>,
+    identifiers: HashSet<String>,
+    items: Vec<PublicItem>,
+}
+
+/// Unused public API validator.
+pub struct DeadApiValidator {
+    public_api_crates: HashSet<String>,
+    allowed_items: HashSet<String>,
+    enabled: bool,
+}
+
+crate::impl_config_only_validator_new!(DeadApiValidator, dead_api);
+
+impl DeadApiValidator {
+    /// Creates an unused public API validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &DeadApiRulesConfig) -> Self {
+        Self {
+            public_api_crates: config.public_api_crates.iter().cloned().collect(),
+            allowed_items: config.allowed_items.iter().cloned().collect(),
+            enabled: config.enabled,
+        }
+    }
+
+    /// Public items never referenced outside their crate.
+    ///
+    /// # Errors
+    /// Returns an error if the workspace cannot be scanned or a file cannot
+    /// be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<DeadApiViolation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let src_dirs: Vec<PathBuf> = config
+            .get_scan_dirs()?
+            .into_iter()
+            .map(|dir| std::fs::canonicalize(&dir).unwrap_or(dir))
+            .collect();
+        let crate_names: Vec<String> = src_dirs
+            .iter()
+            .map(|dir| {
+                dir.parent()
+                    .and_then(|parent| parent.file_name())
+                    .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+            })
+            .collect();
+
+        let files = self.scan_files(config, &src_dirs, &crate_names)?;
+
+        let mut referenced_from: HashMap<&str, HashSet<Option<usize>>> = HashMap::new();
+        for file in &files {
+            for identifier in &file.identifiers {
+                referenced_from
+                    .entry(identifier.as_str())
+                    .or_default()
+                    .insert(file.owner);
+            }
+        }
+
+        let mut violations = Vec::new();
+        for file in &files {
+            let Some(owner) = file.owner else {
+                continue;
+            };
+            for item in &file.items {
+                let used_outside = referenced_from
+                    .get(item.name.as_str())
+                    .is_some_and(|owners| owners.iter().any(|other| *other != Some(owner)));
+                if !used_outside {
+                    violations.push(DeadApiViolation::UnusedPublicItem {
+                        crate_name: crate_names[owner].clone(),
+                        item_kind: item.kind.to_owned(),
+                        item_name: item.name.clone(),
+                        file: file.path.clone(),
+                        line: item.line,
+                    });
+                }
+            }
+        }
+        Ok(violations)
+    }
+
+    /// Identifiers of every Rust file in the workspace, and the public items
+    /// of library files in crates that are not public API crates.
+    fn scan_files(
+        &self,
+        config: &ValidationConfig,
+        src_dirs: &[PathBuf],
+        crate_names: &[String],
+    ) -> Result<Vec<ScannedFile>> {
+        let context = ValidationRunContext::active_or_build(config)?;
+        let identifier = compile_regex(r"[A-Za-z_][A-Za-z0-9_]*")?;
+        let paths: Vec<PathBuf> = context
+            .file_inventory()
+            .iter()
+            .filter(|entry| entry.detected_language == Some(LanguageId::Rust))
+            .map(|entry| entry.absolute_path.clone())
+            .collect();
+
+        paths
+            .into_par_iter()
+            .map(|path| {
+                let owner = src_dirs.iter().position(|dir| path.starts_with(dir));
+                let content = ValidationRunContext::with_active(&context, || read_source(&path))?;
+                let identifiers = collect_identifiers(&identifier, &content);
+                let items = match owner {
+                    Some(index)
+                        if !self.public_api_crates.contains(&crate_names[index])
+                            && rust_module_path(&path, &src_dirs[index]).is_some() =>
+                    {
+                        let facts = ValidationRunContext::with_active(&context, || {
+                            RustExtractor.extract_facts(&path)
+                        })?;
+                        self.public_items(&facts)
+                    }
+                    _ => Vec::new(),
+                };
+                Ok(ScannedFile {
+                    path,
+                    owner,
+                    identifiers,
+                    items,
+                })
+            })
+            .collect()
+    }
+
+    fn public_items(&self, facts: &[Fact]) -> Vec<PublicItem> {
+        facts
+            .iter()
+            .filter(|fact| {
+                fact.attributes
+                    .get(FACT_ATTR_VISIBILITY)
+                    .map(String::as_str)
+                    == Some("pub")
+            })
+            .filter(|fact| !self.allowed_items.contains(&fact.name))
+            .filter_map(|fact| {
+                let kind = match fact.fact_type {
+                    FactType::Struct => "struct",
+                    FactType::Enum => "enum",
+                    FactType::Trait => "trait",
+                    FactType::Function if !ENTRY_POINT_FUNCTIONS.contains(&fact.name.as_str()) => {
+                        "fn"
+                    }
+                    _ => return None,
+                };
+                Some(PublicItem {
+                    kind,
+                    name: fact.name.clone(),
+                    line: fact.location.start_line,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Identifiers in `content` outside `//` comments.
+fn collect_identifiers(identifier: &Regex, content: &str) -> HashSet<String> {
+    content
+        .lines()
+        .flat_map(|line| identifier.find_iter(line.split("//").next().unwrap_or_default()))
+        .map(|found| found.as_str().to_owned())
+        .collect()
+}
+
+impl Validator for DeadApiValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_DEAD_API
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags public items never used outside their crate"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_DEAD_API,
+    "Flags public items never used outside their crate",
+    |root| {
+        Ok(Box::new(DeadApiValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
@@ -19,6 +19,7 @@ use crate::filters::dependency_parser::WorkspaceDependencies;
 use crate::filters::rule_filters::RuleFilterExecutor;
 use crate::linters::YamlRuleExecutor;
 use crate::metrics::{MetricThresholds, MetricViolation, RcaAnalyzer};
+use crate::rules::plugins::{load_rule_plugins, merge_plugin_rules};
 use crate::rules::yaml_loader::{ValidatedRule, YamlRuleLoader};
 use crate::scan::for_each_scan_file;
 use crate::validators::declarative_support::{
@@ -62,11 +63,18 @@ impl DeclarativeValidator {
         }
     }
 
-    fn load_rules(&self) -> Result<Vec<ValidatedRule>> {
+    /// Load the workspace rules (or the embedded defaults), deduplicated by
+    /// ID, followed by the configured rule plugins.
+    pub(crate) fn load_rules(&self) -> Result<Vec<ValidatedRule>> {
         let variables = build_substitution_variables(&self.workspace_root);
         let file_config = FileConfig::load(&self.workspace_root);
         let rules_path = self.workspace_root.join(&file_config.general.rules_path);
 
+        let plugins = load_rule_plugins(
+            &self.workspace_root,
+            &file_config.plugins.rule_dirs,
+            Some(&variables),
+        )?;
         let mut loader = YamlRuleLoader::with_variables(rules_path, Some(variables))?;
         if !self
             .workspace_root
@@ -83,7 +91,7 @@ impl DeclarativeValidator {
                 rules.push(rule);
             }
         }
-        Ok(rules)
+        merge_plugin_rules(rules, plugins)
     }
 
     fn collect_files(config: &ValidationConfig, language: Option<LanguageId>) -> Vec<PathBuf> {
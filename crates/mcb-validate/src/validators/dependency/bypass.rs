@@ -90,7 +90,7 @@ where
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             collect_pattern_matches(path, &content, pattern, &make_violation, out);
             Ok(())
         },
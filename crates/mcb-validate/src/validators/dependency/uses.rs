@@ -39,7 +39,7 @@ pub fn validate_use_statements(
             Some(LanguageId::Rust),
             |entry| {
                 let path = &entry.absolute_path;
-                let content = std::fs::read_to_string(path)?;
+                let content = crate::scan::read_source(path)?;
                 collect_forbidden_uses(
                     path,
                     &content,
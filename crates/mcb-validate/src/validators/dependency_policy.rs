@@ -0,0 +1,378 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Dependency Policy
+//!
+//! cargo-deny style checks over the workspace dependency graph:
+//!
+//! - banned crates anywhere in `Cargo.lock`;
+//! - third-party licenses outside `[rules.dependency_policy] allowed_licenses`
+//!   (read through `cargo metadata --offline`, skipped when the list is empty);
+//! - crates locked at more than one version;
+//! - `*` version requirements in workspace manifests.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::path::{Path, PathBuf};
+
+use cargo_metadata::MetadataCommand;
+use serde::Deserialize;
+
+use crate::ValidationConfigExt;
+use crate::config::DependencyPolicyRulesConfig;
+use crate::define_violations;
+use crate::{Result, ValidationConfig, ValidationError};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    CARGO_DEPENDENCY_TABLES, CARGO_LOCK_FILENAME, CARGO_TOML_FILENAME, CARGO_WILDCARD_VERSION,
+    VALIDATOR_DEPENDENCY_POLICY,
+};
+
+/// License reported for crates without a `license` field.
+const UNSPECIFIED_LICENSE: &str = "unspecified";
+
+define_violations! {
+    ViolationCategory::Configuration,
+    pub enum DependencyPolicyViolation {
+        /// Banned crate present in the dependency graph.
+        #[violation(
+            id = "DENY001",
+            severity = Error,
+            message = "Banned crate {crate_name} ({versions}) is in the dependency graph ({location})",
+            suggestion = "Remove the dependency that pulls in {crate_name}, or drop it from [rules.dependency_policy] banned_crates"
+        )]
+        BannedCrate {
+            crate_name: String,
+            versions: String,
+            location: PathBuf,
+        },
+        /// Third-party crate under a license outside the allowlist.
+        #[violation(
+            id = "DENY002",
+            severity = Error,
+            message = "License {license} of {crate_name} {version} is not allowed ({location})",
+            suggestion = "Replace {crate_name}, or add an acceptable license to [rules.dependency_policy] allowed_licenses"
+        )]
+        DisallowedLicense {
+            crate_name: String,
+            version: String,
+            license: String,
+            location: PathBuf,
+        },
+        /// Crate locked at several versions.
+        #[violation(
+            id = "DENY003",
+            severity = Warning,
+            message = "Crate {crate_name} is locked at several versions: {versions} ({location})",
+            suggestion = "Align the requirements on {crate_name}, or list it under [rules.dependency_policy] allowed_duplicates"
+        )]
+        DuplicateVersions {
+            crate_name: String,
+            versions: String,
+            location: PathBuf,
+        },
+        /// Dependency declared with a `*` version requirement.
+        #[violation(
+            id = "DENY004",
+            severity = Error,
+            message = "Wildcard version for dependency {dependency} at {file}:{line}",
+            suggestion = "Require a concrete version range for {dependency}, e.g. \"1\""
+        )]
+        WildcardVersion {
+            dependency: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// A `[[package]]` entry of `Cargo.lock`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LockedPackage {
+    /// Package name.
+    pub name: String,
+    /// Locked version.
+    pub version: String,
+}
+
+#[derive(Deserialize)]
+struct Lockfile {
+    #[serde(default)]
+    package: Vec<LockedPackage>,
+}
+
+/// Dependency policy validator.
+pub struct DependencyPolicyValidator {
+    banned_crates: BTreeSet<String>,
+    allowed_licenses: HashSet<String>,
+    allowed_duplicates: HashSet<String>,
+    enabled: bool,
+}
+
+crate::impl_config_only_validator_new!(DependencyPolicyValidator, dependency_policy);
+
+impl DependencyPolicyValidator {
+    /// Creates a dependency policy validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &DependencyPolicyRulesConfig) -> Self {
+        Self {
+            banned_crates: config.banned_crates.iter().cloned().collect(),
+            allowed_licenses: config.allowed_licenses.iter().cloned().collect(),
+            allowed_duplicates: config.allowed_duplicates.iter().cloned().collect(),
+            enabled: config.enabled,
+        }
+    }
+
+    /// Dependency policy violations of the workspace.
+    ///
+    /// # Errors
+    /// Returns an error if a manifest or the lockfile cannot be parsed, or
+    /// if the license check is enabled and `cargo metadata` fails.
+    pub fn validate_all(
+        &self,
+        config: &ValidationConfig,
+    ) -> Result<Vec<DependencyPolicyViolation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+        let mut violations = wildcard_versions(config)?;
+
+        let lockfile = config.workspace_root.join(CARGO_LOCK_FILENAME);
+        if lockfile.is_file() {
+            let locked: Lockfile = toml::from_str(&std::fs::read_to_string(&lockfile)?)?;
+            violations.extend(self.check_lockfile(&locked.package, &lockfile));
+        }
+
+        if !self.allowed_licenses.is_empty() {
+            violations.extend(self.check_licenses(&config.workspace_root, &lockfile)?);
+        }
+        Ok(violations)
+    }
+
+    /// Banned and duplicated crates among `packages`, reported at `lockfile`.
+    #[must_use]
+    pub fn check_lockfile(
+        &self,
+        packages: &[LockedPackage],
+        lockfile: &Path,
+    ) -> Vec<DependencyPolicyViolation> {
+        let mut versions: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        for package in packages {
+            versions
+                .entry(package.name.as_str())
+                .or_default()
+                .insert(package.version.as_str());
+        }
+
+        let mut violations = Vec::new();
+        for (name, locked) in versions {
+            let listed = locked.iter().copied().collect::<Vec<_>>().join(", ");
+            if self.banned_crates.contains(name) {
+                violations.push(DependencyPolicyViolation::BannedCrate {
+                    crate_name: name.to_owned(),
+                    versions: listed.clone(),
+                    location: lockfile.to_path_buf(),
+                });
+            }
+            if locked.len() > 1 && !self.allowed_duplicates.contains(name) {
+                violations.push(DependencyPolicyViolation::DuplicateVersions {
+                    crate_name: name.to_owned(),
+                    versions: listed,
+                    location: lockfile.to_path_buf(),
+                });
+            }
+        }
+        violations
+    }
+
+    /// Third-party packages whose license expression the allowlist does not
+    /// satisfy.
+    fn check_licenses(
+        &self,
+        workspace_root: &Path,
+        lockfile: &Path,
+    ) -> Result<Vec<DependencyPolicyViolation>> {
+        let metadata = MetadataCommand::new()
+            .manifest_path(workspace_root.join(CARGO_TOML_FILENAME))
+            .other_options(vec!["--offline".to_owned()])
+            .exec()
+            .map_err(|e| ValidationError::Config(format!("cargo_metadata failed: {e}")))?;
+
+        Ok(metadata
+            .packages
+            .iter()
+            .filter(|package| package.source.is_some())
+            .filter_map(|package| {
+                let license = package.license.as_deref().unwrap_or(UNSPECIFIED_LICENSE);
+                (!license_allowed(license, &self.allowed_licenses)).then(|| {
+                    DependencyPolicyViolation::DisallowedLicense {
+                        crate_name: package.name.to_string(),
+                        version: package.version.to_string(),
+                        license: license.to_owned(),
+                        location: lockfile.to_path_buf(),
+                    }
+                })
+            })
+            .collect())
+    }
+}
+
+/// Whether the SPDX `expression` is satisfied by `allowed` licenses.
+///
+/// Supports `OR`, `AND`, parentheses, `WITH` exceptions (which must be
+/// allowed as `License WITH Exception`, or the license itself), the `+`
+/// suffix and the legacy `MIT/Apache-2.0` form.
+#[must_use]
+pub fn license_allowed(expression: &str, allowed: &HashSet<String>) -> bool {
+    let normalized = expression
+        .replace('(', " ( ")
+        .replace(')', " ) ")
+        .replace('/', " OR ");
+    let tokens: Vec<&str> = normalized.split_whitespace().collect();
+    let mut position = 0;
+    let satisfied = eval_or(&tokens, &mut position, allowed);
+    satisfied && position == tokens.len()
+}
+
+fn eval_or(tokens: &[&str], position: &mut usize, allowed: &HashSet<String>) -> bool {
+    let mut satisfied = eval_and(tokens, position, allowed);
+    while tokens.get(*position) == Some(&"OR") {
+        *position += 1;
+        satisfied |= eval_and(tokens, position, allowed);
+    }
+    satisfied
+}
+
+fn eval_and(tokens: &[&str], position: &mut usize, allowed: &HashSet<String>) -> bool {
+    let mut satisfied = eval_license(tokens, position, allowed);
+    while tokens.get(*position) == Some(&"AND") {
+        *position += 1;
+        satisfied &= eval_license(tokens, position, allowed);
+    }
+    satisfied
+}
+
+fn eval_license(tokens: &[&str], position: &mut usize, allowed: &HashSet<String>) -> bool {
+    let Some(token) = tokens.get(*position) else {
+        return false;
+    };
+    *position += 1;
+    if *token == "(" {
+        let satisfied = eval_or(tokens, position, allowed);
+        if tokens.get(*position) == Some(&")") {
+            *position += 1;
+            return satisfied;
+        }
+        return false;
+    }
+    let license = token.trim_end_matches('+');
+    if tokens.get(*position) == Some(&"WITH") {
+        let exception = tokens.get(*position + 1).copied().unwrap_or_default();
+        *position += 2;
+        return allowed.contains(license)
+            || allowed.contains(&format!("{license} WITH {exception}"));
+    }
+    allowed.contains(license)
+}
+
+/// `*` requirements in the workspace manifest and every crate manifest.
+fn wildcard_versions(config: &ValidationConfig) -> Result<Vec<DependencyPolicyViolation>> {
+    let mut manifests = vec![config.workspace_root.join(CARGO_TOML_FILENAME)];
+    manifests.extend(
+        config
+            .get_source_dirs()?
+            .into_iter()
+            .map(|dir| dir.join(CARGO_TOML_FILENAME)),
+    );
+
+    let mut violations = Vec::new();
+    for manifest in manifests.into_iter().filter(|path| path.is_file()) {
+        let content = std::fs::read_to_string(&manifest)?;
+        let parsed: toml::Value = toml::from_str(&content)?;
+        for dependency in wildcard_dependencies(&parsed) {
+            violations.push(DependencyPolicyViolation::WildcardVersion {
+                line: dependency_line(&content, &dependency),
+                dependency,
+                file: manifest.clone(),
+            });
+        }
+    }
+    Ok(violations)
+}
+
+/// Dependencies of `manifest` required as `*`, from the regular, dev and
+/// build tables, their `[target.*]` variants and `[workspace.dependencies]`.
+#[must_use]
+pub fn wildcard_dependencies(manifest: &toml::Value) -> BTreeSet<String> {
+    let owners = std::iter::once(manifest)
+        .chain(
+            manifest
+                .get("target")
+                .and_then(toml::Value::as_table)
+                .into_iter()
+                .flat_map(|targets| targets.values()),
+        )
+        .chain(manifest.get("workspace"));
+
+    owners
+        .flat_map(|owner| {
+            CARGO_DEPENDENCY_TABLES
+                .iter()
+                .filter_map(move |table| owner.get(*table))
+        })
+        .filter_map(toml::Value::as_table)
+        .flatten()
+        .filter(|(_, spec)| {
+            let version = spec
+                .as_str()
+                .or_else(|| spec.get("version").and_then(toml::Value::as_str));
+            version.is_some_and(|version| version.trim() == CARGO_WILDCARD_VERSION)
+        })
+        .map(|(name, _)| name.clone())
+        .collect()
+}
+
+/// 1-based line declaring `dependency` in manifest `content`, or 1.
+pub(crate) fn dependency_line(content: &str, dependency: &str) -> usize {
+    content
+        .lines()
+        .position(|line| {
+            let line = line.trim();
+            let key = line.split('=').next().unwrap_or_default().trim();
+            key == dependency
+                || (line.starts_with('[')
+                    && line
+                        .trim_end_matches(']')
+                        .ends_with(&format!(".{dependency}")))
+        })
+        .map_or(1, |index| index + 1)
+}
+
+impl Validator for DependencyPolicyValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_DEPENDENCY_POLICY
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks banned crates, licenses and version requirements"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_DEPENDENCY_POLICY,
+    "Checks banned crates, licenses and version requirements",
+    |root| {
+        Ok(Box::new(DependencyPolicyValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
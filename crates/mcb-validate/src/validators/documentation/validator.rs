@@ -144,7 +144,7 @@ impl DocumentationValidator {
             Some(LanguageId::Rust),
             |entry, _src_dir, _crate_name| {
                 let path = &entry.absolute_path;
-                let content = std::fs::read_to_string(path)?;
+                let content = crate::scan::read_source(path)?;
                 let file_name = path.file_name().and_then(|s| s.to_str()).unwrap_or("");
 
                 // Only check module files that require documentation
@@ -188,7 +188,7 @@ impl DocumentationValidator {
             config,
             Some(LanguageId::Rust),
             |entry, _src_dir, _crate_name| {
-                let content = std::fs::read_to_string(&entry.absolute_path)?;
+                let content = crate::scan::read_source(&entry.absolute_path)?;
                 let lines: Vec<&str> = content.lines().collect();
                 Self::scan_file_for_pub_docs(
                     &entry.absolute_path,
@@ -90,7 +90,7 @@ impl ErrorBoundaryValidator {
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             Self::scan_file_lines(path, &content, &mut line_handler);
             Ok(())
         })
@@ -0,0 +1,419 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Feature Flag Hygiene
+//!
+//! Cross-references each crate's `[features]` table with the feature
+//! predicates in its Rust code (`cfg`, `cfg_attr`, `cfg!` and
+//! `CARGO_FEATURE_*` in build scripts) and reports:
+//!
+//! - code gated on a feature the crate does not define, which never compiles;
+//! - defined features that gate no code and only enable dependencies;
+//! - feature-gated `pub` items without a `docsrs` `doc(cfg(...))` attribute,
+//!   unless the crate root enables `doc_auto_cfg`.
+//!
+//! Manifests are read in the same JSON shape as `RuleContext::cargo_data`
+//! (see [`cargo_data`]), so rule engines and this validator see one model.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::ValidationConfigExt;
+use crate::config::FeaturesRulesConfig;
+use crate::define_violations;
+use crate::filters::LanguageId;
+use crate::run_context::ValidationRunContext;
+use crate::scan::read_source;
+use crate::{Result, ValidationConfig};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    CARGO_DEFAULT_FEATURE, CARGO_FEATURE_ENV_PREFIX, CARGO_TOML_FILENAME, DOC_AUTO_CFG_FEATURE,
+    DOCSRS_CFG, FEATURE_PREDICATE_REGEX, VALIDATOR_FEATURES,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+define_violations! {
+    ViolationCategory::Configuration,
+    pub enum FeatureViolation {
+        /// Code gated on a feature the crate does not define.
+        #[violation(
+            id = "FEAT001",
+            severity = Error,
+            message = "Undefined feature \"{feature}\" of {crate_name} used at {file}:{line} - the gated code never compiles",
+            suggestion = "Fix the feature name or declare it under [features] in {crate_name}/Cargo.toml"
+        )]
+        UndefinedFeature {
+            crate_name: String,
+            feature: String,
+            file: PathBuf,
+            line: usize,
+        },
+        /// Feature declared in the manifest but gating no code.
+        #[violation(
+            id = "FEAT002",
+            severity = Warning,
+            message = "Feature \"{feature}\" of {crate_name} gates no code ({location}:{line})",
+            suggestion = "Gate the code that needs it with #[cfg(feature = \"{feature}\")], or remove the feature"
+        )]
+        UnusedFeature {
+            crate_name: String,
+            feature: String,
+            location: PathBuf,
+            line: usize,
+        },
+        /// Feature-gated public item without a docs.rs cfg annotation.
+        #[violation(
+            id = "FEAT003",
+            severity = Info,
+            message = "Public item gated on feature \"{feature}\" lacks a docsrs doc(cfg) attribute at {file}:{line}",
+            suggestion = "Add #[cfg_attr(docsrs, doc(cfg(feature = \"{feature}\")))] so docs.rs shows the requirement"
+        )]
+        MissingDocsrsCfg {
+            feature: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// Manifest of every crate in the scan directories as JSON, keyed by crate
+/// directory name — the shape of `RuleContext::cargo_data`.
+///
+/// # Errors
+/// Returns an error if a manifest cannot be read or parsed.
+pub fn cargo_data(config: &ValidationConfig) -> Result<HashMap<String, serde_json::Value>> {
+    let mut data = HashMap::new();
+    for crate_dir in config.get_source_dirs()? {
+        let manifest = crate_dir.join(CARGO_TOML_FILENAME);
+        let Some(crate_name) = crate_dir
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+        else {
+            continue;
+        };
+        if !manifest.is_file() {
+            continue;
+        }
+        let parsed: toml::Value = toml::from_str(&std::fs::read_to_string(&manifest)?)?;
+        data.insert(
+            crate_name,
+            serde_json::to_value(parsed).unwrap_or(serde_json::Value::Null),
+        );
+    }
+    Ok(data)
+}
+
+/// Features a crate can be built with.
+#[derive(Debug, Default)]
+pub struct FeatureSet {
+    /// `[features]` entries and what each enables.
+    pub declared: BTreeMap<String, Vec<String>>,
+    /// Optional dependencies not referenced as `dep:name`, which Cargo
+    /// turns into features of the same name.
+    pub implicit: BTreeSet<String>,
+}
+
+impl FeatureSet {
+    /// Features of the manifest `manifest` (as in `RuleContext::cargo_data`).
+    #[must_use]
+    pub fn from_manifest(manifest: &serde_json::Value) -> Self {
+        let declared: BTreeMap<String, Vec<String>> = manifest
+            .get("features")
+            .and_then(serde_json::Value::as_object)
+            .map(|features| {
+                features
+                    .iter()
+                    .map(|(name, enables)| {
+                        let enables = enables
+                            .as_array()
+                            .into_iter()
+                            .flatten()
+                            .filter_map(serde_json::Value::as_str)
+                            .map(str::to_owned)
+                            .collect();
+                        (name.clone(), enables)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let explicit_deps: BTreeSet<&str> = declared
+            .values()
+            .flatten()
+            .filter_map(|enabled| enabled.strip_prefix("dep:"))
+            .collect();
+        let implicit = ["dependencies", "build-dependencies"]
+            .iter()
+            .filter_map(|table| manifest.get(*table).and_then(serde_json::Value::as_object))
+            .flatten()
+            .filter(|(_, spec)| {
+                spec.get("optional").and_then(serde_json::Value::as_bool) == Some(true)
+            })
+            .map(|(name, _)| name.clone())
+            .filter(|name| !explicit_deps.contains(name.as_str()))
+            .collect();
+
+        Self { declared, implicit }
+    }
+
+    /// Whether code may be gated on `feature`.
+    #[must_use]
+    pub fn defines(&self, feature: &str) -> bool {
+        self.declared.contains_key(feature) || self.implicit.contains(feature)
+    }
+
+    /// Whether `feature` only groups other features of this or other crates,
+    /// so it needs no code of its own.
+    fn is_aggregate(&self, feature: &str) -> bool {
+        self.declared.get(feature).is_some_and(|enables| {
+            enables
+                .iter()
+                .any(|enabled| enabled.contains('/') || self.declared.contains_key(enabled))
+        })
+    }
+}
+
+/// A feature predicate in source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureUse {
+    /// Feature name.
+    pub feature: String,
+    /// 1-based line.
+    pub line: usize,
+}
+
+/// Feature-flag hygiene validator.
+pub struct FeatureValidator {
+    enabled: bool,
+    require_docsrs: bool,
+}
+
+crate::impl_config_only_validator_new!(FeatureValidator, features);
+
+impl FeatureValidator {
+    /// Creates a feature hygiene validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &FeaturesRulesConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            require_docsrs: config.require_docsrs,
+        }
+    }
+
+    /// Feature hygiene violations of every crate in the workspace.
+    ///
+    /// # Errors
+    /// Returns an error if a manifest or source file cannot be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<FeatureViolation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+        let predicate = compile_regex(FEATURE_PREDICATE_REGEX)?;
+        let cargo_data = cargo_data(config)?;
+        let context = ValidationRunContext::active_or_build(config)?;
+
+        let mut violations = Vec::new();
+        for crate_dir in config.get_source_dirs()? {
+            let Some(crate_name) = crate_dir
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+            else {
+                continue;
+            };
+            let Some(manifest) = cargo_data.get(&crate_name) else {
+                continue;
+            };
+            let features = FeatureSet::from_manifest(manifest);
+            let crate_dir = std::fs::canonicalize(&crate_dir).unwrap_or(crate_dir);
+            let src_dir = crate_dir.join("src");
+
+            let mut used: BTreeSet<String> = BTreeSet::new();
+            let mut auto_doc_cfg = false;
+            let mut gated_items = Vec::new();
+            for entry in context.file_inventory() {
+                let path = &entry.absolute_path;
+                if entry.detected_language != Some(LanguageId::Rust)
+                    || !path.starts_with(&crate_dir)
+                {
+                    continue;
+                }
+                let content = read_source(path)?;
+                for feature_use in feature_uses(&predicate, &content) {
+                    if !features.defines(&feature_use.feature) {
+                        violations.push(FeatureViolation::UndefinedFeature {
+                            crate_name: crate_name.clone(),
+                            feature: feature_use.feature.clone(),
+                            file: path.clone(),
+                            line: feature_use.line,
+                        });
+                    }
+                    used.insert(feature_use.feature);
+                }
+                used.extend(
+                    features
+                        .declared
+                        .keys()
+                        .filter(|name| content.contains(&build_script_var(name)))
+                        .cloned(),
+                );
+                if path.starts_with(&src_dir) {
+                    auto_doc_cfg |= content.contains(DOC_AUTO_CFG_FEATURE);
+                    gated_items.extend(
+                        undocumented_gated_items(&predicate, &content)
+                            .into_iter()
+                            .map(|feature_use| (path.clone(), feature_use)),
+                    );
+                }
+            }
+
+            let manifest_path = crate_dir.join(CARGO_TOML_FILENAME);
+            for feature in features.declared.keys() {
+                if feature == CARGO_DEFAULT_FEATURE
+                    || used.contains(feature)
+                    || features.is_aggregate(feature)
+                {
+                    continue;
+                }
+                violations.push(FeatureViolation::UnusedFeature {
+                    crate_name: crate_name.clone(),
+                    feature: feature.clone(),
+                    line: manifest_line(&manifest_path, feature),
+                    location: manifest_path.clone(),
+                });
+            }
+
+            if self.require_docsrs && !auto_doc_cfg {
+                violations.extend(gated_items.into_iter().map(|(file, feature_use)| {
+                    FeatureViolation::MissingDocsrsCfg {
+                        feature: feature_use.feature,
+                        file,
+                        line: feature_use.line,
+                    }
+                }));
+            }
+        }
+        Ok(violations)
+    }
+}
+
+/// Feature predicates on `cfg` lines of `content`.
+#[must_use]
+pub fn feature_uses(predicate: &Regex, content: &str) -> Vec<FeatureUse> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line.split("//").next().unwrap_or_default()))
+        .filter(|(_, code)| code.contains("cfg"))
+        .flat_map(|(index, code)| {
+            predicate
+                .captures_iter(code)
+                .map(move |captures| FeatureUse {
+                    feature: captures[1].to_owned(),
+                    line: index + 1,
+                })
+        })
+        .collect()
+}
+
+/// `pub` items under a `#[cfg(... feature ...)]` attribute whose attribute
+/// block never mentions `docsrs`; reported at the `cfg` line.
+#[must_use]
+pub fn undocumented_gated_items(predicate: &Regex, content: &str) -> Vec<FeatureUse> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut found = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let start = index;
+        let mut gate = None;
+        let mut documented = false;
+        while index < lines.len() {
+            let line = lines[index].trim_start();
+            if line.starts_with("#[") {
+                if line.starts_with("#[cfg(") {
+                    gate = gate.or_else(|| predicate.captures(line).map(|c| c[1].to_owned()));
+                }
+                documented |= line.contains(DOCSRS_CFG);
+            } else if !line.starts_with("///") {
+                break;
+            }
+            index += 1;
+        }
+        let is_pub_item = lines
+            .get(index)
+            .is_some_and(|line| line.trim_start().starts_with("pub "));
+        if let Some(feature) = gate
+            && is_pub_item
+            && !documented
+        {
+            found.push(FeatureUse {
+                feature,
+                line: start + 1,
+            });
+        }
+        index += 1;
+    }
+    found
+}
+
+/// `CARGO_FEATURE_<NAME>` as set for build scripts.
+fn build_script_var(feature: &str) -> String {
+    format!(
+        "{CARGO_FEATURE_ENV_PREFIX}{}",
+        feature.to_ascii_uppercase().replace('-', "_")
+    )
+}
+
+/// 1-based line declaring `feature` in `manifest`, or 1 when not found.
+fn manifest_line(manifest: &Path, feature: &str) -> usize {
+    let content = std::fs::read_to_string(manifest).unwrap_or_default();
+    let mut in_features = false;
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_features = line == "[features]";
+            continue;
+        }
+        let key = line
+            .split('=')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_matches('"');
+        if in_features && key == feature {
+            return index + 1;
+        }
+    }
+    1
+}
+
+impl Validator for FeatureValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_FEATURES
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks Cargo feature definitions against their cfg usage"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_FEATURES,
+    "Checks Cargo feature definitions against their cfg usage",
+    |root| {
+        Ok(Box::new(FeatureValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
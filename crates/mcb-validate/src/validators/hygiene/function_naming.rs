@@ -60,7 +60,7 @@ pub fn validate_test_function_naming(config: &ValidationConfig) -> Result<Vec<Hy
             if path.to_str().is_some_and(|s| s.contains("/fixtures/")) {
                 return Ok(());
             }
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             let lines: Vec<&str> = content.lines().collect();
 
             violations.extend(collect_naming_violations_for_file(
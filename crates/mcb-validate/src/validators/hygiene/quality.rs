@@ -124,7 +124,7 @@ pub fn validate_test_quality(config: &ValidationConfig) -> Result<Vec<HygieneVio
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             let lines: Vec<&str> = content.lines().collect();
 
             violations.extend(process_quality_file(path, &lines, &patterns, &scan_input));
@@ -61,7 +61,7 @@ impl ImplementationQualityValidator {
                     return Ok(());
                 }
 
-                let content = std::fs::read_to_string(&entry.absolute_path)?;
+                let content = crate::scan::read_source(&entry.absolute_path)?;
                 files.push((entry.absolute_path.clone(), content));
                 Ok(())
             },
@@ -3,31 +3,41 @@
 //!
 //! Layer Event Flow Validation
 //!
-//! Validates that dependencies flow in correct Clean Architecture direction:
-//! domain -> application -> providers -> infrastructure -> server
+//! Validates that dependencies flow in the direction declared by the
+//! `[[rules.layer_flow.layers]]` architecture DSL. Each layer names its
+//! crates and path globs plus the layers it may depend on; crate manifests
+//! and `use` imports crossing into any other layer are reported. Circular
+//! dependencies between the configured crates are reported as well.
 
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
-use crate::config::LayerFlowRulesConfig;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+
+use crate::config::{LayerConfig, LayerFlowRulesConfig};
 use crate::define_violations;
-use crate::{Result, ValidationConfig};
+use crate::filters::LanguageId;
+use crate::scan::{for_each_scan_file, read_source, rust_module_path};
+use crate::validators::cycles::{crate_graph, expand_use_tree, resolve_module, use_declarations};
+use crate::validators::dependency_policy::dependency_line;
+use crate::{Result, ValidationConfig, ValidationError};
 use mcb_domain::ports::validation::{Violation, ViolationCategory};
-use mcb_utils::constants::validate::CARGO_TOML_FILENAME;
+use mcb_utils::constants::validate::{CARGO_TOML_FILENAME, USE_DECLARATION_REGEX};
+use mcb_utils::utils::regex::compile_regex;
 
 define_violations! {
     ViolationCategory::Architecture,
     pub enum LayerFlowViolation {
-        /// Dependency detected that violates the allowed layer flow.
+        /// Dependency detected that violates the declared layer flow.
         #[violation(
             id = "LAYER001",
             severity = Error,
-            message = "CA: Forbidden import in {source_crate}: {import_path} (imports {target_crate}) at {file}:{line}",
-            suggestion = "Remove {target_crate} from {source_crate} - violates CA"
+            message = "CA: Layer {source_layer} must not depend on {target_layer}: {import_path} at {file}:{line}",
+            suggestion = "Depend on an abstraction in an allowed layer, or add {target_layer} to may_depend_on of layer {source_layer}"
         )]
         ForbiddenDependency {
-            source_crate: String,
-            target_crate: String,
+            source_layer: String,
+            target_layer: String,
             import_path: String,
             file: PathBuf,
             line: usize,
@@ -61,10 +71,125 @@ define_violations! {
     }
 }
 
+/// Layers compiled from `[[rules.layer_flow.layers]]`.
+pub struct LayerMap {
+    layers: Vec<CompiledLayer>,
+}
+
+struct CompiledLayer {
+    name: String,
+    crates: GlobSet,
+    paths: GlobSet,
+    may_depend_on: HashSet<String>,
+}
+
+impl LayerMap {
+    /// Compile layer definitions into matchers.
+    ///
+    /// # Errors
+    ///
+    /// Returns a configuration error for a duplicate layer name, an invalid
+    /// glob, or a `may_depend_on` entry that names no layer.
+    pub fn compile(layers: &[LayerConfig]) -> Result<Self> {
+        let mut names = HashSet::new();
+        if let Some(duplicate) = layers
+            .iter()
+            .find(|layer| !names.insert(layer.name.as_str()))
+        {
+            return Err(ValidationError::Config(format!(
+                "layer `{}` is defined twice",
+                duplicate.name
+            )));
+        }
+
+        let layers = layers
+            .iter()
+            .map(|layer| {
+                if let Some(unknown) = layer
+                    .may_depend_on
+                    .iter()
+                    .find(|name| !names.contains(name.as_str()))
+                {
+                    return Err(ValidationError::Config(format!(
+                        "layer `{}` may depend on unknown layer `{unknown}`",
+                        layer.name
+                    )));
+                }
+                Ok(CompiledLayer {
+                    name: layer.name.clone(),
+                    crates: glob_set(&layer.crates)?,
+                    paths: glob_set(&layer.paths)?,
+                    may_depend_on: layer.may_depend_on.iter().cloned().collect(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self { layers })
+    }
+
+    /// Whether no layers are declared.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Layer owning package `crate_name`.
+    #[must_use]
+    pub fn layer_of_crate(&self, crate_name: &str) -> Option<&str> {
+        self.layers
+            .iter()
+            .find(|layer| layer.crates.is_match(crate_name))
+            .map(|layer| layer.name.as_str())
+    }
+
+    /// Layer owning the workspace-relative `path` of a file in package
+    /// `crate_name`: path globs win over crate globs.
+    #[must_use]
+    pub fn layer_of_file(&self, path: &Path, crate_name: &str) -> Option<&str> {
+        self.layers
+            .iter()
+            .find(|layer| layer.paths.is_match(path))
+            .map(|layer| layer.name.as_str())
+            .or_else(|| self.layer_of_crate(crate_name))
+    }
+
+    /// Whether code in layer `source` may depend on layer `target`.
+    #[must_use]
+    pub fn allows(&self, source: &str, target: &str) -> bool {
+        source == target
+            || self
+                .layers
+                .iter()
+                .any(|layer| layer.name == source && layer.may_depend_on.contains(target))
+    }
+}
+
+fn glob_set(patterns: &[String]) -> Result<GlobSet> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        let glob = Glob::new(pattern).map_err(|e| {
+            ValidationError::Config(format!("invalid layer pattern `{pattern}`: {e}"))
+        })?;
+        builder.add(glob);
+    }
+    builder
+        .build()
+        .map_err(|e| ValidationError::Config(format!("invalid layer patterns: {e}")))
+}
+
+/// A Rust source file with the package and module it belongs to.
+struct RustSource {
+    package: String,
+    module: Option<String>,
+    absolute: PathBuf,
+    relative: PathBuf,
+}
+
 /// Layer Flow Validator
 pub struct LayerFlowValidator {
     /// List of crates to check for circular dependencies
     circular_dependency_check_crates: Vec<String>,
+    /// Declared architecture layers
+    layers: Vec<LayerConfig>,
 }
 
 crate::impl_config_only_validator_new!(LayerFlowValidator, layer_flow);
@@ -75,7 +200,155 @@ impl LayerFlowValidator {
     pub fn with_config(config: &LayerFlowRulesConfig) -> Self {
         Self {
             circular_dependency_check_crates: config.circular_dependency_check_crates.clone(),
+            layers: config.layers.clone(),
+        }
+    }
+
+    /// Layer flow and circular dependency violations.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layer definitions are invalid or a manifest or
+    /// source file cannot be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<LayerFlowViolation>> {
+        let mut violations = self.check_circular_dependencies(config)?;
+        let layers = LayerMap::compile(&self.layers)?;
+        if !layers.is_empty() {
+            violations.extend(Self::check_layer_manifests(&layers, config)?);
+            violations.extend(Self::check_layer_imports(&layers, config)?);
+        }
+        Ok(violations)
+    }
+
+    /// Workspace dependencies in `Cargo.toml` that cross into a layer the
+    /// depending crate's layer may not use.
+    fn check_layer_manifests(
+        layers: &LayerMap,
+        config: &ValidationConfig,
+    ) -> Result<Vec<LayerFlowViolation>> {
+        let (edges, manifests) = crate_graph(config)?;
+        let mut violations = Vec::new();
+        for (source, targets) in &edges {
+            let (Some(source_layer), Some(manifest)) =
+                (layers.layer_of_crate(source), manifests.get(source))
+            else {
+                continue;
+            };
+            let content = std::fs::read_to_string(manifest)?;
+            for target in targets {
+                let Some(target_layer) = layers.layer_of_crate(target) else {
+                    continue;
+                };
+                if layers.allows(source_layer, target_layer) {
+                    continue;
+                }
+                violations.push(LayerFlowViolation::ForbiddenDependency {
+                    source_layer: source_layer.to_owned(),
+                    target_layer: target_layer.to_owned(),
+                    import_path: target.clone(),
+                    file: manifest.clone(),
+                    line: dependency_line(&content, target),
+                });
+            }
+        }
+        Ok(violations)
+    }
+
+    /// `use` imports reaching into a layer the importing file's layer may not
+    /// use, whether through another crate or through `crate::` paths into a
+    /// module owned by a different layer. One violation per declaration and
+    /// target layer.
+    fn check_layer_imports(
+        layers: &LayerMap,
+        config: &ValidationConfig,
+    ) -> Result<Vec<LayerFlowViolation>> {
+        let (_, manifests) = crate_graph(config)?;
+        let packages: BTreeMap<PathBuf, &str> = manifests
+            .iter()
+            .filter_map(|(name, manifest)| {
+                let dir = manifest.parent()?;
+                let dir = std::fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+                Some((dir, name.as_str()))
+            })
+            .collect();
+        let idents: HashMap<String, &str> = manifests
+            .keys()
+            .map(|name| (name.replace('-', "_"), name.as_str()))
+            .collect();
+
+        let mut sources = Vec::new();
+        for_each_scan_file(config, Some(LanguageId::Rust), true, |entry, src_dir| {
+            let crate_dir = src_dir.parent().unwrap_or(src_dir);
+            let package = packages.get(crate_dir).map_or_else(
+                || {
+                    crate_dir
+                        .file_name()
+                        .map_or_else(String::new, |n| n.to_string_lossy().into_owned())
+                },
+                |name| (*name).to_owned(),
+            );
+            sources.push(RustSource {
+                package,
+                module: rust_module_path(&entry.absolute_path, src_dir),
+                absolute: entry.absolute_path.clone(),
+                relative: entry.relative_path.clone(),
+            });
+            Ok(())
+        })?;
+
+        let mut modules: BTreeMap<&str, BTreeMap<&str, &Path>> = BTreeMap::new();
+        for source in &sources {
+            if let Some(module) = &source.module {
+                modules
+                    .entry(source.package.as_str())
+                    .or_default()
+                    .insert(module.as_str(), source.relative.as_path());
+            }
+        }
+
+        let use_declaration = compile_regex(USE_DECLARATION_REGEX)?;
+        let mut violations = Vec::new();
+        for source in &sources {
+            let Some(source_layer) = layers.layer_of_file(&source.relative, &source.package) else {
+                continue;
+            };
+            let crate_modules = modules.get(source.package.as_str());
+            let module_names: BTreeSet<&str> = crate_modules
+                .map(|m| m.keys().copied().collect())
+                .unwrap_or_default();
+            let content = read_source(&source.absolute)?;
+            let mut reported = HashSet::new();
+            for (line, tree) in use_declarations(&use_declaration, &content) {
+                for path in expand_use_tree(&tree) {
+                    let path = path.trim_start_matches("::");
+                    let target_layer = match path.split("::").next().unwrap_or_default() {
+                        "crate" | "self" | "super" => source
+                            .module
+                            .as_deref()
+                            .and_then(|module| resolve_module(module, path, &module_names))
+                            .and_then(|target| crate_modules?.get(target.as_str()).copied())
+                            .and_then(|target| layers.layer_of_file(target, &source.package)),
+                        ident => layers.layer_of_crate(idents.get(ident).copied().unwrap_or(ident)),
+                    };
+                    let Some(target_layer) = target_layer else {
+                        continue;
+                    };
+                    if layers.allows(source_layer, target_layer)
+                        || !reported.insert((line, target_layer))
+                    {
+                        continue;
+                    }
+                    violations.push(LayerFlowViolation::ForbiddenDependency {
+                        source_layer: source_layer.to_owned(),
+                        target_layer: target_layer.to_owned(),
+                        import_path: path.to_owned(),
+                        file: source.absolute.clone(),
+                        line,
+                    });
+                }
+            }
         }
+        Ok(violations)
     }
 
     /// Build a map from each checked crate to the subset of its workspace
@@ -172,7 +445,7 @@ impl mcb_domain::ports::validation::Validator for LayerFlowValidator {
         &self,
         config: &ValidationConfig,
     ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
-        let violations = self.check_circular_dependencies(config)?;
+        let violations = self.validate_all(config)?;
         Ok(violations
             .into_iter()
             .map(|v| Box::new(v) as Box<dyn Violation>)
@@ -4,15 +4,22 @@
 //!
 //! Validation macros (`impl_validator!`, `define_violations!`) live in `crate::macros`.
 
+pub mod adr;
+pub mod api_surface;
 pub mod async_patterns;
 pub mod clean_architecture;
+pub mod complexity_delta;
 pub mod config_quality;
+pub mod cycles;
+pub mod dead_api;
 pub(crate) mod declarative_support;
 pub mod declarative_validator;
 /// Dependency validation module
 pub mod dependency;
+pub mod dependency_policy;
 pub mod documentation;
 pub mod error_boundary;
+pub mod features;
 mod helpers;
 /// Hygiene validation module (e.g., TODOs, formatting)
 pub mod hygiene;
@@ -29,6 +36,7 @@ pub mod pattern_validator;
 pub mod performance;
 pub mod pmat;
 pub(crate) mod pmat_native;
+pub mod polyglot;
 pub mod port_adapter;
 /// Code quality validation module (unwrap, panic, metrics)
 pub mod quality;
@@ -38,32 +46,55 @@ mod runner;
 pub mod solid;
 /// Single Source of Truth (SSOT) invariants validator
 pub mod ssot;
+pub mod test_coverage;
 pub mod test_quality;
+pub mod unsafe_code;
 pub mod visibility;
+pub mod wasm_plugin;
 
 pub(crate) use helpers::for_each_non_test_non_comment_line;
 
+pub use self::adr::{
+    AdrComplianceRules, AdrDocument, AdrSection, AdrValidator, AdrViolation, ComplianceCheck,
+    ComplianceRule,
+};
+pub use self::api_surface::{
+    ApiDeclaration, ApiEntry, ApiItem, ApiScanner, ApiSnapshot, ApiSurfaceValidator,
+    ApiSurfaceViolation,
+};
 pub use self::async_patterns::{AsyncPatternValidator, AsyncViolation};
 pub use self::clean_architecture::{CleanArchitectureValidator, CleanArchitectureViolation};
+pub use self::complexity_delta::{
+    ComplexityBaseline, ComplexityDeltaValidator, ComplexityDeltaViolation, ComplexityEntry,
+    FunctionComplexity,
+};
 pub use self::config_quality::{ConfigQualityValidator, ConfigQualityViolation};
+pub use self::cycles::{CycleValidator, CycleViolation, ModuleGraph};
+pub use self::dead_api::{DeadApiValidator, DeadApiViolation};
 pub use self::declarative_validator::DeclarativeValidator;
 pub use self::dependency::{DependencyValidator, DependencyViolation};
+pub use self::dependency_policy::{DependencyPolicyValidator, DependencyPolicyViolation};
 pub use self::documentation::{DocumentationValidator, DocumentationViolation};
 pub use self::error_boundary::{ErrorBoundaryValidator, ErrorBoundaryViolation};
+pub use self::features::{FeatureSet, FeatureValidator, FeatureViolation};
 pub use self::hygiene::{HygieneValidator, HygieneViolation};
 pub use self::implementation::{ImplementationQualityValidator, ImplementationViolation};
 pub use self::kiss::{KissValidator, KissViolation};
-pub use self::layer_flow::{LayerFlowValidator, LayerFlowViolation};
+pub use self::layer_flow::{LayerFlowValidator, LayerFlowViolation, LayerMap};
 pub use self::naming::{NamingValidator, NamingViolation};
 pub use self::organization::{OrganizationValidator, OrganizationViolation};
 pub use self::pattern_validator::{PatternValidator, PatternViolation};
 pub use self::performance::{PerformanceValidator, PerformanceViolation};
 pub use self::pmat::{PmatValidator, PmatViolation};
+pub use self::polyglot::{LanguageProfile, NameCase, PolyglotValidator, PolyglotViolation};
 pub use self::port_adapter::{PortAdapterValidator, PortAdapterViolation};
 pub use self::quality::{QualityValidator, QualityViolation};
 pub use self::refactoring::{RefactoringValidator, RefactoringViolation};
 pub use self::runner::{standard_validator_names, validate_all, validate_named};
 pub use self::solid::{SolidValidator, SolidViolation};
 pub use self::ssot::{SsotValidator, SsotViolation};
+pub use self::test_coverage::{TestCoverageValidator, TestCoverageViolation};
 pub use self::test_quality::{TestQualityValidator, TestQualityViolation};
+pub use self::unsafe_code::{UnsafeCodeValidator, UnsafeCodeViolation, UnsafePatterns};
 pub use self::visibility::{VisibilityValidator, VisibilityViolation};
+pub use self::wasm_plugin::{PluginViolation, WasmPluginValidator};
@@ -62,7 +62,7 @@ pub fn validate_domain_traits_only(
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(&entry.absolute_path)?;
+        let content = crate::scan::read_source(&entry.absolute_path)?;
         scan_domain_impls(&entry.absolute_path, &content, &patterns, &mut violations);
         Ok(())
     })?;
@@ -44,7 +44,7 @@ pub fn validate_duplicate_strings(config: &ValidationConfig) -> Result<Vec<Organ
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             collect_string_occurrences(path, &content, &string_pattern, &mut string_occurrences);
             Ok(())
         },
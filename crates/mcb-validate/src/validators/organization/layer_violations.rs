@@ -41,7 +41,7 @@ pub fn validate_layer_violations(config: &ValidationConfig) -> Result<Vec<Organi
             return Ok(());
         }
 
-        let content = std::fs::read_to_string(path)?;
+        let content = crate::scan::read_source(path)?;
         scan_layer_file(
             path,
             path_str,
@@ -43,7 +43,7 @@ pub fn validate_magic_numbers(config: &ValidationConfig) -> Result<Vec<Organizat
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             collect_magic_numbers(path, &content, &magic_pattern, &mut violations);
             Ok(())
         },
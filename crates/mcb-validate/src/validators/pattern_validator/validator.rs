@@ -55,7 +55,7 @@ impl PatternValidator {
             Some(LanguageId::Rust),
             |entry, _src_dir, _crate_name| {
                 let path = &entry.absolute_path;
-                let content = std::fs::read_to_string(path)?;
+                let content = crate::scan::read_source(path)?;
 
                 // Async Trait Check
                 violations.extend(check_async_traits(path, &content)?);
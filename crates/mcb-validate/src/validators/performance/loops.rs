@@ -34,7 +34,7 @@ where
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             f(path.clone(), content)
         },
     )
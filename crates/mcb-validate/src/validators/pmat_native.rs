@@ -1,7 +1,6 @@
 //!
 //! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
 //!
-use std::fs;
 use std::path::{Path, PathBuf};
 
 use rust_code_analysis::SpaceKind;
@@ -11,7 +10,7 @@ use mcb_domain::utils::analysis::{self, FunctionRecord};
 
 use crate::ast::rca_helpers;
 use crate::filters::LanguageId;
-use crate::scan::for_each_scan_file;
+use crate::scan::{for_each_scan_file, read_source};
 use crate::{Result, ValidationConfig};
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -22,7 +21,7 @@ impl NativePmatAnalyzer {
         let config = ValidationConfig::new(workspace_root.to_path_buf());
         let mut files = Vec::new();
         for_each_scan_file(&config, Some(LanguageId::Rust), false, |entry, _src_dir| {
-            let content = fs::read_to_string(&entry.absolute_path)?;
+            let content = read_source(&entry.absolute_path)?;
             files.push((entry.absolute_path.clone(), content));
             Ok(())
         })?;
@@ -0,0 +1,424 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Polyglot Validation
+//!
+//! Runs the language-neutral rules — naming, file size, complexity and
+//! pending-task comments — on Python and TypeScript sources in the scan
+//! directories. A [`LanguageProfile`] per language supplies the naming
+//! conventions and comment syntax; RCA supplies the functions, classes,
+//! SLOC and cyclomatic complexity, exactly as it does for Rust.
+
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+use rust_code_analysis::{FuncSpace, SpaceKind};
+
+use crate::ast::rca_helpers;
+use crate::config::PolyglotRulesConfig;
+use crate::define_violations;
+use crate::filters::LanguageId;
+use crate::scan::{for_each_scan_file, read_source};
+use crate::thresholds::thresholds;
+use crate::{Result, ValidationConfig};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    JS_COMMENT_REGEX, PENDING_LABEL_FIXME, PENDING_LABEL_HACK, PENDING_LABEL_TODO,
+    PENDING_LABEL_XXX, PYTHON_COMMENT_REGEX, TEST_PATH_PATTERNS, VALIDATOR_POLYGLOT,
+};
+use mcb_utils::utils::naming::{is_camel_case, is_lower_camel_case, is_snake_case};
+use mcb_utils::utils::regex::compile_regex;
+
+define_violations! {
+    ViolationCategory::Quality,
+    pub enum PolyglotViolation {
+        /// Function, class or module name breaking the language's convention.
+        #[violation(
+            id = "POLY001",
+            severity = Warning,
+            message = "{language} {kind} {name} should be {expected} at {file}:{line}",
+            suggestion = "Rename {name} to {expected}"
+        )]
+        NamingConvention {
+            language: String,
+            kind: String,
+            name: String,
+            expected: String,
+            file: PathBuf,
+            line: usize,
+        },
+        /// Source file longer than the configured maximum.
+        #[violation(
+            id = "POLY002",
+            severity = Warning,
+            message = "File has {lines} source lines (max: {max_allowed}): {file}",
+            suggestion = "Split the file into smaller modules"
+        )]
+        FileTooLarge {
+            file: PathBuf,
+            lines: usize,
+            max_allowed: usize,
+        },
+        /// Function whose cyclomatic complexity exceeds the maximum.
+        #[violation(
+            id = "POLY003",
+            severity = Warning,
+            message = "Function {function} has cyclomatic complexity {complexity} (max: {max_allowed}) at {file}:{line}",
+            suggestion = "Extract branches of {function} into smaller functions"
+        )]
+        ComplexFunction {
+            function: String,
+            complexity: u32,
+            max_allowed: u32,
+            file: PathBuf,
+            line: usize,
+        },
+        /// Pending-task comment left in the source.
+        #[violation(
+            id = "POLY004",
+            severity = Info,
+            message = "Pending comment at {file}:{line}: {content}",
+            suggestion = "Resolve the comment or track it in an issue"
+        )]
+        PendingComment {
+            content: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// Naming convention of an identifier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameCase {
+    /// `parse_input`
+    Snake,
+    /// `parseInput`
+    LowerCamel,
+    /// `ParseInput`
+    Pascal,
+}
+
+impl NameCase {
+    /// Whether `name` follows the convention. Leading and trailing
+    /// underscores (private members, dunder methods) are ignored.
+    #[must_use]
+    pub fn matches(self, name: &str) -> bool {
+        let name = name.trim_matches('_');
+        name.is_empty()
+            || match self {
+                Self::Snake => is_snake_case(name),
+                Self::LowerCamel => is_lower_camel_case(name),
+                Self::Pascal => is_camel_case(name),
+            }
+    }
+
+    /// Name of the convention in messages.
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Snake => "snake_case",
+            Self::LowerCamel => "camelCase",
+            Self::Pascal => "PascalCase",
+        }
+    }
+}
+
+/// Language-specific parts of the polyglot rules.
+#[derive(Debug)]
+pub struct LanguageProfile {
+    /// Language the profile applies to
+    pub language: LanguageId,
+    /// Convention for function and method names
+    pub function_case: NameCase,
+    /// Convention for class and interface names
+    pub type_case: NameCase,
+    /// Convention for file stems (module names), if the language has one
+    pub file_case: Option<NameCase>,
+    /// Regex matching a comment opener
+    pub comment_regex: &'static str,
+    /// Names fixed by frameworks, exempt from the conventions
+    pub exempt_names: &'static [&'static str],
+}
+
+/// `unittest.TestCase` hooks, named in camelCase by the standard library.
+const PYTHON_UNITTEST_HOOKS: &[&str] = &[
+    "setUp",
+    "tearDown",
+    "setUpClass",
+    "tearDownClass",
+    "setUpModule",
+    "tearDownModule",
+    "asyncSetUp",
+    "asyncTearDown",
+];
+
+const TYPESCRIPT_PROFILE: LanguageProfile = LanguageProfile {
+    language: LanguageId::TypeScript,
+    function_case: NameCase::LowerCamel,
+    type_case: NameCase::Pascal,
+    file_case: None,
+    comment_regex: JS_COMMENT_REGEX,
+    exempt_names: &[],
+};
+
+/// Profiles of the languages the polyglot rules support.
+const PROFILES: &[LanguageProfile] = &[
+    LanguageProfile {
+        language: LanguageId::Python,
+        function_case: NameCase::Snake,
+        type_case: NameCase::Pascal,
+        file_case: Some(NameCase::Snake),
+        comment_regex: PYTHON_COMMENT_REGEX,
+        exempt_names: PYTHON_UNITTEST_HOOKS,
+    },
+    TYPESCRIPT_PROFILE,
+    LanguageProfile {
+        language: LanguageId::Tsx,
+        ..TYPESCRIPT_PROFILE
+    },
+    LanguageProfile {
+        language: LanguageId::JavaScript,
+        ..TYPESCRIPT_PROFILE
+    },
+];
+
+impl LanguageProfile {
+    /// Profile of `language`, if the polyglot rules support it.
+    #[must_use]
+    pub fn for_language(language: LanguageId) -> Option<&'static Self> {
+        PROFILES.iter().find(|profile| profile.language == language)
+    }
+
+    /// Naming violations of the functions and classes in `root`, and of the
+    /// file stem of `file`.
+    #[must_use]
+    pub fn naming_violations(&self, file: &Path, root: &FuncSpace) -> Vec<PolyglotViolation> {
+        let mut violations = Vec::new();
+        let mut check = |kind: &str, name: &str, case: NameCase, line: usize| {
+            if !case.matches(name) && !self.exempt_names.contains(&name) {
+                violations.push(PolyglotViolation::NamingConvention {
+                    language: self.language.name().to_owned(),
+                    kind: kind.to_owned(),
+                    name: name.to_owned(),
+                    expected: case.label().to_owned(),
+                    file: file.to_path_buf(),
+                    line,
+                });
+            }
+        };
+
+        if let Some(case) = self.file_case
+            && let Some(stem) = file.file_stem().and_then(|stem| stem.to_str())
+        {
+            check("module", stem, case, 1);
+        }
+        visit(root, &mut |space| {
+            let Some(name) = space.name.as_deref().filter(|name| is_identifier(name)) else {
+                return;
+            };
+            match space.kind {
+                SpaceKind::Function => {
+                    check("function", name, self.function_case, space.start_line)
+                }
+                SpaceKind::Class | SpaceKind::Interface => {
+                    check("class", name, self.type_case, space.start_line);
+                }
+                _ => {}
+            }
+        });
+        violations
+    }
+
+    /// Compile the pending-task comment regex (captures: 1 = label, 2 = text).
+    ///
+    /// # Errors
+    /// Returns an error if the regex fails to compile.
+    pub fn pending_comment_regex(&self) -> Result<Regex> {
+        compile_regex(&format!(
+            r"(?i)(?:{})\s*({PENDING_LABEL_TODO}|{PENDING_LABEL_FIXME}|{PENDING_LABEL_XXX}|{PENDING_LABEL_HACK})\b:?\s*(.*)",
+            self.comment_regex
+        ))
+    }
+}
+
+/// Whether `name` is a plain identifier (RCA names anonymous functions
+/// `<anonymous>` and computed members by their expression).
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Visit `space` and every space nested in it.
+fn visit(space: &FuncSpace, f: &mut impl FnMut(&FuncSpace)) {
+    f(space);
+    for child in &space.spaces {
+        visit(child, f);
+    }
+}
+
+/// Python and TypeScript source validator.
+pub struct PolyglotValidator {
+    languages: Vec<LanguageId>,
+    excluded_paths: Vec<String>,
+    max_file_lines: usize,
+    max_complexity: u32,
+    enabled: bool,
+}
+
+crate::impl_config_only_validator_new!(PolyglotValidator, polyglot);
+
+impl PolyglotValidator {
+    /// Creates a polyglot validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &PolyglotRulesConfig) -> Self {
+        Self {
+            languages: config
+                .languages
+                .iter()
+                .filter_map(|name| LanguageId::from_name(name))
+                .flat_map(|language| match language {
+                    LanguageId::TypeScript => vec![LanguageId::TypeScript, LanguageId::Tsx],
+                    other => vec![other],
+                })
+                .collect(),
+            excluded_paths: config.excluded_paths.clone(),
+            max_file_lines: thresholds().max_file_lines,
+            max_complexity: thresholds().max_cyclomatic_complexity,
+            enabled: config.enabled,
+        }
+    }
+
+    /// Overrides the maximum source lines per file.
+    #[must_use]
+    pub fn with_max_file_lines(mut self, max: usize) -> Self {
+        self.max_file_lines = max;
+        self
+    }
+
+    /// Overrides the maximum cyclomatic complexity per function.
+    #[must_use]
+    pub fn with_max_complexity(mut self, max: u32) -> Self {
+        self.max_complexity = max;
+        self
+    }
+
+    /// Violations in the Python and TypeScript files of the scan directories.
+    ///
+    /// # Errors
+    /// Returns an error if a regex fails to compile or a file cannot be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<PolyglotViolation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+        let mut violations = Vec::new();
+        for_each_scan_file(config, None, false, |entry, _src_dir| {
+            let Some(profile) = entry
+                .detected_language
+                .filter(|language| self.languages.contains(language))
+                .and_then(LanguageProfile::for_language)
+            else {
+                return Ok(());
+            };
+            let path = &entry.absolute_path;
+            let path_str = path.to_string_lossy();
+            if self
+                .excluded_paths
+                .iter()
+                .any(|excluded| path_str.contains(excluded.as_str()))
+            {
+                return Ok(());
+            }
+            let content = read_source(path)?;
+            violations.extend(self.check_file(profile, path, &content)?);
+            Ok(())
+        })?;
+        Ok(violations)
+    }
+
+    /// Violations in the source `content` of `file`.
+    ///
+    /// # Errors
+    /// Returns an error if the comment regex fails to compile.
+    pub fn check_file(
+        &self,
+        profile: &LanguageProfile,
+        file: &Path,
+        content: &str,
+    ) -> Result<Vec<PolyglotViolation>> {
+        let pending = profile.pending_comment_regex()?;
+        let mut violations: Vec<PolyglotViolation> = content
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let captures = pending.captures(line)?;
+                Some(PolyglotViolation::PendingComment {
+                    content: format!("{}: {}", captures[1].to_uppercase(), captures[2].trim()),
+                    file: file.to_path_buf(),
+                    line: index + 1,
+                })
+            })
+            .collect();
+
+        let Some(root) = rca_helpers::parse_file_spaces(file, content) else {
+            return Ok(violations);
+        };
+        violations.extend(profile.naming_violations(file, &root));
+
+        let sloc = root.metrics.loc.sloc().round() as usize;
+        let path_str = file.to_string_lossy();
+        if sloc > self.max_file_lines && !TEST_PATH_PATTERNS.iter().any(|p| path_str.contains(p)) {
+            violations.push(PolyglotViolation::FileTooLarge {
+                file: file.to_path_buf(),
+                lines: sloc,
+                max_allowed: self.max_file_lines,
+            });
+        }
+
+        visit(&root, &mut |space| {
+            let complexity = space.metrics.cyclomatic.cyclomatic().round() as u32;
+            if space.kind == SpaceKind::Function && complexity > self.max_complexity {
+                violations.push(PolyglotViolation::ComplexFunction {
+                    function: space.name.clone().unwrap_or_default(),
+                    complexity,
+                    max_allowed: self.max_complexity,
+                    file: file.to_path_buf(),
+                    line: space.start_line,
+                });
+            }
+        });
+        Ok(violations)
+    }
+}
+
+impl Validator for PolyglotValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_POLYGLOT
+    }
+
+    fn description(&self) -> &'static str {
+        "Validates naming, size, complexity and pending comments of Python and TypeScript"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_POLYGLOT,
+    "Validates naming, size, complexity and pending comments of Python and TypeScript",
+    |root| {
+        Ok(Box::new(PolyglotValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
@@ -124,7 +124,7 @@ impl PortAdapterValidator {
 
         for_each_file_under_root(config, &ports_dir, Some(LanguageId::Rust), |entry| {
             let path = &entry.absolute_path;
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             violations.extend(collect_port_size_violations(
                 path,
                 &content,
@@ -158,7 +158,7 @@ impl PortAdapterValidator {
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             violations.extend(collect_adapter_usage_violations(
                 path,
                 &content,
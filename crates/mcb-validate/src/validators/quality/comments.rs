@@ -31,7 +31,7 @@ pub fn validate(validator: &QualityValidator) -> Result<Vec<QualityViolation>> {
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(&entry.absolute_path)?;
+            let content = crate::scan::read_source(&entry.absolute_path)?;
 
             for (line_num, line) in content.lines().enumerate() {
                 if let Some(cap) = todo_pattern.captures(line) {
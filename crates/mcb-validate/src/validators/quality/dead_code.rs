@@ -43,7 +43,7 @@ pub fn validate(validator: &QualityValidator) -> Result<Vec<QualityViolation>> {
             if is_skippable_file(path) {
                 return Ok(());
             }
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             collect_dead_code_allows(path, &content, &patterns, &mut violations);
             Ok(())
         },
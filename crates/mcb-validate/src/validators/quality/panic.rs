@@ -27,7 +27,7 @@ pub fn validate(validator: &QualityValidator) -> Result<Vec<QualityViolation>> {
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(&entry.absolute_path)?;
+            let content = crate::scan::read_source(&entry.absolute_path)?;
             let mut in_test_module = false;
 
             for (line_num, line) in content.lines().enumerate() {
@@ -114,7 +114,7 @@ pub fn validate(validator: &QualityValidator) -> Result<Vec<QualityViolation>> {
             if should_skip_source_file(&entry.absolute_path) {
                 return Ok(());
             }
-            let content = std::fs::read_to_string(&entry.absolute_path)?;
+            let content = crate::scan::read_source(&entry.absolute_path)?;
             let lines: Vec<&str> = content.lines().collect();
             let detections = detector.detect_in_file(&entry.absolute_path)?;
 
@@ -140,7 +140,7 @@ pub fn validate_duplicate_definitions(
                 return Ok(());
             }
 
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             record_definitions(
                 validator,
                 path,
@@ -28,7 +28,7 @@ pub fn validate_mod_declarations(
             }
 
             let path = &entry.absolute_path;
-            let content = std::fs::read_to_string(path)?;
+            let content = crate::scan::read_source(path)?;
             collect_deleted_module_refs(path, &content, &mod_pattern, &mut violations);
             Ok(())
         },
@@ -149,7 +149,7 @@ fn missing_test_file_violation(
         return Ok(None);
     }
 
-    let content = std::fs::read_to_string(path)?;
+    let content = crate::scan::read_source(path)?;
     if content.contains(CFG_TEST_MARKER) || has_test_coverage(relative, test_files, test_dirs) {
         return Ok(None);
     }
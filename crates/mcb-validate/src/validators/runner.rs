@@ -5,12 +5,42 @@ use std::sync::Arc;
 
 use crate::run_context::ValidationRunContext;
 use crate::{Result, ValidationConfig, ValidationError, Validator, Violation};
+use mcb_utils::constants::validate::CROSS_FILE_VALIDATORS;
+
+/// Context a validator runs under: cross-file validators keep the whole
+/// workspace in scope during incremental runs.
+fn context_for<'c>(
+    validator: &dyn Validator,
+    scoped: &'c Arc<ValidationRunContext>,
+    unscoped: Option<&'c Arc<ValidationRunContext>>,
+) -> &'c Arc<ValidationRunContext> {
+    match unscoped {
+        Some(full) if CROSS_FILE_VALIDATORS.contains(&validator.name()) => full,
+        _ => scoped,
+    }
+}
+
+/// Sort violations by file, line, rule and message so reports do not depend
+/// on the order parallel validators finish in.
+fn sort_violations(violations: &mut [Box<dyn Violation>]) {
+    violations
+        .sort_by_cached_key(|v| (v.file().cloned(), v.line(), v.id().to_owned(), v.message()));
+}
 
 /// Run all enabled validators and return violations.
+///
+/// Source files are read in parallel up front and validators run in
+/// parallel; violations are sorted by file, line, rule and message.
+///
+/// When `config.changed_files` is set, only violations in those files (or
+/// without a file) are returned.
 /// # Errors
 /// Returns an error if the validation context cannot be built.
 pub fn validate_all(config: &ValidationConfig) -> Result<Vec<Box<dyn Violation>>> {
     let context = Arc::new(ValidationRunContext::build(config)?);
+    let unscoped = context
+        .is_incremental()
+        .then(|| Arc::new(context.unscoped()));
     ValidationRunContext::with_active(&context, || {
         let Some(active) = ValidationRunContext::active() else {
             return Err(ValidationError::ContextNotActive);
@@ -18,6 +48,7 @@ pub fn validate_all(config: &ValidationConfig) -> Result<Vec<Box<dyn Violation>>
         let validators =
             mcb_domain::registry::validation::build_all_validators(&config.workspace_root)
                 .map_err(|e| ValidationError::Config(e.to_string()))?;
+        active.preload();
         mcb_domain::info!(
             "validators",
             "Validation run started",
@@ -38,16 +69,19 @@ pub fn validate_all(config: &ValidationConfig) -> Result<Vec<Box<dyn Violation>>
                 langs.is_empty() || langs.iter().any(|l| active.has_files_for_language(*l))
             })
             .flat_map_iter(|validator| {
-                ValidationRunContext::with_active(&context, || {
+                let scope = context_for(validator.as_ref(), &context, unscoped.as_ref());
+                ValidationRunContext::with_active(scope, || {
                     run_single_validator(validator.as_ref(), config, active.trace_id())
                 })
             })
             .collect();
-        Ok(all_violations)
+        let mut violations = context.retain_in_scope(all_violations);
+        sort_violations(&mut violations);
+        Ok(violations)
     })
 }
 
-/// Run only the named validators and return violations.
+/// Run only the named validators, in parallel, and return sorted violations.
 /// # Errors
 /// Returns an error if the validation context cannot be built or unknown validators are requested.
 pub fn validate_named(
@@ -77,21 +111,27 @@ pub fn validate_named(
     }
 
     let context = Arc::new(ValidationRunContext::build(config)?);
+    let unscoped = context
+        .is_incremental()
+        .then(|| Arc::new(context.unscoped()));
     ValidationRunContext::with_active(&context, || {
         let Some(active) = ValidationRunContext::active() else {
             return Err(ValidationError::ContextNotActive);
         };
-        let mut all_violations = Vec::new();
-        for validator in &validators {
-            if names.contains(&validator.name()) {
-                all_violations.extend(run_single_validator(
-                    validator.as_ref(),
-                    config,
-                    active.trace_id(),
-                ));
-            }
-        }
-        Ok(all_violations)
+        active.preload();
+        let all_violations: Vec<Box<dyn Violation>> = validators
+            .par_iter()
+            .filter(|validator| names.contains(&validator.name()))
+            .flat_map_iter(|validator| {
+                let scope = context_for(validator.as_ref(), &context, unscoped.as_ref());
+                ValidationRunContext::with_active(scope, || {
+                    run_single_validator(validator.as_ref(), config, active.trace_id())
+                })
+            })
+            .collect();
+        let mut violations = context.retain_in_scope(all_violations);
+        sort_violations(&mut violations);
+        Ok(violations)
     })
 }
 
@@ -147,7 +147,7 @@ impl SsotValidator {
             Some(LanguageId::Rust),
             false,
             |entry, _candidate_src_dir| {
-                let content = std::fs::read_to_string(&entry.absolute_path)?;
+                let content = crate::scan::read_source(&entry.absolute_path)?;
                 files.push((entry.absolute_path.clone(), content));
                 Ok(())
             },
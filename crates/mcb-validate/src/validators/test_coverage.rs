@@ -0,0 +1,345 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Test Coverage Heuristics
+//!
+//! Estimates, without running a coverage tool, which public functions are
+//! exercised by tests: a `pub fn` found by the fact extractor counts as
+//! tested when its name is called from test code anywhere in the workspace —
+//! files under `tests/`, `#[cfg(test)]` modules, or doc-test examples.
+//! Crates whose share of tested public functions falls below
+//! `[rules.test_coverage] min_coverage_percent` report it together with
+//! their untested functions.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::PathBuf;
+
+use rayon::prelude::*;
+
+use crate::config::TestCoverageRulesConfig;
+use crate::define_violations;
+use crate::extractor::{Fact, FactType, RustExtractor};
+use crate::filters::LanguageId;
+use crate::run_context::ValidationRunContext;
+use crate::scan::{read_source, rust_module_path};
+use crate::{Result, ValidationConfig, ValidationConfigExt};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    CALL_SITE_REGEX, CARGO_TOML_FILENAME, CFG_TEST_MARKER, DOC_COMMENT_PREFIX, DOC_TEST_FENCE,
+    FACT_ATTR_VISIBILITY, MODULE_DOC_PREFIX, TEST_DIR_FRAGMENT, VALIDATOR_TEST_COVERAGE,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+/// Functions that are public by convention rather than for callers.
+const ENTRY_POINT_FUNCTIONS: &[&str] = &["main"];
+
+/// Code fence attributes of examples rustdoc compiles as doc-tests.
+const DOC_TEST_ATTRIBUTES: &[&str] = &["rust", "ignore", "no_run", "should_panic", "compile_fail"];
+
+define_violations! {
+    ViolationCategory::Testing,
+    pub enum TestCoverageViolation {
+        /// Crate whose public functions are mostly untested.
+        #[violation(
+            id = "TCOV001",
+            severity = Warning,
+            message = "{crate_name}: {tested}/{total} public functions are called from tests ({percent}% < {threshold}%) ({location})",
+            suggestion = "Add tests or doc-test examples for the untested functions listed for {crate_name}"
+        )]
+        CoverageBelowThreshold {
+            crate_name: String,
+            tested: usize,
+            total: usize,
+            percent: usize,
+            threshold: u8,
+            location: PathBuf,
+        },
+        /// Public function that no test calls.
+        #[violation(
+            id = "TCOV002",
+            severity = Info,
+            message = "Public fn {function} in {crate_name} is not called from any test at {file}:{line}",
+            suggestion = "Call {function} from a test or a doc-test example"
+        )]
+        UntestedPublicFunction {
+            crate_name: String,
+            function: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// Public function declared in a crate's library code.
+struct PublicFunction {
+    name: String,
+    file: PathBuf,
+    line: usize,
+}
+
+/// Per-file scan result.
+struct ScannedFile {
+    /// Index of the crate whose `src/` holds the file.
+    owner: Option<usize>,
+    /// Names called from the file's test code.
+    tested_calls: HashSet<String>,
+    functions: Vec<PublicFunction>,
+}
+
+/// Public function test coverage validator.
+pub struct TestCoverageValidator {
+    min_coverage_percent: u8,
+    excluded_crates: HashSet<String>,
+    enabled: bool,
+}
+
+crate::impl_config_only_validator_new!(TestCoverageValidator, test_coverage);
+
+impl TestCoverageValidator {
+    /// Creates a test coverage validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &TestCoverageRulesConfig) -> Self {
+        Self {
+            min_coverage_percent: config.min_coverage_percent,
+            excluded_crates: config.excluded_crates.iter().cloned().collect(),
+            enabled: config.enabled,
+        }
+    }
+
+    /// Crates below the coverage threshold and their untested functions.
+    ///
+    /// # Errors
+    /// Returns an error if the workspace cannot be scanned or a file cannot
+    /// be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<TestCoverageViolation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let src_dirs: Vec<PathBuf> = config
+            .get_scan_dirs()?
+            .into_iter()
+            .map(|dir| std::fs::canonicalize(&dir).unwrap_or(dir))
+            .collect();
+        let crate_names: Vec<String> = src_dirs
+            .iter()
+            .map(|dir| {
+                dir.parent()
+                    .and_then(|parent| parent.file_name())
+                    .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+            })
+            .collect();
+
+        let files = self.scan_files(config, &src_dirs, &crate_names)?;
+        let tested: HashSet<&str> = files
+            .iter()
+            .flat_map(|file| file.tested_calls.iter().map(String::as_str))
+            .collect();
+
+        let mut by_crate: BTreeMap<usize, Vec<&PublicFunction>> = BTreeMap::new();
+        for file in &files {
+            if let Some(owner) = file.owner {
+                by_crate.entry(owner).or_default().extend(&file.functions);
+            }
+        }
+
+        let mut violations = Vec::new();
+        for (owner, functions) in by_crate {
+            let untested: Vec<&PublicFunction> = functions
+                .iter()
+                .copied()
+                .filter(|function| !tested.contains(function.name.as_str()))
+                .collect();
+            let total = functions.len();
+            let covered = total - untested.len();
+            if total == 0 || covered * 100 / total >= usize::from(self.min_coverage_percent) {
+                continue;
+            }
+
+            let crate_name = &crate_names[owner];
+            violations.push(TestCoverageViolation::CoverageBelowThreshold {
+                crate_name: crate_name.clone(),
+                tested: covered,
+                total,
+                percent: covered * 100 / total,
+                threshold: self.min_coverage_percent,
+                location: src_dirs[owner]
+                    .parent()
+                    .map_or_else(PathBuf::new, |dir| dir.join(CARGO_TOML_FILENAME)),
+            });
+            violations.extend(untested.into_iter().map(|function| {
+                TestCoverageViolation::UntestedPublicFunction {
+                    crate_name: crate_name.clone(),
+                    function: function.name.clone(),
+                    file: function.file.clone(),
+                    line: function.line,
+                }
+            }));
+        }
+        Ok(violations)
+    }
+
+    /// Test calls of every Rust file in the workspace, and the public
+    /// functions of library files in checked crates.
+    fn scan_files(
+        &self,
+        config: &ValidationConfig,
+        src_dirs: &[PathBuf],
+        crate_names: &[String],
+    ) -> Result<Vec<ScannedFile>> {
+        let context = ValidationRunContext::active_or_build(config)?;
+        let call_site = compile_regex(CALL_SITE_REGEX)?;
+        let paths: Vec<PathBuf> = context
+            .file_inventory()
+            .iter()
+            .filter(|entry| entry.detected_language == Some(LanguageId::Rust))
+            .map(|entry| entry.absolute_path.clone())
+            .collect();
+
+        paths
+            .into_par_iter()
+            .map(|path| {
+                let owner = src_dirs.iter().position(|dir| path.starts_with(dir));
+                let content = ValidationRunContext::with_active(&context, || read_source(&path))?;
+                let in_tests_dir = path
+                    .to_string_lossy()
+                    .replace('\\', "/")
+                    .contains(TEST_DIR_FRAGMENT);
+                let test_code = if in_tests_dir && owner.is_none() {
+                    content.clone()
+                } else {
+                    test_code(&content)
+                };
+                let tested_calls = call_site
+                    .captures_iter(&test_code)
+                    .map(|captures| captures[1].to_owned())
+                    .collect();
+
+                let functions = match owner {
+                    Some(index)
+                        if !self.excluded_crates.contains(&crate_names[index])
+                            && rust_module_path(&path, &src_dirs[index]).is_some() =>
+                    {
+                        let facts = ValidationRunContext::with_active(&context, || {
+                            RustExtractor.extract_facts(&path)
+                        })?;
+                        public_functions(&facts, first_test_line(&content))
+                    }
+                    _ => Vec::new(),
+                };
+                Ok(ScannedFile {
+                    owner,
+                    tested_calls,
+                    functions,
+                })
+            })
+            .collect()
+    }
+}
+
+/// Public, non-entry-point functions declared before `test_start`.
+fn public_functions(facts: &[Fact], test_start: usize) -> Vec<PublicFunction> {
+    facts
+        .iter()
+        .filter(|fact| fact.fact_type == FactType::Function)
+        .filter(|fact| {
+            fact.attributes
+                .get(FACT_ATTR_VISIBILITY)
+                .map(String::as_str)
+                == Some("pub")
+        })
+        .filter(|fact| !ENTRY_POINT_FUNCTIONS.contains(&fact.name.as_str()))
+        .filter(|fact| fact.location.start_line < test_start)
+        .map(|fact| PublicFunction {
+            name: fact.name.clone(),
+            file: fact.location.file_path.clone(),
+            line: fact.location.start_line,
+        })
+        .collect()
+}
+
+/// 1-based line of the first `#[cfg(test)]`, or past the end.
+fn first_test_line(content: &str) -> usize {
+    content
+        .lines()
+        .position(|line| line.contains(CFG_TEST_MARKER))
+        .map_or(usize::MAX, |index| index + 1)
+}
+
+/// Test code of a library file: doc-test examples in `///` and `//!`
+/// comments, and everything from the first `#[cfg(test)]` on.
+#[must_use]
+pub fn test_code(content: &str) -> String {
+    let mut code = String::new();
+    // Inside a fence: `Some(true)` for a doc-test, `Some(false)` for other text.
+    let mut fence: Option<bool> = None;
+    let mut in_test_module = false;
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if in_test_module || trimmed.contains(CFG_TEST_MARKER) {
+            in_test_module = true;
+            code.push_str(line);
+            code.push('\n');
+            continue;
+        }
+        let Some(doc) = trimmed
+            .strip_prefix(DOC_COMMENT_PREFIX)
+            .or_else(|| trimmed.strip_prefix(MODULE_DOC_PREFIX))
+        else {
+            fence = None;
+            continue;
+        };
+        if let Some(language) = doc.trim_start().strip_prefix(DOC_TEST_FENCE) {
+            fence = match fence {
+                Some(_) => None,
+                None => Some(is_doc_test_fence(language)),
+            };
+        } else if fence == Some(true) {
+            code.push_str(doc);
+            code.push('\n');
+        }
+    }
+    code
+}
+
+/// Whether a fence with info string `language` holds a doc-test: an empty
+/// info string or only attributes such as `rust`, `no_run` or `edition2021`.
+fn is_doc_test_fence(language: &str) -> bool {
+    language
+        .split(',')
+        .map(str::trim)
+        .filter(|attribute| !attribute.is_empty())
+        .all(|attribute| {
+            DOC_TEST_ATTRIBUTES.contains(&attribute) || attribute.starts_with("edition")
+        })
+}
+
+impl Validator for TestCoverageValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_TEST_COVERAGE
+    }
+
+    fn description(&self) -> &'static str {
+        "Reports public functions no test or doc-test calls"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_TEST_COVERAGE,
+    "Reports public functions no test or doc-test calls",
+    |root| {
+        Ok(Box::new(TestCoverageValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
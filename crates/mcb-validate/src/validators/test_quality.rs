@@ -183,7 +183,7 @@ impl TestQualityValidator {
                     return Ok(());
                 }
 
-                let content = std::fs::read_to_string(path)?;
+                let content = crate::scan::read_source(path)?;
                 let lines: Vec<&str> = content.lines().collect();
                 self.check_test_file(path, &lines, &patterns, &mut violations);
                 Ok(())
@@ -0,0 +1,237 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! Unsafe Code and FFI Boundary Audit
+//!
+//! Flags production code (outside `#[cfg(test)]`) that needs a reviewer's
+//! attention at the memory-safety boundary:
+//!
+//! - `unsafe` blocks without a `// SAFETY:` comment on the same line or in
+//!   the comment lines directly above;
+//! - `transmute` / `transmute_copy` usage;
+//! - exported `extern` functions whose docs lack an `# Errors` section
+//!   describing how failure is reported to the foreign caller.
+//!
+//! Crates accept individual checks with `[rules.unsafe_code.allow]`.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::config::UnsafeCodeRulesConfig;
+use crate::define_violations;
+use crate::filters::LanguageId;
+use crate::scan::{for_each_scan_file, read_source};
+use crate::{Result, ValidationConfig};
+use mcb_domain::ports::validation::{Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    COMMENT_PREFIX, ERRORS_DOC_SECTION, EXTERN_FN_REGEX, SAFETY_COMMENT_MARKERS,
+    STRING_LITERAL_REGEX, TRANSMUTE_REGEX, UNSAFE_BLOCK_REGEX, VALIDATOR_UNSAFE_CODE,
+};
+use mcb_utils::utils::regex::compile_regex;
+
+define_violations! {
+    ViolationCategory::Quality,
+    pub enum UnsafeCodeViolation {
+        /// `unsafe` block without a safety justification.
+        #[violation(
+            id = "UNSAFE001",
+            severity = Warning,
+            message = "unsafe block without a SAFETY comment at {file}:{line}",
+            suggestion = "Add a `// SAFETY:` comment above the block explaining why its invariants hold"
+        )]
+        MissingSafetyComment {
+            file: PathBuf,
+            line: usize,
+        },
+        /// `transmute` reinterprets memory without type checks.
+        #[violation(
+            id = "UNSAFE002",
+            severity = Warning,
+            message = "transmute used at {file}:{line}: {context}",
+            suggestion = "Prefer from_bits/to_bits, from_ne_bytes, pointer casts or bytemuck over transmute"
+        )]
+        TransmuteUsage {
+            file: PathBuf,
+            line: usize,
+            context: String,
+        },
+        /// Exported FFI function without error documentation.
+        #[violation(
+            id = "UNSAFE003",
+            severity = Warning,
+            message = "extern fn {function} lacks an `# Errors` doc section at {file}:{line}",
+            suggestion = "Document under `# Errors` how {function} reports failure to foreign callers"
+        )]
+        UndocumentedExternFn {
+            function: String,
+            file: PathBuf,
+            line: usize,
+        },
+    }
+}
+
+/// Compiled detection patterns.
+pub struct UnsafePatterns {
+    unsafe_block: Regex,
+    transmute: Regex,
+    extern_fn: Regex,
+    string_literal: Regex,
+}
+
+impl UnsafePatterns {
+    /// Compile the detection patterns.
+    ///
+    /// # Errors
+    /// Returns an error if a pattern fails to compile.
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            unsafe_block: compile_regex(UNSAFE_BLOCK_REGEX)?,
+            transmute: compile_regex(TRANSMUTE_REGEX)?,
+            extern_fn: compile_regex(EXTERN_FN_REGEX)?,
+            string_literal: compile_regex(STRING_LITERAL_REGEX)?,
+        })
+    }
+
+    /// Violations in the source `content` of `file`.
+    #[must_use]
+    pub fn scan(&self, file: &Path, content: &str) -> Vec<UnsafeCodeViolation> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut violations = Vec::new();
+        crate::validators::for_each_non_test_non_comment_line(content, |index, line, trimmed| {
+            let code = self.string_literal.replace_all(line, "\"\"");
+            let code = code.split(COMMENT_PREFIX).next().unwrap_or_default();
+            if self.unsafe_block.is_match(code) && !has_safety_comment(&lines, index) {
+                violations.push(UnsafeCodeViolation::MissingSafetyComment {
+                    file: file.to_path_buf(),
+                    line: index + 1,
+                });
+            }
+            if !trimmed.starts_with("use ") && self.transmute.is_match(code) {
+                violations.push(UnsafeCodeViolation::TransmuteUsage {
+                    file: file.to_path_buf(),
+                    line: index + 1,
+                    context: trimmed.to_owned(),
+                });
+            }
+            if let Some(captures) = self.extern_fn.captures(code)
+                && !doc_block(&lines, index).contains(ERRORS_DOC_SECTION)
+            {
+                violations.push(UnsafeCodeViolation::UndocumentedExternFn {
+                    function: captures[1].to_owned(),
+                    file: file.to_path_buf(),
+                    line: index + 1,
+                });
+            }
+        });
+        violations
+    }
+}
+
+/// Whether line `index` or the comment/attribute lines right above it carry
+/// a `SAFETY:` justification.
+fn has_safety_comment(lines: &[&str], index: usize) -> bool {
+    let is_safety = |line: &str| SAFETY_COMMENT_MARKERS.iter().any(|m| line.contains(m));
+    if is_safety(lines[index]) {
+        return true;
+    }
+    lines[..index]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with(COMMENT_PREFIX) || line.starts_with("#["))
+        .any(is_safety)
+}
+
+/// Doc comments and attributes directly above line `index`, joined.
+fn doc_block(lines: &[&str], index: usize) -> String {
+    let mut block: Vec<&str> = lines[..index]
+        .iter()
+        .rev()
+        .map(|line| line.trim())
+        .take_while(|line| line.starts_with("///") || line.starts_with("#["))
+        .collect();
+    block.reverse();
+    block.join("\n")
+}
+
+/// Unsafe code and FFI boundary validator.
+pub struct UnsafeCodeValidator {
+    allow: BTreeMap<String, HashSet<String>>,
+    enabled: bool,
+}
+
+crate::impl_config_only_validator_new!(UnsafeCodeValidator, unsafe_code);
+
+impl UnsafeCodeValidator {
+    /// Creates an unsafe code validator with the given configuration.
+    #[must_use]
+    pub fn with_config(config: &UnsafeCodeRulesConfig) -> Self {
+        Self {
+            allow: config
+                .allow
+                .iter()
+                .map(|(crate_name, ids)| (crate_name.clone(), ids.iter().cloned().collect()))
+                .collect(),
+            enabled: config.enabled,
+        }
+    }
+
+    /// Unsafe code violations in every crate's `src/`.
+    ///
+    /// # Errors
+    /// Returns an error if a pattern fails to compile or a file cannot be read.
+    pub fn validate_all(&self, config: &ValidationConfig) -> Result<Vec<UnsafeCodeViolation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+        let patterns = UnsafePatterns::new()?;
+        let mut violations = Vec::new();
+        for_each_scan_file(config, Some(LanguageId::Rust), false, |entry, src_dir| {
+            let allowed = src_dir
+                .parent()
+                .and_then(Path::file_name)
+                .and_then(|name| self.allow.get(name.to_string_lossy().as_ref()));
+            let content = read_source(&entry.absolute_path)?;
+            violations.extend(
+                patterns
+                    .scan(&entry.absolute_path, &content)
+                    .into_iter()
+                    .filter(|violation| allowed.is_none_or(|ids| !ids.contains(violation.id()))),
+            );
+            Ok(())
+        })?;
+        Ok(violations)
+    }
+}
+
+impl Validator for UnsafeCodeValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_UNSAFE_CODE
+    }
+
+    fn description(&self) -> &'static str {
+        "Audits unsafe blocks, transmute and FFI boundaries"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self
+            .validate_all(config)?
+            .into_iter()
+            .map(Violation::boxed)
+            .collect())
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_UNSAFE_CODE,
+    "Audits unsafe blocks, transmute and FFI boundaries",
+    |root| {
+        Ok(Box::new(UnsafeCodeValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
@@ -133,7 +133,7 @@ impl VisibilityValidator {
 
             for_each_file_under_root(config, &full_path, Some(LanguageId::Rust), |entry| {
                 let path = &entry.absolute_path;
-                let content = std::fs::read_to_string(path)?;
+                let content = crate::scan::read_source(path)?;
                 Self::collect_internal_helper_violations(
                     &InternalHelperScanInput {
                         content: &content,
@@ -173,7 +173,7 @@ impl VisibilityValidator {
                     return Ok(());
                 }
 
-                let content = std::fs::read_to_string(path)?;
+                let content = crate::scan::read_source(path)?;
                 let pub_count =
                     Self::count_utility_pub_items(&content, &pub_crate_re, &pub_item_re_utility);
 
@@ -0,0 +1,345 @@
+//!
+//! **Documentation**: [docs/modules/validate.md](../../../../docs/modules/validate.md)
+//!
+//! WebAssembly Plugin Validators
+//!
+//! Runs compiled validators listed under `[[plugins.wasm]]` so teams can
+//! ship checks too complex for YAML rules without forking the crate. Each
+//! plugin is a core WebAssembly module without imports that exports:
+//!
+//! - `memory` — its linear memory;
+//! - `mcb_alloc(len: i32) -> i32` — reserves `len` bytes for the input;
+//! - `mcb_validate(ptr: i32, len: i32) -> i64` — reads the input JSON and
+//!   returns `(out_ptr << 32) | out_len` locating the output JSON.
+//!
+//! The input is a [`PluginInput`]: the facts of every Rust file in scope and
+//! the dependency edges between them. The output is a [`PluginOutput`].
+//! Plugins run sandboxed with a fuel budget; a plugin that traps, runs out
+//! of fuel or returns malformed output is logged and skipped.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{FileConfig, WasmPluginConfig};
+use crate::extractor::{Fact, RustExtractor};
+use crate::filters::LanguageId;
+use crate::graph::DependencyGraph;
+use crate::rules::plugins::is_valid_namespace;
+use crate::run_context::ValidationRunContext;
+use crate::scan::for_each_scan_file;
+use crate::{Result, ValidationConfig, ValidationError};
+use mcb_domain::ports::validation::{Severity, Validator, Violation, ViolationCategory};
+use mcb_utils::constants::validate::{
+    RULE_NAMESPACE_SEPARATOR, SEVERITY_ERROR, SEVERITY_INFO, VALIDATOR_WASM_PLUGINS,
+    WASM_PLUGIN_API_VERSION,
+};
+
+/// Facts extracted from one source file.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginFile {
+    /// Absolute file path.
+    pub path: PathBuf,
+    /// Facts extracted from the file.
+    pub facts: Vec<Fact>,
+}
+
+/// JSON document passed to `mcb_validate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginInput {
+    /// Input format version.
+    pub api_version: u32,
+    /// Absolute workspace root.
+    pub workspace_root: PathBuf,
+    /// Rust files in scope with their facts.
+    pub files: Vec<PluginFile>,
+    /// `(source, target)` fact IDs of the dependency graph's edges.
+    pub dependencies: Vec<(String, String)>,
+}
+
+/// JSON document returned by `mcb_validate`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PluginOutput {
+    /// Violations found by the plugin.
+    #[serde(default)]
+    pub violations: Vec<PluginViolation>,
+}
+
+/// Violation reported by a WebAssembly plugin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginViolation {
+    /// Rule ID, prefixed with the plugin namespace once loaded.
+    pub id: String,
+    /// Violation message.
+    pub message: String,
+    /// `error`, `warning` (default) or `info`.
+    #[serde(default)]
+    pub severity: Option<String>,
+    /// Violation category name (default `quality`).
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Flagged file, absolute or relative to the workspace root.
+    #[serde(default)]
+    pub file: Option<PathBuf>,
+    /// Flagged line (1-based).
+    #[serde(default)]
+    pub line: Option<usize>,
+    /// Suggested fix.
+    #[serde(default)]
+    pub suggestion: Option<String>,
+}
+
+impl fmt::Display for PluginViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {}", self.id, self.message)
+    }
+}
+
+impl Violation for PluginViolation {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn category(&self) -> ViolationCategory {
+        self.category
+            .as_deref()
+            .and_then(|category| category.parse().ok())
+            .unwrap_or(ViolationCategory::Quality)
+    }
+
+    fn severity(&self) -> Severity {
+        match self
+            .severity
+            .as_deref()
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some(SEVERITY_ERROR) => Severity::Error,
+            Some(SEVERITY_INFO) => Severity::Info,
+            _ => Severity::Warning,
+        }
+    }
+
+    fn file(&self) -> Option<&PathBuf> {
+        self.file.as_ref()
+    }
+
+    fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    fn suggestion(&self) -> Option<String> {
+        self.suggestion.clone()
+    }
+}
+
+#[cfg(feature = "wasm-plugins")]
+mod host {
+    use std::path::Path;
+
+    use wasmtime::{Config, Engine, Instance, Module, Store};
+
+    use crate::{Result, ValidationError};
+    use mcb_utils::constants::validate::{
+        WASM_PLUGIN_DEFAULT_FUEL, WASM_PLUGIN_EXPORT_ALLOC, WASM_PLUGIN_EXPORT_MEMORY,
+        WASM_PLUGIN_EXPORT_VALIDATE,
+    };
+
+    fn plugin_error(module: &Path, error: impl std::fmt::Display) -> ValidationError {
+        ValidationError::Config(format!("WASM plugin {}: {error:#}", module.display()))
+    }
+
+    /// Instantiate `module`, pass it `input` and return its output bytes.
+    pub(super) fn run_module(module: &Path, input: &[u8]) -> Result<Vec<u8>> {
+        let err = |e| plugin_error(module, e);
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(err)?;
+        let compiled = Module::from_file(&engine, module).map_err(err)?;
+        let mut store = Store::new(&engine, ());
+        store.set_fuel(WASM_PLUGIN_DEFAULT_FUEL).map_err(err)?;
+        let instance = Instance::new(&mut store, &compiled, &[]).map_err(err)?;
+
+        let memory = instance
+            .get_memory(&mut store, WASM_PLUGIN_EXPORT_MEMORY)
+            .ok_or_else(|| plugin_error(module, "missing `memory` export"))?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, WASM_PLUGIN_EXPORT_ALLOC)
+            .map_err(err)?;
+        let validate = instance
+            .get_typed_func::<(i32, i32), i64>(&mut store, WASM_PLUGIN_EXPORT_VALIDATE)
+            .map_err(err)?;
+
+        let len = i32::try_from(input.len())
+            .map_err(|_| plugin_error(module, "input larger than 2 GiB"))?;
+        let ptr = alloc.call(&mut store, len).map_err(err)?;
+        memory
+            .write(&mut store, ptr as u32 as usize, input)
+            .map_err(|e| plugin_error(module, e))?;
+        let packed = validate.call(&mut store, (ptr, len)).map_err(err)? as u64;
+
+        let mut output = vec![0; (packed & 0xFFFF_FFFF) as usize];
+        memory
+            .read(&store, (packed >> 32) as usize, &mut output)
+            .map_err(|e| plugin_error(module, e))?;
+        Ok(output)
+    }
+}
+
+#[cfg(not(feature = "wasm-plugins"))]
+mod host {
+    use std::path::Path;
+
+    use crate::{Result, ValidationError};
+
+    pub(super) fn run_module(module: &Path, _input: &[u8]) -> Result<Vec<u8>> {
+        Err(ValidationError::Config(format!(
+            "WASM plugin {}: mcb-validate was built without the `wasm-plugins` feature",
+            module.display()
+        )))
+    }
+}
+
+/// Runs the configured WebAssembly validator plugins.
+pub struct WasmPluginValidator {
+    workspace_root: PathBuf,
+}
+
+impl WasmPluginValidator {
+    /// Create a plugin validator rooted at `workspace_root`.
+    pub fn new(workspace_root: impl Into<PathBuf>) -> Self {
+        Self {
+            workspace_root: workspace_root.into(),
+        }
+    }
+
+    /// Facts of the Rust files in scope and their dependency edges.
+    ///
+    /// # Errors
+    /// Returns an error if the workspace cannot be scanned or a file cannot
+    /// be read.
+    pub fn build_input(config: &ValidationConfig) -> Result<PluginInput> {
+        let mut paths = Vec::new();
+        for_each_scan_file(config, Some(LanguageId::Rust), true, |entry, _src_dir| {
+            paths.push(entry.absolute_path.clone());
+            Ok(())
+        })?;
+
+        let context = ValidationRunContext::active_or_build(config)?;
+        let extractor = RustExtractor;
+        let files: Vec<PluginFile> = paths
+            .into_par_iter()
+            .map(|path| {
+                let facts =
+                    ValidationRunContext::with_active(&context, || extractor.extract_facts(&path))?;
+                Ok(PluginFile { path, facts })
+            })
+            .collect::<Result<_>>()?;
+
+        let mut graph = DependencyGraph::new();
+        let all_facts: Vec<Fact> = files.iter().flat_map(|f| f.facts.clone()).collect();
+        graph.build(&all_facts);
+
+        Ok(PluginInput {
+            api_version: WASM_PLUGIN_API_VERSION,
+            workspace_root: config.workspace_root.clone(),
+            files,
+            dependencies: graph.dependency_edges(),
+        })
+    }
+
+    /// Run one plugin on the serialized `input` and return its violations,
+    /// namespaced and with file paths resolved against the workspace root.
+    ///
+    /// # Errors
+    /// Returns an error if the namespace is invalid, or the module cannot be
+    /// loaded, traps, runs out of fuel or returns malformed output.
+    pub fn run_plugin(
+        &self,
+        plugin: &WasmPluginConfig,
+        input: &[u8],
+    ) -> Result<Vec<PluginViolation>> {
+        let module = self.workspace_root.join(&plugin.path);
+        if let Some(namespace) = &plugin.namespace
+            && !is_valid_namespace(namespace)
+        {
+            return Err(ValidationError::Config(format!(
+                "invalid WASM plugin namespace '{namespace}' for {}",
+                module.display()
+            )));
+        }
+
+        let output = host::run_module(&module, input)?;
+        let output: PluginOutput =
+            serde_json::from_slice(&output).map_err(|e| ValidationError::Parse {
+                file: module.clone(),
+                message: format!("invalid plugin output: {e}"),
+            })?;
+
+        Ok(output
+            .violations
+            .into_iter()
+            .map(|mut violation| {
+                if let Some(namespace) = &plugin.namespace {
+                    violation.id = format!("{namespace}{RULE_NAMESPACE_SEPARATOR}{}", violation.id);
+                }
+                violation.file = violation.file.map(|file| self.workspace_root.join(file));
+                violation
+            })
+            .collect())
+    }
+
+    fn run_validation(&self, config: &ValidationConfig) -> Result<Vec<Box<dyn Violation>>> {
+        let plugins = FileConfig::load(&self.workspace_root).plugins.wasm;
+        if plugins.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let input = serde_json::to_vec(&Self::build_input(config)?)
+            .map_err(|e| ValidationError::Config(format!("cannot serialize plugin input: {e}")))?;
+        let mut violations: Vec<Box<dyn Violation>> = Vec::new();
+        for plugin in &plugins {
+            match self.run_plugin(plugin, &input) {
+                Ok(found) => violations.extend(found.into_iter().map(Violation::boxed)),
+                Err(e) => mcb_domain::warn!(
+                    "wasm_plugin",
+                    "Plugin failed, skipping",
+                    &format!("plugin={} error={e}", plugin.path.display())
+                ),
+            }
+        }
+        Ok(violations)
+    }
+}
+
+impl Validator for WasmPluginValidator {
+    fn name(&self) -> &'static str {
+        VALIDATOR_WASM_PLUGINS
+    }
+
+    fn description(&self) -> &'static str {
+        "Runs compiled WebAssembly validator plugins"
+    }
+
+    fn validate(
+        &self,
+        config: &ValidationConfig,
+    ) -> mcb_domain::ports::validation::ValidatorResult<Vec<Box<dyn Violation>>> {
+        Ok(self.run_validation(config)?)
+    }
+}
+
+mcb_domain::register_validator!(
+    VALIDATOR_WASM_PLUGINS,
+    "Runs compiled WebAssembly validator plugins",
+    |root| {
+        Ok(Box::new(WasmPluginValidator::new(root))
+            as Box<dyn mcb_domain::ports::validation::Validator>)
+    }
+);
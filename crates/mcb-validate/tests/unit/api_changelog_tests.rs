@@ -0,0 +1,94 @@
+//! Unit tests for `mcb_validate::api_changelog`
+
+use std::collections::BTreeMap;
+
+use mcb_validate::{ApiChangelog, ApiItem, ApiSnapshot};
+use rstest::rstest;
+
+fn snapshot(items: &[(&str, &str, &str)]) -> ApiSnapshot {
+    let mut crates: BTreeMap<String, BTreeMap<String, ApiItem>> = BTreeMap::new();
+    for (crate_name, path, signature) in items {
+        crates.entry((*crate_name).to_owned()).or_default().insert(
+            (*path).to_owned(),
+            ApiItem {
+                kind: signature
+                    .split_whitespace()
+                    .nth(1)
+                    .unwrap_or_default()
+                    .to_owned(),
+                signature: (*signature).to_owned(),
+            },
+        );
+    }
+    ApiSnapshot { version: 1, crates }
+}
+
+fn changelog() -> ApiChangelog {
+    let old = snapshot(&[
+        ("core", "keep", "pub fn keep()"),
+        ("core", "gone", "pub fn gone()"),
+        ("core", "Widget", "pub struct Widget"),
+        ("legacy", "old", "pub fn old()"),
+    ]);
+    let new = snapshot(&[
+        ("core", "keep", "pub fn keep()"),
+        ("core", "Widget", "pub struct Widget<T>"),
+        ("core", "added", "pub fn added()"),
+    ]);
+    ApiChangelog::between("v0.1.0", &old, "HEAD", &new)
+}
+
+#[rstest]
+fn test_between_lists_added_removed_and_changed_items() {
+    let changelog = changelog();
+
+    let paths = |changes: &[mcb_validate::ApiChange]| -> Vec<String> {
+        changes
+            .iter()
+            .map(|c| format!("{}::{}", c.crate_name, c.path))
+            .collect()
+    };
+    assert_eq!(paths(&changelog.added), ["core::added"]);
+    assert_eq!(paths(&changelog.removed), ["core::gone", "legacy::old"]);
+    assert_eq!(paths(&changelog.changed), ["core::Widget"]);
+    assert_eq!(
+        changelog.changed[0].old_signature.as_deref(),
+        Some("pub struct Widget")
+    );
+    assert_eq!(changelog.changed[0].kind, "struct");
+    assert!(changelog.is_breaking());
+}
+
+#[rstest]
+fn test_markdown_groups_breaking_changes_first() {
+    let markdown = changelog().to_markdown();
+
+    assert_eq!(
+        markdown,
+        "## Public API changes (v0.1.0..HEAD)\n\
+         \n### Removed (breaking)\n\n\
+         - `core::gone`: `pub fn gone()`\n\
+         - `legacy::old`: `pub fn old()`\n\
+         \n### Changed (breaking)\n\n\
+         - `core::Widget`: `pub struct Widget` → `pub struct Widget<T>`\n\
+         \n### Added\n\n\
+         - `core::added`: `pub fn added()`\n"
+    );
+}
+
+#[rstest]
+fn test_identical_snapshots_have_no_changes() {
+    let api = snapshot(&[("core", "keep", "pub fn keep()")]);
+
+    let changelog = ApiChangelog::between("a", &api, "b", &api);
+
+    assert!(changelog.is_empty());
+    assert!(!changelog.is_breaking());
+    assert!(
+        changelog
+            .to_markdown()
+            .ends_with("No public API changes.\n")
+    );
+    let json = serde_json::to_value(&changelog).unwrap();
+    assert_eq!(json["added"], serde_json::json!([]));
+}
@@ -0,0 +1,67 @@
+//! Unit tests for `mcb_validate::baseline`
+
+use std::path::Path;
+
+use mcb_domain::ports::validation::Severity;
+use mcb_validate::{Baseline, HygieneViolation, Violation};
+use rstest::rstest;
+
+fn inline_test_at(file: &Path, line: usize) -> Box<dyn Violation> {
+    HygieneViolation::InlineTestModule {
+        file: file.to_path_buf(),
+        line,
+        severity: Severity::Warning,
+    }
+    .boxed()
+}
+
+#[rstest]
+fn baseline_hides_existing_violations_even_after_lines_move() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(&file, "mod tests {}\n").unwrap();
+    let baseline = Baseline::from_violations(&[inline_test_at(&file, 1)], dir.path());
+    assert_eq!(baseline.entries.len(), 1);
+    assert_eq!(baseline.entries[0].file.as_deref(), Some("lib.rs"));
+
+    std::fs::write(&file, "use x;\n\nmod tests {}\nmod new_tests {}\n").unwrap();
+    let outcome = baseline.filter_new(
+        vec![inline_test_at(&file, 3), inline_test_at(&file, 4)],
+        dir.path(),
+    );
+
+    assert_eq!(outcome.baselined, 1);
+    assert_eq!(outcome.violations.len(), 1);
+    assert_eq!(outcome.violations[0].line(), Some(4));
+}
+
+#[rstest]
+fn baseline_counts_identical_fingerprints() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(&file, "mod tests {}\nmod tests {}\nmod tests {}\n").unwrap();
+    let baseline = Baseline::from_violations(&[inline_test_at(&file, 1)], dir.path());
+
+    let outcome = baseline.filter_new(
+        vec![inline_test_at(&file, 1), inline_test_at(&file, 2)],
+        dir.path(),
+    );
+
+    assert_eq!(outcome.baselined, 1);
+    assert_eq!(outcome.violations.len(), 1);
+}
+
+#[rstest]
+fn baseline_round_trips_through_its_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(&file, "mod tests {}\n").unwrap();
+    let baseline = Baseline::from_violations(&[inline_test_at(&file, 1)], dir.path());
+    let path = dir.path().join(".mcb-baseline.json");
+
+    baseline.save(&path).unwrap();
+
+    assert_eq!(Baseline::load(&path).unwrap(), baseline);
+    std::fs::write(&path, r#"{"version": 99, "entries": []}"#).unwrap();
+    assert!(Baseline::load(&path).is_err());
+}
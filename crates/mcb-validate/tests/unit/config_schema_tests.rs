@@ -0,0 +1,65 @@
+//! Unit tests for `mcb_validate::config::schema`
+
+use mcb_validate::FileConfig;
+use rstest::rstest;
+
+const DEFAULTS: &str = include_str!("../../../../config/mcb-validate.toml");
+
+fn issues(source: &str) -> Vec<(usize, String)> {
+    FileConfig::check(source)
+        .unwrap()
+        .into_iter()
+        .map(|issue| (issue.line, issue.key))
+        .collect()
+}
+
+#[test]
+fn test_embedded_defaults_match_schema() {
+    assert_eq!(issues(DEFAULTS), vec![]);
+}
+
+#[test]
+fn test_scaffold_is_commented_and_valid() {
+    let scaffold = FileConfig::scaffold();
+
+    assert!(scaffold.starts_with("#:schema ./.mcb-validate.schema.json\n"));
+    assert!(scaffold.contains("\n[rules.quality]\n"));
+    assert!(scaffold.contains("\n# max_file_lines = 500\n"));
+    assert!(scaffold.contains("\n# Enable dependency validation\n# dependency = true\n"));
+    assert_eq!(issues(&scaffold), vec![]);
+}
+
+#[rstest]
+#[case(
+    "[rules.quality]\nenabled = true\nmax_file_lines = \"many\"\n",
+    vec![(3, "rules.quality.max_file_lines".to_owned())]
+)]
+#[case(
+    "[general]\nexclude_patterns = [\n  \"target/\",\n]\n\n[rules.test_coverage]\nmin_coverage_pct = 60\n",
+    vec![(7, "rules.test_coverage.min_coverage_pct".to_owned())]
+)]
+#[case(
+    "[rules.architecture]\nseverity = \"FATAL\"\n",
+    vec![(2, "rules.architecture.severity".to_owned())]
+)]
+#[case(
+    "[[plugins.rule_dirs]]\npath = \"a\"\n\n[[plugins.rule_dirs]]\nnamespce = \"B\"\n",
+    vec![(5, "plugins.rule_dirs.1.namespce".to_owned())]
+)]
+#[case("[validators]\nkiss = flase\n", vec![(2, String::new())])]
+#[case("[rules.unsafe_code]\nallow = { \"mcb-ffi\" = [\"UNSAFE002\"] }\n", vec![])]
+fn test_check_reports_offending_key_line(
+    #[case] source: &str,
+    #[case] expected: Vec<(usize, String)>,
+) {
+    assert_eq!(issues(source), expected);
+}
+
+#[test]
+fn test_schema_rejects_unknown_keys() {
+    let schema = FileConfig::json_schema();
+
+    assert_eq!(schema["additionalProperties"], false);
+    assert!(schema.to_string().contains("max_file_lines"));
+    assert!(!schema.to_string().contains("\"required\""));
+}
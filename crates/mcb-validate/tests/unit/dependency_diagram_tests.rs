@@ -0,0 +1,132 @@
+//! Unit tests for `mcb_validate::dependency_diagram`
+
+use mcb_domain::ports::validation::ValidationConfig;
+use mcb_validate::DependencyDiagram;
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::{cargo_toml_with_deps, create_test_crate, create_test_crate_with_file};
+
+fn edges(diagram: &DependencyDiagram) -> Vec<(&str, &str, bool)> {
+    diagram
+        .edges
+        .iter()
+        .map(|((from, to), reexport)| (from.as_str(), to.as_str(), *reexport))
+        .collect()
+}
+
+fn workspace() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(
+        &temp,
+        "alpha",
+        "pub mod engine;\nmod report;\npub use self::engine::Engine;\npub use beta::Beta;\n",
+    );
+    create_test_crate_with_file(
+        &temp,
+        "alpha",
+        "engine.rs",
+        "use crate::report::Report;\npub struct Engine;\n",
+    );
+    create_test_crate_with_file(&temp, "alpha", "report.rs", "pub struct Report;\n");
+    create_test_crate(&temp, "beta", "pub struct Beta;\n");
+    create_test_crate(&temp, "gamma", "use beta::Beta;\n");
+    let crates = temp.path().join("crates");
+    for (name, deps) in [
+        ("alpha", &[("beta", "0.1.0")]),
+        ("gamma", &[("beta", "0.1.0")]),
+    ] {
+        std::fs::write(
+            crates.join(name).join("Cargo.toml"),
+            cargo_toml_with_deps(name, deps),
+        )
+        .unwrap();
+    }
+    temp
+}
+
+#[rstest]
+fn test_renders_mermaid_and_dot() {
+    let mut diagram = DependencyDiagram::new("demo");
+    diagram.add_edge("a", "b", false);
+    diagram.add_edge("a", "c \"x\"", true);
+    diagram.add_node("d");
+
+    assert_eq!(
+        diagram.to_mermaid(),
+        "---\ntitle: demo\n---\nflowchart LR\n    n0[\"a\"]\n    n1[\"b\"]\n    n2[\"c #quot;x#quot;\"]\n    n3[\"d\"]\n    n0 --> n1\n    n0 -->|re-export| n2\n"
+    );
+    assert_eq!(
+        diagram.to_dot(),
+        "digraph \"demo\" {\n    rankdir=LR;\n    node [shape=box];\n    \"a\";\n    \"b\";\n    \"c \\\"x\\\"\";\n    \"d\";\n    \"a\" -> \"b\";\n    \"a\" -> \"c \\\"x\\\"\" [label=\"re-export\"];\n}\n"
+    );
+}
+
+#[rstest]
+fn test_add_edge_keeps_reexport_label() {
+    let mut diagram = DependencyDiagram::new("demo");
+    diagram.add_edge("a", "b", true);
+    diagram.add_edge("a", "b", false);
+
+    assert_eq!(edges(&diagram), [("a", "b", true)]);
+}
+
+#[rstest]
+fn test_crate_diagram_labels_reexported_dependencies() {
+    let temp = workspace();
+
+    let diagram = DependencyDiagram::crates(&ValidationConfig::new(temp.path())).unwrap();
+
+    let nodes: Vec<&str> = diagram.nodes.iter().map(String::as_str).collect();
+    assert_eq!(nodes, ["alpha", "beta", "gamma"]);
+    assert_eq!(
+        edges(&diagram),
+        [("alpha", "beta", true), ("gamma", "beta", false)]
+    );
+}
+
+#[rstest]
+fn test_module_diagrams_include_submodule_reexports() {
+    let temp = workspace();
+
+    let diagrams = DependencyDiagram::modules(&ValidationConfig::new(temp.path())).unwrap();
+
+    let alpha = &diagrams["alpha"];
+    let nodes: Vec<&str> = alpha.nodes.iter().map(String::as_str).collect();
+    assert_eq!(nodes, ["crate", "engine", "report"]);
+    assert_eq!(
+        edges(alpha),
+        [("crate", "engine", true), ("engine", "report", false)]
+    );
+}
+
+#[rstest]
+fn test_write_all_writes_mermaid_and_dot_per_diagram() {
+    let temp = workspace();
+    let out_dir = temp.path().join("diagrams");
+
+    let written =
+        DependencyDiagram::write_all(&ValidationConfig::new(temp.path()), &out_dir).unwrap();
+
+    let mut names: Vec<String> = written
+        .iter()
+        .filter_map(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "alpha-modules.dot",
+            "alpha-modules.mmd",
+            "beta-modules.dot",
+            "beta-modules.mmd",
+            "crates.dot",
+            "crates.mmd",
+            "gamma-modules.dot",
+            "gamma-modules.mmd",
+        ]
+    );
+    let crates = std::fs::read_to_string(out_dir.join("crates.mmd")).unwrap();
+    assert!(crates.contains("n0 -->|re-export| n1"), "{crates}");
+}
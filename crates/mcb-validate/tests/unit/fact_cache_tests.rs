@@ -0,0 +1,106 @@
+//! Unit tests for `mcb_validate::fact_cache`
+
+use std::cell::Cell;
+use std::path::Path;
+use std::sync::Arc;
+
+use mcb_domain::ports::validation::ValidationConfig;
+use mcb_validate::{FactCache, RcaAnalyzer, ValidationRunContext};
+use rstest::rstest;
+
+#[rstest]
+fn entries_are_keyed_by_path_and_content() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = FactCache::new(dir.path());
+    let file = Path::new("src/lib.rs");
+
+    cache.put("facts", file, "fn a() {}", &vec!["a".to_owned()]);
+
+    assert_eq!(
+        cache.get::<Vec<String>>("facts", file, "fn a() {}"),
+        Some(vec!["a".to_owned()])
+    );
+    assert_eq!(cache.get::<Vec<String>>("facts", file, "fn b() {}"), None);
+    assert_eq!(
+        cache.get::<Vec<String>>("facts", Path::new("src/other.rs"), "fn a() {}"),
+        None
+    );
+    assert_eq!(cache.get::<Vec<String>>("metrics", file, "fn a() {}"), None);
+}
+
+#[rstest]
+fn get_or_extract_only_extracts_on_miss() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = FactCache::new(dir.path());
+    let file = Path::new("src/lib.rs");
+    let calls = Cell::new(0);
+    let extract = || {
+        calls.set(calls.get() + 1);
+        Ok(42_u32)
+    };
+
+    assert_eq!(
+        cache.get_or_extract("facts", file, "x", extract).unwrap(),
+        42
+    );
+    assert_eq!(
+        cache.get_or_extract("facts", file, "x", extract).unwrap(),
+        42
+    );
+    assert_eq!(calls.get(), 1);
+}
+
+#[rstest]
+fn corrupt_entries_are_misses_and_clear_removes_everything() {
+    let dir = tempfile::tempdir().unwrap();
+    let cache = FactCache::new(dir.path().join("cache"));
+    let file = Path::new("src/lib.rs");
+    cache.put("facts", file, "x", &1_u32);
+
+    for entry in walkdir::WalkDir::new(cache.dir())
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+    {
+        std::fs::write(entry.path(), "not json").unwrap();
+    }
+    assert_eq!(cache.get::<u32>("facts", file, "x"), None);
+
+    cache.clear().unwrap();
+    assert!(!cache.dir().exists());
+    cache.clear().unwrap();
+}
+
+#[rstest]
+fn analyzer_reuses_cached_metrics_for_unchanged_files() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join("src")).unwrap();
+    let file = root.join("src/lib.rs");
+    std::fs::write(&file, "pub fn answer() -> u32 {\n    42\n}\n").unwrap();
+
+    let config = ValidationConfig::new(root).with_cache_dir(root.join(".mcb-validate-cache"));
+    let file = std::fs::canonicalize(&file).unwrap();
+    let analyze = || {
+        let context = Arc::new(ValidationRunContext::build(&config).unwrap());
+        ValidationRunContext::with_active(&context, || {
+            RcaAnalyzer::new().analyze_file(&file).unwrap()
+        })
+    };
+
+    let first = analyze();
+    assert_eq!(first.len(), 1);
+    assert!(root.join(".mcb-validate-cache").is_dir());
+    assert!(
+        ValidationRunContext::build(&config)
+            .unwrap()
+            .file_inventory()
+            .iter()
+            .all(|entry| !entry.relative_path.starts_with(".mcb-validate-cache"))
+    );
+
+    let second = analyze();
+    assert_eq!(second.len(), 1);
+    assert_eq!(second[0].name, first[0].name);
+    assert_eq!(second[0].metrics.sloc, first[0].metrics.sloc);
+}
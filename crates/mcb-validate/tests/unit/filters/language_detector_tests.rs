@@ -1,6 +1,7 @@
 //! Unit tests for language detection (filters).
 
 use rstest::rstest;
+use std::collections::HashMap;
 use std::path::Path;
 
 use mcb_domain::ports::validation::LanguageId;
@@ -61,6 +62,12 @@ fn from_extension_mapping(#[case] ext: &str, #[case] expected: LanguageId) {
 #[case("Makefile", LanguageId::Makefile)]
 #[case("GNUmakefile", LanguageId::Makefile)]
 #[case("CMakeLists.txt", LanguageId::Cpp)]
+#[case("Containerfile", LanguageId::Dockerfile)]
+#[case("Dockerfile.dev", LanguageId::Dockerfile)]
+#[case("BUILD.bazel", LanguageId::Python)]
+#[case("WORKSPACE", LanguageId::Python)]
+#[case("Rakefile", LanguageId::Ruby)]
+#[case(".bashrc", LanguageId::Shell)]
 fn from_filename_mapping(#[case] filename: &str, #[case] expected: LanguageId) {
     assert_eq!(LanguageId::from_filename(filename), Some(expected));
 }
@@ -70,10 +77,48 @@ fn from_filename_mapping(#[case] filename: &str, #[case] expected: LanguageId) {
 #[case("#!/bin/bash", LanguageId::Shell)]
 #[case("#!/usr/bin/env sh", LanguageId::Shell)]
 #[case("#!/usr/bin/env ruby", LanguageId::Ruby)]
+#[case("#!/usr/bin/python3.12 -u", LanguageId::Python)]
+#[case("#!/usr/bin/env -S deno run --allow-net", LanguageId::JavaScript)]
+#[case("#!/usr/bin/env ts-node", LanguageId::TypeScript)]
+#[case("#!/usr/bin/make -f", LanguageId::Makefile)]
 fn from_shebang_mapping(#[case] first_line: &str, #[case] expected: LanguageId) {
     assert_eq!(LanguageId::from_shebang(first_line), Some(expected));
 }
 
+#[rstest]
+#[case("#!/usr/bin/env perl")]
+#[case("#!/usr/bin/env fish")]
+#[case("# not a shebang: #!/bin/sh")]
+fn from_shebang_rejects_unknown_interpreters(#[case] first_line: &str) {
+    assert_eq!(LanguageId::from_shebang(first_line), None);
+}
+
+#[rstest]
+#[case("#!/bin/sh\necho hi\n", LanguageId::Shell)]
+#[case("<?php\necho 'hi';\n", LanguageId::Php)]
+#[case("<!DOCTYPE html>\n<html></html>\n", LanguageId::Html)]
+#[case(
+    "# syntax=docker/dockerfile:1\nARG RUST=1.85\nFROM rust:${RUST} AS build\n",
+    LanguageId::Dockerfile
+)]
+#[case(".PHONY: all\nall:\n\tcargo build\n", LanguageId::Makefile)]
+#[case("build: deps\n\tcargo build\n", LanguageId::Makefile)]
+#[case(
+    "load(\"@rules_rust//rust:defs.bzl\", \"rust_binary\")\n",
+    LanguageId::Python
+)]
+fn from_content_heuristics(#[case] content: &str, #[case] expected: LanguageId) {
+    assert_eq!(LanguageId::from_content(content), Some(expected));
+}
+
+#[rstest]
+#[case("From the maintainers: please read CONTRIBUTING first.\n")]
+#[case("def main():\n    return 0\n")]
+#[case("")]
+fn from_content_ignores_plain_text(#[case] content: &str) {
+    assert_eq!(LanguageId::from_content(content), None);
+}
+
 #[rstest]
 fn test_content_detection() {
     let detector = LanguageDetector::new();
@@ -115,6 +160,39 @@ fn test_shebang_detection() {
     );
 }
 
+#[rstest]
+fn extensionless_script_content_beats_its_name() {
+    let detector = LanguageDetector::new();
+    assert_eq!(
+        detector.detect(Path::new("build"), Some("#!/bin/bash\nmake\n")),
+        Some(LanguageId::Shell)
+    );
+    assert_eq!(
+        detector.detect(Path::new("build"), Some("rust_binary(name = \"app\")\n")),
+        Some(LanguageId::Python)
+    );
+}
+
+#[rstest]
+fn configured_filenames_override_detection() {
+    let detector = LanguageDetector::new().with_filenames(HashMap::from([
+        ("Jenkinsfile".to_owned(), LanguageId::Shell),
+        ("config.json".to_owned(), LanguageId::Yaml),
+    ]));
+    assert_eq!(
+        detector.detect(Path::new("ci/jenkinsfile"), Some("pipeline {}")),
+        Some(LanguageId::Shell)
+    );
+    assert_eq!(
+        detector.detect(Path::new("config.json"), Some("{}")),
+        Some(LanguageId::Yaml)
+    );
+    assert_eq!(
+        detector.detect(Path::new("Jenkinsfile.bak"), Some("")),
+        None
+    );
+}
+
 #[rstest]
 fn test_unknown_existing_file_returns_none() {
     let detector = LanguageDetector::new();
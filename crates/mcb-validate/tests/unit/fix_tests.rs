@@ -0,0 +1,158 @@
+//! Unit tests for `mcb_validate::fix`
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use mcb_domain::ports::validation::{Severity, Violation, ViolationCategory};
+use mcb_validate::{EmbeddedRules, FixEngine, RuleFix, ValidatedRule, YamlRuleLoader};
+use rstest::rstest;
+
+#[derive(Debug)]
+struct RuleHit {
+    rule_id: &'static str,
+    file: PathBuf,
+    line: usize,
+}
+
+impl fmt::Display for RuleHit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at line {}", self.rule_id, self.line)
+    }
+}
+
+impl Violation for RuleHit {
+    fn id(&self) -> &str {
+        self.rule_id
+    }
+    fn category(&self) -> ViolationCategory {
+        ViolationCategory::Quality
+    }
+    fn severity(&self) -> Severity {
+        Severity::Info
+    }
+    fn file(&self) -> Option<&PathBuf> {
+        Some(&self.file)
+    }
+    fn line(&self) -> Option<usize> {
+        Some(self.line)
+    }
+}
+
+fn hit(rule_id: &'static str, file: &Path, line: usize) -> Box<dyn Violation> {
+    Box::new(RuleHit {
+        rule_id,
+        file: file.to_path_buf(),
+        line,
+    })
+}
+
+fn useless_format_rule() -> ValidatedRule {
+    YamlRuleLoader::from_embedded(&EmbeddedRules::all_yaml())
+        .unwrap()
+        .load_embedded_rules()
+        .unwrap()
+        .into_iter()
+        .find(|rule| rule.id == "QUAL007")
+        .unwrap()
+}
+
+fn rule_with_fix(id: &str, fix_type: &str, replacement: Option<&str>) -> ValidatedRule {
+    let mut rule = useless_format_rule();
+    rule.id = id.to_owned();
+    rule.fixes = vec![RuleFix {
+        fix_type: fix_type.to_owned(),
+        pattern: None,
+        replacement: replacement.map(str::to_owned),
+        message: "fix".to_owned(),
+    }];
+    rule
+}
+
+#[rstest]
+fn replace_pattern_fix_rewrites_the_flagged_line_only() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    let source = "fn a(x: u32) -> String {\n    format!(\"{}\", x)\n}\nfn b(y: u32) -> String {\n    format!(\"{}\", y)\n}\n";
+    std::fs::write(&file, source).unwrap();
+
+    let engine = FixEngine::from_rules(&[useless_format_rule()]).unwrap();
+    assert!(engine.is_fixable("QUAL007"));
+    let plan = engine.plan(&[hit("QUAL007", &file, 2)], dir.path());
+
+    assert_eq!(plan.fixed, vec![0]);
+    assert_eq!(plan.edit_count(), 1);
+    assert_eq!(
+        plan.diff(dir.path()),
+        "--- a/lib.rs\n+++ b/lib.rs\n@@ -2,1 +2,1 @@\n-    format!(\"{}\", x)\n+    x.to_string()\n"
+    );
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), source);
+
+    plan.apply().unwrap();
+    assert_eq!(
+        std::fs::read_to_string(&file).unwrap(),
+        source.replacen("format!(\"{}\", x)", "x.to_string()", 1)
+    );
+}
+
+#[rstest]
+fn insert_and_delete_fixes_shift_later_hunks() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(
+        &file,
+        "use std::fmt;\nimpl A {\n    pub fn len(&self) -> usize {\n",
+    )
+    .unwrap();
+
+    let engine = FixEngine::from_rules(&[
+        rule_with_fix("IMP001", "remove_import", None),
+        rule_with_fix("MUST001", "insert_before", Some("#[must_use]")),
+        rule_with_fix("ADV001", "refactor", None),
+    ])
+    .unwrap();
+    assert!(!engine.is_fixable("ADV001"));
+
+    let violations = [
+        hit("IMP001", &file, 1),
+        hit("MUST001", &file, 3),
+        hit("ADV001", &file, 2),
+        hit("MUST001", Path::new("missing.rs"), 1),
+    ];
+    let plan = engine.plan(&violations, dir.path());
+
+    assert_eq!(plan.fixed, vec![0, 1]);
+    assert_eq!(
+        plan.diff(dir.path()),
+        "--- a/lib.rs\n+++ b/lib.rs\n@@ -1,1 +0,0 @@\n-use std::fmt;\n\
+         @@ -3,1 +2,2 @@\n-    pub fn len(&self) -> usize {\n+    #[must_use]\n+    pub fn len(&self) -> usize {\n"
+    );
+    assert_eq!(
+        plan.files[0].fixed(),
+        "impl A {\n    #[must_use]\n    pub fn len(&self) -> usize {\n"
+    );
+}
+
+#[rstest]
+fn overlapping_fixes_keep_the_first_edit() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(&file, "let s = format!(\"{}\", x);\n").unwrap();
+
+    let engine = FixEngine::from_rules(&[
+        useless_format_rule(),
+        rule_with_fix("DEL001", "delete_line", None),
+    ])
+    .unwrap();
+    let plan = engine.plan(
+        &[
+            hit("QUAL007", &file, 1),
+            hit("QUAL007", &file, 1),
+            hit("DEL001", &file, 1),
+        ],
+        dir.path(),
+    );
+
+    assert_eq!(plan.fixed, vec![0, 1]);
+    assert_eq!(plan.skipped, 1);
+    assert_eq!(plan.files[0].fixed(), "let s = x.to_string();\n");
+}
@@ -0,0 +1,94 @@
+//! Unit tests for `mcb_validate::github_reporter`
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use mcb_domain::ports::ViolationEntry;
+use mcb_validate::{GenericReport, GenericSummary, GithubReporter};
+use rstest::rstest;
+
+fn entry(id: &str, severity: &str, file: Option<&str>, line: Option<usize>) -> ViolationEntry {
+    ViolationEntry {
+        id: id.to_owned(),
+        category: "quality".to_owned(),
+        severity: severity.to_owned(),
+        file: file.map(str::to_owned),
+        line,
+        message: format!("{id} fired"),
+        suggestion: None,
+    }
+}
+
+fn report(entries: Vec<ViolationEntry>) -> GenericReport {
+    GenericReport {
+        timestamp: "2026-10-16 00:00:00 UTC".to_owned(),
+        workspace_root: PathBuf::from("/work/repo"),
+        summary: GenericSummary {
+            total_violations: entries.len(),
+            errors: 0,
+            warnings: 0,
+            infos: 0,
+            by_category: HashMap::new(),
+            passed: true,
+        },
+        violations_by_category: HashMap::from([("quality".to_owned(), entries)]),
+    }
+}
+
+#[rstest]
+#[case(
+    "ERROR",
+    Some("/work/repo/src/a.rs"),
+    Some(3),
+    "::error file=src/a.rs,line=3,title=Q001::[Q001] Q001 fired"
+)]
+#[case(
+    "WARNING",
+    Some("/work/repo/src/a.rs"),
+    Some(0),
+    "::warning file=src/a.rs,title=Q001::[Q001] Q001 fired"
+)]
+#[case("INFO", None, None, "::notice title=Q001::[Q001] Q001 fired")]
+fn annotations_use_the_severity_level_and_relative_paths(
+    #[case] severity: &str,
+    #[case] file: Option<&str>,
+    #[case] line: Option<usize>,
+    #[case] expected: &str,
+) {
+    let output = GithubReporter::to_annotations(&report(vec![entry("Q001", severity, file, line)]));
+    assert_eq!(output, format!("{expected}\n"));
+}
+
+#[rstest]
+fn annotations_escape_workflow_command_syntax() {
+    let mut tricky = entry("Q002", "ERROR", Some("/work/repo/src/a,b:c.rs"), Some(1));
+    tricky.message = "100% broken\nsecond line".to_owned();
+    tricky.suggestion = Some("fix it".to_owned());
+    let output = GithubReporter::to_annotations(&report(vec![tricky]));
+
+    assert_eq!(
+        output.trim_end(),
+        "::error file=src/a%2Cb%3Ac.rs,line=1,title=Q002::[Q002] 100%25 broken%0Asecond line (fix it)"
+    );
+}
+
+#[rstest]
+fn annotations_are_ordered_by_file_and_line() {
+    let output = GithubReporter::to_annotations(&report(vec![
+        entry("Q003", "ERROR", Some("/work/repo/src/b.rs"), Some(1)),
+        entry("Q004", "ERROR", Some("/work/repo/src/a.rs"), Some(9)),
+        entry("Q005", "ERROR", Some("/work/repo/src/a.rs"), Some(2)),
+    ]));
+    let ids: Vec<&str> = output
+        .lines()
+        .map(|line| {
+            line.rsplit("title=")
+                .next()
+                .unwrap()
+                .split("::")
+                .next()
+                .unwrap()
+        })
+        .collect();
+    assert_eq!(ids, ["Q005", "Q004", "Q003"]);
+}
@@ -0,0 +1,101 @@
+//! Unit tests for `mcb_validate::html_reporter`
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use mcb_domain::ports::ViolationEntry;
+use mcb_validate::{GenericReport, GenericSummary, HtmlReporter, ReportHistory};
+use rstest::rstest;
+use tempfile::TempDir;
+
+fn entry(id: &str, severity: &str, file: &str, message: &str) -> ViolationEntry {
+    ViolationEntry {
+        id: id.to_owned(),
+        category: "quality".to_owned(),
+        severity: severity.to_owned(),
+        file: Some(format!("/work/repo/{file}")),
+        line: Some(3),
+        message: message.to_owned(),
+        suggestion: Some("Fix it".to_owned()),
+    }
+}
+
+fn report(timestamp: &str, entries: Vec<ViolationEntry>) -> GenericReport {
+    let errors = entries.iter().filter(|e| e.severity == "ERROR").count();
+    let mut by_category = HashMap::new();
+    if !entries.is_empty() {
+        by_category.insert("quality".to_owned(), entries.len());
+    }
+    GenericReport {
+        timestamp: timestamp.to_owned(),
+        workspace_root: PathBuf::from("/work/repo"),
+        summary: GenericSummary {
+            total_violations: entries.len(),
+            errors,
+            warnings: entries.len() - errors,
+            infos: 0,
+            by_category,
+            passed: errors == 0,
+        },
+        violations_by_category: HashMap::from([("quality".to_owned(), entries)]),
+    }
+}
+
+#[rstest]
+#[case("crates/mcb-core/src/lib.rs", "mcb-core")]
+#[case("src/main.rs", "(workspace)")]
+#[case("crates/Cargo.toml", "(workspace)")]
+fn crate_of_groups_by_crate_directory(#[case] file: &str, #[case] expected: &str) {
+    assert_eq!(HtmlReporter::crate_of(file), expected);
+}
+
+#[rstest]
+fn html_renders_rows_breakdown_and_escapes_messages() {
+    let html = HtmlReporter::to_html(
+        &report(
+            "2026-10-16 00:00:00 UTC",
+            vec![
+                entry(
+                    "Q001",
+                    "ERROR",
+                    "crates/core/src/lib.rs",
+                    "uses <unsafe> & more",
+                ),
+                entry("Q002", "WARNING", "crates/api/src/lib.rs", "long fn"),
+            ],
+        ),
+        &ReportHistory::default(),
+    );
+
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("uses &lt;unsafe&gt; &amp; more"));
+    assert!(
+        html.contains("<tr class=\"crate\" data-crate=\"core\"><td>core</td><td>1</td><td>0</td>")
+    );
+    assert!(
+        html.contains("data-severity=\"WARNING\" data-category=\"quality\" data-crate=\"api\"")
+    );
+    assert!(html.contains("crates/core/src/lib.rs:3"));
+    assert!(html.contains("FAILED"));
+    assert!(!html.contains("<h2>Trend"));
+}
+
+#[rstest]
+fn history_appends_runs_and_feeds_the_trend_chart() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("history.json");
+
+    ReportHistory::append(&path, &report("run-1", vec![])).unwrap();
+    let history = ReportHistory::append(
+        &path,
+        &report("run-2", vec![entry("Q001", "ERROR", "src/lib.rs", "bad")]),
+    )
+    .unwrap();
+
+    assert_eq!(history.runs.len(), 2);
+    assert_eq!(history.runs[1].errors, 1);
+    assert_eq!(ReportHistory::load(&path).unwrap().runs, history.runs);
+    let html = HtmlReporter::to_html(&report("run-2", vec![]), &history);
+    assert!(html.contains("<h2>Trend (2 runs, run-1 &rarr; run-2)</h2>"));
+    assert_eq!(html.matches("<polyline").count(), 3);
+}
@@ -0,0 +1,86 @@
+//! Unit tests for `mcb_validate::junit_reporter`
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use mcb_domain::ports::ViolationEntry;
+use mcb_validate::{GenericReport, GenericSummary, JunitReporter};
+use rstest::rstest;
+
+fn entry(id: &str, category: &str, severity: &str, line: Option<usize>) -> ViolationEntry {
+    ViolationEntry {
+        id: id.to_owned(),
+        category: category.to_owned(),
+        severity: severity.to_owned(),
+        file: Some("/work/repo/src/lib.rs".to_owned()),
+        line,
+        message: format!("{id} fired"),
+        suggestion: None,
+    }
+}
+
+fn report(entries: Vec<ViolationEntry>) -> GenericReport {
+    let mut violations_by_category: HashMap<String, Vec<ViolationEntry>> = HashMap::new();
+    for entry in entries {
+        violations_by_category
+            .entry(entry.category.clone())
+            .or_default()
+            .push(entry);
+    }
+    GenericReport {
+        timestamp: "2026-10-16 00:00:00 UTC".to_owned(),
+        workspace_root: PathBuf::from("/work/repo"),
+        summary: GenericSummary {
+            total_violations: violations_by_category.values().map(Vec::len).sum(),
+            errors: 0,
+            warnings: 0,
+            infos: 0,
+            by_category: HashMap::new(),
+            passed: true,
+        },
+        violations_by_category,
+    }
+}
+
+#[rstest]
+fn junit_groups_violations_into_one_suite_per_category() {
+    let xml = JunitReporter::to_junit(&report(vec![
+        entry("Q001", "quality", "ERROR", Some(4)),
+        entry("Q002", "quality", "INFO", None),
+        entry("A001", "architecture", "WARNING", Some(1)),
+    ]));
+
+    assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+    assert!(xml.contains("<testsuites name=\"mcb-validate\" tests=\"3\" failures=\"2\">"));
+    let architecture = xml.find("<testsuite name=\"architecture\" tests=\"1\" failures=\"1\"");
+    let quality = xml.find("<testsuite name=\"quality\" tests=\"2\" failures=\"1\"");
+    assert!(architecture.unwrap() < quality.unwrap());
+    assert!(xml.contains(
+        "<testcase classname=\"quality.Q001\" name=\"Q001 src/lib.rs:4\" file=\"src/lib.rs\" line=\"4\">"
+    ));
+    assert!(
+        xml.contains("<failure type=\"Q001\" message=\"Q001 fired\">severity: ERROR</failure>")
+    );
+    assert!(xml.contains("<system-out>Q002 fired\nseverity: INFO</system-out>"));
+    assert_eq!(xml.matches("<failure").count(), 2);
+}
+
+#[rstest]
+fn junit_escapes_xml_special_characters() {
+    let mut tricky = entry("Q003", "quality", "WARNING", Some(1));
+    tricky.message = "expected <T> & \"U\"".to_owned();
+    tricky.suggestion = Some("use Vec<T>".to_owned());
+    let xml = JunitReporter::to_junit(&report(vec![tricky]));
+
+    assert!(xml.contains("message=\"expected &lt;T&gt; &amp; &quot;U&quot;\""));
+    assert!(xml.contains("suggestion: use Vec&lt;T&gt;</failure>"));
+}
+
+#[rstest]
+fn junit_clean_run_records_a_passing_case() {
+    let xml = JunitReporter::to_junit(&report(Vec::new()));
+
+    assert!(xml.contains("<testsuites name=\"mcb-validate\" tests=\"1\" failures=\"0\">"));
+    assert!(xml.contains("<testcase classname=\"mcb-validate\" name=\"workspace\"/>"));
+    assert!(!xml.contains("<failure"));
+}
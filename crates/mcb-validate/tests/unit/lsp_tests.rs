@@ -0,0 +1,186 @@
+//! Unit tests for `mcb_validate::lsp`
+
+use std::fmt;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+use mcb_domain::ports::validation::{Severity, Violation, ViolationCategory};
+use mcb_validate::LspServer;
+use mcb_validate::lsp::{position, read_message, to_diagnostic, uri_to_path, write_message};
+use rstest::rstest;
+use serde_json::{Value, json};
+
+#[derive(Debug)]
+struct LineHit {
+    line: Option<usize>,
+    severity: Severity,
+}
+
+impl fmt::Display for LineHit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "flagged")
+    }
+}
+
+impl Violation for LineHit {
+    fn id(&self) -> &str {
+        "QUAL007"
+    }
+    fn category(&self) -> ViolationCategory {
+        ViolationCategory::Quality
+    }
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+    fn file(&self) -> Option<&PathBuf> {
+        None
+    }
+    fn line(&self) -> Option<usize> {
+        self.line
+    }
+}
+
+fn frame(messages: &[Value]) -> Vec<u8> {
+    let mut input = Vec::new();
+    for message in messages {
+        write_message(&mut input, message).unwrap();
+    }
+    input
+}
+
+fn unframe(output: &[u8]) -> Vec<Value> {
+    let mut reader = Cursor::new(output);
+    std::iter::from_fn(|| read_message(&mut reader).unwrap()).collect()
+}
+
+fn serve(messages: &[Value]) -> Vec<Value> {
+    let workspace = tempfile::tempdir().unwrap();
+    let mut output = Vec::new();
+    LspServer::new(workspace.path(), &mut output)
+        .run(&mut Cursor::new(frame(messages)))
+        .unwrap();
+    unframe(&output)
+}
+
+#[test]
+fn test_framing_round_trips_messages() {
+    let messages = vec![
+        json!({"jsonrpc": "2.0", "id": 1, "method": "é"}),
+        json!(null),
+    ];
+
+    assert_eq!(unframe(&frame(&messages)), messages);
+}
+
+#[test]
+fn test_read_message_requires_content_length() {
+    let mut reader = Cursor::new(b"Content-Type: x\r\n\r\n{}".to_vec());
+
+    assert!(read_message(&mut reader).is_err());
+}
+
+#[rstest]
+#[case("file:///tmp/a%20b/lib.rs", Some("/tmp/a b/lib.rs"))]
+#[case("file:///tmp/100%/lib.rs", Some("/tmp/100%/lib.rs"))]
+#[case("untitled:Untitled-1", None)]
+fn test_uri_to_path(#[case] uri: &str, #[case] expected: Option<&str>) {
+    assert_eq!(uri_to_path(uri), expected.map(PathBuf::from));
+}
+
+#[rstest]
+#[case(0, 0, 0)]
+#[case(4, 1, 0)]
+#[case(9, 1, 3)]
+fn test_position_counts_utf16_columns(
+    #[case] offset: usize,
+    #[case] line: u64,
+    #[case] character: u64,
+) {
+    // "😀" is four bytes in UTF-8 and two code units in UTF-16.
+    let content = "abc\n😀x\n";
+
+    assert_eq!(
+        position(content, offset),
+        json!({ "line": line, "character": character })
+    );
+}
+
+#[rstest]
+#[case(Severity::Error, 1)]
+#[case(Severity::Warning, 2)]
+#[case(Severity::Info, 3)]
+fn test_diagnostic_spans_flagged_line(#[case] severity: Severity, #[case] expected: u64) {
+    let hit = LineHit {
+        line: Some(2),
+        severity,
+    };
+
+    let diagnostic = to_diagnostic(&hit, "fn a() {}\nlet x = 1;\n");
+
+    assert_eq!(
+        diagnostic["range"]["start"],
+        json!({"line": 1, "character": 0})
+    );
+    assert_eq!(
+        diagnostic["range"]["end"],
+        json!({"line": 1, "character": 10})
+    );
+    assert_eq!(diagnostic["severity"], expected);
+    assert_eq!(diagnostic["code"], "QUAL007");
+    assert_eq!(diagnostic["source"], "mcb-validate");
+}
+
+#[test]
+fn test_diagnostic_without_line_uses_first_line() {
+    let hit = LineHit {
+        line: None,
+        severity: Severity::Warning,
+    };
+
+    let diagnostic = to_diagnostic(&hit, "");
+
+    assert_eq!(
+        diagnostic["range"]["end"],
+        json!({"line": 0, "character": 0})
+    );
+}
+
+#[test]
+fn test_server_lifecycle() {
+    let replies = serve(&[
+        json!({"jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {}}),
+        json!({"jsonrpc": "2.0", "method": "initialized", "params": {}}),
+        json!({"jsonrpc": "2.0", "id": 2, "method": "workspace/symbol", "params": {}}),
+        json!({"jsonrpc": "2.0", "id": 3, "method": "shutdown"}),
+        json!({"jsonrpc": "2.0", "method": "exit"}),
+        json!({"jsonrpc": "2.0", "id": 4, "method": "shutdown"}),
+    ]);
+
+    assert_eq!(replies.len(), 3);
+    let capabilities = &replies[0]["result"]["capabilities"];
+    assert_eq!(capabilities["textDocumentSync"]["save"], true);
+    assert_eq!(capabilities["codeActionProvider"], true);
+    assert_eq!(replies[1]["error"]["code"], -32601);
+    assert_eq!(
+        replies[2],
+        json!({"jsonrpc": "2.0", "id": 3, "result": null})
+    );
+}
+
+#[test]
+fn test_server_clears_diagnostics_on_close() {
+    let uri = "file:///tmp/closed.rs";
+
+    let replies = serve(&[
+        json!({"jsonrpc": "2.0", "method": "textDocument/didClose", "params": {"textDocument": {"uri": uri}}}),
+        json!({"jsonrpc": "2.0", "id": 1, "method": "textDocument/codeAction", "params": {
+            "textDocument": {"uri": uri},
+            "range": {"start": {"line": 0, "character": 0}, "end": {"line": 0, "character": 0}},
+            "context": {"diagnostics": []},
+        }}),
+    ]);
+
+    assert_eq!(replies[0]["method"], "textDocument/publishDiagnostics");
+    assert_eq!(replies[0]["params"], json!({"uri": uri, "diagnostics": []}));
+    assert_eq!(replies[1]["result"], json!([]));
+}
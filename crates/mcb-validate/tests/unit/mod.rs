@@ -13,7 +13,19 @@ pub mod scan;
 pub mod util_tests;
 pub mod validators;
 
+mod api_changelog_tests;
+mod baseline_tests;
+mod config_schema_tests;
 mod declarative_validator_tests;
+mod dependency_diagram_tests;
 mod embedded_rules_tests;
+mod fact_cache_tests;
+mod fix_tests;
+mod github_reporter_tests;
+mod html_reporter_tests;
+mod junit_reporter_tests;
 mod lib_tests;
+mod lsp_tests;
 mod run_context_tests;
+mod sarif_reporter_tests;
+mod suppression_tests;
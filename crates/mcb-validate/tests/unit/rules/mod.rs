@@ -1,3 +1,4 @@
 //! Unit tests.
 
-// No files moved here yet
+/// Rule plugin tests.
+pub mod plugins_tests;
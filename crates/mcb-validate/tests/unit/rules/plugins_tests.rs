@@ -0,0 +1,146 @@
+//! Unit tests for `mcb_validate::rules::plugins`
+
+use std::path::{Path, PathBuf};
+
+use mcb_validate::rules::{is_valid_namespace, load_rule_plugins, merge_plugin_rules};
+use mcb_validate::{EmbeddedRules, FileConfig, RulePluginConfig, YamlRuleLoader};
+use rstest::rstest;
+use tempfile::TempDir;
+
+fn rule_yaml(id: &str) -> String {
+    format!(
+        r#"
+schema: "rule/v2"
+id: {id}
+name: Legacy client call
+category: quality
+severity: warning
+description: "Flags calls to the legacy client from application code"
+rationale: "The legacy client is being retired in favour of the new API"
+engine: regex
+rule:
+  type: regex_scan
+config:
+  patterns:
+    legacy_client: 'legacy_client\('
+"#
+    )
+}
+
+fn plugin_dir(root: &Path, name: &str, ids: &[&str]) -> PathBuf {
+    let dir = root.join(name);
+    std::fs::create_dir_all(&dir).unwrap();
+    for id in ids {
+        std::fs::write(dir.join(format!("{id}.yml")), rule_yaml(id)).unwrap();
+    }
+    dir
+}
+
+fn plugin(path: &str, namespace: Option<&str>) -> RulePluginConfig {
+    RulePluginConfig {
+        path: PathBuf::from(path),
+        namespace: namespace.map(str::to_owned),
+    }
+}
+
+#[rstest]
+#[case("ACME", true)]
+#[case("team-b_2", true)]
+#[case("", false)]
+#[case("1ACME", false)]
+#[case("AC::ME", false)]
+fn test_is_valid_namespace(#[case] namespace: &str, #[case] expected: bool) {
+    assert_eq!(is_valid_namespace(namespace), expected);
+}
+
+#[test]
+fn test_namespace_prefixes_rule_ids() {
+    let temp = TempDir::new().unwrap();
+    plugin_dir(temp.path(), "rules-acme", &["SEC001"]);
+
+    let loaded =
+        load_rule_plugins(temp.path(), &[plugin("rules-acme", Some("ACME"))], None).unwrap();
+
+    assert_eq!(loaded.len(), 1);
+    let ids: Vec<&str> = loaded[0].1.iter().map(|r| r.id.as_str()).collect();
+    assert_eq!(ids, ["ACME::SEC001"]);
+}
+
+#[rstest]
+#[case(plugin("missing", None), "not found")]
+#[case(plugin("rules-acme", Some("AC ME")), "invalid rule plugin namespace")]
+fn test_invalid_plugin_is_rejected(#[case] config: RulePluginConfig, #[case] expected: &str) {
+    let temp = TempDir::new().unwrap();
+    plugin_dir(temp.path(), "rules-acme", &["SEC001"]);
+
+    let error = load_rule_plugins(temp.path(), &[config], None).unwrap_err();
+
+    assert!(error.to_string().contains(expected), "{error}");
+}
+
+#[rstest]
+#[case(None, true)]
+#[case(Some("ACME"), false)]
+fn test_plugin_conflicts_with_builtin_rule(
+    #[case] namespace: Option<&str>,
+    #[case] conflicts: bool,
+) {
+    let temp = TempDir::new().unwrap();
+    plugin_dir(temp.path(), "rules-acme", &["QUAL007"]);
+    let builtin = YamlRuleLoader::from_embedded(&EmbeddedRules::all_yaml())
+        .unwrap()
+        .load_embedded_rules()
+        .unwrap();
+    let builtin_count = builtin.len();
+    let plugins = load_rule_plugins(temp.path(), &[plugin("rules-acme", namespace)], None).unwrap();
+
+    let merged = merge_plugin_rules(builtin, plugins);
+
+    if conflicts {
+        let error = merged.unwrap_err().to_string();
+        assert!(error.contains("conflicts with a built-in rule"), "{error}");
+    } else {
+        assert_eq!(merged.unwrap().len(), builtin_count + 1);
+    }
+}
+
+#[test]
+fn test_plugins_conflict_with_each_other() {
+    let temp = TempDir::new().unwrap();
+    plugin_dir(temp.path(), "first", &["TEAM001"]);
+    plugin_dir(temp.path(), "second", &["TEAM001"]);
+    let plugins = load_rule_plugins(
+        temp.path(),
+        &[plugin("first", None), plugin("second", None)],
+        None,
+    )
+    .unwrap();
+
+    let error = merge_plugin_rules(Vec::new(), plugins)
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("first"), "{error}");
+}
+
+#[test]
+fn test_project_config_declares_plugins() {
+    let temp = TempDir::new().unwrap();
+    std::fs::write(
+        temp.path().join(".mcb-validate.toml"),
+        "[[plugins.rule_dirs]]\npath = \"rules-acme\"\nnamespace = \"ACME\"\n",
+    )
+    .unwrap();
+
+    let config = FileConfig::load(temp.path());
+
+    assert_eq!(config.plugins.rule_dirs.len(), 1);
+    assert_eq!(
+        config.plugins.rule_dirs[0].path,
+        PathBuf::from("rules-acme")
+    );
+    assert_eq!(
+        config.plugins.rule_dirs[0].namespace.as_deref(),
+        Some("ACME")
+    );
+}
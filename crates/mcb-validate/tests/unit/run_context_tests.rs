@@ -73,3 +73,68 @@ fn git_inventory_uses_git_source_when_repository_exists() {
             .any(|entry| entry.relative_path == std::path::Path::new("src/lib.rs"))
     );
 }
+
+#[rstest]
+fn changed_files_limit_scope_and_reported_violations() {
+    use mcb_domain::ports::validation::{Severity, Violation};
+    use mcb_validate::HygieneViolation;
+
+    let temp = TempDir::new().expect("tempdir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    std::fs::write(root.join("src/lib.rs"), "pub fn ok() {}\n").expect("write lib");
+    std::fs::write(root.join("src/old.rs"), "pub fn old() {}\n").expect("write old");
+
+    let config = ValidationConfig::new(root)
+        .with_changed_files(vec!["src/lib.rs".into(), "src/deleted.rs".into()]);
+    let context = ValidationRunContext::build(&config).expect("context");
+    let canonical_root = std::fs::canonicalize(root).expect("canonical root");
+
+    assert!(context.is_incremental());
+    assert!(context.in_scope(&canonical_root.join("src/lib.rs")));
+    assert!(!context.in_scope(&canonical_root.join("src/old.rs")));
+    assert!(
+        context
+            .unscoped()
+            .in_scope(&canonical_root.join("src/old.rs"))
+    );
+
+    let at = |file: &str| -> Box<dyn Violation> {
+        HygieneViolation::InlineTestModule {
+            file: root.join(file),
+            line: 1,
+            severity: Severity::Warning,
+        }
+        .boxed()
+    };
+    let kept = context.retain_in_scope(vec![at("src/lib.rs"), at("src/old.rs")]);
+    assert_eq!(kept.len(), 1);
+    assert!(kept[0].file().is_some_and(|f| f.ends_with("src/lib.rs")));
+}
+
+#[rstest]
+fn preload_caches_in_scope_sources_for_read_source() {
+    use std::sync::Arc;
+
+    let temp = TempDir::new().expect("tempdir");
+    let root = temp.path();
+    std::fs::create_dir_all(root.join("src")).expect("create src");
+    std::fs::write(root.join("src/lib.rs"), "pub fn before() {}\n").expect("write lib");
+    std::fs::write(root.join("src/old.rs"), "pub fn old() {}\n").expect("write old");
+
+    let config = ValidationConfig::new(root).with_changed_files(vec!["src/lib.rs".into()]);
+    let context = Arc::new(ValidationRunContext::build(&config).expect("context"));
+    context.preload();
+
+    std::fs::write(root.join("src/lib.rs"), "pub fn after() {}\n").expect("rewrite lib");
+    std::fs::write(root.join("src/old.rs"), "pub fn newer() {}\n").expect("rewrite old");
+
+    let (lib, old) = ValidationRunContext::with_active(&context, || {
+        (
+            mcb_validate::scan::read_source(&root.join("src/lib.rs")).expect("read lib"),
+            mcb_validate::scan::read_source(&root.join("src/old.rs")).expect("read old"),
+        )
+    });
+    assert_eq!(lib, "pub fn before() {}\n");
+    assert_eq!(old, "pub fn newer() {}\n");
+}
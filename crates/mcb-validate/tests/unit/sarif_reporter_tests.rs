@@ -0,0 +1,118 @@
+//! Unit tests for `mcb_validate::sarif_reporter`
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use mcb_domain::ports::ViolationEntry;
+use mcb_validate::{GenericReport, GenericSummary, SarifReporter};
+use rstest::rstest;
+
+fn entry(id: &str, severity: &str, file: Option<&str>, line: Option<usize>) -> ViolationEntry {
+    ViolationEntry {
+        id: id.to_owned(),
+        category: "quality".to_owned(),
+        severity: severity.to_owned(),
+        file: file.map(str::to_owned),
+        line,
+        message: format!("{id} fired"),
+        suggestion: None,
+    }
+}
+
+fn report(entries: Vec<ViolationEntry>) -> GenericReport {
+    GenericReport {
+        timestamp: "2026-10-16 00:00:00 UTC".to_owned(),
+        workspace_root: PathBuf::from("/work/repo"),
+        summary: GenericSummary {
+            total_violations: entries.len(),
+            errors: 0,
+            warnings: 0,
+            infos: 0,
+            by_category: HashMap::new(),
+            passed: true,
+        },
+        violations_by_category: HashMap::from([("quality".to_owned(), entries)]),
+    }
+}
+
+#[rstest]
+fn sarif_log_lists_each_rule_once_with_metadata() {
+    let log = SarifReporter::create_log(&report(vec![
+        entry("DUP001", "WARNING", Some("/work/repo/src/a.rs"), Some(3)),
+        entry("DUP001", "WARNING", Some("/work/repo/src/b.rs"), Some(7)),
+        entry("CUSTOM9", "ERROR", Some("/work/repo/src/c.rs"), None),
+    ]));
+    let json = serde_json::to_value(&log).unwrap();
+
+    assert_eq!(json["version"], "2.1.0");
+    let run = &json["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "mcb-validate");
+    assert_eq!(
+        run["originalUriBaseIds"]["%SRCROOT%"]["uri"],
+        "file:///work/repo/"
+    );
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0]["id"], "DUP001");
+    assert!(
+        rules[0]["shortDescription"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Type 1 clones")
+    );
+    assert_eq!(rules[0]["defaultConfiguration"]["level"], "warning");
+    assert_eq!(rules[1]["id"], "CUSTOM9");
+    assert_eq!(rules[1]["properties"]["category"], "quality");
+
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[1]["ruleIndex"], 0);
+    assert_eq!(results[2]["ruleIndex"], 1);
+    assert_eq!(results[2]["level"], "error");
+}
+
+#[rstest]
+#[case(
+    Some("/work/repo/src/my file.rs"),
+    Some(12),
+    Some("src/my%20file.rs"),
+    Some(12)
+)]
+#[case(
+    Some("/elsewhere/x.rs"),
+    Some(1),
+    Some("file:///elsewhere/x.rs"),
+    Some(1)
+)]
+#[case(Some("/work/repo/Cargo.toml"), Some(0), Some("Cargo.toml"), None)]
+#[case(None, None, None, None)]
+fn sarif_locations_are_relative_to_the_workspace(
+    #[case] file: Option<&str>,
+    #[case] line: Option<usize>,
+    #[case] uri: Option<&str>,
+    #[case] start_line: Option<u64>,
+) {
+    let log = SarifReporter::create_log(&report(vec![entry("X001", "INFO", file, line)]));
+    let json = serde_json::to_value(&log).unwrap();
+    let result = &json["runs"][0]["results"][0];
+
+    assert_eq!(result["level"], "note");
+    let location = &result["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"].as_str(), uri);
+    assert_eq!(location["region"]["startLine"].as_u64(), start_line);
+}
+
+#[rstest]
+fn sarif_message_includes_the_suggestion() {
+    let mut with_fix = entry("X002", "WARNING", None, None);
+    with_fix.suggestion = Some("use ? instead".to_owned());
+    let sarif = SarifReporter::to_sarif(&report(vec![with_fix]));
+    let json: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+
+    assert_eq!(
+        json["runs"][0]["results"][0]["message"]["text"],
+        "X002 fired (use ? instead)"
+    );
+    assert!(json["runs"][0]["results"][0].get("locations").is_none());
+}
@@ -0,0 +1,98 @@
+//! Unit tests for `mcb_validate::suppression`
+
+use std::path::Path;
+
+use mcb_domain::ports::validation::Severity;
+use mcb_validate::{
+    HygieneViolation, SuppressionParser, Violation, ViolationCategory, apply_suppressions,
+};
+use rstest::rstest;
+
+fn inline_test_at(file: &Path, line: usize) -> Box<dyn Violation> {
+    HygieneViolation::InlineTestModule {
+        file: file.to_path_buf(),
+        line,
+        severity: Severity::Warning,
+    }
+    .boxed()
+}
+
+#[rstest]
+#[case("x(); // mcb-allow(TEST001): fixture module kept inline", &["TEST001"], "fixture module kept inline")]
+#[case("# mcb-allow(TEST001, QUAL002) - generated by the build script", &["TEST001", "QUAL002"], "generated by the build script")]
+#[case("/* mcb-allow(TEST001): legacy parser, tracked in #42 */", &["TEST001"], "legacy parser, tracked in #42")]
+#[case("// mcb-allow(TEST001)", &["TEST001"], "")]
+fn parses_rule_ids_and_justification(
+    #[case] line: &str,
+    #[case] rule_ids: &[&str],
+    #[case] justification: &str,
+) {
+    let parsed = SuppressionParser::new()
+        .unwrap()
+        .parse(&format!("fn a() {{}}\n{line}\n"));
+
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].line, 2);
+    assert_eq!(parsed[0].rule_ids, rule_ids);
+    assert_eq!(parsed[0].justification, justification);
+}
+
+#[rstest]
+fn justified_suppressions_cover_their_line_and_the_next() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(
+        &file,
+        "// mcb-allow(TEST001): doc examples need a private helper\nmod tests {}\n\nmod more_tests {}\n",
+    )
+    .unwrap();
+
+    let outcome = apply_suppressions(
+        vec![
+            inline_test_at(&file, 1),
+            inline_test_at(&file, 2),
+            inline_test_at(&file, 4),
+        ],
+        dir.path(),
+    )
+    .unwrap();
+
+    assert_eq!(outcome.suppressed, 2);
+    assert_eq!(outcome.violations.len(), 1);
+    assert_eq!(outcome.violations[0].line(), Some(4));
+}
+
+#[rstest]
+fn unjustified_suppressions_keep_the_violation_and_report_supp001() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("lib.rs"),
+        "mod tests {} // mcb-allow(TEST001): ok\n",
+    )
+    .unwrap();
+
+    let outcome =
+        apply_suppressions(vec![inline_test_at(Path::new("lib.rs"), 1)], dir.path()).unwrap();
+
+    assert_eq!(outcome.suppressed, 0);
+    let ids: Vec<&str> = outcome.violations.iter().map(|v| v.id()).collect();
+    assert_eq!(ids, ["TEST001", "SUPP001"]);
+    assert_eq!(outcome.violations[1].category(), ViolationCategory::Quality);
+    assert!(outcome.violations[1].message().contains("TEST001"));
+}
+
+#[rstest]
+fn suppressions_for_other_rules_do_not_apply() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("lib.rs");
+    std::fs::write(
+        &file,
+        "mod tests {} // mcb-allow(QUAL001): unrelated reason here\n",
+    )
+    .unwrap();
+
+    let outcome = apply_suppressions(vec![inline_test_at(&file, 1)], dir.path()).unwrap();
+
+    assert_eq!(outcome.suppressed, 0);
+    assert_eq!(outcome.violations.len(), 1);
+}
@@ -0,0 +1,190 @@
+//! Unit tests for `mcb_validate::validators::adr`
+
+use std::path::Path;
+
+use mcb_domain::ports::validation::{ValidationConfig, Violation};
+use mcb_validate::config::AdrRulesConfig;
+use mcb_validate::{AdrComplianceRules, AdrDocument, AdrValidator};
+use rstest::rstest;
+use tempfile::TempDir;
+
+const COMPLIANT: &str = "\
+<!-- markdownlint-disable MD013 -->
+---
+adr: 7
+title: Use Postgres
+status: ACCEPTED
+---
+
+# ADR 007: Use Postgres
+
+## Status
+
+**Accepted** (v0.2.0)
+
+## Context
+
+We need a database.
+
+```markdown
+## Decision inside a code block
+```
+
+## Decision
+
+Use Postgres.
+
+## Consequences
+
+Operations must run Postgres.
+
+## Alternatives Considered
+
+SQLite.
+";
+
+const BROKEN: &str = "\
+# ADR 12: Cache everything
+
+## Status
+
+> Note: revisit after the benchmark.
+
+Pondering
+
+## Decision
+
+Cache everything.
+";
+
+fn document(file_name: &str, content: &str) -> AdrDocument {
+    AdrDocument::parse(Path::new(file_name), content)
+}
+
+fn ids(violations: &[impl Violation]) -> Vec<(String, usize)> {
+    let mut found: Vec<(String, usize)> = violations
+        .iter()
+        .map(|violation| (violation.id().to_owned(), violation.line().unwrap_or(0)))
+        .collect();
+    found.sort();
+    found
+}
+
+#[rstest]
+fn test_parse_reads_front_matter_title_and_sections() {
+    let adr = document("007-use-postgres.md", COMPLIANT);
+
+    assert_eq!(adr.number, Some(7));
+    assert_eq!(adr.title_number, Some(7));
+    assert_eq!(adr.title_line, Some(8));
+    assert_eq!(adr.status.as_deref(), Some("ACCEPTED"));
+    assert_eq!(adr.status_line, Some(5));
+    assert_eq!(
+        adr.front_matter.get("title").map(String::as_str),
+        Some("Use Postgres")
+    );
+    let sections: Vec<&str> = adr.sections.iter().map(|s| s.title.as_str()).collect();
+    assert_eq!(
+        sections,
+        [
+            "Status",
+            "Context",
+            "Decision",
+            "Consequences",
+            "Alternatives Considered"
+        ]
+    );
+}
+
+#[rstest]
+#[case("## Status\n\n**Proposed**\n", Some("Proposed"))]
+#[case(
+    "## Status\n\n> Draft note\n\nSuperseded by ADR-024\n",
+    Some("Superseded by ADR-024")
+)]
+#[case("**Status**: Rejected\n", Some("Rejected"))]
+#[case("## Status\n\n## Context\n", None)]
+fn test_parse_status_sources(#[case] content: &str, #[case] expected: Option<&str>) {
+    let adr = document("001-x.md", content);
+
+    assert_eq!(adr.status.as_deref(), expected);
+}
+
+#[rstest]
+fn test_embedded_rules_accept_compliant_adr() {
+    let rules = AdrComplianceRules::embedded().unwrap();
+
+    let violations = rules
+        .check(&[document("007-use-postgres.md", COMPLIANT)])
+        .unwrap();
+
+    assert!(violations.is_empty(), "{violations:?}");
+}
+
+#[rstest]
+fn test_embedded_rules_report_broken_adr() {
+    let rules = AdrComplianceRules::embedded().unwrap();
+
+    let violations = rules
+        .check(&[
+            document("007-use-postgres.md", COMPLIANT),
+            document("7_Cache.md", BROKEN),
+        ])
+        .unwrap();
+
+    assert_eq!(
+        ids(&violations),
+        [
+            ("ADR001".to_owned(), 0),
+            ("ADR002".to_owned(), 1),
+            ("ADR003".to_owned(), 7),
+            ("ADR004".to_owned(), 0),
+            ("ADR008".to_owned(), 0),
+            ("ADR008".to_owned(), 0),
+        ]
+    );
+}
+
+#[rstest]
+#[case("schema: \"adr-compliance/v2\"\nrules: []\n")]
+#[case(
+    "schema: \"adr-compliance/v1\"\nrules:\n  - id: X1\n    description: d\n    severity: ERROR\n    check: file_name\n    pattern: \"[\"\n"
+)]
+#[case(
+    "schema: \"adr-compliance/v1\"\nrules:\n  - id: X1\n    description: d\n    severity: ERROR\n    check: spelling\n"
+)]
+fn test_from_yaml_rejects_invalid_rule_sets(#[case] yaml: &str) {
+    assert!(AdrComplianceRules::from_yaml(yaml, Path::new("rules.yml")).is_err());
+}
+
+#[rstest]
+#[case("", &["ADR001", "ADR004"])]
+#[case("adr-rules.yml", &["TEAM001"])]
+fn test_validate_all_uses_configured_rules(#[case] rules_file: &str, #[case] expected: &[&str]) {
+    let temp = TempDir::new().unwrap();
+    let adr_dir = temp.path().join("docs/adr");
+    std::fs::create_dir_all(&adr_dir).unwrap();
+    std::fs::write(adr_dir.join("README.md"), "# ADRs\n").unwrap();
+    std::fs::write(
+        adr_dir.join("01-cache.md"),
+        "# ADR 1: Cache\n\n## Status\n\nProposed\n\n## Decision\n\nCache.\n",
+    )
+    .unwrap();
+    std::fs::write(
+        temp.path().join("adr-rules.yml"),
+        "schema: \"adr-compliance/v1\"\nrules:\n  - id: TEAM001\n    description: ADRs need an owner\n    severity: WARNING\n    check: required_section\n    section: Owner\n",
+    )
+    .unwrap();
+
+    let validator = AdrValidator::with_config(&AdrRulesConfig {
+        enabled: true,
+        adr_dir: "docs/adr".to_owned(),
+        rules_file: rules_file.to_owned(),
+    });
+    let violations = validator
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+
+    let found: Vec<String> = ids(&violations).into_iter().map(|(id, _)| id).collect();
+    assert_eq!(found, expected);
+}
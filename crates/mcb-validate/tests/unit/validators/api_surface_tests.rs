@@ -0,0 +1,217 @@
+//! Unit tests for `mcb_validate::validators::api_surface`
+
+use mcb_domain::ports::validation::{Severity, ValidationConfig, Violation};
+use mcb_utils::constants::validate::DEFAULT_API_SNAPSHOT_FILE;
+use mcb_validate::{ApiScanner, ApiSnapshot, ApiSurfaceValidator};
+use rstest::rstest;
+
+use crate::utils::with_inline_crate;
+
+const LIB: &str = r#"
+pub mod shapes {
+    pub struct Circle {
+        pub radius: f64,
+    }
+
+    impl Circle {
+        pub fn area(&self) -> f64 {
+            let inner = "}";
+            inner.len() as f64
+        }
+
+        fn private(&self) {}
+    }
+}
+
+pub trait Render {
+    fn render(&self,
+        scale: f64) -> String;
+}
+
+pub const LIMIT: usize = 10;
+pub(crate) fn internal() {}
+
+pub use shapes::{
+    Circle,
+};
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+}
+"#;
+
+fn scanned_paths(content: &str) -> Vec<(String, String)> {
+    ApiScanner::new()
+        .unwrap()
+        .scan(content)
+        .into_iter()
+        .map(|d| (d.path, d.item.signature))
+        .collect()
+}
+
+#[test]
+fn test_scan_records_public_items_with_paths() {
+    let items = scanned_paths(LIB);
+
+    assert_eq!(
+        items,
+        vec![
+            ("shapes".to_owned(), "pub mod shapes".to_owned()),
+            ("shapes::Circle".to_owned(), "pub struct Circle".to_owned()),
+            (
+                "shapes::Circle::area".to_owned(),
+                "pub fn area(&self) -> f64".to_owned()
+            ),
+            ("Render".to_owned(), "pub trait Render".to_owned()),
+            (
+                "Render::render".to_owned(),
+                "fn render(&self, scale: f64) -> String".to_owned()
+            ),
+            ("LIMIT".to_owned(), "pub const LIMIT: usize".to_owned()),
+            (
+                "shapes::{ Circle, }".to_owned(),
+                "pub use shapes::{ Circle, }".to_owned()
+            ),
+        ]
+    );
+}
+
+#[rstest]
+#[case("pub fn f(buf: [u8; 4]) -> u8 {", "pub fn f(buf: [u8; 4]) -> u8")]
+#[case("pub static NAME: &str = \"x\";", "pub static NAME: &str")]
+#[case("pub struct Id(pub u64);", "pub struct Id(pub u64)")]
+fn test_scan_normalizes_signatures(#[case] source: &str, #[case] expected: &str) {
+    let items = scanned_paths(source);
+
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0].1, expected);
+}
+
+const TRAIT_IMPLS: &str = r#"
+#[derive(
+    Debug,
+    Clone,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum Shape {
+    Circle,
+}
+
+/// Renders shapes.
+#[derive(serde::Serialize)]
+pub struct Canvas;
+
+impl fmt::Display for Canvas {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+impl<'a> From<&'a str> for Shape {
+    fn from(_: &'a str) -> Self {
+        Self::Circle
+    }
+}
+
+impl Canvas {
+    pub fn draw(&self) {}
+}
+"#;
+
+#[test]
+fn test_scan_records_written_and_derived_trait_impls() {
+    let items = scanned_paths(TRAIT_IMPLS);
+
+    let expected = [
+        ("Shape", "pub enum Shape"),
+        ("Shape::<impl Debug>", "impl Debug for Shape"),
+        ("Shape::<impl Clone>", "impl Clone for Shape"),
+        ("Canvas", "pub struct Canvas"),
+        ("Canvas::<impl Serialize>", "impl Serialize for Canvas"),
+        ("Canvas::<impl Display>", "impl Display for Canvas"),
+        (
+            "Shape::<impl From<&'a str>>",
+            "impl From<&'a str> for Shape",
+        ),
+        ("Canvas::draw", "pub fn draw(&self)"),
+    ];
+    assert_eq!(
+        items,
+        expected
+            .iter()
+            .map(|(path, signature)| ((*path).to_owned(), (*signature).to_owned()))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_snapshot_skips_impls_of_private_types() {
+    let (_temp, root) = with_inline_crate(
+        "widgets",
+        "struct Hidden;\n\nimpl Clone for Hidden {\n    fn clone(&self) -> Self {\n        Hidden\n    }\n}\n\n#[derive(Debug)]\npub struct Shown;\n",
+    );
+
+    let snapshot = ApiSnapshot::collect(&ValidationConfig::new(&root)).unwrap();
+
+    let paths: Vec<&str> = snapshot.crates["widgets"]
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(paths, ["Shown", "Shown::<impl Debug>"]);
+}
+
+#[test]
+fn test_validator_is_silent_without_snapshot() {
+    let (_temp, root) = with_inline_crate("widgets", LIB);
+
+    let violations = ApiSurfaceValidator::new(&root)
+        .validate_all(&ValidationConfig::new(&root))
+        .unwrap();
+
+    assert!(violations.is_empty());
+}
+
+#[test]
+fn test_blessed_snapshot_reports_drift() {
+    let (_temp, root) = with_inline_crate("widgets", "pub fn keep() {}\npub fn gone() {}\n");
+    let config = ValidationConfig::new(&root);
+    ApiSnapshot::collect(&config)
+        .unwrap()
+        .save(&root.join(DEFAULT_API_SNAPSHOT_FILE))
+        .unwrap();
+    assert!(
+        ApiSurfaceValidator::new(&root)
+            .validate_all(&config)
+            .unwrap()
+            .is_empty()
+    );
+
+    let lib = root.join("crates/widgets/src/lib.rs");
+    std::fs::write(&lib, "pub fn keep(flag: bool) {}\npub fn added() {}\n").unwrap();
+    let violations = ApiSurfaceValidator::new(&root)
+        .validate_all(&config)
+        .unwrap();
+
+    let mut found: Vec<(&str, Severity, String)> = violations
+        .iter()
+        .map(|v| (v.id(), v.severity(), v.message()))
+        .collect();
+    found.sort_by(|a, b| a.0.cmp(b.0));
+    assert_eq!(found.len(), 3);
+    assert_eq!((found[0].0, found[0].1), ("API001", Severity::Info));
+    assert!(found[0].2.contains("added"));
+    assert_eq!((found[1].0, found[1].1), ("API002", Severity::Error));
+    assert!(found[1].2.contains("gone"));
+    assert_eq!((found[2].0, found[2].1), ("API003", Severity::Error));
+    assert!(found[2].2.contains("pub fn keep(flag: bool)"));
+}
+
+#[test]
+fn test_snapshot_rejects_unknown_version() {
+    let temp = tempfile::tempdir().unwrap();
+    let path = temp.path().join(DEFAULT_API_SNAPSHOT_FILE);
+    std::fs::write(&path, r#"{"version": 99, "crates": {}}"#).unwrap();
+
+    assert!(ApiSnapshot::load(&path).is_err());
+}
@@ -0,0 +1,133 @@
+//! Unit tests for `mcb_validate::validators::complexity_delta`
+
+use std::path::PathBuf;
+
+use mcb_domain::ports::validation::{ValidationConfig, Violation};
+use mcb_utils::constants::validate::DEFAULT_COMPLEXITY_BASELINE_FILE;
+use mcb_validate::config::ComplexityDeltaRulesConfig;
+use mcb_validate::{
+    ComplexityBaseline, ComplexityDeltaValidator, ComplexityEntry, FunctionComplexity,
+};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate;
+
+const SIMPLE: &str = r"
+pub fn classify(value: i32) -> i32 {
+    if value > 0 {
+        return 1;
+    }
+    0
+}
+
+pub fn untouched() -> i32 {
+    7
+}
+";
+
+const BRANCHY: &str = r"
+pub fn classify(value: i32) -> i32 {
+    if value > 0 {
+        if value > 10 {
+            return 2;
+        }
+        return 1;
+    } else if value < -10 {
+        return -2;
+    }
+    0
+}
+
+pub fn untouched() -> i32 {
+    7
+}
+
+pub fn added(value: i32) -> i32 {
+    if value > 0 { if value > 1 { if value > 2 { return 3; } } }
+    0
+}
+";
+
+fn rules(max_cyclomatic_increase: u32, max_cognitive_increase: u32) -> ComplexityDeltaRulesConfig {
+    ComplexityDeltaRulesConfig {
+        enabled: true,
+        max_cyclomatic_increase,
+        max_cognitive_increase,
+    }
+}
+
+fn entry(function: &str, cyclomatic: u32, cognitive: u32) -> ComplexityEntry {
+    ComplexityEntry {
+        file: "crates/app/src/lib.rs".to_owned(),
+        function: function.to_owned(),
+        complexity: FunctionComplexity {
+            cyclomatic,
+            cognitive,
+        },
+        path: PathBuf::from("crates/app/src/lib.rs"),
+        line: 1,
+    }
+}
+
+#[rstest]
+#[case(rules(0, 0), entry("run", 5, 4), vec![])]
+#[case(rules(0, 0), entry("run", 6, 4), vec!["CPLX001"])]
+#[case(rules(1, 0), entry("run", 6, 5), vec!["CPLX002"])]
+#[case(rules(2, 2), entry("run", 7, 6), vec![])]
+#[case(rules(0, 0), entry("run", 3, 1), vec![])]
+#[case(rules(0, 0), entry("fresh", 40, 40), vec![])]
+fn test_regressions_respect_allowed_increase(
+    #[case] rules: ComplexityDeltaRulesConfig,
+    #[case] current: ComplexityEntry,
+    #[case] expected: Vec<&str>,
+) {
+    let baseline = ComplexityBaseline::from_entries(&[entry("run", 5, 4)]);
+    let violations = baseline.regressions(&[current], &rules);
+
+    let ids: Vec<&str> = violations.iter().map(Violation::id).collect();
+    assert_eq!(ids, expected);
+}
+
+#[test]
+fn test_validate_all_reports_only_grown_functions() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "app", SIMPLE);
+    let config = ValidationConfig::new(temp.path());
+    let validator = ComplexityDeltaValidator::with_config(&rules(0, 0));
+
+    assert!(validator.validate_all(&config).unwrap().is_empty());
+
+    let baseline = ComplexityBaseline::collect(&config).unwrap();
+    assert_eq!(baseline.function_count(), 2);
+    baseline
+        .save(&temp.path().join(DEFAULT_COMPLEXITY_BASELINE_FILE))
+        .unwrap();
+    std::fs::write(temp.path().join("crates/app/src/lib.rs"), BRANCHY).unwrap();
+
+    let violations = validator
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+    assert!(!violations.is_empty());
+    for violation in &violations {
+        assert!(
+            violation.message().contains("of classify rose from"),
+            "{violation:?}"
+        );
+        assert_eq!(violation.line(), Some(2));
+    }
+}
+
+#[test]
+fn test_load_rejects_other_versions() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join(DEFAULT_COMPLEXITY_BASELINE_FILE);
+    std::fs::write(&path, r#"{"version": 99, "files": {}}"#).unwrap();
+
+    let error = ComplexityBaseline::load(&path).unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("unsupported complexity baseline version 99")
+    );
+}
@@ -0,0 +1,148 @@
+//! Unit tests for `mcb_validate::validators::cycles`
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+
+use mcb_domain::ports::validation::{ValidationConfig, Violation};
+use mcb_validate::graph::cycle_paths;
+use mcb_validate::validators::cycles::expand_use_tree;
+use mcb_validate::{CycleValidator, ModuleGraph};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::{cargo_toml_with_deps, create_test_crate, create_test_crate_with_file};
+
+fn graph(edges: &[(&str, &str)]) -> BTreeMap<String, BTreeSet<String>> {
+    let mut graph: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+    for (source, target) in edges {
+        graph
+            .entry((*source).to_owned())
+            .or_default()
+            .insert((*target).to_owned());
+    }
+    graph
+}
+
+fn module_edges(files: &[(&str, &str)]) -> Vec<(String, String)> {
+    let graph = ModuleGraph::build(
+        files
+            .iter()
+            .map(|(module, content)| ((*module).to_owned(), PathBuf::from(module), *content)),
+    )
+    .unwrap();
+    graph
+        .edges
+        .into_iter()
+        .flat_map(|(source, targets)| targets.into_iter().map(move |t| (source.clone(), t)))
+        .collect()
+}
+
+#[test]
+fn test_cycle_paths_reports_one_path_per_component() {
+    let edges = graph(&[
+        ("a", "b"),
+        ("b", "c"),
+        ("c", "a"),
+        ("c", "b"),
+        ("d", "d"),
+        ("e", "a"),
+    ]);
+
+    assert_eq!(
+        cycle_paths(&edges),
+        vec![vec!["a", "b", "c", "a"], vec!["d", "d"],]
+    );
+}
+
+#[test]
+fn test_cycle_paths_ignores_acyclic_graphs() {
+    assert!(cycle_paths(&graph(&[("a", "b"), ("b", "c"), ("a", "c")])).is_empty());
+}
+
+#[rstest]
+#[case("crate::a::B", vec!["crate::a::B"])]
+#[case("crate::{a::B, b::{self, C as D}}", vec!["crate::a::B", "crate::b", "crate::b::C"])]
+#[case("super::*", vec!["super::*"])]
+fn test_expand_use_tree(#[case] tree: &str, #[case] expected: Vec<&str>) {
+    assert_eq!(expand_use_tree(tree), expected);
+}
+
+#[test]
+fn test_module_graph_skips_ancestors_and_external_crates() {
+    let edges = module_edges(&[
+        ("", "pub use crate::engine::Engine;\n"),
+        (
+            "engine",
+            "use super::report;\nuse crate::engine::rules::Rule;\n",
+        ),
+        (
+            "engine::rules",
+            "use super::super::{report::Report, engine};\n",
+        ),
+        (
+            "report",
+            "use serde::Serialize;\nuse crate::{\n    engine::Engine, // engine\n};\n#[cfg(test)]\nmod tests { use crate::engine::rules; }\n",
+        ),
+    ]);
+
+    assert_eq!(
+        edges,
+        vec![
+            ("engine".to_owned(), "report".to_owned()),
+            ("engine::rules".to_owned(), "report".to_owned()),
+            ("report".to_owned(), "engine".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_validator_reports_module_and_crate_cycles() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "alpha", "mod a;\nmod b;\n");
+    create_test_crate_with_file(&temp, "alpha", "a.rs", "use crate::b::B;\npub struct A;\n");
+    create_test_crate_with_file(&temp, "alpha", "b.rs", "use crate::a::A;\npub struct B;\n");
+    create_test_crate(&temp, "beta", "pub fn beta() {}\n");
+    let crates = temp.path().join("crates");
+    std::fs::write(
+        crates.join("alpha/Cargo.toml"),
+        cargo_toml_with_deps("alpha", &[("beta", "0.1.0")]),
+    )
+    .unwrap();
+    std::fs::write(
+        crates.join("beta/Cargo.toml"),
+        cargo_toml_with_deps("beta", &[("alpha", "0.1.0")]),
+    )
+    .unwrap();
+
+    let violations = CycleValidator::new(temp.path())
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+
+    let mut found: Vec<(&str, String)> = violations.iter().map(|v| (v.id(), v.message())).collect();
+    found.sort();
+    assert_eq!(found.len(), 2, "{found:?}");
+    assert_eq!(found[0].0, "CYC001");
+    assert!(found[0].1.contains("alpha::a -> alpha::b -> alpha::a"));
+    assert!(found[0].1.contains("a.rs:1"));
+    assert_eq!(found[1].0, "CYC002");
+    assert!(found[1].1.contains("alpha -> beta -> alpha"));
+}
+
+#[test]
+fn test_allowed_cycles_are_not_reported() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "alpha", "mod a;\nmod b;\n");
+    create_test_crate_with_file(&temp, "alpha", "a.rs", "use crate::b::B;\npub struct A;\n");
+    create_test_crate_with_file(&temp, "alpha", "b.rs", "use crate::a::A;\npub struct B;\n");
+    std::fs::write(
+        temp.path().join(".mcb-validate.toml"),
+        "[rules.cycles]\nenabled = true\nallowed_cycles = [[\"alpha::b\", \"alpha::a\"]]\n",
+    )
+    .unwrap();
+
+    let violations = CycleValidator::new(temp.path())
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+
+    assert!(violations.is_empty());
+}
@@ -0,0 +1,88 @@
+//! Unit tests for `mcb_validate::validators::dead_api`
+
+use mcb_domain::ports::validation::{Severity, ValidationConfig, Violation};
+use mcb_validate::config::DeadApiRulesConfig;
+use mcb_validate::{DeadApiValidator, DeadApiViolation};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate;
+
+const CORE_LIB: &str = r"
+pub struct Used;
+pub struct Unused;
+pub enum Mode { On, Off }
+pub(crate) struct Internal;
+struct Private;
+
+pub fn helper() {}
+";
+
+fn workspace() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "core", CORE_LIB);
+    create_test_crate(
+        &temp,
+        "app",
+        "use core::Used;\n// Mode is only mentioned here\n",
+    );
+    let tests_dir = temp.path().join("crates/core/tests");
+    std::fs::create_dir_all(&tests_dir).unwrap();
+    std::fs::write(
+        tests_dir.join("it.rs"),
+        "#[test]\nfn it() { core::helper(); }\n",
+    )
+    .unwrap();
+    temp
+}
+
+fn unused_items(public_api_crates: &[&str], allowed_items: &[&str]) -> Vec<String> {
+    let temp = workspace();
+    let validator = DeadApiValidator::with_config(&DeadApiRulesConfig {
+        enabled: true,
+        public_api_crates: public_api_crates.iter().map(|s| (*s).to_owned()).collect(),
+        allowed_items: allowed_items.iter().map(|s| (*s).to_owned()).collect(),
+    });
+
+    let mut names: Vec<String> = validator
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap()
+        .into_iter()
+        .map(|violation| match violation {
+            DeadApiViolation::UnusedPublicItem { item_name, .. } => item_name,
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[test]
+fn test_reports_public_items_unused_outside_their_crate() {
+    assert_eq!(unused_items(&[], &[]), vec!["Mode", "Unused"]);
+}
+
+#[rstest]
+#[case(&["core"], &[], vec![])]
+#[case(&[], &["Unused"], vec!["Mode"])]
+fn test_allowlists_suppress_reports(
+    #[case] public_api_crates: &[&str],
+    #[case] allowed_items: &[&str],
+    #[case] expected: Vec<&str>,
+) {
+    assert_eq!(unused_items(public_api_crates, allowed_items), expected);
+}
+
+#[test]
+fn test_violation_suggests_pub_crate() {
+    let violation = DeadApiViolation::UnusedPublicItem {
+        crate_name: "core".to_owned(),
+        item_kind: "struct".to_owned(),
+        item_name: "Unused".to_owned(),
+        file: "crates/core/src/lib.rs".into(),
+        line: 3,
+    };
+
+    assert_eq!(violation.id(), "VIS004");
+    assert_eq!(violation.severity(), Severity::Info);
+    assert!(violation.message().contains("pub(crate)"));
+}
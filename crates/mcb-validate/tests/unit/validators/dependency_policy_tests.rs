@@ -0,0 +1,151 @@
+//! Unit tests for `mcb_validate::validators::dependency_policy`
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use mcb_domain::ports::validation::{Severity, ValidationConfig, Violation};
+use mcb_validate::config::DependencyPolicyRulesConfig;
+use mcb_validate::validators::dependency_policy::{
+    LockedPackage, license_allowed, wildcard_dependencies,
+};
+use mcb_validate::{DependencyPolicyValidator, DependencyPolicyViolation};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate;
+
+const LOCKFILE: &str = r#"version = 4
+
+[[package]]
+name = "app"
+version = "0.1.0"
+
+[[package]]
+name = "openssl"
+version = "0.10.0"
+
+[[package]]
+name = "syn"
+version = "1.0.109"
+
+[[package]]
+name = "syn"
+version = "2.0.0"
+
+[[package]]
+name = "windows-sys"
+version = "0.52.0"
+
+[[package]]
+name = "windows-sys"
+version = "0.59.0"
+"#;
+
+fn policy(banned: &[&str], allowed_duplicates: &[&str]) -> DependencyPolicyValidator {
+    DependencyPolicyValidator::with_config(&DependencyPolicyRulesConfig {
+        enabled: true,
+        banned_crates: banned.iter().map(|s| (*s).to_owned()).collect(),
+        allowed_licenses: Vec::new(),
+        allowed_duplicates: allowed_duplicates.iter().map(|s| (*s).to_owned()).collect(),
+    })
+}
+
+fn allowlist(licenses: &[&str]) -> HashSet<String> {
+    licenses.iter().map(|s| (*s).to_owned()).collect()
+}
+
+#[rstest]
+#[case("MIT", true)]
+#[case("MIT OR Apache-2.0", true)]
+#[case("MIT/Apache-2.0", true)]
+#[case("GPL-3.0", false)]
+#[case("(MIT OR Apache-2.0) AND Unicode-3.0", false)]
+#[case("(MIT OR GPL-3.0) AND Apache-2.0", true)]
+#[case("Apache-2.0 WITH LLVM-exception", true)]
+#[case("GPL-2.0+", false)]
+#[case("MIT AND", false)]
+fn test_license_allowed(#[case] expression: &str, #[case] expected: bool) {
+    assert_eq!(
+        license_allowed(expression, &allowlist(&["MIT", "Apache-2.0"])),
+        expected
+    );
+}
+
+#[test]
+fn test_lockfile_bans_and_duplicates() {
+    let packages: Vec<LockedPackage> = toml::from_str::<toml::Value>(LOCKFILE)
+        .unwrap()
+        .get("package")
+        .cloned()
+        .unwrap()
+        .try_into()
+        .unwrap();
+
+    let found: Vec<(String, String)> = policy(&["openssl"], &["windows-sys"])
+        .check_lockfile(&packages, Path::new("Cargo.lock"))
+        .iter()
+        .map(|violation| (violation.id().to_owned(), violation.message()))
+        .collect();
+
+    assert_eq!(found.len(), 2, "{found:?}");
+    assert_eq!(found[0].0, "DENY001");
+    assert!(found[0].1.contains("openssl (0.10.0)"));
+    assert_eq!(found[1].0, "DENY003");
+    assert!(
+        found[1]
+            .1
+            .contains("syn is locked at several versions: 1.0.109, 2.0.0")
+    );
+}
+
+#[test]
+fn test_wildcard_dependencies_cover_all_tables() {
+    let manifest: toml::Value = toml::from_str(
+        r#"
+[dependencies]
+serde = "*"
+regex = "1"
+local = { path = "../local" }
+
+[dev-dependencies]
+rand = { version = "*" }
+
+[target.'cfg(unix)'.build-dependencies]
+cc = "*"
+
+[workspace.dependencies]
+tokio = "*"
+"#,
+    )
+    .unwrap();
+
+    let found: Vec<String> = wildcard_dependencies(&manifest).into_iter().collect();
+    assert_eq!(found, vec!["cc", "rand", "serde", "tokio"]);
+}
+
+#[test]
+fn test_validator_reports_wildcards_with_line_and_lockfile_findings() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "app", "pub fn run() {}\n");
+    std::fs::write(
+        temp.path().join("crates/app/Cargo.toml"),
+        "[package]\nname = \"app\"\nversion = \"0.1.0\"\n\n[dependencies]\nserde = \"*\"\n",
+    )
+    .unwrap();
+    std::fs::write(temp.path().join("Cargo.lock"), LOCKFILE).unwrap();
+
+    let violations = policy(&[], &["windows-sys", "syn"])
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+
+    assert_eq!(violations.len(), 1, "{violations:?}");
+    let DependencyPolicyViolation::WildcardVersion {
+        dependency, line, ..
+    } = &violations[0]
+    else {
+        panic!("expected a wildcard violation, got {:?}", violations[0]);
+    };
+    assert_eq!(dependency, "serde");
+    assert_eq!(*line, 6);
+    assert_eq!(violations[0].severity(), Severity::Error);
+}
@@ -0,0 +1,134 @@
+//! Unit tests for `mcb_validate::validators::features`
+
+use mcb_domain::ports::validation::{Severity, ValidationConfig, Violation};
+use mcb_utils::constants::validate::FEATURE_PREDICATE_REGEX;
+use mcb_utils::utils::regex::compile_regex;
+use mcb_validate::config::FeaturesRulesConfig;
+use mcb_validate::validators::features::{feature_uses, undocumented_gated_items};
+use mcb_validate::{FeatureSet, FeatureValidator, FeatureViolation};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate;
+
+const MANIFEST: &str = r#"[package]
+name = "flags"
+version = "0.1.0"
+edition = "2024"
+
+[dependencies]
+serde = { version = "1", optional = true }
+wasmtime = { version = "1", optional = true }
+
+[features]
+default = ["json"]
+json = []
+wasm = ["dep:wasmtime"]
+full = ["json", "wasm"]
+stale = []
+"#;
+
+const LIB: &str = r#"
+#[cfg(feature = "json")]
+pub mod json;
+
+#[cfg(feature = "wasm")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wasm")))]
+/// Plugin host.
+pub struct Host;
+
+#[cfg(all(test, feature = "jsno"))]
+mod typo {}
+
+#[cfg(feature = "serde")]
+fn private() {}
+"#;
+
+fn violations(require_docsrs: bool) -> Vec<(String, String)> {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "flags", LIB);
+    std::fs::write(temp.path().join("crates/flags/Cargo.toml"), MANIFEST).unwrap();
+
+    let mut found: Vec<(String, String)> = FeatureValidator::with_config(&FeaturesRulesConfig {
+        enabled: true,
+        require_docsrs,
+    })
+    .validate_all(&ValidationConfig::new(temp.path()))
+    .unwrap()
+    .into_iter()
+    .map(|violation| {
+        let feature = match &violation {
+            FeatureViolation::UndefinedFeature { feature, .. }
+            | FeatureViolation::UnusedFeature { feature, .. }
+            | FeatureViolation::MissingDocsrsCfg { feature, .. } => feature.clone(),
+        };
+        (violation.id().to_owned(), feature)
+    })
+    .collect();
+    found.sort();
+    found
+}
+
+#[test]
+fn test_reports_undefined_unused_and_undocumented_features() {
+    assert_eq!(
+        violations(true),
+        vec![
+            ("FEAT001".to_owned(), "jsno".to_owned()),
+            ("FEAT002".to_owned(), "stale".to_owned()),
+            ("FEAT003".to_owned(), "json".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn test_docsrs_check_can_be_disabled() {
+    assert!(violations(false).iter().all(|(id, _)| id != "FEAT003"));
+}
+
+#[test]
+fn test_feature_set_includes_implicit_optional_dependencies() {
+    let manifest: toml::Value = toml::from_str(MANIFEST).unwrap();
+    let features = FeatureSet::from_manifest(&serde_json::to_value(manifest).unwrap());
+
+    assert!(features.defines("serde"));
+    assert!(features.defines("stale"));
+    assert!(!features.defines("wasmtime"));
+}
+
+#[rstest]
+#[case("#[cfg(feature = \"a\")]", vec!["a"])]
+#[case("#[cfg(any(feature = \"a\", feature=\"b\"))]", vec!["a", "b"])]
+#[case("if cfg!(feature = \"a\") {}", vec!["a"])]
+#[case("let feature = \"a\"; // cfg", vec![])]
+fn test_feature_uses(#[case] line: &str, #[case] expected: Vec<&str>) {
+    let predicate = compile_regex(FEATURE_PREDICATE_REGEX).unwrap();
+    let found: Vec<String> = feature_uses(&predicate, line)
+        .into_iter()
+        .map(|feature_use| feature_use.feature)
+        .collect();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn test_undocumented_gated_items_point_at_the_cfg_line() {
+    let predicate = compile_regex(FEATURE_PREDICATE_REGEX).unwrap();
+    let found = undocumented_gated_items(&predicate, LIB);
+
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].feature, "json");
+    assert_eq!(found[0].line, 2);
+}
+
+#[test]
+fn test_undefined_feature_is_an_error() {
+    let violation = FeatureViolation::UndefinedFeature {
+        crate_name: "flags".to_owned(),
+        feature: "jsno".to_owned(),
+        file: "crates/flags/src/lib.rs".into(),
+        line: 10,
+    };
+
+    assert_eq!(violation.id(), "FEAT001");
+    assert_eq!(violation.severity(), Severity::Error);
+}
@@ -0,0 +1,155 @@
+//! Unit tests for `mcb_validate::validators::layer_flow`
+
+use std::path::Path;
+
+use mcb_domain::ports::validation::{ValidationConfig, Violation};
+use mcb_validate::config::{LayerConfig, LayerFlowRulesConfig};
+use mcb_validate::{LayerFlowValidator, LayerMap};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::{cargo_toml_with_deps, create_test_crate, create_test_crate_with_file};
+
+fn layer(name: &str, crates: &[&str], paths: &[&str], may_depend_on: &[&str]) -> LayerConfig {
+    let owned = |items: &[&str]| items.iter().map(|item| (*item).to_owned()).collect();
+    LayerConfig {
+        name: name.to_owned(),
+        crates: owned(crates),
+        paths: owned(paths),
+        may_depend_on: owned(may_depend_on),
+    }
+}
+
+fn validator(layers: Vec<LayerConfig>) -> LayerFlowValidator {
+    LayerFlowValidator::with_config(&LayerFlowRulesConfig {
+        enabled: true,
+        forbidden_dependencies: std::collections::HashMap::new(),
+        circular_dependency_check_crates: Vec::new(),
+        layers,
+    })
+}
+
+fn findings(temp: &TempDir, layers: Vec<LayerConfig>) -> Vec<(String, usize, String)> {
+    let mut found: Vec<(String, usize, String)> = validator(layers)
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap()
+        .iter()
+        .map(|violation| {
+            let file = violation
+                .file()
+                .and_then(|file| file.file_name())
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            (file, violation.line().unwrap_or(0), violation.message())
+        })
+        .collect();
+    found.sort();
+    found
+}
+
+#[rstest]
+#[case(vec![layer("domain", &[], &[], &["core"])], "unknown layer `core`")]
+#[case(
+    vec![layer("domain", &[], &[], &[]), layer("domain", &[], &[], &[])],
+    "layer `domain` is defined twice"
+)]
+#[case(vec![layer("domain", &["app-{"], &[], &[])], "invalid layer pattern `app-{`")]
+fn test_compile_rejects_invalid_layers(#[case] layers: Vec<LayerConfig>, #[case] expected: &str) {
+    let Err(error) = LayerMap::compile(&layers) else {
+        panic!("layers should be rejected");
+    };
+    assert!(error.to_string().contains(expected), "{error}");
+}
+
+#[rstest]
+#[case("crates/app/src/adapters/db.rs", "app", Some("infrastructure"))]
+#[case("crates/app/src/user.rs", "app", Some("domain"))]
+#[case("crates/tool/src/lib.rs", "tool", None)]
+fn test_layer_of_file_prefers_paths(
+    #[case] path: &str,
+    #[case] crate_name: &str,
+    #[case] expected: Option<&str>,
+) {
+    let layers = LayerMap::compile(&[
+        layer("domain", &["app"], &[], &[]),
+        layer(
+            "infrastructure",
+            &[],
+            &["crates/*/src/adapters/**"],
+            &["domain"],
+        ),
+    ])
+    .unwrap();
+
+    assert_eq!(layers.layer_of_file(Path::new(path), crate_name), expected);
+    assert!(layers.allows("infrastructure", "domain"));
+    assert!(layers.allows("domain", "domain"));
+    assert!(!layers.allows("domain", "infrastructure"));
+}
+
+#[test]
+fn test_crate_layers_report_manifest_and_import() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "app-domain", "use app_infra::Database;\n");
+    create_test_crate(&temp, "app-infra", "use app_domain::User;\n");
+    std::fs::write(
+        temp.path().join("crates/app-domain/Cargo.toml"),
+        cargo_toml_with_deps("app-domain", &[("app-infra", "0.1.0")]),
+    )
+    .unwrap();
+
+    let found = findings(
+        &temp,
+        vec![
+            layer("domain", &["*-domain"], &[], &[]),
+            layer("infrastructure", &["*-infra"], &[], &["domain"]),
+        ],
+    );
+
+    assert_eq!(found.len(), 2, "{found:?}");
+    assert_eq!((found[0].0.as_str(), found[0].1), ("Cargo.toml", 6));
+    assert_eq!((found[1].0.as_str(), found[1].1), ("lib.rs", 1));
+    assert!(
+        found[1]
+            .2
+            .contains("Layer domain must not depend on infrastructure: app_infra::Database")
+    );
+}
+
+#[test]
+fn test_path_layers_resolve_crate_imports() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "app", "pub mod adapters;\npub mod domain;\n");
+    create_test_crate_with_file(
+        &temp,
+        "app",
+        "domain.rs",
+        "use std::fmt;\nuse crate::adapters::{db::Pool, http};\n",
+    );
+    create_test_crate_with_file(&temp, "app", "adapters.rs", "use crate::domain::User;\n");
+
+    let found = findings(
+        &temp,
+        vec![
+            layer("domain", &[], &["crates/app/src/domain.rs"], &[]),
+            layer(
+                "infrastructure",
+                &[],
+                &["crates/app/src/adapters.rs"],
+                &["domain"],
+            ),
+        ],
+    );
+
+    assert_eq!(found.len(), 1, "{found:?}");
+    assert_eq!((found[0].0.as_str(), found[0].1), ("domain.rs", 2));
+    assert!(found[0].2.contains("crate::adapters::db::Pool"));
+}
+
+#[test]
+fn test_no_layers_reports_nothing() {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "app-domain", "use app_infra::Database;\n");
+
+    assert!(findings(&temp, Vec::new()).is_empty());
+}
@@ -1,20 +1,34 @@
 //! Unit tests.
 
+pub mod adr_tests;
+pub mod api_surface_tests;
 pub mod architecture;
 pub mod async_patterns;
 pub mod cargo_dependency;
+pub mod complexity_delta_tests;
+pub mod cycles_tests;
+pub mod dead_api_tests;
 pub mod declarative_validator_tests;
 pub mod dependency;
+pub mod dependency_policy_tests;
 pub mod documentation;
 pub mod error_boundary_tests;
+pub mod features_tests;
 pub mod hygiene;
 pub mod implementation;
 pub mod kiss;
+pub mod layer_flow_tests;
 pub mod organization;
 pub mod pattern_validator;
 pub mod performance;
+pub mod polyglot_tests;
 pub mod quality;
 pub mod refactoring;
 pub mod solid;
 /// SSOT validator tests.
 pub mod ssot;
+pub mod test_coverage_tests;
+pub mod unsafe_code_tests;
+/// WebAssembly plugin validator tests.
+#[cfg(feature = "wasm-plugins")]
+pub mod wasm_plugin_tests;
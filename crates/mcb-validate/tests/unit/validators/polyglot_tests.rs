@@ -0,0 +1,129 @@
+//! Unit tests for `mcb_validate::validators::polyglot`
+
+use std::path::{Path, PathBuf};
+
+use mcb_domain::ports::validation::{LanguageId, ValidationConfig, Violation};
+use mcb_validate::config::PolyglotRulesConfig;
+use mcb_validate::{LanguageProfile, NameCase, PolyglotValidator};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate_with_file;
+
+const PYTHON_SOURCE: &str = r"
+# TODO: drop the legacy path
+class http_client:
+    def setUp(self):
+        pass
+
+    def fetchPage(self, url):
+        return url
+
+def parse_input(value):
+    if value == 1:
+        return 1
+    elif value == 2:
+        return 2
+    elif value == 3:
+        return 3
+    return 0
+";
+
+const TYPESCRIPT_SOURCE: &str = r"
+// FIXME: handle retries
+export class httpClient {
+  fetch_page(url: string): string {
+    return url;
+  }
+}
+
+export function parseInput(value: number): number {
+  return value > 0 ? value : 0;
+}
+";
+
+fn validator(languages: &[&str]) -> PolyglotValidator {
+    PolyglotValidator::with_config(&PolyglotRulesConfig {
+        enabled: true,
+        languages: languages.iter().map(|name| (*name).to_owned()).collect(),
+        excluded_paths: Vec::new(),
+    })
+    .with_max_complexity(3)
+}
+
+fn ids_and_lines(violations: &[impl Violation]) -> Vec<(String, usize)> {
+    let mut found: Vec<(String, usize)> = violations
+        .iter()
+        .map(|violation| (violation.id().to_owned(), violation.line().unwrap_or(0)))
+        .collect();
+    found.sort();
+    found
+}
+
+#[rstest]
+#[case(
+    LanguageId::Python,
+    "app.py",
+    PYTHON_SOURCE,
+    &[("POLY001", 3), ("POLY001", 7), ("POLY003", 10), ("POLY004", 2)]
+)]
+#[case(
+    LanguageId::TypeScript,
+    "client.ts",
+    TYPESCRIPT_SOURCE,
+    &[("POLY001", 3), ("POLY001", 4), ("POLY004", 2)]
+)]
+fn test_check_file_dispatches_on_language(
+    #[case] language: LanguageId,
+    #[case] file: &str,
+    #[case] content: &str,
+    #[case] expected: &[(&str, usize)],
+) {
+    let profile = LanguageProfile::for_language(language).unwrap();
+    let violations = validator(&["python", "typescript"])
+        .check_file(profile, Path::new(file), content)
+        .unwrap();
+
+    let expected: Vec<(String, usize)> = expected
+        .iter()
+        .map(|(id, line)| ((*id).to_owned(), *line))
+        .collect();
+    assert_eq!(ids_and_lines(&violations), expected);
+}
+
+#[rstest]
+#[case(&["python", "typescript"], vec!["app.py", "client.ts"])]
+#[case(&["python"], vec!["app.py"])]
+#[case(&[], vec![])]
+fn test_validate_all_checks_configured_languages(
+    #[case] languages: &[&str],
+    #[case] expected: Vec<&str>,
+) {
+    let temp = TempDir::new().unwrap();
+    create_test_crate_with_file(&temp, "tools", "app.py", PYTHON_SOURCE);
+    create_test_crate_with_file(&temp, "web", "client.ts", TYPESCRIPT_SOURCE);
+
+    let violations = validator(languages)
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+
+    let mut files: Vec<String> = violations
+        .iter()
+        .filter_map(|violation| violation.file().map(PathBuf::clone))
+        .filter_map(|file| Some(file.file_name()?.to_string_lossy().into_owned()))
+        .collect();
+    files.sort();
+    files.dedup();
+    assert_eq!(files, expected);
+}
+
+#[rstest]
+#[case(NameCase::Snake, "__init__", true)]
+#[case(NameCase::Snake, "fetchPage", false)]
+#[case(NameCase::LowerCamel, "parseInput", true)]
+#[case(NameCase::LowerCamel, "parse_input", false)]
+#[case(NameCase::Pascal, "HttpClient", true)]
+#[case(NameCase::Pascal, "http_client", false)]
+fn test_name_case(#[case] case: NameCase, #[case] name: &str, #[case] expected: bool) {
+    assert_eq!(case.matches(name), expected);
+}
@@ -0,0 +1,121 @@
+//! Unit tests for `mcb_validate::validators::test_coverage`
+
+use mcb_domain::ports::validation::{ValidationConfig, Violation};
+use mcb_validate::config::TestCoverageRulesConfig;
+use mcb_validate::validators::test_coverage::test_code;
+use mcb_validate::{TestCoverageValidator, TestCoverageViolation};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate;
+
+const CORE_LIB: &str = r"
+/// Parses input.
+///
+/// ```
+/// let value = core::parse_input();
+/// ```
+pub fn parse_input() {}
+
+/// ```text
+/// render_output()
+/// ```
+pub fn render_output() {}
+
+pub fn from_integration_test() {}
+
+pub fn untested() {}
+
+pub(crate) fn internal() {}
+
+pub struct Engine;
+
+impl Engine {
+    pub fn run(&self) {}
+}
+
+#[cfg(test)]
+mod tests {
+    pub fn helper() {}
+
+    #[test]
+    fn engine_runs() {
+        super::Engine.run();
+    }
+}
+";
+
+fn workspace() -> TempDir {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "core", CORE_LIB);
+    let tests_dir = temp.path().join("crates/core/tests");
+    std::fs::create_dir_all(&tests_dir).unwrap();
+    std::fs::write(
+        tests_dir.join("it.rs"),
+        "#[test]\nfn it() { core::from_integration_test(); }\n",
+    )
+    .unwrap();
+    temp
+}
+
+fn untested_functions(min_coverage_percent: u8, excluded: &[&str]) -> Vec<String> {
+    let temp = workspace();
+    let validator = TestCoverageValidator::with_config(&TestCoverageRulesConfig {
+        enabled: true,
+        min_coverage_percent,
+        excluded_crates: excluded.iter().map(|s| (*s).to_owned()).collect(),
+    });
+
+    let mut names: Vec<String> = validator
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap()
+        .into_iter()
+        .filter_map(|violation| match violation {
+            TestCoverageViolation::UntestedPublicFunction { function, .. } => Some(function),
+            TestCoverageViolation::CoverageBelowThreshold { .. } => None,
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+#[rstest]
+#[case(100, &[], vec!["render_output", "untested"])]
+#[case(60, &[], vec![])]
+#[case(100, &["core"], vec![])]
+fn test_reports_untested_functions_below_threshold(
+    #[case] min_coverage_percent: u8,
+    #[case] excluded: &[&str],
+    #[case] expected: Vec<&str>,
+) {
+    assert_eq!(untested_functions(min_coverage_percent, excluded), expected);
+}
+
+#[test]
+fn test_summary_reports_crate_coverage() {
+    let temp = workspace();
+    let violations = TestCoverageValidator::with_config(&TestCoverageRulesConfig {
+        enabled: true,
+        min_coverage_percent: 80,
+        excluded_crates: Vec::new(),
+    })
+    .validate_all(&ValidationConfig::new(temp.path()))
+    .unwrap();
+
+    let summary = violations
+        .iter()
+        .find(|violation| violation.id() == "TCOV001")
+        .unwrap();
+    assert!(summary.message().contains("3/5 public functions"));
+    assert!(summary.message().contains("60% < 80%"));
+}
+
+#[test]
+fn test_test_code_keeps_doc_tests_and_test_modules() {
+    let code = test_code(CORE_LIB);
+
+    assert!(code.contains("core::parse_input()"));
+    assert!(!code.contains("render_output"));
+    assert!(code.contains("super::Engine.run()"));
+    assert!(!code.contains("pub fn untested"));
+}
@@ -0,0 +1,96 @@
+//! Unit tests for `mcb_validate::validators::unsafe_code`
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use mcb_domain::ports::validation::{ValidationConfig, Violation};
+use mcb_validate::config::UnsafeCodeRulesConfig;
+use mcb_validate::{UnsafeCodeValidator, UnsafePatterns};
+use rstest::rstest;
+use tempfile::TempDir;
+
+use crate::utils::create_test_crate;
+
+const FFI_LIB: &str = r#"
+pub fn read(ptr: *const u8) -> u8 {
+    // SAFETY: callers pass a valid, aligned pointer.
+    unsafe { *ptr }
+}
+
+pub fn write(ptr: *mut u8) {
+    unsafe { *ptr = 0 }
+}
+
+pub fn bits(x: f32) -> u32 {
+    unsafe { std::mem::transmute(x) } // SAFETY: same size
+}
+
+/// Parses a config.
+///
+/// # Errors
+/// Returns a negative code on invalid input.
+#[unsafe(no_mangle)]
+pub extern "C" fn mcb_parse() -> i32 { 0 }
+
+/// Frees a handle.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn mcb_free() {}
+
+const MESSAGE: &str = "no unsafe { here, nor transmute";
+
+#[cfg(test)]
+mod tests {
+    fn raw() { unsafe { std::mem::transmute::<u32, f32>(0) }; }
+}
+"#;
+
+fn scan(content: &str) -> Vec<(String, usize)> {
+    UnsafePatterns::new()
+        .unwrap()
+        .scan(Path::new("lib.rs"), content)
+        .iter()
+        .map(|violation| (violation.id().to_owned(), violation.line().unwrap()))
+        .collect()
+}
+
+#[test]
+fn test_scan_flags_unsafe_transmute_and_extern_fns() {
+    assert_eq!(
+        scan(FFI_LIB),
+        vec![
+            ("UNSAFE001".to_owned(), 8),
+            ("UNSAFE002".to_owned(), 12),
+            ("UNSAFE003".to_owned(), 24),
+        ]
+    );
+}
+
+#[rstest]
+#[case("// SAFETY: checked above\n// and below\nunsafe { f() }\n", 0)]
+#[case("// SAFETY: checked\n#[allow(unused)]\nlet x = unsafe { f() };\n", 0)]
+#[case("// SAFETY: stale\n\nunsafe { f() }\n", 1)]
+#[case("unsafe fn f() {}\nunsafe impl Send for X {}\n", 0)]
+fn test_safety_comment_lookup(#[case] content: &str, #[case] expected: usize) {
+    assert_eq!(scan(content).len(), expected);
+}
+
+#[rstest]
+#[case(&[], 3)]
+#[case(&["UNSAFE002", "UNSAFE003"], 1)]
+fn test_per_crate_allow(#[case] allowed: &[&str], #[case] expected: usize) {
+    let temp = TempDir::new().unwrap();
+    create_test_crate(&temp, "ffi", FFI_LIB);
+    let validator = UnsafeCodeValidator::with_config(&UnsafeCodeRulesConfig {
+        enabled: true,
+        allow: BTreeMap::from([(
+            "ffi".to_owned(),
+            allowed.iter().map(|id| (*id).to_owned()).collect(),
+        )]),
+    });
+
+    let violations = validator
+        .validate_all(&ValidationConfig::new(temp.path()))
+        .unwrap();
+
+    assert_eq!(violations.len(), expected, "{violations:?}");
+}
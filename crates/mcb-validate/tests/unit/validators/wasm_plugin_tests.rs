@@ -0,0 +1,109 @@
+//! Unit tests for `mcb_validate::validators::wasm_plugin`
+
+use std::path::{Path, PathBuf};
+
+use mcb_domain::ports::validation::{Severity, Violation};
+use mcb_validate::{WasmPluginConfig, WasmPluginValidator};
+use rstest::rstest;
+use tempfile::TempDir;
+
+/// Module that ignores its input and returns `output` from offset 0.
+fn fixed_output_module(output: &str) -> String {
+    format!(
+        r#"(module
+  (memory (export "memory") 1)
+  (data (i32.const 0) "{}")
+  (func (export "mcb_alloc") (param i32) (result i32) (i32.const 4096))
+  (func (export "mcb_validate") (param i32 i32) (result i64) (i64.const {})))"#,
+        output.replace('\\', "\\\\").replace('"', "\\\""),
+        output.len()
+    )
+}
+
+fn write_plugin(root: &Path, name: &str, wat: &str) -> WasmPluginConfig {
+    std::fs::write(root.join(name), wat).unwrap();
+    WasmPluginConfig {
+        path: PathBuf::from(name),
+        namespace: None,
+    }
+}
+
+#[test]
+fn test_plugin_violations_are_returned() {
+    let temp = TempDir::new().unwrap();
+    let plugin = write_plugin(
+        temp.path(),
+        "plugin.wat",
+        &fixed_output_module(
+            r#"{"violations":[{"id":"SEC001","message":"Raw SQL","severity":"ERROR","file":"src/db.rs","line":7,"suggestion":"Use the query builder"}]}"#,
+        ),
+    );
+
+    let violations = WasmPluginValidator::new(temp.path())
+        .run_plugin(&plugin, b"{}")
+        .unwrap();
+
+    assert_eq!(violations.len(), 1);
+    let violation = &violations[0];
+    assert_eq!(violation.id(), "SEC001");
+    assert_eq!(violation.severity(), Severity::Error);
+    assert_eq!(violation.file(), Some(&temp.path().join("src/db.rs")));
+    assert_eq!(violation.line(), Some(7));
+    assert_eq!(
+        violation.suggestion().as_deref(),
+        Some("Use the query builder")
+    );
+}
+
+#[test]
+fn test_namespace_prefixes_plugin_ids() {
+    let temp = TempDir::new().unwrap();
+    let mut plugin = write_plugin(
+        temp.path(),
+        "plugin.wat",
+        &fixed_output_module(r#"{"violations":[{"id":"SEC001","message":"Raw SQL"}]}"#),
+    );
+    plugin.namespace = Some("ACME".to_owned());
+
+    let violations = WasmPluginValidator::new(temp.path())
+        .run_plugin(&plugin, b"{}")
+        .unwrap();
+
+    assert_eq!(violations[0].id(), "ACME::SEC001");
+    assert_eq!(violations[0].severity(), Severity::Warning);
+}
+
+#[rstest]
+#[case(&fixed_output_module("not json"), "invalid plugin output")]
+#[case(r#"(module (memory (export "memory") 1))"#, "mcb_alloc")]
+#[case(
+    r#"(module
+  (memory (export "memory") 1)
+  (func (export "mcb_alloc") (param i32) (result i32) (i32.const 0))
+  (func (export "mcb_validate") (param i32 i32) (result i64) unreachable))"#,
+    "WASM plugin"
+)]
+fn test_broken_plugin_is_an_error(#[case] wat: &str, #[case] expected: &str) {
+    let temp = TempDir::new().unwrap();
+    let plugin = write_plugin(temp.path(), "plugin.wat", wat);
+
+    let error = WasmPluginValidator::new(temp.path())
+        .run_plugin(&plugin, b"{}")
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains(expected), "{error}");
+}
+
+#[test]
+fn test_invalid_namespace_is_rejected() {
+    let temp = TempDir::new().unwrap();
+    let mut plugin = write_plugin(temp.path(), "plugin.wat", &fixed_output_module("{}"));
+    plugin.namespace = Some("not a namespace".to_owned());
+
+    assert!(
+        WasmPluginValidator::new(temp.path())
+            .run_plugin(&plugin, b"{}")
+            .is_err()
+    );
+}
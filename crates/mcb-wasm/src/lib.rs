@@ -0,0 +1,159 @@
+//! WebAssembly build of the chunking and BM25 ranking subsystem.
+//!
+//! **Documentation**: [docs/modules/bindings.md](../../../docs/modules/bindings.md)
+//!
+//! Lets browser-based tools chunk source files with the tree-sitter
+//! processors and rank the chunks lexically with BM25, entirely locally.
+//! Depends on `mcb-providers` without its `native` feature, so no network
+//! provider, database or Tokio runtime is compiled in.
+//!
+//! ```js
+//! import init, { chunkCode, Bm25Index } from "./pkg/mcb_wasm.js";
+//!
+//! await init();
+//! const chunks = chunkCode(source, "src/auth/token.rs");
+//! const index = new Bm25Index(chunks);
+//! for (const { chunk, score } of index.search("refresh token", 5)) {
+//!   console.log(score, chunk.file_path, chunk.start_line);
+//! }
+//! ```
+//!
+//! Chunks and results cross the boundary as plain objects with the field
+//! names of [`CodeChunk`].
+
+use std::path::Path;
+
+use mcb_domain::entities::CodeChunk;
+use mcb_providers::hybrid_search::{BM25Params, BM25Scorer};
+use mcb_providers::language::{IntelligentChunker, language_from_extension, supported_languages};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// A chunk with its BM25 score for a query.
+#[derive(Debug, Clone, Serialize)]
+pub struct RankedChunk {
+    /// The ranked chunk
+    pub chunk: CodeChunk,
+    /// BM25 score, higher is better
+    pub score: f64,
+}
+
+/// Split `content` into chunks, detecting the language from the extension
+/// of `file_path`. Unsupported languages fall back to generic chunking.
+#[must_use]
+pub fn chunk_file(content: &str, file_path: &str) -> Vec<CodeChunk> {
+    let language = detect_language(file_path);
+    IntelligentChunker::new().chunk_code(content, file_path, &language)
+}
+
+/// Language identifier for `file_path`, or `unknown`.
+#[wasm_bindgen(js_name = detectLanguage)]
+#[must_use]
+pub fn detect_language(file_path: &str) -> String {
+    let extension = Path::new(file_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+    language_from_extension(extension)
+}
+
+/// Languages with structural (tree-sitter) chunking.
+#[wasm_bindgen(js_name = supportedLanguages)]
+#[must_use]
+pub fn supported_languages_js() -> Vec<String> {
+    supported_languages()
+}
+
+/// JavaScript form of [`chunk_file`]: an array of chunk objects.
+///
+/// # Errors
+///
+/// Fails when the chunks cannot be converted to JavaScript values.
+#[wasm_bindgen(js_name = chunkCode)]
+pub fn chunk_code_js(content: &str, file_path: &str) -> Result<JsValue, JsError> {
+    to_js(&chunk_file(content, file_path))
+}
+
+/// BM25 index over a fixed set of chunks.
+#[wasm_bindgen]
+#[derive(Debug)]
+pub struct Bm25Index {
+    chunks: Vec<CodeChunk>,
+    scorer: BM25Scorer,
+}
+
+impl Bm25Index {
+    /// Build the term statistics for `chunks`.
+    #[must_use]
+    pub fn from_chunks(chunks: Vec<CodeChunk>, params: BM25Params) -> Self {
+        let scorer = BM25Scorer::new(&chunks, params);
+        Self { chunks, scorer }
+    }
+
+    /// Up to `limit` chunks matching `query`, best first. Chunks sharing no
+    /// term with the query are left out.
+    #[must_use]
+    pub fn rank(&self, query: &str, limit: usize) -> Vec<RankedChunk> {
+        let documents: Vec<&CodeChunk> = self.chunks.iter().collect();
+        let scores = self.scorer.score_batch(&documents, query);
+        let mut ranked: Vec<(usize, f64)> = scores
+            .into_iter()
+            .enumerate()
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranked
+            .into_iter()
+            .take(limit)
+            .map(|(index, score)| RankedChunk {
+                chunk: self.chunks[index].clone(),
+                score,
+            })
+            .collect()
+    }
+}
+
+#[wasm_bindgen]
+impl Bm25Index {
+    /// Index an array of chunk objects, as returned by `chunkCode`. `k1` and
+    /// `b` override the default BM25 parameters.
+    ///
+    /// # Errors
+    ///
+    /// Fails when `chunks` is not an array of chunk objects.
+    #[wasm_bindgen(constructor)]
+    pub fn new(chunks: JsValue, k1: Option<f64>, b: Option<f64>) -> Result<Self, JsError> {
+        let chunks: Vec<CodeChunk> =
+            serde_wasm_bindgen::from_value(chunks).map_err(|e| JsError::new(&e.to_string()))?;
+        let defaults = BM25Params::default();
+        let params = BM25Params {
+            k1: k1.unwrap_or(defaults.k1),
+            b: b.unwrap_or(defaults.b),
+        };
+        Ok(Self::from_chunks(chunks, params))
+    }
+
+    /// Number of indexed chunks.
+    #[wasm_bindgen(getter)]
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// JavaScript form of [`Bm25Index::rank`]: an array of
+    /// `{ chunk, score }` objects.
+    ///
+    /// # Errors
+    ///
+    /// Fails when the results cannot be converted to JavaScript values.
+    pub fn search(&self, query: &str, limit: usize) -> Result<JsValue, JsError> {
+        to_js(&self.rank(query, limit))
+    }
+}
+
+/// Serialize as plain objects; chunk metadata would otherwise become `Map`s.
+fn to_js(value: &impl Serialize) -> Result<JsValue, JsError> {
+    value
+        .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+        .map_err(|e| JsError::new(&e.to_string()))
+}
@@ -0,0 +1,5 @@
+//! Unit tests — `cargo test -p mcb-wasm --test unit`
+//!
+//! Exercise the Rust side of the bindings on the host target.
+
+mod ranking_tests;
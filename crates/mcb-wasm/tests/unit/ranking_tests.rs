@@ -0,0 +1,71 @@
+//! Tests for chunking and BM25 ranking exposed to WebAssembly.
+
+use mcb_domain::entities::CodeChunk;
+use mcb_providers::hybrid_search::BM25Params;
+use mcb_wasm::{Bm25Index, chunk_file, detect_language};
+use rstest::rstest;
+
+fn chunk(id: &str, content: &str) -> CodeChunk {
+    CodeChunk {
+        id: id.to_owned(),
+        content: content.to_owned(),
+        file_path: format!("src/{id}.rs"),
+        start_line: 1,
+        end_line: 1,
+        language: "rust".to_owned(),
+        metadata: serde_json::Value::Null,
+    }
+}
+
+#[rstest]
+#[case("src/lib.rs", "rust")]
+#[case("app/main.py", "python")]
+#[case("README", "unknown")]
+fn test_detect_language(#[case] file_path: &str, #[case] expected: &str) {
+    assert_eq!(detect_language(file_path), expected);
+}
+
+#[rstest]
+fn test_chunk_file_uses_detected_language() {
+    let source = "fn refresh_token() -> u32 {\n    42\n}\n";
+
+    let chunks = chunk_file(source, "src/auth.rs");
+
+    assert!(!chunks.is_empty());
+    assert!(chunks.iter().all(|c| c.language == "rust"));
+    assert!(chunks.iter().all(|c| c.file_path == "src/auth.rs"));
+}
+
+#[rstest]
+fn test_rank_orders_matches_and_drops_misses() {
+    let index = Bm25Index::from_chunks(
+        vec![
+            chunk("parse", "fn parse_config(path: &str) -> Config"),
+            chunk(
+                "refresh",
+                "fn refresh_token(token: Token) -> Token { token.refresh() }",
+            ),
+            chunk("mention", "// the token cache is cleared on logout"),
+        ],
+        BM25Params::default(),
+    );
+
+    let ranked = index.rank("refresh token", 10);
+
+    let ids: Vec<&str> = ranked.iter().map(|r| r.chunk.id.as_str()).collect();
+    assert_eq!(ids, ["refresh", "mention"]);
+    assert!(ranked[0].score > ranked[1].score);
+}
+
+#[rstest]
+fn test_rank_respects_limit() {
+    let index = Bm25Index::from_chunks(
+        (0..5)
+            .map(|i| chunk(&i.to_string(), "fn token() {}"))
+            .collect(),
+        BM25Params::default(),
+    );
+
+    assert_eq!(index.size(), 5);
+    assert_eq!(index.rank("token", 2).len(), 2);
+}
@@ -0,0 +1,29 @@
+//! Completions command - emits shell completion scripts
+//!
+//! ```bash
+//! mcb completions bash > /etc/bash_completion.d/mcb
+//! mcb completions zsh  > "${fpath[1]}/_mcb"
+//! mcb completions fish > ~/.config/fish/completions/mcb.fish
+//! ```
+
+use clap::Args;
+use clap_complete::Shell;
+
+/// Arguments for the `completions` command
+#[derive(Args, Debug, Clone)]
+pub struct CompletionsArgs {
+    /// Shell to generate completions for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+impl CompletionsArgs {
+    /// Write the completion script for `command` to stdout.
+    ///
+    /// Takes the top-level [`clap::Command`] because the CLI definition lives
+    /// in the binary crate.
+    pub fn execute(self, command: &mut clap::Command) {
+        let name = command.get_name().to_owned();
+        clap_complete::generate(self.shell, command, name, &mut std::io::stdout());
+    }
+}
@@ -0,0 +1,111 @@
+//! Config command - inspects the effective configuration
+
+use std::io::Write;
+
+use clap::{Args, Subcommand};
+use loco_rs::environment::Environment;
+use mcb_domain::registry::config::{ConfigProviderConfig, resolve_config_provider};
+use mcb_infrastructure::config::app::AppConfig;
+
+use super::OutputFormat;
+use crate::loco_app::McbApp;
+
+/// Key fragments whose values are masked in `config show --resolved` output.
+const SECRET_KEY_MARKERS: [&str; 4] = ["secret", "api_key", "password", "token"];
+
+/// Placeholder printed instead of a secret value.
+const REDACTED: &str = "***";
+
+/// Arguments for the `config` command
+#[derive(Args, Debug, Clone)]
+pub struct ConfigArgs {
+    /// Config operation to run
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+/// Config operations
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommand {
+    /// Print the layered `settings:` tree (base + profile + `MCB__*` overrides)
+    Show {
+        /// Resolve secrets, deserialize and validate, then print the effective
+        /// `AppConfig` (secret values are masked)
+        #[arg(long)]
+        resolved: bool,
+    },
+}
+
+impl ConfigArgs {
+    /// Execute the config command
+    /// # Errors
+    /// Returns an error if the configuration cannot be loaded, resolved, or printed.
+    pub async fn execute(
+        self,
+        profile: Option<&str>,
+        output_format: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let ConfigCommand::Show { resolved } = self.command;
+
+        let environment = Environment::from(loco_rs::environment::resolve_from_env());
+        let profile = mcb_infrastructure::config::profile::active_profile(profile);
+        let loco_config = McbApp::load_layered_config(&environment, profile.as_deref()).await?;
+        let settings = loco_config
+            .settings
+            .ok_or("loaded config has no `settings:` section")?;
+
+        let output = if resolved {
+            let mut effective = serde_json::to_value(resolve_app_config(&settings)?)?;
+            redact_secrets(&mut effective);
+            effective
+        } else {
+            settings
+        };
+
+        let mut out = std::io::stdout();
+        if output_format.is_machine() {
+            output_format.write_value(&mut out, &output)?;
+        } else {
+            writeln!(out, "{}", serde_yaml::to_string(&output)?)?;
+        }
+        Ok(())
+    }
+}
+
+/// Deserialize and validate settings through the registered config provider.
+pub(crate) fn resolve_app_config(
+    settings: &serde_json::Value,
+) -> Result<AppConfig, Box<dyn std::error::Error>> {
+    let provider = resolve_config_provider(&ConfigProviderConfig::new(
+        mcb_utils::constants::DEFAULT_CONFIG_PROVIDER,
+    ))?;
+    let config = provider
+        .deserialize_from_value(settings)?
+        .downcast::<AppConfig>()
+        .map_err(|_| "ConfigProvider returned unexpected type")?;
+    Ok(*config)
+}
+
+/// Mask non-empty string values whose key looks like a secret.
+fn redact_secrets(value: &mut serde_json::Value) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                let is_secret = SECRET_KEY_MARKERS.iter().any(|m| key.contains(m));
+                if let serde_json::Value::String(s) = child
+                    && is_secret
+                    && !s.is_empty()
+                {
+                    *s = REDACTED.to_owned();
+                } else {
+                    redact_secrets(child);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => items.iter_mut().for_each(redact_secrets),
+        serde_json::Value::Null
+        | serde_json::Value::Bool(_)
+        | serde_json::Value::Number(_)
+        | serde_json::Value::String(_) => {}
+    }
+}
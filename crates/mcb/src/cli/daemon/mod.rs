@@ -0,0 +1,132 @@
+//! Daemon command - background server process management
+//!
+//! `start` spawns `mcb serve --server` detached from the terminal, appends its
+//! output to `operations_daemon.daemon.log_file` and records the PID in
+//! `operations_daemon.daemon.pid_file`. The log is rotated by size
+//! (`logging.max_file_size` / `logging.max_files`) only when the daemon
+//! starts: the server writes straight to the inherited file descriptor, so a
+//! long-running daemon keeps growing one file until it is restarted.
+//! `stop` sends SIGTERM so the server drains in-flight requests, and `status`
+//! reports uptime plus live data from the admin API when an admin key is
+//! available.
+//!
+//! Process groups and signals are Unix-only; elsewhere every daemon
+//! operation fails with an error.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Subcommand};
+use serde::Serialize;
+
+#[cfg(unix)]
+mod unix;
+
+#[cfg(unix)]
+pub use unix::running_pid;
+
+#[cfg(not(unix))]
+use super::OutputFormat;
+
+/// Arguments for the `daemon` command
+#[derive(Args, Debug, Clone)]
+pub struct DaemonArgs {
+    /// Daemon operation to run
+    #[command(subcommand)]
+    pub command: DaemonCommand,
+}
+
+/// Daemon operations
+#[derive(Subcommand, Debug, Clone)]
+pub enum DaemonCommand {
+    /// Start the server in the background
+    Start,
+    /// Stop the background server (SIGTERM, then wait for exit)
+    Stop {
+        /// Send SIGKILL if the server has not exited within the shutdown timeout
+        #[arg(long)]
+        force: bool,
+    },
+    /// Report whether the server is running, its uptime and workload
+    Status {
+        /// Admin API key for collection and job details (defaults to `MCB_ADMIN_API_KEY`)
+        #[arg(long)]
+        api_key: Option<String>,
+    },
+}
+
+/// Snapshot reported by `mcb daemon status`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DaemonStatus {
+    /// Whether the pidfile points at a live process
+    pub running: bool,
+    /// PID from the pidfile
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pid: Option<i32>,
+    /// Seconds since the daemon was started
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uptime_secs: Option<u64>,
+    /// Log file receiving server output
+    pub log_file: String,
+    /// Collections known to the vector store (requires an admin key)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collections: Option<Vec<String>>,
+    /// Jobs waiting to run (requires an admin key)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue_depth: Option<u64>,
+    /// Jobs currently running (requires an admin key)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jobs_running: Option<u64>,
+}
+
+#[cfg(not(unix))]
+impl DaemonArgs {
+    /// Execute the daemon command
+    /// # Errors
+    /// Always returns an error: daemon mode is only supported on Unix.
+    pub async fn execute(
+        self,
+        _profile: Option<&str>,
+        _output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        Err("daemon mode is only supported on Unix".into())
+    }
+}
+
+/// Rotate `path` to `path.1` (shifting older files up to `path.{max_files}`)
+/// once it reaches `max_size` bytes. Returns whether a rotation happened.
+///
+/// # Errors
+///
+/// Returns an error if a log file cannot be renamed or removed.
+pub fn rotate_log(path: &Path, max_size: u64, max_files: usize) -> std::io::Result<bool> {
+    let size = match fs::metadata(path) {
+        Ok(meta) => meta.len(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(false),
+        Err(e) => return Err(e),
+    };
+    if max_size == 0 || size < max_size {
+        return Ok(false);
+    }
+    if max_files == 0 {
+        fs::remove_file(path)?;
+        return Ok(true);
+    }
+    let rotated = |n: usize| -> PathBuf {
+        let mut name = path.as_os_str().to_owned();
+        name.push(format!(".{n}"));
+        PathBuf::from(name)
+    };
+    let oldest = rotated(max_files);
+    if oldest.exists() {
+        fs::remove_file(&oldest)?;
+    }
+    for n in (1..max_files).rev() {
+        let from = rotated(n);
+        if from.exists() {
+            fs::rename(&from, rotated(n + 1))?;
+        }
+    }
+    fs::rename(path, rotated(1))?;
+    Ok(true)
+}
@@ -0,0 +1,281 @@
+//! Unix process management behind `mcb daemon start/stop/status`
+
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::os::unix::process::CommandExt;
+use std::path::Path;
+use std::process::Stdio;
+use std::time::{Duration, Instant, SystemTime};
+
+use loco_rs::config::Config as LocoConfig;
+use mcb_infrastructure::config::app::AppConfig;
+use mcb_utils::constants::auth::{ADMIN_API_KEY_ENV, BEARER_PREFIX};
+use mcb_utils::constants::limits::{
+    DAEMON_START_GRACE_MS, DAEMON_STOP_POLL_MS, DOCTOR_PROBE_TIMEOUT_MS,
+};
+use nix::sys::signal::{Signal, kill};
+use nix::unistd::Pid;
+
+use super::{DaemonArgs, DaemonCommand, DaemonStatus, rotate_log};
+use crate::cli::OutputFormat;
+use crate::cli::headless::load_configs;
+
+impl DaemonArgs {
+    /// Execute the daemon command
+    /// # Errors
+    /// Returns an error if the configuration cannot be loaded, the daemon is
+    /// already running (start), does not exit in time (stop), or the pidfile
+    /// or log file cannot be accessed.
+    pub async fn execute(
+        self,
+        profile: Option<&str>,
+        output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let (loco_config, app_config) = load_configs(profile).await?;
+        let daemon = &app_config.operations_daemon.daemon;
+        let mut out = std::io::stdout();
+
+        match self.command {
+            DaemonCommand::Start => {
+                let pid = start(&app_config, profile).await?;
+                writeln!(
+                    out,
+                    "● Started mcb (pid {pid}), logging to {}",
+                    daemon.log_file.display()
+                )?;
+            }
+            DaemonCommand::Stop { force } => {
+                let message = stop(
+                    &daemon.pid_file,
+                    Duration::from_secs(daemon.shutdown_timeout_secs),
+                    force,
+                )
+                .await?;
+                writeln!(out, "● {message}")?;
+            }
+            DaemonCommand::Status { api_key } => {
+                let api_key = api_key.or_else(|| std::env::var(ADMIN_API_KEY_ENV).ok());
+                let status = status(&loco_config, &app_config, api_key.as_deref()).await;
+                if output.is_machine() {
+                    output.write_value(&mut out, &status)?;
+                } else {
+                    write_status(&mut out, &status)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Rotate the log, spawn the server detached and record its PID.
+async fn start(
+    config: &AppConfig,
+    profile: Option<&str>,
+) -> Result<u32, Box<dyn std::error::Error>> {
+    let daemon = &config.operations_daemon.daemon;
+    if let Some(pid) = running_pid(&daemon.pid_file)? {
+        return Err(format!("mcb is already running (pid {pid})").into());
+    }
+
+    if let Some(parent) = daemon.log_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    rotate_log(
+        &daemon.log_file,
+        config.logging.max_file_size,
+        config.logging.max_files,
+    )?;
+    let log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&daemon.log_file)?;
+
+    let mut command = std::process::Command::new(std::env::current_exe()?);
+    if let Some(profile) = profile {
+        command.args(["--profile", profile]);
+    }
+    let mut child = command
+        .args(["serve", "--server"])
+        .stdin(Stdio::null())
+        .stdout(log.try_clone()?)
+        .stderr(log)
+        // New process group: terminal hangups and Ctrl-C no longer reach the server.
+        .process_group(0)
+        .spawn()?;
+
+    tokio::time::sleep(Duration::from_millis(DAEMON_START_GRACE_MS)).await;
+    if let Some(exit) = child.try_wait()? {
+        return Err(format!(
+            "server exited during startup ({exit}); see {}",
+            daemon.log_file.display()
+        )
+        .into());
+    }
+
+    if let Some(parent) = daemon.pid_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&daemon.pid_file, format!("{}\n", child.id()))?;
+    Ok(child.id())
+}
+
+/// Signal the daemon to shut down and wait for it to exit.
+async fn stop(
+    pid_file: &Path,
+    timeout: Duration,
+    force: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let Some(pid) = running_pid(pid_file)? else {
+        return Ok("mcb is not running".to_owned());
+    };
+
+    kill(Pid::from_raw(pid), Signal::SIGTERM)?;
+    let deadline = Instant::now() + timeout;
+    while Instant::now() < deadline {
+        if !is_alive(pid) {
+            fs::remove_file(pid_file)?;
+            return Ok(format!("Stopped mcb (pid {pid})"));
+        }
+        tokio::time::sleep(Duration::from_millis(DAEMON_STOP_POLL_MS)).await;
+    }
+
+    if !force {
+        return Err(format!(
+            "mcb (pid {pid}) did not exit within {}s; retry with --force to send SIGKILL",
+            timeout.as_secs()
+        )
+        .into());
+    }
+    kill(Pid::from_raw(pid), Signal::SIGKILL)?;
+    fs::remove_file(pid_file)?;
+    Ok(format!("Killed mcb (pid {pid}) after shutdown timeout"))
+}
+
+async fn status(
+    loco_config: &LocoConfig,
+    config: &AppConfig,
+    api_key: Option<&str>,
+) -> DaemonStatus {
+    let daemon = &config.operations_daemon.daemon;
+    let mut status = DaemonStatus {
+        log_file: daemon.log_file.display().to_string(),
+        ..DaemonStatus::default()
+    };
+    let Ok(Some(pid)) = running_pid(&daemon.pid_file) else {
+        return status;
+    };
+    status.running = true;
+    status.pid = Some(pid);
+    status.uptime_secs = fs::metadata(&daemon.pid_file)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|started| SystemTime::now().duration_since(started).ok())
+        .map(|d| d.as_secs());
+
+    if let Some(key) = api_key {
+        let base = format!("http://127.0.0.1:{}", server_port(loco_config));
+        if let Some(collections) = admin_get(&base, "/collections", key).await {
+            status.collections = collections.as_array().map(|items| {
+                items
+                    .iter()
+                    .filter_map(|c| c.get("name").and_then(serde_json::Value::as_str))
+                    .map(str::to_owned)
+                    .collect()
+            });
+        }
+        if let Some(jobs) = admin_get(&base, "/jobs", key).await {
+            status.queue_depth = jobs.get("queued").and_then(serde_json::Value::as_u64);
+            status.jobs_running = jobs.get("running").and_then(serde_json::Value::as_u64);
+        }
+    }
+    status
+}
+
+/// Port the daemon listens on; mirrors the `SERVER_PORT` override in `serve`.
+fn server_port(loco_config: &LocoConfig) -> i32 {
+    std::env::var("SERVER_PORT")
+        .ok()
+        .and_then(|p| p.parse().ok())
+        .unwrap_or(loco_config.server.port)
+}
+
+async fn admin_get(base: &str, path: &str, api_key: &str) -> Option<serde_json::Value> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(DOCTOR_PROBE_TIMEOUT_MS))
+        .build()
+        .ok()?;
+    client
+        .get(format!("{base}{path}"))
+        .header("authorization", format!("{BEARER_PREFIX}{api_key}"))
+        .send()
+        .await
+        .ok()?
+        .error_for_status()
+        .ok()?
+        .json()
+        .await
+        .ok()
+}
+
+fn write_status(out: &mut impl Write, status: &DaemonStatus) -> std::io::Result<()> {
+    if !status.running {
+        writeln!(out, "mcb is not running")?;
+        return writeln!(out, "{:<12} {}", "log", status.log_file);
+    }
+    writeln!(out, "{:<12} {}", "pid", status.pid.unwrap_or_default())?;
+    writeln!(
+        out,
+        "{:<12} {}s",
+        "uptime",
+        status.uptime_secs.unwrap_or_default()
+    )?;
+    writeln!(out, "{:<12} {}", "log", status.log_file)?;
+    match (&status.collections, status.queue_depth) {
+        (None, None) => writeln!(
+            out,
+            "{:<12} set {ADMIN_API_KEY_ENV} or pass --api-key for collection and job details",
+            "workload"
+        ),
+        (collections, queue_depth) => {
+            if let Some(collections) = collections {
+                writeln!(out, "{:<12} {}", "collections", collections.join(", "))?;
+            }
+            writeln!(
+                out,
+                "{:<12} {} queued, {} running",
+                "jobs",
+                queue_depth.unwrap_or_default(),
+                status.jobs_running.unwrap_or_default()
+            )
+        }
+    }
+}
+
+/// PID from `pid_file` if that process is alive. A stale pidfile is removed.
+///
+/// # Errors
+///
+/// Returns an error if the pidfile exists but cannot be read, parsed or removed.
+pub fn running_pid(pid_file: &Path) -> std::io::Result<Option<i32>> {
+    let raw = match fs::read_to_string(pid_file) {
+        Ok(raw) => raw,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let pid: i32 = raw.trim().parse().map_err(|_| {
+        std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("{} does not contain a PID", pid_file.display()),
+        )
+    })?;
+    if is_alive(pid) {
+        Ok(Some(pid))
+    } else {
+        fs::remove_file(pid_file)?;
+        Ok(None)
+    }
+}
+
+fn is_alive(pid: i32) -> bool {
+    pid > 0 && kill(Pid::from_raw(pid), None).is_ok()
+}
@@ -0,0 +1,415 @@
+//! Doctor command - environment diagnostics
+//!
+//! Runs a fixed sequence of checks against the effective configuration and
+//! prints a color-coded report with a remediation hint for every problem.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use clap::Args;
+use loco_rs::config::Config as LocoConfig;
+use loco_rs::environment::Environment;
+use mcb_domain::registry::embedding::resolve_embedding_provider;
+use mcb_domain::registry::vector_store::resolve_vector_store_provider;
+use mcb_infrastructure::config::app::AppConfig;
+use mcb_infrastructure::config::infrastructure::CacheProvider;
+use mcb_utils::constants::PROVIDER_SLUG_OLLAMA;
+use mcb_utils::constants::embedding::{INIT_PROBE_TIMEOUT_SECS, OLLAMA_TAGS_ENDPOINT};
+use mcb_utils::constants::limits::{
+    DOCTOR_DISK_FAIL_BYTES, DOCTOR_DISK_WARN_BYTES, DOCTOR_PROBE_TIMEOUT_MS,
+};
+use serde::Serialize;
+
+use super::OutputFormat;
+use super::config::resolve_app_config;
+use crate::initializers::mcp_server::{build_embedding_config, build_vector_store_config};
+use crate::loco_app::McbApp;
+
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+const RED: &str = "\x1b[31m";
+const RESET: &str = "\x1b[0m";
+
+/// Arguments for the `doctor` command
+#[derive(Args, Debug, Clone)]
+pub struct DoctorArgs {
+    /// Disable ANSI colors in the report
+    #[arg(long)]
+    pub no_color: bool,
+}
+
+/// Outcome of a single diagnostic check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// Check passed
+    Pass,
+    /// Check passed with a problem worth fixing
+    Warn,
+    /// Check failed
+    Fail,
+}
+
+/// A single diagnostic result
+#[derive(Debug, Clone, Serialize)]
+pub struct CheckResult {
+    /// Check name
+    pub name: String,
+    /// Outcome
+    pub status: CheckStatus,
+    /// What was observed
+    pub detail: String,
+    /// How to fix it (only for warnings and failures)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+impl CheckResult {
+    fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_owned(),
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            hint: None,
+        }
+    }
+
+    fn problem(
+        name: &str,
+        status: CheckStatus,
+        detail: impl Into<String>,
+        hint: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.to_owned(),
+            status,
+            detail: detail.into(),
+            hint: Some(hint.into()),
+        }
+    }
+}
+
+/// Doctor report for exit code determination
+pub struct DoctorReport {
+    /// All check results in execution order
+    pub checks: Vec<CheckResult>,
+}
+
+impl DoctorReport {
+    /// Returns true if any check failed
+    #[must_use]
+    pub fn failed(&self) -> bool {
+        self.checks.iter().any(|c| c.status == CheckStatus::Fail)
+    }
+}
+
+impl DoctorArgs {
+    /// Execute the doctor command
+    /// # Errors
+    /// Returns an error only if the report cannot be written; failed checks
+    /// are reported through [`DoctorReport::failed`].
+    pub async fn execute(
+        self,
+        profile: Option<&str>,
+        output: OutputFormat,
+    ) -> Result<DoctorReport, Box<dyn std::error::Error>> {
+        let checks = run_checks(profile).await;
+        if output.is_machine() {
+            output.write_records(&mut std::io::stdout(), &checks)?;
+        } else {
+            self.print(&checks)?;
+        }
+        Ok(DoctorReport { checks })
+    }
+
+    fn print(&self, checks: &[CheckResult]) -> std::io::Result<()> {
+        let mut out = std::io::stdout();
+        for check in checks {
+            let (color, mark) = match check.status {
+                CheckStatus::Pass => (GREEN, "✔"),
+                CheckStatus::Warn => (YELLOW, "!"),
+                CheckStatus::Fail => (RED, "✘"),
+            };
+            let (color, reset) = if self.no_color {
+                ("", "")
+            } else {
+                (color, RESET)
+            };
+            writeln!(
+                out,
+                "{color}{mark} {:<16}{reset} {}",
+                check.name, check.detail
+            )?;
+            if let Some(ref hint) = check.hint {
+                writeln!(out, "    → {hint}")?;
+            }
+        }
+        let failures = checks
+            .iter()
+            .filter(|c| c.status == CheckStatus::Fail)
+            .count();
+        let warnings = checks
+            .iter()
+            .filter(|c| c.status == CheckStatus::Warn)
+            .count();
+        writeln!(out, "\n{failures} failure(s), {warnings} warning(s)")
+    }
+}
+
+async fn run_checks(profile: Option<&str>) -> Vec<CheckResult> {
+    let environment = Environment::from(loco_rs::environment::resolve_from_env());
+    let profile = mcb_infrastructure::config::profile::active_profile(profile);
+
+    let loco_config = match McbApp::load_layered_config(&environment, profile.as_deref()).await {
+        Ok(config) => config,
+        Err(e) => return vec![config_failure(&e.to_string())],
+    };
+    let app_config = match loco_config
+        .settings
+        .as_ref()
+        .ok_or_else(|| Box::<dyn std::error::Error>::from("no `settings:` section"))
+        .and_then(resolve_app_config)
+    {
+        Ok(config) => config,
+        Err(e) => return vec![config_failure(&e.to_string())],
+    };
+
+    let mut checks = vec![CheckResult::pass(
+        "config",
+        format!("valid ({environment})"),
+    )];
+    checks.push(check_embedding(&app_config).await);
+    if let Some(model_check) = check_ollama_model(&app_config).await {
+        checks.push(model_check);
+    }
+    checks.push(check_vector_store(&app_config).await);
+    checks.push(check_cache(&app_config).await);
+    checks.push(check_database(&loco_config).await);
+    checks.extend(check_disk(&app_config, &loco_config));
+    checks
+}
+
+fn config_failure(detail: &str) -> CheckResult {
+    CheckResult::problem(
+        "config",
+        CheckStatus::Fail,
+        detail,
+        "run `mcb config show` to inspect the layered settings, or `mcb init` to generate a starter config",
+    )
+}
+
+async fn check_embedding(config: &AppConfig) -> CheckResult {
+    let provider_config = build_embedding_config(config);
+    let name = provider_config.provider.clone();
+    let provider = match resolve_embedding_provider(&provider_config) {
+        Ok(p) => p,
+        Err(e) => {
+            return CheckResult::problem(
+                "embedding",
+                CheckStatus::Fail,
+                format!("{name}: {e}"),
+                "check settings.providers.embedding (provider, base_url, api_key)",
+            );
+        }
+    };
+    if let Err(e) = provider.health_check().await {
+        return CheckResult::problem(
+            "embedding",
+            CheckStatus::Fail,
+            format!("{name}: {e}"),
+            "make sure the provider is reachable and the API key / model are valid",
+        );
+    }
+    match config.providers.embedding.dimensions {
+        Some(configured) if configured != provider.dimensions() => CheckResult::problem(
+            "embedding",
+            CheckStatus::Warn,
+            format!(
+                "{name}: model produces {} dimensions, config says {configured}",
+                provider.dimensions()
+            ),
+            "align providers.embedding.dimensions and providers.vector_store.dimensions with the model",
+        ),
+        Some(_) | None => CheckResult::pass(
+            "embedding",
+            format!("{name} reachable ({} dims)", provider.dimensions()),
+        ),
+    }
+}
+
+/// Ollama serves only pulled models; verify the configured one is present.
+async fn check_ollama_model(config: &AppConfig) -> Option<CheckResult> {
+    let embedding = &config.providers.embedding;
+    if embedding.provider.as_deref() != Some(PROVIDER_SLUG_OLLAMA) {
+        return None;
+    }
+    let base_url = embedding.base_url.as_deref()?;
+    let model = embedding.model.as_deref()?;
+
+    let tags = async {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(INIT_PROBE_TIMEOUT_SECS))
+            .build()?
+            .get(format!("{base_url}{OLLAMA_TAGS_ENDPOINT}"))
+            .send()
+            .await?
+            .json::<serde_json::Value>()
+            .await
+    }
+    .await;
+
+    Some(match tags {
+        Ok(body) => {
+            let pulled = body["models"].as_array().is_some_and(|models| {
+                models.iter().filter_map(|m| m["name"].as_str()).any(|n| {
+                    n == model
+                        || n.strip_prefix(model)
+                            .is_some_and(|tag| tag.starts_with(':'))
+                })
+            });
+            if pulled {
+                CheckResult::pass("model", format!("{model} available in Ollama"))
+            } else {
+                CheckResult::problem(
+                    "model",
+                    CheckStatus::Fail,
+                    format!("{model} is not pulled"),
+                    format!("ollama pull {model}"),
+                )
+            }
+        }
+        Err(e) => CheckResult::problem(
+            "model",
+            CheckStatus::Fail,
+            format!("cannot list Ollama models: {e}"),
+            "start Ollama (`ollama serve`) or fix providers.embedding.base_url",
+        ),
+    })
+}
+
+async fn check_vector_store(config: &AppConfig) -> CheckResult {
+    let provider_config = build_vector_store_config(config);
+    let name = provider_config.provider.clone();
+    let result = match resolve_vector_store_provider(&provider_config) {
+        Ok(provider) => provider.health_check().await,
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(()) => CheckResult::pass("vector store", format!("{name} reachable")),
+        Err(e) => CheckResult::problem(
+            "vector store",
+            CheckStatus::Fail,
+            format!("{name}: {e}"),
+            "check settings.providers.vector_store.address and that the server is running",
+        ),
+    }
+}
+
+async fn check_cache(config: &AppConfig) -> CheckResult {
+    let cache = &config.system.infrastructure.cache;
+    if !cache.enabled {
+        return CheckResult::pass("cache", "disabled");
+    }
+    match cache.provider {
+        CacheProvider::Moka => CheckResult::pass("cache", "in-process (moka)"),
+        CacheProvider::Redis => {
+            let Some(url) = cache.redis_url.as_deref() else {
+                return CheckResult::problem(
+                    "cache",
+                    CheckStatus::Fail,
+                    "redis selected but redis_url is not set",
+                    "set system.infrastructure.cache.redis_url",
+                );
+            };
+            if tcp_reachable(url).await {
+                CheckResult::pass("cache", format!("redis reachable at {url}"))
+            } else {
+                CheckResult::problem(
+                    "cache",
+                    CheckStatus::Fail,
+                    format!("redis unreachable at {url}"),
+                    "start Redis or switch system.infrastructure.cache.provider to Moka",
+                )
+            }
+        }
+    }
+}
+
+/// TCP reachability of a `scheme://host:port/...` URL. Name resolution and
+/// the connect both count against the probe timeout.
+async fn tcp_reachable(url: &str) -> bool {
+    let authority = url
+        .split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split(['/', '?'])
+        .next()
+        .unwrap_or_default();
+    let host_port = authority.rsplit_once('@').map_or(authority, |(_, hp)| hp);
+    tokio::time::timeout(
+        Duration::from_millis(DOCTOR_PROBE_TIMEOUT_MS),
+        tokio::net::TcpStream::connect(host_port),
+    )
+    .await
+    .is_ok_and(|connected| connected.is_ok())
+}
+
+async fn check_database(loco_config: &LocoConfig) -> CheckResult {
+    let result = match loco_rs::db::connect(&loco_config.database).await {
+        Ok(db) => db.ping().await,
+        Err(e) => Err(e),
+    };
+    match result {
+        Ok(()) => CheckResult::pass("database", "connected"),
+        Err(e) => CheckResult::problem(
+            "database",
+            CheckStatus::Fail,
+            e.to_string(),
+            "check database.uri in the config file (and DATABASE_URL if templated)",
+        ),
+    }
+}
+
+/// Free space under every on-disk store the config points at.
+fn check_disk(config: &AppConfig, loco_config: &LocoConfig) -> Vec<CheckResult> {
+    let mut paths: Vec<PathBuf> = vec![config.system.data.snapshot.directory.clone()];
+    if let Some(db_path) = loco_config
+        .database
+        .uri
+        .strip_prefix("sqlite://")
+        .and_then(|rest| rest.split('?').next())
+        .filter(|p| !p.is_empty() && *p != ":memory:")
+    {
+        paths.push(PathBuf::from(db_path));
+    }
+    paths.iter().map(|p| check_free_space(p)).collect()
+}
+
+fn check_free_space(path: &Path) -> CheckResult {
+    let probe = path
+        .ancestors()
+        .find(|p| !p.as_os_str().is_empty() && p.exists())
+        .unwrap_or_else(|| Path::new("."));
+    let label = format!("disk {}", path.display());
+    match fs2::available_space(probe) {
+        Ok(free) if free < DOCTOR_DISK_FAIL_BYTES => CheckResult::problem(
+            &label,
+            CheckStatus::Fail,
+            format!("{} MiB free", free / (1024 * 1024)),
+            "free disk space or move the store to a larger volume",
+        ),
+        Ok(free) if free < DOCTOR_DISK_WARN_BYTES => CheckResult::problem(
+            &label,
+            CheckStatus::Warn,
+            format!("{} MiB free", free / (1024 * 1024)),
+            "indexing large repositories may run out of space",
+        ),
+        Ok(free) => CheckResult::pass(&label, format!("{} GiB free", free / (1024 * 1024 * 1024))),
+        Err(e) => CheckResult::problem(
+            &label,
+            CheckStatus::Warn,
+            format!("cannot stat {}: {e}", probe.display()),
+            "verify the path exists and is readable",
+        ),
+    }
+}
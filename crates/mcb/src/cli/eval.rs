@@ -0,0 +1,198 @@
+//! Eval command - retrieval quality against a labeled query set
+//!
+//! Runs every query of a YAML query set through the search use case and
+//! scores the top `k` hits against the files and symbols each query is
+//! expected to find, reporting recall@k, MRR and nDCG@k so ranking changes
+//! can be measured run over run.
+//!
+//! ```yaml
+//! queries:
+//!   - query: where are refresh tokens rotated
+//!     files: [src/auth/refresh.rs]
+//!     symbols: [rotate_refresh_token]
+//! ```
+//!
+//! With `--synthetic` the queries are generated from the collection's own
+//! doc comments and symbol names instead, and the means are combined into
+//! an index-quality score.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use clap::Args;
+use mcb_domain::ports::{SelfEvalOptions, SelfEvalReport};
+use mcb_domain::value_objects::{EvalReport, LabeledQuery};
+use mcb_utils::constants::limits::DEFAULT_SEARCH_LIMIT;
+use serde::{Deserialize, Serialize};
+
+use super::OutputFormat;
+use crate::engine::Engine;
+
+/// Arguments for the `eval` command
+#[derive(Args, Debug, Clone)]
+pub struct EvalArgs {
+    /// YAML query set (`queries: [{ query, files, symbols }]`)
+    #[arg(required_unless_present = "synthetic", conflicts_with = "synthetic")]
+    pub query_set: Option<PathBuf>,
+
+    /// Collection to search
+    #[arg(long, short = 'c')]
+    pub collection: String,
+
+    /// Rank cutoff for recall@k, MRR and nDCG@k
+    #[arg(long, short = 'k', default_value_t = DEFAULT_SEARCH_LIMIT)]
+    pub k: usize,
+
+    /// Generate queries from this many sampled files instead of reading a query set
+    #[arg(long, value_name = "FILES")]
+    pub synthetic: Option<usize>,
+
+    /// Write the generated queries to this path as a query set
+    #[arg(long, requires = "synthetic")]
+    pub save_queries: Option<PathBuf>,
+}
+
+/// Labeled queries loaded from a query set file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QuerySet {
+    /// Queries to evaluate, in file order
+    pub queries: Vec<LabeledQuery>,
+}
+
+impl EvalArgs {
+    /// Execute the eval command
+    /// # Errors
+    /// Returns an error if the query set cannot be read or is invalid, the
+    /// services cannot be resolved, no synthetic query can be generated, a
+    /// search fails, or the generated queries cannot be saved.
+    pub async fn execute(
+        self,
+        profile: Option<&str>,
+        output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.k == 0 {
+            return Err("--k must be at least 1".into());
+        }
+        let engine = Engine::from_config(profile).await?;
+        let mut out = std::io::stdout();
+
+        if let Some(sample_size) = self.synthetic {
+            let report = engine
+                .self_evaluate(
+                    &self.collection,
+                    &SelfEvalOptions {
+                        sample_size,
+                        k: self.k,
+                    },
+                )
+                .await?;
+            if let Some(path) = &self.save_queries {
+                let set = QuerySet {
+                    queries: report.queries.clone(),
+                };
+                std::fs::write(path, serde_yaml::to_string(&set)?)
+                    .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+            }
+            if output.is_machine() {
+                output.write_value(&mut out, &report)?;
+            } else {
+                write_self_eval(&mut out, &report)?;
+            }
+            return Ok(());
+        }
+
+        let path = self
+            .query_set
+            .ok_or("a query set or --synthetic is required")?;
+        let text = std::fs::read_to_string(&path)
+            .map_err(|e| format!("cannot read {}: {e}", path.display()))?;
+        let query_set = QuerySet::from_yaml(&text)?;
+        let mut scores = Vec::with_capacity(query_set.queries.len());
+        for labeled in &query_set.queries {
+            let results = engine
+                .search(&self.collection, &labeled.query, self.k)
+                .await?;
+            scores.push(labeled.score(&results, self.k));
+        }
+        let report = EvalReport::new(self.collection, self.k, scores);
+
+        if output.is_machine() {
+            output.write_value(&mut out, &report)?;
+        } else {
+            write_report(&mut out, &report)?;
+        }
+        Ok(())
+    }
+}
+
+impl QuerySet {
+    /// Parse a query set, rejecting empty sets and queries without targets.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the YAML is malformed or a query cannot be scored.
+    pub fn from_yaml(text: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let set: Self = serde_yaml::from_str(text)?;
+        if set.queries.is_empty() {
+            return Err("query set has no queries".into());
+        }
+        if let Some(unlabeled) = set.queries.iter().find(|q| q.target_count() == 0) {
+            return Err(format!(
+                "query {:?} lists no expected files or symbols",
+                unlabeled.query
+            )
+            .into());
+        }
+        Ok(set)
+    }
+}
+
+/// Render per-query scores and the means as a fixed-width table.
+///
+/// # Errors
+///
+/// Returns an error if writing to `out` fails.
+pub fn write_report(out: &mut impl Write, report: &EvalReport) -> std::io::Result<()> {
+    let k = report.k;
+    let recall_header = format!("recall@{k}");
+    let ndcg_header = format!("ndcg@{k}");
+    writeln!(
+        out,
+        "{recall_header:>9}  {:>6}  {ndcg_header:>8}  {:>5}  query",
+        "rr", "first"
+    )?;
+    for q in &report.queries {
+        let first = q
+            .first_relevant_rank
+            .map_or_else(|| "-".to_owned(), |rank| rank.to_string());
+        writeln!(
+            out,
+            "{:>9.3}  {:>6.3}  {:>8.3}  {first:>5}  {}",
+            q.recall, q.reciprocal_rank, q.ndcg, q.query
+        )?;
+    }
+    writeln!(
+        out,
+        "\n{} queries on {}: recall@{k} {:.3}  MRR {:.3}  nDCG@{k} {:.3}",
+        report.queries.len(),
+        report.collection,
+        report.recall,
+        report.mrr,
+        report.ndcg
+    )
+}
+
+/// Render a synthetic evaluation: the per-query table and the quality score.
+///
+/// # Errors
+///
+/// Returns an error if writing to `out` fails.
+pub fn write_self_eval(out: &mut impl Write, report: &SelfEvalReport) -> std::io::Result<()> {
+    write_report(out, &report.eval)?;
+    writeln!(
+        out,
+        "index quality {:.3} from {} sampled files",
+        report.quality_score, report.files_sampled
+    )
+}
@@ -0,0 +1,56 @@
+//! Headless service wiring for one-shot CLI commands
+//!
+//! `index`, `search` and the embeddable [`crate::Engine`] drive the
+//! application services directly, without booting the HTTP server or the MCP
+//! transports. This module resolves the layered configuration, connects (and
+//! migrates) the database, and builds the same [`ServiceResolutionContext`]
+//! the MCP initializer uses. `daemon` only needs the configuration and uses
+//! [`load_configs`].
+
+use loco_rs::config::Config as LocoConfig;
+use loco_rs::environment::Environment;
+use mcb_domain::registry::ServiceResolutionContext;
+use mcb_infrastructure::config::app::AppConfig;
+use mcb_infrastructure::infrastructure::DynamicMigrator;
+
+use super::config::resolve_app_config;
+use crate::initializers::mcp_server::{build_resolution_ctx, resolve_metrics};
+use crate::loco_app::McbApp;
+
+/// Build a service resolution context from the effective configuration.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded or validated, the
+/// database is unreachable or cannot be migrated, or a provider fails to resolve.
+pub(crate) async fn resolution_context(
+    profile: Option<&str>,
+) -> Result<ServiceResolutionContext, Box<dyn std::error::Error>> {
+    let (loco_config, app_config) = load_configs(profile).await?;
+
+    let db = loco_rs::db::connect(&loco_config.database).await?;
+    if loco_config.database.auto_migrate {
+        loco_rs::db::migrate::<DynamicMigrator>(&db).await?;
+    }
+
+    Ok(build_resolution_ctx(&db, app_config, &resolve_metrics()?)?)
+}
+
+/// Load the layered Loco config and the validated `AppConfig` it carries.
+///
+/// # Errors
+///
+/// Returns an error if the configuration cannot be loaded or validated.
+pub(crate) async fn load_configs(
+    profile: Option<&str>,
+) -> Result<(LocoConfig, AppConfig), Box<dyn std::error::Error>> {
+    let environment = Environment::from(loco_rs::environment::resolve_from_env());
+    let profile = mcb_infrastructure::config::profile::active_profile(profile);
+    let loco_config = McbApp::load_layered_config(&environment, profile.as_deref()).await?;
+    let settings = loco_config
+        .settings
+        .as_ref()
+        .ok_or("loaded config has no `settings:` section")?;
+    let app_config = resolve_app_config(settings)?;
+    Ok((loco_config, app_config))
+}
@@ -0,0 +1,157 @@
+//! Index command - index a codebase without running the server
+//!
+//! Drives the indexing use case through the embeddable [`Engine`] and waits
+//! for the background task to finish, so `mcb index` can be used from
+//! scripts and CI. `--dry-run` only walks and chunks the codebase and
+//! reports what a run would cost, without the database or any provider API.
+
+use std::io::Write;
+use std::path::PathBuf;
+
+use clap::Args;
+use mcb_domain::ports::IndexingEstimate;
+use mcb_domain::registry::language::{LanguageProviderConfig, resolve_language_provider};
+use mcb_infrastructure::services::{estimate_codebase, resolve_allowed_path};
+use mcb_utils::constants::DEFAULT_LANGUAGE_PROVIDER;
+
+use super::OutputFormat;
+use super::headless::load_configs;
+use crate::engine::Engine;
+pub use crate::engine::IndexSummary;
+
+/// Arguments for the `index` command
+#[derive(Args, Debug, Clone)]
+pub struct IndexArgs {
+    /// Root directory of the codebase to index
+    pub path: PathBuf,
+
+    /// Target collection name
+    #[arg(long, short = 'c', required_unless_present = "dry_run")]
+    pub collection: Option<String>,
+
+    /// Report chunk counts, token and cost estimates and projected storage
+    /// instead of indexing
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl IndexArgs {
+    /// Execute the index command
+    /// # Errors
+    /// Returns an error if the path or collection is invalid, the services
+    /// cannot be resolved, or indexing fails to start or finish.
+    pub async fn execute(
+        self,
+        profile: Option<&str>,
+        output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if self.dry_run {
+            return self.estimate(profile, output).await;
+        }
+        let collection = self.collection.ok_or("--collection is required")?;
+        let engine = Engine::from_config(profile).await?;
+        let mut reported = false;
+        let summary = engine
+            .index_path_with_progress(&self.path, &collection, |status| {
+                if !output.is_machine() && status.is_indexing {
+                    reported = true;
+                    let _ = write!(
+                        std::io::stderr(),
+                        "\r● {}/{} files",
+                        status.processed_files,
+                        status.total_files
+                    );
+                }
+            })
+            .await?;
+        if reported {
+            let _ = writeln!(std::io::stderr());
+        }
+
+        let mut out = std::io::stdout();
+        if output.is_machine() {
+            output.write_value(&mut out, &summary)?;
+        } else {
+            writeln!(out, "{:<12} {}", "collection", summary.collection)?;
+            writeln!(out, "{:<12} {}", "path", summary.path)?;
+            writeln!(out, "{:<12} {}", "files", summary.files)?;
+            writeln!(out, "{:<12} {}", "chunks", summary.chunks)?;
+            writeln!(out, "{:<12} {}", "duration_ms", summary.duration_ms)?;
+        }
+        Ok(())
+    }
+
+    /// Walk and chunk the codebase and print the estimate.
+    async fn estimate(
+        &self,
+        profile: Option<&str>,
+        output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.path.is_dir() {
+            return Err(format!("{} is not a directory", self.path.display()).into());
+        }
+        let (_, app_config) = load_configs(profile).await?;
+        let path = resolve_allowed_path(&self.path, &app_config.mcp.indexing.allowed_roots)?;
+        let chunker =
+            resolve_language_provider(&LanguageProviderConfig::new(DEFAULT_LANGUAGE_PROVIDER))?;
+        let estimate = estimate_codebase(
+            &path,
+            chunker.as_ref(),
+            app_config.mcp.indexing.supported_extensions,
+        );
+
+        let mut out = std::io::stdout();
+        if output.is_machine() {
+            output.write_value(&mut out, &estimate)?;
+        } else {
+            write_estimate(
+                &mut out,
+                &estimate,
+                app_config.providers.embedding.provider.as_deref(),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Render an estimate as a summary followed by one row per embedding model;
+/// rows of the `configured` provider are marked with `*`.
+///
+/// # Errors
+///
+/// Returns an error if writing to `out` fails.
+pub fn write_estimate(
+    out: &mut impl Write,
+    estimate: &IndexingEstimate,
+    configured: Option<&str>,
+) -> std::io::Result<()> {
+    writeln!(out, "{:<12} {}", "files", estimate.files)?;
+    if estimate.files_skipped > 0 {
+        writeln!(out, "{:<12} {}", "unreadable", estimate.files_skipped)?;
+    }
+    writeln!(out, "{:<12} {}", "chunks", estimate.chunks)?;
+    for (language, chunks) in &estimate.chunks_by_language {
+        writeln!(out, "  {language:<10} {chunks}")?;
+    }
+    writeln!(out, "{:<12} {}", "bytes", estimate.content_bytes)?;
+    writeln!(out, "{:<12} {}", "tokens", estimate.estimated_tokens)?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "  {:<10} {:<24} {:>5} {:>10} {:>14}",
+        "provider", "model", "dims", "cost_usd", "storage_bytes"
+    )?;
+    for p in &estimate.projections {
+        let mark = if configured == Some(p.provider.as_str()) {
+            '*'
+        } else {
+            ' '
+        };
+        writeln!(
+            out,
+            "{mark} {:<10} {:<24} {:>5} {:>10.4} {:>14}",
+            p.provider, p.model, p.dimensions, p.cost_usd, p.storage_bytes
+        )?;
+    }
+    Ok(())
+}
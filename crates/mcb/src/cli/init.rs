@@ -0,0 +1,241 @@
+//! Init command - first-run setup wizard
+//!
+//! Detects available embedding providers, verifies the chosen one is
+//! reachable, and writes a starter config to the XDG config directory
+//! (`$XDG_CONFIG_HOME/mcb/config/{env}.yaml`), which is where
+//! [`McbApp::load_config`](crate::McbApp) looks when no local `config/` exists.
+
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::Args;
+use mcb_domain::registry::embedding::{EmbeddingProviderConfig, resolve_embedding_provider};
+use mcb_utils::constants::embedding::{
+    EMBEDDING_DIMENSION_FASTEMBED_DEFAULT, EMBEDDING_DIMENSION_OLLAMA_NOMIC,
+    EMBEDDING_DIMENSION_OPENAI_SMALL, FASTEMBED_DEFAULT_MODEL, INIT_PROBE_TIMEOUT_SECS,
+    OLLAMA_DEFAULT_BASE_URL, OLLAMA_DEFAULT_MODEL, OLLAMA_TAGS_ENDPOINT, OPENAI_API_BASE_URL,
+    OPENAI_API_KEY_ENV, OPENAI_INIT_MODEL,
+};
+use mcb_utils::constants::{PROVIDER_SLUG_EDGEVEC, PROVIDER_SLUG_FASTEMBED, PROVIDER_SLUG_OLLAMA};
+
+/// Base config the starter file is derived from.
+const TEMPLATE: &str = include_str!("../../../../config/development.yaml");
+
+/// Collection name written to the starter config.
+const STARTER_COLLECTION: &str = "default";
+
+/// Arguments for the `init` command
+#[derive(Args, Debug, Clone)]
+pub struct InitArgs {
+    /// Accept the first detected provider without prompting
+    #[arg(long, short = 'y')]
+    pub yes: bool,
+
+    /// Overwrite an existing config file
+    #[arg(long)]
+    pub force: bool,
+
+    /// Write the config here instead of the XDG config path
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+/// An embedding provider the wizard can configure.
+#[derive(Debug, Clone)]
+struct ProviderChoice {
+    slug: &'static str,
+    label: &'static str,
+    model: &'static str,
+    dimensions: usize,
+    base_url: Option<&'static str>,
+    /// API key used for the connectivity check (never written to disk).
+    api_key: Option<String>,
+    /// Value written to the config's `api_key` field.
+    api_key_ref: Option<String>,
+}
+
+impl ProviderChoice {
+    fn provider_config(&self) -> EmbeddingProviderConfig {
+        let mut cfg = EmbeddingProviderConfig::new(self.slug)
+            .with_model(self.model)
+            .with_dimensions(self.dimensions);
+        if let Some(url) = self.base_url {
+            cfg = cfg.with_base_url(url);
+        }
+        if let Some(ref key) = self.api_key {
+            cfg = cfg.with_api_key(key.clone());
+        }
+        cfg
+    }
+}
+
+impl InitArgs {
+    /// Execute the init command
+    /// # Errors
+    /// Returns an error if no provider is usable, the chosen provider fails its
+    /// connectivity check, or the config file cannot be written.
+    pub async fn execute(self) -> Result<(), Box<dyn std::error::Error>> {
+        let target = self.target_path()?;
+        if target.exists() && !self.force {
+            return Err(format!(
+                "{} already exists (use --force to overwrite)",
+                target.display()
+            )
+            .into());
+        }
+
+        let candidates = detect_providers().await;
+        for (idx, c) in candidates.iter().enumerate() {
+            say(&format!("  [{}] {} ({})", idx + 1, c.label, c.model));
+        }
+        let choice = if self.yes {
+            candidates
+                .first()
+                .ok_or("no embedding provider available")?
+        } else {
+            prompt_choice(&candidates)?
+        };
+
+        say(&format!("● Verifying {} ...", choice.label));
+        let provider = resolve_embedding_provider(&choice.provider_config())?;
+        provider.health_check().await.map_err(|e| {
+            format!(
+                "{} is not reachable: {e} — config not written",
+                choice.label
+            )
+        })?;
+
+        let content = render_config(choice)?;
+        if let Some(parent) = target.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&target, content)?;
+        say(&format!("● Wrote {}", target.display()));
+        Ok(())
+    }
+
+    fn target_path(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        if let Some(ref output) = self.output {
+            return Ok(output.clone());
+        }
+        let env_name = loco_rs::environment::resolve_from_env();
+        Ok(dirs::config_dir()
+            .ok_or("cannot determine XDG config directory")?
+            .join("mcb")
+            .join("config")
+            .join(format!("{env_name}.yaml")))
+    }
+}
+
+/// Probe the environment and return usable providers, best first.
+async fn detect_providers() -> Vec<ProviderChoice> {
+    let mut found = Vec::new();
+    if ollama_running().await {
+        found.push(ProviderChoice {
+            slug: PROVIDER_SLUG_OLLAMA,
+            label: "Ollama (running locally)",
+            model: OLLAMA_DEFAULT_MODEL,
+            dimensions: EMBEDDING_DIMENSION_OLLAMA_NOMIC,
+            base_url: Some(OLLAMA_DEFAULT_BASE_URL),
+            api_key: None,
+            api_key_ref: None,
+        });
+    }
+    if let Ok(key) = std::env::var(OPENAI_API_KEY_ENV)
+        && !key.is_empty()
+    {
+        found.push(ProviderChoice {
+            slug: "openai",
+            label: "OpenAI (OPENAI_API_KEY is set)",
+            model: OPENAI_INIT_MODEL,
+            dimensions: EMBEDDING_DIMENSION_OPENAI_SMALL,
+            base_url: Some(OPENAI_API_BASE_URL),
+            api_key: Some(key),
+            api_key_ref: Some(format!("${{{OPENAI_API_KEY_ENV}}}")),
+        });
+    }
+    found.push(ProviderChoice {
+        slug: PROVIDER_SLUG_FASTEMBED,
+        label: "FastEmbed (local ONNX, downloads model on first use)",
+        model: FASTEMBED_DEFAULT_MODEL,
+        dimensions: EMBEDDING_DIMENSION_FASTEMBED_DEFAULT,
+        base_url: None,
+        api_key: None,
+        api_key_ref: None,
+    });
+    found
+}
+
+async fn ollama_running() -> bool {
+    let Ok(client) = reqwest::Client::builder()
+        .timeout(Duration::from_secs(INIT_PROBE_TIMEOUT_SECS))
+        .build()
+    else {
+        return false;
+    };
+    client
+        .get(format!("{OLLAMA_DEFAULT_BASE_URL}{OLLAMA_TAGS_ENDPOINT}"))
+        .send()
+        .await
+        .is_ok_and(|r| r.status().is_success())
+}
+
+fn prompt_choice(
+    candidates: &[ProviderChoice],
+) -> Result<&ProviderChoice, Box<dyn std::error::Error>> {
+    let mut stdout = std::io::stdout();
+    write!(stdout, "Select embedding provider [1]: ")?;
+    stdout.flush()?;
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    let answer = line.trim();
+    let index = if answer.is_empty() {
+        0
+    } else {
+        answer
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .ok_or_else(|| format!("invalid selection '{answer}'"))?
+    };
+    candidates
+        .get(index)
+        .ok_or_else(|| format!("selection {answer} is out of range").into())
+}
+
+/// Derive the starter config from the bundled development template.
+fn render_config(choice: &ProviderChoice) -> Result<String, Box<dyn std::error::Error>> {
+    let mut doc: serde_yaml::Value = serde_yaml::from_str(TEMPLATE)?;
+    let providers = doc
+        .get_mut("settings")
+        .and_then(|s| s.get_mut("providers"))
+        .and_then(serde_yaml::Value::as_mapping_mut)
+        .ok_or("config template has no settings.providers section")?;
+
+    let mut embedding = serde_yaml::Mapping::new();
+    embedding.insert("provider".into(), choice.slug.into());
+    embedding.insert("model".into(), choice.model.into());
+    embedding.insert("dimensions".into(), choice.dimensions.into());
+    if let Some(url) = choice.base_url {
+        embedding.insert("base_url".into(), url.into());
+    }
+    if let Some(ref key_ref) = choice.api_key_ref {
+        embedding.insert("api_key".into(), key_ref.as_str().into());
+    }
+    embedding.insert("configs".into(), serde_yaml::Mapping::new().into());
+
+    let mut vector_store = serde_yaml::Mapping::new();
+    vector_store.insert("provider".into(), PROVIDER_SLUG_EDGEVEC.into());
+    vector_store.insert("dimensions".into(), choice.dimensions.into());
+    vector_store.insert("collection".into(), STARTER_COLLECTION.into());
+    vector_store.insert("configs".into(), serde_yaml::Mapping::new().into());
+
+    providers.insert("embedding".into(), embedding.into());
+    providers.insert("vector_store".into(), vector_store.into());
+    Ok(serde_yaml::to_string(&doc)?)
+}
+
+fn say(msg: &str) {
+    let _ = writeln!(std::io::stdout(), "{msg}");
+}
@@ -3,11 +3,50 @@
 //! Provides subcommand handling for the `mcb` binary:
 //! - `serve` - Run as MCP server (default)
 //! - `validate` - Run architecture validation
+//! - `config` - Inspect the effective configuration
+//! - `init` - First-run setup wizard
+//! - `doctor` - Environment diagnostics
+//! - `index` - Index a codebase headlessly
+//! - `search` - Search a collection headlessly
+//! - `eval` - Measure retrieval quality against a labeled query set
+//! - `completions` - Shell completion scripts
+//! - `daemon` - Background server management
+//!
+//! Result-producing commands honor the global `--output table|json|ndjson`.
 
+/// Shell completion subcommand.
+pub mod completions;
+/// Configuration inspection subcommand.
+pub mod config;
+/// Background server management subcommand.
+pub mod daemon;
+/// Environment diagnostics subcommand.
+pub mod doctor;
+/// Retrieval quality evaluation subcommand.
+pub mod eval;
+/// Headless config and service wiring shared by one-shot subcommands.
+pub(crate) mod headless;
+/// Headless indexing subcommand.
+pub mod index;
+/// First-run setup wizard subcommand.
+pub mod init;
+/// Output format shared by subcommands.
+pub mod output;
+/// Headless search subcommand.
+pub mod search;
 /// MCP server subcommand.
 pub mod serve;
 /// Architecture validation subcommand.
 pub mod validate;
 
+pub use completions::CompletionsArgs;
+pub use config::ConfigArgs;
+pub use daemon::DaemonArgs;
+pub use doctor::DoctorArgs;
+pub use eval::EvalArgs;
+pub use index::IndexArgs;
+pub use init::InitArgs;
+pub use output::OutputFormat;
+pub use search::SearchArgs;
 pub use serve::ServeArgs;
-pub use validate::ValidateArgs;
+pub use validate::{ReportFormat, ValidateArgs, ValidateCommand};
@@ -0,0 +1,74 @@
+//! Output format shared by the CLI subcommands
+//!
+//! `table` is meant for humans; `json` prints one pretty document and
+//! `ndjson` prints one compact JSON record per line for streaming into
+//! `jq`, log shippers and CI steps.
+
+use std::io::Write;
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+/// How a subcommand renders its result on stdout
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Human-readable text
+    #[default]
+    Table,
+    /// A single pretty-printed JSON document
+    Json,
+    /// One compact JSON record per line
+    Ndjson,
+}
+
+impl OutputFormat {
+    /// True for the machine-readable formats.
+    #[must_use]
+    pub fn is_machine(self) -> bool {
+        self != Self::Table
+    }
+
+    /// Write `records` as a JSON array (`json`) or one record per line (`ndjson`).
+    ///
+    /// Does nothing for `table`; callers render their own table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or writing fails.
+    pub fn write_records<T: Serialize>(
+        self,
+        out: &mut impl Write,
+        records: &[T],
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Table => {}
+            Self::Json => writeln!(out, "{}", serde_json::to_string_pretty(records)?)?,
+            Self::Ndjson => {
+                for record in records {
+                    writeln!(out, "{}", serde_json::to_string(record)?)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a single `value` as pretty JSON (`json`) or one compact line (`ndjson`).
+    ///
+    /// Does nothing for `table`; callers render their own table.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization or writing fails.
+    pub fn write_value<T: Serialize>(
+        self,
+        out: &mut impl Write,
+        value: &T,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match self {
+            Self::Table => {}
+            Self::Json => writeln!(out, "{}", serde_json::to_string_pretty(value)?)?,
+            Self::Ndjson => writeln!(out, "{}", serde_json::to_string(value)?)?,
+        }
+        Ok(())
+    }
+}
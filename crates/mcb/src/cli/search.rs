@@ -0,0 +1,101 @@
+//! Search command - semantic search without running the server
+//!
+//! Drives the search use case through the embeddable [`Engine`] against an
+//! indexed collection and prints the hits as a human-readable table or JSON
+//! records.
+
+use std::io::Write;
+
+use clap::Args;
+use mcb_domain::value_objects::SearchResult;
+use mcb_utils::constants::limits::DEFAULT_SEARCH_LIMIT;
+
+use super::OutputFormat;
+use crate::engine::Engine;
+
+/// Width of the content preview column in table output.
+const PREVIEW_WIDTH: usize = 60;
+
+/// Arguments for the `search` command
+#[derive(Args, Debug, Clone)]
+pub struct SearchArgs {
+    /// Natural-language or code query
+    pub query: String,
+
+    /// Collection to search
+    #[arg(long, short = 'c')]
+    pub collection: String,
+
+    /// Maximum number of results
+    #[arg(long, short = 'n', default_value_t = DEFAULT_SEARCH_LIMIT)]
+    pub limit: usize,
+}
+
+impl SearchArgs {
+    /// Execute the search command
+    /// # Errors
+    /// Returns an error if the collection is invalid, the services cannot be
+    /// resolved, or the search fails.
+    pub async fn execute(
+        self,
+        profile: Option<&str>,
+        output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let engine = Engine::from_config(profile).await?;
+        let results = engine
+            .search(&self.collection, &self.query, self.limit)
+            .await?;
+
+        let mut out = std::io::stdout();
+        if output.is_machine() {
+            output.write_records(&mut out, &results)?;
+        } else {
+            write_table(&mut out, &results)?;
+        }
+        Ok(())
+    }
+}
+
+/// Render results as a fixed-width table, one row per hit.
+///
+/// # Errors
+///
+/// Returns an error if writing to `out` fails.
+pub fn write_table(out: &mut impl Write, results: &[SearchResult]) -> std::io::Result<()> {
+    if results.is_empty() {
+        return writeln!(out, "no results");
+    }
+    writeln!(
+        out,
+        "{:>6}  {:<40}  {:>6}  {:<10}  {}",
+        "score", "file", "line", "language", "preview"
+    )?;
+    for r in results {
+        writeln!(
+            out,
+            "{:>6.3}  {:<40}  {:>6}  {:<10}  {}",
+            r.score,
+            r.file_path,
+            r.start_line,
+            r.language,
+            preview(&r.content)
+        )?;
+    }
+    Ok(())
+}
+
+/// First non-blank line of `content`, truncated to [`PREVIEW_WIDTH`] chars.
+fn preview(content: &str) -> String {
+    let line = content
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or_default();
+    if line.chars().count() > PREVIEW_WIDTH {
+        let mut cut: String = line.chars().take(PREVIEW_WIDTH - 1).collect();
+        cut.push('…');
+        cut
+    } else {
+        line.to_owned()
+    }
+}
@@ -14,17 +14,24 @@ pub struct ServeArgs {
     /// Run in stdio-only mode (MCP over stdin/stdout, no HTTP server).
     #[arg(long, help = "Stdio-only mode for MCP clients (no HTTP server)")]
     pub stdio: bool,
+    /// Reject tool calls and admin requests that write.
+    #[arg(
+        long,
+        help = "Read-only mode: disable indexing, writes and GraphQL mutations"
+    )]
+    pub read_only: bool,
 }
 
 impl ServeArgs {
     /// # Errors
     /// Returns an error if Loco boot or MCP server initialization fails.
-    pub async fn execute(self) -> Result<(), Box<dyn std::error::Error>> {
+    pub async fn execute(self, profile: Option<&str>) -> Result<(), Box<dyn std::error::Error>> {
         let environment = Environment::from(loco_rs::environment::resolve_from_env());
-        let mut loco_config = McbApp::load_config(&environment).await?;
+        let profile = mcb_infrastructure::config::profile::active_profile(profile);
+        let mut loco_config = McbApp::load_layered_config(&environment, profile.as_deref()).await?;
 
         // Inject CLI mode flags into Loco config settings.
-        if (self.server || self.stdio)
+        if (self.server || self.stdio || self.read_only)
             && let Some(ref mut settings) = loco_config.settings
             && let Some(mcp) = settings.pointer_mut("/mcp")
             && let Some(mcp_obj) = mcp.as_object_mut()
@@ -35,6 +42,9 @@ impl ServeArgs {
             if self.stdio {
                 mcp_obj.insert("stdio_only".to_owned(), serde_json::json!(true));
             }
+            if self.read_only {
+                mcp_obj.insert("read_only".to_owned(), serde_json::json!(true));
+            }
         }
 
         let boot_result =
@@ -0,0 +1,134 @@
+//! API snapshot, complexity baseline, diagram and API diff subcommands
+
+use std::io::Write;
+
+use super::ValidateArgs;
+
+impl ValidateArgs {
+    /// Write the workspace's current public API to the API snapshot.
+    pub(super) fn bless_api(
+        &self,
+        workspace_root: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_domain::ports::validation::ValidationConfig;
+        use mcb_utils::constants::validate::DEFAULT_API_SNAPSHOT_FILE;
+
+        let snapshot_path = workspace_root.join(DEFAULT_API_SNAPSHOT_FILE);
+        let snapshot = mcb_validate::ApiSnapshot::collect(&ValidationConfig::new(workspace_root))?;
+        snapshot.save(&snapshot_path)?;
+        self.progress(&format!(
+            "● API snapshot with {} public item(s) written to {}",
+            snapshot.item_count(),
+            snapshot_path.display()
+        ));
+        Ok(())
+    }
+
+    /// Write the workspace's current function complexity to the complexity
+    /// baseline, if the complexity delta gate is enabled.
+    pub(super) fn bless_complexity(
+        &self,
+        workspace_root: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_domain::ports::validation::ValidationConfig;
+        use mcb_utils::constants::validate::DEFAULT_COMPLEXITY_BASELINE_FILE;
+
+        if !mcb_validate::FileConfig::load(workspace_root)
+            .rules
+            .complexity_delta
+            .enabled
+        {
+            return Ok(());
+        }
+        let baseline_path = workspace_root.join(DEFAULT_COMPLEXITY_BASELINE_FILE);
+        let baseline =
+            mcb_validate::ComplexityBaseline::collect(&ValidationConfig::new(workspace_root))?;
+        baseline.save(&baseline_path)?;
+        self.progress(&format!(
+            "● Complexity baseline with {} function(s) written to {}",
+            baseline.function_count(),
+            baseline_path.display()
+        ));
+        Ok(())
+    }
+
+    /// Write the crate and module dependency diagrams of `workspace_root`
+    /// to `output` (relative to the workspace root).
+    pub(super) fn write_diagrams(
+        &self,
+        workspace_root: &std::path::Path,
+        output: &std::path::Path,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_domain::ports::validation::ValidationConfig;
+        use mcb_validate::DependencyDiagram;
+
+        let written = DependencyDiagram::write_all(
+            &ValidationConfig::new(workspace_root),
+            &workspace_root.join(output),
+        )?;
+        self.progress(&format!(
+            "● Wrote {} diagram file(s) to {}",
+            written.len(),
+            workspace_root.join(output).display()
+        ));
+        Ok(())
+    }
+
+    /// Print the public API changes between revisions `from` and `to` of
+    /// the repository containing `path`.
+    pub(super) async fn api_diff(
+        &self,
+        path: &std::path::Path,
+        from: &str,
+        to: &str,
+        json: bool,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_domain::registry::vcs::{VcsProviderConfig, resolve_vcs_provider};
+        use mcb_utils::constants::DEFAULT_VCS_PROVIDER;
+        use mcb_validate::ApiChangelog;
+
+        let vcs = resolve_vcs_provider(&VcsProviderConfig::new(DEFAULT_VCS_PROVIDER))?;
+        let repo = vcs.open_repository(path).await?;
+        self.progress(&format!("● Comparing public API: {from}..{to}"));
+        let old = Self::api_snapshot_at(vcs.as_ref(), &repo, from).await?;
+        let new = Self::api_snapshot_at(vcs.as_ref(), &repo, to).await?;
+        let changelog = ApiChangelog::between(from, &old, to, &new);
+        let rendered = if json {
+            serde_json::to_string_pretty(&changelog)?
+        } else {
+            changelog.to_markdown()
+        };
+        writeln!(std::io::stdout(), "{}", rendered.trim_end())?;
+        Ok(())
+    }
+
+    /// Public API snapshot of revision `rev`, scanned from a temporary
+    /// checkout of its Rust sources, manifests and validate config.
+    async fn api_snapshot_at(
+        vcs: &dyn mcb_domain::ports::VcsProvider,
+        repo: &mcb_domain::entities::vcs::VcsRepository,
+        rev: &str,
+    ) -> Result<mcb_validate::ApiSnapshot, Box<dyn std::error::Error>> {
+        use mcb_domain::ports::validation::ValidationConfig;
+        use mcb_utils::constants::validate::{CARGO_TOML_FILENAME, VALIDATE_PROJECT_CONFIG_FILE};
+
+        let checkout = tempfile::TempDir::new()?;
+        for file in vcs.list_files(repo, rev).await? {
+            let is_source = file.extension().is_some_and(|ext| ext == "rs")
+                || file.file_name().is_some_and(|name| {
+                    name == CARGO_TOML_FILENAME || name == VALIDATE_PROJECT_CONFIG_FILE
+                });
+            if !is_source {
+                continue;
+            }
+            let target = checkout.path().join(&file);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&target, vcs.read_file(repo, rev, &file).await?)?;
+        }
+        Ok(mcb_validate::ApiSnapshot::collect(&ValidationConfig::new(
+            checkout.path(),
+        ))?)
+    }
+}
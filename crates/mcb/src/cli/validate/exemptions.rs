@@ -0,0 +1,103 @@
+//! Inline suppressions, the violation baseline and `--fix`
+
+use std::io::Write;
+
+use super::ValidateArgs;
+
+impl ValidateArgs {
+    /// Drop violations silenced inline or recorded in the baseline, writing
+    /// the baseline first when `--write-baseline` is set.
+    pub(super) fn apply_exemptions(
+        &self,
+        violations: Vec<Box<dyn mcb_domain::ports::validation::Violation>>,
+        workspace_root: &std::path::Path,
+    ) -> Result<Vec<Box<dyn mcb_domain::ports::validation::Violation>>, Box<dyn std::error::Error>>
+    {
+        use mcb_utils::constants::validate::DEFAULT_BASELINE_FILE;
+        use mcb_validate::Baseline;
+
+        let outcome = mcb_validate::apply_suppressions(violations, workspace_root)?;
+        if outcome.suppressed > 0 {
+            self.progress(&format!(
+                "● {} violation(s) suppressed inline",
+                outcome.suppressed
+            ));
+        }
+
+        let baseline_path = self.baseline.as_ref().map_or_else(
+            || workspace_root.join(DEFAULT_BASELINE_FILE),
+            |path| {
+                if path.is_absolute() {
+                    path.clone()
+                } else {
+                    workspace_root.join(path)
+                }
+            },
+        );
+        if self.write_baseline {
+            let baseline = Baseline::from_violations(&outcome.violations, workspace_root);
+            baseline.save(&baseline_path)?;
+            self.progress(&format!(
+                "● Baseline with {} violation(s) written to {}",
+                baseline.entries.len(),
+                baseline_path.display()
+            ));
+            return Ok(Vec::new());
+        }
+        if self.no_baseline || !baseline_path.is_file() {
+            return Ok(outcome.violations);
+        }
+
+        let filtered =
+            Baseline::load(&baseline_path)?.filter_new(outcome.violations, workspace_root);
+        if filtered.baselined > 0 {
+            self.progress(&format!(
+                "● {} baselined violation(s) hidden ({})",
+                filtered.baselined,
+                baseline_path.display()
+            ));
+        }
+        Ok(filtered.violations)
+    }
+
+    /// Apply the rules' mechanical fixes to `violations` (or print them as a
+    /// diff with `--dry-run`) and return the violations left unresolved.
+    pub(super) fn apply_fixes(
+        &self,
+        violations: Vec<Box<dyn mcb_domain::ports::validation::Violation>>,
+        workspace_root: &std::path::Path,
+    ) -> Result<Vec<Box<dyn mcb_domain::ports::validation::Violation>>, Box<dyn std::error::Error>>
+    {
+        let plan = mcb_validate::FixEngine::for_workspace(workspace_root)?
+            .plan(&violations, workspace_root);
+        if plan.skipped > 0 {
+            self.progress(&format!(
+                "● {} overlapping fix(es) skipped, run --fix again",
+                plan.skipped
+            ));
+        }
+        if self.dry_run {
+            write!(std::io::stdout(), "{}", plan.diff(workspace_root))?;
+            self.progress(&format!(
+                "● {} fix(es) in {} file(s) not applied (dry run)",
+                plan.edit_count(),
+                plan.files.len()
+            ));
+            return Ok(violations);
+        }
+
+        plan.apply()?;
+        self.progress(&format!(
+            "● Fixed {} violation(s) in {} file(s)",
+            plan.fixed.len(),
+            plan.files.len()
+        ));
+        let fixed: std::collections::HashSet<usize> = plan.fixed.into_iter().collect();
+        Ok(violations
+            .into_iter()
+            .enumerate()
+            .filter(|(index, _)| !fixed.contains(index))
+            .map(|(_, violation)| violation)
+            .collect())
+    }
+}
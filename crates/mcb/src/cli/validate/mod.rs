@@ -0,0 +1,482 @@
+//! Validate command - runs architecture validation
+
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use clap::{Args, Subcommand, ValueEnum};
+
+use super::OutputFormat;
+
+mod api;
+mod exemptions;
+mod report;
+mod text;
+
+/// Report format of `mcb validate`
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportFormat {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// The full report as one JSON document
+    Json,
+    /// SARIF 2.1.0 log for code scanning
+    Sarif,
+    /// JUnit XML for CI test reports
+    Junit,
+    /// GitHub Actions annotations
+    Github,
+    /// Self-contained HTML page with run history
+    Html,
+}
+
+/// Arguments for the validate command
+#[derive(Args, Debug, Clone)]
+pub struct ValidateArgs {
+    /// Configuration subcommand; validates the workspace when absent
+    #[command(subcommand)]
+    pub command: Option<ValidateCommand>,
+
+    /// Path to workspace root (default: current directory)
+    #[arg(default_value = ".")]
+    pub path: PathBuf,
+
+    /// Quick mode (summary only, no file details)
+    #[arg(long)]
+    pub quick: bool,
+
+    /// Strict mode (fail on warnings, exit code 1)
+    #[arg(long)]
+    pub strict: bool,
+
+    /// Custom rules directory
+    #[arg(long)]
+    pub rules: Option<PathBuf>,
+
+    /// Specific validators to run (comma-separated)
+    #[arg(long, value_delimiter = ',')]
+    pub validators: Option<Vec<String>>,
+
+    /// Minimum severity: error, warning, info
+    #[arg(long, default_value = "warning")]
+    pub severity: String,
+
+    /// Report format; `--output json|ndjson` may only be combined with `text`
+    /// (or `json` for `--output json`)
+    #[arg(long, value_enum, default_value_t = ReportFormat::Text)]
+    pub format: ReportFormat,
+
+    /// Only validate files changed since this revision (committed, staged,
+    /// unstaged or untracked)
+    #[arg(long, value_name = "REV", conflicts_with = "staged")]
+    pub changed_since: Option<String>,
+
+    /// Only validate files staged for commit (for pre-commit hooks)
+    #[arg(long)]
+    pub staged: bool,
+
+    /// Record the current violations in the baseline file and report none of them
+    #[arg(long)]
+    pub write_baseline: bool,
+
+    /// Baseline file (default: `.mcb-baseline.json` in the workspace root)
+    #[arg(long)]
+    pub baseline: Option<PathBuf>,
+
+    /// Report every violation, ignoring the baseline file
+    #[arg(long, conflicts_with = "write_baseline")]
+    pub no_baseline: bool,
+
+    /// Run history the HTML report appends to and charts (default:
+    /// `.mcb-validate-history.json` in the workspace root)
+    #[arg(long)]
+    pub history: Option<PathBuf>,
+
+    /// Apply the mechanical fixes declared by rules and report what is left
+    #[arg(long)]
+    pub fix: bool,
+
+    /// With `--fix`, print the fixes as a unified diff instead of the report
+    /// and leave files untouched
+    #[arg(long, requires = "fix")]
+    pub dry_run: bool,
+
+    /// Re-extract facts and metrics from every file instead of reusing
+    /// `.mcb-validate-cache/`
+    #[arg(long)]
+    pub no_cache: bool,
+
+    /// Record the current public API in `.mcb-api.json` and, when the
+    /// complexity delta gate is enabled, function complexity in
+    /// `.mcb-complexity.json`, accepting any drift, before validating
+    #[arg(long)]
+    pub bless: bool,
+
+    /// Run as a language server on stdin/stdout: diagnostics on open and
+    /// save, quick fixes as code actions
+    #[arg(long, conflicts_with_all = ["fix", "write_baseline", "changed_since", "staged", "bless"])]
+    pub lsp: bool,
+
+    /// Silent mode: suppress all progress output on stderr
+    #[arg(long, short = 's')]
+    pub silent: bool,
+
+    /// Debug mode: show detailed validator internals
+    #[arg(long, short = 'd')]
+    pub debug: bool,
+
+    /// Trace mode: show everything including per-file processing
+    #[arg(long, short = 't')]
+    pub trace: bool,
+}
+
+/// Configuration subcommands of `mcb validate`
+#[derive(Subcommand, Debug, Clone)]
+pub enum ValidateCommand {
+    /// Write a commented `.mcb-validate.toml` and its JSON Schema to the
+    /// workspace root
+    Init {
+        /// Path to workspace root (default: current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Overwrite an existing config file
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print the JSON Schema of `.mcb-validate.toml`
+    Schema,
+    /// Write Mermaid and DOT dependency diagrams of the workspace crates and
+    /// of each crate's modules
+    Graph {
+        /// Path to workspace root (default: current directory)
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Output directory, relative to the workspace root
+        #[arg(long, default_value = mcb_utils::constants::validate::DEFAULT_DIAGRAM_DIR)]
+        output: PathBuf,
+    },
+    /// Print the public API items added, removed and changed between two
+    /// git revisions as a Markdown changelog
+    Diff {
+        /// Older revision (branch, tag or commit)
+        from: String,
+
+        /// Newer revision (default: HEAD)
+        #[arg(default_value = "HEAD")]
+        to: String,
+
+        /// Path inside the git repository (default: current directory)
+        #[arg(long, default_value = ".")]
+        path: PathBuf,
+
+        /// Print the changelog as JSON instead of Markdown
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Validation result for exit code determination
+pub struct ValidationResult {
+    /// Number of error violations found
+    pub errors: usize,
+    /// Number of warning violations found
+    pub warnings: usize,
+    /// Number of info violations found
+    pub _infos: usize,
+    /// Whether strict mode was enabled
+    pub strict_mode: bool,
+}
+
+impl ValidationResult {
+    /// Returns true if validation failed based on mode
+    #[must_use]
+    pub fn failed(&self) -> bool {
+        if self.strict_mode {
+            self.errors > 0 || self.warnings > 0
+        } else {
+            self.errors > 0
+        }
+    }
+}
+
+impl ValidateArgs {
+    /// Initialize logging based on verbosity flags
+    fn init_logging(&self) {
+        use mcb_domain::ports::LogLevel;
+
+        if self.silent {
+            // No logging at all — set_log_fn is never called, so dispatch is a no-op
+            return;
+        }
+
+        let level = if self.trace {
+            LogLevel::Trace
+        } else if self.debug {
+            LogLevel::Debug
+        } else {
+            LogLevel::Info
+        };
+
+        mcb_domain::infra::logging::set_stderr_log_level(level);
+        mcb_domain::infra::logging::set_log_fn(mcb_domain::infra::logging::stderr_log_fn);
+    }
+
+    /// Print a progress message to stderr (respects --silent)
+    fn progress(&self, msg: &str) {
+        if !self.silent {
+            let _ = writeln!(std::io::stderr(), "{msg}");
+        }
+    }
+
+    /// Resolve the workspace root from the configured path.
+    fn resolve_workspace_root(&self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        if self.path.is_absolute() {
+            Ok(self.path.clone())
+        } else {
+            Ok(std::env::current_dir()?.join(&self.path))
+        }
+    }
+
+    /// Files selected by `--changed-since` / `--staged`, or `None` for a
+    /// full run. Deleted files are left out.
+    async fn changed_files(
+        &self,
+        workspace_root: &std::path::Path,
+    ) -> Result<Option<Vec<PathBuf>>, Box<dyn std::error::Error>> {
+        use mcb_domain::entities::vcs::{DiffStatus, WorkingTreeScope};
+        use mcb_domain::registry::vcs::{VcsProviderConfig, resolve_vcs_provider};
+        use mcb_utils::constants::DEFAULT_VCS_PROVIDER;
+
+        let scope = match (&self.changed_since, self.staged) {
+            (Some(rev), _) => WorkingTreeScope::Since(rev.clone()),
+            (None, true) => WorkingTreeScope::Staged,
+            (None, false) => return Ok(None),
+        };
+        let vcs = resolve_vcs_provider(&VcsProviderConfig::new(DEFAULT_VCS_PROVIDER))?;
+        let repo = vcs.open_repository(workspace_root).await?;
+        let files: Vec<PathBuf> = vcs
+            .working_tree_changes(&repo, &scope)
+            .await?
+            .into_iter()
+            .filter(|change| change.status != DiffStatus::Deleted)
+            .map(|change| repo.path().join(change.path))
+            .collect();
+        self.progress(&format!("● {} changed file(s) in scope", files.len()));
+        Ok(Some(files))
+    }
+
+    /// Run the configured validators and build the report.
+    fn run_validation(
+        &self,
+        workspace_root: &std::path::Path,
+        changed_files: Option<Vec<PathBuf>>,
+    ) -> Result<mcb_validate::GenericReport, Box<dyn std::error::Error>> {
+        use mcb_domain::ports::validation::ValidationConfig;
+        use mcb_utils::constants::validate::DEFAULT_FACT_CACHE_DIR;
+        use mcb_validate::GenericReporter;
+
+        let mut config = ValidationConfig::new(workspace_root);
+        if let Some(files) = changed_files {
+            config = config.with_changed_files(files);
+        }
+        if !self.no_cache {
+            config = config.with_cache_dir(workspace_root.join(DEFAULT_FACT_CACHE_DIR));
+        }
+
+        if self.bless {
+            self.bless_api(workspace_root)?;
+            self.bless_complexity(workspace_root)?;
+        }
+
+        let validator_count = if let Some(ref v) = self.validators {
+            v.len()
+        } else {
+            mcb_domain::registry::validation::validator_count()
+        };
+        self.progress(&format!("● Running {validator_count} validator(s)..."));
+
+        let started = Instant::now();
+        let violations = if let Some(ref validators) = self.validators {
+            let validator_names: Vec<&str> = validators.iter().map(String::as_str).collect();
+            mcb_validate::validators::validate_named(&config, &validator_names)?
+        } else {
+            mcb_validate::validators::validate_all(&config)?
+        };
+        let violations = self.apply_exemptions(violations, workspace_root)?;
+        let violations = if self.fix {
+            self.apply_fixes(violations, workspace_root)?
+        } else {
+            violations
+        };
+        let report = GenericReporter::create_report(&violations, workspace_root.to_path_buf());
+
+        self.progress(&format!("● Done in {:.2?}", started.elapsed()));
+        Ok(report)
+    }
+
+    /// Reject a `--format` that the global `--output` contradicts.
+    ///
+    /// # Errors
+    /// Returns an error naming both flags when they ask for different formats.
+    pub fn check_format(&self, output: OutputFormat) -> Result<(), Box<dyn std::error::Error>> {
+        let agrees = match output {
+            OutputFormat::Table => true,
+            OutputFormat::Json => matches!(self.format, ReportFormat::Text | ReportFormat::Json),
+            OutputFormat::Ndjson => self.format == ReportFormat::Text,
+        };
+        if agrees {
+            return Ok(());
+        }
+        let name = |value: Option<clap::builder::PossibleValue>| {
+            value.map_or_else(String::new, |value| value.get_name().to_owned())
+        };
+        Err(format!(
+            "--format {} conflicts with --output {}",
+            name(self.format.to_possible_value()),
+            name(output.to_possible_value())
+        )
+        .into())
+    }
+
+    /// Execute the validate command
+    /// # Errors
+    /// Returns an error if validation setup or execution fails.
+    pub async fn execute(
+        self,
+        output: OutputFormat,
+    ) -> Result<ValidationResult, Box<dyn std::error::Error>> {
+        self.init_logging();
+        self.check_format(output)?;
+
+        if let Some(ref command) = self.command {
+            self.run_command(command).await?;
+            return Ok(ValidationResult {
+                errors: 0,
+                warnings: 0,
+                _infos: 0,
+                strict_mode: false,
+            });
+        }
+
+        let workspace_root = self.resolve_workspace_root()?;
+        if self.lsp {
+            return Self::serve_lsp(workspace_root).await;
+        }
+        Self::check_config(&workspace_root)?;
+        self.progress(&format!(
+            "● Validating workspace: {}",
+            workspace_root.display()
+        ));
+
+        let changed_files = self.changed_files(&workspace_root).await?;
+        let report = self.run_validation(&workspace_root, changed_files)?;
+        if !self.dry_run {
+            self.emit_report(&report, output)?;
+        }
+
+        Ok(ValidationResult {
+            errors: report.summary.errors,
+            warnings: report.summary.warnings,
+            _infos: report.summary.infos,
+            strict_mode: self.strict,
+        })
+    }
+
+    /// Run a subcommand.
+    async fn run_command(
+        &self,
+        command: &ValidateCommand,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_utils::constants::validate::{
+            VALIDATE_CONFIG_SCHEMA_FILE, VALIDATE_PROJECT_CONFIG_FILE,
+        };
+        use mcb_validate::FileConfig;
+
+        let schema = serde_json::to_string_pretty(&FileConfig::json_schema())?;
+        let (path, force) = match command {
+            ValidateCommand::Schema => {
+                writeln!(std::io::stdout(), "{schema}")?;
+                return Ok(());
+            }
+            ValidateCommand::Graph { path, output } => {
+                return self.write_diagrams(&Self::absolute(path)?, output);
+            }
+            ValidateCommand::Diff {
+                from,
+                to,
+                path,
+                json,
+            } => return self.api_diff(&Self::absolute(path)?, from, to, *json).await,
+            ValidateCommand::Init { path, force } => (path, *force),
+        };
+
+        let workspace_root = Self::absolute(path)?;
+        let config_path = workspace_root.join(VALIDATE_PROJECT_CONFIG_FILE);
+        if config_path.exists() && !force {
+            return Err(format!(
+                "{} already exists (use --force to overwrite)",
+                config_path.display()
+            )
+            .into());
+        }
+        let schema_path = workspace_root.join(VALIDATE_CONFIG_SCHEMA_FILE);
+        std::fs::write(&schema_path, format!("{schema}\n"))?;
+        std::fs::write(&config_path, FileConfig::scaffold())?;
+        self.progress(&format!(
+            "● Wrote {} and {}",
+            config_path.display(),
+            schema_path.display()
+        ));
+        Ok(())
+    }
+
+    /// `path`, resolved against the current directory.
+    fn absolute(path: &std::path::Path) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        if path.is_absolute() {
+            Ok(path.to_path_buf())
+        } else {
+            Ok(std::env::current_dir()?.join(path))
+        }
+    }
+
+    /// Reject a workspace `.mcb-validate.toml` that does not match the
+    /// config schema, listing each offending key with its line.
+    fn check_config(workspace_root: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_utils::constants::validate::VALIDATE_PROJECT_CONFIG_FILE;
+
+        let path = workspace_root.join(VALIDATE_PROJECT_CONFIG_FILE);
+        if !path.is_file() {
+            return Ok(());
+        }
+        let issues = mcb_validate::FileConfig::check(&std::fs::read_to_string(&path)?)?;
+        if issues.is_empty() {
+            return Ok(());
+        }
+        let details: Vec<String> = issues
+            .iter()
+            .map(|issue| format!("  {}:{}: {issue}", path.display(), issue.line))
+            .collect();
+        Err(format!("invalid configuration:\n{}", details.join("\n")).into())
+    }
+
+    /// Serve diagnostics over the Language Server Protocol until the client
+    /// exits. Nothing is reported on stdout besides protocol messages.
+    async fn serve_lsp(
+        workspace_root: PathBuf,
+    ) -> Result<ValidationResult, Box<dyn std::error::Error>> {
+        tokio::task::spawn_blocking(move || {
+            let stdin = std::io::stdin();
+            mcb_validate::LspServer::new(workspace_root, std::io::stdout()).run(&mut stdin.lock())
+        })
+        .await??;
+        Ok(ValidationResult {
+            errors: 0,
+            warnings: 0,
+            _infos: 0,
+            strict_mode: false,
+        })
+    }
+}
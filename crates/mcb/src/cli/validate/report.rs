@@ -0,0 +1,102 @@
+//! Machine-readable reporters selected by `--format` and `--output`
+
+use std::io::Write;
+
+use super::{OutputFormat, ReportFormat, ValidateArgs};
+
+impl ValidateArgs {
+    /// Format the report to stdout per `--output`, or `--format` for `table`.
+    pub(super) fn emit_report(
+        &self,
+        report: &mcb_validate::GenericReport,
+        output: OutputFormat,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        match (output, self.format) {
+            (OutputFormat::Json, _) | (OutputFormat::Table, ReportFormat::Json) => {
+                Self::print_json(report)?;
+            }
+            (OutputFormat::Ndjson, _) => Self::print_ndjson(report)?,
+            (OutputFormat::Table, ReportFormat::Sarif) => Self::print_sarif(report)?,
+            (OutputFormat::Table, ReportFormat::Junit) => Self::print_junit(report)?,
+            (OutputFormat::Table, ReportFormat::Github) => Self::print_github(report)?,
+            (OutputFormat::Table, ReportFormat::Html) => self.print_html(report)?,
+            (OutputFormat::Table, ReportFormat::Text) => self.print_text(report),
+        }
+        Ok(())
+    }
+
+    /// Print report as JSON
+    fn print_json(report: &mcb_validate::GenericReport) -> Result<(), Box<dyn std::error::Error>> {
+        let json = serde_json::to_string_pretty(report)?;
+        writeln!(std::io::stdout(), "{json}")?;
+        Ok(())
+    }
+
+    /// Print report as a SARIF 2.1.0 log
+    fn print_sarif(report: &mcb_validate::GenericReport) -> Result<(), Box<dyn std::error::Error>> {
+        let sarif = serde_json::to_string_pretty(&mcb_validate::SarifReporter::create_log(report))?;
+        writeln!(std::io::stdout(), "{sarif}")?;
+        Ok(())
+    }
+
+    /// Print report as JUnit XML
+    fn print_junit(report: &mcb_validate::GenericReport) -> Result<(), Box<dyn std::error::Error>> {
+        write!(
+            std::io::stdout(),
+            "{}",
+            mcb_validate::JunitReporter::to_junit(report)
+        )?;
+        Ok(())
+    }
+
+    /// Print report as GitHub Actions annotations
+    fn print_github(
+        report: &mcb_validate::GenericReport,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        write!(
+            std::io::stdout(),
+            "{}",
+            mcb_validate::GithubReporter::to_annotations(report)
+        )?;
+        Ok(())
+    }
+
+    /// Record the run in the history file and print the HTML report
+    fn print_html(
+        &self,
+        report: &mcb_validate::GenericReport,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use mcb_utils::constants::validate::DEFAULT_REPORT_HISTORY_FILE;
+
+        let history_path = self.history.as_ref().map_or_else(
+            || report.workspace_root.join(DEFAULT_REPORT_HISTORY_FILE),
+            |path| {
+                if path.is_absolute() {
+                    path.clone()
+                } else {
+                    report.workspace_root.join(path)
+                }
+            },
+        );
+        let history = mcb_validate::ReportHistory::append(&history_path, report)?;
+        self.progress(&format!(
+            "● Run {} recorded in {}",
+            history.runs.len(),
+            history_path.display()
+        ));
+        write!(
+            std::io::stdout(),
+            "{}",
+            mcb_validate::HtmlReporter::to_html(report, &history)
+        )?;
+        Ok(())
+    }
+
+    /// Print one violation per line as compact JSON
+    fn print_ndjson(
+        report: &mcb_validate::GenericReport,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let violations: Vec<_> = report.violations_by_category.values().flatten().collect();
+        OutputFormat::Ndjson.write_records(&mut std::io::stdout(), &violations)
+    }
+}
@@ -0,0 +1,93 @@
+//! Human-readable text reporter
+
+use std::io::Write;
+
+use super::ValidateArgs;
+
+impl ValidateArgs {
+    /// Print report as text
+    pub(super) fn print_text(&self, report: &mcb_validate::GenericReport) {
+        let severity_threshold = self.get_severity_threshold();
+
+        // Print violations (unless quick mode)
+        if !self.quick {
+            Self::print_violations(report, severity_threshold);
+        }
+
+        // Print summary
+        self.print_summary(report);
+    }
+
+    fn get_severity_threshold(&self) -> u8 {
+        match self.severity.as_str() {
+            "error" => 0,   // Only errors
+            "warning" => 1, // Errors + warnings
+            _ => 2,         // All (including info)
+        }
+    }
+
+    fn print_violations(report: &mcb_validate::GenericReport, threshold: u8) {
+        let mut has_violations = false;
+
+        for violations in report.violations_by_category.values() {
+            for violation in violations {
+                if Self::should_print_violation(violation, threshold) {
+                    has_violations = true;
+                    Self::print_single_violation(violation);
+                }
+            }
+        }
+
+        if has_violations {
+            let _ = writeln!(std::io::stdout());
+        }
+    }
+
+    fn should_print_violation(
+        violation: &mcb_domain::ports::ViolationEntry,
+        threshold: u8,
+    ) -> bool {
+        let sev_level = match violation.severity.as_str() {
+            "ERROR" => 0,
+            "WARNING" => 1,
+            _ => 2,
+        };
+        sev_level <= threshold
+    }
+
+    fn print_single_violation(violation: &mcb_domain::ports::ViolationEntry) {
+        let file_display = violation.file.as_deref().unwrap_or("-");
+        let line = violation.line.unwrap_or(0);
+
+        let _ = writeln!(
+            std::io::stdout(),
+            "[{}] {}: {} ({}:{})",
+            violation.severity,
+            violation.id,
+            violation.message,
+            file_display,
+            line
+        );
+        if let Some(ref suggestion) = violation.suggestion {
+            let _ = writeln!(std::io::stdout(), "  → {suggestion}");
+        }
+    }
+
+    fn print_summary(&self, report: &mcb_validate::GenericReport) {
+        let _ = writeln!(
+            std::io::stdout(),
+            "Validation complete: {} error(s), {} warning(s), {} info(s)",
+            report.summary.errors,
+            report.summary.warnings,
+            report.summary.infos
+        );
+
+        // Print category breakdown (unless quick mode)
+        if !self.quick && !report.summary.by_category.is_empty() {
+            let _ = writeln!(std::io::stdout(), "\nBy category:");
+            for (category, count) in &report.summary.by_category {
+                let _ = writeln!(std::io::stdout(), "  {category}: {count}");
+            }
+        }
+    }
+}
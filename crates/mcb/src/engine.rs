@@ -0,0 +1,255 @@
+//! Embeddable engine - indexing, search and browsing without the server
+//!
+//! [`Engine`] wires the application services from the layered configuration
+//! the same way `mcb index` and `mcb search` do, so Rust applications can
+//! embed semantic code search without booting the HTTP server or spawning
+//! the MCP transports.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let engine = mcb::Engine::from_config(None).await?;
+//! engine.index_path("./my-project", "my-project").await?;
+//! for hit in engine.search("my-project", "where are tokens refreshed", 5).await? {
+//!     println!("{:.3} {}:{}", hit.score, hit.file_path, hit.start_line);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+use futures::StreamExt;
+use mcb_domain::events::DomainEvent;
+use mcb_domain::ports::services::evaluation::{SelfEvalOptions, SelfEvalReport};
+use mcb_domain::ports::services::indexing::{IndexingServiceInterface, IndexingStatus};
+use mcb_domain::ports::services::search::SearchServiceInterface;
+use mcb_domain::registry::ServiceResolutionContext;
+use mcb_domain::registry::services::{
+    resolve_index_evaluation_service, resolve_indexing_service, resolve_search_service,
+};
+use mcb_domain::value_objects::{CollectionInfo, FileInfo, SearchResult};
+use mcb_server::utils::collections::normalize_collection_name;
+use mcb_server::utils::mcp::resolve_org_id;
+use mcb_utils::constants::INDEXING_STATUS_STARTED;
+use mcb_utils::constants::limits::CLI_INDEX_PROGRESS_POLL_MS;
+use serde::Serialize;
+
+use crate::cli::headless::resolution_context;
+
+/// Result type of [`Engine`] operations.
+pub type EngineResult<T> = Result<T, Box<dyn std::error::Error>>;
+
+/// Summary of a completed indexing run
+#[derive(Debug, Clone, Serialize)]
+pub struct IndexSummary {
+    /// Normalized collection name
+    pub collection: String,
+    /// Indexed root directory
+    pub path: String,
+    /// Files discovered for indexing
+    pub files: usize,
+    /// Chunks written to the vector store
+    pub chunks: usize,
+    /// Wall-clock duration reported by the indexing task
+    pub duration_ms: u64,
+}
+
+/// Headless handle on the indexing, search and browse services.
+pub struct Engine {
+    context: ServiceResolutionContext,
+    indexing: Arc<dyn IndexingServiceInterface>,
+    search: Arc<dyn SearchServiceInterface>,
+}
+
+impl Engine {
+    /// Build an engine from the layered configuration, optionally with a
+    /// profile overlay (`config/profiles/<name>.yaml`); `None` falls back
+    /// to `MCB_PROFILE`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration cannot be loaded or validated,
+    /// the database is unreachable or cannot be migrated, or a provider or
+    /// service fails to resolve.
+    pub async fn from_config(profile: Option<&str>) -> EngineResult<Self> {
+        Self::from_context(resolution_context(profile).await?)
+    }
+
+    /// Build an engine from an already wired resolution context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the indexing or search service fails to resolve.
+    pub fn from_context(context: ServiceResolutionContext) -> EngineResult<Self> {
+        let indexing = resolve_indexing_service(&context)?;
+        let search = resolve_search_service(&context)?;
+        Ok(Self {
+            context,
+            indexing,
+            search,
+        })
+    }
+
+    /// Index the directory at `path` into `collection` and wait until the
+    /// background indexing task completes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path or collection is invalid, or indexing
+    /// fails to start or finish.
+    pub async fn index_path(
+        &self,
+        path: impl AsRef<Path>,
+        collection: &str,
+    ) -> EngineResult<IndexSummary> {
+        self.index_path_with_progress(path, collection, |_| {})
+            .await
+    }
+
+    /// [`Engine::index_path`], calling `on_progress` with the indexing
+    /// status while the background task runs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path or collection is invalid, or indexing
+    /// fails to start or finish.
+    pub async fn index_path_with_progress(
+        &self,
+        path: impl AsRef<Path>,
+        collection: &str,
+        mut on_progress: impl FnMut(&IndexingStatus),
+    ) -> EngineResult<IndexSummary> {
+        let path = path.as_ref();
+        let path = std::fs::canonicalize(path)
+            .map_err(|e| format!("cannot index {}: {e}", path.display()))?;
+        if !path.is_dir() {
+            return Err(format!("{} is not a directory", path.display()).into());
+        }
+        let collection = normalize_collection_name(collection)?;
+
+        // Subscribe before starting so the completion event cannot be missed.
+        let mut events = self.context.event_bus.subscribe_events().await?;
+        let started = self
+            .indexing
+            .index_codebase(&path, &collection, &resolve_org_id(None))
+            .await?;
+        let mut summary = IndexSummary {
+            collection: collection.to_string(),
+            path: path.display().to_string(),
+            files: started.files_processed,
+            chunks: started.chunks_created,
+            duration_ms: 0,
+        };
+        if started.status != INDEXING_STATUS_STARTED {
+            return Ok(summary);
+        }
+
+        let mut ticker = tokio::time::interval(Duration::from_millis(CLI_INDEX_PROGRESS_POLL_MS));
+        loop {
+            tokio::select! {
+                event = events.next() => {
+                    let event = event.ok_or("event bus closed before indexing completed")?;
+                    if let DomainEvent::IndexingStarted { collection, total_files } = &event
+                        && *collection == summary.collection
+                    {
+                        summary.files = *total_files;
+                    }
+                    if let DomainEvent::IndexingCompleted { collection, chunks, duration_ms } = event
+                        && collection == summary.collection
+                    {
+                        summary.chunks = chunks;
+                        summary.duration_ms = duration_ms;
+                        return Ok(summary);
+                    }
+                }
+                _ = ticker.tick() => on_progress(&self.indexing.get_status()),
+            }
+        }
+    }
+
+    /// Semantic search for `query` in `collection`, best hits first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection name is invalid or the search fails.
+    pub async fn search(
+        &self,
+        collection: &str,
+        query: &str,
+        limit: usize,
+    ) -> EngineResult<Vec<SearchResult>> {
+        let collection = normalize_collection_name(collection)?;
+        Ok(self.search.search(&collection, query, limit).await?)
+    }
+
+    /// Score `collection` against queries generated from its own chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection name is invalid, the evaluation
+    /// service fails to resolve, or no query can be generated or searched.
+    pub async fn self_evaluate(
+        &self,
+        collection: &str,
+        options: &SelfEvalOptions,
+    ) -> EngineResult<SelfEvalReport> {
+        let collection = normalize_collection_name(collection)?;
+        let evaluation = resolve_index_evaluation_service(&self.context)?;
+        Ok(evaluation.self_evaluate(&collection, options).await?)
+    }
+
+    /// Every collection in the vector store.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vector store cannot be listed.
+    pub async fn collections(&self) -> EngineResult<Vec<CollectionInfo>> {
+        Ok(self
+            .context
+            .vector_store_provider
+            .list_collections()
+            .await?)
+    }
+
+    /// Up to `limit` indexed files of `collection`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection name is invalid or the vector store
+    /// cannot be listed.
+    pub async fn browse(&self, collection: &str, limit: usize) -> EngineResult<Vec<FileInfo>> {
+        let collection = normalize_collection_name(collection)?;
+        Ok(self
+            .context
+            .vector_store_provider
+            .list_file_paths(&collection, limit)
+            .await?)
+    }
+
+    /// The indexed chunks of `file_path` in `collection`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collection name is invalid or the chunks
+    /// cannot be read.
+    pub async fn file_chunks(
+        &self,
+        collection: &str,
+        file_path: &str,
+    ) -> EngineResult<Vec<SearchResult>> {
+        let collection = normalize_collection_name(collection)?;
+        Ok(self
+            .context
+            .vector_store_provider
+            .get_chunks_by_file(&collection, file_path)
+            .await?)
+    }
+
+    /// The resolution context, for resolving further services.
+    #[must_use]
+    pub fn context(&self) -> &ServiceResolutionContext {
+        &self.context
+    }
+}
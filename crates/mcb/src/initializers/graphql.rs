@@ -22,7 +22,9 @@ impl Initializer for GraphQLInitializer {
     async fn after_routes(&self, router: AxumRouter, ctx: &AppContext) -> Result<AxumRouter> {
         // Resolve the GraphQL schema provider via domain DI registry
         let provider = mcb_domain::registry::graphql::resolve_graphql_schema_provider(
-            &mcb_domain::registry::graphql::GraphQLSchemaProviderConfig::new("seaography"),
+            &mcb_domain::registry::graphql::GraphQLSchemaProviderConfig::new(
+                mcb_utils::constants::DEFAULT_GRAPHQL_SCHEMA_PROVIDER,
+            ),
         )
         .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
 
@@ -0,0 +1,76 @@
+//! Readiness probe wiring.
+//!
+//! Database and cache probes need Loco's `AppContext`, so they live here;
+//! provider probes come from `mcb_server::health`.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use loco_rs::prelude::*;
+use mcb_domain::ports::HealthProbe;
+use mcb_infrastructure::config::infrastructure::HealthConfig;
+use mcb_server::health::{EmbeddingProbe, ReadinessChecker, StorageQuotaProbe, VectorStoreProbe};
+
+/// Key looked up by the cache probe; it never needs to exist.
+const CACHE_PROBE_KEY: &str = "mcb:readyz";
+
+/// Pings the Loco database connection.
+struct DatabaseProbe(DatabaseConnection);
+
+#[async_trait]
+impl HealthProbe for DatabaseProbe {
+    fn name(&self) -> &str {
+        "database"
+    }
+
+    async fn probe(&self) -> mcb_domain::error::Result<()> {
+        self.0
+            .ping()
+            .await
+            .map_err(|e| mcb_domain::error::Error::database(e.to_string()))
+    }
+}
+
+/// Round-trips a key lookup through the Loco cache backend.
+struct CacheProbe(Arc<loco_rs::cache::Cache>);
+
+#[async_trait]
+impl HealthProbe for CacheProbe {
+    fn name(&self) -> &str {
+        "cache"
+    }
+
+    async fn probe(&self) -> mcb_domain::error::Result<()> {
+        self.0
+            .contains_key(CACHE_PROBE_KEY)
+            .await
+            .map(|_| ())
+            .map_err(|e| mcb_domain::error::Error::cache(e.to_string()))
+    }
+}
+
+/// Build the readiness checker over embedding, vector store, storage quota,
+/// cache and database.
+pub(crate) fn build_readiness_checker(
+    ctx: &AppContext,
+    config: &HealthConfig,
+    bootstrap: &mcb_server::state::McpServerBootstrap,
+) -> ReadinessChecker {
+    let probes: [Arc<dyn HealthProbe>; 5] = [
+        Arc::new(EmbeddingProbe(Arc::clone(&bootstrap.embedding_provider))),
+        Arc::new(VectorStoreProbe(Arc::clone(&bootstrap.vector_store))),
+        Arc::new(StorageQuotaProbe(Arc::clone(&bootstrap.vector_store))),
+        Arc::new(CacheProbe(Arc::clone(&ctx.cache))),
+        Arc::new(DatabaseProbe(ctx.db.clone())),
+    ];
+    probes.into_iter().fold(
+        ReadinessChecker::new(
+            Duration::from_millis(config.probe_timeout_ms),
+            Duration::from_secs(config.probe_cache_ttl_secs),
+        ),
+        |checker, probe| {
+            let critical = config.critical.iter().any(|name| name == probe.name());
+            checker.with_probe(probe, critical)
+        },
+    )
+}
@@ -4,19 +4,30 @@
 //! All handler state is managed by Loco; no manual bootstrap in Hooks.
 
 use std::sync::Arc;
+use std::time::Duration;
 
 use async_trait::async_trait;
 use axum::Extension;
 use axum::Router as AxumRouter;
 use loco_rs::prelude::*;
 
-use mcb_domain::registry::ServiceResolutionContext;
+use mcb_domain::ports::{
+    BulkheadInterface, EmbeddingProvider, JobSchedulerInterface, MetricsProvider,
+    MetricsSnapshotProvider, VectorStoreProvider,
+};
 use mcb_domain::registry::config::{ConfigProviderConfig, resolve_config_provider};
 use mcb_domain::registry::embedding::{EmbeddingProviderConfig, resolve_embedding_provider};
+use mcb_domain::registry::metrics::{MetricsProviderConfig, resolve_metrics_provider};
 use mcb_domain::registry::vector_store::{
     VectorStoreProviderConfig, resolve_vector_store_provider,
 };
+use mcb_domain::registry::{DiReport, ProviderSelection, ServiceResolutionContext};
+use mcb_infrastructure::infrastructure::{
+    CircuitBreakerPolicy, CircuitBreakerVectorStoreProvider, FairJobScheduler,
+    InstrumentedEmbeddingProvider, InstrumentedVectorStoreProvider, SemaphoreBulkheads,
+};
 use mcb_server::build_mcp_server_bootstrap;
+use mcb_server::health::ReadinessChecker;
 use mcb_server::tools::ExecutionFlow;
 use mcb_server::transport::stdio::StdioServerExt;
 use rmcp::transport::streamable_http_server::{
@@ -24,8 +35,10 @@ use rmcp::transport::streamable_http_server::{
 };
 use tokio_util::sync::CancellationToken;
 
+use super::health::build_readiness_checker;
+
 /// Build the embedding provider config from the resolved `AppConfig`.
-fn build_embedding_config(
+pub(crate) fn build_embedding_config(
     app_config: &mcb_infrastructure::config::app::AppConfig,
 ) -> EmbeddingProviderConfig {
     let mut embed_cfg = EmbeddingProviderConfig::new(
@@ -51,11 +64,17 @@ fn build_embedding_config(
     if let Some(d) = app_config.providers.embedding.dimensions {
         embed_cfg = embed_cfg.with_dimensions(d);
     }
-    embed_cfg
+    if let Some(policy) = app_config.providers.embedding.resilience {
+        embed_cfg = embed_cfg.with_resilience(policy);
+    }
+    if let Some(ref signing) = app_config.providers.embedding.signing {
+        embed_cfg = embed_cfg.with_signing(signing.clone());
+    }
+    embed_cfg.with_transport(app_config.providers.http.clone())
 }
 
 /// Build the vector store provider config from the resolved `AppConfig`.
-fn build_vector_store_config(
+pub(crate) fn build_vector_store_config(
     app_config: &mcb_infrastructure::config::app::AppConfig,
 ) -> VectorStoreProviderConfig {
     let mut vec_cfg = VectorStoreProviderConfig::new(
@@ -75,12 +94,95 @@ fn build_vector_store_config(
     if let Some(d) = app_config.providers.vector_store.dimensions {
         vec_cfg = vec_cfg.with_dimensions(d);
     }
-    vec_cfg
+    if let Some(policy) = app_config.providers.vector_store.resilience {
+        vec_cfg = vec_cfg.with_resilience(policy);
+    }
+    if let Some(policy) = app_config.providers.vector_store.insert_batch {
+        vec_cfg = vec_cfg.with_insert_batch(policy);
+    }
+    if let Some(policy) = app_config.providers.vector_store.tombstones {
+        vec_cfg = vec_cfg.with_tombstones(policy);
+    }
+    if let Some(ref policy) = app_config.providers.vector_store.persistence {
+        vec_cfg = vec_cfg.with_persistence(policy.clone());
+    }
+    if let Some(policy) = app_config.providers.vector_store.quota {
+        vec_cfg = vec_cfg.with_quota(policy);
+    }
+    if let Some(metric) = app_config.providers.vector_store.distance_metric {
+        vec_cfg = vec_cfg.with_distance_metric(metric);
+    }
+    vec_cfg.with_transport(app_config.providers.http.clone())
+}
+
+/// Providers the composition root resolves, with where each name comes from.
+///
+/// Kept next to the resolution code above so the `/di` report lists what
+/// `build_resolution_ctx` and `build_mcp_server_bootstrap` actually request.
+fn di_selections(
+    app_config: &mcb_infrastructure::config::app::AppConfig,
+) -> Vec<ProviderSelection> {
+    use mcb_utils::constants::{
+        DEFAULT_CONFIG_PROVIDER, DEFAULT_DATABASE_PROVIDER, DEFAULT_GRAPHQL_SCHEMA_PROVIDER,
+        DEFAULT_HYBRID_SEARCH_PROVIDER, DEFAULT_INDEXING_OP_PROVIDER, DEFAULT_LANGUAGE_PROVIDER,
+        DEFAULT_METRICS_PROVIDER, DEFAULT_NULL_PROVIDER, DEFAULT_VALIDATION_OP_PROVIDER,
+        DEFAULT_VCS_PROVIDER, REGISTRY_CONFIG, REGISTRY_DATABASE, REGISTRY_EMBEDDING,
+        REGISTRY_EVENT_BUS, REGISTRY_GRAPHQL_SCHEMA, REGISTRY_HYBRID_SEARCH,
+        REGISTRY_INDEXING_OPERATIONS, REGISTRY_LANGUAGE, REGISTRY_METRICS,
+        REGISTRY_PROJECT_DETECTION, REGISTRY_VALIDATION_OPERATIONS, REGISTRY_VCS,
+        REGISTRY_VECTOR_STORE,
+    };
+
+    vec![
+        ProviderSelection::from_default(REGISTRY_CONFIG, DEFAULT_CONFIG_PROVIDER),
+        ProviderSelection::from_optional_config(
+            REGISTRY_EMBEDDING,
+            app_config.providers.embedding.provider.as_deref(),
+            DEFAULT_NULL_PROVIDER,
+            "providers.embedding.provider",
+        ),
+        ProviderSelection::from_optional_config(
+            REGISTRY_VECTOR_STORE,
+            app_config.providers.vector_store.provider.as_deref(),
+            DEFAULT_NULL_PROVIDER,
+            "providers.vector_store.provider",
+        ),
+        ProviderSelection::from_config(
+            REGISTRY_EVENT_BUS,
+            app_config
+                .system
+                .infrastructure
+                .event_bus
+                .provider
+                .provider_name(),
+            "system.infrastructure.event_bus.provider",
+        ),
+        ProviderSelection::from_default(REGISTRY_METRICS, DEFAULT_METRICS_PROVIDER),
+        ProviderSelection::from_default(REGISTRY_HYBRID_SEARCH, DEFAULT_HYBRID_SEARCH_PROVIDER),
+        ProviderSelection::from_default(REGISTRY_DATABASE, DEFAULT_DATABASE_PROVIDER),
+        ProviderSelection::from_default(REGISTRY_LANGUAGE, DEFAULT_LANGUAGE_PROVIDER),
+        ProviderSelection::from_default(REGISTRY_PROJECT_DETECTION, DEFAULT_LANGUAGE_PROVIDER),
+        ProviderSelection::from_default(REGISTRY_VCS, DEFAULT_VCS_PROVIDER),
+        ProviderSelection::from_default(REGISTRY_INDEXING_OPERATIONS, DEFAULT_INDEXING_OP_PROVIDER),
+        ProviderSelection::from_default(
+            REGISTRY_VALIDATION_OPERATIONS,
+            DEFAULT_VALIDATION_OP_PROVIDER,
+        ),
+        ProviderSelection::from_default(REGISTRY_GRAPHQL_SCHEMA, DEFAULT_GRAPHQL_SCHEMA_PROVIDER),
+    ]
 }
 
-/// Public routes — no auth required (static assets + redirect).
+/// Public routes — no auth required (probes, static assets + redirect).
 fn build_public_routes() -> AxumRouter {
     axum::Router::new()
+        .route(
+            "/healthz",
+            axum::routing::get(mcb_server::controllers::health_api::alive),
+        )
+        .route(
+            "/readyz",
+            axum::routing::get(mcb_server::controllers::health_api::readyz),
+        )
         .route(
             "/",
             axum::routing::get(|| async { axum::response::Redirect::temporary("/ui/") }),
@@ -158,14 +260,30 @@ fn admin_api_routes() -> AxumRouter {
             "/collections",
             axum::routing::get(mcb_server::controllers::collections_api::collections),
         )
+        .route(
+            "/collections/{collection}/report",
+            axum::routing::get(mcb_server::controllers::collections_api::collection_report),
+        )
         .route(
             "/chunks",
             axum::routing::get(mcb_server::controllers::collections_api::chunks),
         )
+        .route(
+            "/chunks/{collection}/{id}",
+            axum::routing::get(mcb_server::controllers::collections_api::chunk),
+        )
         .route(
             "/config",
             axum::routing::get(mcb_server::controllers::admin::config_via_middleware),
         )
+        .route(
+            "/di",
+            axum::routing::get(mcb_server::controllers::di_api::di_report),
+        )
+        .route(
+            "/usage",
+            axum::routing::get(mcb_server::controllers::usage_api::usage_report),
+        )
 }
 
 /// Admin route table (without auth layer applied).
@@ -234,10 +352,22 @@ fn resolve_app_config(ctx: &AppContext) -> Result<mcb_infrastructure::config::ap
     Ok(app_config)
 }
 
+/// Resolve the in-process metrics recorder shared by providers and `server_stats`.
+pub(crate) fn resolve_metrics() -> Result<Arc<dyn MetricsSnapshotProvider>> {
+    resolve_metrics_provider(&MetricsProviderConfig::new(
+        mcb_utils::constants::DEFAULT_METRICS_PROVIDER,
+    ))
+    .map_err(|e| loco_rs::Error::string(&e.to_string()))
+}
+
 /// Resolve event bus and provider adapters into a `ServiceResolutionContext`.
-fn build_resolution_ctx(
-    ctx: &AppContext,
+///
+/// Embedding and vector store providers are wrapped so their call latency is
+/// recorded into `metrics`.
+pub(crate) fn build_resolution_ctx(
+    db: &DatabaseConnection,
     app_config: mcb_infrastructure::config::app::AppConfig,
+    metrics: &Arc<dyn MetricsSnapshotProvider>,
 ) -> Result<ServiceResolutionContext> {
     let event_bus = mcb_domain::registry::events::resolve_event_bus_provider(
         &mcb_domain::registry::events::EventBusProviderConfig::new(
@@ -259,22 +389,81 @@ fn build_resolution_ctx(
         resolve_vector_store_provider(&build_vector_store_config(&app_config))
             .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
 
+    let recorder: Arc<dyn MetricsProvider> = Arc::clone(metrics);
+    let embedding_provider: Arc<dyn EmbeddingProvider> = Arc::new(
+        InstrumentedEmbeddingProvider::new(embedding_provider, Arc::clone(&recorder)),
+    );
+    let vector_store_provider: Arc<dyn VectorStoreProvider> = Arc::new(
+        InstrumentedVectorStoreProvider::new(vector_store_provider, recorder),
+    );
+    // Outermost, so fast-failed calls are not recorded as vector store latency.
+    let resilience = &app_config.system.infrastructure.resilience;
+    let circuit_breaker = CircuitBreakerPolicy {
+        failure_threshold: resilience.circuit_breaker_failure_threshold,
+        open_duration: Duration::from_secs(resilience.circuit_breaker_timeout_secs),
+        success_threshold: resilience.circuit_breaker_success_threshold,
+    };
+    let vector_store_provider: Arc<dyn VectorStoreProvider> = if circuit_breaker.enabled() {
+        Arc::new(CircuitBreakerVectorStoreProvider::new(
+            vector_store_provider,
+            circuit_breaker,
+        ))
+    } else {
+        vector_store_provider
+    };
+    let job_scheduler: Arc<dyn JobSchedulerInterface> = Arc::new(
+        FairJobScheduler::new(app_config.system.infrastructure.jobs)
+            .map_err(|e| loco_rs::Error::string(&e.to_string()))?,
+    );
+    let bulkheads: Arc<dyn BulkheadInterface> = Arc::new(
+        SemaphoreBulkheads::new(app_config.system.infrastructure.bulkheads)
+            .map_err(|e| loco_rs::Error::string(&e.to_string()))?,
+    );
+
     Ok(ServiceResolutionContext {
-        db: Arc::new(ctx.db.clone()),
+        db: Arc::new(db.clone()),
         config: Arc::new(app_config),
         event_bus,
         embedding_provider,
         vector_store_provider,
+        job_scheduler,
+        bulkheads,
     })
 }
 
 /// Resolve `AppConfig` from Loco settings and build the MCP server bootstrap.
 ///
 /// Centralizes config-provider deserialization, provider resolution, and the
-/// bootstrap wiring so `after_routes` reads as a short orchestration. Returns
-/// the bootstrap plus whether the stdio transport should be started.
-fn build_bootstrap(ctx: &AppContext) -> Result<(mcb_server::state::McpServerBootstrap, bool)> {
+/// bootstrap wiring so `after_routes` reads as a short orchestration. Also
+/// starts the search feedback tuning job when enabled. Returns
+/// the bootstrap, whether the stdio transport should be started, the
+/// readiness checker backing `/readyz` and the DI report backing `/di`.
+fn build_bootstrap(
+    ctx: &AppContext,
+) -> Result<(
+    mcb_server::state::McpServerBootstrap,
+    bool,
+    ReadinessChecker,
+    DiReport,
+)> {
     let app_config = resolve_app_config(ctx)?;
+    let di_report = DiReport::new(di_selections(&app_config));
+    if !di_report.is_consistent() {
+        mcb_domain::warn!(
+            "mcp_initializer",
+            "DI registry has unresolved or conflicting providers",
+            &format!(
+                "{} unresolved, {} conflicts; see /di",
+                di_report.unresolved.len(),
+                di_report.conflicts.len()
+            )
+        );
+    }
+    let health_config = app_config.system.infrastructure.health.clone();
+    let feedback_tuning_interval_secs = app_config
+        .operations_daemon
+        .operations
+        .feedback_tuning_interval_secs;
 
     let execution_flow = if app_config.mcp.stdio_only {
         ExecutionFlow::StdioOnly
@@ -282,8 +471,11 @@ fn build_bootstrap(ctx: &AppContext) -> Result<(mcb_server::state::McpServerBoot
         ExecutionFlow::ServerHybrid
     };
     let start_stdio = stdio_enabled(&app_config.mcp);
+    let read_only = app_config.mcp.read_only;
+    let idempotency_window_secs = app_config.mcp.idempotency_window_secs;
 
-    let resolution_ctx = build_resolution_ctx(ctx, app_config)?;
+    let metrics = resolve_metrics()?;
+    let resolution_ctx = build_resolution_ctx(&ctx.db, app_config, &metrics)?;
 
     let hybrid_search: Arc<dyn mcb_domain::ports::HybridSearchProvider> =
         mcb_domain::registry::hybrid_search::resolve_hybrid_search_provider(
@@ -299,10 +491,18 @@ fn build_bootstrap(ctx: &AppContext) -> Result<(mcb_server::state::McpServerBoot
         Arc::clone(&resolution_ctx.embedding_provider),
         Arc::clone(&resolution_ctx.vector_store_provider),
         hybrid_search,
+        metrics,
         execution_flow,
+        read_only,
+        idempotency_window_secs,
     )
     .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
-    Ok((bootstrap, start_stdio))
+    spawn_feedback_tuning(
+        bootstrap.mcp_server.search_feedback_service(),
+        feedback_tuning_interval_secs,
+    );
+    let readiness = build_readiness_checker(ctx, &health_config, &bootstrap);
+    Ok((bootstrap, start_stdio, readiness, di_report))
 }
 
 /// Build the HTTP MCP streamable service from the resolved server handle.
@@ -324,6 +524,35 @@ fn build_mcp_service(
     )
 }
 
+/// Spawn the periodic job that turns search feedback into per-file boosts.
+///
+/// An interval of zero disables tuning.
+fn spawn_feedback_tuning(
+    search_feedback: Arc<dyn mcb_domain::ports::SearchFeedbackServiceInterface>,
+    interval_secs: u64,
+) {
+    if interval_secs == 0 {
+        return;
+    }
+    // Detached for the process lifetime, like the stdio server.
+    let _handle = tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+        loop {
+            ticker.tick().await;
+            match search_feedback.tune_file_boosts().await {
+                Ok(boosted) => mcb_domain::info!(
+                    "mcp_initializer",
+                    "Search feedback boosts tuned",
+                    &format!("{boosted} files boosted")
+                ),
+                Err(e) => {
+                    mcb_domain::warn!("mcp_initializer", "Search feedback tuning failed", &e);
+                }
+            }
+        }
+    });
+}
+
 /// Spawn the MCP stdio server, detaching the task.
 fn spawn_stdio_server(mcp_server: Arc<mcb_server::McpServer>) {
     // Detached: handle intentionally dropped so the stdio server runs for the
@@ -348,7 +577,7 @@ impl Initializer for McpServerInitializer {
     async fn after_routes(&self, router: AxumRouter, ctx: &AppContext) -> Result<AxumRouter> {
         mcb_domain::infra::logging::set_log_fn(mcb_infrastructure::logging::tracing_log_fn);
 
-        let (bootstrap, start_stdio) = build_bootstrap(ctx)?;
+        let (bootstrap, start_stdio, readiness, di_report) = build_bootstrap(ctx)?;
 
         if start_stdio {
             spawn_stdio_server(Arc::clone(&bootstrap.mcp_server));
@@ -366,7 +595,9 @@ impl Initializer for McpServerInitializer {
         let router = router
             .merge(build_public_routes())
             .merge(protected_routes)
-            .layer(Extension(mcb_state));
+            .layer(Extension(mcb_state))
+            .layer(Extension(Arc::new(readiness)))
+            .layer(Extension(Arc::new(di_report)));
         let mcp_routes = axum::Router::new().nest_service("/mcp", mcp_service);
 
         // 404 fallback handler for unknown routes
@@ -1,3 +1,4 @@
 //! Initializers for the MCB application.
 pub mod graphql;
+pub mod health;
 pub mod mcp_server;
@@ -8,13 +8,22 @@
 //! mcb serve --transport http
 //! mcb serve --transport stdio
 //! ```
+//!
+//! Applications can embed indexing and search without the server through
+//! [`Engine`].
+
+// linkme force-link only — DO NOT use for type/function imports (CA019 enforced)
+extern crate mcb_providers;
 
 /// CLI subcommand handlers and arguments.
 pub mod cli;
+/// Embeddable indexing, search and browse API.
+pub mod engine;
 /// Loco initializers for the MCP server.
 pub mod initializers;
 /// Loco application hooks and specialized application logic.
 pub mod loco_app;
 
+pub use crate::engine::{Engine, EngineResult, IndexSummary};
 pub use crate::loco_app::McbApp;
 pub use mcb_server::McpServer;
@@ -12,6 +12,7 @@ use loco_rs::boot::{BootResult, StartMode, create_app};
 use loco_rs::config::Config as LocoConfig;
 use loco_rs::controller::AppRoutes;
 use loco_rs::environment::Environment;
+use mcb_infrastructure::config::profile;
 use mcb_infrastructure::infrastructure::DynamicMigrator;
 use std::path::{Path, PathBuf};
 
@@ -58,9 +59,64 @@ fn recover_sqlite_before_boot(path: &std::path::Path) {
     }
 }
 
+/// Resolve the folder [`McbApp::load_config`] reads from.
+///
+/// Returns `None` when the config is supplied inline via `MCB_CONFIG_INLINE`.
+fn config_folder() -> loco_rs::Result<Option<PathBuf>> {
+    if std::env::var("MCB_CONFIG_INLINE").is_ok() {
+        return Ok(None);
+    }
+    if let Ok(folder) = std::env::var("MCB_CONFIG_FOLDER") {
+        return Ok(Some(PathBuf::from(folder)));
+    }
+    let env_name = loco_rs::environment::resolve_from_env();
+    let local_candidates = [
+        PathBuf::from("config").join(format!("{env_name}.local.yaml")),
+        PathBuf::from("config").join(format!("{env_name}.yaml")),
+    ];
+    if local_candidates.iter().any(|p| p.exists()) {
+        return Ok(Some(PathBuf::from("config")));
+    }
+    let installed = dirs::config_dir()
+        .ok_or_else(|| loco_rs::Error::string("Cannot determine config directory"))?
+        .join("mcb")
+        .join("config");
+    Ok(Some(installed))
+}
+
 /// MCB Loco application type implementing [`Hooks`].
 #[derive(Debug)]
 pub struct McbApp;
+
+impl McbApp {
+    /// Load the Loco config and layer the profile overlay and `MCB__*`
+    /// environment overrides onto its `settings:` tree.
+    ///
+    /// # Errors
+    /// Returns an error if the base config cannot be loaded, a profile is
+    /// requested for an inline config, or a layer fails to apply.
+    pub async fn load_layered_config(
+        env: &Environment,
+        profile: Option<&str>,
+    ) -> loco_rs::Result<LocoConfig> {
+        let mut config = Self::load_config(env).await?;
+        let folder = config_folder()?;
+        let settings = config
+            .settings
+            .get_or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        match (folder, profile) {
+            (Some(folder), _) => profile::apply_layers(settings, &folder, profile),
+            (None, None) => profile::apply_env_overrides(settings, std::env::vars()),
+            (None, Some(p)) => {
+                return Err(loco_rs::Error::string(&format!(
+                    "profile '{p}' cannot be applied to MCB_CONFIG_INLINE"
+                )));
+            }
+        }
+        .map_err(|e| loco_rs::Error::string(&e.to_string()))?;
+        Ok(config)
+    }
+}
 #[async_trait]
 impl Hooks for McbApp {
     fn app_name() -> &'static str {
@@ -89,22 +145,9 @@ impl Hooks for McbApp {
             return serde_yaml::from_str(&inline)
                 .map_err(|e| loco_rs::Error::string(&format!("MCB_CONFIG_INLINE: {e}")));
         }
-        if let Ok(folder) = std::env::var("MCB_CONFIG_FOLDER") {
-            return env.load_from_folder(Path::new(&folder));
-        }
-        let env_name = loco_rs::environment::resolve_from_env();
-        let local_candidates = [
-            PathBuf::from("config").join(format!("{env_name}.local.yaml")),
-            PathBuf::from("config").join(format!("{env_name}.yaml")),
-        ];
-        if local_candidates.iter().any(|p| p.exists()) {
-            return env.load_from_folder(Path::new("config"));
-        }
-        let installed = dirs::config_dir()
-            .ok_or_else(|| loco_rs::Error::string("Cannot determine config directory"))?
-            .join("mcb")
-            .join("config");
-        env.load_from_folder(&installed)
+        let folder = config_folder()?
+            .ok_or_else(|| loco_rs::Error::string("Cannot determine config directory"))?;
+        env.load_from_folder(&folder)
     }
     fn routes(_ctx: &LocoAppContext) -> AppRoutes {
         AppRoutes::with_default_routes()
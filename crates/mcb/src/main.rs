@@ -5,14 +5,25 @@
 // linkme force-link only — DO NOT use for type/function imports (CA019 enforced)
 extern crate mcb_providers;
 
-use clap::{Parser, Subcommand};
-use mcb::cli::{ServeArgs, ValidateArgs};
+use clap::{CommandFactory, Parser, Subcommand};
+use mcb::cli::{
+    CompletionsArgs, ConfigArgs, DaemonArgs, DoctorArgs, EvalArgs, IndexArgs, InitArgs,
+    OutputFormat, SearchArgs, ServeArgs, ValidateArgs,
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "mcb")]
 #[command(about = "MCP Context Browser - Semantic Code Search Server")]
 #[command(version)]
 struct Cli {
+    /// Configuration profile overlay (`config/profiles/<name>.yaml`); overrides `MCB_PROFILE`
+    #[arg(long, global = true)]
+    profile: Option<String>,
+
+    /// Output format for command results
+    #[arg(long, short = 'o', global = true, value_enum, default_value_t = OutputFormat::Table)]
+    output: OutputFormat,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -22,16 +33,44 @@ enum Command {
     #[command(alias = "server")]
     Serve(ServeArgs),
     Validate(ValidateArgs),
+    Config(ConfigArgs),
+    Init(InitArgs),
+    Doctor(DoctorArgs),
+    Index(IndexArgs),
+    Search(SearchArgs),
+    Eval(EvalArgs),
+    Completions(CompletionsArgs),
+    Daemon(DaemonArgs),
 }
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
+    let profile = cli.profile.as_deref();
+    let output = cli.output;
+
     match cli.command {
-        Command::Serve(args) => args.execute().await,
+        Command::Serve(args) => args.execute(profile).await,
+        Command::Config(args) => args.execute(profile, output).await,
+        Command::Init(args) => args.execute().await,
+        Command::Index(args) => args.execute(profile, output).await,
+        Command::Search(args) => args.execute(profile, output).await,
+        Command::Eval(args) => args.execute(profile, output).await,
+        Command::Daemon(args) => args.execute(profile, output).await,
+        Command::Completions(args) => {
+            args.execute(&mut Cli::command());
+            Ok(())
+        }
+        Command::Doctor(args) => {
+            let report = args.execute(profile, output).await?;
+            if report.failed() {
+                std::process::exit(1);
+            }
+            Ok(())
+        }
         Command::Validate(args) => {
-            let result = args.execute()?;
+            let result = args.execute(output).await?;
             if result.failed() {
                 std::process::exit(1);
             }
@@ -0,0 +1,65 @@
+//! Tests for daemon pidfile and log rotation helpers.
+
+use std::fs;
+
+use mcb::cli::daemon::rotate_log;
+#[cfg(unix)]
+use mcb::cli::daemon::running_pid;
+use rstest::rstest;
+use tempfile::TempDir;
+
+#[rstest]
+fn test_rotate_log_shifts_and_caps_files() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let log = temp.path().join("mcb.log");
+    fs::write(&log, "current")?;
+    fs::write(temp.path().join("mcb.log.1"), "older")?;
+    fs::write(temp.path().join("mcb.log.2"), "oldest")?;
+
+    assert!(rotate_log(&log, 4, 2)?);
+
+    assert!(!log.exists());
+    assert_eq!(
+        fs::read_to_string(temp.path().join("mcb.log.1"))?,
+        "current"
+    );
+    assert_eq!(fs::read_to_string(temp.path().join("mcb.log.2"))?, "older");
+    assert!(!temp.path().join("mcb.log.3").exists());
+    Ok(())
+}
+
+#[rstest]
+fn test_rotate_log_skips_small_or_missing_files() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let log = temp.path().join("mcb.log");
+    assert!(!rotate_log(&log, 4, 2)?);
+    fs::write(&log, "abc")?;
+    assert!(!rotate_log(&log, 4, 2)?);
+    assert!(log.exists());
+    Ok(())
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_running_pid_reports_live_process() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let pid_file = temp.path().join("mcb.pid");
+    fs::write(&pid_file, format!("{}\n", std::process::id()))?;
+
+    let pid = running_pid(&pid_file)?;
+    assert_eq!(pid, i32::try_from(std::process::id()).ok());
+    Ok(())
+}
+
+#[cfg(unix)]
+#[rstest]
+fn test_running_pid_removes_stale_pidfile() -> Result<(), Box<dyn std::error::Error>> {
+    let temp = TempDir::new()?;
+    let pid_file = temp.path().join("mcb.pid");
+    // PIDs are capped well below i32::MAX on every supported platform.
+    fs::write(&pid_file, format!("{}\n", i32::MAX))?;
+
+    assert_eq!(running_pid(&pid_file)?, None);
+    assert!(!pid_file.exists(), "stale pidfile must be removed");
+    Ok(())
+}
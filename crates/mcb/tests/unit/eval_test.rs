@@ -0,0 +1,76 @@
+//! Tests for the eval command's query set parsing and report output.
+
+use mcb::cli::eval::{QuerySet, write_report, write_self_eval};
+use mcb_domain::ports::SelfEvalReport;
+use mcb_domain::value_objects::{EvalReport, QueryScore};
+use rstest::rstest;
+
+fn query_score(query: &str, recall: f64, first: Option<usize>) -> QueryScore {
+    QueryScore {
+        query: query.to_owned(),
+        recall,
+        reciprocal_rank: first.map_or(0.0, |rank| 1.0 / rank as f64),
+        ndcg: recall,
+        first_relevant_rank: first,
+    }
+}
+
+#[rstest]
+#[case("queries: []", "no queries")]
+#[case("queries:\n  - query: orphan\n", "no expected files or symbols")]
+fn test_query_set_rejects_unscorable_sets(#[case] yaml: &str, #[case] message: &str) {
+    let err = QuerySet::from_yaml(yaml).expect_err("invalid query set");
+    assert!(err.to_string().contains(message), "got: {err}");
+}
+
+#[rstest]
+fn test_query_set_reads_files_and_symbols() -> Result<(), Box<dyn std::error::Error>> {
+    let set = QuerySet::from_yaml(
+        "queries:\n  - query: rotate tokens\n    files: [src/a.rs]\n    symbols: [rotate]\n",
+    )?;
+    assert_eq!(set.queries.len(), 1);
+    assert_eq!(set.queries[0].files, ["src/a.rs"]);
+    assert_eq!(set.queries[0].symbols, ["rotate"]);
+    Ok(())
+}
+
+#[rstest]
+fn test_report_lists_queries_and_means() -> Result<(), Box<dyn std::error::Error>> {
+    let report = EvalReport::new(
+        "repo".to_owned(),
+        5,
+        vec![
+            query_score("hit", 1.0, Some(1)),
+            query_score("miss", 0.0, None),
+        ],
+    );
+
+    let mut out = Vec::new();
+    write_report(&mut out, &report)?;
+    let text = String::from_utf8(out)?;
+
+    assert!(text.starts_with(" recall@5"), "got: {text}");
+    assert!(text.lines().any(|l| l.ends_with("-  miss")), "got: {text}");
+    assert!(
+        text.contains("2 queries on repo: recall@5 0.500  MRR 0.500"),
+        "got: {text}"
+    );
+    Ok(())
+}
+
+#[rstest]
+fn test_self_eval_reports_quality_score() -> Result<(), Box<dyn std::error::Error>> {
+    let eval = EvalReport::new("repo".to_owned(), 5, vec![query_score("hit", 1.0, Some(2))]);
+    let report = SelfEvalReport {
+        quality_score: 2.5 / 3.0,
+        files_sampled: 4,
+        queries: Vec::new(),
+        eval,
+    };
+
+    let mut out = Vec::new();
+    write_self_eval(&mut out, &report)?;
+
+    assert!(String::from_utf8(out)?.ends_with("index quality 0.833 from 4 sampled files\n"));
+    Ok(())
+}
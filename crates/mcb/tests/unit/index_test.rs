@@ -0,0 +1,31 @@
+//! Tests for the index command's dry-run output.
+
+use mcb::cli::index::write_estimate;
+use mcb_domain::ports::IndexingEstimate;
+use rstest::rstest;
+
+#[rstest]
+fn test_estimate_marks_configured_provider() -> Result<(), Box<dyn std::error::Error>> {
+    let mut estimate = IndexingEstimate::default();
+    estimate.files = 1;
+    estimate.record_chunk("rust", 4_000);
+    let estimate = estimate.with_projections(&[
+        ("fastembed", "small", 384, 0.0),
+        ("openai", "large", 3_072, 0.13),
+    ]);
+
+    let mut out = Vec::new();
+    write_estimate(&mut out, &estimate, Some("openai"))?;
+    let text = String::from_utf8(out)?;
+
+    assert!(text.contains("tokens       1000"), "got: {text}");
+    assert!(text.contains("  rust"), "got: {text}");
+    let fastembed = text.lines().find(|l| l.contains("fastembed"));
+    let openai = text.lines().find(|l| l.contains("openai"));
+    assert!(
+        fastembed.is_some_and(|l| l.starts_with("  ")),
+        "got: {text}"
+    );
+    assert!(openai.is_some_and(|l| l.starts_with("* ")), "got: {text}");
+    Ok(())
+}
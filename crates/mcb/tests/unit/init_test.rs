@@ -0,0 +1,34 @@
+//! Tests for the init command.
+
+use std::fs;
+
+use mcb::cli::InitArgs;
+use rstest::rstest;
+
+#[rstest]
+#[tokio::test]
+async fn test_init_refuses_to_overwrite_without_force() {
+    let dir = std::env::temp_dir().join(format!("mcb-init-test-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap_or_else(|e| unreachable!("create temp dir: {e}"));
+    let target = dir.join("development.yaml");
+    fs::write(&target, "existing: true").unwrap_or_else(|e| unreachable!("write config: {e}"));
+
+    let args = InitArgs {
+        yes: true,
+        force: false,
+        output: Some(target.clone()),
+    };
+    let result = args.execute().await;
+    let content = fs::read_to_string(&target).unwrap_or_default();
+    let _ = fs::remove_dir_all(&dir);
+
+    let err = result.err().map(|e| e.to_string()).unwrap_or_default();
+    assert!(
+        err.contains("--force"),
+        "expected overwrite refusal, got: {err}"
+    );
+    assert_eq!(
+        content, "existing: true",
+        "existing config must be untouched"
+    );
+}
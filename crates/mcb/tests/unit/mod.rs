@@ -1,3 +1,9 @@
 //! Unit tests — `cargo test -p mcb --test unit`
 
+mod daemon_test;
+mod eval_test;
+mod index_test;
+mod init_test;
+mod output_test;
+mod search_test;
 mod validate_test;
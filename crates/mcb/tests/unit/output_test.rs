@@ -0,0 +1,30 @@
+//! Tests for the shared CLI output formats.
+
+use mcb::cli::OutputFormat;
+use rstest::rstest;
+use serde_json::json;
+
+#[rstest]
+fn test_ndjson_writes_one_record_per_line() -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = Vec::new();
+    OutputFormat::Ndjson.write_records(&mut out, &[json!({"a": 1}), json!({"a": 2})])?;
+    assert_eq!(String::from_utf8(out)?, "{\"a\":1}\n{\"a\":2}\n");
+    Ok(())
+}
+
+#[rstest]
+fn test_json_writes_single_array() -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = Vec::new();
+    OutputFormat::Json.write_records(&mut out, &[json!({"a": 1})])?;
+    let parsed: serde_json::Value = serde_json::from_slice(&out)?;
+    assert_eq!(parsed, json!([{"a": 1}]));
+    Ok(())
+}
+
+#[rstest]
+#[case(OutputFormat::Table, false)]
+#[case(OutputFormat::Json, true)]
+#[case(OutputFormat::Ndjson, true)]
+fn test_is_machine(#[case] format: OutputFormat, #[case] expected: bool) {
+    assert_eq!(format.is_machine(), expected);
+}
@@ -0,0 +1,39 @@
+//! Tests for the search command's table output.
+
+use mcb::cli::search::write_table;
+use mcb_domain::value_objects::SearchResult;
+use rstest::rstest;
+
+fn hit(content: &str) -> SearchResult {
+    SearchResult {
+        id: "chunk_1".to_owned(),
+        file_path: "src/auth/login.rs".to_owned(),
+        start_line: 42,
+        content: content.to_owned(),
+        score: 0.9234,
+        language: "rust".to_owned(),
+        last_modified: None,
+        explanation: None,
+    }
+}
+
+#[rstest]
+fn test_table_shows_first_non_blank_line() -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = Vec::new();
+    write_table(&mut out, &[hit("\n  pub fn authenticate() {}\nmore")])?;
+    let text = String::from_utf8(out)?;
+
+    let row = text.lines().nth(1).ok_or("missing result row")?;
+    assert!(row.contains("0.923"), "got: {row}");
+    assert!(row.contains("src/auth/login.rs"), "got: {row}");
+    assert!(row.ends_with("pub fn authenticate() {}"), "got: {row}");
+    Ok(())
+}
+
+#[rstest]
+fn test_table_reports_empty_results() -> Result<(), Box<dyn std::error::Error>> {
+    let mut out = Vec::new();
+    write_table(&mut out, &[])?;
+    assert_eq!(String::from_utf8(out)?, "no results\n");
+    Ok(())
+}
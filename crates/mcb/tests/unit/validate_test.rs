@@ -1,6 +1,8 @@
 //! Integration tests for the validate command.
 
+use clap::Parser;
 use mcb::cli::validate::ValidateArgs;
+use mcb::cli::{OutputFormat, ReportFormat};
 use rstest::*;
 use std::fs;
 
@@ -22,21 +24,34 @@ fn clean_workspace(workspace_root: std::path::PathBuf) -> std::path::PathBuf {
 }
 
 #[rstest]
-fn test_validate_execution(clean_workspace: std::path::PathBuf) {
+#[tokio::test]
+async fn test_validate_execution(clean_workspace: std::path::PathBuf) {
     let args = ValidateArgs {
+        command: None,
         path: clean_workspace.clone(),
         quick: true,
         strict: false,
         rules: None,
         validators: None,
         severity: "warning".to_owned(),
-        format: "text".to_owned(),
+        format: ReportFormat::Text,
+        changed_since: None,
+        staged: false,
+        write_baseline: false,
+        baseline: None,
+        no_baseline: false,
+        history: None,
+        fix: false,
+        dry_run: false,
+        no_cache: true,
+        lsp: false,
+        bless: false,
         silent: true,
         debug: false,
         trace: false,
     };
 
-    let result = args.execute();
+    let result = args.execute(OutputFormat::Table).await;
 
     // Cleanup
     let _ = fs::remove_dir_all(&clean_workspace);
@@ -50,21 +65,34 @@ fn test_validate_execution(clean_workspace: std::path::PathBuf) {
 }
 
 #[rstest]
-fn test_validate_strict_mode(clean_workspace: std::path::PathBuf) {
+#[tokio::test]
+async fn test_validate_strict_mode(clean_workspace: std::path::PathBuf) {
     let args = ValidateArgs {
+        command: None,
         path: clean_workspace.clone(),
         quick: true,
         strict: true,
         rules: None,
         validators: None,
         severity: "warning".to_owned(),
-        format: "text".to_owned(),
+        format: ReportFormat::Text,
+        changed_since: None,
+        staged: false,
+        write_baseline: false,
+        baseline: None,
+        no_baseline: false,
+        history: None,
+        fix: false,
+        dry_run: false,
+        no_cache: true,
+        lsp: false,
+        bless: false,
         silent: true,
         debug: false,
         trace: false,
     };
 
-    let result = args.execute();
+    let result = args.execute(OutputFormat::Table).await;
 
     // Cleanup
     let _ = fs::remove_dir_all(&clean_workspace);
@@ -77,3 +105,47 @@ fn test_validate_strict_mode(clean_workspace: std::path::PathBuf) {
         "Should pass strict mode in clean workspace"
     );
 }
+
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(clap::Subcommand)]
+enum Command {
+    Validate(ValidateArgs),
+}
+
+fn parse_validate(flags: &[&str]) -> ValidateArgs {
+    let argv = ["mcb", "validate"].into_iter().chain(flags.iter().copied());
+    match Cli::try_parse_from(argv)
+        .unwrap_or_else(|e| unreachable!("parse validate flags: {e}"))
+        .command
+    {
+        Command::Validate(args) => args,
+    }
+}
+
+#[rstest]
+#[case::report_with_table(&["--format", "sarif"], OutputFormat::Table, true)]
+#[case::json_twice(&["--format", "json"], OutputFormat::Json, true)]
+#[case::default_with_ndjson(&[], OutputFormat::Ndjson, true)]
+#[case::sarif_with_json(&["--format", "sarif"], OutputFormat::Json, false)]
+#[case::json_with_ndjson(&["--format", "json"], OutputFormat::Ndjson, false)]
+fn format_must_agree_with_output(
+    #[case] flags: &[&str],
+    #[case] output: OutputFormat,
+    #[case] agrees: bool,
+) {
+    assert_eq!(parse_validate(flags).check_format(output).is_ok(), agrees);
+}
+
+#[rstest]
+fn unknown_format_is_rejected_by_the_parser() {
+    assert!(Cli::try_parse_from(["mcb", "validate", "--format", "xml"]).is_err());
+    assert_eq!(
+        parse_validate(&["--format", "junit"]).format,
+        ReportFormat::Junit
+    );
+}
@@ -54,6 +54,15 @@ case "$(SCOPE)" in \
 esac
 endef
 
+# --- bench (provider benchmarks; SCOPE selects one bench target) -------------
+define DISPATCH_BENCH
+@case "$(SCOPE)" in \
+  '')                        cargo bench -p mcb-providers --features testkit --benches ;; \
+  search|chunking|embedding) cargo bench -p mcb-providers --features testkit --bench $(SCOPE) ;; \
+  *)                         printf "ERRO: SCOPE '%s' invalido. Validos: search chunking embedding\n" "$(SCOPE)" >&2; exit 2 ;; \
+esac
+endef
+
 define MCB_E2E
 echo "Running Playwright E2E on port $(MCB_TEST_PORT)..."; \
 lsof -ti:$(MCB_TEST_PORT) | xargs -r kill -9 2>/dev/null || true; sleep 1; \
@@ -70,6 +79,7 @@ define DISPATCH_CHECK
 @case "$(WHAT)" in \
   fmt)      cargo fmt --all -- --check ;; \
   lint)     cargo fmt --all -- --check && cargo clippy --all-targets -- -D warnings ;; \
+  targets)  cargo check -p mcb-providers --no-default-features && cargo build -p mcb-wasm --target wasm32-unknown-unknown ;; \
   validate) bash $(MCB_SH) validate $(if $(filter 1,$(QUICK)),quick,full) ;; \
   audit)    cargo audit $(foreach i,$(MCB_AUDIT_IGNORES),--ignore $(i)) && $(MAKE) check WHAT=udeps ;; \
   udeps)    command -v cargo-udeps >/dev/null 2>&1 || cargo install cargo-udeps; cargo +nightly udeps --workspace ;; \